@@ -14,7 +14,7 @@ static START_TIME: LazyLock<std::time::Instant> = LazyLock::new(std::time::Insta
 
 use nova_vm::{
     ecmascript::{
-        Agent, AgentOptions, ArgumentsList, Behaviour, BigInt, BuiltinFunctionArgs, ExceptionType,
+        Agent, AgentBuilder, ArgumentsList, Behaviour, BigInt, BuiltinFunctionArgs, ExceptionType,
         Function, GcAgent, InternalMethods, JsResult, Number, Object, OrdinaryObject,
         PropertyDescriptor, PropertyKey, RegularFn, SharedArrayBuffer, String, Value,
         create_builtin_function, parse_script, script_evaluation, unwrap_try,
@@ -318,15 +318,11 @@ pub fn initialize_global_object_with_internals(agent: &mut Agent, global: Object
         host_hooks.add_child(child_sender);
         let _ = thread::spawn(|| {
             let child_hooks = &*Box::leak(Box::new(child_hooks));
-            let mut child_agent = GcAgent::new(
-                AgentOptions {
-                    disable_gc: false,
-                    print_internals: false,
-                    // Always allow children to block.
-                    no_block: false,
-                },
-                child_hooks,
-            );
+            let mut child_agent = AgentBuilder::new()
+                // Always allow children to block.
+                .with_can_block(true)
+                .with_host_hooks(child_hooks)
+                .build();
             let create_global_object: Option<
                 for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>,
             > = None;
@@ -616,35 +612,33 @@ fn initialize_child_global_object(agent: &mut Agent, global: Object, mut gc: GcS
 
     let property_key = PropertyKey::from_static_str(agent, "$262", gc);
     let test262_obj = OrdinaryObject::create_empty_object(agent, gc);
-    unwrap_try(global.try_define_own_property(
-        agent,
-        property_key,
-        PropertyDescriptor {
-            value: Some(test262_obj.into()),
-            writable: Some(true),
-            enumerable: Some(false),
-            configurable: Some(true),
-            ..Default::default()
-        },
-        None,
-        gc,
-    ));
+    unwrap_try(
+        global.try_define_own_property(
+            agent,
+            property_key,
+            PropertyDescriptor::data(test262_obj)
+                .writable()
+                .configurable()
+                .build(),
+            None,
+            gc,
+        ),
+    );
 
     let property_key = PropertyKey::from_static_str(agent, "agent", gc);
     let agent_obj = OrdinaryObject::create_empty_object(agent, gc);
-    unwrap_try(test262_obj.try_define_own_property(
-        agent,
-        property_key,
-        PropertyDescriptor {
-            value: Some(agent_obj.into()),
-            writable: Some(true),
-            enumerable: Some(false),
-            configurable: Some(true),
-            ..Default::default()
-        },
-        None,
-        gc,
-    ));
+    unwrap_try(
+        test262_obj.try_define_own_property(
+            agent,
+            property_key,
+            PropertyDescriptor::data(agent_obj)
+                .writable()
+                .configurable()
+                .build(),
+            None,
+            gc,
+        ),
+    );
 
     create_obj_func(
         agent,
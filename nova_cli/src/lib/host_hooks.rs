@@ -121,19 +121,11 @@ impl HostHooks for CliHostHooks {
     ) {
         let specifier = module_request.specifier(agent);
         let specifier = specifier.to_string_lossy(agent);
-        let get_referrer_path = || {
-            referrer
-                .host_defined(agent)
-                .unwrap()
-                .downcast::<PathBuf>()
-                .unwrap()
-        };
+        let get_referrer_path = || referrer.host_defined_as::<PathBuf>(agent).unwrap();
         let specifier_target = crate::module_map::specifier_target(specifier, get_referrer_path);
         let realm = referrer.realm(agent, gc);
         let module_map = realm
-            .host_defined(agent)
-            .expect("No referrer realm [[HostDefined]] slot")
-            .downcast::<super::ModuleMap>()
+            .host_defined_as::<super::ModuleMap>(agent)
             .expect("No referrer realm ModuleMap");
         if let Some(module) = module_map.get(agent, &specifier_target, gc) {
             finish_loading_imported_module(
@@ -21,7 +21,7 @@ pub use module_map::ModuleMap;
 
 use globals::{initialize_global_object, initialize_global_object_with_internals};
 use nova_vm::{
-    ecmascript::{Agent, AgentOptions, GcAgent, Job, JsResult, Object, RealmRoot, Value},
+    ecmascript::{Agent, AgentBuilder, GcAgent, Job, JsResult, Object, RealmRoot, Value},
     engine::{Bindable, GcScope, NoGcScope},
 };
 use std::rc::Rc;
@@ -115,15 +115,13 @@ unsafe fn extend_lifetime<'a, 'b, T>(reference: &'a T) -> &'b T {
 impl Instance {
     pub fn new(config: InstanceConfig) -> Self {
         let host_hooks = Box::new(CliHostHooks::new());
-        let mut agent = GcAgent::new(
-            AgentOptions {
-                disable_gc: !config.enable_gc,
-                print_internals: config.verbose,
-                no_block: !config.block,
-            },
+        let mut agent = AgentBuilder::new()
+            .with_gc_disabled(!config.enable_gc)
+            .with_print_internals(config.verbose)
+            .with_can_block(config.block)
             // SAFETY: We keep the host hooks alive for at least as long as the agent
-            unsafe { extend_lifetime(&*host_hooks) as &'static _ },
-        );
+            .with_host_hooks(unsafe { extend_lifetime(&*host_hooks) as &'static _ })
+            .build();
 
         let create_global_object: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> =
             None;
@@ -177,10 +175,5 @@ impl Instance {
 }
 
 pub fn get_module_map(agent: &Agent, nogc: NoGcScope) -> Rc<ModuleMap> {
-    agent
-        .current_realm(nogc)
-        .host_defined(agent)
-        .unwrap()
-        .downcast()
-        .unwrap()
+    agent.current_realm(nogc).host_defined_as(agent).unwrap()
 }
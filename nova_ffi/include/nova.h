@@ -0,0 +1,79 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+/*
+ * C API for embedding the Nova JavaScript engine.
+ *
+ * See the documentation of the nova_ffi crate for the ownership rules of
+ * agents, value handles, and returned strings.
+ */
+
+#ifndef NOVA_H
+#define NOVA_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct NovaAgent NovaAgent;
+typedef struct NovaValue NovaValue;
+
+typedef enum NovaStatus {
+  NOVA_STATUS_OK = 0,
+  NOVA_STATUS_EXCEPTION = 1,
+  NOVA_STATUS_INVALID_ARGUMENT = 2,
+  NOVA_STATUS_TYPE_MISMATCH = 3,
+  NOVA_STATUS_FATAL = 4,
+} NovaStatus;
+
+typedef enum NovaValueType {
+  NOVA_VALUE_TYPE_UNDEFINED = 0,
+  NOVA_VALUE_TYPE_NULL = 1,
+  NOVA_VALUE_TYPE_BOOLEAN = 2,
+  NOVA_VALUE_TYPE_NUMBER = 3,
+  NOVA_VALUE_TYPE_BIGINT = 4,
+  NOVA_VALUE_TYPE_STRING = 5,
+  NOVA_VALUE_TYPE_SYMBOL = 6,
+  NOVA_VALUE_TYPE_OBJECT = 7,
+} NovaValueType;
+
+NovaAgent *nova_agent_new(void);
+void nova_agent_free(NovaAgent *agent);
+void nova_agent_gc(NovaAgent *agent);
+const char *nova_agent_fatal_error(const NovaAgent *agent, size_t *len);
+
+NovaStatus nova_eval_script(NovaAgent *agent, const char *source,
+                            size_t source_len, NovaValue **result);
+
+NovaStatus nova_global_get(NovaAgent *agent, const char *name, size_t name_len,
+                           NovaValue **result);
+NovaStatus nova_global_set(NovaAgent *agent, const char *name, size_t name_len,
+                           const NovaValue *value, NovaValue **exception);
+
+NovaValue *nova_value_undefined(NovaAgent *agent);
+NovaValue *nova_value_null(NovaAgent *agent);
+NovaValue *nova_value_from_boolean(NovaAgent *agent, bool value);
+NovaValue *nova_value_from_number(NovaAgent *agent, double value);
+NovaValue *nova_value_from_string(NovaAgent *agent, const char *data,
+                                  size_t len);
+void nova_value_free(NovaAgent *agent, NovaValue *value);
+
+NovaValueType nova_value_type(NovaAgent *agent, const NovaValue *value);
+NovaStatus nova_value_get_boolean(NovaAgent *agent, const NovaValue *value,
+                                  bool *result);
+NovaStatus nova_value_to_number(NovaAgent *agent, const NovaValue *value,
+                                double *result, NovaValue **exception);
+NovaStatus nova_value_to_string(NovaAgent *agent, const NovaValue *value,
+                                char **result, size_t *result_len,
+                                NovaValue **exception);
+void nova_string_free(char *data, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NOVA_H */
@@ -0,0 +1,750 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! # Nova C API
+//!
+//! This crate exposes a C-compatible API for embedding Nova from non-Rust
+//! hosts. The matching C header is found in `include/nova.h`.
+//!
+//! ## Handles
+//!
+//! A [`NovaAgent`] owns a Nova Agent together with its default Realm. All
+//! other functions take the agent as their first parameter.
+//!
+//! JavaScript values are passed across the API as [`NovaValue`] handles.
+//! Each handle roots its value as a [`Global`], so the value stays alive
+//! across garbage collections until the handle is released with
+//! [`nova_value_free`]. Handles belong to the agent that created them and
+//! must be released before that agent is freed.
+//!
+//! ## Errors
+//!
+//! Fallible functions return a [`NovaStatus`]. If JavaScript code throws,
+//! [`NovaStatus::Exception`] is returned and the thrown value is written into
+//! the `exception` out-parameter when one is given.
+//!
+//! If the engine itself fails, for instance because it panics, the agent is
+//! terminated and [`NovaStatus::Fatal`] is returned. From then on every call
+//! that would run in the agent returns [`NovaStatus::Fatal`], or null where
+//! a handle is returned, without touching the agent again; the failure is
+//! described by [`nova_agent_fatal_error`]. A terminated agent must still be
+//! released with [`nova_agent_free`].
+//!
+//! Strings are passed in as UTF-8 pointer and length pairs and are returned
+//! as NUL-terminated UTF-8 buffers that must be released with
+//! [`nova_string_free`]. Lone surrogates are replaced with U+FFFD when a
+//! string is returned to the host.
+
+use core::{ffi::c_char, ptr, slice, str};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ExceptionType, GcAgent, InternalMethods, JsResult, PropertyKey,
+        RealmRoot, Value,
+    },
+    engine::{Bindable, GcScope, Global, NoGcScope},
+};
+
+/// A Nova Agent together with the Realm that scripts are evaluated in.
+pub struct NovaAgent {
+    agent: GcAgent,
+    realm: RealmRoot,
+    /// The NUL-terminated message of the fatal error that terminated the
+    /// agent, if any.
+    fatal_error: Option<Box<[u8]>>,
+}
+
+impl NovaAgent {
+    /// Run a closure in the agent's realm.
+    ///
+    /// Returns `None` without running the closure if the agent has been
+    /// terminated, and terminates the agent if the engine panics.
+    fn run<R>(&mut self, func: impl FnOnce(&mut Agent, GcScope) -> R) -> Option<R> {
+        if self.fatal_error.is_some() {
+            return None;
+        }
+        match self.agent.try_run_in_realm(&self.realm, func) {
+            Ok(result) => Some(result),
+            Err(error) => {
+                self.fatal_error = Some(nul_terminated(&error.to_string()));
+                None
+            }
+        }
+    }
+
+    /// Run a garbage collection unless the agent has been terminated.
+    fn gc(&mut self) {
+        if self.fatal_error.is_some() {
+            return;
+        }
+        if catch_unwind(AssertUnwindSafe(|| self.agent.gc())).is_err() {
+            self.fatal_error = Some(nul_terminated("Engine panicked during garbage collection"));
+        }
+    }
+}
+
+/// A rooted JavaScript value.
+pub struct NovaValue(Global<Value<'static>>);
+
+/// Result of a fallible API call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NovaStatus {
+    /// The call completed normally.
+    Ok = 0,
+    /// JavaScript code threw an exception.
+    Exception = 1,
+    /// A required pointer was null or a string was not valid UTF-8.
+    InvalidArgument = 2,
+    /// The value was not of the requested type.
+    TypeMismatch = 3,
+    /// The engine failed and the agent was terminated; see
+    /// [`nova_agent_fatal_error`].
+    Fatal = 4,
+}
+
+/// The ECMAScript language type of a value.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NovaValueType {
+    Undefined = 0,
+    Null = 1,
+    Boolean = 2,
+    Number = 3,
+    BigInt = 4,
+    String = 5,
+    Symbol = 6,
+    Object = 7,
+}
+
+/// Root a value into a new handle owned by the caller.
+fn root_value(agent: &Agent, value: Value) -> *mut NovaValue {
+    Box::into_raw(Box::new(NovaValue(Global::new(agent, value.unbind()))))
+}
+
+/// Write a thrown value into an optional out-parameter and return
+/// [`NovaStatus::Exception`].
+///
+/// # Safety
+///
+/// `exception` must be null or valid for writes.
+unsafe fn write_exception(
+    agent: &Agent,
+    exception: *mut *mut NovaValue,
+    value: Value,
+) -> NovaStatus {
+    if !exception.is_null() {
+        // SAFETY: Caller guarantees exception is valid for writes.
+        unsafe { exception.write(root_value(agent, value)) };
+    }
+    NovaStatus::Exception
+}
+
+/// Write the result of an evaluation into an out-parameter.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write_completion(
+    agent: &Agent,
+    out: *mut *mut NovaValue,
+    result: JsResult<Value>,
+) -> NovaStatus {
+    match result {
+        Ok(value) => {
+            if !out.is_null() {
+                // SAFETY: Caller guarantees out is valid for writes.
+                unsafe { out.write(root_value(agent, value)) };
+            }
+            NovaStatus::Ok
+        }
+        // SAFETY: Caller guarantees out is valid for writes.
+        Err(err) => unsafe { write_exception(agent, out, err.value()) },
+    }
+}
+
+/// Copy a string into a NUL-terminated buffer.
+fn nul_terminated(string: &str) -> Box<[u8]> {
+    let mut bytes = Vec::with_capacity(string.len() + 1);
+    bytes.extend_from_slice(string.as_bytes());
+    bytes.push(0);
+    bytes.into_boxed_slice()
+}
+
+/// Read a UTF-8 string from a pointer and length pair.
+///
+/// # Safety
+///
+/// If `data` is not null, it must be valid for reads of `len` bytes for the
+/// returned lifetime.
+unsafe fn read_str<'a>(data: *const c_char, len: usize) -> Option<&'a str> {
+    if data.is_null() {
+        return if len == 0 { Some("") } else { None };
+    }
+    // SAFETY: Caller guarantees data is valid for reads of len bytes.
+    let bytes = unsafe { slice::from_raw_parts(data.cast::<u8>(), len) };
+    str::from_utf8(bytes).ok()
+}
+
+/// Get the value behind a handle.
+///
+/// # Safety
+///
+/// `value` must be a live handle created by the same agent.
+unsafe fn get_value<'a>(
+    agent: &Agent,
+    value: *const NovaValue,
+    gc: NoGcScope<'a, '_>,
+) -> Value<'a> {
+    // SAFETY: Caller guarantees value is a live handle.
+    unsafe { &*value }.0.get(agent, gc).bind(gc)
+}
+
+/// Create a new agent with a default realm.
+///
+/// The returned agent must be released with [`nova_agent_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn nova_agent_new() -> *mut NovaAgent {
+    let (agent, realm) = AgentBuilder::new().build_with_default_realm();
+    Box::into_raw(Box::new(NovaAgent {
+        agent,
+        realm,
+        fatal_error: None,
+    }))
+}
+
+/// Release an agent created by [`nova_agent_new`].
+///
+/// # Safety
+///
+/// `agent` must be null or an agent created by [`nova_agent_new`] that has
+/// not yet been freed. All value handles created by the agent must have been
+/// released beforehand.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_agent_free(agent: *mut NovaAgent) {
+    if !agent.is_null() {
+        // SAFETY: Caller guarantees agent was created by nova_agent_new.
+        drop(unsafe { Box::from_raw(agent) });
+    }
+}
+
+/// Run a garbage collection in the agent.
+///
+/// Values referenced by live [`NovaValue`] handles are kept alive.
+///
+/// # Safety
+///
+/// `agent` must be null or a live agent.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_agent_gc(agent: *mut NovaAgent) {
+    // SAFETY: Caller guarantees agent is null or live.
+    if let Some(agent) = unsafe { agent.as_mut() } {
+        agent.gc();
+    }
+}
+
+/// Get the message of the fatal error that terminated the agent.
+///
+/// Returns null if the agent has not been terminated. Otherwise the length
+/// of the message, excluding the NUL terminator, is written into `len` when
+/// it is not null. The message is owned by the agent and stays valid until
+/// the agent is freed.
+///
+/// # Safety
+///
+/// `agent` must be null or a live agent, and `len` must be null or valid for
+/// writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_agent_fatal_error(
+    agent: *const NovaAgent,
+    len: *mut usize,
+) -> *const c_char {
+    // SAFETY: Caller guarantees agent is null or live.
+    let Some(message) = (unsafe { agent.as_ref() }).and_then(|agent| agent.fatal_error.as_ref())
+    else {
+        return ptr::null();
+    };
+    if !len.is_null() {
+        // SAFETY: Caller guarantees len is valid for writes.
+        unsafe { len.write(message.len() - 1) };
+    }
+    message.as_ptr().cast()
+}
+
+/// Evaluate UTF-8 source text as a Script in the agent's realm.
+///
+/// On success the completion value is written into `result`. If the script
+/// throws, including when it fails to parse, the thrown value is written
+/// into `result` instead and [`NovaStatus::Exception`] is returned. `result`
+/// may be null if the caller is not interested in the value.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `source` must be valid for reads of
+/// `source_len` bytes, and `result` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_eval_script(
+    agent: *mut NovaAgent,
+    source: *const c_char,
+    source_len: usize,
+    result: *mut *mut NovaValue,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees source is valid for reads.
+    let (Some(agent), Some(source)) = (unsafe { agent.as_mut() }, unsafe {
+        read_str(source, source_len)
+    }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    agent
+        .run(|agent, mut gc| {
+            let source_text = nova_vm::ecmascript::String::from_str(agent, source, gc.nogc());
+            let completion = agent
+                .run_script(source_text.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            // SAFETY: Caller guarantees result is null or valid for writes.
+            unsafe { write_completion(agent, result, completion) }
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Read a property of the realm's global object.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `name` must be valid for reads of
+/// `name_len` bytes, and `result` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_global_get(
+    agent: *mut NovaAgent,
+    name: *const c_char,
+    name_len: usize,
+    result: *mut *mut NovaValue,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees name is valid for reads.
+    let (Some(agent), Some(name)) = (unsafe { agent.as_mut() }, unsafe {
+        read_str(name, name_len)
+    }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    if result.is_null() {
+        return NovaStatus::InvalidArgument;
+    }
+    agent
+        .run(|agent, mut gc| {
+            let global = agent.current_global_object(gc.nogc());
+            let key = PropertyKey::from_str(agent, name, gc.nogc());
+            let completion = global
+                .unbind()
+                .internal_get(agent, key.unbind(), global.unbind().into(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            // SAFETY: Caller guarantees result is valid for writes.
+            unsafe { write_completion(agent, result, completion) }
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Set a property of the realm's global object.
+///
+/// Like assignment in strict mode code, a failed assignment throws a
+/// TypeError. `exception` may be null if the caller is not interested in
+/// the thrown value.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `name` must be valid for reads of
+/// `name_len` bytes, `value` must be a live handle created by `agent`, and
+/// `exception` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_global_set(
+    agent: *mut NovaAgent,
+    name: *const c_char,
+    name_len: usize,
+    value: *const NovaValue,
+    exception: *mut *mut NovaValue,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees name is valid for reads.
+    let (Some(agent), Some(name)) = (unsafe { agent.as_mut() }, unsafe {
+        read_str(name, name_len)
+    }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    if value.is_null() {
+        return NovaStatus::InvalidArgument;
+    }
+    agent
+        .run(|agent, mut gc| {
+            // SAFETY: Caller guarantees value is a live handle.
+            let value = unsafe { get_value(agent, value, gc.nogc()) };
+            let global = agent.current_global_object(gc.nogc());
+            let key = PropertyKey::from_str(agent, name, gc.nogc());
+            let completion = global
+                .unbind()
+                .internal_set(
+                    agent,
+                    key.unbind(),
+                    value.unbind(),
+                    global.unbind().into(),
+                    gc.reborrow(),
+                )
+                .unbind();
+            let gc = gc.into_nogc();
+            let thrown = match completion {
+                Ok(true) => return NovaStatus::Ok,
+                Ok(false) => agent
+                    .throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Cannot assign to global property",
+                        gc,
+                    )
+                    .value(),
+                Err(err) => err.value().bind(gc),
+            };
+            // SAFETY: Caller guarantees exception is null or valid for writes.
+            unsafe { write_exception(agent, exception, thrown) }
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Create a handle to `undefined`.
+///
+/// # Safety
+///
+/// `agent` must be a live agent.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_undefined(agent: *mut NovaAgent) -> *mut NovaValue {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return ptr::null_mut();
+    };
+    agent
+        .run(|agent, _| root_value(agent, Value::Undefined))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Create a handle to `null`.
+///
+/// # Safety
+///
+/// `agent` must be a live agent.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_null(agent: *mut NovaAgent) -> *mut NovaValue {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return ptr::null_mut();
+    };
+    agent
+        .run(|agent, _| root_value(agent, Value::Null))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Create a handle to a Boolean value.
+///
+/// # Safety
+///
+/// `agent` must be a live agent.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_from_boolean(
+    agent: *mut NovaAgent,
+    value: bool,
+) -> *mut NovaValue {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return ptr::null_mut();
+    };
+    agent
+        .run(|agent, _| root_value(agent, Value::Boolean(value)))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Create a handle to a Number value.
+///
+/// # Safety
+///
+/// `agent` must be a live agent.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_from_number(
+    agent: *mut NovaAgent,
+    value: f64,
+) -> *mut NovaValue {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return ptr::null_mut();
+    };
+    agent
+        .run(|agent, gc| {
+            let value = Value::from_f64(agent, value, gc.nogc());
+            root_value(agent, value)
+        })
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Create a handle to a String value from UTF-8 data.
+///
+/// Returns null if the data is not valid UTF-8.
+///
+/// # Safety
+///
+/// `agent` must be a live agent and `data` must be valid for reads of `len`
+/// bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_from_string(
+    agent: *mut NovaAgent,
+    data: *const c_char,
+    len: usize,
+) -> *mut NovaValue {
+    // SAFETY: Caller guarantees data is valid for reads.
+    let (Some(agent), Some(data)) = (unsafe { agent.as_mut() }, unsafe { read_str(data, len) })
+    else {
+        return ptr::null_mut();
+    };
+    agent
+        .run(|agent, gc| {
+            let value = Value::from_str(agent, data, gc.nogc());
+            root_value(agent, value)
+        })
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Release a value handle.
+///
+/// # Safety
+///
+/// `agent` must be a live agent and `value` must be null or a live handle
+/// created by `agent`. The handle must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_free(agent: *mut NovaAgent, value: *mut NovaValue) {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return;
+    };
+    if value.is_null() {
+        return;
+    }
+    // SAFETY: Caller guarantees value is a live handle.
+    let value = unsafe { Box::from_raw(value) };
+    let _ = agent.run(|agent, _| {
+        value.0.take(agent);
+    });
+}
+
+/// Get the ECMAScript language type of a value.
+///
+/// Returns [`NovaValueType::Undefined`] if the agent has been terminated.
+///
+/// # Safety
+///
+/// `agent` must be a live agent and `value` must be a live handle created by
+/// `agent`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_type(
+    agent: *mut NovaAgent,
+    value: *const NovaValue,
+) -> NovaValueType {
+    // SAFETY: Caller guarantees agent is live.
+    let agent = unsafe { &mut *agent };
+    agent
+        .run(|agent, gc| {
+            // SAFETY: Caller guarantees value is a live handle.
+            let value = unsafe { get_value(agent, value, gc.nogc()) };
+            if value.is_undefined() {
+                NovaValueType::Undefined
+            } else if value.is_null() {
+                NovaValueType::Null
+            } else if value.is_boolean() {
+                NovaValueType::Boolean
+            } else if value.is_number() {
+                NovaValueType::Number
+            } else if value.is_bigint() {
+                NovaValueType::BigInt
+            } else if value.is_string() {
+                NovaValueType::String
+            } else if value.is_symbol() {
+                NovaValueType::Symbol
+            } else {
+                NovaValueType::Object
+            }
+        })
+        .unwrap_or(NovaValueType::Undefined)
+}
+
+/// Read a Boolean value.
+///
+/// Returns [`NovaStatus::TypeMismatch`] if the value is not a Boolean.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `value` must be a live handle created by
+/// `agent`, and `result` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_get_boolean(
+    agent: *mut NovaAgent,
+    value: *const NovaValue,
+    result: *mut bool,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    if value.is_null() || result.is_null() {
+        return NovaStatus::InvalidArgument;
+    }
+    agent
+        .run(|agent, gc| {
+            // SAFETY: Caller guarantees value is a live handle.
+            let Value::Boolean(boolean) = (unsafe { get_value(agent, value, gc.nogc()) }) else {
+                return NovaStatus::TypeMismatch;
+            };
+            // SAFETY: Caller guarantees result is valid for writes.
+            unsafe { result.write(boolean) };
+            NovaStatus::Ok
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Convert a value to a Number using the ToNumber abstract operation.
+///
+/// Objects may run JavaScript code during the conversion. If that code
+/// throws, the thrown value is written into `exception`, which may be null.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `value` must be a live handle created by
+/// `agent`, `result` must be valid for writes, and `exception` must be null
+/// or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_to_number(
+    agent: *mut NovaAgent,
+    value: *const NovaValue,
+    result: *mut f64,
+    exception: *mut *mut NovaValue,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    if value.is_null() || result.is_null() {
+        return NovaStatus::InvalidArgument;
+    }
+    agent
+        .run(|agent, mut gc| {
+            // SAFETY: Caller guarantees value is a live handle.
+            let value = unsafe { get_value(agent, value, gc.nogc()) };
+            match value.unbind().to_real(agent, gc.reborrow()).unbind() {
+                Ok(number) => {
+                    // SAFETY: Caller guarantees result is valid for writes.
+                    unsafe { result.write(number) };
+                    NovaStatus::Ok
+                }
+                // SAFETY: Caller guarantees exception is null or valid for writes.
+                Err(err) => unsafe { write_exception(agent, exception, err.value()) },
+            }
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Convert a value to a String using the ToString abstract operation.
+///
+/// On success a NUL-terminated UTF-8 buffer is written into `result` and its
+/// length, excluding the terminator, into `result_len`. The buffer must be
+/// released with [`nova_string_free`]. Note that the string may itself
+/// contain NUL characters.
+///
+/// Objects may run JavaScript code during the conversion. If that code
+/// throws, the thrown value is written into `exception`, which may be null.
+///
+/// # Safety
+///
+/// `agent` must be a live agent, `value` must be a live handle created by
+/// `agent`, `result` and `result_len` must be valid for writes, and
+/// `exception` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_value_to_string(
+    agent: *mut NovaAgent,
+    value: *const NovaValue,
+    result: *mut *mut c_char,
+    result_len: *mut usize,
+    exception: *mut *mut NovaValue,
+) -> NovaStatus {
+    // SAFETY: Caller guarantees agent is live.
+    let Some(agent) = (unsafe { agent.as_mut() }) else {
+        return NovaStatus::InvalidArgument;
+    };
+    if value.is_null() || result.is_null() || result_len.is_null() {
+        return NovaStatus::InvalidArgument;
+    }
+    agent
+        .run(|agent, mut gc| {
+            // SAFETY: Caller guarantees value is a live handle.
+            let value = unsafe { get_value(agent, value, gc.nogc()) };
+            match value.unbind().to_string(agent, gc.reborrow()).unbind() {
+                Ok(string) => {
+                    let string = string.to_string_lossy(agent);
+                    let len = string.len();
+                    let data = Box::into_raw(nul_terminated(&string));
+                    // SAFETY: Caller guarantees result and result_len are valid
+                    // for writes.
+                    unsafe {
+                        result.write(data.cast::<c_char>());
+                        result_len.write(len);
+                    }
+                    NovaStatus::Ok
+                }
+                // SAFETY: Caller guarantees exception is null or valid for writes.
+                Err(err) => unsafe { write_exception(agent, exception, err.value()) },
+            }
+        })
+        .unwrap_or(NovaStatus::Fatal)
+}
+
+/// Release a string returned by [`nova_value_to_string`].
+///
+/// # Safety
+///
+/// `data` must be null or a string returned by [`nova_value_to_string`]
+/// together with its length. The string must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nova_string_free(data: *mut c_char, len: usize) {
+    if data.is_null() {
+        return;
+    }
+    // SAFETY: Caller guarantees data and len were returned by
+    // nova_value_to_string; the allocation includes the NUL terminator.
+    drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(data.cast::<u8>(), len + 1)) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_terminate_the_agent() {
+        unsafe {
+            let agent = nova_agent_new();
+            let value = nova_value_from_number(agent, 1.0);
+            assert!(nova_agent_fatal_error(agent, ptr::null_mut()).is_null());
+
+            assert!((*agent).run(|_, _| panic!("engine failed")).is_none());
+            let mut len = 0;
+            let message = nova_agent_fatal_error(agent, &mut len);
+            let message = slice::from_raw_parts(message.cast::<u8>(), len + 1);
+            assert_eq!(message, b"Engine panicked: engine failed\0");
+
+            // The terminated agent is not touched again.
+            let source = "1";
+            let status =
+                nova_eval_script(agent, source.as_ptr().cast(), source.len(), ptr::null_mut());
+            assert_eq!(status, NovaStatus::Fatal);
+            let mut number = 0.0;
+            let status = nova_value_to_number(agent, value, &mut number, ptr::null_mut());
+            assert_eq!(status, NovaStatus::Fatal);
+            assert!(nova_value_null(agent).is_null());
+            nova_agent_gc(agent);
+            nova_value_free(agent, value);
+            nova_agent_free(agent);
+        }
+    }
+}
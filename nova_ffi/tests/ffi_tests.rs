@@ -0,0 +1,157 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{ffi::c_char, ptr};
+
+use nova_ffi::*;
+
+unsafe fn eval(agent: *mut NovaAgent, source: &str) -> (NovaStatus, *mut NovaValue) {
+    let mut result = ptr::null_mut();
+    let status =
+        unsafe { nova_eval_script(agent, source.as_ptr().cast(), source.len(), &mut result) };
+    (status, result)
+}
+
+unsafe fn to_string(agent: *mut NovaAgent, value: *const NovaValue) -> String {
+    let mut data: *mut c_char = ptr::null_mut();
+    let mut len = 0;
+    let status =
+        unsafe { nova_value_to_string(agent, value, &mut data, &mut len, ptr::null_mut()) };
+    assert_eq!(status, NovaStatus::Ok);
+    let string = unsafe { core::slice::from_raw_parts(data.cast::<u8>(), len + 1) };
+    assert_eq!(string[len], 0);
+    let string = std::str::from_utf8(&string[..len]).unwrap().to_owned();
+    unsafe { nova_string_free(data, len) };
+    string
+}
+
+#[test]
+fn evaluate_and_convert_values() {
+    unsafe {
+        let agent = nova_agent_new();
+
+        let (status, value) = eval(agent, "[1, 2, 3].map((x) => x * 2).join()");
+        assert_eq!(status, NovaStatus::Ok);
+        assert_eq!(nova_value_type(agent, value), NovaValueType::String);
+        assert_eq!(to_string(agent, value), "2,4,6");
+        nova_value_free(agent, value);
+
+        let (status, value) = eval(agent, "({ valueOf() { return 41 + 1; } })");
+        assert_eq!(status, NovaStatus::Ok);
+        assert_eq!(nova_value_type(agent, value), NovaValueType::Object);
+        let mut number = 0.0;
+        let status = nova_value_to_number(agent, value, &mut number, ptr::null_mut());
+        assert_eq!(status, NovaStatus::Ok);
+        assert_eq!(number, 42.0);
+        let mut boolean = false;
+        let status = nova_value_get_boolean(agent, value, &mut boolean);
+        assert_eq!(status, NovaStatus::TypeMismatch);
+        nova_value_free(agent, value);
+
+        let value = nova_value_from_boolean(agent, true);
+        assert_eq!(
+            nova_value_get_boolean(agent, value, &mut boolean),
+            NovaStatus::Ok
+        );
+        assert!(boolean);
+        nova_value_free(agent, value);
+
+        let value = nova_value_from_number(agent, 0.5);
+        assert_eq!(nova_value_type(agent, value), NovaValueType::Number);
+        assert_eq!(to_string(agent, value), "0.5");
+        nova_value_free(agent, value);
+
+        let (status, value) = eval(agent, "10n ** 20n");
+        assert_eq!(status, NovaStatus::Ok);
+        assert_eq!(nova_value_type(agent, value), NovaValueType::BigInt);
+        assert_eq!(to_string(agent, value), "100000000000000000000");
+        nova_value_free(agent, value);
+
+        let undefined = nova_value_undefined(agent);
+        let null = nova_value_null(agent);
+        assert_eq!(nova_value_type(agent, undefined), NovaValueType::Undefined);
+        assert_eq!(nova_value_type(agent, null), NovaValueType::Null);
+        nova_value_free(agent, undefined);
+        nova_value_free(agent, null);
+
+        nova_agent_free(agent);
+    }
+}
+
+#[test]
+fn globals_and_exceptions() {
+    unsafe {
+        let agent = nova_agent_new();
+
+        let name = "greeting";
+        let text = "héllo, wörld with a long enough text to be heap allocated";
+        let value = nova_value_from_string(agent, text.as_ptr().cast(), text.len());
+        let status = nova_global_set(
+            agent,
+            name.as_ptr().cast(),
+            name.len(),
+            value,
+            ptr::null_mut(),
+        );
+        assert_eq!(status, NovaStatus::Ok);
+        nova_value_free(agent, value);
+
+        // Values set from the host survive garbage collection.
+        let (status, value) = eval(agent, "var count = greeting.length; greeting.toUpperCase()");
+        assert_eq!(status, NovaStatus::Ok);
+        nova_agent_gc(agent);
+        assert_eq!(
+            to_string(agent, value),
+            "HÉLLO, WÖRLD WITH A LONG ENOUGH TEXT TO BE HEAP ALLOCATED"
+        );
+        nova_value_free(agent, value);
+
+        let mut value = ptr::null_mut();
+        let status = nova_global_get(agent, "count".as_ptr().cast(), 5, &mut value);
+        assert_eq!(status, NovaStatus::Ok);
+        assert_eq!(to_string(agent, value), text.chars().count().to_string());
+        nova_value_free(agent, value);
+
+        let (status, error) = eval(agent, "throw new RangeError('nope')");
+        assert_eq!(status, NovaStatus::Exception);
+        assert_eq!(to_string(agent, error), "RangeError: nope");
+        nova_value_free(agent, error);
+
+        let (status, error) = eval(agent, "let x = ;");
+        assert_eq!(status, NovaStatus::Exception);
+        assert!(to_string(agent, error).starts_with("SyntaxError"));
+        nova_value_free(agent, error);
+
+        let (status, _) = eval(
+            agent,
+            "Object.defineProperty(globalThis, 'fixed', { value: 1 }); void 0",
+        );
+        assert_eq!(status, NovaStatus::Ok);
+        let value = nova_value_from_number(agent, 2.0);
+        let mut error = ptr::null_mut();
+        let status = nova_global_set(agent, "fixed".as_ptr().cast(), 5, value, &mut error);
+        assert_eq!(status, NovaStatus::Exception);
+        assert!(to_string(agent, error).starts_with("TypeError"));
+        nova_value_free(agent, error);
+        nova_value_free(agent, value);
+
+        let (status, value) = eval(agent, "({ toString() { throw 'inner'; } })");
+        assert_eq!(status, NovaStatus::Ok);
+        let mut data = ptr::null_mut();
+        let mut len = 0;
+        let mut error = ptr::null_mut();
+        let status = nova_value_to_string(agent, value, &mut data, &mut len, &mut error);
+        assert_eq!(status, NovaStatus::Exception);
+        assert_eq!(to_string(agent, error), "inner");
+        nova_value_free(agent, error);
+        nova_value_free(agent, value);
+
+        let invalid = [0xFFu8];
+        assert!(nova_value_from_string(agent, invalid.as_ptr().cast(), 1).is_null());
+        let status = nova_eval_script(agent, invalid.as_ptr().cast(), 1, ptr::null_mut());
+        assert_eq!(status, NovaStatus::InvalidArgument);
+
+        nova_agent_free(agent);
+    }
+}
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Measures the cost of suspending and resuming async functions at `await`.
+//!
+//! Run with `cargo bench -p nova_vm --bench await_resumption`.
+
+use std::{cell::RefCell, collections::VecDeque, hint::black_box};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use nova_vm::{
+    ecmascript::{AgentBuilder, GcAgent, HostHooks, Job, RealmRoot, String},
+    engine::Bindable,
+};
+
+#[derive(Default)]
+struct QueueHostHooks {
+    promise_jobs: RefCell<VecDeque<Job>>,
+}
+
+// Job doesn't implement Debug
+impl core::fmt::Debug for QueueHostHooks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueHostHooks").finish()
+    }
+}
+
+impl HostHooks for QueueHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_jobs.borrow_mut().push_back(job);
+    }
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        self.promise_jobs.borrow_mut().pop_front()
+    }
+}
+
+const SETUP: &str = r#"
+var done = 0;
+async function awaitValues(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        sum += await i;
+    }
+    done = sum;
+}
+async function awaitInTryBlock(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        try {
+            sum += await Promise.resolve(i);
+        } finally {
+            sum -= 1;
+        }
+    }
+    done = sum;
+}
+async function awaitInForOf(count) {
+    let sum = 0;
+    for (const i of Array.from({ length: count }, (_, i) => i)) {
+        sum += await i;
+    }
+    done = sum;
+}
+async function awaitChain(depth, count) {
+    if (depth === 0) {
+        await awaitValues(count);
+        return;
+    }
+    await awaitChain(depth - 1, count);
+}
+"#;
+
+fn create_agent() -> (GcAgent, RealmRoot) {
+    let host_hooks: &'static QueueHostHooks = Box::leak(Box::default());
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .build_with_default_realm();
+    run_script(&mut agent, &realm, SETUP);
+    (agent, realm)
+}
+
+fn run_script(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        if agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .is_err()
+        {
+            panic!("Benchmark script threw");
+        }
+    });
+    agent.perform_microtask_checkpoint(realm);
+}
+
+fn bench_await(c: &mut Criterion, name: &str, source: &'static str) {
+    let (mut agent, realm) = create_agent();
+    c.bench_function(name, |b| {
+        b.iter(|| run_script(&mut agent, &realm, black_box(source)))
+    });
+}
+
+fn await_resumption(c: &mut Criterion) {
+    bench_await(c, "await 10000 values", "awaitValues(10000)");
+    bench_await(c, "await 10000 promises in try", "awaitInTryBlock(10000)");
+    bench_await(c, "await 10000 values in for-of", "awaitInForOf(10000)");
+    bench_await(
+        c,
+        "await 1000 values 10 frames deep",
+        "awaitChain(10, 1000)",
+    );
+}
+
+criterion_group!(benches, await_resumption);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Measures the cost of calling ECMAScript functions.
+//!
+//! Run with `cargo bench -p nova_vm --bench function_calls`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use nova_vm::{
+    ecmascript::{AgentBuilder, GcAgent, RealmRoot, String},
+    engine::Bindable,
+};
+
+const SETUP: &str = r#"
+function add(a, b) {
+    return a + b;
+}
+function callMany(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        sum = add(sum, i);
+    }
+    return sum;
+}
+function fib(n) {
+    return n < 2 ? n : fib(n - 1) + fib(n - 2);
+}
+function callClosures(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        sum += ((x) => x * 2)(i);
+    }
+    return sum;
+}
+"#;
+
+fn run_script(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        if agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .is_err()
+        {
+            panic!("Benchmark script threw");
+        }
+    });
+}
+
+fn bench_calls(c: &mut Criterion, name: &str, source: &'static str) {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    run_script(&mut agent, &realm, SETUP);
+    c.bench_function(name, |b| {
+        b.iter(|| run_script(&mut agent, &realm, black_box(source)))
+    });
+}
+
+fn function_calls(c: &mut Criterion) {
+    bench_calls(c, "call 100000 functions", "callMany(100000)");
+    bench_calls(c, "recursive fib(20)", "fib(20)");
+    bench_calls(c, "call 100000 closures", "callClosures(100000)");
+}
+
+criterion_group!(benches, function_calls);
+criterion_main!(benches);
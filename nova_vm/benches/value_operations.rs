@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Measures operations that copy and inspect Values: property access and
+//! arithmetic on integers and doubles.
+//!
+//! Run with `cargo bench -p nova_vm --bench value_operations`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use nova_vm::{
+    ecmascript::{AgentBuilder, GcAgent, RealmRoot, String},
+    engine::Bindable,
+};
+
+const SETUP: &str = r#"
+const point = { x: 1, y: 2, z: 3 };
+const array = Array.from({ length: 1000 }, (_, i) => i);
+function readProperties(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        sum += point.x + point.y + point.z;
+    }
+    return sum;
+}
+function writeProperties(count) {
+    for (let i = 0; i < count; i++) {
+        point.x = i;
+        point.y = i + 1;
+    }
+    return point.x;
+}
+function readElements(count) {
+    let sum = 0;
+    for (let i = 0; i < count; i++) {
+        sum += array[i % 1000];
+    }
+    return sum;
+}
+function integerArithmetic(count) {
+    let a = 0;
+    for (let i = 0; i < count; i++) {
+        a = (a + i * 3 - (i >> 1)) | 0;
+    }
+    return a;
+}
+function doubleArithmetic(count) {
+    let a = 0.5;
+    for (let i = 0; i < count; i++) {
+        a = a * 1.000001 + i / 3;
+    }
+    return a;
+}
+"#;
+
+fn run_script(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        if agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .is_err()
+        {
+            panic!("Benchmark script threw");
+        }
+    });
+}
+
+fn bench_script(c: &mut Criterion, name: &str, source: &'static str) {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    run_script(&mut agent, &realm, SETUP);
+    c.bench_function(name, |b| {
+        b.iter(|| run_script(&mut agent, &realm, black_box(source)))
+    });
+}
+
+fn value_operations(c: &mut Criterion) {
+    bench_script(c, "read 100000x3 properties", "readProperties(100000)");
+    bench_script(c, "write 100000x2 properties", "writeProperties(100000)");
+    bench_script(c, "read 100000 array elements", "readElements(100000)");
+    bench_script(c, "100000 integer operations", "integerArithmetic(100000)");
+    bench_script(c, "100000 double operations", "doubleArithmetic(100000)");
+}
+
+criterion_group!(benches, value_operations);
+criterion_main!(benches);
@@ -9,11 +9,11 @@ use core::ops::ControlFlow;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, BuiltinConstructorFunction,
-        ECMAScriptCodeEvaluationState, Environment, ExceptionType, ExecutionContext, Function,
-        InternalMethods, InternalSlots, IteratorRecord, JsError, JsResult, KeyedGroup, Number,
-        Object, OrdinaryObject, PrivateName, PropertyDescriptor, PropertyKey, PropertyKeySet,
-        PropertyLookupCache, ProtoIntrinsics, Realm, SetResult, SmallInteger, String, TryError,
-        TryGetResult, TryHasResult, TryResult, Value, array_create,
+        ECMAScriptCodeEvaluationState, Environment, ExceptionType, ExecutionContext, FrameMetadata,
+        Function, InternalMethods, InternalSlots, IteratorRecord, JsError, JsResult, KeyedGroup,
+        Number, Object, OrdinaryObject, PrivateName, PropertyDescriptor, PropertyKey,
+        PropertyKeySet, PropertyLookupCache, ProtoIntrinsics, Realm, SetResult, SmallInteger,
+        String, TryError, TryGetResult, TryHasResult, TryResult, Value, array_create,
         canonicalize_keyed_collection_key, get_iterator, if_abrupt_close_iterator, is_callable,
         is_constructor, iterator_close_with_error, iterator_step_value, js_result_into_try,
         new_class_field_initializer_environment, require_object_coercible, to_length, to_object,
@@ -671,7 +671,18 @@ pub(crate) fn call<'gc>(
     let arguments_list = arguments_list.unwrap_or_default();
     // 2. If IsCallable(F) is false, throw a TypeError exception.
     match is_callable(f, gc.nogc()) {
-        None => Err(throw_not_callable(agent, gc.into_nogc()).unbind()),
+        // NOTE: Callable Proxies are not Functions, and are checked for
+        // separately.
+        None => match f {
+            Value::Proxy(proxy) if proxy.is_callable(agent, gc.nogc()) => {
+                // 3. Return ? F.[[Call]](V, argumentsList).
+                let current_stack_size = agent.stack_refs.borrow().len();
+                let result = proxy.unbind().internal_call(agent, v, arguments_list, gc);
+                agent.stack_refs.borrow_mut().truncate(current_stack_size);
+                result
+            }
+            _ => Err(throw_not_callable(agent, gc.into_nogc()).unbind()),
+        },
         // 3. Return ? F.[[Call]](V, argumentsList).
         Some(f) => {
             let current_stack_size = agent.stack_refs.borrow().len();
@@ -1087,6 +1098,7 @@ pub(crate) fn create_list_from_array_like<'gc>(
     match obj {
         Value::Array(array) if array.is_simple(agent) => {
             let gc = gc.into_nogc();
+            agent.check_arguments_length(array.len(agent) as usize, gc)?;
             Ok(array
                 .as_slice(agent)
                 .iter()
@@ -1099,6 +1111,9 @@ pub(crate) fn create_list_from_array_like<'gc>(
             // 3. Let len be ? LengthOfArrayLike(obj).
             let len = length_of_array_like(agent, object, gc.reborrow()).unbind()?;
             let len = usize::try_from(len).unwrap();
+            // Note: All callers use the list as function arguments, so lists
+            // that are too long are rejected before reading any elements.
+            agent.check_arguments_length(len, gc.nogc()).unbind()?;
             // 4. Let list be a new empty list.
             let mut list = Vec::<Value>::with_capacity(len).scope(agent, gc.nogc());
             // 5. Let index be 0.
@@ -2072,7 +2087,7 @@ pub(crate) fn copy_data_properties<'a>(
         // Attempt a super-fast path: if we're just making a copy of an
         // ordinary object that contains data properties, we can simply reuse
         // the source object's Shape and make a copy of the data.
-        if from.try_copy_from_object(agent, from) {
+        if target.try_copy_from_object(agent, from) {
             return Ok(());
         }
     }
@@ -2178,6 +2193,33 @@ fn copy_data_properties_slow<'a>(
     Ok(())
 }
 
+/// Attempt to copy an ordinary object into a new object created with
+/// `OrdinaryObjectCreate(%Object.prototype%)` by reusing the source object's
+/// Shape, like the fast path of [`copy_data_properties`] does for object
+/// spread.
+///
+/// Returns `None` if the source object's prototype is not
+/// %Object.prototype%, or if it has symbol keys, non-enumerable properties,
+/// or accessor properties.
+fn try_copy_ordinary_object_into_object<'gc>(
+    agent: &mut Agent,
+    from: OrdinaryObject,
+    gc: NoGcScope<'gc, '_>,
+) -> Option<OrdinaryObject<'gc>> {
+    let object_prototype = agent
+        .current_realm_record()
+        .intrinsics()
+        .object_prototype()
+        .into();
+    if from.internal_prototype(agent) != Some(object_prototype) {
+        return None;
+    }
+    let object = OrdinaryObject::create_object(agent, Some(object_prototype), &[])
+        .ok()?
+        .bind(gc);
+    object.try_copy_from_object(agent, from).then_some(object)
+}
+
 /// ### Try [7.3.25 CopyDataProperties ( target, source, excludedItems )](https://tc39.es/ecma262/#sec-copydataproperties)
 /// The abstract operation CopyDataProperties takes arguments target (an Object), source (an
 /// ECMAScript language value), and excludedItems (a List of property keys) and returns either a
@@ -2193,6 +2235,12 @@ pub(crate) fn try_copy_data_properties_into_object<'gc, 'b>(
     gc: NoGcScope<'gc, '_>,
 ) -> TryResult<'gc, OrdinaryObject<'gc>> {
     let from = source.into();
+    if let Object::Object(from) = from
+        && excluded_items.is_empty()
+        && let Some(object) = try_copy_ordinary_object_into_object(agent, from, gc)
+    {
+        return TryResult::Continue(object);
+    }
     let mut entries = Vec::new();
 
     // 3. Let keys be ? from.[[OwnPropertyKeys]]().
@@ -2263,6 +2311,14 @@ pub(crate) fn copy_data_properties_into_object<'a, 'b>(
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, OrdinaryObject<'a>> {
     let from = source.into().bind(gc.nogc());
+    if let Object::Object(from) = from
+        && excluded_items.is_empty(agent)
+        && let Some(object) = try_copy_ordinary_object_into_object(agent, from, gc.nogc())
+    {
+        // Drop the excluded items set.
+        let _ = excluded_items.take(agent);
+        return Ok(object.unbind());
+    }
     let scoped_from = from.scope(agent, gc.nogc());
     let mut entries = Vec::new();
 
@@ -2726,6 +2782,10 @@ pub(crate) fn initialize_instance_elements<'a>(
         let outer_env = constructor_data.environment;
         let outer_priv_env = constructor_data.private_environment;
         let source_code = constructor_data.source_code;
+        let frame = FrameMetadata::new(
+            Some(constructor_data.class_name),
+            constructor_data.source_text.start,
+        );
         let decl_env = new_class_field_initializer_environment(agent, f, o, outer_env, gc.nogc());
         agent.push_execution_context(ExecutionContext {
             ecmascript_code: Some(ECMAScriptCodeEvaluationState {
@@ -2738,6 +2798,7 @@ pub(crate) fn initialize_instance_elements<'a>(
             function: Some(f.unbind()),
             realm: constructor.get(agent).realm.unbind(),
             script_or_module: None,
+            frame,
         });
         let bytecode = bytecode.scope(agent, gc.nogc());
         let result = Vm::execute(agent, bytecode, None, gc).into_js_result();
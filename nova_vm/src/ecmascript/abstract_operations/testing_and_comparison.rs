@@ -4,6 +4,8 @@
 
 //! ## [7.2 Testing and Comparison Operations](https://tc39.es/ecma262/#sec-testing-and-comparison-operations)
 
+use core::cmp::Ordering;
+
 use crate::{
     ecmascript::{
         Agent, BigInt, ExceptionType, Function, InternalMethods, JsResult, NonRevokedProxy, Number,
@@ -53,23 +55,27 @@ pub(crate) fn is_array<'a, 'gc>(
     argument: impl Into<Value<'a>>,
     gc: NoGcScope<'gc, '_>,
 ) -> JsResult<'gc, bool> {
-    let argument = argument.into().bind(gc);
-
-    match argument {
-        // 1. If argument is not an Object, return false.
-        // 2. If argument is an Array exotic object, return true.
-        Value::Array(_) => Ok(true),
-        // 3. If argument is a Proxy exotic object, then
-        Value::Proxy(proxy) => {
-            // a. Perform ? ValidateNonRevokedProxy(argument).
-            // b. Let proxyTarget be argument.[[ProxyTarget]].
-            let NonRevokedProxy { target, handler: _ } =
-                validate_non_revoked_proxy(agent, proxy, gc)?;
-            // c. Return ? IsArray(proxyTarget).
-            is_array(agent, target, gc)
+    let mut argument = argument.into().bind(gc);
+
+    // NOTE: The recursion in step 3.c is performed as a loop so that long
+    // chains of Proxies cannot overflow the native stack.
+    loop {
+        match argument {
+            // 1. If argument is not an Object, return false.
+            // 2. If argument is an Array exotic object, return true.
+            Value::Array(_) => return Ok(true),
+            // 3. If argument is a Proxy exotic object, then
+            Value::Proxy(proxy) => {
+                // a. Perform ? ValidateNonRevokedProxy(argument).
+                // b. Let proxyTarget be argument.[[ProxyTarget]].
+                let NonRevokedProxy { target, handler: _ } =
+                    validate_non_revoked_proxy(agent, proxy, gc)?;
+                // c. Return ? IsArray(proxyTarget).
+                argument = target.into();
+            }
+            // 4. Return false.
+            _ => return Ok(false),
         }
-        // 4. Return false.
-        _ => Ok(false),
     }
 }
 
@@ -441,34 +447,7 @@ pub(crate) fn is_less_than<'a, const LEFT_FIRST: bool>(
         // d. If lx < ly, return true. Otherwise, return false.
         let sx = String::try_from(px).unwrap();
         let sy = String::try_from(py).unwrap();
-        if let (Some(sx), Some(sy)) = (sx.as_str_(agent), sy.as_str_(agent)) {
-            Ok(Some(sx < sy))
-        } else {
-            let lx = sx.utf16_len_(agent);
-            let ly = sy.utf16_len_(agent);
-            let l = lx.min(ly);
-            let mut sx = sx.as_wtf8_(agent).to_ill_formed_utf16();
-            let mut sy = sy.as_wtf8_(agent).to_ill_formed_utf16();
-            for _ in 0..l {
-                // i. Let cx be the numeric value of the code unit at index i within px.
-                let cx = sx.next().unwrap();
-                // ii. Let cy be the numeric value of the code unit at index i within py.
-                let cy = sy.next().unwrap();
-                // iii. If cx < cy, return true.
-                match cx.cmp(&cy) {
-                    std::cmp::Ordering::Less => {
-                        return Ok(Some(true));
-                    }
-                    std::cmp::Ordering::Equal => {}
-                    std::cmp::Ordering::Greater => {
-                        return Ok(Some(false));
-                    }
-                }
-                // iv. If cx > cy, return false.
-            }
-            // d. If lx < ly, return true. Otherwise, return false.
-            Ok(Some(lx < ly))
-        }
+        Ok(Some(sx.cmp_code_units_(agent, sy).is_lt()))
     }
     // 4. Else,
     else {
@@ -482,8 +461,10 @@ pub(crate) fn is_less_than<'a, const LEFT_FIRST: bool>(
             };
 
             // i. Let ny be StringToBigInt(py).
-            let ny = string_to_big_int(agent, py, gc).unbind()?.bind(gc);
             // ii. If ny is undefined, return undefined.
+            let Some(ny) = string_to_big_int(agent, py, gc) else {
+                return Ok(None);
+            };
             // iii. Return BigInt::lessThan(px, ny).
             return Ok(Some(BigInt::less_than(agent, px, ny)));
         }
@@ -498,8 +479,10 @@ pub(crate) fn is_less_than<'a, const LEFT_FIRST: bool>(
             };
 
             // i. Let nx be StringToBigInt(px).
-            let nx = string_to_big_int(agent, px, gc).unbind()?.bind(gc);
             // ii. If nx is undefined, return undefined.
+            let Some(nx) = string_to_big_int(agent, px, gc) else {
+                return Ok(None);
+            };
             // iii. Return BigInt::lessThan(nx, py).
             return Ok(Some(BigInt::less_than(agent, nx, py)));
         }
@@ -534,48 +517,57 @@ pub(crate) fn is_less_than<'a, const LEFT_FIRST: bool>(
         assert!(nx.is_bigint() && ny.is_number() || nx.is_number() && ny.is_bigint());
 
         // h. If nx or ny is NaN, return undefined.
-        if nx.is_nan(agent) || ny.is_nan(agent) {
-            return Ok(None);
-        }
-
         // i. If nx is -∞𝔽 or ny is +∞𝔽, return true.
-        if nx.is_neg_infinity(agent) || ny.is_pos_infinity(agent) {
-            return Ok(Some(true));
-        }
-
         // j. If nx is +∞𝔽 or ny is -∞𝔽, return false.
-        if nx.is_pos_infinity(agent) || ny.is_neg_infinity(agent) {
-            return Ok(Some(false));
-        }
-
         // k. If ℝ(nx) < ℝ(ny), return true; otherwise return false.
-        Ok(Some(match (nx, ny) {
-            (Numeric::Number(x), Numeric::Number(y)) => x != y && x.get(agent) < y.get(agent),
-            (Numeric::Number(x), Numeric::Integer(y)) => *x.get(agent) < y.into_i64() as f64,
-            (Numeric::Number(x), Numeric::SmallF64(y)) => *x.get(agent) < y.into_f64(),
-            (Numeric::Integer(x), Numeric::Number(y)) => (x.into_i64() as f64) < *y.get(agent),
-            (Numeric::Integer(x), Numeric::Integer(y)) => x.into_i64() < y.into_i64(),
-            (Numeric::Number(x), Numeric::BigInt(y)) => y.get(agent).ge(x.get(agent)),
-            (Numeric::Number(x), Numeric::SmallBigInt(y)) => *x.get(agent) < y.into_i64() as f64,
-            (Numeric::Integer(x), Numeric::SmallF64(y)) => (x.into_i64() as f64) < y.into_f64(),
-            (Numeric::Integer(x), Numeric::BigInt(y)) => y.get(agent).ge(&x.into_i64()),
-            (Numeric::Integer(x), Numeric::SmallBigInt(y)) => x.into_i64() < y.into_i64(),
-            (Numeric::SmallF64(x), Numeric::Number(y)) => x.into_f64() < *y.get(agent),
-            (Numeric::SmallF64(x), Numeric::Integer(y)) => x.into_f64() < y.into_i64() as f64,
-            (Numeric::SmallF64(x), Numeric::SmallF64(y)) => x.into_f64() < y.into_f64(),
-            (Numeric::SmallF64(x), Numeric::BigInt(y)) => y.get(agent).ge(&x.into_f64()),
-            (Numeric::SmallF64(x), Numeric::SmallBigInt(y)) => x.into_f64() < y.into_i64() as f64,
-            (Numeric::BigInt(x), Numeric::Number(y)) => x.get(agent).le(y.get(agent)),
-            (Numeric::BigInt(x), Numeric::Integer(y)) => x.get(agent).le(&y.into_i64()),
-            (Numeric::BigInt(x), Numeric::SmallF64(y)) => x.get(agent).le(&y.into_f64()),
-            (Numeric::BigInt(x), Numeric::BigInt(y)) => x.get(agent).data < y.get(agent).data,
-            (Numeric::BigInt(x), Numeric::SmallBigInt(y)) => x.get(agent).le(&y.into_i64()),
-            (Numeric::SmallBigInt(x), Numeric::Number(y)) => (x.into_i64() as f64) < *y.get(agent),
-            (Numeric::SmallBigInt(x), Numeric::Integer(y)) => x.into_i64() < y.into_i64(),
-            (Numeric::SmallBigInt(x), Numeric::SmallF64(y)) => (x.into_i64() as f64) < y.into_f64(),
-            (Numeric::SmallBigInt(x), Numeric::BigInt(y)) => y.get(agent).ge(&x.into_i64()),
-            (Numeric::SmallBigInt(x), Numeric::SmallBigInt(y)) => x.into_i64() < y.into_i64(),
-        }))
+        Ok(match (nx, ny) {
+            (Numeric::BigInt(_) | Numeric::SmallBigInt(_), _) => compare_big_int_and_number(
+                agent,
+                BigInt::try_from(nx).unwrap(),
+                Number::try_from(ny).unwrap(),
+            )
+            .map(Ordering::is_lt),
+            _ => compare_big_int_and_number(
+                agent,
+                BigInt::try_from(ny).unwrap(),
+                Number::try_from(nx).unwrap(),
+            )
+            .map(Ordering::is_gt),
+        })
+    }
+}
+
+/// Compare the mathematical values of a BigInt and a Number. Returns None if
+/// the Number is NaN.
+///
+/// Infinities compare as larger or smaller than all BigInts, and BigInts are
+/// compared exactly against non-integral or large Numbers.
+fn compare_big_int_and_number(agent: &Agent, x: BigInt, y: Number) -> Option<Ordering> {
+    match (x, y) {
+        (BigInt::SmallBigInt(x), Number::Integer(y)) => Some(x.into_i64().cmp(&y.into_i64())),
+        (BigInt::BigInt(x), Number::Integer(y)) => x.get(agent).partial_cmp(&y.into_i64()),
+        (BigInt::SmallBigInt(x), y) => {
+            let x = x.into_i64();
+            let y = y.to_real(agent);
+            if y.is_nan() {
+                return None;
+            }
+            // Note: i64::MIN and i64::MAX + 1 are exactly representable as
+            // f64; all Numbers outside that range (including infinities) are
+            // trivially ordered against a SmallBigInt.
+            let truncated = y.trunc();
+            if truncated >= -(i64::MIN as f64) {
+                return Some(Ordering::Less);
+            } else if truncated < i64::MIN as f64 {
+                return Some(Ordering::Greater);
+            }
+            Some(match x.cmp(&(truncated as i64)) {
+                Ordering::Equal if y > truncated => Ordering::Less,
+                Ordering::Equal if y < truncated => Ordering::Greater,
+                ordering => ordering,
+            })
+        }
+        (BigInt::BigInt(x), y) => x.get(agent).partial_cmp(&y.to_real(agent)),
     }
 }
 
@@ -636,7 +628,7 @@ pub(crate) fn is_loosely_equal<'a>(
         // a. Let n be StringToBigInt(y).
         // b. If n is undefined, return false.
         let gc = gc.into_nogc();
-        if let Ok(n) = string_to_big_int(agent, y, gc) {
+        if let Some(n) = string_to_big_int(agent, y, gc) {
             // c. Return ! IsLooselyEqual(x, n).
             // Note: IsLooselyEqual with two BigInts calls IsStrictlyEqual
             // which eventually calls BigInt::euqla
@@ -654,7 +646,7 @@ pub(crate) fn is_loosely_equal<'a>(
         // a. Let n be StringToBigInt(x).
         // b. If n is undefined, return false.
         let gc = gc.into_nogc();
-        if let Ok(n) = string_to_big_int(agent, x, gc) {
+        if let Some(n) = string_to_big_int(agent, x, gc) {
             // c. Return ! IsLooselyEqual(x, n).
             // Note: IsLooselyEqual with two BigInts calls IsStrictlyEqual
             // which eventually calls BigInt::euqla
@@ -703,19 +695,10 @@ pub(crate) fn is_loosely_equal<'a>(
         None
     } {
         // a. If x is not finite or y is not finite, return false.
-        // Note: BigInt is always finite.
-        if !b.is_finite_(agent) {
-            return Ok(false);
-        }
-
         // b. If ℝ(x) = ℝ(y), return true; otherwise return false.
-        let b = b.to_real(agent);
-
-        // Compare BigInt with f64 using precise comparison.
-        return Ok(match a {
-            BigInt::BigInt(heap_big_int) => heap_big_int.get(agent) == &b,
-            BigInt::SmallBigInt(small_big_int) => small_big_int.into_i64() as f64 == b,
-        });
+        // Note: BigInt is always finite and never compares equal to an
+        // infinity.
+        return Ok(compare_big_int_and_number(agent, a, b) == Some(Ordering::Equal));
     }
 
     // 14. Return false.
@@ -14,7 +14,7 @@
 //! The BigInt type has no implicit conversions in the ECMAScript language;
 //! programmers must call BigInt explicitly to convert values from other types.
 
-use std::convert::Infallible;
+use std::{convert::Infallible, ops::ControlFlow};
 
 use num_bigint::Sign;
 use wtf8::Wtf8;
@@ -22,7 +22,7 @@ use wtf8::Wtf8;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, ExceptionType, JsResult, PrimitiveObjectData, PrimitiveObjectRecord,
-        SmallInteger, TryError, TryResult, js_result_into_try,
+        PropertyLookupCache, SmallInteger, TryError, TryGetResult, TryResult, js_result_into_try,
         types::{
             BUILTIN_STRING_MEMORY, BigInt, Number, Numeric, Object, Primitive, PropertyKey, String,
             Value,
@@ -33,7 +33,7 @@ use crate::{
 };
 
 use super::{
-    operations_on_objects::{call_function, get, get_method},
+    operations_on_objects::{call_function, get, get_method, try_get, try_get_object_method},
     testing_and_comparison::{is_callable, require_object_coercible},
 };
 
@@ -82,15 +82,23 @@ pub(crate) fn to_primitive_object<'a, 'gc>(
 ) -> JsResult<'gc, Primitive<'gc>> {
     let input = input.into().bind(gc.nogc());
     // a. Let exoticToPrim be ? GetMethod(input, @@toPrimitive).
+    let to_primitive_key = PropertyKey::Symbol(WellKnownSymbols::ToPrimitive.into());
+    // Optimisation: Most objects inherit no @@toPrimitive method; look it up
+    // without calling into JavaScript first and only fall back to the full
+    // GetMethod if a getter or Proxy trap is in the way.
     let scoped_input = input.scope(agent, gc.nogc());
-    let exotic_to_prim = get_method(
-        agent,
-        input.unbind().into(),
-        PropertyKey::Symbol(WellKnownSymbols::ToPrimitive.into()),
-        gc.reborrow(),
-    )
-    .unbind()?
-    .bind(gc.nogc());
+    let exotic_to_prim = match try_get_object_method(agent, input, to_primitive_key, gc.nogc()) {
+        ControlFlow::Continue(exotic_to_prim) => exotic_to_prim,
+        ControlFlow::Break(TryError::Err(err)) => return Err(err.unbind().bind(gc.into_nogc())),
+        ControlFlow::Break(TryError::GcError) => get_method(
+            agent,
+            input.unbind().into(),
+            to_primitive_key,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc()),
+    };
     // b. If exoticToPrim is not undefined, then
     if let Some(exotic_to_prim) = exotic_to_prim {
         let hint = match preferred_type {
@@ -167,9 +175,27 @@ pub(crate) fn ordinary_to_primitive<'gc>(
     let scoped_o = o.scope(agent, gc.nogc());
     for name in method_names {
         // a. Let method be ? Get(O, name).
-        let method = get(agent, o.unbind(), name, gc.reborrow())
-            .unbind()?
-            .bind(gc.nogc());
+        let cache = PropertyLookupCache::get(agent, name);
+        let method = match try_get(agent, o, name, cache, gc.nogc()) {
+            ControlFlow::Continue(TryGetResult::Unset) => Value::Undefined,
+            ControlFlow::Continue(TryGetResult::Value(method)) => method,
+            _ => get(agent, o.unbind(), name, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc()),
+        };
+        // Optimisation: %Object.prototype.valueOf% returns ToObject(O), which
+        // is O itself. Calling it has no observable effect and never produces
+        // a primitive, so we can skip straight to the next method.
+        if method
+            == agent
+                .current_realm_record()
+                .intrinsics()
+                .object_prototype_value_of()
+                .into()
+        {
+            o = scoped_o.get(agent).bind(gc.nogc());
+            continue;
+        }
         // b. If IsCallable(method) is true, then
         if let Some(method) = is_callable(method, gc.nogc()) {
             // i. Let result be ? Call(method, O).
@@ -958,8 +984,20 @@ pub(crate) fn to_big_int_primitive<'a>(
                 Ok(BigInt::from(0))
             }
         }
-        Primitive::String(idx) => string_to_big_int(agent, idx.into(), gc),
-        Primitive::SmallString(data) => string_to_big_int(agent, data.into(), gc),
+        Primitive::String(_) | Primitive::SmallString(_) => {
+            let prim = String::try_from(prim).unwrap();
+            // 1. Let n be StringToBigInt(prim).
+            // 2. If n is undefined, throw a SyntaxError exception.
+            // 3. Return n.
+            string_to_big_int(agent, prim, gc).ok_or_else(|| {
+                let message = format!(
+                    "Cannot convert {} to a BigInt",
+                    prim.to_string_lossy_(agent)
+                );
+                let message = String::from_string(agent, message, gc);
+                agent.throw_exception_with_message(ExceptionType::SyntaxError, message, gc)
+            })
+        }
         Primitive::Symbol(_) => Err(agent.throw_exception_with_static_message(
             ExceptionType::TypeError,
             "Cannot convert Symbol to BigInt",
@@ -977,11 +1015,13 @@ pub(crate) fn to_big_int_primitive<'a>(
 }
 
 /// ### [7.1.14 StringToBigInt ( str )](https://tc39.es/ecma262/#sec-stringtobigint)
+///
+/// Returns None if the string is not a StringIntegerLiteral.
 pub(crate) fn string_to_big_int<'a>(
     agent: &mut Agent,
     argument: String,
-    nogc: NoGcScope<'a, '_>,
-) -> JsResult<'a, BigInt<'a>> {
+    _gc: NoGcScope<'a, '_>,
+) -> Option<BigInt<'a>> {
     // 1. Let text be StringToCodePoints(str).
     // 2. Let literal be ParseText(text, StringIntegerLiteral).
     // 3. If literal is a List of errors, return undefined.
@@ -990,7 +1030,7 @@ pub(crate) fn string_to_big_int<'a>(
     // StringIntegerLiteral is either whitespace only or a StrIntegerLiteral surrounded by
     // optional whitespace.
 
-    let literal = argument.to_string_lossy_(agent); // Extra line literally just for displaying error
+    let literal = argument.to_string_lossy_(agent);
 
     // 4. Let mv be the MV of literal.
     // 5. Assert: mv is an integer.
@@ -1001,7 +1041,7 @@ pub(crate) fn string_to_big_int<'a>(
 
     // If mv is empty result is Zero
     if mv.is_empty() {
-        return Ok(BigInt::from(0));
+        return Some(BigInt::from(0));
     }
 
     // MV should now be StrIntegerLiteral
@@ -1022,15 +1062,8 @@ pub(crate) fn string_to_big_int<'a>(
     // 6. Return ℤ(mv).
     // Parse with the required radix calculated from the base
     let num_big_int =
-        num_bigint::BigInt::parse_bytes(string_to_convert.as_bytes(), base.unwrap_or(10));
-
-    if let Some(num_big_int) = num_big_int {
-        Ok(BigInt::from_num_bigint(agent, num_big_int))
-    } else {
-        let message =
-            String::from_string(agent, format!("Cannot convert {literal} to a BigInt"), nogc);
-        Err(agent.throw_exception_with_message(ExceptionType::SyntaxError, message, nogc))
-    }
+        num_bigint::BigInt::parse_bytes(string_to_convert.as_bytes(), base.unwrap_or(10))?;
+    Some(BigInt::from_num_bigint(agent, num_big_int))
 }
 
 /// ### [7.1.15 ToBigInt64 ( argument )](https://tc39.es/ecma262/#sec-tobigint64)
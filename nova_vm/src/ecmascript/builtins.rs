@@ -49,6 +49,8 @@ mod finalization_registry;
 mod fundamental_objects;
 mod global_object;
 mod indexed_collections;
+#[cfg(feature = "intl")]
+mod intl;
 mod keyed_collections;
 mod managing_memory;
 mod map;
@@ -77,6 +79,14 @@ mod weak_map;
 mod weak_ref;
 #[cfg(feature = "weak-refs")]
 mod weak_set;
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch"
+))]
+mod web;
 
 pub(crate) use arguments::*;
 pub use array::*;
@@ -97,6 +107,8 @@ pub use finalization_registry::*;
 pub(crate) use fundamental_objects::*;
 pub(crate) use global_object::*;
 pub use indexed_collections::*;
+#[cfg(feature = "intl")]
+pub(crate) use intl::*;
 pub use keyed_collections::*;
 pub(crate) use managing_memory::*;
 pub use map::*;
@@ -125,3 +137,11 @@ pub use weak_map::*;
 pub use weak_ref::*;
 #[cfg(feature = "weak-refs")]
 pub use weak_set::*;
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch"
+))]
+pub use web::*;
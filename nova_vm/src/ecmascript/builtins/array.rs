@@ -34,7 +34,8 @@ use soavec::SoAVec;
 
 use super::{
     PropertyLookupCache, ordinary_delete, ordinary_get, ordinary_get_own_property,
-    ordinary_has_property, ordinary_try_get, ordinary_try_has_property,
+    ordinary_has_property, ordinary_own_property_keys_into, ordinary_try_get,
+    ordinary_try_has_property,
 };
 
 /// ### [10.4.2 Array Exotic Objects](https://tc39.es/ecma262/#sec-array-exotic-objects)
@@ -223,38 +224,41 @@ impl<'a> Array<'a> {
     }
 
     /// Returns true if it is trivially iterable, ie. it contains no element
-    /// accessor descriptors and uses the Array intrinsic itrator method.
-    #[cfg(any(feature = "set", feature = "weak-refs"))]
+    /// accessor descriptors or holes, uses the Array intrinsic iterator
+    /// method, and the ArrayIterator protocol is intact, so iterating it
+    /// cannot be observed.
     pub(crate) fn is_trivially_iterable(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> bool {
         use crate::ecmascript::abstract_operations::try_get_object_method;
+        use crate::engine::array_iterator_protocol_is_intact;
         use crate::heap::WellKnownSymbols;
         if !self.is_dense(agent) {
             // Contains holes or getters, so cannot be iterated without looking
             // into the prototype chain or calling getters.
-            false
-        } else {
-            let TryResult::Continue(Some(iterator_method)) = try_get_object_method(
-                agent,
-                self.into(),
-                PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
-                gc,
-            ) else {
-                // Can't get iterator method without calling a getter or Proxy
-                // method; or getting the method threw an error which we ignore
-                // here; or there is no iterator method, which will throw an
-                // error later.
-                return false;
-            };
-
-            // We got a proper iterator method; but is it the intrinsic Array
-            // values iterator method?
-            iterator_method
-                == agent
-                    .current_realm_record()
-                    .intrinsics()
-                    .array_prototype_values()
-                    .into()
+            return false;
         }
+        let TryResult::Continue(Some(iterator_method)) = try_get_object_method(
+            agent,
+            self.into(),
+            PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+            gc,
+        ) else {
+            // Can't get iterator method without calling a getter or Proxy
+            // method; or getting the method threw an error which we ignore
+            // here; or there is no iterator method, which will throw an
+            // error later.
+            return false;
+        };
+
+        // We got a proper iterator method; but is it the intrinsic Array
+        // values iterator method, and does it iterate using the intrinsic
+        // %ArrayIteratorPrototype%.next?
+        iterator_method
+            == agent
+                .current_realm_record()
+                .intrinsics()
+                .array_prototype_values()
+                .into()
+            && array_iterator_protocol_is_intact(agent, gc)
     }
 
     // This method creates a "shallow clone" of the elements of a simple array (no descriptors).
@@ -803,21 +807,17 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        let backing_keys = if let Some(backing_object) = self.get_backing_object(agent) {
-            unwrap_try(backing_object.try_own_property_keys(agent, gc))
-        } else {
-            Default::default()
-        };
+    ) -> TryResult<'gc, ()> {
         let ElementStorageRef {
             values,
             descriptors,
         } = self.get_elements(agent).get_storage(agent);
-        let mut keys = Vec::with_capacity(values.len() + 1 + backing_keys.len());
+        keys.reserve(values.len() + 1);
 
         for (index, value) in values.iter().enumerate() {
             let index = index as u32;
@@ -827,9 +827,11 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         }
 
         keys.push(BUILTIN_STRING_MEMORY.length.to_property_key());
-        keys.extend(backing_keys);
+        if let Some(backing_object) = self.get_backing_object(agent) {
+            ordinary_own_property_keys_into(agent, backing_object, keys, gc);
+        }
 
-        TryResult::Continue(keys)
+        TryResult::Continue(())
     }
 }
 
@@ -4,17 +4,18 @@
 
 use ecmascript_atomics::Ordering;
 
-use super::{AnyArrayBuffer, ArrayBuffer, InternalBuffer};
+use super::{AnyArrayBuffer, ArrayBuffer, ArrayBufferHeapData, InternalBuffer};
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::SharedDataBlock;
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, ExceptionType, Function, JsResult, Numeric, Object,
-        ProtoIntrinsics, Value, Viewable, create_byte_data_block, get,
-        ordinary_create_from_constructor, to_index,
+        ProtoIntrinsics, Value, Viewable, copy_data_block_bytes, create_byte_data_block,
+        create_resizable_byte_data_block, get, ordinary_create_from_constructor,
+        require_internal_slot_array_buffer, to_index, try_result_into_js, try_to_index,
     },
-    engine::{Bindable, GcScope, NoGcScope},
-    heap::{ArenaAccess, ArenaAccessMut},
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccess, ArenaAccessMut, CreateHeapData},
 };
 
 // TODO: Implement the contents of the `DetachKey` struct?
@@ -73,8 +74,7 @@ pub(crate) fn allocate_array_buffer<'a>(
         //    with in-place growth. Implementations may throw if, for example,
         //    virtual memory cannot be reserved up front.
         // c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
-        let mut block = create_byte_data_block(agent, max_byte_length, gc)?;
-        block.realloc(byte_length as usize);
+        let block = create_resizable_byte_data_block(agent, byte_length, max_byte_length, gc)?;
         InternalBuffer::resizable(block, max_byte_length as usize)
     } else {
         InternalBuffer::fixed_length(create_byte_data_block(agent, byte_length, gc)?)
@@ -86,6 +86,129 @@ pub(crate) fn allocate_array_buffer<'a>(
     Ok(obj)
 }
 
+/// Whether ArrayBufferCopyAndDetach should keep a resizable ArrayBuffer
+/// resizable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PreserveResizability {
+    PreserveResizability,
+    FixedLength,
+}
+
+/// ### [25.1.3.2 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )](https://tc39.es/ecma262/#sec-arraybuffercopyanddetach)
+///
+/// The abstract operation ArrayBufferCopyAndDetach takes arguments
+/// *arrayBuffer* (an ECMAScript language value), *newLength* (an ECMAScript
+/// language value), and *preserveResizability* (PRESERVE-RESIZABILITY or
+/// FIXED-LENGTH) and returns either a normal completion containing an
+/// ArrayBuffer or a throw completion.
+pub(crate) fn array_buffer_copy_and_detach<'a>(
+    agent: &mut Agent,
+    array_buffer: Value,
+    new_length: Value,
+    preserve_resizability: PreserveResizability,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ArrayBuffer<'a>> {
+    let new_length = new_length.bind(gc.nogc());
+    // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+    // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+    let mut array_buffer = require_internal_slot_array_buffer(agent, array_buffer, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 3. If newLength is undefined, then
+    let new_byte_length = if new_length.is_undefined() {
+        // a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        array_buffer.byte_length(agent)
+    } else if let Some(res) =
+        try_result_into_js(try_to_index(agent, new_length, gc.nogc())).unbind()?
+    {
+        // 4. Else,
+        // a. Let newByteLength be ? ToIndex(newLength).
+        res as usize
+    } else {
+        let scoped_array_buffer = array_buffer.scope(agent, gc.nogc());
+        let res = to_index(agent, new_length.unbind(), gc.reborrow()).unbind()?;
+        array_buffer = scoped_array_buffer.get(agent).bind(gc.nogc());
+        res as usize
+    };
+    let array_buffer = array_buffer.unbind();
+    let gc = gc.into_nogc();
+    let array_buffer = array_buffer.bind(gc);
+    // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+    if is_detached_buffer(agent, array_buffer) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer a detached ArrayBuffer",
+            gc,
+        ));
+    }
+    // 6. If preserveResizability is PRESERVE-RESIZABILITY and
+    //    IsFixedLengthArrayBuffer(arrayBuffer) is false, then
+    let new_max_byte_length = if preserve_resizability == PreserveResizability::PreserveResizability
+        && !is_fixed_length_array_buffer(agent, array_buffer.into())
+    {
+        // a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+        Some(array_buffer.max_byte_length(agent))
+    } else {
+        // 7. Else,
+        // a. Let newMaxByteLength be EMPTY.
+        None
+    };
+    // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a
+    //    TypeError exception.
+    if array_buffer.get_detach_key(agent).is_some() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer an ArrayBuffer with a detach key",
+            gc,
+        ));
+    }
+    // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+    if new_max_byte_length.is_some_and(|max_byte_length| new_byte_length > max_byte_length) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Byte length is over maximumm byte length",
+            gc,
+        ));
+    }
+    // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+    let byte_length = array_buffer.byte_length(agent);
+    // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+    // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+    // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+    // 14. NOTE: Neither creation of the new Data Block nor copying from the
+    //     old Data Block are observable. Implementations may implement this
+    //     method as a zero-copy move or a realloc.
+    let block = if new_byte_length > byte_length {
+        let mut block = create_byte_data_block(agent, new_byte_length as u64, gc)?;
+        copy_data_block_bytes(
+            &mut block,
+            0,
+            array_buffer.get(agent).get_data_block(),
+            0,
+            byte_length,
+        );
+        block
+    } else {
+        // Note: the old Data Block is moved into the new buffer and shrunk
+        // in place.
+        let mut block = array_buffer.get_mut(agent).buffer.take_data_block();
+        block.realloc(new_byte_length);
+        block
+    };
+    // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+    detach_array_buffer(agent, array_buffer, None, gc).unwrap();
+    let buffer = if let Some(new_max_byte_length) = new_max_byte_length {
+        InternalBuffer::resizable(block, new_max_byte_length)
+    } else {
+        InternalBuffer::fixed_length(block)
+    };
+    // 16. Return newBuffer.
+    Ok(agent.heap.create(ArrayBufferHeapData {
+        object_index: None,
+        buffer,
+    }))
+}
+
 /// ### [25.1.3.3 IsDetachedBuffer ( arrayBuffer )](https://tc39.es/ecma262/#sec-isdetachedbuffer)
 ///
 /// The abstract operation IsDetachedBuffer takes argument *arrayBuffer* (an
@@ -173,6 +296,107 @@ pub(crate) fn is_fixed_length_array_buffer(agent: &Agent, array_buffer: AnyArray
     !array_buffer.is_resizable(agent)
 }
 
+/// The \[\[CachedBufferByteLength\]\] field shared by
+/// [TypedArray With Buffer Witness Records](https://tc39.es/ecma262/#sec-typedarray-with-buffer-witness-records)
+/// and [DataView With Buffer Witness Records](https://tc39.es/ecma262/#sec-dataview-with-buffer-witness-records).
+///
+/// Both view kinds perform the same steps to read the byte length of their
+/// viewed buffer and to check their bounds against it; those steps live here
+/// so that views over a shrunk resizable buffer behave identically.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CachedBufferByteLength(pub(crate) usize);
+
+impl CachedBufferByteLength {
+    pub(crate) const fn value(value: usize) -> Self {
+        assert!(
+            value != usize::MAX,
+            "byte length cannot be usize::MAX as it is reserved for detached buffers"
+        );
+        Self(value)
+    }
+
+    /// A sentinel value of `usize::MAX` means that the buffer is detached.
+    pub(crate) const fn detached() -> Self {
+        Self(usize::MAX)
+    }
+
+    pub(crate) fn is_detached(self) -> bool {
+        self == Self::detached()
+    }
+
+    /// Read the cached byte length of a view's buffer.
+    ///
+    /// This performs steps 1-3 of both MakeTypedArrayWithBufferWitnessRecord
+    /// and MakeDataViewWithBufferWitnessRecord.
+    #[inline]
+    pub(crate) fn from_buffer(agent: &Agent, buffer: AnyArrayBuffer, order: Ordering) -> Self {
+        // 1. Let buffer be obj.[[ViewedArrayBuffer]].
+        // 2. If IsDetachedBuffer(buffer) is true, then
+        if buffer.is_detached(agent) {
+            // a. Let byteLength be detached.
+            Self::detached()
+        } else {
+            // 3. Else,
+            // a. Let byteLength be ArrayBufferByteLength(buffer, order).
+            Self::value(buffer.byte_length(agent, order))
+        }
+    }
+
+    /// Check if a view starting at `byte_offset_start` and covering
+    /// `view_byte_length` bytes, or the rest of the buffer if the view is
+    /// length-tracking, lies outside of the buffer.
+    ///
+    /// This performs steps 4-10 of both IsTypedArrayOutOfBounds and
+    /// IsViewOutOfBounds.
+    #[inline]
+    pub(crate) fn is_view_out_of_bounds(
+        self,
+        byte_offset_start: usize,
+        view_byte_length: Option<usize>,
+    ) -> bool {
+        // 4. If bufferByteLength is detached, return true.
+        let Some(buffer_byte_length) = self.into() else {
+            return true;
+        };
+
+        // 5. Let byteOffsetStart be O.[[ByteOffset]].
+        // 6. If O.[[ByteLength]] is auto, then
+        let byte_offset_end = if let Some(view_byte_length) = view_byte_length {
+            // 7. Else,
+            // a. Let byteOffsetEnd be byteOffsetStart + O.[[ByteLength]].
+            byte_offset_start + view_byte_length
+        } else {
+            // a. Let byteOffsetEnd be bufferByteLength.
+            buffer_byte_length
+        };
+
+        // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 9. NOTE: 0-length views are not considered out-of-bounds.
+        // 10. Return false.
+        byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length
+    }
+
+    /// Get the byte length of a length-tracking view starting at
+    /// `byte_offset`.
+    ///
+    /// The view must not be out of bounds.
+    #[inline]
+    pub(crate) fn length_tracking_byte_length(self, byte_offset: usize) -> usize {
+        // Let byteLength be record.[[CachedBufferByteLength]].
+        // Assert: byteLength is not detached.
+        debug_assert!(!self.is_detached());
+        // Return byteLength - byteOffset.
+        self.0 - byte_offset
+    }
+}
+
+impl From<CachedBufferByteLength> for Option<usize> {
+    fn from(val: CachedBufferByteLength) -> Self {
+        if val.is_detached() { None } else { Some(val.0) }
+    }
+}
+
 /// ### [25.1.3.13 RawBytesToNumeric ( type, rawBytes, isLittleEndian )](https://tc39.es/ecma262/#sec-rawbytestonumeric)
 ///
 /// The abstract operation RawBytesToNumeric takes arguments type (a
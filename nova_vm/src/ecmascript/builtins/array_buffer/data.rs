@@ -154,6 +154,12 @@ impl InternalBuffer {
         self.data_block = DataBlock::DETACHED_DATA_BLOCK;
     }
 
+    /// Detaches the buffer and returns its previously contained DataBlock.
+    pub(crate) fn take_data_block(&mut self) -> DataBlock {
+        self.capacity = 0;
+        core::mem::replace(&mut self.data_block, DataBlock::DETACHED_DATA_BLOCK)
+    }
+
     const fn detached() -> Self {
         Self {
             data_block: DataBlock::DETACHED_DATA_BLOCK,
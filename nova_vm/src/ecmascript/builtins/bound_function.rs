@@ -200,8 +200,9 @@ impl<'a> FunctionInternalProperties<'a> for BoundFunction<'a> {
             // as calling to JS may invalidate the slice pointer. Arguments
             // must also be given as exclusive slice, which we couldn't provide
             // if we were basing it on the ElementsVector's data in the heap.
-            let mut args: Vec<Value<'static>> =
-                Vec::with_capacity(bound_args.len() as usize + arguments_list.len());
+            let length = bound_args.len() as usize + arguments_list.len();
+            agent.check_arguments_length(length, gc.nogc()).unbind()?;
+            let mut args: Vec<Value<'static>> = Vec::with_capacity(length);
             bound_args
                 .get(agent)
                 .iter()
@@ -248,7 +249,10 @@ impl<'a> FunctionInternalProperties<'a> for BoundFunction<'a> {
         // list, as we must create a Vec from the bound_args ElementsVector
         // in any case to use it as arguments. A slice pointing to it would
         // be unsound as calling to JS may invalidate the slice pointer.
-        let mut args = Vec::with_capacity(bound_args.len() as usize + arguments_list.len());
+        let length = bound_args.len() as usize + arguments_list.len();
+        agent.check_arguments_length(length, gc.nogc()).unbind()?;
+        let mut args = Vec::with_capacity(length);
+        let bound_args = &self.get(agent).bound_arguments;
         bound_args
             .get(agent)
             .iter()
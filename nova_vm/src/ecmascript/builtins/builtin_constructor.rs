@@ -7,9 +7,9 @@ use oxc_span::Span;
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, BuiltinConstructorRecord, Environment, ExceptionType,
-        ExecutionContext, Function, FunctionInternalProperties, JsResult, Object, OrdinaryObject,
-        PrivateEnvironment, PropertyKey, SourceCode, String, Value, base_class_default_constructor,
-        derived_class_default_constructor, function_handle,
+        ExecutionContext, FrameMetadata, Function, FunctionInternalProperties, JsResult, Object,
+        OrdinaryObject, PrivateEnvironment, PropertyKey, SourceCode, String, Value,
+        base_class_default_constructor, derived_class_default_constructor, function_handle,
     },
     engine::{Bindable, Executable, GcScope, NoGcScope, bindable_handle},
     heap::{
@@ -152,6 +152,7 @@ fn builtin_call_or_construct<'a>(
     let heap_data = &f.get(agent);
     let callee_realm = heap_data.realm;
     let is_derived = heap_data.is_derived;
+    let frame = FrameMetadata::new(Some(heap_data.class_name), heap_data.source_text.start);
     // 3. Let calleeContext be a new execution context.
     let callee_context = ExecutionContext {
         // 8. Perform any necessary implementation-defined initialization of calleeContext.
@@ -162,6 +163,7 @@ fn builtin_call_or_construct<'a>(
         realm: callee_realm.unbind(),
         // 7. Set the ScriptOrModule of calleeContext to null.
         script_or_module: None,
+        frame,
     };
     // 9. Push calleeContext onto the execution context stack; calleeContext is now the running execution context.
     agent.push_execution_context(callee_context);
@@ -2,14 +2,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod js_function;
+
+pub use js_function::*;
+
 use core::{marker::PhantomData, ops::Deref};
 use std::{borrow::Cow, ptr::NonNull};
 
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, BuiltinFunctionHeapData, ExceptionType, ExecutionContext,
-        Function, FunctionInternalProperties, InternalSlots, JsResult, Object, OrdinaryObject,
-        PropertyKey, Realm, ScopedValuesIterator, String, Value, function_handle,
+        FrameMetadata, Function, FunctionInternalProperties, InternalSlots, JsResult, Object,
+        OrdinaryObject, PropertyKey, Realm, ScopedValuesIterator, String, Value, function_handle,
     },
     engine::{Bindable, GcScope, HeapRootCollection, NoGcScope, bindable_handle},
     heap::{
@@ -698,6 +702,7 @@ fn builtin_call_or_construct<'gc>(
     let heap_data = &f.get(agent);
     let callee_realm = heap_data.realm;
     let func = heap_data.behaviour;
+    let frame = FrameMetadata::new(heap_data.initial_name, 0);
     // 3. Let calleeContext be a new execution context.
     let callee_context = ExecutionContext {
         // 8. Perform any necessary implementation-defined initialization of calleeContext.
@@ -708,6 +713,7 @@ fn builtin_call_or_construct<'gc>(
         realm: callee_realm.unbind(),
         // 7. Set the ScriptOrModule of calleeContext to null.
         script_or_module: None,
+        frame,
     };
     // 9. Push calleeContext onto the execution context stack; calleeContext is now the running execution context.
     agent.push_execution_context(callee_context);
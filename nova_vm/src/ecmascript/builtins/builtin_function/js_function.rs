@@ -0,0 +1,439 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Binding plain Rust functions as builtin functions.
+//!
+//! See the [`js_function!`](crate::js_function) macro.
+
+use crate::{
+    ecmascript::{
+        Agent, Behaviour, BuiltinFunction, BuiltinFunctionArgs, ExceptionType, JsResult, Number,
+        String, Value, create_builtin_function, to_boolean, to_int32, to_number, to_string,
+        to_uint32,
+    },
+    engine::{GcScope, NoGcScope},
+};
+
+/// A Rust function that can be created as a builtin function object.
+///
+/// This trait is usually implemented using the
+/// [`js_function!`](crate::js_function) macro.
+pub trait JsFunction {
+    /// Name of the function.
+    const NAME: &'static str;
+    /// Length of the function: the number of arguments before the first
+    /// optional argument.
+    const LENGTH: u32;
+    /// Behaviour of the function.
+    const BEHAVIOUR: Behaviour;
+
+    /// Create the builtin function object in the current Realm.
+    fn create<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> BuiltinFunction<'a> {
+        create_builtin_function(
+            agent,
+            Self::BEHAVIOUR,
+            BuiltinFunctionArgs::new(Self::LENGTH, Self::NAME),
+            gc,
+        )
+    }
+}
+
+/// Conversion of a JavaScript argument into a Rust function parameter.
+///
+/// The argument is first converted into an owned Rust value; the parameter is
+/// then borrowed from it. This allows parameters such as `&str` to be
+/// converted from JavaScript values that may be moved by garbage collection.
+pub trait JsArgument<'a>: Sized {
+    /// Owned Rust data that the argument is converted into.
+    type Owned;
+
+    /// If true, the argument may be omitted and does not count towards the
+    /// length of the function.
+    const OPTIONAL: bool = false;
+
+    /// Convert a JavaScript value into the owned Rust data.
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self::Owned>;
+
+    /// Borrow the parameter from the owned Rust data.
+    fn as_argument(owned: &'a Self::Owned) -> Self;
+}
+
+impl JsArgument<'_> for bool {
+    type Owned = bool;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        _: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        Ok(to_boolean(agent, value))
+    }
+
+    fn as_argument(owned: &bool) -> Self {
+        *owned
+    }
+}
+
+impl JsArgument<'_> for i32 {
+    type Owned = i32;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, i32> {
+        to_int32(agent, value, gc)
+    }
+
+    fn as_argument(owned: &i32) -> Self {
+        *owned
+    }
+}
+
+impl JsArgument<'_> for u32 {
+    type Owned = u32;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, u32> {
+        to_uint32(agent, value, gc)
+    }
+
+    fn as_argument(owned: &u32) -> Self {
+        *owned
+    }
+}
+
+impl JsArgument<'_> for f64 {
+    type Owned = f64;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, f64> {
+        Ok(to_number(agent, value, gc)?.into_f64(agent))
+    }
+
+    fn as_argument(owned: &f64) -> Self {
+        *owned
+    }
+}
+
+impl JsArgument<'_> for std::string::String {
+    type Owned = std::string::String;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, std::string::String> {
+        Ok(to_string(agent, value, gc)?
+            .to_string_lossy(agent)
+            .into_owned())
+    }
+
+    fn as_argument(owned: &std::string::String) -> Self {
+        owned.clone()
+    }
+}
+
+impl<'a> JsArgument<'a> for &'a str {
+    type Owned = std::string::String;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, std::string::String> {
+        std::string::String::from_js_argument(agent, value, gc)
+    }
+
+    fn as_argument(owned: &'a std::string::String) -> Self {
+        owned
+    }
+}
+
+impl<'a, T: JsArgument<'a>> JsArgument<'a> for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    const OPTIONAL: bool = true;
+
+    fn from_js_argument<'gc>(
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Option<T::Owned>> {
+        if value.is_undefined() {
+            Ok(None)
+        } else {
+            T::from_js_argument(agent, value, gc).map(Some)
+        }
+    }
+
+    fn as_argument(owned: &'a Option<T::Owned>) -> Self {
+        owned.as_ref().map(T::as_argument)
+    }
+}
+
+#[cfg(feature = "array-buffer")]
+macro_rules! typed_array_argument {
+    ($element: ty, $typed_array: ident) => {
+        impl<'a> JsArgument<'a> for &'a [$element] {
+            type Owned = Vec<$element>;
+
+            fn from_js_argument<'gc>(
+                agent: &mut Agent,
+                value: Value,
+                gc: GcScope<'gc, '_>,
+            ) -> JsResult<'gc, Vec<$element>> {
+                let Ok(array) = crate::ecmascript::$typed_array::try_from(value) else {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        concat!("Expected ", stringify!($typed_array), " argument"),
+                        gc.into_nogc(),
+                    ));
+                };
+                Ok(array.as_slice(agent).to_vec())
+            }
+
+            fn as_argument(owned: &'a Vec<$element>) -> Self {
+                owned
+            }
+        }
+    };
+}
+
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(u8, Uint8Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(i8, Int8Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(u16, Uint16Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(i16, Int16Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(u32, Uint32Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(i32, Int32Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(u64, BigUint64Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(i64, BigInt64Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(f32, Float32Array);
+#[cfg(feature = "array-buffer")]
+typed_array_argument!(f64, Float64Array);
+
+/// Conversion of a Rust function's return value into a JavaScript completion.
+pub trait IntoJsResult {
+    /// Convert the return value into a JavaScript value or a thrown error.
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>>;
+}
+
+impl IntoJsResult for () {
+    fn into_js_result<'gc>(
+        self,
+        _: &mut Agent,
+        _: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(Value::Undefined)
+    }
+}
+
+impl IntoJsResult for bool {
+    fn into_js_result<'gc>(
+        self,
+        _: &mut Agent,
+        _: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(Value::Boolean(self))
+    }
+}
+
+impl IntoJsResult for i32 {
+    fn into_js_result<'gc>(
+        self,
+        _: &mut Agent,
+        _: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(Number::from(self).into())
+    }
+}
+
+impl IntoJsResult for u32 {
+    fn into_js_result<'gc>(
+        self,
+        _: &mut Agent,
+        _: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(Number::from(self).into())
+    }
+}
+
+impl IntoJsResult for f64 {
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(Number::from_f64(agent, self, gc).into())
+    }
+}
+
+impl IntoJsResult for std::string::String {
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(String::from_string(agent, self, gc).into())
+    }
+}
+
+impl IntoJsResult for &str {
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(String::from_str(agent, self, gc).into())
+    }
+}
+
+impl<T: IntoJsResult> IntoJsResult for Option<T> {
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        match self {
+            Some(value) => value.into_js_result(agent, gc),
+            None => Ok(Value::Undefined),
+        }
+    }
+}
+
+/// An `Err` is thrown as an `Error` with the error's display message.
+impl<T: IntoJsResult, E: core::fmt::Display> IntoJsResult for Result<T, E> {
+    fn into_js_result<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        match self {
+            Ok(value) => value.into_js_result(agent, gc),
+            Err(err) => Err(agent.throw_exception(ExceptionType::Error, err.to_string(), gc)),
+        }
+    }
+}
+
+/// Calculate the length of a function from the optionality of its arguments.
+#[doc(hidden)]
+pub const fn js_function_length(optional: &[bool]) -> u32 {
+    let mut length = 0;
+    while length < optional.len() && !optional[length] {
+        length += 1;
+    }
+    length as u32
+}
+
+/// Bind a plain Rust function as a builtin function.
+///
+/// The macro defines a unit struct implementing [`JsFunction`], which can be
+/// used to create the function object. Arguments are converted from
+/// JavaScript values using [`JsArgument`]: missing arguments are `undefined`.
+/// The return value is converted using [`IntoJsResult`], with `Err` values
+/// thrown as JavaScript errors. The Rust function itself becomes an
+/// associated function of the struct.
+///
+/// ```rust
+/// use nova_vm::{ecmascript::JsFunction, js_function};
+///
+/// js_function! {
+///     /// `repeat(text, count?)`
+///     pub struct Repeat = "repeat";
+///     fn repeat(text: &str, count: Option<u32>) -> String {
+///         text.repeat(count.unwrap_or(1) as usize)
+///     }
+/// }
+///
+/// assert_eq!(Repeat::NAME, "repeat");
+/// assert_eq!(Repeat::LENGTH, 1);
+/// assert_eq!(Repeat::repeat("ab", Some(2)), "abab");
+/// ```
+#[macro_export]
+macro_rules! js_function {
+    (
+        $(#[$meta: meta])*
+        $vis: vis struct $name: ident = $js_name: literal;
+        $(#[$fn_meta: meta])*
+        $fn_vis: vis fn $fn_name: ident($($arg: ident: $ty: ty),* $(,)?) $(-> $ret: ty)? $body: block
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        $vis struct $name;
+
+        impl $name {
+            $(#[$fn_meta])*
+            $fn_vis fn $fn_name($($arg: $ty),*) $(-> $ret)? $body
+
+            fn call<'gc>(
+                agent: &mut $crate::ecmascript::Agent,
+                _this: $crate::ecmascript::Value,
+                mut arguments: $crate::ecmascript::ArgumentsList,
+                gc: $crate::engine::GcScope<'gc, '_>,
+            ) -> $crate::ecmascript::JsResult<'gc, $crate::ecmascript::Value<'gc>> {
+                arguments.with_scoped(
+                    agent,
+                    |agent, arguments, mut gc| {
+                        let _ = &arguments;
+                        #[allow(unused_mut, unused_variables)]
+                        let mut index: u32 = 0;
+                        $(
+                            let value = arguments.get(agent, index, gc.nogc());
+                            index += 1;
+                            let $arg = match <$ty as $crate::ecmascript::JsArgument>::from_js_argument(
+                                agent,
+                                $crate::engine::Bindable::unbind(value),
+                                gc.reborrow(),
+                            ) {
+                                Ok(owned) => owned,
+                                Err(err) => return Err($crate::engine::Bindable::unbind(err)),
+                            };
+                        )*
+                        let result = Self::$fn_name(
+                            $(<$ty as $crate::ecmascript::JsArgument>::as_argument(&$arg)),*
+                        );
+                        $crate::ecmascript::IntoJsResult::into_js_result(
+                            result,
+                            agent,
+                            gc.into_nogc(),
+                        )
+                    },
+                    gc,
+                )
+            }
+        }
+
+        impl $crate::ecmascript::JsFunction for $name {
+            const NAME: &'static str = $js_name;
+            const LENGTH: u32 = $crate::ecmascript::js_function_length(&[
+                $(<$ty as $crate::ecmascript::JsArgument>::OPTIONAL),*
+            ]);
+            const BEHAVIOUR: $crate::ecmascript::Behaviour =
+                $crate::ecmascript::Behaviour::Regular(Self::call);
+        }
+    };
+}
@@ -4,8 +4,8 @@
 
 use crate::{
     ecmascript::{
-        Agent, ECMAScriptFunction, ExecutionContext, PromiseCapability, PromiseReactionHandler,
-        PromiseReactionType, SourceTextModule, Value, inner_promise_then,
+        Agent, ECMAScriptFunction, ExecutionContext, JsResult, PromiseCapability,
+        PromiseReactionHandler, PromiseReactionType, SourceTextModule, Value, inner_promise_then,
     },
     engine::{
         Bindable, Executable, ExecutionResult, GcScope, Scopable, SuspendedVm, bindable_handle,
@@ -23,13 +23,13 @@ index_handle!(AwaitReaction);
 arena_vec_access!(AwaitReaction, 'a, AwaitReactionRecord, await_reactions);
 
 impl AwaitReaction<'_> {
-    pub(crate) fn resume(
+    pub(crate) fn resume<'gc>(
         self,
         agent: &mut Agent,
         reaction_type: PromiseReactionType,
         value: Value,
-        mut gc: GcScope,
-    ) {
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
         let reaction = self.bind(gc.nogc());
         let value = value.bind(gc.nogc());
         // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
@@ -97,6 +97,10 @@ impl AwaitReaction<'_> {
                 //       the execution context stack as the running execution
                 //       context.
                 agent.pop_execution_context();
+                // NOTE: Termination is not turned into a rejection.
+                if err.is_termination(agent) {
+                    return Err(err.unbind());
+                }
                 // 2. g. i. Assert: result is a throw completion.
                 //       ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
                 reaction
@@ -132,6 +136,7 @@ impl AwaitReaction<'_> {
             }
             ExecutionResult::Yield { .. } => unreachable!(),
         }
+        Ok(())
     }
 }
 
@@ -12,7 +12,7 @@ use std::collections::VecDeque;
 
 use crate::{
     ecmascript::{
-        Agent, ExecutionContext, InternalMethods, InternalSlots, JsError, OrdinaryObject,
+        Agent, ExecutionContext, InternalMethods, InternalSlots, JsError, JsResult, OrdinaryObject,
         PromiseCapability, PromiseReactionType, ProtoIntrinsics, Value, object_handle,
     },
     engine::{Bindable, Executable, GcScope, NoGcScope, Scopable, SuspendedVm, bindable_handle},
@@ -244,13 +244,13 @@ impl AsyncGenerator<'_> {
         });
     }
 
-    pub(crate) fn resume_await(
+    pub(crate) fn resume_await<'gc>(
         self,
         agent: &mut Agent,
         reaction_type: PromiseReactionType,
         value: Value,
-        mut gc: GcScope,
-    ) {
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
         let value = value.bind(gc.nogc());
         if self.is_draining_queue(agent) {
             // We're coming here because return was called.
@@ -263,7 +263,7 @@ impl AsyncGenerator<'_> {
                     async_generator_await_return_on_rejected(agent, self, value.unbind(), gc);
                 }
             }
-            return;
+            return Ok(());
         }
         // 1. Assert: generator.[[AsyncGeneratorState]] is either suspended-start or suspended-yield.
         let state = self.get_mut(agent).async_generator_state.take().unwrap();
@@ -305,18 +305,17 @@ impl AsyncGenerator<'_> {
                     let executable = self.get(agent).executable.unwrap().scope(agent, gc.nogc());
                     vm.resume_throw(agent, executable, value.unbind(), gc.reborrow())
                 } else {
-                    async_generator_yield(
+                    return async_generator_yield(
                         agent,
                         value.unbind(),
                         scoped_generator.clone(),
                         vm,
-                        gc.reborrow(),
+                        gc,
                     );
-                    return;
                 }
             }
         };
-        resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc);
+        resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc)
     }
 }
 
@@ -131,12 +131,12 @@ fn async_generator_complete_step(
 ///
 /// The abstract operation AsyncGeneratorResume takes arguments generator (an
 /// AsyncGenerator) and completion (a Completion Record) and returns unused.
-pub(crate) fn async_generator_resume(
+pub(crate) fn async_generator_resume<'gc>(
     agent: &mut Agent,
     generator: AsyncGenerator,
     completion: AsyncGeneratorRequestCompletion,
-    mut gc: GcScope,
-) {
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
     let nogc = gc.nogc();
     let generator = generator.bind(nogc);
     let completion = completion.bind(nogc);
@@ -173,16 +173,16 @@ pub(crate) fn async_generator_resume(
     // 9. Assert: When we return here, genContext has already been removed from
     //    the execution context stack and callerContext is the currently
     //    running execution context.
-    resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc);
+    resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc)
     // 10. Return unused.
 }
 
-pub(super) fn resume_handle_result(
+pub(super) fn resume_handle_result<'gc>(
     agent: &mut Agent,
     execution_result: ExecutionResult,
     scoped_generator: Scoped<AsyncGenerator>,
-    mut gc: GcScope,
-) {
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
     match execution_result {
         ExecutionResult::Return(result) => {
             // Function is done.
@@ -209,6 +209,10 @@ pub(super) fn resume_handle_result(
         ExecutionResult::Throw(err) => {
             // Function is done.
             let _ = agent.pop_execution_context().unwrap();
+            // NOTE: Termination is not turned into a rejection.
+            if err.is_termination(agent) {
+                return Err(err.unbind());
+            }
             let generator = scoped_generator.get(agent).bind(gc.nogc());
             // AsyncGeneratorStart step 4:
             // g. Set acGenerator.[[AsyncGeneratorState]] to draining-queue.
@@ -230,7 +234,7 @@ pub(super) fn resume_handle_result(
             // 27.5.3.7 Yield ( value )
             // If generatorKind is async, return ? AsyncGeneratorYield(? Await(value)).
             // NOTE: Await is performed in the bytecode.
-            async_generator_yield(agent, yielded_value, scoped_generator, vm, gc);
+            return async_generator_yield(agent, yielded_value, scoped_generator, vm, gc);
         }
         ExecutionResult::Await { vm, promise } => {
             async_generator_perform_await(
@@ -243,6 +247,7 @@ pub(super) fn resume_handle_result(
             );
         }
     }
+    Ok(())
 }
 
 fn async_generator_perform_await(
@@ -272,13 +277,13 @@ fn async_generator_perform_await(
 /// The abstract operation AsyncGeneratorUnwrapYieldResumption takes argument
 /// resumptionValue (a Completion Record) and returns either a normal
 /// completion containing an ECMAScript language value or an abrupt completion.
-fn async_generator_unwrap_yield_resumption(
+fn async_generator_unwrap_yield_resumption<'gc>(
     agent: &mut Agent,
     vm: SuspendedVm,
     generator: Scoped<AsyncGenerator>,
     resumption_value: AsyncGeneratorRequestCompletion,
-    mut gc: GcScope,
-) {
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
     let resumption_value = resumption_value.bind(gc.nogc());
     // 1. If resumptionValue is not a return completion, return ? resumptionValue.
     let execution_result = match resumption_value {
@@ -306,7 +311,7 @@ fn async_generator_unwrap_yield_resumption(
             vm.resume_return(agent, executable, value.unbind(), gc.reborrow())
         }
     };
-    resume_handle_result(agent, execution_result.unbind(), generator, gc);
+    resume_handle_result(agent, execution_result.unbind(), generator, gc)
 }
 
 /// ### [27.6.3.8 AsyncGeneratorYield ( value )](https://tc39.es/ecma262/#sec-asyncgeneratoryield)
@@ -314,13 +319,13 @@ fn async_generator_unwrap_yield_resumption(
 /// The abstract operation AsyncGeneratorYield takes argument value (an
 /// ECMAScript language value) and returns either a normal completion
 /// containing an ECMAScript language value or an abrupt completion.
-pub(super) fn async_generator_yield(
+pub(super) fn async_generator_yield<'gc>(
     agent: &mut Agent,
     value: Value,
     generator: Scoped<AsyncGenerator>,
     vm: SuspendedVm,
-    gc: GcScope,
-) {
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
     // 1. Let genContext be the running execution context.
     let gen_context = agent.running_execution_context();
     // 2. Assert: genContext is the execution context of a generator.
@@ -353,13 +358,7 @@ pub(super) fn async_generator_yield(
         // c. Let resumptionValue be Completion(toYield.[[Completion]]).
         let resumption_value = to_yield.completion;
         // d. Return ? AsyncGeneratorUnwrapYieldResumption(resumptionValue).
-        async_generator_unwrap_yield_resumption(
-            agent,
-            vm,
-            generator,
-            resumption_value.unbind(),
-            gc,
-        );
+        async_generator_unwrap_yield_resumption(agent, vm, generator, resumption_value.unbind(), gc)
     } else {
         // 12. Else,
         // a. Set generator.[[AsyncGeneratorState]] to suspended-yield.
@@ -375,6 +374,7 @@ pub(super) fn async_generator_yield(
         //    which it is resumed.
         // e. Assert: If control reaches here, then genContext is the running execution context again.
         // f. Return ? AsyncGeneratorUnwrapYieldResumption(resumptionValue).
+        Ok(())
     }
 }
 
@@ -8,7 +8,7 @@ use crate::{
         Builtin, JsError, JsResult, Promise, PromiseCapability, Realm, String, Value,
         async_generator_await_return, async_generator_enqueue, async_generator_resume,
         async_generator_validate, builders::OrdinaryObjectBuilder, create_iter_result_object,
-        if_abrupt_reject_promise, if_abrupt_reject_promise_m,
+        if_abrupt_reject_promise,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{ArenaAccess, WellKnownSymbols},
@@ -47,7 +47,7 @@ impl AsyncGeneratorPrototype {
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         let value = arguments.get(0).bind(gc.nogc());
         // 1. Let generator be the this value.
@@ -91,7 +91,13 @@ impl AsyncGeneratorPrototype {
         // 9. If state is either suspended-start or suspended-yield, then
         if state_is_suspended {
             // a. Perform AsyncGeneratorResume(generator, completion).
-            async_generator_resume(agent, generator.unbind(), completion.unbind(), gc);
+            async_generator_resume(
+                agent,
+                generator.unbind(),
+                completion.unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?;
         } else {
             // 10. Else,
             // a. Assert: state is either executing or draining-queue.
@@ -148,7 +154,8 @@ impl AsyncGeneratorPrototype {
                 generator.unbind(),
                 completion.unbind(),
                 gc.reborrow(),
-            );
+            )
+            .unbind()?;
             // 11. Return promiseCapability.[[Promise]].
             Ok(promise.get(agent).into())
         } else {
@@ -167,7 +174,7 @@ impl AsyncGeneratorPrototype {
         arguments: ArgumentsList,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let result: Promise = (|| {
+        let result: JsResult<Promise> = (|| {
             let exception = arguments.get(0).bind(gc.nogc());
             // 1. Let generator be the this value.
             let generator = this_value.bind(gc.nogc());
@@ -177,7 +184,15 @@ impl AsyncGeneratorPrototype {
             // 3. Let result be Completion(AsyncGeneratorValidate(generator, empty)).
             let result = async_generator_validate(agent, generator, (), gc.nogc());
             // 4. IfAbruptRejectPromise(result, promiseCapability).
-            let generator = if_abrupt_reject_promise_m!(agent, result, promise_capability, gc);
+            let generator = match if_abrupt_reject_promise(
+                agent,
+                result,
+                promise_capability.clone(),
+                gc.nogc(),
+            ) {
+                Ok(g) => g,
+                Err(p) => return Ok(p.unbind()),
+            };
             // 5. Let state be generator.[[AsyncGeneratorState]].
             // 6. If state is suspended-start, then
             let mut completed = false;
@@ -192,7 +207,7 @@ impl AsyncGeneratorPrototype {
                 // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « exception »).
                 promise_capability.reject(agent, exception, gc.nogc());
                 // b. Return promiseCapability.[[Promise]].
-                return promise.unbind();
+                return Ok(promise.unbind());
             }
             // 8. Let completion be ThrowCompletion(exception).
             let completion = AsyncGeneratorRequestCompletion::Err(JsError::new(exception.unbind()))
@@ -208,7 +223,8 @@ impl AsyncGeneratorPrototype {
                     generator.unbind(),
                     completion.unbind(),
                     gc.reborrow(),
-                );
+                )
+                .unbind()?;
                 promise = scoped_promise.get(agent).bind(gc.nogc());
             } else {
                 // 11. Else,
@@ -216,9 +232,9 @@ impl AsyncGeneratorPrototype {
                 assert!(generator.is_executing(agent) || generator.is_draining_queue(agent));
             }
             // 12. Return promiseCapability.[[Promise]].
-            promise.unbind()
+            Ok(promise.unbind())
         })();
-        Ok(result.into())
+        Ok(result?.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
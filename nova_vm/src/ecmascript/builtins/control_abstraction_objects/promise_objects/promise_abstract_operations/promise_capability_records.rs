@@ -6,13 +6,15 @@
 
 use crate::{
     ecmascript::{
-        Agent, BUILTIN_STRING_MEMORY, ExceptionType, Function, JsResult, Object, Promise,
-        PromiseHeapData, PromiseRejectionTrackerOperation, PromiseState, TryError, TryGetResult,
-        TryResult, Value, get, try_get,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, BuiltinFunctionArgs, ExceptionType,
+        Function, JsResult, Object, Promise, PromiseHeapData, PromiseRejectionTrackerOperation,
+        PromiseState, TryError, TryGetResult, TryResult, Value, construct, create_builtin_function,
+        get, is_constructor, try_get,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
     heap::{
-        ArenaAccess, ArenaAccessMut, CompactionLists, CreateHeapData, HeapMarkAndSweep, WorkQueues,
+        ArenaAccess, ArenaAccessMut, CompactionLists, CreateHeapData, DirectArenaAccess,
+        HeapMarkAndSweep, WorkQueues,
     },
 };
 
@@ -24,9 +26,10 @@ use super::promise_jobs::new_promise_resolve_thenable_job;
 /// NOTE: In the spec, promise capability records contain an object that is
 /// usable as a promise, together with its resolve and reject functions. In our
 /// current implementation, we only ever support built-in promises, and not
-/// other promise-like objects (e.g. we don't support Promise subclasses), and
-/// for that we don't need to store resolve and reject functions, we can create
-/// them only when needed.
+/// other promise-like objects (Promise subclasses are supported as long as
+/// their constructor creates a built-in promise through `super`), and for that
+/// we don't need to store resolve and reject functions, we can create them
+/// only when needed.
 ///
 /// The `must_be_unresolved` boolean is used to map the `AlreadyResolved` state
 /// of a pair of resolve/reject functions with the promise state. If
@@ -50,6 +53,68 @@ impl<'a> PromiseCapability<'a> {
         Self::from_promise(agent.heap.create(PromiseHeapData::default()), true).bind(gc)
     }
 
+    /// ### [27.2.1.5 NewPromiseCapability ( C )](https://tc39.es/ecma262/#sec-newpromisecapability)
+    ///
+    /// Create a new PromiseCapability using the constructor C.
+    ///
+    /// NOTE: We only support constructors that return a built-in promise, such
+    /// as subclasses of %Promise%. The promise is resolved using its own
+    /// resolving functions rather than the ones passed to the executor.
+    pub(crate) fn new_from_constructor(
+        agent: &mut Agent,
+        c: Value,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        let c = c.bind(gc.nogc());
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let Some(c) = is_constructor(agent, c) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected a Promise constructor",
+                gc.into_nogc(),
+            ));
+        };
+        if c == agent.current_realm_record().intrinsics().promise().into() {
+            return Ok(Self::new(agent, gc.into_nogc()));
+        }
+        // 2. NOTE: C is assumed to be a constructor function that supports
+        //    the parameter conventions of the Promise constructor (see
+        //    27.2.3.1).
+        // 3. Let resolvingFunctions be the Record { [[Resolve]]: undefined, [[Reject]]: undefined }.
+        // 4. Let executorClosure be a new Abstract Closure with parameters
+        //    (resolve, reject) that captures resolvingFunctions and performs
+        //    the following steps when called:
+        // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor = create_builtin_function(
+            agent,
+            Behaviour::Regular(get_capabilities_executor),
+            BuiltinFunctionArgs::new(2, ""),
+            gc.nogc(),
+        );
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = construct(
+            agent,
+            c.unbind(),
+            Some(ArgumentsList::from_mut_value(&mut executor.unbind().into())),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let promise = promise.bind(gc);
+        // 7. If IsCallable(resolvingFunctions.[[Resolve]]) is false, throw a TypeError exception.
+        // 8. If IsCallable(resolvingFunctions.[[Reject]]) is false, throw a TypeError exception.
+        // 9. Return the PromiseCapability Record { [[Promise]]: promise, [[Resolve]]: resolvingFunctions.[[Resolve]], [[Reject]]: resolvingFunctions.[[Reject]] }.
+        let Object::Promise(promise) = promise else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Promise constructor did not return a Promise",
+                gc,
+            ));
+        };
+        Ok(Self::from_promise(promise, true))
+    }
+
     /// Recreate a PromiseCapability from its associated [`Promise`] and the
     /// `must_be_resolved` boolean value.
     ///
@@ -69,10 +134,17 @@ impl<'a> PromiseCapability<'a> {
     }
 
     fn is_already_resolved(&self, agent: &Agent) -> bool {
+        self.is_already_resolved_in(&agent.heap.promises)
+    }
+
+    /// Returns true if calling the resolve or reject function of this
+    /// capability has no effect.
+    #[allow(clippy::ptr_arg)]
+    pub(crate) fn is_already_resolved_in(&self, promises: &Vec<PromiseHeapData<'static>>) -> bool {
         // If `self.must_be_unresolved` is true, then `alreadyResolved`
         // corresponds with the `is_resolved` flag in PromiseState::Pending.
         // Otherwise, it corresponds to `promise_state` not being Pending.
-        match self.promise.get(agent).promise_state {
+        match self.promise.get_direct(promises).promise_state {
             PromiseState::Pending { is_resolved, .. } => {
                 if self.must_be_unresolved {
                     is_resolved
@@ -125,15 +197,19 @@ impl<'a> PromiseCapability<'a> {
         // 6. Set promise.[[PromiseState]] to REJECTED.
         // NOTE: [[PromiseIsHandled]] for pending promises corresponds to
         // whether [[PromiseRejectReactions]] is not empty.
+        let is_handled = reactions.is_some();
         *promise_state = PromiseState::Rejected {
             promise_result: reason.unbind(),
-            is_handled: reactions.is_some(),
+            is_handled,
         };
 
         // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
-        agent
-            .host_hooks
-            .promise_rejection_tracker(self.promise, PromiseRejectionTrackerOperation::Reject);
+        if !is_handled {
+            agent.host_promise_rejection_tracker(
+                self.promise,
+                PromiseRejectionTrackerOperation::Reject,
+            );
+        }
 
         // 8. Perform TriggerPromiseReactions(reactions, reason)
         if let Some(reactions) = reactions {
@@ -366,6 +442,24 @@ impl<'a> PromiseCapability<'a> {
 
 bindable_handle!(PromiseCapability);
 
+/// ### [27.2.1.5 NewPromiseCapability ( C )](https://tc39.es/ecma262/#sec-newpromisecapability)
+///
+/// The executor closure passed to C. The resolving functions are not needed,
+/// as the built-in promise returned by C is resolved directly.
+fn get_capabilities_executor<'gc>(
+    _agent: &mut Agent,
+    _this_value: Value,
+    _arguments: ArgumentsList,
+    _gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // a. If resolvingFunctions.[[Resolve]] is not undefined, throw a TypeError exception.
+    // b. If resolvingFunctions.[[Reject]] is not undefined, throw a TypeError exception.
+    // c. Set resolvingFunctions.[[Resolve]] to resolve.
+    // d. Set resolvingFunctions.[[Reject]] to reject.
+    // e. Return NormalCompletion(undefined).
+    Ok(Value::Undefined)
+}
+
 impl HeapMarkAndSweep for PromiseCapability<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
@@ -130,11 +130,16 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
                 .unbind()?
                 .bind(gc.nogc());
                 // SAFETY: not shared.
-                let _c = unsafe { c.take(agent) }.bind(gc.nogc());
+                let c = unsafe { c.take(agent) }.bind(gc.nogc());
                 // ii. Let p be ? PromiseResolve(C, result).
-                let p = Promise::resolve(agent, result.unbind(), gc.reborrow())
-                    .unbind()?
-                    .bind(gc.nogc());
+                let p = Promise::resolve_with_constructor(
+                    agent,
+                    c.unbind().into(),
+                    result.unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
                 // SAFETY: not shared.
                 let value = unsafe { value.take(agent) }.bind(gc.nogc());
                 // iii. Let returnValue be a new Abstract Closure with no
@@ -172,11 +177,16 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
                 .unbind()?
                 .bind(gc.nogc());
                 // SAFETY: not shared.
-                let _c = unsafe { c.take(agent) }.bind(gc.nogc());
+                let c = unsafe { c.take(agent) }.bind(gc.nogc());
                 // ii. Let p be ? PromiseResolve(C, result).
-                let p = Promise::resolve(agent, result.unbind(), gc.reborrow())
-                    .unbind()?
-                    .bind(gc.nogc());
+                let p = Promise::resolve_with_constructor(
+                    agent,
+                    c.unbind().into(),
+                    result.unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
                 let reason = unsafe { reason.take(agent) }.bind(gc.nogc());
                 // iii. Let throwReason be a new Abstract Closure with no
                 // parameters that captures reason and performs the following
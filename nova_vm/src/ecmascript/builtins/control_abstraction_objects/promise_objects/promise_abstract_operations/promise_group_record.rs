@@ -5,13 +5,13 @@
 use crate::{
     ecmascript::{
         Agent, Array, BUILTIN_STRING_MEMORY, ErrorHeapData, ExceptionType, OrdinaryObject, Promise,
-        PromiseCapability, PromiseReactionType, PropertyDescriptor, Value,
-        try_define_property_or_throw, unwrap_try,
+        PromiseCapability, PromiseReactionType, PromiseState, PropertyDescriptor, Value,
+        invariant_violation, try_define_property_or_throw, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, bindable_handle},
     heap::{
-        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
-        HeapMarkAndSweep, ObjectEntry, WorkQueues, arena_vec_access, index_handle,
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, DirectArenaAccess,
+        Heap, HeapMarkAndSweep, ObjectEntry, WorkQueues, arena_vec_access, index_handle,
     },
 };
 
@@ -26,8 +26,13 @@ pub(crate) enum PromiseGroupType {
 pub(crate) struct PromiseGroupRecord<'a> {
     pub(crate) promise_group_type: PromiseGroupType,
     pub(crate) remaining_elements_count: u32,
-    pub(crate) result_array: Array<'a>,
-    pub(crate) promise: Promise<'a>,
+    /// The values array and the group's result promise, or None if the
+    /// result promise has already been settled.
+    ///
+    /// Once settled, the remaining element reactions have no effect. The
+    /// values are then released so that input promises which never settle
+    /// do not keep them alive.
+    pub(crate) result: Option<(Array<'a>, Promise<'a>)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -37,13 +42,15 @@ index_handle!(PromiseGroup);
 arena_vec_access!(PromiseGroup, 'a, PromiseGroupRecord, promise_group_records);
 
 impl<'a> PromiseGroupRecord<'a> {
-    fn take_result_and_promise(&mut self) -> (Array<'a>, Option<Promise<'a>>) {
+    fn take_result_and_promise(&mut self) -> Option<(Array<'a>, Option<Promise<'a>>)> {
         self.remaining_elements_count = self.remaining_elements_count.saturating_sub(1);
+        let (result_array, promise) = self.result?;
 
         if self.remaining_elements_count > 0 {
-            (self.result_array, None)
+            Some((result_array, None))
         } else {
-            (self.result_array, Some(self.promise))
+            self.result = None;
+            Some((result_array, Some(promise)))
         }
     }
 }
@@ -100,7 +107,11 @@ impl<'a> PromiseGroup<'a> {
         let value = value.bind(gc.nogc());
 
         let promise_group_record = group.get_mut(agent);
-        let (result_array, promise_to_resolve) = promise_group_record.take_result_and_promise();
+        let Some((result_array, promise_to_resolve)) =
+            promise_group_record.take_result_and_promise()
+        else {
+            return;
+        };
 
         let elements = result_array.as_mut_slice(agent);
         elements[index as usize] = Some(value.unbind());
@@ -120,7 +131,11 @@ impl<'a> PromiseGroup<'a> {
         let error = error.bind(gc);
 
         let promise_group_record = promise_group.get_mut(agent);
-        let (result_array, promise_to_resolve) = promise_group_record.take_result_and_promise();
+        let Some((result_array, promise_to_resolve)) =
+            promise_group_record.take_result_and_promise()
+        else {
+            return;
+        };
 
         let elements = result_array.as_mut_slice(agent);
         elements[index as usize] = Some(error.unbind());
@@ -128,13 +143,12 @@ impl<'a> PromiseGroup<'a> {
         if let Some(promise_to_resolve) = promise_to_resolve {
             promise_group.pop_empty_records(agent);
 
+            let stack = agent.capture_stack_trace(gc);
             let aggregate_error = agent
                 .heap
-                .create(ErrorHeapData::new(
-                    ExceptionType::AggregateError,
-                    None,
-                    None,
-                ))
+                .create(
+                    ErrorHeapData::new(ExceptionType::AggregateError, None, None).with_stack(stack),
+                )
                 .bind(gc);
 
             let capability = PromiseCapability::from_promise(promise_to_resolve, true);
@@ -161,9 +175,11 @@ impl<'a> PromiseGroup<'a> {
     fn immediately_resolve(self, agent: &mut Agent, value: Value<'a>, gc: GcScope<'a, '_>) {
         let value = value.bind(gc.nogc());
         let promise_group = self.bind(gc.nogc());
-        let data = promise_group.get_mut(agent);
+        let Some((_, promise)) = promise_group.get_mut(agent).result.take() else {
+            return;
+        };
 
-        let capability = PromiseCapability::from_promise(data.promise, true);
+        let capability = PromiseCapability::from_promise(promise, true);
 
         promise_group.pop_empty_records(agent);
         capability.unbind().resolve(agent, value.unbind(), gc);
@@ -172,9 +188,11 @@ impl<'a> PromiseGroup<'a> {
     fn immediately_reject(self, agent: &mut Agent, value: Value<'a>, gc: NoGcScope<'a, '_>) {
         let value = value.bind(gc);
         let promise_group = self.bind(gc);
-        let data = promise_group.get_mut(agent);
+        let Some((_, promise)) = promise_group.get_mut(agent).result.take() else {
+            return;
+        };
 
-        let capability = PromiseCapability::from_promise(data.promise, true);
+        let capability = PromiseCapability::from_promise(promise, true);
 
         promise_group.pop_empty_records(agent);
         capability.reject(agent, value.unbind(), gc);
@@ -233,6 +251,27 @@ impl<'a> PromiseGroup<'a> {
     }
 }
 
+/// Check that no promise group record retains its values after its result
+/// Promise has been settled.
+///
+/// This is performed during garbage collection if
+/// [`AgentOptions::check_promise_retention`] is set.
+///
+/// [`AgentOptions::check_promise_retention`]: crate::ecmascript::AgentOptions::check_promise_retention
+pub(crate) fn check_settled_promise_groups(heap: &Heap) {
+    let retained = heap.promise_group_records.iter().any(|record| {
+        record.result.is_some_and(|(_, promise)| {
+            !matches!(
+                promise.get_direct(&heap.promises).promise_state,
+                PromiseState::Pending { .. }
+            )
+        })
+    });
+    if retained {
+        invariant_violation("Promise group record retains values after settlement");
+    }
+}
+
 #[doc(hidden)]
 impl AsRef<[PromiseGroupRecord<'static>]> for Agent {
     fn as_ref(&self) -> &[PromiseGroupRecord<'static>] {
@@ -252,22 +291,24 @@ impl HeapMarkAndSweep for PromiseGroupRecord<'static> {
         let Self {
             promise_group_type: _,
             remaining_elements_count: _,
-            result_array,
-            promise,
+            result,
         } = self;
-        result_array.mark_values(queues);
-        promise.mark_values(queues);
+        if let Some((result_array, promise)) = result {
+            result_array.mark_values(queues);
+            promise.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             promise_group_type: _,
             remaining_elements_count: _,
-            result_array,
-            promise,
+            result,
         } = self;
-        result_array.sweep_values(compactions);
-        promise.sweep_values(compactions);
+        if let Some((result_array, promise)) = result {
+            result_array.sweep_values(compactions);
+            promise.sweep_values(compactions);
+        }
     }
 }
 
@@ -4,14 +4,18 @@
 
 //! ### [27.2.2 Promise Jobs](https://tc39.es/ecma262/#sec-promise-jobs)
 
+#[cfg(feature = "web-streams")]
+use crate::ecmascript::ReadableStreamReaction;
+#[cfg(feature = "futures")]
+use crate::ecmascript::settle_promise_future;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Function, InnerJob, Job, JsError, JsResult, Object, Promise,
         PromiseCapability, PromiseReaction, PromiseReactionHandler, PromiseReactionType,
-        PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType, Value,
+        PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType, ProtoIntrinsics, Value,
         async_module_execution_fulfilled, async_module_execution_rejected, call_function,
         create_iter_result_object, get_function_realm, import_get_module_namespace,
-        iterator_close_with_error, link_and_evaluate,
+        iterator_close_with_error, link_and_evaluate, perform_promise_then, species_constructor,
     },
     engine::{Bindable, GcScope, Global, NoGcScope, Scopable},
     heap::{ArenaAccess, CreateHeapData},
@@ -30,6 +34,17 @@ impl PromiseResolveThenableJob {
             thenable,
             then,
         } = self;
+        if let Object::Promise(promise) = thenable.get(agent, gc.nogc())
+            && then.get(agent, gc.nogc())
+                == agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .promise_prototype_then()
+                    .into()
+        {
+            let promise = promise.unbind();
+            return resolve_with_native_promise(agent, promise_to_resolve, promise, gc);
+        }
         // The following are substeps of point 1 in NewPromiseResolveThenableJob.
         // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
         // Note: We do not take the Promise from the Global yet. It must be taken
@@ -38,12 +53,12 @@ impl PromiseResolveThenableJob {
         let promise_capability = PromiseCapability::from_promise(promise, false);
         let resolve_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Resolve,
         });
         let reject_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Reject,
         });
 
@@ -72,6 +87,10 @@ impl PromiseResolveThenableJob {
 
         // c. If thenCallResult is an abrupt completion, then
         if let Err(err) = then_call_result {
+            // NOTE: Termination is not turned into a rejection.
+            if err.is_termination(agent) {
+                return Err(err.unbind());
+            }
             // i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
             promise_capability.reject(agent, err.value(), gc.nogc());
         }
@@ -80,6 +99,93 @@ impl PromiseResolveThenableJob {
     }
 }
 
+/// Run NewPromiseResolveThenableJob with a native Promise as the thenable and
+/// %Promise.prototype.then% as its then function.
+///
+/// Calling the then function performs SpeciesConstructor, NewPromiseCapability
+/// and PerformPromiseThen with the resolving functions of promiseToResolve.
+/// When the species constructor is %Promise%, neither the resolving functions
+/// nor the derived promise are observable, so the thenable's reactions can
+/// settle promiseToResolve directly without creating them.
+fn resolve_with_native_promise<'a>(
+    agent: &mut Agent,
+    promise_to_resolve: Global<Promise<'static>>,
+    thenable: Promise,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let thenable = thenable.bind(gc.nogc());
+    let scoped_thenable = thenable.scope(agent, gc.nogc());
+    // 27.2.5.4 Promise.prototype.then ( onFulfilled, onRejected )
+    // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+    let c = species_constructor(
+        agent,
+        thenable.unbind().into(),
+        ProtoIntrinsics::Promise,
+        gc.reborrow(),
+    )
+    .unbind()
+    .bind(gc.nogc());
+    let c = match c {
+        Ok(c) if c != agent.current_realm_record().intrinsics().promise().into() => {
+            // 4. Let resultCapability be ? NewPromiseCapability(C).
+            PromiseCapability::new_from_constructor(agent, c.unbind().into(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc())
+                .map(Some)
+        }
+        Ok(_) => Ok(None),
+        Err(err) => Err(err),
+    };
+    let c = c.unbind();
+    let gc = gc.into_nogc();
+    let c = c.bind(gc);
+    let promise = promise_to_resolve.take(agent).bind(gc);
+    // SAFETY: not shared.
+    let thenable = unsafe { scoped_thenable.take(agent) }.bind(gc);
+    let promise_capability = PromiseCapability::from_promise(promise, false);
+    match c {
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(None) => {
+            // Note: Reactions without handlers pass the thenable's result on
+            // to their capability, as the resolving functions would.
+            perform_promise_then(
+                agent,
+                thenable,
+                Value::Undefined,
+                Value::Undefined,
+                Some(promise_capability),
+                gc,
+            );
+        }
+        Ok(Some(result_capability)) => {
+            let resolve_function = agent.heap.create(PromiseResolvingFunctionHeapData {
+                object_index: None,
+                promise_capability: Some(promise_capability.clone()),
+                resolve_type: PromiseResolvingFunctionType::Resolve,
+            });
+            let reject_function = agent.heap.create(PromiseResolvingFunctionHeapData {
+                object_index: None,
+                promise_capability: Some(promise_capability),
+                resolve_type: PromiseResolvingFunctionType::Reject,
+            });
+            perform_promise_then(
+                agent,
+                thenable,
+                resolve_function.into(),
+                reject_function.into(),
+                Some(result_capability),
+                gc,
+            );
+        }
+        // c. If thenCallResult is an abrupt completion, then
+        Err(err) => {
+            // i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+            promise_capability.reject(agent, err.value(), gc);
+        }
+    }
+    Ok(())
+}
+
 /// ### [27.2.2.2 NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )](https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob)
 pub(crate) fn new_promise_resolve_thenable_job(
     agent: &mut Agent,
@@ -164,26 +270,21 @@ impl PromiseReactionJob {
             PromiseReactionHandler::Await(await_reaction) => {
                 assert!(reaction_data.capability.is_none());
                 let reaction_type = reaction_data.reaction_type;
-                await_reaction.unbind().resume(
-                    agent,
-                    reaction_type,
-                    argument.unbind(),
-                    gc.reborrow(),
-                );
                 // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
                 // 5. f. Return undefined.
-                return Ok(());
+                return await_reaction
+                    .unbind()
+                    .resume(agent, reaction_type, argument.unbind(), gc);
             }
             PromiseReactionHandler::AsyncGenerator(async_generator) => {
                 assert!(reaction_data.capability.is_none());
                 let reaction_type = reaction_data.reaction_type;
-                async_generator.unbind().resume_await(
+                return async_generator.unbind().resume_await(
                     agent,
                     reaction_type,
                     argument.unbind(),
-                    gc.reborrow(),
+                    gc,
                 );
-                return Ok(());
             }
             PromiseReactionHandler::AsyncFromSyncIterator { done } => {
                 let capability = reaction_data.capability.clone().unwrap().bind(gc.nogc());
@@ -287,6 +388,34 @@ impl PromiseReactionJob {
                 );
                 return Ok(());
             }
+            #[cfg(feature = "futures")]
+            PromiseReactionHandler::Future(index) => {
+                assert!(reaction_data.capability.is_none());
+                let reaction_type = reaction_data.reaction_type;
+                settle_promise_future(agent, index, reaction_type, argument.unbind());
+                return Ok(());
+            }
+            #[cfg(feature = "web-streams")]
+            PromiseReactionHandler::ReadableStream(ReadableStreamReaction::Cancel) => {
+                let capability = reaction_data.capability.clone().unwrap().bind(gc.nogc());
+                match reaction_data.reaction_type {
+                    // Fulfilled with undefined.
+                    PromiseReactionType::Fulfill => (Ok(Value::Undefined), capability),
+                    PromiseReactionType::Reject => (Err(JsError::new(argument)), capability),
+                }
+            }
+            #[cfg(feature = "web-streams")]
+            PromiseReactionHandler::ReadableStream(stream_reaction) => {
+                assert!(reaction_data.capability.is_none());
+                let reaction_type = reaction_data.reaction_type;
+                stream_reaction.unbind().settle(
+                    agent,
+                    reaction_type,
+                    argument.unbind(),
+                    gc.reborrow(),
+                );
+                return Ok(());
+            }
         };
 
         // f. If promiseCapability is undefined, then
@@ -294,6 +423,8 @@ impl PromiseReactionJob {
         // ii. Return empty.
 
         match handler_result {
+            // NOTE: Termination is not turned into a rejection.
+            Err(err) if err.is_termination(agent) => return Err(err.unbind()),
             // h. If handlerResult is an abrupt completion, then
             Err(err) => {
                 // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
@@ -350,6 +481,10 @@ pub(crate) fn new_promise_reaction_job(
         | PromiseReactionHandler::DynamicImport { .. }
         | PromiseReactionHandler::DynamicImportEvaluate { .. }
         | PromiseReactionHandler::PromiseGroup { .. } => None,
+        #[cfg(feature = "futures")]
+        PromiseReactionHandler::Future(_) => None,
+        #[cfg(feature = "web-streams")]
+        PromiseReactionHandler::ReadableStream(_) => None,
     };
 
     // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "web-streams")]
+use crate::ecmascript::ReadableStreamReaction;
 use crate::{
     ecmascript::{
         AbstractModule, AsyncGenerator, AwaitReaction, Function, Object, Promise,
@@ -58,6 +60,15 @@ pub(crate) enum PromiseReactionHandler<'a> {
         index: u32,
         promise_group: PromiseGroup<'a>,
     },
+    /// See [`Promise::into_future`].
+    ///
+    /// [`Promise::into_future`]: crate::ecmascript::Promise::into_future
+    #[cfg(feature = "futures")]
+    Future(u32),
+    /// Reaction to a promise returned by an underlying source algorithm of a
+    /// ReadableStream.
+    #[cfg(feature = "web-streams")]
+    ReadableStream(ReadableStreamReaction<'a>),
     Empty,
 }
 bindable_handle!(PromiseReactionHandler);
@@ -80,6 +91,10 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 index: _,
                 promise_group,
             } => promise_group.mark_values(queues),
+            #[cfg(feature = "futures")]
+            Self::Future(_) => {}
+            #[cfg(feature = "web-streams")]
+            Self::ReadableStream(reaction) => reaction.mark_values(queues),
             Self::Empty => {}
         }
     }
@@ -103,6 +118,10 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 index: _,
                 promise_group,
             } => promise_group.sweep_values(compactions),
+            #[cfg(feature = "futures")]
+            Self::Future(_) => {}
+            #[cfg(feature = "web-streams")]
+            Self::ReadableStream(reaction) => reaction.sweep_values(compactions),
             Self::Empty => {}
         }
     }
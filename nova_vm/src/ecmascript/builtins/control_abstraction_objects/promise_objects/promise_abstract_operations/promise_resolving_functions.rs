@@ -29,7 +29,13 @@ pub(crate) enum PromiseResolvingFunctionType {
 #[derive(Debug, Clone)]
 pub(crate) struct PromiseResolvingFunctionHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
-    pub(crate) promise_capability: PromiseCapability<'a>,
+    /// The \[\[Promise\]\] of the function, or None if the function's
+    /// \[\[AlreadyResolved\]\] state is true.
+    ///
+    /// Once the Promise is already resolved, calling the function has no
+    /// effect. The capability is then released so that a retained resolving
+    /// function does not keep the Promise and its result alive.
+    pub(crate) promise_capability: Option<PromiseCapability<'a>>,
     pub(crate) resolve_type: PromiseResolvingFunctionType,
 }
 
@@ -83,7 +89,13 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseResolvingFunction<'a>
     ) -> JsResult<'gc, Value<'gc>> {
         agent.check_call_depth(gc.nogc()).unbind()?;
         let arguments_list = arguments_list.get(0).bind(gc.nogc());
-        let promise_capability = self.get(agent).promise_capability.clone();
+        // Note: Calling either function of the pair sets their shared
+        // [[AlreadyResolved]] state to true, after which calling this function
+        // has no effect. The other function of the pair is released by
+        // release_resolved_promise_capabilities.
+        let Some(promise_capability) = self.get_mut(agent).promise_capability.take() else {
+            return Ok(Value::Undefined);
+        };
         match self.get(agent).resolve_type {
             PromiseResolvingFunctionType::Resolve => {
                 promise_capability.resolve(agent, arguments_list.unbind(), gc)
@@ -96,6 +108,29 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseResolvingFunction<'a>
     }
 }
 
+/// Release the \[\[Promise\]\] of all promise resolving functions whose
+/// \[\[AlreadyResolved\]\] state is true.
+///
+/// Only one function of a resolve/reject pair is usually called; this is
+/// performed before garbage collection to release the other function's
+/// reference to the Promise.
+pub(crate) fn release_resolved_promise_capabilities(heap: &mut Heap) {
+    let Heap {
+        promise_resolving_functions,
+        promises,
+        ..
+    } = heap;
+    for function in promise_resolving_functions.iter_mut() {
+        if function
+            .promise_capability
+            .as_ref()
+            .is_some_and(|capability| capability.is_already_resolved_in(promises))
+        {
+            function.promise_capability = None;
+        }
+    }
+}
+
 impl<'a> CreateHeapData<PromiseResolvingFunctionHeapData<'a>, BuiltinPromiseResolvingFunction<'a>>
     for Heap
 {
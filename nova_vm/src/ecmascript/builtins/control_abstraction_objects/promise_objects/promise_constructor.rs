@@ -7,15 +7,15 @@ use crate::heap::ArenaAccessSoA;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
-        BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsError, JsResult,
-        Object, OrdinaryObject, Promise, PromiseCapability, PromiseGroupRecord, PromiseGroupType,
-        PromiseHeapData, PromiseReactionHandler, PromiseResolvingFunctionHeapData,
-        PromiseResolvingFunctionType, PromiseState, PropertyKey, ProtoIntrinsics, Realm, String,
-        Value, array_create, builders::BuiltinFunctionBuilder, call, call_function, get,
-        get_iterator, inner_promise_then, is_callable, is_constructor, iterator_close_with_error,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsResult, Object,
+        OrdinaryObject, Promise, PromiseCapability, PromiseGroupRecord, PromiseGroupType,
+        PromiseReactionHandler, PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType,
+        PropertyKey, ProtoIntrinsics, Realm, String, Value, array_create,
+        builders::BuiltinFunctionBuilder, call, call_function, get, get_iterator,
+        inner_promise_then, is_callable, is_constructor, iterator_close_with_error,
         iterator_step_value, ordinary_create_from_constructor,
     },
-    engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, bindable_handle},
+    engine::{Bindable, GcScope, Scopable, Scoped, bindable_handle},
     heap::{
         ArenaAccessMut, CreateHeapData, IntrinsicConstructorIndexes, ObjectEntry, WellKnownSymbols,
     },
@@ -124,13 +124,6 @@ impl PromiseConstructor {
         };
         let new_target = new_target.unbind().bind(gc.nogc());
 
-        if new_target != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
-                gc.into_nogc(),
-            ));
-        }
-
         // 2. If IsCallable(executor) is false, throw a TypeError exception.
         // TODO: Callable proxies
         let Ok(executor) = Function::try_from(executor) else {
@@ -164,12 +157,12 @@ impl PromiseConstructor {
         let promise_capability = PromiseCapability::from_promise(promise, true);
         let resolve_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Resolve,
         });
         let reject_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Reject,
         });
 
@@ -185,6 +178,10 @@ impl PromiseConstructor {
             ])),
             gc.reborrow(),
         ) {
+            // NOTE: Termination is not turned into a rejection.
+            if err.is_termination(agent) {
+                return Err(err.unbind());
+            }
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
             let promise_capability =
                 PromiseCapability::from_promise(scoped_promise.get(agent), true);
@@ -480,21 +477,25 @@ impl PromiseConstructor {
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        let r = arguments.get(0).bind(gc);
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(agent, gc));
-        }
-
+        let r = arguments.get(0).bind(gc.nogc());
         // 1. Let C be the this value.
+        if this_value == agent.current_realm_record().intrinsics().promise().into() {
+            // NOTE: For %Promise% this is equivalent to creating an
+            // already-rejected promise.
+            return Ok(Promise::new_rejected(agent, r.unbind(), gc.into_nogc()).into());
+        }
+        let r = r.scope(agent, gc.nogc());
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability =
+            PromiseCapability::new_from_constructor(agent, this_value, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let promise_capability = promise_capability.bind(gc);
         // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        promise_capability.reject(agent, r.get(agent), gc);
         // 4. Return promiseCapability.[[Promise]].
-        // NOTE: Since we don't support promise subclassing, this is equivalent
-        // to creating an already-rejected promise.
-        Ok(Promise::new_rejected(agent, r, gc).into())
+        Ok(promise_capability.promise().into())
     }
 
     /// ### [27.2.4.7 Promise.resolve ( x )](https://tc39.es/ecma262/#sec-promise.resolve)
@@ -508,15 +509,18 @@ impl PromiseConstructor {
         arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
+        // 1. Let C be the this value.
+        // 2. If C is not an Object, throw a TypeError exception.
+        if !this_value.is_object() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected the this value to be an object.",
                 gc.into_nogc(),
             ));
         }
 
         // 3. Return ? PromiseResolve(C, x).
-        Promise::resolve(agent, arguments.get(0), gc).map(Value::from)
+        Promise::resolve_with_constructor(agent, this_value, arguments.get(0), gc).map(Value::from)
     }
 
     /// ### [27.2.4.8 Promise.try ( callback, ...args )](https://tc39.es/ecma262/#sec-promise.try)
@@ -530,54 +534,56 @@ impl PromiseConstructor {
         arguments: ArgumentsList,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let callback_fn = arguments.get(0).bind(gc.nogc());
-        let args = arguments.slice_from(1).bind(gc.nogc());
+        let callback_fn = arguments.get(0).scope(agent, gc.nogc());
+        let mut args = arguments.slice_from(1);
         // 1. Let C be the this value.
         // 2. If C is not an Object, throw a TypeError exception.
-        if is_constructor(agent, this_value).is_none() {
+        if !this_value.is_object() {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
-                "Expected the this value to be a constructor.",
-                gc.into_nogc(),
-            ));
-        }
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
+                "Expected the this value to be an object.",
                 gc.into_nogc(),
             ));
         }
 
         // 3. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = args
+            .with_scoped(
+                agent,
+                |agent, _, gc| PromiseCapability::new_from_constructor(agent, this_value, gc),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+        let promise = promise_capability.promise().scope(agent, gc.nogc());
+
         // 4. Let status be Completion(Call(callbackfn, undefined, args)).
         let status = call(
             agent,
-            callback_fn.unbind(),
+            callback_fn.get(agent),
             Value::Undefined,
             Some(args.unbind()),
             gc.reborrow(),
-        );
-        let promise = match status {
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        match status {
+            // NOTE: Termination is not turned into a rejection.
+            Err(err) if err.is_termination(agent) => return Err(err.unbind()),
             // 5. If status is an abrupt completion, then
             Err(err) => {
                 // a. Perform ? Call(promiseCapability.[[Reject]], undefined, « status.[[Value]] »).
-                // 7. Return promiseCapability.[[Promise]].
-                agent.heap.create(PromiseHeapData {
-                    object_index: None,
-                    promise_state: PromiseState::Rejected {
-                        promise_result: err.value(),
-                        is_handled: false,
-                    },
-                })
+                promise_capability.reject(agent, err.value().unbind(), gc.nogc());
             }
             // 6. Else,
             Ok(result) => {
                 // a. Perform ? Call(promiseCapability.[[Resolve]], undefined, « status.[[Value]] »).
-                Promise::resolve(agent, result.unbind(), gc)?
+                promise_capability.resolve(agent, result.unbind(), gc.reborrow());
             }
         };
         // 7. Return promiseCapability.[[Promise]].
-        Ok(promise.unbind().into())
+        Ok(promise.get(agent).into())
     }
 
     /// ### [27.2.4.9 Promise.withResolvers ( )](https://tc39.es/ecma262/#sec-promise.withResolvers)
@@ -587,30 +593,17 @@ impl PromiseConstructor {
         _arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        // Step 2 will throw if `this_value` is not a constructor.
-        if is_constructor(agent, this_value).is_none() {
-            return Err(agent.throw_exception_with_static_message(
-                ExceptionType::TypeError,
-                "Expected the this value to be a constructor.",
-                gc,
-            ));
-        }
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(agent, gc));
-        }
-
         // 1. Let C be the this value.
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
-        let promise_capability = PromiseCapability::new(agent, gc);
+        let promise_capability = PromiseCapability::new_from_constructor(agent, this_value, gc)?;
         let resolve_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Resolve,
         });
         let reject_function = agent.heap.create(PromiseResolvingFunctionHeapData {
             object_index: None,
-            promise_capability: promise_capability.clone(),
+            promise_capability: Some(promise_capability.clone()),
             resolve_type: PromiseResolvingFunctionType::Reject,
         });
 
@@ -723,24 +716,17 @@ fn promise_group_setup<'gc>(
     let iterable = arguments.get(0).scope(agent, gc.nogc());
 
     // 1. Let C be the this value.
-    if this_value != agent.current_realm_record().intrinsics().promise().into() {
-        return Err(throw_promise_subclassing_not_supported(
-            agent,
-            gc.into_nogc(),
-        ));
-    }
-
+    let c = this_value.scope(agent, gc.nogc());
     // 2. Let promiseCapability be ? NewPromiseCapability(C).
-    let Some(constructor) = is_constructor(agent, this_value) else {
-        return Err(agent.throw_exception_with_static_message(
-            ExceptionType::TypeError,
-            "Expected the this value to be a constructor.",
-            gc.into_nogc(),
-        ));
-    };
-    let constructor = constructor.scope(agent, gc.nogc());
-    let promise_capability = PromiseCapability::new(agent, gc.nogc());
+    let promise_capability =
+        PromiseCapability::new_from_constructor(agent, this_value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
     let promise = promise_capability.promise().scope(agent, gc.nogc());
+    // NOTE: NewPromiseCapability checked that C is a constructor.
+    let constructor = is_constructor(agent, c.get(agent))
+        .unwrap()
+        .scope(agent, gc.nogc());
 
     // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
     let promise_resolve = get_promise_resolve(agent, constructor.get(agent), gc.reborrow())
@@ -754,6 +740,8 @@ fn promise_group_setup<'gc>(
     };
 
     let promise_resolve = match promise_resolve.unbind().bind(gc.nogc()) {
+        // NOTE: Termination is not turned into a rejection.
+        Err(err) if err.is_termination(agent) => return Err(err.unbind()),
         Err(err) => {
             promise_capability.reject(agent, err.value().unbind(), gc.nogc());
             let promise = promise_capability.promise.unbind().bind(gc.into_nogc());
@@ -775,6 +763,8 @@ fn promise_group_setup<'gc>(
     };
 
     let iterator_record = match iterator_record.unbind().bind(gc.nogc()) {
+        // NOTE: Termination is not turned into a rejection.
+        Err(err) if err.is_termination(agent) => return Err(err.unbind()),
         Err(err) => {
             promise_capability.reject(agent, err.value().unbind(), gc.nogc());
             let promise = promise_capability.promise.unbind().bind(gc.into_nogc());
@@ -826,6 +816,10 @@ fn handle_promise_group_result<'gc>(
                 .unbind()
                 .bind(gc.nogc());
             }
+            // NOTE: Termination is not turned into a rejection.
+            if result.is_termination(agent) {
+                return Err(result.unbind());
+            }
 
             // b. IfAbruptRejectPromise(result, promiseCapability).
             let promise_capability = PromiseCapability {
@@ -886,8 +880,7 @@ fn perform_promise_group<'gc>(
         .create(PromiseGroupRecord {
             promise_group_type,
             remaining_elements_count: 1,
-            result_array: result_array.get(agent),
-            promise: promise.get(agent),
+            result: Some((result_array.get(agent), promise.get(agent))),
         })
         .scope(agent, gc.nogc());
 
@@ -918,6 +911,7 @@ fn perform_promise_group<'gc>(
 
             // ii. If remainingElementsCount.[[Value]] = 0, then
             if data.remaining_elements_count == 0 {
+                data.result = None;
                 // 1. Let valuesArray be CreateArrayFromList(values).
                 let values_array = result_array.get(agent).bind(gc.nogc());
                 // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
@@ -955,7 +949,7 @@ fn perform_promise_group<'gc>(
         .unbind()?
         .bind(gc.nogc());
 
-        // Note: as we don't yet support Promise subclassing, if we see a
+        // Note: as we only support built-in promises, if we see a
         // non-Promise return we wrap it inside a resolved Promise to get
         // then-chaining.
         let next_promise = match call_result {
@@ -1058,14 +1052,3 @@ fn perform_promise_race<'gc>(
         );
     }
 }
-
-fn throw_promise_subclassing_not_supported<'a>(
-    agent: &mut Agent,
-    gc: NoGcScope<'a, '_>,
-) -> JsError<'a> {
-    agent.throw_exception_with_static_message(
-        ExceptionType::Error,
-        "Promise subclassing is not supported",
-        gc,
-    )
-}
@@ -4,7 +4,7 @@
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic,
         BuiltinPromiseFinallyFunction, ExceptionType, Function, JsResult, Object, Promise,
         PromiseCapability, PromiseReactionHandler, PromiseReactionRecord, PromiseReactionType,
         PromiseReactions, PromiseRejectionTrackerOperation, PromiseState, ProtoIntrinsics, Realm,
@@ -12,7 +12,7 @@ use crate::{
         new_promise_reaction_job, species_constructor,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
-    heap::{ArenaAccessMut, CreateHeapData, WellKnownSymbols},
+    heap::{ArenaAccessMut, CreateHeapData, IntrinsicFunctionIndexes, WellKnownSymbols},
 };
 
 pub(crate) struct PromisePrototype;
@@ -35,6 +35,9 @@ impl Builtin for PromisePrototypeThen {
     const LENGTH: u8 = 2;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(PromisePrototype::then);
 }
+impl BuiltinIntrinsic for PromisePrototypeThen {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::PromisePrototypeThen;
+}
 
 impl PromisePrototype {
     /// ### [27.2.5.1 Promise.prototype.catch ( onRejected )](https://tc39.es/ecma262/#sec-promise.prototype.catch)
@@ -132,27 +135,48 @@ impl PromisePrototype {
     fn then<'gc>(
         agent: &mut Agent,
         this_value: Value,
-        args: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        let on_fulfilled = args.get(0).bind(gc);
-        let on_rejected = args.get(1).bind(gc);
         // 1. Let promise be the this value.
         // 2. If IsPromise(promise) is false, throw a TypeError exception.
         let Value::Promise(promise) = this_value else {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
                 "'this' is not a promise",
-                gc,
+                gc.into_nogc(),
             ));
         };
 
+        let promise = promise.bind(gc.nogc());
+        let scoped_promise = promise.scope(agent, gc.nogc());
+
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
         // 4. Let resultCapability be ? NewPromiseCapability(C).
-        // NOTE: We're ignoring species and subclasses.
-        let result_capability = PromiseCapability::new(agent, gc);
+        let promise = promise.unbind();
+        let result_capability = args
+            .with_scoped(
+                agent,
+                |agent, _, mut gc| {
+                    let c = species_constructor(
+                        agent,
+                        promise.into(),
+                        ProtoIntrinsics::Promise,
+                        gc.reborrow(),
+                    )
+                    .unbind()?
+                    .bind(gc.nogc());
+                    PromiseCapability::new_from_constructor(agent, c.unbind().into(), gc)
+                },
+                gc.reborrow(),
+            )
+            .unbind()?;
+        let gc = gc.into_nogc();
+        let result_capability = result_capability.bind(gc);
         let result_capability_promise = result_capability.promise();
+        let promise = scoped_promise.get(agent).bind(gc);
+        let on_fulfilled = args.get(0).bind(gc);
+        let on_rejected = args.get(1).bind(gc);
 
         // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
         perform_promise_then(
@@ -178,7 +202,7 @@ impl PromisePrototype {
             .with_builtin_function_property::<PromisePrototypeCatch>()
             .with_constructor_property(promise_constructor)
             .with_builtin_function_property::<PromisePrototypeFinally>()
-            .with_builtin_function_property::<PromisePrototypeThen>()
+            .with_builtin_intrinsic_function_property::<PromisePrototypeThen>()
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbols::ToStringTag.into())
@@ -310,9 +334,10 @@ pub(crate) fn inner_promise_then(
                 // the mutable reference before calling into the host hook.
                 *is_handled = true;
 
-                agent
-                    .host_hooks
-                    .promise_rejection_tracker(promise, PromiseRejectionTrackerOperation::Handle);
+                agent.host_promise_rejection_tracker(
+                    promise,
+                    PromiseRejectionTrackerOperation::Handle,
+                );
             }
             // d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
             let reject_job = new_promise_reaction_job(agent, reject_reaction, promise_result, gc);
@@ -2,33 +2,15 @@ use ecmascript_atomics::Ordering;
 
 use crate::{
     ecmascript::{
-        Agent, BigInt, ExceptionType, JsResult, Number, Numeric, Value, Viewable,
-        is_fixed_length_array_buffer, require_internal_slot_data_view, set_value_in_buffer,
-        to_big_int, to_index, to_number, try_result_into_js, try_to_index,
+        Agent, BigInt, CachedBufferByteLength, ExceptionType, JsResult, Number, Numeric, Value,
+        Viewable, is_fixed_length_array_buffer, require_internal_slot_data_view,
+        set_value_in_buffer, to_big_int, to_index, to_number, try_result_into_js, try_to_index,
     },
     engine::{Bindable, GcScope, Scopable},
 };
 
 use super::AnyDataView;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct ByteLength(pub usize);
-
-impl ByteLength {
-    pub const fn value(value: usize) -> Self {
-        Self(value)
-    }
-
-    pub const fn detached() -> Self {
-        Self(usize::MAX)
-    }
-
-    /// Returns `true` if the byte length is the detached sentinel.
-    pub(crate) fn is_detached(&self) -> bool {
-        *self == Self::detached()
-    }
-}
-
 /// ### [25.3.1.1 DataView With Buffer Witness Records](https://tc39.es/ecma262/#sec-dataview-with-buffer-witness-records)
 ///
 /// A DataView With Buffer Witness Record is a Record value used to encapsulate
@@ -40,7 +22,7 @@ pub(crate) struct DataViewWithBufferWitnessRecord<'a> {
     /// ### [\[\[Object\]\]](https://tc39.es/ecma262/#table-dataview-with-buffer-witness-record-fields)
     object: AnyDataView<'a>,
     /// ### [\[\[CachedBufferByteLength\]\]](https://tc39.es/ecma262/#table-dataview-with-buffer-witness-record-fields)
-    cached_buffer_byte_length: ByteLength,
+    cached_buffer_byte_length: CachedBufferByteLength,
 }
 
 /// ### [25.3.1.2 MakeDataViewWithBufferWitnessRecord ( obj, order )](https://tc39.es/ecma262/#sec-makedataviewwithbufferwitnessrecord)
@@ -54,12 +36,14 @@ pub(crate) fn make_data_view_with_buffer_witness_record<'a>(
     obj: AnyDataView<'a>,
     order: Ordering,
 ) -> DataViewWithBufferWitnessRecord<'a> {
-    let buffer = obj.viewed_array_buffer(agent);
-    let byte_length = if buffer.is_detached(agent) {
-        ByteLength::detached()
-    } else {
-        ByteLength::value(buffer.byte_length(agent, order))
-    };
+    // 1. Let buffer be obj.[[ViewedArrayBuffer]].
+    // 2. If IsDetachedBuffer(buffer) is true, then
+    // a. Let byteLength be detached.
+    // 3. Else,
+    // a. Let byteLength be ArrayBufferByteLength(buffer, order).
+    let byte_length =
+        CachedBufferByteLength::from_buffer(agent, obj.viewed_array_buffer(agent), order);
+    // 4. Return the DataView With Buffer Witness Record { [[Object]]: obj, [[CachedBufferByteLength]]: byteLength }.
     DataViewWithBufferWitnessRecord {
         object: obj,
         cached_buffer_byte_length: byte_length,
@@ -99,11 +83,10 @@ pub(crate) fn get_view_byte_length(
 
     // 6. Let byteLength be viewRecord.[[CachedBufferByteLength]].
     // 7. Assert: byteLength is not detached.
-    assert!(!view_record.cached_buffer_byte_length.is_detached());
-    let byte_length = view_record.cached_buffer_byte_length.0;
-
     // 8. Return byteLength - byteOffset.
-    byte_length - byte_offset
+    view_record
+        .cached_buffer_byte_length
+        .length_tracking_byte_length(byte_offset)
 }
 
 /// ### [25.3.1.4 IsViewOutOfBounds ( viewRecord )](https://tc39.es/ecma262/#sec-isviewoutofbounds)
@@ -117,41 +100,26 @@ pub(crate) fn is_view_out_of_bounds(
 ) -> bool {
     // 1. Let view be viewRecord.[[Object]].
     let view = view_record.object;
-    let ab = view.viewed_array_buffer(agent);
-    // 5. Let byteOffsetStart be view.[[ByteOffset]].
-    let byte_offset_start = view.byte_offset(agent);
-    let byte_length = view.byte_length(agent);
 
     // 2. Let bufferByteLength be viewRecord.[[CachedBufferByteLength]].
     let buffer_byte_length = view_record.cached_buffer_byte_length;
 
     // 3. Assert: IsDetachedBuffer(view.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
-    assert!(ab.is_detached(agent) == buffer_byte_length.is_detached());
+    assert_eq!(
+        view.viewed_array_buffer(agent).is_detached(agent),
+        buffer_byte_length.is_detached()
+    );
 
     // 4. If bufferByteLength is detached, return true.
-    if buffer_byte_length.is_detached() {
-        return true;
-    }
-    let buffer_byte_length = buffer_byte_length.0;
-
+    // 5. Let byteOffsetStart be view.[[ByteOffset]].
     // 6. If view.[[ByteLength]] is auto, then
-    let byte_offset_end = if let Some(byte_length) = byte_length {
-        // 7. Else,
-        // a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
-        byte_offset_start + byte_length
-    } else {
-        // a. Let byteOffsetEnd be bufferByteLength.
-        buffer_byte_length
-    };
-
+    // a. Let byteOffsetEnd be bufferByteLength.
+    // 7. Else,
+    // a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
     // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
-    if byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length {
-        return true;
-    }
-
     // 9. NOTE: 0-length DataViews are not considered out-of-bounds.
     // 10. Return false.
-    false
+    buffer_byte_length.is_view_out_of_bounds(view.byte_offset(agent), view.byte_length(agent))
 }
 
 /// ### [25.3.1.5 GetViewValue ( view, requestIndex, isLittleEndian, type )](https://tc39.es/ecma262/#sec-getviewvalue)
@@ -2,10 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::time::SystemTime;
-
 use crate::{
-    ecmascript::{OrdinaryObject, SmallInteger, Value},
+    ecmascript::{Agent, OrdinaryObject, SmallInteger, Value},
     engine::bindable_handle,
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -42,13 +40,12 @@ impl DateValue {
         self.get_i64().map(|v| v as f64)
     }
 
-    pub(crate) fn now() -> Self {
-        let now = SystemTime::now();
-        let now = now
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_millis();
-        Self(now as i64)
+    /// The time value identifying the current time, as given by
+    /// [`HostHooks::current_time`].
+    ///
+    /// [`HostHooks::current_time`]: crate::ecmascript::HostHooks::current_time
+    pub(crate) fn now(agent: &Agent) -> Self {
+        Self::time_clip(agent.host_hooks.current_time() as f64)
     }
 
     /// ### [21.4.1.31 TimeClip ( time )](https://tc39.es/ecma262/#sec-timeclip)
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::ptr::NonNull;
+use core::{ops::ControlFlow, ptr::NonNull};
 use std::borrow::Cow;
 
 use oxc_ast::ast::{FormalParameters, FunctionBody};
@@ -12,11 +12,11 @@ use oxc_span::Span;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, ECMAScriptCodeEvaluationState,
-        ECMAScriptFunctionHeapData, Environment, ExceptionType, ExecutionContext, Function,
-        FunctionEnvironment, FunctionInternalProperties, InternalSlots, JsResult, Object,
-        OrdinaryObject, PrivateEnvironment, PropertyDescriptor, PropertyKey, ProtoIntrinsics,
-        Realm, ScriptOrModule, SourceCode, String, ThisBindingStatus, Value,
-        evaluate_async_function_body, evaluate_async_generator_body, evaluate_function_body,
+        ECMAScriptFunctionHeapData, Environment, ExceptionType, ExecutionContext, FrameMetadata,
+        Function, FunctionEnvironment, FunctionInternalProperties, InternalMethods, InternalSlots,
+        JsResult, Number, Object, OrdinaryObject, PrivateEnvironment, PropertyDescriptor,
+        PropertyKey, ProtoIntrinsics, Realm, ScriptOrModule, SourceCode, String, ThisBindingStatus,
+        Value, evaluate_async_function_body, evaluate_async_generator_body, evaluate_function_body,
         evaluate_generator_body, function_handle, get_active_script_or_module,
         new_function_environment, ordinary_create_from_constructor,
         ordinary_object_create_with_intrinsics, to_object,
@@ -598,7 +598,7 @@ impl<'a> FunctionInternalProperties<'a> for ECMAScriptFunction<'a> {
                 "derived class constructor returned invalid value {}",
                 value
                     .unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             let message = String::from_string(agent, message, gc.nogc());
@@ -649,6 +649,10 @@ pub(crate) fn prepare_for_ordinary_call<'a>(
 ) -> &'a ExecutionContext {
     let f = f.bind(gc);
     let new_target = new_target.bind(gc);
+    let frame = FrameMetadata::new(
+        f.get(agent).name,
+        f.get(agent).ecmascript_function.source_text.start,
+    );
     let ecmascript_function_object = &f.get(agent).ecmascript_function;
     let private_environment = ecmascript_function_object.private_environment.bind(gc);
     let is_strict_mode = ecmascript_function_object.strict;
@@ -678,6 +682,7 @@ pub(crate) fn prepare_for_ordinary_call<'a>(
         realm: callee_realm.unbind(),
         // 6. Set the ScriptOrModule of calleeContext to F.[[ScriptOrModule]].
         script_or_module: Some(script_or_module.unbind()),
+        frame,
     };
     // 11. If callerContext is not already suspended, suspend callerContext.
     // 12. Push calleeContext onto the execution context stack; calleeContext is now the running execution context.
@@ -764,10 +769,8 @@ pub(crate) fn evaluate_body<'gc>(
             // 1. Return ? EvaluateAsyncFunctionBody of AsyncFunctionBody with arguments functionObject and argumentsList.
             // AsyncConciseBody : ExpressionBody
             // 1. Return ? EvaluateAsyncConciseBody of AsyncConciseBody with arguments functionObject and argumentsList.
-            Ok(
-                evaluate_async_function_body(agent, function_object.unbind(), arguments_list, gc)
-                    .into(),
-            )
+            evaluate_async_function_body(agent, function_object.unbind(), arguments_list, gc)
+                .map(Into::into)
         }
         (false, false) => {
             // FunctionBody : FunctionStatementList
@@ -911,9 +914,13 @@ pub(crate) fn ordinary_function_create<'gc>(
     // 21. Let len be the ExpectedArgumentCount of ParameterList.
     let len = expected_arguments_count(params.ast.formal_parameters());
     // 22. Perform SetFunctionLength(F, len).
-    set_ecmascript_function_length(agent, &mut function, len, gc).unwrap();
+    function.length = u8::try_from(len).unwrap_or(u8::MAX);
+    let function = agent.heap.create(function);
+    if len > u8::MAX as usize {
+        define_function_length(agent, function.into(), len, gc);
+    }
     // 23. Return F.
-    agent.heap.create(function)
+    function
 }
 
 /// ### [15.1.5 Static Semantics: ExpectedArgumentCount](https://tc39.es/ecma262/#sec-static-semantics-expectedargumentcount)
@@ -969,7 +976,7 @@ pub(crate) fn make_constructor<'a>(
         Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
         | Function::BuiltinPromiseFinallyFunction(_)
-        | Function::BuiltinProxyRevokerFunction => unreachable!(),
+        | Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
     }
     // 5. If prototype is not present, then
     let prototype = prototype.unwrap_or_else(|| {
@@ -1140,7 +1147,8 @@ pub(crate) fn set_function_name<'a>(
             .expect("Should always find PrivateName in scope when calling SetFunctionName"),
     };
 
-    match function.into() {
+    let function = function.into();
+    match function {
         Function::BoundFunction(idx) => {
             let function = idx.get_mut(agent);
             // Note: It's possible that the bound function targeted a function
@@ -1161,31 +1169,59 @@ pub(crate) fn set_function_name<'a>(
         Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
         | Function::BuiltinPromiseFinallyFunction(_)
-        | Function::BuiltinProxyRevokerFunction => unreachable!(),
+        | Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
+    }
+    // Note: If the function already has a backing object, eg. because its
+    // length did not fit inline, the name must be defined there as well.
+    if function.get_backing_object(agent).is_some() {
+        let result = function.try_define_own_property(
+            agent,
+            BUILTIN_STRING_MEMORY.name.into(),
+            PropertyDescriptor {
+                value: Some(name.into()),
+                writable: Some(false),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            None,
+            gc,
+        );
+        debug_assert!(matches!(result, ControlFlow::Continue(true)));
     }
 }
 
 /// ### [10.2.10 SetFunctionLength ( F, length )](https://tc39.es/ecma262/#sec-setfunctionlength)
-fn set_ecmascript_function_length<'a>(
+///
+/// Function objects store their length inline as a `u8`. Lengths that do not
+/// fit are defined as a "length" own property on the function's backing
+/// object instead. A `length` of `usize::MAX` stands for +∞.
+pub(crate) fn define_function_length(
     agent: &mut Agent,
-    function: &mut ECMAScriptFunctionHeapData,
+    function: Function,
     length: usize,
-    gc: NoGcScope<'a, '_>,
-) -> JsResult<'a, ()> {
-    // TODO: 1. Assert: F is an extensible object that does not have a "length" own property.
-
+    gc: NoGcScope,
+) {
+    let length = if length == usize::MAX {
+        Number::pos_inf()
+    } else {
+        Number::from_f64(agent, length as f64, gc)
+    };
     // 2. Perform ! DefinePropertyOrThrow(F, "length", PropertyDescriptor { [[Value]]: 𝔽(length), [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: true }).
-    if length > u8::MAX as usize {
-        return Err(agent.throw_exception_with_static_message(
-            ExceptionType::SyntaxError,
-            "Too many arguments in function call (only 255 allowed)",
-            gc,
-        ));
-    }
-    function.length = length as u8;
-
-    // 3. Return unused.
-    Ok(())
+    let result = function.try_define_own_property(
+        agent,
+        BUILTIN_STRING_MEMORY.length.into(),
+        PropertyDescriptor {
+            value: Some(length.into()),
+            writable: Some(false),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+        None,
+        gc,
+    );
+    debug_assert!(matches!(result, ControlFlow::Continue(true)));
 }
 
 impl HeapMarkAndSweep for ECMAScriptFunction<'static> {
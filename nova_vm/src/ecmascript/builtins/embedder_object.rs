@@ -4,60 +4,169 @@
 
 mod data;
 
+use core::any::Any;
+
 pub(crate) use data::*;
 
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch",
+    feature = "intl"
+))]
+use crate::{
+    ecmascript::{Function, JsResult, ProtoIntrinsics, get_prototype_from_constructor},
+    engine::GcScope,
+};
 use crate::{
     ecmascript::{
-        InternalMethods, InternalSlots, Object, OrdinaryObject, execution::Agent, object_handle,
+        InternalMethods, InternalSlots, Object, OrdinaryObject, Value, execution::Agent,
+        object_handle,
     },
+    engine::{Bindable, NoGcScope},
     heap::{
-        BaseIndex, CompactionLists, HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues,
-        arena_vec_access,
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
     },
 };
 
 /// Embedder objects are intended for embedders to create objects with native
-/// data embedded into them. The type is currently unimplemented but the
-/// intention will be that each embedder object is always provided with a
-/// backing object reference while the embedder provides the data.
+/// data embedded into them.
+///
+/// Each embedder object has a backing object holding its prototype and
+/// properties, a fixed number of internal slots holding JavaScript values,
+/// and native Rust data of an arbitrary `Send` type. The internal slots are
+/// traced by the garbage collector while the native data is not: JavaScript
+/// values referred to by the object must be stored in its internal slots.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct EmbedderObject<'a>(BaseIndex<'a, EmbedderObjectHeapData<'static>>);
 object_handle!(EmbedderObject);
 arena_vec_access!(EmbedderObject, 'a, EmbedderObjectHeapData, embedder_objects);
 
-impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
-    #[inline(always)]
-    fn get_backing_object(self, _agent: &Agent) -> Option<OrdinaryObject<'static>> {
-        todo!();
+impl<'a> EmbedderObject<'a> {
+    /// Create a new embedder object with the given prototype, internal slot
+    /// values, and native data.
+    pub fn new<T: Any + Send>(
+        agent: &mut Agent,
+        prototype: Option<Object>,
+        slots: &[Value],
+        data: T,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let backing_object = OrdinaryObject::create_object(agent, prototype.unbind(), &[])
+            .expect("Should perform GC here")
+            .unbind();
+        agent.heap.alloc_counter += core::mem::size_of::<T>();
+        agent
+            .heap
+            .create(EmbedderObjectHeapData {
+                backing_object: Some(backing_object),
+                slots: slots.iter().map(|value| value.unbind()).collect(),
+                data: Box::new(data),
+            })
+            .bind(gc)
     }
 
-    fn set_backing_object(self, _agent: &mut Agent, _backing_object: OrdinaryObject<'static>) {
-        todo!();
+    /// Returns true if the native data of the embedder object is of type `T`.
+    pub fn is<T: Any>(self, agent: &Agent) -> bool {
+        self.get(agent).data.is::<T>()
     }
 
-    fn create_backing_object(self, _agent: &mut Agent) -> OrdinaryObject<'static> {
-        todo!();
+    /// Get the native data of the embedder object, or None if the data is not
+    /// of type `T`.
+    pub fn get_data<T: Any>(self, agent: &Agent) -> Option<&T> {
+        self.unbind().get(agent).data.downcast_ref()
     }
-    fn internal_extensible(self, _agent: &Agent) -> bool {
-        todo!();
+
+    /// Get the native data of the embedder object as mutable, or None if the
+    /// data is not of type `T`.
+    pub fn get_data_mut<T: Any>(self, agent: &mut Agent) -> Option<&mut T> {
+        self.unbind().get_mut(agent).data.downcast_mut()
     }
 
-    fn internal_set_extensible(self, _agent: &mut Agent, _value: bool) {
-        todo!();
+    /// Get the value of an internal slot of the embedder object.
+    ///
+    /// ## Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn get_slot(self, agent: &Agent, index: usize) -> Value<'a> {
+        self.get(agent).slots[index]
     }
 
-    fn internal_prototype(self, _agent: &Agent) -> Option<Object<'static>> {
-        todo!();
+    /// Set the value of an internal slot of the embedder object.
+    ///
+    /// ## Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn set_slot(self, agent: &mut Agent, index: usize, value: Value) {
+        self.get_mut(agent).slots[index] = value.unbind();
     }
+}
 
-    fn internal_set_prototype(self, _agent: &mut Agent, _prototype: Option<Object>) {
-        todo!();
+/// Create an embedder object whose \[\[Prototype]] is retrieved from a
+/// constructor's "prototype" property, like OrdinaryCreateFromConstructor.
+///
+/// The internal slots of the created object are initialised to undefined.
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch",
+    feature = "intl"
+))]
+pub(crate) fn embedder_object_create_from_constructor<'a, T: Any + Send>(
+    agent: &mut Agent,
+    constructor: Function,
+    intrinsic_default_proto: ProtoIntrinsics,
+    slot_count: usize,
+    data: T,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, EmbedderObject<'a>> {
+    let proto =
+        get_prototype_from_constructor(agent, constructor, intrinsic_default_proto, gc.reborrow())
+            .unbind()?;
+    let gc = gc.into_nogc();
+    let proto = proto.bind(gc).unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .get_intrinsic_default_proto(intrinsic_default_proto)
+    });
+    let slots = vec![Value::Undefined; slot_count];
+    Ok(EmbedderObject::new(agent, Some(proto), &slots, data, gc))
+}
+
+impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).backing_object.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .backing_object
+                .replace(backing_object.unbind())
+                .is_none()
+        );
     }
 }
 
 impl<'a> InternalMethods<'a> for EmbedderObject<'a> {}
 
+impl<'a> CreateHeapData<EmbedderObjectHeapData<'a>, EmbedderObject<'a>> for Heap {
+    fn create(&mut self, data: EmbedderObjectHeapData<'a>) -> EmbedderObject<'a> {
+        self.alloc_counter += core::mem::size_of::<EmbedderObjectHeapData<'static>>()
+            + core::mem::size_of_val(data.slots.as_ref());
+        self.embedder_objects.push(data.unbind());
+        EmbedderObject(BaseIndex::last(&self.embedder_objects))
+    }
+}
+
 impl HeapMarkAndSweep for EmbedderObject<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         queues.embedder_objects.push(*self);
@@ -76,3 +185,112 @@ impl HeapSweepWeakReference for EmbedderObject<'static> {
             .map(Self)
     }
 }
+
+/// Define the handle type `$name` as a wrapper around an [`EmbedderObject`]
+/// whose native data is of type `$data`.
+///
+/// The handle type must be declared as `pub struct $name<'a>(EmbedderObject<'a>);`.
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch",
+    feature = "intl"
+))]
+macro_rules! embedder_object_handle {
+    ($name: ident, $data: ty) => {
+        crate::engine::bindable_handle!($name);
+
+        impl<'a> $name<'a> {
+            /// Returns the value as this type if it is an embedder object
+            /// holding the expected native data, or None otherwise.
+            pub fn try_from_value(
+                agent: &crate::ecmascript::Agent,
+                value: crate::ecmascript::Value<'a>,
+            ) -> Option<Self> {
+                match value {
+                    crate::ecmascript::Value::EmbedderObject(object)
+                        if object.is::<$data>(agent) =>
+                    {
+                        Some(Self(object))
+                    }
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            #[allow(dead_code)]
+            pub(crate) fn data(self, agent: &crate::ecmascript::Agent) -> &$data {
+                self.0.get_data::<$data>(agent).unwrap()
+            }
+
+            #[inline]
+            #[allow(dead_code)]
+            pub(crate) fn data_mut(self, agent: &mut crate::ecmascript::Agent) -> &mut $data {
+                self.0.get_data_mut::<$data>(agent).unwrap()
+            }
+        }
+
+        impl<'a> From<$name<'a>> for crate::ecmascript::EmbedderObject<'a> {
+            #[inline(always)]
+            fn from(value: $name<'a>) -> Self {
+                value.0
+            }
+        }
+
+        impl<'a> From<$name<'a>> for crate::ecmascript::Object<'a> {
+            #[inline(always)]
+            fn from(value: $name<'a>) -> Self {
+                Self::EmbedderObject(value.0)
+            }
+        }
+
+        impl<'a> From<$name<'a>> for crate::ecmascript::Value<'a> {
+            #[inline(always)]
+            fn from(value: $name<'a>) -> Self {
+                Self::EmbedderObject(value.0)
+            }
+        }
+
+        impl<'a> From<$name<'a>> for crate::engine::HeapRootData {
+            #[inline(always)]
+            fn from(value: $name<'a>) -> Self {
+                Self::EmbedderObject(crate::engine::Bindable::unbind(value.0))
+            }
+        }
+
+        impl TryFrom<crate::engine::HeapRootData> for $name<'_> {
+            type Error = ();
+
+            /// Root data is only ever created from values of this type, so
+            /// the native data is not checked.
+            #[inline]
+            fn try_from(value: crate::engine::HeapRootData) -> Result<Self, Self::Error> {
+                match value {
+                    crate::engine::HeapRootData::EmbedderObject(object) => Ok(Self(object)),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        impl crate::heap::HeapMarkAndSweep for $name<'static> {
+            fn mark_values(&self, queues: &mut crate::heap::WorkQueues) {
+                self.0.mark_values(queues);
+            }
+
+            fn sweep_values(&mut self, compactions: &crate::heap::CompactionLists) {
+                self.0.sweep_values(compactions);
+            }
+        }
+    };
+}
+#[cfg(any(
+    feature = "web-streams",
+    feature = "web-abort",
+    feature = "web-events",
+    feature = "web-blob",
+    feature = "web-fetch",
+    feature = "intl"
+))]
+pub(crate) use embedder_object_handle;
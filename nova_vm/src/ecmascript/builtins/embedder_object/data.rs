@@ -2,24 +2,44 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use core::any::Any;
+
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::types::{OrdinaryObject, Value},
+    engine::bindable_handle,
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub(crate) struct EmbedderObjectHeapData<'a> {
-    backing_object: Option<OrdinaryObject<'a>>,
+    pub(super) backing_object: Option<OrdinaryObject<'a>>,
+    /// Internal slots holding JavaScript values. These are traced by the
+    /// garbage collector.
+    pub(super) slots: Box<[Value<'a>]>,
+    /// Native data of the embedder. This is not traced by the garbage
+    /// collector and must not contain unrooted JavaScript values.
+    pub(super) data: Box<dyn Any + Send>,
 }
+bindable_handle!(EmbedderObjectHeapData);
 
 impl HeapMarkAndSweep for EmbedderObjectHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { backing_object } = self;
+        let Self {
+            backing_object,
+            slots,
+            data: _,
+        } = self;
         backing_object.mark_values(queues);
+        slots.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { backing_object } = self;
+        let Self {
+            backing_object,
+            slots,
+            data: _,
+        } = self;
         backing_object.sweep_values(compactions);
+        slots.sweep_values(compactions);
     }
 }
@@ -10,7 +10,8 @@ use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, ExceptionType, InternalMethods, InternalSlots, JsResult,
         Object, OrdinaryObject, PropertyDescriptor, PropertyKey, ProtoIntrinsics, SetResult,
-        String, TryGetResult, TryHasResult, TryResult, Value, object_handle, unwrap_try,
+        StackFrame, String, TryGetResult, TryHasResult, TryResult, Value, object_handle,
+        unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope},
     heap::{
@@ -45,6 +46,15 @@ arena_vec_access!(
     errors
 );
 
+impl<'a> Error<'a> {
+    /// The stack trace captured when the error was created.
+    ///
+    /// See [`Agent::capture_stack_trace`].
+    pub fn stack_trace(self, agent: &Agent) -> &[StackFrame<'a>] {
+        &self.unbind().get(agent).stack
+    }
+}
+
 impl<'a> InternalSlots<'a> for Error<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Error;
 
@@ -381,26 +391,26 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         match self.get_backing_object(agent) {
             Some(backing_object) => {
-                TryResult::Continue(unwrap_try(backing_object.try_own_property_keys(agent, gc)))
+                unwrap_try(backing_object.try_own_property_keys_into(agent, keys, gc))
             }
             None => {
-                let mut property_keys = Vec::with_capacity(2);
                 if self.get_mut(agent).message.is_some() {
-                    property_keys.push(BUILTIN_STRING_MEMORY.message.into());
+                    keys.push(BUILTIN_STRING_MEMORY.message.into());
                 }
                 if self.get_mut(agent).cause.is_some() {
-                    property_keys.push(BUILTIN_STRING_MEMORY.cause.into());
+                    keys.push(BUILTIN_STRING_MEMORY.cause.into());
                 }
-                TryResult::Continue(property_keys)
             }
         }
+        TryResult::Continue(())
     }
 }
 
@@ -3,18 +3,19 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::{OrdinaryObject, String, Value, execution::ExceptionType},
-    engine::bindable_handle,
+    ecmascript::{OrdinaryObject, StackFrame, String, Value, execution::ExceptionType},
+    engine::{Bindable, bindable_handle},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(crate) struct ErrorHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
     pub(crate) kind: ExceptionType,
     pub(crate) message: Option<String<'a>>,
     pub(crate) cause: Option<Value<'a>>,
-    // TODO: stack? name?
+    /// Stack trace captured when the error was created.
+    pub(crate) stack: Box<[StackFrame<'a>]>,
 }
 
 impl<'a> ErrorHeapData<'a> {
@@ -28,8 +29,15 @@ impl<'a> ErrorHeapData<'a> {
             kind,
             message,
             cause,
+            stack: Box::default(),
         }
     }
+
+    /// Set the stack trace of the error.
+    pub(crate) fn with_stack(mut self, stack: Vec<StackFrame>) -> Self {
+        self.stack = stack.into_iter().map(|frame| frame.unbind()).collect();
+        self
+    }
 }
 
 bindable_handle!(ErrorHeapData);
@@ -41,11 +49,13 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
 
         object_index.mark_values(queues);
         message.mark_values(queues);
         cause.mark_values(queues);
+        stack.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -54,9 +64,11 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
         object_index.sweep_values(compactions);
         message.sweep_values(compactions);
         cause.sweep_values(compactions);
+        stack.sweep_values(compactions);
     }
 }
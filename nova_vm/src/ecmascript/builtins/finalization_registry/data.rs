@@ -6,22 +6,34 @@ use ahash::AHashMap;
 use soavec_derive::SoAble;
 
 use crate::{
-    ecmascript::{Function, OrdinaryObject, Realm, Value, WeakKey},
+    ecmascript::{BuiltinProxyRevokerFunction, Function, OrdinaryObject, Realm, Value, WeakKey},
     engine::{Bindable, bindable_handle},
     heap::{
         CompactionLists, HeapIndexHandle, HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues,
     },
 };
 
+/// A _cell_ of a FinalizationRegistry, excluding its \[\[WeakRefTarget]].
+#[derive(Debug, Clone, Copy)]
+struct Cell<'a> {
+    /// \[\[HeldValue]]
+    held_value: Value<'a>,
+    /// \[\[UnregisterToken]]
+    unregister_token: Option<WeakKey<'a>>,
+}
+
 /// \[\[Cells]]
 ///
-/// This maps a _cell_.\[\[WeakRefTarget]] to a _cell_.\[\[HeldValue]].
+/// This maps a _cell_.\[\[WeakRefTarget]] to the rest of each _cell_ with that
+/// target.
 #[derive(Debug, Default)]
 pub(crate) struct Cells<'a> {
-    /// This maps a _cell_.\[\[WeakRefTarget]] to a _cell_.\[\[HeldValue]].
-    cells_weak_ref_target_to_held_value: AHashMap<WeakKey<'a>, Value<'a>>,
-    /// This maps a _cell_.\[\[UnregisterToken]] to a _cell_.\[\[WeakRefTarget]].
-    cells_unregister_token_to_weak_ref_target: AHashMap<WeakKey<'a>, WeakKey<'a>>,
+    /// This maps a _cell_.\[\[WeakRefTarget]] to the \[\[HeldValue]] and
+    /// \[\[UnregisterToken]] of each _cell_ with that target.
+    cells_weak_ref_target_to_cells: AHashMap<WeakKey<'a>, Vec<Cell<'a>>>,
+    /// This maps a _cell_.\[\[UnregisterToken]] to the \[\[WeakRefTarget]] of
+    /// each _cell_ registered with that token.
+    cells_unregister_token_to_weak_ref_targets: AHashMap<WeakKey<'a>, Vec<WeakKey<'a>>>,
 }
 
 impl Cells<'_> {
@@ -31,35 +43,56 @@ impl Cells<'_> {
         held_value: Value,
         unregister_token: Option<WeakKey>,
     ) {
-        self.cells_weak_ref_target_to_held_value
-            .insert(weak_ref_target.unbind(), held_value.unbind());
+        self.cells_weak_ref_target_to_cells
+            .entry(weak_ref_target.unbind())
+            .or_default()
+            .push(Cell {
+                held_value: held_value.unbind(),
+                unregister_token: unregister_token.unbind(),
+            });
         if let Some(unregister_token) = unregister_token {
-            self.cells_unregister_token_to_weak_ref_target
-                .insert(unregister_token.unbind(), weak_ref_target.unbind());
+            self.cells_unregister_token_to_weak_ref_targets
+                .entry(unregister_token.unbind())
+                .or_default()
+                .push(weak_ref_target.unbind());
         }
     }
 
     pub(super) fn unregister(&mut self, unregister_token: WeakKey) -> bool {
+        let unregister_token = unregister_token.unbind();
         // 4. Let removed be false.
+        let mut removed = false;
+        let Some(weak_ref_targets) = self
+            .cells_unregister_token_to_weak_ref_targets
+            .remove(&unregister_token)
+        else {
+            return removed;
+        };
         // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] }
         //    cell of finalizationRegistry.[[Cells]], do
-        // a. If cell.[[UnregisterToken]] is not empty and
-        //    SameValue(cell.[[UnregisterToken]], unregisterToken) is true,
-        //    then
-        if let Some(weak_ref_target) = self
-            .cells_unregister_token_to_weak_ref_target
-            .remove(&unregister_token.unbind())
-        {
+        for weak_ref_target in weak_ref_targets {
+            let Some(cells) = self
+                .cells_weak_ref_target_to_cells
+                .get_mut(&weak_ref_target)
+            else {
+                // The target was registered multiple times with this token,
+                // and all of its cells have already been removed.
+                continue;
+            };
+            let old_len = cells.len();
+            // a. If cell.[[UnregisterToken]] is not empty and
+            //    SameValue(cell.[[UnregisterToken]], unregisterToken) is true,
+            //    then
             // i. Remove cell from finalizationRegistry.[[Cells]].
-            self.cells_weak_ref_target_to_held_value
-                .remove(&weak_ref_target)
-                .unwrap();
+            cells.retain(|cell| cell.unregister_token != Some(unregister_token));
             // ii. Set removed to true.
-            true
-        } else {
-            // 6. Return removed.
-            false
+            removed |= cells.len() != old_len;
+            if cells.is_empty() {
+                self.cells_weak_ref_target_to_cells.remove(&weak_ref_target);
+            }
         }
+        // 6. Return removed.
+        removed
     }
 }
 
@@ -79,7 +112,7 @@ impl Default for CleanupRecord<'_> {
         Self {
             cleanup_queue: Default::default(),
             // Note: impossible value currently.
-            callback: Function::BuiltinProxyRevokerFunction,
+            callback: Function::BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction::_DEF),
             realm: Realm::_DEF,
             cleanup_requested: false,
         }
@@ -102,7 +135,10 @@ impl<'fr> CleanupRecord<'fr> {
     /// FinalizationRegistry must be previously uninitialised.
     pub(super) unsafe fn initialise(&mut self, realm: Realm, cleanup_callback: Function) {
         debug_assert_eq!(self.realm, Realm::_DEF);
-        debug_assert_eq!(self.callback, Function::BuiltinProxyRevokerFunction);
+        debug_assert_eq!(
+            self.callback,
+            Function::BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction::_DEF)
+        );
         self.realm = realm.unbind();
         self.callback = cleanup_callback.unbind();
     }
@@ -139,17 +175,18 @@ impl HeapMarkAndSweep for FinalizationRegistryRecordRef<'_, 'static> {
         let Self {
             cells:
                 Cells {
-                    cells_weak_ref_target_to_held_value,
-                    // Note: cells_unregister_token_to_weak_ref_target holds
-                    // neither key nor value strongly and thus performs no
+                    cells_weak_ref_target_to_cells,
+                    // Note: cells_unregister_token_to_weak_ref_targets holds
+                    // neither keys nor values strongly and thus performs no
                     // marking.
-                    cells_unregister_token_to_weak_ref_target: _,
+                    cells_unregister_token_to_weak_ref_targets: _,
                 },
             cleanup,
             object_index,
         } = self;
-        for value in cells_weak_ref_target_to_held_value.values() {
-            value.mark_values(queues);
+        // Note: only the held values of cells are held strongly.
+        for cell in cells_weak_ref_target_to_cells.values().flatten() {
+            cell.held_value.mark_values(queues);
         }
         cleanup.mark_values(queues);
         object_index.mark_values(queues);
@@ -169,52 +206,71 @@ impl HeapMarkAndSweep for FinalizationRegistryRecordMut<'_, 'static> {
         let Self {
             cells:
                 Cells {
-                    cells_weak_ref_target_to_held_value,
-                    cells_unregister_token_to_weak_ref_target,
+                    cells_weak_ref_target_to_cells,
+                    cells_unregister_token_to_weak_ref_targets,
                 },
             cleanup,
             object_index,
         } = self;
         cleanup.sweep_values(compactions);
         object_index.sweep_values(compactions);
-        if cells_weak_ref_target_to_held_value.is_empty() {
-            cells_unregister_token_to_weak_ref_target.clear();
+        if cells_weak_ref_target_to_cells.is_empty() {
+            cells_unregister_token_to_weak_ref_targets.clear();
             return;
         }
         let old_cells = core::mem::replace(
-            cells_weak_ref_target_to_held_value,
-            AHashMap::with_capacity(cells_weak_ref_target_to_held_value.len()),
+            cells_weak_ref_target_to_cells,
+            AHashMap::with_capacity(cells_weak_ref_target_to_cells.len()),
         );
-        for (weak_ref_target, mut held_value) in old_cells {
-            held_value.sweep_values(compactions);
+        for (weak_ref_target, mut cells) in old_cells {
+            for cell in cells.iter_mut() {
+                cell.held_value.sweep_values(compactions);
+            }
             let new_weak_ref_target = weak_ref_target.sweep_weak_reference(compactions);
             if let Some(new_weak_ref_target) = new_weak_ref_target {
-                // [[WeakRefTarget]] still lives, add it back to
-                // cells_weak_ref_target_to_held_value.
-                cells_weak_ref_target_to_held_value.insert(new_weak_ref_target, held_value);
+                // [[WeakRefTarget]] still lives, add its cells back to
+                // cells_weak_ref_target_to_cells. Unregister tokens that were
+                // collected can no longer be used to unregister the cells.
+                for cell in cells.iter_mut() {
+                    cell.unregister_token = cell
+                        .unregister_token
+                        .and_then(|token| token.sweep_weak_reference(compactions));
+                }
+                cells_weak_ref_target_to_cells.insert(new_weak_ref_target, cells);
             } else {
-                cleanup.cleanup_queue.push(held_value);
+                cleanup
+                    .cleanup_queue
+                    .extend(cells.into_iter().map(|cell| cell.held_value));
             }
         }
-        if cells_weak_ref_target_to_held_value.is_empty()
-            || cells_unregister_token_to_weak_ref_target.is_empty()
+        if cells_weak_ref_target_to_cells.is_empty()
+            || cells_unregister_token_to_weak_ref_targets.is_empty()
         {
-            cells_unregister_token_to_weak_ref_target.clear();
+            cells_unregister_token_to_weak_ref_targets.clear();
             return;
         }
         let old_token_map = core::mem::replace(
-            cells_unregister_token_to_weak_ref_target,
-            AHashMap::with_capacity(cells_unregister_token_to_weak_ref_target.len()),
+            cells_unregister_token_to_weak_ref_targets,
+            AHashMap::with_capacity(cells_unregister_token_to_weak_ref_targets.len()),
         );
-        for (unregister_token, weak_ref_target) in old_token_map {
-            let unregister_token = unregister_token.sweep_weak_reference(compactions);
-            let weak_ref_target = weak_ref_target.sweep_weak_reference(compactions);
-            if let (Some(unregister_token), Some(weak_ref_target)) =
-                (unregister_token, weak_ref_target)
-            {
-                // Both the unregister token and the weak_ref_target still
-                // live, so we must continue tracking them.
-                cells_unregister_token_to_weak_ref_target.insert(unregister_token, weak_ref_target);
+        for (unregister_token, mut weak_ref_targets) in old_token_map {
+            let Some(unregister_token) = unregister_token.sweep_weak_reference(compactions) else {
+                continue;
+            };
+            weak_ref_targets.retain_mut(|weak_ref_target| {
+                if let Some(new_weak_ref_target) = weak_ref_target.sweep_weak_reference(compactions)
+                {
+                    *weak_ref_target = new_weak_ref_target;
+                    true
+                } else {
+                    false
+                }
+            });
+            if !weak_ref_targets.is_empty() {
+                // Both the unregister token and some of its weak_ref_targets
+                // still live, so we must continue tracking them.
+                cells_unregister_token_to_weak_ref_targets
+                    .insert(unregister_token, weak_ref_targets);
             }
         }
     }
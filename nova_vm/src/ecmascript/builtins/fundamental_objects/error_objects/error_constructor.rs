@@ -9,7 +9,7 @@ use crate::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
         BuiltinIntrinsicConstructor, Error, ErrorHeapData, ExceptionType, Function,
         InternalMethods, JsResult, Object, PropertyDescriptor, PropertyKey, ProtoIntrinsics, Realm,
-        String, Value, builders::BuiltinFunctionBuilder, get, has_property,
+        STACK_TRACE_LIMIT, String, Value, builders::BuiltinFunctionBuilder, get, has_property,
         ordinary_populate_from_constructor, to_string, unwrap_try,
     },
     engine::{Bindable, GcScope, Scopable},
@@ -70,11 +70,15 @@ impl ErrorConstructor {
             || agent.running_execution_context().function.unwrap(),
             |new_target| Function::try_from(new_target).unwrap(),
         );
+        // Note: the stack trace starts from the caller of the constructor.
+        let stack = agent
+            .capture_stack(STACK_TRACE_LIMIT + 1, gc.nogc())
+            .split_off(1);
         // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%NativeError.prototype%", « [[ErrorData]] »).
         let o = agent
             .heap
             // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
-            .create(ErrorHeapData::new(error_kind, None, None))
+            .create(ErrorHeapData::new(error_kind, None, None).with_stack(stack))
             .bind(gc.nogc());
         let o = ordinary_populate_from_constructor(
             agent,
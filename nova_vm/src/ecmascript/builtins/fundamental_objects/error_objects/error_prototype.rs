@@ -4,8 +4,9 @@
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType, JsResult,
-        Object, PropertyKey, Realm, String, Value, builders::OrdinaryObjectBuilder, get, to_string,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        BuiltinSetter, Error, ExceptionType, JsResult, Object, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder, get, setter_that_ignores_prototype_properties, to_string,
     },
     engine::{Bindable, GcScope, Scopable},
 };
@@ -22,7 +23,95 @@ impl Builtin for ErrorPrototypeToString {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::to_string);
 }
 
+struct ErrorPrototypeStack;
+
+impl Builtin for ErrorPrototypeStack {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_stack;
+
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.stack.to_property_key());
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::get_stack);
+}
+impl BuiltinGetter for ErrorPrototypeStack {}
+impl BuiltinSetter for ErrorPrototypeStack {
+    const SETTER_NAME: String<'static> = BUILTIN_STRING_MEMORY.set_stack;
+
+    const SETTER_BEHAVIOUR: Behaviour = Behaviour::Regular(ErrorPrototype::set_stack);
+}
+
 impl ErrorPrototype {
+    /// ### [get Error.prototype.stack](https://tc39.es/proposal-error-stacks/#sec-get-error.prototype-stack)
+    fn get_stack<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let E be the this value.
+        // 2. If E is not an Object, throw a TypeError exception.
+        let Ok(e) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. If E does not have an [[ErrorData]] internal slot, return
+        //    undefined.
+        let Object::Error(e) = e else {
+            return Ok(Value::Undefined);
+        };
+        // 4. Return ? GetStackString(E).
+        get_stack_string(agent, e.unbind(), gc).map(|stack| stack.into())
+    }
+
+    /// ### [set Error.prototype.stack](https://tc39.es/proposal-error-stacks/#sec-set-error.prototype-stack)
+    fn set_stack<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let E be the this value.
+        // 2. If E is not an Object, throw a TypeError exception.
+        if !this_value.is_object() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        }
+        // 3. Let numberOfArgs be the number of arguments passed to this
+        //    function call.
+        // 4. If numberOfArgs is 0, throw a TypeError exception.
+        if arguments.is_empty() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Missing value to set",
+                gc.into_nogc(),
+            ));
+        }
+        // 5. Perform ? SetterThatIgnoresPrototypeProperties(E,
+        //    %Error.prototype%, "stack", value).
+        setter_that_ignores_prototype_properties(
+            agent,
+            this_value,
+            agent
+                .current_realm_record()
+                .intrinsics()
+                .error_prototype()
+                .into(),
+            BUILTIN_STRING_MEMORY.stack.into(),
+            arguments.get(0),
+            gc,
+        )?;
+        // 6. Return undefined.
+        Ok(Value::Undefined)
+    }
+
     /// ### [20.5.3.4 Error.prototype.toString ( )](https://tc39.es/ecma262/#sec-error.prototype.tostring)
     fn to_string<'gc>(
         agent: &mut Agent,
@@ -109,7 +198,7 @@ impl ErrorPrototype {
         let error_constructor = intrinsics.error();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(4)
+            .with_property_capacity(5)
             .with_prototype(object_prototype)
             .with_constructor_property(error_constructor)
             .with_property(|builder| {
@@ -127,6 +216,44 @@ impl ErrorPrototype {
                     .build()
             })
             .with_builtin_function_property::<ErrorPrototypeToString>()
+            .with_builtin_function_getter_setter_property::<ErrorPrototypeStack>()
             .build();
     }
 }
+
+/// ### GetStackString ( error )
+///
+/// Format the stack trace captured when the error was created. The first line
+/// is the result of Error.prototype.toString, followed by a line for each
+/// frame, unless the host formats the stack with
+/// [`HostHooks::prepare_stack_trace`].
+///
+/// [`HostHooks::prepare_stack_trace`]: crate::ecmascript::HostHooks::prepare_stack_trace
+fn get_stack_string<'gc>(
+    agent: &mut Agent,
+    error: Error,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, String<'gc>> {
+    let scoped_error = error.scope(agent, gc.nogc());
+    let header = ErrorPrototype::to_string(
+        agent,
+        error.into(),
+        ArgumentsList::from_mut_slice(&mut []),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let header = String::try_from(header.bind(gc)).unwrap();
+    // SAFETY: not shared.
+    let error = unsafe { scoped_error.take(agent) }.bind(gc);
+    let host_hooks = agent.host_hooks;
+    if let Some(stack) = host_hooks.prepare_stack_trace(agent, error, header, gc) {
+        return Ok(stack);
+    }
+    let mut stack = header.to_string_lossy_(agent).into_owned();
+    for frame in error.stack_trace(agent) {
+        stack.push_str("\n    at ");
+        stack.push_str(&frame.describe(agent));
+    }
+    Ok(String::from_string(agent, stack, gc))
+}
@@ -11,8 +11,9 @@ use crate::{
         JsResult, Number, OrdinaryObject, PropertyKey, Realm, SetFunctionNamePrefix, String,
         TryError, TryGetResult, TryResult, Value, bound_function_create,
         builders::BuiltinFunctionBuilder, call_function, create_list_from_array_like,
-        handle_try_get_result, has_own_property, is_callable, ordinary_has_instance,
-        set_function_name, to_integer_or_infinity_number, try_get, try_has_own_property,
+        define_function_length, handle_try_get_result, has_own_property, is_callable,
+        ordinary_has_instance, set_function_name, to_integer_or_infinity_number, try_get,
+        try_has_own_property,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{
@@ -274,6 +275,9 @@ impl FunctionPrototype {
         }
         // 7. Perform SetFunctionLength(F, L).
         f.get_mut(agent).length = u8::try_from(l).unwrap_or(u8::MAX);
+        if l > u8::MAX as usize {
+            define_function_length(agent, f.into(), l, gc.nogc());
+        }
         // 8. Let targetName be ? Get(Target, "name").
         let target_name = try_get(
             agent,
@@ -409,16 +413,15 @@ impl FunctionPrototype {
             // The representation must have the syntax of a NativeFunction.
             Function::BoundFunction(_)
             | Function::BuiltinPromiseResolvingFunction(_)
-            | Function::BuiltinPromiseFinallyFunction(_) => {
-                // Promise resolving and finally functions have no initial name.
+            | Function::BuiltinPromiseFinallyFunction(_)
+            | Function::BuiltinProxyRevokerFunction(_) => {
+                // Promise resolving and finally functions, and Proxy revoker
+                // functions have no initial name.
                 Ok(
                     Value::from_static_str(agent, "function () { [ native code ] }", gc.nogc())
                         .unbind(),
                 )
             }
-            Function::BuiltinProxyRevokerFunction => {
-                unreachable!()
-            }
         }
 
         // NOTE: NativeFunction means the following string:
@@ -399,7 +399,7 @@ impl ObjectConstructor {
             let error_message = format!(
                 "{} is not an object or null",
                 o.unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -438,7 +438,7 @@ impl ObjectConstructor {
         agent: &mut Agent,
         _: Value,
         arguments: ArgumentsList,
-        mut gc: GcScope<'gc, '_>,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         let nogc = gc.nogc();
         let o = arguments.get(0).bind(nogc);
@@ -448,7 +448,7 @@ impl ObjectConstructor {
             let error_message = format!(
                 "{} is not an object",
                 o.unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -481,7 +481,7 @@ impl ObjectConstructor {
             let error_message = format!(
                 "{} is not an object",
                 o.unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -1098,7 +1098,7 @@ impl ObjectConstructor {
                 "{} is not an object or null",
                 proto
                     .unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -1339,7 +1339,7 @@ pub(crate) fn add_entries_from_iterable_from_entries<'a>(
             let error_message = format!(
                 "Invalid iterator next return value: {} is not an object",
                 next.unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             let error = agent.throw_exception(ExceptionType::TypeError, error_message, gc.nogc());
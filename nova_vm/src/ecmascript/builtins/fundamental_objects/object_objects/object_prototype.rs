@@ -74,6 +74,9 @@ impl Builtin for ObjectPrototypeValueOf {
 
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ObjectPrototype::value_of);
 }
+impl BuiltinIntrinsic for ObjectPrototypeValueOf {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::ObjectPrototypeValueOf;
+}
 
 impl ObjectPrototype {
     fn has_own_property<'gc>(
@@ -193,8 +196,6 @@ impl ObjectPrototype {
             Value::Proxy(proxy) if proxy.is_callable(agent, gc.nogc()) => {
                 BUILTIN_STRING_MEMORY._object_Function_
             }
-            // TODO: Check for [[Call]] slot of EmbedderObject
-            Value::EmbedderObject(_) => todo!(),
             // 13. Else if O has a [[RegExpMatcher]] internal slot, let builtinTag be "RegExp".
             // 17. Return the string-concatenation of "[object ", tag, and "]".
             #[cfg(feature = "regexp")]
@@ -315,7 +316,7 @@ impl ObjectPrototype {
             .with_builtin_function_property::<ObjectPrototypePropertyIsEnumerable>()
             .with_builtin_function_property::<ObjectPrototypeToLocaleString>()
             .with_builtin_intrinsic_function_property::<ObjectPrototypeToString>()
-            .with_builtin_function_property::<ObjectPrototypeValueOf>()
+            .with_builtin_intrinsic_function_property::<ObjectPrototypeValueOf>()
             .build();
     }
 }
@@ -12,7 +12,7 @@ use wtf8::{CodePoint, Wtf8Buf};
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, Contains, ContainsSymbol, ECMAScriptCodeEvaluationState,
-        Environment, ExceptionType, ExecutionContext, Function, JsResult,
+        Environment, ExceptionType, ExecutionContext, FrameMetadata, Function, JsResult,
         LexicallyScopedDeclaration, ParseResult, Primitive, PrivateEnvironment, Realm,
         STRING_DISCRIMINANT, SourceCode, SourceCodeType, String, Value, VarScopedDeclaration,
         builders::BuiltinFunctionBuilder, get_this_environment, instantiate_function_object,
@@ -392,6 +392,7 @@ pub(crate) fn perform_eval<'gc>(
         // 25. Set evalContext's LexicalEnvironment to lexEnv.
         // 26. Set evalContext's PrivateEnvironment to privateEnv.
         ecmascript_code: Some(ecmascript_code),
+        frame: FrameMetadata::NONE,
     };
     // 27. Push evalContext onto the execution context stack; evalContext is now the running execution context.
     agent.push_execution_context(eval_context);
@@ -10,12 +10,12 @@ use crate::ecmascript::builtins::{AnyTypedArray, make_typed_array_with_buffer_wi
 use crate::{
     ecmascript::{
         ARRAY_INDEX_RANGE, Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
-        CollectionIteratorKind, ExceptionType, InternalSlots, JsResult, Object, Realm, String,
-        Value, builders::OrdinaryObjectBuilder, create_array_from_list, create_iter_result_object,
-        get, length_of_array_like,
+        BuiltinIntrinsic, CollectionIteratorKind, ExceptionType, InternalSlots, JsResult, Object,
+        Realm, String, Value, builders::OrdinaryObjectBuilder, create_array_from_list,
+        create_iter_result_object, get, length_of_array_like,
     },
     engine::{Bindable, GcScope, Scopable},
-    heap::{ArenaAccess, ArenaAccessMut, WellKnownSymbols},
+    heap::{ArenaAccess, ArenaAccessMut, IntrinsicFunctionIndexes, WellKnownSymbols},
 };
 
 pub(crate) struct ArrayIteratorPrototype;
@@ -28,6 +28,9 @@ impl Builtin for ArrayIteratorPrototypeNext {
 
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayIteratorPrototype::next);
 }
+impl BuiltinIntrinsic for ArrayIteratorPrototypeNext {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::ArrayIteratorPrototypeNext;
+}
 
 impl ArrayIteratorPrototype {
     fn next<'gc>(
@@ -188,7 +191,7 @@ impl ArrayIteratorPrototype {
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
             .with_property_capacity(2)
             .with_prototype(iterator_prototype)
-            .with_builtin_function_property::<ArrayIteratorPrototypeNext>()
+            .with_builtin_intrinsic_function_property::<ArrayIteratorPrototypeNext>()
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbols::ToStringTag.into())
@@ -4,21 +4,19 @@
 
 use core::cmp::Ordering;
 
-use wtf8::Wtf8Buf;
-
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, ArrayIterator, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        Agent, ArgumentsList, Array, ArrayIterator, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
         BuiltinIntrinsic, CollectionIteratorKind, ExceptionType, Function, InternalMethods,
-        JsError, JsResult, Number, Object, PropertyKey, Realm, SmallInteger, String, Value,
-        array_create, array_species_create, builders::OrdinaryObjectBuilder, call_function,
+        JsError, JsResult, Number, Object, PropertyKey, Realm, SmallInteger, String, StringBuilder,
+        Value, array_create, array_species_create, builders::OrdinaryObjectBuilder, call_function,
         create_data_property_or_throw, delete_property_or_throw, get, has_property, invoke,
         is_array, is_callable, is_strictly_equal, length_of_array_like, same_value_zero, set,
         to_boolean, to_integer_or_infinity, to_number, to_object, to_string,
         try_create_data_property_or_throw, try_length_of_array_like, try_result_into_js,
         try_to_integer_or_infinity, try_to_string, unwrap_try,
     },
-    engine::{Bindable, GcScope, Rootable, Scopable, ScopableCollection, Scoped},
+    engine::{Bindable, GcScope, NoGcScope, Rootable, Scopable, ScopableCollection, Scoped},
     heap::{ArenaAccessSoAMut, Heap, HeapIndexHandle, IntrinsicFunctionIndexes, WellKnownSymbols},
 };
 
@@ -418,6 +416,7 @@ impl ArrayPrototype {
                 let mut k = 0;
                 // iv. Repeat, while k < len,
                 while k < len {
+                    agent.check_termination(gc.nogc()).unbind()?;
                     // 1. Let Pk be ! ToString(𝔽(k)).
                     let pk = PropertyKey::Integer(k.try_into().unwrap());
                     // 2. Let exists be ? HasProperty(E, Pk).
@@ -533,9 +532,9 @@ impl ArrayPrototype {
         ) = (this_value, target, start, end)
         {
             // Fast path: Array with integer parameters, array is trivial
-            // (no descriptors). Holes can exist, we'll just copy them
-            // equivalently.
-            if array.is_trivial(agent) {
+            // (no descriptors) and dense (no holes). Holes would need to be
+            // looked up from the prototype chain.
+            if array.is_trivial(agent) && array.is_dense(agent) {
                 let len = array.len(agent) as i64;
 
                 let relative_target = target.into_i64();
@@ -658,6 +657,7 @@ impl ArrayPrototype {
         let mut to = to;
         // 18. Repeat, while count > 0,
         while count > 0 {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let fromKey be ! ToString(𝔽(from)).
             let from_key = PropertyKey::Integer(from.try_into().unwrap());
             // b. Let toKey be ! ToString(𝔽(to)).
@@ -784,6 +784,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 5. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -939,6 +940,7 @@ impl ArrayPrototype {
 
         // 11. Repeat, while k < final,
         while k < final_end {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Perform ? Set(O, Pk, value, true).
@@ -1030,6 +1032,7 @@ impl ArrayPrototype {
         let mut scoped_k_value: Scoped<Value> = Value::Undefined.scope_static(gc.nogc());
         // 7. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1225,22 +1228,32 @@ impl ArrayPrototype {
         let source_len =
             length_of_array_like(agent, o.get(agent), gc.reborrow()).unbind()? as usize;
         // 3. Let depthNum be 1.
-        let mut depth_num = 1;
+        let mut depth_num = Some(1);
         // 4. If depth is not undefined, then
         if !depth.get(agent).is_undefined() {
             // a. Set depthNum to ? ToIntegerOrInfinity(depth).
-            depth_num = to_integer_or_infinity(agent, depth.get(agent), gc.reborrow())
-                .unbind()?
-                .into_i64();
-        }
-        // b. If depthNum < 0, set depthNum to 0.
-        if depth_num < 0 {
-            depth_num = 0;
+            let depth = to_integer_or_infinity(agent, depth.get(agent), gc.reborrow()).unbind()?;
+            depth_num = if depth.is_pos_infinity() {
+                // Note: None represents an infinite depth.
+                None
+            } else {
+                // b. If depthNum < 0, set depthNum to 0.
+                Some(depth.into_i64().max(0) as usize)
+            };
         }
         // 5. Let A be ? ArraySpeciesCreate(O, 0).
         let a = array_species_create(agent, o.get(agent), 0, gc.reborrow())
             .unbind()?
             .scope(agent, gc.nogc());
+        if depth_num == Some(1)
+            && let (Object::Array(target), Object::Array(source)) = (a.get(agent), o.get(agent))
+            && try_flatten_dense_array_into_array(agent, target, source, source_len, gc.nogc())
+                .unbind()?
+        {
+            // Fast path: O and its Array elements were dense and were
+            // flattened into an empty A without calling into JavaScript.
+            return Ok(a.get(agent).into());
+        }
         // 6. Perform ? FlattenIntoArray(A, O, sourceLen, 0, depthNum).
         flatten_into_array(
             agent,
@@ -1248,7 +1261,7 @@ impl ArrayPrototype {
             o,
             source_len,
             0,
-            Some(depth_num as usize),
+            depth_num,
             None,
             None,
             gc.reborrow(),
@@ -1377,6 +1390,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 5. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1472,22 +1486,14 @@ impl ArrayPrototype {
                 0
             };
             let data = &array.as_slice(agent)[k..];
-            let mut found_hole = false;
-            for element_k in data {
-                if let Some(element_k) = element_k {
-                    if same_value_zero(agent, search_element, *element_k) {
-                        return Ok(true.into());
-                    }
-                } else {
-                    // A hole would require looking through the prototype
-                    // chain. We're not going to do that.
-                    found_hole = true;
-                    break;
-                }
-            }
-            if !found_hole {
+            let matcher = ElementMatcher::new(search_element, true);
+            match matcher.position(agent, data, false) {
+                Some(index) if data[index].is_some() => return Ok(true.into()),
+                // A hole would require looking through the prototype
+                // chain. We're not going to do that.
+                Some(_) => {}
                 // No holes found so we can trust the result.
-                return Ok(false.into());
+                None => return Ok(false.into()),
             }
         };
         let from_index_is_undefined = from_index.is_undefined();
@@ -1532,6 +1538,7 @@ impl ArrayPrototype {
         };
         // 10. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let elementK be ? Get(O, ! ToString(𝔽(k))).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             let element_k = get(agent, o.get(agent), pk, gc.reborrow())
@@ -1604,22 +1611,16 @@ impl ArrayPrototype {
                 0
             };
             let data = &array.as_slice(agent)[k..];
-            let mut found_hole = false;
-            for (index, element_k) in data.iter().enumerate() {
-                if let Some(element_k) = element_k {
-                    if is_strictly_equal(agent, search_element, *element_k) {
-                        return Ok((k as u32 + index as u32).into());
-                    }
-                } else {
-                    // A hole would require looking through the prototype
-                    // chain. We're not going to do that.
-                    found_hole = true;
-                    break;
+            let matcher = ElementMatcher::new(search_element, false);
+            match matcher.position(agent, data, false) {
+                Some(index) if data[index].is_some() => {
+                    return Ok((k as u32 + index as u32).into());
                 }
-            }
-            if !found_hole {
+                // A hole would require looking through the prototype
+                // chain. We're not going to do that.
+                Some(_) => {}
                 // No holes found so we can trust the result.
-                return Ok((-1).into());
+                None => return Ok((-1).into()),
             }
         };
         let from_index_is_undefined = from_index.is_undefined();
@@ -1664,6 +1665,7 @@ impl ArrayPrototype {
         };
         // 10. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1724,7 +1726,7 @@ impl ArrayPrototype {
             unsafe { separator.replace_self(agent, sep.unbind()) }
         };
         // 5. Let R be the empty String.
-        let mut r = Wtf8Buf::with_capacity(len * 10);
+        let mut r = StringBuilder::with_capacity(len * 10);
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         // b. Let element be ? Get(O, ! ToString(𝔽(k))).
@@ -1739,12 +1741,13 @@ impl ArrayPrototype {
                     .unbind()?
                     .bind(gc.nogc());
                 // ii. Set R to the string-concatenation of R and S.
-                r.push_wtf8(s.as_wtf8_(agent));
+                r.push_string(agent, s);
             }
         }
         for k in 1..len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. If k > 0, set R to the string-concatenation of R and sep.
-            r.push_wtf8(separator.get(agent).as_wtf8_(agent));
+            r.push_string(agent, separator.get(agent));
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
             let element = get(
                 agent,
@@ -1761,12 +1764,12 @@ impl ArrayPrototype {
                     .unbind()?
                     .bind(gc.nogc());
                 // ii. Set R to the string-concatenation of R and S.
-                r.push_wtf8(s.as_wtf8_(agent));
+                r.push_string(agent, s);
             }
             // d. Set k to k + 1.
         }
         // 8. Return R.
-        Ok(String::from_wtf8_buf(agent, r, gc.into_nogc()).into())
+        Ok(r.finish(agent, gc.into_nogc()).into())
     }
 
     /// ### [23.1.3.19 Array.prototype.keys ( )](https://tc39.es/ecma262/#sec-array.prototype.keys)
@@ -1837,7 +1840,10 @@ impl ArrayPrototype {
                     (n as usize).min(last)
                 } else {
                     let result = len as i64 + n;
-                    if result < 0 { 0 } else { result as usize }
+                    if result < 0 {
+                        return Ok((-1).into());
+                    }
+                    result as usize
                 }
             } else if from_index == Some(Value::Undefined) {
                 0
@@ -1845,22 +1851,14 @@ impl ArrayPrototype {
                 last
             };
             let data = &array.as_slice(agent)[..=k];
-            let mut found_hole = false;
-            for (index, element_k) in data.iter().enumerate().rev() {
-                if let Some(element_k) = element_k {
-                    if is_strictly_equal(agent, search_element, *element_k) {
-                        return Ok((index as u32).into());
-                    }
-                } else {
-                    // A hole would require looking through the prototype
-                    // chain. We're not going to do that.
-                    found_hole = true;
-                    break;
-                }
-            }
-            if !found_hole {
+            let matcher = ElementMatcher::new(search_element, false);
+            match matcher.position(agent, data, true) {
+                Some(index) if data[index].is_some() => return Ok((index as u32).into()),
+                // A hole would require looking through the prototype
+                // chain. We're not going to do that.
+                Some(_) => {}
                 // No holes found so we can trust the result.
-                return Ok((-1).into());
+                None => return Ok((-1).into()),
             }
         };
         let from_index = from_index.map(|i| i.scope(agent, nogc));
@@ -1898,6 +1896,7 @@ impl ArrayPrototype {
 
         // 8. Repeat, while k ≥ 0,
         while k >= 0 {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1988,6 +1987,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 6. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -2299,6 +2299,7 @@ impl ArrayPrototype {
 
             // b. Repeat, while kPresent is false and k < len,
             while !k_present && k < len {
+                agent.check_termination(gc.nogc()).unbind()?;
                 // i. Let Pk be ! ToString(𝔽(k)).
                 let pk = PropertyKey::Integer(k.try_into().unwrap());
 
@@ -2331,6 +2332,7 @@ impl ArrayPrototype {
 
         // 9. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             let k_int = k.try_into().unwrap();
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k_int);
@@ -2466,6 +2468,7 @@ impl ArrayPrototype {
 
             // b. Repeat, while kPresent is false and k ≥ 0,
             while !k_present && k >= 0 {
+                agent.check_termination(gc.nogc()).unbind()?;
                 // i. Let Pk be ! ToString(𝔽(k)).
                 let pk = PropertyKey::try_from(k).unwrap();
 
@@ -2498,6 +2501,7 @@ impl ArrayPrototype {
 
         // 9. Repeat, while k ≥ 0,
         while k >= 0 {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::try_from(k).unwrap();
 
@@ -2574,6 +2578,7 @@ impl ArrayPrototype {
         let mut lower: i64 = 0;
         // 5. Repeat, while lower ≠ middle,
         while lower != middle {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let upper be len - lower - 1.
             let upper = len - lower - 1;
             // b. Let upperP be ! ToString(𝔽(upper)).
@@ -2766,6 +2771,7 @@ impl ArrayPrototype {
         let mut k = 1;
         // 6. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let from be ! ToString(𝔽(k)).
             let from = k.try_into().unwrap();
             // b. Let to be ! ToString(𝔽(k - 1)).
@@ -2845,152 +2851,80 @@ impl ArrayPrototype {
         let this_value = this_value.bind(nogc);
         let start = arguments.get(0).bind(nogc);
         let end = arguments.get(1).bind(nogc);
-        if let (
+        let (o, mut k, final_end) = if let (
             Value::Array(array),
             Value::Undefined | Value::Integer(_),
             Value::Undefined | Value::Integer(_),
         ) = (this_value, start, end)
+            && array.is_trivial(agent)
+            && array.is_dense(agent)
         {
+            // Fast path: Array with integer parameters. Computing the range
+            // cannot call into JavaScript.
             let len = array.len(agent) as usize;
-            if array.is_trivial(agent) && array.is_dense(agent) {
-                let start = if let Value::Integer(relative_start) = start {
-                    let relative_start = relative_start.into_i64();
-                    if relative_start < 0 {
-                        (len as i64 + relative_start).max(0) as usize
-                    } else {
-                        relative_start as usize
-                    }
+            let k = if let Value::Integer(relative_start) = start {
+                let relative_start = relative_start.into_i64();
+                if relative_start < 0 {
+                    (len as i64 + relative_start).max(0) as usize
                 } else {
-                    0
-                };
-                let end = if let Value::Integer(relative_end) = end {
-                    let relative_end = relative_end.into_i64();
-                    if relative_end < 0 {
-                        (len as i64 + relative_end).max(0) as usize
-                    } else {
-                        (relative_end as usize).min(len)
-                    }
-                } else {
-                    len
-                };
-                let array = array.scope(agent, nogc);
-                let count = end.saturating_sub(start);
-                let a = array_species_create(agent, array.get(agent).into(), count, gc.reborrow())
-                    .unbind()?
-                    .scope(agent, gc.nogc());
-                if count == 0 {
-                    set(
-                        agent,
-                        a.get(agent),
-                        BUILTIN_STRING_MEMORY.length.into(),
-                        0.into(),
-                        true,
-                        gc.reborrow(),
-                    )
-                    .unbind()?;
-                    return Ok(a.get(agent).into());
+                    (relative_start as usize).min(len)
                 }
-                if let Object::Array(a) = a.get(agent)
-                    && a.len(agent) as usize == count
-                    && a.is_trivial(agent)
-                    && a.as_slice(agent).iter().all(|el| el.is_none())
-                {
-                    // Array full of holes
-                    let source_data = array.get(agent).as_slice(agent)[start..end].as_ptr();
-                    let destination_data = a.as_mut_slice(agent).as_mut_ptr();
-                    // SAFETY: Source and destination are properly aligned
-                    // and valid for reads/writes. They do not overlap.
-                    // From JS point of view, setting data properties to
-                    // the destination would not call any JS code so this
-                    // is spec-wise correct.
-                    unsafe { core::ptr::copy_nonoverlapping(source_data, destination_data, count) };
-                    set(
-                        agent,
-                        a.into(),
-                        BUILTIN_STRING_MEMORY.length.into(),
-                        Number::try_from(count).unwrap().into(),
-                        true,
-                        gc.reborrow(),
-                    )
-                    .unbind()?;
-                    return Ok(a.into());
-                }
-                let mut k = start;
-                let mut n = 0u32;
-                while k < end {
-                    // a. Let Pk be ! ToString(𝔽(k)).
-                    // b. Let kPresent be ? HasProperty(O, Pk).
-                    // Note: Array is dense, we do not need to check this.
-                    // c. If kPresent is true, then
-                    // i. Let kValue be ? Get(O, Pk).
-                    let k_value = array.get(agent).as_slice(agent)[k].unwrap();
-                    // ii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), kValue).
-                    create_data_property_or_throw(
-                        agent,
-                        a.get(agent),
-                        n.into(),
-                        k_value,
-                        gc.reborrow(),
-                    )
-                    .unbind()?;
-                    // d. Set k to k + 1.
-                    k += 1;
-                    // e. Set n to n + 1.
-                    n += 1;
+            } else {
+                0
+            };
+            let final_end = if let Value::Integer(relative_end) = end {
+                let relative_end = relative_end.into_i64();
+                if relative_end < 0 {
+                    (len as i64 + relative_end).max(0) as usize
+                } else {
+                    (relative_end as usize).min(len)
                 }
-                // 15. Perform ? Set(A, "length", 𝔽(n), true).
-                set(
-                    agent,
-                    a.get(agent),
-                    BUILTIN_STRING_MEMORY.length.into(),
-                    n.into(),
-                    true,
-                    gc.reborrow(),
-                )
-                .unbind()?;
-                // 16. Return A.
-                return Ok(a.get(agent).into());
-            }
-        }
-        let start = start.scope(agent, nogc);
-        let end = end.scope(agent, nogc);
-        // 1. Let O be ? ToObject(this value).
-        let o = to_object(agent, this_value, nogc)
-            .unbind()?
-            .scope(agent, nogc);
-        // 2. Let len be ? LengthOfArrayLike(O).
-        let len = length_of_array_like(agent, o.get(agent), gc.reborrow()).unbind()? as usize;
-        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
-        let relative_start =
-            to_integer_or_infinity(agent, start.get(agent), gc.reborrow()).unbind()?;
-        // 4. If relativeStart = -∞, let k be 0.
-        let mut k = if relative_start.is_neg_infinity() {
-            0
-        } else if relative_start.is_negative() {
-            // 5. Else if relativeStart < 0, let k be max(len + relativeStart, 0).
-            (len as i64 + relative_start.into_i64()).max(0) as usize
-        } else {
-            // 6. Else, let k be min(relativeStart, len).
-            (relative_start.into_i64() as usize).min(len)
-        };
-
-        // 7. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
-        let end = end.get(agent).bind(gc.nogc());
-        let final_end = if end.is_undefined() {
-            len
+            } else {
+                len
+            };
+            (Object::Array(array).scope(agent, nogc), k, final_end)
         } else {
-            let relative_end =
-                to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
-            // 8. If relativeEnd = -∞, let final be 0.
-            if relative_end.is_neg_infinity() {
+            let start = start.scope(agent, nogc);
+            let end = end.scope(agent, nogc);
+            // 1. Let O be ? ToObject(this value).
+            let o = to_object(agent, this_value, nogc)
+                .unbind()?
+                .scope(agent, nogc);
+            // 2. Let len be ? LengthOfArrayLike(O).
+            let len = length_of_array_like(agent, o.get(agent), gc.reborrow()).unbind()? as usize;
+            // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+            let relative_start =
+                to_integer_or_infinity(agent, start.get(agent), gc.reborrow()).unbind()?;
+            // 4. If relativeStart = -∞, let k be 0.
+            let k = if relative_start.is_neg_infinity() {
                 0
-            } else if relative_end.is_negative() {
-                // 9. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
-                (len as i64 + relative_end.into_i64()).max(0) as usize
+            } else if relative_start.is_negative() {
+                // 5. Else if relativeStart < 0, let k be max(len + relativeStart, 0).
+                (len as i64 + relative_start.into_i64()).max(0) as usize
             } else {
-                // 10. Else, let final be min(relativeEnd, len).
-                (relative_end.into_i64() as usize).min(len)
-            }
+                // 6. Else, let k be min(relativeStart, len).
+                (relative_start.into_i64() as usize).min(len)
+            };
+
+            // 7. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+            let end = end.get(agent).bind(gc.nogc());
+            let final_end = if end.is_undefined() {
+                len
+            } else {
+                let relative_end =
+                    to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+                // 8. If relativeEnd = -∞, let final be 0.
+                if relative_end.is_neg_infinity() {
+                    0
+                } else if relative_end.is_negative() {
+                    // 9. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+                    (len as i64 + relative_end.into_i64()).max(0) as usize
+                } else {
+                    // 10. Else, let final be min(relativeEnd, len).
+                    (relative_end.into_i64() as usize).min(len)
+                }
+            };
+            (o, k, final_end)
         };
         // 11. Let count be max(final - k, 0).
         let count = final_end.saturating_sub(k);
@@ -2998,20 +2932,65 @@ impl ArrayPrototype {
         let a = array_species_create(agent, o.get(agent), count, gc.reborrow())
             .unbind()?
             .scope(agent, gc.nogc());
+        // Note: ArraySpeciesCreate may have called into JavaScript, so the
+        // source Array has to be checked again before reading its elements
+        // directly.
         // 13. Let n be 0.
         let mut n = 0u32;
+        if count > 0
+            && let Object::Array(array) = o.get(agent)
+            && array.is_trivial(agent)
+            && array.is_dense(agent)
+            && array.len(agent) as usize >= final_end
+            && let Object::Array(a) = a.get(agent)
+            && a.len(agent) as usize == count
+            && a.is_trivial(agent)
+            && a.as_slice(agent).iter().all(|el| el.is_none())
+        {
+            // Array full of holes
+            let source_data = array.as_slice(agent)[k..final_end].as_ptr();
+            let destination_data = a.as_mut_slice(agent).as_mut_ptr();
+            // SAFETY: Source and destination are properly aligned and valid
+            // for reads/writes. They do not overlap. From JS point of view,
+            // setting data properties to the destination would not call any
+            // JS code so this is spec-wise correct.
+            unsafe { core::ptr::copy_nonoverlapping(source_data, destination_data, count) };
+            k = final_end;
+            n = count as u32;
+        }
         // 14. Repeat, while k < final,
         while k < final_end {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            let pk = k.try_into().unwrap();
-            // b. Let kPresent be ? HasProperty(O, Pk).
-            let k_present = has_property(agent, o.get(agent), pk, gc.reborrow()).unbind()?;
-            // c. If kPresent is true, then
-            if k_present {
-                // i. Let kValue be ? Get(O, Pk).
-                let k_value = get(agent, o.get(agent), pk, gc.reborrow())
-                    .unbind()?
-                    .bind(gc.nogc());
+            agent.check_termination(gc.nogc()).unbind()?;
+            let k_value = match o.get(agent) {
+                Object::Array(array)
+                    if array.is_trivial(agent)
+                        && array.is_dense(agent)
+                        && k < array.len(agent) as usize =>
+                {
+                    // Note: The Array is dense and has no descriptors, so
+                    // the element is present and Get cannot call into
+                    // JavaScript.
+                    array.as_slice(agent)[k]
+                }
+                o => {
+                    // a. Let Pk be ! ToString(𝔽(k)).
+                    let pk = k.try_into().unwrap();
+                    // b. Let kPresent be ? HasProperty(O, Pk).
+                    let k_present = has_property(agent, o.unbind(), pk, gc.reborrow()).unbind()?;
+                    // c. If kPresent is true, then
+                    if k_present {
+                        // i. Let kValue be ? Get(O, Pk).
+                        Some(
+                            get(agent, o.unbind(), pk, gc.reborrow())
+                                .unbind()?
+                                .bind(gc.nogc()),
+                        )
+                    } else {
+                        None
+                    }
+                }
+            };
+            if let Some(k_value) = k_value {
                 // ii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), kValue).
                 create_data_property_or_throw(
                     agent,
@@ -3106,6 +3085,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 5. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = k.try_into().unwrap();
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -3204,6 +3184,7 @@ impl ArrayPrototype {
         // 7. Let j be 0.
         // 8. Repeat, while j < itemCount,
         for (j, value) in sorted_list.iter(agent).enumerate() {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
             set(
                 agent,
@@ -3222,6 +3203,7 @@ impl ArrayPrototype {
 
         // 10. Repeat, while j < len,
         for j in item_count..len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
             delete_property_or_throw(agent, obj.get(agent), j.try_into().unwrap(), gc.reborrow())
                 .unbind()?;
@@ -3315,6 +3297,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 14. Repeat, while k < actualDeleteCount,
         while k < actual_delete_count {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let from be ! ToString(𝔽(actualStart + k)).
             let from = (actual_start + k).try_into().unwrap();
             // b. If ? HasProperty(O, from) is true, then
@@ -3353,6 +3336,7 @@ impl ArrayPrototype {
                 k = actual_start;
                 // b. Repeat, while k < (len - actualDeleteCount),
                 while k < (len as usize - actual_delete_count) {
+                    agent.check_termination(gc.nogc()).unbind()?;
                     // i. Let from be ! ToString(𝔽(k + actualDeleteCount)).
                     let from = (k + actual_delete_count).try_into().unwrap();
                     // ii. Let to be ! ToString(𝔽(k + itemCount)).
@@ -3386,6 +3370,7 @@ impl ArrayPrototype {
                 k = len as usize;
                 // d. Repeat, while k > (len - actualDeleteCount + itemCount),
                 while k > (len as usize - actual_delete_count + item_count) {
+                    agent.check_termination(gc.nogc()).unbind()?;
                     // i. Perform ? DeletePropertyOrThrow(O, ! ToString(𝔽(k - 1))).
                     delete_property_or_throw(
                         agent,
@@ -3404,6 +3389,7 @@ impl ArrayPrototype {
                 k = len as usize - actual_delete_count;
                 // b. Repeat, while k > actualStart,
                 while k > actual_start {
+                    agent.check_termination(gc.nogc()).unbind()?;
                     // i. Let from be ! ToString(𝔽(k + actualDeleteCount - 1)).
                     let from = (k + actual_delete_count - 1).try_into().unwrap();
                     // ii. Let to be ! ToString(𝔽(k + itemCount - 1)).
@@ -3508,11 +3494,12 @@ impl ArrayPrototype {
         // 3. Let separator be the implementation-defined list-separator String value appropriate for the host environment's current locale (such as ", ").
         let separator = ", ";
         // 4. Let R be the empty String.
-        let mut r = Wtf8Buf::new();
+        let mut r = StringBuilder::new();
         // 5. Let k be 0.
         let mut k = 0;
         // 6. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. If k > 0, set R to the string-concatenation of R and separator.
             if k > 0 {
                 r.push_str(separator);
@@ -3542,13 +3529,13 @@ impl ArrayPrototype {
                     .unbind()?
                     .bind(gc.nogc());
                 //  ii. Set R to the string-concatenation of R and S.
-                r.push_wtf8(s.as_wtf8_(agent));
+                r.push_string(agent, s);
             };
             // d. Set k to k + 1.
             k += 1;
         }
         // 7. Return R.
-        Ok(String::from_wtf8_buf(agent, r, gc.into_nogc()).into())
+        Ok(r.finish(agent, gc.into_nogc()).into())
     }
 
     /// ### [23.1.3.33 Array.prototype.toReversed ( )](https://tc39.es/ecma262/#sec-array.prototype.toreversed)
@@ -3585,6 +3572,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 5. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             //    a. Let from be ! ToString(𝔽(len - k - 1)).
             let from = PropertyKey::Integer((len - k - 1).try_into().unwrap());
             //    b. Let Pk be ! ToString(𝔽(k)).
@@ -3746,6 +3734,7 @@ impl ArrayPrototype {
         let mut r = actual_start + actual_skip_count;
         // 16. Repeat, while i < actualStart,
         while i < actual_start {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pi be ! ToString(𝔽(i)).
             let pi = i.try_into().unwrap();
             // b. Let iValue be ? Get(O, Pi).
@@ -3784,6 +3773,7 @@ impl ArrayPrototype {
         }
         // 18. Repeat, while i < newLen,
         while i < new_len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pi be ! ToString(𝔽(i)).
             let pi = i.try_into().unwrap();
             // b. Let from be ! ToString(𝔽(r)).
@@ -3916,6 +3906,7 @@ impl ArrayPrototype {
             let mut k = len;
             // c. Repeat, while k > 0,
             while k > 0 {
+                agent.check_termination(gc.nogc()).unbind()?;
                 // i. Let from be ! ToString(𝔽(k - 1)).
                 let from = (k - 1).try_into().unwrap();
                 // ii. Let to be ! ToString(𝔽(k + argCount - 1)).
@@ -4069,6 +4060,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 9. Repeat, while k < len,
         while k < len {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::try_from(k).unwrap();
             // b. If k = actualIndex, let fromValue be value.
@@ -4312,6 +4304,7 @@ pub(crate) fn find_via_predicate<'gc, T: 'static + Rootable + InternalMethods<'s
         k: u64,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Option<(u64, Value<'gc>)>> {
+        agent.check_termination(gc.nogc()).unbind()?;
         // a. Let Pk be ! ToString(𝔽(k)).
         let pk = PropertyKey::Integer(k.try_into().unwrap());
         // b. NOTE: If O is a TypedArray, the following invocation of Get will
@@ -4392,6 +4385,80 @@ pub(crate) fn find_via_predicate<'gc, T: 'static + Rootable + InternalMethods<'s
     Ok((-1, Value::Undefined))
 }
 
+/// Strategy for scanning an Array's elements for a search element in the
+/// `includes`, `indexOf`, and `lastIndexOf` fast paths.
+///
+/// Numbers, strings, and BigInts have a canonical representation in Nova: a
+/// value that fits on the stack is never stored on the heap. Most search
+/// elements can thus be compared against elements by their representation
+/// alone, which keeps the scanning loop free of heap accesses.
+enum ElementMatcher<'a> {
+    /// Search element only equals elements with the same representation.
+    Identical(Value<'a>),
+    /// Search element is +0 or -0, and equals both.
+    Zero,
+    /// Search element is NaN and the comparison is IsStrictlyEqual; no
+    /// element can match.
+    Nothing,
+    /// Search element is heap-allocated and must be compared by its data.
+    Heap(Value<'a>),
+}
+
+impl<'a> ElementMatcher<'a> {
+    /// Create a matcher using SameValueZero if `same_value_zero` is true, and
+    /// IsStrictlyEqual otherwise.
+    fn new(search_element: Value<'a>, same_value_zero: bool) -> Self {
+        match search_element {
+            Value::Integer(i) if i == SmallInteger::zero() => Self::Zero,
+            Value::SmallF64(f) if f.into_f64() == 0.0 => Self::Zero,
+            // Note: NaN is always stored as a canonical SmallF64.
+            Value::SmallF64(f) if f.into_f64().is_nan() && !same_value_zero => Self::Nothing,
+            Value::String(_) | Value::Number(_) | Value::BigInt(_) => Self::Heap(search_element),
+            _ => Self::Identical(search_element),
+        }
+    }
+
+    /// Find the index of the first element, or the last if `reverse` is true,
+    /// that either matches the search element or is a hole.
+    fn position(
+        &self,
+        agent: &Agent,
+        elements: &[Option<Value<'a>>],
+        reverse: bool,
+    ) -> Option<usize> {
+        fn find<'a>(
+            elements: &[Option<Value<'a>>],
+            reverse: bool,
+            predicate: impl FnMut(&Option<Value<'a>>) -> bool,
+        ) -> Option<usize> {
+            if reverse {
+                elements.iter().rposition(predicate)
+            } else {
+                elements.iter().position(predicate)
+            }
+        }
+        match *self {
+            Self::Identical(search_element) => {
+                let search_element = Some(search_element);
+                // Note: non-short-circuiting to keep the loop branch-free.
+                find(elements, reverse, |e| e.is_none() | (*e == search_element))
+            }
+            Self::Zero => find(elements, reverse, |e| match e {
+                None => true,
+                Some(Value::Integer(i)) => *i == SmallInteger::zero(),
+                Some(Value::SmallF64(f)) => f.into_f64() == 0.0,
+                _ => false,
+            }),
+            Self::Nothing => find(elements, reverse, Option::is_none),
+            // Note: heap-allocated Numbers are never NaN or zero, so
+            // IsStrictlyEqual and SameValueZero are equivalent here.
+            Self::Heap(search_element) => find(elements, reverse, |e| {
+                e.is_none_or(|e| is_strictly_equal(agent, search_element, e))
+            }),
+        }
+    }
+}
+
 /// ### [23.1.3.13.1 FlattenIntoArray ( target, source, sourceLen, start, depth \[ , mapperFunction \[ , thisArg \] \] )](https://tc39.es/ecma262/#sec-flattenintoarray)
 /// The abstract operation FlattenIntoArray takes arguments target (an Object),
 /// source (an Object), sourceLen (a non-negative integer), start (a
@@ -4418,6 +4485,7 @@ fn flatten_into_array<'a>(
     // 3. Let sourceIndex be +0𝔽.
     // 4. Repeat, while ℝ(sourceIndex) < sourceLen,
     for source_index in 0..source_len {
+        agent.check_termination(gc.nogc()).unbind()?;
         // a. Let P be ! ToString(sourceIndex).
         let source_index_number = Number::try_from(source_index).unwrap();
         let p = PropertyKey::try_from(source_index).unwrap();
@@ -4507,6 +4575,72 @@ fn flatten_into_array<'a>(
     Ok(target_index)
 }
 
+/// Fast path for FlattenIntoArray with a depth of 1 and no mapperFunction.
+///
+/// If the source Array and all of its Array elements are dense and contain no
+/// property descriptors, and the target is an empty Array with a writable
+/// length, then flattening is unobservable and the result length can be
+/// computed ahead of time to allocate the target's storage only once.
+///
+/// Returns false without modifying the target if the fast path cannot be
+/// taken.
+fn try_flatten_dense_array_into_array<'a>(
+    agent: &mut Agent,
+    target: Array,
+    source: Array,
+    source_len: usize,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, bool> {
+    let target = target.bind(gc);
+    let source = source.bind(gc);
+    if !target.is_empty(agent) || !target.is_trivial(agent) || !target.length_writable(agent) {
+        return Ok(false);
+    }
+    // Note: if the source has shrunk since sourceLen was read, the missing
+    // indexes would need to be looked up from the prototype chain.
+    if (source.len(agent) as usize) < source_len
+        || !source.is_trivial(agent)
+        || !source.is_dense(agent)
+    {
+        return Ok(false);
+    }
+    let mut target_len = 0usize;
+    for element in &source.as_slice(agent)[..source_len] {
+        target_len += match element.unwrap() {
+            Value::Array(element) => {
+                if !element.is_trivial(agent) || !element.is_dense(agent) {
+                    return Ok(false);
+                }
+                element.len(agent) as usize
+            }
+            // IsArray sees through Proxies; leave those to the generic path.
+            Value::Proxy(_) => return Ok(false),
+            _ => 1,
+        };
+    }
+    let Ok(target_len) = u32::try_from(target_len) else {
+        return Ok(false);
+    };
+    let result = target.reserve(agent, target_len).and_then(|_| {
+        for source_index in 0..source_len {
+            match source.as_slice(agent)[source_index].unwrap() {
+                Value::Array(element) => {
+                    for element_index in 0..element.len(agent) as usize {
+                        let value = element.as_slice(agent)[element_index].unwrap();
+                        target.push(agent, value)?;
+                    }
+                }
+                value => target.push(agent, value)?,
+            }
+        }
+        Ok(())
+    });
+    if let Err(err) = result {
+        return Err(agent.throw_allocation_exception(err, gc));
+    }
+    Ok(true)
+}
+
 /// ### [23.1.3.30.1 SortIndexedProperties ( obj, len, SortCompare, holes )](https://tc39.es/ecma262/#sec-sortindexedproperties)
 ///
 /// The abstract operation SortIndexedProperties takes arguments obj (an
@@ -4574,6 +4708,7 @@ fn sort_indexed_properties<'gc, 'scope, const SKIP_HOLES: bool>(
     let mut k = 0;
     // 3. Repeat, while k < len,
     while k < len {
+        agent.check_termination(gc.nogc()).unbind()?;
         // a. Let Pk be ! ToString(𝔽(k)).
         let pk: PropertyKey<'static> = k.try_into().unwrap();
         // b. If holes is skip-holes, then
@@ -11,47 +11,18 @@ use crate::ecmascript::{GenericSharedTypedArray, SharedTypedArrayRecord};
 use crate::{
     ecmascript::{
         Agent, AnyArrayBuffer, AnyTypedArray, ArgumentsList, ArrayBuffer, ArrayBufferHeapData,
-        DataBlock, ExceptionType, Function, GenericTypedArray, InternalSlots, JsResult, Number,
-        Numeric, Object, PropertyKey, SmallInteger, TryError, TryResult, TypedArray,
-        TypedArrayRecord, Value, Viewable, VoidArray, construct, create_byte_data_block, get,
-        get_prototype_from_constructor, get_value_from_buffer, is_fixed_length_array_buffer,
-        js_result_into_try, length_of_array_like, require_internal_slot_typed_array, set,
-        set_value_in_buffer, species_constructor, to_index, try_result_into_js,
-        try_species_constructor, try_to_index,
+        CachedBufferByteLength, DataBlock, ExceptionType, Function, GenericTypedArray,
+        InternalSlots, JsResult, Number, Numeric, Object, PropertyKey, SmallInteger, TryError,
+        TryResult, TypedArray, TypedArrayRecord, Value, Viewable, VoidArray, construct,
+        create_byte_data_block, get, get_prototype_from_constructor, get_value_from_buffer,
+        is_fixed_length_array_buffer, js_result_into_try, length_of_array_like,
+        require_internal_slot_typed_array, set, set_value_in_buffer, species_constructor, to_index,
+        try_result_into_js, try_species_constructor, try_to_index,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, ScopedCollection, bindable_handle},
     heap::CreateHeapData,
 };
 
-#[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct CachedBufferByteLength(pub(crate) usize);
-
-impl CachedBufferByteLength {
-    pub(crate) const fn value(value: usize) -> Self {
-        assert!(
-            value != usize::MAX,
-            "byte length cannot be usize::MAX as it is reserved for detached buffers"
-        );
-        Self(value)
-    }
-
-    /// A sentinel value of `usize::MAX` means that the buffer is detached.
-    pub(crate) const fn detached() -> Self {
-        Self(usize::MAX)
-    }
-
-    pub(crate) fn is_detached(self) -> bool {
-        self == Self::detached()
-    }
-}
-
-impl From<CachedBufferByteLength> for Option<usize> {
-    fn from(val: CachedBufferByteLength) -> Self {
-        if val.is_detached() { None } else { Some(val.0) }
-    }
-}
-
 #[derive(Debug)]
 pub(crate) struct TypedArrayWithBufferWitnessRecords<'a> {
     pub(crate) object: AnyTypedArray<'a>,
@@ -92,17 +63,12 @@ pub(crate) fn make_typed_array_with_buffer_witness_record<'a>(
     order: Ordering,
 ) -> TypedArrayWithBufferWitnessRecords<'a> {
     // 1. Let buffer be obj.[[ViewedArrayBuffer]].
-    let buffer = obj.viewed_array_buffer(agent);
-
     // 2. If IsDetachedBuffer(buffer) is true, then
-    let byte_length = if buffer.is_detached(agent) {
-        // a. Let byteLength be detached.
-        CachedBufferByteLength::detached()
-    } else {
-        // 3. Else,
-        // a. Let byteLength be ArrayBufferByteLength(buffer, order).
-        CachedBufferByteLength::value(buffer.byte_length(agent, order))
-    };
+    // a. Let byteLength be detached.
+    // 3. Else,
+    // a. Let byteLength be ArrayBufferByteLength(buffer, order).
+    let byte_length =
+        CachedBufferByteLength::from_buffer(agent, obj.viewed_array_buffer(agent), order);
 
     // 4. Return the TypedArray With Buffer Witness Record { [[Object]]: obj, [[CachedBufferByteLength]]: byteLength }.
     TypedArrayWithBufferWitnessRecords {
@@ -261,11 +227,8 @@ pub(crate) trait TypedArrayAbstractOperations<'ta>: Copy + Sized {
 
         // 7. Let byteLength be taRecord.[[CachedBufferByteLength]].
         // 8. Assert: byteLength is not detached.
-        debug_assert!(!cached_buffer_byte_length.is_detached());
-        let byte_length = cached_buffer_byte_length.0;
-
         // 9. Return floor((byteLength - byteOffset) / elementSize).
-        (byte_length - byte_offset) / element_size
+        cached_buffer_byte_length.length_tracking_byte_length(byte_offset) / element_size
     }
 
     /// ### [10.4.5.13 IsTypedArrayOutOfBounds ( taRecord )](https://tc39.es/ecma262/#sec-istypedarrayoutofbounds)
@@ -285,34 +248,23 @@ pub(crate) trait TypedArrayAbstractOperations<'ta>: Copy + Sized {
             cached_buffer_byte_length.is_detached()
         );
 
-        // 4. If bufferByteLength is detached, return true.
-        let Some(buffer_byte_length) = cached_buffer_byte_length.into() else {
-            return true;
-        };
-
         // 5. Let byteOffsetStart be O.[[ByteOffset]].
         let byte_offset_start = self.byte_offset(agent);
 
         // 6. If O.[[ArrayLength]] is auto, then
-        let byte_offset_end = if let Some(array_length) = self.array_length(agent) {
-            // 7. Else,
-            // a. Let elementSize be TypedArrayElementSize(O).
-            let element_size = self.typed_array_element_size();
-            // b. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
-            byte_offset_start + array_length * element_size
-        } else {
-            // a. Let byteOffsetEnd be bufferByteLength.
-            buffer_byte_length
-        };
+        // a. Let byteOffsetEnd be bufferByteLength.
+        // 7. Else,
+        // a. Let elementSize be TypedArrayElementSize(O).
+        // b. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+        let byte_length = self
+            .array_length(agent)
+            .map(|array_length| array_length * self.typed_array_element_size());
 
+        // 4. If bufferByteLength is detached, return true.
         // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
-        if byte_offset_start > buffer_byte_length || byte_offset_end > buffer_byte_length {
-            return true;
-        }
-
         // 9. NOTE: 0-length TypedArrays are not considered out-of-bounds.
         // 10. Return false.
-        false
+        cached_buffer_byte_length.is_view_out_of_bounds(byte_offset_start, byte_length)
     }
 
     /// ### [10.4.5.15 IsTypedArrayFixedLength ( O )](https://tc39.es/ecma262/#sec-istypedarrayfixedlength)
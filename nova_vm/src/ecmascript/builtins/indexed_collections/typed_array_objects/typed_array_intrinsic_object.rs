@@ -166,7 +166,7 @@ impl TypedArrayIntrinsicObject {
                 //  i. Let Pk be ! ToString(𝔽(k)).
                 // ii. Let kValue be the first element of values.
                 // iii. Remove the first element from values.
-                let sk = SmallInteger::from(k as u32);
+                let sk = SmallInteger::try_from(k as u64).unwrap();
                 let fk = Number::from(sk).into();
                 let pk = PropertyKey::from(sk);
                 //  iv. If mapping is true, then
@@ -227,7 +227,7 @@ impl TypedArrayIntrinsicObject {
         let mut k = 0;
         // 12. Repeat, while k < len,
         while k < len {
-            let sk = SmallInteger::from(k as u32);
+            let sk = SmallInteger::try_from(k as u64).unwrap();
             // 𝔽(k)
             let fk = Number::from(sk).into();
             // a. Let Pk be ! ToString(𝔽(k)).
@@ -787,8 +787,15 @@ impl TypedArrayPrototype {
         // 17. If count > 0, then
         if count > 0 {
             // g. Set count to min(count, len - startIndex, len - targetIndex).
-            let count = count.min(new_len - start_index).min(new_len - target_index);
-            o.copy_within(agent, start_index, target_index, count)
+            // Note: the TypedArray may have shrunk while the arguments were
+            // converted, in which case startIndex or targetIndex may be past
+            // the end of it.
+            let count = count
+                .min(new_len.saturating_sub(start_index))
+                .min(new_len.saturating_sub(target_index));
+            if count > 0 {
+                o.copy_within(agent, start_index, target_index, count)
+            }
         }
         Ok(o.into())
     }
@@ -841,14 +848,18 @@ impl TypedArrayPrototype {
         // SAFETY: not shared.
         let o = unsafe { o.take(agent) }.bind(gc);
 
+        // 17. If count > 0, then
+        // c. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
         let ta_record = make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst);
+        // d. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
         if ta_record.is_typed_array_out_of_bounds(agent) {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
-                "Callback is not callable",
+                "TypedArray out of bounds",
                 gc,
             ));
         }
+        // e. Set len to TypedArrayLength(taRecord).
         let len = ta_record.typed_array_length(agent);
         Ok((ta_record.object, len, target_index, start_index, end_index))
     }
@@ -907,7 +918,7 @@ impl TypedArrayPrototype {
         // 6. Repeat, while k < len,
         while k < len {
             // a. Let Pk be ! ToString(𝔽(k)).
-            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            let pk = PropertyKey::from(SmallInteger::try_from(k as u64).unwrap());
             // b. Let kValue be ! Get(O, Pk).
             let k_value = unwrap_try_get_value_or_unset(try_get(agent, o, pk, None, gc.nogc()));
             // c. Let testResult be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
@@ -2272,7 +2283,9 @@ impl TypedArrayPrototype {
                 // d. Set countBytes to max(endIndex - startIndex, 0).
                 count = end_index.saturating_sub(start_index);
             }
-            o.set_into_data_block(agent, &mut data_block, start_index, count);
+            if count > 0 {
+                o.set_into_data_block(agent, &mut data_block, start_index, count);
+            }
             for_any_typed_array!(
                 o,
                 o,
@@ -2317,7 +2330,10 @@ impl TypedArrayPrototype {
                 // d. Set countBytes to max(endIndex - startIndex, 0).
                 count = end_index.saturating_sub(start_index);
             }
-            a.slice(agent, o, start_index, count);
+            // Note: if the TypedArray shrunk, startIndex may be past its end.
+            if count > 0 {
+                a.slice(agent, o, start_index, count);
+            }
             a
         };
         // 15. Return A.
@@ -2399,7 +2415,7 @@ impl TypedArrayPrototype {
         // 6. Repeat, while k < len,
         while k < len {
             // a. Let Pk be ! ToString(𝔽(k)).
-            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            let pk = PropertyKey::from(SmallInteger::try_from(k as u64).unwrap());
             // b. Let kValue be ! Get(O, Pk).
             let k_value = unwrap_try_get_value_or_unset(try_get(agent, o, pk, None, gc.nogc()));
             // c. Let testResult be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [ECMAScript Internationalization API](https://tc39.es/ecma402/)
+//!
+//! The Intl object with Intl.NumberFormat and Intl.DateTimeFormat, backed by
+//! [ICU4X](https://github.com/unicode-org/icu4x) and its compiled locale
+//! data.
+
+mod date_time_format;
+mod locales;
+mod number_format;
+mod options;
+
+pub(crate) use date_time_format::*;
+pub(crate) use locales::*;
+pub(crate) use number_format::*;
+pub(crate) use options::*;
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, JsResult, Realm, String,
+        Value, builders::OrdinaryObjectBuilder, create_array_from_list,
+    },
+    engine::{Bindable, GcScope},
+    heap::WellKnownSymbols,
+};
+
+pub(crate) struct IntlObject;
+
+struct IntlObjectGetCanonicalLocales;
+impl Builtin for IntlObjectGetCanonicalLocales {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getCanonicalLocales;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IntlObject::get_canonical_locales);
+}
+
+impl IntlObject {
+    /// ### [8.3.1 Intl.getCanonicalLocales ( locales )](https://tc39.es/ecma402/#sec-intl.getcanonicallocales)
+    fn get_canonical_locales<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        // 1. Let ll be ? CanonicalizeLocaleList(locales).
+        let ll = canonicalize_locale_list(agent, locales.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 2. Return CreateArrayFromList(ll).
+        let ll = ll
+            .iter()
+            .map(|locale| String::from_string(agent, locale.to_string(), gc).into())
+            .collect::<Vec<Value>>();
+        Ok(create_array_from_list(agent, &ll, gc).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.intl();
+
+        let date_time_format_constructor = intrinsics.intl_date_time_format();
+        let number_format_constructor = intrinsics.intl_number_format();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            // 8.2.1 Intl.Collator ( . . . )
+            // 8.2.2 Intl.DateTimeFormat ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.DateTimeFormat.into())
+                    .with_value(date_time_format_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            // 8.2.3 Intl.DisplayNames ( . . . )
+            // 8.2.4 Intl.DurationFormat ( . . . )
+            // 8.2.5 Intl.ListFormat ( . . . )
+            // 8.2.6 Intl.Locale ( . . . )
+            // 8.2.7 Intl.NumberFormat ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.NumberFormat.into())
+                    .with_value(number_format_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            // 8.2.8 Intl.PluralRules ( . . . )
+            // 8.2.9 Intl.RelativeTimeFormat ( . . . )
+            // 8.2.10 Intl.Segmenter ( . . . )
+            // 8.3.1 Intl.getCanonicalLocales ( locales )
+            .with_builtin_function_property::<IntlObjectGetCanonicalLocales>()
+            // 8.3.2 Intl.supportedValuesOf ( key )
+            // 8.1.1 Intl [ %Symbol.toStringTag% ]
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
@@ -0,0 +1,804 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod date_time_format_constructor;
+mod date_time_format_prototype;
+
+pub(crate) use date_time_format_constructor::*;
+pub(crate) use date_time_format_prototype::*;
+
+use icu_calendar::{Date, DateTime, Time};
+use icu_datetime::{
+    DateTimeFormatter,
+    options::{DateTimeFormatterOptions, components, length, preferences},
+};
+use icu_locid::{
+    Locale,
+    extensions::unicode::{Value as UnicodeValue, key},
+};
+
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, DateValue, EmbedderObject, ExceptionType, JsResult,
+        LocaleMatcher, Object, StringOption, Value, canonicalize_locale_list,
+        coerce_options_to_object, date_from_time, embedder_object_handle, get, get_boolean_option,
+        get_number_option, get_string_option, hour_from_time, is_available_locale, min_from_time,
+        month_from_time, ms_from_time, resolve_locale, sec_from_time, string_option,
+        system_time_zone_identifier, to_string, validate_unicode_type_option, year_from_time,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+};
+
+/// ### [11 DateTimeFormat Objects](https://tc39.es/ecma402/#datetimeformat-objects)
+///
+/// An Intl.DateTimeFormat object formats dates and times with the
+/// conventions of a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct DateTimeFormat<'a>(EmbedderObject<'a>);
+embedder_object_handle!(DateTimeFormat, DateTimeFormatRecord);
+
+string_option!(HourCycle {
+    H11 => "h11",
+    H12 => "h12",
+    H23 => "h23",
+    H24 => "h24",
+});
+
+string_option!(TextWidth {
+    Narrow => "narrow",
+    Short => "short",
+    Long => "long",
+});
+
+string_option!(NumericWidth {
+    Numeric => "numeric",
+    TwoDigit => "2-digit",
+});
+
+string_option!(MonthWidth {
+    Numeric => "numeric",
+    TwoDigit => "2-digit",
+    Narrow => "narrow",
+    Short => "short",
+    Long => "long",
+});
+
+string_option!(TimeZoneName {
+    Short => "short",
+    Long => "long",
+    ShortOffset => "shortOffset",
+    LongOffset => "longOffset",
+    ShortGeneric => "shortGeneric",
+    LongGeneric => "longGeneric",
+});
+
+string_option!(FormatMatcher {
+    Basic => "basic",
+    BestFit => "best fit",
+});
+
+string_option!(DateTimeStyle {
+    Full => "full",
+    Long => "long",
+    Medium => "medium",
+    Short => "short",
+});
+
+/// The required components of [`create_date_time_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeRequired {
+    Date,
+    Time,
+    Any,
+}
+
+/// The default components of [`create_date_time_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeDefaults {
+    Date,
+    Time,
+    All,
+}
+
+/// ### [Table 16: Components of date and time formats](https://tc39.es/ecma402/#table-datetimeformat-components)
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct DateTimeComponents {
+    pub(super) weekday: Option<TextWidth>,
+    pub(super) era: Option<TextWidth>,
+    pub(super) year: Option<NumericWidth>,
+    pub(super) month: Option<MonthWidth>,
+    pub(super) day: Option<NumericWidth>,
+    pub(super) hour: Option<NumericWidth>,
+    pub(super) minute: Option<NumericWidth>,
+    pub(super) second: Option<NumericWidth>,
+    pub(super) fractional_second_digits: Option<u8>,
+}
+
+trivially_bindable!(DateTimeComponents);
+
+/// The time zone of an Intl.DateTimeFormat.
+///
+/// NOTE: Only UTC and offset time zones are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZone {
+    Utc,
+    /// A UTC offset in minutes.
+    Offset(i16),
+}
+
+trivially_bindable!(TimeZone);
+
+impl TimeZone {
+    /// ### [11.1.3 GetAvailableNamedTimeZoneIdentifier ( timeZoneIdentifier )](https://tc39.es/ecma402/#sec-getavailablenamedtimezoneidentifier)
+    /// ### [21.4.1.33.1 IsTimeZoneOffsetString ( offsetString )](https://tc39.es/ecma262/#sec-istimezoneoffsetstring)
+    ///
+    /// Parses a time zone identifier: "UTC" and its aliases or a UTC offset
+    /// of the form ±HH, ±HHMM, or ±HH:MM.
+    fn parse(identifier: &str) -> Option<Self> {
+        if ["UTC", "Etc/UTC", "Etc/GMT", "GMT"]
+            .iter()
+            .any(|utc| utc.eq_ignore_ascii_case(identifier))
+        {
+            return Some(Self::Utc);
+        }
+        let (sign, offset) = match identifier.as_bytes().first()? {
+            b'+' => (1, &identifier[1..]),
+            b'-' => (-1, &identifier[1..]),
+            _ => return None,
+        };
+        let (hours, minutes) = match offset.len() {
+            2 => (offset, "00"),
+            4 => offset.split_at(2),
+            5 if offset.as_bytes()[2] == b':' => (&offset[..2], &offset[3..]),
+            _ => return None,
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let hours: i16 = hours.parse().ok()?;
+        let minutes: i16 = minutes.parse().ok()?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        Some(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+
+    /// Returns the canonical identifier of the time zone.
+    pub(crate) fn identifier(self) -> std::string::String {
+        match self {
+            Self::Utc => "UTC".to_owned(),
+            Self::Offset(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                format!("{sign}{:02}:{:02}", offset / 60, offset % 60)
+            }
+        }
+    }
+
+    fn offset_milliseconds(self) -> f64 {
+        match self {
+            Self::Utc => 0.0,
+            Self::Offset(offset) => offset as f64 * 60_000.0,
+        }
+    }
+}
+
+/// The resolved options of an Intl.DateTimeFormat.
+pub(crate) struct DateTimeFormatRecord {
+    pub(super) locale: Locale,
+    formatter: DateTimeFormatter,
+    pub(super) time_zone: TimeZone,
+    pub(super) hour_cycle: Option<HourCycle>,
+    pub(super) components: DateTimeComponents,
+    pub(super) date_style: Option<DateTimeStyle>,
+    pub(super) time_style: Option<DateTimeStyle>,
+}
+
+trivially_bindable!(DateTimeFormatRecord);
+
+impl core::fmt::Debug for DateTimeFormatRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DateTimeFormatRecord")
+            .field("locale", &self.locale)
+            .field("time_zone", &self.time_zone)
+            .field("hour_cycle", &self.hour_cycle)
+            .field("components", &self.components)
+            .field("date_style", &self.date_style)
+            .field("time_style", &self.time_style)
+            .finish()
+    }
+}
+
+impl<'a> DateTimeFormat<'a> {
+    /// \[\[BoundFormat]]
+    const BOUND_FORMAT: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(crate) fn bound_format(self, agent: &Agent) -> Option<Object<'a>> {
+        Object::try_from(self.0.get_slot(agent, Self::BOUND_FORMAT)).ok()
+    }
+
+    pub(crate) fn set_bound_format(self, agent: &mut Agent, bound_format: Object) {
+        self.0
+            .set_slot(agent, Self::BOUND_FORMAT, bound_format.into());
+    }
+}
+
+/// ### [11.1.2 CreateDateTimeFormat ( newTarget, locales, options, required, defaults )](https://tc39.es/ecma402/#sec-createdatetimeformat)
+///
+/// Processes the locales and options of an Intl.DateTimeFormat into its
+/// resolved options.
+///
+/// NOTE: Only the Gregorian calendar and the "latn" numbering system are
+/// supported. The "dayPeriod" and "timeZoneName" options are not supported.
+pub(crate) fn create_date_time_format<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    options: Value,
+    required: DateTimeRequired,
+    defaults: DateTimeDefaults,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DateTimeFormatRecord> {
+    let options = options.scope(agent, gc.nogc());
+    // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+    let requested_locales = canonicalize_locale_list(agent, locales, gc.reborrow()).unbind()?;
+    // 3. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 4. Let opt be a new Record.
+    // 5. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    // 6. Set opt.[[localeMatcher]] to matcher.
+    get_string_option::<LocaleMatcher>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.localeMatcher,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 7. Let calendar be ? GetOption(options, "calendar", string, empty,
+    //    undefined).
+    // 8. If calendar is not undefined, then
+    //    a. If calendar cannot be matched by the type Unicode locale
+    //       nonterminal, throw a RangeError exception.
+    validate_unicode_type_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.calendar,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 10. Let numberingSystem be ? GetOption(options, "numberingSystem",
+    //     string, empty, undefined).
+    // 11. If numberingSystem is not undefined, then
+    //     a. If numberingSystem cannot be matched by the type Unicode locale
+    //        nonterminal, throw a RangeError exception.
+    validate_unicode_type_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.numberingSystem,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty,
+    //     undefined).
+    let hour12 = get_boolean_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hour12,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 14. Let hourCycle be ? GetOption(options, "hourCycle", string,
+    //     « "h11", "h12", "h23", "h24" », undefined).
+    let mut hour_cycle = get_string_option::<HourCycle>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hourCycle,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 15. If hour12 is not undefined, then
+    if hour12.is_some() {
+        // a. Set hourCycle to null.
+        hour_cycle = None;
+    }
+    // 17. Let r be ResolveLocale(%Intl.DateTimeFormat%.[[AvailableLocales]],
+    //     requestedLocales, opt, %Intl.DateTimeFormat%.[[RelevantExtensionKeys]],
+    //     localeData).
+    // 18. Set dateTimeFormat.[[Locale]] to r.[[Locale]].
+    let mut locale = resolve_locale(&requested_locales, is_available_locale, &[key!("hc")]);
+    if hour12.is_some() || hour_cycle.is_some() {
+        locale.extensions.unicode.keywords.remove(key!("hc"));
+    }
+    // 29. Let timeZone be ? Get(options, "timeZone").
+    let time_zone = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeZone.to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 30. If timeZone is undefined, then
+    let time_zone = if time_zone.is_undefined() {
+        // a. Set timeZone to SystemTimeZoneIdentifier().
+        // NOTE: Named time zones other than UTC are not supported and fall
+        // back to UTC.
+        TimeZone::parse(system_time_zone_identifier(agent)).unwrap_or(TimeZone::Utc)
+    } else {
+        // 31. Else,
+        //     a. Set timeZone to ? ToString(timeZone).
+        let time_zone = to_string(agent, time_zone.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 32. If IsTimeZoneOffsetString(timeZone) is true, then
+        //     ...
+        // 33. Else,
+        //     a. Let timeZoneIdentifierRecord be
+        //        GetAvailableNamedTimeZoneIdentifier(timeZone).
+        //     b. If timeZoneIdentifierRecord is empty, throw a RangeError
+        //        exception.
+        match time_zone.as_str(agent).and_then(TimeZone::parse) {
+            Some(time_zone) => time_zone,
+            None => {
+                let message = format!(
+                    "Invalid time zone specified: {}",
+                    time_zone.to_string_lossy(agent)
+                );
+                return Err(agent.throw_exception(
+                    ExceptionType::RangeError,
+                    message,
+                    gc.into_nogc(),
+                ));
+            }
+        }
+    };
+    // 36. Let formatOptions be a new Record.
+    // 37. Set formatOptions.[[hourCycle]] to hc.
+    // 38. Let hasExplicitFormatComponents be false.
+    // 39. For each row of Table 16, except the header row, in table order, do
+    //     a. Let prop be the name given in the Property column of the
+    //        current row.
+    //     b. If prop is "fractionalSecondDigits", then
+    //        i. Let value be ? GetNumberOption(options,
+    //           "fractionalSecondDigits", 1, 3, undefined).
+    //     c. Else,
+    //        i. Let values be a List whose elements are the strings given in
+    //           the Values column of the current row.
+    //        ii. Let value be ? GetOption(options, prop, string, values,
+    //            undefined).
+    //     d. Set formatOptions.[[<prop>]] to value.
+    //     e. If value is not undefined, then
+    //        i. Set hasExplicitFormatComponents to true.
+    let mut components =
+        get_date_time_components(agent, options.get(agent), gc.reborrow()).unbind()?;
+    let has_explicit_format_components = components.has_any();
+    // 40. Let formatMatcher be ? GetOption(options, "formatMatcher", string,
+    //     « "basic", "best fit" », "best fit").
+    get_string_option::<FormatMatcher>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.formatMatcher,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 41. Let dateStyle be ? GetOption(options, "dateStyle", string,
+    //     « "full", "long", "medium", "short" », undefined).
+    let date_style = get_string_option::<DateTimeStyle>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.dateStyle,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 43. Let timeStyle be ? GetOption(options, "timeStyle", string,
+    //     « "full", "long", "medium", "short" », undefined).
+    let time_style = get_string_option::<DateTimeStyle>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeStyle,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 45. If dateStyle is not undefined or timeStyle is not undefined, then
+    if date_style.is_some() || time_style.is_some() {
+        // a. If hasExplicitFormatComponents is true, then
+        if has_explicit_format_components {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Can't set option dateStyle or timeStyle with other date and time components",
+                gc,
+            ));
+        }
+        // b. If required is date and timeStyle is not undefined, then
+        if required == DateTimeRequired::Date && time_style.is_some() {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid option: timeStyle",
+                gc,
+            ));
+        }
+        // c. If required is time and dateStyle is not undefined, then
+        if required == DateTimeRequired::Time && date_style.is_some() {
+            // i. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid option: dateStyle",
+                gc,
+            ));
+        }
+    } else {
+        // 46. Else,
+        //     b. Let needDefaults be true.
+        //     c. If required is date or any, then
+        //        i. For each property name prop of « "weekday", "year",
+        //           "month", "day" », do
+        //           1. Let value be formatOptions.[[<prop>]].
+        //           2. If value is not undefined, set needDefaults to false.
+        //     d. If required is time or any, then
+        //        i. For each property name prop of « "dayPeriod", "hour",
+        //           "minute", "second", "fractionalSecondDigits" », do
+        //           1. Let value be formatOptions.[[<prop>]].
+        //           2. If value is not undefined, set needDefaults to false.
+        let has_date = matches!(required, DateTimeRequired::Date | DateTimeRequired::Any)
+            && components.has_date();
+        let has_time = matches!(required, DateTimeRequired::Time | DateTimeRequired::Any)
+            && components.has_time();
+        let need_defaults = !has_date && !has_time;
+        //     e. If needDefaults is true and defaults is either date or all,
+        //        then
+        if need_defaults && matches!(defaults, DateTimeDefaults::Date | DateTimeDefaults::All) {
+            // i. For each property name prop of « "year", "month", "day" », do
+            //    1. Set formatOptions.[[<prop>]] to "numeric".
+            components.year = Some(NumericWidth::Numeric);
+            components.month = Some(MonthWidth::Numeric);
+            components.day = Some(NumericWidth::Numeric);
+        }
+        //     f. If needDefaults is true and defaults is either time or all,
+        //        then
+        if need_defaults && matches!(defaults, DateTimeDefaults::Time | DateTimeDefaults::All) {
+            // i. For each property name prop of « "hour", "minute", "second"
+            //    », do
+            //    1. Set formatOptions.[[<prop>]] to "numeric".
+            components.hour = Some(NumericWidth::Numeric);
+            components.minute = Some(NumericWidth::Numeric);
+            components.second = Some(NumericWidth::Numeric);
+        }
+    }
+    // 47. If dateTimeFormat.[[Hour]] is undefined, then
+    //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+    let hour_cycle = if components.hour.is_some() || time_style.is_some() {
+        // 42. Let hcDefault be dataLocaleData.[[hourCycle]].
+        // 43. If hour12 is true, then
+        //     a. Let hc be dataLocaleData.[[hourCycle12]].
+        // 44. Else if hour12 is false, then
+        //     a. Let hc be dataLocaleData.[[hourCycle24]].
+        // 45. Else,
+        //     a. Assert: hour12 is undefined.
+        //     b. Let hc be r.[[hc]].
+        //     c. If hc is null, set hc to hcDefault.
+        Some(match (hour12, hour_cycle) {
+            (Some(true), _) => HourCycle::H12,
+            (Some(false), _) => HourCycle::H23,
+            (None, Some(hour_cycle)) => hour_cycle,
+            (None, None) => locale
+                .extensions
+                .unicode
+                .keywords
+                .get(&key!("hc"))
+                .and_then(|hc| HourCycle::from_str(&hc.to_string()))
+                .unwrap_or_else(|| default_hour_cycle(&locale)),
+        })
+    } else {
+        None
+    };
+    let Some(formatter) =
+        create_formatter(&locale, hour_cycle, &components, date_style, time_style)
+    else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Locale data is not available",
+            gc,
+        ));
+    };
+    // 48. Return dateTimeFormat.
+    Ok(DateTimeFormatRecord {
+        locale,
+        formatter,
+        time_zone,
+        hour_cycle,
+        components,
+        date_style,
+        time_style,
+    })
+}
+
+/// Reads the date and time component options in the order of Table 16.
+fn get_date_time_components<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DateTimeComponents> {
+    let options = options.scope(agent, gc.nogc());
+    let weekday = get_string_option::<TextWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.weekday,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let era = get_string_option::<TextWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.era,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let year = get_string_option::<NumericWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.year,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let month = get_string_option::<MonthWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.month,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let day = get_string_option::<NumericWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.day,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let day_period = get_string_option::<TextWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.dayPeriod,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let hour = get_string_option::<NumericWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.hour,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let minute = get_string_option::<NumericWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minute,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let second = get_string_option::<NumericWidth>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.second,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let fractional_second_digits = get_number_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.fractionalSecondDigits,
+        1,
+        3,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let time_zone_name = get_string_option::<TimeZoneName>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.timeZoneName,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    if day_period.is_some() || time_zone_name.is_some() {
+        let option = if day_period.is_some() {
+            "dayPeriod"
+        } else {
+            "timeZoneName"
+        };
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!("Intl.DateTimeFormat option {option} is not supported"),
+            gc.into_nogc(),
+        ));
+    }
+    Ok(DateTimeComponents {
+        weekday,
+        era,
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        fractional_second_digits,
+    })
+}
+
+impl DateTimeComponents {
+    fn has_date(&self) -> bool {
+        self.weekday.is_some() || self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    fn has_time(&self) -> bool {
+        self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+
+    fn has_any(&self) -> bool {
+        self.has_date() || self.has_time() || self.era.is_some()
+    }
+
+    fn to_bag(self, hour_cycle: Option<HourCycle>) -> components::Bag {
+        fn text(width: TextWidth) -> components::Text {
+            match width {
+                TextWidth::Narrow => components::Text::Narrow,
+                TextWidth::Short => components::Text::Short,
+                TextWidth::Long => components::Text::Long,
+            }
+        }
+        fn numeric(width: NumericWidth) -> components::Numeric {
+            match width {
+                NumericWidth::Numeric => components::Numeric::Numeric,
+                NumericWidth::TwoDigit => components::Numeric::TwoDigit,
+            }
+        }
+        let mut bag = components::Bag::empty();
+        bag.weekday = self.weekday.map(text);
+        bag.era = self.era.map(text);
+        bag.year = self.year.map(|width| match width {
+            NumericWidth::Numeric => components::Year::Numeric,
+            NumericWidth::TwoDigit => components::Year::TwoDigit,
+        });
+        bag.month = self.month.map(|width| match width {
+            MonthWidth::Numeric => components::Month::Numeric,
+            MonthWidth::TwoDigit => components::Month::TwoDigit,
+            MonthWidth::Narrow => components::Month::Narrow,
+            MonthWidth::Short => components::Month::Short,
+            MonthWidth::Long => components::Month::Long,
+        });
+        bag.day = self.day.map(|width| match width {
+            NumericWidth::Numeric => components::Day::NumericDayOfMonth,
+            NumericWidth::TwoDigit => components::Day::TwoDigitDayOfMonth,
+        });
+        bag.hour = self.hour.map(numeric);
+        bag.minute = self.minute.map(numeric);
+        bag.second = self.second.map(numeric);
+        bag.fractional_second = self.fractional_second_digits;
+        bag.preferences = hour_cycle.map(|hour_cycle| {
+            preferences::Bag::from_hour_cycle(match hour_cycle {
+                HourCycle::H11 => preferences::HourCycle::H11,
+                HourCycle::H12 => preferences::HourCycle::H12,
+                HourCycle::H23 => preferences::HourCycle::H23,
+                HourCycle::H24 => preferences::HourCycle::H24,
+            })
+        });
+        bag
+    }
+}
+
+/// Returns the locale with the Gregorian calendar and the given hour cycle
+/// selected, for loading locale data.
+fn data_locale(locale: &Locale, hour_cycle: Option<HourCycle>) -> Locale {
+    let mut locale = locale.clone();
+    let keywords = &mut locale.extensions.unicode.keywords;
+    keywords.set(
+        key!("ca"),
+        UnicodeValue::try_from_bytes(b"gregory").unwrap(),
+    );
+    if let Some(hour_cycle) = hour_cycle {
+        keywords.set(
+            key!("hc"),
+            UnicodeValue::try_from_bytes(hour_cycle.as_str().as_bytes()).unwrap(),
+        );
+    }
+    locale
+}
+
+fn create_formatter(
+    locale: &Locale,
+    hour_cycle: Option<HourCycle>,
+    components: &DateTimeComponents,
+    date_style: Option<DateTimeStyle>,
+    time_style: Option<DateTimeStyle>,
+) -> Option<DateTimeFormatter> {
+    let data_locale = (&data_locale(locale, hour_cycle)).into();
+    if date_style.is_none() && time_style.is_none() {
+        let options = DateTimeFormatterOptions::from(components.to_bag(hour_cycle));
+        return DateTimeFormatter::try_new_experimental(&data_locale, options).ok();
+    }
+    let date = date_style.map(|style| match style {
+        DateTimeStyle::Full => length::Date::Full,
+        DateTimeStyle::Long => length::Date::Long,
+        DateTimeStyle::Medium => length::Date::Medium,
+        DateTimeStyle::Short => length::Date::Short,
+    });
+    let time = time_style.map(|style| match style {
+        DateTimeStyle::Full => length::Time::Full,
+        DateTimeStyle::Long => length::Time::Long,
+        DateTimeStyle::Medium => length::Time::Medium,
+        DateTimeStyle::Short => length::Time::Short,
+    });
+    let bag = match (date, time) {
+        (Some(date), Some(time)) => length::Bag::from_date_time_style(date, time),
+        (Some(date), None) => length::Bag::from_date_style(date),
+        (None, Some(time)) => length::Bag::from_time_style(time),
+        (None, None) => unreachable!(),
+    };
+    DateTimeFormatter::try_new(&data_locale, bag.into()).ok()
+}
+
+/// Returns the hour cycle the locale uses by default.
+fn default_hour_cycle(locale: &Locale) -> HourCycle {
+    let bag = length::Bag::from_time_style(length::Time::Short);
+    let formatter = DateTimeFormatter::try_new(&(&data_locale(locale, None)).into(), bag.into());
+    let afternoon = Date::try_new_iso_date(2000, 1, 1)
+        .ok()
+        .zip(Time::try_new(13, 0, 0, 0).ok())
+        .map(|(date, time)| DateTime::new(date, time).to_any());
+    match formatter.ok().zip(afternoon) {
+        Some((formatter, afternoon))
+            if formatter
+                .format_to_string(&afternoon)
+                .is_ok_and(|formatted| !formatted.contains("13")) =>
+        {
+            HourCycle::H12
+        }
+        _ => HourCycle::H23,
+    }
+}
+
+impl DateTimeFormatRecord {
+    /// ### [11.5.6 FormatDateTime ( dateTimeFormat, x )](https://tc39.es/ecma402/#sec-formatdatetime)
+    ///
+    /// Formats the time value. Returns None if the time value is not valid,
+    /// in which case a RangeError should be thrown.
+    pub(crate) fn format(&self, x: f64) -> Option<std::string::String> {
+        // 11.5.5 PartitionDateTimePattern ( dateTimeFormat, x )
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        let x = DateValue::time_clip(x).get_f64()?;
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10**6).
+        // 11.5.12 ToLocalTime ( epochNs, calendar, timeZoneIdentifier )
+        let t = x + self.time_zone.offset_milliseconds();
+        let date =
+            Date::try_new_iso_date(year_from_time(t), month_from_time(t) + 1, date_from_time(t))
+                .ok()?;
+        let time = Time::try_new(
+            hour_from_time(t),
+            min_from_time(t),
+            sec_from_time(t),
+            ms_from_time(t) as u32 * 1_000_000,
+        )
+        .ok()?;
+        self.formatter
+            .format_to_string(&DateTime::new(date, time).to_any())
+            .ok()
+    }
+}
@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, Function, JsResult, Object, ProtoIntrinsics, Realm, String,
+        Value, builders::BuiltinFunctionBuilder, embedder_object_create_from_constructor,
+        is_available_locale, supported_locales,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{DateTimeDefaults, DateTimeFormat, DateTimeRequired, create_date_time_format};
+
+pub(crate) struct DateTimeFormatConstructor;
+impl Builtin for DateTimeFormatConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.DateTimeFormat;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for DateTimeFormatConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::IntlDateTimeFormat;
+}
+
+struct DateTimeFormatSupportedLocalesOf;
+impl Builtin for DateTimeFormatSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(DateTimeFormatConstructor::supported_locales_of);
+}
+
+impl DateTimeFormatConstructor {
+    /// ### [11.1.1 Intl.DateTimeFormat ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.datetimeformat)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object, else let newTarget be NewTarget.
+        let new_target = match new_target {
+            Some(new_target) => Function::try_from(new_target).unwrap(),
+            None => agent
+                .current_realm_record()
+                .intrinsics()
+                .intl_date_time_format()
+                .into(),
+        }
+        .scope(agent, gc.nogc());
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales,
+        //    options, any, date).
+        let record = create_date_time_format(
+            agent,
+            locales.unbind(),
+            options.unbind(),
+            DateTimeRequired::Any,
+            DateTimeDefaults::Date,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // NOTE: The object is created after the options are processed, as
+        // its native data is the result of processing them.
+        let date_time_format = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::IntlDateTimeFormat,
+            DateTimeFormat::SLOT_COUNT,
+            record,
+            gc,
+        )?;
+        // 3. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        //    a. Let this be the this value.
+        //    b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // 4. Return dateTimeFormat.
+        Ok(DateTimeFormat::from_embedder_object(date_time_format).into())
+    }
+
+    /// ### [11.2.2 Intl.DateTimeFormat.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. Let availableLocales be %Intl.DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales,
+        //    options).
+        supported_locales(
+            agent,
+            locales.unbind(),
+            options.unbind(),
+            is_available_locale,
+            gc,
+        )
+        .map(|locales| locales.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let date_time_format_prototype = intrinsics.intl_date_time_format_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<DateTimeFormatConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(2)
+        .with_prototype_property(date_time_format_prototype.into())
+        .with_builtin_function_property::<DateTimeFormatSupportedLocalesOf>()
+        .build();
+    }
+}
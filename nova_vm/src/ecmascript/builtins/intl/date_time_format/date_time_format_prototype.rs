@@ -0,0 +1,296 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinFunctionArgs,
+        BuiltinGetter, DateValue, ExceptionType, JsResult, Number, OrdinaryObject, PropertyKey,
+        Realm, String, StringOption, Value, bound_function_create, builders::OrdinaryObjectBuilder,
+        create_builtin_function, to_number,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccessMut, ObjectEntry, WellKnownSymbols},
+};
+
+use super::{DateTimeFormat, HourCycle};
+
+pub(crate) struct DateTimeFormatPrototype;
+
+struct DateTimeFormatPrototypeGetFormat;
+impl Builtin for DateTimeFormatPrototypeGetFormat {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_format;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.format.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DateTimeFormatPrototype::get_format);
+}
+impl BuiltinGetter for DateTimeFormatPrototypeGetFormat {}
+struct DateTimeFormatPrototypeResolvedOptions;
+impl Builtin for DateTimeFormatPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DateTimeFormatPrototype::resolved_options);
+}
+
+impl DateTimeFormatPrototype {
+    /// ### [11.3.3 get Intl.DateTimeFormat.prototype.format](https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format)
+    fn get_format<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        //    a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = require_date_time_format(agent, this_value, gc.nogc()).unbind()?;
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        if let Some(bound_format) = dtf.bound_format(agent) {
+            // 5. Return dtf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+        // a. Let F be a new built-in function object as defined in DateTime
+        //    Format Functions (11.5.4).
+        // b. Set F.[[DateTimeFormat]] to dtf.
+        // c. Set dtf.[[BoundFormat]] to F.
+        // NOTE: F is a bound function with dtf as its bound this value.
+        let target = create_builtin_function(
+            agent,
+            Behaviour::Regular(date_time_format_function),
+            BuiltinFunctionArgs::new(1, ""),
+            gc.nogc(),
+        );
+        let f = bound_function_create(agent, target.unbind().into(), dtf.into(), &[], gc)?;
+        f.get_mut(agent).length = 1;
+        dtf.set_bound_format(agent, f.into());
+        Ok(f.into())
+    }
+
+    /// ### [11.3.7 Intl.DateTimeFormat.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let dtf be the this value.
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = require_date_time_format(agent, this_value, gc)?;
+        let record = dtf.data(agent);
+        let locale = record.locale.to_string();
+        let time_zone = record.time_zone.identifier();
+        let hour_cycle = record.hour_cycle;
+        let components = record.components;
+        let date_style = record.date_style;
+        let time_style = record.time_style;
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 17, except the header row, in table
+        //    order, do
+        //    a. Let p be the Property value of the current row.
+        //    b. If there is an Internal Slot value in the current row, then
+        //       i. Let v be the value of dtf's internal slot whose name is
+        //          the Internal Slot value of the current row.
+        //    c. Else,
+        //       i. Let format be dtf.[[DateTimeFormat]].
+        //       ii. If format has a field [[<p>]] and dtf.[[DateStyle]] is
+        //           undefined and dtf.[[TimeStyle]] is undefined, then
+        //           1. Let v be format.[[<p>]].
+        //       iii. Else,
+        //            1. Let v be undefined.
+        //    d. If v is not undefined, then
+        //       i. If there is a Conversion value in the current row, then
+        //          1. Let conversion be the Conversion value of the current
+        //             row.
+        //          2. If conversion is hour12, then
+        //             a. If v is "h11" or "h12", set v to true. Otherwise,
+        //                set v to false.
+        //          3. Else,
+        //             a. Assert: conversion is number.
+        //             b. Set v to 𝔽(v).
+        //       ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut entries = vec![
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.locale.into(),
+                String::from_string(agent, locale, gc).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.calendar.into(),
+                BUILTIN_STRING_MEMORY.gregory.into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.numberingSystem.into(),
+                BUILTIN_STRING_MEMORY.latn.into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.timeZone.into(),
+                String::from_string(agent, time_zone, gc).into(),
+            ),
+        ];
+        if let Some(hour_cycle) = hour_cycle {
+            entries.push(ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.hourCycle.into(),
+                String::from_static_str(agent, hour_cycle.as_str(), gc).into(),
+            ));
+            entries.push(ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.hour12.into(),
+                matches!(hour_cycle, HourCycle::H11 | HourCycle::H12).into(),
+            ));
+        }
+        if date_style.is_none() && time_style.is_none() {
+            let text_components = [
+                (
+                    BUILTIN_STRING_MEMORY.weekday,
+                    components.weekday.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.era,
+                    components.era.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.year,
+                    components.year.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.month,
+                    components.month.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.day,
+                    components.day.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.hour,
+                    components.hour.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.minute,
+                    components.minute.map(|w| w.as_str()),
+                ),
+                (
+                    BUILTIN_STRING_MEMORY.second,
+                    components.second.map(|w| w.as_str()),
+                ),
+            ];
+            for (key, value) in text_components {
+                if let Some(value) = value {
+                    entries.push(ObjectEntry::new_data_entry(
+                        key.into(),
+                        String::from_static_str(agent, value, gc).into(),
+                    ));
+                }
+            }
+            if let Some(digits) = components.fractional_second_digits {
+                entries.push(ObjectEntry::new_data_entry(
+                    BUILTIN_STRING_MEMORY.fractionalSecondDigits.into(),
+                    Number::from(digits).into(),
+                ));
+            }
+        }
+        for (key, style) in [
+            (BUILTIN_STRING_MEMORY.dateStyle, date_style),
+            (BUILTIN_STRING_MEMORY.timeStyle, time_style),
+        ] {
+            if let Some(style) = style {
+                entries.push(ObjectEntry::new_data_entry(
+                    key.into(),
+                    String::from_static_str(agent, style.as_str(), gc).into(),
+                ));
+            }
+        }
+        let options = OrdinaryObject::create_object(
+            agent,
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .object_prototype()
+                    .into(),
+            ),
+            &entries,
+        )
+        .expect("Should perform GC here");
+        // 6. Return options.
+        Ok(options.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.intl_date_time_format_prototype();
+        let date_time_format_constructor = intrinsics.intl_date_time_format();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            .with_constructor_property(date_time_format_constructor)
+            .with_builtin_function_getter_property::<DateTimeFormatPrototypeGetFormat>()
+            .with_builtin_function_property::<DateTimeFormatPrototypeResolvedOptions>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_DateTimeFormat.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [11.5.4 DateTime Format Functions](https://tc39.es/ecma402/#sec-datetime-format-functions)
+///
+/// A DateTime format function is an anonymous built-in function that has a
+/// [[DateTimeFormat]] internal slot. Here the Intl.DateTimeFormat is the this
+/// value of the function, bound by the format getter.
+fn date_time_format_function<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let date = arguments.get(0).bind(gc.nogc());
+    // 1. Let dtf be F.[[DateTimeFormat]].
+    // 2. Assert: dtf is an Object and dtf has an
+    //    [[InitializedDateTimeFormat]] internal slot.
+    let dtf = require_date_time_format(agent, this_value, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 3. If date is not provided or is undefined, then
+    let x = if date.is_undefined() {
+        // a. Let x be ! Call(%Date.now%, undefined).
+        DateValue::now(agent).get_f64().unwrap_or(f64::NAN)
+    } else {
+        // 4. Else,
+        //    a. Let x be ? ToNumber(date).
+        to_number(agent, date.unbind(), gc.reborrow())
+            .unbind()?
+            .into_f64(agent)
+    };
+    let gc = gc.into_nogc();
+    // 5. Return ? FormatDateTime(dtf, x).
+    match dtf.get(agent).data(agent).format(x) {
+        Some(result) => Ok(String::from_string(agent, result, gc).into()),
+        None => Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Invalid time value",
+            gc,
+        )),
+    }
+}
+
+pub(crate) fn require_date_time_format<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DateTimeFormat<'a>> {
+    DateTimeFormat::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an Intl.DateTimeFormat",
+            gc,
+        )
+    })
+}
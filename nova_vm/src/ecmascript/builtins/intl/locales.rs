@@ -0,0 +1,288 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use icu_datetime::{DateTimeFormatter, options::components};
+use icu_locid::{
+    LanguageIdentifier, Locale,
+    extensions::unicode::{Value as UnicodeValue, key},
+    locale,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, Array, BUILTIN_STRING_MEMORY, ExceptionType, JsResult, PropertyKey, String, Value,
+        coerce_options_to_object, create_array_from_list, get, get_string_option, has_property,
+        length_of_array_like, string_option, to_object, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+};
+
+trivially_bindable!(Locale);
+trivially_bindable!(UnicodeValue);
+
+/// The locale used when none of the requested locales are available.
+pub(crate) const DEFAULT_LOCALE: Locale = locale!("en-US");
+
+string_option!(LocaleMatcher {
+    Lookup => "lookup",
+    BestFit => "best fit",
+});
+
+/// ### [9.2.1 CanonicalizeLocaleList ( locales )](https://tc39.es/ecma402/#sec-canonicalizelocalelist)
+///
+/// The abstract operation CanonicalizeLocaleList takes argument locales (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a List of Unicode canonicalized locale identifiers or a throw
+/// completion.
+///
+/// NOTE: Locale identifiers are canonicalized to the case and subtag order of
+/// [UTS 35](https://unicode.org/reports/tr35/#Canonical_Unicode_Locale_Identifiers);
+/// deprecated and aliased subtags are not replaced.
+pub(crate) fn canonicalize_locale_list<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Vec<Locale>> {
+    let locales = locales.bind(gc.nogc());
+    // 1. If locales is undefined, then
+    if locales.is_undefined() {
+        // a. Return a new empty List.
+        return Ok(vec![]);
+    }
+    // 2. Let seen be a new empty List.
+    let mut seen = Vec::new();
+    // 3. If locales is a String or locales has an [[InitializedLocale]]
+    //    internal slot, then
+    if let Ok(locales) = String::try_from(locales) {
+        // a. Let O be CreateArrayFromList(« locales »).
+        let locale = canonicalize_language_tag(agent, locales.unbind(), gc.into_nogc())?;
+        seen.push(locale);
+        return Ok(seen);
+    }
+    // 4. Else,
+    //    a. Let O be ? ToObject(locales).
+    let o = to_object(agent, locales, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 5. Let len be ? LengthOfArrayLike(O).
+    let len = length_of_array_like(agent, o.get(agent), gc.reborrow()).unbind()?;
+    // 6. Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::Integer(k.try_into().unwrap());
+        // b. Let kPresent be ? HasProperty(O, Pk).
+        let k_present = has_property(agent, o.get(agent), pk, gc.reborrow()).unbind()?;
+        // c. If kPresent is true, then
+        if !k_present {
+            continue;
+        }
+        // i. Let kValue be ? Get(O, Pk).
+        let k_value = get(agent, o.get(agent), pk, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // ii. If kValue is not a String and kValue is not an Object, throw a
+        //     TypeError exception.
+        if !k_value.is_string() && !k_value.is_object() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Locale list elements must be strings or objects",
+                gc.into_nogc(),
+            ));
+        }
+        // iii. If kValue is an Object and kValue has an
+        //      [[InitializedLocale]] internal slot, then
+        //      1. Let tag be kValue.[[Locale]].
+        // iv. Else,
+        //     1. Let tag be ? ToString(kValue).
+        let tag = to_string(agent, k_value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // v. If IsStructurallyValidLanguageTag(tag) is false, throw a
+        //    RangeError exception.
+        // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
+        let canonicalized_tag =
+            canonicalize_language_tag(agent, tag.unbind(), gc.nogc()).unbind()?;
+        // vii. If seen does not contain canonicalizedTag, append
+        //      canonicalizedTag to seen.
+        if !seen.contains(&canonicalized_tag) {
+            seen.push(canonicalized_tag);
+        }
+        // d. Set k to k + 1.
+    }
+    // 8. Return seen.
+    Ok(seen)
+}
+
+/// ### [6.2.1 IsStructurallyValidLanguageTag ( locale )](https://tc39.es/ecma402/#sec-isstructurallyvalidlanguagetag)
+///
+/// Parses the language tag, throwing a RangeError if it is not a structurally
+/// valid Unicode BCP 47 locale identifier.
+fn canonicalize_language_tag<'gc>(
+    agent: &mut Agent,
+    tag: String,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Locale> {
+    let parsed = tag
+        .as_str(agent)
+        .and_then(|tag| Locale::try_from_bytes(tag.as_bytes()).ok());
+    match parsed {
+        // NOTE: Private use and extensions other than the Unicode extension
+        // are not supported.
+        Some(locale)
+            if locale.extensions.private.is_empty() && locale.extensions.other.is_empty() =>
+        {
+            Ok(locale)
+        }
+        _ => {
+            let message = format!(
+                "Incorrect locale information provided: {}",
+                tag.to_string_lossy(agent)
+            );
+            Err(agent.throw_exception(ExceptionType::RangeError, message, gc))
+        }
+    }
+}
+
+/// ### [9.2.2 BestAvailableLocale ( availableLocales, locale )](https://tc39.es/ecma402/#sec-bestavailablelocale)
+///
+/// The abstract operation BestAvailableLocale takes arguments
+/// availableLocales (an Available Locales List) and locale (a language
+/// priority list) and returns either a Unicode canonicalized locale
+/// identifier or undefined. It compares the provided argument locale, which
+/// must be a String value with a structurally valid and canonicalized Unicode
+/// BCP 47 locale identifier, against the locales in availableLocales and
+/// returns either the longest non-empty prefix of locale that is an element
+/// of availableLocales, or undefined if there is no such element.
+fn best_available_locale(
+    is_available: impl Fn(&LanguageIdentifier) -> bool,
+    locale: &Locale,
+) -> Option<LanguageIdentifier> {
+    // 1. Let candidate be locale.
+    let mut candidate = locale.id.clone();
+    // 2. Repeat,
+    loop {
+        // a. If availableLocales contains candidate, return candidate.
+        if is_available(&candidate) {
+            return Some(candidate);
+        }
+        // b. Let pos be the character index of the last occurrence of "-"
+        //    (U+002D) within candidate. If that character does not occur,
+        //    return undefined.
+        // c. If pos ≥ 2 and the character "-" occurs at index pos - 2 of
+        //    candidate, decrease pos by 2.
+        // d. Let candidate be the substring of candidate from position 0,
+        //    inclusive, to position pos, exclusive.
+        if !candidate.variants.is_empty() {
+            candidate.variants = Default::default();
+        } else if candidate.region.is_some() {
+            candidate.region = None;
+        } else if candidate.script.is_some() {
+            candidate.script = None;
+        } else {
+            return None;
+        }
+    }
+}
+
+/// ### [9.2.3 LookupMatchingLocaleByPrefix ( availableLocales, requestedLocales )](https://tc39.es/ecma402/#sec-lookupmatchinglocalebyprefix)
+///
+/// Returns the first available locale matching one of the requested locales
+/// along with the Unicode extension of the requested locale, or the default
+/// locale if none of them are available. Only the Unicode extension keywords
+/// in `relevant_keys` are kept in the returned locale.
+pub(crate) fn resolve_locale(
+    requested_locales: &[Locale],
+    is_available: impl Fn(&LanguageIdentifier) -> bool,
+    relevant_keys: &[icu_locid::extensions::unicode::Key],
+) -> Locale {
+    // 1. For each element locale of requestedLocales, do
+    for locale in requested_locales {
+        // a. Let extension be empty.
+        // b. If locale contains a Unicode locale extension, then
+        //    i. Set extension to the Unicode locale extension of locale.
+        //    ii. Set locale to the String value that is locale with any
+        //        Unicode locale extension sequences removed.
+        // c. Let prefix be BestAvailableLocale(availableLocales, locale).
+        // d. If prefix is not undefined, return the Record
+        //    { [[locale]]: prefix, [[extension]]: extension }.
+        if let Some(prefix) = best_available_locale(&is_available, locale) {
+            let mut resolved = Locale::from(prefix);
+            for key in relevant_keys {
+                if let Some(value) = locale.extensions.unicode.keywords.get(key) {
+                    resolved
+                        .extensions
+                        .unicode
+                        .keywords
+                        .set(*key, value.clone());
+                }
+            }
+            return resolved;
+        }
+    }
+    // 2. Return undefined.
+    DEFAULT_LOCALE
+}
+
+/// ### [9.2.10 SupportedLocales ( availableLocales, requestedLocales, options )](https://tc39.es/ecma402/#sec-supportedlocales)
+///
+/// The abstract operation SupportedLocales takes arguments availableLocales
+/// (an Available Locales List), requestedLocales (a Language Priority List),
+/// and options (an ECMAScript language value) and returns either a normal
+/// completion containing a List of Unicode canonicalized locale identifiers
+/// or a throw completion. It returns the subset of the requested locales that
+/// are available.
+pub(crate) fn supported_locales<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    options: Value,
+    is_available: impl Fn(&LanguageIdentifier) -> bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Array<'gc>> {
+    let options = options.scope(agent, gc.nogc());
+    let requested_locales = canonicalize_locale_list(agent, locales, gc.reborrow()).unbind()?;
+    // 1. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options.get(agent), gc.nogc()).unbind()?;
+    // 2. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    get_string_option::<LocaleMatcher>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.localeMatcher,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // 3. Let subset be a new empty List.
+    // 4. For each element locale of requestedLocales, do
+    //    a. Let noExtensionsLocale be the String value that is locale with
+    //       any Unicode locale extension sequences removed.
+    //    b. Let match be LookupMatchingLocaleByPrefix(availableLocales,
+    //       noExtensionsLocale).
+    //    c. If match is not undefined, append locale to subset.
+    let subset = requested_locales
+        .iter()
+        .filter(|locale| best_available_locale(&is_available, locale).is_some())
+        .map(|locale| String::from_string(agent, locale.to_string(), gc).into())
+        .collect::<Vec<Value>>();
+    // 5. Return CreateArrayFromList(subset).
+    Ok(create_array_from_list(agent, &subset, gc))
+}
+
+/// Returns true if locale data for the language identifier is available.
+///
+/// NOTE: Intl.NumberFormat and Intl.DateTimeFormat share the list of
+/// available locales: the locales with date and time formatting data. Number
+/// formatting data falls back to the root locale for locales without data of
+/// their own.
+pub(crate) fn is_available_locale(locale: &LanguageIdentifier) -> bool {
+    let mut locale = Locale::from(locale.clone());
+    locale.extensions.unicode.keywords.set(
+        key!("ca"),
+        UnicodeValue::try_from_bytes(b"gregory").unwrap(),
+    );
+    let mut bag = components::Bag::empty();
+    bag.year = Some(components::Year::Numeric);
+    DateTimeFormatter::try_new_experimental(&(&locale).into(), bag.into()).is_ok()
+}
@@ -0,0 +1,793 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod number_format_constructor;
+mod number_format_prototype;
+
+pub(crate) use number_format_constructor::*;
+pub(crate) use number_format_prototype::*;
+
+use fixed_decimal::{FixedDecimal, FloatPrecision, SignDisplay as FixedDecimalSignDisplay};
+use icu_decimal::{
+    FixedDecimalFormatter,
+    options::{FixedDecimalFormatterOptions, GroupingStrategy},
+};
+use icu_locid::Locale;
+
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, BigInt, EmbedderObject, ExceptionType, JsResult,
+        LocaleMatcher, Numeric, Object, String, StringOption, Value, canonicalize_locale_list,
+        coerce_options_to_object, default_number_option, embedder_object_handle, get,
+        get_number_option, get_string_option, get_string_option_value, invalid_option_error,
+        is_available_locale, resolve_locale, string_option, to_boolean, to_numeric, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+    heap::ArenaAccess,
+};
+
+/// ### [16 NumberFormat Objects](https://tc39.es/ecma402/#numberformat-objects)
+///
+/// An Intl.NumberFormat object formats numbers with the conventions of a
+/// locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct NumberFormat<'a>(EmbedderObject<'a>);
+embedder_object_handle!(NumberFormat, NumberFormatRecord);
+
+string_option!(NumberFormatStyle {
+    Decimal => "decimal",
+    Percent => "percent",
+    Currency => "currency",
+    Unit => "unit",
+});
+
+string_option!(Notation {
+    Standard => "standard",
+    Scientific => "scientific",
+    Engineering => "engineering",
+    Compact => "compact",
+});
+
+string_option!(RoundingMode {
+    Ceil => "ceil",
+    Floor => "floor",
+    Expand => "expand",
+    Trunc => "trunc",
+    HalfCeil => "halfCeil",
+    HalfFloor => "halfFloor",
+    HalfExpand => "halfExpand",
+    HalfTrunc => "halfTrunc",
+    HalfEven => "halfEven",
+});
+
+string_option!(RoundingPriority {
+    Auto => "auto",
+    MorePrecision => "morePrecision",
+    LessPrecision => "lessPrecision",
+});
+
+string_option!(TrailingZeroDisplay {
+    Auto => "auto",
+    StripIfInteger => "stripIfInteger",
+});
+
+string_option!(UseGrouping {
+    Min2 => "min2",
+    Auto => "auto",
+    Always => "always",
+});
+
+string_option!(SignDisplay {
+    Auto => "auto",
+    Never => "never",
+    Always => "always",
+    ExceptZero => "exceptZero",
+    Negative => "negative",
+});
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RoundingType {
+    FractionDigits { minimum: u8, maximum: u8 },
+    SignificantDigits { minimum: u8, maximum: u8 },
+}
+
+/// The resolved options of an Intl.NumberFormat.
+pub(crate) struct NumberFormatRecord {
+    pub(super) locale: Locale,
+    formatter: FixedDecimalFormatter,
+    pub(super) style: NumberFormatStyle,
+    pub(super) minimum_integer_digits: u8,
+    pub(super) rounding_type: RoundingType,
+    pub(super) rounding_mode: RoundingMode,
+    pub(super) trailing_zero_display: TrailingZeroDisplay,
+    /// The grouping strategy, or None if grouping is disabled.
+    pub(super) use_grouping: Option<UseGrouping>,
+    pub(super) sign_display: SignDisplay,
+}
+
+trivially_bindable!(NumberFormatRecord);
+
+impl core::fmt::Debug for NumberFormatRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NumberFormatRecord")
+            .field("locale", &self.locale)
+            .field("style", &self.style)
+            .field("minimum_integer_digits", &self.minimum_integer_digits)
+            .field("rounding_type", &self.rounding_type)
+            .field("rounding_mode", &self.rounding_mode)
+            .field("trailing_zero_display", &self.trailing_zero_display)
+            .field("use_grouping", &self.use_grouping)
+            .field("sign_display", &self.sign_display)
+            .finish()
+    }
+}
+
+/// ### [16.5.2 Intl Mathematical Values](https://tc39.es/ecma402/#intl-mathematical-value)
+pub(crate) enum IntlMathematicalValue {
+    Decimal(FixedDecimal),
+    NotANumber,
+    PositiveInfinity,
+    NegativeInfinity,
+}
+
+trivially_bindable!(IntlMathematicalValue);
+
+impl<'a> NumberFormat<'a> {
+    /// \[\[BoundFormat]]
+    const BOUND_FORMAT: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(crate) fn bound_format(self, agent: &Agent) -> Option<Object<'a>> {
+        Object::try_from(self.0.get_slot(agent, Self::BOUND_FORMAT)).ok()
+    }
+
+    pub(crate) fn set_bound_format(self, agent: &mut Agent, bound_format: Object) {
+        self.0
+            .set_slot(agent, Self::BOUND_FORMAT, bound_format.into());
+    }
+}
+
+/// ### [16.1.2 InitializeNumberFormat ( numberFormat, locales, options )](https://tc39.es/ecma402/#sec-initializenumberformat)
+///
+/// Processes the locales and options of an Intl.NumberFormat into its
+/// resolved options.
+pub(crate) fn initialize_number_format<'gc>(
+    agent: &mut Agent,
+    locales: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, NumberFormatRecord> {
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+    let requested_locales = canonicalize_locale_list(agent, locales, gc.reborrow()).unbind()?;
+    // 2. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 3. Let opt be a new Record.
+    // 4. Let matcher be ? GetOption(options, "localeMatcher", string,
+    //    « "lookup", "best fit" », "best fit").
+    // 5. Set opt.[[localeMatcher]] to matcher.
+    get_string_option::<LocaleMatcher>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.localeMatcher,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 6. Let numberingSystem be ? GetOption(options, "numberingSystem",
+    //    string, empty, undefined).
+    // 7. If numberingSystem is not undefined, then
+    //    a. If numberingSystem cannot be matched by the type Unicode locale
+    //       nonterminal, throw a RangeError exception.
+    // NOTE: Only the "latn" numbering system is supported, other numbering
+    // systems are ignored.
+    validate_unicode_type_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.numberingSystem,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 8. Set opt.[[nu]] to numberingSystem.
+    // 9. Let r be ResolveLocale(%Intl.NumberFormat%.[[AvailableLocales]],
+    //    requestedLocales, opt, %Intl.NumberFormat%.[[RelevantExtensionKeys]],
+    //    %Intl.NumberFormat%.[[LocaleData]]).
+    // 10. Set numberFormat.[[Locale]] to r.[[Locale]].
+    let locale = resolve_locale(&requested_locales, is_available_locale, &[]);
+    // 13. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+    let style =
+        set_number_format_unit_options(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 14. Let style be numberFormat.[[Style]].
+    // 15. Let notation be ? GetOption(options, "notation", string,
+    //     « "standard", "scientific", "engineering", "compact" », "standard").
+    let notation = get_string_option::<Notation>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.notation,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(Notation::Standard);
+    // 16. Set numberFormat.[[Notation]] to notation.
+    if notation != Notation::Standard {
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!(
+                "Intl.NumberFormat notation \"{}\" is not supported",
+                notation.as_str()
+            ),
+            gc.into_nogc(),
+        ));
+    }
+    // 17. If style is "currency" and notation is "standard", then
+    //     ...
+    // 18. Else,
+    //     a. Let mnfdDefault be 0.
+    //     b. If style is "percent", then
+    //        i. Let mxfdDefault be 0.
+    //     c. Else,
+    //        i. Let mxfdDefault be 3.
+    let mxfd_default = if style == NumberFormatStyle::Percent {
+        0
+    } else {
+        3
+    };
+    // 19. Perform ? SetNumberFormatDigitOptions(numberFormat, options,
+    //     mnfdDefault, mxfdDefault, notation).
+    let digit_options =
+        set_number_format_digit_options(agent, options.get(agent), 0, mxfd_default, gc.reborrow())
+            .unbind()?;
+    // 20. Let compactDisplay be ? GetOption(options, "compactDisplay", string,
+    //     « "short", "long" », "short").
+    // 21. Let defaultUseGrouping be "auto".
+    // 22. If notation is "compact", then
+    //     ...
+    // 23. Let useGrouping be ? GetBooleanOrStringNumberFormatOption(options,
+    //     "useGrouping", « "min2", "auto", "always", "true", "false" »,
+    //     "always", false, defaultUseGrouping).
+    let use_grouping =
+        get_use_grouping_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 24. If useGrouping is "true" or useGrouping is "false", then
+    //     a. Set useGrouping to defaultUseGrouping.
+    // 25. If useGrouping is true, then
+    //     a. Set useGrouping to "always".
+    // 26. Set numberFormat.[[UseGrouping]] to useGrouping.
+    // 27. Let signDisplay be ? GetOption(options, "signDisplay", string,
+    //     « "auto", "never", "always", "exceptZero", "negative" », "auto").
+    let sign_display = get_string_option::<SignDisplay>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.signDisplay,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(SignDisplay::Auto);
+    // 28. Set numberFormat.[[SignDisplay]] to signDisplay.
+    let gc = gc.into_nogc();
+    let mut formatter_options = FixedDecimalFormatterOptions::default();
+    formatter_options.grouping_strategy = match use_grouping {
+        Some(UseGrouping::Min2) => GroupingStrategy::Min2,
+        Some(UseGrouping::Auto) => GroupingStrategy::Auto,
+        Some(UseGrouping::Always) => GroupingStrategy::Always,
+        None => GroupingStrategy::Never,
+    };
+    let Ok(formatter) = FixedDecimalFormatter::try_new(&(&locale).into(), formatter_options) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Locale data is not available",
+            gc,
+        ));
+    };
+    // 29. Return numberFormat.
+    Ok(NumberFormatRecord {
+        locale,
+        formatter,
+        style,
+        minimum_integer_digits: digit_options.minimum_integer_digits,
+        rounding_type: digit_options.rounding_type,
+        rounding_mode: digit_options.rounding_mode,
+        trailing_zero_display: digit_options.trailing_zero_display,
+        use_grouping,
+        sign_display,
+    })
+}
+
+/// Reads a string option whose value must be a Unicode locale identifier
+/// `type` nonterminal, like the "numberingSystem" and "calendar" options.
+pub(crate) fn validate_unicode_type_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: String<'static>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<icu_locid::extensions::unicode::Value>> {
+    let Some(value) = get_string_option_value(agent, options, property, gc.reborrow()).unbind()?
+    else {
+        return Ok(None);
+    };
+    let gc = gc.into_nogc();
+    let value = value.bind(gc);
+    let parsed = value
+        .as_str(agent)
+        .filter(|value| !value.is_empty())
+        .and_then(|value| {
+            icu_locid::extensions::unicode::Value::try_from_bytes(value.as_bytes()).ok()
+        });
+    match parsed {
+        Some(parsed) => Ok(Some(parsed)),
+        None => Err(invalid_option_error(agent, property, value, gc)),
+    }
+}
+
+/// ### [16.1.4 SetNumberFormatUnitOptions ( intlObj, options )](https://tc39.es/ecma402/#sec-setnumberformatunitoptions)
+///
+/// NOTE: Only the "decimal" and "percent" styles are supported.
+fn set_number_format_unit_options<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, NumberFormatStyle> {
+    // 1. Let style be ? GetOption(options, "style", string, « "decimal",
+    //    "percent", "currency", "unit" », "decimal").
+    let style = get_string_option::<NumberFormatStyle>(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.style,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(NumberFormatStyle::Decimal);
+    // 2. Set intlObj.[[Style]] to style.
+    match style {
+        NumberFormatStyle::Decimal | NumberFormatStyle::Percent => Ok(style),
+        NumberFormatStyle::Currency | NumberFormatStyle::Unit => Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!(
+                "Intl.NumberFormat style \"{}\" is not supported",
+                style.as_str()
+            ),
+            gc.into_nogc(),
+        )),
+    }
+}
+
+struct DigitOptions {
+    minimum_integer_digits: u8,
+    rounding_type: RoundingType,
+    rounding_mode: RoundingMode,
+    trailing_zero_display: TrailingZeroDisplay,
+}
+
+trivially_bindable!(DigitOptions);
+
+/// ### [16.1.3 SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )](https://tc39.es/ecma402/#sec-setnfdigitoptions)
+///
+/// NOTE: Only the "auto" rounding priority and a rounding increment of 1 are
+/// supported.
+fn set_number_format_digit_options<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mnfd_default: u8,
+    mxfd_default: u8,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DigitOptions> {
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits", 1,
+    //    21, 1).
+    let mnid = get_number_option(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.minimumIntegerDigits,
+        1,
+        21,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(1);
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    let mnfd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY
+            .minimumFractionDigits
+            .to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    let mxfd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY
+            .maximumFractionDigits
+            .to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+    let mnsd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY
+            .minimumSignificantDigits
+            .to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+    let mxsd = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY
+            .maximumSignificantDigits
+            .to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .scope(agent, gc.nogc());
+    // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+    // 7. Let roundingIncrement be ? GetNumberOption(options,
+    //    "roundingIncrement", 1, 5000, 1).
+    // 8. If roundingIncrement is not in « 1, 2, 5, 10, 20, 25, 50, 100, 200,
+    //    250, 500, 1000, 2000, 2500, 5000 », throw a RangeError exception.
+    // 9. Let roundingMode be ? GetOption(options, "roundingMode", string,
+    //    « "ceil", "floor", "expand", "trunc", "halfCeil", "halfFloor",
+    //    "halfExpand", "halfTrunc", "halfEven" », "halfExpand").
+    let rounding_mode = get_string_option::<RoundingMode>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.roundingMode,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(RoundingMode::HalfExpand);
+    // 10. Let roundingPriority be ? GetOption(options, "roundingPriority",
+    //     string, « "auto", "morePrecision", "lessPrecision" », "auto").
+    let rounding_priority = get_string_option::<RoundingPriority>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.roundingPriority,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(RoundingPriority::Auto);
+    if rounding_priority != RoundingPriority::Auto {
+        return Err(agent.throw_exception(
+            ExceptionType::RangeError,
+            format!(
+                "Intl.NumberFormat roundingPriority \"{}\" is not supported",
+                rounding_priority.as_str()
+            ),
+            gc.into_nogc(),
+        ));
+    }
+    // 11. Let trailingZeroDisplay be ? GetOption(options,
+    //     "trailingZeroDisplay", string, « "auto", "stripIfInteger" », "auto").
+    let trailing_zero_display = get_string_option::<TrailingZeroDisplay>(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.trailingZeroDisplay,
+        gc.reborrow(),
+    )
+    .unbind()?
+    .unwrap_or(TrailingZeroDisplay::Auto);
+    // 12. NOTE: All fields required by SetNumberFormatDigitOptions have now
+    //     been read from options. The remainder of this AO interprets the
+    //     options and may throw exceptions.
+    // 16. Let hasSd be true if mnsd is not undefined or mxsd is not
+    //     undefined; otherwise, let hasSd be false.
+    let has_sd = !mnsd.get(agent).is_undefined() || !mxsd.get(agent).is_undefined();
+    // 17. Let hasFd be true if mnfd is not undefined or mxfd is not
+    //     undefined; otherwise, let hasFd be false.
+    let has_fd = !mnfd.get(agent).is_undefined() || !mxfd.get(agent).is_undefined();
+    // 19. If roundingPriority is "auto", then
+    //     a. Set needSd to hasSd.
+    //     b. If needSd is true, or hasFd is false and notation is "compact",
+    //        then
+    //        i. Set needFd to false.
+    let rounding_type = if has_sd {
+        // 20. If needSd is true, then
+        //     a. If hasSd is true, then
+        //        i. Set intlObj.[[MinimumSignificantDigits]] to
+        //           ? DefaultNumberOption(mnsd, 1, 21, 1).
+        let minimum = default_number_option(
+            agent,
+            mnsd.get(agent),
+            1,
+            21,
+            BUILTIN_STRING_MEMORY.minimumSignificantDigits,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap_or(1);
+        //        ii. Set intlObj.[[MaximumSignificantDigits]] to
+        //            ? DefaultNumberOption(mxsd,
+        //            intlObj.[[MinimumSignificantDigits]], 21, 21).
+        let maximum = default_number_option(
+            agent,
+            mxsd.get(agent),
+            minimum,
+            21,
+            BUILTIN_STRING_MEMORY.maximumSignificantDigits,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .unwrap_or(21);
+        RoundingType::SignificantDigits { minimum, maximum }
+    } else if has_fd {
+        // 21. If needFd is true, then
+        //     a. If hasFd is true, then
+        //        i. Let mnfd be ? DefaultNumberOption(mnfd, 0, 100, undefined).
+        let minimum = default_number_option(
+            agent,
+            mnfd.get(agent),
+            0,
+            100,
+            BUILTIN_STRING_MEMORY.minimumFractionDigits,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        //        ii. Let mxfd be ? DefaultNumberOption(mxfd, 0, 100, undefined).
+        let maximum = default_number_option(
+            agent,
+            mxfd.get(agent),
+            0,
+            100,
+            BUILTIN_STRING_MEMORY.maximumFractionDigits,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        match (minimum, maximum) {
+            // iii. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+            (None, Some(maximum)) => RoundingType::FractionDigits {
+                minimum: mnfd_default.min(maximum),
+                maximum,
+            },
+            // iv. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+            (Some(minimum), None) => RoundingType::FractionDigits {
+                minimum,
+                maximum: mxfd_default.max(minimum),
+            },
+            // v. Else if mnfd is greater than mxfd, throw a RangeError exception.
+            (Some(minimum), Some(maximum)) if minimum > maximum => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::RangeError,
+                    "maximumFractionDigits value is out of range",
+                    gc.into_nogc(),
+                ));
+            }
+            (Some(minimum), Some(maximum)) => RoundingType::FractionDigits { minimum, maximum },
+            (None, None) => unreachable!(),
+        }
+    } else {
+        //     b. Else,
+        //        i. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+        //        ii. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+        RoundingType::FractionDigits {
+            minimum: mnfd_default,
+            maximum: mxfd_default,
+        }
+    };
+    Ok(DigitOptions {
+        minimum_integer_digits: mnid,
+        rounding_type,
+        rounding_mode,
+        trailing_zero_display,
+    })
+}
+
+/// ### [16.1.5 GetBooleanOrStringNumberFormatOption ( options, property, stringValues, trueValue, falsyValue, fallback )](https://tc39.es/ecma402/#sec-getbooleanorstringnumberformatoption)
+///
+/// Reads the "useGrouping" option. Returns None if grouping is disabled.
+fn get_use_grouping_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<UseGrouping>> {
+    let property = BUILTIN_STRING_MEMORY.useGrouping;
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property.to_property_key(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(Some(UseGrouping::Auto));
+    }
+    // 3. If value is true, return trueValue.
+    if value == Value::Boolean(true) {
+        return Ok(Some(UseGrouping::Always));
+    }
+    // 4. If ToBoolean(value) is false, return falsyValue.
+    if !to_boolean(agent, value) {
+        return Ok(None);
+    }
+    // 5. Set value to ? ToString(value).
+    let value = to_string(agent, value.unbind(), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let value = value.bind(gc);
+    match value.as_str(agent) {
+        // 6. If value is "true" or "false", return fallback.
+        Some("true" | "false") => Ok(Some(UseGrouping::Auto)),
+        // 7. If stringValues does not contain value, throw a RangeError
+        //    exception.
+        // 8. Return value.
+        Some(string) => match UseGrouping::from_str(string) {
+            Some(use_grouping) => Ok(Some(use_grouping)),
+            None => Err(invalid_option_error(agent, property, value, gc)),
+        },
+        None => Err(invalid_option_error(agent, property, value, gc)),
+    }
+}
+
+/// ### [16.5.16 ToIntlMathematicalValue ( value )](https://tc39.es/ecma402/#sec-tointlmathematicalvalue)
+///
+/// The abstract operation ToIntlMathematicalValue takes argument value (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing an Intl mathematical value or a throw completion. It returns
+/// value converted to an Intl mathematical value, which is a mathematical
+/// value together with positive-infinity, negative-infinity, not-a-number,
+/// and negative-zero.
+///
+/// NOTE: String values are converted with ToNumber, so they are rounded to
+/// the precision of a Number.
+pub(crate) fn to_intl_mathematical_value<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IntlMathematicalValue> {
+    // 1. Let primValue be ? ToPrimitive(value, number).
+    // 2. If primValue is a BigInt, return ℝ(primValue).
+    // 3. If primValue is a String, then
+    //    a. Let str be primValue.
+    // 4. Else,
+    //    a. Let x be ? ToNumber(primValue).
+    let numeric = to_numeric(agent, value, gc)?;
+    Ok(numeric_to_intl_mathematical_value(agent, numeric))
+}
+
+pub(crate) fn numeric_to_intl_mathematical_value(
+    agent: &Agent,
+    numeric: Numeric,
+) -> IntlMathematicalValue {
+    if let Ok(bigint) = BigInt::try_from(numeric) {
+        let value = match bigint {
+            BigInt::SmallBigInt(x) => FixedDecimal::from(x.into_i64()),
+            BigInt::BigInt(x) => x.get(agent).data.to_string().parse().unwrap(),
+        };
+        return IntlMathematicalValue::Decimal(value);
+    }
+    let x = crate::ecmascript::Number::try_from(numeric)
+        .unwrap()
+        .into_f64(agent);
+    //    b. If x is -0𝔽, return negative-zero.
+    //    c. Let str be Number::toString(x, 10).
+    if x.is_nan() {
+        IntlMathematicalValue::NotANumber
+    } else if x == f64::INFINITY {
+        IntlMathematicalValue::PositiveInfinity
+    } else if x == f64::NEG_INFINITY {
+        IntlMathematicalValue::NegativeInfinity
+    } else {
+        IntlMathematicalValue::Decimal(
+            FixedDecimal::try_from_f64(x, FloatPrecision::Floating).unwrap(),
+        )
+    }
+}
+
+/// ### [20.2.1 Number.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-number.prototype.tolocalestring)
+/// ### [20.3.1 BigInt.prototype.toLocaleString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring)
+///
+/// Formats x, the this value of the method, with a new Intl.NumberFormat
+/// created from locales and options.
+pub(crate) fn numeric_to_locale_string<'gc>(
+    agent: &mut Agent,
+    x: Numeric,
+    locales: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, String<'gc>> {
+    // NOTE: ToIntlMathematicalValue(x) cannot call into user code, so it is
+    // performed first to avoid keeping x alive across the construction.
+    let x = numeric_to_intl_mathematical_value(agent, x);
+    // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales,
+    //    options »).
+    let number_format =
+        initialize_number_format(agent, locales, options, gc.reborrow()).unbind()?;
+    // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+    let result = number_format.format(x);
+    Ok(String::from_string(agent, result, gc.into_nogc()))
+}
+
+impl NumberFormatRecord {
+    /// ### [16.5.4 FormatNumericToString ( intlObject, x )](https://tc39.es/ecma402/#sec-formatnumberstring)
+    /// ### [16.5.5 PartitionNumberPattern ( numberFormat, x )](https://tc39.es/ecma402/#sec-partitionnumberpattern)
+    ///
+    /// NOTE: Percentages are formatted with a "%" suffix in all locales.
+    pub(crate) fn format(&self, x: IntlMathematicalValue) -> std::string::String {
+        let mut result = match x {
+            IntlMathematicalValue::NotANumber => "NaN".to_owned(),
+            IntlMathematicalValue::PositiveInfinity | IntlMathematicalValue::NegativeInfinity => {
+                let negative = matches!(x, IntlMathematicalValue::NegativeInfinity);
+                let sign = match self.sign_display {
+                    SignDisplay::Never => "",
+                    SignDisplay::Auto | SignDisplay::Negative if !negative => "",
+                    _ if negative => "-",
+                    _ => "+",
+                };
+                format!("{sign}∞")
+            }
+            IntlMathematicalValue::Decimal(mut x) => {
+                // a. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+                if self.style == NumberFormatStyle::Percent {
+                    x.multiply_pow10(2);
+                }
+                self.round(&mut x);
+                x.apply_sign_display(match self.sign_display {
+                    SignDisplay::Auto => FixedDecimalSignDisplay::Auto,
+                    SignDisplay::Never => FixedDecimalSignDisplay::Never,
+                    SignDisplay::Always => FixedDecimalSignDisplay::Always,
+                    SignDisplay::ExceptZero => FixedDecimalSignDisplay::ExceptZero,
+                    SignDisplay::Negative => FixedDecimalSignDisplay::Negative,
+                });
+                self.formatter.format_to_string(&x)
+            }
+        };
+        if self.style == NumberFormatStyle::Percent {
+            result.push('%');
+        }
+        result
+    }
+
+    /// Rounds and pads the number with the digit options of the
+    /// Intl.NumberFormat.
+    ///
+    /// NOTE: Rounding a FixedDecimal pads it with zeros down to the rounding
+    /// position, so the trailing zeros are trimmed before padding the number
+    /// to the minimum digits.
+    fn round(&self, x: &mut FixedDecimal) {
+        match self.rounding_type {
+            RoundingType::FractionDigits { minimum, maximum } => {
+                round_to_position(x, -(maximum as i16), self.rounding_mode);
+                x.trim_end();
+                let integer = x.nonzero_magnitude_end() >= 0;
+                if self.trailing_zero_display == TrailingZeroDisplay::Auto || !integer {
+                    x.pad_end(-(minimum as i16));
+                }
+            }
+            RoundingType::SignificantDigits { minimum, maximum } => {
+                let magnitude = x.nonzero_magnitude_start();
+                round_to_position(x, magnitude - maximum as i16 + 1, self.rounding_mode);
+                x.trim_end();
+                let integer = x.nonzero_magnitude_end() >= 0;
+                if self.trailing_zero_display == TrailingZeroDisplay::Auto || !integer {
+                    let magnitude = x.nonzero_magnitude_start();
+                    x.pad_end(magnitude - minimum as i16 + 1);
+                }
+            }
+        }
+        x.pad_start(self.minimum_integer_digits as i16);
+    }
+}
+
+fn round_to_position(x: &mut FixedDecimal, position: i16, mode: RoundingMode) {
+    match mode {
+        RoundingMode::Ceil => x.ceil(position),
+        RoundingMode::Floor => x.floor(position),
+        RoundingMode::Expand => x.expand(position),
+        RoundingMode::Trunc => x.trunc(position),
+        RoundingMode::HalfCeil => x.half_ceil(position),
+        RoundingMode::HalfFloor => x.half_floor(position),
+        RoundingMode::HalfExpand => x.half_expand(position),
+        RoundingMode::HalfTrunc => x.half_trunc(position),
+        RoundingMode::HalfEven => x.half_even(position),
+    }
+}
@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, Function, JsResult, Object, ProtoIntrinsics, Realm, String,
+        Value, builders::BuiltinFunctionBuilder, embedder_object_create_from_constructor,
+        is_available_locale, supported_locales,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{NumberFormat, initialize_number_format};
+
+pub(crate) struct NumberFormatConstructor;
+impl Builtin for NumberFormatConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.NumberFormat;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for NumberFormatConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::IntlNumberFormat;
+}
+
+struct NumberFormatSupportedLocalesOf;
+impl Builtin for NumberFormatSupportedLocalesOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.supportedLocalesOf;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatConstructor::supported_locales_of);
+}
+
+impl NumberFormatConstructor {
+    /// ### [16.1.1 Intl.NumberFormat ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-intl.numberformat)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object, else let newTarget be NewTarget.
+        let new_target = match new_target {
+            Some(new_target) => Function::try_from(new_target).unwrap(),
+            None => agent
+                .current_realm_record()
+                .intrinsics()
+                .intl_number_format()
+                .into(),
+        }
+        .scope(agent, gc.nogc());
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let record =
+            initialize_number_format(agent, locales.unbind(), options.unbind(), gc.reborrow())
+                .unbind()?;
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget,
+        //    "%Intl.NumberFormat.prototype%", « [[InitializedNumberFormat]],
+        //    [[Locale]], [[LocaleData]], [[NumberingSystem]], [[Style]],
+        //    [[Unit]], [[UnitDisplay]], [[Currency]], [[CurrencyDisplay]],
+        //    [[CurrencySign]], [[MinimumIntegerDigits]],
+        //    [[MinimumFractionDigits]], [[MaximumFractionDigits]],
+        //    [[MinimumSignificantDigits]], [[MaximumSignificantDigits]],
+        //    [[RoundingType]], [[Notation]], [[CompactDisplay]],
+        //    [[UseGrouping]], [[SignDisplay]], [[RoundingIncrement]],
+        //    [[RoundingMode]], [[ComputedRoundingPriority]],
+        //    [[TrailingZeroDisplay]], [[BoundFormat]] »).
+        // NOTE: The object is created after the options are processed, as
+        // its native data is the result of processing them.
+        let number_format = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::IntlNumberFormat,
+            NumberFormat::SLOT_COUNT,
+            record,
+            gc,
+        )?;
+        // 4. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        //    a. Let this be the this value.
+        //    b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
+        // 5. Return numberFormat.
+        Ok(NumberFormat::from_embedder_object(number_format).into())
+    }
+
+    /// ### [16.2.2 Intl.NumberFormat.supportedLocalesOf ( locales \[ , options \] )](https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof)
+    fn supported_locales_of<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let locales = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. Let availableLocales be %Intl.NumberFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales,
+        //    options).
+        supported_locales(
+            agent,
+            locales.unbind(),
+            options.unbind(),
+            is_available_locale,
+            gc,
+        )
+        .map(|locales| locales.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let number_format_prototype = intrinsics.intl_number_format_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<NumberFormatConstructor>(agent, realm)
+            .with_property_capacity(2)
+            .with_prototype_property(number_format_prototype.into())
+            .with_builtin_function_property::<NumberFormatSupportedLocalesOf>()
+            .build();
+    }
+}
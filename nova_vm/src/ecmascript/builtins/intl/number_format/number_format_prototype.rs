@@ -0,0 +1,254 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinFunctionArgs,
+        BuiltinGetter, ExceptionType, JsResult, Number, OrdinaryObject, PropertyKey, Realm, String,
+        StringOption, Value, bound_function_create, builders::OrdinaryObjectBuilder,
+        create_builtin_function,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccessMut, ObjectEntry, WellKnownSymbols},
+};
+
+use super::{NumberFormat, RoundingType, to_intl_mathematical_value};
+
+pub(crate) struct NumberFormatPrototype;
+
+struct NumberFormatPrototypeGetFormat;
+impl Builtin for NumberFormatPrototypeGetFormat {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_format;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.format.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatPrototype::get_format);
+}
+impl BuiltinGetter for NumberFormatPrototypeGetFormat {}
+struct NumberFormatPrototypeResolvedOptions;
+impl Builtin for NumberFormatPrototypeResolvedOptions {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.resolvedOptions;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(NumberFormatPrototype::resolved_options);
+}
+
+impl NumberFormatPrototype {
+    /// ### [16.3.3 get Intl.NumberFormat.prototype.format](https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format)
+    fn get_format<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let nf be the this value.
+        // 2. If the implementation supports the normative optional
+        //    constructor mode of 4.3 Note 1, then
+        //    a. Set nf to ? UnwrapNumberFormat(nf).
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = require_number_format(agent, this_value, gc.nogc()).unbind()?;
+        // 4. If nf.[[BoundFormat]] is undefined, then
+        if let Some(bound_format) = nf.bound_format(agent) {
+            // 5. Return nf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+        // a. Let F be a new built-in function object as defined in Number
+        //    Format Functions (16.5.2).
+        // b. Set F.[[NumberFormat]] to nf.
+        // c. Set nf.[[BoundFormat]] to F.
+        // NOTE: F is a bound function with nf as its bound this value.
+        let target = create_builtin_function(
+            agent,
+            Behaviour::Regular(number_format_function),
+            BuiltinFunctionArgs::new(1, ""),
+            gc.nogc(),
+        );
+        let f = bound_function_create(agent, target.unbind().into(), nf.into(), &[], gc)?;
+        f.get_mut(agent).length = 1;
+        nf.set_bound_format(agent, f.into());
+        Ok(f.into())
+    }
+
+    /// ### [16.3.7 Intl.NumberFormat.prototype.resolvedOptions ( )](https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions)
+    fn resolved_options<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let nf be the this value.
+        // 3. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = require_number_format(agent, this_value, gc)?;
+        let record = nf.data(agent);
+        let locale = record.locale.to_string();
+        let style = record.style;
+        let minimum_integer_digits = record.minimum_integer_digits;
+        let rounding_type = record.rounding_type;
+        let use_grouping = record.use_grouping;
+        let sign_display = record.sign_display;
+        let rounding_mode = record.rounding_mode;
+        let trailing_zero_display = record.trailing_zero_display;
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 27, except the header row, in table
+        //    order, do
+        //    a. Let p be the Property value of the current row.
+        //    b. If p is "useGrouping", let v be nf.[[UseGrouping]]; else let
+        //       v be the value of nf's internal slot whose name is the
+        //       Internal Slot value of the current row.
+        //    c. If v is not undefined, then
+        //       i. If there is a Conversion value in the current row, then
+        //          1. Assert: The Conversion value of the current row is
+        //             number.
+        //          2. Set v to 𝔽(v).
+        //       ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut entries = vec![
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.locale.into(),
+                String::from_string(agent, locale, gc).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.numberingSystem.into(),
+                BUILTIN_STRING_MEMORY.latn.into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.style.into(),
+                String::from_static_str(agent, style.as_str(), gc).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.minimumIntegerDigits.into(),
+                Number::from(minimum_integer_digits).into(),
+            ),
+        ];
+        let (minimum_key, maximum_key, minimum, maximum) = match rounding_type {
+            RoundingType::FractionDigits { minimum, maximum } => (
+                BUILTIN_STRING_MEMORY.minimumFractionDigits,
+                BUILTIN_STRING_MEMORY.maximumFractionDigits,
+                minimum,
+                maximum,
+            ),
+            RoundingType::SignificantDigits { minimum, maximum } => (
+                BUILTIN_STRING_MEMORY.minimumSignificantDigits,
+                BUILTIN_STRING_MEMORY.maximumSignificantDigits,
+                minimum,
+                maximum,
+            ),
+        };
+        entries.push(ObjectEntry::new_data_entry(
+            minimum_key.into(),
+            Number::from(minimum).into(),
+        ));
+        entries.push(ObjectEntry::new_data_entry(
+            maximum_key.into(),
+            Number::from(maximum).into(),
+        ));
+        let use_grouping = match use_grouping {
+            Some(use_grouping) => String::from_static_str(agent, use_grouping.as_str(), gc).into(),
+            None => false.into(),
+        };
+        entries.extend([
+            ObjectEntry::new_data_entry(BUILTIN_STRING_MEMORY.useGrouping.into(), use_grouping),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.notation.into(),
+                BUILTIN_STRING_MEMORY.standard.into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.signDisplay.into(),
+                String::from_static_str(agent, sign_display.as_str(), gc).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.roundingIncrement.into(),
+                Number::from(1).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.roundingMode.into(),
+                String::from_static_str(agent, rounding_mode.as_str(), gc).into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.roundingPriority.into(),
+                BUILTIN_STRING_MEMORY.auto.into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.trailingZeroDisplay.into(),
+                String::from_static_str(agent, trailing_zero_display.as_str(), gc).into(),
+            ),
+        ]);
+        let options = OrdinaryObject::create_object(
+            agent,
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .object_prototype()
+                    .into(),
+            ),
+            &entries,
+        )
+        .expect("Should perform GC here");
+        // 6. Return options.
+        Ok(options.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.intl_number_format_prototype();
+        let number_format_constructor = intrinsics.intl_number_format();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            .with_constructor_property(number_format_constructor)
+            .with_builtin_function_getter_property::<NumberFormatPrototypeGetFormat>()
+            .with_builtin_function_property::<NumberFormatPrototypeResolvedOptions>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Intl_NumberFormat.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [16.5.2 Number Format Functions](https://tc39.es/ecma402/#sec-number-format-functions)
+///
+/// A Number format function is an anonymous built-in function that has a
+/// [[NumberFormat]] internal slot. Here the Intl.NumberFormat is the this
+/// value of the function, bound by the format getter.
+fn number_format_function<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let value = arguments.get(0).bind(gc.nogc());
+    // 1. Let nf be F.[[NumberFormat]].
+    // 2. Assert: nf is an Object and nf has an
+    //    [[InitializedNumberFormat]] internal slot.
+    let nf = require_number_format(agent, this_value, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 3. If value is not provided, let value be undefined.
+    // 4. Let x be ? ToIntlMathematicalValue(value).
+    let x = to_intl_mathematical_value(agent, value.unbind(), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // 5. Return FormatNumeric(nf, x).
+    let result = nf.get(agent).data(agent).format(x);
+    Ok(String::from_string(agent, result, gc).into())
+}
+
+pub(crate) fn require_number_format<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, NumberFormat<'a>> {
+    NumberFormat::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an Intl.NumberFormat",
+            gc,
+        )
+    })
+}
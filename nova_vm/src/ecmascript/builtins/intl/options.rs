@@ -0,0 +1,223 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ExceptionType, JsResult, Object, OrdinaryObject, String, Value, get, to_boolean,
+        to_number, to_object, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+/// A string option with a fixed list of allowed values.
+pub(crate) trait StringOption: Sized + Copy + PartialEq + 'static {
+    /// The allowed values of the option and their string identifiers.
+    const VALUES: &'static [(&'static str, Self)];
+
+    fn from_str(value: &str) -> Option<Self> {
+        Self::VALUES
+            .iter()
+            .find(|(identifier, _)| *identifier == value)
+            .map(|(_, option)| *option)
+    }
+
+    fn as_str(self) -> &'static str {
+        Self::VALUES
+            .iter()
+            .find(|(_, option)| *option == self)
+            .map(|(identifier, _)| *identifier)
+            .unwrap()
+    }
+}
+
+/// Defines an enum implementing [`StringOption`] with the given string
+/// identifiers for its variants.
+macro_rules! string_option {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident => $value:literal),+ $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub(crate) enum $name {
+            $($variant),+
+        }
+
+        impl $crate::ecmascript::StringOption for $name {
+            const VALUES: &'static [(&'static str, Self)] = &[$(($value, Self::$variant)),+];
+        }
+
+        $crate::engine::trivially_bindable!($name);
+    };
+}
+pub(crate) use string_option;
+
+/// ### [9.2.12 CoerceOptionsToObject ( options )](https://tc39.es/ecma402/#sec-coerceoptionstoobject)
+///
+/// The abstract operation CoerceOptionsToObject takes argument options (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing an Object or a throw completion. It coerces options into an
+/// Object suitable for use with GetOption, defaulting to an empty Object.
+pub(crate) fn coerce_options_to_object<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Object<'gc>> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(OrdinaryObject::create_object(agent, None, &[])
+            .expect("Should perform GC here")
+            .into());
+    }
+    // 2. Return ? ToObject(options).
+    to_object(agent, options, gc)
+}
+
+/// ### [9.2.13 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type string and a non-empty list of values: the option is
+/// converted to a String and must be one of the identifiers of `T`, otherwise
+/// a RangeError is thrown. Returns None if the option is undefined.
+pub(crate) fn get_string_option<'gc, T: StringOption>(
+    agent: &mut Agent,
+    options: Object,
+    property: String<'static>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<T>> {
+    let Some(value) = get_string_option_value(agent, options, property, gc.reborrow()).unbind()?
+    else {
+        return Ok(None);
+    };
+    let gc = gc.into_nogc();
+    let value = value.bind(gc);
+    // 7. If values is not empty and values does not contain value, throw a
+    //    RangeError exception.
+    match value.as_str(agent).and_then(T::from_str) {
+        Some(option) => Ok(Some(option)),
+        None => Err(invalid_option_error(agent, property, value, gc)),
+    }
+}
+
+/// ### [9.2.13 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type string and an empty list of values. Returns None if
+/// the option is undefined.
+pub(crate) fn get_string_option_value<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: String<'static>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<String<'gc>>> {
+    let options = options.bind(gc.nogc());
+    // 1. Let value be ? Get(options, property).
+    let value = get(
+        agent,
+        options.unbind(),
+        property.to_property_key(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // a. If default is required, throw a RangeError exception.
+        // b. Return default.
+        return Ok(None);
+    }
+    // 4. Else,
+    //    a. Assert: type is string.
+    //    b. Set value to ? ToString(value).
+    to_string(agent, value.unbind(), gc).map(Some)
+}
+
+/// ### [9.2.13 GetOption ( options, property, type, values, default )](https://tc39.es/ecma402/#sec-getoption)
+///
+/// GetOption with type boolean. Returns None if the option is undefined.
+pub(crate) fn get_boolean_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: String<'static>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<bool>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property.to_property_key(), gc)?;
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // b. Return default.
+        return Ok(None);
+    }
+    // 3. If type is boolean, then
+    //    a. Set value to ToBoolean(value).
+    Ok(Some(to_boolean(agent, value)))
+}
+
+/// ### [9.2.15 DefaultNumberOption ( value, minimum, maximum, fallback )](https://tc39.es/ecma402/#sec-defaultnumberoption)
+///
+/// The abstract operation DefaultNumberOption takes arguments value (an
+/// ECMAScript language value), minimum (an integer), maximum (an integer), and
+/// fallback (an integer or undefined) and returns either a normal completion
+/// containing either an integer or undefined, or a throw completion. It
+/// converts value to an integer, checks whether it is in the allowed range,
+/// and fills in a fallback value if necessary.
+pub(crate) fn default_number_option<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    minimum: u8,
+    maximum: u8,
+    property: String<'static>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u8>> {
+    // 1. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    // 2. Set value to ? ToNumber(value).
+    let value = to_number(agent, value, gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 3. If value is not finite or ℝ(value) < minimum or ℝ(value) > maximum,
+    //    throw a RangeError exception.
+    if !value.is_finite() || value < minimum as f64 || value > maximum as f64 {
+        let name = property.to_string_lossy(agent);
+        let message = format!("{name} value is out of range");
+        return Err(agent.throw_exception(ExceptionType::RangeError, message, gc.into_nogc()));
+    }
+    // 4. Return floor(ℝ(value)).
+    Ok(Some(value.floor() as u8))
+}
+
+/// ### [9.2.14 GetNumberOption ( options, property, minimum, maximum, fallback )](https://tc39.es/ecma402/#sec-getnumberoption)
+///
+/// The abstract operation GetNumberOption takes arguments options (an
+/// Object), property (a String), minimum (an integer), maximum (an integer),
+/// and fallback (an integer or undefined) and returns either a normal
+/// completion containing either an integer or undefined, or a throw
+/// completion. It extracts the value of the specified property of options,
+/// converts it to an integer, checks whether it is in the allowed range, and
+/// fills in a fallback value if necessary.
+pub(crate) fn get_number_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    property: String<'static>,
+    minimum: u8,
+    maximum: u8,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<u8>> {
+    // 1. Let value be ? Get(options, property).
+    let value = get(agent, options, property.to_property_key(), gc.reborrow()).unbind()?;
+    // 2. Return ? DefaultNumberOption(value, minimum, maximum, fallback).
+    default_number_option(agent, value, minimum, maximum, property, gc)
+}
+
+pub(crate) fn invalid_option_error<'gc>(
+    agent: &mut Agent,
+    property: String<'static>,
+    value: String,
+    gc: NoGcScope<'gc, '_>,
+) -> crate::ecmascript::JsError<'gc> {
+    let message = format!(
+        "Value {} out of range for option {}",
+        value.to_string_lossy(agent),
+        property.to_string_lossy(agent)
+    );
+    agent.throw_exception(ExceptionType::RangeError, message, gc)
+}
@@ -13,7 +13,7 @@ use crate::{
         BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsResult, Map,
         MapHeapData, MapPrototypeSet, Object, PropertyKey, ProtoIntrinsics, Realm, String,
         TryError, TryGetResult, Value, builders::BuiltinFunctionBuilder, call_function,
-        canonicalize_keyed_collection_key, create_array_from_list, get, get_iterator, get_method,
+        canonicalize_keyed_collection_key, create_array_from_list, get, get_iterator,
         group_by_collection, handle_try_get_result, if_abrupt_close_iterator, is_callable,
         iterator_close_with_error, iterator_step_value, ordinary_create_from_constructor,
         same_value, throw_not_callable, try_get,
@@ -261,123 +261,90 @@ pub(crate) fn add_entries_from_iterable_map_constructor<'a>(
     target: Map,
     iterable: Value,
     adder: Function,
-    mut gc: GcScope<'a, '_>,
+    gc: GcScope<'a, '_>,
 ) -> JsResult<'a, Map<'a>> {
     let nogc = gc.nogc();
-    let mut target = target.bind(nogc);
-    let mut iterable = iterable.bind(nogc);
-    let mut adder = adder.bind(nogc);
+    let target = target.bind(nogc);
+    let iterable = iterable.bind(nogc);
+    let adder = adder.bind(nogc);
     if let Function::BuiltinFunction(bf) = adder
         && bf.get(agent).behaviour == MapPrototypeSet::BEHAVIOUR
+        && let Value::Array(arr_iterable) = iterable
+        && arr_iterable.is_trivial(agent)
+        && arr_iterable.is_trivially_iterable(agent, nogc)
     {
-        // Normal Map.prototype.set
-        if let Value::Array(arr_iterable) = iterable {
-            let scoped_target = target.scope(agent, nogc);
-            let scoped_iterable = arr_iterable.scope(agent, nogc);
-            let scoped_adder = bf.scope(agent, nogc);
-            let using_iterator = get_method(
-                agent,
-                arr_iterable.unbind().into(),
-                WellKnownSymbols::Iterator.into(),
-                gc.reborrow(),
-            )
-            .unbind()?
-            .bind(gc.nogc());
-            target = scoped_target.get(agent).bind(gc.nogc());
-            if using_iterator
-                == Some(
-                    agent
-                        .current_realm_record()
-                        .intrinsics()
-                        .array_prototype_values()
-                        .into(),
-                )
-            {
-                let arr_iterable = scoped_iterable.get(agent).bind(gc.nogc());
-                let Heap {
-                    elements,
-                    arrays,
-                    bigints,
-                    numbers,
-                    strings,
-                    maps,
-                    ..
-                } = &mut agent.heap;
-                let array_heap = ArrayHeap::new(elements, arrays);
+        // Normal Map.prototype.set and an Array iterated using the normal
+        // Array iterator of this realm.
+        let Heap {
+            elements,
+            arrays,
+            bigints,
+            numbers,
+            strings,
+            maps,
+            ..
+        } = &mut agent.heap;
+        let array_heap = ArrayHeap::new(elements, arrays);
 
-                let arr_elements = arr_iterable.get_elements(&array_heap);
-                // Iterable uses the normal Array iterator of this realm.
-                if arr_elements.is_empty() {
-                    // Array iterator does not iterate empty arrays.
-                    return Ok(scoped_target.get(agent).bind(gc.into_nogc()));
+        let arr_elements = arr_iterable.get_elements(&array_heap);
+        if arr_elements
+            .get_storage(&array_heap)
+            .values
+            .iter()
+            .all(|entry| {
+                if let Some(Value::Array(entry)) = *entry {
+                    entry.get_elements(&array_heap).len() == 2
+                        && entry.is_trivial(&array_heap)
+                        && entry.is_dense(&array_heap)
+                } else {
+                    false
                 }
-                if arr_elements.is_trivial(&array_heap)
-                    && arr_elements
-                        .get_storage(&array_heap)
-                        .values
-                        .iter()
-                        .all(|entry| {
-                            if let Some(Value::Array(entry)) = *entry {
-                                entry.get_elements(&array_heap).len() == 2
-                                    && entry.is_trivial(&array_heap)
-                                    && entry.is_dense(&array_heap)
-                            } else {
-                                false
-                            }
-                        })
-                {
-                    // Trivial, dense array of trivial, dense arrays of two elements.
-                    let gc = gc.nogc();
-                    let length = arr_elements.len();
-                    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
-                    let (map_data, keys, values) = target.get_map_data_mut(maps, &primitive_heap);
+            })
+        {
+            // Trivial, dense array of trivial, dense arrays of two elements.
+            let length = arr_elements.len() as usize;
+            let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+            let (map_data, keys, values) = target.get_map_data_mut(maps, &primitive_heap);
 
-                    let length = length as usize;
-                    keys.reserve(length);
-                    values.reserve(length);
-                    // Note: The Map is empty at this point, we don't need the hasher function.
-                    assert!(map_data.is_empty());
-                    map_data.reserve(length, |_| 0);
-                    let hasher = |value: Value| {
-                        let mut hasher = AHasher::default();
-                        value.hash(&primitive_heap, &mut hasher);
-                        hasher.finish()
-                    };
-                    for entry in arr_iterable.as_slice(&array_heap).iter() {
-                        let Some(Value::Array(entry)) = *entry else {
-                            unreachable!()
-                        };
-                        let slice = entry.as_slice(&array_heap);
-                        let key =
-                            canonicalize_keyed_collection_key(numbers, slice[0].unwrap().bind(gc));
-                        let key_hash = hasher(key);
-                        let value = slice[1].unwrap().bind(gc);
-                        let next_index = keys.len() as u32;
-                        let entry = map_data.entry(
-                            key_hash,
-                            |hash_equal_index| keys[*hash_equal_index as usize].unwrap() == key,
-                            |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
-                        );
-                        match entry {
-                            hashbrown::hash_table::Entry::Occupied(occupied) => {
-                                // We have duplicates in the array. Latter
-                                // ones overwrite earlier ones.
-                                let index = *occupied.get();
-                                values[index as usize] = Some(value.unbind());
-                            }
-                            hashbrown::hash_table::Entry::Vacant(vacant) => {
-                                vacant.insert(next_index);
-                                keys.push(Some(key.unbind()));
-                                values.push(Some(value.unbind()));
-                            }
-                        }
+            keys.reserve(length);
+            values.reserve(length);
+            // Note: The Map is empty at this point, we don't need the hasher function.
+            assert!(map_data.is_empty());
+            map_data.reserve(length, |_| 0);
+            let hasher = |value: Value| {
+                let mut hasher = AHasher::default();
+                value.hash(&primitive_heap, &mut hasher);
+                hasher.finish()
+            };
+            for entry in arr_iterable.as_slice(&array_heap).iter() {
+                let Some(Value::Array(entry)) = *entry else {
+                    unreachable!()
+                };
+                let slice = entry.as_slice(&array_heap);
+                let key = canonicalize_keyed_collection_key(numbers, slice[0].unwrap().bind(nogc));
+                let key_hash = hasher(key);
+                let value = slice[1].unwrap().bind(nogc);
+                let next_index = keys.len() as u32;
+                let entry = map_data.entry(
+                    key_hash,
+                    |hash_equal_index| keys[*hash_equal_index as usize].unwrap() == key,
+                    |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
+                );
+                match entry {
+                    hashbrown::hash_table::Entry::Occupied(occupied) => {
+                        // We have duplicates in the array. Latter
+                        // ones overwrite earlier ones.
+                        let index = *occupied.get();
+                        values[index as usize] = Some(value.unbind());
+                    }
+                    hashbrown::hash_table::Entry::Vacant(vacant) => {
+                        vacant.insert(next_index);
+                        keys.push(Some(key.unbind()));
+                        values.push(Some(value.unbind()));
                     }
-                    return Ok(scoped_target.get(agent));
                 }
             }
-            let gc = gc.nogc();
-            iterable = scoped_iterable.get(agent).bind(gc).into();
-            adder = scoped_adder.get(agent).bind(gc).into();
+            return Ok(target.unbind().bind(gc.into_nogc()));
         }
     }
 
@@ -5,9 +5,9 @@
 use crate::{
     ecmascript::{
         Agent, CollectionIteratorKind, InternalMethods, InternalSlots, Map, OrdinaryObject,
-        ProtoIntrinsics, object_handle,
+        ProtoIntrinsics, Value, object_handle,
     },
-    engine::{Bindable, bindable_handle},
+    engine::{Bindable, NoGcScope, bindable_handle},
     heap::{
         ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
         HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
@@ -43,6 +43,47 @@ impl MapIterator<'_> {
             kind,
         })
     }
+
+    pub(crate) fn kind(self, agent: &Agent) -> CollectionIteratorKind {
+        self.get(agent).kind
+    }
+
+    /// Step the iterator to the next entry of its Map, returning the entry's
+    /// key and value or None if the iterator has completed.
+    ///
+    /// This performs the entry iteration steps of
+    /// [CreateMapIterator](https://tc39.es/ecma262/#sec-createmapiterator);
+    /// the result is selected based on the iterator's kind by the caller.
+    pub(crate) fn next_entry<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<(Value<'gc>, Value<'gc>)> {
+        // NOTE: We set `map` to None when the generator in the spec text has returned.
+        let map = self.get(agent).map?.bind(gc);
+
+        // a. Let entries be map.[[MapData]].
+        // c. Let numEntries be the number of elements in entries.
+        // d. Repeat, while index < numEntries,
+        while self.get(agent).next_index < map.entries_len(agent) as usize {
+            // i. Let e be entries[index].
+            // ii. Set index to index + 1.
+            let index = self.get(agent).next_index;
+            self.get_mut(agent).next_index += 1;
+
+            // iii. If e.[[Key]] is not EMPTY, then
+            let (keys, values) = map.get_entries(agent);
+            if let Some(key) = keys[index] {
+                return Some((key, values[index].unwrap()));
+            }
+        }
+
+        debug_assert_eq!(self.get(agent).next_index, map.entries_len(agent) as usize);
+
+        // e. Return undefined.
+        self.get_mut(agent).map = None;
+        None
+    }
 }
 
 impl<'a> InternalSlots<'a> for MapIterator<'a> {
@@ -4,12 +4,12 @@
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, CollectionIteratorKind,
-        ExceptionType, JsResult, Realm, String, Value, builders::OrdinaryObjectBuilder,
-        create_array_from_list, create_iter_result_object,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic,
+        CollectionIteratorKind, ExceptionType, JsResult, Realm, String, Value,
+        builders::OrdinaryObjectBuilder, create_array_from_list, create_iter_result_object,
     },
     engine::{Bindable, GcScope},
-    heap::{ArenaAccess, ArenaAccessMut, WellKnownSymbols},
+    heap::{IntrinsicFunctionIndexes, WellKnownSymbols},
 };
 
 pub(crate) struct MapIteratorPrototype;
@@ -22,6 +22,9 @@ impl Builtin for MapIteratorPrototypeNext {
 
     const BEHAVIOUR: Behaviour = Behaviour::Regular(MapIteratorPrototype::next);
 }
+impl BuiltinIntrinsic for MapIteratorPrototypeNext {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::MapIteratorPrototypeNext;
+}
 
 impl MapIteratorPrototype {
     fn next<'gc>(
@@ -43,67 +46,26 @@ impl MapIteratorPrototype {
         };
 
         // 24.1.5.1 CreateMapIterator ( map, kind ), step 2
-        // NOTE: We set `map` to None when the generator in the spec text has returned.
-        let Some(map) = iterator.get(agent).map else {
-            return create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
-                .map(|o| o.into());
+        let Some((key, value)) = iterator.next_entry(agent, gc) else {
+            return create_iter_result_object(agent, Value::Undefined, true, gc).map(|o| o.into());
+        };
+        let result = match iterator.kind(agent) {
+            // 1. If kind is KEY, then
+            //   a. Let result be e.[[Key]].
+            CollectionIteratorKind::Key => key,
+            // 2. If kind is VALUE, then
+            //   a. Let result be e.[[Value]].
+            CollectionIteratorKind::Value => value,
+            // 3. Else,
+            //   a. Assert: kind is KEY+VALUE.
+            //   b. Let result be CreateArrayFromList(« e.[[Key]], e.[[Value]] »).
+            CollectionIteratorKind::KeyAndValue => {
+                create_array_from_list(agent, &[key, value], gc).into()
+            }
         };
 
-        // a. Let entries be map.[[MapData]].
-        // c. Let numEntries be the number of elements in entries.
-        // d. Repeat, while index < numEntries,
-        while iterator.get(agent).next_index < map.entries_len(agent) as usize {
-            // i. Let e be entries[index].
-            // ii. Set index to index + 1.
-            let index = iterator.get(agent).next_index;
-            iterator.get_mut(agent).next_index += 1;
-
-            let (keys, values) = map.get_entries(agent);
-            let result = match iterator.get(agent).kind {
-                CollectionIteratorKind::Key => {
-                    // iii. If e.[[Key]] is not EMPTY, then
-                    //   1. If kind is KEY, then
-                    //     a. Let result be e.[[Key]].
-                    let Some(key) = keys[index] else {
-                        continue;
-                    };
-                    key
-                }
-                CollectionIteratorKind::Value => {
-                    // iii. If e.[[Key]] is not EMPTY, then
-                    //   2. If kind is VALUE, then
-                    //     a. Let result be e.[[Value]].
-                    let Some(value) = values[index] else {
-                        continue;
-                    };
-                    value
-                }
-                CollectionIteratorKind::KeyAndValue => {
-                    // iii. If e.[[Key]] is not EMPTY, then
-                    //   3. Else,
-                    //     a. Assert: kind is KEY+VALUE.
-                    //     b. Let result be CreateArrayFromList(« e.[[Key]], e.[[Value]] »).
-                    let Some(key) = keys[index] else {
-                        continue;
-                    };
-                    let value = values[index].unwrap();
-                    create_array_from_list(agent, &[key, value], gc).into()
-                }
-            };
-
-            // 4. Perform ? GeneratorYield(CreateIteratorResultObject(result, false)).
-            return create_iter_result_object(agent, result, false, gc.into_nogc())
-                .map(|o| o.into());
-        }
-
-        debug_assert_eq!(
-            iterator.get(agent).next_index,
-            map.entries_len(agent) as usize
-        );
-
-        // e. Return undefined.
-        iterator.get_mut(agent).map = None;
-        create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc()).map(|o| o.into())
+        // 4. Perform ? GeneratorYield(CreateIteratorResultObject(result, false)).
+        create_iter_result_object(agent, result, false, gc).map(|o| o.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -114,7 +76,7 @@ impl MapIteratorPrototype {
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
             .with_property_capacity(2)
             .with_prototype(iterator_prototype)
-            .with_builtin_function_property::<MapIteratorPrototypeNext>()
+            .with_builtin_intrinsic_function_property::<MapIteratorPrototypeNext>()
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbols::ToStringTag.into())
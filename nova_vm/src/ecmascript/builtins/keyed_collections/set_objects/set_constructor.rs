@@ -10,14 +10,15 @@ use crate::{
     ecmascript::{
         Agent, ArgumentsList, ArrayHeap, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
         BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsResult, Object,
-        PropertyKey, ProtoIntrinsics, Realm, Set, String, Value, builders::BuiltinFunctionBuilder,
-        call_function, canonicalize_keyed_collection_key, get, get_iterator,
-        if_abrupt_close_iterator, is_callable, iterator_step_value,
+        PropertyKey, ProtoIntrinsics, Realm, Set, SetPrototypeAdd, String, Value,
+        builders::BuiltinFunctionBuilder, call_function, canonicalize_keyed_collection_key, get,
+        get_iterator, if_abrupt_close_iterator, is_callable, iterator_step_value,
         ordinary_create_from_constructor, throw_not_callable,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{
-        DirectArenaAccessSoAMut, Heap, IntrinsicConstructorIndexes, PrimitiveHeap, WellKnownSymbols,
+        ArenaAccess, DirectArenaAccessSoAMut, Heap, IntrinsicConstructorIndexes, PrimitiveHeap,
+        WellKnownSymbols,
     },
 };
 
@@ -107,8 +108,12 @@ impl SetConstructor {
                 gc.into_nogc(),
             ));
         };
+        let is_set_prototype_add = matches!(
+            adder,
+            Function::BuiltinFunction(bf) if bf.get(agent).behaviour == SetPrototypeAdd::BEHAVIOUR
+        );
         let adder = adder.scope(agent, gc.nogc());
-        if let Value::Array(iterable) = scoped_iterable.get(agent) {
+        if is_set_prototype_add && let Value::Array(iterable) = scoped_iterable.get(agent) {
             let iterable = iterable.bind(gc.nogc());
             if iterable.is_trivial(agent) && iterable.is_trivially_iterable(agent, gc.nogc()) {
                 // Accessorless, holeless array with standard Array values
@@ -22,7 +22,7 @@ use crate::{
 
 pub(crate) struct SetPrototype;
 
-struct SetPrototypeAdd;
+pub(crate) struct SetPrototypeAdd;
 impl Builtin for SetPrototypeAdd {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
     const LENGTH: u8 = 1;
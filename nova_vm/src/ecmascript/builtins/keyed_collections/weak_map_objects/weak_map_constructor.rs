@@ -6,10 +6,11 @@ use std::hint::unreachable_unchecked;
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
         BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
-        Realm, String, Value, add_entries_from_iterable, builders::BuiltinFunctionBuilder, get,
-        is_callable, ordinary_create_from_constructor,
+        Realm, String, Value, WeakMap, add_entries_from_iterable, builders::BuiltinFunctionBuilder,
+        can_be_held_weakly, get, is_callable, ordinary_create_from_constructor,
+        throw_not_weak_key_error,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::IntrinsicConstructorIndexes,
@@ -95,6 +96,33 @@ impl WeakMapConstructor {
                 gc.into_nogc(),
             ));
         };
+        if WeakMap::is_weak_map_prototype_set(agent, adder)
+            && let Value::Array(array) = iterable.get(agent)
+            && array.is_trivial(agent)
+            && array.is_trivially_iterable(agent, gc.nogc())
+            && let Some(entries) = trivial_weak_map_entries(agent, array)
+        {
+            // Adder function is the normal WeakMap.prototype.set and the
+            // Array of entries is trivially iterable: iterating it and calling
+            // the adder cannot be observed, so we can add the entries
+            // directly.
+            let gc = gc.into_nogc();
+            let map = scoped_map.get(agent).bind(gc);
+            let mut weak_entries = Vec::with_capacity(entries.len());
+            for (key, value) in entries {
+                let key = key.bind(gc);
+                // CanBeHeldWeakly(key) is false: WeakMap.prototype.set throws
+                // a TypeError exception.
+                let Some(weak_key) = can_be_held_weakly(agent, key) else {
+                    return Err(throw_not_weak_key_error(agent, key, gc));
+                };
+                weak_entries.push((weak_key, value.bind(gc)));
+            }
+            for (key, value) in weak_entries {
+                map.set(agent, key, value);
+            }
+            return Ok(map.into());
+        }
         // 7. Return ? AddEntriesFromIterable(map, iterable, adder).
         add_entries_from_iterable(
             agent,
@@ -118,3 +146,25 @@ impl WeakMapConstructor {
             .build();
     }
 }
+
+/// Returns the key-value pairs of an Array of entries if every entry is a
+/// trivial, dense Array of two elements, ie. reading the key and value of an
+/// entry cannot be observed.
+fn trivial_weak_map_entries<'a>(
+    agent: &Agent,
+    array: Array<'a>,
+) -> Option<Vec<(Value<'a>, Value<'a>)>> {
+    array
+        .as_slice(agent)
+        .iter()
+        .map(|entry| match *entry {
+            Some(Value::Array(entry))
+                if entry.len(agent) == 2 && entry.is_trivial(agent) && entry.is_dense(agent) =>
+            {
+                let slice = entry.as_slice(agent);
+                Some((slice[0].unwrap(), slice[1].unwrap()))
+            }
+            _ => None,
+        })
+        .collect()
+}
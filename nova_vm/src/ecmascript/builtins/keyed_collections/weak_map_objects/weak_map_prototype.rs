@@ -121,7 +121,7 @@ impl WeakMapPrototype {
     }
 
     /// ### [24.3.3.5 WeakMap.prototype.set ( key, value )](https://tc39.es/ecma262/#sec-weakmap.prototype.set)
-    fn set<'gc>(
+    pub(crate) fn set<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
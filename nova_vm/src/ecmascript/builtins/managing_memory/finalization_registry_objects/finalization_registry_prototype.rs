@@ -12,6 +12,9 @@ use crate::{
     heap::WellKnownSymbols,
 };
 
+#[cfg(feature = "proposal-cleanup-some")]
+use crate::ecmascript::{cleanup_finalization_registry, is_callable};
+
 pub(crate) struct FinalizationRegistryPrototype;
 
 struct FinalizationRegistryPrototypeRegister;
@@ -26,6 +29,14 @@ impl Builtin for FinalizationRegistryPrototypeUnregister {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(FinalizationRegistryPrototype::unregister);
 }
+#[cfg(feature = "proposal-cleanup-some")]
+struct FinalizationRegistryPrototypeCleanupSome;
+#[cfg(feature = "proposal-cleanup-some")]
+impl Builtin for FinalizationRegistryPrototypeCleanupSome {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.cleanupSome;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(FinalizationRegistryPrototype::cleanup_some);
+}
 
 impl FinalizationRegistryPrototype {
     /// ### [26.2.3.2 FinalizationRegistry.prototype.register ( target, heldValue \[ , unregisterToken \] )](https://tc39.es/ecma262/#sec-finalization-registry.prototype)
@@ -129,16 +140,70 @@ impl FinalizationRegistryPrototype {
             .into())
     }
 
+    /// ### [2.1 FinalizationRegistry.prototype.cleanupSome ( \[ callback \] )](https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome)
+    ///
+    /// Synchronously calls the cleanup callback, or _callback_ if given, for
+    /// the held values of targets that have already been collected.
+    #[cfg(feature = "proposal-cleanup-some")]
+    fn cleanup_some<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let callback = arguments.get(0).bind(gc.nogc());
+        // 1. Let finalizationRegistry be the this value.
+        let finalization_registry = this_value.bind(gc.nogc());
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let finalization_registry =
+            require_internal_slot_finalization_registry(agent, finalization_registry, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. If callback is present and not undefined, and IsCallable(callback)
+        //    is false, throw a TypeError exception.
+        let callback = if callback.is_undefined() {
+            None
+        } else if let Some(callback) = is_callable(callback, gc.nogc()) {
+            Some(callback)
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "callback is not a function",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        cleanup_finalization_registry(
+            agent,
+            finalization_registry.unbind(),
+            callback.unbind(),
+            gc,
+        )?;
+        // 5. Return undefined.
+        Ok(Value::Undefined)
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let object_prototype = intrinsics.object_prototype();
         let this = intrinsics.finalization_registry_prototype();
         let finalization_registry_constructor = intrinsics.finalization_registry();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(4)
+        let mut property_capacity = 4;
+        if cfg!(feature = "proposal-cleanup-some") {
+            property_capacity += 1;
+        }
+
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(property_capacity)
             .with_prototype(object_prototype)
-            .with_constructor_property(finalization_registry_constructor)
+            .with_constructor_property(finalization_registry_constructor);
+
+        #[cfg(feature = "proposal-cleanup-some")]
+        let builder =
+            builder.with_builtin_function_property::<FinalizationRegistryPrototypeCleanupSome>();
+
+        builder
             .with_builtin_function_property::<FinalizationRegistryPrototypeRegister>()
             .with_builtin_function_property::<FinalizationRegistryPrototypeUnregister>()
             .with_property(|builder| {
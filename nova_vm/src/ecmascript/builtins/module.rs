@@ -12,9 +12,9 @@ use crate::{
     ecmascript::{
         AbstractModule, AbstractModuleMethods, AbstractModuleSlots, Agent, BUILTIN_STRING_MEMORY,
         ExceptionType, InternalMethods, InternalSlots, JsResult, Object, OrdinaryObject,
-        PropertyDescriptor, PropertyKey, ResolvedBinding, SetResult, String, TryError,
-        TryGetResult, TryHasResult, TryResult, Value, get_module_namespace, object_handle,
-        same_value, throw_uninitialized_binding,
+        PropertyDescriptor, PropertyKey, ResolvedBinding, SetResult, String, TryGetResult,
+        TryHasResult, TryResult, Value, get_module_namespace, object_handle, same_value,
+        throw_uninitialized_binding,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
@@ -53,6 +53,95 @@ pub struct Module<'a>(BaseIndex<'a, ModuleHeapData<'static>>);
 object_handle!(Module);
 arena_vec_access!(Module, 'a, ModuleHeapData, modules);
 
+impl<'a> Module<'a> {
+    /// Find the index of P in O.\[\[Exports]].
+    ///
+    /// \[\[Exports]] is sorted in lexicographic code unit order, so this is
+    /// a binary search. Heap Strings are interned, so an export name equal to
+    /// P is found with a handle comparison.
+    fn find_export(self, agent: &Agent, p: String) -> Option<usize> {
+        self.get(agent)
+            .exports
+            .binary_search_by(|export| export.cmp_code_units_(agent, p))
+            .ok()
+    }
+
+    /// Find the index of the String or Integer property key P in
+    /// O.\[\[Exports]].
+    ///
+    /// An Integer key is compared against the export names as its decimal
+    /// digits, which are formatted on the stack instead of allocating a heap
+    /// String.
+    fn find_export_by_key(self, agent: &Agent, p: PropertyKey) -> Option<usize> {
+        match p {
+            PropertyKey::SmallString(data) => self.find_export(agent, String::SmallString(data)),
+            PropertyKey::String(data) => self.find_export(agent, String::String(data)),
+            PropertyKey::Integer(data) => {
+                use std::io::Write;
+                // Note: Integer keys are safe integers, so they fit in 16
+                // digits and a sign.
+                let mut buffer = [0u8; 20];
+                let mut cursor = &mut buffer[..];
+                write!(cursor, "{}", data.into_i64()).unwrap();
+                let len = 20 - cursor.len();
+                let digits = &buffer[..len];
+                // Note: the digits are ASCII, so comparing them with the WTF-8
+                // bytes of an export name orders the same as comparing code
+                // units.
+                self.get(agent)
+                    .exports
+                    .binary_search_by(|export| export.as_bytes_(agent).cmp(digits))
+                    .ok()
+            }
+            PropertyKey::Symbol(_) | PropertyKey::PrivateName(_) => unreachable!(),
+        }
+    }
+
+    /// Get the value of the export at index in O.\[\[Exports]].
+    ///
+    /// This performs steps 4 through 12 of \[\[Get]] ( P, Receiver ).
+    fn get_export_value<'gc>(
+        self,
+        agent: &mut Agent,
+        index: usize,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 4. Let m be O.[[Module]].
+        // 5. Let binding be m.ResolveExport(P).
+        // 6. Assert: binding is a ResolvedBinding Record.
+        // NOTE: ResolveExport was performed for all exports in
+        // ModuleNamespaceCreate.
+        let ModuleExportBinding {
+            // 7. Let targetModule be binding.[[Module]].
+            // 8. Assert: targetModule is not undefined.
+            module: target_module,
+            binding_name,
+        } = self.get(agent).bindings[index].bind(gc);
+        // 9. If binding.[[BindingName]] is NAMESPACE, then
+        let Some(binding_name) = binding_name else {
+            // a. Return GetModuleNamespace(targetModule).
+            return Ok(get_module_namespace(agent, target_module.unbind(), gc).into());
+        };
+        // 10. Let targetEnv be targetModule.[[Environment]].
+        let target_env = target_module.environment(agent, gc);
+        // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
+        let Some(target_env) = target_env else {
+            let p = self.get(agent).exports[index].bind(gc);
+            return Err(agent.throw_exception(
+                ExceptionType::ReferenceError,
+                format!("Could not resolve module '{}'.", p.to_string_lossy_(agent)),
+                gc,
+            ));
+        };
+        // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+        if let Some(value) = target_env.get_binding_value(agent, binding_name, true, gc) {
+            Ok(value)
+        } else {
+            Err(throw_uninitialized_binding(agent, binding_name, gc))
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for Module<'a> {
     #[inline(always)]
     fn get_backing_object(self, _agent: &Agent) -> Option<OrdinaryObject<'static>> {
@@ -155,37 +244,25 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(data) => {
-                        String::from_string(agent, format!("{}", data.into_i64()), gc)
-                    }
-                    PropertyKey::Symbol(_) | PropertyKey::PrivateName(_) => unreachable!(),
-                };
                 // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
-                let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
-                if !exports_contains_p {
-                    TryResult::Continue(None)
-                } else {
-                    // 4. Let value be ? O.[[Get]](P, O).
-                    let value = match self.try_get(agent, property_key, self.into(), None, gc) {
-                        ControlFlow::Continue(TryGetResult::Unset) => Value::Undefined,
-                        ControlFlow::Continue(TryGetResult::Value(v)) => v,
-                        _ => return TryError::GcError.into(),
-                    };
-                    // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
-                    TryResult::Continue(Some(PropertyDescriptor {
-                        value: Some(value),
-                        writable: Some(true),
-                        get: None,
-                        set: None,
-                        enumerable: Some(true),
-                        configurable: Some(false),
-                    }))
-                }
+                let Some(index) = self.find_export_by_key(agent, property_key) else {
+                    return TryResult::Continue(None);
+                };
+                // 4. Let value be ? O.[[Get]](P, O).
+                let value = match self.get_export_value(agent, index, gc) {
+                    Ok(value) => value,
+                    Err(err) => return err.into(),
+                };
+                // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
+                TryResult::Continue(Some(PropertyDescriptor {
+                    value: Some(value),
+                    writable: Some(true),
+                    get: None,
+                    set: None,
+                    enumerable: Some(true),
+                    configurable: Some(false),
+                }))
             }
         }
     }
@@ -216,33 +293,22 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(data) => {
-                        String::from_string(agent, format!("{}", data.into_i64()), gc.nogc())
-                    }
-                    PropertyKey::Symbol(_) | PropertyKey::PrivateName(_) => unreachable!(),
-                };
                 // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
-                let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
-                if !exports_contains_p {
-                    Ok(None)
-                } else {
-                    // 4. Let value be ? O.[[Get]](P, O).
-                    let value = self.internal_get(agent, property_key.unbind(), self.into(), gc)?;
-                    // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
-                    Ok(Some(PropertyDescriptor {
-                        value: Some(value.unbind()),
-                        writable: Some(true),
-                        get: None,
-                        set: None,
-                        enumerable: Some(true),
-                        configurable: Some(false),
-                    }))
-                }
+                let Some(index) = self.find_export_by_key(agent, property_key) else {
+                    return Ok(None);
+                };
+                // 4. Let value be ? O.[[Get]](P, O).
+                let value = self.get_export_value(agent, index, gc.into_nogc())?;
+                // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
+                Ok(Some(PropertyDescriptor {
+                    value: Some(value.unbind()),
+                    writable: Some(true),
+                    get: None,
+                    set: None,
+                    enumerable: Some(true),
+                    configurable: Some(false),
+                }))
             }
         }
     }
@@ -403,16 +469,9 @@ impl<'a> InternalMethods<'a> for Module<'a> {
     ) -> TryResult<'gc, TryHasResult<'gc>> {
         match property_key {
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let p = match property_key {
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::Integer(_data) => todo!(),
-                    _ => unreachable!(),
-                };
                 // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
                 // 3. If exports contains P, return true.
-                if exports.contains(&p) {
+                if self.find_export_by_key(agent, property_key).is_some() {
                     TryHasResult::Custom(1, self.bind(gc).into()).into()
                 } else {
                     // 4. Return false.
@@ -457,6 +516,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         // it must return the same result. An implementation might choose to
         // pre-compute or cache the ResolveExport results for the [[Exports]]
         // of each module namespace exotic object.
+        // NOTE: We pre-compute the results in ModuleNamespaceCreate.
 
         match property_key {
             // 1. If P is a Symbol, then
@@ -471,59 +531,13 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
                 // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    _ => unreachable!(),
-                };
-                let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
-                if !exports_contains_p {
-                    TryGetResult::Unset.into()
-                } else {
-                    // 4. Let m be O.[[Module]].
-                    let m = &self.get(agent).module;
-                    // 5. Let binding be m.ResolveExport(P).
-                    let binding = m.resolve_export(agent, key, &mut vec![], gc);
-                    // 6. Assert: binding is a ResolvedBinding Record.
-                    let Some(ResolvedBinding::Resolved {
-                        // 7. Let targetModule be binding.[[Module]].
-                        // 8. Assert: targetModule is not undefined.
-                        module: target_module,
-                        binding_name,
-                    }) = binding
-                    else {
-                        unreachable!();
-                    };
-                    // 9. If binding.[[BindingName]] is NAMESPACE, then
-                    let Some(binding_name) = binding_name else {
-                        // a. Return GetModuleNamespace(targetModule).
-                        return TryGetResult::Value(
-                            get_module_namespace(agent, target_module.unbind(), gc).into(),
-                        )
-                        .into();
-                    };
-                    // 10. Let targetEnv be targetModule.[[Environment]].
-                    let target_env = target_module.environment(agent, gc);
-                    // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
-                    let Some(target_env) = target_env else {
-                        return agent
-                            .throw_exception_with_static_message(
-                                ExceptionType::ReferenceError,
-                                "Attempted to access unlinked module's environment",
-                                gc,
-                            )
-                            .into();
-                    };
-                    // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-                    if let Some(value) = target_env.get_binding_value(agent, binding_name, true, gc)
-                    {
-                        TryGetResult::Value(value).into()
-                    } else {
-                        throw_uninitialized_binding(agent, binding_name, gc).into()
-                    }
+                let Some(index) = self.find_export_by_key(agent, property_key) else {
+                    return TryGetResult::Unset.into();
+                };
+                match self.get_export_value(agent, index, gc) {
+                    Ok(value) => TryGetResult::Value(value).into(),
+                    Err(err) => err.into(),
                 }
             }
         }
@@ -545,6 +559,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         // it must return the same result. An implementation might choose to
         // pre-compute or cache the ResolveExport results for the [[Exports]]
         // of each module namespace exotic object.
+        // NOTE: We pre-compute the results in ModuleNamespaceCreate.
 
         match property_key {
             // 1. If P is a Symbol, then
@@ -559,59 +574,11 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
                 // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    _ => unreachable!(),
+                // 3. If exports does not contain P, return undefined.
+                let Some(index) = self.find_export_by_key(agent, property_key) else {
+                    return Ok(Value::Undefined);
                 };
-                let exports_contains_p = exports.contains(&key);
-                // 3. If exports does not contain P,
-                if !exports_contains_p {
-                    // return undefined.
-                    Ok(Value::Undefined)
-                } else {
-                    // 4. Let m be O.[[Module]].
-                    let m = &self.get(agent).module;
-                    // 5. Let binding be m.ResolveExport(P).
-                    let binding = m.resolve_export(agent, key, &mut vec![], gc);
-                    // 6. Assert: binding is a ResolvedBinding Record.
-                    let Some(ResolvedBinding::Resolved {
-                        // 7. Let targetModule be binding.[[Module]].
-                        // 8. Assert: targetModule is not undefined.
-                        module: target_module,
-                        binding_name,
-                    }) = binding
-                    else {
-                        unreachable!();
-                    };
-                    // 9. If binding.[[BindingName]] is NAMESPACE, then
-                    let Some(binding_name) = binding_name else {
-                        // a. Return GetModuleNamespace(targetModule).
-                        return Ok(get_module_namespace(agent, target_module.unbind(), gc).into());
-                    };
-                    // 10. Let targetEnv be targetModule.[[Environment]].
-                    let target_env = target_module.environment(agent, gc);
-                    // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
-                    let Some(target_env) = target_env else {
-                        return Err(agent.throw_exception(
-                            ExceptionType::ReferenceError,
-                            format!(
-                                "Could not resolve module '{}'.",
-                                key.to_string_lossy_(agent)
-                            ),
-                            gc,
-                        ));
-                    };
-                    // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-                    if let Some(value) = target_env.get_binding_value(agent, binding_name, true, gc)
-                    {
-                        Ok(value)
-                    } else {
-                        Err(throw_uninitialized_binding(agent, binding_name, gc))
-                    }
-                }
+                self.get_export_value(agent, index, gc)
             }
         }
     }
@@ -657,16 +624,9 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 unreachable!()
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let p = match property_key {
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    _ => unreachable!(),
-                };
                 // 2. Let exports be O.[[Exports]].
-                let exports = &self.get(agent).exports;
                 // 3. If exports contains P,
-                if exports.contains(&p) {
+                if self.find_export_by_key(agent, property_key).is_some() {
                     // return false.
                     TryResult::Continue(false)
                 } else {
@@ -678,25 +638,20 @@ impl<'a> InternalMethods<'a> for Module<'a> {
     }
 
     /// ### [10.4.6.11 \[\[OwnPropertyKeys\]\] ( )](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-ownpropertykeys)
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         // 1. Let exports be O.[[Exports]].
-        let exports = self
-            .bind(gc)
-            .get(agent)
-            .exports
-            .iter()
-            .map(|string| PropertyKey::from(*string));
-        let exports_count = exports.len();
+        let exports = &self.bind(gc).get(agent).exports;
         // 2. Let symbolKeys be OrdinaryOwnPropertyKeys(O).
         // 3. Return the list-concatenation of exports and symbolKeys.
-        let mut own_property_keys = Vec::with_capacity(exports_count + 1);
-        exports.for_each(|export_key| own_property_keys.push(export_key));
-        own_property_keys.push(WellKnownSymbols::ToStringTag.into());
-        TryResult::Continue(own_property_keys)
+        keys.reserve(exports.len() + 1);
+        keys.extend(exports.iter().map(|string| PropertyKey::from(*string)));
+        keys.push(WellKnownSymbols::ToStringTag.into());
+        TryResult::Continue(())
     }
 
     #[inline(always)]
@@ -730,11 +685,32 @@ pub(crate) fn module_namespace_create<'a>(
     // 5. Set M.[[Module]] to module.
     // 6. Let sortedExports be a List whose elements are the elements of
     //    exports, sorted according to lexicographic code unit order.
-    // TODO: this implements UTF-8 lexicographic order, not UTF-16.
-    exports.sort_by(|a, b| a.as_wtf8_(agent).cmp(b.as_wtf8_(agent)));
+    exports.sort_by(|a, b| a.cmp_code_units_(agent, *b));
+    // NOTE: ResolveExport always returns the same result for an export name,
+    // so we resolve each export once here instead of on every [[Get]].
+    let bindings = exports
+        .iter()
+        .map(|export_name| {
+            let Some(ResolvedBinding::Resolved {
+                module,
+                binding_name,
+            }) = module.resolve_export(agent, *export_name, &mut vec![], gc)
+            else {
+                unreachable!()
+            };
+            ModuleExportBinding {
+                module,
+                binding_name,
+            }
+        })
+        .collect();
     // 7. Set M.[[Exports]] to sortedExports.
     // 8. Create own properties of M corresponding to the definitions in 28.3.
-    let m = agent.heap.create(ModuleHeapData { module, exports });
+    let m = agent.heap.create(ModuleHeapData {
+        module,
+        exports,
+        bindings,
+    });
     // 9. Set module.[[Namespace]] to M.
     module.set_namespace(agent, m);
     // 10. Return M.
@@ -13,7 +13,23 @@ use super::Module;
 #[derive(Debug, Clone)]
 pub(crate) struct ModuleHeapData<'a> {
     pub(super) module: AbstractModule<'a>,
+    /// \[\[Exports]], sorted in lexicographic code unit order.
     pub(super) exports: Box<[String<'a>]>,
+    /// Pre-computed ResolveExport results for each entry in \[\[Exports]],
+    /// stored at the same index as the export name.
+    pub(super) bindings: Box<[ModuleExportBinding<'a>]>,
+}
+
+/// The ResolvedBinding Record of a module namespace export.
+///
+/// ResolveExport always returns the same result for a given export name, so
+/// module namespace objects resolve all of their exports once on creation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ModuleExportBinding<'a> {
+    /// \[\[Module]]
+    pub(super) module: AbstractModule<'a>,
+    /// \[\[BindingName]]; None if the binding is NAMESPACE.
+    pub(super) binding_name: Option<String<'a>>,
 }
 
 impl<'a> CreateHeapData<ModuleHeapData<'a>, Module<'a>> for Heap {
@@ -26,21 +42,56 @@ impl<'a> CreateHeapData<ModuleHeapData<'a>, Module<'a>> for Heap {
 }
 
 bindable_handle!(ModuleHeapData);
+bindable_handle!(ModuleExportBinding);
 
 impl HeapMarkAndSweep for ModuleHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { module, exports } = self;
+        let Self {
+            module,
+            exports,
+            bindings,
+        } = self;
         module.mark_values(queues);
         for ele in exports.iter() {
             ele.mark_values(queues);
         }
+        for ele in bindings.iter() {
+            ele.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { module, exports } = self;
+        let Self {
+            module,
+            exports,
+            bindings,
+        } = self;
         module.sweep_values(compactions);
         for ele in exports.iter_mut() {
             ele.sweep_values(compactions);
         }
+        for ele in bindings.iter_mut() {
+            ele.sweep_values(compactions);
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ModuleExportBinding<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            module,
+            binding_name,
+        } = self;
+        module.mark_values(queues);
+        binding_name.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            module,
+            binding_name,
+        } = self;
+        module.sweep_values(compactions);
+        binding_name.sweep_values(compactions);
     }
 }
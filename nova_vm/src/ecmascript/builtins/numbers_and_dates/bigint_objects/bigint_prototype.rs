@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::numeric_to_locale_string;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, BigInt, Builtin, ExceptionType,
@@ -43,12 +45,26 @@ impl Builtin for BigIntPrototypeValueOf {
 
 impl BigIntPrototype {
     /// ### [21.2.3.2 BigInt.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-bigint.prototype.tolocalestring)
+    ///
+    /// With the intl feature this method is implemented as specified in
+    /// [ECMA-402 20.3.1](https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring).
     fn to_locale_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        #[cfg(feature = "intl")]
+        {
+            // 1. Let x be ? ThisBigIntValue(this value).
+            let x = this_big_int_value(agent, this_value, gc.nogc()).unbind()?;
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%,
+            //    « locales, options »).
+            // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+            numeric_to_locale_string(agent, x.into(), arguments.get(0), arguments.get(1), gc)
+                .map(|s| s.into())
+        }
+        #[cfg(not(feature = "intl"))]
         Self::to_string(agent, this_value, arguments, gc)
     }
 
@@ -2,14 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::time::SystemTime;
-
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
-        BuiltinIntrinsicConstructor, Date, DateValue, Function, JsResult, Number, Object,
-        ProtoIntrinsics, Realm, SmallInteger, String, Value, builders::BuiltinFunctionBuilder,
-        ordinary_create_from_constructor, to_number, to_primitive,
+        BuiltinIntrinsicConstructor, Date, DateValue, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder, ordinary_create_from_constructor,
+        to_number, to_primitive,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{ArenaAccessMut, IntrinsicConstructorIndexes},
@@ -67,7 +65,7 @@ impl DateConstructor {
         // 1. If NewTarget is undefined, then
         let Some(new_target) = new_target else {
             // a. Let now be the time value (UTC) identifying the current time.
-            let now = DateValue::now();
+            let now = DateValue::now(agent);
             // b. Return ToDateString(now).
             return Ok(Value::from_string(
                 agent,
@@ -81,7 +79,7 @@ impl DateConstructor {
             // 3. If numberOfArgs = 0, then
             0 => {
                 // a. Let dv be the time value (UTC) identifying the current time.
-                DateValue::now()
+                DateValue::now(agent)
             }
             // 4. Else if numberOfArgs = 1, then
             1 => {
@@ -194,21 +192,12 @@ impl DateConstructor {
     ///
     /// This function returns the time value designating the UTC date and time of the occurrence of the call to it.
     fn now<'gc>(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
         _arguments: ArgumentsList,
         _gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let time_value = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_millis();
-        assert_eq!(time_value as u64 as u128, time_value);
-        let time_value = time_value as u64;
-        Ok(
-            Number::from(SmallInteger::try_from(time_value).expect("SystemTime is beyond range"))
-                .into(),
-        )
+        Ok(DateValue::now(agent).into())
     }
 
     /// ### [21.4.3.2 Date.parse ( string )](https://tc39.es/ecma262/#sec-date.parse)
@@ -382,21 +371,100 @@ mod parse_date {
         }
 
         // `toString` format: `Thu Jan 01 1970 00:00:00 GMT+0000`
-        // TODO:
-        // if let Ok(t) = OffsetDateTime::parse(&date, &format_description!("[weekday repr:short] [month repr:short] [day] [year] [hour]:[minute]:[second] GMT[offset_hour sign:mandatory][offset_minute][end]")) {
-        //     return Some(t.unix_timestamp() * 1000 + i64::from(t.millisecond()));
-        // }
-
         // `toUTCString` format: `Thu, 01 Jan 1970 00:00:00 GMT`
-        // TODO:
-        // if let Ok(t) = PrimitiveDateTime::parse(&date, &format_description!("[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT[end]")) {
-        //     let t = t.assume_utc();
-        //     return Some(t.unix_timestamp() * 1000 + i64::from(t.millisecond()));
-        // }
+        if let Some(dt) = parse_to_string_format(date) {
+            return dt as f64;
+        }
 
         f64::NAN
     }
 
+    /// Parses a date string in the format produced by
+    /// [`Date.prototype.toString`][to-string], optionally followed by a time
+    /// zone name in parentheses, or by
+    /// [`Date.prototype.toUTCString`][to-utc-string].
+    ///
+    /// [to-string]: https://tc39.es/ecma262/#sec-date.prototype.tostring
+    /// [to-utc-string]: https://tc39.es/ecma262/#sec-date.prototype.toutcstring
+    fn parse_to_string_format(date: &str) -> Option<i64> {
+        const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        fn number(digits: &str, len: usize, max: u32) -> Option<u32> {
+            if digits.len() != len || !digits.bytes().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok().filter(|value| *value <= max)
+        }
+
+        let mut parts = date.splitn(7, ' ');
+        let weekday = parts.next()?;
+        // toUTCString puts a comma after the weekday and the day before the
+        // month.
+        let (weekday, utc_format) = match weekday.strip_suffix(',') {
+            Some(weekday) => (weekday, true),
+            None => (weekday, false),
+        };
+        if !WEEKDAYS.contains(&weekday) {
+            return None;
+        }
+        let (month, day) = if utc_format {
+            let day = parts.next()?;
+            (parts.next()?, day)
+        } else {
+            (parts.next()?, parts.next()?)
+        };
+        let month = MONTHS.iter().position(|m| *m == month)?;
+        let day = number(day, 2, 31).filter(|day| *day >= 1)?;
+        let year = parts.next()?;
+        let (negative, year_digits) = match year.strip_prefix('-') {
+            Some(year) => (true, year),
+            None => (false, year),
+        };
+        if year_digits.len() < 4 || !year_digits.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let year: i32 = year_digits.parse().ok()?;
+        let year = if negative { -year } else { year };
+        let mut time = parts.next()?.split(':');
+        let hour = number(time.next()?, 2, 23)?;
+        let minute = number(time.next()?, 2, 59)?;
+        let second = number(time.next()?, 2, 59)?;
+        if time.next().is_some() {
+            return None;
+        }
+        let zone = parts.next()?;
+        let offset = if utc_format {
+            if zone != "GMT" || parts.next().is_some() {
+                return None;
+            }
+            0
+        } else {
+            let offset = zone.strip_prefix("GMT")?;
+            let (sign, offset) = match offset.split_at_checked(1)? {
+                ("+", offset) => (1, offset),
+                ("-", offset) => (-1, offset),
+                _ => return None,
+            };
+            let offset_hour = number(offset.get(..2)?, 2, 23)?;
+            let offset_minute = number(offset.get(2..)?, 2, 59)?;
+            // The implementation-defined time zone name.
+            if let Some(tz_name) = parts.next()
+                && !(tz_name.starts_with('(') && tz_name.ends_with(')'))
+            {
+                return None;
+            }
+            sign * (offset_hour * 60 + offset_minute) as i64
+        };
+
+        let date = make_date(
+            make_day(year.into(), month as f64, day.into()),
+            make_time(hour.into(), minute.into(), second.into(), 0.0),
+        );
+        DateValue::time_clip(date - (offset as f64) * MS_PER_MINUTE).get_i64()
+    }
+
     /// Parses a date string according to the [`Date Time String Format`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::{DateTimeDefaults, DateTimeRequired, create_date_time_format};
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic, Date,
@@ -1021,8 +1023,16 @@ impl DatePrototype {
         let minute = min_from_time(tv);
         let second = sec_from_time(tv);
         let ms = ms_from_time(tv);
+        // Years outside 0 to 9999 use the expanded year format: a sign
+        // followed by six digits.
+        let year = if (0..=9999).contains(&year) {
+            format!("{year:04}")
+        } else {
+            let sign = if year < 0 { '-' } else { '+' };
+            format!("{sign}{:06}", year.unsigned_abs())
+        };
         let date_string =
-            format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{ms:03}Z");
+            format!("{year}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{ms:03}Z");
         Ok(Value::from_string(agent, date_string, gc.into_nogc()))
     }
 
@@ -1081,11 +1091,26 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_date_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleDateString", gc.into_nogc()))
+        #[cfg(feature = "intl")]
+        {
+            to_locale_date_time_string(
+                agent,
+                this_value,
+                arguments,
+                DateTimeRequired::Date,
+                DateTimeDefaults::Date,
+                gc,
+            )
+        }
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this_value, arguments);
+            Err(agent.todo("Date.prototype.toLocaleDateString", gc.into_nogc()))
+        }
     }
 
     /// ### [21.4.4.39 Date.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
@@ -1105,11 +1130,26 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleString", gc.into_nogc()))
+        #[cfg(feature = "intl")]
+        {
+            to_locale_date_time_string(
+                agent,
+                this_value,
+                arguments,
+                DateTimeRequired::Any,
+                DateTimeDefaults::All,
+                gc,
+            )
+        }
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this_value, arguments);
+            Err(agent.todo("Date.prototype.toLocaleString", gc.into_nogc()))
+        }
     }
 
     /// ### [21.4.4.40 Date.prototype.toLocaleTimeString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring)
@@ -1130,11 +1170,26 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_time_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleTimeString", gc.into_nogc()))
+        #[cfg(feature = "intl")]
+        {
+            to_locale_date_time_string(
+                agent,
+                this_value,
+                arguments,
+                DateTimeRequired::Time,
+                DateTimeDefaults::Time,
+                gc,
+            )
+        }
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (this_value, arguments);
+            Err(agent.todo("Date.prototype.toLocaleTimeString", gc.into_nogc()))
+        }
     }
 
     /// ### [21.4.4.41 Date.prototype.toString ( )](https://tc39.es/ecma262/#sec-date.prototype.tostring)
@@ -1308,7 +1363,7 @@ impl DatePrototype {
             let error_message = format!(
                 "{} is not an object",
                 this_value
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -1333,7 +1388,7 @@ impl DatePrototype {
             let error_message = format!(
                 "Expected 'hint' to be \"string\", \"default\", or \"number\", got {}",
                 hint.unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -1407,6 +1462,55 @@ impl DatePrototype {
     }
 }
 
+/// ### [20.4.1 Date.prototype.toLocaleDateString ( \[ locales \[ , options \] \] )](https://tc39.es/ecma402/#sec-date.prototype.tolocaledatestring)
+///
+/// The ECMA-402 definitions of Date.prototype.toLocaleDateString,
+/// toLocaleString, and toLocaleTimeString differ only in the required and
+/// defaults arguments passed to CreateDateTimeFormat.
+#[cfg(feature = "intl")]
+fn to_locale_date_time_string<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    required: DateTimeRequired,
+    defaults: DateTimeDefaults,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let locales = arguments.get(0).bind(gc.nogc());
+    let options = arguments.get(1).bind(gc.nogc());
+    // 1. Let dateObject be the this value.
+    // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+    let date_object = require_internal_slot_date(agent, this_value, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 3. Let x be dateObject.[[DateValue]].
+    let x = date_object.date_value(agent);
+    // 4. If x is NaN, return "Invalid Date".
+    let Some(x) = x.get_f64() else {
+        return Ok(Value::from_static_str(
+            agent,
+            "Invalid Date",
+            gc.into_nogc(),
+        ));
+    };
+    // 5. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%,
+    //    locales, options, required, defaults).
+    let date_format = create_date_time_format(
+        agent,
+        locales.unbind(),
+        options.unbind(),
+        required,
+        defaults,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 6. Return ! FormatDateTime(dateFormat, x).
+    let result = date_format
+        .format(x)
+        .expect("Date value should be a valid time value");
+    Ok(Value::from_string(agent, result, gc.into_nogc()))
+}
+
 #[inline(always)]
 fn require_internal_slot_date<'a>(
     agent: &mut Agent,
@@ -1439,6 +1543,8 @@ pub(super) const MS_PER_MINUTE: f64 = MS_PER_SECOND * SECONDS_PER_MINUTE;
 const MS_PER_HOUR: f64 = MS_PER_MINUTE * MINUTES_PER_HOUR;
 /// msPerDay = 86400000𝔽 = msPerHour × 𝔽(HoursPerDay)
 const MS_PER_DAY: f64 = MS_PER_HOUR * HOURS_PER_DAY;
+/// The number of nanoseconds in a day, for epoch nanoseconds.
+const NS_PER_DAY: i128 = MS_PER_DAY as i128 * 1_000_000;
 
 /// ### [21.4.1.3 Day ( t )](https://tc39.es/ecma262/#sec-day)
 ///
@@ -1532,7 +1638,7 @@ fn time_from_year(y: f64) -> f64 {
 /// The abstract operation YearFromTime takes argument t (a finite time value)
 /// and returns an integral Number. It returns the year in which t falls. It
 /// performs the following steps when called:
-fn year_from_time(t: f64) -> i32 {
+pub(crate) fn year_from_time(t: f64) -> i32 {
     const MS_PER_AVERAGE_YEAR: f64 = 12.0 * 30.436_875 * MS_PER_DAY;
 
     // 1. Return the largest integral Number y (closest to +∞) such that TimeFromYear(y) ≤ t.
@@ -1576,7 +1682,7 @@ fn in_leap_year(t: f64) -> u16 {
 /// specifies November; and 11𝔽 specifies December. Note that MonthFromTime
 /// (+0𝔽) = +0𝔽, corresponding to Thursday, 1 January 1970. It performs the
 /// following steps when called:
-fn month_from_time(t: f64) -> u8 {
+pub(crate) fn month_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -1617,7 +1723,7 @@ fn month_from_time(t: f64) -> u8 {
 /// The abstract operation DateFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from 1𝔽 to 31𝔽.
 /// It returns the day of the month in which t falls.
-fn date_from_time(t: f64) -> u8 {
+pub(crate) fn date_from_time(t: f64) -> u8 {
     // 1. Let inLeapYear be InLeapYear(t).
     let in_leap_year = in_leap_year(t);
 
@@ -1676,7 +1782,7 @@ fn week_day(t: f64) -> u8 {
 /// The abstract operation HourFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 23𝔽.
 /// It returns the hour of the day in which t falls.
-fn hour_from_time(t: f64) -> u8 {
+pub(crate) fn hour_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerHour)) modulo HoursPerDay).
     ((t / MS_PER_HOUR).floor()).rem_euclid(HOURS_PER_DAY) as u8
 }
@@ -1686,7 +1792,7 @@ fn hour_from_time(t: f64) -> u8 {
 /// The abstract operation MinFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 59𝔽.
 /// It returns the minute of the hour in which t falls.
-pub(super) fn min_from_time(t: f64) -> u8 {
+pub(crate) fn min_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerMinute)) modulo MinutesPerHour).
     ((t / MS_PER_MINUTE).floor()).rem_euclid(MINUTES_PER_HOUR) as u8
 }
@@ -1696,7 +1802,7 @@ pub(super) fn min_from_time(t: f64) -> u8 {
 /// The abstract operation SecFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 59𝔽.
 /// It returns the second of the minute in which t falls.
-fn sec_from_time(t: f64) -> u8 {
+pub(crate) fn sec_from_time(t: f64) -> u8 {
     // 1. Return 𝔽(floor(ℝ(t / msPerSecond)) modulo SecondsPerMinute).
     ((t / MS_PER_SECOND).floor()).rem_euclid(SECONDS_PER_MINUTE) as u8
 }
@@ -1706,7 +1812,7 @@ fn sec_from_time(t: f64) -> u8 {
 /// The abstract operation msFromTime takes argument t (a finite time value)
 /// and returns an integral Number in the inclusive interval from +0𝔽 to 999𝔽.
 /// It returns the millisecond of the second in which t falls.
-fn ms_from_time(t: f64) -> u16 {
+pub(crate) fn ms_from_time(t: f64) -> u16 {
     // 1. Return 𝔽(ℝ(t) modulo ℝ(msPerSecond)).
     (t.rem_euclid(MS_PER_SECOND)) as u16
 }
@@ -1735,7 +1841,7 @@ fn get_utc_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> i64 {
+) -> i128 {
     // 1. Let date be MakeDay(𝔽(year), 𝔽(month - 1), 𝔽(day)).
     let date = make_day(year as f64, (month - 1) as f64, day as f64);
     // 2. Let time be MakeTime(𝔽(hour), 𝔽(minute), 𝔽(second), 𝔽(millisecond)).
@@ -1750,7 +1856,7 @@ fn get_utc_epoch_nanoseconds(
     // 4. Assert: ms is an integral Number.
     assert!(ms.fract() == 0.0);
     // 5. Return ℤ(ℝ(ms) × 10**6 + microsecond × 10**3 + nanosecond).
-    (ms * 1e6 + (microsecond as f64) * 1e3 + (nanosecond as f64)) as i64
+    ms as i128 * 1_000_000 + microsecond as i128 * 1_000 + nanosecond as i128
 }
 
 /// ### [21.4.1.20 GetNamedTimeZoneEpochNanoseconds ( timeZoneIdentifier, year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )](https://tc39.es/ecma262/#sec-getnamedtimezoneepochnanoseconds)
@@ -1779,9 +1885,13 @@ fn get_utc_epoch_nanoseconds(
 /// returned List will be empty. Otherwise, the returned List will have one
 /// element.
 ///
-/// The default implementation of GetNamedTimeZoneEpochNanoseconds, to be used
-/// for ECMAScript implementations that do not include local political rules
-/// for any time zones, performs the following steps when called:
+/// The offsets of named time zones are given by the host through
+/// [`HostHooks::get_named_time_zone_offset_nanoseconds`]. The possible
+/// instants are found by trying the offsets in effect a day before and a day
+/// after the wall-clock time, which finds all of them as long as the offset of
+/// the time zone changes at most once within any 24 hours.
+///
+/// [`HostHooks::get_named_time_zone_offset_nanoseconds`]: crate::ecmascript::HostHooks::get_named_time_zone_offset_nanoseconds
 ///
 /// > NOTE: It is required for time zone aware implementations (and recommended
 /// > for all others) to use the time zone information of the IANA Time Zone
@@ -1799,6 +1909,7 @@ fn get_utc_epoch_nanoseconds(
 /// > 0, 0, 0, 0) would return an empty List.
 #[allow(clippy::too_many_arguments)]
 fn get_named_time_zone_epoch_nanoseconds(
+    agent: &Agent,
     time_zone_identifier: &str,
     year: i32,
     month: u8,
@@ -1809,11 +1920,8 @@ fn get_named_time_zone_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> [i64; 1] {
-    // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
-    // 2. Let epochNanoseconds be GetUTCEpochNanoseconds(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond).
-    let epoch_nanoseconds = get_utc_epoch_nanoseconds(
+) -> Vec<i128> {
+    let local_nanoseconds = get_utc_epoch_nanoseconds(
         year,
         month,
         day,
@@ -1824,8 +1932,25 @@ fn get_named_time_zone_epoch_nanoseconds(
         microsecond,
         nanosecond,
     );
-    // 3. Return « epochNanoseconds ».
-    [epoch_nanoseconds]
+    let mut possible_instants = Vec::with_capacity(2);
+    for offset_time in [
+        local_nanoseconds - NS_PER_DAY,
+        local_nanoseconds + NS_PER_DAY,
+    ] {
+        let offset_ns =
+            get_named_time_zone_offset_nanoseconds(agent, time_zone_identifier, offset_time);
+        let epoch_nanoseconds = local_nanoseconds - offset_ns as i128;
+        // The wall-clock time occurs at the instant if the time zone has this
+        // offset at the instant.
+        if get_named_time_zone_offset_nanoseconds(agent, time_zone_identifier, epoch_nanoseconds)
+            == offset_ns
+            && !possible_instants.contains(&epoch_nanoseconds)
+        {
+            possible_instants.push(epoch_nanoseconds);
+        }
+    }
+    possible_instants.sort_unstable();
+    possible_instants
 }
 
 /// ### [21.4.1.21 GetNamedTimeZoneOffsetNanoseconds ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/ecma262/#sec-getnamedtimezoneoffsetnanoseconds)
@@ -1838,19 +1963,21 @@ fn get_named_time_zone_epoch_nanoseconds(
 /// identified by timeZoneIdentifier, at the instant corresponding with
 /// epochNanoseconds relative to the epoch, both in nanoseconds.
 ///
-/// The default implementation of GetNamedTimeZoneOffsetNanoseconds, to be used
-/// for ECMAScript implementations that do not include local political rules
-/// for any time zones, performs the following steps when called:
+/// The offsets are given by the host through
+/// [`HostHooks::get_named_time_zone_offset_nanoseconds`], which returns 0 by
+/// default.
+///
+/// [`HostHooks::get_named_time_zone_offset_nanoseconds`]: crate::ecmascript::HostHooks::get_named_time_zone_offset_nanoseconds
 ///
 /// > NOTE: Time zone offset values may be positive or negative.
 fn get_named_time_zone_offset_nanoseconds(
+    agent: &Agent,
     time_zone_identifier: &str,
-    _epoch_nanoseconds: i64,
-) -> f64 {
-    // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
-    // 2. Return 0.
-    0.0
+    epoch_nanoseconds: i128,
+) -> i64 {
+    agent
+        .host_hooks
+        .get_named_time_zone_offset_nanoseconds(time_zone_identifier, epoch_nanoseconds)
 }
 
 /// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
@@ -1872,14 +1999,12 @@ fn get_named_time_zone_offset_nanoseconds(
 /// > For example, if the host environment is a browser on a system where the
 /// > user has chosen US Eastern Time as their time zone,
 /// > SystemTimeZoneIdentifier returns "America/New_York".
-fn system_time_zone_identifier(_agent: &Agent) -> &'static str {
+pub(crate) fn system_time_zone_identifier(agent: &Agent) -> &'static str {
     // 1. If the implementation only supports the UTC time zone, return "UTC".
     // 2. Let systemTimeZoneString be the String representing the host environment's
     // current time zone, either a primary time zone identifier or an offset time zone identifier.
     // 3. Return systemTimeZoneString.
-
-    // TODO: implement this
-    "UTC"
+    agent.host_hooks.system_time_zone_identifier()
 }
 
 /// ### [21.4.1.25 LocalTime ( t )](https://tc39.es/ecma262/#sec-localtime)
@@ -1917,9 +2042,10 @@ fn local_time(agent: &Agent, t: f64) -> f64 {
     else {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(t) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
+            agent,
             system_time_zone_identifier,
-            (t * 1_000_000.0) as i64,
-        )
+            t as i128 * 1_000_000,
+        ) as f64
     };
     // 4. Let offsetMs be truncate(offsetNs / 10**6).
     let offset_ms = (offset_ns / 1_000_000.0).trunc();
@@ -1984,6 +2110,7 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
     else {
         // a. Let possibleInstants be GetNamedTimeZoneEpochNanoseconds(systemTimeZoneIdentifier, ℝ(YearFromTime(t)), ℝ(MonthFromTime(t)) + 1, ℝ(DateFromTime(t)), ℝ(HourFromTime(t)), ℝ(MinFromTime(t)), ℝ(SecFromTime(t)), ℝ(msFromTime(t)), 0, 0).
         let possible_instants = get_named_time_zone_epoch_nanoseconds(
+            agent,
             system_time_zone_identifier,
             year_from_time(t),
             month_from_time(t) + 1,
@@ -2022,10 +2149,17 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
             // possibleInstantsBefore is not empty (i.e., tBefore represents
             // the last local time before the transition).
             // iii. Let disambiguatedInstant be the last element of possibleInstantsBefore.
-            todo!()
+            // NOTE: Only the offset of disambiguatedInstant is needed. The
+            // offset changes at most once within a day, so the instant a day
+            // before t has the offset in effect before the transition.
+            t as i128 * 1_000_000 - NS_PER_DAY
         };
         // e. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, disambiguatedInstant).
-        get_named_time_zone_offset_nanoseconds(system_time_zone_identifier, disambiguated_instant)
+        get_named_time_zone_offset_nanoseconds(
+            agent,
+            system_time_zone_identifier,
+            disambiguated_instant,
+        ) as f64
     };
     // 5. Let offsetMs be truncate(offsetNs / 10**6).
     let offset_ms = (offset_ns / 1_000_000.0).trunc();
@@ -2188,11 +2322,11 @@ pub(super) fn make_full_year(year: f64) -> f64 {
 /// (a String) and returns a Boolean. The return value indicates whether
 /// offsetString conforms to the grammar given by UTCOffset. It performs the
 /// following steps when called:
-fn is_time_zone_offset_string(_offset_string: &str) -> bool {
+fn is_time_zone_offset_string(offset_string: &str) -> bool {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
-    let parse_result: Result<(), ()> = Err(()); // TODO:
+    let parse_result = parse_utc_offset(offset_string);
     // 2. If parseResult is a List of errors, return false.
-    if parse_result.is_err() {
+    if parse_result.is_none() {
         return false;
     }
     // 3. Return true.
@@ -2205,38 +2339,78 @@ fn is_time_zone_offset_string(_offset_string: &str) -> bool {
 /// offsetString (a String) and returns an integer. The return value is the UTC
 /// offset, as a number of nanoseconds, that corresponds to the String
 /// offsetString.
-fn parse_time_zone_offset_string(_offset_string: &str) -> f64 {
+fn parse_time_zone_offset_string(offset_string: &str) -> f64 {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
+    let parse_result = parse_utc_offset(offset_string);
     // 2. Assert: parseResult is not a List of errors.
-    // 3. Assert: parseResult contains a ASCIISign Parse Node.
-    // 4. Let parsedSign be the source text matched by the ASCIISign Parse Node contained within parseResult.
-    // 5. If parsedSign is the single code point U+002D (HYPHEN-MINUS), then
-    //    a. Let sign be -1.
-    // 6. Else,
-    //    a. Let sign be 1.
-    // 7. NOTE: Applications of StringToNumber below do not lose precision, since each of the parsed values is guaranteed to be a sufficiently short string of decimal digits.
-    // 8. Assert: parseResult contains an Hour Parse Node.
-    // 9. Let parsedHours be the source text matched by the Hour Parse Node contained within parseResult.
-    // 10. Let hours be ℝ(StringToNumber(CodePointsToString(parsedHours))).
-    // 11. If parseResult does not contain a MinuteSecond Parse Node, then
-    //     a. Let minutes be 0.
-    // 12. Else,
-    //     a. Let parsedMinutes be the source text matched by the first MinuteSecond Parse Node contained within parseResult.
-    //     b. Let minutes be ℝ(StringToNumber(CodePointsToString(parsedMinutes))).
-    // 13. If parseResult does not contain two MinuteSecond Parse Nodes, then
-    //     a. Let seconds be 0.
-    // 14. Else,
-    //     a. Let parsedSeconds be the source text matched by the second MinuteSecond Parse Node contained within parseResult.
-    //     b. Let seconds be ℝ(StringToNumber(CodePointsToString(parsedSeconds))).
-    // 15. If parseResult does not contain a TemporalDecimalFraction Parse Node, then
-    //     a. Let nanoseconds be 0.
-    // 16. Else,
-    //     a. Let parsedFraction be the source text matched by the TemporalDecimalFraction Parse Node contained within parseResult.
-    //     b. Let fraction be the string-concatenation of CodePointsToString(parsedFraction) and "000000000".
-    //     c. Let nanosecondsString be the substring of fraction from 1 to 10.
-    //     d. Let nanoseconds be ℝ(StringToNumber(nanosecondsString)).
+    // 3-16. NOTE: The sign, hours, minutes, seconds and nanoseconds are
+    //       found while parsing.
     // 17. Return sign × (((hours × 60 + minutes) × 60 + seconds) × 10**9 + nanoseconds).
-    todo!()
+    parse_result.unwrap() as f64
+}
+
+/// ### [21.4.1.33 Time Zone Offset String Format](https://tc39.es/ecma262/#sec-time-zone-offset-strings)
+///
+/// Parses a string matching the UTCOffset grammar, ie. `±HH`, `±HHMM`,
+/// `±HHMMSS[.fraction]` or the same with `:` separators, into an offset in
+/// nanoseconds. Returns None if the string does not match.
+fn parse_utc_offset(offset_string: &str) -> Option<i64> {
+    /// Parses two ASCII digits forming a number no larger than max.
+    fn two_digits(digits: &[u8], max: i64) -> Option<i64> {
+        let [tens @ b'0'..=b'9', ones @ b'0'..=b'9'] = *digits.get(..2)? else {
+            return None;
+        };
+        let value = ((tens - b'0') * 10 + (ones - b'0')) as i64;
+        (value <= max).then_some(value)
+    }
+    // If parsedSign is the single code point U+002D (HYPHEN-MINUS), let
+    // sign be -1. Otherwise let sign be 1.
+    let (sign, rest) = match offset_string.as_bytes().split_first()? {
+        (b'+', rest) => (1, rest),
+        (b'-', rest) => (-1, rest),
+        _ => return None,
+    };
+    let hours = two_digits(rest, 23)?;
+    let mut rest = &rest[2..];
+    // If parseResult does not contain a MinuteSecond Parse Node, let minutes
+    // be 0. If it does not contain two, let seconds be 0. If it does not
+    // contain a TemporalDecimalFraction Parse Node, let nanoseconds be 0.
+    let (mut minutes, mut seconds, mut nanoseconds) = (0, 0, 0);
+    if !rest.is_empty() {
+        // TimeSeparator[+Extended] is ":", TimeSeparator[~Extended] is empty.
+        let extended = rest[0] == b':';
+        if extended {
+            rest = &rest[1..];
+        }
+        minutes = two_digits(rest, 59)?;
+        rest = &rest[2..];
+        if !rest.is_empty() {
+            if extended {
+                rest = rest.strip_prefix(b":")?;
+            }
+            seconds = two_digits(rest, 59)?;
+            rest = &rest[2..];
+            if !rest.is_empty() {
+                // TemporalDecimalFraction is a decimal separator followed by
+                // one to nine digits. Let nanoseconds be the digits padded
+                // with zeros to nine digits.
+                let (b'.' | b',', fraction) = rest.split_first()? else {
+                    return None;
+                };
+                if fraction.is_empty()
+                    || fraction.len() > 9
+                    || !fraction.iter().all(u8::is_ascii_digit)
+                {
+                    return None;
+                }
+                for i in 0..9 {
+                    let digit = fraction.get(i).map_or(0, |digit| digit - b'0');
+                    nanoseconds = nanoseconds * 10 + digit as i64;
+                }
+            }
+        }
+    }
+    Some(sign * (((hours * 60 + minutes) * 60 + seconds) * 1_000_000_000 + nanoseconds))
 }
 
 /// ### [21.4.4.41.1 TimeString ( tv )](https://tc39.es/ecma262/#sec-timestring)
@@ -2315,9 +2489,10 @@ fn time_zone_string(agent: &Agent, tv: f64) -> std::string::String {
     else {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(tv) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
+            agent,
             system_time_zone_identifier,
-            (tv * 1_000_000.0) as i64,
-        )
+            tv as i128 * 1_000_000,
+        ) as f64
     };
     // 4. Let offset be 𝔽(truncate(offsetNs / 10**6)).
     let offset = (offset_ns / 1_000_000.0).trunc();
@@ -1411,11 +1411,8 @@ impl MathObject {
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Ok(Value::from_f64(
-            agent,
-            rand::random::<f64>(),
-            gc.into_nogc(),
-        ))
+        let random = agent.random_f64();
+        Ok(Value::from_f64(agent, random, gc.into_nogc()))
     }
 
     fn round<'gc>(
@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "intl")]
+use crate::ecmascript::numeric_to_locale_string;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType, JsResult,
@@ -180,12 +182,26 @@ impl NumberPrototype {
     }
 
     /// ### [21.1.3.4 Number.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-number.prototype.tolocalestring)
+    ///
+    /// With the intl feature this method is implemented as specified in
+    /// [ECMA-402 20.2.1](https://tc39.es/ecma402/#sup-number.prototype.tolocalestring).
     fn to_locale_string<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        #[cfg(feature = "intl")]
+        {
+            // 1. Let x be ? ThisNumberValue(this value).
+            let x = this_number_value(agent, this_value, gc.nogc()).unbind()?;
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%,
+            //    « locales, options »).
+            // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+            numeric_to_locale_string(agent, x.into(), arguments.get(0), arguments.get(1), gc)
+                .map(|s| s.into())
+        }
+        #[cfg(not(feature = "intl"))]
         Self::to_string(agent, this_value, arguments, gc)
     }
 
@@ -11,7 +11,6 @@ pub use shape::*;
 use std::{
     collections::{TryReserveError, hash_map::Entry},
     ops::ControlFlow,
-    vec,
 };
 
 #[cfg(feature = "shared-array-buffer")]
@@ -343,6 +342,10 @@ fn validate_and_apply_property_descriptor(
     gc: NoGcScope,
 ) -> Result<bool, TryReserveError> {
     // 1. Assert: IsPropertyKey(P) is true.
+    debug_assert!(
+        descriptor.validate().is_ok(),
+        "Property descriptor has both data and accessor fields"
+    );
 
     // 2. If current is undefined, then
     let Some(current) = current else {
@@ -1481,61 +1484,61 @@ pub(crate) fn ordinary_delete(
 }
 
 /// ### [10.1.11.1 OrdinaryOwnPropertyKeys ( O )](https://tc39.es/ecma262/#sec-ordinaryownpropertykeys)
-pub(crate) fn ordinary_own_property_keys<'a>(
+///
+/// Appends the keys to the end of an existing List instead of returning a new
+/// one, so that callers can reuse a buffer between calls.
+pub(crate) fn ordinary_own_property_keys_into<'a>(
     agent: &Agent,
     object: OrdinaryObject<'a>,
+    keys_vec: &mut Vec<PropertyKey<'a>>,
     gc: NoGcScope<'a, '_>,
-) -> Vec<PropertyKey<'a>> {
+) {
     let PropertyStorageRef {
         keys,
         values: _,
         descriptors: _,
     } = object.get_property_storage(agent);
-    // 1. Let keys be a new empty List.
-    let mut integer_keys = vec![];
-    let mut keys_vec = Vec::with_capacity(keys.len());
-    let mut symbol_keys = vec![];
-
-    // 3. For each own property key P of O such that P is a String and P is not an array index, in
-    //    ascending chronological order of property creation, do
-    for key in keys.iter() {
-        // SAFETY: Keys are all PropertyKeys reinterpreted as Values without
-        // conversion.
-        match key {
-            PropertyKey::Integer(integer_key) => {
-                let key_value = integer_key.into_i64();
-                if (0..u32::MAX as i64).contains(&key_value) {
-                    // Integer property key! This requires sorting
-                    integer_keys.push(key_value as u32);
-                } else {
-                    keys_vec.push(key.bind(gc));
-                }
-            }
-            PropertyKey::Symbol(symbol) => symbol_keys.push(symbol.bind(gc)),
-            // Note: PrivateName keys are always invisible.
-            PropertyKey::PrivateName(_) => {}
-            // a. Append P to keys.
-            _ => keys_vec.push(key.bind(gc)),
-        }
-    }
+    keys_vec.reserve(keys.len());
+    let is_array_index = |key: &PropertyKey| match key {
+        PropertyKey::Integer(integer_key) => (0..u32::MAX as i64).contains(&integer_key.into_i64()),
+        _ => false,
+    };
 
     // 2. For each own property key P of O such that P is an array index,
-    if !integer_keys.is_empty() {
-        // in ascending numeric index order, do
-        integer_keys.sort();
-        // a. Append P to keys.
-        keys_vec.splice(0..0, integer_keys.into_iter().map(|key| key.into()));
-    }
+    let integer_keys_start = keys_vec.len();
+    // a. Append P to keys.
+    keys_vec.extend(
+        keys.iter()
+            .filter(|key| is_array_index(key))
+            .map(|key| key.bind(gc)),
+    );
+    // in ascending numeric index order, do
+    keys_vec[integer_keys_start..].sort_unstable_by_key(|key| match key {
+        PropertyKey::Integer(integer_key) => integer_key.into_i64(),
+        _ => unreachable!(),
+    });
 
-    // 4. For each own property key P of O such that P is a Symbol,
-    if !symbol_keys.is_empty() {
-        // in ascending chronological order of property creation, do
-        // a. Append P to keys.
-        keys_vec.extend(symbol_keys.iter().map(|key| PropertyKey::Symbol(*key)));
-    }
+    // 3. For each own property key P of O such that P is a String and P is not an array index, in
+    //    ascending chronological order of property creation, do
+    // a. Append P to keys.
+    keys_vec.extend(
+        keys.iter()
+            .filter(|key| {
+                // Note: PrivateName keys are always invisible.
+                !is_array_index(key)
+                    && !matches!(key, PropertyKey::Symbol(_) | PropertyKey::PrivateName(_))
+            })
+            .map(|key| key.bind(gc)),
+    );
 
-    // 5. Return keys.
-    keys_vec
+    // 4. For each own property key P of O such that P is a Symbol, in
+    //    ascending chronological order of property creation, do
+    // a. Append P to keys.
+    keys_vec.extend(
+        keys.iter()
+            .filter(|key| matches!(key, PropertyKey::Symbol(_)))
+            .map(|key| key.bind(gc)),
+    );
 }
 
 pub(crate) fn ordinary_object_create_null<'a>(
@@ -1738,6 +1741,26 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         ProtoIntrinsics::Map => agent.heap.create(MapHeapData::default()).into(),
         ProtoIntrinsics::MapIterator => agent.heap.create(MapIteratorHeapData::default()).into(),
         ProtoIntrinsics::Promise => agent.heap.create(PromiseHeapData::default()).into(),
+        #[cfg(feature = "web-abort")]
+        ProtoIntrinsics::AbortController => {
+            // Web API objects carry native data and are created as embedder
+            // objects by their constructors.
+            unreachable!()
+        }
+        #[cfg(feature = "web-blob")]
+        ProtoIntrinsics::Blob | ProtoIntrinsics::File => unreachable!(),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::IntlDateTimeFormat | ProtoIntrinsics::IntlNumberFormat => unreachable!(),
+        #[cfg(feature = "web-events")]
+        ProtoIntrinsics::Event | ProtoIntrinsics::EventTarget => unreachable!(),
+        #[cfg(feature = "web-fetch")]
+        ProtoIntrinsics::Headers | ProtoIntrinsics::Request | ProtoIntrinsics::Response => {
+            unreachable!()
+        }
+        #[cfg(feature = "web-streams")]
+        ProtoIntrinsics::ReadableStream | ProtoIntrinsics::ReadableStreamDefaultReader => {
+            unreachable!()
+        }
         #[cfg(feature = "regexp")]
         ProtoIntrinsics::RegExp => agent.heap.create(RegExpHeapData::default()).into(),
         #[cfg(feature = "regexp")]
@@ -2012,6 +2035,8 @@ fn get_intrinsic_constructor<'a>(
 ) -> Option<Function<'a>> {
     let intrinsics = agent.get_realm_record_by_id(function_realm).intrinsics();
     match intrinsic_default_proto {
+        #[cfg(feature = "web-abort")]
+        ProtoIntrinsics::AbortController => Some(intrinsics.abort_controller().into()),
         ProtoIntrinsics::AggregateError => Some(intrinsics.aggregate_error().into()),
         ProtoIntrinsics::Array => Some(intrinsics.array().into()),
         ProtoIntrinsics::ArrayIterator => None,
@@ -2027,6 +2052,8 @@ fn get_intrinsic_constructor<'a>(
         ProtoIntrinsics::BigInt64Array => Some(intrinsics.big_int64_array().into()),
         #[cfg(feature = "array-buffer")]
         ProtoIntrinsics::BigUint64Array => Some(intrinsics.big_uint64_array().into()),
+        #[cfg(feature = "web-blob")]
+        ProtoIntrinsics::Blob => Some(intrinsics.blob().into()),
         ProtoIntrinsics::Boolean => Some(intrinsics.boolean().into()),
         #[cfg(feature = "array-buffer")]
         ProtoIntrinsics::DataView => Some(intrinsics.data_view().into()),
@@ -2036,6 +2063,12 @@ fn get_intrinsic_constructor<'a>(
         ProtoIntrinsics::Date => Some(intrinsics.date().into()),
         ProtoIntrinsics::Error => Some(intrinsics.error().into()),
         ProtoIntrinsics::EvalError => Some(intrinsics.eval_error().into()),
+        #[cfg(feature = "web-events")]
+        ProtoIntrinsics::Event => Some(intrinsics.event().into()),
+        #[cfg(feature = "web-events")]
+        ProtoIntrinsics::EventTarget => Some(intrinsics.event_target().into()),
+        #[cfg(feature = "web-blob")]
+        ProtoIntrinsics::File => Some(intrinsics.file().into()),
         ProtoIntrinsics::FinalizationRegistry => Some(intrinsics.finalization_registry().into()),
         #[cfg(feature = "proposal-float16array")]
         ProtoIntrinsics::Float16Array => Some(intrinsics.float16_array().into()),
@@ -2046,12 +2079,18 @@ fn get_intrinsic_constructor<'a>(
         ProtoIntrinsics::Function => Some(intrinsics.function().into()),
         ProtoIntrinsics::Generator => None,
         ProtoIntrinsics::GeneratorFunction => Some(intrinsics.generator_function().into()),
+        #[cfg(feature = "web-fetch")]
+        ProtoIntrinsics::Headers => Some(intrinsics.headers().into()),
         #[cfg(feature = "array-buffer")]
         ProtoIntrinsics::Int16Array => Some(intrinsics.int16_array().into()),
         #[cfg(feature = "array-buffer")]
         ProtoIntrinsics::Int32Array => Some(intrinsics.int32_array().into()),
         #[cfg(feature = "array-buffer")]
         ProtoIntrinsics::Int8Array => Some(intrinsics.int8_array().into()),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::IntlDateTimeFormat => Some(intrinsics.intl_date_time_format().into()),
+        #[cfg(feature = "intl")]
+        ProtoIntrinsics::IntlNumberFormat => Some(intrinsics.intl_number_format().into()),
         ProtoIntrinsics::Iterator => Some(intrinsics.iterator().into()),
         ProtoIntrinsics::Map => Some(intrinsics.map().into()),
         ProtoIntrinsics::MapIterator => None,
@@ -2059,9 +2098,19 @@ fn get_intrinsic_constructor<'a>(
         ProtoIntrinsics::Object => Some(intrinsics.object().into()),
         ProtoIntrinsics::Promise => Some(intrinsics.promise().into()),
         ProtoIntrinsics::RangeError => Some(intrinsics.range_error().into()),
+        #[cfg(feature = "web-streams")]
+        ProtoIntrinsics::ReadableStream => Some(intrinsics.readable_stream().into()),
+        #[cfg(feature = "web-streams")]
+        ProtoIntrinsics::ReadableStreamDefaultReader => {
+            Some(intrinsics.readable_stream_default_reader().into())
+        }
         ProtoIntrinsics::ReferenceError => Some(intrinsics.reference_error().into()),
         #[cfg(feature = "regexp")]
         ProtoIntrinsics::RegExp => Some(intrinsics.reg_exp().into()),
+        #[cfg(feature = "web-fetch")]
+        ProtoIntrinsics::Request => Some(intrinsics.request().into()),
+        #[cfg(feature = "web-fetch")]
+        ProtoIntrinsics::Response => Some(intrinsics.response().into()),
         #[cfg(feature = "set")]
         ProtoIntrinsics::Set => Some(intrinsics.set().into()),
         #[cfg(feature = "set")]
@@ -24,7 +24,7 @@ use crate::{
 use small_string::SmallString;
 
 use super::{
-    ObjectShape, PropertyLookupCache, ordinary_own_property_keys, ordinary_try_get,
+    ObjectShape, PropertyLookupCache, ordinary_own_property_keys_into, ordinary_try_get,
     ordinary_try_has_property, ordinary_try_set,
 };
 
@@ -446,14 +446,15 @@ impl<'a> InternalMethods<'a> for PrimitiveObject<'a> {
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         if let Ok(string) = String::try_from(self.get(agent).data) {
             let len = string.utf16_len_(agent);
-            let mut keys = Vec::with_capacity(len + 1);
+            keys.reserve(len + 1);
 
             // Insert keys for every index into the string.
             keys.extend(
@@ -461,40 +462,31 @@ impl<'a> InternalMethods<'a> for PrimitiveObject<'a> {
                     .map(|idx| PropertyKey::Integer(SmallInteger::try_from(idx as u64).unwrap())),
             );
 
-            let backing_object_keys;
-            let (integer_keys, other_keys) = match self.get_backing_object(agent) {
-                Some(backing_object) => {
-                    backing_object_keys = ordinary_own_property_keys(agent, backing_object, gc);
-                    if let Some(PropertyKey::Integer(smi)) = backing_object_keys.first() {
-                        debug_assert!(smi.into_i64() >= len as i64);
-                    }
-                    let split_idx = backing_object_keys
-                        .iter()
-                        .position(|pk| !pk.is_array_index());
-                    if let Some(idx) = split_idx {
-                        backing_object_keys.split_at(idx)
-                    } else {
-                        (&backing_object_keys[..], &[][..])
-                    }
-                }
-                None => (&[][..], &[][..]),
+            let Some(backing_object) = self.get_backing_object(agent) else {
+                keys.push(BUILTIN_STRING_MEMORY.length.into());
+                return TryResult::Continue(());
             };
+            let backing_object_start = keys.len();
+            ordinary_own_property_keys_into(agent, backing_object, keys, gc);
+            if let Some(PropertyKey::Integer(smi)) = keys.get(backing_object_start) {
+                debug_assert!(smi.into_i64() >= len as i64);
+            }
 
             // Insert the `length` key as the first non-array index key.
-            keys.extend(integer_keys);
-            keys.push(BUILTIN_STRING_MEMORY.length.into());
-            keys.extend(other_keys);
+            let split_idx = keys[backing_object_start..]
+                .iter()
+                .position(|pk| !pk.is_array_index())
+                .map_or(keys.len(), |idx| backing_object_start + idx);
+            keys.insert(split_idx, BUILTIN_STRING_MEMORY.length.into());
 
-            return TryResult::Continue(keys);
+            return TryResult::Continue(());
         }
 
         // 1. Return OrdinaryOwnPropertyKeys(O).
-        match self.get_backing_object(agent) {
-            Some(backing_object) => {
-                TryResult::Continue(ordinary_own_property_keys(agent, backing_object, gc))
-            }
-            None => TryResult::Continue(vec![]),
+        if let Some(backing_object) = self.get_backing_object(agent) {
+            ordinary_own_property_keys_into(agent, backing_object, keys, gc);
         }
+        TryResult::Continue(())
     }
 }
 
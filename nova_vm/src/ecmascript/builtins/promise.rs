@@ -3,13 +3,18 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod data;
+#[cfg(feature = "futures")]
+mod future;
 
 pub(crate) use data::*;
+#[cfg(feature = "futures")]
+pub use future::*;
 
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, InternalMethods, InternalSlots, JsError, JsResult,
-        OrdinaryObject, PromiseCapability, ProtoIntrinsics, Value, get, object_handle,
+        OrdinaryObject, PromiseCapability, PromiseRejectionTrackerOperation, ProtoIntrinsics,
+        Value, get, object_handle,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
@@ -45,7 +50,8 @@ use crate::{
 ///
 /// ## Support status
 ///
-/// `Promise` in Nova does not currently support subclassing.
+/// `Promise` in Nova supports subclassing only when the subclass constructor
+/// creates a built-in promise by calling `super`.
 ///
 /// [Job]: crate::ecmascript::Job
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -55,8 +61,13 @@ object_handle!(Promise);
 arena_vec_access!(Promise, 'a, PromiseHeapData, promises);
 
 impl<'a> Promise<'a> {
-    /// Create a new resolved Promise.
-    pub(crate) fn new_resolved(agent: &mut Agent, value: Value<'a>) -> Self {
+    /// Create a new Promise that is already fulfilled with the given value.
+    ///
+    /// This is cheaper than creating a [`PromiseCapability`] and resolving
+    /// it, and is meant for host functions that already have their result.
+    /// The value is not unwrapped: if it may be a thenable, resolve a
+    /// [`PromiseCapability`] with it instead.
+    pub fn new_resolved(agent: &mut Agent, value: Value<'a>) -> Self {
         agent.heap.create(PromiseHeapData {
             object_index: None,
             promise_state: PromiseState::Fulfilled {
@@ -65,9 +76,13 @@ impl<'a> Promise<'a> {
         })
     }
 
-    /// Create a new rejected, unhandled Promise.
-    pub(crate) fn new_rejected(agent: &mut Agent, error: Value, gc: NoGcScope<'a, '_>) -> Self {
-        agent
+    /// Create a new Promise that is already rejected with the given error.
+    ///
+    /// This is cheaper than creating a [`PromiseCapability`] and rejecting
+    /// it. The rejection is reported as unhandled at the next microtask
+    /// checkpoint unless a handler is added before then.
+    pub fn new_rejected(agent: &mut Agent, error: Value, gc: NoGcScope<'a, '_>) -> Self {
+        let promise = agent
             .heap
             .create(PromiseHeapData {
                 object_index: None,
@@ -76,7 +91,11 @@ impl<'a> Promise<'a> {
                     is_handled: false,
                 },
             })
-            .bind(gc)
+            .bind(gc);
+        // NOTE: Rejecting a new Promise performs
+        // HostPromiseRejectionTracker(promise, "reject").
+        agent.host_promise_rejection_tracker(promise, PromiseRejectionTrackerOperation::Reject);
+        promise
     }
 
     /// Get the result of a resolved Promise, or None if the Promise is not
@@ -95,6 +114,28 @@ impl<'a> Promise<'a> {
         }
     }
 
+    /// Returns true if the Promise is rejected and has been handled, or if
+    /// the Promise is not rejected.
+    pub(crate) fn is_handled(self, agent: &Agent) -> bool {
+        match &self.get(agent).promise_state {
+            PromiseState::Rejected { is_handled, .. } => *is_handled,
+            _ => true,
+        }
+    }
+
+    /// Set \[\[PromiseIsHandled]] of a rejected Promise to true, performing
+    /// HostPromiseRejectionTracker(promise, "handle") if it was not yet
+    /// handled. Has no effect on Promises that are not rejected.
+    #[cfg(feature = "web-streams")]
+    pub(crate) fn set_handled(self, agent: &mut Agent) {
+        if let PromiseState::Rejected { is_handled, .. } = &mut self.get_mut(agent).promise_state
+            && !*is_handled
+        {
+            *is_handled = true;
+            agent.host_promise_rejection_tracker(self, PromiseRejectionTrackerOperation::Handle);
+        }
+    }
+
     pub(crate) fn set_already_resolved(self, agent: &mut Agent) {
         match &mut self.get_mut(agent).promise_state {
             PromiseState::Pending { is_resolved, .. } => *is_resolved = true,
@@ -103,11 +144,21 @@ impl<'a> Promise<'a> {
     }
 
     /// ### [27.2.4.7.1 PromiseResolve ( C, x )](https://tc39.es/ecma262/#sec-promise-resolve)
-    pub(crate) fn resolve(
+    ///
+    /// PromiseResolve with C set to the current Realm's %Promise%.
+    pub(crate) fn resolve(agent: &mut Agent, x: Value, gc: GcScope<'a, '_>) -> JsResult<'a, Self> {
+        let c = agent.current_realm_record().intrinsics().promise();
+        Self::resolve_with_constructor(agent, c.into(), x, gc)
+    }
+
+    /// ### [27.2.4.7.1 PromiseResolve ( C, x )](https://tc39.es/ecma262/#sec-promise-resolve)
+    pub(crate) fn resolve_with_constructor(
         agent: &mut Agent,
+        c: Value,
         x: Value,
         mut gc: GcScope<'a, '_>,
     ) -> JsResult<'a, Self> {
+        let c = c.scope(agent, gc.nogc());
         let x = x.bind(gc.nogc());
         // 1. If IsPromise(x) is true, then
         let x = if let Value::Promise(x) = x {
@@ -128,16 +179,22 @@ impl<'a> Promise<'a> {
             // SAFETY: not shared.
             let x = unsafe { scoped_x.take(agent) }.bind(gc.nogc());
             // b. If SameValue(xConstructor, C) is true, return x.
-            if x_constructor == agent.current_realm_record().intrinsics().promise().into() {
+            if x_constructor == c.get(agent) {
                 return Ok(x.unbind().bind(gc.into_nogc()));
             }
             x.into()
         } else {
             x
         };
+        let x = x.scope(agent, gc.nogc());
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
-        let promise_capability = PromiseCapability::new(agent, gc.nogc());
+        let promise_capability =
+            PromiseCapability::new_from_constructor(agent, c.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
         let promise = promise_capability.promise().scope(agent, gc.nogc());
+        // SAFETY: not shared.
+        let x = unsafe { x.take(agent) }.bind(gc.nogc());
         // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
         promise_capability
             .unbind()
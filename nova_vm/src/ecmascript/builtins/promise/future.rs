@@ -0,0 +1,230 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Futures
+//!
+//! Bridging between JavaScript Promises and Rust futures, independent of any
+//! async runtime.
+//!
+//! A Promise is awaited in Rust using [`Promise::into_future`]: the returned
+//! [`PromiseFuture`] is woken by a promise reaction job once the Promise
+//! settles. A Rust future is awaited in JavaScript using
+//! [`Promise::from_future`]: the returned [`PromiseTask`] drives the future
+//! and, once it completes, enqueues a generic job that settles the Promise.
+//!
+//! In both directions the Promise is only ever settled or read inside jobs
+//! run by the embedder on the Agent's thread. Neither type is `Send`; they
+//! must be run on a single-threaded executor on the thread that owns the
+//! Agent.
+
+use core::{
+    cell::RefCell,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::rc::Rc;
+
+use crate::{
+    ecmascript::{
+        Agent, InnerJob, IntoJsResult, Job, JsResult, Promise, PromiseCapability,
+        PromiseReactionHandler, PromiseReactionType, Value, inner_promise_then,
+    },
+    engine::{Bindable, GcScope, Global, NoGcScope},
+};
+
+/// The settlement of a Promise awaited as a Rust future: the fulfillment
+/// value, or the rejection reason as an error.
+///
+/// The values are rooted and must be released using [`Global::take`].
+pub type PromiseFutureResult = Result<Global<Value<'static>>, Global<Value<'static>>>;
+
+/// State shared between a [`PromiseFuture`] and the promise reaction that
+/// settles it.
+#[derive(Debug, Default)]
+pub(crate) struct PromiseFutureSlot {
+    result: Option<PromiseFutureResult>,
+    waker: Option<Waker>,
+}
+
+/// A Rust future that completes when a Promise settles.
+///
+/// See [`Promise::into_future`]. Note that the settled value is rooted when
+/// the Promise settles: dropping the future after that but before polling it
+/// to completion leaks the rooted value.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct PromiseFuture(Rc<RefCell<PromiseFutureSlot>>);
+
+impl Future for PromiseFuture {
+    type Output = PromiseFutureResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.borrow_mut();
+        if let Some(result) = slot.result.take() {
+            return Poll::Ready(result);
+        }
+        match &mut slot.waker {
+            Some(waker) => waker.clone_from(cx.waker()),
+            waker => *waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+/// A task that drives a Rust future and settles a Promise with its output.
+///
+/// See [`Promise::from_future`].
+#[must_use = "futures do nothing unless polled"]
+pub struct PromiseTask(Pin<Box<dyn Future<Output = ()>>>);
+
+impl fmt::Debug for PromiseTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PromiseTask").finish_non_exhaustive()
+    }
+}
+
+impl Future for PromiseTask {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+/// Type-erased output of a future passed to [`Promise::from_future`].
+trait FutureOutput {
+    fn into_js_result<'gc>(
+        self: Box<Self>,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>>;
+}
+
+impl<T: IntoJsResult> FutureOutput for T {
+    fn into_js_result<'gc>(
+        self: Box<Self>,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        IntoJsResult::into_js_result(*self, agent, gc)
+    }
+}
+
+struct PromiseFutureJobInner {
+    promise_to_resolve: Global<Promise<'static>>,
+    output: Box<dyn FutureOutput + Send>,
+}
+
+/// Job settling a Promise created by [`Promise::from_future`] with the output
+/// of its future.
+#[repr(transparent)]
+pub(crate) struct PromiseFutureJob(Box<PromiseFutureJobInner>);
+
+impl PromiseFutureJob {
+    pub(crate) fn run<'gc>(self, agent: &mut Agent, mut gc: GcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        let PromiseFutureJobInner {
+            promise_to_resolve,
+            output,
+        } = *self.0;
+        let promise = promise_to_resolve.take(agent).bind(gc.nogc());
+        let capability = PromiseCapability::from_promise(promise, true);
+        match output.into_js_result(agent, gc.nogc()) {
+            Ok(value) => capability
+                .unbind()
+                .resolve(agent, value.unbind(), gc.reborrow()),
+            Err(err) => capability.reject(agent, err.value(), gc.nogc()),
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Promise<'a> {
+    /// Await this Promise as a Rust future.
+    ///
+    /// The future completes with the fulfillment value or the rejection
+    /// reason of the Promise. Like a `then` call, this marks the Promise as
+    /// handled. The future is only woken by a promise job: the embedder must
+    /// keep running the Agent's promise jobs for it to complete.
+    pub fn into_future(self, agent: &mut Agent, gc: NoGcScope) -> PromiseFuture {
+        let slot = Rc::new(RefCell::new(PromiseFutureSlot::default()));
+        let futures = &mut agent.promise_futures;
+        let index = if let Some(index) = futures.iter().position(Option::is_none) {
+            futures[index] = Some(slot.clone());
+            index
+        } else {
+            futures.push(Some(slot.clone()));
+            futures.len() - 1
+        };
+        let handler = PromiseReactionHandler::Future(index as u32);
+        inner_promise_then(agent, self, handler, handler, None, gc);
+        PromiseFuture(slot)
+    }
+
+    /// Create a Promise that settles with the output of a Rust future.
+    ///
+    /// The future is not run until the returned [`PromiseTask`] is spawned
+    /// on the embedder's executor. When the future completes, a Job settling
+    /// the Promise is passed to [`HostHooks::enqueue_generic_job`]; its output
+    /// is converted into a JavaScript value when the Job is run. An `Err`
+    /// output rejects the Promise with an `Error`. Like Jobs, the output must
+    /// be `Send`; the future itself need not be.
+    ///
+    /// [`HostHooks::enqueue_generic_job`]: crate::ecmascript::HostHooks::enqueue_generic_job
+    pub fn from_future<F>(
+        agent: &mut Agent,
+        future: F,
+        gc: NoGcScope<'a, '_>,
+    ) -> (Self, PromiseTask)
+    where
+        F: Future + 'static,
+        F::Output: IntoJsResult + Send + 'static,
+    {
+        let promise = PromiseCapability::new(agent, gc).promise();
+        let promise_to_resolve = Global::new(agent, promise.unbind());
+        let realm = Global::new(agent, agent.current_realm(gc).unbind());
+        let host_hooks = agent.host_hooks;
+        let task = async move {
+            let output = future.await;
+            host_hooks.enqueue_generic_job(Job {
+                realm: Some(realm),
+                inner: InnerJob::Future(PromiseFutureJob(Box::new(PromiseFutureJobInner {
+                    promise_to_resolve,
+                    output: Box::new(output),
+                }))),
+            });
+        };
+        (promise, PromiseTask(Box::pin(task)))
+    }
+}
+
+/// Settle the [`PromiseFuture`] awaiting a Promise, and wake its task.
+pub(crate) fn settle_promise_future(
+    agent: &mut Agent,
+    index: u32,
+    reaction_type: PromiseReactionType,
+    argument: Value,
+) {
+    let futures = &mut agent.promise_futures;
+    let slot = futures[index as usize]
+        .take()
+        .expect("Promise future was already settled");
+    while futures.last().is_some_and(Option::is_none) {
+        futures.pop();
+    }
+    if Rc::strong_count(&slot) == 1 {
+        // The future was dropped: nothing is waiting for the result.
+        return;
+    }
+    let argument = Global::new(agent, argument.unbind());
+    let mut slot = slot.borrow_mut();
+    slot.result = Some(match reaction_type {
+        PromiseReactionType::Fulfill => Ok(argument),
+        PromiseReactionType::Reject => Err(argument),
+    });
+    if let Some(waker) = slot.waker.take() {
+        waker.wake();
+    }
+}
@@ -4,28 +4,29 @@
 
 mod abstract_operations;
 mod data;
+mod revoker_function;
 
 pub(crate) use abstract_operations::*;
 pub(crate) use data::*;
-
-use std::collections::VecDeque;
+pub use revoker_function::*;
 
 use ahash::AHashSet;
 
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, ExceptionType, Function, InternalMethods,
-        InternalSlots, JsResult, Object, ObjectShape, OrdinaryObject, PropertyDescriptor,
-        PropertyKey, PropertyLookupCache, PropertyOffset, SetAtOffsetProps, SetResult, String,
-        TryError, TryGetResult, TryHasResult, TryResult, Value, call, call_function, construct,
-        create_array_from_list, create_property_key_list_from_array_like, get_object_method,
-        is_callable, is_compatible_property_descriptor, is_constructor, is_extensible,
-        object_handle, same_value, to_boolean, try_get_object_method, try_result_into_js,
+        InternalSlots, JsError, JsResult, Object, ObjectShape, OrdinaryObject, PropertyDescriptor,
+        PropertyKey, PropertyKeySet, PropertyLookupCache, PropertyOffset, SetAtOffsetProps,
+        SetResult, String, TryError, TryGetResult, TryHasResult, TryResult, Value, call,
+        call_function, construct, create_array_from_list, create_property_key_list_from_array_like,
+        get_object_method, is_callable, is_compatible_property_descriptor, is_constructor,
+        is_extensible, object_handle, same_value, to_boolean, try_get_object_method,
+        try_result_into_js,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, ScopableCollection},
     heap::{
-        ArenaAccess, BaseIndex, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep,
-        HeapSweepWeakReference, WorkQueues, arena_vec_access,
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
     },
 };
 
@@ -45,6 +46,10 @@ pub struct Proxy<'a>(BaseIndex<'a, ProxyHeapData<'static>>);
 object_handle!(Proxy);
 arena_vec_access!(Proxy, 'a, ProxyHeapData, proxies);
 
+/// Trap results of the ownKeys trap with at most this many keys are checked
+/// for duplicates by linear search instead of hashing.
+const SMALL_KEY_LIST_LENGTH: usize = 16;
+
 impl Proxy<'_> {
     pub(crate) fn is_callable(self, agent: &Agent, gc: NoGcScope) -> bool {
         match self.get(agent) {
@@ -57,10 +62,24 @@ impl Proxy<'_> {
                     is_callable(*proxy_target, gc).is_some()
                 }
             }
-            ProxyHeapData::_RevokedCallable => true,
-            ProxyHeapData::_Revoked => false,
+            ProxyHeapData::RevokedCallable => true,
+            ProxyHeapData::Revoked => false,
         }
     }
+
+    /// Revoke the Proxy, setting its \[\[ProxyTarget]] and
+    /// \[\[ProxyHandler]] to null.
+    ///
+    /// A revoked Proxy remembers whether it was callable, as its \[\[Call]]
+    /// internal method is not removed by revocation.
+    pub(crate) fn revoke(self, agent: &mut Agent, gc: NoGcScope) {
+        let revoked = if self.is_callable(agent, gc) {
+            ProxyHeapData::RevokedCallable
+        } else {
+            ProxyHeapData::Revoked
+        };
+        *self.get_mut(agent) = revoked;
+    }
 }
 
 impl<'a> InternalSlots<'a> for Proxy<'a> {
@@ -1425,11 +1444,12 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
         Ok(true)
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         _: &mut Agent,
+        _: &mut Vec<PropertyKey<'gc>>,
         _: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         TryError::GcError.into()
     }
 
@@ -1501,22 +1521,29 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
         .unbind()?
         .bind(gc.nogc());
         // 9. If trapResult contains any duplicate entries, throw a TypeError exception.
-        let mut unique_trap_results = Vec::with_capacity(trap_result.len(agent));
-        for value in trap_result.iter(agent) {
-            let p = value.get(gc.nogc());
-            if unique_trap_results.contains(&p) {
-                let message = format!(
-                    "proxy [[OwnPropertyKeys]] can't report property '{}' more than once",
-                    p.as_display(agent),
-                );
-                return Err(agent.throw_exception(
-                    ExceptionType::TypeError,
-                    message,
-                    gc.into_nogc(),
-                ));
+        let trap_result_len = trap_result.len(agent);
+        let trap_result_set = if trap_result_len <= SMALL_KEY_LIST_LENGTH {
+            for (i, value) in trap_result.iter(agent).enumerate() {
+                let p = value.get(gc.nogc());
+                if trap_result
+                    .iter(agent)
+                    .take(i)
+                    .any(|other| other.get(gc.nogc()) == p)
+                {
+                    return Err(throw_duplicate_key(agent, p, gc.nogc()).unbind());
+                }
             }
-            unique_trap_results.push(p);
-        }
+            None
+        } else {
+            let mut set = PropertyKeySet::with_capacity(trap_result_len, gc.nogc());
+            for value in trap_result.iter(agent) {
+                let p = value.get(gc.nogc());
+                if !set.insert(agent, p) {
+                    return Err(throw_duplicate_key(agent, p, gc.nogc()).unbind());
+                }
+            }
+            Some(set.scope(agent, gc.nogc()))
+        };
         // 10. Let extensibleTarget be ? IsExtensible(target).
         let extensible_target =
             is_extensible(agent, scoped_target.get(agent), gc.reborrow()).unbind()?;
@@ -1577,12 +1604,19 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
         }
         let target_configurable_keys = target_configurable_keys.take(agent);
         let target_nonconfigurable_keys = target_nonconfigurable_keys.take(agent);
+        let trap_result_contains = |agent: &Agent, key: PropertyKey| match &trap_result_set {
+            Some(set) => set.contains(agent, key),
+            None => trap_result.contains(&key),
+        };
         // 18. Let uncheckedResultKeys be a List whose elements are the elements of trapResult.
-        let mut unchecked_result_keys = VecDeque::from(trap_result.clone());
+        // NOTE: Neither trapResult nor targetKeys contain duplicate entries,
+        // so removing a key from uncheckedResultKeys is equivalent to counting
+        // it as checked.
+        let mut checked_result_keys = 0;
         // 19. For each element key of targetNonconfigurableKeys, do
         for key in target_nonconfigurable_keys {
             // a. If uncheckedResultKeys does not contain key, throw a TypeError exception.
-            if !unchecked_result_keys.contains(&key) {
+            if !trap_result_contains(agent, key) {
                 return Err(agent.throw_exception(
                     ExceptionType::TypeError,
                     format!(
@@ -1592,13 +1626,8 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
                     gc,
                 ));
             }
-            if let Some(pos) = unchecked_result_keys
-                .iter()
-                .position(|unchecked_key| *unchecked_key == key)
-            {
-                // b. Remove the key from uncheckedResultKeys
-                unchecked_result_keys.remove(pos);
-            }
+            // b. Remove key from uncheckedResultKeys.
+            checked_result_keys += 1;
         }
         // 20. If extensibleTarget is true, return trapResult.
         if extensible_target {
@@ -1607,23 +1636,18 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
         // 21. For each element key of targetConfigurableKeys, do
         for key in target_configurable_keys {
             // a. If uncheckedResultKeys does not contain key, throw a TypeError exception.
-            if !unchecked_result_keys.contains(&key) {
+            if !trap_result_contains(agent, key) {
                 return Err(agent.throw_exception(
                     ExceptionType::TypeError,
                     format!("proxy can't report an existing own property '{}' as non-existent on a non-extensible object", key.as_display(agent)),
                     gc,
                 ));
             }
-            if let Some(pos) = unchecked_result_keys
-                .iter()
-                .position(|unchecked_key| *unchecked_key == key)
-            {
-                // b. Remove the key from uncheckedResultKeys
-                unchecked_result_keys.remove(pos);
-            }
+            // b. Remove key from uncheckedResultKeys.
+            checked_result_keys += 1;
         }
         // 22. If uncheckedResultKeys is not empty, throw a TypeError exception.
-        if !unchecked_result_keys.is_empty() {
+        if checked_result_keys < trap_result.len() {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
                 "trap returned extra keys but proxy target is non-extensible",
@@ -1851,6 +1875,18 @@ impl<'a> InternalMethods<'a> for Proxy<'a> {
     }
 }
 
+fn throw_duplicate_key<'gc>(
+    agent: &mut Agent,
+    key: PropertyKey,
+    gc: NoGcScope<'gc, '_>,
+) -> JsError<'gc> {
+    let message = format!(
+        "proxy [[OwnPropertyKeys]] can't report property '{}' more than once",
+        key.as_display(agent),
+    );
+    agent.throw_exception(ExceptionType::TypeError, message, gc)
+}
+
 /// ### [10.5.15 ProxyCreate ( target, handler )](https://tc39.es/ecma262/#sec-proxycreate)
 ///
 /// The abstract operation ProxyCreate takes arguments target (an ECMAScript
@@ -18,9 +18,9 @@ pub(crate) enum ProxyHeapData<'a> {
         proxy_handler: Object<'a>,
     },
     /// A callable Proxy was revoked.
-    _RevokedCallable,
+    RevokedCallable,
     /// A non-callable Proxy was revoked.
-    _Revoked,
+    Revoked,
 }
 
 bindable_handle!(ProxyHeapData);
@@ -0,0 +1,152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, FunctionInternalProperties, JsResult, OrdinaryObject, String, Value,
+        function_handle,
+    },
+    engine::{Bindable, GcScope, bindable_handle},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+use super::Proxy;
+
+/// ### [28.2.2.1.1 Proxy Revocation Functions](https://tc39.es/ecma262/#sec-proxy-revocation-functions)
+///
+/// A Proxy revocation function is an anonymous built-in function with a
+/// \[\[RevocableProxy]] internal slot.
+///
+/// The "length" property of a Proxy revocation function is 0𝔽.
+#[derive(Debug, Clone)]
+pub(crate) struct ProxyRevokerFunctionHeapData<'a> {
+    backing_object: Option<OrdinaryObject<'a>>,
+    /// \[\[RevocableProxy]]
+    revocable_proxy: Option<Proxy<'a>>,
+}
+bindable_handle!(ProxyRevokerFunctionHeapData);
+
+/// Special functions created by `Proxy.revocable` to revoke a Proxy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BuiltinProxyRevokerFunction<'a>(BaseIndex<'a, ProxyRevokerFunctionHeapData<'static>>);
+function_handle!(BuiltinProxyRevokerFunction);
+arena_vec_access!(BuiltinProxyRevokerFunction, 'a, ProxyRevokerFunctionHeapData, proxy_revoker_functions);
+
+impl<'f> BuiltinProxyRevokerFunction<'f> {
+    /// Create a new revoker function for the given Proxy.
+    pub(crate) fn new(agent: &mut Agent, proxy: Proxy<'f>) -> Self {
+        agent.heap.create(ProxyRevokerFunctionHeapData {
+            backing_object: None,
+            revocable_proxy: Some(proxy),
+        })
+    }
+}
+
+impl<'a> FunctionInternalProperties<'a> for BuiltinProxyRevokerFunction<'a> {
+    fn get_name(self, _: &Agent) -> &String<'a> {
+        &String::EMPTY_STRING
+    }
+
+    fn get_length(self, _: &Agent) -> u8 {
+        0
+    }
+
+    #[inline(always)]
+    fn get_function_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.unbind().get(agent).backing_object
+    }
+
+    fn set_function_backing_object(
+        self,
+        agent: &mut Agent,
+        backing_object: OrdinaryObject<'static>,
+    ) {
+        assert!(
+            self.get_mut(agent)
+                .backing_object
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+
+    fn function_call<'gc>(
+        self,
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments_list: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // a. Let F be the active function object.
+        // b. Let p be F.[[RevocableProxy]].
+        // d. Set F.[[RevocableProxy]] to null.
+        let Some(p) = self.get_mut(agent).revocable_proxy.take() else {
+            // c. If p is null, return NormalCompletion(undefined).
+            return Ok(Value::Undefined);
+        };
+        // e. Assert: p is a Proxy exotic object.
+        // f. Set p.[[ProxyTarget]] to null.
+        // g. Set p.[[ProxyHandler]] to null.
+        p.revoke(agent, gc);
+        // h. Return NormalCompletion(undefined).
+        Ok(Value::Undefined)
+    }
+}
+
+impl<'a> CreateHeapData<ProxyRevokerFunctionHeapData<'a>, BuiltinProxyRevokerFunction<'a>>
+    for Heap
+{
+    fn create(
+        &mut self,
+        data: ProxyRevokerFunctionHeapData<'a>,
+    ) -> BuiltinProxyRevokerFunction<'a> {
+        self.proxy_revoker_functions.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<ProxyRevokerFunctionHeapData<'static>>();
+
+        BuiltinProxyRevokerFunction(BaseIndex::last(&self.proxy_revoker_functions))
+    }
+}
+
+impl HeapMarkAndSweep for BuiltinProxyRevokerFunction<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.proxy_revoker_functions.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.proxy_revoker_functions.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for BuiltinProxyRevokerFunction<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .proxy_revoker_functions
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
+
+impl HeapMarkAndSweep for ProxyRevokerFunctionHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            backing_object,
+            revocable_proxy,
+        } = self;
+        backing_object.mark_values(queues);
+        revocable_proxy.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            backing_object,
+            revocable_proxy,
+        } = self;
+        backing_object.sweep_values(compactions);
+        revocable_proxy.sweep_values(compactions);
+    }
+}
@@ -5,11 +5,11 @@
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
-        BuiltinIntrinsicConstructor, ExceptionType, JsResult, Object, Realm, String, Value,
-        builders::BuiltinFunctionBuilder, proxy_create,
+        BuiltinIntrinsicConstructor, BuiltinProxyRevokerFunction, ExceptionType, JsResult, Object,
+        OrdinaryObject, Realm, String, Value, builders::BuiltinFunctionBuilder, proxy_create,
     },
     engine::{Bindable, GcScope},
-    heap::IntrinsicConstructorIndexes,
+    heap::{IntrinsicConstructorIndexes, ObjectEntry},
 };
 
 pub(crate) struct ProxyConstructor;
@@ -61,10 +61,14 @@ impl ProxyConstructor {
     fn revocable<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let target = arguments.get(0).bind(gc);
+        let handler = arguments.get(1).bind(gc);
         // 1. Let proxy be ? ProxyCreate(target, handler).
+        let proxy = proxy_create(agent, target, handler, gc)?;
         // 2. Let revokerClosure be a new Abstract Closure with no parameters that captures nothing and performs the following steps when called:
         //        a. Let F be the active function object.
         //        b. Let p be F.[[RevocableProxy]].
@@ -76,11 +80,27 @@ impl ProxyConstructor {
         //        h. Return NormalCompletion(undefined).
         // 3. Let revoker be CreateBuiltinFunction(revokerClosure, 0, "", « [[RevocableProxy]] »).
         // 4. Set revoker.[[RevocableProxy]] to proxy.
+        let revoker = BuiltinProxyRevokerFunction::new(agent, proxy);
         // 5. Let result be OrdinaryObjectCreate(%Object.prototype%).
         // 6. Perform ! CreateDataPropertyOrThrow(result, "proxy", proxy).
         // 7. Perform ! CreateDataPropertyOrThrow(result, "revoke", revoker).
+        let result = OrdinaryObject::create_object(
+            agent,
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .object_prototype()
+                    .into(),
+            ),
+            &[
+                ObjectEntry::new_data_entry(BUILTIN_STRING_MEMORY.proxy.into(), proxy.into()),
+                ObjectEntry::new_data_entry(BUILTIN_STRING_MEMORY.revoke.into(), revoker.into()),
+            ],
+        )
+        .expect("Should perform GC here");
         // 8. Return result.
-        Err(agent.todo("Proxy.revocable", gc.into_nogc()))
+        Ok(result.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -384,20 +384,20 @@ impl<'a> InternalMethods<'a> for RegExp<'a> {
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        TryResult::Continue(
-            if let Some(backing_object) = self.get_backing_object(agent) {
-                // Note: If backing object exists, it also contains the
-                // "lastIndex" key so we do not need to add it ourselves.
-                unwrap_try(backing_object.try_own_property_keys(agent, gc))
-            } else {
-                vec![BUILTIN_STRING_MEMORY.lastIndex.into()]
-            },
-        )
+    ) -> TryResult<'gc, ()> {
+        if let Some(backing_object) = self.get_backing_object(agent) {
+            // Note: If backing object exists, it also contains the
+            // "lastIndex" key so we do not need to add it ourselves.
+            unwrap_try(backing_object.try_own_property_keys_into(agent, keys, gc))
+        } else {
+            keys.push(BUILTIN_STRING_MEMORY.lastIndex.into());
+        }
+        TryResult::Continue(())
     }
 }
 
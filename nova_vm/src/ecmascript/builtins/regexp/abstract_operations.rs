@@ -639,6 +639,13 @@ pub(crate) fn reg_exp_builtin_exec<'a>(
     // 19. Assert: n < 2**32 - 1.
     debug_assert!(n < 2usize.pow(32) - 1);
     let has_group_name = matcher.capture_names().any(|n| n.is_some());
+    // Note: The group names are copied out so that the matcher isn't borrowed
+    // while the result array is being filled.
+    let group_names: Vec<Option<Box<str>>> = if has_group_name {
+        matcher.capture_names().map(|n| n.map(Box::from)).collect()
+    } else {
+        Vec::new()
+    };
     // 20. Let A be ! ArrayCreate(n + 1).
     // Note: we use n because it already contains the full-match group in it.
     let a = array_create(agent, n, n, None, gc).unwrap();
@@ -728,15 +735,27 @@ pub(crate) fn reg_exp_builtin_exec<'a>(
             gc,
         ));
         // e. If the ith capture of R was defined with a GroupName, then
-        //         i. Let s be the CapturingGroupName of that GroupName.
-        //         ii. If matchedGroupNames contains s, then
-        //                 1. Assert: capturedValue is undefined.
-        //                 2. Append undefined to groupNames.
-        //         iii. Else,
-        //                 1. If capturedValue is not undefined, append s to matchedGroupNames.
-        //                 2. NOTE: If there are multiple groups named s, groups may already have an s property at this point. However, because groups is an ordinary object whose properties are all writable data properties, the call to CreateDataPropertyOrThrow is nevertheless guaranteed to succeed.
-        //                 3. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-        //                 4. Append s to groupNames.
+        if let (Some(groups), Some(Some(name))) = (groups, group_names.get(i)) {
+            // i. Let s be the CapturingGroupName of that GroupName.
+            let name = String::from_str(agent, name, gc).to_property_key();
+            // ii. If matchedGroupNames contains s, then
+            //         1. Assert: capturedValue is undefined.
+            //         2. Append undefined to groupNames.
+            // iii. Else,
+            //         1. If capturedValue is not undefined, append s to matchedGroupNames.
+            // Note: The matcher rejects patterns with duplicate group names,
+            // so matchedGroupNames never contains s.
+            // 3. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+            unwrap_try(try_create_data_property_or_throw(
+                agent,
+                groups,
+                name,
+                captured_value,
+                None,
+                gc,
+            ));
+            // 4. Append s to groupNames.
+        }
         // f. Else,
         //         i. Append undefined to groupNames.
     }
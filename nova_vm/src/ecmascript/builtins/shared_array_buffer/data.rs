@@ -22,6 +22,12 @@ impl<'a> SharedArrayBufferRecord<'a> {
             data_block: block,
         }
     }
+
+    /// Returns the byte length of the SharedArrayBuffer's data block.
+    pub(crate) fn byte_length(&self) -> usize {
+        self.data_block
+            .byte_length(ecmascript_atomics::Ordering::Unordered)
+    }
 }
 
 impl HeapMarkAndSweep for SharedArrayBufferRecord<'static> {
@@ -5,8 +5,9 @@
 use crate::{
     ecmascript::{
         Agent, AnyArrayBuffer, ArgumentsList, ArrayBuffer, BUILTIN_STRING_MEMORY, Behaviour,
-        Builtin, BuiltinGetter, ExceptionType, JsResult, PropertyKey, ProtoIntrinsics, Realm,
-        String, Value, builders::OrdinaryObjectBuilder, construct, is_detached_buffer,
+        Builtin, BuiltinGetter, ExceptionType, JsResult, PreserveResizability, PropertyKey,
+        ProtoIntrinsics, Realm, String, Value, array_buffer_copy_and_detach,
+        builders::OrdinaryObjectBuilder, construct, is_detached_buffer,
         is_fixed_length_array_buffer, species_constructor, to_index, to_integer_or_infinity,
         try_result_into_js, try_to_index,
     },
@@ -221,6 +222,11 @@ impl ArrayBufferPrototype {
         // 13. NOTE: Neither creation of the new Data Block nor copying from
         // the old Data Block are observable. Implementations may implement
         // this method as in-place growth or shrinkage.
+        let growth = new_byte_length.saturating_sub(o.byte_length(agent));
+        agent
+            .check_data_block_allocation(growth as u64, gc.nogc())
+            .unbind()?;
+        agent.record_data_block_allocation(growth);
         // 14. Set O.[[ArrayBufferData]] to newBlock.
         // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
         o.resize(agent, new_byte_length);
@@ -369,28 +375,39 @@ impl ArrayBufferPrototype {
     /// ### [25.1.6.8 ArrayBuffer.prototype.transfer ( [ newLength ] )](https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer)
     fn transfer<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         // 1. Let O be the this value.
         // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
-        Err(agent.todo("ArrayBuffer.prototype.transfer", gc.into_nogc()))
+        array_buffer_copy_and_detach(
+            agent,
+            this_value,
+            arguments.get(0),
+            PreserveResizability::PreserveResizability,
+            gc,
+        )
+        .map(|array_buffer| array_buffer.into())
     }
 
     /// ### [25.1.6.9 ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )](https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength)
     fn transfer_to_fixed_length<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         // 1. Let O be the this value.
         // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
-        Err(agent.todo(
-            "ArrayBuffer.prototype.transferToFixedLength",
-            gc.into_nogc(),
-        ))
+        array_buffer_copy_and_detach(
+            agent,
+            this_value,
+            arguments.get(0),
+            PreserveResizability::FixedLength,
+            gc,
+        )
+        .map(|array_buffer| array_buffer.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
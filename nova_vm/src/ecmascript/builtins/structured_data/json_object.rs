@@ -2,19 +2,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use sonic_rs::{JsonContainerTrait, JsonValueTrait};
-use wtf8::{CodePoint, Wtf8Buf};
+mod json_parser;
+
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
 
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType, Function,
         InternalMethods, JsResult, Number, Object, Primitive, PrimitiveObject, PrimitiveObjectData,
         PropertyDescriptor, PropertyKey, ProtoIntrinsics, Realm, ScopedPropertyKey, SmallInteger,
-        String, Value, array_create, builders::OrdinaryObjectBuilder, call_function,
-        create_data_property, enumerable_own_keys, get, get_v, is_array, is_callable,
-        length_of_array_like, ordinary_object_create_with_intrinsics, scoped_enumerable_own_keys,
+        String, Value, builders::OrdinaryObjectBuilder, call_function, create_data_property,
+        enumerable_own_keys, get, get_v, is_array, is_callable, length_of_array_like,
+        ordinary_object_create_with_intrinsics, scoped_enumerable_own_keys,
         to_integer_or_infinity_number, to_number, to_property_key, to_property_key_simple,
-        to_string, try_create_data_property, try_create_data_property_or_throw, unwrap_try,
+        to_string, try_create_data_property_or_throw, unwrap_try,
     },
     engine::{
         Bindable, GcScope, NoGcScope, Scopable, ScopableCollection, Scoped, ScopedCollection,
@@ -89,28 +90,22 @@ impl JSONObject {
             .bind(gc.nogc());
 
         // 2. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
-        let json_value =
-            match sonic_rs::from_str::<sonic_rs::Value>(&json_string.to_string_lossy_(agent)) {
-                Ok(value) => value,
-                Err(error) => {
-                    return Err(agent.throw_exception(
-                        ExceptionType::SyntaxError,
-                        error.to_string(),
-                        gc.into_nogc(),
-                    ));
-                }
-            };
+        let unfiltered = match json_parser::parse_json_text(agent, json_string.unbind(), gc.nogc())
+        {
+            Ok(value) => value,
+            Err(error) => return Err(error.throw(agent, json_string.unbind(), gc.into_nogc())),
+        };
 
         // 3. Let scriptString be the string-concatenation of "(", jsonString, and ");".
         // 4. Let script be ParseText(scriptString, Script).
         // 5. NOTE: The early error rules defined in 13.2.5.1 have special handling for the above invocation of ParseText.
         // 6. Assert: script is a Parse Node.
         // 7. Let completion be Completion(Evaluation of script).
-        let completion = value_from_json(agent, &json_value, gc.nogc());
-
         // 8. NOTE: The PropertyDefinitionEvaluation semantics defined in 13.2.5.5 have special handling for the above evaluation.
         // 9. Let unfiltered be completion.[[Value]].
-        let unfiltered = completion;
+        // NOTE: The JSON text is parsed directly into the resulting values
+        // in step 2; duplicate keys and "__proto__" members are handled as
+        // the special evaluation semantics require.
 
         // 10. Assert: unfiltered is either a String, a Number, a Boolean, an Object that is defined by either an ArrayLiteral or an ObjectLiteral, or null.
         assert!(
@@ -261,6 +256,7 @@ impl JSONObject {
                 // 4. Repeat, while k < len,
                 // h. Set k to k + 1.
                 for k in 0..len {
+                    agent.check_termination(gc.nogc()).unbind()?;
                     // a. Let prop be ! ToString(𝔽(k)).
                     let prop = PropertyKey::from(SmallInteger::try_from(k).unwrap());
                     // b. Let v be ? Get(replacer, prop).
@@ -347,7 +343,7 @@ impl JSONObject {
             None
         };
 
-        let gap: Box<str> = space.map_or("".into(), |space| {
+        let gap = space.map_or_else(Wtf8Buf::new, |space| {
             // 7. If space is a Number, then
             if let Ok(space) = Number::try_from(space) {
                 // a. Let spaceMV be ! ToIntegerOrInfinity(space).
@@ -355,20 +351,36 @@ impl JSONObject {
                 // b. Set spaceMV to min(10, spaceMV).
                 // c. If spaceMV < 1, let gap be the empty String; otherwise let gap be the String value containing spaceMV occurrences of the code unit 0x0020 (SPACE).
                 let space_mv = space_mv.into_i64().clamp(0, 10) as usize;
-                " ".repeat(space_mv).into()
+                Wtf8Buf::from_string(" ".repeat(space_mv))
             } else if let Ok(space) = String::try_from(space) {
                 // 8. Else if space is a String, then
-                let space = space.to_string_lossy_(agent);
                 // a. If the length of space ≤ 10, let gap be space; otherwise let gap be the substring of space from 0 to 10.
-                if space.len() <= 10 {
-                    space.into()
-                } else {
-                    space[..10].into()
+                let mut gap = Wtf8Buf::with_capacity(10);
+                let mut gap_length = 0;
+                for c in space.as_wtf8_(agent).code_points() {
+                    let c = c.to_u32();
+                    if c > 0xFFFF {
+                        // Supplementary code points are two code units long;
+                        // a cut through the middle keeps only the leading
+                        // surrogate.
+                        let lead = 0xD800 + ((c - 0x10000) >> 10);
+                        if gap_length == 9 {
+                            gap.push(CodePoint::from_u32(lead).unwrap());
+                        }
+                        gap_length += 2;
+                    } else {
+                        gap_length += 1;
+                    }
+                    if gap_length > 10 {
+                        break;
+                    }
+                    gap.push(CodePoint::from_u32(c).unwrap());
                 }
+                gap
             } else {
                 // 9. Else,
                 // a. Let gap be the empty String.
-                "".into()
+                Wtf8Buf::new()
             }
         });
 
@@ -416,7 +428,7 @@ impl JSONObject {
             replacer_function,
             stack,
             // 2. Let indent be the empty String.
-            indent: Default::default(),
+            indent: Wtf8Buf::new(),
             gap,
             property_list,
         };
@@ -465,6 +477,19 @@ impl JSONObject {
     }
 }
 
+/// Parse a String as a JSON text and create the value it describes, like
+/// `JSON.parse` without a reviver. Throws a SyntaxError if the String is not
+/// a valid JSON text.
+#[cfg(feature = "web-fetch")]
+pub(crate) fn parse_json<'gc>(
+    agent: &mut Agent,
+    text: String,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let text = text.bind(gc);
+    json_parser::parse_json_text(agent, text, gc).map_err(|error| error.throw(agent, text, gc))
+}
+
 /// ### [25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )](https://tc39.es/ecma262/#sec-internalizejsonproperty)
 ///
 /// The abstract operation InternalizeJSONProperty takes arguments holder (an
@@ -508,6 +533,7 @@ fn internalize_json_property<'a>(
             let mut i = 0;
             // iii. Repeat, while I < len,
             while i < len {
+                agent.check_termination(gc.nogc()).unbind()?;
                 // 1. Let prop be ! ToString(𝔽(I)).
                 let prop = PropertyKey::from(SmallInteger::try_from(i).unwrap()).scope_static();
 
@@ -619,8 +645,8 @@ struct JSONSerializationRecord<'a> {
     result: Wtf8Buf,
     replacer_function: Option<Scoped<'a, Function<'static>>>,
     stack: ScopedCollection<'a, Vec<Value<'static>>>,
-    indent: Box<str>,
-    gap: Box<str>,
+    indent: Wtf8Buf,
+    gap: Wtf8Buf,
     property_list: Option<ScopedCollection<'a, Vec<PropertyKey<'static>>>>,
 }
 
@@ -913,6 +939,16 @@ fn quote_json_string(agent: &Agent, product: &mut Wtf8Buf, value: String) {
     // 4. Return product.
 }
 
+/// Concatenate `prefix`, `indent` and `suffix` into a separator or bracket
+/// string used when the JSON Serialization Record has a non-empty gap.
+fn indented_string(prefix: &str, indent: &Wtf8, suffix: &str) -> Wtf8Buf {
+    let mut string = Wtf8Buf::with_capacity(prefix.len() + indent.len() + suffix.len());
+    string.push_str(prefix);
+    string.push_wtf8(indent);
+    string.push_str(suffix);
+    string
+}
+
 fn quote_property_key(agent: &Agent, product: &mut Wtf8Buf, key: PropertyKey) {
     if let PropertyKey::Integer(key) = key {
         let key = key.into_i64();
@@ -979,14 +1015,14 @@ fn serialize_json_object<'a, 'b>(
         return Ok(());
     }
 
-    let open_string: Box<str>;
-    let separator_string: Box<str>;
-    let close_string: Box<str>;
-    let step_back: Box<str>;
+    let open_string: Wtf8Buf;
+    let separator_string: Wtf8Buf;
+    let close_string: Wtf8Buf;
+    let step_back: Wtf8Buf;
 
     // a. If state.[[Gap]] is the empty String, then
-    let (open, separator, key_value_separator, close) = if state.gap.is_empty() {
-        step_back = Default::default();
+    let (open, separator, key_value_separator, close) = if state.gap.len() == 0 {
+        step_back = Wtf8Buf::new();
         // i. Let properties be the String value formed by concatenating
         //    all the element Strings of partial with each adjacent pair of
         //    Strings separated with the code unit 0x002C (COMMA). A comma
@@ -994,21 +1030,25 @@ fn serialize_json_object<'a, 'b>(
         //    last String.
         // ii. Let final be the string-concatenation of "{", properties,
         //     and "}".
-        ("{", ",", ":", "}")
+        (
+            Wtf8::from_str("{"),
+            Wtf8::from_str(","),
+            ":",
+            Wtf8::from_str("}"),
+        )
     } else {
         // 3. Let stepBack be state.[[Indent]].
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        let mut new_ident =
-            std::string::String::with_capacity(state.indent.len() + state.gap.len());
-        new_ident.push_str(&state.indent);
-        new_ident.push_str(&state.gap);
-        step_back = core::mem::replace(&mut state.indent, new_ident.into_boxed_str());
+        let mut new_indent = Wtf8Buf::with_capacity(state.indent.len() + state.gap.len());
+        new_indent.push_wtf8(&state.indent);
+        new_indent.push_wtf8(&state.gap);
+        step_back = core::mem::replace(&mut state.indent, new_indent);
 
         // b. Else,
         // i. Let separator be the string-concatenation of the code unit
         //    0x002C (COMMA), the code unit 0x000A (LINE FEED), and
         //    state.[[Indent]].
-        separator_string = format!(",\n{}", &state.indent).into_boxed_str();
+        separator_string = indented_string(",\n", &state.indent, "");
         // ii. Let properties be the String value formed by concatenating
         //     all the element Strings of partial with each adjacent pair
         //     of Strings separated with separator. The separator String is
@@ -1017,14 +1057,9 @@ fn serialize_json_object<'a, 'b>(
         // iii. Let final be the string-concatenation of "{", the code unit
         //      0x000A (LINE FEED), state.[[Indent]], properties, the code
         //      unit 0x000A (LINE FEED), stepBack, and "}".
-        open_string = format!("{{\n{}", &state.indent).into_boxed_str();
-        close_string = format!("\n{}}}", &step_back).into_boxed_str();
-        (
-            open_string.as_ref(),
-            separator_string.as_ref(),
-            ": ",
-            close_string.as_ref(),
-        )
+        open_string = indented_string("{\n", &state.indent, "");
+        close_string = indented_string("\n", &step_back, "}");
+        (&*open_string, &*separator_string, ": ", &*close_string)
     };
 
     let mut first_inserted = false;
@@ -1038,6 +1073,7 @@ fn serialize_json_object<'a, 'b>(
     // 7. Let partial be a new empty List.
     // 8. For each element P of K, do
     for p in k.iter(agent) {
+        agent.check_termination(gc.nogc()).unbind()?;
         // a. Let strP be ? SerializeJSONProperty(state, P, value).
         let value_p = get_serializable_json_property_value(
             agent,
@@ -1060,9 +1096,9 @@ fn serialize_json_object<'a, 'b>(
 
         if !first_inserted {
             first_inserted = true;
-            state.result.push_str(open);
+            state.result.push_wtf8(open);
         } else {
-            state.result.push_str(separator);
+            state.result.push_wtf8(separator);
         }
 
         // i. Let member be QuoteJSONString(P).
@@ -1082,7 +1118,7 @@ fn serialize_json_object<'a, 'b>(
     // 11. Remove the last element of state.[[Stack]].
     state.stack.pop(agent, gc.nogc());
 
-    if !state.gap.is_empty() {
+    if state.gap.len() != 0 {
         // 12. Set state.[[Indent]] to stepBack.
         state.indent = step_back;
         // 13. Return final.
@@ -1093,7 +1129,7 @@ fn serialize_json_object<'a, 'b>(
         // a. Let final be "{}".
         state.result.push_str("{}");
     } else {
-        state.result.push_str(close);
+        state.result.push_wtf8(close);
     }
     Ok(())
 }
@@ -1143,34 +1179,37 @@ fn serialize_json_array<'a, 'b>(
         return Ok(());
     }
 
-    let open_string: Box<str>;
-    let separator_string: Box<str>;
-    let close_string: Box<str>;
-    let step_back: Box<str>;
+    let open_string: Wtf8Buf;
+    let separator_string: Wtf8Buf;
+    let close_string: Wtf8Buf;
+    let step_back: Wtf8Buf;
 
     // a. If state.[[Gap]] is the empty String, then
-    let (open, separator, close) = if state.gap.is_empty() {
-        step_back = Default::default();
+    let (open, separator, close) = if state.gap.len() == 0 {
+        step_back = Wtf8Buf::new();
         // i. Let properties be the String value formed by concatenating all
         //    the element Strings of partial with each adjacent pair of Strings
         //    separated with the code unit 0x002C (COMMA). A comma is not
         //    inserted either before the first String or after the last String.
         // ii. Let final be the string-concatenation of "[", properties, and
         //     "]".
-        ("[", ",", "]")
+        (
+            Wtf8::from_str("["),
+            Wtf8::from_str(","),
+            Wtf8::from_str("]"),
+        )
     } else {
         // 3. Let stepBack be state.[[Indent]].
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        let mut new_ident =
-            std::string::String::with_capacity(state.indent.len() + state.gap.len());
-        new_ident.push_str(&state.indent);
-        new_ident.push_str(&state.gap);
-        step_back = core::mem::replace(&mut state.indent, new_ident.into_boxed_str());
+        let mut new_indent = Wtf8Buf::with_capacity(state.indent.len() + state.gap.len());
+        new_indent.push_wtf8(&state.indent);
+        new_indent.push_wtf8(&state.gap);
+        step_back = core::mem::replace(&mut state.indent, new_indent);
 
         // b. Else,
         // i. Let separator be the string-concatenation of the code unit 0x002C
         //    (COMMA), the code unit 0x000A (LINE FEED), and state.[[Indent]].
-        separator_string = format!(",\n{}", &state.indent).into_boxed_str();
+        separator_string = indented_string(",\n", &state.indent, "");
         // ii. Let properties be the String value formed by concatenating all
         //     the element Strings of partial with each adjacent pair of
         //     Strings separated with separator. The separator String is not
@@ -1179,25 +1218,22 @@ fn serialize_json_array<'a, 'b>(
         // iii. Let final be the string-concatenation of "[", the code unit
         //      0x000A (LINE FEED), state.[[Indent]], properties, the code unit
         //      0x000A (LINE FEED), stepBack, and "]".
-        open_string = format!("[\n{}", &state.indent).into_boxed_str();
-        close_string = format!("\n{}]", &step_back).into_boxed_str();
-        (
-            open_string.as_ref(),
-            separator_string.as_ref(),
-            close_string.as_ref(),
-        )
+        open_string = indented_string("[\n", &state.indent, "");
+        close_string = indented_string("\n", &step_back, "]");
+        (&*open_string, &*separator_string, &*close_string)
     };
 
     // 5. Let partial be a new empty List.
     state
         .result
         .reserve(open.len() + close.len() + (len as usize) * (separator.len() + 1));
-    state.result.push_str(open);
+    state.result.push_wtf8(open);
     // 7. Let index be 0.
     // 8. Repeat, while index < len,
     for index in 0..len {
+        agent.check_termination(gc.nogc()).unbind()?;
         if index > 0 {
-            state.result.push_str(separator);
+            state.result.push_wtf8(separator);
         }
         let key = PropertyKey::try_from(index).unwrap().scope_static();
         // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
@@ -1227,7 +1263,7 @@ fn serialize_json_array<'a, 'b>(
         }
         // d. Set index to index + 1.
     }
-    state.result.push_str(close);
+    state.result.push_wtf8(close);
     // 11. Remove the last element of state.[[Stack]].
     state.stack.pop(agent, gc.nogc());
     // 12. Set state.[[Indent]] to stepBack.
@@ -1235,45 +1271,3 @@ fn serialize_json_array<'a, 'b>(
     // 13. Return final.
     Ok(())
 }
-
-pub(crate) fn value_from_json<'gc>(
-    agent: &mut Agent,
-    json: &sonic_rs::Value,
-    gc: NoGcScope<'gc, '_>,
-) -> Value<'gc> {
-    match json.get_type() {
-        sonic_rs::JsonType::Null => Value::Null,
-        sonic_rs::JsonType::Boolean => Value::Boolean(json.is_true()),
-        sonic_rs::JsonType::Number => Number::from_f64(agent, json.as_f64().unwrap(), gc).into(),
-        sonic_rs::JsonType::String => String::from_str(agent, json.as_str().unwrap(), gc).into(),
-        sonic_rs::JsonType::Array => {
-            let json_array = json.as_array().unwrap();
-            let len = json_array.len();
-            let array_obj = array_create(agent, len, len, None, gc).unwrap();
-            for (i, value) in json_array.iter().enumerate() {
-                let prop = PropertyKey::from(SmallInteger::try_from(i as i64).unwrap());
-                let js_value = value_from_json(agent, value, gc);
-                unwrap_try(try_create_data_property(
-                    agent, array_obj, prop, js_value, None, gc,
-                ));
-            }
-            array_obj.into()
-        }
-        sonic_rs::JsonType::Object => {
-            let json_object = json.as_object().unwrap();
-            let Object::Object(object) =
-                ordinary_object_create_with_intrinsics(agent, ProtoIntrinsics::Object, None, gc)
-            else {
-                unreachable!()
-            };
-            for (key, value) in json_object.iter() {
-                let prop = PropertyKey::from_str(agent, key, gc);
-                let js_value = value_from_json(agent, value, gc);
-                unwrap_try(try_create_data_property(
-                    agent, object, prop, js_value, None, gc,
-                ));
-            }
-            object.into()
-        }
-    }
-}
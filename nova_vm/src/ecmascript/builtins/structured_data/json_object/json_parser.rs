@@ -0,0 +1,534 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Direct-to-heap parser for
+//! [ECMA-404 JSON texts](https://ecma-international.org/publications-and-standards/standards/ecma-404/).
+//!
+//! The parser reads the WTF-8 bytes of the source String in place and
+//! creates the resulting Strings, Arrays and Objects on the heap as soon as
+//! each of them is complete: an Array is created from its full element list
+//! and an Object from its full member list, so no intermediate document tree
+//! is built and no per-property \[\[DefineOwnProperty]] calls are made.
+//! Nesting is tracked with an explicit stack, so deeply nested texts cannot
+//! overflow the native stack.
+
+use std::collections::{TryReserveError, hash_map::Entry};
+
+use ahash::{AHashMap, AHashSet};
+use memchr::memchr2;
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
+
+use crate::{
+    ecmascript::{
+        Agent, ExceptionType, JsError, Number, OrdinaryObject, PropertyKey, String, Value,
+        create_array_from_list,
+    },
+    engine::{Bindable, NoGcScope},
+    heap::ObjectEntry,
+};
+
+/// Objects with at most this many members are checked for duplicate keys
+/// by a linear scan instead of hashing.
+const LINEAR_DUPLICATE_SCAN_LIMIT: usize = 16;
+
+pub(super) enum JsonParseError {
+    /// The text ended in the middle of a JSON value.
+    UnexpectedEnd,
+    /// The byte at the given offset cannot start or continue a JSON value.
+    UnexpectedToken(usize),
+    /// A string contains an unescaped control character at the given offset.
+    ControlCharacter(usize),
+    /// A string contains an invalid escape sequence at the given offset.
+    InvalidEscape(usize),
+    /// Allocating an object failed.
+    Allocation(TryReserveError),
+}
+
+impl JsonParseError {
+    /// Throw the error as a SyntaxError, reporting offsets as UTF-16 indexes
+    /// into `text`.
+    pub(super) fn throw<'gc>(
+        self,
+        agent: &mut Agent,
+        text: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsError<'gc> {
+        let (message, position) = match self {
+            JsonParseError::UnexpectedEnd => {
+                return agent.throw_exception_with_static_message(
+                    ExceptionType::SyntaxError,
+                    "Unexpected end of JSON input",
+                    gc,
+                );
+            }
+            JsonParseError::UnexpectedToken(position) => ("Unexpected token", position),
+            JsonParseError::ControlCharacter(position) => {
+                ("Bad control character in string literal", position)
+            }
+            JsonParseError::InvalidEscape(position) => ("Bad escaped character", position),
+            JsonParseError::Allocation(err) => return agent.throw_allocation_exception(err, gc),
+        };
+        let position = text.utf16_index_(agent, position);
+        let token = text.code_point_at_(agent, position).to_char_lossy();
+        agent.throw_exception(
+            ExceptionType::SyntaxError,
+            format!("{message} {token:?} in JSON at position {position}"),
+            gc,
+        )
+    }
+}
+
+/// Parse `text` as a JSON text and create the value it describes.
+pub(super) fn parse_json_text<'gc>(
+    agent: &mut Agent,
+    text: String,
+    gc: NoGcScope<'gc, '_>,
+) -> Result<Value<'gc>, JsonParseError> {
+    let text = text.bind(gc);
+    // Note: The byte slice borrows from the local String handle rather than
+    // from the Agent. String data does not move while garbage collection
+    // cannot run, so the slice stays valid while new values are allocated.
+    let bytes = text.as_bytes_(agent);
+    let mut parser = JsonParser {
+        text: bytes,
+        position: 0,
+        is_utf8: text.as_wtf8_(agent).as_str().is_some(),
+        elements: Vec::new(),
+        members: Vec::new(),
+        containers: Vec::new(),
+        scratch: Wtf8Buf::new(),
+    };
+    parser.parse(agent, gc)
+}
+
+/// An Array or Object whose contents are still being parsed.
+enum Container<'gc> {
+    /// The elements of the Array start at this index of the element stack.
+    Array { start: usize },
+    /// The members of the Object start at this index of the member stack, and
+    /// `key` is the key of the member whose value is being parsed.
+    Object { start: usize, key: PropertyKey<'gc> },
+}
+
+struct JsonParser<'t, 'gc> {
+    text: &'t [u8],
+    position: usize,
+    /// True if the text contains no lone surrogates, making every string
+    /// token valid UTF-8.
+    is_utf8: bool,
+    /// Elements of all Arrays currently being parsed.
+    elements: Vec<Value<'gc>>,
+    /// Members of all Objects currently being parsed.
+    members: Vec<ObjectEntry<'gc>>,
+    containers: Vec<Container<'gc>>,
+    /// Decoded contents of the latest string token with escape sequences.
+    scratch: Wtf8Buf,
+}
+
+impl<'t, 'gc> JsonParser<'t, 'gc> {
+    fn parse(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<Value<'gc>, JsonParseError> {
+        'value: loop {
+            self.skip_whitespace();
+            let mut value = match self.peek() {
+                Some(b'{') => {
+                    self.position += 1;
+                    self.skip_whitespace();
+                    if self.eat(b'}') {
+                        self.create_object(agent, self.members.len(), gc)?
+                    } else {
+                        let key = self.parse_member_key(agent, gc)?;
+                        self.containers.push(Container::Object {
+                            start: self.members.len(),
+                            key,
+                        });
+                        continue 'value;
+                    }
+                }
+                Some(b'[') => {
+                    self.position += 1;
+                    self.skip_whitespace();
+                    if self.eat(b']') {
+                        create_array_from_list(agent, &[], gc).into()
+                    } else {
+                        self.containers.push(Container::Array {
+                            start: self.elements.len(),
+                        });
+                        continue 'value;
+                    }
+                }
+                Some(b'"') => {
+                    let raw = self.scan_string()?;
+                    self.create_string(agent, raw, gc).into()
+                }
+                Some(b'-' | b'0'..=b'9') => self.parse_number(agent, gc)?.into(),
+                Some(b't') => {
+                    self.expect_literal(b"true")?;
+                    Value::Boolean(true)
+                }
+                Some(b'f') => {
+                    self.expect_literal(b"false")?;
+                    Value::Boolean(false)
+                }
+                Some(b'n') => {
+                    self.expect_literal(b"null")?;
+                    Value::Null
+                }
+                _ => return Err(self.unexpected_token()),
+            };
+
+            // Add the value to its container, and create every container
+            // that the following tokens close.
+            loop {
+                match self.containers.last() {
+                    None => {
+                        self.skip_whitespace();
+                        if self.position != self.text.len() {
+                            return Err(self.unexpected_token());
+                        }
+                        return Ok(value);
+                    }
+                    Some(&Container::Array { start }) => {
+                        self.elements.push(value);
+                        self.skip_whitespace();
+                        if self.eat(b',') {
+                            continue 'value;
+                        }
+                        if !self.eat(b']') {
+                            return Err(self.unexpected_token());
+                        }
+                        self.containers.pop();
+                        value = create_array_from_list(agent, &self.elements[start..], gc).into();
+                        self.elements.truncate(start);
+                    }
+                    Some(&Container::Object { start, key }) => {
+                        self.members.push(ObjectEntry::new_data_entry(key, value));
+                        self.skip_whitespace();
+                        if self.eat(b',') {
+                            self.skip_whitespace();
+                            let next_key = self.parse_member_key(agent, gc)?;
+                            if let Some(Container::Object { key, .. }) = self.containers.last_mut()
+                            {
+                                *key = next_key;
+                            }
+                            continue 'value;
+                        }
+                        if !self.eat(b'}') {
+                            return Err(self.unexpected_token());
+                        }
+                        self.containers.pop();
+                        value = self.create_object(agent, start, gc)?;
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    #[inline]
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn unexpected_token(&self) -> JsonParseError {
+        if self.position >= self.text.len() {
+            JsonParseError::UnexpectedEnd
+        } else {
+            JsonParseError::UnexpectedToken(self.position)
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &[u8]) -> Result<(), JsonParseError> {
+        for &byte in literal {
+            if !self.eat(byte) {
+                return Err(self.unexpected_token());
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse an object member's key and the following colon.
+    fn parse_member_key(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<PropertyKey<'gc>, JsonParseError> {
+        if self.peek() != Some(b'"') {
+            return Err(self.unexpected_token());
+        }
+        let raw = self.scan_string()?;
+        let key = match self.utf8_contents(raw) {
+            Some(key) => PropertyKey::from_str(agent, key, gc),
+            // Note: Strings with lone surrogates are never integer keys.
+            None => self.create_string(agent, raw, gc).into(),
+        };
+        self.skip_whitespace();
+        if !self.eat(b':') {
+            return Err(self.unexpected_token());
+        }
+        Ok(key)
+    }
+
+    /// Scan the string token at the current position.
+    ///
+    /// Returns the raw contents of the token if it contains no escape
+    /// sequences; otherwise the decoded contents are left in `scratch`.
+    fn scan_string(&mut self) -> Result<Option<&'t [u8]>, JsonParseError> {
+        debug_assert_eq!(self.peek(), Some(b'"'));
+        self.position += 1;
+        let start = self.position;
+        let mut end = self.find_segment_end()?;
+        if self.text[end] == b'"' {
+            self.position = end + 1;
+            return Ok(Some(&self.text[start..end]));
+        }
+        self.scratch.truncate(0);
+        let mut segment_start = start;
+        loop {
+            self.scratch
+                .push_wtf8(bytes_as_wtf8(&self.text[segment_start..end]));
+            self.position = end + 1;
+            if self.text[end] == b'"' {
+                return Ok(None);
+            }
+            self.parse_escape()?;
+            segment_start = self.position;
+            end = self.find_segment_end()?;
+        }
+    }
+
+    /// Find the end of the unescaped string segment at the current position:
+    /// the offset of the next quotation mark or reverse solidus.
+    #[inline]
+    fn find_segment_end(&mut self) -> Result<usize, JsonParseError> {
+        let rest = &self.text[self.position..];
+        let Some(length) = memchr2(b'"', b'\\', rest) else {
+            return Err(JsonParseError::UnexpectedEnd);
+        };
+        let segment = &rest[..length];
+        // Note: Written without early exits so that the check vectorises.
+        if segment
+            .iter()
+            .fold(false, |found, &byte| found | (byte < 0x20))
+        {
+            let offset = segment.iter().position(|&byte| byte < 0x20).unwrap();
+            return Err(JsonParseError::ControlCharacter(self.position + offset));
+        }
+        Ok(self.position + length)
+    }
+
+    /// Decode the escape sequence after a reverse solidus into `scratch`.
+    fn parse_escape(&mut self) -> Result<(), JsonParseError> {
+        let character = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.position += 1;
+                let mut code_unit = 0;
+                for _ in 0..4 {
+                    let Some(digit) = self.peek() else {
+                        return Err(JsonParseError::UnexpectedEnd);
+                    };
+                    let Some(digit) = (digit as char).to_digit(16) else {
+                        return Err(JsonParseError::InvalidEscape(self.position));
+                    };
+                    code_unit = code_unit * 16 + digit;
+                    self.position += 1;
+                }
+                // Note: Pushing a trailing surrogate directly after a
+                // leading one combines them into a single code point.
+                self.scratch.push(CodePoint::from_u32(code_unit).unwrap());
+                return Ok(());
+            }
+            Some(_) => return Err(JsonParseError::InvalidEscape(self.position)),
+            None => return Err(JsonParseError::UnexpectedEnd),
+        };
+        self.scratch.push_char(character);
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Get the contents of a scanned string token as UTF-8, if they are
+    /// valid UTF-8.
+    fn utf8_contents<'s>(&'s self, raw: Option<&'t [u8]>) -> Option<&'s str> {
+        match raw {
+            // SAFETY: The text contains no lone surrogates, so any slice of
+            // it between two ASCII bytes is valid UTF-8.
+            Some(raw) if self.is_utf8 => Some(unsafe { core::str::from_utf8_unchecked(raw) }),
+            Some(raw) => bytes_as_wtf8(raw).as_str(),
+            None => self.scratch.as_str(),
+        }
+    }
+
+    fn create_string(
+        &self,
+        agent: &mut Agent,
+        raw: Option<&'t [u8]>,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        if let Some(contents) = self.utf8_contents(raw) {
+            return String::from_str(agent, contents, gc);
+        }
+        let contents = match raw {
+            Some(raw) => {
+                let mut contents = Wtf8Buf::with_capacity(raw.len());
+                contents.push_wtf8(bytes_as_wtf8(raw));
+                contents
+            }
+            None => self.scratch.clone(),
+        };
+        String::from_wtf8_buf(agent, contents, gc)
+    }
+
+    fn parse_number(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<Number<'gc>, JsonParseError> {
+        let start = self.position;
+        let negative = self.eat(b'-');
+        let digits_start = self.position;
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return Err(self.unexpected_token()),
+        }
+        let digits_end = self.position;
+        let mut is_integer = true;
+        if self.eat(b'.') {
+            is_integer = false;
+            self.expect_digits()?;
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            is_integer = false;
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            self.expect_digits()?;
+        }
+        // Integers of up to 18 digits fit in an i64, and converting them to
+        // an f64 rounds them the same way parsing them as decimals would.
+        if is_integer && digits_end - digits_start <= 18 {
+            let magnitude = self.text[digits_start..digits_end]
+                .iter()
+                .fold(0i64, |value, &digit| value * 10 + i64::from(digit - b'0'));
+            return Ok(match (negative, magnitude) {
+                (true, 0) => Number::from_f64(agent, -0.0, gc),
+                (true, magnitude) => Number::from_i64(agent, -magnitude, gc),
+                (false, magnitude) => Number::from_i64(agent, magnitude, gc),
+            });
+        }
+        // Note: The JSON number grammar is a subset of what the float parser
+        // accepts. Numbers too large for an f64 parse as infinities.
+        let value: f64 = fast_float::parse(&self.text[start..self.position]).unwrap();
+        Ok(Number::from_f64(agent, value, gc))
+    }
+
+    #[inline]
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn expect_digits(&mut self) -> Result<(), JsonParseError> {
+        let start = self.position;
+        self.skip_digits();
+        if self.position == start {
+            Err(self.unexpected_token())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Create an Object from the members on the member stack from `start`
+    /// onwards.
+    fn create_object(
+        &mut self,
+        agent: &mut Agent,
+        start: usize,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Result<Value<'gc>, JsonParseError> {
+        remove_duplicate_members(&mut self.members, start);
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .object_prototype()
+            .into();
+        let object = OrdinaryObject::create_object(agent, Some(prototype), &self.members[start..])
+            .map_err(JsonParseError::Allocation)?;
+        self.members.truncate(start);
+        Ok(object.bind(gc).into())
+    }
+}
+
+/// Remove members with repeated keys from the members on the member stack
+/// from `start` onwards.
+///
+/// As when the members are defined one by one with CreateDataProperty, the
+/// last value for a key wins but the property keeps the position at which the
+/// key first appeared.
+fn remove_duplicate_members(members: &mut Vec<ObjectEntry>, start: usize) {
+    let count = members.len() - start;
+    let has_duplicates = if count <= LINEAR_DUPLICATE_SCAN_LIMIT {
+        (start + 1..members.len())
+            .any(|i| members[start..i].iter().any(|m| m.key == members[i].key))
+    } else {
+        let mut keys = AHashSet::with_capacity(count);
+        !members[start..].iter().all(|m| keys.insert(m.key))
+    };
+    if !has_duplicates {
+        return;
+    }
+    let mut first_indexes: AHashMap<PropertyKey, usize> = AHashMap::with_capacity(count);
+    let mut write = start;
+    for read in start..members.len() {
+        let member = members[read];
+        match first_indexes.entry(member.key) {
+            Entry::Occupied(first) => members[*first.get()].value = member.value,
+            Entry::Vacant(first) => {
+                first.insert(write);
+                members[write] = member;
+                write += 1;
+            }
+        }
+    }
+    members.truncate(write);
+}
+
+/// Reinterpret a slice of a WTF-8 text as WTF-8.
+///
+/// The slice must start and end at code point boundaries; slices between
+/// ASCII bytes always do.
+fn bytes_as_wtf8(bytes: &[u8]) -> &Wtf8 {
+    // SAFETY: Wtf8 is a transparent wrapper around its bytes, and the bytes
+    // are a code point aligned slice of a WTF-8 string.
+    unsafe { core::mem::transmute::<&[u8], &Wtf8>(bytes) }
+}
@@ -303,6 +303,7 @@ impl RegExpPrototype {
         // 11. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) to codeUnits.
         if multiline {
             code_units[i] = b'm';
+            i += 1;
         };
 
         // 12. Let dotAll be ToBoolean(? Get(R, "dotAll")).
@@ -369,7 +370,7 @@ impl RegExpPrototype {
 
         // 19. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) to codeUnits.
         if sticky {
-            code_units[i] = b'v';
+            code_units[i] = b'y';
             i += 1;
         };
 
@@ -499,6 +500,7 @@ impl RegExpPrototype {
             let mut n = 0u32;
             // e. Repeat,
             loop {
+                agent.check_termination(gc.nogc()).unbind()?;
                 // i. Let result be ? RegExpExec(rx, S).
                 let result = reg_exp_exec(agent, rx.get(agent), s.get(agent), gc.reborrow())
                     .unbind()?
@@ -799,6 +801,7 @@ impl RegExpPrototype {
         // 11. Let done be false.
         // 12. Repeat, while done is false,
         loop {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Let result be ? RegExpExec(rx, S).
             let result = reg_exp_exec(agent, rx.get(agent), s.get(agent), gc.reborrow())
                 .unbind()?
@@ -1339,6 +1342,7 @@ impl RegExpPrototype {
         let mut q = 0;
         // 19. Repeat, while q < size,
         while q < size {
+            agent.check_termination(gc.nogc()).unbind()?;
             // a. Perform ? Set(splitter, "lastIndex", 𝔽(q), true).
             let f_q = Number::try_from(q).unwrap();
             set(
@@ -1563,7 +1567,7 @@ impl RegExpPrototype {
                 "{} is not an object",
                 this_value
                     .unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -174,9 +174,7 @@ impl StringConstructor {
                 .bind(gc.nogc())
         };
 
-        let result = Wtf8Buf::from_ill_formed_utf16(&buf);
-
-        Ok(String::from_wtf8_buf(agent, result, gc.into_nogc()).into())
+        Ok(String::from_utf16(agent, &buf, gc.into_nogc()).into())
     }
 
     /// ### [22.1.2.2 String.fromCodePoint ( ...`codePoints` )](https://tc39.es/ecma262/#sec-string.fromcodepoint)
@@ -233,7 +231,7 @@ impl StringConstructor {
             ))
         }
         // 1. Let result be the empty String.
-        let mut result = Wtf8Buf::with_capacity(code_points.len() * 3);
+        let mut result = Vec::with_capacity(code_points.len());
         if code_points.iter().all(|cp| cp.is_number()) {
             // 2. For each element next of codePoints, do
             for next in code_points.iter() {
@@ -242,7 +240,7 @@ impl StringConstructor {
                 let Number::Integer(next) = next else {
                     if next == Number::neg_zero() {
                         // Special case: -0 is an acceptable value here.
-                        result.push_char('\0');
+                        result.push(CodePoint::from_char('\0'));
                         continue;
                     }
                     return Err(agent.throw_exception(
@@ -269,7 +267,7 @@ impl StringConstructor {
                 let Number::Integer(next_cp) = next_cp else {
                     if next_cp == Number::neg_zero() {
                         // Special case: -0 is an acceptable value here.
-                        result.push_char('\0');
+                        result.push(CodePoint::from_char('\0'));
                         continue;
                     }
                     return Err(agent.throw_exception(
@@ -283,7 +281,7 @@ impl StringConstructor {
             }
         }
         // 4. Return result.
-        Ok(String::from_wtf8_buf(agent, result, gc.into_nogc()).into())
+        Ok(String::from_code_points(agent, &result, gc.into_nogc()).into())
     }
 
     /// ### [22.1.2.4 String.raw ( template, ...substitutions )](https://tc39.es/ecma262/#sec-string.raw)
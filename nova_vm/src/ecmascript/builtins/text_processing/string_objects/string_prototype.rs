@@ -8,24 +8,25 @@ use std::{cmp::Ordering, ops::Deref};
 use unicode_normalization::{
     IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick,
 };
-use wtf8::{CodePoint, Wtf8Buf};
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
 
+#[cfg(feature = "regexp")]
+use crate::ecmascript::{get_object_method, invoke, reg_exp_create};
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic,
         ExceptionType, JsResult, Number, Primitive, PrimitiveObjectData, PrimitiveObjectRecord,
-        PropertyKey, Realm, String, StringIterator, Value, builders::OrdinaryObjectBuilder,
-        call_function, create_array_from_list, is_callable, is_reg_exp, is_trimmable_whitespace,
-        require_object_coercible, to_integer_or_infinity, to_integer_or_infinity_number, to_length,
-        to_number, to_string, to_string_primitive, to_uint32, try_result_into_js,
-        try_to_integer_or_infinity, try_to_length, try_to_string,
+        PropertyKey, Realm, String, StringBuilder, StringIterator, Value,
+        builders::OrdinaryObjectBuilder, call_function, create_array_from_list, is_callable,
+        is_reg_exp, is_trimmable_whitespace, require_object_coercible, to_integer_or_infinity,
+        to_integer_or_infinity_number, to_length, to_number, to_string, to_string_primitive,
+        to_uint32, try_result_into_js, try_to_integer_or_infinity, try_to_length, try_to_string,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{ArenaAccess, HeapIndexHandle, IntrinsicFunctionIndexes, WellKnownSymbols},
 };
-#[cfg(feature = "regexp")]
 use crate::{
-    ecmascript::{Object, get, get_object_method, invoke, reg_exp_create},
+    ecmascript::{Object, get},
     engine::Scoped,
 };
 
@@ -871,22 +872,13 @@ impl StringPrototype {
 
         // 8. Let len be the length of S.
         // 9. Let start be the result of clamping pos between 0 and len.
-        let haystack_str = {
-            let start = if pos != 0 {
-                // NOTE: `pos` was already clamped to 0.
-                pos.min(s.utf16_len_(agent))
-            } else {
-                0
-            };
-            &s.to_string_lossy_(agent)[start..]
-        };
+        // NOTE: `pos` was already clamped to 0.
+        let start = pos.min(s.utf16_len_(agent));
 
         // 10. Let index be StringIndexOf(S, searchStr, start).
         // 11. If index is not-found, return false.
         // 12. Return true.
-        Ok(Value::from(
-            haystack_str.contains(search_str.to_string_lossy_(agent).deref()),
-        ))
+        Ok(Value::from(s.index_of_(agent, search_str, start).is_some()))
     }
 
     /// ### [22.1.3.9 String.prototype.indexOf ( searchString \[ , position \] )](https://tc39.es/ecma262/#sec-string.prototype.indexof)
@@ -957,25 +949,13 @@ impl StringPrototype {
 
         // 6. Let len be the length of S.
         // 7. Let start be the result of clamping pos between 0 and len.
-        let utf8_start = if pos != 0 {
-            let u16_len = s.utf16_len_(agent);
-            if pos >= u16_len {
-                s.len_(agent)
-            } else {
-                s.utf8_index_(agent, pos).unwrap()
-            }
-        } else {
-            0
-        };
+        // NOTE: `pos` was already clamped to 0.
+        let start = pos.min(s.utf16_len_(agent));
 
         // 8. Let result be StringIndexOf(S, searchStr, start).
         // 9. If result is not-found, return -1𝔽.
         // 10. Return 𝔽(result).
-        if let Some(rel_u8_pos) =
-            s.to_string_lossy_(agent)[utf8_start..].find(search_str.to_string_lossy_(agent).deref())
-        {
-            let u8_pos = utf8_start + rel_u8_pos;
-            let result = s.utf16_index_(agent, u8_pos);
+        if let Some(result) = s.index_of_(agent, search_str, start) {
             Ok(Number::try_from(result).unwrap().into())
         } else {
             Ok(Number::from(-1).into())
@@ -1080,33 +1060,19 @@ impl StringPrototype {
         };
 
         // 7. Let len be the length of S.
+        let len = s.utf16_len_(agent);
         // 8. Let searchLen be the length of searchStr.
+        let search_len = search_str.utf16_len_(agent);
+        if search_len > len {
+            return Ok(Number::from(-1).into());
+        }
         // 9. Let start be the result of clamping pos between 0 and len - searchLen.
+        // NOTE: `pos` was already clamped to 0.
+        let start = pos.min(len - search_len);
         // 10. Let result be StringLastIndexOf(S, searchStr, start).
-        // NOTE: If start >= (len - searchLen), there is a last index if and
-        // only if start.endsWith(searchLen).
-        let haystack_str = {
-            if pos == usize::MAX {
-                s.to_string_lossy_(agent)
-            } else {
-                // When starting from a position, the position may mark the
-                // start of the search string, so we need to include the search
-                // string length in the haystack.
-                let utf8_pos = s.utf8_index_(agent, pos).unwrap();
-                let utf8_len = s.len_(agent);
-                let search_str_len = search_str.len_(agent);
-                s.as_wtf8_(agent)
-                    .slice_to(utf8_len.min(utf8_pos + search_str_len))
-                    .to_string_lossy()
-            }
-        };
-        let search_str = search_str.to_string_lossy_(agent);
-        let utf8_result = haystack_str.rfind(search_str.deref());
-
         // 11. If result is not-found, return -1𝔽.
         // 12. Return 𝔽(result).
-        if let Some(utf8_idx) = utf8_result {
-            let result = s.utf16_index_(agent, utf8_idx);
+        if let Some(result) = s.last_index_of_(agent, search_str, start) {
             Ok(Number::try_from(result).unwrap().into())
         } else {
             Ok(Number::from(-1).into())
@@ -1699,70 +1665,83 @@ impl StringPrototype {
             .bind(gc.nogc());
 
         // 5. Let functionalReplace be IsCallable(replaceValue).
-        if let Some(functional_replace) = is_callable(replace_value.get(agent), gc.nogc()) {
-            // 7. Let searchLength be the length of searchString.
-            let search_length = search_string.len_(agent);
-
-            // 8. Let position be StringIndexOf(s, searchString, 0).
-            let position = if let Some(position) = s
-                .to_string_lossy(agent)
-                .find(search_string.to_string_lossy_(agent).deref())
-            {
-                position
-            } else {
-                // 9. If position is not-found, return s.
-                return Ok(s.get(agent).into());
-            };
+        let functional_replace =
+            is_callable(replace_value.get(agent), gc.nogc()).map(|f| f.scope(agent, gc.nogc()));
+        let search_string = search_string.scope(agent, gc.nogc());
+
+        // 6. If functionalReplace is false, then
+        let replace_string = if functional_replace.is_none() {
+            // a. Set replaceValue to ? ToString(replaceValue).
+            Some(
+                to_string(agent, replace_value.get(agent), gc.reborrow())
+                    .unbind()?
+                    .scope(agent, gc.nogc()),
+            )
+        } else {
+            None
+        };
 
-            // Let replacement be ? ToString(? Call(replaceValue, undefined, « searchString, 𝔽(position), string »)).
-            let result = call_function(
+        // 7. Let searchLength be the length of searchString.
+        let search_length = search_string.get(agent).utf16_len_(agent);
+
+        // 8. Let position be StringIndexOf(string, searchString, 0).
+        let Some(position) = s.get(agent).index_of_(agent, search_string.get(agent), 0) else {
+            // 9. If position is not-found, return string.
+            return Ok(s.get(agent).into());
+        };
+
+        let replacement = if let Some(functional_replace) = functional_replace {
+            // 12. If functionalReplace is true, then
+            // a. Let replacement be ? ToString(? Call(replaceValue, undefined,
+            //    « searchString, 𝔽(position), string »)).
+            let replacement = call_function(
                 agent,
-                functional_replace.unbind(),
+                functional_replace.get(agent),
                 Value::Undefined,
                 Some(ArgumentsList::from_mut_slice(&mut [
-                    search_string.unbind().into(),
+                    search_string.get(agent).into(),
                     Number::from(position as u32).into(),
-                    s.get(agent).unbind().into(),
+                    s.get(agent).into(),
                 ])),
                 gc.reborrow(),
             )
             .unbind()?
             .bind(gc.nogc());
-
-            let result = to_string(agent, result.unbind(), gc.reborrow())
+            to_string(agent, replacement.unbind(), gc.reborrow())
                 .unbind()?
-                .bind(gc.nogc());
-
-            // 10. Let preceding be the substring of s from 0 to position.
-            // 11. Let following be the substring of s from position + searchLength.
-            // 12. If functionalReplace is true,
-            let preceding = &s.to_string_lossy(agent)[0..position];
-            let following = &s.to_string_lossy(agent)[position + search_length..];
-
-            // 14. Return the string-concatenation of preceding, replacement, and following.
-            let concatenated_result = format!(
-                "{}{}{}",
-                preceding,
-                result.to_string_lossy_(agent),
-                following
-            );
-            return Ok(String::from_string(agent, concatenated_result, gc.into_nogc()).into());
-        }
-
-        let search_string_root = search_string.scope(agent, gc.nogc());
-
-        // 6. If functionalReplace is false, Set replaceValue to ? ToString(replaceValue).
-        let replace_string = to_string(agent, replace_value.get(agent), gc.reborrow())
+                .bind(gc.nogc())
+        } else {
+            // 13. Else,
+            // a. Assert: replaceValue is a String.
+            // b. Let captures be a new empty List.
+            // c. Let replacement be ! GetSubstitution(searchString, string,
+            //    position, captures, undefined, replaceValue).
+            get_substitution(
+                agent,
+                search_string,
+                s.clone(),
+                position,
+                vec![],
+                None,
+                replace_string.unwrap(),
+                gc.reborrow(),
+            )
             .unbind()?
-            .bind(gc.nogc());
+            .bind(gc.nogc())
+        };
 
-        // Everything are strings: `"foo".replace("o", "a")` => use rust's replace
-        let result = s.to_string_lossy(agent).into_owned().replacen(
-            search_string_root.to_string_lossy(agent).deref(),
-            &replace_string.to_string_lossy_(agent),
-            1,
-        );
-        Ok(String::from_string(agent, result, gc.into_nogc()).into())
+        let string = s.get(agent).bind(gc.nogc());
+        let string_length = string.utf16_len_(agent);
+        let mut result = StringBuilder::with_capacity(string.len_(agent) + replacement.len_(agent));
+        // 10. Let preceding be the substring of string from 0 to position.
+        result.push_substring(agent, string, 0, position);
+        result.push_string(agent, replacement);
+        // 11. Let following be the substring of string from position +
+        //     searchLength.
+        result.push_substring(agent, string, position + search_length, string_length);
+        // 14. Return the string-concatenation of preceding, replacement, and
+        //     following.
+        Ok(result.finish(agent, gc.into_nogc()).into())
     }
 
     /// ### [22.1.3.20 String.prototype.replaceAll ( searchValue, replaceValue )](https://tc39.es/ecma262/#sec-string.prototype.replaceall)
@@ -1857,60 +1836,71 @@ impl StringPrototype {
             .scope(agent, gc.nogc());
 
         // 4. Let searchString be ? ToString(searchValue).
-        let mut search_string = to_string(agent, scoped_search_value.get(agent), gc.reborrow())
+        let search_string = to_string(agent, scoped_search_value.get(agent), gc.reborrow())
             .unbind()?
-            .bind(gc.nogc());
-        let search_string_root = search_string.scope(agent, gc.nogc());
+            .scope(agent, gc.nogc());
 
         // 5. Let functionalReplace be IsCallable(replaceValue).
-        if let Some(functional_replace) = is_callable(replace_value.get(agent), gc.nogc()) {
-            // 7. Let searchLength be the length of searchString.
-            let search_length = search_string.len_(agent);
+        let functional_replace =
+            is_callable(replace_value.get(agent), gc.nogc()).map(|f| f.scope(agent, gc.nogc()));
+
+        // 6. If functionalReplace is false, then
+        let replace_string = if functional_replace.is_none() {
+            // a. Set replaceValue to ? ToString(replaceValue).
+            Some(
+                to_string(agent, replace_value.get(agent), gc.reborrow())
+                    .unbind()?
+                    .scope(agent, gc.nogc()),
+            )
+        } else {
+            None
+        };
 
-            // 8. Let advanceBy be max(1, searchLength).
-            let advance_by = max(1, search_length);
+        // 7. Let searchLength be the length of searchString.
+        let search_length = search_string.get(agent).utf16_len_(agent);
 
-            // 9. Let matchPositions be a new empty List.
-            let mut match_positions: Vec<usize> = vec![];
+        // 8. Let advanceBy be max(1, searchLength).
+        let advance_by = max(1, search_length);
 
-            // 10. Let position be StringIndexOf(s, searchString, 0).
-            let search_str = search_string.to_string_lossy_(agent);
-            let subject = s.to_string_lossy(agent).into_owned();
-            let mut position = 0;
+        // 9. Let matchPositions be a new empty List.
+        let mut match_positions: Vec<usize> = vec![];
 
-            // 11. Repeat, while position is not not-found,
-            while let Some(pos) = subject
-                .split_at_checked(position)
-                .and_then(|(_, str)| str.find(search_str.deref()))
-            {
-                // a. Append position to matchPositions.
-                match_positions.push(position + pos);
-                // b. Set position to StringIndexOf(string, searchString,
-                //    position + advanceBy).
-                position += advance_by + pos;
-            }
+        // 10. Let position be StringIndexOf(string, searchString, 0).
+        let string = s.get(agent).bind(gc.nogc());
+        let mut position = string.index_of_(agent, search_string.get(agent), 0);
 
-            // If none has found, return s.
-            if match_positions.is_empty() {
-                return Ok(s.get(agent).into());
-            }
+        // 11. Repeat, while position is not not-found,
+        while let Some(p) = position {
+            // a. Append position to matchPositions.
+            match_positions.push(p);
+            // b. Set position to StringIndexOf(string, searchString,
+            //    position + advanceBy).
+            position = string.index_of_(agent, search_string.get(agent), p + advance_by);
+        }
 
-            // 12. Let endOfLastMatch be 0.
-            let mut end_of_last_match = 0;
+        // If none has found, return string.
+        if match_positions.is_empty() {
+            return Ok(s.get(agent).into());
+        }
+
+        // 12. Let endOfLastMatch be 0.
+        let mut end_of_last_match = 0;
 
-            // 13. Let result be the empty String.
-            let mut result = std::string::String::with_capacity(subject.len());
+        // 13. Let result be the empty String.
+        let mut result = StringBuilder::new();
 
-            // 14. For each element p of matchPositions, do
-            let functional_replace = functional_replace.scope(agent, gc.nogc());
-            for p in match_positions {
-                // b. let replacement be ? ToString(? Call(replaceValue, undefined, « searchString, 𝔽(p), string »)).
+        // 14. For each element p of matchPositions, do
+        for p in match_positions {
+            let replacement = if let Some(functional_replace) = &functional_replace {
+                // b. If functionalReplace is true, then
+                // i. Let replacement be ? ToString(? Call(replaceValue,
+                //    undefined, « searchString, 𝔽(p), string »)).
                 let replacement = call_function(
                     agent,
                     functional_replace.get(agent),
                     Value::Undefined,
                     Some(ArgumentsList::from_mut_slice(&mut [
-                        search_string_root.get(agent).into(),
+                        search_string.get(agent).into(),
                         Number::from(p as u32).into(),
                         s.get(agent).into(),
                     ])),
@@ -1918,42 +1908,47 @@ impl StringPrototype {
                 )
                 .unbind()?
                 .bind(gc.nogc());
-                let replacement = to_string(agent, replacement.unbind(), gc.reborrow())
+                to_string(agent, replacement.unbind(), gc.reborrow())
                     .unbind()?
-                    .bind(gc.nogc());
-
-                // a. Let preserved be the substring of string from endOfLastMatch to p.
-                let preserved = &subject[end_of_last_match..p];
-                // d. Set result to the string-concatenation of result, preserved, and replacement.
-                let replacement_str = replacement.to_string_lossy_(agent);
-                result.reserve(preserved.len() + replacement_str.len());
-                result.push_str(preserved);
-                result.push_str(&replacement_str);
-                end_of_last_match = p + search_length;
-            }
-
-            // 15. If endOfLastMatch < the length of string, set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            if end_of_last_match < subject.len() {
-                let preserved = &subject[end_of_last_match..];
-                result.push_str(preserved);
-            }
-
-            // 16. Return result.
-            return Ok(String::from_string(agent, result, gc.into_nogc()).into());
+                    .bind(gc.nogc())
+            } else {
+                // c. Else,
+                // i. Assert: replaceValue is a String.
+                // ii. Let captures be a new empty List.
+                // iii. Let replacement be ! GetSubstitution(searchString,
+                //      string, p, captures, undefined, replaceValue).
+                get_substitution(
+                    agent,
+                    search_string.clone(),
+                    s.clone(),
+                    p,
+                    vec![],
+                    None,
+                    replace_string.clone().unwrap(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc())
+            };
+            // a. Let preserved be the substring of string from
+            //    endOfLastMatch to p.
+            // d. Set result to the string-concatenation of result, preserved,
+            //    and replacement.
+            result.push_substring(agent, s.get(agent), end_of_last_match, p);
+            result.push_string(agent, replacement);
+            // e. Set endOfLastMatch to p + searchLength.
+            end_of_last_match = p + search_length;
         }
 
-        // 6. If functionalReplace is false, Set replaceValue to ? ToString(replaceValue).
-        let replace_string = to_string(agent, replace_value.get(agent), gc.reborrow())
-            .unbind()?
-            .bind(gc.nogc());
-        // Everything are strings: `"foo".replaceAll("o", "a")` => use rust's replace
-        search_string = search_string_root.get(agent).bind(gc.nogc());
-        let s = s.get(agent).bind(gc.nogc());
-        let result = s.to_string_lossy_(agent).into_owned().replace(
-            search_string.to_string_lossy_(agent).deref(),
-            &replace_string.to_string_lossy_(agent),
-        );
-        Ok(String::from_string(agent, result, gc.into_nogc()).into())
+        // 15. If endOfLastMatch < the length of string, then
+        let string = s.get(agent).bind(gc.nogc());
+        let string_length = string.utf16_len_(agent);
+        // a. Set result to the string-concatenation of result and the
+        //    substring of string from endOfLastMatch.
+        result.push_substring(agent, string, end_of_last_match, string_length);
+
+        // 16. Return result.
+        Ok(result.finish(agent, gc.into_nogc()).into())
     }
 
     /// ### [22.1.3.21 String.prototype.search ( regexp )](https://tc39.es/ecma262/#sec-string.prototype.search)
@@ -2352,30 +2347,16 @@ impl StringPrototype {
             )
         };
 
+        // NOTE: `start` was already clamped to 0.
+        let start = start.min(s.utf16_len_(agent));
         // 9. Let searchLength be the length of searchStr.
         // 10. If searchLength = 0, return true.
-        if search_str.len_(agent) == 0 {
-            return Ok(true.into());
-        }
         // 11. Let end be start + searchLength.
         // 12. If end > len, return false.
         // 13. Let substring be the substring of S from start to end.
         // 14. If substring is searchStr, return true.
         // 15. Return false.
-        let haystack_str = if start == 0 {
-            s.to_string_lossy_(agent)
-        } else {
-            let len = s.utf16_len_(agent);
-            if start >= len {
-                "".into()
-            } else {
-                let start = s.utf8_index_(agent, start).unwrap();
-                s.as_wtf8_(agent).slice_from(start).to_string_lossy()
-            }
-        };
-        Ok(haystack_str
-            .starts_with(search_str.to_string_lossy_(agent).deref())
-            .into())
+        Ok(s.starts_with_at_(agent, search_str, start).into())
     }
 
     /// ### [22.1.3.25 String.prototype.substring ( start, end )](https://tc39.es/ecma262/#sec-string.prototype.substring)
@@ -3337,7 +3318,6 @@ pub(crate) fn to_zero_padded_decimal_string(
 /// abstract operation, a decimal digit is a code unit in the inclusive
 /// interval from 0x0030 (DIGIT ZERO) to 0x0039 (DIGIT NINE).
 #[allow(clippy::too_many_arguments)]
-#[cfg(feature = "regexp")]
 pub(crate) fn get_substitution<'gc, 'scope>(
     agent: &mut Agent,
     scoped_matched: Scoped<'scope, String>,
@@ -3349,99 +3329,111 @@ pub(crate) fn get_substitution<'gc, 'scope>(
     mut gc: GcScope<'gc, 'scope>,
 ) -> JsResult<'gc, String<'gc>> {
     let named_captures = named_captures.map(|c| c.scope(agent, gc.nogc()));
-    let str = scoped_str.to_string_lossy(agent);
-    // SAFETY: Scoped string data cannot be mutated or dropped.
-    let str = unsafe { core::mem::transmute::<&str, &'scope str>(str.as_ref()) };
-    let matched = scoped_matched.to_string_lossy(agent);
-    // SAFETY: Scoped string data cannot be mutated or dropped.
-    let matched = unsafe { core::mem::transmute::<&str, &'scope str>(matched.as_ref()) };
     // 1. Let stringLength be the length of str.
-    let utf8_string_length = str.len();
+    let string_length = scoped_str.get(agent).utf16_len_(agent);
     // 2. Assert: position ≤ stringLength.
-    debug_assert!(position <= scoped_str.get(agent).utf16_len_(agent));
-    let utf8_position = scoped_str
-        .get(agent)
-        .utf8_index_(agent, position)
-        .expect("Invalid UTF-8 position");
+    debug_assert!(position <= string_length);
     // 3. Let result be the empty String.
-    let mut result = Wtf8Buf::new();
+    let mut result = StringBuilder::new();
     // 4. Let templateRemainder be replacementTemplate.
-    let template_remainder = scoped_replacement_template.to_string_lossy(agent);
-    // SAFETY: Scoped string data cannot be mutated or dropped.
-    let mut template_remainder =
-        unsafe { core::mem::transmute::<&str, &'scope str>(template_remainder.as_ref()) };
+    // NOTE: All replacement patterns are ASCII, so the template is scanned
+    // directly as WTF-8 bytes. The template is copied as the Get of a named
+    // capture may trigger garbage collection.
+    let mut template = Wtf8Buf::new();
+    template.push_wtf8(scoped_replacement_template.get(agent).as_wtf8_(agent));
+    // SAFETY: converting to backing store data.
+    let template_bytes = unsafe { core::mem::transmute::<&Wtf8, &[u8]>(&template) };
+    let mut index = 0;
     // 5. Repeat, while templateRemainder is not the empty String,
-    while !template_remainder.is_empty() {
-        let template_remainder_bytes = template_remainder.as_bytes();
+    while index < template_bytes.len() {
         // a. NOTE: The following steps isolate ref (a prefix of
         //    templateRemainder), determine refReplacement (its replacement),
         //    and then append that replacement to result.
-        let mut r#ref = template_remainder;
-        let mut ref_replacement = std::borrow::Cow::Borrowed(template_remainder);
-        if template_remainder_bytes.len() == 1 {
+        let Some(dollar) = memchr::memchr(b'$', &template_bytes[index..]) else {
             // h. Else,
             // i. Let ref be the substring of templateRemainder from 0 to 1.
             // ii. Let refReplacement be ref.
-        } else if template_remainder_bytes[0] == b'$' {
-            if template_remainder_bytes[1] == b'$' {
+            result.push_wtf8(template.slice_from(index));
+            break;
+        };
+        if dollar > 0 {
+            // NOTE: Everything up to the next "$" is its own replacement.
+            result.push_wtf8(template.slice(index, index + dollar));
+            index += dollar;
+        }
+        // i. Let refLength be the length of ref.
+        // j. Set templateRemainder to the substring of templateRemainder from
+        //    refLength.
+        // k. Set result to the string-concatenation of result and
+        //    refReplacement.
+        let ref_length = match template_bytes.get(index + 1) {
+            Some(b'$') => {
                 // b. If templateRemainder starts with "$$", then
                 // i. Let ref be "$$".
-                r#ref = "$$";
                 // ii. Let refReplacement be "$".
-                ref_replacement = "$".into();
-            } else if template_remainder_bytes[1] == b'`' {
+                result.push_char('$');
+                2
+            }
+            Some(b'`') => {
                 // c. Else if templateRemainder starts with "$`", then
                 // i. Let ref be "$`".
-                r#ref = "$`";
-                // ii. Let refReplacement be the substring of str from 0 to position.
-                ref_replacement = str[0..utf8_position].into()
-            } else if template_remainder_bytes[1] == b'&' {
+                // ii. Let refReplacement be the substring of str from 0 to
+                //     position.
+                result.push_substring(agent, scoped_str.get(agent), 0, position);
+                2
+            }
+            Some(b'&') => {
                 // d. Else if templateRemainder starts with "$&", then
                 // i. Let ref be "$&".
-                r#ref = "$&";
                 // ii. Let refReplacement be matched.
-                ref_replacement = matched.into();
-            } else if template_remainder_bytes[1] == b'\'' {
+                result.push_string(agent, scoped_matched.get(agent));
+                2
+            }
+            Some(b'\'') => {
                 // e. Else if templateRemainder starts with "$'" (0x0024
                 //    (DOLLAR SIGN) followed by 0x0027 (APOSTROPHE)), then
                 // i. Let ref be "$'".
-                r#ref = "$'";
                 // ii. Let matchLength be the length of matched.
-                let match_length = matched.len();
+                let match_length = scoped_matched.get(agent).utf16_len_(agent);
                 // iii. Let tailPos be position + matchLength.
                 let tail_pos = position.saturating_add(match_length);
                 // iv. Let refReplacement be the substring of str from
                 //     min(tailPos, stringLength).
-                ref_replacement = str[tail_pos.min(utf8_string_length)..].into();
                 // v. NOTE: tailPos can exceed stringLength only if this
                 //    abstract operation was invoked by a call to the intrinsic
                 //    %Symbol.replace% method of %RegExp.prototype% on an
                 //    object whose "exec" property is not the intrinsic
                 //    %RegExp.prototype.exec%.
-            } else if template_remainder_bytes[1].is_ascii_digit() {
+                result.push_substring(
+                    agent,
+                    scoped_str.get(agent),
+                    tail_pos.min(string_length),
+                    string_length,
+                );
+                2
+            }
+            Some(&first_digit) if first_digit.is_ascii_digit() => {
                 // f. Else if templateRemainder starts with "$" followed by 1
                 //    or more decimal digits, then
                 // i. If templateRemainder starts with "$" followed by 2 or
                 //    more decimal digits, let digitCount be 2; otherwise let
                 //    digitCount be 1.
-                let mut digit_count = if template_remainder_bytes.len() > 2
-                    && template_remainder_bytes[2].is_ascii_digit()
-                {
-                    2
-                } else {
-                    1
-                };
-                // ii. Let digits be the substring of templateRemainder from 1 to 1 + digitCount.
-                let mut digits = &template_remainder[1..1 + digit_count];
+                // ii. Let digits be the substring of templateRemainder from 1
+                //     to 1 + digitCount.
                 // iii. Let index be ℝ(StringToNumber(digits)).
-                let index: u8 = digits.parse().unwrap();
+                let first_digit = (first_digit - b'0') as usize;
+                let (mut digit_count, mut capture_index) = match template_bytes.get(index + 2) {
+                    Some(second_digit) if second_digit.is_ascii_digit() => {
+                        (2, first_digit * 10 + (second_digit - b'0') as usize)
+                    }
+                    _ => (1, first_digit),
+                };
                 // iv. Assert: 0 ≤ index ≤ 99.
-                debug_assert!(index <= 99);
-                let mut index = index as usize;
+                debug_assert!(capture_index <= 99);
                 // v. Let captureLen be the number of elements in captures.
                 let capture_len = scoped_captures.len();
                 // vi. If index > captureLen and digitCount = 2, then
-                if index > capture_len && digit_count == 2 {
+                if capture_index > capture_len && digit_count == 2 {
                     // 1. NOTE: When a two-digit replacement pattern specifies
                     //    an index exceeding the count of capturing groups, it
                     //    is treated as a one-digit replacement pattern
@@ -3449,82 +3441,86 @@ pub(crate) fn get_substitution<'gc, 'scope>(
                     // 2. Set digitCount to 1.
                     digit_count = 1;
                     // 3. Set digits to the substring of digits from 0 to 1.
-                    digits = &digits[0..1];
                     // 4. Set index to ℝ(StringToNumber(digits)).
-                    index = digits.parse::<u8>().unwrap() as usize;
+                    capture_index = first_digit;
                 }
                 // vii. Let ref be the substring of templateRemainder from 0 to
                 //      1 + digitCount.
-                r#ref = &template_remainder[0..1 + digit_count];
                 // viii. If 1 ≤ index ≤ captureLen, then
-                if 1 <= index && index <= capture_len {
+                if 1 <= capture_index && capture_index <= capture_len {
                     // 1. Let capture be captures[index - 1].
-                    let capture = scoped_captures[index - 1].clone();
-                    if let Some(capture) = capture {
-                        // 3. Else,
-                        // a. Let refReplacement be capture.
-                        ref_replacement = capture.to_string_lossy(agent).to_string().into();
-                    } else {
-                        // 2. If capture is undefined, then
-                        // a. Let refReplacement be the empty String.
-                        ref_replacement = "".into();
+                    // 2. If capture is undefined, then
+                    //    a. Let refReplacement be the empty String.
+                    // 3. Else,
+                    //    a. Let refReplacement be capture.
+                    if let Some(capture) = &scoped_captures[capture_index - 1] {
+                        result.push_string(agent, capture.get(agent));
                     }
                 } else {
                     // ix. Else,
                     // 1. Let refReplacement be ref.
-                    ref_replacement = r#ref.into();
+                    result.push_wtf8(template.slice(index, index + 1 + digit_count));
                 }
-            } else if template_remainder_bytes[1] == b'<' {
+                1 + digit_count
+            }
+            Some(b'<') => {
                 // g. Else if templateRemainder starts with "$<", then
                 // i. Let gtPos be StringIndexOf(templateRemainder, ">", 0).
-                let gt_pos = template_remainder.find(">");
-                if let (Some(gt_pos), Some(named_captures)) = (gt_pos, named_captures.clone()) {
-                    // iii. Else,
-                    // 1. Let ref be the substring of templateRemainder from 0 to gtPos + 1.
-                    r#ref = &template_remainder[0..gt_pos + 1];
-                    // 2. Let groupName be the substring of templateRemainder from 2 to gtPos.
-                    let group_name = &template_remainder[2..gt_pos];
-                    let group_name = String::from_str(agent, group_name, gc.nogc());
-                    // 3. Assert: namedCaptures is an Object.
-                    // 4. Let capture be ? Get(namedCaptures, groupName).
-                    let capture = get(
-                        agent,
-                        named_captures.get(agent),
-                        group_name.to_property_key().unbind(),
-                        gc.reborrow(),
-                    )
-                    .unbind()?
-                    .bind(gc.nogc());
-                    // 5. If capture is undefined, then
-                    if capture.is_undefined() {
+                let gt_pos = memchr::memchr(b'>', &template_bytes[index..]);
+                match (gt_pos, &named_captures) {
+                    (Some(gt_pos), Some(named_captures)) => {
+                        // iii. Else,
+                        // 1. Let ref be the substring of templateRemainder
+                        //    from 0 to gtPos + 1.
+                        // 2. Let groupName be the substring of
+                        //    templateRemainder from 2 to gtPos.
+                        let mut group_name = Wtf8Buf::new();
+                        group_name.push_wtf8(template.slice(index + 2, index + gt_pos));
+                        let group_name = String::from_wtf8_buf(agent, group_name, gc.nogc());
+                        // 3. Assert: namedCaptures is an Object.
+                        // 4. Let capture be ? Get(namedCaptures, groupName).
+                        let capture = get(
+                            agent,
+                            named_captures.get(agent),
+                            group_name.to_property_key().unbind(),
+                            gc.reborrow(),
+                        )
+                        .unbind()?
+                        .bind(gc.nogc());
+                        // 5. If capture is undefined, then
                         // a. Let refReplacement be the empty String.
-                        ref_replacement = "".into();
-                    } else {
-                        // 6. Else,
-                        // a. Let refReplacement be ? ToString(capture).
-                        let capture = to_string(agent, capture.unbind(), gc.reborrow())
-                            .unbind()?
-                            .bind(gc.nogc());
-                        ref_replacement = capture.to_string_lossy_(agent).to_string().into();
+                        if !capture.is_undefined() {
+                            // 6. Else,
+                            // a. Let refReplacement be ? ToString(capture).
+                            let capture = to_string(agent, capture.unbind(), gc.reborrow())
+                                .unbind()?
+                                .bind(gc.nogc());
+                            result.push_string(agent, capture);
+                        }
+                        gt_pos + 1
+                    }
+                    _ => {
+                        // ii. If gtPos is not-found or namedCaptures is
+                        //     undefined, then
+                        // 1. Let ref be "$<".
+                        // 2. Let refReplacement be ref.
+                        result.push_str("$<");
+                        2
                     }
-                } else {
-                    // ii. If gtPos is not-found or namedCaptures is undefined, then
-                    // 1. Let ref be "$<".
-                    r#ref = "$<";
-                    // 2. Let refReplacement be ref.
-                    ref_replacement = r#ref.into();
                 }
             }
-        }
-        // i. Let refLength be the length of ref.
-        let ref_length = r#ref.len();
-        // j. Set templateRemainder to the substring of templateRemainder from refLength.
-        template_remainder = &template_remainder[ref_length..];
-        // k. Set result to the string-concatenation of result and refReplacement.
-        result.push_str(&ref_replacement);
+            _ => {
+                // h. Else,
+                // i. Let ref be the substring of templateRemainder from 0 to 1.
+                // ii. Let refReplacement be ref.
+                result.push_char('$');
+                1
+            }
+        };
+        index += ref_length;
     }
     // 6. Return result.
-    Ok(String::from_wtf8_buf(agent, result, gc.into_nogc()))
+    Ok(result.finish(agent, gc.into_nogc()))
 }
 
 /// ### [22.1.3.35.1 ThisStringValue ( value )](https://tc39.es/ecma262/#sec-thisstringvalue)
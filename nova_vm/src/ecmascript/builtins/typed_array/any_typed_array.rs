@@ -702,12 +702,13 @@ impl<'a> InternalMethods<'a> for AnyTypedArray<'a> {
         any_typed_array_delegate!(self, internal_delete, agent, property_key, gc)
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        any_typed_array_delegate!(self, try_own_property_keys, agent, gc)
+    ) -> TryResult<'gc, ()> {
+        any_typed_array_delegate!(self, try_own_property_keys_into, agent, keys, gc)
     }
 
     fn internal_own_property_keys<'gc>(
@@ -16,7 +16,7 @@ use crate::ecmascript::FLOAT_16_ARRAY_DISCRIMINANT;
 use crate::ecmascript::SharedDataBlock;
 use crate::{
     ecmascript::{
-        Agent, AnyArrayBuffer, AnyTypedArray, ArgumentsList, ArrayBuffer,
+        Agent, AnyArrayBuffer, AnyTypedArray, ArgumentsList, ArrayBuffer, ArrayBufferHeapData,
         BIGINT_64_ARRAY_DISCRIMINANT, BIGUINT_64_ARRAY_DISCRIMINANT, BigInt,
         CachedBufferByteLength, DataBlock, FLOAT_32_ARRAY_DISCRIMINANT,
         FLOAT_64_ARRAY_DISCRIMINANT, Function, INT_8_ARRAY_DISCRIMINANT, INT_16_ARRAY_DISCRIMINANT,
@@ -156,6 +156,48 @@ impl<'ta, T: Viewable> GenericTypedArray<'ta, T> {
         TryResult::Continue(())
     }
 
+    /// Create a new TypedArray with a fresh ArrayBuffer holding a copy of
+    /// the given elements.
+    ///
+    /// Throws a RangeError if the ArrayBuffer cannot be allocated.
+    pub fn from_slice(
+        agent: &mut Agent,
+        elements: &[T],
+        gc: NoGcScope<'ta, '_>,
+    ) -> JsResult<'ta, Self> {
+        Self::check_not_void_array();
+
+        let byte_length = (elements.len() as u64).saturating_mul(size_of::<T>() as u64);
+        let mut data_block = create_byte_data_block(agent, byte_length, gc)?;
+        // SAFETY: Viewables can be safely transmuted from bytes.
+        let (head, target, tail) = unsafe { data_block.align_to_mut::<T>() };
+        // SAFETY: cannot have any head or tail since we created length by
+        // multiplying with `size_of::<T>()`, and allocation is done 8-byte
+        // aligned.
+        unsafe {
+            assert_unchecked(head.is_empty() && tail.is_empty() && target.len() == elements.len())
+        };
+        target.copy_from_slice(elements);
+        let ab = agent
+            .heap
+            .create(ArrayBufferHeapData::new_fixed_length(data_block));
+        let result: VoidArray = agent.heap.create(TypedArrayRecord::default());
+        // SAFETY: Initialising new TypedArrayRecord.
+        unsafe {
+            result.initialise_data(agent, ab, 0, Some((byte_length as usize, elements.len())))
+        };
+        // SAFETY: the TypedArray was created with elements of type T.
+        Ok(unsafe { result.cast::<T>() }.bind(gc))
+    }
+
+    /// Copy the elements of this TypedArray into a new Vec.
+    ///
+    /// Returns an empty Vec if the TypedArray is out of bounds, e.g. because
+    /// its ArrayBuffer has been detached.
+    pub fn to_vec(self, agent: &Agent) -> Vec<T> {
+        self.as_slice(agent).to_vec()
+    }
+
     #[inline(always)]
     pub(crate) fn as_slice(self, agent: &'ta Agent) -> &'ta [T] {
         Self::check_not_void_array();
@@ -1017,11 +1059,12 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericTypedArray<'a, T> {
     }
 
     /// ### [10.4.5.8 \[\[OwnPropertyKeys\]\] ( )](https://tc39.es/ecma262/#sec-typedarray-ownpropertykeys)
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         let o = self.bind(gc);
         // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
         let cached_byte_length = o.get_cached_buffer_byte_length(agent, Ordering::SeqCst);
@@ -1033,12 +1076,10 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericTypedArray<'a, T> {
             0
         };
         // 2. Let keys be a new empty List.
-        let mut keys = Vec::with_capacity(length);
+        keys.reserve(length);
         // b. For each integer i such that 0 ≤ i < length, in ascending order, do
         // i. Append ! ToString(𝔽(i)) to keys.
-        for i in 0..length {
-            keys.push(i.try_into().unwrap());
-        }
+        keys.extend((0..length).map(|i| PropertyKey::try_from(i).unwrap()));
         if let Some(backing_object) = self.get_backing_object(agent) {
             // 4. For each own property key P of O such that P is a String and P is
             //    not an integer index, in ascending chronological order of
@@ -1047,12 +1088,10 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericTypedArray<'a, T> {
             // 5. For each own property key P of O such that P is a Symbol, in
             //    ascending chronological order of property creation, do
             // a. Append P to keys.
-            keys.append(&mut unwrap_try(
-                backing_object.try_own_property_keys(agent, gc),
-            ));
+            unwrap_try(backing_object.try_own_property_keys_into(agent, keys, gc));
         }
         // 6. Return keys.
-        TryResult::Continue(keys)
+        TryResult::Continue(())
     }
 }
 
@@ -1337,12 +1376,13 @@ impl<'a> InternalMethods<'a> for TypedArray<'a> {
         typed_array_delegate!(self, internal_delete, agent, property_key, gc)
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        typed_array_delegate!(self, try_own_property_keys, agent, gc)
+    ) -> TryResult<'gc, ()> {
+        typed_array_delegate!(self, try_own_property_keys_into, agent, keys, gc)
     }
 
     fn internal_own_property_keys<'gc>(
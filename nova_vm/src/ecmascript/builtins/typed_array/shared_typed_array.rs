@@ -180,7 +180,7 @@ impl<'ta, T: Viewable> GenericSharedTypedArray<'ta, T> {
         let data = self.into_void_array().get(agent);
         let buffer = data.viewed_array_buffer;
         let byte_offset = data.get_byte_offset(key, &agent.heap.shared_typed_array_byte_offsets);
-        let byte_length = data.get_byte_length(key, &agent.heap.shared_typed_array_byte_offsets);
+        let byte_length = data.get_byte_length(key, &agent.heap.shared_typed_array_byte_lengths);
         let mut slice = buffer.as_slice(agent).slice_from(byte_offset);
         if let Some(byte_length) = byte_length {
             slice = slice.slice_to(byte_length);
@@ -584,12 +584,13 @@ impl<'a> InternalMethods<'a> for SharedTypedArray<'a> {
         shared_typed_array_delegate!(self, internal_delete, agent, property_key, gc)
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        shared_typed_array_delegate!(self, try_own_property_keys, agent, gc)
+    ) -> TryResult<'gc, ()> {
+        shared_typed_array_delegate!(self, try_own_property_keys_into, agent, keys, gc)
     }
 
     fn internal_own_property_keys<'gc>(
@@ -1258,11 +1259,12 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericSharedTypedArray<'a, T> {
     }
 
     /// ### [10.4.5.8 \[\[OwnPropertyKeys\]\] ( )](https://tc39.es/ecma262/#sec-typedarray-ownpropertykeys)
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         let o = self.bind(gc);
         // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
         let cached_byte_length = o.get_cached_buffer_byte_length(agent, Ordering::SeqCst);
@@ -1274,12 +1276,10 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericSharedTypedArray<'a, T> {
             0
         };
         // 2. Let keys be a new empty List.
-        let mut keys = Vec::with_capacity(length);
+        keys.reserve(length);
         // b. For each integer i such that 0 ≤ i < length, in ascending order, do
         // i. Append ! ToString(𝔽(i)) to keys.
-        for i in 0..length {
-            keys.push(i.try_into().unwrap());
-        }
+        keys.extend((0..length).map(|i| PropertyKey::try_from(i).unwrap()));
         if let Some(backing_object) = self.get_backing_object(agent) {
             // 4. For each own property key P of O such that P is a String and P is
             //    not an integer index, in ascending chronological order of
@@ -1288,12 +1288,10 @@ impl<'a, T: Viewable> InternalMethods<'a> for GenericSharedTypedArray<'a, T> {
             // 5. For each own property key P of O such that P is a Symbol, in
             //    ascending chronological order of property creation, do
             // a. Append P to keys.
-            keys.append(&mut unwrap_try(
-                backing_object.try_own_property_keys(agent, gc),
-            ));
+            unwrap_try(backing_object.try_own_property_keys_into(agent, keys, gc));
         }
         // 6. Return keys.
-        TryResult::Continue(keys)
+        TryResult::Continue(())
     }
 }
 
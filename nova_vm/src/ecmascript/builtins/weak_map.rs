@@ -8,8 +8,8 @@ pub(crate) use data::*;
 
 use crate::{
     ecmascript::{
-        Agent, InternalMethods, InternalSlots, OrdinaryObject, ProtoIntrinsics, Value, WeakKey,
-        object_handle,
+        Agent, Behaviour, Function, InternalMethods, InternalSlots, OrdinaryObject,
+        ProtoIntrinsics, Value, WeakKey, WeakMapPrototype, object_handle,
     },
     engine::Bindable,
     heap::{
@@ -33,6 +33,28 @@ object_handle!(WeakMap);
 arena_vec_access!(WeakMap, 'a, WeakMapRecord, weak_maps);
 
 impl<'m> WeakMap<'m> {
+    /// Returns true if the function is equal to %WeakMap.prototype.set%.
+    pub(crate) fn is_weak_map_prototype_set(agent: &Agent, function: Function) -> bool {
+        let Function::BuiltinFunction(function) = function else {
+            return false;
+        };
+        let Behaviour::Regular(behaviour) = function.get(agent).behaviour else {
+            return false;
+        };
+        // See WeakSet::is_weak_set_prototype_add for the function address
+        // comparison.
+        #[allow(unknown_lints, renamed_and_removed_lints)]
+        {
+            #[allow(
+                clippy::fn_address_comparisons,
+                unpredictable_function_pointer_comparisons
+            )]
+            {
+                behaviour == WeakMapPrototype::set
+            }
+        }
+    }
+
     pub(crate) fn delete(self, agent: &mut Agent, key: WeakKey<'m>) -> bool {
         self.get_mut(agent).delete(key)
     }
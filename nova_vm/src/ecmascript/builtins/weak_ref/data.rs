@@ -5,7 +5,7 @@
 use crate::{
     ecmascript::{execution::WeakKey, types::OrdinaryObject},
     engine::bindable_handle,
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues},
 };
 
 #[derive(Default, Debug, Clone)]
@@ -45,10 +45,11 @@ impl HeapMarkAndSweep for WeakRefHeapData<'static> {
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             object_index,
-            weak_ref_target: value,
+            weak_ref_target,
             kept_alive: _,
         } = self;
         object_index.sweep_values(compactions);
-        value.sweep_values(compactions);
+        *weak_ref_target =
+            weak_ref_target.and_then(|target| target.sweep_weak_reference(compactions));
     }
 }
@@ -0,0 +1,68 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! # Web APIs
+//!
+//! Subsets of web platform APIs that are commonly needed by embedders but are
+//! not part of the ECMAScript specification. Each API is behind its own
+//! feature flag.
+//!
+//! Web API objects are [embedder objects]: their native data is stored as
+//! Rust data in the object while the JavaScript values they refer to are
+//! stored in the object's internal slots.
+//!
+//! [embedder objects]: crate::ecmascript::EmbedderObject
+
+#[cfg(feature = "web-abort")]
+mod abort;
+#[cfg(feature = "web-events")]
+mod events;
+#[cfg(feature = "web-fetch")]
+mod fetch;
+#[cfg(feature = "web-blob")]
+mod file;
+#[cfg(feature = "web-streams")]
+mod streams;
+
+#[cfg(feature = "web-abort")]
+pub use abort::*;
+#[cfg(feature = "web-events")]
+pub use events::*;
+#[cfg(feature = "web-fetch")]
+pub use fetch::*;
+#[cfg(feature = "web-blob")]
+pub use file::*;
+#[cfg(feature = "web-streams")]
+pub use streams::*;
+
+#[cfg(any(feature = "web-abort", feature = "web-events"))]
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, ExceptionType, InternalMethods, PropertyDescriptor, String,
+        Value, unwrap_try,
+    },
+    engine::NoGcScope,
+};
+
+/// Create an Error object standing in for a DOMException with the given name.
+#[cfg(any(feature = "web-abort", feature = "web-events"))]
+pub(crate) fn create_dom_exception<'a>(
+    agent: &mut Agent,
+    message: &'static str,
+    name: String<'static>,
+    gc: NoGcScope<'a, '_>,
+) -> Value<'a> {
+    let error = agent.create_exception_with_static_message(ExceptionType::Error, message, gc);
+    let Value::Error(object) = error else {
+        unreachable!()
+    };
+    unwrap_try(object.try_define_own_property(
+        agent,
+        BUILTIN_STRING_MEMORY.name.into(),
+        PropertyDescriptor::non_enumerable_data_descriptor(name),
+        None,
+        gc,
+    ));
+    error
+}
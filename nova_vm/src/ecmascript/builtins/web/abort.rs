@@ -0,0 +1,25 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [3.3 Aborting ongoing activities](https://dom.spec.whatwg.org/#aborting-ongoing-activities)
+//!
+//! [`AbortController`] and [`AbortSignal`]. AbortSignal is not an
+//! EventTarget: the abort event is only delivered to its `onabort` event
+//! handler, which is called without an event argument. DOMException is not
+//! supported: the default abort reasons are Error objects with the
+//! DOMException's name.
+
+mod abort_controller;
+mod abort_controller_constructor;
+mod abort_controller_prototype;
+mod abort_signal;
+mod abort_signal_constructor;
+mod abort_signal_prototype;
+
+pub use abort_controller::*;
+pub(crate) use abort_controller_constructor::*;
+pub(crate) use abort_controller_prototype::*;
+pub use abort_signal::*;
+pub(crate) use abort_signal_constructor::*;
+pub(crate) use abort_signal_prototype::*;
@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{AbortSignal, Agent, EmbedderObject, Value, embedder_object_handle},
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+use super::signal_abort;
+
+/// ### [3.3.2 Interface AbortController](https://dom.spec.whatwg.org/#interface-abortcontroller)
+///
+/// An AbortController aborts its [`AbortSignal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AbortController<'a>(EmbedderObject<'a>);
+embedder_object_handle!(AbortController, AbortControllerRecord);
+
+#[derive(Debug)]
+pub(crate) struct AbortControllerRecord;
+
+impl<'a> AbortController<'a> {
+    /// signal
+    const SIGNAL: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    /// ### [new AbortController()](https://dom.spec.whatwg.org/#dom-abortcontroller-abortcontroller)
+    ///
+    /// Create a new AbortController in the current Realm.
+    pub fn new(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Self {
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .abort_controller_prototype();
+        let controller = Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            AbortControllerRecord,
+            gc,
+        ));
+        controller.initialize(agent, gc);
+        controller
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    /// Set the controller's signal to a new AbortSignal.
+    pub(crate) fn initialize(self, agent: &mut Agent, gc: NoGcScope) {
+        // 1. Let signal be a new AbortSignal object.
+        let signal = AbortSignal::create(agent, gc);
+        // 2. Set this's signal to signal.
+        self.0.set_slot(agent, Self::SIGNAL, signal.into());
+    }
+
+    /// Returns the controller's signal.
+    pub fn signal(self, agent: &Agent) -> AbortSignal<'a> {
+        AbortSignal::try_from_value(agent, self.0.get_slot(agent, Self::SIGNAL)).unwrap()
+    }
+
+    /// ### [signal abort](https://dom.spec.whatwg.org/#abortcontroller-signal-abort)
+    ///
+    /// Abort the controller's signal with the given reason. An undefined
+    /// reason is replaced with an "AbortError" Error. Nothing is done if the
+    /// signal is already aborted.
+    pub fn abort(self, agent: &mut Agent, reason: Value, gc: GcScope) {
+        // 1. Signal abort on controller's signal with reason if it is given.
+        let signal = self.signal(agent).unbind();
+        signal_abort(agent, signal, reason, gc);
+    }
+}
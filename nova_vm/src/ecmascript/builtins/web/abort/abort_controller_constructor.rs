@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor,
+    },
+    engine::{Bindable, GcScope},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{AbortController, AbortControllerRecord};
+
+pub(crate) struct AbortControllerConstructor;
+impl Builtin for AbortControllerConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.AbortController;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for AbortControllerConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::AbortController;
+}
+
+impl AbortControllerConstructor {
+    /// ### [new AbortController()](https://dom.spec.whatwg.org/#dom-abortcontroller-abortcontroller)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin AbortController constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        let controller = embedder_object_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::AbortController,
+            AbortController::SLOT_COUNT,
+            AbortControllerRecord,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let controller = AbortController::from_embedder_object(controller.bind(gc));
+        controller.initialize(agent, gc);
+        Ok(controller.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let abort_controller_prototype = intrinsics.abort_controller_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<AbortControllerConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(abort_controller_prototype.into())
+        .build();
+    }
+}
@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::AbortController;
+
+pub(crate) struct AbortControllerPrototype;
+
+struct AbortControllerPrototypeGetSignal;
+impl Builtin for AbortControllerPrototypeGetSignal {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_signal;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.signal.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortControllerPrototype::get_signal);
+}
+impl BuiltinGetter for AbortControllerPrototypeGetSignal {}
+struct AbortControllerPrototypeAbort;
+impl Builtin for AbortControllerPrototypeAbort {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.abort;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortControllerPrototype::abort);
+}
+
+impl AbortControllerPrototype {
+    /// ### [get AbortController.prototype.signal](https://dom.spec.whatwg.org/#dom-abortcontroller-signal)
+    fn get_signal<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let controller = require_abort_controller(agent, this_value, gc)?;
+        // 1. Return this's signal.
+        Ok(controller.signal(agent).into())
+    }
+
+    /// ### [AbortController.prototype.abort ( reason )](https://dom.spec.whatwg.org/#dom-abortcontroller-abort)
+    fn abort<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let reason = arguments.get(0).bind(gc.nogc());
+        let controller = require_abort_controller(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 1. Signal abort on this with reason if it is given.
+        controller.unbind().abort(agent, reason.unbind(), gc);
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.abort_controller_prototype();
+        let abort_controller_constructor = intrinsics.abort_controller();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<AbortControllerPrototypeAbort>()
+            .with_constructor_property(abort_controller_constructor)
+            .with_builtin_function_getter_property::<AbortControllerPrototypeGetSignal>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.AbortController.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_abort_controller<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, AbortController<'a>> {
+    AbortController::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an AbortController",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,304 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::TryReserveError;
+
+use crate::{
+    ecmascript::{
+        Agent, Array, BUILTIN_STRING_MEMORY, EmbedderObject, Function, JsError, JsResult, Value,
+        call_function, create_dom_exception, embedder_object_handle,
+    },
+    engine::{Bindable, GcScope, Global, NoGcScope, Scopable},
+};
+
+/// ### [3.3.3 Interface AbortSignal](https://dom.spec.whatwg.org/#interface-AbortSignal)
+///
+/// An AbortSignal signals to an ongoing operation that it should be aborted.
+/// Signals are created and aborted with an [`AbortController`].
+///
+/// Embedders can cancel host operations when a signal is aborted by adding
+/// an abort algorithm with [`AbortSignal::add_algorithm`].
+///
+/// [`AbortController`]: crate::ecmascript::AbortController
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AbortSignal<'a>(EmbedderObject<'a>);
+embedder_object_handle!(AbortSignal, AbortSignalRecord);
+
+type AbortAlgorithm = Box<dyn FnOnce(&mut Agent, Value, GcScope) + Send>;
+
+pub(crate) struct AbortSignalRecord {
+    /// abort algorithms
+    abort_algorithms: Vec<AbortAlgorithm>,
+    /// dependent
+    dependent: bool,
+}
+
+impl core::fmt::Debug for AbortSignalRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AbortSignalRecord")
+            .field("abort_algorithms", &self.abort_algorithms.len())
+            .field("dependent", &self.dependent)
+            .finish()
+    }
+}
+
+impl<'a> AbortSignal<'a> {
+    /// abort reason
+    const REASON: usize = 0;
+    /// source signals
+    const SOURCE_SIGNALS: usize = 1;
+    /// dependent signals
+    const DEPENDENT_SIGNALS: usize = 2;
+    /// onabort event handler
+    const ONABORT: usize = 3;
+    const SLOT_COUNT: usize = 4;
+
+    /// Create a new AbortSignal in the current Realm.
+    pub(crate) fn create(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Self {
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .abort_signal_prototype();
+        Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            AbortSignalRecord {
+                abort_algorithms: Vec::new(),
+                dependent: false,
+            },
+            gc,
+        ))
+    }
+
+    /// Returns true if the signal has been aborted.
+    pub fn is_aborted(self, agent: &Agent) -> bool {
+        // An AbortSignal object is aborted when its abort reason is not
+        // undefined.
+        !self.reason(agent).is_undefined()
+    }
+
+    /// Returns the abort reason of the signal, or undefined if the signal has
+    /// not been aborted.
+    pub fn reason(self, agent: &Agent) -> Value<'a> {
+        self.0.get_slot(agent, Self::REASON)
+    }
+
+    pub(crate) fn set_reason(self, agent: &mut Agent, reason: Value) {
+        self.0.set_slot(agent, Self::REASON, reason);
+    }
+
+    /// ### [add](https://dom.spec.whatwg.org/#abortsignal-add)
+    ///
+    /// Add an algorithm to run with the abort reason when the signal is
+    /// aborted. Nothing is done if the signal is already aborted.
+    pub fn add_algorithm(
+        self,
+        agent: &mut Agent,
+        algorithm: impl FnOnce(&mut Agent, Value, GcScope) + Send + 'static,
+    ) {
+        // 1. If signal is aborted, then return.
+        if self.is_aborted(agent) {
+            return;
+        }
+        // 2. Append algorithm to signal's abort algorithms.
+        self.data_mut(agent)
+            .abort_algorithms
+            .push(Box::new(algorithm));
+    }
+
+    /// ### [throw if aborted](https://dom.spec.whatwg.org/#abortsignal-throw-if-aborted)
+    ///
+    /// Throw the abort reason of the signal if it has been aborted.
+    pub fn throw_if_aborted<'gc>(self, agent: &Agent, gc: NoGcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        // 1. If this is aborted, then throw this's abort reason.
+        if self.is_aborted(agent) {
+            return Err(JsError::new(self.reason(agent).bind(gc)));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn onabort(self, agent: &Agent) -> Option<Function<'a>> {
+        Function::try_from(self.0.get_slot(agent, Self::ONABORT)).ok()
+    }
+
+    pub(crate) fn set_onabort(self, agent: &mut Agent, handler: Option<Function>) {
+        let handler = handler.map_or(Value::Null, |handler| handler.into());
+        self.0.set_slot(agent, Self::ONABORT, handler);
+    }
+
+    /// Returns the signals in one of the signal's sets of signals.
+    fn signals(self, agent: &Agent, slot: usize) -> Vec<AbortSignal<'a>> {
+        match self.0.get_slot(agent, slot) {
+            Value::Array(signals) => signals
+                .as_slice(agent)
+                .iter()
+                .map(|signal| AbortSignal::try_from_value(agent, signal.unwrap()).unwrap())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Append a signal to one of the signal's sets of signals.
+    fn append_signal(
+        self,
+        agent: &mut Agent,
+        slot: usize,
+        signal: AbortSignal,
+        gc: NoGcScope,
+    ) -> Result<(), TryReserveError> {
+        let signals = match self.0.get_slot(agent, slot) {
+            Value::Array(signals) => signals,
+            _ => {
+                let signals = Array::new(agent, gc);
+                self.0.set_slot(agent, slot, signals.into());
+                signals
+            }
+        };
+        let signal = Value::from(signal.unbind());
+        if signals.as_slice(agent).contains(&Some(signal)) {
+            return Ok(());
+        }
+        signals.push(agent, signal)
+    }
+}
+
+/// ### [create a dependent abort signal](https://dom.spec.whatwg.org/#create-a-dependent-abort-signal)
+pub(crate) fn create_dependent_abort_signal<'a>(
+    agent: &mut Agent,
+    signals: &[AbortSignal],
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, AbortSignal<'a>> {
+    // 1. Let resultSignal be a new object implementing signalInterface using
+    //    realm.
+    let result_signal = AbortSignal::create(agent, gc);
+    // 2. For each signal of signals: if signal is aborted, then set
+    //    resultSignal's abort reason to signal's abort reason and return
+    //    resultSignal.
+    if let Some(signal) = signals.iter().find(|signal| signal.is_aborted(agent)) {
+        result_signal.set_reason(agent, signal.reason(agent));
+        return Ok(result_signal);
+    }
+    // 3. Set resultSignal's dependent to true.
+    result_signal.data_mut(agent).dependent = true;
+    // 4. For each signal of signals:
+    for &signal in signals {
+        // a. If signal's dependent is false:
+        let source_signals = if !signal.data(agent).dependent {
+            vec![signal]
+        } else {
+            // b. Otherwise, for each sourceSignal of signal's source signals:
+            // i. Assert: sourceSignal is not aborted and not dependent.
+            signal.signals(agent, AbortSignal::SOURCE_SIGNALS)
+        };
+        for source_signal in source_signals {
+            // i. Append signal to resultSignal's source signals.
+            // ii. Append resultSignal to signal's dependent signals.
+            result_signal
+                .append_signal(agent, AbortSignal::SOURCE_SIGNALS, source_signal, gc)
+                .and_then(|_| {
+                    source_signal.append_signal(
+                        agent,
+                        AbortSignal::DEPENDENT_SIGNALS,
+                        result_signal,
+                        gc,
+                    )
+                })
+                .map_err(|err| agent.throw_allocation_exception(err, gc))?;
+        }
+    }
+    // 5. Return resultSignal.
+    Ok(result_signal)
+}
+
+/// ### [signal abort](https://dom.spec.whatwg.org/#abortsignal-signal-abort)
+///
+/// An undefined reason is replaced with a new "AbortError" DOMException.
+pub(crate) fn signal_abort(agent: &mut Agent, signal: AbortSignal, reason: Value, mut gc: GcScope) {
+    let signal = signal.bind(gc.nogc());
+    let reason = reason.bind(gc.nogc());
+    // 1. If signal is aborted, then return.
+    if signal.is_aborted(agent) {
+        return;
+    }
+    // 2. Set signal's abort reason to reason if it is given; otherwise to a
+    //    new "AbortError" DOMException.
+    let reason = if reason.is_undefined() {
+        create_dom_exception(
+            agent,
+            "The operation was aborted",
+            BUILTIN_STRING_MEMORY.AbortError,
+            gc.nogc(),
+        )
+    } else {
+        reason
+    };
+    signal.set_reason(agent, reason);
+    // 3. Let dependentSignalsToAbort be a new list.
+    let mut dependent_signals_to_abort = Vec::new();
+    // 4. For each dependentSignal of signal's dependent signals:
+    for dependent_signal in signal.signals(agent, AbortSignal::DEPENDENT_SIGNALS) {
+        // a. If dependentSignal is not aborted:
+        if !dependent_signal.is_aborted(agent) {
+            // i. Set dependentSignal's abort reason to signal's abort reason.
+            dependent_signal.set_reason(agent, reason);
+            // ii. Append dependentSignal to dependentSignalsToAbort.
+            dependent_signals_to_abort.push(dependent_signal.scope(agent, gc.nogc()));
+        }
+    }
+    // 5. Run the abort steps for signal.
+    run_abort_steps(agent, signal.unbind(), gc.reborrow());
+    // 6. For each dependentSignal of dependentSignalsToAbort, run the abort
+    //    steps for dependentSignal.
+    for dependent_signal in dependent_signals_to_abort {
+        run_abort_steps(agent, dependent_signal.get(agent), gc.reborrow());
+    }
+}
+
+/// ### [run the abort steps](https://dom.spec.whatwg.org/#run-the-abort-steps)
+fn run_abort_steps(agent: &mut Agent, signal: AbortSignal, mut gc: GcScope) {
+    let signal = signal.bind(gc.nogc());
+    // 1. For each algorithm of signal's abort algorithms: run algorithm.
+    // 2. Empty signal's abort algorithms.
+    let algorithms = core::mem::take(&mut signal.data_mut(agent).abort_algorithms);
+    let signal = signal.scope(agent, gc.nogc());
+    for algorithm in algorithms {
+        let reason = signal.get(agent).reason(agent);
+        algorithm(agent, reason, gc.reborrow());
+    }
+    // 3. Fire an event named abort at signal.
+    let signal = signal.get(agent).bind(gc.nogc());
+    if let Some(handler) = signal.onabort(agent)
+        && let Err(err) = call_function(
+            agent,
+            handler.unbind(),
+            signal.unbind().into(),
+            None,
+            gc.reborrow(),
+        )
+    {
+        // NOTE: Exceptions thrown by event handlers are reported.
+        let host_hooks = agent.host_hooks;
+        host_hooks.report_job_error(agent, err.unbind(), gc.nogc());
+    }
+}
+
+/// Job aborting a signal created by AbortSignal.timeout with a
+/// "TimeoutError" DOMException.
+pub(crate) struct AbortSignalTimeoutJob(pub(crate) Global<AbortSignal<'static>>);
+
+impl AbortSignalTimeoutJob {
+    pub(crate) fn run<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        let signal = self.0.take(agent).bind(gc.nogc());
+        let reason = create_dom_exception(
+            agent,
+            "The operation timed out",
+            BUILTIN_STRING_MEMORY.TimeoutError,
+            gc.nogc(),
+        );
+        signal_abort(agent, signal.unbind(), reason.unbind(), gc);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, InnerJob, Job, JsResult, Object, Realm, String,
+        Value, builders::BuiltinFunctionBuilder, create_dom_exception, get_iterator,
+        iterator_to_list, throw_not_callable, to_number,
+    },
+    engine::{Bindable, GcScope, Global},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{AbortSignal, AbortSignalTimeoutJob, create_dependent_abort_signal};
+
+pub(crate) struct AbortSignalConstructor;
+impl Builtin for AbortSignalConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.AbortSignal;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for AbortSignalConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::AbortSignal;
+}
+
+struct AbortSignalAbort;
+impl Builtin for AbortSignalAbort {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalConstructor::abort);
+    const LENGTH: u8 = 0;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.abort;
+}
+struct AbortSignalAny;
+impl Builtin for AbortSignalAny {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalConstructor::any);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.any;
+}
+struct AbortSignalTimeout;
+impl Builtin for AbortSignalTimeout {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalConstructor::timeout);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.timeout;
+}
+
+impl AbortSignalConstructor {
+    /// ### [3.3.3 Interface AbortSignal](https://dom.spec.whatwg.org/#interface-AbortSignal)
+    ///
+    /// The AbortSignal interface has no constructor: signals are created by
+    /// AbortController and the static methods of AbortSignal.
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        _new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Illegal constructor",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [AbortSignal.abort ( reason )](https://dom.spec.whatwg.org/#dom-abortsignal-abort)
+    fn abort<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let reason = arguments.get(0).bind(gc);
+        // 1. Let signal be a new AbortSignal object.
+        let signal = AbortSignal::create(agent, gc);
+        // 2. Set signal's abort reason to reason if it is given; otherwise to
+        //    a new "AbortError" DOMException.
+        let reason = if reason.is_undefined() {
+            create_dom_exception(
+                agent,
+                "The operation was aborted",
+                BUILTIN_STRING_MEMORY.AbortError,
+                gc,
+            )
+        } else {
+            reason
+        };
+        signal.set_reason(agent, reason);
+        // 3. Return signal.
+        Ok(signal.into())
+    }
+
+    /// ### [AbortSignal.timeout ( milliseconds )](https://dom.spec.whatwg.org/#dom-abortsignal-timeout)
+    fn timeout<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let milliseconds = arguments.get(0).bind(gc.nogc());
+        // NOTE: The milliseconds argument is converted to an
+        // [EnforceRange] unsigned long long.
+        let milliseconds = to_number(agent, milliseconds.unbind(), gc.reborrow())
+            .unbind()?
+            .into_f64(agent);
+        let gc = gc.into_nogc();
+        if !milliseconds.is_finite() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "milliseconds is not a finite number",
+                gc,
+            ));
+        }
+        let milliseconds = milliseconds.trunc();
+        if !(0.0..=9007199254740991.0).contains(&milliseconds) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "milliseconds is out of range",
+                gc,
+            ));
+        }
+        // 1. Let signal be a new AbortSignal object.
+        let signal = AbortSignal::create(agent, gc);
+        // 2. Let global be signal's relevant global object.
+        // 3. Run steps after a timeout given global, "AbortSignal-timeout",
+        //    milliseconds, and the following step:
+        // a. Queue a global task on the timer task source given global to
+        //    signal abort given signal and a new "TimeoutError" DOMException.
+        let realm = Global::new(agent, agent.current_realm(gc).unbind());
+        let job = Job {
+            realm: Some(realm),
+            inner: InnerJob::AbortSignalTimeout(AbortSignalTimeoutJob(Global::new(
+                agent,
+                signal.unbind(),
+            ))),
+        };
+        agent
+            .host_hooks
+            .enqueue_timeout_job(job, milliseconds as u64);
+        // 4. Return signal.
+        Ok(signal.into())
+    }
+
+    /// ### [AbortSignal.any ( signals )](https://dom.spec.whatwg.org/#dom-abortsignal-any)
+    fn any<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let signals = arguments.get(0).bind(gc.nogc());
+        // NOTE: The signals argument is converted to a
+        // sequence<AbortSignal>.
+        let Some(iterator_record) = get_iterator(agent, signals.unbind(), false, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+            .into_iterator_record()
+        else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+        let values = iterator_to_list(agent, iterator_record.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let mut signals = Vec::with_capacity(values.len(agent));
+        for value in values.iter(agent) {
+            let Some(signal) = AbortSignal::try_from_value(agent, value.get(gc)) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "signals contains a value that is not an AbortSignal",
+                    gc,
+                ));
+            };
+            signals.push(signal);
+        }
+        // 1. Return the result of creating a dependent abort signal from
+        //    signals using AbortSignal and the current realm.
+        create_dependent_abort_signal(agent, &signals, gc).map(|signal| signal.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let abort_signal_prototype = intrinsics.abort_signal_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<AbortSignalConstructor>(agent, realm)
+            .with_property_capacity(4)
+            .with_builtin_function_property::<AbortSignalAbort>()
+            .with_builtin_function_property::<AbortSignalAny>()
+            .with_prototype_property(abort_signal_prototype.into())
+            .with_builtin_function_property::<AbortSignalTimeout>()
+            .build();
+    }
+}
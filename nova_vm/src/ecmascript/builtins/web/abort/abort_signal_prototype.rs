@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        BuiltinSetter, ExceptionType, Function, JsResult, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::AbortSignal;
+
+pub(crate) struct AbortSignalPrototype;
+
+struct AbortSignalPrototypeGetAborted;
+impl Builtin for AbortSignalPrototypeGetAborted {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_aborted;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.aborted.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalPrototype::get_aborted);
+}
+impl BuiltinGetter for AbortSignalPrototypeGetAborted {}
+struct AbortSignalPrototypeGetReason;
+impl Builtin for AbortSignalPrototypeGetReason {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_reason;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.reason.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalPrototype::get_reason);
+}
+impl BuiltinGetter for AbortSignalPrototypeGetReason {}
+struct AbortSignalPrototypeOnabort;
+impl Builtin for AbortSignalPrototypeOnabort {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_onabort;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.onabort.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalPrototype::get_onabort);
+}
+impl BuiltinGetter for AbortSignalPrototypeOnabort {}
+impl BuiltinSetter for AbortSignalPrototypeOnabort {
+    const SETTER_NAME: String<'static> = BUILTIN_STRING_MEMORY.set_onabort;
+    const SETTER_BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalPrototype::set_onabort);
+}
+struct AbortSignalPrototypeThrowIfAborted;
+impl Builtin for AbortSignalPrototypeThrowIfAborted {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.throwIfAborted;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AbortSignalPrototype::throw_if_aborted);
+}
+
+impl AbortSignalPrototype {
+    /// ### [get AbortSignal.prototype.aborted](https://dom.spec.whatwg.org/#dom-abortsignal-aborted)
+    fn get_aborted<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let signal = require_abort_signal(agent, this_value, gc)?;
+        // 1. Return true if this is aborted; otherwise false.
+        Ok(signal.is_aborted(agent).into())
+    }
+
+    /// ### [get AbortSignal.prototype.reason](https://dom.spec.whatwg.org/#dom-abortsignal-reason)
+    fn get_reason<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let signal = require_abort_signal(agent, this_value, gc)?;
+        // 1. Return this's abort reason.
+        Ok(signal.reason(agent))
+    }
+
+    /// ### [AbortSignal.prototype.throwIfAborted ( )](https://dom.spec.whatwg.org/#dom-abortsignal-throwifaborted)
+    fn throw_if_aborted<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let signal = require_abort_signal(agent, this_value, gc)?;
+        // 1. If this is aborted, then throw this's abort reason.
+        signal.throw_if_aborted(agent, gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [get AbortSignal.prototype.onabort](https://html.spec.whatwg.org/multipage/webappapis.html#event-handler-idl-attributes)
+    fn get_onabort<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let signal = require_abort_signal(agent, this_value, gc)?;
+        Ok(signal
+            .onabort(agent)
+            .map_or(Value::Null, |handler| handler.into()))
+    }
+
+    /// ### [set AbortSignal.prototype.onabort](https://html.spec.whatwg.org/multipage/webappapis.html#event-handler-idl-attributes)
+    fn set_onabort<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let handler = arguments.get(0).bind(gc);
+        let signal = require_abort_signal(agent, this_value, gc)?;
+        // NOTE: The value is converted to an EventHandler: values that are
+        // not callable objects are converted to null.
+        signal.set_onabort(agent, Function::try_from(handler).ok());
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.abort_signal_prototype();
+        let abort_signal_constructor = intrinsics.abort_signal();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(6)
+            .with_prototype(object_prototype)
+            .with_builtin_function_getter_property::<AbortSignalPrototypeGetAborted>()
+            .with_constructor_property(abort_signal_constructor)
+            .with_builtin_function_getter_setter_property::<AbortSignalPrototypeOnabort>()
+            .with_builtin_function_getter_property::<AbortSignalPrototypeGetReason>()
+            .with_builtin_function_property::<AbortSignalPrototypeThrowIfAborted>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.AbortSignal.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_abort_signal<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, AbortSignal<'a>> {
+    AbortSignal::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an AbortSignal",
+            gc,
+        )
+    })
+}
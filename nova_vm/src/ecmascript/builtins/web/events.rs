@@ -0,0 +1,25 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [2 Events](https://dom.spec.whatwg.org/#events)
+//!
+//! [`EventTarget`] and [`Event`]. There is no node tree: an event is only
+//! dispatched to its target, so dispatch has a single phase with the event's
+//! phase being `AT_TARGET`. The capture option of a listener only identifies
+//! it when adding and removing listeners; it does not affect the order in
+//! which listeners are called.
+
+mod event;
+mod event_constructor;
+mod event_prototype;
+mod event_target;
+mod event_target_constructor;
+mod event_target_prototype;
+
+pub use event::*;
+pub(crate) use event_constructor::*;
+pub(crate) use event_prototype::*;
+pub use event_target::*;
+pub(crate) use event_target_constructor::*;
+pub(crate) use event_target_prototype::*;
@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{Agent, EmbedderObject, String, Value, embedder_object_handle},
+    engine::NoGcScope,
+};
+
+/// ### [2.2 Interface Event](https://dom.spec.whatwg.org/#interface-event)
+///
+/// An Event signals that something has occurred. Events are dispatched to
+/// an [`EventTarget`] with [`EventTarget::dispatch_event`].
+///
+/// [`EventTarget`]: crate::ecmascript::EventTarget
+/// [`EventTarget::dispatch_event`]: crate::ecmascript::EventTarget::dispatch_event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Event<'a>(EmbedderObject<'a>);
+embedder_object_handle!(Event, EventRecord);
+
+/// ### [EventInit](https://dom.spec.whatwg.org/#dictdef-eventinit)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventInit {
+    /// Whether the event bubbles. Events are only dispatched to their target,
+    /// so this is only reflected by the `bubbles` attribute.
+    pub bubbles: bool,
+    /// Whether the event's default action can be prevented.
+    pub cancelable: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct EventRecord {
+    pub(super) bubbles: bool,
+    pub(super) cancelable: bool,
+    /// stop propagation flag
+    pub(super) stop_propagation: bool,
+    /// stop immediate propagation flag
+    pub(super) stop_immediate_propagation: bool,
+    /// canceled flag
+    pub(super) canceled: bool,
+    /// in passive listener flag
+    pub(super) in_passive_listener: bool,
+    /// dispatch flag
+    pub(super) dispatch: bool,
+    pub(super) is_trusted: bool,
+}
+
+impl EventRecord {
+    pub(super) fn new(init: EventInit, is_trusted: bool) -> Self {
+        Self {
+            bubbles: init.bubbles,
+            cancelable: init.cancelable,
+            is_trusted,
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Event<'a> {
+    /// The event is not being dispatched.
+    pub const NONE: u8 = 0;
+    /// The event is being dispatched to the ancestors of its target. Events
+    /// are only dispatched to their target, so this phase is never entered.
+    pub const CAPTURING_PHASE: u8 = 1;
+    /// The event is being dispatched to its target.
+    pub const AT_TARGET: u8 = 2;
+    /// The event is being dispatched to the ancestors of its target in
+    /// reverse order. Events are only dispatched to their target, so this
+    /// phase is never entered.
+    pub const BUBBLING_PHASE: u8 = 3;
+
+    /// type
+    const TYPE: usize = 0;
+    /// target
+    const TARGET: usize = 1;
+    /// currentTarget
+    const CURRENT_TARGET: usize = 2;
+    pub(crate) const SLOT_COUNT: usize = 3;
+
+    /// ### [create an event](https://dom.spec.whatwg.org/#concept-event-create)
+    ///
+    /// Create a new trusted Event in the current Realm. Events created by the
+    /// host are trusted: their `isTrusted` attribute is true until they are
+    /// dispatched by script.
+    pub fn new(
+        agent: &mut Agent,
+        event_type: String,
+        init: EventInit,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let prototype = agent.current_realm_record().intrinsics().event_prototype();
+        let event = Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            EventRecord::new(init, true),
+            gc,
+        ));
+        event.initialize(agent, event_type);
+        event
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    /// ### [initialize](https://dom.spec.whatwg.org/#concept-event-initialize)
+    pub(crate) fn initialize(self, agent: &mut Agent, event_type: String) {
+        self.0.set_slot(agent, Self::TYPE, event_type.into());
+        self.0.set_slot(agent, Self::TARGET, Value::Null);
+        self.0.set_slot(agent, Self::CURRENT_TARGET, Value::Null);
+    }
+
+    /// Returns the type of the event.
+    pub fn event_type(self, agent: &Agent) -> String<'a> {
+        String::try_from(self.0.get_slot(agent, Self::TYPE)).unwrap()
+    }
+
+    /// Returns the object the event was dispatched to, or null if the event
+    /// has not been dispatched.
+    pub fn target(self, agent: &Agent) -> Value<'a> {
+        self.0.get_slot(agent, Self::TARGET)
+    }
+
+    pub(crate) fn set_target(self, agent: &mut Agent, target: Value) {
+        self.0.set_slot(agent, Self::TARGET, target);
+    }
+
+    /// Returns the object whose listeners are being called, or null if the
+    /// event is not being dispatched.
+    pub fn current_target(self, agent: &Agent) -> Value<'a> {
+        self.0.get_slot(agent, Self::CURRENT_TARGET)
+    }
+
+    pub(crate) fn set_current_target(self, agent: &mut Agent, current_target: Value) {
+        self.0.set_slot(agent, Self::CURRENT_TARGET, current_target);
+    }
+
+    /// Returns the event's phase: [`Event::AT_TARGET`] while the event is
+    /// being dispatched and [`Event::NONE`] otherwise.
+    pub fn event_phase(self, agent: &Agent) -> u8 {
+        if self.is_dispatching(agent) {
+            Self::AT_TARGET
+        } else {
+            Self::NONE
+        }
+    }
+
+    /// Returns true if the event is being dispatched.
+    pub fn is_dispatching(self, agent: &Agent) -> bool {
+        self.data(agent).dispatch
+    }
+
+    /// Returns true if the event's default action was prevented.
+    pub fn default_prevented(self, agent: &Agent) -> bool {
+        self.data(agent).canceled
+    }
+
+    /// Returns true if the event was created by the host and has not been
+    /// dispatched by script.
+    pub fn is_trusted(self, agent: &Agent) -> bool {
+        self.data(agent).is_trusted
+    }
+
+    /// ### [set the canceled flag](https://dom.spec.whatwg.org/#set-the-canceled-flag)
+    pub(crate) fn set_canceled_flag(self, agent: &mut Agent) {
+        let data = self.data_mut(agent);
+        // If event's cancelable attribute value is true and event's in
+        // passive listener flag is unset, then set event's canceled flag.
+        if data.cancelable && !data.in_passive_listener {
+            data.canceled = true;
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, PropertyKey,
+        ProtoIntrinsics, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor, get, to_boolean, to_string,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{Event, EventInit, EventRecord};
+
+pub(crate) struct EventConstructor;
+impl Builtin for EventConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Event;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for EventConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Event;
+}
+
+impl EventConstructor {
+    /// ### [new Event(type, eventInitDict)](https://dom.spec.whatwg.org/#dom-event-event)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let event_type = arguments.get(0).bind(gc.nogc());
+        let event_init_dict = arguments.get(1).scope(agent, gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Event constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        if arguments.is_empty() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Event constructor requires a type argument",
+                gc.into_nogc(),
+            ));
+        }
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The arguments are converted to a DOMString and an EventInit
+        // dictionary before the constructor steps.
+        let event_type = to_string(agent, event_type.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let init = match event_init_dict.get(agent).bind(gc.nogc()) {
+            Value::Undefined | Value::Null => EventInit::default(),
+            event_init_dict => {
+                let Ok(event_init_dict) = Object::try_from(event_init_dict) else {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "eventInitDict is not an object",
+                        gc.into_nogc(),
+                    ));
+                };
+                let scoped_event_init_dict = event_init_dict.scope(agent, gc.nogc());
+                let bubbles = get_boolean_member(
+                    agent,
+                    event_init_dict.unbind(),
+                    BUILTIN_STRING_MEMORY.bubbles.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                let cancelable = get_boolean_member(
+                    agent,
+                    scoped_event_init_dict.get(agent),
+                    BUILTIN_STRING_MEMORY.cancelable.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                EventInit {
+                    bubbles,
+                    cancelable,
+                }
+            }
+        };
+        // 1. Let event be the result of running the inner event creation
+        //    steps with this interface, null, now, and eventInitDict.
+        let event = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::Event,
+            Event::SLOT_COUNT,
+            EventRecord::new(init, false),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let event = Event::from_embedder_object(event.bind(gc));
+        // 2. Initialize event's type attribute to type.
+        event.initialize(agent, event_type.get(agent));
+        // 3. Return event.
+        Ok(event.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let event_prototype = intrinsics.event_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<EventConstructor>(agent, realm)
+            .with_property_capacity(5)
+            .with_prototype_property(event_prototype.into())
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.NONE.into())
+                    .with_value_readonly(Value::from(Event::NONE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.CAPTURING_PHASE.into())
+                    .with_value_readonly(Value::from(Event::CAPTURING_PHASE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.AT_TARGET.into())
+                    .with_value_readonly(Value::from(Event::AT_TARGET))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.BUBBLING_PHASE.into())
+                    .with_value_readonly(Value::from(Event::BUBBLING_PHASE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Get a boolean member of a dictionary, defaulting to false.
+pub(super) fn get_boolean_member<'gc>(
+    agent: &mut Agent,
+    dictionary: Object,
+    key: PropertyKey,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, bool> {
+    let value = get(agent, dictionary, key, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    Ok(to_boolean(agent, value))
+}
@@ -0,0 +1,339 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::Event;
+
+pub(crate) struct EventPrototype;
+
+struct EventPrototypeGetType;
+impl Builtin for EventPrototypeGetType {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_type;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.r#type.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_type);
+}
+impl BuiltinGetter for EventPrototypeGetType {}
+struct EventPrototypeGetTarget;
+impl Builtin for EventPrototypeGetTarget {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_target;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.target.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_target);
+}
+impl BuiltinGetter for EventPrototypeGetTarget {}
+struct EventPrototypeGetCurrentTarget;
+impl Builtin for EventPrototypeGetCurrentTarget {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_currentTarget;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.currentTarget.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_current_target);
+}
+impl BuiltinGetter for EventPrototypeGetCurrentTarget {}
+struct EventPrototypeGetEventPhase;
+impl Builtin for EventPrototypeGetEventPhase {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eventPhase;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.eventPhase.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_event_phase);
+}
+impl BuiltinGetter for EventPrototypeGetEventPhase {}
+struct EventPrototypeGetBubbles;
+impl Builtin for EventPrototypeGetBubbles {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_bubbles;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.bubbles.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_bubbles);
+}
+impl BuiltinGetter for EventPrototypeGetBubbles {}
+struct EventPrototypeGetCancelable;
+impl Builtin for EventPrototypeGetCancelable {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_cancelable;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.cancelable.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_cancelable);
+}
+impl BuiltinGetter for EventPrototypeGetCancelable {}
+struct EventPrototypeGetDefaultPrevented;
+impl Builtin for EventPrototypeGetDefaultPrevented {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_defaultPrevented;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.defaultPrevented.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_default_prevented);
+}
+impl BuiltinGetter for EventPrototypeGetDefaultPrevented {}
+struct EventPrototypeGetIsTrusted;
+impl Builtin for EventPrototypeGetIsTrusted {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_isTrusted;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.isTrusted.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::get_is_trusted);
+}
+impl BuiltinGetter for EventPrototypeGetIsTrusted {}
+struct EventPrototypePreventDefault;
+impl Builtin for EventPrototypePreventDefault {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.preventDefault;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::prevent_default);
+}
+struct EventPrototypeStopImmediatePropagation;
+impl Builtin for EventPrototypeStopImmediatePropagation {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.stopImmediatePropagation;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::stop_immediate_propagation);
+}
+struct EventPrototypeStopPropagation;
+impl Builtin for EventPrototypeStopPropagation {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.stopPropagation;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventPrototype::stop_propagation);
+}
+
+impl EventPrototype {
+    /// ### [get Event.prototype.type](https://dom.spec.whatwg.org/#dom-event-type)
+    fn get_type<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The type attribute must return the value it was initialized to.
+        Ok(event.event_type(agent).into())
+    }
+
+    /// ### [get Event.prototype.target](https://dom.spec.whatwg.org/#dom-event-target)
+    fn get_target<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The target getter steps are to return this's target.
+        Ok(event.target(agent))
+    }
+
+    /// ### [get Event.prototype.currentTarget](https://dom.spec.whatwg.org/#dom-event-currenttarget)
+    fn get_current_target<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The currentTarget attribute must return the value it was
+        // initialized to.
+        Ok(event.current_target(agent))
+    }
+
+    /// ### [get Event.prototype.eventPhase](https://dom.spec.whatwg.org/#dom-event-eventphase)
+    fn get_event_phase<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The eventPhase attribute must return the value it was initialized
+        // to.
+        Ok(event.event_phase(agent).into())
+    }
+
+    /// ### [get Event.prototype.bubbles](https://dom.spec.whatwg.org/#dom-event-bubbles)
+    fn get_bubbles<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        Ok(event.data(agent).bubbles.into())
+    }
+
+    /// ### [get Event.prototype.cancelable](https://dom.spec.whatwg.org/#dom-event-cancelable)
+    fn get_cancelable<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        Ok(event.data(agent).cancelable.into())
+    }
+
+    /// ### [get Event.prototype.defaultPrevented](https://dom.spec.whatwg.org/#dom-event-defaultprevented)
+    fn get_default_prevented<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The defaultPrevented getter steps are to return true if this's
+        // canceled flag is set; otherwise false.
+        Ok(event.default_prevented(agent).into())
+    }
+
+    /// ### [get Event.prototype.isTrusted](https://dom.spec.whatwg.org/#dom-event-istrusted)
+    ///
+    /// NOTE: isTrusted is an accessor on Event.prototype instead of an
+    /// unforgeable accessor on each Event.
+    fn get_is_trusted<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        Ok(event.is_trusted(agent).into())
+    }
+
+    /// ### [Event.prototype.preventDefault ( )](https://dom.spec.whatwg.org/#dom-event-preventdefault)
+    fn prevent_default<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The preventDefault() method steps are to set the canceled flag with
+        // this.
+        event.set_canceled_flag(agent);
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Event.prototype.stopImmediatePropagation ( )](https://dom.spec.whatwg.org/#dom-event-stopimmediatepropagation)
+    fn stop_immediate_propagation<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The stopImmediatePropagation() method steps are to set this's stop
+        // propagation flag and this's stop immediate propagation flag.
+        let data = event.data_mut(agent);
+        data.stop_propagation = true;
+        data.stop_immediate_propagation = true;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Event.prototype.stopPropagation ( )](https://dom.spec.whatwg.org/#dom-event-stoppropagation)
+    fn stop_propagation<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let event = require_event(agent, this_value, gc)?;
+        // The stopPropagation() method steps are to set this's stop
+        // propagation flag.
+        event.data_mut(agent).stop_propagation = true;
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.event_prototype();
+        let event_constructor = intrinsics.event();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(17)
+            .with_prototype(object_prototype)
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.NONE.into())
+                    .with_value_readonly(Value::from(Event::NONE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.CAPTURING_PHASE.into())
+                    .with_value_readonly(Value::from(Event::CAPTURING_PHASE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.AT_TARGET.into())
+                    .with_value_readonly(Value::from(Event::AT_TARGET))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.BUBBLING_PHASE.into())
+                    .with_value_readonly(Value::from(Event::BUBBLING_PHASE))
+                    .with_enumerable(true)
+                    .with_configurable(false)
+                    .build()
+            })
+            .with_builtin_function_getter_property::<EventPrototypeGetBubbles>()
+            .with_builtin_function_getter_property::<EventPrototypeGetCancelable>()
+            .with_constructor_property(event_constructor)
+            .with_builtin_function_getter_property::<EventPrototypeGetCurrentTarget>()
+            .with_builtin_function_getter_property::<EventPrototypeGetDefaultPrevented>()
+            .with_builtin_function_getter_property::<EventPrototypeGetEventPhase>()
+            .with_builtin_function_getter_property::<EventPrototypeGetIsTrusted>()
+            .with_builtin_function_property::<EventPrototypePreventDefault>()
+            .with_builtin_function_property::<EventPrototypeStopImmediatePropagation>()
+            .with_builtin_function_property::<EventPrototypeStopPropagation>()
+            .with_builtin_function_getter_property::<EventPrototypeGetTarget>()
+            .with_builtin_function_getter_property::<EventPrototypeGetType>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Event.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_event<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Event<'a>> {
+    Event::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an Event",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,414 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, EmbedderObject, Event, ExceptionType,
+        JsError, JsResult, Object, String, Value, call_function, create_dom_exception,
+        embedder_object_handle, get, is_callable,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+};
+
+/// ### [2.7 Interface EventTarget](https://dom.spec.whatwg.org/#interface-eventtarget)
+///
+/// An EventTarget is an object to which an [`Event`] can be dispatched.
+///
+/// Embedders deliver events to scripts by creating an Event with
+/// [`Event::new`] and dispatching it with [`EventTarget::dispatch_event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct EventTarget<'a>(EmbedderObject<'a>);
+embedder_object_handle!(EventTarget, EventTargetRecord);
+
+#[derive(Debug)]
+pub(crate) struct EventTargetRecord;
+
+/// ### [AddEventListenerOptions](https://dom.spec.whatwg.org/#dictdef-addeventlisteneroptions)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AddEventListenerOptions {
+    /// Identifies the listener together with its type and callback.
+    pub capture: bool,
+    /// Remove the listener after it has been called once.
+    pub once: bool,
+    /// Ignore calls to `preventDefault` made by the listener.
+    pub passive: bool,
+}
+
+/// An event listener entry is an Array holding the listener's type, callback,
+/// capture, once, passive, and removed values.
+mod listener {
+    /// type
+    pub(super) const TYPE: usize = 0;
+    /// callback
+    pub(super) const CALLBACK: usize = 1;
+    /// capture
+    pub(super) const CAPTURE: usize = 2;
+    /// once
+    pub(super) const ONCE: usize = 3;
+    /// passive
+    pub(super) const PASSIVE: usize = 4;
+    /// removed
+    pub(super) const REMOVED: usize = 5;
+    pub(super) const LENGTH: usize = 6;
+}
+
+impl<'a> EventTarget<'a> {
+    /// event listener list
+    const EVENT_LISTENERS: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    /// ### [new EventTarget()](https://dom.spec.whatwg.org/#dom-eventtarget-eventtarget)
+    ///
+    /// Create a new EventTarget in the current Realm.
+    pub fn new(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Self {
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .event_target_prototype();
+        Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            EventTargetRecord,
+            gc,
+        ))
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    /// Returns the target's event listener list, if any listeners have been
+    /// added.
+    fn event_listeners(self, agent: &Agent) -> Option<Array<'a>> {
+        match self.0.get_slot(agent, Self::EVENT_LISTENERS) {
+            Value::Array(listeners) => Some(listeners),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the listener with the given type, callback, and
+    /// capture in the target's event listener list.
+    fn find_event_listener(
+        self,
+        agent: &Agent,
+        event_type: String,
+        callback: Object,
+        capture: bool,
+    ) -> Option<usize> {
+        let listeners = self.event_listeners(agent)?;
+        listeners.as_slice(agent).iter().position(|entry| {
+            let Some(Value::Array(entry)) = entry else {
+                unreachable!()
+            };
+            let entry = entry.as_slice(agent);
+            String::eq(
+                agent,
+                String::try_from(entry[listener::TYPE].unwrap()).unwrap(),
+                event_type,
+            ) && entry[listener::CALLBACK] == Some(callback.unbind().into())
+                && entry[listener::CAPTURE] == Some(capture.into())
+        })
+    }
+
+    /// ### [add an event listener](https://dom.spec.whatwg.org/#add-an-event-listener)
+    ///
+    /// Add a listener for events of the given type. The callback is either a
+    /// function or an object with a `handleEvent` method. Nothing is done if
+    /// an equal listener has already been added.
+    pub fn add_event_listener<'gc>(
+        self,
+        agent: &mut Agent,
+        event_type: String,
+        callback: Object,
+        options: AddEventListenerOptions,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        // 1.-3. NOTE: Service workers and abort signals are not supported,
+        //    and a null callback is handled by the caller.
+        // 4. If eventTarget's event listener list does not contain an event
+        //    listener whose type is listener's type, callback is listener's
+        //    callback, and capture is listener's capture, then append
+        //    listener to eventTarget's event listener list.
+        if self
+            .find_event_listener(agent, event_type, callback, options.capture)
+            .is_some()
+        {
+            return Ok(());
+        }
+        let entry = Array::from_slice(
+            agent,
+            &[
+                event_type.into(),
+                callback.into(),
+                options.capture.into(),
+                options.once.into(),
+                options.passive.into(),
+                false.into(),
+            ],
+            gc,
+        );
+        debug_assert_eq!(entry.len(agent) as usize, listener::LENGTH);
+        let listeners = match self.event_listeners(agent) {
+            Some(listeners) => listeners,
+            None => {
+                let listeners = Array::new(agent, gc);
+                self.0
+                    .set_slot(agent, Self::EVENT_LISTENERS, listeners.into());
+                listeners
+            }
+        };
+        listeners
+            .push(agent, entry.unbind().into())
+            .map_err(|err| agent.throw_allocation_exception(err, gc))
+    }
+
+    /// ### [remove an event listener](https://dom.spec.whatwg.org/#remove-an-event-listener)
+    ///
+    /// Remove the listener with the given type, callback, and capture.
+    /// Listeners that are removed while an event is being dispatched are not
+    /// called for that event.
+    pub fn remove_event_listener(
+        self,
+        agent: &mut Agent,
+        event_type: String,
+        callback: Object,
+        capture: bool,
+        gc: NoGcScope,
+    ) {
+        let Some(index) = self.find_event_listener(agent, event_type, callback, capture) else {
+            return;
+        };
+        self.remove_event_listener_at(agent, index, gc);
+    }
+
+    /// Remove the listener at the given index of the target's event listener
+    /// list.
+    fn remove_event_listener_at(self, agent: &mut Agent, index: usize, gc: NoGcScope) {
+        let listeners = self.event_listeners(agent).unwrap();
+        // 2. Set listener's removed to true and remove listener from
+        //    eventTarget's event listener list.
+        let mut entries = listeners
+            .as_slice(agent)
+            .iter()
+            .map(|entry| entry.unwrap())
+            .collect::<Vec<_>>();
+        let Value::Array(entry) = entries.remove(index) else {
+            unreachable!()
+        };
+        entry.as_mut_slice(agent)[listener::REMOVED] = Some(true.into());
+        let listeners = Array::from_slice(agent, &entries, gc);
+        self.0
+            .set_slot(agent, Self::EVENT_LISTENERS, listeners.into());
+    }
+
+    /// ### [dispatch](https://dom.spec.whatwg.org/#concept-event-dispatch)
+    ///
+    /// Dispatch an event to the target, calling the target's listeners for
+    /// the event's type in the order they were added. Exceptions thrown by
+    /// listeners are reported to the host and do not stop the dispatch.
+    ///
+    /// Returns false if a listener prevented the event's default action, and
+    /// true otherwise. An "InvalidStateError" is thrown if the event is
+    /// already being dispatched.
+    pub fn dispatch_event<'gc>(
+        self,
+        agent: &mut Agent,
+        event: Event,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        let target = self.bind(gc.nogc());
+        let event = event.bind(gc.nogc());
+        if event.is_dispatching(agent) {
+            let gc = gc.into_nogc();
+            return Err(JsError::new(create_dom_exception(
+                agent,
+                "The event is already being dispatched",
+                BUILTIN_STRING_MEMORY.InvalidStateError,
+                gc,
+            )));
+        }
+        // 1. Set event's dispatch flag.
+        event.data_mut(agent).dispatch = true;
+        // 2.-4. NOTE: There is no node tree, so the event's target is the
+        //    target itself and the event path only contains the target.
+        event.set_target(agent, target.into());
+        event.set_current_target(agent, target.into());
+        // 5. If event's stop propagation flag is unset, invoke the target's
+        //    event listeners.
+        let scoped_event = event.scope(agent, gc.nogc());
+        if !event.data(agent).stop_propagation
+            && let Some(listeners) = target.event_listeners(agent)
+        {
+            invoke_event_listeners(
+                agent,
+                target.unbind(),
+                listeners.unbind(),
+                event.unbind(),
+                gc.reborrow(),
+            );
+        }
+        let event = scoped_event.get(agent).bind(gc.nogc());
+        // 6. Set event's eventPhase attribute to NONE.
+        // 7. Set event's currentTarget attribute to null.
+        event.set_current_target(agent, Value::Null);
+        // 9. Unset event's dispatch flag, stop propagation flag, and stop
+        //    immediate propagation flag.
+        let data = event.data_mut(agent);
+        data.dispatch = false;
+        data.stop_propagation = false;
+        data.stop_immediate_propagation = false;
+        // 12. If event's canceled flag is set, then return false; otherwise
+        //     return true.
+        Ok(!data.canceled)
+    }
+}
+
+/// ### [inner invoke](https://dom.spec.whatwg.org/#concept-event-listener-inner-invoke)
+fn invoke_event_listeners(
+    agent: &mut Agent,
+    target: EventTarget,
+    listeners: Array,
+    event: Event,
+    mut gc: GcScope,
+) {
+    let target = target.bind(gc.nogc());
+    let event = event.bind(gc.nogc());
+    // NOTE: Listeners added during the dispatch are not called, so the
+    // target's event listener list is cloned.
+    let listeners = listeners
+        .as_slice(agent)
+        .iter()
+        .map(|entry| {
+            let Some(Value::Array(entry)) = entry else {
+                unreachable!()
+            };
+            *entry
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|entry| entry.scope(agent, gc.nogc()))
+        .collect::<Vec<_>>();
+    let event_type = event.event_type(agent).scope(agent, gc.nogc());
+    let scoped_target = target.scope(agent, gc.nogc());
+    let scoped_event = event.scope(agent, gc.nogc());
+    // 2. For each listener of listeners:
+    for entry in listeners {
+        let entry = entry.get(agent).bind(gc.nogc());
+        let slice = entry.as_slice(agent);
+        let entry_type = slice[listener::TYPE].unwrap();
+        let callback = slice[listener::CALLBACK].unwrap();
+        let once = slice[listener::ONCE] == Some(true.into());
+        let passive = slice[listener::PASSIVE] == Some(true.into());
+        let removed = slice[listener::REMOVED] == Some(true.into());
+        // 1. If event's type attribute value is not listener's type, then
+        //    continue.
+        // 2. If listener's removed is true, then continue.
+        if removed
+            || !String::eq(
+                agent,
+                String::try_from(entry_type).unwrap(),
+                event_type.get(agent),
+            )
+        {
+            continue;
+        }
+        let target = scoped_target.get(agent).bind(gc.nogc());
+        let event = scoped_event.get(agent).bind(gc.nogc());
+        // 5. If listener's once is true, then remove an event listener given
+        //    event's currentTarget and listener.
+        if once
+            && let Some(index) = target.event_listeners(agent).and_then(|listeners| {
+                listeners
+                    .as_slice(agent)
+                    .iter()
+                    .position(|other| *other == Some(entry.into()))
+            })
+        {
+            target.remove_event_listener_at(agent, index, gc.nogc());
+        }
+        // 8. If listener's passive is true, then set event's in passive
+        //    listener flag.
+        event.data_mut(agent).in_passive_listener = passive;
+        // 10. Call a user object's operation with listener's callback,
+        //     "handleEvent", « event », and event's currentTarget attribute
+        //     value. If this throws an exception exceptionObject, report
+        //     exceptionObject.
+        let callback = Object::try_from(callback).unwrap();
+        if let Err(err) = call_event_listener(
+            agent,
+            callback.unbind(),
+            target.unbind(),
+            event.unbind(),
+            gc.reborrow(),
+        ) {
+            let host_hooks = agent.host_hooks;
+            host_hooks.report_job_error(agent, err.unbind(), gc.nogc());
+        }
+        let event = scoped_event.get(agent).bind(gc.nogc());
+        // 11. Unset event's in passive listener flag.
+        event.data_mut(agent).in_passive_listener = false;
+        // 13. If event's stop immediate propagation flag is set, then break.
+        if event.data(agent).stop_immediate_propagation {
+            break;
+        }
+    }
+}
+
+/// ### [call a user object's operation](https://webidl.spec.whatwg.org/#call-a-user-objects-operation)
+///
+/// Call an event listener's callback with the event. A callback that is not
+/// callable is an object whose `handleEvent` method is called instead.
+fn call_event_listener<'gc>(
+    agent: &mut Agent,
+    callback: Object,
+    target: EventTarget,
+    event: Event,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let callback = callback.bind(gc.nogc());
+    let mut event = Value::from(event.bind(gc.nogc()));
+    // 7. Let X be O.
+    // 8. If ! IsCallable(X) is false, then:
+    let (function, this_value) = if let Some(function) = is_callable(callback, gc.nogc()) {
+        // 9. If thisArg was not given, let thisArg be undefined.
+        (function, Value::from(target.bind(gc.nogc())))
+    } else {
+        let scoped_event = event.scope(agent, gc.nogc());
+        let scoped_callback = callback.scope(agent, gc.nogc());
+        // a. Let getResult be Completion(Get(O, opName)).
+        let handle_event = get(
+            agent,
+            callback.unbind(),
+            BUILTIN_STRING_MEMORY.handleEvent.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // c. Set X to getResult.[[Value]].
+        // d. If ! IsCallable(X) is false, then set completion to a new
+        //    Completion{[[Type]]: throw, [[Value]]: a newly created
+        //    TypeError object, [[Target]]: empty}.
+        let Some(handle_event) = is_callable(handle_event, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "EventListener handleEvent is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        event = scoped_event.get(agent).bind(gc.nogc());
+        // e. Set thisArg to O (overriding the provided value).
+        (handle_event, Value::from(scoped_callback.get(agent)))
+    };
+    // 12. Let callResult be Completion(Call(X, thisArg, jsArgs)).
+    call_function(
+        agent,
+        function.unbind(),
+        this_value.unbind(),
+        Some(ArgumentsList::from_mut_value(&mut event.unbind())),
+        gc,
+    )?;
+    Ok(())
+}
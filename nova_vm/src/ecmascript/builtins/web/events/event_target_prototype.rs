@@ -0,0 +1,262 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType, JsResult,
+        Object, Realm, String, Value, builders::OrdinaryObjectBuilder, to_boolean, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped},
+    heap::WellKnownSymbols,
+};
+
+use super::{AddEventListenerOptions, Event, EventTarget, get_boolean_member};
+
+pub(crate) struct EventTargetPrototype;
+
+struct EventTargetPrototypeAddEventListener;
+impl Builtin for EventTargetPrototypeAddEventListener {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.addEventListener;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventTargetPrototype::add_event_listener);
+}
+struct EventTargetPrototypeDispatchEvent;
+impl Builtin for EventTargetPrototypeDispatchEvent {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.dispatchEvent;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventTargetPrototype::dispatch_event);
+}
+struct EventTargetPrototypeRemoveEventListener;
+impl Builtin for EventTargetPrototypeRemoveEventListener {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.removeEventListener;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(EventTargetPrototype::remove_event_listener);
+}
+
+impl EventTargetPrototype {
+    /// ### [EventTarget.prototype.addEventListener ( type, callback \[ , options \] )](https://dom.spec.whatwg.org/#dom-eventtarget-addeventlistener)
+    fn add_event_listener<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let event_type = arguments.get(0).bind(gc.nogc());
+        let callback = arguments.get(1).scope(agent, gc.nogc());
+        let options = arguments.get(2).scope(agent, gc.nogc());
+        let target = require_event_target(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let (event_type, callback) =
+            convert_event_listener_arguments(agent, event_type.unbind(), callback, gc.reborrow())
+                .unbind()?;
+        let event_type = event_type.scope(agent, gc.nogc());
+        let callback = callback.map(|callback| callback.scope(agent, gc.nogc()));
+        // NOTE: The options argument is converted to an
+        // AddEventListenerOptions dictionary or a boolean.
+        let options = match options.get(agent).bind(gc.nogc()) {
+            Value::Undefined | Value::Null => AddEventListenerOptions::default(),
+            options => match Object::try_from(options) {
+                Ok(options) => {
+                    let scoped_options = options.scope(agent, gc.nogc());
+                    let capture = get_boolean_member(
+                        agent,
+                        options.unbind(),
+                        BUILTIN_STRING_MEMORY.capture.into(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    let once = get_boolean_member(
+                        agent,
+                        scoped_options.get(agent),
+                        BUILTIN_STRING_MEMORY.once.into(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    let passive = get_boolean_member(
+                        agent,
+                        scoped_options.get(agent),
+                        BUILTIN_STRING_MEMORY.passive.into(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    AddEventListenerOptions {
+                        capture,
+                        once,
+                        passive,
+                    }
+                }
+                Err(_) => AddEventListenerOptions {
+                    capture: to_boolean(agent, options),
+                    ..Default::default()
+                },
+            },
+        };
+        let gc = gc.into_nogc();
+        // 1. Let capture, passive, once, and signal be the result of
+        //    flattening more options.
+        // NOTE: Adding an event listener returns early if the callback is
+        // null.
+        let Some(callback) = callback else {
+            return Ok(Value::Undefined);
+        };
+        // 2. Add an event listener with this and an event listener whose type
+        //    is type, callback is callback, capture is capture, passive is
+        //    passive, and once is once.
+        target.get(agent).bind(gc).add_event_listener(
+            agent,
+            event_type.get(agent).bind(gc),
+            callback.get(agent).bind(gc),
+            options,
+            gc,
+        )?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [EventTarget.prototype.removeEventListener ( type, callback \[ , options \] )](https://dom.spec.whatwg.org/#dom-eventtarget-removeeventlistener)
+    fn remove_event_listener<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let event_type = arguments.get(0).bind(gc.nogc());
+        let callback = arguments.get(1).scope(agent, gc.nogc());
+        let options = arguments.get(2).scope(agent, gc.nogc());
+        let target = require_event_target(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let (event_type, callback) =
+            convert_event_listener_arguments(agent, event_type.unbind(), callback, gc.reborrow())
+                .unbind()?;
+        let event_type = event_type.scope(agent, gc.nogc());
+        let callback = callback.map(|callback| callback.scope(agent, gc.nogc()));
+        // NOTE: The options argument is converted to an EventListenerOptions
+        // dictionary or a boolean.
+        let capture = match options.get(agent).bind(gc.nogc()) {
+            Value::Undefined | Value::Null => false,
+            options => match Object::try_from(options) {
+                Ok(options) => get_boolean_member(
+                    agent,
+                    options.unbind(),
+                    BUILTIN_STRING_MEMORY.capture.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?,
+                Err(_) => to_boolean(agent, options),
+            },
+        };
+        let gc = gc.into_nogc();
+        let Some(callback) = callback else {
+            return Ok(Value::Undefined);
+        };
+        // 1. Let capture be the result of flattening options.
+        // 2. If this's event listener list contains an event listener whose
+        //    type is type, callback is callback, and capture is capture, then
+        //    remove an event listener with this and that event listener.
+        target.get(agent).bind(gc).remove_event_listener(
+            agent,
+            event_type.get(agent).bind(gc),
+            callback.get(agent).bind(gc),
+            capture,
+            gc,
+        );
+        Ok(Value::Undefined)
+    }
+
+    /// ### [EventTarget.prototype.dispatchEvent ( event )](https://dom.spec.whatwg.org/#dom-eventtarget-dispatchevent)
+    fn dispatch_event<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let event = arguments.get(0).bind(gc.nogc());
+        let target = require_event_target(agent, this_value, gc.nogc()).unbind()?;
+        let Some(event) = Event::try_from_value(agent, event) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "event is not an Event",
+                gc.into_nogc(),
+            ));
+        };
+        // 1. If event's dispatch flag is set, or if its initialized flag is
+        //    not set, then throw an "InvalidStateError" DOMException.
+        // NOTE: This is checked by dispatch before isTrusted is changed.
+        if !event.is_dispatching(agent) {
+            // 2. Initialize event's isTrusted attribute to false.
+            event.data_mut(agent).is_trusted = false;
+        }
+        // 3. Return the result of dispatching event to this.
+        let result = target.dispatch_event(agent, event.unbind(), gc)?;
+        Ok(result.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.event_target_prototype();
+        let event_target_constructor = intrinsics.event_target();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(5)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<EventTargetPrototypeAddEventListener>()
+            .with_constructor_property(event_target_constructor)
+            .with_builtin_function_property::<EventTargetPrototypeDispatchEvent>()
+            .with_builtin_function_property::<EventTargetPrototypeRemoveEventListener>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.EventTarget.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_event_target<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, EventTarget<'a>> {
+    EventTarget::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an EventTarget",
+            gc,
+        )
+    })
+}
+
+/// Convert the type and callback arguments of addEventListener and
+/// removeEventListener to a DOMString and a nullable EventListener.
+fn convert_event_listener_arguments<'gc>(
+    agent: &mut Agent,
+    event_type: Value,
+    callback: Scoped<Value>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (String<'gc>, Option<Object<'gc>>)> {
+    let event_type = to_string(agent, event_type, gc.reborrow())
+        .unbind()?
+        .unbind();
+    let gc = gc.into_nogc();
+    let event_type = event_type.bind(gc);
+    let callback = match callback.get(agent).bind(gc) {
+        Value::Undefined | Value::Null => None,
+        callback => match Object::try_from(callback) {
+            Ok(callback) => Some(callback),
+            Err(_) => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "callback is not an object",
+                    gc,
+                ));
+            }
+        },
+    };
+    Ok((event_type, callback))
+}
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Fetch Standard](https://fetch.spec.whatwg.org/)
+//!
+//! `fetch()`, [`Request`], [`Response`] and [`Headers`]. The network IO of
+//! `fetch()` is performed by the embedder: the request is handed to
+//! [`HostHooks::fetch`] and the host settles the returned promise with
+//! [`Agent::finish_fetch`] once the response has arrived.
+//!
+//! Bodies are byte sequences that are read in one go: reading a body with
+//! `text()`, `json()` or `arrayBuffer()` returns an already settled promise,
+//! and bodies cannot be read as streams. Bodies are created from strings or,
+//! with the `web-blob` feature, Blobs. URLs are passed to the host as
+//! written without being parsed or resolved, and requests cannot be aborted.
+//!
+//! [`HostHooks::fetch`]: crate::ecmascript::HostHooks::fetch
+//! [`Agent::finish_fetch`]: crate::ecmascript::Agent::finish_fetch
+
+mod body;
+mod fetching;
+mod headers;
+mod headers_constructor;
+mod headers_prototype;
+mod request;
+mod request_constructor;
+mod request_prototype;
+mod response;
+mod response_constructor;
+mod response_prototype;
+
+use body::Body;
+
+pub use fetching::*;
+pub use headers::*;
+pub(crate) use headers_constructor::*;
+pub(crate) use headers_prototype::*;
+pub use request::*;
+pub(crate) use request_constructor::*;
+pub(crate) use request_prototype::*;
+pub use response::*;
+pub(crate) use response_constructor::*;
+pub(crate) use response_prototype::*;
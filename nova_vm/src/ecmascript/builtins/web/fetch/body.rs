@@ -0,0 +1,169 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "web-blob")]
+use crate::ecmascript::Blob;
+use crate::{
+    ecmascript::{
+        Agent, ArrayBuffer, ExceptionType, JsResult, Promise, String, Value, parse_json, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+/// ### [body](https://fetch.spec.whatwg.org/#concept-body)
+///
+/// The body of a [`Request`] or [`Response`]. The bytes of the body are
+/// moved out of it when it is read.
+///
+/// [`Request`]: super::Request
+/// [`Response`]: super::Response
+#[derive(Debug, Default)]
+pub(crate) struct Body {
+    /// The bytes of the body, or None if the body is null.
+    pub(super) bytes: Option<Vec<u8>>,
+    /// True if the body has been read.
+    pub(super) disturbed: bool,
+}
+
+impl Body {
+    pub(super) fn new(bytes: Option<Vec<u8>>) -> Self {
+        Self {
+            bytes,
+            disturbed: false,
+        }
+    }
+
+    /// ### [body used](https://fetch.spec.whatwg.org/#dom-body-bodyused)
+    pub(super) fn is_used(&self) -> bool {
+        // The bodyUsed getter steps are to return true if this's body is
+        // non-null and this's body's stream is disturbed; otherwise false.
+        self.bytes.is_some() && self.disturbed
+    }
+
+    /// Read the body, returning its bytes. Returns None if the body is
+    /// [unusable](https://fetch.spec.whatwg.org/#body-unusable).
+    ///
+    /// A null body is read as an empty byte sequence.
+    pub(super) fn read(&mut self) -> Option<Vec<u8>> {
+        if self.is_used() {
+            return None;
+        }
+        self.disturbed = true;
+        Some(self.bytes.as_mut().map(core::mem::take).unwrap_or_default())
+    }
+}
+
+/// The type of the value a body is read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BodyType {
+    ArrayBuffer,
+    Json,
+    Text,
+}
+
+/// ### [consume body](https://fetch.spec.whatwg.org/#concept-body-consume-body)
+///
+/// Returns a promise settled with the bytes read from a body as the given
+/// type. The bytes are None if the body was unusable.
+pub(super) fn consume_body<'gc>(
+    agent: &mut Agent,
+    bytes: Option<Vec<u8>>,
+    body_type: BodyType,
+    gc: NoGcScope<'gc, '_>,
+) -> Value<'gc> {
+    // 1. If object is unusable, then return a promise rejected with a
+    //    TypeError.
+    let Some(bytes) = bytes else {
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Body has already been consumed",
+            gc,
+        );
+        return Promise::new_rejected(agent, error.value(), gc).into();
+    };
+    // 2. Let promise be a new promise.
+    // 3. Let errorSteps given error be to reject promise with error.
+    // 4. Let successSteps given a byte sequence data be to resolve promise
+    //    with the result of running convertBytesToJSValue with data. If that
+    //    threw an exception, then run errorSteps with that exception.
+    // 5. If object's body is null, then run successSteps with an empty byte
+    //    sequence.
+    // 6. Otherwise, fully read object's body given successSteps, errorSteps,
+    //    and object's relevant global object.
+    // 7. Return promise.
+    // NOTE: The body has already been read.
+    match package_data(agent, bytes, body_type, gc) {
+        Ok(value) => Promise::new_resolved(agent, value).into(),
+        Err(err) => Promise::new_rejected(agent, err.value(), gc).into(),
+    }
+}
+
+/// Convert the bytes read from a body to a value of the given type.
+fn package_data<'gc>(
+    agent: &mut Agent,
+    bytes: Vec<u8>,
+    body_type: BodyType,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    match body_type {
+        BodyType::ArrayBuffer => {
+            let buffer = ArrayBuffer::new(agent, bytes.len(), gc)?;
+            buffer.as_mut_slice(agent).copy_from_slice(&bytes);
+            Ok(buffer.into())
+        }
+        BodyType::Json => {
+            let text = utf8_decode(agent, &bytes, gc);
+            parse_json(agent, text, gc)
+        }
+        BodyType::Text => Ok(utf8_decode(agent, &bytes, gc).into()),
+    }
+}
+
+/// ### [UTF-8 decode](https://encoding.spec.whatwg.org/#utf-8-decode)
+fn utf8_decode<'gc>(agent: &mut Agent, bytes: &[u8], gc: NoGcScope<'gc, '_>) -> String<'gc> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let text = std::string::String::from_utf8_lossy(bytes).into_owned();
+    String::from_string(agent, text, gc)
+}
+
+/// ### [extract](https://fetch.spec.whatwg.org/#concept-bodyinit-extract)
+///
+/// Returns the bytes of the body and its content type. Values other than
+/// Blobs are converted to strings.
+pub(super) fn extract_body<'gc>(
+    agent: &mut Agent,
+    object: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (Vec<u8>, Option<std::string::String>)> {
+    let object = object.bind(gc.nogc());
+    // 1. Let stream be null.
+    // ...
+    // 6. Switch on object:
+    // ↪ Blob
+    #[cfg(feature = "web-blob")]
+    if let Some(blob) = Blob::try_from_value(agent, object) {
+        // Set source to object.
+        // Set length to object's size.
+        // If object's type attribute is not the empty string, set type to
+        // its value.
+        let content_type = blob.content_type(agent);
+        let content_type = (!content_type.is_empty_string())
+            .then(|| content_type.to_string_lossy(agent).into_owned());
+        return Ok((blob.to_vec(agent), content_type));
+    }
+    // ↪ scalar value string
+    // Set source to the UTF-8 encoding of object.
+    // Set type to `text/plain;charset=UTF-8`.
+    let text = to_string(agent, object.unbind(), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let text = text.bind(gc).to_string_lossy(agent).into_owned();
+    // 7. If source is a byte sequence, then set action to a step that
+    //    returns source and length to source's length.
+    // ...
+    // 12. Return (body, type).
+    Ok((
+        text.into_bytes(),
+        Some("text/plain;charset=UTF-8".to_owned()),
+    ))
+}
@@ -0,0 +1,171 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic,
+        ExceptionType, JsResult, Promise, PromiseCapability, Realm, String, Value,
+        builders::BuiltinFunctionBuilder,
+    },
+    engine::{Bindable, GcScope, Global, Scopable},
+    heap::IntrinsicFunctionIndexes,
+};
+
+use super::{Response, construct_request};
+
+/// An HTTP request made by a `fetch()` call, passed to
+/// [`HostHooks::fetch`].
+///
+/// [`HostHooks::fetch`]: crate::ecmascript::HostHooks::fetch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchRequest {
+    /// The URL of the request as given to `fetch()`.
+    pub url: std::string::String,
+    /// The normalized method of the request.
+    pub method: std::string::String,
+    /// The header names and values of the request in order. Header names are
+    /// in lowercase.
+    pub headers: Vec<(std::string::String, std::string::String)>,
+    /// The body of the request, or None if the request has no body.
+    pub body: Option<Vec<u8>>,
+}
+
+/// An HTTP response given by the host to [`Agent::finish_fetch`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FetchResponse {
+    /// The status code of the response.
+    pub status: u16,
+    /// The status message of the response.
+    pub status_text: std::string::String,
+    /// The header names and values of the response in order.
+    pub headers: Vec<(std::string::String, std::string::String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+/// A `fetch()` call waiting for its response from the host.
+///
+/// See [`HostHooks::fetch`].
+///
+/// [`HostHooks::fetch`]: crate::ecmascript::HostHooks::fetch
+#[derive(Debug)]
+#[must_use = "a pending fetch must be finished"]
+pub struct PendingFetch {
+    /// The promise returned by the `fetch()` call.
+    promise: Global<Promise<'static>>,
+    /// The URL of the request.
+    url: std::string::String,
+}
+
+impl Agent {
+    /// Finish a `fetch()` call that was passed to [`HostHooks::fetch`].
+    ///
+    /// If `result` is a response, the promise returned by the `fetch()` call
+    /// is resolved with a Response object holding it. Otherwise the promise
+    /// is rejected with a TypeError with the given message, as for a network
+    /// error.
+    ///
+    /// [`HostHooks::fetch`]: crate::ecmascript::HostHooks::fetch
+    pub fn finish_fetch(
+        &mut self,
+        pending: PendingFetch,
+        result: Result<FetchResponse, std::string::String>,
+        mut gc: GcScope,
+    ) {
+        let PendingFetch { promise, url } = pending;
+        let promise = promise.take(self).bind(gc.nogc());
+        let capability = PromiseCapability::from_promise(promise, true);
+        match result {
+            Ok(response) => {
+                let response = Response::from_fetch_response(self, url, response, gc.nogc());
+                capability
+                    .unbind()
+                    .resolve(self, response.unbind().into(), gc.reborrow());
+            }
+            Err(message) => {
+                let error = self.throw_exception(ExceptionType::TypeError, message, gc.nogc());
+                capability.reject(self, error.value(), gc.nogc());
+            }
+        }
+    }
+}
+
+pub(crate) struct FetchFunction;
+impl Builtin for FetchFunction {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fetch;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(FetchFunction::fetch);
+}
+impl BuiltinIntrinsic for FetchFunction {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::Fetch;
+}
+
+impl FetchFunction {
+    /// ### [fetch(input, init)](https://fetch.spec.whatwg.org/#dom-global-fetch)
+    fn fetch<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let input = arguments.get(0).bind(gc.nogc());
+        let init = arguments.get(1).bind(gc.nogc());
+        // 1. Let p be a new promise.
+        // 2. Let requestObject be the result of invoking the initial value of
+        //    Request as constructor with input and init as arguments. If this
+        //    throws an exception, reject p with it and return p.
+        let request_constructor = agent.current_realm_record().intrinsics().request();
+        let request_object = match construct_request(
+            agent,
+            request_constructor.into(),
+            input.unbind(),
+            init.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()
+        {
+            Ok(request_object) => request_object,
+            Err(err) => {
+                let gc = gc.into_nogc();
+                return Ok(Promise::new_rejected(agent, err.value().bind(gc), gc).into());
+            }
+        };
+        let request_object = request_object.bind(gc.nogc());
+        let promise = PromiseCapability::new(agent, gc.nogc()).promise();
+        // 3. Let request be requestObject's request.
+        // 4. If requestObject's signal is aborted, then: ...
+        // ...
+        // 12. Set controller to the result of calling fetch given request and
+        //     processResponse given response being these steps: ...
+        // NOTE: The body of the request is moved to the host, which disturbs
+        // it.
+        let data = request_object.data_mut(agent);
+        let body = if data.body.bytes.is_some() {
+            data.body.read()
+        } else {
+            None
+        };
+        let request = FetchRequest {
+            url: request_object.url(agent).to_owned(),
+            method: request_object.method(agent).to_owned(),
+            headers: request_object.headers(agent).list(agent).to_vec(),
+            body,
+        };
+        let pending = PendingFetch {
+            promise: Global::new(agent, promise.unbind()),
+            url: request.url.clone(),
+        };
+        let scoped_promise = promise.scope(agent, gc.nogc());
+        let host_hooks = agent.host_hooks;
+        host_hooks.fetch(agent, request, pending, gc.reborrow());
+        // 13. Return p.
+        Ok(scoped_promise.get(agent).bind(gc.into_nogc()).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        BuiltinFunctionBuilder::new_intrinsic_function::<FetchFunction>(agent, realm).build();
+    }
+}
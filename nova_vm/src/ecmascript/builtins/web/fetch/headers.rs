@@ -0,0 +1,304 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, EmbedderObject, ExceptionType, JsResult, Value, embedder_object_handle, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+/// ### [5.1 Headers class](https://fetch.spec.whatwg.org/#headers-class)
+///
+/// A Headers object holds an ordered list of HTTP header names and values.
+/// Header names are stored in lowercase. Header values are byte sequences,
+/// stored as strings of characters in the range U+0000 to U+00FF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Headers<'a>(EmbedderObject<'a>);
+embedder_object_handle!(Headers, HeadersRecord);
+
+/// A header list: a list of header names and values.
+pub(crate) type HeaderList = Vec<(std::string::String, std::string::String)>;
+
+/// ### [headers guard](https://fetch.spec.whatwg.org/#concept-headers-guard)
+///
+/// Forbidden request and response header names are not filtered: the
+/// "request", "request-no-cors" and "response" guards behave like "none",
+/// and the host decides which headers it sends and exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeadersGuard {
+    Immutable,
+    None,
+}
+
+#[derive(Debug)]
+pub(crate) struct HeadersRecord {
+    /// \[\[header list]]
+    pub(super) list: HeaderList,
+    /// \[\[guard]]
+    pub(super) guard: HeadersGuard,
+}
+
+impl<'a> Headers<'a> {
+    pub(crate) const SLOT_COUNT: usize = 0;
+
+    /// Create a new Headers object in the current Realm holding the given
+    /// header list. Header names are converted to lowercase.
+    pub(crate) fn create(
+        agent: &mut Agent,
+        mut list: HeaderList,
+        guard: HeadersGuard,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        for (name, _) in list.iter_mut() {
+            name.make_ascii_lowercase();
+        }
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .headers_prototype();
+        Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[],
+            HeadersRecord { list, guard },
+            gc,
+        ))
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    /// Returns the header list in order, with the header names in
+    /// lowercase.
+    pub fn list(self, agent: &Agent) -> &[(std::string::String, std::string::String)] {
+        &self.data(agent).list
+    }
+
+    /// Returns the values of all headers with the given name separated by
+    /// ", ", or None if there are no headers with the name.
+    pub fn get(self, agent: &Agent, name: &str) -> Option<std::string::String> {
+        self.data(agent).get(name)
+    }
+}
+
+impl HeadersRecord {
+    /// ### [append](https://fetch.spec.whatwg.org/#concept-headers-append)
+    pub(super) fn append(&mut self, name: &str, value: &str) -> Result<(), &'static str> {
+        // 1. Normalize value.
+        let value = normalize_header_value(value);
+        // 2. If validating (name, value) for headers returns false, then
+        //    return.
+        self.validate(name, value)?;
+        // 3. If headers's guard is "request-no-cors", then: ...
+        // 4. Append (name, value) to headers's header list.
+        self.list
+            .push((name.to_ascii_lowercase(), value.to_owned()));
+        // 5. If headers's guard is "request-no-cors", then remove
+        //    privileged no-CORS request-headers from headers.
+        Ok(())
+    }
+
+    /// ### [fill](https://fetch.spec.whatwg.org/#concept-headers-fill)
+    ///
+    /// The object has already been converted to a header list.
+    pub(super) fn fill(&mut self, list: HeaderList) -> Result<(), &'static str> {
+        // 1. If object is a sequence, then for each header of object:
+        //    a. If header's size is not 2, then throw a TypeError.
+        //    b. Append (header[0], header[1]) to headers.
+        // 2. Otherwise, object is a record, then for each key → value of
+        //    object, append (key, value) to headers.
+        for (name, value) in list {
+            self.append(&name, &value)?;
+        }
+        Ok(())
+    }
+
+    /// ### [Headers.prototype.delete(name)](https://fetch.spec.whatwg.org/#dom-headers-delete)
+    pub(super) fn delete(&mut self, name: &str) -> Result<(), &'static str> {
+        // 1. If validating (name, ``) for this returns false, then return.
+        self.validate(name, "")?;
+        // 2. If this's guard is "request-no-cors", name is not a no-CORS-
+        //    safelisted request-header name, and name is not a privileged
+        //    no-CORS request-header name, then return.
+        // 3. If this's header list does not contain name, then return.
+        // 4. Delete name from this's header list.
+        self.list.retain(|(n, _)| !n.eq_ignore_ascii_case(name));
+        // 5. If this's guard is "request-no-cors", then remove privileged
+        //    no-CORS request-headers from this.
+        Ok(())
+    }
+
+    /// ### [get](https://fetch.spec.whatwg.org/#concept-header-list-get)
+    pub(super) fn get(&self, name: &str) -> Option<std::string::String> {
+        // 1. If list does not contain name, then return null.
+        // 2. Return the values of all headers in list whose name is a
+        //    byte-case-insensitive match for name, separated from each other
+        //    by 0x2C 0x20, in order.
+        let mut values = self
+            .list
+            .iter()
+            .filter(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str());
+        let first = values.next()?;
+        Some(values.fold(first.to_owned(), |mut result, value| {
+            result.push_str(", ");
+            result.push_str(value);
+            result
+        }))
+    }
+
+    /// ### [contains](https://fetch.spec.whatwg.org/#header-list-contains)
+    pub(super) fn contains(&self, name: &str) -> bool {
+        self.list.iter().any(|(n, _)| n.eq_ignore_ascii_case(name))
+    }
+
+    /// ### [Headers.prototype.set(name, value)](https://fetch.spec.whatwg.org/#dom-headers-set)
+    pub(super) fn set(&mut self, name: &str, value: &str) -> Result<(), &'static str> {
+        // 1. Normalize value.
+        let value = normalize_header_value(value);
+        // 2. If validating (name, value) for this returns false, then return.
+        self.validate(name, value)?;
+        // 3. If this's guard is "request-no-cors" and (name, value) is not a
+        //    no-CORS-safelisted request-header, then return.
+        // 4. Set (name, value) in this's header list.
+        // ### [set](https://fetch.spec.whatwg.org/#concept-header-list-set)
+        // 1. If list contains name, then set the value of the first such
+        //    header to value and remove the others.
+        if let Some(index) = self
+            .list
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            self.list[index].1 = value.to_owned();
+            let mut position = 0;
+            self.list.retain(|(n, _)| {
+                let keep = position <= index || !n.eq_ignore_ascii_case(name);
+                position += 1;
+                keep
+            });
+        } else {
+            // 2. Otherwise, append (name, value) to list.
+            self.list
+                .push((name.to_ascii_lowercase(), value.to_owned()));
+        }
+        // 5. If this's guard is "request-no-cors", then remove privileged
+        //    no-CORS request-headers from this.
+        Ok(())
+    }
+
+    /// ### [sort and combine](https://fetch.spec.whatwg.org/#concept-header-list-sort-and-combine)
+    pub(super) fn sort_and_combine(&self) -> HeaderList {
+        // 1. Let headers be an empty list of headers with the key being the
+        //    name and value the value.
+        let mut headers = HeaderList::new();
+        // 2. Let names be the result of convert header names to a sorted-
+        //    lowercase set with all the names of the headers in list.
+        let mut names = self
+            .list
+            .iter()
+            .map(|(n, _)| n.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+        // 3. For each name of names:
+        for name in names {
+            // a. If name is `set-cookie`, then:
+            if name == "set-cookie" {
+                // i. Let values be a list of all values of headers in list
+                //    whose name is a byte-case-insensitive match for name, in
+                //    order.
+                // ii. For each value of values: append (name, value) to
+                //     headers.
+                headers.extend(
+                    self.list
+                        .iter()
+                        .filter(|(n, _)| n == name)
+                        .map(|(_, v)| (name.to_owned(), v.clone())),
+                );
+            } else {
+                // b. Otherwise:
+                // i. Let value be the result of getting name from list.
+                // ii. Assert: value is non-null.
+                let value = self.get(name).unwrap();
+                // iii. Append (name, value) to headers.
+                headers.push((name.to_owned(), value));
+            }
+        }
+        // 4. Return headers.
+        headers
+    }
+
+    /// ### [validate](https://fetch.spec.whatwg.org/#headers-validate)
+    fn validate(&self, name: &str, value: &str) -> Result<(), &'static str> {
+        // 1. If name is not a header name or value is not a header value,
+        //    then throw a TypeError.
+        if !is_header_name(name) {
+            return Err("Invalid header name");
+        }
+        if !is_header_value(value) {
+            return Err("Invalid header value");
+        }
+        // 2. If headers's guard is "immutable", then throw a TypeError.
+        if self.guard == HeadersGuard::Immutable {
+            return Err("Headers are immutable");
+        }
+        // 3. If headers's guard is "request" and (name, value) is a
+        //    forbidden request-header, then return false.
+        // 4. If headers's guard is "response" and name is a forbidden
+        //    response-header name, then return false.
+        // 5. Return true.
+        Ok(())
+    }
+}
+
+/// Returns true if the string is a [header name](https://fetch.spec.whatwg.org/#header-name):
+/// a non-empty [token](https://httpwg.org/specs/rfc9110.html#tokens).
+pub(super) fn is_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+/// Returns true if the string is a [header value](https://fetch.spec.whatwg.org/#header-value).
+fn is_header_value(value: &str) -> bool {
+    value.len() == value.trim_matches(is_http_whitespace).len()
+        && !value.contains(['\0', '\n', '\r'])
+}
+
+/// ### [normalize](https://fetch.spec.whatwg.org/#concept-header-value-normalize)
+///
+/// Remove any leading and trailing HTTP whitespace bytes from the value.
+fn normalize_header_value(value: &str) -> &str {
+    value.trim_matches(is_http_whitespace)
+}
+
+/// ### [HTTP whitespace](https://fetch.spec.whatwg.org/#http-whitespace)
+fn is_http_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | ' ')
+}
+
+/// Convert a value to a [ByteString](https://webidl.spec.whatwg.org/#js-ByteString).
+///
+/// Throws a TypeError if the value contains characters above U+00FF.
+pub(super) fn to_byte_string<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, std::string::String> {
+    let string = to_string(agent, value, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let string = string.bind(gc).to_string_lossy(agent).into_owned();
+    if string.chars().any(|c| c > '\u{FF}') {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Value is not a valid ByteString",
+            gc,
+        ));
+    }
+    Ok(string)
+}
@@ -0,0 +1,223 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, EnumerateKeysAndValues, ExceptionType, Function, JsResult,
+        Object, PropertyKey, ProtoIntrinsics, Realm, String, Value,
+        builders::BuiltinFunctionBuilder, embedder_object_create_from_constructor,
+        enumerable_own_properties, get_iterator_from_method, get_method, iterator_to_list,
+        throw_not_callable,
+    },
+    engine::{Bindable, GcScope, Scopable, ScopableCollection, ScopedCollection},
+    heap::{IntrinsicConstructorIndexes, WellKnownSymbols},
+};
+
+use super::{HeaderList, Headers, HeadersGuard, HeadersRecord, to_byte_string};
+
+pub(crate) struct HeadersConstructor;
+impl Builtin for HeadersConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Headers;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for HeadersConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Headers;
+}
+
+impl HeadersConstructor {
+    /// ### [new Headers(init)](https://fetch.spec.whatwg.org/#dom-headers)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let init = arguments.get(0).bind(gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Headers constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The init argument is converted to a HeadersInit before the
+        // constructor steps.
+        let init = if init.is_undefined() {
+            None
+        } else {
+            Some(convert_headers_init(agent, init.unbind(), gc.reborrow()).unbind()?)
+        };
+        // 1. Set this's guard to "none".
+        let mut record = HeadersRecord {
+            list: HeaderList::new(),
+            guard: HeadersGuard::None,
+        };
+        // 2. If init is given, then fill this with init.
+        if let Some(init) = init
+            && let Err(message) = record.fill(init)
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+        let headers = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::Headers,
+            Headers::SLOT_COUNT,
+            record,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        Ok(Headers::from_embedder_object(headers.bind(gc)).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let headers_prototype = intrinsics.headers_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<HeadersConstructor>(agent, realm)
+            .with_property_capacity(1)
+            .with_prototype_property(headers_prototype.into())
+            .build();
+    }
+}
+
+/// Convert a value to a [HeadersInit](https://fetch.spec.whatwg.org/#typedefdef-headersinit):
+/// either a sequence of name-value pairs or a record of names to values.
+pub(super) fn convert_headers_init<'gc>(
+    agent: &mut Agent,
+    init: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, HeaderList> {
+    let init = init.bind(gc.nogc());
+    let Ok(object) = Object::try_from(init) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Headers init is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    // NOTE: Headers objects are iterable; their pairs are copied directly.
+    if let Some(headers) = Headers::try_from_value(agent, object.into()) {
+        return Ok(headers.data(agent).sort_and_combine());
+    }
+    let scoped_object = object.scope(agent, gc.nogc());
+    let method = get_method(
+        agent,
+        object.unbind().into(),
+        PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    let mut list = HeaderList::new();
+    if let Some(method) = method {
+        // sequence<sequence<ByteString>>
+        let values = convert_sequence(
+            agent,
+            scoped_object.get(agent).into(),
+            method.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        for scoped_value in values.iter(agent) {
+            let value = scoped_value.get(gc.nogc());
+            let Ok(pair) = Object::try_from(value) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Header is not a sequence",
+                    gc.into_nogc(),
+                ));
+            };
+            let method = get_method(
+                agent,
+                pair.unbind().into(),
+                PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            let Some(method) = method else {
+                return Err(throw_not_callable(agent, gc.into_nogc()));
+            };
+            let value = scoped_value.get(gc.nogc());
+            let pair =
+                convert_sequence(agent, value.unbind(), method.unbind(), gc.reborrow()).unbind()?;
+            if pair.len(agent) != 2 {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Header does not consist of a name and a value",
+                    gc.into_nogc(),
+                ));
+            }
+            let mut pair = pair.iter(agent);
+            let name = pair.next().unwrap().get(gc.nogc());
+            let name = to_byte_string(agent, name.unbind(), gc.reborrow()).unbind()?;
+            let value = pair.next().unwrap().get(gc.nogc());
+            let value = to_byte_string(agent, value.unbind(), gc.reborrow()).unbind()?;
+            list.push((name, value));
+        }
+    } else {
+        // record<ByteString, ByteString>
+        let entries = enumerable_own_properties::<EnumerateKeysAndValues>(
+            agent,
+            scoped_object.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let mut names = Vec::with_capacity(entries.len());
+        let mut values = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let entry = Array::try_from(entry).unwrap();
+            let entry = entry.get_storage(agent).values;
+            let name = String::try_from(entry[0].unwrap()).unwrap();
+            names.push(name.to_string_lossy(agent).into_owned());
+            values.push(entry[1].unwrap());
+        }
+        let values = values.scope(agent, gc.nogc());
+        for (name, value) in names.into_iter().zip(values.iter(agent)) {
+            if name.chars().any(|c| c > '\u{FF}') {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Value is not a valid ByteString",
+                    gc.into_nogc(),
+                ));
+            }
+            let value = value.get(gc.nogc());
+            let value = to_byte_string(agent, value.unbind(), gc.reborrow()).unbind()?;
+            list.push((name, value));
+        }
+    }
+    Ok(list)
+}
+
+/// Convert an object to a sequence using the given @@iterator method.
+fn convert_sequence<'gc, 'scope>(
+    agent: &mut Agent,
+    object: Value,
+    method: Function,
+    mut gc: GcScope<'gc, 'scope>,
+) -> JsResult<'gc, ScopedCollection<'scope, Vec<Value<'static>>>> {
+    let Some(iterator_record) = get_iterator_from_method(agent, object, method, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc())
+        .into_iterator_record()
+    else {
+        return Err(throw_not_callable(agent, gc.into_nogc()));
+    };
+    iterator_to_list(agent, iterator_record.unbind(), gc)
+}
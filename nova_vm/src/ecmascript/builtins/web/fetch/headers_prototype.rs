@@ -0,0 +1,373 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, ArrayIterator, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsic, CollectionIteratorKind, ExceptionType, JsResult, Realm, String, Value,
+        builders::OrdinaryObjectBuilder, call_function, create_array_from_list, is_callable,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{IntrinsicFunctionIndexes, WellKnownSymbols},
+};
+
+use super::{Headers, headers::is_header_name, to_byte_string};
+
+pub(crate) struct HeadersPrototype;
+
+struct HeadersPrototypeAppend;
+impl Builtin for HeadersPrototypeAppend {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.append;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::append);
+}
+struct HeadersPrototypeDelete;
+impl Builtin for HeadersPrototypeDelete {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.delete;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::delete);
+}
+struct HeadersPrototypeEntries;
+impl Builtin for HeadersPrototypeEntries {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.entries;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::entries);
+}
+impl BuiltinIntrinsic for HeadersPrototypeEntries {
+    const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::HeadersPrototypeEntries;
+}
+struct HeadersPrototypeForEach;
+impl Builtin for HeadersPrototypeForEach {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.forEach;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::for_each);
+}
+struct HeadersPrototypeGet;
+impl Builtin for HeadersPrototypeGet {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::get);
+}
+struct HeadersPrototypeHas;
+impl Builtin for HeadersPrototypeHas {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.has;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::has);
+}
+struct HeadersPrototypeKeys;
+impl Builtin for HeadersPrototypeKeys {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.keys;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::keys);
+}
+struct HeadersPrototypeSet;
+impl Builtin for HeadersPrototypeSet {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.set;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::set);
+}
+struct HeadersPrototypeValues;
+impl Builtin for HeadersPrototypeValues {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.values;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(HeadersPrototype::values);
+}
+
+impl HeadersPrototype {
+    /// ### [Headers.prototype.append(name, value)](https://fetch.spec.whatwg.org/#dom-headers-append)
+    fn append<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let value = arguments.get(1).scope(agent, gc.nogc());
+        let headers = require_headers(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let name = to_byte_string(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let value = to_byte_string(agent, value.get(agent), gc.reborrow()).unbind()?;
+        // The append(name, value) method steps are to append (name, value)
+        // to this.
+        if let Err(message) = headers.get(agent).data_mut(agent).append(&name, &value) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Headers.prototype.delete(name)](https://fetch.spec.whatwg.org/#dom-headers-delete)
+    fn delete<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let headers = require_headers(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let name = to_byte_string(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        if let Err(message) = headers.get(agent).data_mut(agent).delete(&name) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Headers.prototype.entries()](https://webidl.spec.whatwg.org/#es-iterable-entries)
+    ///
+    /// Iterators iterate over a snapshot of the value pairs taken when the
+    /// iterator is created.
+    fn entries<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let headers = require_headers(agent, this_value, gc)?;
+        let pairs = headers.data(agent).sort_and_combine();
+        let entries = pairs
+            .into_iter()
+            .map(|(name, value)| {
+                let name = String::from_string(agent, name, gc);
+                let value = String::from_string(agent, value, gc);
+                create_array_from_list(agent, &[name.into(), value.into()], gc).into()
+            })
+            .collect::<Vec<Value>>();
+        Ok(create_iterator(agent, &entries, gc))
+    }
+
+    /// ### [Headers.prototype.forEach(callback, thisArg)](https://webidl.spec.whatwg.org/#es-forEach)
+    fn for_each<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let callback = arguments.get(0).bind(nogc);
+        let this_arg = arguments.get(1).scope(agent, nogc);
+        let headers = require_headers(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 2. If IsCallable(idlCallback) is false, then throw a TypeError.
+        let Some(callback) = is_callable(callback, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback function parameter is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, nogc);
+        let headers = headers.scope(agent, nogc);
+        // 3. Let idlCallback be idlCallback, converted to a Function.
+        // 4. Let pairs be esValue's target's list of value pairs to iterate
+        //    over.
+        // 5. Let i be 0.
+        let mut i = 0;
+        // 6. While i < pairs's size:
+        loop {
+            let pairs = headers.get(agent).data(agent).sort_and_combine();
+            let Some((name, value)) = pairs.into_iter().nth(i) else {
+                break;
+            };
+            // a. Let pair be pairs[i].
+            // b. Invoke idlCallback with « pair's value, pair's key, esValue »
+            //    and with thisArg as the callback this value.
+            let name = String::from_string(agent, name, gc.nogc());
+            let value = String::from_string(agent, value, gc.nogc());
+            call_function(
+                agent,
+                callback.get(agent),
+                this_arg.get(agent),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    value.unbind().into(),
+                    name.unbind().into(),
+                    headers.get(agent).into(),
+                ])),
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // c. Set pairs to esValue's target's current list of value pairs
+            //    to iterate over. (It might have changed.)
+            // d. Set i to i + 1.
+            i += 1;
+        }
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Headers.prototype.get(name)](https://fetch.spec.whatwg.org/#dom-headers-get)
+    fn get<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let headers = require_headers(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let name = to_byte_string(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 1. If name is not a header name, then throw a TypeError.
+        if !is_header_name(&name) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid header name",
+                gc,
+            ));
+        }
+        // 2. Return the result of getting name from this's header list.
+        match headers.get(agent).data(agent).get(&name) {
+            Some(value) => Ok(String::from_string(agent, value, gc).into()),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// ### [Headers.prototype.has(name)](https://fetch.spec.whatwg.org/#dom-headers-has)
+    fn has<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let headers = require_headers(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let name = to_byte_string(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        // 1. If name is not a header name, then throw a TypeError.
+        if !is_header_name(&name) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid header name",
+                gc.into_nogc(),
+            ));
+        }
+        // 2. Return true if this's header list contains name; otherwise
+        //    false.
+        Ok(headers.get(agent).data(agent).contains(&name).into())
+    }
+
+    /// ### [Headers.prototype.keys()](https://webidl.spec.whatwg.org/#es-iterable-keys)
+    fn keys<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let headers = require_headers(agent, this_value, gc)?;
+        let pairs = headers.data(agent).sort_and_combine();
+        let keys = pairs
+            .into_iter()
+            .map(|(name, _)| String::from_string(agent, name, gc).into())
+            .collect::<Vec<Value>>();
+        Ok(create_iterator(agent, &keys, gc))
+    }
+
+    /// ### [Headers.prototype.set(name, value)](https://fetch.spec.whatwg.org/#dom-headers-set)
+    fn set<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let value = arguments.get(1).scope(agent, gc.nogc());
+        let headers = require_headers(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        let name = to_byte_string(agent, arguments.get(0), gc.reborrow()).unbind()?;
+        let value = to_byte_string(agent, value.get(agent), gc.reborrow()).unbind()?;
+        if let Err(message) = headers.get(agent).data_mut(agent).set(&name, &value) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+        Ok(Value::Undefined)
+    }
+
+    /// ### [Headers.prototype.values()](https://webidl.spec.whatwg.org/#es-iterable-values)
+    fn values<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let headers = require_headers(agent, this_value, gc)?;
+        let pairs = headers.data(agent).sort_and_combine();
+        let values = pairs
+            .into_iter()
+            .map(|(_, value)| String::from_string(agent, value, gc).into())
+            .collect::<Vec<Value>>();
+        Ok(create_iterator(agent, &values, gc))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.headers_prototype();
+        let headers_constructor = intrinsics.headers();
+        let headers_prototype_entries = intrinsics.headers_prototype_entries();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(12)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<HeadersPrototypeAppend>()
+            .with_constructor_property(headers_constructor)
+            .with_builtin_function_property::<HeadersPrototypeDelete>()
+            .with_builtin_intrinsic_function_property::<HeadersPrototypeEntries>()
+            .with_builtin_function_property::<HeadersPrototypeForEach>()
+            .with_builtin_function_property::<HeadersPrototypeGet>()
+            .with_builtin_function_property::<HeadersPrototypeHas>()
+            .with_builtin_function_property::<HeadersPrototypeKeys>()
+            .with_builtin_function_property::<HeadersPrototypeSet>()
+            .with_builtin_function_property::<HeadersPrototypeValues>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::Iterator.into())
+                    .with_value(headers_prototype_entries.into())
+                    .with_enumerable(HeadersPrototypeEntries::ENUMERABLE)
+                    .with_configurable(HeadersPrototypeEntries::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Headers.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Create an iterator over a list of values.
+fn create_iterator<'gc>(agent: &mut Agent, values: &[Value], gc: NoGcScope<'gc, '_>) -> Value<'gc> {
+    let array = create_array_from_list(agent, values, gc);
+    ArrayIterator::from_object(agent, array.into(), CollectionIteratorKind::Value).into()
+}
+
+fn require_headers<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Headers<'a>> {
+    Headers::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a Headers",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::ecmascript::{Agent, EmbedderObject, embedder_object_handle};
+
+use super::{Body, Headers};
+
+/// ### [5.4 Request class](https://fetch.spec.whatwg.org/#request-class)
+///
+/// A Request is an HTTP request that can be passed to `fetch()`: a URL, a
+/// method, a list of headers and an optional body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Request<'a>(EmbedderObject<'a>);
+embedder_object_handle!(Request, RequestRecord);
+
+#[derive(Debug)]
+pub(crate) struct RequestRecord {
+    /// \[\[request]]'s URL
+    pub(super) url: std::string::String,
+    /// \[\[request]]'s method
+    pub(super) method: std::string::String,
+    /// \[\[request]]'s body
+    pub(super) body: Body,
+}
+
+impl<'a> Request<'a> {
+    /// \[\[headers]]
+    const HEADERS: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(super) fn initialize(self, agent: &mut Agent, headers: Headers) {
+        self.0.set_slot(agent, Self::HEADERS, headers.into());
+    }
+
+    /// Returns the URL of the Request as given to the constructor.
+    pub fn url(self, agent: &Agent) -> &str {
+        &self.data(agent).url
+    }
+
+    /// Returns the normalized method of the Request.
+    pub fn method(self, agent: &Agent) -> &str {
+        &self.data(agent).method
+    }
+
+    /// Returns the headers of the Request.
+    pub fn headers(self, agent: &Agent) -> Headers<'a> {
+        Headers::try_from_value(agent, self.0.get_slot(agent, Self::HEADERS)).unwrap()
+    }
+
+    /// Returns true if the body of the Request has been read.
+    pub fn body_used(self, agent: &Agent) -> bool {
+        self.data(agent).body.is_used()
+    }
+}
@@ -0,0 +1,340 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor, get, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{
+    Body, HeaderList, Headers, HeadersGuard, HeadersRecord, Request, RequestRecord,
+    body::extract_body, convert_headers_init, headers::is_header_name, to_byte_string,
+};
+
+pub(crate) struct RequestConstructor;
+impl Builtin for RequestConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Request;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for RequestConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Request;
+}
+
+impl RequestConstructor {
+    /// ### [new Request(input, init)](https://fetch.spec.whatwg.org/#dom-request)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let input = arguments.get(0).bind(gc.nogc());
+        let init = arguments.get(1).bind(gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Request constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        construct_request(
+            agent,
+            new_target.unbind(),
+            input.unbind(),
+            init.unbind(),
+            gc,
+        )
+        .map(|request| request.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let request_prototype = intrinsics.request_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<RequestConstructor>(agent, realm)
+            .with_property_capacity(1)
+            .with_prototype_property(request_prototype.into())
+            .build();
+    }
+}
+
+/// ### [RequestInit](https://fetch.spec.whatwg.org/#requestinit)
+///
+/// Only the body, headers and method members are supported.
+#[derive(Default)]
+struct RequestInit {
+    /// The extracted body and its content type.
+    body: Option<(Vec<u8>, Option<std::string::String>)>,
+    headers: Option<HeaderList>,
+    method: Option<std::string::String>,
+}
+trivially_bindable!(RequestInit);
+
+/// Run the steps of the Request constructor, creating the Request with the
+/// prototype of the given constructor.
+pub(super) fn construct_request<'gc>(
+    agent: &mut Agent,
+    new_target: Function,
+    input: Value,
+    init: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Request<'gc>> {
+    let new_target = new_target.scope(agent, gc.nogc());
+    let input = input.bind(gc.nogc());
+    let init = init.scope(agent, gc.nogc());
+    // NOTE: The input argument is converted to a RequestInfo and the init
+    // argument to a RequestInit before the constructor steps.
+    let (input_request, input_url) = match Request::try_from_value(agent, input) {
+        Some(request) => (Some(request.scope(agent, gc.nogc())), None),
+        None => {
+            let url = to_string(agent, input.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            (None, Some(url.to_string_lossy(agent).into_owned()))
+        }
+    };
+    let init = convert_request_init(agent, init.get(agent), gc.reborrow()).unbind()?;
+    // 1. Let request be null.
+    // ...
+    // 5. If input is a string, then:
+    //    a. Let parsedURL be the result of parsing input with baseURL.
+    //    b. If parsedURL is failure, then throw a TypeError.
+    //    c. If parsedURL includes credentials, then throw a TypeError.
+    //    d. Set request to a new request whose URL is parsedURL.
+    //    e. Set fallbackMode to "cors".
+    // NOTE: URLs are not parsed; the host receives the URL as written.
+    // 6. Otherwise:
+    //    a. Assert: input is a Request object.
+    //    b. Set request to input's request.
+    //    c. Set signal to input's signal.
+    // ...
+    // 12. Set request to a new request with the following properties:
+    //     URL: request's URL.
+    //     method: request's method.
+    //     header list: A copy of request's header list.
+    //     ...
+    let (url, mut method, header_list, input_has_body) = match &input_request {
+        Some(input_request) => {
+            let input_request = input_request.get(agent);
+            let data = input_request.data(agent);
+            (
+                data.url.clone(),
+                data.method.clone(),
+                input_request.headers(agent).data(agent).list.clone(),
+                data.body.bytes.is_some(),
+            )
+        }
+        None => (
+            input_url.unwrap(),
+            "GET".to_owned(),
+            HeaderList::new(),
+            false,
+        ),
+    };
+    // ...
+    // 25. If init["method"] exists, then:
+    if let Some(init_method) = init.method {
+        // a. Let method be init["method"].
+        // b. If method is not a method or method is a forbidden method, then
+        //    throw a TypeError.
+        if !is_header_name(&init_method) || is_forbidden_method(&init_method) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid request method",
+                gc.into_nogc(),
+            ));
+        }
+        // c. Normalize method.
+        // d. Set request's method to method.
+        method = normalize_method(init_method);
+    }
+    // ...
+    // 32. Set this's headers to a new Headers object with this's relevant
+    //     realm, whose header list is request's header list and guard is
+    //     "request".
+    let mut headers = HeadersRecord {
+        list: header_list,
+        guard: HeadersGuard::None,
+    };
+    // 33. If init is not empty, then:
+    //     a. Let headers be a copy of this's headers and its associated
+    //        header list.
+    //     b. If init["headers"] exists, then set headers to init["headers"].
+    //     c. Empty this's headers's header list.
+    //     d. If headers is a Headers object, then for each header of its
+    //        header list, append header to this's headers.
+    //     e. Otherwise, fill this's headers with headers.
+    if let Some(init_headers) = init.headers {
+        headers.list.clear();
+        if let Err(message) = headers.fill(init_headers) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+    }
+    // 34. Let inputBody be input's request's body if input is a Request
+    //     object; otherwise null.
+    // 35. If either init["body"] exists and is non-null or inputBody is
+    //     non-null, and request's method is `GET` or `HEAD`, then throw a
+    //     TypeError.
+    if (init.body.is_some() || input_has_body) && (method == "GET" || method == "HEAD") {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Request with GET or HEAD method cannot have a body",
+            gc.into_nogc(),
+        ));
+    }
+    // 36. Let initBody be null.
+    // 37. If init["body"] exists and is non-null, then:
+    let init_body = init.body.map(|(bytes, content_type)| {
+        // a. Let bodyWithType be the result of extracting init["body"], with
+        //    keepalive set to request's keepalive.
+        // b. Set initBody to bodyWithType's body.
+        // c. Let type be bodyWithType's type.
+        // d. If type is non-null and this's headers's header list does not
+        //    contain `Content-Type`, then append (`Content-Type`, type) to
+        //    this's headers.
+        if let Some(content_type) = content_type
+            && !headers.contains("content-type")
+        {
+            headers.list.push(("content-type".to_owned(), content_type));
+        }
+        bytes
+    });
+    // 38. Let inputOrInitBody be initBody if it is non-null; otherwise
+    //     inputBody.
+    // ...
+    // 40. Let finalBody be inputOrInitBody.
+    // 41. If initBody is null and inputBody is non-null, then:
+    let body = match (init_body, input_request) {
+        (Some(init_body), _) => Some(init_body),
+        (None, Some(input_request)) if input_has_body => {
+            // a. If input is unusable, then throw a TypeError.
+            // b. Set finalBody to the result of creating a proxy for
+            //    inputBody.
+            // NOTE: The bytes of inputBody are moved to finalBody, which
+            // disturbs inputBody.
+            let Some(bytes) = input_request.get(agent).data_mut(agent).body.read() else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Request body has already been consumed",
+                    gc.into_nogc(),
+                ));
+            };
+            Some(bytes)
+        }
+        _ => None,
+    };
+    // 42. Set this's request's body to finalBody.
+    let request = embedder_object_create_from_constructor(
+        agent,
+        new_target.get(agent),
+        ProtoIntrinsics::Request,
+        Request::SLOT_COUNT,
+        RequestRecord {
+            url,
+            method,
+            body: Body::new(body),
+        },
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let request = Request::from_embedder_object(request.bind(gc));
+    let headers = Headers::create(agent, headers.list, HeadersGuard::None, gc);
+    request.initialize(agent, headers);
+    Ok(request)
+}
+
+/// Convert a value to a RequestInit.
+fn convert_request_init<'gc>(
+    agent: &mut Agent,
+    init: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, RequestInit> {
+    let init = init.bind(gc.nogc());
+    let mut request_init = RequestInit::default();
+    let init = match init {
+        Value::Undefined | Value::Null => return Ok(request_init),
+        init => match Object::try_from(init) {
+            Ok(init) => init.scope(agent, gc.nogc()),
+            Err(_) => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "init is not an object",
+                    gc.into_nogc(),
+                ));
+            }
+        },
+    };
+    let body = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.body.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !body.is_undefined() && !body.is_null() {
+        request_init.body = Some(extract_body(agent, body.unbind(), gc.reborrow()).unbind()?);
+    }
+    let headers = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.headers.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !headers.is_undefined() {
+        request_init.headers =
+            Some(convert_headers_init(agent, headers.unbind(), gc.reborrow()).unbind()?);
+    }
+    let method = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.method.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !method.is_undefined() {
+        request_init.method = Some(to_byte_string(agent, method.unbind(), gc.reborrow()).unbind()?);
+    }
+    Ok(request_init)
+}
+
+/// Returns true if the method is a [forbidden method](https://fetch.spec.whatwg.org/#forbidden-method).
+fn is_forbidden_method(method: &str) -> bool {
+    ["CONNECT", "TRACE", "TRACK"]
+        .iter()
+        .any(|forbidden| method.eq_ignore_ascii_case(forbidden))
+}
+
+/// ### [normalize](https://fetch.spec.whatwg.org/#concept-method-normalize)
+///
+/// If the method is a byte-case-insensitive match for `DELETE`, `GET`,
+/// `HEAD`, `OPTIONS`, `POST`, or `PUT`, byte-uppercase it.
+fn normalize_method(mut method: std::string::String) -> std::string::String {
+    if ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"]
+        .iter()
+        .any(|normalized| method.eq_ignore_ascii_case(normalized))
+    {
+        method.make_ascii_uppercase();
+    }
+    method
+}
@@ -0,0 +1,221 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, Promise, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::{
+    Request,
+    body::{BodyType, consume_body},
+};
+
+pub(crate) struct RequestPrototype;
+
+struct RequestPrototypeArrayBuffer;
+impl Builtin for RequestPrototypeArrayBuffer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.arrayBuffer;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::array_buffer);
+}
+struct RequestPrototypeGetBodyUsed;
+impl Builtin for RequestPrototypeGetBodyUsed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_bodyUsed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.bodyUsed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::get_body_used);
+}
+impl BuiltinGetter for RequestPrototypeGetBodyUsed {}
+struct RequestPrototypeGetHeaders;
+impl Builtin for RequestPrototypeGetHeaders {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_headers;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.headers.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::get_headers);
+}
+impl BuiltinGetter for RequestPrototypeGetHeaders {}
+struct RequestPrototypeJson;
+impl Builtin for RequestPrototypeJson {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.json;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::json);
+}
+struct RequestPrototypeGetMethod;
+impl Builtin for RequestPrototypeGetMethod {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_method;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.method.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::get_method);
+}
+impl BuiltinGetter for RequestPrototypeGetMethod {}
+struct RequestPrototypeText;
+impl Builtin for RequestPrototypeText {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.text;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::text);
+}
+struct RequestPrototypeGetUrl;
+impl Builtin for RequestPrototypeGetUrl {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_url;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.url.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RequestPrototype::get_url);
+}
+impl BuiltinGetter for RequestPrototypeGetUrl {}
+
+impl RequestPrototype {
+    /// ### [Request.prototype.arrayBuffer()](https://fetch.spec.whatwg.org/#dom-body-arraybuffer)
+    fn array_buffer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(
+            agent,
+            this_value,
+            BodyType::ArrayBuffer,
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [get Request.prototype.bodyUsed](https://fetch.spec.whatwg.org/#dom-body-bodyused)
+    fn get_body_used<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let request = require_request(agent, this_value, gc)?;
+        Ok(request.body_used(agent).into())
+    }
+
+    /// ### [get Request.prototype.headers](https://fetch.spec.whatwg.org/#dom-request-headers)
+    fn get_headers<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let request = require_request(agent, this_value, gc)?;
+        // The headers getter steps are to return this's headers.
+        Ok(request.headers(agent).into())
+    }
+
+    /// ### [Request.prototype.json()](https://fetch.spec.whatwg.org/#dom-body-json)
+    fn json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(agent, this_value, BodyType::Json, gc.into_nogc()))
+    }
+
+    /// ### [get Request.prototype.method](https://fetch.spec.whatwg.org/#dom-request-method)
+    fn get_method<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let request = require_request(agent, this_value, gc)?;
+        // The method getter steps are to return this's request's method.
+        let method = request.method(agent).to_owned();
+        Ok(String::from_string(agent, method, gc).into())
+    }
+
+    /// ### [Request.prototype.text()](https://fetch.spec.whatwg.org/#dom-body-text)
+    fn text<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(agent, this_value, BodyType::Text, gc.into_nogc()))
+    }
+
+    /// ### [get Request.prototype.url](https://fetch.spec.whatwg.org/#dom-request-url)
+    fn get_url<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let request = require_request(agent, this_value, gc)?;
+        // The url getter steps are to return this's request's URL,
+        // serialized.
+        let url = request.url(agent).to_owned();
+        Ok(String::from_string(agent, url, gc).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.request_prototype();
+        let request_constructor = intrinsics.request();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<RequestPrototypeArrayBuffer>()
+            .with_builtin_function_getter_property::<RequestPrototypeGetBodyUsed>()
+            .with_constructor_property(request_constructor)
+            .with_builtin_function_getter_property::<RequestPrototypeGetHeaders>()
+            .with_builtin_function_property::<RequestPrototypeJson>()
+            .with_builtin_function_getter_property::<RequestPrototypeGetMethod>()
+            .with_builtin_function_property::<RequestPrototypeText>()
+            .with_builtin_function_getter_property::<RequestPrototypeGetUrl>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Request.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Read the body of a Request, returning a promise. Exceptions are returned
+/// as rejected promises.
+fn read_body<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    body_type: BodyType,
+    gc: NoGcScope<'gc, '_>,
+) -> Value<'gc> {
+    let request = match require_request(agent, this_value, gc) {
+        Ok(request) => request,
+        Err(err) => return Promise::new_rejected(agent, err.value(), gc).into(),
+    };
+    let bytes = request.data_mut(agent).body.read();
+    consume_body(agent, bytes, body_type, gc)
+}
+
+fn require_request<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Request<'a>> {
+    Request::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a Request",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{Agent, EmbedderObject, Value, embedder_object_handle},
+    engine::NoGcScope,
+};
+
+use super::{Body, FetchResponse, Headers, HeadersGuard};
+
+/// ### [5.5 Response class](https://fetch.spec.whatwg.org/#response-class)
+///
+/// A Response is the HTTP response to a request: a status, a list of
+/// headers and a body. Responses to `fetch()` calls are created from the
+/// [`FetchResponse`] given by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Response<'a>(EmbedderObject<'a>);
+embedder_object_handle!(Response, ResponseRecord);
+
+#[derive(Debug)]
+pub(crate) struct ResponseRecord {
+    /// \[\[response]]'s URL, or the empty string if the URL is null.
+    pub(super) url: std::string::String,
+    /// \[\[response]]'s status
+    pub(super) status: u16,
+    /// \[\[response]]'s status message
+    pub(super) status_text: std::string::String,
+    /// \[\[response]]'s body
+    pub(super) body: Body,
+}
+
+impl<'a> Response<'a> {
+    /// \[\[headers]]
+    const HEADERS: usize = 0;
+    pub(crate) const SLOT_COUNT: usize = 1;
+
+    /// Create a new Response to a request for the given URL in the current
+    /// Realm. The headers of the Response are immutable.
+    pub(crate) fn from_fetch_response(
+        agent: &mut Agent,
+        url: std::string::String,
+        response: FetchResponse,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let FetchResponse {
+            status,
+            status_text,
+            headers,
+            body,
+        } = response;
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .response_prototype();
+        let response = Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            ResponseRecord {
+                url,
+                status,
+                status_text,
+                body: Body::new(Some(body)),
+            },
+            gc,
+        ));
+        let headers = Headers::create(agent, headers, HeadersGuard::Immutable, gc);
+        response.initialize(agent, headers);
+        response
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(super) fn initialize(self, agent: &mut Agent, headers: Headers) {
+        self.0.set_slot(agent, Self::HEADERS, headers.into());
+    }
+
+    /// Returns the URL of the request the Response is for, or the empty
+    /// string if the Response was created by the Response constructor.
+    pub fn url(self, agent: &Agent) -> &str {
+        &self.data(agent).url
+    }
+
+    /// Returns the HTTP status code of the Response.
+    pub fn status(self, agent: &Agent) -> u16 {
+        self.data(agent).status
+    }
+
+    /// Returns the HTTP status message of the Response.
+    pub fn status_text(self, agent: &Agent) -> &str {
+        &self.data(agent).status_text
+    }
+
+    /// Returns the headers of the Response.
+    pub fn headers(self, agent: &Agent) -> Headers<'a> {
+        Headers::try_from_value(agent, self.0.get_slot(agent, Self::HEADERS)).unwrap()
+    }
+
+    /// Returns true if the body of the Response has been read.
+    pub fn body_used(self, agent: &Agent) -> bool {
+        self.data(agent).body.is_used()
+    }
+}
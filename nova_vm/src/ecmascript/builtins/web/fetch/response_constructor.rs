@@ -0,0 +1,255 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor, get, to_number,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{
+    Body, HeaderList, Headers, HeadersGuard, HeadersRecord, Response, ResponseRecord,
+    body::extract_body, convert_headers_init, to_byte_string,
+};
+
+pub(crate) struct ResponseConstructor;
+impl Builtin for ResponseConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Response;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for ResponseConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Response;
+}
+
+impl ResponseConstructor {
+    /// ### [new Response(body, init)](https://fetch.spec.whatwg.org/#dom-response)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let body = arguments.get(0).bind(gc.nogc());
+        let init = arguments.get(1).scope(agent, gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Response constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The body argument is converted to a BodyInit and the init
+        // argument to a ResponseInit before the constructor steps. Bodies
+        // are extracted during the conversion.
+        // 3. Let bodyWithType be null.
+        // 4. If body is non-null, then set bodyWithType to the result of
+        //    extracting body.
+        let body_with_type = if body.is_undefined() || body.is_null() {
+            None
+        } else {
+            Some(extract_body(agent, body.unbind(), gc.reborrow()).unbind()?)
+        };
+        let init = convert_response_init(agent, init.get(agent), gc.reborrow()).unbind()?;
+        // 1. Set this's response to a new response.
+        // 2. Set this's headers to a new Headers object with this's relevant
+        //    realm, whose header list is this's response's header list and
+        //    guard is "response".
+        let mut headers = HeadersRecord {
+            list: HeaderList::new(),
+            guard: HeadersGuard::None,
+        };
+        // 5. Perform initialize a response given this, init, and
+        //    bodyWithType.
+
+        // ### [initialize a response](https://fetch.spec.whatwg.org/#initialize-a-response)
+        // 1. If init["status"] is not in the range 200 to 599, inclusive,
+        //    then throw a RangeError.
+        if !(200..=599).contains(&init.status) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Response status must be in the range 200 to 599",
+                gc.into_nogc(),
+            ));
+        }
+        // 2. If init["statusText"] is not the empty string and does not match
+        //    the reason-phrase token production, then throw a TypeError.
+        if !is_reason_phrase(&init.status_text) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid response status text",
+                gc.into_nogc(),
+            ));
+        }
+        // 3. Set response's response's status to init["status"].
+        // 4. Set response's response's status message to init["statusText"].
+        // 5. If init["headers"] exists, then fill response's headers with
+        //    init["headers"].
+        if let Some(init_headers) = init.headers
+            && let Err(message) = headers.fill(init_headers)
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                message,
+                gc.into_nogc(),
+            ));
+        }
+        // 6. If body is non-null, then:
+        let body = if let Some((bytes, content_type)) = body_with_type {
+            // a. If response's status is a null body status, then throw a
+            //    TypeError.
+            if is_null_body_status(init.status) {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Response with a null body status cannot have a body",
+                    gc.into_nogc(),
+                ));
+            }
+            // b. Set response's body to body's body.
+            // c. If body's type is non-null and response's header list does
+            //    not contain `Content-Type`, then append (`Content-Type`,
+            //    body's type) to response's header list.
+            if let Some(content_type) = content_type
+                && !headers.contains("content-type")
+            {
+                headers.list.push(("content-type".to_owned(), content_type));
+            }
+            Some(bytes)
+        } else {
+            None
+        };
+        let response = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::Response,
+            Response::SLOT_COUNT,
+            ResponseRecord {
+                url: std::string::String::new(),
+                status: init.status,
+                status_text: init.status_text,
+                body: Body::new(body),
+            },
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let response = Response::from_embedder_object(response.bind(gc));
+        let headers = Headers::create(agent, headers.list, HeadersGuard::None, gc);
+        response.initialize(agent, headers);
+        Ok(response.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let response_prototype = intrinsics.response_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<ResponseConstructor>(agent, realm)
+            .with_property_capacity(1)
+            .with_prototype_property(response_prototype.into())
+            .build();
+    }
+}
+
+/// ### [ResponseInit](https://fetch.spec.whatwg.org/#responseinit)
+struct ResponseInit {
+    headers: Option<HeaderList>,
+    status: u16,
+    status_text: std::string::String,
+}
+trivially_bindable!(ResponseInit);
+
+/// Convert a value to a ResponseInit.
+fn convert_response_init<'gc>(
+    agent: &mut Agent,
+    init: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ResponseInit> {
+    let init = init.bind(gc.nogc());
+    let mut response_init = ResponseInit {
+        headers: None,
+        status: 200,
+        status_text: std::string::String::new(),
+    };
+    let init = match init {
+        Value::Undefined | Value::Null => return Ok(response_init),
+        init => match Object::try_from(init) {
+            Ok(init) => init.scope(agent, gc.nogc()),
+            Err(_) => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "init is not an object",
+                    gc.into_nogc(),
+                ));
+            }
+        },
+    };
+    let headers = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.headers.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !headers.is_undefined() {
+        response_init.headers =
+            Some(convert_headers_init(agent, headers.unbind(), gc.reborrow()).unbind()?);
+    }
+    let status = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.status.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !status.is_undefined() {
+        // NOTE: The status is converted to an unsigned short.
+        let status = to_number(agent, status.unbind(), gc.reborrow())
+            .unbind()?
+            .into_f64(agent);
+        response_init.status = if status.is_finite() {
+            status.trunc().rem_euclid(65536.0) as u16
+        } else {
+            0
+        };
+    }
+    let status_text = get(
+        agent,
+        init.get(agent),
+        BUILTIN_STRING_MEMORY.statusText.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !status_text.is_undefined() {
+        response_init.status_text =
+            to_byte_string(agent, status_text.unbind(), gc.reborrow()).unbind()?;
+    }
+    Ok(response_init)
+}
+
+/// Returns true if the string matches the [reason-phrase](https://httpwg.org/specs/rfc9112.html#status.line)
+/// token production.
+fn is_reason_phrase(status_text: &str) -> bool {
+    status_text
+        .chars()
+        .all(|c| c == '\t' || ((' '..='\u{FF}').contains(&c) && c != '\u{7F}'))
+}
+
+/// Returns true if the status is a [null body status](https://fetch.spec.whatwg.org/#null-body-status).
+fn is_null_body_status(status: u16) -> bool {
+    matches!(status, 101 | 103 | 204 | 205 | 304)
+}
@@ -0,0 +1,269 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, Promise, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::{
+    Response,
+    body::{BodyType, consume_body},
+};
+
+pub(crate) struct ResponsePrototype;
+
+struct ResponsePrototypeArrayBuffer;
+impl Builtin for ResponsePrototypeArrayBuffer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.arrayBuffer;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::array_buffer);
+}
+struct ResponsePrototypeGetBodyUsed;
+impl Builtin for ResponsePrototypeGetBodyUsed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_bodyUsed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.bodyUsed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_body_used);
+}
+impl BuiltinGetter for ResponsePrototypeGetBodyUsed {}
+struct ResponsePrototypeGetHeaders;
+impl Builtin for ResponsePrototypeGetHeaders {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_headers;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.headers.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_headers);
+}
+impl BuiltinGetter for ResponsePrototypeGetHeaders {}
+struct ResponsePrototypeJson;
+impl Builtin for ResponsePrototypeJson {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.json;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::json);
+}
+struct ResponsePrototypeGetOk;
+impl Builtin for ResponsePrototypeGetOk {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_ok;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.ok.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_ok);
+}
+impl BuiltinGetter for ResponsePrototypeGetOk {}
+struct ResponsePrototypeGetStatus;
+impl Builtin for ResponsePrototypeGetStatus {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_status;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.status.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_status);
+}
+impl BuiltinGetter for ResponsePrototypeGetStatus {}
+struct ResponsePrototypeGetStatusText;
+impl Builtin for ResponsePrototypeGetStatusText {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_statusText;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.statusText.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_status_text);
+}
+impl BuiltinGetter for ResponsePrototypeGetStatusText {}
+struct ResponsePrototypeText;
+impl Builtin for ResponsePrototypeText {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.text;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::text);
+}
+struct ResponsePrototypeGetUrl;
+impl Builtin for ResponsePrototypeGetUrl {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_url;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.url.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ResponsePrototype::get_url);
+}
+impl BuiltinGetter for ResponsePrototypeGetUrl {}
+
+impl ResponsePrototype {
+    /// ### [Response.prototype.arrayBuffer()](https://fetch.spec.whatwg.org/#dom-body-arraybuffer)
+    fn array_buffer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(
+            agent,
+            this_value,
+            BodyType::ArrayBuffer,
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [get Response.prototype.bodyUsed](https://fetch.spec.whatwg.org/#dom-body-bodyused)
+    fn get_body_used<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        Ok(response.body_used(agent).into())
+    }
+
+    /// ### [get Response.prototype.headers](https://fetch.spec.whatwg.org/#dom-response-headers)
+    fn get_headers<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        // The headers getter steps are to return this's headers.
+        Ok(response.headers(agent).into())
+    }
+
+    /// ### [Response.prototype.json()](https://fetch.spec.whatwg.org/#dom-body-json)
+    fn json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(agent, this_value, BodyType::Json, gc.into_nogc()))
+    }
+
+    /// ### [get Response.prototype.ok](https://fetch.spec.whatwg.org/#dom-response-ok)
+    fn get_ok<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        // The ok getter steps are to return true if this's response's status
+        // is an ok status; otherwise false.
+        Ok((200..=299).contains(&response.status(agent)).into())
+    }
+
+    /// ### [get Response.prototype.status](https://fetch.spec.whatwg.org/#dom-response-status)
+    fn get_status<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        // The status getter steps are to return this's response's status.
+        Ok(response.status(agent).into())
+    }
+
+    /// ### [get Response.prototype.statusText](https://fetch.spec.whatwg.org/#dom-response-statustext)
+    fn get_status_text<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        // The statusText getter steps are to return this's response's status
+        // message.
+        let status_text = response.status_text(agent).to_owned();
+        Ok(String::from_string(agent, status_text, gc).into())
+    }
+
+    /// ### [Response.prototype.text()](https://fetch.spec.whatwg.org/#dom-body-text)
+    fn text<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(read_body(agent, this_value, BodyType::Text, gc.into_nogc()))
+    }
+
+    /// ### [get Response.prototype.url](https://fetch.spec.whatwg.org/#dom-response-url)
+    fn get_url<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let response = require_response(agent, this_value, gc)?;
+        // The url getter steps are to return the empty string if this's
+        // response's URL is null; otherwise this's response's URL,
+        // serialized with exclude fragment set to true.
+        let url = response.url(agent).to_owned();
+        Ok(String::from_string(agent, url, gc).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.response_prototype();
+        let response_constructor = intrinsics.response();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(11)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<ResponsePrototypeArrayBuffer>()
+            .with_builtin_function_getter_property::<ResponsePrototypeGetBodyUsed>()
+            .with_constructor_property(response_constructor)
+            .with_builtin_function_getter_property::<ResponsePrototypeGetHeaders>()
+            .with_builtin_function_property::<ResponsePrototypeJson>()
+            .with_builtin_function_getter_property::<ResponsePrototypeGetOk>()
+            .with_builtin_function_getter_property::<ResponsePrototypeGetStatus>()
+            .with_builtin_function_getter_property::<ResponsePrototypeGetStatusText>()
+            .with_builtin_function_property::<ResponsePrototypeText>()
+            .with_builtin_function_getter_property::<ResponsePrototypeGetUrl>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Response.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Read the body of a Response, returning a promise. Exceptions are returned
+/// as rejected promises.
+fn read_body<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    body_type: BodyType,
+    gc: NoGcScope<'gc, '_>,
+) -> Value<'gc> {
+    let response = match require_response(agent, this_value, gc) {
+        Ok(response) => response,
+        Err(err) => return Promise::new_rejected(agent, err.value(), gc).into(),
+    };
+    let bytes = response.data_mut(agent).body.read();
+    consume_body(agent, bytes, body_type, gc)
+}
+
+fn require_response<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Response<'a>> {
+    Response::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a Response",
+            gc,
+        )
+    })
+}
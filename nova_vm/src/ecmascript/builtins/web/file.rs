@@ -0,0 +1,23 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [File API](https://w3c.github.io/FileAPI/)
+//!
+//! [`Blob`] and File. The bytes of a Blob are stored in data blocks that are
+//! shared between Blobs: slicing a Blob or creating a Blob from other Blobs
+//! does not copy their bytes. Reading a Blob with `text()` or
+//! `arrayBuffer()` completes synchronously and returns an already settled
+//! promise. Blobs cannot be read as streams.
+
+mod blob;
+mod blob_constructor;
+mod blob_prototype;
+mod file_constructor;
+mod file_prototype;
+
+pub use blob::*;
+pub(crate) use blob_constructor::*;
+pub(crate) use blob_prototype::*;
+pub(crate) use file_constructor::*;
+pub(crate) use file_prototype::*;
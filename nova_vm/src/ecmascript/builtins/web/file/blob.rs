@@ -0,0 +1,321 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::Range;
+use std::sync::Arc;
+
+use crate::{
+    ecmascript::{
+        Agent, DataBlock, EmbedderObject, JsResult, OrdinaryObject, String, Value,
+        create_byte_data_block, embedder_object_handle,
+    },
+    engine::{NoGcScope, trivially_bindable},
+};
+
+/// ### [3. The Blob Interface](https://w3c.github.io/FileAPI/#blob-section)
+///
+/// A Blob is an immutable sequence of bytes with a MIME type. A Blob created
+/// with [`Blob::new_file`] is a File: it also has a name and a last
+/// modification date.
+///
+/// Embedders hand binary data to scripts by creating a Blob with
+/// [`Blob::new`]. The bytes are copied into the Blob once; slices of the Blob
+/// and Blobs created from it share them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Blob<'a>(EmbedderObject<'a>);
+embedder_object_handle!(Blob, BlobRecord);
+
+/// A range of bytes in a data block shared between Blobs.
+#[derive(Clone)]
+pub(crate) struct BlobSegment {
+    block: Arc<DataBlock>,
+    range: Range<usize>,
+}
+
+impl BlobSegment {
+    fn as_slice(&self) -> &[u8] {
+        &self.block[self.range.clone()]
+    }
+}
+
+pub(crate) struct BlobRecord {
+    pub(super) segments: Box<[BlobSegment]>,
+    pub(super) size: usize,
+    /// The last modification date of a File in milliseconds since the Unix
+    /// epoch, or None if the Blob is not a File.
+    pub(super) last_modified: Option<i64>,
+}
+
+trivially_bindable!(BlobRecord);
+
+impl core::fmt::Debug for BlobRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BlobRecord")
+            .field("segments", &self.segments.len())
+            .field("size", &self.size)
+            .field("last_modified", &self.last_modified)
+            .finish()
+    }
+}
+
+impl BlobRecord {
+    fn new(segments: Vec<BlobSegment>, last_modified: Option<i64>) -> Self {
+        let size = segments.iter().map(|segment| segment.range.len()).sum();
+        Self {
+            segments: segments.into_boxed_slice(),
+            size,
+            last_modified,
+        }
+    }
+
+    /// Returns the segments holding the bytes of the Blob in the given range.
+    fn slice_segments(&self, range: Range<usize>) -> Vec<BlobSegment> {
+        let mut segments = Vec::new();
+        let mut offset = 0;
+        for segment in self.segments.iter() {
+            if offset >= range.end {
+                break;
+            }
+            let length = segment.range.len();
+            let start = range.start.max(offset);
+            let end = range.end.min(offset + length);
+            if start < end {
+                let block_start = segment.range.start;
+                segments.push(BlobSegment {
+                    block: segment.block.clone(),
+                    range: block_start + (start - offset)..block_start + (end - offset),
+                });
+            }
+            offset += length;
+        }
+        segments
+    }
+}
+
+/// Copy the bytes of the segments into the start of `target`.
+pub(super) fn copy_segments(segments: &[BlobSegment], target: &mut [u8]) {
+    let mut offset = 0;
+    for segment in segments {
+        let bytes = segment.as_slice();
+        target[offset..offset + bytes.len()].copy_from_slice(bytes);
+        offset += bytes.len();
+    }
+}
+
+/// The bytes of a Blob being created. Bytes pushed into the builder are
+/// copied into a single new data block while the bytes of Blobs pushed into
+/// it are shared.
+#[derive(Default)]
+pub(super) struct BlobBuilder {
+    bytes: Vec<u8>,
+    segments: Vec<PendingSegment>,
+}
+
+enum PendingSegment {
+    Bytes(Range<usize>),
+    Shared(BlobSegment),
+}
+
+impl BlobBuilder {
+    pub(super) fn push_bytes(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let start = self.bytes.len();
+        self.bytes.extend_from_slice(bytes);
+        let end = self.bytes.len();
+        if let Some(PendingSegment::Bytes(range)) = self.segments.last_mut() {
+            range.end = end;
+        } else {
+            self.segments.push(PendingSegment::Bytes(start..end));
+        }
+    }
+
+    pub(super) fn push_segments(&mut self, segments: &[BlobSegment]) {
+        self.segments
+            .extend(segments.iter().cloned().map(PendingSegment::Shared));
+    }
+
+    /// Allocate the data block for the copied bytes and create the Blob's
+    /// record.
+    pub(super) fn finish<'a>(
+        self,
+        agent: &mut Agent,
+        last_modified: Option<i64>,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, BlobRecord> {
+        let mut block = create_byte_data_block(agent, self.bytes.len() as u64, gc)?;
+        block.copy_from_slice(&self.bytes);
+        let block = Arc::new(block);
+        let segments = self
+            .segments
+            .into_iter()
+            .map(|segment| match segment {
+                PendingSegment::Bytes(range) => BlobSegment {
+                    block: block.clone(),
+                    range,
+                },
+                PendingSegment::Shared(segment) => segment,
+            })
+            .collect();
+        Ok(BlobRecord::new(segments, last_modified))
+    }
+}
+
+/// Normalize the type of a Blob: the type is lowercased, or replaced with the
+/// empty string if it contains characters outside the range U+0020 to U+007E.
+pub(super) fn normalize_type(content_type: &str) -> std::string::String {
+    if content_type.chars().all(|c| matches!(c, ' '..='~')) {
+        content_type.to_ascii_lowercase()
+    } else {
+        std::string::String::new()
+    }
+}
+
+impl<'a> Blob<'a> {
+    /// type
+    const TYPE: usize = 0;
+    /// name
+    const NAME: usize = 1;
+    pub(crate) const SLOT_COUNT: usize = 2;
+
+    /// Create a new Blob in the current Realm holding a copy of the given
+    /// bytes. The content type is normalized like the `type` option of the
+    /// Blob constructor.
+    ///
+    /// Throws a RangeError if the bytes cannot be allocated.
+    pub fn new(
+        agent: &mut Agent,
+        bytes: &[u8],
+        content_type: &str,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        let mut builder = BlobBuilder::default();
+        builder.push_bytes(bytes);
+        let record = builder.finish(agent, None, gc)?;
+        let prototype = agent.current_realm_record().intrinsics().blob_prototype();
+        Ok(Self::create(
+            agent,
+            prototype,
+            record,
+            content_type,
+            None,
+            gc,
+        ))
+    }
+
+    /// Create a new File in the current Realm holding a copy of the given
+    /// bytes. The last modification date is given in milliseconds since the
+    /// Unix epoch.
+    ///
+    /// Throws a RangeError if the bytes cannot be allocated.
+    pub fn new_file(
+        agent: &mut Agent,
+        bytes: &[u8],
+        name: &str,
+        content_type: &str,
+        last_modified: i64,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        let mut builder = BlobBuilder::default();
+        builder.push_bytes(bytes);
+        let record = builder.finish(agent, Some(last_modified), gc)?;
+        let prototype = agent.current_realm_record().intrinsics().file_prototype();
+        let name = String::from_str(agent, name, gc);
+        Ok(Self::create(
+            agent,
+            prototype,
+            record,
+            content_type,
+            Some(name),
+            gc,
+        ))
+    }
+
+    fn create(
+        agent: &mut Agent,
+        prototype: OrdinaryObject,
+        record: BlobRecord,
+        content_type: &str,
+        name: Option<String>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let blob = Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            record,
+            gc,
+        ));
+        let content_type = String::from_string(agent, normalize_type(content_type), gc);
+        blob.initialize(agent, content_type, name);
+        blob
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(super) fn initialize(self, agent: &mut Agent, content_type: String, name: Option<String>) {
+        self.0.set_slot(agent, Self::TYPE, content_type.into());
+        if let Some(name) = name {
+            self.0.set_slot(agent, Self::NAME, name.into());
+        }
+    }
+
+    /// Returns the size of the Blob in bytes.
+    pub fn size(self, agent: &Agent) -> usize {
+        self.data(agent).size
+    }
+
+    /// Returns the normalized MIME type of the Blob, or the empty string if
+    /// the type is unknown.
+    pub fn content_type(self, agent: &Agent) -> String<'a> {
+        String::try_from(self.0.get_slot(agent, Self::TYPE)).unwrap()
+    }
+
+    /// Returns true if the Blob is a File.
+    pub fn is_file(self, agent: &Agent) -> bool {
+        self.data(agent).last_modified.is_some()
+    }
+
+    /// Returns the name of the File, or None if the Blob is not a File.
+    pub fn name(self, agent: &Agent) -> Option<String<'a>> {
+        String::try_from(self.0.get_slot(agent, Self::NAME)).ok()
+    }
+
+    /// Returns the last modification date of the File in milliseconds since
+    /// the Unix epoch, or None if the Blob is not a File.
+    pub fn last_modified(self, agent: &Agent) -> Option<i64> {
+        self.data(agent).last_modified
+    }
+
+    /// Returns a copy of the bytes of the Blob.
+    pub fn to_vec(self, agent: &Agent) -> Vec<u8> {
+        let data = self.data(agent);
+        let mut bytes = vec![0; data.size];
+        copy_segments(&data.segments, &mut bytes);
+        bytes
+    }
+
+    /// Create a new Blob in the current Realm holding the bytes of this Blob
+    /// in the given range. The range is clamped to the size of this Blob.
+    ///
+    /// The new Blob shares the bytes with this Blob.
+    pub fn slice(
+        self,
+        agent: &mut Agent,
+        range: Range<usize>,
+        content_type: &str,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let data = self.data(agent);
+        let end = range.end.min(data.size);
+        let start = range.start.min(end);
+        let record = BlobRecord::new(data.slice_segments(start..end), None);
+        let prototype = agent.current_realm_record().intrinsics().blob_prototype();
+        Self::create(agent, prototype, record, content_type, None, gc)
+    }
+}
@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use ecmascript_atomics::Ordering;
+
+use crate::{
+    ecmascript::{
+        Agent, AnyArrayBuffer, AnyDataView, AnyTypedArray, ArgumentsList, BUILTIN_STRING_MEMORY,
+        Behaviour, Builtin, BuiltinIntrinsicConstructor, ExceptionType, Function, JsError,
+        JsResult, Object, ProtoIntrinsics, Realm, String, TypedArrayAbstractOperations, Value,
+        builders::BuiltinFunctionBuilder, embedder_object_create_from_constructor, get,
+        get_iterator, get_view_byte_length, is_view_out_of_bounds, iterator_to_list,
+        make_data_view_with_buffer_witness_record, throw_not_callable, to_number, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{Blob, BlobBuilder, BlobSegment, normalize_type};
+
+pub(crate) struct BlobConstructor;
+impl Builtin for BlobConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.Blob;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for BlobConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Blob;
+}
+
+impl BlobConstructor {
+    /// ### [new Blob(blobParts, options)](https://w3c.github.io/FileAPI/#constructorBlob)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let blob_parts = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Blob constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The arguments are converted to a sequence<BlobPart> and a
+        // BlobPropertyBag dictionary before the constructor steps.
+        let blob_parts = if blob_parts.is_undefined() {
+            Vec::new()
+        } else {
+            convert_blob_parts(agent, blob_parts.unbind(), gc.reborrow()).unbind()?
+        };
+        let options =
+            convert_blob_property_bag(agent, options.get(agent), false, gc.reborrow()).unbind()?;
+        // 1. If invoked with zero parameters, return a new Blob object
+        //    consisting of 0 bytes, with size set to 0, and with type set to
+        //    the empty string.
+        // 2. Let bytes be the result of processing blob parts given blobParts
+        //    and options.
+        let record = process_blob_parts(blob_parts, options.endings)
+            .finish(agent, None, gc.nogc())
+            .unbind()?;
+        // 3. If the type member of the options argument is not the empty
+        //    string, run the following sub-steps:
+        //    1. Let t be the type dictionary member. If t contains any
+        //       characters outside the range U+0020 to U+007E, then set t to
+        //       the empty string and return from these substeps.
+        //    2. Convert every character in t to ASCII lowercase.
+        let content_type = normalize_type(&options.content_type);
+        // 4. Return a Blob object referring to bytes as its associated byte
+        //    sequence, with its size set to the length of bytes, and its type
+        //    set to the value of t from the substeps above.
+        let blob = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::Blob,
+            Blob::SLOT_COUNT,
+            record,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let blob = Blob::from_embedder_object(blob.bind(gc));
+        let content_type = String::from_string(agent, content_type, gc);
+        blob.initialize(agent, content_type, None);
+        Ok(blob.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let blob_prototype = intrinsics.blob_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<BlobConstructor>(agent, realm)
+            .with_property_capacity(1)
+            .with_prototype_property(blob_prototype.into())
+            .build();
+    }
+}
+
+/// ### [BlobPart](https://w3c.github.io/FileAPI/#typedefdef-blobpart)
+pub(super) enum BlobPart {
+    /// A USVString.
+    Text(std::string::String),
+    /// A copy of the bytes held by a BufferSource.
+    Bytes(Vec<u8>),
+    /// The bytes of a Blob.
+    Blob(Box<[BlobSegment]>),
+}
+trivially_bindable!(BlobPart);
+
+/// ### [EndingType](https://w3c.github.io/FileAPI/#enumdef-endingtype)
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum EndingType {
+    Transparent,
+    Native,
+}
+
+/// ### [BlobPropertyBag](https://w3c.github.io/FileAPI/#dfn-BlobPropertyBag)
+///
+/// The lastModified member is only present in a FilePropertyBag.
+pub(super) struct BlobPropertyBag {
+    pub(super) endings: EndingType,
+    pub(super) content_type: std::string::String,
+    pub(super) last_modified: Option<i64>,
+}
+trivially_bindable!(BlobPropertyBag);
+
+/// Convert a value to a sequence<BlobPart>.
+pub(super) fn convert_blob_parts<'gc>(
+    agent: &mut Agent,
+    blob_parts: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Vec<BlobPart>> {
+    let blob_parts = blob_parts.bind(gc.nogc());
+    if !blob_parts.is_object() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "blobParts is not an object",
+            gc.into_nogc(),
+        ));
+    }
+    let Some(iterator_record) = get_iterator(agent, blob_parts.unbind(), false, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc())
+        .into_iterator_record()
+    else {
+        return Err(throw_not_callable(agent, gc.into_nogc()));
+    };
+    let values = iterator_to_list(agent, iterator_record.unbind(), gc.reborrow()).unbind()?;
+    let mut parts = Vec::with_capacity(values.len(agent));
+    for value in values.iter(agent) {
+        let value = value.get(gc.nogc());
+        let part = if let Some(blob) = Blob::try_from_value(agent, value) {
+            BlobPart::Blob(blob.data(agent).segments.clone())
+        } else if let Some(bytes) = get_buffer_source_bytes(agent, value, gc.nogc()).unbind()? {
+            BlobPart::Bytes(bytes)
+        } else {
+            let string = to_string(agent, value.unbind(), gc.reborrow()).unbind()?;
+            BlobPart::Text(string.to_string_lossy(agent).into_owned())
+        };
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+/// ### [get a copy of the bytes held by the buffer source](https://webidl.spec.whatwg.org/#dfn-get-buffer-source-copy)
+///
+/// Returns None if the value is not a BufferSource. Views of shared memory
+/// are not BufferSources and throw a TypeError.
+fn get_buffer_source_bytes<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Option<Vec<u8>>> {
+    if let Value::ArrayBuffer(buffer) = value {
+        return Ok(Some(buffer.as_slice(agent).to_vec()));
+    }
+    let (buffer, byte_offset, byte_length) = if let Ok(view) = AnyTypedArray::try_from(value) {
+        if view.is_shared() {
+            return Err(throw_shared_view(agent, gc));
+        }
+        let cached_buffer_byte_length = view.get_cached_buffer_byte_length(agent, Ordering::SeqCst);
+        let byte_length = view.typed_array_byte_length(agent, cached_buffer_byte_length);
+        (
+            view.viewed_array_buffer(agent),
+            view.byte_offset(agent),
+            byte_length,
+        )
+    } else if let Ok(view) = AnyDataView::try_from(value) {
+        #[cfg(feature = "shared-array-buffer")]
+        if let AnyDataView::SharedDataView(_) = view {
+            return Err(throw_shared_view(agent, gc));
+        }
+        let view_record = make_data_view_with_buffer_witness_record(agent, view, Ordering::SeqCst);
+        if is_view_out_of_bounds(agent, &view_record) {
+            return Ok(Some(Vec::new()));
+        }
+        (
+            view.viewed_array_buffer(agent),
+            view.byte_offset(agent),
+            get_view_byte_length(agent, &view_record),
+        )
+    } else {
+        return Ok(None);
+    };
+    if byte_length == 0 {
+        return Ok(Some(Vec::new()));
+    }
+    match buffer {
+        AnyArrayBuffer::ArrayBuffer(buffer) => Ok(Some(
+            buffer.as_slice(agent)[byte_offset..byte_offset + byte_length].to_vec(),
+        )),
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(_) => unreachable!(),
+    }
+}
+
+fn throw_shared_view<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "blobParts contains a view of a SharedArrayBuffer",
+        gc,
+    )
+}
+
+/// Convert a value to a BlobPropertyBag dictionary, or to a FilePropertyBag
+/// dictionary if `is_file` is true.
+pub(super) fn convert_blob_property_bag<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    is_file: bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, BlobPropertyBag> {
+    let options = options.bind(gc.nogc());
+    let mut property_bag = BlobPropertyBag {
+        endings: EndingType::Transparent,
+        content_type: std::string::String::new(),
+        last_modified: None,
+    };
+    let options = match options {
+        Value::Undefined | Value::Null => {
+            if is_file {
+                property_bag.last_modified = Some(now());
+            }
+            return Ok(property_bag);
+        }
+        options => match Object::try_from(options) {
+            Ok(options) => options.scope(agent, gc.nogc()),
+            Err(_) => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "options is not an object",
+                    gc.into_nogc(),
+                ));
+            }
+        },
+    };
+    let endings = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.endings.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !endings.is_undefined() {
+        let endings = to_string(agent, endings.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        property_bag.endings = if String::eq(agent, endings, BUILTIN_STRING_MEMORY.transparent) {
+            EndingType::Transparent
+        } else if String::eq(agent, endings, BUILTIN_STRING_MEMORY.native) {
+            EndingType::Native
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "endings is not a valid EndingType",
+                gc.into_nogc(),
+            ));
+        };
+    }
+    if is_file {
+        let last_modified = get(
+            agent,
+            options.get(agent),
+            BUILTIN_STRING_MEMORY.lastModified.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        property_bag.last_modified = Some(if last_modified.is_undefined() {
+            now()
+        } else {
+            // NOTE: The value is converted to a long long.
+            let last_modified = to_number(agent, last_modified.unbind(), gc.reborrow())
+                .unbind()?
+                .into_f64(agent);
+            if last_modified.is_finite() {
+                last_modified.trunc() as i64
+            } else {
+                0
+            }
+        });
+    }
+    let content_type = get(
+        agent,
+        options.get(agent),
+        BUILTIN_STRING_MEMORY.r#type.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    if !content_type.is_undefined() {
+        let content_type = to_string(agent, content_type.unbind(), gc.reborrow()).unbind()?;
+        property_bag.content_type = content_type.to_string_lossy(agent).into_owned();
+    }
+    Ok(property_bag)
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+/// ### [process blob parts](https://w3c.github.io/FileAPI/#process-blob-parts)
+pub(super) fn process_blob_parts(parts: Vec<BlobPart>, endings: EndingType) -> BlobBuilder {
+    // 1. Let bytes be an empty sequence of bytes.
+    let mut bytes = BlobBuilder::default();
+    // 2. For each element in parts:
+    for element in parts {
+        match element {
+            // 1. If element is a USVString, run the following substeps:
+            BlobPart::Text(mut s) => {
+                // 1. Let s be element.
+                // 2. If the endings member of options is "native", set s to
+                //    the result of converting line endings to native of
+                //    element.
+                if endings == EndingType::Native {
+                    s = convert_line_endings_to_native(&s);
+                }
+                // 3. Append the result of UTF-8 encoding s to bytes.
+                bytes.push_bytes(s.as_bytes());
+            }
+            // 2. If element is a BufferSource, get a copy of the bytes held
+            //    by the buffer source, and append those bytes to bytes.
+            BlobPart::Bytes(element) => bytes.push_bytes(&element),
+            // 3. If element is a Blob, append the bytes it represents to
+            //    bytes.
+            BlobPart::Blob(segments) => bytes.push_segments(&segments),
+        }
+    }
+    // 3. Return bytes.
+    bytes
+}
+
+/// ### [convert line endings to native](https://w3c.github.io/FileAPI/#convert-line-endings-to-native)
+fn convert_line_endings_to_native(s: &str) -> std::string::String {
+    // 1. Let native line ending be the code point U+000A LF.
+    // 2. If the underlying platform's conventions are to represent newlines
+    //    as a carriage return and line feed sequence, set native line ending
+    //    to the code point U+000D CR followed by the code point U+000A LF.
+    let native_line_ending = if cfg!(windows) { "\r\n" } else { "\n" };
+    // 3. Set result to the empty string.
+    let mut result = std::string::String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // If the code point at position within s equals U+000D CR:
+            '\r' => {
+                // If the next code point is U+000A LF, advance position by 1.
+                chars.next_if_eq(&'\n');
+                // Append native line ending to result.
+                result.push_str(native_line_ending);
+            }
+            // Otherwise if the code point at position within s equals U+000A
+            // LF, append native line ending to result.
+            '\n' => result.push_str(native_line_ending),
+            c => result.push(c),
+        }
+    }
+    result
+}
@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, ArrayBuffer, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinGetter, ExceptionType, JsResult, Promise, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder, to_number, to_string,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::WellKnownSymbols,
+};
+
+use super::{Blob, copy_segments};
+
+pub(crate) struct BlobPrototype;
+
+struct BlobPrototypeArrayBuffer;
+impl Builtin for BlobPrototypeArrayBuffer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.arrayBuffer;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(BlobPrototype::array_buffer);
+}
+struct BlobPrototypeGetSize;
+impl Builtin for BlobPrototypeGetSize {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_size;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.size.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(BlobPrototype::get_size);
+}
+impl BuiltinGetter for BlobPrototypeGetSize {}
+struct BlobPrototypeSlice;
+impl Builtin for BlobPrototypeSlice {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.slice;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(BlobPrototype::slice);
+}
+struct BlobPrototypeText;
+impl Builtin for BlobPrototypeText {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.text;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(BlobPrototype::text);
+}
+struct BlobPrototypeGetType;
+impl Builtin for BlobPrototypeGetType {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_type;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.r#type.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(BlobPrototype::get_type);
+}
+impl BuiltinGetter for BlobPrototypeGetType {}
+
+impl BlobPrototype {
+    /// ### [Blob.prototype.arrayBuffer()](https://w3c.github.io/FileAPI/#dom-blob-arraybuffer)
+    fn array_buffer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // NOTE: Exceptions are returned as rejected promises.
+        let blob = match require_blob(agent, this_value, gc) {
+            Ok(blob) => blob,
+            Err(err) => return Ok(Promise::new_rejected(agent, err.value(), gc).into()),
+        };
+        // 1. Let stream be the result of calling get stream on this.
+        // 2. Let reader be the result of getting a reader from stream.
+        // 3. Let promise be the result of reading all bytes from stream with
+        //    reader.
+        // 4. Return the result of transforming promise by a fulfillment
+        //    handler that returns a new ArrayBuffer whose contents are its
+        //    first argument.
+        // NOTE: The bytes are copied into the ArrayBuffer directly.
+        let buffer = match ArrayBuffer::new(agent, blob.size(agent), gc) {
+            Ok(buffer) => buffer,
+            Err(err) => return Ok(Promise::new_rejected(agent, err.value(), gc).into()),
+        };
+        let segments = blob.data(agent).segments.clone();
+        copy_segments(&segments, buffer.as_mut_slice(agent));
+        Ok(Promise::new_resolved(agent, buffer.into()).into())
+    }
+
+    /// ### [Blob.prototype.size](https://w3c.github.io/FileAPI/#dfn-size)
+    fn get_size<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let blob = require_blob(agent, this_value, gc)?;
+        let size = blob.size(agent) as i64;
+        Ok(Value::from_i64(agent, size, gc))
+    }
+
+    /// ### [Blob.prototype.slice(start, end, contentType)](https://w3c.github.io/FileAPI/#dfn-slice)
+    fn slice<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let start = arguments.get(0).bind(gc.nogc());
+        let end = arguments.get(1).scope(agent, gc.nogc());
+        let content_type = arguments.get(2).scope(agent, gc.nogc());
+        let blob = require_blob(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // NOTE: The start and end arguments are converted to [Clamp] long
+        // long values and contentType is converted to a DOMString.
+        let start = to_clamped_long_long(agent, start.unbind(), gc.reborrow()).unbind()?;
+        let end = to_clamped_long_long(agent, end.get(agent), gc.reborrow()).unbind()?;
+        let content_type = match content_type.get(agent).bind(gc.nogc()) {
+            Value::Undefined => None,
+            content_type => Some(
+                to_string(agent, content_type.unbind(), gc.reborrow())
+                    .unbind()?
+                    .to_string_lossy(agent)
+                    .into_owned(),
+            ),
+        };
+        let gc = gc.into_nogc();
+        let blob = blob.get(agent).bind(gc);
+        let size = blob.size(agent) as i64;
+        // 1. Let sliceStart, sliceEnd, and sliceContentType be null.
+        // 2. If start is given, set sliceStart to start.
+        // 3. If end is given, set sliceEnd to end.
+        // 4. If contentType is given, set sliceContentType to contentType.
+        // 5. Return the result of slice blob given this, sliceStart,
+        //    sliceEnd, and sliceContentType.
+
+        // ### [slice blob](https://w3c.github.io/FileAPI/#slice-blob)
+        // 1. Let originalSize be blob's size.
+        // 2. The start parameter, if non-null, is a value for the start point
+        //    of a slice blob call, and must be treated as a byte-order
+        //    position, with the zeroth position representing the first byte.
+        //    User agents must normalize start according to the following:
+        let relative_start = match start {
+            // a. If start is null, let relativeStart be 0.
+            None => 0,
+            // b. If start is negative, let relativeStart be
+            //    max((originalSize + start), 0).
+            Some(start) if start < 0 => (size + start).max(0),
+            // c. Otherwise, let relativeStart be min(start, originalSize).
+            Some(start) => start.min(size),
+        };
+        // 3. The end parameter, if non-null. is a value for the end point of
+        //    a slice blob call. User agents must normalize end according to
+        //    the following:
+        let relative_end = match end {
+            // a. If end is null, let relativeEnd be originalSize.
+            None => size,
+            // b. If end is negative, let relativeEnd be
+            //    max((originalSize + end), 0).
+            Some(end) if end < 0 => (size + end).max(0),
+            // c. Otherwise, let relativeEnd be min(end, originalSize).
+            Some(end) => end.min(size),
+        };
+        // 4. The contentType parameter, if non-null, is used to set the ASCII
+        //    -encoded string in lower case representing the media type of the
+        //    Blob. User agents must normalize contentType according to the
+        //    following:
+        //    a. If contentType is null, let relativeContentType be set to the
+        //       empty string.
+        //    b. Otherwise, let relativeContentType be set to contentType and
+        //       run the substeps below:
+        //       1. If relativeContentType contains any characters outside the
+        //          range of U+0020 to U+007E, then set relativeContentType to
+        //          the empty string and return from these substeps.
+        //       2. Convert every character in relativeContentType to ASCII
+        //          lowercase.
+        // NOTE: Blob::slice normalizes the content type.
+        let relative_content_type = content_type.unwrap_or_default();
+        // 5. Let span be max((relativeEnd - relativeStart), 0).
+        let span = (relative_end - relative_start).max(0);
+        // 6. Return a new Blob object S with the following characteristics:
+        //    a. S refers to span consecutive bytes from blob's associated byte
+        //       sequence, beginning with the byte at byte-order position
+        //       relativeStart.
+        //    b. S.size = span.
+        //    c. S.type = relativeContentType.
+        let start = relative_start as usize;
+        let end = start + span as usize;
+        Ok(blob
+            .slice(agent, start..end, &relative_content_type, gc)
+            .into())
+    }
+
+    /// ### [Blob.prototype.text()](https://w3c.github.io/FileAPI/#dom-blob-text)
+    fn text<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // NOTE: Exceptions are returned as rejected promises.
+        let blob = match require_blob(agent, this_value, gc) {
+            Ok(blob) => blob,
+            Err(err) => return Ok(Promise::new_rejected(agent, err.value(), gc).into()),
+        };
+        // 1. Let stream be the result of calling get stream on this.
+        // 2. Let reader be the result of getting a reader from stream.
+        // 3. Let promise be the result of reading all bytes from stream with
+        //    reader.
+        // 4. Return the result of transforming promise by a fulfillment
+        //    handler that returns the result of running UTF-8 decode on its
+        //    first argument.
+        let bytes = blob.to_vec(agent);
+        let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&bytes);
+        let text = std::string::String::from_utf8_lossy(bytes).into_owned();
+        let text = String::from_string(agent, text, gc);
+        Ok(Promise::new_resolved(agent, text.into()).into())
+    }
+
+    /// ### [Blob.prototype.type](https://w3c.github.io/FileAPI/#dfn-type)
+    fn get_type<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let blob = require_blob(agent, this_value, gc)?;
+        Ok(blob.content_type(agent).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.blob_prototype();
+        let blob_constructor = intrinsics.blob();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(7)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<BlobPrototypeArrayBuffer>()
+            .with_constructor_property(blob_constructor)
+            .with_builtin_function_getter_property::<BlobPrototypeGetSize>()
+            .with_builtin_function_property::<BlobPrototypeSlice>()
+            .with_builtin_function_property::<BlobPrototypeText>()
+            .with_builtin_function_getter_property::<BlobPrototypeGetType>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Blob.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_blob<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Blob<'a>> {
+    Blob::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a Blob",
+            gc,
+        )
+    })
+}
+
+/// Convert a value to a \[Clamp\] long long, or None if the value is
+/// undefined.
+fn to_clamped_long_long<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<i64>> {
+    /// 2^53 - 1
+    const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let number = to_number(agent, value, gc)?.into_f64(agent);
+    if number.is_nan() {
+        return Ok(Some(0));
+    }
+    Ok(Some(
+        number
+            .clamp(-MAX_SAFE_INTEGER, MAX_SAFE_INTEGER)
+            .round_ties_even() as i64,
+    ))
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor, to_string,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{
+    Blob, convert_blob_parts, convert_blob_property_bag, normalize_type, process_blob_parts,
+};
+
+pub(crate) struct FileConstructor;
+impl Builtin for FileConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.File;
+
+    const LENGTH: u8 = 2;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for FileConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::File;
+}
+
+impl FileConstructor {
+    /// ### [new File(fileBits, fileName, options)](https://w3c.github.io/FileAPI/#file-constructor)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let file_bits = arguments.get(0).bind(gc.nogc());
+        let file_name = arguments.get(1).scope(agent, gc.nogc());
+        let options = arguments.get(2).scope(agent, gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin File constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        if arguments.len() < 2 {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "File constructor requires fileBits and fileName arguments",
+                gc.into_nogc(),
+            ));
+        }
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The arguments are converted to a sequence<BlobPart>, a
+        // USVString, and a FilePropertyBag dictionary before the constructor
+        // steps.
+        let file_bits = convert_blob_parts(agent, file_bits.unbind(), gc.reborrow()).unbind()?;
+        let file_name = to_string(agent, file_name.get(agent), gc.reborrow())
+            .unbind()?
+            .to_string_lossy(agent)
+            .into_owned();
+        let options =
+            convert_blob_property_bag(agent, options.get(agent), true, gc.reborrow()).unbind()?;
+        // 1. Let bytes be the result of processing blob parts given fileBits
+        //    and options.
+        // 2. Let n be the fileName argument to the constructor.
+        // 3. Process FilePropertyBag dictionary argument by running the
+        //    following substeps:
+        //    1. If the type member is provided and is not the empty string,
+        //       let t be set to the type dictionary member. If t contains any
+        //       characters outside the range U+0020 to U+007E, then set t to
+        //       the empty string and return from these substeps.
+        //    2. Convert every character in t to ASCII lowercase.
+        //    3. If the lastModified member is provided, let d be set to the
+        //       lastModified dictionary member. If it is not provided, set d
+        //       to the current date and time represented as the number of
+        //       milliseconds since the Unix Epoch.
+        let record = process_blob_parts(file_bits, options.endings)
+            .finish(agent, options.last_modified, gc.nogc())
+            .unbind()?;
+        let content_type = normalize_type(&options.content_type);
+        // 4. Return a new File object F such that:
+        //    1. F refers to the bytes byte sequence.
+        //    2. F.size is set to the number of total bytes in bytes.
+        //    3. F.name is set to n.
+        //    4. F.type is set to t.
+        //    5. F.lastModified is set to d.
+        let file = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::File,
+            Blob::SLOT_COUNT,
+            record,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let file = Blob::from_embedder_object(file.bind(gc));
+        let content_type = String::from_string(agent, content_type, gc);
+        let file_name = String::from_string(agent, file_name, gc);
+        file.initialize(agent, content_type, Some(file_name));
+        Ok(file.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let blob_constructor = intrinsics.blob();
+        let file_prototype = intrinsics.file_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<FileConstructor>(agent, realm)
+            .with_property_capacity(1)
+            .with_prototype(blob_constructor)
+            .with_prototype_property(file_prototype.into())
+            .build();
+    }
+}
@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::Blob;
+
+pub(crate) struct FilePrototype;
+
+struct FilePrototypeGetLastModified;
+impl Builtin for FilePrototypeGetLastModified {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_lastModified;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.lastModified.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(FilePrototype::get_last_modified);
+}
+impl BuiltinGetter for FilePrototypeGetLastModified {}
+struct FilePrototypeGetName;
+impl Builtin for FilePrototypeGetName {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_name;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.name.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(FilePrototype::get_name);
+}
+impl BuiltinGetter for FilePrototypeGetName {}
+
+impl FilePrototype {
+    /// ### [File.prototype.lastModified](https://w3c.github.io/FileAPI/#dfn-lastModified)
+    fn get_last_modified<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let file = require_file(agent, this_value, gc)?;
+        let last_modified = file.last_modified(agent).unwrap();
+        Ok(Value::from_i64(agent, last_modified, gc))
+    }
+
+    /// ### [File.prototype.name](https://w3c.github.io/FileAPI/#dfn-name)
+    fn get_name<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let file = require_file(agent, this_value, gc)?;
+        Ok(file.name(agent).unwrap().into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let blob_prototype = intrinsics.blob_prototype();
+        let this = intrinsics.file_prototype();
+        let file_constructor = intrinsics.file();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(4)
+            .with_prototype(blob_prototype)
+            .with_constructor_property(file_constructor)
+            .with_builtin_function_getter_property::<FilePrototypeGetLastModified>()
+            .with_builtin_function_getter_property::<FilePrototypeGetName>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.File.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_file<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Blob<'a>> {
+    match Blob::try_from_value(agent, value.bind(gc)) {
+        Some(file) if file.is_file(agent) => Ok(file),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a File",
+            gc,
+        )),
+    }
+}
@@ -0,0 +1,27 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Streams](https://streams.spec.whatwg.org/)
+//!
+//! A minimal subset of the Streams Standard: [`ReadableStream`] with a
+//! default controller and default readers. Byte streams, BYOB readers, custom
+//! queuing strategy size functions, piping, and teeing are not supported.
+
+mod abstract_operations;
+mod readable_stream;
+mod readable_stream_constructor;
+mod readable_stream_default_controller_constructor;
+mod readable_stream_default_controller_prototype;
+mod readable_stream_default_reader_constructor;
+mod readable_stream_default_reader_prototype;
+mod readable_stream_prototype;
+
+pub(crate) use abstract_operations::*;
+pub use readable_stream::*;
+pub(crate) use readable_stream_constructor::*;
+pub(crate) use readable_stream_default_controller_constructor::*;
+pub(crate) use readable_stream_default_controller_prototype::*;
+pub(crate) use readable_stream_default_reader_constructor::*;
+pub(crate) use readable_stream_default_reader_prototype::*;
+pub(crate) use readable_stream_prototype::*;
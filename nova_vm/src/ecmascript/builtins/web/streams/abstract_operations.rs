@@ -0,0 +1,827 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [4.9 Abstract operations](https://streams.spec.whatwg.org/#rs-abstract-ops)
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, EmbedderObject, ExceptionType, Function, JsResult, Promise,
+        PromiseCapability, PromiseReactionHandler, Value, call_function, create_iter_result_object,
+        inner_promise_then,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+};
+
+use super::{
+    ReadableStream, ReadableStreamDefaultController, ReadableStreamDefaultReader,
+    ReadableStreamDefaultReaderRecord, ReadableStreamReaction, ReadableStreamState,
+};
+
+/// ### [AcquireReadableStreamDefaultReader ( stream )](https://streams.spec.whatwg.org/#acquire-readable-stream-reader)
+pub(crate) fn acquire_readable_stream_default_reader<'a>(
+    agent: &mut Agent,
+    stream: ReadableStream,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ReadableStreamDefaultReader<'a>> {
+    let stream = stream.bind(gc);
+    // 1. Let reader be a new ReadableStreamDefaultReader.
+    let prototype = agent
+        .current_realm_record()
+        .intrinsics()
+        .readable_stream_default_reader_prototype();
+    let reader = ReadableStreamDefaultReader::from_embedder_object(EmbedderObject::new(
+        agent,
+        Some(prototype.into()),
+        &[Value::Undefined; ReadableStreamDefaultReader::SLOT_COUNT],
+        ReadableStreamDefaultReaderRecord {
+            read_requests_head: 0,
+        },
+        gc,
+    ));
+    // 2. Perform ? SetUpReadableStreamDefaultReader(reader, stream).
+    set_up_readable_stream_default_reader(agent, reader, stream, gc)?;
+    // 3. Return reader.
+    Ok(reader)
+}
+
+/// ### [ReadableStreamCancel ( stream, reason )](https://streams.spec.whatwg.org/#readable-stream-cancel)
+pub(crate) fn readable_stream_cancel<'gc>(
+    agent: &mut Agent,
+    stream: ReadableStream,
+    reason: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let stream = stream.bind(gc.nogc());
+    let reason = reason.bind(gc.nogc());
+    // 1. Set stream.[[disturbed]] to true.
+    stream.data_mut(agent).disturbed = true;
+    match stream.state(agent) {
+        // 2. If stream.[[state]] is "closed", return a promise resolved with
+        //    undefined.
+        ReadableStreamState::Closed => return Promise::new_resolved(agent, Value::Undefined),
+        // 3. If stream.[[state]] is "errored", return a promise rejected with
+        //    stream.[[storedError]].
+        ReadableStreamState::Errored => {
+            let stored_error = stream.stored_error(agent);
+            return Promise::new_rejected(agent, stored_error.unbind(), gc.into_nogc());
+        }
+        ReadableStreamState::Readable => {}
+    }
+    // 4. Perform ! ReadableStreamClose(stream).
+    readable_stream_close(agent, stream, gc.nogc());
+    // 5. Let reader be stream.[[reader]].
+    // 6. If reader is not undefined and reader implements
+    //    ReadableStreamBYOBReader, ...
+    // NOTE: BYOB readers are not supported.
+    // 7. Let sourceCancelPromise be ! stream.[[controller]].[[CancelSteps]](reason).
+    let controller = stream.controller(agent);
+    let source_cancel_promise = readable_stream_default_controller_cancel_steps(
+        agent,
+        controller.unbind(),
+        reason.unbind(),
+        gc.reborrow(),
+    )
+    .unbind();
+    let gc = gc.into_nogc();
+    let source_cancel_promise = source_cancel_promise.bind(gc);
+    // 8. Return the result of reacting to sourceCancelPromise with a
+    //    fulfillment step that returns undefined.
+    let capability = PromiseCapability::new(agent, gc);
+    let handler = PromiseReactionHandler::ReadableStream(ReadableStreamReaction::Cancel);
+    inner_promise_then(
+        agent,
+        source_cancel_promise,
+        handler,
+        handler,
+        Some(capability.clone()),
+        gc,
+    );
+    capability.promise()
+}
+
+/// ### [ReadableStreamClose ( stream )](https://streams.spec.whatwg.org/#readable-stream-close)
+pub(crate) fn readable_stream_close(agent: &mut Agent, stream: ReadableStream, gc: NoGcScope) {
+    let stream = stream.bind(gc);
+    // 1. Assert: stream.[[state]] is "readable".
+    debug_assert_eq!(stream.state(agent), ReadableStreamState::Readable);
+    // 2. Set stream.[[state]] to "closed".
+    stream.data_mut(agent).state = ReadableStreamState::Closed;
+    // 3. Let reader be stream.[[reader]].
+    // 4. If reader is undefined, return.
+    let Some(reader) = stream.reader(agent) else {
+        return;
+    };
+    // 5. Resolve reader.[[closedPromise]] with undefined.
+    let closed_promise = reader.closed_promise(agent);
+    if closed_promise.try_get_result(agent, gc).is_none() {
+        PromiseCapability::from_promise(closed_promise, true).internal_fulfill(
+            agent,
+            Value::Undefined,
+            gc,
+        );
+    }
+    // 6. If reader implements ReadableStreamDefaultReader,
+    // a. Let readRequests be reader.[[readRequests]].
+    // b. Set reader.[[readRequests]] to an empty list.
+    let read_requests = reader.take_read_requests(agent);
+    // c. For each readRequest of readRequests,
+    for read_request in read_requests {
+        // i. Perform readRequest's close steps.
+        read_request_close_steps(agent, read_request, gc);
+    }
+}
+
+/// ### [ReadableStreamError ( stream, e )](https://streams.spec.whatwg.org/#readable-stream-error)
+pub(crate) fn readable_stream_error(
+    agent: &mut Agent,
+    stream: ReadableStream,
+    e: Value,
+    gc: NoGcScope,
+) {
+    let stream = stream.bind(gc);
+    let e = e.bind(gc);
+    // 1. Assert: stream.[[state]] is "readable".
+    debug_assert_eq!(stream.state(agent), ReadableStreamState::Readable);
+    // 2. Set stream.[[state]] to "errored".
+    stream.data_mut(agent).state = ReadableStreamState::Errored;
+    // 3. Set stream.[[storedError]] to e.
+    stream.set_stored_error(agent, e);
+    // 4. Let reader be stream.[[reader]].
+    // 5. If reader is undefined, return.
+    let Some(reader) = stream.reader(agent) else {
+        return;
+    };
+    // 6. Reject reader.[[closedPromise]] with e.
+    let closed_promise = reader.closed_promise(agent);
+    PromiseCapability::from_promise(closed_promise, true).reject(agent, e, gc);
+    // 7. Set reader.[[closedPromise]].[[PromiseIsHandled]] to true.
+    closed_promise.set_handled(agent);
+    // 8. If reader implements ReadableStreamDefaultReader,
+    // a. Perform ! ReadableStreamDefaultReaderErrorReadRequests(reader, e).
+    readable_stream_default_reader_error_read_requests(agent, reader, e, gc);
+}
+
+/// ### [ReadableStreamFulfillReadRequest ( stream, chunk, done )](https://streams.spec.whatwg.org/#readable-stream-fulfill-read-request)
+fn readable_stream_fulfill_read_request(
+    agent: &mut Agent,
+    stream: ReadableStream,
+    chunk: Value,
+    done: bool,
+    gc: NoGcScope,
+) {
+    // 1. Assert: ! ReadableStreamHasDefaultReader(stream) is true.
+    // 2. Let reader be stream.[[reader]].
+    let reader = stream.bind(gc).reader(agent).unwrap();
+    // 3. Assert: reader.[[readRequests]] is not empty.
+    // 4. Let readRequest be reader.[[readRequests]][0].
+    // 5. Remove readRequest from reader.[[readRequests]].
+    let read_request = reader.take_first_read_request(agent).unwrap();
+    if done {
+        // 6. If done is true, perform readRequest's close steps.
+        read_request_close_steps(agent, read_request, gc);
+    } else {
+        // 7. Otherwise, perform readRequest's chunk steps, given chunk.
+        read_request_chunk_steps(agent, read_request, chunk, gc);
+    }
+}
+
+/// ### [ReadableStreamGetNumReadRequests ( stream )](https://streams.spec.whatwg.org/#readable-stream-get-num-read-requests)
+fn readable_stream_get_num_read_requests(agent: &Agent, stream: ReadableStream) -> u32 {
+    // 1. Assert: ! ReadableStreamHasDefaultReader(stream) is true.
+    // 2. Return stream.[[reader]].[[readRequests]]'s size.
+    stream
+        .reader(agent)
+        .map_or(0, |reader| reader.num_read_requests(agent))
+}
+
+/// Read requests are represented by the promise returned from the
+/// `read()` call that created them.
+///
+/// > NOTE: The iterator result object is a new ordinary object, so the promise
+/// > is fulfilled with it directly instead of resolved.
+fn read_request_chunk_steps(agent: &mut Agent, read_request: Promise, chunk: Value, gc: NoGcScope) {
+    // chunk steps, given chunk
+    // 1. Resolve promise with «[ "value" → chunk, "done" → false ]».
+    settle_read_request(agent, read_request, chunk, false, gc);
+}
+
+fn read_request_close_steps(agent: &mut Agent, read_request: Promise, gc: NoGcScope) {
+    // close steps
+    // 1. Resolve promise with «[ "value" → undefined, "done" → true ]».
+    settle_read_request(agent, read_request, Value::Undefined, true, gc);
+}
+
+fn read_request_error_steps(agent: &mut Agent, read_request: Promise, e: Value, gc: NoGcScope) {
+    // error steps, given e
+    // 1. Reject promise with e.
+    PromiseCapability::from_promise(read_request, true).reject(agent, e, gc);
+}
+
+fn settle_read_request(
+    agent: &mut Agent,
+    read_request: Promise,
+    value: Value,
+    done: bool,
+    gc: NoGcScope,
+) {
+    let capability = PromiseCapability::from_promise(read_request.bind(gc), true);
+    match create_iter_result_object(agent, value.bind(gc), done, gc) {
+        Ok(result) => capability.internal_fulfill(agent, result.into(), gc),
+        Err(err) => capability.reject(agent, err.value(), gc),
+    }
+}
+
+/// ### [ReadableStreamDefaultReaderRead ( reader, readRequest )](https://streams.spec.whatwg.org/#readable-stream-default-reader-read)
+pub(crate) fn readable_stream_default_reader_read<'gc>(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    read_request: Promise,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let reader = reader.bind(gc.nogc());
+    let read_request = read_request.bind(gc.nogc());
+    // 1. Let stream be reader.[[stream]].
+    // 2. Assert: stream is not undefined.
+    let stream = reader.stream(agent).unwrap();
+    // 3. Set stream.[[disturbed]] to true.
+    stream.data_mut(agent).disturbed = true;
+    match stream.state(agent) {
+        // 4. If stream.[[state]] is "closed", perform readRequest's close steps.
+        ReadableStreamState::Closed => read_request_close_steps(agent, read_request, gc.nogc()),
+        // 5. Otherwise, if stream.[[state]] is "errored", perform
+        //    readRequest's error steps given stream.[[storedError]].
+        ReadableStreamState::Errored => {
+            let stored_error = stream.stored_error(agent);
+            read_request_error_steps(agent, read_request, stored_error, gc.nogc());
+        }
+        // 6. Otherwise,
+        ReadableStreamState::Readable => {
+            // a. Assert: stream.[[state]] is "readable".
+            // b. Perform ! stream.[[controller]].[[PullSteps]](readRequest).
+            let controller = stream.controller(agent);
+            return readable_stream_default_controller_pull_steps(
+                agent,
+                controller.unbind(),
+                read_request.unbind(),
+                gc,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// ### [ReadableStreamDefaultReaderRelease ( reader )](https://streams.spec.whatwg.org/#abstract-opdef-readablestreamdefaultreaderrelease)
+pub(crate) fn readable_stream_default_reader_release(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    gc: NoGcScope,
+) {
+    let reader = reader.bind(gc);
+    // 1. Perform ! ReadableStreamReaderGenericRelease(reader).
+    readable_stream_reader_generic_release(agent, reader, gc);
+    // 2. Let e be a new TypeError exception.
+    let e = agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "ReadableStreamDefaultReader was released",
+        gc,
+    );
+    // 3. Perform ! ReadableStreamDefaultReaderErrorReadRequests(reader, e).
+    readable_stream_default_reader_error_read_requests(agent, reader, e.value(), gc);
+}
+
+/// ### [ReadableStreamDefaultReaderErrorReadRequests ( reader, e )](https://streams.spec.whatwg.org/#abstract-opdef-readablestreamdefaultreadererrorreadrequests)
+fn readable_stream_default_reader_error_read_requests(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    e: Value,
+    gc: NoGcScope,
+) {
+    // 1. Let readRequests be reader.[[readRequests]].
+    // 2. Set reader.[[readRequests]] to a new empty list.
+    let read_requests = reader.bind(gc).take_read_requests(agent);
+    // 3. For each readRequest of readRequests,
+    for read_request in read_requests {
+        // a. Perform readRequest's error steps, given e.
+        read_request_error_steps(agent, read_request, e, gc);
+    }
+}
+
+/// ### [ReadableStreamReaderGenericRelease ( reader )](https://streams.spec.whatwg.org/#readable-stream-reader-generic-release)
+fn readable_stream_reader_generic_release(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    gc: NoGcScope,
+) {
+    let reader = reader.bind(gc);
+    // 1. Let stream be reader.[[stream]].
+    // 2. Assert: stream is not undefined.
+    let stream = reader.stream(agent).unwrap();
+    // 3. Assert: stream.[[reader]] is reader.
+    debug_assert_eq!(stream.reader(agent), Some(reader));
+    let e = agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "ReadableStreamDefaultReader was released",
+        gc,
+    );
+    let closed_promise = if stream.state(agent) == ReadableStreamState::Readable {
+        // 4. If stream.[[state]] is "readable", reject reader.[[closedPromise]]
+        //    with a TypeError exception.
+        let closed_promise = reader.closed_promise(agent);
+        PromiseCapability::from_promise(closed_promise, true).reject(agent, e.value(), gc);
+        closed_promise
+    } else {
+        // 5. Otherwise, set reader.[[closedPromise]] to a promise rejected
+        //    with a TypeError exception.
+        let closed_promise = Promise::new_rejected(agent, e.value(), gc);
+        reader.set_closed_promise(agent, closed_promise);
+        closed_promise
+    };
+    // 6. Set reader.[[closedPromise]].[[PromiseIsHandled]] to true.
+    closed_promise.set_handled(agent);
+    // 7. Perform ! stream.[[controller]].[[ReleaseSteps]]().
+    // NOTE: The release steps of a default controller do nothing.
+    // 8. Set stream.[[reader]] to undefined.
+    stream.set_reader(agent, None);
+    // 9. Set reader.[[stream]] to undefined.
+    reader.set_stream(agent, None);
+}
+
+/// ### [SetUpReadableStreamDefaultReader ( reader, stream )](https://streams.spec.whatwg.org/#set-up-readable-stream-default-reader)
+pub(crate) fn set_up_readable_stream_default_reader<'a>(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    stream: ReadableStream,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let reader = reader.bind(gc);
+    let stream = stream.bind(gc);
+    // 1. If ! IsReadableStreamLocked(stream) is true, throw a TypeError
+    //    exception.
+    if stream.is_locked(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "ReadableStream is locked",
+            gc,
+        ));
+    }
+    // 2. Perform ! ReadableStreamReaderGenericInitialize(reader, stream).
+    readable_stream_reader_generic_initialize(agent, reader, stream, gc);
+    // 3. Set reader.[[readRequests]] to a new empty list.
+    Ok(())
+}
+
+/// ### [ReadableStreamReaderGenericInitialize ( reader, stream )](https://streams.spec.whatwg.org/#readable-stream-reader-generic-initialize)
+fn readable_stream_reader_generic_initialize(
+    agent: &mut Agent,
+    reader: ReadableStreamDefaultReader,
+    stream: ReadableStream,
+    gc: NoGcScope,
+) {
+    // 1. Set reader.[[stream]] to stream.
+    reader.set_stream(agent, Some(stream));
+    // 2. Set stream.[[reader]] to reader.
+    stream.set_reader(agent, Some(reader));
+    let closed_promise = match stream.state(agent) {
+        // 3. If stream.[[state]] is "readable",
+        // a. Set reader.[[closedPromise]] to a new promise.
+        ReadableStreamState::Readable => PromiseCapability::new(agent, gc).promise(),
+        // 4. Otherwise, if stream.[[state]] is "closed",
+        // a. Set reader.[[closedPromise]] to a promise resolved with undefined.
+        ReadableStreamState::Closed => Promise::new_resolved(agent, Value::Undefined),
+        // 5. Otherwise,
+        ReadableStreamState::Errored => {
+            // a. Assert: stream.[[state]] is "errored".
+            // b. Set reader.[[closedPromise]] to a promise rejected with
+            //    stream.[[storedError]].
+            let stored_error = stream.stored_error(agent);
+            let closed_promise = Promise::new_rejected(agent, stored_error, gc);
+            // c. Set reader.[[closedPromise]].[[PromiseIsHandled]] to true.
+            closed_promise.set_handled(agent);
+            closed_promise
+        }
+    };
+    reader.set_closed_promise(agent, closed_promise);
+}
+
+/// ### [ReadableStreamDefaultControllerCallPullIfNeeded ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-call-pull-if-needed)
+pub(crate) fn readable_stream_default_controller_call_pull_if_needed(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    mut gc: GcScope,
+) {
+    let controller = controller.bind(gc.nogc());
+    // 1. Let shouldPull be ! ReadableStreamDefaultControllerShouldCallPull(controller).
+    // 2. If shouldPull is false, return.
+    if !readable_stream_default_controller_should_call_pull(agent, controller) {
+        return;
+    }
+    let data = controller.data_mut(agent);
+    // 3. If controller.[[pulling]] is true,
+    if data.pulling {
+        // a. Set controller.[[pullAgain]] to true.
+        data.pull_again = true;
+        // b. Return.
+        return;
+    }
+    // 4. Assert: controller.[[pullAgain]] is false.
+    debug_assert!(!data.pull_again);
+    // 5. Set controller.[[pulling]] to true.
+    data.pulling = true;
+    // 6. Let pullPromise be the result of performing controller.[[pullAlgorithm]].
+    let scoped_controller = controller.scope(agent, gc.nogc());
+    let pull_promise = readable_stream_default_controller_pull_algorithm(
+        agent,
+        controller.unbind(),
+        gc.reborrow(),
+    )
+    .unbind();
+    let gc = gc.into_nogc();
+    let pull_promise = pull_promise.bind(gc);
+    // SAFETY: scoped_controller is not shared.
+    let controller = unsafe { scoped_controller.take(agent) }.bind(gc);
+    // 7. Upon fulfillment of pullPromise, ...
+    // 8. Upon rejection of pullPromise with reason e, ...
+    let handler = PromiseReactionHandler::ReadableStream(ReadableStreamReaction::Pull(controller));
+    inner_promise_then(agent, pull_promise, handler, handler, None, gc);
+}
+
+/// ### [ReadableStreamDefaultControllerShouldCallPull ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-should-call-pull)
+fn readable_stream_default_controller_should_call_pull(
+    agent: &Agent,
+    controller: ReadableStreamDefaultController,
+) -> bool {
+    // 1. Let stream be controller.[[stream]].
+    let stream = controller.stream(agent);
+    // 2. If ! ReadableStreamDefaultControllerCanCloseOrEnqueue(controller) is
+    //    false, return false.
+    if !readable_stream_default_controller_can_close_or_enqueue(agent, controller) {
+        return false;
+    }
+    // 3. If controller.[[started]] is false, return false.
+    if !controller.data(agent).started {
+        return false;
+    }
+    // 4. If ! IsReadableStreamLocked(stream) is true and !
+    //    ReadableStreamGetNumReadRequests(stream) > 0, return true.
+    if stream.is_locked(agent) && readable_stream_get_num_read_requests(agent, stream) > 0 {
+        return true;
+    }
+    // 5. Let desiredSize be ! ReadableStreamDefaultControllerGetDesiredSize(controller).
+    // 6. Assert: desiredSize is not null.
+    let desired_size = readable_stream_default_controller_get_desired_size(agent, controller)
+        .expect("stream is errored");
+    // 7. If desiredSize > 0, return true.
+    // 8. Return false.
+    desired_size > 0.0
+}
+
+/// ### [ReadableStreamDefaultControllerClose ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-close)
+pub(crate) fn readable_stream_default_controller_close(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    gc: NoGcScope,
+) {
+    let controller = controller.bind(gc);
+    // 1. If ! ReadableStreamDefaultControllerCanCloseOrEnqueue(controller) is
+    //    false, return.
+    if !readable_stream_default_controller_can_close_or_enqueue(agent, controller) {
+        return;
+    }
+    // 2. Let stream be controller.[[stream]].
+    let stream = controller.stream(agent);
+    // 3. Set controller.[[closeRequested]] to true.
+    controller.data_mut(agent).close_requested = true;
+    // 4. If controller.[[queue]] is empty,
+    if controller.queue_total_size(agent) == 0 {
+        // a. Perform ! ReadableStreamDefaultControllerClearAlgorithms(controller).
+        controller.clear_algorithms(agent);
+        // b. Perform ! ReadableStreamClose(stream).
+        readable_stream_close(agent, stream, gc);
+    }
+}
+
+/// ### [ReadableStreamDefaultControllerEnqueue ( controller, chunk )](https://streams.spec.whatwg.org/#readable-stream-default-controller-enqueue)
+pub(crate) fn readable_stream_default_controller_enqueue<'gc>(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    chunk: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let controller = controller.bind(gc.nogc());
+    let chunk = chunk.bind(gc.nogc());
+    // 1. If ! ReadableStreamDefaultControllerCanCloseOrEnqueue(controller) is
+    //    false, return.
+    if !readable_stream_default_controller_can_close_or_enqueue(agent, controller) {
+        return Ok(());
+    }
+    // 2. Let stream be controller.[[stream]].
+    let stream = controller.stream(agent);
+    // 3. If ! IsReadableStreamLocked(stream) is true and !
+    //    ReadableStreamGetNumReadRequests(stream) > 0, perform !
+    //    ReadableStreamFulfillReadRequest(stream, chunk, false).
+    if stream.is_locked(agent) && readable_stream_get_num_read_requests(agent, stream) > 0 {
+        readable_stream_fulfill_read_request(agent, stream, chunk, false, gc.nogc());
+    } else {
+        // 4. Otherwise,
+        // a. Let result be the result of performing
+        //    controller.[[strategySizeAlgorithm]], passing in chunk, and
+        //    interpreting the result as a completion record.
+        // b. If result is an abrupt completion, ...
+        // c. Let chunkSize be result.[[Value]].
+        // NOTE: Custom size algorithms are not supported: the size of every
+        // chunk is 1.
+        // d. Let enqueueResult be EnqueueValueWithSize(controller, chunk, chunkSize).
+        // e. If enqueueResult is an abrupt completion,
+        if let Err(err) = controller.enqueue_value(agent, chunk, gc.nogc()) {
+            let controller = controller.unbind();
+            let gc = gc.into_nogc();
+            let controller = controller.bind(gc);
+            let err = agent.throw_allocation_exception(err, gc);
+            // i. Perform ! ReadableStreamDefaultControllerError(controller, enqueueResult.[[Value]]).
+            readable_stream_default_controller_error(agent, controller, err.value(), gc);
+            // ii. Return enqueueResult.
+            return Err(err);
+        }
+    }
+    // 5. Perform ! ReadableStreamDefaultControllerCallPullIfNeeded(controller).
+    readable_stream_default_controller_call_pull_if_needed(agent, controller.unbind(), gc);
+    Ok(())
+}
+
+/// ### [ReadableStreamDefaultControllerError ( controller, e )](https://streams.spec.whatwg.org/#readable-stream-default-controller-error)
+pub(crate) fn readable_stream_default_controller_error(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    e: Value,
+    gc: NoGcScope,
+) {
+    let controller = controller.bind(gc);
+    // 1. Let stream be controller.[[stream]].
+    let stream = controller.stream(agent);
+    // 2. If stream.[[state]] is not "readable", return.
+    if stream.state(agent) != ReadableStreamState::Readable {
+        return;
+    }
+    // 3. Perform ! ResetQueue(controller).
+    controller.reset_queue(agent);
+    // 4. Perform ! ReadableStreamDefaultControllerClearAlgorithms(controller).
+    controller.clear_algorithms(agent);
+    // 5. Perform ! ReadableStreamError(stream, e).
+    readable_stream_error(agent, stream, e, gc);
+}
+
+/// ### [ReadableStreamDefaultControllerGetDesiredSize ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-get-desired-size)
+pub(crate) fn readable_stream_default_controller_get_desired_size(
+    agent: &Agent,
+    controller: ReadableStreamDefaultController,
+) -> Option<f64> {
+    // 1. Let state be controller.[[stream]].[[state]].
+    match controller.stream(agent).state(agent) {
+        // 2. If state is "errored", return null.
+        ReadableStreamState::Errored => None,
+        // 3. If state is "closed", return 0.
+        ReadableStreamState::Closed => Some(0.0),
+        // 4. Return controller.[[strategyHWM]] − controller.[[queueTotalSize]].
+        ReadableStreamState::Readable => Some(
+            controller.data(agent).strategy_hwm - f64::from(controller.queue_total_size(agent)),
+        ),
+    }
+}
+
+/// ### [ReadableStreamDefaultControllerCanCloseOrEnqueue ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-can-close-or-enqueue)
+pub(crate) fn readable_stream_default_controller_can_close_or_enqueue(
+    agent: &Agent,
+    controller: ReadableStreamDefaultController,
+) -> bool {
+    // 1. Let state be controller.[[stream]].[[state]].
+    // 2. If controller.[[closeRequested]] is false and state is "readable",
+    //    return true.
+    // 3. Otherwise, return false.
+    !controller.data(agent).close_requested
+        && controller.stream(agent).state(agent) == ReadableStreamState::Readable
+}
+
+/// ### [\[\[CancelSteps\]\] ( reason )](https://streams.spec.whatwg.org/#rs-default-controller-private-cancel)
+fn readable_stream_default_controller_cancel_steps<'gc>(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    reason: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let controller = controller.bind(gc.nogc());
+    let reason = reason.bind(gc.nogc());
+    // 1. Perform ! ResetQueue(this).
+    controller.reset_queue(agent);
+    // 2. Let result be the result of performing this.[[cancelAlgorithm]],
+    //    passing reason.
+    // 3. Perform ! ReadableStreamDefaultControllerClearAlgorithms(this).
+    // NOTE: The cancel algorithm is taken out of the controller and the
+    // algorithms are cleared before it is performed; this is not observable.
+    let cancel = controller.cancel_algorithm(agent);
+    let underlying_source = controller.underlying_source(agent);
+    let host_source = controller.take_host_source(agent);
+    controller.clear_algorithms(agent);
+    let result = if let Some(cancel) = cancel {
+        call_function(
+            agent,
+            cancel.unbind(),
+            underlying_source.unbind(),
+            Some(ArgumentsList::from_mut_value(&mut reason.unbind())),
+            gc.reborrow(),
+        )
+        .unbind()
+    } else if let Some(mut source) = host_source {
+        source
+            .cancel(agent, reason.unbind(), gc.reborrow())
+            .map(|_| Value::Undefined)
+            .unbind()
+    } else {
+        Ok(Value::Undefined)
+    };
+    // 4. Return result.
+    promise_resolved_with(agent, result, gc)
+}
+
+/// ### [\[\[PullSteps\]\] ( readRequest )](https://streams.spec.whatwg.org/#rs-default-controller-private-pull)
+fn readable_stream_default_controller_pull_steps<'gc>(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    read_request: Promise,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let controller = controller.bind(gc.nogc());
+    let read_request = read_request.bind(gc.nogc());
+    // 1. Let stream be this.[[stream]].
+    let stream = controller.stream(agent);
+    // 2. If this.[[queue]] is not empty,
+    if controller.queue_total_size(agent) > 0 {
+        // a. Let chunk be ! DequeueValue(this).
+        let chunk = controller.dequeue_value(agent);
+        // b. If this.[[closeRequested]] is true and this.[[queue]] is empty,
+        if controller.data(agent).close_requested && controller.queue_total_size(agent) == 0 {
+            // i. Perform ! ReadableStreamDefaultControllerClearAlgorithms(this).
+            controller.clear_algorithms(agent);
+            // ii. Perform ! ReadableStreamClose(stream).
+            readable_stream_close(agent, stream, gc.nogc());
+            // d. Perform readRequest's chunk steps, given chunk.
+            read_request_chunk_steps(agent, read_request, chunk, gc.nogc());
+        } else {
+            // c. Otherwise, perform ! ReadableStreamDefaultControllerCallPullIfNeeded(this).
+            let chunk = chunk.scope(agent, gc.nogc());
+            let read_request = read_request.scope(agent, gc.nogc());
+            readable_stream_default_controller_call_pull_if_needed(
+                agent,
+                controller.unbind(),
+                gc.reborrow(),
+            );
+            let gc = gc.into_nogc();
+            // SAFETY: not shared.
+            let (chunk, read_request) = unsafe {
+                (
+                    chunk.take(agent).bind(gc),
+                    read_request.take(agent).bind(gc),
+                )
+            };
+            // d. Perform readRequest's chunk steps, given chunk.
+            read_request_chunk_steps(agent, read_request, chunk, gc);
+        }
+    } else {
+        // 3. Otherwise,
+        // a. Perform ! ReadableStreamAddReadRequest(stream, readRequest).
+        readable_stream_add_read_request(agent, stream, read_request, gc.nogc()).unbind()?;
+        // b. Perform ! ReadableStreamDefaultControllerCallPullIfNeeded(this).
+        readable_stream_default_controller_call_pull_if_needed(agent, controller.unbind(), gc);
+    }
+    Ok(())
+}
+
+/// ### [ReadableStreamAddReadRequest ( stream, readRequest )](https://streams.spec.whatwg.org/#readable-stream-add-read-request)
+fn readable_stream_add_read_request<'a>(
+    agent: &mut Agent,
+    stream: ReadableStream,
+    read_request: Promise,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Assert: stream.[[reader]] implements ReadableStreamDefaultReader.
+    // 2. Assert: stream.[[state]] is "readable".
+    // 3. Append readRequest to stream.[[reader]].[[readRequests]].
+    let reader = stream.reader(agent).unwrap();
+    reader
+        .add_read_request(agent, read_request, gc)
+        .map_err(|err| agent.throw_allocation_exception(err, gc))
+}
+
+/// ### [SetUpReadableStreamDefaultController ( stream, controller, startAlgorithm, pullAlgorithm, cancelAlgorithm, highWaterMark, sizeAlgorithm )](https://streams.spec.whatwg.org/#set-up-readable-stream-default-controller)
+///
+/// Steps 1 to 7 are performed when creating the controller. If `start` is
+/// given, it is called as the start method of the underlying source of the
+/// controller; otherwise the start method of the embedder's underlying
+/// source is called, if any.
+pub(crate) fn set_up_readable_stream_default_controller<'gc>(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    start: Option<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let controller = controller.bind(gc.nogc());
+    let start = start.bind(gc.nogc());
+    let scoped_controller = controller.scope(agent, gc.nogc());
+    // 8. Let startResult be the result of performing startAlgorithm. (This
+    //    might throw an exception.)
+    let start_result = if let Some(start) = start {
+        let underlying_source = controller.underlying_source(agent);
+        call_function(
+            agent,
+            start.unbind(),
+            underlying_source.unbind(),
+            Some(ArgumentsList::from_mut_value(&mut Value::from(
+                controller.unbind(),
+            ))),
+            gc.reborrow(),
+        )
+        .unbind()?
+    } else if let Some(mut source) = controller.take_host_source(agent) {
+        let result = source
+            .start(agent, controller.unbind(), gc.reborrow())
+            .unbind();
+        scoped_controller
+            .get(agent)
+            .return_host_source(agent, source);
+        result?;
+        Value::Undefined
+    } else {
+        Value::Undefined
+    };
+    // 9. Let startPromise be a promise resolved with startResult.
+    let start_promise = Promise::resolve(agent, start_result, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let start_promise = start_promise.bind(gc);
+    // SAFETY: scoped_controller is not shared.
+    let controller = unsafe { scoped_controller.take(agent) }.bind(gc);
+    // 10. Upon fulfillment of startPromise, ...
+    // 11. Upon rejection of startPromise with reason r, ...
+    let handler = PromiseReactionHandler::ReadableStream(ReadableStreamReaction::Start(controller));
+    inner_promise_then(agent, start_promise, handler, handler, None, gc);
+    Ok(())
+}
+
+/// The pullAlgorithm of SetUpReadableStreamDefaultControllerFromUnderlyingSource,
+/// or of the embedder's underlying source.
+fn readable_stream_default_controller_pull_algorithm<'gc>(
+    agent: &mut Agent,
+    controller: ReadableStreamDefaultController,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let controller = controller.bind(gc.nogc());
+    let result = if let Some(pull) = controller.pull_algorithm(agent) {
+        // 7. If underlyingSourceDict["pull"] exists, then set pullAlgorithm
+        //    to an algorithm which returns the result of invoking
+        //    underlyingSourceDict["pull"] with argument list « controller »
+        //    and callback this value underlyingSource.
+        let underlying_source = controller.underlying_source(agent);
+        call_function(
+            agent,
+            pull.unbind(),
+            underlying_source.unbind(),
+            Some(ArgumentsList::from_mut_value(&mut Value::from(
+                controller.unbind(),
+            ))),
+            gc.reborrow(),
+        )
+        .unbind()
+    } else if let Some(mut source) = controller.take_host_source(agent) {
+        let scoped_controller = controller.scope(agent, gc.nogc());
+        let result = source
+            .pull(agent, controller.unbind(), gc.reborrow())
+            .map(|_| Value::Undefined)
+            .unbind();
+        scoped_controller
+            .get(agent)
+            .return_host_source(agent, source);
+        result
+    } else {
+        // 3. Let pullAlgorithm be an algorithm that returns a promise
+        //    resolved with undefined.
+        Ok(Value::Undefined)
+    };
+    promise_resolved_with(agent, result, gc)
+}
+
+/// Returns a promise resolved with the result of an underlying source
+/// algorithm, or a promise rejected with its thrown error.
+fn promise_resolved_with<'gc>(
+    agent: &mut Agent,
+    result: JsResult<Value>,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let result = match result {
+        Ok(value) => Promise::resolve(agent, value.unbind(), gc.reborrow()).unbind(),
+        Err(err) => Err(err.unbind()),
+    };
+    let gc = gc.into_nogc();
+    match result.bind(gc) {
+        Ok(promise) => promise,
+        Err(err) => Promise::new_rejected(agent, err.value(), gc),
+    }
+}
@@ -0,0 +1,680 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::TryReserveError;
+
+use crate::{
+    ecmascript::{
+        Agent, Array, EmbedderObject, ExceptionType, Function, JsResult, Promise,
+        PromiseReactionType, Value, embedder_object_handle,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+use super::{
+    readable_stream_default_controller_call_pull_if_needed,
+    readable_stream_default_controller_can_close_or_enqueue,
+    readable_stream_default_controller_close, readable_stream_default_controller_enqueue,
+    readable_stream_default_controller_error, readable_stream_default_controller_get_desired_size,
+    set_up_readable_stream_default_controller,
+};
+
+/// ### [4.2 The ReadableStream class](https://streams.spec.whatwg.org/#rs-class)
+///
+/// A readable stream represents a source of data, from which JavaScript code
+/// can read chunks with a [`ReadableStreamDefaultReader`].
+///
+/// Embedders can create streams backed by native data sources using
+/// [`ReadableStream::new`] and an [`UnderlyingSource`] implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ReadableStream<'a>(EmbedderObject<'a>);
+embedder_object_handle!(ReadableStream, ReadableStreamRecord);
+
+/// ### [4.4 The ReadableStreamDefaultReader class](https://streams.spec.whatwg.org/#default-reader-class)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ReadableStreamDefaultReader<'a>(EmbedderObject<'a>);
+embedder_object_handle!(
+    ReadableStreamDefaultReader,
+    ReadableStreamDefaultReaderRecord
+);
+
+/// ### [4.6 The ReadableStreamDefaultController class](https://streams.spec.whatwg.org/#rs-default-controller-class)
+///
+/// The controller of a [`ReadableStream`], used by its underlying source to
+/// enqueue chunks into the stream, close it, or error it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ReadableStreamDefaultController<'a>(EmbedderObject<'a>);
+embedder_object_handle!(
+    ReadableStreamDefaultController,
+    ReadableStreamDefaultControllerRecord
+);
+
+/// The underlying source of a [`ReadableStream`] implemented by the
+/// embedder.
+///
+/// The source produces chunks by calling
+/// [`ReadableStreamDefaultController::enqueue`], either directly from its
+/// methods or later on, for instance when data arrives from the network. To
+/// enqueue chunks later on, keep the controller in a [`Global`].
+///
+/// [`Global`]: crate::engine::Global
+pub trait UnderlyingSource: Send + 'static {
+    /// Called once when the stream is created. An error thrown here is thrown
+    /// from [`ReadableStream::new`].
+    fn start<'gc>(
+        &mut self,
+        _agent: &mut Agent,
+        _controller: ReadableStreamDefaultController,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        Ok(())
+    }
+
+    /// Called whenever the stream's queue is not full and more chunks are
+    /// wanted. The call is not repeated until it has returned, even if no
+    /// chunks were enqueued. An error thrown here errors the stream.
+    fn pull<'gc>(
+        &mut self,
+        _agent: &mut Agent,
+        _controller: ReadableStreamDefaultController,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        Ok(())
+    }
+
+    /// Called when the stream is cancelled by its consumer. The source is
+    /// dropped after this call.
+    fn cancel<'gc>(
+        &mut self,
+        _agent: &mut Agent,
+        _reason: Value,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        Ok(())
+    }
+}
+
+/// \[\[state]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReadableStreamState {
+    Readable,
+    Closed,
+    Errored,
+}
+
+#[derive(Debug)]
+pub(crate) struct ReadableStreamRecord {
+    /// \[\[state]]
+    pub(crate) state: ReadableStreamState,
+    /// \[\[disturbed]]
+    pub(crate) disturbed: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct ReadableStreamDefaultReaderRecord {
+    /// Index of the first pending read request in the \[\[readRequests]]
+    /// array.
+    pub(crate) read_requests_head: u32,
+}
+
+/// The algorithms of the underlying source of a stream.
+pub(crate) enum ReadableStreamSource {
+    /// The algorithms call the methods of a JavaScript underlying source,
+    /// stored in the controller's internal slots.
+    Script,
+    /// The algorithms call the methods of an embedder's underlying source.
+    /// The source is taken out for the duration of a call.
+    Host(Option<Box<dyn UnderlyingSource>>),
+    /// The algorithms have been cleared.
+    Cleared,
+}
+
+impl core::fmt::Debug for ReadableStreamSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Script => write!(f, "Script"),
+            Self::Host(_) => write!(f, "Host"),
+            Self::Cleared => write!(f, "Cleared"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ReadableStreamDefaultControllerRecord {
+    /// \[\[pullAlgorithm]] and \[\[cancelAlgorithm]]
+    pub(crate) source: ReadableStreamSource,
+    /// \[\[strategyHWM]]
+    pub(crate) strategy_hwm: f64,
+    /// Index of the first chunk in the \[\[queue]] array.
+    pub(crate) queue_head: u32,
+    /// \[\[closeRequested]]
+    pub(crate) close_requested: bool,
+    /// \[\[pullAgain]]
+    pub(crate) pull_again: bool,
+    /// \[\[pulling]]
+    pub(crate) pulling: bool,
+    /// \[\[started]]
+    pub(crate) started: bool,
+}
+
+impl<'a> ReadableStream<'a> {
+    /// \[\[controller]]
+    const CONTROLLER: usize = 0;
+    /// \[\[reader]]
+    const READER: usize = 1;
+    /// \[\[storedError]]
+    const STORED_ERROR: usize = 2;
+    pub(crate) const SLOT_COUNT: usize = 3;
+
+    /// Create a new ReadableStream backed by an embedder's underlying source.
+    ///
+    /// The high water mark is the number of chunks the stream queues up
+    /// before it stops pulling from the source. The source's
+    /// [`start`](UnderlyingSource::start) method is called before this
+    /// returns.
+    ///
+    /// ## Panics
+    ///
+    /// If the high water mark is NaN or negative.
+    pub fn new(
+        agent: &mut Agent,
+        source: impl UnderlyingSource,
+        high_water_mark: f64,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        assert!(
+            !high_water_mark.is_nan() && high_water_mark >= 0.0,
+            "invalid high water mark"
+        );
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .readable_stream_prototype();
+        let stream = ReadableStream::from_embedder_object(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &[Value::Undefined; Self::SLOT_COUNT],
+            ReadableStreamRecord::new(),
+            gc.nogc(),
+        ));
+        let scoped_stream = stream.scope(agent, gc.nogc());
+        let controller = ReadableStreamDefaultController::create(
+            agent,
+            stream,
+            ReadableStreamSource::Host(Some(Box::new(source))),
+            high_water_mark,
+            Value::Undefined,
+            None,
+            None,
+            gc.nogc(),
+        );
+        set_up_readable_stream_default_controller(agent, controller.unbind(), None, gc.reborrow())
+            .unbind()?;
+        // SAFETY: scoped_stream is not shared.
+        Ok(unsafe { scoped_stream.take(agent) }.bind(gc.into_nogc()))
+    }
+
+    /// ### [IsReadableStreamLocked ( stream )](https://streams.spec.whatwg.org/#is-readable-stream-locked)
+    ///
+    /// Returns true if the stream has a reader.
+    pub fn is_locked(self, agent: &Agent) -> bool {
+        // 1. If stream.[[reader]] is undefined, return false.
+        // 2. Return true.
+        self.reader(agent).is_some()
+    }
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(crate) fn state(self, agent: &Agent) -> ReadableStreamState {
+        self.data(agent).state
+    }
+
+    pub(crate) fn controller(self, agent: &Agent) -> ReadableStreamDefaultController<'a> {
+        let Value::EmbedderObject(controller) = self.0.get_slot(agent, Self::CONTROLLER) else {
+            unreachable!()
+        };
+        ReadableStreamDefaultController(controller)
+    }
+
+    pub(crate) fn reader(self, agent: &Agent) -> Option<ReadableStreamDefaultReader<'a>> {
+        match self.0.get_slot(agent, Self::READER) {
+            Value::EmbedderObject(reader) => Some(ReadableStreamDefaultReader(reader)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_reader(self, agent: &mut Agent, reader: Option<ReadableStreamDefaultReader>) {
+        let reader = reader.map_or(Value::Undefined, |reader| reader.into());
+        self.0.set_slot(agent, Self::READER, reader);
+    }
+
+    pub(crate) fn stored_error(self, agent: &Agent) -> Value<'a> {
+        self.0.get_slot(agent, Self::STORED_ERROR)
+    }
+
+    pub(crate) fn set_stored_error(self, agent: &mut Agent, error: Value) {
+        self.0.set_slot(agent, Self::STORED_ERROR, error);
+    }
+}
+
+impl ReadableStreamRecord {
+    /// ### [InitializeReadableStream ( stream )](https://streams.spec.whatwg.org/#initialize-readable-stream)
+    pub(crate) fn new() -> Self {
+        // 1. Set stream.[[state]] to "readable".
+        // 2. Set stream.[[reader]] and stream.[[storedError]] to undefined.
+        // 3. Set stream.[[disturbed]] to false.
+        Self {
+            state: ReadableStreamState::Readable,
+            disturbed: false,
+        }
+    }
+}
+
+impl<'a> ReadableStreamDefaultReader<'a> {
+    /// \[\[stream]]
+    const STREAM: usize = 0;
+    /// \[\[readRequests]]
+    const READ_REQUESTS: usize = 1;
+    /// \[\[closedPromise]]
+    const CLOSED_PROMISE: usize = 2;
+    pub(crate) const SLOT_COUNT: usize = 3;
+
+    pub(crate) fn from_embedder_object(object: EmbedderObject<'a>) -> Self {
+        Self(object)
+    }
+
+    pub(crate) fn stream(self, agent: &Agent) -> Option<ReadableStream<'a>> {
+        match self.0.get_slot(agent, Self::STREAM) {
+            Value::EmbedderObject(stream) => Some(ReadableStream(stream)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_stream(self, agent: &mut Agent, stream: Option<ReadableStream>) {
+        let stream = stream.map_or(Value::Undefined, |stream| stream.into());
+        self.0.set_slot(agent, Self::STREAM, stream);
+    }
+
+    pub(crate) fn closed_promise(self, agent: &Agent) -> Promise<'a> {
+        let Value::Promise(promise) = self.0.get_slot(agent, Self::CLOSED_PROMISE) else {
+            unreachable!()
+        };
+        promise
+    }
+
+    pub(crate) fn set_closed_promise(self, agent: &mut Agent, promise: Promise) {
+        self.0.set_slot(agent, Self::CLOSED_PROMISE, promise.into());
+    }
+
+    /// Returns the number of pending read requests.
+    pub(crate) fn num_read_requests(self, agent: &Agent) -> u32 {
+        match self.0.get_slot(agent, Self::READ_REQUESTS) {
+            Value::Array(read_requests) => {
+                read_requests.len(agent) - self.data(agent).read_requests_head
+            }
+            _ => 0,
+        }
+    }
+
+    /// Append a read request to \[\[readRequests]]. Read requests are
+    /// represented by the promise that the read settles.
+    pub(crate) fn add_read_request(
+        self,
+        agent: &mut Agent,
+        read_request: Promise,
+        gc: NoGcScope,
+    ) -> Result<(), TryReserveError> {
+        let read_requests = match self.0.get_slot(agent, Self::READ_REQUESTS) {
+            Value::Array(read_requests) => read_requests,
+            _ => {
+                let read_requests = Array::new(agent, gc);
+                self.0
+                    .set_slot(agent, Self::READ_REQUESTS, read_requests.into());
+                read_requests
+            }
+        };
+        read_requests.push(agent, read_request.into())
+    }
+
+    /// Remove the first read request from \[\[readRequests]].
+    pub(crate) fn take_first_read_request(self, agent: &mut Agent) -> Option<Promise<'a>> {
+        let Value::Array(read_requests) = self.0.get_slot(agent, Self::READ_REQUESTS) else {
+            return None;
+        };
+        let head = self.data(agent).read_requests_head;
+        let read_request = read_requests.as_mut_slice(agent)[head as usize].take();
+        if head + 1 == read_requests.len(agent) {
+            self.0
+                .set_slot(agent, Self::READ_REQUESTS, Value::Undefined);
+            self.data_mut(agent).read_requests_head = 0;
+        } else {
+            self.data_mut(agent).read_requests_head = head + 1;
+        }
+        let Some(Value::Promise(read_request)) = read_request else {
+            unreachable!()
+        };
+        Some(read_request)
+    }
+
+    /// Set \[\[readRequests]] to an empty list, returning the previous read
+    /// requests.
+    pub(crate) fn take_read_requests(self, agent: &mut Agent) -> Vec<Promise<'a>> {
+        let Value::Array(read_requests) = self.0.get_slot(agent, Self::READ_REQUESTS) else {
+            return vec![];
+        };
+        let head = self.data(agent).read_requests_head as usize;
+        let result = read_requests.as_slice(agent)[head..]
+            .iter()
+            .map(|read_request| {
+                let Some(Value::Promise(read_request)) = read_request else {
+                    unreachable!()
+                };
+                *read_request
+            })
+            .collect();
+        self.0
+            .set_slot(agent, Self::READ_REQUESTS, Value::Undefined);
+        self.data_mut(agent).read_requests_head = 0;
+        result
+    }
+}
+
+impl<'a> ReadableStreamDefaultController<'a> {
+    /// \[\[stream]]
+    const STREAM: usize = 0;
+    /// \[\[queue]]
+    const QUEUE: usize = 1;
+    /// The underlying source object, used as the this value of its methods.
+    const UNDERLYING_SOURCE: usize = 2;
+    /// The pull method of the underlying source.
+    const PULL: usize = 3;
+    /// The cancel method of the underlying source.
+    const CANCEL: usize = 4;
+    const SLOT_COUNT: usize = 5;
+
+    /// Create a new controller for a stream, without setting it up.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create(
+        agent: &mut Agent,
+        stream: ReadableStream,
+        source: ReadableStreamSource,
+        high_water_mark: f64,
+        underlying_source: Value,
+        pull: Option<Function>,
+        cancel: Option<Function>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Self {
+        let prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .readable_stream_default_controller_prototype();
+        let mut slots = [Value::Undefined; Self::SLOT_COUNT];
+        slots[Self::STREAM] = stream.into();
+        slots[Self::UNDERLYING_SOURCE] = underlying_source;
+        slots[Self::PULL] = pull.map_or(Value::Undefined, |pull| pull.into());
+        slots[Self::CANCEL] = cancel.map_or(Value::Undefined, |cancel| cancel.into());
+        let controller = Self(EmbedderObject::new(
+            agent,
+            Some(prototype.into()),
+            &slots,
+            ReadableStreamDefaultControllerRecord {
+                source,
+                strategy_hwm: high_water_mark,
+                queue_head: 0,
+                close_requested: false,
+                pull_again: false,
+                pulling: false,
+                started: false,
+            },
+            gc,
+        ));
+        stream
+            .0
+            .set_slot(agent, ReadableStream::CONTROLLER, controller.into());
+        controller
+    }
+
+    /// Enqueue a chunk into the stream.
+    ///
+    /// Throws a TypeError if the stream is closed, errored, or closing.
+    pub fn enqueue<'gc>(
+        self,
+        agent: &mut Agent,
+        chunk: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        self.check_can_close_or_enqueue(agent, gc.nogc()).unbind()?;
+        readable_stream_default_controller_enqueue(agent, self, chunk, gc)
+    }
+
+    /// Close the stream. Chunks already enqueued can still be read.
+    ///
+    /// Throws a TypeError if the stream is closed, errored, or closing.
+    pub fn close<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        self.check_can_close_or_enqueue(agent, gc.nogc()).unbind()?;
+        readable_stream_default_controller_close(agent, self, gc.into_nogc());
+        Ok(())
+    }
+
+    /// Error the stream. Pending and future reads are rejected with the
+    /// error. Has no effect if the stream is already closed or errored.
+    pub fn error(self, agent: &mut Agent, error: Value, gc: NoGcScope) {
+        readable_stream_default_controller_error(agent, self, error, gc);
+    }
+
+    /// Returns the number of chunks the stream wants to be enqueued before
+    /// its queue is full, or None if the stream is errored.
+    pub fn desired_size(self, agent: &Agent) -> Option<f64> {
+        readable_stream_default_controller_get_desired_size(agent, self)
+    }
+
+    fn check_can_close_or_enqueue<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        if readable_stream_default_controller_can_close_or_enqueue(agent, self) {
+            Ok(())
+        } else {
+            Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "ReadableStream is closed or closing",
+                gc,
+            ))
+        }
+    }
+
+    pub(crate) fn stream(self, agent: &Agent) -> ReadableStream<'a> {
+        let Value::EmbedderObject(stream) = self.0.get_slot(agent, Self::STREAM) else {
+            unreachable!()
+        };
+        ReadableStream(stream)
+    }
+
+    pub(crate) fn underlying_source(self, agent: &Agent) -> Value<'a> {
+        self.0.get_slot(agent, Self::UNDERLYING_SOURCE)
+    }
+
+    pub(crate) fn pull_algorithm(self, agent: &Agent) -> Option<Function<'a>> {
+        Function::try_from(self.0.get_slot(agent, Self::PULL)).ok()
+    }
+
+    pub(crate) fn cancel_algorithm(self, agent: &Agent) -> Option<Function<'a>> {
+        Function::try_from(self.0.get_slot(agent, Self::CANCEL)).ok()
+    }
+
+    /// ### [ReadableStreamDefaultControllerClearAlgorithms ( controller )](https://streams.spec.whatwg.org/#readable-stream-default-controller-clear-algorithms)
+    pub(crate) fn clear_algorithms(self, agent: &mut Agent) {
+        // 1. Set controller.[[pullAlgorithm]] to undefined.
+        // 2. Set controller.[[cancelAlgorithm]] to undefined.
+        // 3. Set controller.[[strategySizeAlgorithm]] to undefined.
+        self.0
+            .set_slot(agent, Self::UNDERLYING_SOURCE, Value::Undefined);
+        self.0.set_slot(agent, Self::PULL, Value::Undefined);
+        self.0.set_slot(agent, Self::CANCEL, Value::Undefined);
+        self.data_mut(agent).source = ReadableStreamSource::Cleared;
+    }
+
+    /// Take the embedder's underlying source out of the controller for the
+    /// duration of a call. Returns None if the source is not an embedder
+    /// source, or if it is already being called.
+    pub(crate) fn take_host_source(self, agent: &mut Agent) -> Option<Box<dyn UnderlyingSource>> {
+        match &mut self.data_mut(agent).source {
+            ReadableStreamSource::Host(source) => source.take(),
+            _ => None,
+        }
+    }
+
+    /// Return the embedder's underlying source after a call, unless the
+    /// algorithms were cleared during the call.
+    pub(crate) fn return_host_source(self, agent: &mut Agent, source: Box<dyn UnderlyingSource>) {
+        if let ReadableStreamSource::Host(slot @ None) = &mut self.data_mut(agent).source {
+            *slot = Some(source);
+        }
+    }
+
+    /// Returns the total size of all chunks in \[\[queue]]. All chunks have
+    /// size 1.
+    pub(crate) fn queue_total_size(self, agent: &Agent) -> u32 {
+        match self.0.get_slot(agent, Self::QUEUE) {
+            Value::Array(queue) => queue.len(agent) - self.data(agent).queue_head,
+            _ => 0,
+        }
+    }
+
+    /// ### [EnqueueValueWithSize ( container, value, size )](https://streams.spec.whatwg.org/#enqueue-value-with-size)
+    pub(crate) fn enqueue_value(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        gc: NoGcScope,
+    ) -> Result<(), TryReserveError> {
+        let queue = match self.0.get_slot(agent, Self::QUEUE) {
+            Value::Array(queue) => queue,
+            _ => {
+                let queue = Array::new(agent, gc);
+                self.0.set_slot(agent, Self::QUEUE, queue.into());
+                queue
+            }
+        };
+        queue.push(agent, value)
+    }
+
+    /// ### [DequeueValue ( container )](https://streams.spec.whatwg.org/#dequeue-value)
+    pub(crate) fn dequeue_value(self, agent: &mut Agent) -> Value<'a> {
+        // 1. Assert: container has [[queue]] and [[queueTotalSize]] internal slots.
+        // 2. Assert: container.[[queue]] is not empty.
+        let Value::Array(queue) = self.0.get_slot(agent, Self::QUEUE) else {
+            unreachable!()
+        };
+        // 3. Let valueWithSize be container.[[queue]][0].
+        // 4. Remove valueWithSize from container.[[queue]].
+        let head = self.data(agent).queue_head;
+        let value = queue.as_mut_slice(agent)[head as usize].take();
+        // 5. Set container.[[queueTotalSize]] to container.[[queueTotalSize]] − valueWithSize's size.
+        // 6. If container.[[queueTotalSize]] < 0, set container.[[queueTotalSize]] to 0. (This can occur due to rounding errors.)
+        if head + 1 == queue.len(agent) {
+            self.reset_queue(agent);
+        } else {
+            self.data_mut(agent).queue_head = head + 1;
+        }
+        // 7. Return valueWithSize's value.
+        value.unwrap_or(Value::Undefined)
+    }
+
+    /// ### [ResetQueue ( container )](https://streams.spec.whatwg.org/#reset-queue)
+    pub(crate) fn reset_queue(self, agent: &mut Agent) {
+        // 1. Assert: container has [[queue]] and [[queueTotalSize]] internal slots.
+        // 2. Set container.[[queue]] to a new empty list.
+        // 3. Set container.[[queueTotalSize]] to 0.
+        self.0.set_slot(agent, Self::QUEUE, Value::Undefined);
+        self.data_mut(agent).queue_head = 0;
+    }
+}
+
+/// Reaction to a promise returned by an underlying source algorithm.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ReadableStreamReaction<'a> {
+    /// Reaction to the startPromise in
+    /// SetUpReadableStreamDefaultController.
+    Start(ReadableStreamDefaultController<'a>),
+    /// Reaction to the pullPromise in
+    /// ReadableStreamDefaultControllerCallPullIfNeeded.
+    Pull(ReadableStreamDefaultController<'a>),
+    /// Reaction to the sourceCancelPromise in ReadableStreamCancel: fulfills
+    /// the reaction's capability with undefined.
+    Cancel,
+}
+bindable_handle!(ReadableStreamReaction);
+
+impl ReadableStreamReaction<'_> {
+    pub(crate) fn settle(
+        self,
+        agent: &mut Agent,
+        reaction_type: PromiseReactionType,
+        argument: Value,
+        gc: GcScope,
+    ) {
+        match (self, reaction_type) {
+            // SetUpReadableStreamDefaultController
+            // 11. Upon fulfillment of startPromise,
+            (Self::Start(controller), PromiseReactionType::Fulfill) => {
+                // a. Set controller.[[started]] to true.
+                let data = controller.data_mut(agent);
+                data.started = true;
+                // b. Assert: controller.[[pulling]] is false.
+                debug_assert!(!data.pulling);
+                // c. Assert: controller.[[pullAgain]] is false.
+                debug_assert!(!data.pull_again);
+                // d. Perform ! ReadableStreamDefaultControllerCallPullIfNeeded(controller).
+                readable_stream_default_controller_call_pull_if_needed(agent, controller, gc);
+            }
+            // ReadableStreamDefaultControllerCallPullIfNeeded
+            // 7. Upon fulfillment of pullPromise,
+            (Self::Pull(controller), PromiseReactionType::Fulfill) => {
+                // a. Set controller.[[pulling]] to false.
+                let data = controller.data_mut(agent);
+                data.pulling = false;
+                // b. If controller.[[pullAgain]] is true,
+                if data.pull_again {
+                    // i. Set controller.[[pullAgain]] to false.
+                    data.pull_again = false;
+                    // ii. Perform ! ReadableStreamDefaultControllerCallPullIfNeeded(controller).
+                    readable_stream_default_controller_call_pull_if_needed(agent, controller, gc);
+                }
+            }
+            // 12. Upon rejection of startPromise with reason r,
+            // 8. Upon rejection of pullPromise with reason e,
+            (Self::Start(controller) | Self::Pull(controller), PromiseReactionType::Reject) => {
+                // a. Perform ! ReadableStreamDefaultControllerError(controller, r).
+                readable_stream_default_controller_error(agent, controller, argument, gc.nogc());
+            }
+            (Self::Cancel, _) => unreachable!(),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ReadableStreamReaction<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Start(controller) | Self::Pull(controller) => controller.mark_values(queues),
+            Self::Cancel => {}
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Start(controller) | Self::Pull(controller) => {
+                controller.sweep_values(compactions)
+            }
+            Self::Cancel => {}
+        }
+    }
+}
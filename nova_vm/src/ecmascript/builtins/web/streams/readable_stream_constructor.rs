@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, PropertyKey,
+        ProtoIntrinsics, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor, get, is_callable, to_number,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{
+    ReadableStream, ReadableStreamDefaultController, ReadableStreamRecord, ReadableStreamSource,
+    set_up_readable_stream_default_controller,
+};
+
+pub(crate) struct ReadableStreamConstructor;
+impl Builtin for ReadableStreamConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.ReadableStream;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for ReadableStreamConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::ReadableStream;
+}
+
+impl ReadableStreamConstructor {
+    /// ### [new ReadableStream(underlyingSource, strategy)](https://streams.spec.whatwg.org/#rs-constructor)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let underlying_source = arguments.get(0).bind(gc.nogc());
+        let strategy = arguments.get(1).bind(gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin ReadableStream constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target)
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // NOTE: The underlyingSource argument is an optional object.
+        let underlying_source = match underlying_source {
+            Value::Undefined => None,
+            _ => match Object::try_from(underlying_source) {
+                Ok(underlying_source) => Some(underlying_source.scope(agent, gc.nogc())),
+                Err(_) => {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "underlyingSource is not an object",
+                        gc.into_nogc(),
+                    ));
+                }
+            },
+        };
+        // NOTE: The strategy argument is converted to a QueuingStrategy
+        // dictionary before the constructor steps.
+        let high_water_mark = match strategy {
+            Value::Undefined | Value::Null => None,
+            _ => {
+                let Ok(strategy) = Object::try_from(strategy) else {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "strategy is not an object",
+                        gc.into_nogc(),
+                    ));
+                };
+                let scoped_strategy = strategy.scope(agent, gc.nogc());
+                let high_water_mark = get(
+                    agent,
+                    strategy.unbind(),
+                    BUILTIN_STRING_MEMORY.highWaterMark.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                let high_water_mark = if high_water_mark.is_undefined() {
+                    None
+                } else {
+                    Some(
+                        to_number(agent, high_water_mark.unbind(), gc.reborrow())
+                            .unbind()?
+                            .into_f64(agent),
+                    )
+                };
+                let size = get(
+                    agent,
+                    scoped_strategy.get(agent),
+                    BUILTIN_STRING_MEMORY.size.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                if !size.is_undefined() {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "ReadableStream queuing strategy size is not supported",
+                        gc.into_nogc(),
+                    ));
+                }
+                high_water_mark
+            }
+        };
+        // NOTE: The new object is created before the constructor steps.
+        let stream = embedder_object_create_from_constructor(
+            agent,
+            new_target.get(agent),
+            ProtoIntrinsics::ReadableStream,
+            ReadableStream::SLOT_COUNT,
+            // 3. Perform ! InitializeReadableStream(this).
+            ReadableStreamRecord::new(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let scoped_stream = ReadableStream::from_embedder_object(stream).scope(agent, gc.nogc());
+        // 1. If underlyingSource is missing, set it to null.
+        // 2. Let underlyingSourceDict be underlyingSource, converted to an IDL
+        //    value of type UnderlyingSource.
+        let (start, pull, cancel) = if let Some(underlying_source) = &underlying_source {
+            let cancel = get_callback(
+                agent,
+                underlying_source.get(agent),
+                BUILTIN_STRING_MEMORY.cancel.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .map(|f| f.scope(agent, gc.nogc()));
+            let pull = get_callback(
+                agent,
+                underlying_source.get(agent),
+                BUILTIN_STRING_MEMORY.pull.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .map(|f| f.scope(agent, gc.nogc()));
+            let start = get_callback(
+                agent,
+                underlying_source.get(agent),
+                BUILTIN_STRING_MEMORY.start.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .map(|f| f.scope(agent, gc.nogc()));
+            let r#type = get(
+                agent,
+                underlying_source.get(agent),
+                BUILTIN_STRING_MEMORY.r#type.into(),
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // 4. If underlyingSourceDict["type"] is "bytes", ...
+            // 5. Otherwise,
+            // a. Assert: underlyingSourceDict["type"] does not exist.
+            if !r#type.is_undefined() {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "ReadableStream type is not supported",
+                    gc.into_nogc(),
+                ));
+            }
+            (start, pull, cancel)
+        } else {
+            (None, None, None)
+        };
+        // b. Let sizeAlgorithm be ! ExtractSizeAlgorithm(strategy).
+        // c. Let highWaterMark be ? ExtractHighWaterMark(strategy, 1).
+        let high_water_mark =
+            extract_high_water_mark(agent, high_water_mark, 1.0, gc.nogc()).unbind()?;
+        let gc_nogc = gc.nogc();
+        let stream = scoped_stream.get(agent).bind(gc_nogc);
+        // d. Perform ? SetUpReadableStreamDefaultControllerFromUnderlyingSource(this,
+        //    underlyingSource, underlyingSourceDict, highWaterMark, sizeAlgorithm).
+        // 1. Let controller be a new ReadableStreamDefaultController.
+        // 2. Let startAlgorithm be an algorithm that returns undefined.
+        // 3. Let pullAlgorithm be an algorithm that returns a promise resolved
+        //    with undefined.
+        // 4. Let cancelAlgorithm be an algorithm that returns a promise
+        //    resolved with undefined.
+        // 5. If underlyingSourceDict["start"] exists, then set startAlgorithm
+        //    to an algorithm which returns the result of invoking
+        //    underlyingSourceDict["start"] with argument list « controller »
+        //    and callback this value underlyingSource.
+        // 6. If underlyingSourceDict["pull"] exists, ...
+        // 7. If underlyingSourceDict["cancel"] exists, ...
+        let underlying_source = underlying_source.map_or(Value::Null, |underlying_source| {
+            underlying_source.get(agent).into()
+        });
+        let pull = pull.map(|pull| pull.get(agent));
+        let cancel = cancel.map(|cancel| cancel.get(agent));
+        let controller = ReadableStreamDefaultController::create(
+            agent,
+            stream,
+            ReadableStreamSource::Script,
+            high_water_mark,
+            underlying_source,
+            pull,
+            cancel,
+            gc_nogc,
+        );
+        // 8. Perform ? SetUpReadableStreamDefaultController(stream, controller,
+        //    startAlgorithm, pullAlgorithm, cancelAlgorithm, highWaterMark,
+        //    sizeAlgorithm).
+        let start = start.map(|start| start.get(agent));
+        set_up_readable_stream_default_controller(
+            agent,
+            controller.unbind(),
+            start.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // SAFETY: scoped_stream is not shared.
+        Ok(unsafe { scoped_stream.take(agent) }
+            .bind(gc.into_nogc())
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let readable_stream_prototype = intrinsics.readable_stream_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<ReadableStreamConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(readable_stream_prototype.into())
+        .build();
+    }
+}
+
+/// Get a callback function member of a dictionary, throwing a TypeError if
+/// the member is neither undefined nor callable.
+fn get_callback<'gc>(
+    agent: &mut Agent,
+    dictionary: Object,
+    key: PropertyKey,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Function<'gc>>> {
+    let value = get(agent, dictionary, key, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = value.unbind();
+    let gc = gc.into_nogc();
+    match is_callable(value.bind(gc), gc) {
+        Some(callback) => Ok(Some(callback)),
+        None => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "underlyingSource method is not callable",
+            gc,
+        )),
+    }
+}
+
+/// ### [ExtractHighWaterMark ( strategy, defaultHWM )](https://streams.spec.whatwg.org/#validate-and-normalize-high-water-mark)
+fn extract_high_water_mark<'a>(
+    agent: &mut Agent,
+    high_water_mark: Option<f64>,
+    default_hwm: f64,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, f64> {
+    // 1. If strategy["highWaterMark"] does not exist, return defaultHWM.
+    let Some(high_water_mark) = high_water_mark else {
+        return Ok(default_hwm);
+    };
+    // 2. Let highWaterMark be strategy["highWaterMark"].
+    // 3. If highWaterMark is NaN or highWaterMark < 0, throw a RangeError
+    //    exception.
+    if high_water_mark.is_nan() || high_water_mark < 0.0 {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "highWaterMark must be a non-negative number",
+            gc,
+        ));
+    }
+    // 4. Return highWaterMark.
+    Ok(high_water_mark)
+}
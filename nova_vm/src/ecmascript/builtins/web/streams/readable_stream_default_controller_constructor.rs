@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, JsResult, Object, Realm, String, Value,
+        builders::BuiltinFunctionBuilder,
+    },
+    engine::GcScope,
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct ReadableStreamDefaultControllerConstructor;
+impl Builtin for ReadableStreamDefaultControllerConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.ReadableStreamDefaultController;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for ReadableStreamDefaultControllerConstructor {
+    const INDEX: IntrinsicConstructorIndexes =
+        IntrinsicConstructorIndexes::ReadableStreamDefaultController;
+}
+
+impl ReadableStreamDefaultControllerConstructor {
+    /// ### [4.6.1 Interface definition](https://streams.spec.whatwg.org/#rs-default-controller-class-definition)
+    ///
+    /// The ReadableStreamDefaultController interface has no constructor:
+    /// controllers are only created by ReadableStream.
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        _new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Illegal constructor",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let readable_stream_default_controller_prototype =
+            intrinsics.readable_stream_default_controller_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<
+            ReadableStreamDefaultControllerConstructor,
+        >(agent, realm)
+        .with_property_capacity(1)
+        .with_prototype_property(readable_stream_default_controller_prototype.into())
+        .build();
+    }
+}
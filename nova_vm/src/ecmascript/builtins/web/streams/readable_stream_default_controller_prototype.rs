@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, Number, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::WellKnownSymbols,
+};
+
+use super::ReadableStreamDefaultController;
+
+pub(crate) struct ReadableStreamDefaultControllerPrototype;
+
+struct ReadableStreamDefaultControllerPrototypeGetDesiredSize;
+impl Builtin for ReadableStreamDefaultControllerPrototypeGetDesiredSize {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_desiredSize;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.desiredSize.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultControllerPrototype::get_desired_size);
+}
+impl BuiltinGetter for ReadableStreamDefaultControllerPrototypeGetDesiredSize {}
+struct ReadableStreamDefaultControllerPrototypeClose;
+impl Builtin for ReadableStreamDefaultControllerPrototypeClose {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.close;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultControllerPrototype::close);
+}
+struct ReadableStreamDefaultControllerPrototypeEnqueue;
+impl Builtin for ReadableStreamDefaultControllerPrototypeEnqueue {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.enqueue;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultControllerPrototype::enqueue);
+}
+struct ReadableStreamDefaultControllerPrototypeError;
+impl Builtin for ReadableStreamDefaultControllerPrototypeError {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.error;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultControllerPrototype::error);
+}
+
+impl ReadableStreamDefaultControllerPrototype {
+    /// ### [get ReadableStreamDefaultController.prototype.desiredSize](https://streams.spec.whatwg.org/#rs-default-controller-desired-size)
+    fn get_desired_size<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let controller = require_controller(agent, this_value, gc)?;
+        // 1. Return ! ReadableStreamDefaultControllerGetDesiredSize(this).
+        Ok(match controller.desired_size(agent) {
+            Some(desired_size) => Number::from_f64(agent, desired_size, gc).into(),
+            None => Value::Null,
+        })
+    }
+
+    /// ### [ReadableStreamDefaultController.prototype.close ( )](https://streams.spec.whatwg.org/#rs-default-controller-close)
+    fn close<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let controller = require_controller(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 1. If ! ReadableStreamDefaultControllerCanCloseOrEnqueue(this) is
+        //    false, throw a TypeError exception.
+        // 2. Perform ! ReadableStreamDefaultControllerClose(this).
+        controller.unbind().close(agent, gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [ReadableStreamDefaultController.prototype.enqueue ( chunk )](https://streams.spec.whatwg.org/#rs-default-controller-enqueue)
+    fn enqueue<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let chunk = arguments.get(0).bind(gc.nogc());
+        let controller = require_controller(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 1. If ! ReadableStreamDefaultControllerCanCloseOrEnqueue(this) is
+        //    false, throw a TypeError exception.
+        // 2. Perform ? ReadableStreamDefaultControllerEnqueue(this, chunk).
+        controller.unbind().enqueue(agent, chunk.unbind(), gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [ReadableStreamDefaultController.prototype.error ( e )](https://streams.spec.whatwg.org/#rs-default-controller-error)
+    fn error<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let e = arguments.get(0).bind(gc);
+        let controller = require_controller(agent, this_value, gc)?;
+        // 1. Perform ! ReadableStreamDefaultControllerError(this, e).
+        controller.error(agent, e, gc);
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.readable_stream_default_controller_prototype();
+        let readable_stream_default_controller_constructor =
+            intrinsics.readable_stream_default_controller();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(6)
+            .with_prototype(object_prototype)
+            .with_constructor_property(readable_stream_default_controller_constructor)
+            .with_builtin_function_getter_property::<
+                ReadableStreamDefaultControllerPrototypeGetDesiredSize,
+            >()
+            .with_builtin_function_property::<ReadableStreamDefaultControllerPrototypeClose>()
+            .with_builtin_function_property::<ReadableStreamDefaultControllerPrototypeEnqueue>()
+            .with_builtin_function_property::<ReadableStreamDefaultControllerPrototypeError>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(
+                        BUILTIN_STRING_MEMORY
+                            .ReadableStreamDefaultController
+                            .into(),
+                    )
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_controller<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ReadableStreamDefaultController<'a>> {
+    ReadableStreamDefaultController::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a ReadableStreamDefaultController",
+            gc,
+        )
+    })
+}
@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder,
+        embedder_object_create_from_constructor,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::IntrinsicConstructorIndexes,
+};
+
+use super::{
+    ReadableStream, ReadableStreamDefaultReader, ReadableStreamDefaultReaderRecord,
+    set_up_readable_stream_default_reader,
+};
+
+pub(crate) struct ReadableStreamDefaultReaderConstructor;
+impl Builtin for ReadableStreamDefaultReaderConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.ReadableStreamDefaultReader;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for ReadableStreamDefaultReaderConstructor {
+    const INDEX: IntrinsicConstructorIndexes =
+        IntrinsicConstructorIndexes::ReadableStreamDefaultReader;
+}
+
+impl ReadableStreamDefaultReaderConstructor {
+    /// ### [new ReadableStreamDefaultReader(stream)](https://streams.spec.whatwg.org/#default-reader-constructor)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let stream = arguments.get(0).bind(gc.nogc());
+        let Some(new_target) = new_target.bind(gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin ReadableStreamDefaultReader constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // NOTE: The stream argument is converted to a ReadableStream.
+        let Some(stream) = ReadableStream::try_from_value(agent, stream) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "stream is not a ReadableStream",
+                gc.into_nogc(),
+            ));
+        };
+        let stream = stream.scope(agent, gc.nogc());
+        let reader = embedder_object_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::ReadableStreamDefaultReader,
+            ReadableStreamDefaultReader::SLOT_COUNT,
+            ReadableStreamDefaultReaderRecord {
+                read_requests_head: 0,
+            },
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let reader = ReadableStreamDefaultReader::from_embedder_object(reader.bind(gc));
+        // SAFETY: stream is not shared.
+        let stream = unsafe { stream.take(agent) }.bind(gc);
+        // 1. Perform ? SetUpReadableStreamDefaultReader(this, stream).
+        set_up_readable_stream_default_reader(agent, reader, stream, gc)?;
+        Ok(reader.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let readable_stream_default_reader_prototype =
+            intrinsics.readable_stream_default_reader_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<ReadableStreamDefaultReaderConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(readable_stream_default_reader_prototype.into())
+        .build();
+    }
+}
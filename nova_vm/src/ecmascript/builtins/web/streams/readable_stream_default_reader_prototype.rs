@@ -0,0 +1,207 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, Promise, PromiseCapability, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::WellKnownSymbols,
+};
+
+use super::{
+    ReadableStreamDefaultReader, readable_stream_cancel, readable_stream_default_reader_read,
+    readable_stream_default_reader_release,
+};
+
+pub(crate) struct ReadableStreamDefaultReaderPrototype;
+
+struct ReadableStreamDefaultReaderPrototypeGetClosed;
+impl Builtin for ReadableStreamDefaultReaderPrototypeGetClosed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_closed;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.closed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultReaderPrototype::get_closed);
+}
+impl BuiltinGetter for ReadableStreamDefaultReaderPrototypeGetClosed {}
+struct ReadableStreamDefaultReaderPrototypeCancel;
+impl Builtin for ReadableStreamDefaultReaderPrototypeCancel {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.cancel;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ReadableStreamDefaultReaderPrototype::cancel);
+}
+struct ReadableStreamDefaultReaderPrototypeRead;
+impl Builtin for ReadableStreamDefaultReaderPrototypeRead {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.read;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ReadableStreamDefaultReaderPrototype::read);
+}
+struct ReadableStreamDefaultReaderPrototypeReleaseLock;
+impl Builtin for ReadableStreamDefaultReaderPrototypeReleaseLock {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.releaseLock;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(ReadableStreamDefaultReaderPrototype::release_lock);
+}
+
+impl ReadableStreamDefaultReaderPrototype {
+    /// ### [get ReadableStreamDefaultReader.prototype.closed](https://streams.spec.whatwg.org/#generic-reader-closed)
+    fn get_closed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let reader = match require_reader(agent, this_value, gc) {
+            Ok(reader) => reader,
+            Err(err) => return Ok(Promise::new_rejected(agent, err.value(), gc).into()),
+        };
+        // 1. Return this.[[closedPromise]].
+        Ok(reader.closed_promise(agent).into())
+    }
+
+    /// ### [ReadableStreamDefaultReader.prototype.cancel ( reason )](https://streams.spec.whatwg.org/#generic-reader-cancel)
+    fn cancel<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let reason = arguments.get(0).bind(gc.nogc());
+        let reader = match require_reader(agent, this_value, gc.nogc()) {
+            Ok(reader) => reader,
+            Err(err) => {
+                let err = err.unbind();
+                let gc = gc.into_nogc();
+                return Ok(Promise::new_rejected(agent, err.value(), gc).into());
+            }
+        };
+        // 1. If this.[[stream]] is undefined, return a promise rejected with a
+        //    TypeError exception.
+        let Some(stream) = reader.stream(agent) else {
+            return Ok(reject_released(agent, gc.into_nogc()).into());
+        };
+        // 2. Return ! ReadableStreamReaderGenericCancel(this, reason).
+        // ReadableStreamReaderGenericCancel ( reader, reason )
+        // 1. Let stream be reader.[[stream]].
+        // 2. Assert: stream is not undefined.
+        // 3. Return ! ReadableStreamCancel(stream, reason).
+        Ok(readable_stream_cancel(agent, stream.unbind(), reason.unbind(), gc).into())
+    }
+
+    /// ### [ReadableStreamDefaultReader.prototype.read ( )](https://streams.spec.whatwg.org/#default-reader-read)
+    fn read<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let reader = match require_reader(agent, this_value, gc.nogc()) {
+            Ok(reader) => reader,
+            Err(err) => {
+                let err = err.unbind();
+                let gc = gc.into_nogc();
+                return Ok(Promise::new_rejected(agent, err.value(), gc).into());
+            }
+        };
+        // 1. If this.[[stream]] is undefined, return a promise rejected with a
+        //    TypeError exception.
+        if reader.stream(agent).is_none() {
+            return Ok(reject_released(agent, gc.into_nogc()).into());
+        }
+        // 2. Let promise be a new promise.
+        let promise = PromiseCapability::new(agent, gc.nogc()).promise();
+        let scoped_promise = promise.scope(agent, gc.nogc());
+        // 3. Let readRequest be a new read request with the following items:
+        // NOTE: The read request is represented by the promise.
+        // 4. Perform ! ReadableStreamDefaultReaderRead(this, readRequest).
+        let result = readable_stream_default_reader_read(
+            agent,
+            reader.unbind(),
+            promise.unbind(),
+            gc.reborrow(),
+        )
+        .unbind();
+        let gc = gc.into_nogc();
+        // SAFETY: scoped_promise is not shared.
+        let promise = unsafe { scoped_promise.take(agent) }.bind(gc);
+        if let Err(err) = result {
+            PromiseCapability::from_promise(promise, true).reject(agent, err.value(), gc);
+        }
+        // 5. Return promise.
+        Ok(promise.into())
+    }
+
+    /// ### [ReadableStreamDefaultReader.prototype.releaseLock ( )](https://streams.spec.whatwg.org/#default-reader-release-lock)
+    fn release_lock<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let reader = require_reader(agent, this_value, gc)?;
+        // 1. If this.[[stream]] is undefined, return.
+        if reader.stream(agent).is_none() {
+            return Ok(Value::Undefined);
+        }
+        // 2. Perform ! ReadableStreamDefaultReaderRelease(this).
+        readable_stream_default_reader_release(agent, reader, gc);
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.readable_stream_default_reader_prototype();
+        let readable_stream_default_reader_constructor =
+            intrinsics.readable_stream_default_reader();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(6)
+            .with_prototype(object_prototype)
+            .with_constructor_property(readable_stream_default_reader_constructor)
+            .with_builtin_function_getter_property::<ReadableStreamDefaultReaderPrototypeGetClosed>(
+            )
+            .with_builtin_function_property::<ReadableStreamDefaultReaderPrototypeCancel>()
+            .with_builtin_function_property::<ReadableStreamDefaultReaderPrototypeRead>()
+            .with_builtin_function_property::<ReadableStreamDefaultReaderPrototypeReleaseLock>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.ReadableStreamDefaultReader.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_reader<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ReadableStreamDefaultReader<'a>> {
+    ReadableStreamDefaultReader::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a ReadableStreamDefaultReader",
+            gc,
+        )
+    })
+}
+
+fn reject_released<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Promise<'a> {
+    let err = agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "ReadableStreamDefaultReader was released",
+        gc,
+    );
+    Promise::new_rejected(agent, err.value(), gc)
+}
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        ExceptionType, JsResult, Object, Promise, PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder, get,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::WellKnownSymbols,
+};
+
+use super::{ReadableStream, acquire_readable_stream_default_reader, readable_stream_cancel};
+
+pub(crate) struct ReadableStreamPrototype;
+
+struct ReadableStreamPrototypeGetLocked;
+impl Builtin for ReadableStreamPrototypeGetLocked {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_locked;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.locked.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ReadableStreamPrototype::get_locked);
+}
+impl BuiltinGetter for ReadableStreamPrototypeGetLocked {}
+struct ReadableStreamPrototypeCancel;
+impl Builtin for ReadableStreamPrototypeCancel {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.cancel;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ReadableStreamPrototype::cancel);
+}
+struct ReadableStreamPrototypeGetReader;
+impl Builtin for ReadableStreamPrototypeGetReader {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getReader;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ReadableStreamPrototype::get_reader);
+}
+
+impl ReadableStreamPrototype {
+    /// ### [get ReadableStream.prototype.locked](https://streams.spec.whatwg.org/#rs-locked)
+    fn get_locked<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let stream = require_readable_stream(agent, this_value, gc)?;
+        // 1. Return ! IsReadableStreamLocked(this).
+        Ok(stream.is_locked(agent).into())
+    }
+
+    /// ### [ReadableStream.prototype.cancel ( reason )](https://streams.spec.whatwg.org/#rs-cancel)
+    fn cancel<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let reason = arguments.get(0).bind(gc.nogc());
+        let stream = match require_readable_stream(agent, this_value, gc.nogc()) {
+            Ok(stream) => stream,
+            Err(err) => {
+                let err = err.unbind();
+                let gc = gc.into_nogc();
+                return Ok(Promise::new_rejected(agent, err.value(), gc).into());
+            }
+        };
+        // 1. If ! IsReadableStreamLocked(this) is true, return a promise
+        //    rejected with a TypeError exception.
+        if stream.is_locked(agent) {
+            let gc = gc.into_nogc();
+            let err = agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "ReadableStream is locked",
+                gc,
+            );
+            return Ok(Promise::new_rejected(agent, err.value(), gc).into());
+        }
+        // 2. Return ! ReadableStreamCancel(this, reason).
+        Ok(readable_stream_cancel(agent, stream.unbind(), reason.unbind(), gc).into())
+    }
+
+    /// ### [ReadableStream.prototype.getReader ( options )](https://streams.spec.whatwg.org/#rs-get-reader)
+    fn get_reader<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(0).bind(gc.nogc());
+        let stream = require_readable_stream(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // NOTE: The options argument is converted to a
+        // ReadableStreamGetReaderOptions dictionary.
+        match options {
+            Value::Undefined | Value::Null => {}
+            _ => {
+                let Ok(options) = Object::try_from(options) else {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "options is not an object",
+                        gc.into_nogc(),
+                    ));
+                };
+                let scoped_stream = stream.scope(agent, gc.nogc());
+                let mode = get(
+                    agent,
+                    options.unbind(),
+                    BUILTIN_STRING_MEMORY.mode.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 2. Assert: options["mode"] is "byob".
+                // 3. Return ? AcquireReadableStreamBYOBReader(this).
+                // NOTE: BYOB readers are not supported.
+                if !mode.is_undefined() {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "ReadableStream reader mode is not supported",
+                        gc.into_nogc(),
+                    ));
+                }
+                let gc = gc.into_nogc();
+                // SAFETY: scoped_stream is not shared.
+                let stream = unsafe { scoped_stream.take(agent) }.bind(gc);
+                return acquire_readable_stream_default_reader(agent, stream, gc)
+                    .map(|reader| reader.into());
+            }
+        }
+        // 1. If options["mode"] does not exist, return ?
+        //    AcquireReadableStreamDefaultReader(this).
+        let stream = stream.unbind();
+        let gc = gc.into_nogc();
+        acquire_readable_stream_default_reader(agent, stream.bind(gc), gc)
+            .map(|reader| reader.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.readable_stream_prototype();
+        let readable_stream_constructor = intrinsics.readable_stream();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(5)
+            .with_prototype(object_prototype)
+            .with_constructor_property(readable_stream_constructor)
+            .with_builtin_function_getter_property::<ReadableStreamPrototypeGetLocked>()
+            .with_builtin_function_property::<ReadableStreamPrototypeCancel>()
+            .with_builtin_function_property::<ReadableStreamPrototypeGetReader>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.ReadableStream.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+fn require_readable_stream<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ReadableStream<'a>> {
+    ReadableStream::try_from_value(agent, value.bind(gc)).ok_or_else(|| {
+        agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a ReadableStream",
+            gc,
+        )
+    })
+}
@@ -19,25 +19,57 @@
 //! - This is inspired by and/or copied from Kiesel engine:
 //!   Copyright (c) 2023-2024 Linus Groh
 
+mod allocation_tracking;
+mod builder;
+mod debugger;
+mod fatal_error;
+mod fuel;
+mod heap_statistics;
+mod memory_limit;
+mod stack_capture;
+mod termination;
+
+pub(crate) use allocation_tracking::AllocationTracker;
+pub use allocation_tracking::{AllocationReport, AllocationSite, AllocationSiteStats};
+pub use builder::AgentBuilder;
+pub use debugger::{DebugBinding, DebugScope, DebugScopeKind};
+pub(crate) use fatal_error::invariant_violation;
+pub use fatal_error::{FatalError, FatalErrorKind};
+pub use heap_statistics::{HeapStatistics, HeapVectorStatistics};
+pub use stack_capture::{STACK_TRACE_LIMIT, StackFrame};
+pub use termination::{TerminationHandle, TimedOut};
+
 use ahash::AHashMap;
+use rand::{RngExt, SeedableRng, rngs::SmallRng};
 
+#[cfg(feature = "web-abort")]
+use crate::ecmascript::AbortSignalTimeoutJob;
 #[cfg(test)]
 use crate::ecmascript::GlobalEnvironment;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::SharedArrayBuffer;
 #[cfg(feature = "atomics")]
 use crate::ecmascript::WaitAsyncJob;
+#[cfg(feature = "web-fetch")]
+use crate::ecmascript::{FetchRequest, PendingFetch};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{FinalizationRegistryCleanupJob, clear_kept_objects};
+#[cfg(feature = "futures")]
+use crate::ecmascript::{PromiseFutureJob, PromiseFutureSlot};
+#[cfg(debug_assertions)]
+use crate::engine::UnrootedReference;
 use crate::{
     ecmascript::{
-        AbstractModuleMethods, Environment, ErrorHeapData, ExecutionContext, Function,
-        GraphLoadingStateRecord, HostDefined, ModuleRequest, Object, OrdinaryObject,
-        PrivateEnvironment, PrivateName, Promise, PromiseReactionJob, PromiseResolveThenableJob,
-        PropertyKey, PropertyLookupCache, Realm, RealmRecord, Reference, Referrer, ScriptOrModule,
-        SourceCode, SourceTextModule, String, Symbol, Value, ValueRootRepr,
+        AbstractModuleMethods, Environment, Error, ErrorHeapData, EvaluationOptions,
+        ExecutionContext, FrameMetadata, Function, GraphLoadingStateRecord, HostDefined,
+        ImportAttributesError, LoadedSource, ModuleRequest, ModuleType, NativeModuleBuilder,
+        NativeModuleDefinition, NativeModuleInit, NumberStringCache, Object, OrdinaryObject,
+        PendingDynamicImportRecord, PrivateEnvironment, PrivateName, Promise, PromiseReactionJob,
+        PromiseResolveThenableJob, PropertyKey, PropertyLookupCache, Realm, RealmRecord, Reference,
+        Referrer, Script, ScriptOrModule, SourceCode, SourceKind, SourceRegistry, SourceTextModule,
+        String, Symbol, SynchronousDynamicImport, Value, ValueRootRepr, detect_source_kind,
         get_identifier_reference, initialize_default_realm, initialize_host_defined_realm,
-        parse_script, script_evaluation, to_string, try_get_identifier_reference,
+        parse_module, parse_script, script_evaluation, to_string, try_get_identifier_reference,
     },
     engine::{
         Bindable, GcScope, Global, HeapRootCollection, HeapRootData, HeapRootRef, NoGcScope,
@@ -50,13 +82,16 @@ use crate::{
     ndt,
 };
 
-use core::{any::Any, cell::RefCell, ops::ControlFlow, ptr::NonNull};
-use std::collections::TryReserveError;
+use core::{any::Any, cell::RefCell, ops::ControlFlow, panic::AssertUnwindSafe, ptr::NonNull};
+use oxc_diagnostics::OxcDiagnostic;
+use std::{collections::TryReserveError, rc::Rc};
 
 /// Creation options for [`GcAgent`].
 ///
+/// See [`AgentBuilder`] for a convenient way to set these.
+///
 /// [`GcAgent`]: GcAgent
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct AgentOptions {
     /// Stops the Agent from performing any garbage collection.
     pub disable_gc: bool,
@@ -67,6 +102,142 @@ pub struct AgentOptions {
     /// calling `Atomics.wait()` will throw an error to signal that blocking the
     /// main thread is not allowed.
     pub no_block: bool,
+    /// Number of bytes that can be allocated on the heap before the Agent
+    /// performs garbage collection at its next opportunity. Defaults to 2 MiB.
+    pub gc_allocation_threshold: usize,
+    /// Seeds the Agent's random number generator, making `Math.random()`
+    /// deterministic. If not set, random values are drawn from the thread's
+    /// random number generator.
+    pub random_seed: Option<u64>,
+    /// Makes the Agent check during garbage collection that promise
+    /// combinators such as `Promise.all` do not retain values after their
+    /// result promise has settled, and terminate with a [`FatalError`] if
+    /// they do. Intended for debugging the engine.
+    pub check_promise_retention: bool,
+    /// Number of entries in the Agent's cache of Number to String
+    /// conversions, rounded up to a power of two. Zero disables the cache.
+    /// Defaults to 256.
+    pub number_string_cache_size: usize,
+    /// Makes `import()` link and evaluate the imported module before
+    /// returning if the host loads the module graph synchronously, so that
+    /// the returned promise is already settled. Intended for embedders
+    /// without an event loop.
+    ///
+    /// > Note: This deviates from the specification, which always links and
+    /// > evaluates dynamically imported modules in a later promise job.
+    pub synchronous_dynamic_import: bool,
+    /// Maximum number of arguments that a single function call can receive.
+    /// Calls with more arguments, such as `f(...array)` or `f.apply(null,
+    /// array)` with a longer array, throw a RangeError instead of building
+    /// the argument list. Defaults to 2^20.
+    pub max_arguments_length: usize,
+    /// Makes the Agent attribute its heap allocations to the JavaScript
+    /// function, Script or Module that made them, taking a sample every time
+    /// at least this many bytes have been allocated. Zero disables allocation
+    /// tracking. Defaults to zero.
+    ///
+    /// See [`Agent::allocation_report`].
+    pub allocation_sample_interval: usize,
+    /// Makes the Agent keep every variable in an Environment Record instead
+    /// of storing variables that are not captured by closures in VM stack
+    /// slots, so that debuggers can inspect and change them. This slows down
+    /// execution and only affects code parsed after it is set.
+    ///
+    /// See [`Agent::debug_scope`].
+    pub debug_scopes: bool,
+    /// Makes the Agent call [`HostHooks::heap_threshold_exceeded`] after
+    /// every garbage collection that leaves more than this many bytes on the
+    /// heap, counting heap vectors and data blocks. Zero disables the
+    /// notification. Defaults to zero.
+    ///
+    /// See [`Agent::heap_statistics`].
+    pub heap_size_notification_threshold: usize,
+    /// Maximum number of bytes that the Agent's heap, including ArrayBuffer
+    /// and SharedArrayBuffer data blocks, may use. Zero means no limit.
+    /// Defaults to zero.
+    ///
+    /// Creating an ArrayBuffer or growing one beyond the limit throws a
+    /// RangeError. Other allocations that exceed the limit terminate the
+    /// running JavaScript code after a garbage collection fails to free
+    /// enough memory; see [`HostHooks::memory_limit_exceeded`].
+    pub memory_limit: usize,
+    /// Amount of fuel that the Agent starts with, if fuel metering is
+    /// enabled. Each bytecode instruction executed consumes one unit of
+    /// fuel. Defaults to None, which disables fuel metering.
+    ///
+    /// See [`Agent::set_fuel`] and [`HostHooks::fuel_exhausted`].
+    pub fuel: Option<u64>,
+}
+
+impl Default for AgentOptions {
+    fn default() -> Self {
+        Self {
+            disable_gc: false,
+            print_internals: false,
+            no_block: false,
+            gc_allocation_threshold: 1024 * 1024 * 2,
+            random_seed: None,
+            check_promise_retention: false,
+            number_string_cache_size: 256,
+            synchronous_dynamic_import: false,
+            max_arguments_length: 1 << 20,
+            allocation_sample_interval: 0,
+            debug_scopes: false,
+            heap_size_notification_threshold: 0,
+            memory_limit: 0,
+            fuel: None,
+        }
+    }
+}
+
+/// Runtime statistics of an Agent's memory management.
+///
+/// See [`GcAgent::gc_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GcStats {
+    pub(crate) collections: u64,
+    pub(crate) allocated_bytes: u64,
+    pub(crate) vm_frames_allocated: u64,
+    pub(crate) vm_frames_reused: u64,
+    pub(crate) number_string_cache_hits: u64,
+    pub(crate) number_string_cache_misses: u64,
+}
+
+impl GcStats {
+    /// Number of garbage collections performed.
+    pub fn collections(&self) -> u64 {
+        self.collections
+    }
+
+    /// Number of bytes allocated on the heap over the Agent's lifetime,
+    /// including memory that has since been garbage collected.
+    pub fn allocated_bytes(&self) -> u64 {
+        self.allocated_bytes
+    }
+
+    /// Number of bytecode VM frames allocated for function calls and script
+    /// evaluations.
+    pub fn vm_frames_allocated(&self) -> u64 {
+        self.vm_frames_allocated
+    }
+
+    /// Number of function calls and script evaluations that reused a pooled
+    /// VM frame instead of allocating a new one.
+    pub fn vm_frames_reused(&self) -> u64 {
+        self.vm_frames_reused
+    }
+
+    /// Number of Number to String conversions answered from the Agent's
+    /// conversion cache.
+    pub fn number_string_cache_hits(&self) -> u64 {
+        self.number_string_cache_hits
+    }
+
+    /// Number of Number to String conversions that missed the Agent's
+    /// conversion cache and were formatted anew.
+    pub fn number_string_cache_misses(&self) -> u64 {
+        self.number_string_cache_misses
+    }
 }
 
 /// Result of methods that may throw a JavaScript error.
@@ -105,6 +276,19 @@ impl<'a> JsError<'a> {
     pub fn to_string<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> String<'gc> {
         to_string(agent, self.0, gc).unwrap()
     }
+
+    /// Returns true if this error terminates the running JavaScript code; see
+    /// [`TerminationHandle`].
+    ///
+    /// The termination error is the one thrown by the engine when termination
+    /// is requested or the Agent runs out of fuel; other errors, even if they
+    /// are still propagating when termination is requested, are not. Like
+    /// `catch` and `finally` blocks, Promises and async functions pass the
+    /// termination error on to their caller instead of turning it into a
+    /// rejection.
+    pub fn is_termination(self, agent: &Agent) -> bool {
+        agent.termination_error == Some(self.unbind())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -260,6 +444,10 @@ pub(crate) enum InnerJob {
     WaitAsync(WaitAsyncJob),
     #[cfg(feature = "weak-refs")]
     FinalizationRegistry(FinalizationRegistryCleanupJob),
+    #[cfg(feature = "futures")]
+    Future(PromiseFutureJob),
+    #[cfg(feature = "web-abort")]
+    AbortSignalTimeout(AbortSignalTimeoutJob),
 }
 
 /// # [Job](https://tc39.es/ecma262/#sec-jobs)
@@ -306,6 +494,7 @@ impl Job {
                 function: None,
                 realm,
                 script_or_module: None,
+                frame: FrameMetadata::NONE,
             });
             pushed_context = true;
         }
@@ -316,10 +505,11 @@ impl Job {
             #[cfg(feature = "atomics")]
             InnerJob::WaitAsync(job) => job.run(agent, gc),
             #[cfg(feature = "weak-refs")]
-            InnerJob::FinalizationRegistry(job) => {
-                job.run(agent, gc);
-                Ok(())
-            }
+            InnerJob::FinalizationRegistry(job) => job.run(agent, gc),
+            #[cfg(feature = "futures")]
+            InnerJob::Future(job) => job.run(agent, gc),
+            #[cfg(feature = "web-abort")]
+            InnerJob::AbortSignalTimeout(job) => job.run(agent, gc),
         };
 
         if pushed_context {
@@ -343,6 +533,22 @@ pub enum PromiseRejectionTrackerOperation {
     Handle,
 }
 
+/// Parameter to the [`HostHooks::before_gc`] and [`HostHooks::after_gc`]
+/// embedder hooks describing why a garbage collection was started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcReason {
+    /// The collection was requested explicitly, eg. through [`GcAgent::gc`]
+    /// or [`Agent::gc`].
+    Explicit,
+    /// The bytecode VM started the collection because more than
+    /// [`AgentOptions::gc_allocation_threshold`] bytes were allocated since
+    /// the last collection.
+    AllocationThreshold,
+    /// The bytecode VM started the collection because the Agent's heap was
+    /// estimated to use more than [`AgentOptions::memory_limit`] bytes.
+    MemoryLimit,
+}
+
 #[derive(Clone, Copy, Default, PartialEq, Eq)]
 #[cfg(feature = "shared-array-buffer")]
 /// Parameter to [HostGrowSharedArrayBuffer] embedder hook.
@@ -444,6 +650,221 @@ pub trait HostHooks: core::fmt::Debug {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// Dequeue the oldest Job enqueued using [`enqueue_promise_job`].
+    ///
+    /// This is used by [`Agent::perform_microtask_checkpoint`] to drain the
+    /// host's microtask queue. The default implementation returns `None`.
+    ///
+    /// [`enqueue_promise_job`]: HostHooks::enqueue_promise_job
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        None
+    }
+
+    /// Dequeue the oldest Job enqueued using
+    /// [`enqueue_finalization_registry_cleanup_job`].
+    ///
+    /// This is used by [`Agent::perform_microtask_checkpoint`] to run
+    /// FinalizationRegistry cleanup callbacks. The default implementation
+    /// returns `None`.
+    ///
+    /// [`enqueue_finalization_registry_cleanup_job`]: HostHooks::enqueue_finalization_registry_cleanup_job
+    #[cfg(feature = "weak-refs")]
+    fn dequeue_finalization_registry_cleanup_job(&self) -> Option<Job> {
+        None
+    }
+
+    /// ### [report an exception](https://html.spec.whatwg.org/multipage/webappapis.html#report-an-exception)
+    ///
+    /// Called by [`Agent::perform_microtask_checkpoint`] when a Job throws an
+    /// error, and when a web API event handler throws an error. The default
+    /// implementation ignores the error.
+    #[allow(unused_variables)]
+    fn report_job_error(&self, agent: &mut Agent, error: JsError, gc: NoGcScope) {}
+
+    /// ### [notify about rejected promises](https://html.spec.whatwg.org/multipage/webappapis.html#notify-about-rejected-promises)
+    ///
+    /// Called by [`Agent::perform_microtask_checkpoint`] for each Promise that
+    /// was rejected without handlers and still had no handlers at the end of
+    /// the checkpoint, with the Promise's rejection reason. The default
+    /// implementation ignores the rejection.
+    #[allow(unused_variables)]
+    fn report_unhandled_rejection(
+        &self,
+        agent: &mut Agent,
+        promise: Promise,
+        reason: Value,
+        gc: NoGcScope,
+    ) {
+    }
+
+    /// Called before each garbage collection with the reason for the
+    /// collection and the statistics gathered so far.
+    ///
+    /// All heap handles are still valid at this point. Embedders can use this
+    /// to quiesce external resources that refer to heap data, such as FFI
+    /// handle tables. The default implementation does nothing.
+    #[allow(unused_variables)]
+    fn before_gc(&self, reason: GcReason, stats: GcStats) {}
+
+    /// Called after each garbage collection with the reason for the
+    /// collection and the updated statistics.
+    ///
+    /// Heap data may have moved during the collection, so any embedder caches
+    /// keyed by heap indexes must be dropped or rebuilt here. The default
+    /// implementation does nothing.
+    #[allow(unused_variables)]
+    fn after_gc(&self, reason: GcReason, stats: GcStats) {}
+
+    /// Called after a garbage collection that left more bytes on the heap
+    /// than [`AgentOptions::heap_size_notification_threshold`].
+    ///
+    /// Embedders can use this to enforce memory limits, for instance by
+    /// terminating execution using a [`TerminationHandle`]. The default
+    /// implementation does nothing.
+    #[allow(unused_variables)]
+    fn heap_threshold_exceeded(&self, statistics: &HeapStatistics) {}
+
+    /// Called when the Agent terminates the running JavaScript code because
+    /// its heap uses more than [`AgentOptions::memory_limit`] bytes even
+    /// after garbage collection.
+    ///
+    /// Termination is requested through the Agent's [`TerminationHandle`]
+    /// and stays in effect until the embedder cancels it. The default
+    /// implementation does nothing.
+    #[allow(unused_variables)]
+    fn memory_limit_exceeded(&self, statistics: &HeapStatistics) {}
+
+    /// Called when the Agent runs out of fuel; see [`AgentOptions::fuel`].
+    ///
+    /// Returning an amount of fuel refuels the Agent and lets the running
+    /// JavaScript code continue, which can be used to implement time slicing.
+    /// Returning None terminates the running JavaScript code; the termination
+    /// stays in effect until the Agent is refuelled using [`Agent::set_fuel`].
+    /// It does not affect the Agent's [`TerminationHandle`]. The default
+    /// implementation returns None.
+    fn fuel_exhausted(&self) -> Option<u64> {
+        None
+    }
+
+    /// ### [14.16 The debugger Statement](https://tc39.es/ecma262/#sec-debugger-statement)
+    ///
+    /// Called when a `debugger` statement is evaluated. The JavaScript code
+    /// stays paused until the hook returns; see [`Agent::debug_scope`] for
+    /// inspecting its variables. The default implementation does nothing.
+    #[allow(unused_variables)]
+    fn debugger_statement(&self, agent: &mut Agent, gc: GcScope) {}
+
+    /// Returns the current time as a number of milliseconds since the epoch,
+    /// ie. midnight at the beginning of 1 January 1970 UTC.
+    ///
+    /// This is the time value identifying the current time in `Date.now()`,
+    /// `new Date()` and `Date()`. Hosts can return a fixed or simulated time
+    /// for deterministic execution. The default implementation reads the
+    /// system clock.
+    #[cfg(feature = "date")]
+    fn current_time(&self) -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as i64
+    }
+
+    /// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
+    ///
+    /// Returns the host environment's current time zone, used by the local
+    /// time functions of Date. This is either a UTC offset such as "+01:00",
+    /// or a time zone identifier whose offsets are given by
+    /// [`get_named_time_zone_offset_nanoseconds`]. The default implementation
+    /// returns "UTC".
+    ///
+    /// [`get_named_time_zone_offset_nanoseconds`]: HostHooks::get_named_time_zone_offset_nanoseconds
+    #[cfg(feature = "date")]
+    fn system_time_zone_identifier(&self) -> &str {
+        "UTC"
+    }
+
+    /// ### [21.4.1.21 GetNamedTimeZoneOffsetNanoseconds ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/ecma262/#sec-getnamedtimezoneoffsetnanoseconds)
+    ///
+    /// Returns the offset from UTC of the named time zone at the instant
+    /// `epoch_nanoseconds`, both in nanoseconds. This is called with the
+    /// [`system_time_zone_identifier`] when it is not a UTC offset.
+    ///
+    /// The engine assumes that the offset of a time zone changes at most once
+    /// within any 24 hours. The default implementation returns 0, as for
+    /// UTC.
+    ///
+    /// [`system_time_zone_identifier`]: HostHooks::system_time_zone_identifier
+    #[cfg(feature = "date")]
+    #[allow(unused_variables)]
+    fn get_named_time_zone_offset_nanoseconds(
+        &self,
+        time_zone_identifier: &str,
+        epoch_nanoseconds: i128,
+    ) -> i64 {
+        0
+    }
+
+    /// Performs the network request of a `fetch()` call.
+    ///
+    /// The host performs the request in whatever way it sees fit and passes
+    /// the response, or an error message for a network error, to
+    /// [`Agent::finish_fetch`] together with `pending`. This can be done
+    /// synchronously within the hook or later, for example from a job. The
+    /// promise returned by the `fetch()` call stays pending until then.
+    ///
+    /// The default implementation finishes every request with a network
+    /// error.
+    #[cfg(feature = "web-fetch")]
+    #[allow(unused_variables)]
+    fn fetch(&self, agent: &mut Agent, request: FetchRequest, pending: PendingFetch, gc: GcScope) {
+        agent.finish_fetch(pending, Err("fetch is not supported".to_owned()), gc);
+    }
+
+    /// Formats the `stack` property of an Error object.
+    ///
+    /// Called when the `stack` accessor of Error.prototype is read, with the
+    /// error and the first line of the default format, ie. the result of
+    /// Error.prototype.toString. The frames captured when the error was
+    /// created are available through [`Error::stack_trace`].
+    ///
+    /// Returning `None` uses the default format, which lists the frames on
+    /// separate lines as described by [`StackFrame::describe`]. The default
+    /// implementation returns `None`.
+    #[allow(unused_variables)]
+    fn prepare_stack_trace<'gc>(
+        &self,
+        agent: &mut Agent,
+        error: Error,
+        header: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<String<'gc>> {
+        None
+    }
+
+    /// Resolves a module specifier imported by `referrer` into the key of the
+    /// module it refers to, such as by expanding relative paths against the
+    /// referrer's location.
+    ///
+    /// The resolved key is used to look up modules registered with
+    /// [`Agent::register_module`] and [`Agent::load_module`] for both static
+    /// imports and `import()` calls. Requests that match neither are passed
+    /// on to [`load_imported_module`] with the original specifier; hosts can
+    /// call this method from there to resolve it the same way.
+    ///
+    /// Returning `None` uses the specifier as written. The default
+    /// implementation always returns `None`.
+    ///
+    /// [`load_imported_module`]: HostHooks::load_imported_module
+    #[allow(unused_variables)]
+    fn resolve_module_specifier(
+        &self,
+        agent: &Agent,
+        referrer: Referrer,
+        specifier: &str,
+    ) -> Option<std::string::String> {
+        None
+    }
+
     /// ### [16.2.1.10 HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )](https://tc39.es/ecma262/#sec-HostLoadImportedModule)
     ///
     /// The host-defined abstract operation HostLoadImportedModule takes
@@ -507,6 +928,11 @@ pub trait HostHooks: core::fmt::Debug {
     /// > imported with `type: "json"` (and `HostLoadImportedModule` completes
     /// > normally), but it does not prohibit hosts from supporting JSON
     /// > modules when imported without `type: "json"`.
+    ///
+    /// If `payload` belongs to an `import()` call, see
+    /// [`GraphLoadingStateRecord::is_dynamic_import`], the host may finish
+    /// loading asynchronously by calling [`Agent::defer_dynamic_import`] and
+    /// later [`Agent::finish_dynamic_import`].
     #[allow(unused_variables)]
     fn load_imported_module<'gc>(
         &self,
@@ -547,6 +973,38 @@ pub trait HostHooks: core::fmt::Debug {
         &[]
     }
 
+    /// Validate the import attributes of a module request and choose the
+    /// type of module to load for it.
+    ///
+    /// This is called for both static and dynamic imports before the
+    /// module is loaded. The chosen type is stored on the request and can
+    /// be read in [`HostHooks::load_imported_module`] using
+    /// [`ModuleRequest::module_type`]. Only JavaScript module requests are
+    /// satisfied by native and registered modules.
+    ///
+    /// Returning an error fails loading of the request: the error is thrown
+    /// as a TypeError before the module graph is linked, or rejects the
+    /// promise returned by `import()`.
+    ///
+    /// The default implementation chooses a JavaScript module if the
+    /// request has no `type` attribute, a JSON module for `type: "json"`,
+    /// and reports any other `type` as unsupported.
+    fn validate_import_attributes(
+        &self,
+        agent: &Agent,
+        module_request: ModuleRequest,
+    ) -> Result<ModuleType, ImportAttributesError> {
+        let Some(module_type) = module_request.attribute(agent, "type") else {
+            return Ok(ModuleType::JavaScript);
+        };
+        match module_type.as_str(agent) {
+            Some("json") => Ok(ModuleType::Json),
+            _ => Err(ImportAttributesError::UnsupportedType(
+                module_type.to_string_lossy(agent).into_owned(),
+            )),
+        }
+    }
+
     /// ### [13.3.12.1.1 HostGetImportMetaProperties ( moduleRecord )](https://tc39.es/ecma262/#sec-hostgetimportmetaproperties)
     ///
     /// The host-defined abstract operation HostGetImportMetaProperties takes
@@ -685,6 +1143,8 @@ pub trait HostHooks: core::fmt::Debug {
 pub struct GcAgent {
     agent: Agent,
     realm_roots: Vec<Option<Realm<'static>>>,
+    /// The fatal error that terminated the Agent, if any.
+    fatal_error: Option<FatalError>,
 }
 
 /// # ECMAScript Realm root
@@ -713,10 +1173,13 @@ impl RealmRoot {
 
 impl GcAgent {
     /// Create a new JavaScript engine.
+    ///
+    /// See [`AgentBuilder`] for configuring the engine.
     pub fn new(options: AgentOptions, host_hooks: &'static dyn HostHooks) -> Self {
         Self {
             agent: Agent::new(options, host_hooks),
             realm_roots: Vec::with_capacity(1),
+            fatal_error: None,
         }
     }
 
@@ -793,10 +1256,17 @@ impl GcAgent {
     }
 
     /// Run a closure inside a given realm.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the Agent has been terminated by a [`FatalError`], or if the
+    /// engine panics while running the closure. See
+    /// [`GcAgent::try_run_in_realm`] for a variant that does not panic.
     pub fn run_in_realm<F, R>(&mut self, realm: &RealmRoot, func: F) -> R
     where
         F: for<'agent, 'gc, 'scope> FnOnce(&'agent mut Agent, GcScope<'gc, 'scope>) -> R,
     {
+        self.assert_not_terminated();
         let realm = self.get_realm_by_root(realm);
         assert!(self.agent.execution_context_stack.is_empty());
         let result = self.agent.run_in_realm(realm, func);
@@ -809,6 +1279,12 @@ impl GcAgent {
     }
 
     /// Run a macrotask job.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the Agent has been terminated by a [`FatalError`], or if the
+    /// engine panics while running the job. See [`GcAgent::try_run_job`] for
+    /// a variant that does not panic.
     pub fn run_job<F, R>(&mut self, job: Job, then: F) -> R
     where
         F: for<'agent, 'gc, 'scope> FnOnce(
@@ -817,6 +1293,7 @@ impl GcAgent {
             GcScope<'gc, 'scope>,
         ) -> R,
     {
+        self.assert_not_terminated();
         assert!(self.agent.execution_context_stack.is_empty());
         let result = self.agent.run_job(job, then);
         #[cfg(feature = "weak-refs")]
@@ -827,6 +1304,86 @@ impl GcAgent {
         result
     }
 
+    /// Run a closure inside a given realm, converting any engine panic into a
+    /// [`FatalError`].
+    ///
+    /// JavaScript exceptions are not fatal errors: they are returned by the
+    /// closure as usual. If the engine or embedder code called by the engine
+    /// panics, the Agent is terminated and no further code can be run in it.
+    /// Calling this method on a terminated Agent returns a [`FatalError`] of
+    /// kind [`FatalErrorKind::Terminated`] without running the closure.
+    pub fn try_run_in_realm<F, R>(&mut self, realm: &RealmRoot, func: F) -> Result<R, FatalError>
+    where
+        F: for<'agent, 'gc, 'scope> FnOnce(&'agent mut Agent, GcScope<'gc, 'scope>) -> R,
+    {
+        self.catch_fatal_error(|agent| agent.run_in_realm(realm, func))
+    }
+
+    /// Run a macrotask job, converting any engine panic into a
+    /// [`FatalError`].
+    ///
+    /// See [`GcAgent::try_run_in_realm`].
+    pub fn try_run_job<F, R>(&mut self, job: Job, then: F) -> Result<R, FatalError>
+    where
+        F: for<'agent, 'gc, 'scope> FnOnce(
+            &'agent mut Agent,
+            JsResult<'_, ()>,
+            GcScope<'gc, 'scope>,
+        ) -> R,
+    {
+        self.catch_fatal_error(|agent| agent.run_job(job, then))
+    }
+
+    /// Returns the fatal error that terminated the Agent, if any.
+    pub fn fatal_error(&self) -> Option<&FatalError> {
+        self.fatal_error.as_ref()
+    }
+
+    fn catch_fatal_error<R>(&mut self, func: impl FnOnce(&mut Self) -> R) -> Result<R, FatalError> {
+        if let Some(error) = &self.fatal_error {
+            return Err(FatalError::terminated(error));
+        }
+        match std::panic::catch_unwind(AssertUnwindSafe(|| func(self))) {
+            Ok(result) => Ok(result),
+            Err(payload) => {
+                let error = FatalError::from_panic(payload);
+                self.fatal_error = Some(error.clone());
+                Err(error)
+            }
+        }
+    }
+
+    fn assert_not_terminated(&self) {
+        if let Some(error) = &self.fatal_error {
+            panic!("{}", FatalError::terminated(error));
+        }
+    }
+
+    /// Perform a microtask checkpoint in the given Realm.
+    ///
+    /// See [`Agent::perform_microtask_checkpoint`].
+    pub fn perform_microtask_checkpoint(&mut self, realm: &RealmRoot) {
+        self.run_in_realm(realm, |agent, gc| agent.perform_microtask_checkpoint(gc));
+    }
+
+    /// Register a native module under the given module specifier.
+    ///
+    /// See [`Agent::register_module`].
+    pub fn register_module<F>(&mut self, specifier: &str, init: F)
+    where
+        F: for<'gc, 'scope> Fn(&mut Agent, &mut NativeModuleBuilder<'gc>, NoGcScope<'gc, 'scope>)
+            + 'static,
+    {
+        self.agent.register_module(specifier, init);
+    }
+
+    /// Get the Agent's memory management statistics.
+    ///
+    /// See [`Agent::gc_stats`].
+    pub fn gc_stats(&self) -> GcStats {
+        self.agent.gc_stats()
+    }
+
     fn get_realm_by_root(&self, realm_root: &RealmRoot) -> Realm<'static> {
         let index = realm_root.index;
         let error_message = "Couldn't find Realm by RealmRoot";
@@ -843,8 +1400,8 @@ impl GcAgent {
     ///
     /// [`RealmRoot`]: RealmRoot
     pub fn gc(&mut self) {
-        if self.agent.options.disable_gc {
-            // GC is disabled; no-op
+        if self.agent.options.disable_gc || self.fatal_error.is_some() {
+            // GC is disabled or the heap may be inconsistent; no-op
             return;
         }
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
@@ -852,7 +1409,7 @@ impl GcAgent {
         let Self {
             agent, realm_roots, ..
         } = self;
-        heap_gc(agent, realm_roots, gc);
+        heap_gc(agent, realm_roots, GcReason::Explicit, gc);
     }
 }
 
@@ -878,8 +1435,30 @@ pub struct Agent {
     pub(crate) stack_refs: RefCell<Vec<HeapRootData>>,
     /// Temporary storage for on-stack heap root collections.
     pub(crate) stack_ref_collections: RefCell<Vec<HeapRootCollection>>,
+    /// Unbound heap references recorded for rooting validation.
+    #[cfg(debug_assertions)]
+    pub(crate) unrooted_references: RefCell<Vec<UnrootedReference>>,
     /// Temporary storage for on-stack VMs.
     pub(crate) vm_stack: Vec<NonNull<Vm>>,
+    /// Idle VM frames kept for reuse by later function calls.
+    pub(crate) vm_frame_pool: Vec<Vm>,
+    /// Memory management statistics.
+    pub(crate) gc_stats: GcStats,
+    /// Number of bytes that the heap used after the last garbage collection,
+    /// if a memory limit is set.
+    pub(crate) retained_bytes: usize,
+    /// Recent results of Number to String conversions.
+    pub(crate) number_string_cache: NumberStringCache,
+    /// Sampled heap allocations per call site, if enabled.
+    pub(crate) allocation_tracker: Option<AllocationTracker>,
+    /// The `import()` call currently loading its module graph, if it may
+    /// complete synchronously.
+    pub(crate) synchronous_dynamic_import: Option<SynchronousDynamicImport>,
+    /// `import()` calls whose module the host is loading asynchronously.
+    pub(crate) pending_dynamic_imports: Vec<Option<PendingDynamicImportRecord>>,
+    /// Rust futures awaiting the settlement of a Promise.
+    #[cfg(feature = "futures")]
+    pub(crate) promise_futures: Vec<Option<Rc<RefCell<PromiseFutureSlot>>>>,
     /// ### \[\[KeptAlive]]
     ///
     /// > Note: instead of storing objects in a list here, we only store a
@@ -894,12 +1473,38 @@ pub struct Agent {
     /// \[\[AsyncEvaluationOrder]] field of modules that are asynchronous or
     /// have asynchronous dependencies.
     module_async_evaluation_count: u32,
+    /// Seeded random number generator, if a seed was given in the options.
+    rng: Option<SmallRng>,
+    /// Native modules registered by the embedder.
+    pub(crate) native_modules: Vec<NativeModuleDefinition>,
+    /// ### [about-to-be-notified rejected promises list](https://html.spec.whatwg.org/multipage/webappapis.html#about-to-be-notified-rejected-promises-list)
+    ///
+    /// Promises that were rejected without handlers since the last microtask
+    /// checkpoint.
+    pending_rejections: Vec<Promise<'static>>,
+    /// ### [performing a microtask checkpoint](https://html.spec.whatwg.org/multipage/webappapis.html#performing-a-microtask-checkpoint)
+    performing_microtask_checkpoint: bool,
+    /// Scripts and Modules loaded by the embedder.
+    pub(crate) source_registry: SourceRegistry,
+    /// Termination requests from other threads.
+    termination: TerminationHandle,
+    /// Remaining fuel, if fuel metering is enabled.
+    fuel: Option<u64>,
+    /// True if the Agent ran out of fuel and has not been refuelled since.
+    fuel_exhausted: bool,
+    /// The error that the most recently terminated JavaScript code unwinds
+    /// with.
+    termination_error: Option<JsError<'static>>,
 }
 
 impl Agent {
     pub(crate) fn new(options: AgentOptions, host_hooks: &'static dyn HostHooks) -> Self {
         Self {
             heap: Heap::new(),
+            rng: options.random_seed.map(SmallRng::seed_from_u64),
+            number_string_cache: NumberStringCache::new(options.number_string_cache_size),
+            fuel: options.fuel,
+            allocation_tracker: AllocationTracker::new(options.allocation_sample_interval),
             options,
             symbol_id: 0,
             global_symbol_registry: AHashMap::default(),
@@ -907,11 +1512,37 @@ impl Agent {
             execution_context_stack: Vec::new(),
             stack_refs: RefCell::new(Vec::with_capacity(64)),
             stack_ref_collections: RefCell::new(Vec::with_capacity(32)),
+            #[cfg(debug_assertions)]
+            unrooted_references: RefCell::new(Vec::new()),
             vm_stack: Vec::with_capacity(16),
+            vm_frame_pool: Vec::new(),
+            gc_stats: GcStats::default(),
+            retained_bytes: 0,
+            synchronous_dynamic_import: None,
+            pending_dynamic_imports: Vec::new(),
+            #[cfg(feature = "futures")]
+            promise_futures: Vec::new(),
             #[cfg(feature = "weak-refs")]
             kept_alive: false,
             private_names_counter: 0,
             module_async_evaluation_count: 0,
+            native_modules: Vec::new(),
+            pending_rejections: Vec::new(),
+            performing_microtask_checkpoint: false,
+            source_registry: SourceRegistry::default(),
+            termination: TerminationHandle::default(),
+            fuel_exhausted: false,
+            termination_error: None,
+        }
+    }
+
+    /// Get the Agent's memory management statistics.
+    pub fn gc_stats(&self) -> GcStats {
+        GcStats {
+            // Note: the bytes allocated since the last garbage collection are
+            // added to the total when it runs.
+            allocated_bytes: self.gc_stats.allocated_bytes + self.heap.alloc_counter as u64,
+            ..self.gc_stats
         }
     }
 
@@ -927,6 +1558,12 @@ impl Agent {
     ///
     /// [`Bindable::bind`]: crate::engine::Bindable::bind
     pub fn gc(&mut self, gc: GcScope) {
+        self.gc_with_reason(GcReason::Explicit, gc);
+    }
+
+    /// Perform garbage collection on the Agent's heap, reporting the given
+    /// reason to the host hooks.
+    pub(crate) fn gc_with_reason(&mut self, reason: GcReason, gc: GcScope) {
         let mut root_realms = self
             .heap
             .realms
@@ -934,16 +1571,26 @@ impl Agent {
             .enumerate()
             .map(|(i, _)| Some(Realm::from_index(i)))
             .collect::<Vec<_>>();
-        heap_gc(self, &mut root_realms, gc);
+        heap_gc(self, &mut root_realms, reason, gc);
     }
 
     /// Checks if garbage collection should be performed based on the number of
     /// bytes allocated since last garbage collection.
     pub(crate) fn check_gc(&mut self) -> bool {
-        // Perform garbage collection if over 2 MiB of allocations have been
-        // performed since last GC.
-        const ALLOC_COUNTER_LIMIT: usize = 1024 * 1024 * 2;
-        self.heap.alloc_counter > ALLOC_COUNTER_LIMIT
+        // Perform garbage collection if over the configured amount of
+        // allocations have been performed since last GC.
+        self.heap.alloc_counter > self.options.gc_allocation_threshold
+    }
+
+    /// Get a random number in the range \[0, 1).
+    ///
+    /// The numbers come from a seeded generator if the Agent was created with
+    /// [`AgentOptions::random_seed`].
+    pub(crate) fn random_f64(&mut self) -> f64 {
+        match &mut self.rng {
+            Some(rng) => rng.random::<f64>(),
+            None => rand::random::<f64>(),
+        }
     }
 
     fn get_created_realm_root(&mut self) -> Realm<'static> {
@@ -1021,6 +1668,7 @@ impl Agent {
             function: None,
             realm: realm.unbind(),
             script_or_module: None,
+            frame: FrameMetadata::NONE,
         });
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
         let gc = GcScope::new(&mut gc, &mut scope);
@@ -1050,6 +1698,7 @@ impl Agent {
             function: None,
             realm,
             script_or_module: None,
+            frame: FrameMetadata::NONE,
         });
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
         let mut gc = GcScope::new(&mut gc, &mut scope);
@@ -1118,12 +1767,14 @@ impl Agent {
         gc: NoGcScope<'a, '_>,
     ) -> Value<'a> {
         let message = String::from_static_str(self, message, gc).unbind();
+        let stack = self.capture_stack_trace(gc);
         self.heap
-            .create(ErrorHeapData::new(kind, Some(message), None))
+            .create(ErrorHeapData::new(kind, Some(message), None).with_stack(stack))
             .into()
     }
 
     #[must_use]
+    #[cfg_attr(feature = "intl", allow(dead_code))]
     pub(crate) fn todo<'a>(&mut self, feature: &'static str, gc: NoGcScope<'a, '_>) -> JsError<'a> {
         self.throw_exception(
             ExceptionType::Error,
@@ -1155,9 +1806,10 @@ impl Agent {
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
         let message = String::from_string(self, message, gc).unbind();
+        let stack = self.capture_stack_trace(gc);
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message), None))
+                .create(ErrorHeapData::new(kind, Some(message), None).with_stack(stack))
                 .into(),
         )
     }
@@ -1170,9 +1822,10 @@ impl Agent {
         message: String,
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
+        let stack = self.capture_stack_trace(gc);
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message.unbind()), None))
+                .create(ErrorHeapData::new(kind, Some(message.unbind()), None).with_stack(stack))
                 .bind(gc)
                 .into(),
         )
@@ -1220,6 +1873,24 @@ impl Agent {
         }
     }
 
+    /// Throw a RangeError if a function call would receive more than
+    /// [`AgentOptions::max_arguments_length`] arguments.
+    pub(crate) fn check_arguments_length<'gc>(
+        &mut self,
+        length: usize,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        if length > self.options.max_arguments_length {
+            let message = format!(
+                "Too many arguments in function call: {length} given, at most {} allowed",
+                self.options.max_arguments_length
+            );
+            Err(self.throw_exception(ExceptionType::RangeError, message, gc))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Returns the realm of the previous execution context.
     ///
     /// See steps 6-8 of [27.6.3.8 AsyncGeneratorYield ( value )](https://tc39.es/ecma262/#sec-asyncgeneratoryield).
@@ -1235,10 +1906,12 @@ impl Agent {
     }
 
     pub(crate) fn push_execution_context(&mut self, context: ExecutionContext) {
+        self.sample_allocations();
         self.execution_context_stack.push(context);
     }
 
     pub(crate) fn pop_execution_context(&mut self) -> Option<ExecutionContext> {
+        self.sample_allocations();
         self.execution_context_stack.pop()
     }
 
@@ -1417,33 +2090,349 @@ impl Agent {
         self.host_hooks.get_host_data()
     }
 
+    /// Register a native module under the given module specifier.
+    ///
+    /// Static and dynamic imports of the specifier resolve to a module whose
+    /// exports are defined by the `init` function, without consulting
+    /// [`HostHooks::load_imported_module`]. The `init` function is called
+    /// once for each Realm that imports the module.
+    ///
+    /// Registering the same specifier again replaces the `init` function for
+    /// Realms that have not yet imported the module.
+    ///
+    /// ```rust
+    /// use nova_vm::ecmascript::{AgentBuilder, Behaviour, Value};
+    /// let (mut agent, _realm) = AgentBuilder::new().build_with_default_realm();
+    /// agent.register_module("host:config", |agent, module, gc| {
+    ///     module.export_value(agent, "debug", Value::Boolean(true), gc);
+    /// });
+    /// ```
+    ///
+    /// [`HostHooks::load_imported_module`]: crate::ecmascript::HostHooks::load_imported_module
+    pub fn register_module<F>(&mut self, specifier: &str, init: F)
+    where
+        F: for<'gc, 'scope> Fn(&mut Agent, &mut NativeModuleBuilder<'gc>, NoGcScope<'gc, 'scope>)
+            + 'static,
+    {
+        let init: NativeModuleInit = Rc::new(init);
+        if let Some(definition) = self
+            .native_modules
+            .iter_mut()
+            .find(|definition| &*definition.specifier == specifier)
+        {
+            definition.init = init;
+        } else {
+            self.native_modules.push(NativeModuleDefinition {
+                specifier: specifier.into(),
+                init,
+            });
+        }
+    }
+
     /// Run a script in the current Realm.
+    ///
+    /// This is equivalent to [`Agent::evaluate_script`] with default options.
     pub fn run_script<'gc>(
         &mut self,
         source_text: String,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        self.evaluate_script(source_text, EvaluationOptions::default(), gc)
+    }
+
+    /// Evaluate source text as a Script in the current Realm.
+    ///
+    /// Syntax errors are thrown as SyntaxErrors. Source text containing
+    /// import or export declarations throws a SyntaxError explaining that it
+    /// must be evaluated as a Module.
+    pub fn evaluate_script<'gc>(
+        &mut self,
+        source_text: String,
+        options: EvaluationOptions,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let source_text = source_text.bind(gc.nogc());
         let realm = self.current_realm(gc.nogc());
-        let script = match parse_script(self, source_text, realm, false, None, gc.nogc()) {
+        let EvaluationOptions {
+            strict,
+            host_defined,
+        } = options;
+        let script = match parse_script(self, source_text, realm, strict, host_defined, gc.nogc()) {
             Ok(script) => script,
-            Err(err) => {
+            Err(errors) => {
+                let is_module = matches!(
+                    detect_source_kind(&source_text.to_string_lossy(self)),
+                    Ok(SourceKind::Module)
+                );
                 let gc = gc.into_nogc();
-                let message =
-                    String::from_string(self, err.first().unwrap().message.to_string(), gc);
-                return Err(self.throw_exception_with_message(
-                    ExceptionType::SyntaxError,
-                    message,
-                    gc,
-                ));
+                if is_module {
+                    return Err(self.throw_exception_with_static_message(
+                        ExceptionType::SyntaxError,
+                        "Source text contains import or export declarations and must be evaluated as a Module",
+                        gc,
+                    ));
+                }
+                return Err(throw_parse_errors(self, errors, gc));
             }
         };
         script_evaluation(self, script.unbind(), gc)
     }
 
+    /// Evaluate source text as a Module in the current Realm.
+    ///
+    /// The Module is loaded, linked, and evaluated as by
+    /// [`Agent::run_module`]. Syntax errors are thrown as SyntaxErrors.
+    pub fn evaluate_module<'gc>(
+        &mut self,
+        source_text: String,
+        options: EvaluationOptions,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let realm = self.current_realm(gc.nogc());
+        let EvaluationOptions {
+            strict: _,
+            host_defined,
+        } = options;
+        let module = match parse_module(self, source_text, realm, host_defined.clone(), gc.nogc()) {
+            Ok(module) => module,
+            Err(errors) => return Err(throw_parse_errors(self, errors, gc.into_nogc())),
+        };
+        self.run_module(module.unbind(), host_defined, gc)
+    }
+
+    /// Evaluate source text in the current Realm, detecting whether it is a
+    /// Script or a Module using [`detect_source_kind`].
+    ///
+    /// Syntax errors are thrown as SyntaxErrors.
+    pub fn evaluate<'gc>(
+        &mut self,
+        source_text: String,
+        options: EvaluationOptions,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        match detect_source_kind(&source_text.to_string_lossy(self)) {
+            Ok(SourceKind::Module) => self.evaluate_module(source_text, options, gc),
+            Ok(SourceKind::Script { .. }) => self.evaluate_script(source_text, options, gc),
+            Err(errors) => Err(throw_parse_errors(self, errors, gc.into_nogc())),
+        }
+    }
+
+    /// Get the Script or Module registered under the given key.
+    ///
+    /// See [`Agent::load_script`] and [`Agent::load_module`].
+    pub fn get_loaded_source<'gc>(
+        &self,
+        key: &str,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<LoadedSource<'gc>> {
+        self.source_registry.get(key).bind(gc)
+    }
+
+    /// Iterate over the keys of all registered Scripts and Modules.
+    pub fn loaded_source_keys(&self) -> impl Iterator<Item = &str> {
+        self.source_registry.keys()
+    }
+
+    /// Register a Script or Module under the given key, replacing any
+    /// previously registered Script or Module.
+    pub fn set_loaded_source(&mut self, key: &str, source: LoadedSource) {
+        self.source_registry.insert(key, source.unbind());
+    }
+
+    /// Remove the Script or Module registered under the given key. Returns
+    /// true if a Script or Module was removed.
+    pub fn remove_loaded_source(&mut self, key: &str) -> bool {
+        self.source_registry.remove(key)
+    }
+
+    /// Get the Script registered under the given key, or parse the source
+    /// text in the current Realm and register the resulting Script.
+    ///
+    /// The source text is only parsed if no Script is registered under the
+    /// key. Syntax errors are thrown as SyntaxErrors, and a TypeError is thrown
+    /// if a Module is registered under the key.
+    pub fn load_script<'gc>(
+        &mut self,
+        key: &str,
+        source_text: String,
+        options: EvaluationOptions,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Script<'gc>> {
+        match self.source_registry.get(key) {
+            Some(LoadedSource::Script(script)) => return Ok(script.bind(gc)),
+            Some(LoadedSource::Module(_)) => {
+                return Err(self.throw_exception(
+                    ExceptionType::TypeError,
+                    format!("'{key}' is registered as a Module"),
+                    gc,
+                ));
+            }
+            None => {}
+        }
+        let realm = self.current_realm(gc);
+        let EvaluationOptions {
+            strict,
+            host_defined,
+        } = options;
+        let script = parse_script(self, source_text, realm, strict, host_defined, gc)
+            .map_err(|errors| throw_parse_errors(self, errors, gc))?;
+        self.source_registry.insert(key, script.unbind().into());
+        Ok(script)
+    }
+
+    /// Get the Module registered under the given key, or parse the source
+    /// text in the current Realm and register the resulting Module.
+    ///
+    /// The source text is only parsed if no Module is registered under the
+    /// key, so that repeated loads of the same key return the same Module
+    /// Record. Syntax errors are thrown as SyntaxErrors, and a TypeError is
+    /// thrown if a Script is registered under the key.
+    ///
+    /// Registered Modules are also used to satisfy imports whose specifier
+    /// equals the key, without calling [`HostHooks::load_imported_module`].
+    pub fn load_module<'gc>(
+        &mut self,
+        key: &str,
+        source_text: String,
+        options: EvaluationOptions,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, SourceTextModule<'gc>> {
+        match self.source_registry.get(key) {
+            Some(LoadedSource::Module(module)) => return Ok(module.bind(gc)),
+            Some(LoadedSource::Script(_)) => {
+                return Err(self.throw_exception(
+                    ExceptionType::TypeError,
+                    format!("'{key}' is registered as a Script"),
+                    gc,
+                ));
+            }
+            None => {}
+        }
+        let realm = self.current_realm(gc);
+        let module = parse_module(self, source_text, realm, options.host_defined, gc)
+            .map_err(|errors| throw_parse_errors(self, errors, gc))?;
+        self.source_registry.insert(key, module.unbind().into());
+        Ok(module)
+    }
+
+    /// ### [perform a microtask checkpoint](https://html.spec.whatwg.org/multipage/webappapis.html#perform-a-microtask-checkpoint)
+    ///
+    /// Drain the host's microtask queue and report unhandled Promise
+    /// rejections. Embedders should call this once at the end of each
+    /// event loop turn, in the Realm that ran the turn's task.
+    ///
+    /// The checkpoint performs the following steps in order:
+    ///
+    /// 1. Run Jobs from [`HostHooks::dequeue_promise_job`] until the queue is
+    ///    empty, reporting thrown errors using [`HostHooks::report_job_error`].
+    /// 2. Run one FinalizationRegistry cleanup Job from
+    ///    [`HostHooks::dequeue_finalization_registry_cleanup_job`]. If one was
+    ///    run, go back to step 1.
+    /// 3. Report each Promise that was rejected without handlers and has not
+    ///    gained a handler since using
+    ///    [`HostHooks::report_unhandled_rejection`].
+    /// 4. Perform ClearKeptObjects.
+    ///
+    /// No Jobs are dequeued while termination of the running JavaScript code
+    /// is requested or the Agent is out of fuel: they stay queued until the
+    /// termination is cancelled or the Agent is refuelled.
+    ///
+    /// Calls made while a checkpoint is already being performed, eg. from a
+    /// Job, return immediately.
+    pub fn perform_microtask_checkpoint(&mut self, mut gc: GcScope) {
+        // 1. If the event loop's performing a microtask checkpoint is true,
+        //    then return.
+        if self.performing_microtask_checkpoint {
+            return;
+        }
+        // 2. Set the event loop's performing a microtask checkpoint to true.
+        self.performing_microtask_checkpoint = true;
+        let host_hooks = self.host_hooks;
+        loop {
+            // 3. While the event loop's microtask queue is not empty:
+            while !self.is_terminating()
+                && let Some(job) = host_hooks.dequeue_promise_job()
+            {
+                // a. Let oldestMicrotask be the result of dequeuing from the
+                //    event loop's microtask queue.
+                // b-d. Run oldestMicrotask.
+                if let Err(err) = job.run(self, gc.reborrow()).unbind() {
+                    host_hooks.report_job_error(self, err, gc.nogc());
+                }
+            }
+            // NOTE: FinalizationRegistry cleanup callbacks are run after the
+            // microtask queue is drained. Microtasks they enqueue are drained
+            // before the next callback is run.
+            #[cfg(feature = "weak-refs")]
+            if !self.is_terminating()
+                && let Some(job) = host_hooks.dequeue_finalization_registry_cleanup_job()
+            {
+                if let Err(err) = job.run(self, gc.reborrow()).unbind() {
+                    host_hooks.report_job_error(self, err, gc.nogc());
+                }
+                continue;
+            }
+            break;
+        }
+        // 4. For each environment settings object settingsObject whose
+        //    responsible event loop is this event loop, notify about rejected
+        //    promises given settingsObject's global object.
+        let gc = gc.into_nogc();
+        for promise in core::mem::take(&mut self.pending_rejections) {
+            // If p.[[PromiseIsHandled]] is true, continue.
+            if promise.is_handled(self) {
+                continue;
+            }
+            if let Some(Err(reason)) = promise.try_get_result(self, gc) {
+                host_hooks.report_unhandled_rejection(self, promise, reason.value(), gc);
+            }
+        }
+        // 6. Perform ClearKeptObjects().
+        #[cfg(feature = "weak-refs")]
+        clear_kept_objects(self);
+        // 7. Set the event loop's performing a microtask checkpoint to false.
+        self.performing_microtask_checkpoint = false;
+    }
+
+    /// ### [27.2.1.9 HostPromiseRejectionTracker ( promise, operation )](https://tc39.es/ecma262/#sec-host-promise-rejection-tracker)
+    ///
+    /// Track the Promise in the about-to-be-notified rejected promises list
+    /// and call the host's [`HostHooks::promise_rejection_tracker`].
+    pub(crate) fn host_promise_rejection_tracker(
+        &mut self,
+        promise: Promise,
+        operation: PromiseRejectionTrackerOperation,
+    ) {
+        match operation {
+            PromiseRejectionTrackerOperation::Reject => {
+                self.pending_rejections.push(promise.unbind());
+            }
+            PromiseRejectionTrackerOperation::Handle => {
+                if let Some(index) = self
+                    .pending_rejections
+                    .iter()
+                    .position(|p| *p == promise.unbind())
+                {
+                    self.pending_rejections.remove(index);
+                }
+            }
+        }
+        self.host_hooks
+            .promise_rejection_tracker(promise, operation);
+    }
+
     /// Run a SourceTextModule in the current Realm.
     ///
     /// This runs the LoadRequestedModules (passing in the host_defined
-    /// parameter), Link, and finally Evaluate operations on the module.
+    /// parameter), Link, and finally Evaluate operations on the module. The
+    /// host_defined parameter is passed on to each
+    /// [`HostHooks::load_imported_module`] call made while loading the
+    /// module graph.
+    ///
+    /// If the module graph evaluated synchronously, the result of the
+    /// evaluation is returned directly. If the module or one of its
+    /// dependencies uses top-level await, the still pending evaluation
+    /// Promise is returned instead; it settles as the host runs promise jobs.
     /// This should not be called multiple times on the same module.
     pub fn run_module<'gc>(
         &mut self,
@@ -1465,20 +2454,29 @@ impl Agent {
         result.unbind()?;
 
         module.link(self, gc.nogc()).unbind()?;
-        if let Some(result) = module
-            .unbind()
-            .evaluate(self, gc.reborrow())
-            .unbind()
-            .try_get_result(self, gc.into_nogc())
-        {
+        let promise = module.unbind().evaluate(self, gc.reborrow()).unbind();
+        let gc = gc.into_nogc();
+        let promise = promise.bind(gc);
+        if let Some(result) = promise.try_get_result(self, gc) {
             // Note: module resolved synchronously.
             result
         } else {
-            Ok(Value::Undefined)
+            // Note: module uses top-level await.
+            Ok(promise.into())
         }
     }
 }
 
+/// Throw the first of a list of parse errors as a SyntaxError.
+fn throw_parse_errors<'a>(
+    agent: &mut Agent,
+    errors: Vec<OxcDiagnostic>,
+    gc: NoGcScope<'a, '_>,
+) -> JsError<'a> {
+    let message = String::from_string(agent, errors.first().unwrap().message.to_string(), gc);
+    agent.throw_exception_with_message(ExceptionType::SyntaxError, message, gc)
+}
+
 /// ### [9.4.1 GetActiveScriptOrModule ()](https://tc39.es/ecma262/#sec-getactivescriptormodule)
 ///
 /// The abstract operation GetActiveScriptOrModule takes no arguments and
@@ -1616,7 +2614,22 @@ impl HeapMarkAndSweep for Agent {
             execution_context_stack,
             stack_refs,
             stack_ref_collections,
+            // Note: unrooted references are asserted empty before marking.
+            #[cfg(debug_assertions)]
+                unrooted_references: _,
             vm_stack,
+            // Note: pooled VM frames are empty and hold no values.
+            vm_frame_pool: _,
+            gc_stats: _,
+            retained_bytes: _,
+            number_string_cache,
+            allocation_tracker: _,
+            // Note: a synchronous dynamic import is only recorded while
+            // garbage collection cannot run.
+            synchronous_dynamic_import: _,
+            pending_dynamic_imports,
+            #[cfg(feature = "futures")]
+                promise_futures: _,
             options: _,
             symbol_id: _,
             global_symbol_registry,
@@ -1625,6 +2638,15 @@ impl HeapMarkAndSweep for Agent {
                 kept_alive: _,
             private_names_counter: _,
             module_async_evaluation_count: _,
+            rng: _,
+            native_modules: _,
+            pending_rejections,
+            performing_microtask_checkpoint: _,
+            source_registry,
+            termination: _,
+            fuel: _,
+            fuel_exhausted: _,
+            termination_error,
         } = self;
 
         execution_context_stack.iter().for_each(|ctx| {
@@ -1642,6 +2664,11 @@ impl HeapMarkAndSweep for Agent {
             unsafe { vm_ptr.as_ref() }.mark_values(queues);
         });
         global_symbol_registry.mark_values(queues);
+        pending_rejections.mark_values(queues);
+        pending_dynamic_imports.mark_values(queues);
+        number_string_cache.mark_values(queues);
+        source_registry.mark_values(queues);
+        termination_error.mark_values(queues);
         let mut last_filled_global_value = None;
         heap.globals
             .borrow()
@@ -1667,7 +2694,18 @@ impl HeapMarkAndSweep for Agent {
             execution_context_stack,
             stack_refs,
             stack_ref_collections,
+            #[cfg(debug_assertions)]
+                unrooted_references: _,
             vm_stack,
+            vm_frame_pool: _,
+            gc_stats: _,
+            retained_bytes: _,
+            number_string_cache,
+            allocation_tracker: _,
+            synchronous_dynamic_import: _,
+            pending_dynamic_imports,
+            #[cfg(feature = "futures")]
+                promise_futures: _,
             options: _,
             symbol_id: _,
             global_symbol_registry,
@@ -1676,6 +2714,15 @@ impl HeapMarkAndSweep for Agent {
                 kept_alive: _,
             private_names_counter: _,
             module_async_evaluation_count: _,
+            rng: _,
+            native_modules: _,
+            pending_rejections,
+            performing_microtask_checkpoint: _,
+            source_registry,
+            termination: _,
+            fuel: _,
+            fuel_exhausted: _,
+            termination_error,
         } = self;
 
         execution_context_stack
@@ -1693,11 +2740,16 @@ impl HeapMarkAndSweep for Agent {
             .iter_mut()
             .for_each(|entry| unsafe { entry.as_mut().sweep_values(compactions) });
         global_symbol_registry.sweep_values(compactions);
+        pending_rejections.sweep_values(compactions);
+        pending_dynamic_imports.sweep_values(compactions);
+        number_string_cache.sweep_values(compactions);
+        source_registry.sweep_values(compactions);
+        termination_error.sweep_values(compactions);
     }
 }
 
 #[cold]
 #[inline(never)]
 fn panic_corrupted_agent() -> ! {
-    panic!("Agent is corrupted")
+    invariant_violation("Agent is corrupted")
 }
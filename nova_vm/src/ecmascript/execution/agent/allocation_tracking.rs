@@ -0,0 +1,250 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Allocation tracking
+//!
+//! When [`AgentOptions::allocation_sample_interval`] is set, the Agent
+//! attributes the bytes allocated on its heap to the code that allocated
+//! them. Every time at least the interval's worth of bytes has been allocated
+//! since the previous sample, the allocated bytes are recorded against the
+//! innermost running JavaScript function, Script or Module. Allocations made
+//! by builtin functions are attributed to their JavaScript caller, and
+//! allocations made by the embedder outside of any JavaScript code are
+//! attributed to the host.
+//!
+//! The samples are collected into a ranked [`AllocationReport`] using
+//! [`Agent::allocation_report`].
+//!
+//! [`AgentOptions::allocation_sample_interval`]: super::AgentOptions::allocation_sample_interval
+
+use core::fmt;
+
+use ahash::AHashMap;
+
+use crate::{
+    ecmascript::{Agent, Function, FunctionInternalProperties, GcAgent, ScriptOrModule},
+    heap::ArenaAccess,
+};
+
+/// The code that an allocation sample was attributed to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllocationSite {
+    /// A JavaScript function.
+    Function {
+        /// The function's name; empty for anonymous functions.
+        name: std::string::String,
+        /// Byte offset of the function's source text in its Script or
+        /// Module.
+        source_offset: u32,
+    },
+    /// The top-level code of a Script.
+    Script,
+    /// The top-level code of a Module.
+    Module,
+    /// The embedder, outside of any JavaScript code.
+    Host,
+}
+
+impl fmt::Display for AllocationSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function {
+                name,
+                source_offset,
+            } if name.is_empty() => write!(f, "<anonymous> @ {source_offset}"),
+            Self::Function {
+                name,
+                source_offset,
+            } => write!(f, "{name} @ {source_offset}"),
+            Self::Script => f.write_str("<script>"),
+            Self::Module => f.write_str("<module>"),
+            Self::Host => f.write_str("<host>"),
+        }
+    }
+}
+
+/// Allocations attributed to a single [`AllocationSite`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocationSiteStats {
+    /// The code that the allocations were attributed to.
+    pub site: AllocationSite,
+    /// Number of samples taken while this site was running.
+    pub samples: u64,
+    /// Number of heap bytes attributed to this site.
+    pub bytes: u64,
+}
+
+/// Heap allocations attributed to the code that made them, ordered from the
+/// site with the most allocated bytes to the least.
+///
+/// See [`Agent::allocation_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllocationReport {
+    sites: Vec<AllocationSiteStats>,
+}
+
+impl AllocationReport {
+    /// Get the allocation sites, ordered by allocated bytes in descending
+    /// order.
+    pub fn sites(&self) -> &[AllocationSiteStats] {
+        &self.sites
+    }
+
+    /// Get the total number of bytes attributed to all sites.
+    pub fn total_bytes(&self) -> u64 {
+        self.sites.iter().map(|site| site.bytes).sum()
+    }
+}
+
+impl fmt::Display for AllocationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>12} {:>8}  site", "bytes", "samples")?;
+        for site in &self.sites {
+            writeln!(f, "{:>12} {:>8}  {}", site.bytes, site.samples, site.site)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sampled allocation counts of an Agent.
+#[derive(Debug)]
+pub(crate) struct AllocationTracker {
+    /// Number of bytes to allocate between samples.
+    interval: usize,
+    /// Value of the heap's allocation counter when the last sample was
+    /// taken.
+    last_alloc_counter: usize,
+    /// Samples and bytes recorded per site.
+    sites: AHashMap<AllocationSite, (u64, u64)>,
+}
+
+impl AllocationTracker {
+    pub(crate) fn new(interval: usize) -> Option<Self> {
+        if interval == 0 {
+            return None;
+        }
+        Some(Self {
+            interval,
+            last_alloc_counter: 0,
+            sites: AHashMap::default(),
+        })
+    }
+
+    /// Reset the sampling position after the heap's allocation counter was
+    /// reset by garbage collection.
+    pub(crate) fn reset_alloc_counter(&mut self) {
+        self.last_alloc_counter = 0;
+    }
+}
+
+impl Agent {
+    /// Take an allocation sample if enough bytes have been allocated since
+    /// the last one.
+    #[inline]
+    pub(crate) fn sample_allocations(&mut self) {
+        if let Some(tracker) = &self.allocation_tracker
+            && self
+                .heap
+                .alloc_counter
+                .saturating_sub(tracker.last_alloc_counter)
+                >= tracker.interval
+        {
+            self.record_allocation_sample();
+        }
+    }
+
+    /// Attribute all bytes allocated since the last sample to the currently
+    /// running code.
+    #[cold]
+    pub(crate) fn record_allocation_sample(&mut self) {
+        let Some(tracker) = &self.allocation_tracker else {
+            return;
+        };
+        let alloc_counter = self.heap.alloc_counter;
+        let bytes = alloc_counter.saturating_sub(tracker.last_alloc_counter);
+        if bytes == 0 {
+            return;
+        }
+        let site = self.current_allocation_site();
+        let tracker = self.allocation_tracker.as_mut().unwrap();
+        tracker.last_alloc_counter = alloc_counter;
+        let entry = tracker.sites.entry(site).or_default();
+        entry.0 += 1;
+        entry.1 += bytes as u64;
+    }
+
+    /// Find the innermost JavaScript code on the execution context stack.
+    fn current_allocation_site(&self) -> AllocationSite {
+        for context in self.execution_context_stack.iter().rev() {
+            match context.function {
+                Some(Function::ECMAScriptFunction(f)) => {
+                    return AllocationSite::Function {
+                        name: f.get_name(self).to_string_lossy(self).into_owned(),
+                        source_offset: f.get(self).ecmascript_function.source_text.start,
+                    };
+                }
+                // Builtin functions are attributed to their caller.
+                Some(_) => {}
+                None if context.ecmascript_code.is_some() => {
+                    return match context.script_or_module {
+                        Some(ScriptOrModule::SourceTextModule(_)) => AllocationSite::Module,
+                        _ => AllocationSite::Script,
+                    };
+                }
+                None => break,
+            }
+        }
+        AllocationSite::Host
+    }
+
+    /// Get the heap allocations sampled so far, ranked by allocated bytes.
+    ///
+    /// Any bytes allocated since the last sample are attributed to the
+    /// currently running code. Returns an empty report if allocation tracking
+    /// is not enabled; see [`AgentOptions::allocation_sample_interval`].
+    ///
+    /// [`AgentOptions::allocation_sample_interval`]: super::AgentOptions::allocation_sample_interval
+    pub fn allocation_report(&mut self) -> AllocationReport {
+        self.record_allocation_sample();
+        let Some(tracker) = &self.allocation_tracker else {
+            return AllocationReport::default();
+        };
+        let mut sites = tracker
+            .sites
+            .iter()
+            .map(|(site, &(samples, bytes))| AllocationSiteStats {
+                site: site.clone(),
+                samples,
+                bytes,
+            })
+            .collect::<Vec<_>>();
+        sites.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(b.samples.cmp(&a.samples)));
+        AllocationReport { sites }
+    }
+
+    /// Discard all allocation samples taken so far.
+    pub fn clear_allocation_report(&mut self) {
+        let alloc_counter = self.heap.alloc_counter;
+        if let Some(tracker) = &mut self.allocation_tracker {
+            tracker.last_alloc_counter = alloc_counter;
+            tracker.sites.clear();
+        }
+    }
+}
+
+impl GcAgent {
+    /// Get the heap allocations sampled so far, ranked by allocated bytes.
+    ///
+    /// See [`Agent::allocation_report`].
+    pub fn allocation_report(&mut self) -> AllocationReport {
+        self.agent.allocation_report()
+    }
+
+    /// Discard all allocation samples taken so far.
+    ///
+    /// See [`Agent::clear_allocation_report`].
+    pub fn clear_allocation_report(&mut self) {
+        self.agent.clear_allocation_report()
+    }
+}
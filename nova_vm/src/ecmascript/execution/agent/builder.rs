@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::ecmascript::{DefaultHostHooks, GcAgent, HostHooks, RealmRoot};
+
+use super::AgentOptions;
+
+/// Builder for a configured [`GcAgent`].
+///
+/// All of the Agent's configuration surfaces are set through the builder;
+/// anything left unset uses the same defaults as [`AgentOptions::default`]
+/// and the [`DefaultHostHooks`].
+///
+/// ```rust
+/// use nova_vm::ecmascript::{AgentBuilder, DefaultHostHooks};
+/// let (mut agent, realm) = AgentBuilder::new()
+///     .with_host_hooks(&DefaultHostHooks)
+///     .with_random_seed(0xC0FFEE)
+///     .build_with_default_realm();
+/// let _ = agent.run_in_realm(&realm, |_agent, _gc| {
+///   // do work here
+/// });
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct AgentBuilder {
+    options: AgentOptions,
+    host_hooks: &'static dyn HostHooks,
+}
+
+impl Default for AgentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentBuilder {
+    /// Create a new builder with default options.
+    pub fn new() -> Self {
+        Self {
+            options: AgentOptions::default(),
+            host_hooks: &DefaultHostHooks,
+        }
+    }
+
+    /// Replace all options with the given [`AgentOptions`].
+    pub fn with_options(mut self, options: AgentOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the host hooks that the Agent calls into. The hooks must outlive
+    /// the Agent.
+    pub fn with_host_hooks(mut self, host_hooks: &'static dyn HostHooks) -> Self {
+        self.host_hooks = host_hooks;
+        self
+    }
+
+    /// Stop the Agent from performing any garbage collection.
+    pub fn with_gc_disabled(mut self, disable_gc: bool) -> Self {
+        self.options.disable_gc = disable_gc;
+        self
+    }
+
+    /// Set the number of bytes that can be allocated on the heap before the
+    /// Agent performs garbage collection at its next opportunity.
+    pub fn with_gc_allocation_threshold(mut self, bytes: usize) -> Self {
+        self.options.gc_allocation_threshold = bytes;
+        self
+    }
+
+    /// Make the Agent print its internal bytecode execution debug data into
+    /// stderr.
+    pub fn with_print_internals(mut self, print_internals: bool) -> Self {
+        self.options.print_internals = print_internals;
+        self
+    }
+
+    /// Set the \[\[CanBlock]] field of the Agent Record. If false, calling
+    /// `Atomics.wait()` will throw an error.
+    pub fn with_can_block(mut self, can_block: bool) -> Self {
+        self.options.no_block = !can_block;
+        self
+    }
+
+    /// Seed the Agent's random number generator, making the values returned
+    /// by `Math.random()` reproducible between runs.
+    pub fn with_random_seed(mut self, seed: u64) -> Self {
+        self.options.random_seed = Some(seed);
+        self
+    }
+
+    /// Make the Agent check during garbage collection that promise
+    /// combinators do not retain values after their result promise has
+    /// settled.
+    pub fn with_promise_retention_checks(mut self, check_promise_retention: bool) -> Self {
+        self.options.check_promise_retention = check_promise_retention;
+        self
+    }
+
+    /// Set the number of entries in the Agent's cache of Number to String
+    /// conversions. Zero disables the cache.
+    pub fn with_number_string_cache_size(mut self, entries: usize) -> Self {
+        self.options.number_string_cache_size = entries;
+        self
+    }
+
+    /// Make `import()` link and evaluate the imported module before returning
+    /// if the host loads the module graph synchronously. This deviates from
+    /// the specification; see [`AgentOptions::synchronous_dynamic_import`].
+    pub fn with_synchronous_dynamic_import(mut self, synchronous_dynamic_import: bool) -> Self {
+        self.options.synchronous_dynamic_import = synchronous_dynamic_import;
+        self
+    }
+
+    /// Set the maximum number of arguments that a single function call can
+    /// receive. Calls with more arguments throw a RangeError.
+    pub fn with_max_arguments_length(mut self, max_arguments_length: usize) -> Self {
+        self.options.max_arguments_length = max_arguments_length;
+        self
+    }
+
+    /// Make the Agent attribute its heap allocations to the code that made
+    /// them, taking a sample every time at least the given number of bytes
+    /// have been allocated. Zero disables allocation tracking.
+    pub fn with_allocation_sample_interval(mut self, bytes: usize) -> Self {
+        self.options.allocation_sample_interval = bytes;
+        self
+    }
+
+    /// Keep every variable in an Environment Record so that debuggers can
+    /// inspect and change them; see [`AgentOptions::debug_scopes`].
+    pub fn with_debug_scopes(mut self, debug_scopes: bool) -> Self {
+        self.options.debug_scopes = debug_scopes;
+        self
+    }
+
+    /// Call [`HostHooks::heap_threshold_exceeded`] after garbage collections
+    /// that leave more than the given number of bytes on the heap. Zero
+    /// disables the notification.
+    ///
+    /// [`HostHooks::heap_threshold_exceeded`]: crate::ecmascript::HostHooks::heap_threshold_exceeded
+    pub fn with_heap_size_notification_threshold(mut self, bytes: usize) -> Self {
+        self.options.heap_size_notification_threshold = bytes;
+        self
+    }
+
+    /// Limit the number of bytes that the Agent's heap may use; see
+    /// [`AgentOptions::memory_limit`]. Zero means no limit.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.options.memory_limit = bytes;
+        self
+    }
+
+    /// Enable fuel metering, starting with the given amount of fuel; see
+    /// [`AgentOptions::fuel`].
+    pub fn with_fuel(mut self, fuel: u64) -> Self {
+        self.options.fuel = Some(fuel);
+        self
+    }
+
+    /// Create the configured Agent.
+    pub fn build(self) -> GcAgent {
+        GcAgent::new(self.options, self.host_hooks)
+    }
+
+    /// Create the configured Agent along with an initial default Realm.
+    pub fn build_with_default_realm(self) -> (GcAgent, RealmRoot) {
+        let mut agent = self.build();
+        let realm = agent.create_default_realm();
+        (agent, realm)
+    }
+}
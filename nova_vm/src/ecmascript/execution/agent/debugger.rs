@@ -0,0 +1,193 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Debugger scope inspection
+//!
+//! When JavaScript code evaluates a `debugger` statement, the Agent calls
+//! [`HostHooks::debugger_statement`] and the code stays paused until the hook
+//! returns. While paused, a debugger can walk the Environment Records of each
+//! ECMAScript execution context on the stack using [`Agent::debug_scope`],
+//! list their bindings, and change the values of mutable bindings.
+//!
+//! By default, variables that are not captured by closures may be stored in
+//! VM stack slots instead of Environment Records, and are then not visible to
+//! scope inspection. Set [`AgentOptions::debug_scopes`] to keep every
+//! variable in an Environment Record.
+//!
+//! [`HostHooks::debugger_statement`]: crate::ecmascript::HostHooks::debugger_statement
+//! [`AgentOptions::debug_scopes`]: crate::ecmascript::AgentOptions::debug_scopes
+
+use crate::{
+    ecmascript::{Agent, Environment, JsResult, Object, String, Value},
+    engine::{Bindable, GcScope, NoGcScope, bindable_handle},
+};
+
+/// The kind of Environment Record behind a [`DebugScope`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugScopeKind {
+    /// A Declarative Environment Record, created for blocks, `catch` clauses
+    /// and other lexical scopes.
+    Declarative,
+    /// The Function Environment Record of a function call.
+    Function,
+    /// The Module Environment Record of a module.
+    Module,
+    /// The Global Environment Record of a realm.
+    Global,
+    /// An Object Environment Record created by a `with` statement.
+    Object,
+}
+
+/// A binding of a [`DebugScope`].
+#[derive(Debug, Clone, Copy)]
+pub struct DebugBinding<'a> {
+    name: String<'a>,
+    value: Option<Value<'a>>,
+    mutable: bool,
+}
+bindable_handle!(DebugBinding);
+
+impl<'a> DebugBinding<'a> {
+    /// The name of the binding.
+    pub fn name(&self) -> String<'a> {
+        self.name
+    }
+
+    /// The value of the binding, or `None` if the binding is uninitialized,
+    /// ie. in its temporal dead zone.
+    pub fn value(&self) -> Option<Value<'a>> {
+        self.value
+    }
+
+    /// Returns true if the value of the binding can be changed using
+    /// [`DebugScope::set_binding`]. `const` declarations, class names inside
+    /// their class, and module imports are immutable.
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+/// An Environment Record of paused JavaScript code.
+///
+/// Like other handles, a DebugScope is invalidated by garbage collection.
+/// Call [`Agent::debug_scope`] again after any operation that may trigger it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DebugScope<'a>(Environment<'a>);
+bindable_handle!(DebugScope);
+
+impl<'a> DebugScope<'a> {
+    /// The kind of Environment Record.
+    pub fn kind(self) -> DebugScopeKind {
+        match self.0 {
+            Environment::Declarative(_) => DebugScopeKind::Declarative,
+            Environment::Function(_) => DebugScopeKind::Function,
+            Environment::Module(_) => DebugScopeKind::Module,
+            Environment::Global(_) => DebugScopeKind::Global,
+            Environment::Object(_) => DebugScopeKind::Object,
+        }
+    }
+
+    /// The \[\[OuterEnv]] of the Environment Record, or `None` for the Global
+    /// Environment Record.
+    pub fn outer(self, agent: &Agent) -> Option<DebugScope<'a>> {
+        self.0.get_outer_env(agent).map(DebugScope)
+    }
+
+    /// The object whose properties are the bindings of an Object or Global
+    /// Environment Record.
+    ///
+    /// The properties of the object are not included in
+    /// [`DebugScope::bindings`]: inspecting them may call getters and Proxy
+    /// traps, so debuggers should do it explicitly.
+    pub fn binding_object(self, agent: &Agent) -> Option<Object<'a>> {
+        match self.0 {
+            Environment::Global(e) => Some(e.get_binding_object(agent)),
+            Environment::Object(e) => Some(e.get_binding_object(agent)),
+            Environment::Declarative(_) | Environment::Function(_) | Environment::Module(_) => None,
+        }
+    }
+
+    /// The declarative bindings of the Environment Record, sorted by name.
+    ///
+    /// For the Global Environment Record these are the global `let`, `const`
+    /// and `class` declarations; the other global bindings are properties of
+    /// its [binding object]. Object Environment Records have no declarative
+    /// bindings.
+    ///
+    /// [binding object]: DebugScope::binding_object
+    pub fn bindings(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Vec<DebugBinding<'a>> {
+        let declarative = match self.0 {
+            Environment::Declarative(e) => e,
+            Environment::Function(e) => e.get_declarative_environment(agent),
+            Environment::Module(e) => e.get_declarative_env(agent),
+            Environment::Global(e) => e.get_declarative_record(agent),
+            Environment::Object(_) => return Vec::new(),
+        };
+        let mut bindings = declarative
+            .iter_bindings(agent)
+            .map(|(name, binding)| DebugBinding {
+                name,
+                value: binding.value.bind(gc),
+                mutable: binding.is_mutable(),
+            })
+            .collect::<Vec<_>>();
+        if let Environment::Module(e) = self.0 {
+            // Import bindings are immutable indirect bindings to the target
+            // module's bindings.
+            bindings.extend(e.get_indirect_binding_names(agent).into_iter().map(|name| {
+                DebugBinding {
+                    name,
+                    value: e.get_binding_value(agent, name, true, gc),
+                    mutable: false,
+                }
+            }));
+        }
+        bindings.sort_by(|a, b| a.name.as_wtf8(agent).cmp(b.name.as_wtf8(agent)));
+        bindings
+    }
+
+    /// Change the value of the binding `name`, as if by an assignment in
+    /// strict mode code.
+    ///
+    /// Throws a ReferenceError if the Environment Record has no binding for
+    /// `name` or if the binding is uninitialized, and a TypeError if the
+    /// binding is immutable. For Global and Object Environment Records the
+    /// binding may be a property of the [binding object], in which case
+    /// setters and Proxy traps are called.
+    ///
+    /// [binding object]: DebugScope::binding_object
+    pub fn set_binding<'gc>(
+        self,
+        agent: &mut Agent,
+        name: String,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        self.0
+            .unbind()
+            .set_mutable_binding(agent, name, None, value, true, gc)
+    }
+}
+
+impl Agent {
+    /// Get the LexicalEnvironment of an ECMAScript execution context on the
+    /// execution context stack.
+    ///
+    /// `frame` counts ECMAScript code execution contexts from the running
+    /// one: 0 is the innermost function, Script or Module being evaluated, 1
+    /// is its caller, and so on. Execution contexts of builtin functions are
+    /// skipped. Returns `None` if there are fewer ECMAScript code execution
+    /// contexts on the stack.
+    ///
+    /// Walk the scope chain using [`DebugScope::outer`].
+    pub fn debug_scope<'a>(&self, frame: usize, gc: NoGcScope<'a, '_>) -> Option<DebugScope<'a>> {
+        self.execution_context_stack
+            .iter()
+            .rev()
+            .filter_map(|context| context.ecmascript_code.as_ref())
+            .nth(frame)
+            .map(|code| DebugScope(code.lexical_environment.bind(gc)))
+    }
+}
@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Fatal errors
+//!
+//! Nova distinguishes between two kinds of failures:
+//!
+//! - JavaScript exceptions are thrown as [`JsError`]s. They follow the
+//!   ECMAScript semantics: they can be caught by `try`/`catch`, reject
+//!   promises, and are returned to the embedder as the error variant of a
+//!   [`JsResult`].
+//! - Engine invariant failures, such as a corrupted Agent or malformed
+//!   bytecode, are not JavaScript exceptions. They terminate the Agent: no
+//!   JavaScript code, including `catch` and `finally` blocks, runs after
+//!   the failure.
+//!
+//! Internally, an invariant failure unwinds the Rust stack using a dedicated
+//! panic payload. Embedders can use [`GcAgent::try_run_in_realm`] and
+//! [`GcAgent::try_run_job`] to stop any panic, invariant failure or
+//! otherwise, from crossing into their code; the panic is then reported as a
+//! [`FatalError`] and the Agent refuses to run any further code.
+//!
+//! [`JsError`]: crate::ecmascript::JsError
+//! [`JsResult`]: crate::ecmascript::JsResult
+//! [`GcAgent::try_run_in_realm`]: crate::ecmascript::GcAgent::try_run_in_realm
+//! [`GcAgent::try_run_job`]: crate::ecmascript::GcAgent::try_run_job
+
+use core::{any::Any, fmt};
+
+/// The reason an Agent was terminated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FatalErrorKind {
+    /// An engine invariant was violated.
+    InvariantViolation,
+    /// The engine or embedder code called by the engine panicked.
+    Panic,
+    /// The Agent was already terminated by an earlier fatal error.
+    Terminated,
+}
+
+/// An unrecoverable failure that terminated an Agent.
+///
+/// Unlike a [`JsError`], a fatal error cannot be caught or observed by
+/// JavaScript code. It is returned by [`GcAgent::try_run_in_realm`] and
+/// [`GcAgent::try_run_job`] when the engine, or embedder code called by the
+/// engine, panics.
+///
+/// [`JsError`]: crate::ecmascript::JsError
+/// [`GcAgent::try_run_in_realm`]: crate::ecmascript::GcAgent::try_run_in_realm
+/// [`GcAgent::try_run_job`]: crate::ecmascript::GcAgent::try_run_job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FatalError {
+    kind: FatalErrorKind,
+    message: Box<str>,
+}
+
+impl FatalError {
+    /// The reason the Agent was terminated.
+    pub fn kind(&self) -> FatalErrorKind {
+        self.kind
+    }
+
+    /// A human-readable description of the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Convert a panic payload caught at a public entry point into a fatal
+    /// error.
+    pub(super) fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let payload = match payload.downcast::<InvariantViolation>() {
+            Ok(violation) => {
+                return Self {
+                    kind: FatalErrorKind::InvariantViolation,
+                    message: violation.0.into(),
+                };
+            }
+            Err(payload) => payload,
+        };
+        let message = if let Some(message) = payload.downcast_ref::<&'static str>() {
+            (*message).into()
+        } else if let Some(message) = payload.downcast_ref::<std::string::String>() {
+            message.as_str().into()
+        } else {
+            "Unknown panic".into()
+        };
+        Self {
+            kind: FatalErrorKind::Panic,
+            message,
+        }
+    }
+
+    /// The error returned when a terminated Agent is asked to run code.
+    pub(super) fn terminated(cause: &FatalError) -> Self {
+        Self {
+            kind: FatalErrorKind::Terminated,
+            message: cause.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for FatalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            FatalErrorKind::InvariantViolation => {
+                write!(f, "Engine invariant violated: {}", self.message)
+            }
+            FatalErrorKind::Panic => write!(f, "Engine panicked: {}", self.message),
+            FatalErrorKind::Terminated => {
+                write!(f, "Agent was terminated: {}", self.message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FatalError {}
+
+/// Panic payload of an engine invariant failure.
+struct InvariantViolation(&'static str);
+
+/// Terminate the Agent due to a violated engine invariant.
+///
+/// This must only be used for conditions that indicate a bug in the engine;
+/// errors observable by JavaScript must be thrown as exceptions instead.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn invariant_violation(message: &'static str) -> ! {
+    std::panic::panic_any(InvariantViolation(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invariant_violation_is_reported_as_such() {
+        let payload = std::panic::catch_unwind(|| invariant_violation("broken")).unwrap_err();
+        let error = FatalError::from_panic(payload);
+        assert_eq!(error.kind(), FatalErrorKind::InvariantViolation);
+        assert_eq!(error.message(), "broken");
+        assert_eq!(error.to_string(), "Engine invariant violated: broken");
+    }
+
+    #[test]
+    fn other_panics_keep_their_message() {
+        let payload = std::panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(FatalError::from_panic(payload).message(), "static");
+        let payload = std::panic::catch_unwind(|| panic!("formatted {}", 1)).unwrap_err();
+        let error = FatalError::from_panic(payload);
+        assert_eq!(error.kind(), FatalErrorKind::Panic);
+        assert_eq!(error.message(), "formatted 1");
+    }
+}
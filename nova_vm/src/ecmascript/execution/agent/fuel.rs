@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Fuel metering
+//!
+//! When [`AgentOptions::fuel`] is set, the bytecode VM consumes one unit of
+//! fuel per executed instruction. Unlike a timeout, the amount of work done
+//! with a given amount of fuel is deterministic, which makes fuel suitable
+//! for gas metering of sandboxed code.
+//!
+//! When the fuel runs out, [`HostHooks::fuel_exhausted`] is called. The
+//! embedder can refuel the Agent to continue running, or let the running
+//! JavaScript code be terminated. Running out of fuel does not request
+//! termination through the Agent's [`TerminationHandle`]: the termination
+//! ends as soon as the Agent is refuelled using [`Agent::set_fuel`].
+//! Builtin functions do not consume fuel.
+//!
+//! [`AgentOptions::fuel`]: super::AgentOptions::fuel
+//! [`HostHooks::fuel_exhausted`]: super::HostHooks::fuel_exhausted
+//! [`TerminationHandle`]: super::TerminationHandle
+
+use crate::{
+    ecmascript::{Agent, ExceptionType, GcAgent, JsError},
+    engine::NoGcScope,
+};
+
+impl Agent {
+    /// Get the remaining fuel of the Agent, or None if fuel metering is
+    /// disabled.
+    pub fn fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Set the remaining fuel of the Agent. None disables fuel metering.
+    ///
+    /// This also ends a termination caused by running out of fuel.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+        self.fuel_exhausted = false;
+    }
+
+    /// Consume one unit of fuel. Returns true if the Agent is out of fuel and
+    /// the host did not refuel it.
+    #[inline]
+    pub(crate) fn consume_fuel(&mut self) -> bool {
+        match &mut self.fuel {
+            None => false,
+            Some(0) => self.refuel(),
+            Some(fuel) => {
+                *fuel -= 1;
+                false
+            }
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn refuel(&mut self) -> bool {
+        match self.host_hooks.fuel_exhausted() {
+            Some(fuel) if fuel > 0 => {
+                self.fuel = Some(fuel - 1);
+                self.fuel_exhausted = false;
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Terminate the running JavaScript code because the Agent is out of
+    /// fuel, and create the error that it unwinds with.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn throw_fuel_exhausted_error<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        self.fuel_exhausted = true;
+        let error =
+            self.throw_exception_with_static_message(ExceptionType::Error, "Fuel exhausted", gc);
+        self.set_termination_error(error)
+    }
+}
+
+impl GcAgent {
+    /// Get the remaining fuel of the Agent, or None if fuel metering is
+    /// disabled.
+    ///
+    /// See [`Agent::fuel`].
+    pub fn fuel(&self) -> Option<u64> {
+        self.agent.fuel()
+    }
+
+    /// Set the remaining fuel of the Agent. None disables fuel metering.
+    ///
+    /// See [`Agent::set_fuel`].
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.agent.set_fuel(fuel);
+    }
+}
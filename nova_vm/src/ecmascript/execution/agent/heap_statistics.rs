@@ -0,0 +1,440 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Heap statistics
+//!
+//! A snapshot of the Agent's heap for embedders that monitor or limit memory
+//! usage. [`Agent::heap_statistics`] reports the length and size of every
+//! heap vector and the bytes held by ArrayBuffer and SharedArrayBuffer data
+//! blocks. The number of bytes allocated over the Agent's lifetime and the
+//! number of garbage collections are reported by [`Agent::gc_stats`].
+//!
+//! When [`AgentOptions::heap_size_notification_threshold`] is set, the Agent
+//! calls [`HostHooks::heap_threshold_exceeded`] after every garbage
+//! collection that leaves more than the threshold's worth of bytes on the
+//! heap.
+//!
+//! [`AgentOptions::heap_size_notification_threshold`]: super::AgentOptions::heap_size_notification_threshold
+//! [`HostHooks::heap_threshold_exceeded`]: super::HostHooks::heap_threshold_exceeded
+
+use soavec::{SoAVec, SoAble};
+
+use crate::{
+    ecmascript::{Agent, Environments, GcAgent, StringBuffer},
+    heap::{ElementArrays, Heap},
+};
+
+/// Length and size of a single heap vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapVectorStatistics {
+    /// Name of the heap vector, eg. `"objects"` or `"arrays"`.
+    pub name: &'static str,
+    /// Number of entries in the vector, including entries that are no longer
+    /// reachable but have not yet been garbage collected.
+    pub entries: usize,
+    /// Number of bytes used by the entries. Memory owned by the entries, such
+    /// as the contents of Strings, is included where the heap knows it.
+    pub bytes: usize,
+}
+
+/// A snapshot of the Agent's heap memory usage.
+///
+/// See [`Agent::heap_statistics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeapStatistics {
+    vectors: Vec<HeapVectorStatistics>,
+    data_block_bytes: usize,
+    shared_data_block_bytes: usize,
+}
+
+impl HeapStatistics {
+    /// Statistics of each heap vector.
+    pub fn vectors(&self) -> &[HeapVectorStatistics] {
+        &self.vectors
+    }
+
+    /// Statistics of the heap vector with the given name.
+    pub fn vector(&self, name: &str) -> Option<&HeapVectorStatistics> {
+        self.vectors.iter().find(|vector| vector.name == name)
+    }
+
+    /// Number of bytes used by the heap vectors.
+    pub fn heap_bytes(&self) -> usize {
+        self.vectors.iter().map(|vector| vector.bytes).sum()
+    }
+
+    /// Number of bytes held by the data blocks of ArrayBuffers that are not
+    /// detached.
+    pub fn data_block_bytes(&self) -> usize {
+        self.data_block_bytes
+    }
+
+    /// Number of bytes held by the data blocks of SharedArrayBuffers. Shared
+    /// data blocks may also be referenced by other Agents, so the memory is
+    /// not necessarily freed when this Agent releases them.
+    pub fn shared_data_block_bytes(&self) -> usize {
+        self.shared_data_block_bytes
+    }
+
+    /// Total number of bytes used by the heap vectors and data blocks.
+    pub fn total_bytes(&self) -> usize {
+        self.heap_bytes() + self.data_block_bytes + self.shared_data_block_bytes
+    }
+}
+
+fn push_vector<T>(vectors: &mut Vec<HeapVectorStatistics>, name: &'static str, vector: &[T]) {
+    vectors.push(HeapVectorStatistics {
+        name,
+        entries: vector.len(),
+        bytes: core::mem::size_of_val(vector),
+    });
+}
+
+fn push_soa_vector<T: SoAble>(
+    vectors: &mut Vec<HeapVectorStatistics>,
+    name: &'static str,
+    vector: &SoAVec<T>,
+) {
+    let entries = vector.len() as usize;
+    vectors.push(HeapVectorStatistics {
+        name,
+        entries,
+        bytes: entries * core::mem::size_of::<T>(),
+    });
+}
+
+fn heap_vectors(heap: &Heap) -> Vec<HeapVectorStatistics> {
+    let Heap {
+        #[cfg(feature = "array-buffer")]
+        array_buffers,
+        #[cfg(feature = "array-buffer")]
+            array_buffer_detach_keys: _,
+        arrays,
+        array_iterators,
+        async_generators,
+        await_reactions,
+        bigints,
+        bound_functions,
+        builtin_constructors,
+        builtin_functions,
+        caches: _,
+        #[cfg(feature = "date")]
+        dates,
+        #[cfg(feature = "temporal")]
+        instants,
+        #[cfg(feature = "temporal")]
+        durations,
+        #[cfg(feature = "temporal")]
+        plain_times,
+        ecmascript_functions,
+        elements,
+        embedder_objects,
+        environments,
+        errors,
+        executables,
+        finalization_registrys,
+        generators,
+        globals: _,
+        maps,
+        map_iterators,
+        numbers,
+        object_shapes,
+        object_shape_transitions,
+        prototype_shapes: _,
+        objects,
+        primitive_objects,
+        promise_reaction_records,
+        promise_resolving_functions,
+        promise_finally_functions,
+        promises,
+        proxies,
+        proxy_revoker_functions,
+        realms,
+        promise_group_records,
+        #[cfg(feature = "regexp")]
+        regexps,
+        #[cfg(feature = "regexp")]
+        regexp_string_iterators,
+        #[cfg(feature = "set")]
+        sets,
+        #[cfg(feature = "set")]
+        set_iterators,
+        #[cfg(feature = "shared-array-buffer")]
+        shared_array_buffers,
+        symbols,
+        #[cfg(feature = "array-buffer")]
+        typed_arrays,
+        #[cfg(feature = "array-buffer")]
+            typed_array_byte_lengths: _,
+        #[cfg(feature = "array-buffer")]
+            typed_array_byte_offsets: _,
+        #[cfg(feature = "array-buffer")]
+            typed_array_array_lengths: _,
+        #[cfg(feature = "array-buffer")]
+        data_views,
+        #[cfg(feature = "array-buffer")]
+            data_view_byte_lengths: _,
+        #[cfg(feature = "array-buffer")]
+            data_view_byte_offsets: _,
+        #[cfg(feature = "shared-array-buffer")]
+        shared_typed_arrays,
+        #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_byte_lengths: _,
+        #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_byte_offsets: _,
+        #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_array_lengths: _,
+        #[cfg(feature = "shared-array-buffer")]
+        shared_data_views,
+        #[cfg(feature = "shared-array-buffer")]
+            shared_data_view_byte_lengths: _,
+        #[cfg(feature = "shared-array-buffer")]
+            shared_data_view_byte_offsets: _,
+        #[cfg(feature = "weak-refs")]
+        weak_maps,
+        #[cfg(feature = "weak-refs")]
+        weak_refs,
+        #[cfg(feature = "weak-refs")]
+        weak_sets,
+        modules,
+        module_request_records,
+        source_text_module_records,
+        synthetic_module_records,
+        scripts,
+        string_iterators,
+        source_codes,
+        strings,
+        string_lookup_table: _,
+        string_hasher: _,
+        alloc_counter: _,
+    } = heap;
+    let mut vectors = Vec::with_capacity(128);
+    #[cfg(feature = "array-buffer")]
+    push_vector(&mut vectors, "array_buffers", array_buffers);
+    push_soa_vector(&mut vectors, "arrays", arrays);
+    push_vector(&mut vectors, "array_iterators", array_iterators);
+    push_vector(&mut vectors, "async_generators", async_generators);
+    push_vector(&mut vectors, "await_reactions", await_reactions);
+    push_vector(&mut vectors, "bigints", bigints);
+    push_vector(&mut vectors, "bound_functions", bound_functions);
+    push_vector(&mut vectors, "builtin_constructors", builtin_constructors);
+    push_vector(&mut vectors, "builtin_functions", builtin_functions);
+    #[cfg(feature = "date")]
+    push_vector(&mut vectors, "dates", dates);
+    #[cfg(feature = "temporal")]
+    push_vector(&mut vectors, "instants", instants);
+    #[cfg(feature = "temporal")]
+    push_vector(&mut vectors, "durations", durations);
+    #[cfg(feature = "temporal")]
+    push_vector(&mut vectors, "plain_times", plain_times);
+    push_vector(&mut vectors, "ecmascript_functions", ecmascript_functions);
+    push_vector(&mut vectors, "embedder_objects", embedder_objects);
+    push_vector(&mut vectors, "errors", errors);
+    push_vector(&mut vectors, "executables", executables);
+    push_soa_vector(
+        &mut vectors,
+        "finalization_registrys",
+        finalization_registrys,
+    );
+    push_vector(&mut vectors, "generators", generators);
+    push_soa_vector(&mut vectors, "maps", maps);
+    push_vector(&mut vectors, "map_iterators", map_iterators);
+    push_vector(&mut vectors, "numbers", numbers);
+    push_vector(&mut vectors, "object_shapes", object_shapes);
+    push_vector(
+        &mut vectors,
+        "object_shape_transitions",
+        object_shape_transitions,
+    );
+    push_vector(&mut vectors, "objects", objects);
+    push_vector(&mut vectors, "primitive_objects", primitive_objects);
+    push_vector(
+        &mut vectors,
+        "promise_reaction_records",
+        promise_reaction_records,
+    );
+    push_vector(
+        &mut vectors,
+        "promise_resolving_functions",
+        promise_resolving_functions,
+    );
+    push_vector(
+        &mut vectors,
+        "promise_finally_functions",
+        promise_finally_functions,
+    );
+    push_vector(&mut vectors, "promises", promises);
+    push_vector(&mut vectors, "proxies", proxies);
+    push_vector(
+        &mut vectors,
+        "proxy_revoker_functions",
+        proxy_revoker_functions,
+    );
+    push_vector(&mut vectors, "realms", realms);
+    push_vector(&mut vectors, "promise_group_records", promise_group_records);
+    #[cfg(feature = "regexp")]
+    push_vector(&mut vectors, "regexps", regexps);
+    #[cfg(feature = "regexp")]
+    push_vector(
+        &mut vectors,
+        "regexp_string_iterators",
+        regexp_string_iterators,
+    );
+    #[cfg(feature = "set")]
+    push_soa_vector(&mut vectors, "sets", sets);
+    #[cfg(feature = "set")]
+    push_vector(&mut vectors, "set_iterators", set_iterators);
+    #[cfg(feature = "shared-array-buffer")]
+    push_vector(&mut vectors, "shared_array_buffers", shared_array_buffers);
+    push_vector(&mut vectors, "symbols", symbols);
+    #[cfg(feature = "array-buffer")]
+    push_vector(&mut vectors, "typed_arrays", typed_arrays);
+    #[cfg(feature = "array-buffer")]
+    push_vector(&mut vectors, "data_views", data_views);
+    #[cfg(feature = "shared-array-buffer")]
+    push_vector(&mut vectors, "shared_typed_arrays", shared_typed_arrays);
+    #[cfg(feature = "shared-array-buffer")]
+    push_vector(&mut vectors, "shared_data_views", shared_data_views);
+    #[cfg(feature = "weak-refs")]
+    push_vector(&mut vectors, "weak_maps", weak_maps);
+    #[cfg(feature = "weak-refs")]
+    push_vector(&mut vectors, "weak_refs", weak_refs);
+    #[cfg(feature = "weak-refs")]
+    push_vector(&mut vectors, "weak_sets", weak_sets);
+    push_vector(&mut vectors, "modules", modules);
+    push_vector(
+        &mut vectors,
+        "module_request_records",
+        module_request_records,
+    );
+    push_vector(
+        &mut vectors,
+        "source_text_module_records",
+        source_text_module_records,
+    );
+    push_vector(
+        &mut vectors,
+        "synthetic_module_records",
+        synthetic_module_records,
+    );
+    push_vector(&mut vectors, "scripts", scripts);
+    push_vector(&mut vectors, "string_iterators", string_iterators);
+    push_vector(&mut vectors, "source_codes", source_codes);
+    let mut string_bytes = 0;
+    for string in strings {
+        if let StringBuffer::Owned(data) = &string.data {
+            string_bytes += data.len();
+        }
+    }
+    push_vector(&mut vectors, "strings", strings);
+    if let Some(strings) = vectors.last_mut() {
+        strings.bytes += string_bytes;
+    }
+    let Environments {
+        declarative,
+        function,
+        global,
+        object,
+        module,
+        private,
+    } = environments;
+    push_vector(&mut vectors, "declarative_environments", declarative);
+    push_vector(&mut vectors, "function_environments", function);
+    push_vector(&mut vectors, "global_environments", global);
+    push_vector(&mut vectors, "object_environments", object);
+    push_vector(&mut vectors, "module_environments", module);
+    push_vector(&mut vectors, "private_environments", private);
+    let ElementArrays {
+        k2pow1,
+        e2pow1,
+        k2pow2,
+        e2pow2,
+        k2pow3,
+        e2pow3,
+        k2pow4,
+        e2pow4,
+        k2pow6,
+        e2pow6,
+        k2pow8,
+        e2pow8,
+        k2pow10,
+        e2pow10,
+        k2pow12,
+        e2pow12,
+        k2pow16,
+        e2pow16,
+        k2pow24,
+        e2pow24,
+        k2pow32,
+        e2pow32,
+    } = elements;
+    push_vector(&mut vectors, "elements_2pow1", &e2pow1.values);
+    push_vector(&mut vectors, "property_keys_2pow1", &k2pow1.keys);
+    push_vector(&mut vectors, "elements_2pow2", &e2pow2.values);
+    push_vector(&mut vectors, "property_keys_2pow2", &k2pow2.keys);
+    push_vector(&mut vectors, "elements_2pow3", &e2pow3.values);
+    push_vector(&mut vectors, "property_keys_2pow3", &k2pow3.keys);
+    push_vector(&mut vectors, "elements_2pow4", &e2pow4.values);
+    push_vector(&mut vectors, "property_keys_2pow4", &k2pow4.keys);
+    push_vector(&mut vectors, "elements_2pow6", &e2pow6.values);
+    push_vector(&mut vectors, "property_keys_2pow6", &k2pow6.keys);
+    push_vector(&mut vectors, "elements_2pow8", &e2pow8.values);
+    push_vector(&mut vectors, "property_keys_2pow8", &k2pow8.keys);
+    push_vector(&mut vectors, "elements_2pow10", &e2pow10.values);
+    push_vector(&mut vectors, "property_keys_2pow10", &k2pow10.keys);
+    push_vector(&mut vectors, "elements_2pow12", &e2pow12.values);
+    push_vector(&mut vectors, "property_keys_2pow12", &k2pow12.keys);
+    push_vector(&mut vectors, "elements_2pow16", &e2pow16.values);
+    push_vector(&mut vectors, "property_keys_2pow16", &k2pow16.keys);
+    push_vector(&mut vectors, "elements_2pow24", &e2pow24.values);
+    push_vector(&mut vectors, "property_keys_2pow24", &k2pow24.keys);
+    push_vector(&mut vectors, "elements_2pow32", &e2pow32.values);
+    push_vector(&mut vectors, "property_keys_2pow32", &k2pow32.keys);
+    vectors
+}
+
+impl Agent {
+    /// Take a snapshot of the Agent's heap memory usage.
+    ///
+    /// This walks the Strings and ArrayBuffers on the heap, so it is not
+    /// free; embedders polling for memory usage should prefer
+    /// [`HostHooks::heap_threshold_exceeded`].
+    ///
+    /// [`HostHooks::heap_threshold_exceeded`]: super::HostHooks::heap_threshold_exceeded
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        let vectors = heap_vectors(&self.heap);
+        #[cfg(feature = "array-buffer")]
+        let data_block_bytes = self
+            .heap
+            .array_buffers
+            .iter()
+            .map(|buffer| buffer.byte_length())
+            .sum();
+        #[cfg(not(feature = "array-buffer"))]
+        let data_block_bytes = 0;
+        #[cfg(feature = "shared-array-buffer")]
+        let shared_data_block_bytes = self
+            .heap
+            .shared_array_buffers
+            .iter()
+            .map(|buffer| buffer.byte_length())
+            .sum();
+        #[cfg(not(feature = "shared-array-buffer"))]
+        let shared_data_block_bytes = 0;
+        HeapStatistics {
+            vectors,
+            data_block_bytes,
+            shared_data_block_bytes,
+        }
+    }
+}
+
+impl GcAgent {
+    /// Take a snapshot of the Agent's heap memory usage.
+    ///
+    /// See [`Agent::heap_statistics`].
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        self.agent.heap_statistics()
+    }
+}
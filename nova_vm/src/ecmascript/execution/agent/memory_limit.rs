@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Memory limit
+//!
+//! When [`AgentOptions::memory_limit`] is set, the Agent estimates the memory
+//! used by its heap as the bytes retained by the last garbage collection plus
+//! the bytes allocated since. Data blocks are checked against the limit when
+//! they are created or grown, and throw a RangeError when they do not fit,
+//! like an allocation failure would.
+//!
+//! Other heap allocations cannot fail, so the bytecode VM checks the estimate
+//! between instructions instead. When the estimate exceeds the limit, the VM
+//! first collects garbage; if the heap still uses more memory than allowed,
+//! the running JavaScript code is terminated using the Agent's
+//! [`TerminationHandle`] and [`HostHooks::memory_limit_exceeded`] is called.
+//!
+//! [`AgentOptions::memory_limit`]: super::AgentOptions::memory_limit
+//! [`TerminationHandle`]: super::TerminationHandle
+//! [`HostHooks::memory_limit_exceeded`]: super::HostHooks::memory_limit_exceeded
+
+use crate::{
+    ecmascript::{Agent, ExceptionType, JsError, JsResult},
+    engine::NoGcScope,
+};
+
+impl Agent {
+    /// Returns true if the estimated memory usage of the heap exceeds the
+    /// Agent's memory limit.
+    #[inline]
+    pub(crate) fn memory_limit_exceeded(&self) -> bool {
+        let limit = self.options.memory_limit;
+        limit != 0 && self.retained_bytes.saturating_add(self.heap.alloc_counter) > limit
+    }
+
+    /// Throw a RangeError if allocating a data block of `bytes` bytes would
+    /// exceed the Agent's memory limit.
+    pub(crate) fn check_data_block_allocation<'a>(
+        &mut self,
+        bytes: u64,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let limit = self.options.memory_limit;
+        if limit != 0
+            && (self.retained_bytes as u64)
+                .saturating_add(self.heap.alloc_counter as u64)
+                .saturating_add(bytes)
+                > limit as u64
+        {
+            return Err(self.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Memory limit exceeded",
+                gc,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Count a newly allocated data block of `bytes` bytes as allocated
+    /// memory.
+    pub(crate) fn record_data_block_allocation(&mut self, bytes: usize) {
+        self.heap.alloc_counter = self.heap.alloc_counter.saturating_add(bytes);
+    }
+
+    /// Terminate the running JavaScript code because the heap exceeds the
+    /// Agent's memory limit, and create the error that it unwinds with.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn throw_memory_limit_error<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        self.termination.terminate();
+        let statistics = self.heap_statistics();
+        self.host_hooks.memory_limit_exceeded(&statistics);
+        self.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Memory limit exceeded",
+            gc,
+        )
+    }
+}
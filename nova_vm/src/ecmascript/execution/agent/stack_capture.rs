@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Stack capture
+//!
+//! Capturing a description of the execution context stack for error
+//! reporting, profiler samples, and debugger pauses. The function name and
+//! source position of each execution context are recorded when it is created,
+//! so capturing the stack does not access any function objects.
+
+use crate::{
+    ecmascript::{Agent, LoadedSource, ScriptOrModule, SourceCode, String},
+    engine::{Bindable, NoGcScope, bindable_handle},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// The maximum number of frames in the stack trace of an Error object.
+///
+/// See [`Agent::capture_stack_trace`].
+pub const STACK_TRACE_LIMIT: usize = 10;
+
+/// A frame of a captured stack.
+///
+/// See [`Agent::capture_stack`].
+#[derive(Debug, Clone, Copy)]
+pub struct StackFrame<'a> {
+    function_name: Option<String<'a>>,
+    source_code: Option<SourceCode<'a>>,
+    source: Option<LoadedSource<'a>>,
+    source_offset: u32,
+}
+bindable_handle!(StackFrame);
+
+impl<'a> StackFrame<'a> {
+    /// The name of the function being evaluated, or `None` for Scripts,
+    /// Modules, eval code, and anonymous functions.
+    pub fn function_name(&self) -> Option<String<'a>> {
+        self.function_name
+    }
+
+    /// Returns true if the frame is evaluating a builtin function or a
+    /// function defined by the embedder, ie. not ECMAScript code.
+    pub fn is_native(&self) -> bool {
+        self.source_code.is_none()
+    }
+
+    /// The Script or Module containing the code being evaluated, or `None`
+    /// for native frames.
+    ///
+    /// Embedders can identify the source through its \[\[HostDefined]] field
+    /// or the key it was registered under with [`Agent::load_script`] or
+    /// [`Agent::load_module`].
+    pub fn source(&self) -> Option<LoadedSource<'a>> {
+        self.source
+    }
+
+    /// The 1-based line and column of the start of the evaluated function,
+    /// Script, or Module in its source text. Columns are counted in Unicode
+    /// scalar values. Returns `None` for native frames.
+    ///
+    /// Note that this is computed from the source text on each call.
+    pub fn line_and_column(&self, agent: &Agent) -> Option<(u32, u32)> {
+        let source_text = self.source_code?.get_source_text(agent);
+        let offset = (self.source_offset as usize).min(source_text.len());
+        let preceding = &source_text[..offset];
+        let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+        Some((
+            preceding.matches('\n').count() as u32 + 1,
+            preceding[line_start..].chars().count() as u32 + 1,
+        ))
+    }
+
+    /// Describe the frame in the format used by the `stack` property of
+    /// Error objects, eg. `inner (main.js:1:1)` or `forEach (native)`.
+    ///
+    /// The Script or Module is named by the key it was registered under in
+    /// the Agent's source registry, and left out if it is not registered.
+    pub fn describe(&self, agent: &Agent) -> std::string::String {
+        let name = self.function_name.map_or_else(
+            || "<anonymous>".into(),
+            |name| name.to_string_lossy(agent).into_owned(),
+        );
+        let Some((line, column)) = self.line_and_column(agent) else {
+            return format!("{name} (native)");
+        };
+        match self
+            .source
+            .and_then(|source| agent.source_registry.key_of(source.unbind()))
+        {
+            Some(key) => format!("{name} ({key}:{line}:{column})"),
+            None => format!("{name} ({line}:{column})"),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for StackFrame<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            function_name,
+            source_code,
+            source,
+            source_offset: _,
+        } = self;
+        function_name.mark_values(queues);
+        source_code.mark_values(queues);
+        source.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            function_name,
+            source_code,
+            source,
+            source_offset: _,
+        } = self;
+        function_name.sweep_values(compactions);
+        source_code.sweep_values(compactions);
+        source.sweep_values(compactions);
+    }
+}
+
+impl Agent {
+    /// Capture up to `depth` frames of the execution context stack, starting
+    /// from the running execution context.
+    ///
+    /// Execution contexts that only exist to set the current Realm, such as
+    /// those created by [`Agent::run_in_realm`], are not included.
+    ///
+    /// [`Agent::run_in_realm`]: crate::ecmascript::GcAgent::run_in_realm
+    pub fn capture_stack<'a>(&self, depth: usize, gc: NoGcScope<'a, '_>) -> Vec<StackFrame<'a>> {
+        self.execution_context_stack
+            .iter()
+            .rev()
+            .filter(|context| context.function.is_some() || context.ecmascript_code.is_some())
+            .take(depth)
+            .map(|context| StackFrame {
+                function_name: context.frame.function_name.bind(gc),
+                source_code: context
+                    .ecmascript_code
+                    .as_ref()
+                    .map(|code| code.source_code.bind(gc)),
+                source: context
+                    .ecmascript_code
+                    .as_ref()
+                    .and(context.script_or_module)
+                    .map(|script_or_module| match script_or_module {
+                        ScriptOrModule::Script(script) => script.bind(gc).into(),
+                        ScriptOrModule::SourceTextModule(module) => module.bind(gc).into(),
+                    }),
+                source_offset: context.frame.source_offset,
+            })
+            .collect()
+    }
+
+    /// Capture the stack trace that an Error object created by the running
+    /// execution context records, ie. up to [`STACK_TRACE_LIMIT`] frames.
+    ///
+    /// Frames describe the start of the evaluated function rather than the
+    /// position of the call within it.
+    pub fn capture_stack_trace<'a>(&self, gc: NoGcScope<'a, '_>) -> Vec<StackFrame<'a>> {
+        self.capture_stack(STACK_TRACE_LIMIT, gc)
+    }
+}
@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Termination
+//!
+//! A running script can be interrupted from another thread using a
+//! [`TerminationHandle`], or from the Agent's own thread (eg. in a host hook)
+//! using [`Agent::terminate_execution`]. When termination is requested, the
+//! running JavaScript code throws an Error at its next loop iteration. The
+//! error cannot be caught by `try`/`catch` and `finally` blocks are not run: it
+//! is returned to the embedder as the error of the call that entered the
+//! engine. Promise executors, async functions and Promise Jobs pass the error
+//! on instead of rejecting a Promise with it, and microtask checkpoints do not
+//! run Jobs until the termination is cancelled; see [`JsError::is_termination`].
+//!
+//! Builtins that loop over user-controlled lengths, such as the generic Array
+//! methods, `JSON.stringify`, and the global RegExp matching methods, check
+//! for termination on each iteration as well. Note that a single RegExp match
+//! cannot be interrupted.
+//!
+//! Unlike a [`FatalError`], termination does not invalidate the Agent: after
+//! the request is cancelled, the Agent can run code again.
+//!
+//! [`FatalError`]: crate::ecmascript::FatalError
+
+use core::{fmt, time::Duration};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::{
+    ecmascript::{Agent, ExceptionType, GcAgent, JsError, JsResult, String, Value},
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+/// A thread-safe handle for terminating the JavaScript code running in an
+/// Agent.
+///
+/// See [`Agent::termination_handle`].
+#[derive(Debug, Clone, Default)]
+pub struct TerminationHandle(Arc<AtomicBool>);
+
+impl TerminationHandle {
+    /// Request the Agent to terminate the running JavaScript code.
+    ///
+    /// The request stays in effect until it is cancelled: any JavaScript code
+    /// run before then is terminated as well.
+    pub fn terminate(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Cancel a termination request.
+    pub fn cancel(&self) {
+        self.0.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns true if termination has been requested and not cancelled.
+    pub fn is_terminating(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The error returned by [`Agent::evaluate_with_timeout`] when evaluation
+/// does not finish within the timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Evaluation timed out")
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+impl Agent {
+    /// Get a handle for terminating JavaScript code running in this Agent.
+    ///
+    /// The handle can be sent to other threads, eg. to an embedder-provided
+    /// timer.
+    pub fn termination_handle(&self) -> TerminationHandle {
+        self.termination.clone()
+    }
+
+    /// Request termination of the JavaScript code running in this Agent.
+    ///
+    /// This is equivalent to calling [`TerminationHandle::terminate`] on the
+    /// Agent's termination handle, and likewise stays in effect until the
+    /// request is cancelled.
+    pub fn terminate_execution(&self) {
+        self.termination.terminate();
+    }
+
+    /// Returns true if termination of the running JavaScript code has been
+    /// requested, or the Agent is out of fuel.
+    #[inline]
+    pub(crate) fn is_terminating(&self) -> bool {
+        self.termination.is_terminating() || self.fuel_exhausted
+    }
+
+    /// Throw the termination error if termination of the running JavaScript
+    /// code has been requested.
+    ///
+    /// This should be called on each iteration of builtin loops whose length
+    /// is controlled by JavaScript code.
+    #[inline]
+    pub(crate) fn check_termination<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        if self.is_terminating() {
+            return Err(self.throw_termination_error(gc));
+        }
+        Ok(())
+    }
+
+    /// Create the error that terminated JavaScript code unwinds with.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn throw_termination_error<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        let error = self.throw_exception_with_static_message(
+            ExceptionType::Error,
+            "Execution terminated",
+            gc,
+        );
+        self.set_termination_error(error)
+    }
+
+    /// Record the error that terminated JavaScript code unwinds with, so that
+    /// it can be told apart from other errors; see [`JsError::is_termination`].
+    pub(crate) fn set_termination_error<'a>(&mut self, error: JsError<'a>) -> JsError<'a> {
+        self.termination_error = Some(error.unbind());
+        error
+    }
+
+    /// Evaluate source text as a Script in the current Realm, terminating the
+    /// evaluation if it does not finish within the given timeout.
+    ///
+    /// The timeout is enforced by a watchdog thread using the Agent's
+    /// [`TerminationHandle`]. Embedders with their own timers can use
+    /// [`Agent::termination_handle`] directly instead.
+    ///
+    /// Only the termination requested by the watchdog is cancelled before
+    /// returning; termination requested by others stays in effect.
+    pub fn evaluate_with_timeout<'gc>(
+        &mut self,
+        source_text: String,
+        timeout: Duration,
+        gc: GcScope<'gc, '_>,
+    ) -> Result<JsResult<'gc, Value<'gc>>, TimedOut> {
+        let handle = self.termination_handle();
+        let (finished, watchdog_finished) = mpsc::channel::<()>();
+        let watchdog = {
+            let handle = handle.clone();
+            thread::spawn(move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) =
+                    watchdog_finished.recv_timeout(timeout)
+                {
+                    // Returns true only if this request started the
+                    // termination: a termination already requested by others
+                    // is not the watchdog's to cancel.
+                    handle
+                        .0
+                        .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+                        .is_ok()
+                } else {
+                    false
+                }
+            })
+        };
+        let result = self.run_script(source_text, gc);
+        drop(finished);
+        let timed_out = watchdog.join().unwrap();
+        if timed_out {
+            handle.cancel();
+        }
+        // Note: if the watchdog fired after the evaluation finished, the
+        // result is still valid.
+        match result {
+            Err(_) if timed_out => Err(TimedOut),
+            result => Ok(result),
+        }
+    }
+}
+
+impl GcAgent {
+    /// Get a handle for terminating JavaScript code running in this Agent.
+    ///
+    /// See [`Agent::termination_handle`].
+    pub fn termination_handle(&self) -> TerminationHandle {
+        self.agent.termination_handle()
+    }
+
+    /// Request termination of the JavaScript code running in this Agent.
+    ///
+    /// See [`Agent::terminate_execution`].
+    pub fn terminate_execution(&self) {
+        self.agent.terminate_execution();
+    }
+}
@@ -479,6 +479,29 @@ impl<'e> Environment<'e> {
         }
     }
 
+    /// Get the bound value of the initialized, mutable binding N for
+    /// in-place modification.
+    ///
+    /// Returns None if this is not a Declarative or Function Environment
+    /// Record, or if the binding does not exist, is uninitialized, or is
+    /// immutable. In those cases SetMutableBinding must be used instead.
+    pub(crate) fn get_mutable_binding_value_mut<'a>(
+        self,
+        agent: &'a mut Agent,
+        name: String,
+    ) -> Option<&'a mut Value<'static>> {
+        let binding = match self {
+            Environment::Declarative(e) => e.get_binding_mut(agent, name),
+            Environment::Function(e) => e.get_binding_mut(agent, name),
+            Environment::Global(_) | Environment::Module(_) | Environment::Object(_) => None,
+        }?;
+        if binding.mutable {
+            binding.value.as_mut()
+        } else {
+            None
+        }
+    }
+
     /// ### Try [GetBindingValue(N, S)](https://tc39.es/ecma262/#table-abstract-methods-of-environment-records)
     ///
     /// Returns the value of an already existing binding from an Environment
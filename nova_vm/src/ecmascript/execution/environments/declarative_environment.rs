@@ -39,6 +39,13 @@ pub(crate) struct Binding {
     pub(super) deletable: bool,
 }
 
+impl Binding {
+    /// Returns true if the binding is mutable.
+    pub(crate) fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
 impl DeclarativeEnvironmentRecord {
     /// ### [9.1.2.2 NewDeclarativeEnvironment ( E )](https://tc39.es/ecma262/#sec-newdeclarativeenvironment)
     ///
@@ -210,6 +217,18 @@ impl<'e> DeclarativeEnvironment<'e> {
         self.get(agent).outer_env
     }
 
+    /// Iterate over the names and bindings of the environment in an
+    /// unspecified order.
+    pub(crate) fn iter_bindings(
+        self,
+        agent: &Agent,
+    ) -> impl Iterator<Item = (String<'e>, &Binding)> {
+        self.get(agent)
+            .bindings
+            .iter()
+            .map(|(name, binding)| (*name, binding))
+    }
+
     /// ### [9.1.1.1.1 HasBinding ( N )](https://tc39.es/ecma262/#sec-declarative-environment-records-hasbinding-n)
     ///
     /// The HasBinding concrete method of a Declarative Environment Record
@@ -4,7 +4,7 @@
 
 use crate::{
     ecmascript::{
-        Agent, DeclarativeEnvironment, DeclarativeEnvironmentRecord, ECMAScriptFunction,
+        Agent, Binding, DeclarativeEnvironment, DeclarativeEnvironmentRecord, ECMAScriptFunction,
         Environment, ExceptionType, Function, FunctionEnvironment, InternalMethods, JsResult,
         Object, String, ThisMode, Value, unwrap_try,
     },
@@ -220,6 +220,10 @@ impl<'e> FunctionEnvironment<'e> {
         self.get(agent).declarative_environment.get_outer_env(agent)
     }
 
+    pub(crate) fn get_declarative_environment(self, agent: &Agent) -> DeclarativeEnvironment<'e> {
+        self.get(agent).declarative_environment
+    }
+
     pub(crate) fn get_this_binding_status(self, agent: &Agent) -> ThisBindingStatus {
         self.get(agent).this_binding_status
     }
@@ -255,6 +259,15 @@ impl<'e> FunctionEnvironment<'e> {
             .has_binding(agent, name)
     }
 
+    pub(crate) fn get_binding_mut<'a>(
+        self,
+        agent: &'a mut Agent,
+        name: String,
+    ) -> Option<&'a mut Binding> {
+        let dcl_rec = self.get(agent).declarative_environment;
+        dcl_rec.get_binding_mut(agent, name)
+    }
+
     /// ### [9.1.1.1.2 CreateMutableBinding ( N, D )](https://tc39.es/ecma262/#sec-declarative-environment-records-createmutablebinding-n-d)
     pub(crate) fn create_mutable_binding(
         self,
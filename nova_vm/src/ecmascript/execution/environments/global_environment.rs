@@ -148,6 +148,10 @@ impl<'e> GlobalEnvironment<'e> {
         self.get(agent).object_record.get_binding_object(agent)
     }
 
+    pub(crate) fn get_declarative_record(self, agent: &Agent) -> DeclarativeEnvironment<'e> {
+        self.get(agent).declarative_record
+    }
+
     /// ### Try [9.1.1.4.1 HasBinding ( N )](https://tc39.es/ecma262/#sec-global-environment-records-hasbinding-n)
     ///
     /// The HasBinding concrete method of a Global Environment Record envRec
@@ -97,7 +97,10 @@ pub(crate) fn new_module_environment<'a>(
 }
 
 impl<'e> ModuleEnvironment<'e> {
-    fn get_declarative_env(self, agent: &impl AsRef<Environments>) -> DeclarativeEnvironment<'e> {
+    pub(crate) fn get_declarative_env(
+        self,
+        agent: &impl AsRef<Environments>,
+    ) -> DeclarativeEnvironment<'e> {
         agent
             .as_ref()
             .get_module_environment(self)
@@ -108,6 +111,21 @@ impl<'e> ModuleEnvironment<'e> {
         self.get_declarative_env(agent).get_outer_env(agent)
     }
 
+    /// Get the names of the indirect import bindings of the environment in an
+    /// unspecified order.
+    pub(crate) fn get_indirect_binding_names(
+        self,
+        agent: &impl AsRef<Environments>,
+    ) -> Vec<String<'e>> {
+        agent
+            .as_ref()
+            .get_module_environment(self)
+            .indirect_bindings
+            .keys()
+            .copied()
+            .collect()
+    }
+
     /// # [HasBinding(N)](https://tc39.es/ecma262/#table-abstract-methods-of-environment-records)
     ///
     /// Determine if an Environment Record has a binding for the String value
@@ -615,7 +615,7 @@ impl<'e> ObjectEnvironment<'e> {
         // 3. If stillExists is false and S is true, throw a ReferenceError exception.
         if !still_exists && s {
             let binding_object_repr =
-                Value::from(scoped_binding_object.get(agent)).string_repr(agent, gc.reborrow());
+                Value::from(scoped_binding_object.get(agent)).try_string_repr(agent, gc.nogc());
             Err(Self::throw_property_doesnt_exist_error(
                 agent,
                 binding_object_repr.unbind(),
@@ -4,7 +4,7 @@
 
 use super::{Agent, Environment, JsResult, PrivateEnvironment, Realm, get_this_environment};
 use crate::{
-    ecmascript::{Function, Object, ScriptOrModule, SourceCode, Value},
+    ecmascript::{Function, Object, ScriptOrModule, SourceCode, String, Value},
     engine::{Bindable, NoGcScope},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -86,6 +86,42 @@ pub(crate) struct ExecutionContext {
     /// for the original execution context created in
     /// InitializeHostDefinedRealm, the value is null.
     pub(crate) script_or_module: Option<ScriptOrModule<'static>>,
+
+    /// Nova-specific metadata describing the execution context for stack
+    /// capture. This is recorded when the execution context is created so
+    /// that capturing the stack does not need to access function objects.
+    pub(crate) frame: FrameMetadata,
+}
+
+/// Lightweight description of the code an execution context evaluates.
+///
+/// See [`Agent::capture_stack`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FrameMetadata {
+    /// Name of the function being evaluated, if the function has a name.
+    pub(crate) function_name: Option<String<'static>>,
+    /// Byte offset of the start of the evaluated function's source text in
+    /// the execution context's source code; 0 for Scripts, Modules, and
+    /// eval code.
+    pub(crate) source_offset: u32,
+}
+
+impl FrameMetadata {
+    /// Metadata of an execution context that does not evaluate a function.
+    pub(crate) const NONE: Self = Self {
+        function_name: None,
+        source_offset: 0,
+    };
+
+    pub(crate) fn new(function_name: Option<String>, source_offset: u32) -> Self {
+        Self {
+            // Anonymous functions are given the empty string as their name.
+            function_name: function_name
+                .filter(|name| !name.is_empty_string())
+                .map(|name| name.unbind()),
+            source_offset,
+        }
+    }
 }
 
 impl ExecutionContext {
@@ -131,11 +167,13 @@ impl HeapMarkAndSweep for ExecutionContext {
             function,
             realm,
             script_or_module,
+            frame,
         } = self;
         ecmascript_code.mark_values(queues);
         function.mark_values(queues);
         realm.mark_values(queues);
         script_or_module.mark_values(queues);
+        frame.function_name.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -144,11 +182,13 @@ impl HeapMarkAndSweep for ExecutionContext {
             function,
             realm,
             script_or_module,
+            frame,
         } = self;
         ecmascript_code.sweep_values(compactions);
         function.sweep_values(compactions);
         realm.sweep_values(compactions);
         script_or_module.sweep_values(compactions);
+        frame.function_name.sweep_values(compactions);
     }
 }
 
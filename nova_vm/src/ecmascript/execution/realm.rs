@@ -7,12 +7,14 @@ mod intrinsics;
 pub use intrinsics::*;
 
 use super::{
-    Agent, ExecutionContext, JsResult, environments::GlobalEnvironment, new_global_environment,
+    Agent, ExecutionContext, FrameMetadata, JsResult, environments::GlobalEnvironment,
+    new_global_environment,
 };
 use crate::{
     ecmascript::{
         AbstractModule, BUILTIN_STRING_MEMORY, HostDefined, LoadedModules, ModuleRequest, Number,
-        Object, OrdinaryObject, PropertyDescriptor, PropertyKey, Value, define_property_or_throw,
+        Object, OrdinaryObject, PropertyDescriptor, PropertyKey, SyntheticModule, Value,
+        define_property_or_throw,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
     heap::{
@@ -21,6 +23,7 @@ use crate::{
     },
 };
 use core::marker::PhantomData;
+use std::rc::Rc;
 
 /// ## [9.3 Realms](https://tc39.es/ecma262/#sec-code-realms)
 ///
@@ -50,6 +53,13 @@ impl<'r> Realm<'r> {
         self.get(agent).host_defined.clone()
     }
 
+    /// Get the \[\[HostDefined]] field downcast to `T`.
+    ///
+    /// Returns None if the field is empty or holds a value of another type.
+    pub fn host_defined_as<T: 'static>(self, agent: &Agent) -> Option<Rc<T>> {
+        self.host_defined(agent)?.downcast().ok()
+    }
+
     /// Initialize the \[\[HostDefined]] field to a value.
     ///
     /// ## Panics
@@ -88,6 +98,31 @@ impl<'r> Realm<'r> {
             .loaded_modules
             .insert_loaded_module(requests, request, module);
     }
+
+    /// Get the instance of the native module at the given registration index
+    /// in this realm, if it has been created.
+    pub(crate) fn get_native_module(
+        self,
+        agent: &Agent,
+        index: usize,
+    ) -> Option<SyntheticModule<'r>> {
+        self.get(agent).native_modules.get(index).copied().flatten()
+    }
+
+    /// Set the instance of the native module at the given registration index
+    /// in this realm.
+    pub(crate) fn set_native_module(
+        self,
+        agent: &mut Agent,
+        index: usize,
+        module: SyntheticModule,
+    ) {
+        let native_modules = &mut self.get_mut(agent).native_modules;
+        if native_modules.len() <= index {
+            native_modules.resize(index + 1, None);
+        }
+        native_modules[index] = Some(module.unbind());
+    }
 }
 
 impl HeapMarkAndSweep for Realm<'static> {
@@ -154,6 +189,10 @@ pub(crate) struct RealmRecord<'a> {
     /// same \[\[Specifier]].
     loaded_modules: LoadedModules<'a>,
 
+    /// Native modules instantiated in this realm, indexed by their
+    /// registration order in the Agent.
+    native_modules: Vec<Option<SyntheticModule<'a>>>,
+
     /// ### \[\[HostDefined]]
     ///
     /// Field reserved for use by hosts that need to associate additional
@@ -180,12 +219,14 @@ impl HeapMarkAndSweep for RealmRecord<'static> {
             global_env,
             template_map: _,
             loaded_modules,
+            native_modules,
             host_defined: _,
         } = self;
         intrinsics.mark_values(queues);
         global_env.mark_values(queues);
         global_object.mark_values(queues);
         loaded_modules.mark_values(queues);
+        native_modules.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -196,12 +237,14 @@ impl HeapMarkAndSweep for RealmRecord<'static> {
             global_env,
             template_map: _,
             loaded_modules,
+            native_modules,
             host_defined: _,
         } = self;
         intrinsics.sweep_values(compactions);
         global_env.sweep_values(compactions);
         global_object.sweep_values(compactions);
         loaded_modules.sweep_values(compactions);
+        native_modules.sweep_values(compactions);
     }
 }
 
@@ -230,6 +273,7 @@ pub(crate) fn create_realm<'gc>(agent: &mut Agent, gc: NoGcScope<'gc, '_>) -> Re
         // NOTE: These fields are implicitly empty.
         host_defined: None,
         loaded_modules: Default::default(),
+        native_modules: Default::default(),
     };
 
     // 7. Return realmRec.
@@ -588,6 +632,71 @@ pub(crate) fn set_default_global_bindings<'a>(
 
         #[cfg(feature = "temporal")]
         define_property!(intrinsic Temporal, temporal);
+
+        // ECMA-402 8 The Intl Object
+        #[cfg(feature = "intl")]
+        define_property!(intrinsic Intl, intl);
+    }
+
+    // Web APIs
+    {
+        // AbortController ( . . . )
+        #[cfg(feature = "web-abort")]
+        define_property!(intrinsic AbortController, abort_controller);
+
+        // AbortSignal ( . . . )
+        #[cfg(feature = "web-abort")]
+        define_property!(intrinsic AbortSignal, abort_signal);
+
+        // Blob ( . . . )
+        #[cfg(feature = "web-blob")]
+        define_property!(intrinsic Blob, blob);
+
+        // Event ( . . . )
+        #[cfg(feature = "web-events")]
+        define_property!(intrinsic Event, event);
+
+        // EventTarget ( . . . )
+        #[cfg(feature = "web-events")]
+        define_property!(intrinsic EventTarget, event_target);
+
+        // fetch ( input [ , init ] )
+        #[cfg(feature = "web-fetch")]
+        define_property!(intrinsic fetch, fetch);
+
+        // File ( . . . )
+        #[cfg(feature = "web-blob")]
+        define_property!(intrinsic File, file);
+
+        // Headers ( . . . )
+        #[cfg(feature = "web-fetch")]
+        define_property!(intrinsic Headers, headers);
+
+        // ReadableStream ( . . . )
+        #[cfg(feature = "web-streams")]
+        define_property!(intrinsic ReadableStream, readable_stream);
+
+        // ReadableStreamDefaultController ( . . . )
+        #[cfg(feature = "web-streams")]
+        define_property!(
+            intrinsic ReadableStreamDefaultController,
+            readable_stream_default_controller
+        );
+
+        // ReadableStreamDefaultReader ( . . . )
+        #[cfg(feature = "web-streams")]
+        define_property!(
+            intrinsic ReadableStreamDefaultReader,
+            readable_stream_default_reader
+        );
+
+        // Request ( . . . )
+        #[cfg(feature = "web-fetch")]
+        define_property!(intrinsic Request, request);
+
+        // Response ( . . . )
+        #[cfg(feature = "web-fetch")]
+        define_property!(intrinsic Response, response);
     }
 
     // 3. Return global.
@@ -621,6 +730,7 @@ pub(crate) fn initialize_host_defined_realm(
 
         // 5. Set the ScriptOrModule of newContext to null.
         script_or_module: None,
+        frame: FrameMetadata::NONE,
     };
 
     // 6. Push newContext onto the execution context stack; newContext is now the running execution context.
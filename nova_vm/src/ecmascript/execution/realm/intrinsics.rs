@@ -11,13 +11,40 @@ use crate::ecmascript::AtomicsObject;
 use crate::ecmascript::JSONObject;
 #[cfg(feature = "math")]
 use crate::ecmascript::MathObject;
+#[cfg(feature = "web-abort")]
+use crate::ecmascript::{
+    AbortControllerConstructor, AbortControllerPrototype, AbortSignalConstructor,
+    AbortSignalPrototype,
+};
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{
     ArrayBufferConstructor, ArrayBufferPrototype, DataViewConstructor, DataViewPrototype,
     TypedArrayConstructors, TypedArrayIntrinsicObject, TypedArrayPrototype, TypedArrayPrototypes,
 };
+#[cfg(feature = "web-blob")]
+use crate::ecmascript::{BlobConstructor, BlobPrototype, FileConstructor, FilePrototype};
 #[cfg(feature = "date")]
 use crate::ecmascript::{DateConstructor, DatePrototype};
+#[cfg(feature = "intl")]
+use crate::ecmascript::{
+    DateTimeFormatConstructor, DateTimeFormatPrototype, IntlObject, NumberFormatConstructor,
+    NumberFormatPrototype,
+};
+#[cfg(feature = "web-events")]
+use crate::ecmascript::{
+    EventConstructor, EventPrototype, EventTargetConstructor, EventTargetPrototype,
+};
+#[cfg(feature = "web-fetch")]
+use crate::ecmascript::{
+    FetchFunction, HeadersConstructor, HeadersPrototype, RequestConstructor, RequestPrototype,
+    ResponseConstructor, ResponsePrototype,
+};
+#[cfg(feature = "web-streams")]
+use crate::ecmascript::{
+    ReadableStreamConstructor, ReadableStreamDefaultControllerConstructor,
+    ReadableStreamDefaultControllerPrototype, ReadableStreamDefaultReaderConstructor,
+    ReadableStreamDefaultReaderPrototype, ReadableStreamPrototype,
+};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::{RegExpConstructor, RegExpPrototype, RegExpStringIteratorPrototype};
 #[cfg(feature = "set")]
@@ -77,6 +104,11 @@ pub(crate) struct Intrinsics {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ProtoIntrinsics {
+    #[cfg(feature = "web-abort")]
+    /// ```javascript
+    /// AbortController.prototype
+    /// ```
+    AbortController,
     /// ```javascript
     /// AggregateError.prototype
     /// ```
@@ -120,6 +152,11 @@ pub enum ProtoIntrinsics {
     /// BigUint64Array.prototype
     /// ```
     BigUint64Array,
+    #[cfg(feature = "web-blob")]
+    /// ```javascript
+    /// Blob.prototype
+    /// ```
+    Blob,
     /// ```javascript
     /// Boolean.prototype
     /// ```
@@ -147,6 +184,21 @@ pub enum ProtoIntrinsics {
     /// EvalError.prototype
     /// ```
     EvalError,
+    #[cfg(feature = "web-events")]
+    /// ```javascript
+    /// Event.prototype
+    /// ```
+    Event,
+    #[cfg(feature = "web-events")]
+    /// ```javascript
+    /// EventTarget.prototype
+    /// ```
+    EventTarget,
+    #[cfg(feature = "web-blob")]
+    /// ```javascript
+    /// File.prototype
+    /// ```
+    File,
     /// ```javascript
     /// FinalizationRegistry.prototype
     /// ```
@@ -178,6 +230,11 @@ pub enum ProtoIntrinsics {
     /// GeneratorFunction.prototype
     /// ```
     GeneratorFunction,
+    #[cfg(feature = "web-fetch")]
+    /// ```javascript
+    /// Headers.prototype
+    /// ```
+    Headers,
     #[cfg(feature = "array-buffer")]
     /// ```javascript
     /// Int16Array.prototype
@@ -193,6 +250,16 @@ pub enum ProtoIntrinsics {
     /// Int8Array.prototype
     /// ```
     Int8Array,
+    #[cfg(feature = "intl")]
+    /// ```javascript
+    /// Intl.DateTimeFormat.prototype
+    /// ```
+    IntlDateTimeFormat,
+    #[cfg(feature = "intl")]
+    /// ```javascript
+    /// Intl.NumberFormat.prototype
+    /// ```
+    IntlNumberFormat,
     /// ```javascript
     /// Iterator.prototype
     /// ```
@@ -221,6 +288,16 @@ pub enum ProtoIntrinsics {
     /// RangeError.prototype
     /// ```
     RangeError,
+    #[cfg(feature = "web-streams")]
+    /// ```javascript
+    /// ReadableStream.prototype
+    /// ```
+    ReadableStream,
+    #[cfg(feature = "web-streams")]
+    /// ```javascript
+    /// ReadableStreamDefaultReader.prototype
+    /// ```
+    ReadableStreamDefaultReader,
     /// ```javascript
     /// ReferenceError.prototype
     /// ```
@@ -230,6 +307,16 @@ pub enum ProtoIntrinsics {
     /// RegExp.prototype
     /// ```
     RegExp,
+    #[cfg(feature = "web-fetch")]
+    /// ```javascript
+    /// Request.prototype
+    /// ```
+    Request,
+    #[cfg(feature = "web-fetch")]
+    /// ```javascript
+    /// Response.prototype
+    /// ```
+    Response,
     #[cfg(feature = "set")]
     /// ```javascript
     /// Set.prototype
@@ -461,6 +548,64 @@ impl Intrinsics {
         WeakRefConstructor::create_intrinsic(agent, realm);
         FinalizationRegistryPrototype::create_intrinsic(agent, realm);
         FinalizationRegistryConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "intl")]
+        {
+            IntlObject::create_intrinsic(agent, realm);
+            DateTimeFormatPrototype::create_intrinsic(agent, realm);
+            DateTimeFormatConstructor::create_intrinsic(agent, realm);
+            NumberFormatPrototype::create_intrinsic(agent, realm);
+            NumberFormatConstructor::create_intrinsic(agent, realm);
+        }
+        #[cfg(feature = "web-abort")]
+        AbortSignalPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-abort")]
+        AbortSignalConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-abort")]
+        AbortControllerPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-abort")]
+        AbortControllerConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-events")]
+        EventPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-events")]
+        EventConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-events")]
+        EventTargetPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-events")]
+        EventTargetConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-blob")]
+        BlobPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-blob")]
+        BlobConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-blob")]
+        FilePrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-blob")]
+        FileConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        HeadersPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        HeadersConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        RequestPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        RequestConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        ResponsePrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        ResponseConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-fetch")]
+        FetchFunction::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamDefaultReaderPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamDefaultReaderConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamDefaultControllerPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "web-streams")]
+        ReadableStreamDefaultControllerConstructor::create_intrinsic(agent, realm);
         IteratorPrototype::create_intrinsic(agent, realm);
         AsyncIteratorPrototype::create_intrinsic(agent, realm);
         PromisePrototype::create_intrinsic(agent, realm);
@@ -491,16 +636,36 @@ impl Intrinsics {
             ProtoIntrinsics::ArrayBuffer => self.array_buffer().into(),
             ProtoIntrinsics::ArrayIterator => unreachable!(),
             ProtoIntrinsics::BigInt => self.big_int().into(),
+            #[cfg(feature = "web-blob")]
+            ProtoIntrinsics::Blob => self.blob().into(),
             ProtoIntrinsics::Boolean => self.boolean().into(),
             ProtoIntrinsics::Error => self.error().into(),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => self.date().into(),
             ProtoIntrinsics::EvalError => self.eval_error().into(),
+            #[cfg(feature = "web-events")]
+            ProtoIntrinsics::Event => self.event().into(),
+            #[cfg(feature = "web-events")]
+            ProtoIntrinsics::EventTarget => self.event_target().into(),
+            #[cfg(feature = "web-blob")]
+            ProtoIntrinsics::File => self.file().into(),
             ProtoIntrinsics::Function => self.function().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Headers => self.headers().into(),
             ProtoIntrinsics::Number => self.number().into(),
             ProtoIntrinsics::Object => self.object().into(),
             ProtoIntrinsics::RangeError => self.range_error().into(),
+            #[cfg(feature = "web-streams")]
+            ProtoIntrinsics::ReadableStream => self.readable_stream().into(),
+            #[cfg(feature = "web-streams")]
+            ProtoIntrinsics::ReadableStreamDefaultReader => {
+                self.readable_stream_default_reader().into()
+            }
             ProtoIntrinsics::ReferenceError => self.reference_error().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Request => self.request().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Response => self.response().into(),
             ProtoIntrinsics::StringIterator => unreachable!(),
             #[cfg(feature = "regexp")]
             ProtoIntrinsics::RegExpStringIterator => unreachable!(),
@@ -515,6 +680,8 @@ impl Intrinsics {
             ProtoIntrinsics::TemporalPlainTime => self.temporal_plain_time().into(),
             ProtoIntrinsics::TypeError => self.type_error().into(),
             ProtoIntrinsics::URIError => self.uri_error().into(),
+            #[cfg(feature = "web-abort")]
+            ProtoIntrinsics::AbortController => self.abort_controller().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error().into(),
             ProtoIntrinsics::AsyncFunction => self.async_function().into(),
             ProtoIntrinsics::AsyncGenerator => self.async_generator_function().into(),
@@ -542,6 +709,10 @@ impl Intrinsics {
             ProtoIntrinsics::Int32Array => self.int32_array().into(),
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => self.int8_array().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::IntlDateTimeFormat => self.intl_date_time_format().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::IntlNumberFormat => self.intl_number_format().into(),
             ProtoIntrinsics::Iterator => self.iterator().into(),
             ProtoIntrinsics::Map => self.map().into(),
             ProtoIntrinsics::MapIterator => unreachable!(),
@@ -584,16 +755,36 @@ impl Intrinsics {
             ProtoIntrinsics::ArrayBuffer => self.array_buffer_prototype().into(),
             ProtoIntrinsics::ArrayIterator => self.array_iterator_prototype().into(),
             ProtoIntrinsics::BigInt => self.big_int_prototype().into(),
+            #[cfg(feature = "web-blob")]
+            ProtoIntrinsics::Blob => self.blob_prototype().into(),
             ProtoIntrinsics::Boolean => self.boolean_prototype().into(),
             ProtoIntrinsics::Error => self.error_prototype().into(),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => self.date_prototype().into(),
             ProtoIntrinsics::EvalError => self.eval_error_prototype().into(),
+            #[cfg(feature = "web-events")]
+            ProtoIntrinsics::Event => self.event_prototype().into(),
+            #[cfg(feature = "web-events")]
+            ProtoIntrinsics::EventTarget => self.event_target_prototype().into(),
+            #[cfg(feature = "web-blob")]
+            ProtoIntrinsics::File => self.file_prototype().into(),
             ProtoIntrinsics::Function => self.function_prototype().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Headers => self.headers_prototype().into(),
             ProtoIntrinsics::Number => self.number_prototype().into(),
             ProtoIntrinsics::Object => self.object_prototype().into(),
             ProtoIntrinsics::RangeError => self.range_error_prototype().into(),
+            #[cfg(feature = "web-streams")]
+            ProtoIntrinsics::ReadableStream => self.readable_stream_prototype().into(),
+            #[cfg(feature = "web-streams")]
+            ProtoIntrinsics::ReadableStreamDefaultReader => {
+                self.readable_stream_default_reader_prototype().into()
+            }
             ProtoIntrinsics::ReferenceError => self.reference_error_prototype().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Request => self.request_prototype().into(),
+            #[cfg(feature = "web-fetch")]
+            ProtoIntrinsics::Response => self.response_prototype().into(),
             ProtoIntrinsics::StringIterator => self.string_iterator_prototype().into(),
             #[cfg(feature = "regexp")]
             ProtoIntrinsics::RegExpStringIterator => {
@@ -610,6 +801,8 @@ impl Intrinsics {
             ProtoIntrinsics::TemporalPlainTime => self.temporal_plain_time_prototype().into(),
             ProtoIntrinsics::TypeError => self.type_error_prototype().into(),
             ProtoIntrinsics::URIError => self.uri_error_prototype().into(),
+            #[cfg(feature = "web-abort")]
+            ProtoIntrinsics::AbortController => self.abort_controller_prototype().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error_prototype().into(),
             ProtoIntrinsics::AsyncFunction => self.async_function_prototype().into(),
             ProtoIntrinsics::AsyncGenerator => self.async_generator_prototype().into(),
@@ -639,6 +832,10 @@ impl Intrinsics {
             ProtoIntrinsics::Int32Array => self.int32_array_prototype().into(),
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => self.int8_array_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::IntlDateTimeFormat => self.intl_date_time_format_prototype().into(),
+            #[cfg(feature = "intl")]
+            ProtoIntrinsics::IntlNumberFormat => self.intl_number_format_prototype().into(),
             ProtoIntrinsics::Iterator => self.iterator_prototype().into(),
             ProtoIntrinsics::Map => self.map_prototype().into(),
             ProtoIntrinsics::MapIterator => self.map_iterator_prototype().into(),
@@ -702,6 +899,32 @@ impl Intrinsics {
         index.get_backing_object(self.object_index_base)
     }
 
+    /// %AbortController.prototype%
+    #[cfg(feature = "web-abort")]
+    pub(crate) const fn abort_controller_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AbortControllerPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %AbortController%
+    #[cfg(feature = "web-abort")]
+    pub(crate) const fn abort_controller(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AbortController
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %AbortSignal.prototype%
+    #[cfg(feature = "web-abort")]
+    pub(crate) const fn abort_signal_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AbortSignalPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %AbortSignal%
+    #[cfg(feature = "web-abort")]
+    pub(crate) const fn abort_signal(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AbortSignal
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %AggregateError.prototype%
     pub(crate) const fn aggregate_error_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AggregateErrorPrototype.get_backing_object(self.object_index_base)
@@ -769,6 +992,12 @@ impl Intrinsics {
         IntrinsicObjectIndexes::ArrayIteratorPrototype.get_backing_object(self.object_index_base)
     }
 
+    /// %ArrayIteratorPrototype.next%
+    pub(crate) const fn array_iterator_prototype_next(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::ArrayIteratorPrototypeNext
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %AsyncFunction.prototype%
     pub(crate) const fn async_function_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AsyncFunctionPrototype.get_backing_object(self.object_index_base)
@@ -842,6 +1071,18 @@ impl Intrinsics {
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %Blob.prototype%
+    #[cfg(feature = "web-blob")]
+    pub(crate) const fn blob_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::BlobPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Blob%
+    #[cfg(feature = "web-blob")]
+    pub(crate) const fn blob(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Blob.get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Boolean.prototype%
     pub(crate) fn boolean_prototype(&self) -> PrimitiveObject<'static> {
         IntrinsicPrimitiveObjectIndexes::BooleanPrototype
@@ -942,6 +1183,49 @@ impl Intrinsics {
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %Event.prototype%
+    #[cfg(feature = "web-events")]
+    pub(crate) const fn event_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::EventPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Event%
+    #[cfg(feature = "web-events")]
+    pub(crate) const fn event(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Event.get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %EventTarget.prototype%
+    #[cfg(feature = "web-events")]
+    pub(crate) const fn event_target_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::EventTargetPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %EventTarget%
+    #[cfg(feature = "web-events")]
+    pub(crate) const fn event_target(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::EventTarget
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %fetch%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn fetch(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::Fetch.get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %File.prototype%
+    #[cfg(feature = "web-blob")]
+    pub(crate) const fn file_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::FilePrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %File%
+    #[cfg(feature = "web-blob")]
+    pub(crate) const fn file(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::File.get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %FinalizationRegistry.prototype%
     pub(crate) const fn finalization_registry_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::FinalizationRegistryPrototype
@@ -1025,6 +1309,25 @@ impl Intrinsics {
         IntrinsicObjectIndexes::GeneratorPrototype.get_backing_object(self.object_index_base)
     }
 
+    /// %Headers.prototype.entries%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn headers_prototype_entries(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::HeadersPrototypeEntries
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %Headers.prototype%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn headers_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::HeadersPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Headers%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn headers(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Headers.get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Int16Array%
     #[cfg(feature = "array-buffer")]
     pub(crate) const fn int16_array_prototype(&self) -> OrdinaryObject<'static> {
@@ -1061,6 +1364,39 @@ impl Intrinsics {
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %Intl%
+    #[cfg(feature = "intl")]
+    pub(crate) const fn intl(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IntlObject.get_backing_object(self.object_index_base)
+    }
+
+    /// %Intl.DateTimeFormat.prototype%
+    #[cfg(feature = "intl")]
+    pub(crate) const fn intl_date_time_format_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IntlDateTimeFormatPrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    /// %Intl.DateTimeFormat%
+    #[cfg(feature = "intl")]
+    pub(crate) const fn intl_date_time_format(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::IntlDateTimeFormat
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %Intl.NumberFormat.prototype%
+    #[cfg(feature = "intl")]
+    pub(crate) const fn intl_number_format_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IntlNumberFormatPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Intl.NumberFormat%
+    #[cfg(feature = "intl")]
+    pub(crate) const fn intl_number_format(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::IntlNumberFormat
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %isFinite%
     pub(crate) const fn is_finite(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::IsFinite.get_builtin_function(self.builtin_function_index_base)
@@ -1108,6 +1444,12 @@ impl Intrinsics {
         IntrinsicObjectIndexes::MapIteratorPrototype.get_backing_object(self.object_index_base)
     }
 
+    /// %MapIteratorPrototype.next%
+    pub(crate) const fn map_iterator_prototype_next(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::MapIteratorPrototypeNext
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Math%
     #[cfg(feature = "math")]
     pub(crate) const fn math(&self) -> OrdinaryObject<'static> {
@@ -1178,6 +1520,12 @@ impl Intrinsics {
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %Object.prototype.valueOf%
+    pub(crate) const fn object_prototype_value_of(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::ObjectPrototypeValueOf
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Object.prototype%
     pub(crate) const fn object_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::ObjectPrototype.get_backing_object(self.object_index_base)
@@ -1203,6 +1551,12 @@ impl Intrinsics {
         IntrinsicFunctionIndexes::ParseInt.get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %Promise.prototype.then%
+    pub(crate) const fn promise_prototype_then(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::PromisePrototypeThen
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Promise.prototype%
     pub(crate) const fn promise_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::PromisePrototype.get_backing_object(self.object_index_base)
@@ -1218,6 +1572,49 @@ impl Intrinsics {
         IntrinsicConstructorIndexes::Proxy.get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %ReadableStream.prototype%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::ReadableStreamPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %ReadableStream%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::ReadableStream
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %ReadableStreamDefaultController.prototype%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream_default_controller_prototype(
+        &self,
+    ) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::ReadableStreamDefaultControllerPrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    /// %ReadableStreamDefaultController%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream_default_controller(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::ReadableStreamDefaultController
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %ReadableStreamDefaultReader.prototype%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream_default_reader_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::ReadableStreamDefaultReaderPrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    /// %ReadableStreamDefaultReader%
+    #[cfg(feature = "web-streams")]
+    pub(crate) const fn readable_stream_default_reader(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::ReadableStreamDefaultReader
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %RangeError.prototype%
     pub(crate) const fn range_error_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::RangeErrorPrototype.get_backing_object(self.object_index_base)
@@ -1271,6 +1668,30 @@ impl Intrinsics {
             .get_backing_object(self.object_index_base)
     }
 
+    /// %Request.prototype%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn request_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::RequestPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Request%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn request(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Request.get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %Response.prototype%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn response_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::ResponsePrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %Response%
+    #[cfg(feature = "web-fetch")]
+    pub(crate) const fn response(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::Response.get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Set.prototype.values%
     #[cfg(feature = "set")]
     pub(crate) const fn set_prototype_values(&self) -> BuiltinFunction<'static> {
@@ -1509,6 +1930,14 @@ impl Intrinsics {
 
 impl HeapMarkAndSweep for Intrinsics {
     fn mark_values(&self, queues: &mut WorkQueues) {
+        #[cfg(feature = "web-abort")]
+        self.abort_controller_prototype().mark_values(queues);
+        #[cfg(feature = "web-abort")]
+        self.abort_controller().mark_values(queues);
+        #[cfg(feature = "web-abort")]
+        self.abort_signal_prototype().mark_values(queues);
+        #[cfg(feature = "web-abort")]
+        self.abort_signal().mark_values(queues);
         self.aggregate_error_prototype().mark_values(queues);
         self.aggregate_error().mark_values(queues);
         self.array_prototype_sort().mark_values(queues);
@@ -1540,6 +1969,10 @@ impl HeapMarkAndSweep for Intrinsics {
         self.big_uint64_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.big_uint64_array_prototype().mark_values(queues);
+        #[cfg(feature = "web-blob")]
+        self.blob_prototype().mark_values(queues);
+        #[cfg(feature = "web-blob")]
+        self.blob().mark_values(queues);
         self.boolean_prototype().mark_values(queues);
         self.boolean().mark_values(queues);
         #[cfg(feature = "array-buffer")]
@@ -1563,6 +1996,20 @@ impl HeapMarkAndSweep for Intrinsics {
         self.eval().mark_values(queues);
         self.eval_error_prototype().mark_values(queues);
         self.eval_error().mark_values(queues);
+        #[cfg(feature = "web-events")]
+        self.event_prototype().mark_values(queues);
+        #[cfg(feature = "web-events")]
+        self.event().mark_values(queues);
+        #[cfg(feature = "web-events")]
+        self.event_target_prototype().mark_values(queues);
+        #[cfg(feature = "web-events")]
+        self.event_target().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.fetch().mark_values(queues);
+        #[cfg(feature = "web-blob")]
+        self.file_prototype().mark_values(queues);
+        #[cfg(feature = "web-blob")]
+        self.file().mark_values(queues);
         self.finalization_registry_prototype().mark_values(queues);
         self.finalization_registry().mark_values(queues);
         #[cfg(feature = "array-buffer")]
@@ -1580,6 +2027,12 @@ impl HeapMarkAndSweep for Intrinsics {
         self.generator_function_prototype().mark_values(queues);
         self.generator_function().mark_values(queues);
         self.generator_prototype().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.headers_prototype_entries().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.headers_prototype().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.headers().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.int16_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
@@ -1592,6 +2045,16 @@ impl HeapMarkAndSweep for Intrinsics {
         self.int8_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.int8_array_prototype().mark_values(queues);
+        #[cfg(feature = "intl")]
+        self.intl().mark_values(queues);
+        #[cfg(feature = "intl")]
+        self.intl_date_time_format_prototype().mark_values(queues);
+        #[cfg(feature = "intl")]
+        self.intl_date_time_format().mark_values(queues);
+        #[cfg(feature = "intl")]
+        self.intl_number_format_prototype().mark_values(queues);
+        #[cfg(feature = "intl")]
+        self.intl_number_format().mark_values(queues);
         self.is_finite().mark_values(queues);
         self.is_nan().mark_values(queues);
         self.iterator_prototype().mark_values(queues);
@@ -1606,13 +2069,30 @@ impl HeapMarkAndSweep for Intrinsics {
         self.number_prototype().mark_values(queues);
         self.number().mark_values(queues);
         self.object_prototype_to_string().mark_values(queues);
+        self.object_prototype_value_of().mark_values(queues);
         self.object_prototype().mark_values(queues);
         self.object().mark_values(queues);
         self.parse_float().mark_values(queues);
         self.parse_int().mark_values(queues);
+        self.promise_prototype_then().mark_values(queues);
         self.promise_prototype().mark_values(queues);
         self.promise().mark_values(queues);
         self.proxy().mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream_prototype().mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream().mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream_default_controller_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream_default_controller()
+            .mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream_default_reader_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "web-streams")]
+        self.readable_stream_default_reader().mark_values(queues);
         self.range_error_prototype().mark_values(queues);
         self.range_error().mark_values(queues);
         self.reference_error_prototype().mark_values(queues);
@@ -1626,6 +2106,14 @@ impl HeapMarkAndSweep for Intrinsics {
         self.reg_exp().mark_values(queues);
         #[cfg(feature = "regexp")]
         self.reg_exp_string_iterator_prototype().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.request_prototype().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.request().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.response_prototype().mark_values(queues);
+        #[cfg(feature = "web-fetch")]
+        self.response().mark_values(queues);
         #[cfg(feature = "set")]
         self.set_prototype_values().mark_values(queues);
         #[cfg(feature = "set")]
@@ -67,14 +67,14 @@ use crate::{
         BUILTIN_FUNCTION_DISCRIMINANT, BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction,
-        EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT, EmbedderObject, Error,
-        FINALIZATION_REGISTRY_DISCRIMINANT, FinalizationRegistry, GENERATOR_DISCRIMINANT,
-        Generator, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map,
-        MapIterator, Module, OBJECT_DISCRIMINANT, Object, OrdinaryObject,
-        PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PrimitiveObject,
-        Promise, Proxy, STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, StringIterator, Symbol,
-        Value,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction,
+        ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction, EMBEDDER_OBJECT_DISCRIMINANT,
+        ERROR_DISCRIMINANT, EmbedderObject, Error, FINALIZATION_REGISTRY_DISCRIMINANT,
+        FinalizationRegistry, GENERATOR_DISCRIMINANT, Generator, MAP_DISCRIMINANT,
+        MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map, MapIterator, Module,
+        OBJECT_DISCRIMINANT, Object, OrdinaryObject, PRIMITIVE_OBJECT_DISCRIMINANT,
+        PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PrimitiveObject, Promise, Proxy,
+        STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, StringIterator, Symbol, Value,
     },
     engine::{Bindable, HeapRootData, HeapRootRef, Rootable, bindable_handle},
     heap::{
@@ -97,7 +97,7 @@ pub(crate) enum WeakKey<'a> {
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
     Arguments(UnmappedArguments<'a>) = ARGUMENTS_DISCRIMINANT,
     Array(Array<'a>) = ARRAY_DISCRIMINANT,
@@ -216,7 +216,7 @@ impl<'a> From<WeakKey<'a>> for Value<'a> {
             WeakKey::BuiltinConstructorFunction(d) => Self::BuiltinConstructorFunction(d),
             WeakKey::BuiltinPromiseResolvingFunction(d) => Self::BuiltinPromiseResolvingFunction(d),
             WeakKey::BuiltinPromiseFinallyFunction(d) => Self::BuiltinPromiseFinallyFunction(d),
-            WeakKey::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            WeakKey::BuiltinProxyRevokerFunction(d) => Self::BuiltinProxyRevokerFunction(d),
             WeakKey::PrimitiveObject(d) => Self::PrimitiveObject(d),
             WeakKey::Arguments(d) => Self::Arguments(d),
             WeakKey::Array(d) => Self::Array(d),
@@ -328,7 +328,7 @@ impl<'a> From<Object<'a>> for WeakKey<'a> {
             Object::BuiltinConstructorFunction(d) => Self::BuiltinConstructorFunction(d),
             Object::BuiltinPromiseResolvingFunction(d) => Self::BuiltinPromiseResolvingFunction(d),
             Object::BuiltinPromiseFinallyFunction(d) => Self::BuiltinPromiseFinallyFunction(d),
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(d) => Self::BuiltinProxyRevokerFunction(d),
             Object::PrimitiveObject(d) => Self::PrimitiveObject(d),
             Object::Arguments(d) => Self::Arguments(d),
             Object::Array(d) => Self::Array(d),
@@ -444,7 +444,7 @@ impl<'a> TryFrom<WeakKey<'a>> for Object<'a> {
                 Ok(Self::BuiltinPromiseResolvingFunction(d))
             }
             WeakKey::BuiltinPromiseFinallyFunction(d) => Ok(Self::BuiltinPromiseFinallyFunction(d)),
-            WeakKey::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            WeakKey::BuiltinProxyRevokerFunction(d) => Ok(Self::BuiltinProxyRevokerFunction(d)),
             WeakKey::PrimitiveObject(d) => Ok(Self::PrimitiveObject(d)),
             WeakKey::Arguments(d) => Ok(Self::Arguments(d)),
             WeakKey::Array(d) => Ok(Self::Array(d)),
@@ -607,7 +607,7 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::BuiltinConstructorFunction(d) => d.mark_values(queues),
             Self::BuiltinPromiseResolvingFunction(d) => d.mark_values(queues),
             Self::BuiltinPromiseFinallyFunction(d) => d.mark_values(queues),
-            Self::BuiltinProxyRevokerFunction => {}
+            Self::BuiltinProxyRevokerFunction(d) => d.mark_values(queues),
             Self::PrimitiveObject(d) => d.mark_values(queues),
             Self::Arguments(d) => d.mark_values(queues),
             Self::Array(d) => d.mark_values(queues),
@@ -717,7 +717,7 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::BuiltinConstructorFunction(d) => d.sweep_values(compactions),
             Self::BuiltinPromiseResolvingFunction(d) => d.sweep_values(compactions),
             Self::BuiltinPromiseFinallyFunction(d) => d.sweep_values(compactions),
-            Self::BuiltinProxyRevokerFunction => {}
+            Self::BuiltinProxyRevokerFunction(d) => d.sweep_values(compactions),
             Self::PrimitiveObject(d) => d.sweep_values(compactions),
             Self::Arguments(d) => d.sweep_values(compactions),
             Self::Array(d) => d.sweep_values(compactions),
@@ -841,7 +841,9 @@ impl HeapSweepWeakReference for WeakKey<'static> {
             Self::BuiltinPromiseFinallyFunction(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::BuiltinPromiseFinallyFunction),
-            Self::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
+            Self::BuiltinProxyRevokerFunction(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::BuiltinProxyRevokerFunction),
             Self::PrimitiveObject(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::PrimitiveObject),
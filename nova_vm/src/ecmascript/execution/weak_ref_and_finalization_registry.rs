@@ -6,8 +6,8 @@
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, ExceptionType, FinalizationRegistry, JsError, JsResult, Object,
-        Value, WeakKey, call_function, key_for_symbol,
+        Agent, ArgumentsList, ExceptionType, FinalizationRegistry, Function, JsError, JsResult,
+        Object, Value, WeakKey, call_function, key_for_symbol,
     },
     engine::{Bindable, GcScope, Global, NoGcScope, Scopable, ScopableCollection},
 };
@@ -54,16 +54,15 @@ impl FinalizationRegistryCleanupJob {
             finalization_registry: Global::new(agent, finalization_registry.unbind()),
         }
     }
-    pub(crate) fn run<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) {
+    pub(crate) fn run<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> JsResult<'gc, ()> {
         let finalization_registry = self.finalization_registry.take(agent).bind(gc.nogc());
         // 1. Let cleanupResult be
         //    Completion(CleanupFinalizationRegistry(finalizationRegistry)).
-        let cleanup_result =
-            cleanup_finalization_registry(agent, finalization_registry.unbind(), gc);
-        // 2. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
-        if cleanup_result.is_err() {
-            let _ = cleanup_result;
-        }
+        // 2. If cleanupResult is an abrupt completion, perform any host-defined
+        //    steps for reporting the error.
+        // NOTE: The error is returned from the Job and thus reported to the
+        // host by whoever runs the Job.
+        cleanup_finalization_registry(agent, finalization_registry.unbind(), None, gc)
         // 3. Return unused.
     }
 }
@@ -73,88 +72,81 @@ impl FinalizationRegistryCleanupJob {
 /// The abstract operation CleanupFinalizationRegistry takes argument
 /// finalizationRegistry (a FinalizationRegistry) and returns either a normal
 /// completion containing unused or a throw completion.
+///
+/// > NOTE: The optional `callback` argument is defined by the
+/// > [cleanupSome proposal](https://github.com/tc39/proposal-cleanup-some).
+/// > If it is `None`, the FinalizationRegistry's \[\[CleanupCallback]] is
+/// > used.
 pub(crate) fn cleanup_finalization_registry<'gc>(
     agent: &mut Agent,
     finalization_registry: FinalizationRegistry,
+    callback: Option<Function>,
     mut gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, ()> {
     let finalization_registry = finalization_registry.bind(gc.nogc());
+    let callback = callback.bind(gc.nogc());
     // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]]
     //    internal slots.
     // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
-    let (callback, mut queue) = finalization_registry.get_cleanup_queue(agent);
-    if queue.is_empty() {
-        return Ok(());
-    }
-    let value = queue.pop().unwrap();
+    let (cleanup_callback, queue) = finalization_registry.get_cleanup_queue(agent);
+    let callback = callback.unwrap_or(cleanup_callback);
     // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that
     //    cell.[[WeakRefTarget]] is empty, an implementation may perform the
     //    following steps:
     //         a. Choose any such cell.
     //         b. Remove cell from finalizationRegistry.[[Cells]].
     //         c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
-    if queue.len() == 1 {
-        // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
-        let _ = call_function(
-            agent,
-            callback.unbind(),
-            Value::Undefined,
-            Some(ArgumentsList::from_mut_value(&mut value.unbind())),
-            gc,
-        )?;
-    } else {
-        let scoped_callback = callback.scope(agent, gc.nogc());
-        let finalization_registry = finalization_registry.scope(agent, gc.nogc());
-        let queue = queue.scope(agent, gc.nogc());
+    match queue.as_slice() {
+        [] => return Ok(()),
+        &[value] => {
+            // 2. Return ? Call(jobCallback.[[Callback]], V, argumentsList).
+            call_function(
+                agent,
+                callback.unbind(),
+                Value::Undefined,
+                Some(ArgumentsList::from_mut_value(&mut value.unbind())),
+                gc,
+            )?;
+            return Ok(());
+        }
+        _ => {}
+    }
+    let scoped_callback = callback.scope(agent, gc.nogc());
+    let finalization_registry = finalization_registry.scope(agent, gc.nogc());
+    let queue = queue.scope(agent, gc.nogc());
+    let mut err_and_i = None;
+    for (i, value) in queue.iter(agent).enumerate() {
+        let value = value.get(gc.nogc());
         let result = call_function(
             agent,
-            callback.unbind(),
+            scoped_callback.get(agent),
             Value::Undefined,
             Some(ArgumentsList::from_mut_value(&mut value.unbind())),
             gc.reborrow(),
         )
         .unbind()
         .bind(gc.nogc());
-        let mut err_and_i = None;
         if let Err(err) = result {
-            err_and_i = Some((err.unbind(), 0));
-        } else {
-            for (i, value) in queue.iter(agent).enumerate() {
-                let value = value.get(gc.nogc());
-                let result = call_function(
-                    agent,
-                    scoped_callback.get(agent),
-                    Value::Undefined,
-                    Some(ArgumentsList::from_mut_value(&mut value.unbind())),
-                    gc.reborrow(),
-                )
-                .unbind()
-                .bind(gc.nogc());
-                if let Err(err) = result {
-                    err_and_i = Some((err.unbind(), i));
-                    break;
-                }
-            }
-        }
-        // If an error was thrown by a cleanup callback, we interrupt any
-        // further cleanup and add any leftover cleanups back into the
-        // FinalizationRegistry. This will re-request cleanup of the registry
-        // if necessary.
-        if let Some((err, i)) = err_and_i {
-            let err = err.unbind();
-            let gc = gc.into_nogc();
-            let err = err.bind(gc);
-            let mut queue = queue.take(agent).bind(gc);
-            // Drain all elements that were found so far.
-            queue.drain(0..i);
-            let finalization_registry = unsafe { finalization_registry.take(agent) }.bind(gc);
-            let _ = unsafe { scoped_callback.take(agent) };
-            if !queue.is_empty() {
-                finalization_registry.add_cleanups(agent, queue);
-            }
-            return Err(err);
+            err_and_i = Some((err.unbind(), i));
+            break;
         }
     }
+    // If an error was thrown by a cleanup callback, we interrupt any further
+    // cleanup and add any leftover cleanups back into the
+    // FinalizationRegistry. This will re-request cleanup of the registry if
+    // necessary.
+    if let Some((err, i)) = err_and_i {
+        let gc = gc.into_nogc();
+        let err = err.bind(gc);
+        let mut queue = queue.take(agent).bind(gc);
+        // Drain all elements that were cleaned up so far, including the one
+        // whose callback threw.
+        queue.drain(..=i);
+        let finalization_registry = unsafe { finalization_registry.take(agent) }.bind(gc);
+        let _ = unsafe { scoped_callback.take(agent) };
+        finalization_registry.add_cleanups(agent, queue);
+        return Err(err);
+    }
     // 4. Return unused.
     Ok(())
 }
@@ -9,13 +9,22 @@ use crate::{
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
+mod compiled_source;
 mod module;
 mod script;
 mod source_code;
+mod source_diagnostics;
+mod source_kind;
+mod source_registry;
 
+pub use compiled_source::*;
 pub use module::*;
 pub use script::*;
 pub(crate) use source_code::*;
+pub use source_diagnostics::*;
+pub use source_kind::*;
+pub use source_registry::LoadedSource;
+pub(crate) use source_registry::SourceRegistry;
 
 #[derive(Clone, Copy)]
 pub(crate) enum ScriptOrModule<'a> {
@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Compilation of ECMAScript source text without an Agent.
+//!
+//! Parsing large source text can take a significant amount of time. Parsing
+//! and checking for early errors do not need access to an Agent, so they can
+//! be done on a background thread. The compiled result is then sent to the
+//! Agent's thread and instantiated as a Script or a Module.
+//!
+//! Bytecode is generated when the Script or Module is first evaluated, as it
+//! refers to values allocated on the Agent's heap.
+
+use std::sync::Arc;
+
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{
+    ecmascript::{
+        Agent, HostDefined, ParsedSource, Realm, Script, SourceCode, SourceCodeType,
+        SourceTextModule, create_script, create_source_text_module,
+    },
+    engine::NoGcScope,
+};
+
+/// ECMAScript source text compiled as a Script, ready to be instantiated in
+/// an Agent.
+///
+/// A CompiledScript can be sent to other threads.
+#[derive(Debug)]
+pub struct CompiledScript {
+    parsed: ParsedSource,
+    /// The source text that was parsed. The parsed data refers to it, so it
+    /// must be dropped after `parsed`.
+    source_text: Arc<str>,
+}
+
+impl CompiledScript {
+    /// Parse the source text as a Script and check it for early errors. The
+    /// `strict` boolean parses the source text in strict mode, as in
+    /// [`parse_script`].
+    ///
+    /// This does not require an Agent, and can be called on any thread.
+    ///
+    /// [`parse_script`]: crate::ecmascript::parse_script
+    pub fn compile(
+        source_text: impl Into<Arc<str>>,
+        strict: bool,
+    ) -> Result<Self, Vec<OxcDiagnostic>> {
+        let source_text = source_text.into();
+        // SAFETY: The CompiledScript keeps the source text alive.
+        let parsed = unsafe {
+            ParsedSource::parse(
+                &source_text,
+                SourceCodeType::Script { strict },
+                cfg!(feature = "typescript"),
+            )
+        }?;
+        Ok(Self {
+            parsed,
+            source_text,
+        })
+    }
+
+    /// Get the source text of the Script.
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    /// ### [16.1.5 ParseScript ( sourceText, realm, hostDefined )](https://tc39.es/ecma262/#sec-parse-script)
+    ///
+    /// Create a Script Record in the given Realm from the compiled Script.
+    /// This is equivalent to calling [`parse_script`] with the source text,
+    /// but does not parse it again.
+    ///
+    /// The source text is not copied.
+    ///
+    /// [`parse_script`]: crate::ecmascript::parse_script
+    pub fn instantiate<'a>(
+        self,
+        agent: &mut Agent,
+        realm: Realm,
+        host_defined: Option<HostDefined>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Script<'a> {
+        let Self {
+            parsed,
+            source_text,
+        } = self;
+        // SAFETY: The source text is the text that was parsed.
+        let parse_result = unsafe { SourceCode::from_parsed_text(agent, source_text, parsed, gc) };
+        create_script(agent, parse_result, realm, host_defined, gc)
+    }
+}
+
+/// ECMAScript source text compiled as a Module, ready to be instantiated in
+/// an Agent.
+///
+/// A CompiledModule can be sent to other threads.
+#[derive(Debug)]
+pub struct CompiledModule {
+    parsed: ParsedSource,
+    /// The source text that was parsed. The parsed data refers to it, so it
+    /// must be dropped after `parsed`.
+    source_text: Arc<str>,
+}
+
+impl CompiledModule {
+    /// Parse the source text as a Module and check it for early errors.
+    ///
+    /// This does not require an Agent, and can be called on any thread.
+    pub fn compile(source_text: impl Into<Arc<str>>) -> Result<Self, Vec<OxcDiagnostic>> {
+        let source_text = source_text.into();
+        // SAFETY: The CompiledModule keeps the source text alive.
+        let parsed = unsafe {
+            ParsedSource::parse(
+                &source_text,
+                SourceCodeType::Module,
+                cfg!(feature = "typescript"),
+            )
+        }?;
+        Ok(Self {
+            parsed,
+            source_text,
+        })
+    }
+
+    /// Get the source text of the Module.
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    /// ### [16.2.1.7.1 ParseModule ( sourceText, realm, hostDefined )](https://tc39.es/ecma262/#sec-parsemodule)
+    ///
+    /// Create a Source Text Module Record in the given Realm from the
+    /// compiled Module. This is equivalent to calling [`parse_module`] with
+    /// the source text, but does not parse it again.
+    ///
+    /// The source text is not copied.
+    ///
+    /// [`parse_module`]: crate::ecmascript::parse_module
+    pub fn instantiate<'a>(
+        self,
+        agent: &mut Agent,
+        realm: Realm,
+        host_defined: Option<HostDefined>,
+        gc: NoGcScope<'a, '_>,
+    ) -> SourceTextModule<'a> {
+        let Self {
+            parsed,
+            source_text,
+        } = self;
+        // SAFETY: The source text is the text that was parsed.
+        let parse_result = unsafe { SourceCode::from_parsed_text(agent, source_text, parsed, gc) };
+        create_source_text_module(agent, parse_result, realm, host_defined, gc)
+    }
+}
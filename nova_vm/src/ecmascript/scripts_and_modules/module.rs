@@ -16,8 +16,144 @@ use crate::{
         inner_promise_then, to_string, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, typeof_operator},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
+/// An `import()` call whose module graph may finish loading synchronously.
+///
+/// See [`AgentOptions::synchronous_dynamic_import`].
+///
+/// [`AgentOptions::synchronous_dynamic_import`]: crate::ecmascript::AgentOptions::synchronous_dynamic_import
+#[derive(Debug)]
+pub(crate) struct SynchronousDynamicImport {
+    /// The promise returned by the `import()` call.
+    promise: Promise<'static>,
+    /// The imported module, once its module graph has loaded.
+    module: Option<AbstractModule<'static>>,
+}
+
+/// An `import()` call whose module is being loaded asynchronously by the
+/// host.
+///
+/// See [`Agent::defer_dynamic_import`].
+#[derive(Debug)]
+#[must_use = "a deferred dynamic import must be finished"]
+pub struct PendingDynamicImport(u32);
+
+/// The data needed to finish a deferred `import()` call.
+#[derive(Debug)]
+pub(crate) struct PendingDynamicImportRecord {
+    referrer: Referrer<'static>,
+    module_request: ModuleRequest<'static>,
+    /// The promise returned by the `import()` call.
+    promise: Promise<'static>,
+}
+
+impl Agent {
+    /// Defer finishing the loading of a module requested by an `import()`
+    /// call.
+    ///
+    /// This can be called in [`HostHooks::load_imported_module`] instead of
+    /// [`finish_loading_imported_module`] when the `payload` belongs to an
+    /// `import()` call. The returned handle must later be passed to
+    /// [`Agent::finish_dynamic_import`] to settle the promise returned by the
+    /// `import()` call; the referrer and module request are kept alive until
+    /// then.
+    ///
+    /// Panics if `payload` is not the payload of an `import()` call; see
+    /// [`GraphLoadingStateRecord::is_dynamic_import`].
+    ///
+    /// [`HostHooks::load_imported_module`]: crate::ecmascript::HostHooks::load_imported_module
+    pub fn defer_dynamic_import(
+        &mut self,
+        referrer: Referrer,
+        module_request: ModuleRequest,
+        payload: &GraphLoadingStateRecord,
+    ) -> PendingDynamicImport {
+        assert!(
+            payload.is_dynamic_import(),
+            "Only dynamic imports can be deferred"
+        );
+        let record = PendingDynamicImportRecord {
+            referrer: referrer.unbind(),
+            module_request: module_request.unbind(),
+            promise: payload.promise().unbind(),
+        };
+        let imports = &mut self.pending_dynamic_imports;
+        let index = if let Some(index) = imports.iter().position(Option::is_none) {
+            imports[index] = Some(record);
+            index
+        } else {
+            imports.push(Some(record));
+            imports.len() - 1
+        };
+        PendingDynamicImport(index as u32)
+    }
+
+    /// Finish loading a module requested by an `import()` call that was
+    /// deferred using [`Agent::defer_dynamic_import`].
+    ///
+    /// If `result` is an error, the promise returned by the `import()` call
+    /// is rejected with it. Otherwise the module's dependencies are loaded,
+    /// and the module is linked and evaluated in promise jobs; the promise is
+    /// resolved with the module's namespace object once evaluation finishes.
+    pub fn finish_dynamic_import<'a>(
+        &mut self,
+        pending: PendingDynamicImport,
+        result: JsResult<'a, AbstractModule<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) {
+        let index = pending.0 as usize;
+        let PendingDynamicImportRecord {
+            referrer,
+            module_request,
+            promise,
+        } = self.pending_dynamic_imports[index]
+            .take()
+            .expect("Dynamic import was already finished");
+        while self
+            .pending_dynamic_imports
+            .last()
+            .is_some_and(Option::is_none)
+        {
+            self.pending_dynamic_imports.pop();
+        }
+        let mut payload = GraphLoadingStateRecord::from_promise(promise.bind(gc));
+        finish_loading_imported_module(
+            self,
+            referrer.bind(gc),
+            module_request.bind(gc),
+            &mut payload,
+            result,
+            gc,
+        );
+    }
+}
+
+impl HeapMarkAndSweep for PendingDynamicImportRecord {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            referrer,
+            module_request,
+            promise,
+        } = self;
+        referrer.mark_values(queues);
+        module_request.mark_values(queues);
+        promise.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            referrer,
+            module_request,
+            promise,
+        } = self;
+        referrer.sweep_values(compactions);
+        module_request.sweep_values(compactions);
+        promise.sweep_values(compactions);
+    }
+}
+
 /// ### [13.3.10.2 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] )](https://tc39.es/ecma262/#sec-evaluate-import-call)
 ///
 /// The abstract operation EvaluateImportCall takes argument
@@ -27,11 +163,41 @@ use crate::{
 ///
 /// > NOTE: This method performs steps 1, 2, and 7 onwards. Thus, the arguments
 /// > are already evaluated into Values (optional in the case of options).
+///
+/// If [`AgentOptions::synchronous_dynamic_import`] is set and the host loads
+/// the module graph synchronously, the module is also linked and evaluated
+/// before this method returns.
+///
+/// [`AgentOptions::synchronous_dynamic_import`]: crate::ecmascript::AgentOptions::synchronous_dynamic_import
 pub(crate) fn evaluate_import_call<'gc>(
     agent: &mut Agent,
     specifier: Value,
     options: Option<Value>,
     mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let promise = start_dynamic_import(agent, specifier, options, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    let Some(module) = agent
+        .synchronous_dynamic_import
+        .take()
+        .and_then(|import| import.module)
+    else {
+        return promise.unbind().bind(gc.into_nogc());
+    };
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    // Note: This performs the linkAndEvaluate closure of ContinueDynamicImport
+    // immediately instead of in a promise job.
+    link_and_evaluate(agent, promise.unbind(), module, gc.reborrow());
+    // SAFETY: not shared.
+    unsafe { scoped_promise.take(agent) }.bind(gc.into_nogc())
+}
+
+fn start_dynamic_import<'gc>(
+    agent: &mut Agent,
+    specifier: Value,
+    options: Option<Value>,
+    mut gc: GcScope<'gc, '_>,
 ) -> Promise<'gc> {
     let specifier = specifier.bind(gc.nogc());
     let mut options = options.bind(gc.nogc());
@@ -142,7 +308,7 @@ pub(crate) fn evaluate_import_call<'gc>(
                 // 1. Let key be ! Get(entry, "0").
                 let key = entry[0].unwrap();
                 // 2. Let value be ! Get(entry, "1").
-                let value = entry[0].unwrap();
+                let value = entry[1].unwrap();
                 // 3. If key is a String, then
                 if let Ok(key) = String::try_from(key) {
                     // a. If value is not a String, then
@@ -202,9 +368,13 @@ pub(crate) fn evaluate_import_call<'gc>(
     // 13. Perform HostLoadImportedModule(referrer, moduleRequest, empty, promiseCapability).
     // Note: this is against the spec. We'll fix it in post.
     let mut payload = GraphLoadingStateRecord::from_promise(promise);
-    agent
-        .host_hooks
-        .load_imported_module(agent, referrer, module_request, None, &mut payload, gc);
+    if agent.options.synchronous_dynamic_import {
+        agent.synchronous_dynamic_import = Some(SynchronousDynamicImport {
+            promise: promise.unbind(),
+            module: None,
+        });
+    }
+    host_load_imported_module(agent, referrer, module_request, None, &mut payload, gc);
     // 14. Return promiseCapability.[[Promise]].
     promise
 }
@@ -299,6 +469,20 @@ pub(super) fn continue_dynamic_import<'a>(
     };
     // 3. Let loadPromise be module.LoadRequestedModules().
     let load_promise = module.load_requested_modules(agent, None, gc);
+    if let Some(import) = &agent.synchronous_dynamic_import
+        && import.promise == promise_capability.promise()
+        && let Some(load_result) = load_promise.try_get_result(agent, gc)
+    {
+        // Note: The module graph loaded synchronously; EvaluateImportCall
+        // links and evaluates the module before returning.
+        match load_result {
+            Ok(_) => {
+                agent.synchronous_dynamic_import.as_mut().unwrap().module = Some(module.unbind());
+            }
+            Err(err) => promise_capability.reject(agent, err.value(), gc),
+        }
+        return;
+    }
     // 4. Let rejectedClosure be a new Abstract Closure with parameters
     //    (reason) that captures promiseCapability and performs the following
     //    steps when called:
@@ -6,22 +6,30 @@
 
 mod abstract_module_records;
 mod cyclic_module_records;
+mod link_diagnostics;
 mod source_text_module_records;
+mod synthetic_module_records;
 
 pub use abstract_module_records::*;
 pub use cyclic_module_records::*;
+pub use link_diagnostics::*;
 pub use source_text_module_records::*;
+pub use synthetic_module_records::*;
 
 use super::continue_dynamic_import;
 use ahash::AHasher;
 use hashbrown::{HashTable, hash_table::Entry};
 use oxc_ast::ast;
-use std::hash::{Hash, Hasher};
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 use crate::{
     ecmascript::{
-        Agent, HostDefined, JsResult, Module, Realm, Script, ScriptOrModule,
-        module_namespace_create, types::String,
+        Agent, ExceptionType, HostDefined, JsResult, LoadedSource, Module, Realm, Script,
+        ScriptOrModule, module_namespace_create, types::String,
     },
     engine::{Bindable, HeapRootData, HeapRootRef, NoGcScope, Rootable, bindable_handle},
     heap::{
@@ -53,6 +61,9 @@ pub(crate) struct ModuleRequestRecord<'a> {
     attributes: Option<Box<[ImportAttributeRecord<'a>]>>,
     /// Precomputed hash of the ModuleRequest specifier and attributes.
     hash: u64,
+    /// The type of module chosen by the host for this request, if the
+    /// request's attributes have been validated.
+    module_type: Option<ModuleType>,
 }
 
 impl<'a> ModuleRequestRecord<'a> {
@@ -132,6 +143,7 @@ impl<'r> ModuleRequest<'r> {
             specifier,
             attributes,
             hash,
+            module_type: None,
         });
         Self::from_index_u32(index)
     }
@@ -157,6 +169,7 @@ impl<'r> ModuleRequest<'r> {
                 specifier,
                 attributes: Some(attributes),
                 hash,
+                module_type: None,
             }
             .unbind(),
         );
@@ -172,6 +185,33 @@ impl<'r> ModuleRequest<'r> {
     pub fn attributes(self, agent: &Agent) -> &[ImportAttributeRecord<'r>] {
         self.get(agent).attributes()
     }
+
+    /// Get the value of the import attribute with the given key, if present.
+    pub fn attribute(self, agent: &Agent, key: &str) -> Option<String<'r>> {
+        self.attributes(agent)
+            .iter()
+            .find(|attribute| attribute.key.as_str_(agent) == Some(key))
+            .map(|attribute| attribute.value)
+    }
+
+    /// Get the type of module chosen for this request by
+    /// [`HostHooks::validate_import_attributes`].
+    ///
+    /// Requests are validated before they are passed to
+    /// [`HostHooks::load_imported_module`], so this is always available to
+    /// the host when loading a module.
+    ///
+    /// ## Panics
+    ///
+    /// If the request's import attributes have not yet been validated.
+    ///
+    /// [`HostHooks::validate_import_attributes`]: crate::ecmascript::HostHooks::validate_import_attributes
+    /// [`HostHooks::load_imported_module`]: crate::ecmascript::HostHooks::load_imported_module
+    pub fn module_type(self, agent: &Agent) -> ModuleType {
+        self.get(agent)
+            .module_type
+            .expect("Import attributes have not been validated")
+    }
 }
 
 impl HeapIndexHandle for ModuleRequest<'_> {
@@ -228,6 +268,71 @@ pub struct ImportAttributeRecord<'a> {
 }
 bindable_handle!(ImportAttributeRecord);
 
+impl<'a> ImportAttributeRecord<'a> {
+    /// Get the attribute's \[\[Key]] string.
+    pub fn key(&self) -> String<'a> {
+        self.key
+    }
+
+    /// Get the attribute's \[\[Value]] string.
+    pub fn value(&self) -> String<'a> {
+        self.value
+    }
+}
+
+/// The type of module requested by a ModuleRequest, as chosen by
+/// [`HostHooks::validate_import_attributes`].
+///
+/// [`HostHooks::validate_import_attributes`]: crate::ecmascript::HostHooks::validate_import_attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    /// A JavaScript module, requested without a `type` attribute.
+    JavaScript,
+    /// A JSON module, requested with `type: "json"`.
+    Json,
+    /// A host-defined module type, such as CSS modules.
+    HostDefined(&'static str),
+}
+
+/// Error returned by [`HostHooks::validate_import_attributes`] when a module
+/// request cannot be loaded with its import attributes.
+///
+/// The error is thrown as a TypeError when loading the module graph, before
+/// it is linked, or rejects the `import()` call that made the request.
+///
+/// [`HostHooks::validate_import_attributes`]: crate::ecmascript::HostHooks::validate_import_attributes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportAttributesError {
+    /// The `type` attribute names a module type that the host does not
+    /// support.
+    UnsupportedType(std::string::String),
+    /// An import attribute has a value that the host does not accept.
+    InvalidValue {
+        /// The attribute key.
+        key: std::string::String,
+        /// The rejected attribute value.
+        value: std::string::String,
+    },
+    /// A host-defined error message.
+    Other(std::string::String),
+}
+
+impl core::fmt::Display for ImportAttributesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedType(module_type) => {
+                write!(f, "Unsupported module type '{module_type}'")
+            }
+            Self::InvalidValue { key, value } => {
+                write!(f, "Invalid value '{value}' for import attribute '{key}'")
+            }
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for ImportAttributesError {}
+
 /// ### \[\[LoadedModules]]
 ///
 /// a List of LoadedModuleRequest Records
@@ -387,6 +492,14 @@ impl Referrer<'_> {
             InnerReferrer::Realm(r) => r.host_defined(agent),
         }
     }
+
+    /// Get the host defined data of this referrer downcast to `T`.
+    ///
+    /// Returns None if the referrer has no host defined data or it holds a
+    /// value of another type.
+    pub fn host_defined_as<T: 'static>(self, agent: &Agent) -> Option<Rc<T>> {
+        self.host_defined(agent)?.downcast().ok()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -491,6 +604,120 @@ impl Referrer<'_> {
     }
 }
 
+/// ### [16.2.1.10 HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )](https://tc39.es/ecma262/#sec-HostLoadImportedModule)
+///
+/// The host-defined abstract operation HostLoadImportedModule takes arguments
+/// referrer (a Script Record, a Cyclic Module Record, or a Realm Record),
+/// moduleRequest (a ModuleRequest Record), hostDefined (anything), and payload
+/// (a GraphLoadingState Record or a PromiseCapability Record) and returns
+/// unused.
+///
+/// The request's import attributes are first validated with
+/// [`HostHooks::validate_import_attributes`], and the specifier is resolved
+/// with [`HostHooks::resolve_module_specifier`]. For JavaScript module
+/// requests, native modules registered with
+/// [`Agent::register_module`] and modules loaded with [`Agent::load_module`]
+/// under the resolved key are loaded synchronously by the engine; all other
+/// requests are passed on to [`HostHooks::load_imported_module`].
+///
+/// [`Agent::register_module`]: crate::ecmascript::Agent::register_module
+/// [`Agent::load_module`]: crate::ecmascript::Agent::load_module
+/// [`HostHooks::validate_import_attributes`]: crate::ecmascript::HostHooks::validate_import_attributes
+/// [`HostHooks::resolve_module_specifier`]: crate::ecmascript::HostHooks::resolve_module_specifier
+/// [`HostHooks::load_imported_module`]: crate::ecmascript::HostHooks::load_imported_module
+pub(crate) fn host_load_imported_module<'a>(
+    agent: &mut Agent,
+    referrer: Referrer<'a>,
+    module_request: ModuleRequest<'a>,
+    host_defined: Option<HostDefined>,
+    payload: &mut GraphLoadingStateRecord<'a>,
+    gc: NoGcScope<'a, '_>,
+) {
+    let module_type = match agent
+        .host_hooks
+        .validate_import_attributes(agent, module_request)
+    {
+        Ok(module_type) => module_type,
+        Err(err) => {
+            let error = agent.throw_exception(ExceptionType::TypeError, err.to_string(), gc);
+            finish_loading_imported_module(
+                agent,
+                referrer,
+                module_request,
+                payload,
+                Err(error),
+                gc,
+            );
+            return;
+        }
+    };
+    agent.heap.module_request_records[module_request.get_index()].module_type = Some(module_type);
+    let specifier = module_request.specifier(agent);
+    let resolved = specifier.as_str_(agent).map(|specifier| {
+        match agent
+            .host_hooks
+            .resolve_module_specifier(agent, referrer, specifier)
+        {
+            Some(resolved) => Cow::Owned(resolved),
+            None => Cow::Borrowed(specifier),
+        }
+    });
+    let (Some(key), ModuleType::JavaScript) = (resolved.as_deref(), module_type) else {
+        // Note: Specifiers with lone surrogates cannot name registered
+        // modules, and registered modules are always JavaScript modules.
+        agent.host_hooks.load_imported_module(
+            agent,
+            referrer,
+            module_request,
+            host_defined,
+            payload,
+            gc,
+        );
+        return;
+    };
+    if let Some(module) = load_native_module(agent, referrer, key, gc) {
+        finish_loading_imported_module(
+            agent,
+            referrer,
+            module_request,
+            payload,
+            Ok(module.into()),
+            gc,
+        );
+    } else if let Some(module) = load_registered_module(agent, key, gc) {
+        finish_loading_imported_module(
+            agent,
+            referrer,
+            module_request,
+            payload,
+            Ok(module.into()),
+            gc,
+        );
+    } else {
+        agent.host_hooks.load_imported_module(
+            agent,
+            referrer,
+            module_request,
+            host_defined,
+            payload,
+            gc,
+        );
+    }
+}
+
+/// Returns the Module registered in the Agent's source registry under the
+/// given key, if any.
+fn load_registered_module<'a>(
+    agent: &Agent,
+    key: &str,
+    gc: NoGcScope<'a, '_>,
+) -> Option<SourceTextModule<'a>> {
+    match agent.get_loaded_source(key, gc)? {
+        LoadedSource::Module(module) => Some(module),
+        LoadedSource::Script(_) => None,
+    }
+}
+
 /// ### [16.2.1.11 FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )](https://tc39.es/ecma262/#sec-FinishLoadingImportedModule)
 ///
 /// The abstract operation FinishLoadingImportedModule takes arguments referrer
@@ -618,6 +845,24 @@ impl HeapMarkAndSweep for ModuleRequest<'static> {
     }
 }
 
+impl HeapMarkAndSweep for Referrer<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match &self.0 {
+            InnerReferrer::Script(s) => s.mark_values(queues),
+            InnerReferrer::SourceTextModule(m) => m.mark_values(queues),
+            InnerReferrer::Realm(r) => r.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match &mut self.0 {
+            InnerReferrer::Script(s) => s.sweep_values(compactions),
+            InnerReferrer::SourceTextModule(m) => m.sweep_values(compactions),
+            InnerReferrer::Realm(r) => r.sweep_values(compactions),
+        }
+    }
+}
+
 impl HeapMarkAndSweep for LoadedModules<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         for loaded_module_request in self.table.iter() {
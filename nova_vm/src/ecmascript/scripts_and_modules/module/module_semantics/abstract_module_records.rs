@@ -4,13 +4,17 @@
 
 //! ### [16.2.1.5 Abstract Module Records](https://tc39.es/ecma262/#sec-abstract-module-records)
 
+use std::rc::Rc;
+
 use crate::{
     ecmascript::{Agent, HostDefined, JsResult, Module, ModuleEnvironment, Promise, Realm, String},
     engine::{Bindable, GcScope, HeapRootData, HeapRootRef, NoGcScope, Rootable, bindable_handle},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-use super::source_text_module_records::SourceTextModule;
+use super::{
+    source_text_module_records::SourceTextModule, synthetic_module_records::SyntheticModule,
+};
 
 /// ### [16.2.1.5 Abstract Module Records](https://tc39.es/ecma262/#sec-abstract-module-records)
 #[derive(Debug)]
@@ -108,6 +112,7 @@ impl core::fmt::Debug for AbstractModule<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match &self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.fmt(f),
+            InnerAbstractModule::SyntheticModule(m) => m.fmt(f),
         }
     }
 }
@@ -117,8 +122,21 @@ impl<'m> AbstractModule<'m> {
     pub(super) fn as_source_text_module(self) -> Option<SourceTextModule<'m>> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => Some(m),
+            InnerAbstractModule::SyntheticModule(_) => None,
         }
     }
+
+    /// ### \[\[HostDefined]]
+    pub fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
+        AbstractModuleSlots::host_defined(self, agent)
+    }
+
+    /// Get the \[\[HostDefined]] field downcast to `T`.
+    ///
+    /// Returns None if the field is empty or holds a value of another type.
+    pub fn host_defined_as<T: 'static>(self, agent: &Agent) -> Option<Rc<T>> {
+        self.host_defined(agent)?.downcast().ok()
+    }
 }
 
 impl<'a> From<SourceTextModule<'a>> for AbstractModule<'a> {
@@ -127,6 +145,12 @@ impl<'a> From<SourceTextModule<'a>> for AbstractModule<'a> {
     }
 }
 
+impl<'a> From<SyntheticModule<'a>> for AbstractModule<'a> {
+    fn from(value: SyntheticModule<'a>) -> Self {
+        Self(InnerAbstractModule::SyntheticModule(value))
+    }
+}
+
 bindable_handle!(AbstractModule);
 
 impl Rootable for AbstractModule<'_> {
@@ -150,9 +174,9 @@ impl Rootable for AbstractModule<'_> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-#[repr(transparent)]
 pub(crate) enum InnerAbstractModule<'a> {
     SourceTextModule(SourceTextModule<'a>),
+    SyntheticModule(SyntheticModule<'a>),
 }
 
 bindable_handle!(InnerAbstractModule);
@@ -161,6 +185,7 @@ impl From<InnerAbstractModule<'_>> for HeapRootData {
     fn from(value: InnerAbstractModule<'_>) -> Self {
         match value {
             InnerAbstractModule::SourceTextModule(s) => Self::from(s),
+            InnerAbstractModule::SyntheticModule(s) => Self::from(s),
         }
     }
 }
@@ -171,6 +196,7 @@ impl TryFrom<HeapRootData> for InnerAbstractModule<'_> {
     fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
         match value {
             HeapRootData::SourceTextModule(s) => Ok(Self::SourceTextModule(s)),
+            HeapRootData::SyntheticModule(s) => Ok(Self::SyntheticModule(s)),
             _ => Err(()),
         }
     }
@@ -208,6 +234,7 @@ pub(crate) trait AbstractModuleSlots: Copy {
     fn host_defined(self, agent: &Agent) -> Option<HostDefined>;
 }
 
+#[derive(Clone, Copy)]
 pub(crate) struct ResolveSetEntry<'a> {
     /// ### \[\[Module]]
     pub(crate) module: SourceTextModule<'a>,
@@ -295,36 +322,42 @@ impl AbstractModuleSlots for AbstractModule<'_> {
     ) -> Option<ModuleEnvironment<'a>> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.environment(agent, gc),
+            InnerAbstractModule::SyntheticModule(m) => m.environment(agent, gc),
         }
     }
 
     fn set_environment(self, agent: &mut Agent, env: ModuleEnvironment) {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.set_environment(agent, env),
+            InnerAbstractModule::SyntheticModule(m) => m.set_environment(agent, env),
         }
     }
 
     fn namespace<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.namespace(agent, gc),
+            InnerAbstractModule::SyntheticModule(m) => m.namespace(agent, gc),
         }
     }
 
     fn set_namespace(self, agent: &mut Agent, namespace: Module) {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.set_namespace(agent, namespace),
+            InnerAbstractModule::SyntheticModule(m) => m.set_namespace(agent, namespace),
         }
     }
 
     fn realm<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.realm(agent, gc),
+            InnerAbstractModule::SyntheticModule(m) => m.realm(agent, gc),
         }
     }
 
     fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.host_defined(agent),
+            InnerAbstractModule::SyntheticModule(m) => m.host_defined(agent),
         }
     }
 }
@@ -340,6 +373,9 @@ impl AbstractModuleMethods for AbstractModule<'_> {
             InnerAbstractModule::SourceTextModule(m) => {
                 m.load_requested_modules(agent, host_defined, gc)
             }
+            InnerAbstractModule::SyntheticModule(m) => {
+                m.load_requested_modules(agent, host_defined, gc)
+            }
         }
     }
 
@@ -353,6 +389,9 @@ impl AbstractModuleMethods for AbstractModule<'_> {
             InnerAbstractModule::SourceTextModule(m) => {
                 m.get_exported_names(agent, export_start_set, gc)
             }
+            InnerAbstractModule::SyntheticModule(m) => {
+                m.get_exported_names(agent, export_start_set, gc)
+            }
         }
     }
 
@@ -367,18 +406,23 @@ impl AbstractModuleMethods for AbstractModule<'_> {
             InnerAbstractModule::SourceTextModule(m) => {
                 m.resolve_export(agent, export_name, resolve_set, gc)
             }
+            InnerAbstractModule::SyntheticModule(m) => {
+                m.resolve_export(agent, export_name, resolve_set, gc)
+            }
         }
     }
 
     fn link<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.link(agent, gc),
+            InnerAbstractModule::SyntheticModule(m) => m.link(agent, gc),
         }
     }
 
     fn evaluate<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> Promise<'gc> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.evaluate(agent, gc),
+            InnerAbstractModule::SyntheticModule(m) => m.evaluate(agent, gc),
         }
     }
 }
@@ -387,12 +431,14 @@ impl HeapMarkAndSweep for AbstractModule<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         match &self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.mark_values(queues),
+            InnerAbstractModule::SyntheticModule(m) => m.mark_values(queues),
         }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         match &mut self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.sweep_values(compactions),
+            InnerAbstractModule::SyntheticModule(m) => m.sweep_values(compactions),
         }
     }
 }
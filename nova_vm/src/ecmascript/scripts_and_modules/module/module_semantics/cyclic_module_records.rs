@@ -8,8 +8,8 @@ use crate::{
     ecmascript::{
         AbstractModule, AbstractModuleMethods, Agent, HostDefined, JsError, JsResult,
         LoadedModules, ModuleRequest, ModuleRequestRecord, Promise, PromiseCapability,
-        PromiseReactionHandler, SourceTextModule, Value, get_imported_module, inner_promise_then,
-        unwrap_try,
+        PromiseReactionHandler, SourceTextModule, Value, get_imported_module,
+        host_load_imported_module, inner_promise_then, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, bindable_handle},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
@@ -511,6 +511,22 @@ pub struct GraphLoadingStateRecord<'a> {
 }
 
 impl<'a> GraphLoadingStateRecord<'a> {
+    /// Returns true if this record is the payload of an `import()` call
+    /// rather than the loading state of a static module graph.
+    ///
+    /// The loading of dynamically imported modules may be finished
+    /// asynchronously; see [`Agent::defer_dynamic_import`].
+    pub fn is_dynamic_import(&self) -> bool {
+        // Note: static import always has a non-zero pending modules count
+        // while the host is loading a module.
+        self.pending_modules_count == 0
+    }
+
+    /// Get the promise to resolve when the loading process finishes.
+    pub(crate) fn promise(&self) -> Promise<'a> {
+        self.promise_capability.promise()
+    }
+
     /// Used by dynamic import; this is against the spec and not a good look.
     pub(crate) fn from_promise(promise: Promise<'a>) -> Self {
         Self {
@@ -560,7 +576,7 @@ pub(crate) fn inner_module_loading<'a>(
             //         1. Perform InnerModuleLoading(state, record.[[Module]]).
             // iii. Else,
             // 1. Perform HostLoadImportedModule(module, request, state.[[HostDefined]], state).
-            agent.host_hooks.load_imported_module(
+            host_load_imported_module(
                 agent,
                 module.into(),
                 *request,
@@ -1135,7 +1151,7 @@ pub(crate) fn async_module_execution_rejected(
         // a. Assert: module.[[CycleRoot]] and module are the same Module Record.
         assert_eq!(module.get_cycle_root(agent), Some(module));
         // b. Perform ! Call(module.[[TopLevelCapability]].[[Reject]], undefined, « error »).
-        top_level_capability.reject(agent, Value::Undefined, gc);
+        top_level_capability.reject(agent, error.value(), gc);
     }
     // 11. Return unused.
 }
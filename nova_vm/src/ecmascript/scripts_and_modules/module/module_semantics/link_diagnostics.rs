@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Module linking diagnostics
+//!
+//! When Link() fails because an import or a re-export cannot be resolved, the
+//! specification only requires a SyntaxError to be thrown. Nova additionally
+//! traces the failed resolution and records the chain of module specifiers
+//! and export names that were followed, and why the chain ended. The
+//! diagnostic is used as the SyntaxError message and is available from
+//! [`SourceTextModule::link_diagnostic`].
+//!
+//! [`SourceTextModule::link_diagnostic`]: crate::ecmascript::SourceTextModule::link_diagnostic
+
+use core::fmt;
+
+/// Reason why an export could not be resolved during module linking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkDiagnosticKind {
+    /// The last module in the chain does not provide the export.
+    Unresolved,
+    /// The last module in the chain has multiple star exports that provide
+    /// different bindings for the export.
+    Ambiguous {
+        /// Specifiers of the conflicting `export * from` declarations.
+        candidates: Box<[Box<str>]>,
+    },
+    /// The chain of re-exports leads back to an export that was already being
+    /// resolved. The last step of the chain repeats an earlier step.
+    Circular,
+}
+
+/// A step in the resolution chain of a [`LinkDiagnostic`]: an export name
+/// that was requested from a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkDiagnosticStep {
+    specifier: Option<Box<str>>,
+    export_name: Box<str>,
+}
+
+impl LinkDiagnosticStep {
+    pub(super) fn new(specifier: Option<Box<str>>, export_name: Box<str>) -> Self {
+        Self {
+            specifier,
+            export_name,
+        }
+    }
+
+    /// The module request specifier through which the export was requested.
+    ///
+    /// This is `None` for a re-export of the linked module itself, which is
+    /// not referred to by any specifier.
+    pub fn specifier(&self) -> Option<&str> {
+        self.specifier.as_deref()
+    }
+
+    /// The requested export name.
+    pub fn export_name(&self) -> &str {
+        &self.export_name
+    }
+}
+
+impl fmt::Display for LinkDiagnosticStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.export_name)?;
+        if let Some(specifier) = &self.specifier {
+            write!(f, " from '{specifier}'")?;
+        }
+        Ok(())
+    }
+}
+
+/// Structured description of an import or re-export that failed to resolve
+/// during module linking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkDiagnostic {
+    kind: LinkDiagnosticKind,
+    chain: Box<[LinkDiagnosticStep]>,
+}
+
+impl LinkDiagnostic {
+    pub(super) fn new(kind: LinkDiagnosticKind, chain: Vec<LinkDiagnosticStep>) -> Self {
+        debug_assert!(!chain.is_empty());
+        Self {
+            kind,
+            chain: chain.into_boxed_slice(),
+        }
+    }
+
+    /// Reason why the resolution failed.
+    pub fn kind(&self) -> &LinkDiagnosticKind {
+        &self.kind
+    }
+
+    /// The exports that were followed, starting from the failing import or
+    /// re-export and ending at the step where resolution failed.
+    pub fn chain(&self) -> &[LinkDiagnosticStep] {
+        &self.chain
+    }
+}
+
+impl fmt::Display for LinkDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            LinkDiagnosticKind::Unresolved => "Unresolved export: ",
+            LinkDiagnosticKind::Ambiguous { .. } => "Ambiguous export: ",
+            LinkDiagnosticKind::Circular => "Circular export: ",
+        })?;
+        for (i, step) in self.chain.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" -> ")?;
+            }
+            step.fmt(f)?;
+        }
+        if let LinkDiagnosticKind::Ambiguous { candidates } = &self.kind {
+            f.write_str(" (conflicting star exports from ")?;
+            for (i, candidate) in candidates.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" and ")?;
+                }
+                write!(f, "'{candidate}'")?;
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
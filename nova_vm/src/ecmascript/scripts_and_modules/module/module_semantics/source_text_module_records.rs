@@ -8,6 +8,7 @@ use std::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
     ptr::NonNull,
+    rc::Rc,
 };
 
 use ahash::AHashSet;
@@ -20,10 +21,11 @@ use crate::{
         AbstractModule, AbstractModuleMethods, AbstractModuleRecord, AbstractModuleSlots, Agent,
         AsyncEvaluationOrder, AwaitReactionRecord, BUILTIN_STRING_MEMORY, Contains, ContainsSymbol,
         CyclicModuleMethods, CyclicModuleRecord, CyclicModuleRecordStatus, CyclicModuleSlots,
-        ECMAScriptCodeEvaluationState, ExceptionType, ExecutionContext, GraphLoadingStateRecord,
-        HostDefined, JsError, JsResult, LexicallyScopedDeclaration, LexicallyScopedDeclarations,
+        ECMAScriptCodeEvaluationState, ExceptionType, ExecutionContext, FrameMetadata,
+        GraphLoadingStateRecord, HostDefined, JsError, JsResult, LexicallyScopedDeclaration,
+        LexicallyScopedDeclarations, LinkDiagnostic, LinkDiagnosticKind, LinkDiagnosticStep,
         Module, ModuleEnvironment, ModuleRequest, ModuleRequestRecord, OrdinaryObject, ParseResult,
-        Promise, PromiseCapability, PromiseReactionHandler, Realm, ResolveSetEntry,
+        Promise, PromiseCapability, PromiseReactionHandler, PropertyKeySet, Realm, ResolveSetEntry,
         ResolvedBinding, ScriptOrModule, SourceCode, SourceCodeType, String, Value,
         VarScopedDeclaration, VarScopedDeclarations, create_import_binding,
         create_indirect_import_binding, get_imported_module, get_module_namespace,
@@ -95,6 +97,8 @@ pub(crate) struct SourceTextModuleRecord<'a> {
     ///
     /// > NOTE: These are references to \[\[RequestedModules]] list.
     star_export_entries: Box<[ModuleRequest<'a>]>,
+    /// Diagnostic of the last failed Link() of this module.
+    link_diagnostic: Option<Box<LinkDiagnostic>>,
 
     /// Source text of the script
     ///
@@ -348,6 +352,47 @@ impl<'m> SourceTextModule<'m> {
             .set_evaluation_error(error)
     }
 
+    /// Get the diagnostic of the last failed Link() of this module.
+    ///
+    /// When linking fails because an import or a re-export of this module or
+    /// one of its dependencies cannot be resolved, the diagnostic describes
+    /// the chain of module specifiers and export names that were followed.
+    /// The diagnostic is cleared when the module is successfully linked.
+    pub fn link_diagnostic(self, agent: &Agent) -> Option<&LinkDiagnostic> {
+        self.unbind().get(agent).link_diagnostic.as_deref()
+    }
+
+    /// ### \[\[HostDefined]]
+    ///
+    /// The host defined data given to [`parse_module`].
+    pub fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
+        AbstractModuleSlots::host_defined(self, agent)
+    }
+
+    /// Get the \[\[HostDefined]] field downcast to `T`.
+    ///
+    /// Returns None if the field is empty or holds a value of another type.
+    pub fn host_defined_as<T: 'static>(self, agent: &Agent) -> Option<Rc<T>> {
+        self.host_defined(agent)?.downcast().ok()
+    }
+
+    fn set_link_diagnostic(self, agent: &mut Agent, diagnostic: Option<LinkDiagnostic>) {
+        self.get_mut(agent).link_diagnostic = diagnostic.map(Box::new);
+    }
+
+    /// Record a link diagnostic on this module and create a SyntaxError with
+    /// the diagnostic as its message.
+    fn throw_link_error<'a>(
+        self,
+        agent: &mut Agent,
+        diagnostic: LinkDiagnostic,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsError<'a> {
+        let message = diagnostic.to_string();
+        self.set_link_diagnostic(agent, Some(diagnostic));
+        agent.throw_exception(ExceptionType::SyntaxError, message, gc)
+    }
+
     /// Get a reference to the module's source code storage.
     fn source_code(self, agent: &Agent) -> SourceCode<'m> {
         self.get(agent).source_code
@@ -690,6 +735,15 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
             // c. Append e.[[ExportName]] to exportedNames.
             exported_names.push(e.export_name);
         }
+        // NOTE: Local and indirect export names are unique, so only the star
+        // export names need to be checked against the names seen so far.
+        let mut seen_names = PropertyKeySet::new(gc);
+        if !module.star_export_entries(agent).is_empty() {
+            seen_names.extend(
+                agent,
+                exported_names.iter().map(|name| name.to_property_key()),
+            );
+        }
         // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
         for e in module.star_export_entries(agent) {
             // a. Assert: e.[[ModuleRequest]] is not null.
@@ -702,7 +756,7 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
                 // i. If n is not "default", then
                 if n != BUILTIN_STRING_MEMORY.default {
                     // 1. If exportedNames does not contain n, then
-                    if !exported_names.contains(&n) {
+                    if seen_names.insert(agent, n.to_property_key()) {
                         // a. Append n to exportedNames.
                         exported_names.push(n);
                     }
@@ -917,6 +971,16 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
         let result = inner_module_linking(agent, module.into(), &mut stack, 0, gc);
         // 4. If result is an abrupt completion, then
         if let Err(result) = result {
+            // Note: the module that failed to initialize its environment is
+            // on the stack; expose its diagnostic through this module as well.
+            if let Some(diagnostic) = stack
+                .iter()
+                .rev()
+                .find_map(|m| m.link_diagnostic(agent))
+                .cloned()
+            {
+                module.set_link_diagnostic(agent, Some(diagnostic));
+            }
             // a. For each Cyclic Module Record m of stack, do
             //         i. Assert: m.[[Status]] is linking.
             //         ii. Set m.[[Status]] to unlinked.
@@ -929,6 +993,7 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
             // c. Return ? result.
             return Err(result);
         }
+        module.set_link_diagnostic(agent, None);
         // 5. Assert: module.[[Status]] is one of linked, evaluating-async, or
         //    evaluated.
         debug_assert!(matches!(
@@ -1142,6 +1207,7 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
         gc: NoGcScope<'a, '_>,
     ) -> JsResult<'a, ()> {
         let module = self.bind(gc);
+        module.set_link_diagnostic(agent, None);
         // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
         for e in module.indirect_export_entries(agent) {
             // a. Assert: e.[[ExportName]] is not null.
@@ -1149,11 +1215,9 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
             let resolution = module.resolve_export(agent, e.export_name, &mut vec![], gc);
             // c. If resolution is either null or ambiguous, throw a SyntaxError exception.
             if matches!(resolution, None | Some(ResolvedBinding::Ambiguous)) {
-                return Err(agent.throw_exception_with_static_message(
-                    ExceptionType::SyntaxError,
-                    "ambiguous imports",
-                    gc,
-                ));
+                let diagnostic =
+                    diagnose_unresolved_export(agent, module.into(), e.export_name, None, gc);
+                return Err(module.throw_link_error(agent, diagnostic, gc));
             }
             // d. Assert: resolution is a ResolvedBinding Record.
             debug_assert!(matches!(resolution, Some(ResolvedBinding::Resolved { .. })));
@@ -1190,11 +1254,14 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
                 binding_name: resolution_binding_name,
             }) = resolution
             else {
-                return Err(agent.throw_exception_with_static_message(
-                    ExceptionType::SyntaxError,
-                    "resolution is null or ambiguous",
+                let diagnostic = diagnose_unresolved_export(
+                    agent,
+                    imported_module,
+                    import_name,
+                    Some(r#in.module_request),
                     gc,
-                ));
+                );
+                return Err(module.throw_link_error(agent, diagnostic, gc));
             };
             // iii. If resolution.[[BindingName]] is namespace, then
             let Some(resolution_binding_name) = resolution_binding_name else {
@@ -1256,6 +1323,7 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
             realm: realm.unbind(),
             // 12. Set the ScriptOrModule of moduleContext to module.
             script_or_module: Some(ScriptOrModule::SourceTextModule(module.unbind())),
+            frame: FrameMetadata::NONE,
         };
         // 16. Set module.[[Context]] to moduleContext.
         // 17. Push moduleContext onto the execution context stack;
@@ -1447,6 +1515,7 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
             realm: module.realm(agent, gc.nogc()).unbind(),
             // 4. Set the ScriptOrModule of moduleContext to module.
             script_or_module: Some(module.unbind().into()),
+            frame: FrameMetadata::NONE,
         };
 
         // 8. Suspend the running execution context.
@@ -1498,6 +1567,129 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
 }
 
 #[inline(never)]
+/// Create a diagnostic for an export that module.ResolveExport(exportName)
+/// resolved to null or ambiguous.
+///
+/// The request is the module request through which the module was imported,
+/// or None if the module is resolving one of its own re-exports.
+fn diagnose_unresolved_export(
+    agent: &Agent,
+    module: AbstractModule,
+    export_name: String,
+    request: Option<ModuleRequest>,
+    gc: NoGcScope,
+) -> LinkDiagnostic {
+    let mut chain = vec![LinkDiagnosticStep::new(
+        request.map(|request| request_specifier(agent, request)),
+        export_name.to_string_lossy(agent).into(),
+    )];
+    let kind = trace_unresolved_export(agent, module, export_name, &mut vec![], &mut chain, gc);
+    LinkDiagnostic::new(kind, chain)
+}
+
+/// Follow the steps of ResolveExport for an export that fails to resolve,
+/// appending each followed re-export to the chain and returning the reason
+/// for the failure.
+fn trace_unresolved_export<'a>(
+    agent: &Agent,
+    module: AbstractModule<'a>,
+    export_name: String<'a>,
+    resolve_set: &mut Vec<ResolveSetEntry<'a>>,
+    chain: &mut Vec<LinkDiagnosticStep>,
+    gc: NoGcScope<'a, '_>,
+) -> LinkDiagnosticKind {
+    // Note: Synthetic modules only fail to resolve exports that they do not
+    // define.
+    let Some(module) = module.as_source_text_module() else {
+        return LinkDiagnosticKind::Unresolved;
+    };
+    if resolve_set
+        .iter()
+        .any(|r| module == r.module && export_name == r.export_name)
+    {
+        return LinkDiagnosticKind::Circular;
+    }
+    resolve_set.push(ResolveSetEntry {
+        module,
+        export_name,
+    });
+    debug_assert!(
+        !module
+            .local_export_entries(agent)
+            .iter()
+            .any(|e| e.export_name == export_name)
+    );
+    if let Some(e) = module
+        .indirect_export_entries(agent)
+        .iter()
+        .find(|e| e.export_name == export_name)
+    {
+        // Note: namespace re-exports always resolve.
+        let import_name = e.import_name.unwrap();
+        let imported_module = get_imported_module(agent, module, e.module_request, gc);
+        chain.push(LinkDiagnosticStep::new(
+            Some(request_specifier(agent, e.module_request)),
+            import_name.to_string_lossy(agent).into(),
+        ));
+        return trace_unresolved_export(
+            agent,
+            imported_module,
+            import_name,
+            resolve_set,
+            chain,
+            gc,
+        );
+    }
+    if export_name == BUILTIN_STRING_MEMORY.default {
+        return LinkDiagnosticKind::Unresolved;
+    }
+    let mut star_resolution = None;
+    for e in module.star_export_entries(agent) {
+        let imported_module = get_imported_module(agent, module, *e, gc);
+        let mut previous_resolve_set = resolve_set.clone();
+        match imported_module.resolve_export(agent, export_name, resolve_set, gc) {
+            None => {}
+            Some(ResolvedBinding::Ambiguous) => {
+                chain.push(LinkDiagnosticStep::new(
+                    Some(request_specifier(agent, *e)),
+                    export_name.to_string_lossy(agent).into(),
+                ));
+                return trace_unresolved_export(
+                    agent,
+                    imported_module,
+                    export_name,
+                    &mut previous_resolve_set,
+                    chain,
+                    gc,
+                );
+            }
+            Some(ResolvedBinding::Resolved {
+                module: resolution_module,
+                binding_name,
+            }) => {
+                let Some((star_module, star_binding_name, star_request)) = star_resolution else {
+                    star_resolution = Some((resolution_module, binding_name, *e));
+                    continue;
+                };
+                if resolution_module != star_module || binding_name != star_binding_name {
+                    return LinkDiagnosticKind::Ambiguous {
+                        candidates: Box::new([
+                            request_specifier(agent, star_request),
+                            request_specifier(agent, *e),
+                        ]),
+                    };
+                }
+            }
+        }
+    }
+    debug_assert!(star_resolution.is_none());
+    LinkDiagnosticKind::Unresolved
+}
+
+fn request_specifier(agent: &Agent, request: ModuleRequest) -> Box<str> {
+    request.specifier(agent).to_string_lossy(agent).into()
+}
+
 fn create_id(agent: &Agent, module: SourceTextModule) -> u64 {
     u64::try_from(
         module
@@ -1651,18 +1843,36 @@ pub fn parse_module<'a>(
         )
     };
 
+    match parse_result {
+        // 2. If body is a List of errors, return body.
+        Ok(result) => Ok(create_source_text_module(
+            agent,
+            result,
+            realm,
+            host_defined,
+            gc,
+        )),
+        Err(errors) => Err(errors),
+    }
+}
+
+/// Create a Source Text Module Record from successfully parsed source text.
+///
+/// These are the steps of [`parse_module`] following ParseText.
+pub(crate) fn create_source_text_module<'a>(
+    agent: &mut Agent,
+    parse_result: ParseResult<'a>,
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> SourceTextModule<'a> {
+    let realm = realm.bind(gc);
     let ParseResult {
         source_code,
         body,
         directives: _,
         is_strict: _,
-    } = match parse_result {
-        // 2. If body is a List of errors, return body.
-        Ok(result) => result,
-        Err(errors) => {
-            return Err(errors);
-        }
-    };
+    } = parse_result;
 
     // 3. Let requestedModules be the ModuleRequests of body.
     let mut requested_modules = vec![];
@@ -1984,7 +2194,7 @@ pub fn parse_module<'a>(
     // 11. Let async be body Contains await.
     let r#async = Contains::contains(body, ContainsSymbol::Await);
     // 12. Return Source Text Module Record {
-    Ok(agent
+    agent
         .heap
         .create(SourceTextModuleRecord {
             // [[Realm]]: realm,
@@ -2024,10 +2234,11 @@ pub fn parse_module<'a>(
             indirect_export_entries: indirect_export_entries.into_boxed_slice(),
             // [[StarExportEntries]]: starExportEntries,
             star_export_entries: star_export_entries.into_boxed_slice(),
+            link_diagnostic: None,
 
             source_code,
         })
-        .unbind())
+        .unbind()
     // }.
 }
 
@@ -2087,6 +2298,7 @@ impl HeapMarkAndSweep for SourceTextModuleRecord<'static> {
             local_export_entries,
             indirect_export_entries,
             star_export_entries: _,
+            link_diagnostic: _,
             source_code,
         } = self;
         abstract_fields.mark_values(queues);
@@ -2110,6 +2322,7 @@ impl HeapMarkAndSweep for SourceTextModuleRecord<'static> {
             local_export_entries,
             indirect_export_entries,
             star_export_entries: _,
+            link_diagnostic: _,
             source_code,
         } = self;
         abstract_fields.sweep_values(compactions);
@@ -2192,6 +2405,7 @@ impl HeapMarkAndSweep for ModuleRequestRecord<'static> {
             specifier,
             attributes,
             hash: _,
+            module_type: _,
         } = self;
         specifier.mark_values(queues);
         if let Some(attributes) = attributes {
@@ -2207,6 +2421,7 @@ impl HeapMarkAndSweep for ModuleRequestRecord<'static> {
             specifier,
             attributes,
             hash: _,
+            module_type: _,
         } = self;
         specifier.sweep_values(compactions);
         if let Some(attributes) = attributes {
@@ -0,0 +1,491 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1.8 Synthetic Module Records](https://tc39.es/ecma262/#sec-synthetic-module-records)
+
+use std::rc::Rc;
+
+use crate::{
+    ecmascript::{
+        AbstractModuleMethods, AbstractModuleRecord, AbstractModuleSlots, Agent, Behaviour,
+        BuiltinFunctionArgs, HostDefined, JsFunction, JsResult, Module, ModuleEnvironment, Promise,
+        Realm, Referrer, ResolveSetEntry, ResolvedBinding, SourceTextModule, String, Value,
+        create_builtin_function, new_module_environment,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, WorkQueues, arena_vec_access, index_handle,
+    },
+};
+
+/// ### [16.2.1.8 Synthetic Module Records](https://tc39.es/ecma262/#sec-synthetic-module-records)
+#[derive(Debug)]
+pub(crate) struct SyntheticModuleRecord<'a> {
+    abstract_fields: AbstractModuleRecord<'a>,
+    /// ### \[\[ExportNames]]
+    ///
+    /// The names of the exports of the module. This list does not contain
+    /// duplicates.
+    export_names: Box<[String<'a>]>,
+    /// ### \[\[EvaluationSteps]]
+    ///
+    /// The initial values of each export in \[\[ExportNames]]. Evaluating
+    /// the module sets each export to its corresponding value.
+    export_values: Box<[Value<'a>]>,
+}
+
+/// ### [16.2.1.8 Synthetic Module Records](https://tc39.es/ecma262/#sec-synthetic-module-records)
+///
+/// A _Synthetic Module Record_ is used to represent information about a
+/// module that is defined by specifications or by the host instead of by
+/// ECMAScript source text. Its exported names are statically defined at
+/// creation, while their corresponding values can change over time.
+///
+/// In Nova, Synthetic Module Records are created for native modules
+/// registered using [`Agent::register_module`]. Embedders can also create
+/// them directly with [`SyntheticModule::new`] and return them from
+/// [`HostHooks::load_imported_module`] to make them importable by source text
+/// modules.
+///
+/// [`Agent::register_module`]: crate::ecmascript::Agent::register_module
+/// [`HostHooks::load_imported_module`]: crate::ecmascript::HostHooks::load_imported_module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct SyntheticModule<'a>(BaseIndex<'a, SyntheticModuleRecord<'static>>);
+index_handle!(SyntheticModule);
+arena_vec_access!(SyntheticModule, 'a, SyntheticModuleRecord, synthetic_module_records);
+
+impl<'m> SyntheticModule<'m> {
+    /// Create a Synthetic Module Record in the given Realm with the given
+    /// export names.
+    ///
+    /// Each export is initially undefined; use [`SyntheticModule::set_export`]
+    /// to set its value. Duplicate export names are ignored.
+    pub fn new(
+        agent: &mut Agent,
+        realm: Realm,
+        export_names: &[&str],
+        host_defined: Option<HostDefined>,
+        gc: NoGcScope<'m, '_>,
+    ) -> Self {
+        let mut names = Vec::with_capacity(export_names.len());
+        for name in export_names {
+            let name = String::from_str(agent, name, gc);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let values = vec![Value::Undefined; names.len()];
+        agent.heap.create(SyntheticModuleRecord {
+            abstract_fields: AbstractModuleRecord::new(realm.bind(gc), host_defined),
+            export_names: names.into_boxed_slice(),
+            export_values: values.into_boxed_slice(),
+        })
+    }
+
+    /// ### \[\[ExportNames]]
+    fn export_names<'a>(self, agent: &'a Agent) -> &'a [String<'m>] {
+        &self.get(agent).export_names
+    }
+
+    /// Set the value of an export of the module.
+    ///
+    /// The value is visible to importers of the module immediately if the
+    /// module has already been evaluated, and otherwise once it is.
+    ///
+    /// ## Panics
+    ///
+    /// If the module does not export the given name.
+    pub fn set_export(self, agent: &mut Agent, name: &str, value: Value, gc: NoGcScope) {
+        let module = self.bind(gc);
+        let name = String::from_str(agent, name, gc);
+        let Some(index) = module.export_names(agent).iter().position(|n| *n == name) else {
+            panic!(
+                "Synthetic module does not export '{}'",
+                name.to_string_lossy_(agent)
+            );
+        };
+        module.get_mut(agent).export_values[index] = value.unbind();
+        if module.environment(agent, gc).is_some() {
+            set_synthetic_module_export(agent, module, name, value, gc);
+        }
+    }
+
+    /// ### Link ( )
+    ///
+    /// Create the module's environment. Modules imported by source text
+    /// modules are linked as part of their module graph.
+    pub fn link<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        AbstractModuleMethods::link(self, agent, gc)
+    }
+
+    /// ### Evaluate ( )
+    ///
+    /// Initialize the module's exports to their values. The returned promise
+    /// is always fulfilled. Modules imported by source text modules are
+    /// evaluated as part of their module graph.
+    ///
+    /// ## Panics
+    ///
+    /// If the module has not been linked.
+    pub fn evaluate<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> Promise<'gc> {
+        AbstractModuleMethods::evaluate(self, agent, gc)
+    }
+}
+
+impl AbstractModuleSlots for SyntheticModule<'_> {
+    fn environment<'a>(
+        self,
+        agent: &Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ModuleEnvironment<'a>> {
+        self.get(agent).abstract_fields.environment().bind(gc)
+    }
+
+    fn set_environment(self, agent: &mut Agent, env: ModuleEnvironment) {
+        self.get_mut(agent).abstract_fields.set_environment(env);
+    }
+
+    fn namespace<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>> {
+        self.get(agent).abstract_fields.namespace().bind(gc)
+    }
+
+    fn set_namespace(self, agent: &mut Agent, namespace: Module) {
+        self.get_mut(agent).abstract_fields.set_namespace(namespace);
+    }
+
+    fn realm<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a> {
+        self.get(agent).abstract_fields.realm().bind(gc)
+    }
+
+    fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
+        self.get(agent).abstract_fields.host_defined()
+    }
+}
+
+impl AbstractModuleMethods for SyntheticModule<'_> {
+    /// ### LoadRequestedModules ( )
+    ///
+    /// The LoadRequestedModules concrete method of a Synthetic Module Record
+    /// module takes no arguments and returns a Promise.
+    fn load_requested_modules<'a>(
+        self,
+        agent: &mut Agent,
+        _host_defined: Option<HostDefined>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Promise<'a> {
+        // 1. Return ! PromiseResolve(%Promise%, undefined).
+        Promise::new_resolved(agent, Value::Undefined).bind(gc)
+    }
+
+    /// ### GetExportedNames ( )
+    ///
+    /// The GetExportedNames concrete method of a Synthetic Module Record
+    /// module takes no arguments and returns a List of Strings.
+    fn get_exported_names<'a>(
+        self,
+        agent: &Agent,
+        _export_start_set: &mut Vec<SourceTextModule<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Vec<String<'a>> {
+        // 1. Return module.[[ExportNames]].
+        self.bind(gc)
+            .export_names(agent)
+            .iter()
+            .map(|name| name.bind(gc))
+            .collect()
+    }
+
+    /// ### ResolveExport ( exportName )
+    ///
+    /// The ResolveExport concrete method of a Synthetic Module Record module
+    /// takes argument exportName (a String) and returns a ResolvedBinding
+    /// Record or null.
+    fn resolve_export<'a>(
+        self,
+        agent: &Agent,
+        export_name: String,
+        _resolve_set: &mut Vec<ResolveSetEntry<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ResolvedBinding<'a>> {
+        let module = self.bind(gc);
+        let export_name = export_name.bind(gc);
+        // 1. If module.[[ExportNames]] does not contain exportName, return
+        //    null.
+        if !module.export_names(agent).contains(&export_name) {
+            return None;
+        }
+        // 2. Return ResolvedBinding Record {
+        Some(ResolvedBinding::Resolved {
+            // [[Module]]: module,
+            module: module.into(),
+            // [[BindingName]]: exportName
+            binding_name: Some(export_name),
+        })
+        // }.
+    }
+
+    /// ### Link ( )
+    ///
+    /// The Link concrete method of a Synthetic Module Record module takes no
+    /// arguments and returns a normal completion containing unused.
+    ///
+    /// > NOTE: Link is called once for every module graph that the module is
+    /// > part of. As the export names of the module never change, the
+    /// > environment created by the first call is reused by later calls.
+    fn link<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        let module = self.bind(gc);
+        if module.environment(agent, gc).is_some() {
+            return Ok(());
+        }
+        // 1. Let realm be module.[[Realm]].
+        let realm = module.realm(agent, gc);
+        // 2. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        let global_env = realm.global_env(agent, gc).unwrap();
+        let env = new_module_environment(agent, Some(global_env.into()), gc);
+        // 3. Set module.[[Environment]] to env.
+        module.set_environment(agent, env);
+        // 4. For each String exportName of module.[[ExportNames]], do
+        for i in 0..module.export_names(agent).len() {
+            let export_name = module.export_names(agent)[i];
+            // a. Perform ! env.CreateMutableBinding(exportName, false).
+            env.create_mutable_binding(agent, export_name, false);
+            // b. Perform ! env.InitializeBinding(exportName, undefined).
+            env.initialize_binding(agent, export_name, Value::Undefined);
+        }
+        // 5. Return unused.
+        Ok(())
+    }
+
+    /// ### Evaluate ( )
+    ///
+    /// The Evaluate concrete method of a Synthetic Module Record module takes
+    /// no arguments and returns a Promise.
+    ///
+    /// > NOTE: The evaluation steps of native modules never call into
+    /// > ECMAScript code, so no module execution context is pushed.
+    fn evaluate<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> Promise<'gc> {
+        let gc = gc.into_nogc();
+        let module = self.bind(gc);
+        // 9. Let steps be module.[[EvaluationSteps]].
+        // 10. Let result be Completion(steps(module)).
+        for i in 0..module.export_names(agent).len() {
+            let record = module.get(agent);
+            let export_name = record.export_names[i];
+            let export_value = record.export_values[i];
+            set_synthetic_module_export(agent, module, export_name, export_value, gc);
+        }
+        // 13. Let pc be ! NewPromiseCapability(%Promise%).
+        // 14. IfAbruptRejectPromise(result, pc).
+        // 15. Perform ! Call(pc.[[Resolve]], undefined, « undefined »).
+        // 16. Return pc.[[Promise]].
+        Promise::new_resolved(agent, Value::Undefined).bind(gc)
+    }
+}
+
+/// ### SetSyntheticModuleExport ( module, exportName, exportValue )
+///
+/// The abstract operation SetSyntheticModuleExport takes arguments module (a
+/// Synthetic Module Record), exportName (a String), and exportValue (an
+/// ECMAScript language value) and returns unused. It can be used to set or
+/// change the exported value for an existing export of a Synthetic Module
+/// Record.
+fn set_synthetic_module_export(
+    agent: &mut Agent,
+    module: SyntheticModule,
+    export_name: String,
+    export_value: Value,
+    gc: NoGcScope,
+) {
+    // 1. Assert: module.[[ExportNames]] contains exportName.
+    debug_assert!(module.export_names(agent).contains(&export_name));
+    // 2. Let envRec be module.[[Environment]].
+    // 3. Assert: envRec is not empty.
+    let env_rec = module.environment(agent, gc).unwrap();
+    // 4. Perform envRec.SetMutableBinding(exportName, exportValue, true).
+    env_rec
+        .set_mutable_binding(agent, export_name, export_value, gc)
+        .unwrap();
+    // 5. Return unused.
+}
+
+/// Builder for the exports of a native module.
+///
+/// A mutable reference to the builder is passed to the initialisation
+/// function given to [`Agent::register_module`]. The initialisation function
+/// is called once for each Realm that imports the module.
+///
+/// [`Agent::register_module`]: crate::ecmascript::Agent::register_module
+#[derive(Debug)]
+pub struct NativeModuleBuilder<'a> {
+    realm: Realm<'a>,
+    export_names: Vec<String<'a>>,
+    export_values: Vec<Value<'a>>,
+}
+
+impl<'a> NativeModuleBuilder<'a> {
+    /// The Realm that the module is being created in.
+    pub fn realm(&self) -> Realm<'a> {
+        self.realm
+    }
+
+    /// Export a value from the module under the given name.
+    ///
+    /// Exporting the same name twice replaces the earlier value.
+    pub fn export_value(
+        &mut self,
+        agent: &mut Agent,
+        name: &str,
+        value: impl Into<Value<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) -> &mut Self {
+        let name = String::from_str(agent, name, gc);
+        let value = value.into();
+        if let Some(index) = self.export_names.iter().position(|n| *n == name) {
+            self.export_values[index] = value;
+        } else {
+            self.export_names.push(name);
+            self.export_values.push(value);
+        }
+        self
+    }
+
+    /// Export a native function from the module under the given name.
+    ///
+    /// The function is created in the module's Realm.
+    pub fn export_function(
+        &mut self,
+        agent: &mut Agent,
+        name: &'static str,
+        length: u32,
+        behaviour: Behaviour,
+        gc: NoGcScope<'a, '_>,
+    ) -> &mut Self {
+        let function = create_builtin_function(
+            agent,
+            behaviour,
+            BuiltinFunctionArgs::new_with_realm(length, name, self.realm),
+            gc,
+        );
+        self.export_value(agent, name, function, gc)
+    }
+
+    /// Export a [`JsFunction`] from the module under its name.
+    ///
+    /// The function is created in the module's Realm.
+    pub fn export_js_function<F: JsFunction>(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> &mut Self {
+        self.export_function(agent, F::NAME, F::LENGTH, F::BEHAVIOUR, gc)
+    }
+}
+
+/// Initialisation function of a native module.
+pub(crate) type NativeModuleInit =
+    Rc<dyn for<'gc, 'scope> Fn(&mut Agent, &mut NativeModuleBuilder<'gc>, NoGcScope<'gc, 'scope>)>;
+
+/// A native module registered with [`Agent::register_module`].
+///
+/// [`Agent::register_module`]: crate::ecmascript::Agent::register_module
+pub(crate) struct NativeModuleDefinition {
+    pub(crate) specifier: Box<str>,
+    pub(crate) init: NativeModuleInit,
+}
+
+impl core::fmt::Debug for NativeModuleDefinition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NativeModuleDefinition")
+            .field("specifier", &self.specifier)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Find or create the Synthetic Module Record of the native module registered
+/// under the given key, if any.
+///
+/// Each native module is instantiated at most once per Realm: later requests
+/// for the same key from the same Realm receive the same module.
+pub(crate) fn load_native_module<'a>(
+    agent: &mut Agent,
+    referrer: Referrer<'a>,
+    key: &str,
+    gc: NoGcScope<'a, '_>,
+) -> Option<SyntheticModule<'a>> {
+    let index = agent
+        .native_modules
+        .iter()
+        .position(|definition| &*definition.specifier == key)?;
+    let realm = referrer.realm(agent, gc);
+    if let Some(module) = realm.get_native_module(agent, index) {
+        return Some(module.bind(gc));
+    }
+    let init = agent.native_modules[index].init.clone();
+    let mut builder = NativeModuleBuilder {
+        realm,
+        export_names: vec![],
+        export_values: vec![],
+    };
+    init(agent, &mut builder, gc);
+    let NativeModuleBuilder {
+        realm,
+        export_names,
+        export_values,
+    } = builder;
+    let module = agent.heap.create(SyntheticModuleRecord {
+        abstract_fields: AbstractModuleRecord::new(realm, None),
+        export_names: export_names.into_boxed_slice(),
+        export_values: export_values.into_boxed_slice(),
+    });
+    realm.set_native_module(agent, index, module);
+    Some(module)
+}
+
+impl<'a> CreateHeapData<SyntheticModuleRecord<'a>, SyntheticModule<'a>> for Heap {
+    fn create(&mut self, data: SyntheticModuleRecord<'a>) -> SyntheticModule<'a> {
+        self.synthetic_module_records.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<SyntheticModuleRecord<'static>>();
+        SyntheticModule(BaseIndex::last(&self.synthetic_module_records))
+    }
+}
+
+crate::engine::bindable_handle!(SyntheticModuleRecord);
+
+impl HeapMarkAndSweep for SyntheticModule<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.synthetic_module_records.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .synthetic_module_records
+            .shift_index(&mut self.0);
+    }
+}
+
+impl HeapMarkAndSweep for SyntheticModuleRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            abstract_fields,
+            export_names,
+            export_values,
+        } = self;
+        abstract_fields.mark_values(queues);
+        export_names.mark_values(queues);
+        export_values.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            abstract_fields,
+            export_names,
+            export_values,
+        } = self;
+        abstract_fields.sweep_values(compactions);
+        export_names.sweep_values(compactions);
+        export_values.sweep_values(compactions);
+    }
+}
@@ -7,11 +7,12 @@
 use crate::{
     ecmascript::{
         AbstractModule, Agent, BUILTIN_STRING_MEMORY, ECMAScriptCode, Environment, ExceptionType,
-        ExecutionContext, GlobalEnvironment, JsResult, LexicallyScopedDeclaration, LoadedModules,
-        ModuleRequest, ParseResult, PropertyLookupCache, Realm, ScriptOrModule, SourceCode,
-        SourceCodeType, String, Value, VarScopedDeclaration, instantiate_function_object,
-        script_lexically_declared_names, script_lexically_scoped_declarations,
-        script_var_declared_names, script_var_scoped_declarations,
+        ExecutionContext, FrameMetadata, GlobalEnvironment, JsResult, LexicallyScopedDeclaration,
+        LoadedModules, ModuleRequest, ParseResult, PropertyLookupCache, Realm, ScriptOrModule,
+        SourceCode, SourceCodeType, String, Value, VarScopedDeclaration,
+        instantiate_function_object, script_lexically_declared_names,
+        script_lexically_scoped_declarations, script_var_declared_names,
+        script_var_scoped_declarations,
     },
     engine::{Bindable, Executable, GcScope, NoGcScope, Scopable, Vm, bindable_handle},
     heap::{
@@ -87,11 +88,18 @@ impl Script<'_> {
         self.get(agent).realm.bind(gc)
     }
 
-    /// \[\[\HostDefined]]
-    pub(crate) fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
+    /// ### \[\[HostDefined]]
+    pub fn host_defined(self, agent: &Agent) -> Option<HostDefined> {
         self.get(agent).host_defined.clone()
     }
 
+    /// Get the \[\[HostDefined]] field downcast to `T`.
+    ///
+    /// Returns None if the field is empty or holds a value of another type.
+    pub fn host_defined_as<T: 'static>(self, agent: &Agent) -> Option<Rc<T>> {
+        self.host_defined(agent)?.downcast().ok()
+    }
+
     pub(crate) fn insert_loaded_module(
         self,
         agent: &mut Agent,
@@ -215,7 +223,11 @@ impl HeapMarkAndSweep for ScriptRecord<'static> {
 /// The resulting [`Script`] can be executed using the [`script_evaluation`]
 /// function.
 ///
+/// The source text is not copied. Large sources can be shared with the
+/// embedder by creating them with [`String::from_external`].
+///
 /// [`Script`]: crate::ecmascript::Script
+/// [`String::from_external`]: crate::ecmascript::String::from_external
 /// [`script_evaluation`]: crate::ecmascript::script_evaluation
 /// [ECMAScript source text]: crate::ecmascript::String
 /// [Realm Record]: crate::ecmascript::Realm
@@ -244,18 +256,29 @@ pub fn parse_script<'a>(
         )
     };
 
+    match parse_result {
+        // 2. If script is a List of errors, return script.
+        Ok(result) => Ok(create_script(agent, result, realm, host_defined, gc)),
+        Err(errors) => Err(errors),
+    }
+}
+
+/// Create a Script Record from successfully parsed source text.
+///
+/// These are the steps of [`parse_script`] following ParseText.
+pub(crate) fn create_script<'a>(
+    agent: &mut Agent,
+    parse_result: ParseResult<'a>,
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> Script<'a> {
     let ParseResult {
         source_code,
         body,
         directives: _,
         is_strict,
-    } = match parse_result {
-        // 2. If script is a List of errors, return script.
-        Ok(result) => result,
-        Err(errors) => {
-            return Err(errors);
-        }
-    };
+    } = parse_result;
 
     // 3. Return Script Record {
     let script_record = ScriptRecord {
@@ -275,7 +298,7 @@ pub fn parse_script<'a>(
         source_code: source_code.unbind(),
     };
     // }
-    Ok(agent.heap.create(script_record).bind(gc))
+    agent.heap.create(script_record).bind(gc)
 }
 
 /// ### [16.1.6 ScriptEvaluation ( scriptRecord )](https://tc39.es/ecma262/#sec-runtime-semantics-scriptevaluation)
@@ -328,6 +351,8 @@ pub fn script_evaluation<'a>(
 
             source_code: source_code.unbind(),
         }),
+
+        frame: FrameMetadata::NONE,
     };
 
     // TODO: 9. Suspend the running execution context.
@@ -7,7 +7,8 @@
 //! that the eval call defines functions. Those functions will refer to the
 //! SourceCode for their function source text.
 
-use core::fmt::Debug;
+use core::{fmt::Debug, ptr::NonNull};
+use std::sync::Arc;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast;
@@ -51,6 +52,17 @@ impl SourceCodeType {
             SourceCodeType::Module => true,
         }
     }
+
+    /// Remove syntax errors that do not apply to this type of source code.
+    ///
+    /// The semantic checker reports `new.target` outside of functions as an
+    /// error, but eval code called from within a function may contain
+    /// `new.target`. PerformEval checks this itself.
+    fn retain_syntax_errors(&self, errors: &mut Vec<OxcDiagnostic>) {
+        if matches!(self, SourceCodeType::Eval { .. }) {
+            errors.retain(|error| error.message != "Unexpected new.target expression");
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -61,6 +73,170 @@ pub(crate) struct ParseResult<'a> {
     pub(crate) is_strict: bool,
 }
 
+/// Source text that has been parsed and checked for early errors, but not yet
+/// moved onto the heap as a SourceCode.
+///
+/// Parsing does not require access to an Agent, so a ParsedSource can be
+/// created on a different thread than the one that uses it.
+pub(crate) struct ParsedSource {
+    scoping: Scoping,
+    nodes: AstNodes<'static>,
+    body: NonNull<[ast::Statement<'static>]>,
+    directives: NonNull<[ast::Directive<'static>]>,
+    is_strict: bool,
+    /// The arena that contains the parsed data.
+    allocator: Allocator,
+}
+
+// SAFETY: The parsed data refers only to the allocator, which is owned by the
+// ParsedSource, and to the source text, which the caller of
+// ParsedSource::parse keeps alive. None of it is shared, so the whole can be
+// moved to another thread.
+unsafe impl Send for ParsedSource {}
+
+impl Debug for ParsedSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ParsedSource")
+            .field("is_strict", &self.is_strict)
+            .field("allocator", &"[binary data]")
+            .finish()
+    }
+}
+
+impl ParsedSource {
+    /// Parses the given source text as JavaScript code and checks it for
+    /// early errors.
+    ///
+    /// ## Safety
+    ///
+    /// The parsed data refers to the source text: the caller must keep the
+    /// source text alive and unchanged for as long as the ParsedSource, or
+    /// the SourceCode created from it, lives.
+    pub(crate) unsafe fn parse(
+        source_text: &str,
+        source_type: SourceCodeType,
+        typescript: bool,
+    ) -> Result<Self, Vec<OxcDiagnostic>> {
+        let mut allocator = Allocator::new();
+
+        let parser_result = match source_type {
+            source_code_type @ (SourceCodeType::Script { strict }
+            | SourceCodeType::Eval { strict, .. }) => {
+                // Potentially strict script! We first parse and syntax check
+                // this as a normal script, which checks that the code contains
+                // no module declarations or TLA. If that passes and we're
+                // strict, then we parse the script as a module which sets
+                // strict mode on.
+                let source_type = SourceType::script().with_typescript(typescript);
+                let sloppy_result = Parser::new(&allocator, source_text, source_type).parse();
+                if strict {
+                    let ParserReturn {
+                        errors: sloppy_errors,
+                        program: sloppy_program,
+                        panicked,
+                        ..
+                    } = sloppy_result;
+                    if panicked {
+                        return Err(sloppy_errors);
+                    }
+                    let SemanticBuilderReturn {
+                        errors: mut sloppy_errors,
+                        ..
+                    } = SemanticBuilder::new()
+                        .with_check_syntax_error(true)
+                        .build(&sloppy_program);
+                    source_code_type.retain_syntax_errors(&mut sloppy_errors);
+
+                    if !sloppy_errors.is_empty() {
+                        return Err(sloppy_errors);
+                    }
+
+                    let old_capacity = allocator.capacity();
+                    // Reset the allocator; we don't need the sloppy program
+                    // anymore.
+                    allocator.reset();
+                    if (old_capacity / 2) > allocator.capacity() {
+                        // If we more than halved the capacity of the allocator
+                        // by resetting it, we'll reallocate the whole thing to
+                        // old capacity.
+                        let _ = core::mem::replace(
+                            &mut allocator,
+                            Allocator::with_capacity(old_capacity),
+                        );
+                    }
+
+                    let source_type = SourceType::mjs().with_typescript(typescript);
+                    let strict_result = Parser::new(&allocator, source_text, source_type).parse();
+                    if strict_result.panicked {
+                        let errors = strict_result.errors;
+                        return Err(errors);
+                    }
+
+                    strict_result
+                } else {
+                    sloppy_result
+                }
+            }
+            SourceCodeType::Module => {
+                let source_type = SourceType::mjs().with_typescript(typescript);
+                Parser::new(&allocator, source_text, source_type).parse()
+            }
+        };
+
+        let ParserReturn {
+            errors, program, ..
+        } = parser_result;
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let SemanticBuilderReturn {
+            mut errors,
+            semantic,
+        } = SemanticBuilder::new()
+            .with_check_syntax_error(true)
+            .build(&program);
+        source_type.retain_syntax_errors(&mut errors);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let (scoping, nodes) = semantic.into_scoping_and_nodes();
+        let is_strict = source_type.is_strict() || program.has_use_strict_directive();
+
+        // SAFETY: The Program and AstNodes refer to the bump heap
+        // allocations of allocator and to the source text. We move allocator
+        // into the ParsedSource together with them, making this
+        // self-referential. The bump allocations are never moved or
+        // deallocated until dropping the entire struct, at which point the
+        // "allocator" field is dropped last. Caller guarantees that the source
+        // text outlives the ParsedSource.
+        let (body, directives, nodes) = unsafe {
+            (
+                NonNull::from(core::mem::transmute::<
+                    &[ast::Statement],
+                    &'static [ast::Statement<'static>],
+                >(program.body.as_slice())),
+                NonNull::from(core::mem::transmute::<
+                    &[ast::Directive],
+                    &'static [ast::Directive<'static>],
+                >(program.directives.as_slice())),
+                core::mem::transmute::<AstNodes, AstNodes<'static>>(nodes),
+            )
+        };
+
+        Ok(Self {
+            scoping,
+            nodes,
+            body,
+            directives,
+            is_strict,
+            allocator,
+        })
+    }
+}
+
 impl<'a> SourceCode<'a> {
     /// Parses the given source string as JavaScript code and returns the parsed
     /// result and a SourceCode heap reference.
@@ -148,119 +324,89 @@ impl<'a> SourceCode<'a> {
             }
         };
 
-        let mut allocator = Allocator::new();
-
-        let parser_result = match source_type {
-            SourceCodeType::Script { strict } | SourceCodeType::Eval { strict, .. } => {
-                // Potentially strict script! We first parse and syntax check
-                // this as a normal script, which checks that the code contains
-                // no module declarations or TLA. If that passes and we're
-                // strict, then we parse the script as a module which sets
-                // strict mode on.
-                let source_type = SourceType::script().with_typescript(typescript);
-                let sloppy_result = Parser::new(&allocator, source_text, source_type).parse();
-                if strict {
-                    let ParserReturn {
-                        errors: sloppy_errors,
-                        program: sloppy_program,
-                        panicked,
-                        ..
-                    } = sloppy_result;
-                    if panicked {
-                        return Err(sloppy_errors);
-                    }
-                    let SemanticBuilderReturn {
-                        errors: sloppy_errors,
-                        ..
-                    } = SemanticBuilder::new()
-                        .with_check_syntax_error(true)
-                        .build(&sloppy_program);
-
-                    if !sloppy_errors.is_empty() {
-                        return Err(sloppy_errors);
-                    }
-
-                    let old_capacity = allocator.capacity();
-                    // Reset the allocator; we don't need the sloppy program
-                    // anymore.
-                    allocator.reset();
-                    if (old_capacity / 2) > allocator.capacity() {
-                        // If we more than halved the capacity of the allocator
-                        // by resetting it, we'll reallocate the whole thing to
-                        // old capacity.
-                        let _ = core::mem::replace(
-                            &mut allocator,
-                            Allocator::with_capacity(old_capacity),
-                        );
-                    }
-
-                    let source_type = SourceType::mjs().with_typescript(typescript);
-                    let strict_result = Parser::new(&allocator, source_text, source_type).parse();
-                    if strict_result.panicked {
-                        let errors = strict_result.errors;
-                        return Err(errors);
-                    }
+        // SAFETY: The source text is kept alive by the SourceCode's heap data.
+        let parsed = unsafe { ParsedSource::parse(source_text, source_type, typescript) }?;
+        // SAFETY: The parsed data refers to the source String's data.
+        Ok(unsafe { Self::from_parsed(agent, source, None, parsed, gc) })
+    }
 
-                    strict_result
-                } else {
-                    sloppy_result
-                }
-            }
-            SourceCodeType::Module => {
-                let source_type = SourceType::mjs().with_typescript(typescript);
-                Parser::new(&allocator, source_text, source_type).parse()
+    /// Move source text parsed by [`ParsedSource::parse`] onto the heap as a
+    /// SourceCode.
+    ///
+    /// The source text is not copied: if it cannot be stored as a HeapString
+    /// without copying, it is copied into one and the original is kept alive
+    /// by the SourceCode.
+    ///
+    /// See [`SourceCode::parse_source`] for the lifetime of the Program.
+    ///
+    /// ## Safety
+    ///
+    /// The source text must be the text that was parsed.
+    pub(crate) unsafe fn from_parsed_text(
+        agent: &mut Agent,
+        source_text: Arc<str>,
+        parsed: ParsedSource,
+        gc: NoGcScope<'a, '_>,
+    ) -> ParseResult<'a> {
+        let source = match String::from_external(agent, source_text.clone(), gc) {
+            String::String(source) => source,
+            String::SmallString(_) => {
+                // Pad the source text with whitespace so that it gets
+                // heap-allocated, like in parse_source.
+                let data = format!("{source_text}          ");
+                let String::String(source) = String::from_string(agent, data, gc) else {
+                    unreachable!()
+                };
+                source
             }
         };
+        // SAFETY: The parsed data refers to the source text, which the
+        // SourceCode keeps alive.
+        unsafe { Self::from_parsed(agent, source, Some(source_text), parsed, gc) }
+    }
 
-        let ParserReturn {
-            errors, program, ..
-        } = parser_result;
-
-        if !errors.is_empty() {
-            return Err(errors);
-        }
-
-        let SemanticBuilderReturn { errors, semantic } = SemanticBuilder::new()
-            .with_check_syntax_error(true)
-            .build(&program);
-
-        if !errors.is_empty() {
-            return Err(errors);
-        }
-        let (scoping, nodes) = semantic.into_scoping_and_nodes();
-        let is_strict = source_type.is_strict() || program.has_use_strict_directive();
-
+    /// ## Safety
+    ///
+    /// The parsed data must refer to the data of the source String or of the
+    /// parsed text.
+    unsafe fn from_parsed(
+        agent: &mut Agent,
+        source: HeapString,
+        parsed_text: Option<Arc<str>>,
+        parsed: ParsedSource,
+        gc: NoGcScope<'a, '_>,
+    ) -> ParseResult<'a> {
+        let ParsedSource {
+            scoping,
+            nodes,
+            body,
+            directives,
+            is_strict,
+            allocator,
+        } = parsed;
         // SAFETY: Caller guarantees that they will drop the Program before
         // SourceCode can be garbage collected.
         let (body, directives) = unsafe {
             (
-                core::mem::transmute::<&[oxc_ast::ast::Statement], &'a [oxc_ast::ast::Statement<'a>]>(
-                    program.body.as_slice(),
-                ),
-                core::mem::transmute::<&[oxc_ast::ast::Directive], &'a [oxc_ast::ast::Directive<'a>]>(
-                    program.directives.as_slice(),
+                core::mem::transmute::<&[ast::Statement], &'a [ast::Statement<'a>]>(body.as_ref()),
+                core::mem::transmute::<&[ast::Directive], &'a [ast::Directive<'a>]>(
+                    directives.as_ref(),
                 ),
             )
         };
-        // SAFETY: AstNodes refers to the bump heap allocations of allocator. We
-        // move allocator onto the heap together with nodes, making this
-        // self-referential. The bump allocations are never moved or deallocated
-        // until dropping the entire struct, at which point the "allocator"
-        // field is dropped last.
-        let nodes = unsafe { core::mem::transmute::<AstNodes, AstNodes<'static>>(nodes) };
         let source_code = agent.heap.create(SourceCodeHeapData {
             source: source.unbind(),
+            parsed_text,
             scoping,
             nodes,
             allocator,
         });
-
-        Ok(ParseResult {
-            source_code,
+        ParseResult {
+            source_code: source_code.bind(gc),
             body,
             directives,
             is_strict,
-        })
+        }
     }
 
     /// Manually drop a SourceCode.
@@ -315,6 +461,10 @@ pub(crate) struct SourceCodeHeapData<'a> {
     /// string was small-string optimised and on the stack, then those
     /// references would necessarily and definitely be invalid.
     source: HeapString<'a>,
+    /// The source text that was parsed, if it is not the data of the source
+    /// String. The parsed data refers to it.
+    #[expect(dead_code)]
+    parsed_text: Option<Arc<str>>,
     scoping: Scoping,
     nodes: AstNodes<'static>,
     /// The arena that contains the parsed data of the eval source.
@@ -347,6 +497,7 @@ impl HeapMarkAndSweep for SourceCodeHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             source,
+            parsed_text: _,
             allocator: _,
             scoping: _,
             nodes: _,
@@ -357,6 +508,7 @@ impl HeapMarkAndSweep for SourceCodeHeapData<'static> {
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             source,
+            parsed_text: _,
             allocator: _,
             scoping: _,
             nodes: _,
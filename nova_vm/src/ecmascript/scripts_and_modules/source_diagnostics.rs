@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Compilation diagnostics
+//!
+//! Checking ECMAScript source text for syntax errors and early errors without
+//! creating a Script or Module Record, or evaluating anything. This is
+//! intended for linters, editors, and validating script bundles ahead of
+//! time.
+//!
+//! Unlike [`parse_script`] and [`parse_module`], which stop at the first
+//! failing phase, the functions in this module report the errors of both
+//! parsing and static semantics when the parser is able to recover.
+//!
+//! [`parse_script`]: crate::ecmascript::parse_script
+//! [`parse_module`]: crate::ecmascript::parse_module
+
+use core::fmt;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{Parser, ParserReturn};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+/// A syntax error or early error found in ECMAScript source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceDiagnostic {
+    message: Box<str>,
+    help: Option<Box<str>>,
+    start: u32,
+    end: u32,
+    line: u32,
+    column: u32,
+}
+
+impl SourceDiagnostic {
+    fn new(source_text: &str, diagnostic: &OxcDiagnostic) -> Self {
+        let label = diagnostic.labels.as_ref().and_then(|labels| {
+            labels
+                .iter()
+                .find(|label| label.primary())
+                .or_else(|| labels.first())
+        });
+        let (start, end) = label.map_or((0, 0), |label| {
+            (label.offset(), label.offset() + label.len())
+        });
+        let start = start.min(source_text.len());
+        let end = end.clamp(start, source_text.len());
+        let preceding = &source_text[..start];
+        let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            message: diagnostic.message.as_ref().into(),
+            help: diagnostic.help.as_deref().map(Into::into),
+            start: start as u32,
+            end: end as u32,
+            line: preceding.matches('\n').count() as u32 + 1,
+            column: preceding[line_start..].chars().count() as u32 + 1,
+        }
+    }
+
+    /// The error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// A suggestion for fixing the error, if any.
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    /// The byte range of the source text that the error refers to.
+    pub fn span(&self) -> core::ops::Range<usize> {
+        self.start as usize..self.end as usize
+    }
+
+    /// The 1-based line number of the start of the error.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The 1-based column of the start of the error, counted in Unicode
+    /// scalar values.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl fmt::Display for SourceDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Check Script source text for syntax errors and early errors without
+/// evaluating it.
+///
+/// If `strict` is true, the Script is checked as strict mode code. Returns an
+/// empty list if the Script is valid.
+pub fn compile_script(source_text: &str, strict: bool) -> Vec<SourceDiagnostic> {
+    let source_type = SourceType::script().with_typescript(cfg!(feature = "typescript"));
+    let errors = check_source(source_text, source_type);
+    if !errors.is_empty() || !strict {
+        return errors;
+    }
+    // Strict Scripts are checked as Modules to enable strict mode; this is
+    // only valid once the source text is known to contain no module syntax.
+    let source_type = SourceType::mjs().with_typescript(cfg!(feature = "typescript"));
+    check_source(source_text, source_type)
+}
+
+/// Check Module source text for syntax errors and early errors without
+/// linking or evaluating it.
+///
+/// Returns an empty list if the Module is valid.
+pub fn compile_module(source_text: &str) -> Vec<SourceDiagnostic> {
+    let source_type = SourceType::mjs().with_typescript(cfg!(feature = "typescript"));
+    check_source(source_text, source_type)
+}
+
+fn check_source(source_text: &str, source_type: SourceType) -> Vec<SourceDiagnostic> {
+    let allocator = Allocator::new();
+    let ParserReturn {
+        program,
+        mut errors,
+        panicked,
+        ..
+    } = Parser::new(&allocator, source_text, source_type).parse();
+    if !panicked {
+        let semantic = SemanticBuilder::new()
+            .with_check_syntax_error(true)
+            .build(&program);
+        errors.extend(semantic.errors);
+    }
+    let mut diagnostics = errors
+        .iter()
+        .map(|error| SourceDiagnostic::new(source_text, error))
+        .collect::<Vec<_>>();
+    diagnostics.sort_by_key(|diagnostic| diagnostic.start);
+    diagnostics
+}
@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Detection of the goal symbol of ECMAScript source text.
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::ecmascript::HostDefined;
+
+/// The goal symbol of ECMAScript source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    /// ## [16.1 Scripts](https://tc39.es/ecma262/#sec-scripts)
+    Script {
+        /// True if the Script's directive prologue contains a Use Strict
+        /// Directive.
+        strict: bool,
+    },
+    /// ## [16.2 Modules](https://tc39.es/ecma262/#sec-modules)
+    Module,
+}
+
+impl SourceKind {
+    /// Returns true if the source text is a Module.
+    pub fn is_module(self) -> bool {
+        matches!(self, SourceKind::Module)
+    }
+
+    /// ### [11.2.2 Strict Mode Code](https://tc39.es/ecma262/#sec-strict-mode-code)
+    ///
+    /// Returns true if the source text is strict mode code: Module code is
+    /// always strict mode code, while Script code is strict mode code if it
+    /// begins with a Use Strict Directive.
+    pub fn is_strict(self) -> bool {
+        match self {
+            SourceKind::Script { strict } => strict,
+            SourceKind::Module => true,
+        }
+    }
+}
+
+/// Detect whether ECMAScript source text is a Script or a Module.
+///
+/// Source text is a Module if it contains import or export declarations, or
+/// `import.meta`. Otherwise it is a Script, strict if its directive prologue
+/// contains a Use Strict Directive.
+///
+/// Returns the syntax errors if the source text cannot be parsed.
+pub fn detect_source_kind(source_text: &str) -> Result<SourceKind, Vec<OxcDiagnostic>> {
+    let allocator = Allocator::new();
+    let source_type = SourceType::unambiguous().with_typescript(cfg!(feature = "typescript"));
+    let result = Parser::new(&allocator, source_text, source_type).parse();
+    if !result.errors.is_empty() {
+        return Err(result.errors);
+    }
+    if result.module_record.has_module_syntax {
+        Ok(SourceKind::Module)
+    } else {
+        Ok(SourceKind::Script {
+            strict: result.program.has_use_strict_directive(),
+        })
+    }
+}
+
+/// Options for evaluating source text.
+///
+/// See [`Agent::evaluate`], [`Agent::evaluate_script`], and
+/// [`Agent::evaluate_module`].
+///
+/// [`Agent::evaluate`]: crate::ecmascript::Agent::evaluate
+/// [`Agent::evaluate_script`]: crate::ecmascript::Agent::evaluate_script
+/// [`Agent::evaluate_module`]: crate::ecmascript::Agent::evaluate_module
+#[derive(Default, Clone)]
+pub struct EvaluationOptions {
+    /// If true, Scripts are evaluated as strict mode code. Module code is
+    /// always strict mode code.
+    pub strict: bool,
+    /// The \[\[HostDefined]] field of the Script or Module Record.
+    pub host_defined: Option<HostDefined>,
+}
+
+impl core::fmt::Debug for EvaluationOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EvaluationOptions")
+            .field("strict", &self.strict)
+            .field("host_defined", &self.host_defined.is_some())
+            .finish()
+    }
+}
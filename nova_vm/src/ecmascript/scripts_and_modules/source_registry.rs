@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Registry of loaded Scripts and Modules keyed by embedder identifiers.
+
+use ahash::AHashMap;
+
+use crate::{
+    ecmascript::{Script, SourceTextModule},
+    engine::bindable_handle,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// A Script or Module Record in the Agent's source registry.
+///
+/// See [`Agent::load_script`] and [`Agent::load_module`].
+///
+/// [`Agent::load_script`]: crate::ecmascript::Agent::load_script
+/// [`Agent::load_module`]: crate::ecmascript::Agent::load_module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadedSource<'a> {
+    /// ## [16.1 Scripts](https://tc39.es/ecma262/#sec-scripts)
+    Script(Script<'a>),
+    /// ### [16.2.1.7 Source Text Module Records](https://tc39.es/ecma262/#sec-source-text-module-records)
+    Module(SourceTextModule<'a>),
+}
+bindable_handle!(LoadedSource);
+
+impl<'a> From<Script<'a>> for LoadedSource<'a> {
+    fn from(value: Script<'a>) -> Self {
+        Self::Script(value)
+    }
+}
+
+impl<'a> From<SourceTextModule<'a>> for LoadedSource<'a> {
+    fn from(value: SourceTextModule<'a>) -> Self {
+        Self::Module(value)
+    }
+}
+
+impl HeapMarkAndSweep for LoadedSource<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Script(script) => script.mark_values(queues),
+            Self::Module(module) => module.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Script(script) => script.sweep_values(compactions),
+            Self::Module(module) => module.sweep_values(compactions),
+        }
+    }
+}
+
+/// Scripts and Modules loaded by the embedder, keyed by embedder-supplied
+/// identifiers such as URLs or paths.
+#[derive(Debug, Default)]
+pub(crate) struct SourceRegistry {
+    sources: AHashMap<Box<str>, LoadedSource<'static>>,
+}
+
+impl SourceRegistry {
+    pub(crate) fn get(&self, key: &str) -> Option<LoadedSource<'static>> {
+        self.sources.get(key).copied()
+    }
+
+    pub(crate) fn insert(&mut self, key: &str, source: LoadedSource<'static>) {
+        self.sources.insert(key.into(), source);
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) -> bool {
+        self.sources.remove(key).is_some()
+    }
+
+    /// Find the key a Script or Module is registered under.
+    pub(crate) fn key_of(&self, source: LoadedSource<'static>) -> Option<&str> {
+        self.sources
+            .iter()
+            .find(|(_, registered)| **registered == source)
+            .map(|(key, _)| &**key)
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.sources.keys().map(|key| &**key)
+    }
+}
+
+impl HeapMarkAndSweep for SourceRegistry {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        self.sources
+            .values()
+            .for_each(|source| source.mark_values(queues));
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        self.sources
+            .values_mut()
+            .for_each(|source| source.sweep_values(compactions));
+    }
+}
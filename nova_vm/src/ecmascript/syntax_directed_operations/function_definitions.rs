@@ -251,7 +251,7 @@ pub(crate) fn evaluate_async_function_body<'a>(
     function_object: ECMAScriptFunction,
     arguments_list: ArgumentsList,
     mut gc: GcScope<'a, '_>,
-) -> Promise<'a> {
+) -> JsResult<'a, Promise<'a>> {
     let arguments_list = arguments_list.bind(gc.nogc());
     let function_object = function_object.bind(gc.nogc());
     let scoped_function_object = function_object.scope(agent, gc.nogc());
@@ -301,6 +301,8 @@ pub(crate) fn evaluate_async_function_body<'a>(
             //       i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
             unwrap_try(promise_capability.try_resolve(agent, result, gc));
         }
+        // NOTE: Termination is not turned into a rejection.
+        ExecutionResult::Throw(err) if err.is_termination(agent) => return Err(err),
         ExecutionResult::Throw(err) => {
             let promise_capability = PromiseCapability::from_promise(promise, must_be_unresolved);
             // [27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )](https://tc39.es/ecma262/#sec-asyncblockstart)
@@ -337,7 +339,7 @@ pub(crate) fn evaluate_async_function_body<'a>(
     //}
 
     // 5. Return Completion Record { [[Type]]: return, [[Value]]: promiseCapability.[[Promise]], [[Target]]: empty }.
-    promise
+    Ok(promise)
 }
 
 /// ### [15.5.2 Runtime Semantics: EvaluateGeneratorBody](https://tc39.es/ecma262/#sec-runtime-semantics-evaluategeneratorbody)
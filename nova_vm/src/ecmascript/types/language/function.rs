@@ -15,10 +15,11 @@ use crate::{
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction,
-        InternalMethods, InternalSlots, JsResult, Object, OrdinaryObject, PropertyDescriptor,
-        PropertyKey, PropertyLookupCache, PropertyOffset, ProtoIntrinsics, SetAtOffsetProps,
-        SetResult, String, TryGetResult, TryHasResult, TryResult, Value,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction,
+        ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction, InternalMethods, InternalSlots,
+        JsResult, Object, OrdinaryObject, PropertyDescriptor, PropertyKey, PropertyLookupCache,
+        PropertyOffset, ProtoIntrinsics, SetAtOffsetProps, SetResult, String, TryGetResult,
+        TryHasResult, TryResult, Value,
     },
     engine::{Bindable, GcScope, HeapRootData, NoGcScope, bindable_handle},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
@@ -54,8 +55,8 @@ pub enum Function<'a> {
     /// Special functions created as part of `Promise.prototype.finally`.
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    /// Placeholder.
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    /// Special functions created by `Proxy.revocable` to revoke a Proxy.
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) = BUILTIN_PROXY_REVOKER_FUNCTION,
 }
 bindable_handle!(Function);
 
@@ -85,7 +86,7 @@ impl Function<'_> {
             Function::BuiltinPromiseResolvingFunction(_) => false,
             Function::BuiltinPromiseFinallyFunction(_) => false,
             Function::BuiltinConstructorFunction(_) => true,
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(_) => false,
         }
     }
 
@@ -98,7 +99,7 @@ impl Function<'_> {
             Function::BuiltinConstructorFunction(f) => f.get_name(agent).bind(gc),
             Function::BuiltinPromiseResolvingFunction(f) => f.get_name(agent).bind(gc),
             Function::BuiltinPromiseFinallyFunction(f) => f.get_name(agent).bind(gc),
-            _ => todo!(),
+            Function::BuiltinProxyRevokerFunction(f) => f.get_name(agent).bind(gc),
         }
     }
 }
@@ -118,7 +119,7 @@ impl core::fmt::Debug for Function<'_> {
             Self::BuiltinPromiseFinallyFunction(d) => {
                 write!(f, "BuiltinPromiseFinallyFunction({d:?})")
             }
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(d) => write!(f, "BuiltinProxyRevokerFunction({d:?})"),
         }
     }
 }
@@ -143,7 +144,7 @@ impl<'a> InternalSlots<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(d) => d.get_backing_object(agent),
             Function::BuiltinPromiseResolvingFunction(d) => d.get_backing_object(agent),
             Function::BuiltinPromiseFinallyFunction(d) => d.get_backing_object(agent),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(d) => d.get_backing_object(agent),
         }
     }
 
@@ -169,7 +170,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinPromiseFinallyFunction(x) => x.try_get_prototype_of(agent, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_get_prototype_of(agent, gc),
         }
     }
 
@@ -190,7 +191,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_set_prototype_of(agent, prototype, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_set_prototype_of(agent, prototype, gc)
+            }
         }
     }
 
@@ -206,7 +209,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinPromiseFinallyFunction(x) => x.try_is_extensible(agent, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_is_extensible(agent, gc),
         }
     }
 
@@ -222,7 +225,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinPromiseFinallyFunction(x) => x.try_prevent_extensions(agent, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_prevent_extensions(agent, gc),
         }
     }
 
@@ -248,7 +251,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_get_own_property(agent, property_key, cache, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_get_own_property(agent, property_key, cache, gc)
+            }
         }
     }
 
@@ -279,7 +284,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_define_own_property(agent, property_key, property_descriptor, cache, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_define_own_property(agent, property_key, property_descriptor, cache, gc)
+            }
         }
     }
 
@@ -303,7 +310,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_has_property(agent, property_key, cache, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_has_property(agent, property_key, cache, gc)
+            }
         }
     }
 
@@ -326,7 +335,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.internal_has_property(agent, property_key, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_has_property(agent, property_key, gc)
+            }
         }
     }
 
@@ -351,7 +362,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_get(agent, property_key, receiver, cache, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_get(agent, property_key, receiver, cache, gc)
+            }
         }
     }
 
@@ -375,7 +388,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.internal_get(agent, property_key, receiver, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_get(agent, property_key, receiver, gc)
+            }
         }
     }
 
@@ -407,7 +422,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.try_set(agent, property_key, value, receiver, cache, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_set(agent, property_key, value, receiver, cache, gc)
+            }
         }
     }
 
@@ -436,7 +453,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.internal_set(agent, property_key, value, receiver, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_set(agent, property_key, value, receiver, gc)
+            }
         }
     }
 
@@ -453,23 +472,32 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinConstructorFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinPromiseFinallyFunction(x) => x.try_delete(agent, property_key, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.try_delete(agent, property_key, gc),
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         match self {
-            Function::BoundFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::ECMAScriptFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinConstructorFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinPromiseResolvingFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinPromiseFinallyFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BoundFunction(x) => x.try_own_property_keys_into(agent, keys, gc),
+            Function::BuiltinFunction(x) => x.try_own_property_keys_into(agent, keys, gc),
+            Function::ECMAScriptFunction(x) => x.try_own_property_keys_into(agent, keys, gc),
+            Function::BuiltinConstructorFunction(x) => {
+                x.try_own_property_keys_into(agent, keys, gc)
+            }
+            Function::BuiltinPromiseResolvingFunction(x) => {
+                x.try_own_property_keys_into(agent, keys, gc)
+            }
+            Function::BuiltinPromiseFinallyFunction(x) => {
+                x.try_own_property_keys_into(agent, keys, gc)
+            }
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.try_own_property_keys_into(agent, keys, gc)
+            }
         }
     }
 
@@ -492,7 +520,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(f) => {
                 f.get_own_property_at_offset(agent, offset, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(f) => {
+                f.get_own_property_at_offset(agent, offset, gc)
+            }
         }
     }
 
@@ -512,7 +542,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
                 f.set_at_offset(agent, props, offset, gc)
             }
             Function::BuiltinPromiseFinallyFunction(f) => f.set_at_offset(agent, props, offset, gc),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(f) => f.set_at_offset(agent, props, offset, gc),
         }
     }
 
@@ -536,7 +566,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.internal_call(agent, this_argument, arguments, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_call(agent, this_argument, arguments, gc)
+            }
         }
     }
 
@@ -562,7 +594,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseFinallyFunction(x) => {
                 x.internal_construct(agent, arguments, new_target, gc)
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => {
+                x.internal_construct(agent, arguments, new_target, gc)
+            }
         }
     }
 }
@@ -576,7 +610,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinConstructorFunction(x) => x.mark_values(queues),
             Function::BuiltinPromiseResolvingFunction(x) => x.mark_values(queues),
             Function::BuiltinPromiseFinallyFunction(x) => x.mark_values(queues),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.mark_values(queues),
         }
     }
 
@@ -588,7 +622,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinConstructorFunction(x) => x.sweep_values(compactions),
             Function::BuiltinPromiseResolvingFunction(x) => x.sweep_values(compactions),
             Function::BuiltinPromiseFinallyFunction(x) => x.sweep_values(compactions),
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(x) => x.sweep_values(compactions),
         }
     }
 }
@@ -606,7 +640,7 @@ impl<'a> From<Function<'a>> for Value<'a> {
                 Self::BuiltinPromiseResolvingFunction(d)
             }
             Function::BuiltinPromiseFinallyFunction(d) => Self::BuiltinPromiseFinallyFunction(d),
-            Function::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Function::BuiltinProxyRevokerFunction(d) => Self::BuiltinProxyRevokerFunction(d),
         }
     }
 }
@@ -620,7 +654,7 @@ impl<'a> From<Function<'a>> for HeapRootData {
             Function::BuiltinConstructorFunction(d) => Self::from(d),
             Function::BuiltinPromiseResolvingFunction(d) => Self::from(d),
             Function::BuiltinPromiseFinallyFunction(d) => Self::from(d),
-            Function::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Function::BuiltinProxyRevokerFunction(d) => Self::from(d),
         }
     }
 }
@@ -635,7 +669,10 @@ impl<'a> TryFrom<Value<'a>> for Function<'a> {
             Value::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Self::BuiltinPromiseResolvingFunction(data))
             }
-            Value::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            Value::BuiltinPromiseFinallyFunction(data) => {
+                Ok(Self::BuiltinPromiseFinallyFunction(data))
+            }
+            Value::BuiltinProxyRevokerFunction(data) => Ok(Self::BuiltinProxyRevokerFunction(data)),
             _ => Err(()),
         }
     }
@@ -654,7 +691,12 @@ impl TryFrom<HeapRootData> for Function<'_> {
             HeapRootData::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Self::BuiltinPromiseResolvingFunction(data))
             }
-            HeapRootData::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinPromiseFinallyFunction(data) => {
+                Ok(Self::BuiltinPromiseFinallyFunction(data))
+            }
+            HeapRootData::BuiltinProxyRevokerFunction(data) => {
+                Ok(Self::BuiltinProxyRevokerFunction(data))
+            }
             _ => Err(()),
         }
     }
@@ -670,7 +712,7 @@ impl<'a> From<Function<'a>> for Object<'a> {
                 Self::BuiltinPromiseResolvingFunction(f)
             }
             Function::BuiltinPromiseFinallyFunction(f) => Self::BuiltinPromiseFinallyFunction(f),
-            Function::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Function::BuiltinProxyRevokerFunction(f) => Self::BuiltinProxyRevokerFunction(f),
         }
     }
 }
@@ -685,7 +727,12 @@ impl<'a> TryFrom<Object<'a>> for Function<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Self::BuiltinPromiseResolvingFunction(data))
             }
-            Object::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            Object::BuiltinPromiseFinallyFunction(data) => {
+                Ok(Self::BuiltinPromiseFinallyFunction(data))
+            }
+            Object::BuiltinProxyRevokerFunction(data) => {
+                Ok(Self::BuiltinProxyRevokerFunction(data))
+            }
             _ => Err(()),
         }
     }
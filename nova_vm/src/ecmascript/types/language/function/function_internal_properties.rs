@@ -9,8 +9,8 @@ use crate::{
         Object, OrdinaryObject, PropertyDescriptor, PropertyKey, PropertyLookupCache,
         ProtoIntrinsics, SetResult, String, TryGetResult, TryHasResult, TryResult, Value,
         js_result_into_try, ordinary_define_own_property, ordinary_delete,
-        ordinary_get_own_property, ordinary_has_property, ordinary_own_property_keys, ordinary_set,
-        ordinary_try_get, ordinary_try_has_property, ordinary_try_set, unwrap_try,
+        ordinary_get_own_property, ordinary_has_property, ordinary_own_property_keys_into,
+        ordinary_set, ordinary_try_get, ordinary_try_has_property, ordinary_try_set, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope},
     heap::{ObjectEntry, ObjectEntryPropertyDescriptor},
@@ -383,19 +383,19 @@ impl<'a, T: 'a + FunctionInternalProperties<'a>> InternalMethods<'a> for T {
         }
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+    ) -> TryResult<'gc, ()> {
         if let Some(backing_object) = self.get_backing_object(agent) {
-            TryResult::Continue(ordinary_own_property_keys(agent, backing_object, gc))
+            ordinary_own_property_keys_into(agent, backing_object, keys, gc);
         } else {
-            TryResult::Continue(vec![
-                BUILTIN_STRING_MEMORY.length.into(),
-                BUILTIN_STRING_MEMORY.name.into(),
-            ])
+            keys.push(BUILTIN_STRING_MEMORY.length.into());
+            keys.push(BUILTIN_STRING_MEMORY.name.into());
         }
+        TryResult::Continue(())
     }
 
     fn internal_call<'gc>(
@@ -6,11 +6,13 @@ mod data;
 mod radix;
 mod small_f64;
 mod small_integer;
+mod string_cache;
 
 pub(crate) use data::*;
 pub(crate) use radix::*;
 pub use small_f64::*;
 pub use small_integer::*;
+pub(crate) use string_cache::*;
 
 use super::{
     Numeric, Primitive, String, Value,
@@ -615,6 +617,18 @@ impl<'a> Number<'a> {
 
     /// ### [6.1.6.1.3 Number::exponentiate ( base, exponent )](https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate)
     pub fn exponentiate(agent: &mut Agent, base: Self, exponent: Self) -> Self {
+        // Nonstandard fast path: If both numbers are integers and the exponent
+        // is non-negative, use integer exponentiation and return the exact result
+        // if it is a safe integer.
+        if let (Self::Integer(base), Self::Integer(exponent)) = (base, exponent) {
+            let base = base.into_i64();
+            if let Ok(exponent) = u32::try_from(exponent.into_i64())
+                && let Some(result) = base.checked_pow(exponent)
+                && let Ok(result) = SmallInteger::try_from(result)
+            {
+                return result.into();
+            }
+        }
         // 1. If exponent is NaN, return NaN.
         if exponent.is_nan_(agent) {
             return Number::nan();
@@ -721,7 +735,7 @@ impl<'a> Number<'a> {
 
             // a. If abs(ℝ(base)) > 1, return +0𝔽.
             return if base > 1.0 {
-                Number::pos_inf()
+                Number::pos_zero()
             }
             // b. If abs(ℝ(base)) = 1, return NaN.
             else if base == 1.0 {
@@ -738,7 +752,7 @@ impl<'a> Number<'a> {
 
         // 12. If base < -0𝔽 and exponent is not an integral Number, return NaN.
         if Number::less_than(agent, base, Number::neg_zero()).unwrap_or(false)
-            && !exponent.is_odd_integer(agent)
+            && !exponent.is_integer_(agent)
         {
             return Number::nan();
         }
@@ -766,6 +780,10 @@ impl<'a> Number<'a> {
             let y = y.into_i64();
             let result = x.checked_mul(y);
             if let Some(result) = result {
+                if result == 0 && (x < 0 || y < 0) {
+                    // A zero multiplied by a negative number is -0.
+                    return Self::neg_zero();
+                }
                 if let Ok(result) = SmallInteger::try_from(result) {
                     return result.into();
                 }
@@ -927,6 +945,20 @@ impl<'a> Number<'a> {
     /// implied division of its operands where n is the dividend and d is the
     /// divisor.
     pub fn remainder(agent: &mut Agent, n: Self, d: Self, gc: NoGcScope<'a, '_>) -> Self {
+        // Nonstandard fast path: If both numbers are integers, use integer
+        // remainder. The result is always a safe integer.
+        if let (Self::Integer(n), Self::Integer(d)) = (n, d) {
+            let n = n.into_i64();
+            let d = d.into_i64();
+            if d != 0 {
+                let r = n % d;
+                // 10. If r = 0 and n < -0𝔽, return -0𝔽.
+                if r == 0 && n < 0 {
+                    return Self::neg_zero();
+                }
+                return SmallInteger::try_from(r).unwrap().into();
+            }
+        }
         // 1. If n is NaN or d is NaN, return NaN.
         if n.is_nan_(agent) || d.is_nan_(agent) {
             return Self::nan();
@@ -959,13 +991,12 @@ impl<'a> Number<'a> {
         let d = d.into_f64_(agent);
 
         // 7. Let quotient be ℝ(n) / ℝ(d).
-        let quotient = n / d;
-
         // 8. Let q be truncate(quotient).
-        let q = quotient.trunc();
-
         // 9. Let r be ℝ(n) - (ℝ(d) × q).
-        let r = n - (d * q);
+        // NOTE: The IEEE 754 remainder with truncating division (fmod) is
+        // exactly the mathematical value of r; computing it through a rounded
+        // quotient would lose precision for large n.
+        let r = n % d;
 
         // 10. If r = 0 and n < -0𝔽, return -0𝔽.
         if r == 0.0 && n.is_sign_negative() {
@@ -1350,6 +1381,21 @@ impl<'a> Number<'a> {
         x: Self,
         gc: NoGcScope<'gc, '_>,
     ) -> String<'gc> {
+        if !agent.number_string_cache.is_enabled() {
+            return Self::format_radix_10(agent, x, gc);
+        }
+        let bits = x.to_real(agent).to_bits();
+        if let Some(string) = agent.number_string_cache.get(bits) {
+            agent.gc_stats.number_string_cache_hits += 1;
+            return string.bind(gc);
+        }
+        agent.gc_stats.number_string_cache_misses += 1;
+        let string = Self::format_radix_10(agent, x, gc);
+        agent.number_string_cache.insert(bits, string.unbind());
+        string
+    }
+
+    fn format_radix_10<'gc>(agent: &mut Agent, x: Self, gc: NoGcScope<'gc, '_>) -> String<'gc> {
         match x {
             Number::Number(x) => {
                 let mut buffer = ryu_js::Buffer::new();
@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::String,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// Memoized results of Number::toString(x, 10).
+///
+/// Loops that build strings out of numbers, such as CSV or template literal
+/// generation, tend to convert the same few numbers over and over again. The
+/// cache is direct-mapped and keyed by the bits of the number's f64 value, so
+/// each conversion either hits its one slot or replaces whatever was there.
+#[derive(Debug)]
+pub(crate) struct NumberStringCache {
+    entries: Box<[Option<(u64, String<'static>)>]>,
+    /// Shift that turns a 64-bit hash into a slot index.
+    shift: u32,
+}
+
+impl NumberStringCache {
+    /// Create a cache with room for at least `capacity` entries. A capacity of
+    /// zero disables the cache.
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = if capacity == 0 {
+            0
+        } else {
+            capacity.next_power_of_two()
+        };
+        Self {
+            entries: vec![None; capacity].into_boxed_slice(),
+            shift: u64::BITS - capacity.trailing_zeros(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    #[inline]
+    fn slot(&self, bits: u64) -> usize {
+        // Note: Fibonacci hashing takes the slot index from the top bits of
+        // the product, which depend on all bits of the number. A single-entry
+        // cache shifts out every bit.
+        let hash = bits.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        hash.checked_shr(self.shift).unwrap_or(0) as usize
+    }
+
+    /// Get the cached string for the f64 value with the given bits.
+    ///
+    /// The cache must be enabled.
+    #[inline]
+    pub(crate) fn get(&self, bits: u64) -> Option<String<'static>> {
+        match self.entries[self.slot(bits)] {
+            Some((key, string)) if key == bits => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Cache the string for the f64 value with the given bits.
+    ///
+    /// The cache must be enabled.
+    #[inline]
+    pub(crate) fn insert(&mut self, bits: u64, string: String<'static>) {
+        let slot = self.slot(bits);
+        self.entries[slot] = Some((bits, string));
+    }
+}
+
+impl HeapMarkAndSweep for NumberStringCache {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        for (_, string) in self.entries.iter().flatten() {
+            string.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        for (_, string) in self.entries.iter_mut().flatten() {
+            string.sweep_values(compactions);
+        }
+    }
+}
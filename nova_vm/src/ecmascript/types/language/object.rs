@@ -14,7 +14,7 @@ pub(crate) use data::*;
 pub use internal_methods::*;
 pub use internal_slots::*;
 pub use property_key::*;
-pub(crate) use property_key_set::*;
+pub use property_key_set::*;
 pub(crate) use property_key_vec::*;
 pub(crate) use property_storage::*;
 
@@ -77,10 +77,10 @@ use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, ArrayIterator, AsyncGenerator, BoundFunction,
         BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMAScriptFunction, EmbedderObject, Error,
-        FinalizationRegistry, Generator, JsResult, Map, MapIterator, Module, ObjectShape,
-        ObjectShapeRecord, PrimitiveObject, Promise, PropertyDescriptor, PropertyLookupCache,
-        PropertyOffset, ProtoIntrinsics, Proxy, StringIterator, TryResult,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, ECMAScriptFunction,
+        EmbedderObject, Error, FinalizationRegistry, Generator, JsResult, Map, MapIterator, Module,
+        ObjectShape, ObjectShapeRecord, PrimitiveObject, Promise, PropertyDescriptor,
+        PropertyLookupCache, PropertyOffset, ProtoIntrinsics, Proxy, StringIterator, TryResult,
         ordinary_object_create_with_intrinsics,
     },
     engine::{Bindable, GcScope, HeapRootData, NoGcScope, bindable_handle},
@@ -129,8 +129,8 @@ pub enum Object<'a> {
     /// Special functions created as part of `Promise.prototype.finally`.
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    /// Placeholder.
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    /// Special functions created by `Proxy.revocable` to revoke a Proxy.
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) = BUILTIN_PROXY_REVOKER_FUNCTION,
     /// Primitive objects are special objects that hold a primitive value in their
     /// internal data.
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
@@ -670,17 +670,20 @@ impl<'a> OrdinaryObject<'a> {
         let PropertyStorageRef {
             keys, descriptors, ..
         } = source.unbind().get_property_storage(agent);
-        if descriptors.is_some_and(|d| d.iter().any(|(_, d)| !d.is_enumerable() || d.has_getter()))
-            || keys.iter().any(|k| k.is_symbol() || k.is_private_name())
+        if descriptors.is_some_and(|d| {
+            d.iter()
+                .any(|(_, d)| !d.is_enumerable() || d.is_accessor_descriptor())
+        }) || keys.iter().any(|k| k.is_symbol() || k.is_private_name())
         {
-            // Found a non-enumerable property, a getter, or a non-enumerable
-            // key. Cannot perform the copy.
+            // Found a non-enumerable property, an accessor, or a symbol or
+            // private name key. Cannot perform the copy.
             return false;
         }
-        // All properties in the source object are enumerable, none are
-        // getters, and no key is a symbol or private name: the shape of the
-        // source and the self objects will be identical after this operation.
-        let mut source_shape = self.get(agent).get_shape();
+        // All properties in the source object are enumerable data
+        // properties, and no key is a symbol or private name: the shape of
+        // the source and the self objects will be identical after this
+        // operation.
+        let mut source_shape = source.get(agent).get_shape();
         // Note: our source object can be frozen but we should not become
         // frozen just by copying the source properties.
         source_shape.set_extensible(true);
@@ -783,7 +786,7 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::BuiltinPromiseFinallyFunction(data) => {
                 Self::BuiltinPromiseFinallyFunction(data)
             }
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(data) => Self::BuiltinProxyRevokerFunction(data),
             Object::PrimitiveObject(data) => Self::PrimitiveObject(data),
             Object::Arguments(data) => Self::Arguments(data),
             Object::Array(data) => Self::Array(data),
@@ -932,7 +935,7 @@ macro_rules! object_delegate {
             Self::BuiltinConstructorFunction(data) => data.$method($($arg),+),
             Self::BuiltinPromiseResolvingFunction(data) => data.$method($($arg),+),
             Self::BuiltinPromiseFinallyFunction(data) => data.$method($($arg),+),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data.$method($($arg),+),
             Self::PrimitiveObject(data) => data.$method($($arg),+),
             Self::Arguments(data) => data.$method($($arg),+),
             Self::FinalizationRegistry(data) => data.$method($($arg),+),
@@ -1273,12 +1276,13 @@ impl<'a> InternalMethods<'a> for Object<'a> {
         object_delegate!(self, internal_delete, agent, property_key, gc)
     }
 
-    fn try_own_property_keys<'gc>(
+    fn try_own_property_keys_into<'gc>(
         self,
         agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
         gc: NoGcScope<'gc, '_>,
-    ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        object_delegate!(self, try_own_property_keys, agent, gc)
+    ) -> TryResult<'gc, ()> {
+        object_delegate!(self, try_own_property_keys_into, agent, keys, gc)
     }
 
     fn internal_own_property_keys<'gc>(
@@ -1379,7 +1383,9 @@ impl HeapSweepWeakReference for Object<'static> {
             Self::BuiltinPromiseFinallyFunction(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::BuiltinPromiseFinallyFunction),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::BuiltinProxyRevokerFunction),
             Self::PrimitiveObject(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::PrimitiveObject),
@@ -1554,7 +1560,7 @@ impl From<Object<'_>> for HeapRootData {
             Object::BuiltinConstructorFunction(d) => Self::from(d),
             Object::BuiltinPromiseResolvingFunction(d) => Self::from(d),
             Object::BuiltinPromiseFinallyFunction(d) => Self::from(d),
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(d) => Self::from(d),
             Object::PrimitiveObject(d) => Self::from(d),
             Object::Arguments(d) => Self::from(d),
             Object::Array(d) => Self::from(d),
@@ -1672,7 +1678,7 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::BuiltinConstructorFunction(f) => Ok(Self::BuiltinConstructorFunction(f)),
             HeapRootData::BuiltinPromiseResolvingFunction(f) => Ok(Self::from(f)),
             HeapRootData::BuiltinPromiseFinallyFunction(f) => Ok(Self::from(f)),
-            HeapRootData::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(f) => Ok(Self::from(f)),
             HeapRootData::PrimitiveObject(o) => Ok(Self::from(o)),
             HeapRootData::Arguments(o) => Ok(Self::from(o)),
             HeapRootData::Array(o) => Ok(Self::from(o)),
@@ -1777,6 +1783,7 @@ impl TryFrom<HeapRootData> for Object<'_> {
             | HeapRootData::Script(_)
             | HeapRootData::SourceCode(_)
             | HeapRootData::SourceTextModule(_)
+            | HeapRootData::SyntheticModule(_)
             | HeapRootData::DeclarativeEnvironment(_)
             | HeapRootData::FunctionEnvironment(_)
             | HeapRootData::GlobalEnvironment(_)
@@ -11,7 +11,7 @@ use crate::{
         PropertyOffset, Proxy, TryError, TryResult, Value, call_function, js_result_into_try,
         ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
         ordinary_get_prototype_of, ordinary_has_property, ordinary_is_extensible,
-        ordinary_own_property_keys, ordinary_prevent_extensions, ordinary_set,
+        ordinary_own_property_keys_into, ordinary_prevent_extensions, ordinary_set,
         ordinary_set_at_offset, ordinary_set_prototype_of, ordinary_try_get,
         ordinary_try_has_property, ordinary_try_set, throw_cannot_set_property, try_result_ok,
         unwrap_try,
@@ -474,11 +474,32 @@ where
         agent: &mut Agent,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
+        let mut keys = Vec::new();
+        self.try_own_property_keys_into(agent, &mut keys, gc)?;
+        TryResult::Continue(keys)
+    }
+
+    /// ## Infallible \[\[OwnPropertyKeys\]\] into a buffer
+    ///
+    /// This is a variant of [`try_own_property_keys`] that appends the keys
+    /// to the end of the given buffer instead of allocating a new List. This
+    /// allows hot enumeration paths to reuse a single buffer. If
+    /// [`TryError`] is returned, the contents of the buffer past its original
+    /// length are unspecified.
+    ///
+    /// [`try_own_property_keys`]: InternalMethods::try_own_property_keys
+    /// [`TryError`]: crate::ecmascript::TryError
+    fn try_own_property_keys_into<'gc>(
+        self,
+        agent: &mut Agent,
+        keys: &mut Vec<PropertyKey<'gc>>,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<'gc, ()> {
         // 1. Return OrdinaryOwnPropertyKeys(O).
-        TryResult::Continue(match self.get_backing_object(agent) {
-            Some(backing_object) => ordinary_own_property_keys(agent, backing_object, gc),
-            None => vec![],
-        })
+        if let Some(backing_object) = self.get_backing_object(agent) {
+            ordinary_own_property_keys_into(agent, backing_object, keys, gc);
+        }
+        TryResult::Continue(())
     }
 
     /// ## \[\[OwnPropertyKeys\]\]
@@ -773,7 +794,7 @@ pub(crate) fn call_proxy_set<'a>(
         let succeeded = succeeded.unbind()?;
         if !succeeded {
             // d. If succeeded is false and V.[[Strict]] is true, throw a TypeError exception.
-            let o = o.string_repr(agent, gc.reborrow()).unbind().bind(gc.nogc());
+            let o = o.try_string_repr(agent, gc.nogc());
             // SAFETY: not shared.
             let p = unsafe { scoped_p.take(agent) }.bind(gc.nogc());
             return Err(throw_cannot_set_property(
@@ -267,8 +267,9 @@ impl<'a> PropertyKey<'a> {
                 sym.hash(&mut hasher);
             }
             PropertyKey::String(s) => {
-                // Skip discriminant hashing in strings
-                s.get(agent).data.hash(&mut hasher);
+                // Skip discriminant hashing in strings. Heap strings are
+                // interned, so their cached hash identifies their contents.
+                s.get(agent).hash.hash(&mut hasher);
             }
             PropertyKey::SmallString(s) => {
                 s.as_wtf8().hash(&mut hasher);
@@ -366,11 +367,8 @@ impl<'a> From<String<'a>> for PropertyKey<'a> {
             String::SmallString(x) => {
                 // NOTE: Makes property keys slightly more correct by converting
                 // small strings to integers when possible.
-                if let Ok(n) = x.to_string_lossy().parse::<i64>() {
-                    return PropertyKey::Integer(SmallInteger::try_from(n).unwrap());
-                }
-
-                PropertyKey::SmallString(x)
+                parse_string_to_integer_property_key(&x.to_string_lossy())
+                    .unwrap_or(PropertyKey::SmallString(x))
             }
         }
     }
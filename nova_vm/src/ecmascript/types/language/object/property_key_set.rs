@@ -14,6 +14,10 @@ use crate::{
 };
 
 /// An unordered set of PropertyKeys.
+///
+/// The set holds PropertyKeys bound to a garbage collection scope. To keep a
+/// set alive across garbage collection, scope it with
+/// [`ScopableCollection::scope`].
 #[derive(Clone, Default)]
 #[repr(transparent)]
 pub struct PropertyKeySet<'a>(HashTable<PropertyKey<'a>>);
@@ -53,6 +57,59 @@ impl<'a> PropertyKeySet<'a> {
         let hash = value.heap_hash(agent);
         self.0.find(hash, |p| *p == value).is_some()
     }
+
+    /// Returns `true` if the set contains no PropertyKeys.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of PropertyKeys in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Remove a PropertyKey from the set. Returns `true` if the PropertyKey
+    /// was present in the set.
+    pub fn remove(&mut self, agent: &Agent, value: PropertyKey) -> bool {
+        let hash = value.heap_hash(agent);
+        match self.0.find_entry(hash, |p| *p == value) {
+            Ok(entry) => {
+                entry.remove();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Remove all PropertyKeys from the set.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Iterate over the PropertyKeys in the set in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = PropertyKey<'a>> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Insert all PropertyKeys from an iterator into the set.
+    pub fn extend(&mut self, agent: &Agent, values: impl IntoIterator<Item = PropertyKey<'a>>) {
+        let values = values.into_iter();
+        self.0.reserve(values.size_hint().0, |p| p.heap_hash(agent));
+        for value in values {
+            self.insert(agent, value);
+        }
+    }
+
+    /// Insert all PropertyKeys of `values` into the set, and remove from
+    /// `values` every PropertyKey that was already in the set or that appears
+    /// earlier in `values`. The order of the remaining PropertyKeys is
+    /// preserved.
+    ///
+    /// This is useful for merging lists of keys without duplicates.
+    pub fn retain_new(&mut self, agent: &Agent, values: &mut Vec<PropertyKey<'a>>) {
+        self.0.reserve(values.len(), |p| p.heap_hash(agent));
+        values.retain(|value| self.insert(agent, *value));
+    }
 }
 
 impl ScopableCollection for PropertyKeySet<'_> {
@@ -89,6 +146,18 @@ impl ScopedCollection<'_, PropertyKeySet<'static>> {
         };
         property_key_set.contains(agent, value)
     }
+
+    /// Returns `true` if the scoped set contains no PropertyKeys.
+    pub fn is_empty(&self, agent: &Agent) -> bool {
+        let stack_ref_collections = agent.stack_ref_collections.borrow();
+        let Some(stack_slot) = stack_ref_collections.get(self.inner as usize) else {
+            unreachable!();
+        };
+        let HeapRootCollection::PropertyKeySet(property_key_set) = stack_slot else {
+            unreachable!()
+        };
+        property_key_set.is_empty()
+    }
 }
 
 bindable_handle!(PropertyKeySet);
@@ -3,14 +3,16 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 include!(concat!(env!("OUT_DIR"), "/builtin_strings.rs"));
+mod builder;
 mod data;
 
+pub use builder::StringBuilder;
 pub(crate) use data::*;
 
 pub use small_string::SmallString;
 
 use core::hash::Hash;
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use crate::{
     ecmascript::{
@@ -572,13 +574,11 @@ impl<'a> String<'a> {
         Self::eq_(agent, x, y)
     }
 
-    pub(crate) fn eq_(agent: &impl StringHeapAccess, x: Self, y: Self) -> bool {
+    pub(crate) fn eq_(_agent: &impl StringHeapAccess, x: Self, y: Self) -> bool {
         match (x, y) {
-            (Self::String(x), Self::String(y)) => {
-                let x = &x.unbind().get(agent);
-                let y = &y.unbind().get(agent);
-                x == y
-            }
+            // Heap strings are interned, so equal heap strings always share
+            // the same handle.
+            (Self::String(x), Self::String(y)) => x == y,
             (Self::SmallString(x), Self::SmallString(y)) => x == y,
             // The string heap guarantees that small strings must never equal
             // heap strings.
@@ -586,6 +586,175 @@ impl<'a> String<'a> {
         }
     }
 
+    /// Compare two Strings lexicographically by their UTF-16 code units, as
+    /// done by the String comparison in IsLessThan.
+    ///
+    /// WTF-8 byte order agrees with code unit order up until the first
+    /// differing code point, so only the rest of the strings starting from
+    /// that code point are compared as code units.
+    pub(crate) fn cmp_code_units_(
+        self,
+        agent: &impl StringHeapAccess,
+        other: Self,
+    ) -> core::cmp::Ordering {
+        if self == other {
+            return core::cmp::Ordering::Equal;
+        }
+        let x = self.as_bytes_(agent);
+        let y = other.as_bytes_(agent);
+        let Some(mismatch) = x.iter().zip(y).position(|(a, b)| a != b) else {
+            return x.len().cmp(&y.len());
+        };
+        // Back up to the start of the differing code point: it is the same
+        // in both strings as their bytes are equal up to the mismatch.
+        let start = x[..=mismatch]
+            .iter()
+            .rposition(|b| b & 0xC0 != 0x80)
+            .unwrap_or(0);
+        let x = self.as_wtf8_(agent).slice_from(start).to_ill_formed_utf16();
+        let y = other
+            .as_wtf8_(agent)
+            .slice_from(start)
+            .to_ill_formed_utf16();
+        x.cmp(y)
+    }
+
+    /// ### [6.1.4.1 StringIndexOf ( string, searchValue, fromIndex )](https://tc39.es/ecma262/#sec-stringindexof)
+    ///
+    /// The abstract operation StringIndexOf takes arguments string (a String),
+    /// searchValue (a String), and fromIndex (a non-negative integer) and
+    /// returns a non-negative integer or not-found.
+    ///
+    /// The search is performed directly on the WTF-8 bytes of the strings.
+    pub(crate) fn index_of_(
+        self,
+        agent: &impl StringHeapAccess,
+        search_value: Self,
+        from_index: usize,
+    ) -> Option<usize> {
+        // 1. Let len be the length of string.
+        let len = self.utf16_len_(agent);
+        let search_bytes = search_value.as_bytes_(agent);
+        // 2. If searchValue is the empty String and fromIndex ≤ len, return
+        //    fromIndex.
+        if search_bytes.is_empty() {
+            return (from_index <= len).then_some(from_index);
+        }
+        if from_index >= len {
+            return None;
+        }
+        if needs_utf16_search(search_bytes) {
+            let haystack = self
+                .as_wtf8_(agent)
+                .to_ill_formed_utf16()
+                .collect::<Vec<_>>();
+            let needle = search_value
+                .as_wtf8_(agent)
+                .to_ill_formed_utf16()
+                .collect::<Vec<_>>();
+            return haystack[from_index..]
+                .windows(needle.len())
+                .position(|candidate| candidate == needle)
+                .map(|i| from_index + i);
+        }
+        // 3. Let searchLen be the length of searchValue.
+        // 4. For each integer i such that fromIndex ≤ i ≤ len - searchLen, in
+        //    ascending order, do
+        //    a. Let candidate be the substring of string from i to i + searchLen.
+        //    b. If candidate is searchValue, return i.
+        // 5. Return not-found.
+        // NOTE: If fromIndex points to the middle of a surrogate pair, the
+        // next possible match is at fromIndex + 1 as searchValue does not
+        // start with a trailing surrogate.
+        let utf8_start = self
+            .utf8_index_(agent, from_index)
+            .or_else(|| self.utf8_index_(agent, from_index + 1))?;
+        let utf8_index = memchr::memmem::find(&self.as_bytes_(agent)[utf8_start..], search_bytes)?;
+        Some(self.utf16_index_(agent, utf8_start + utf8_index))
+    }
+
+    /// ### [6.1.4.2 StringLastIndexOf ( string, searchValue, fromIndex )](https://tc39.es/ecma262/#sec-stringlastindexof)
+    ///
+    /// The abstract operation StringLastIndexOf takes arguments string (a
+    /// String), searchValue (a String), and fromIndex (a non-negative integer)
+    /// and returns a non-negative integer or not-found.
+    ///
+    /// The search is performed directly on the WTF-8 bytes of the strings.
+    pub(crate) fn last_index_of_(
+        self,
+        agent: &impl StringHeapAccess,
+        search_value: Self,
+        from_index: usize,
+    ) -> Option<usize> {
+        // 1. Let len be the length of string.
+        let len = self.utf16_len_(agent);
+        // 2. Let searchLen be the length of searchValue.
+        let search_len = search_value.utf16_len_(agent);
+        // 3. Assert: fromIndex + searchLen ≤ len.
+        debug_assert!(from_index + search_len <= len);
+        let search_bytes = search_value.as_bytes_(agent);
+        if search_bytes.is_empty() {
+            return Some(from_index);
+        }
+        if needs_utf16_search(search_bytes) {
+            let haystack = self
+                .as_wtf8_(agent)
+                .to_ill_formed_utf16()
+                .collect::<Vec<_>>();
+            let needle = search_value
+                .as_wtf8_(agent)
+                .to_ill_formed_utf16()
+                .collect::<Vec<_>>();
+            return haystack[..from_index + search_len]
+                .windows(search_len)
+                .rposition(|candidate| candidate == needle);
+        }
+        // 4. For each integer i such that 0 ≤ i ≤ fromIndex, in descending
+        //    order, do
+        //    a. Let candidate be the substring of string from i to i + searchLen.
+        //    b. If candidate is searchValue, return i.
+        // 5. Return not-found.
+        // NOTE: If fromIndex points to the middle of a surrogate pair, the
+        // previous possible match is at fromIndex - 1 as searchValue does not
+        // start with a trailing surrogate.
+        let utf8_start = self
+            .utf8_index_(agent, from_index)
+            .or_else(|| self.utf8_index_(agent, from_index - 1))?;
+        let bytes = self.as_bytes_(agent);
+        let utf8_end = (utf8_start + search_bytes.len()).min(bytes.len());
+        let utf8_index = memchr::memmem::rfind(&bytes[..utf8_end], search_bytes)?;
+        Some(self.utf16_index_(agent, utf8_index))
+    }
+
+    /// Returns true if the substring of this String starting at the UTF-16
+    /// index `start` begins with `search_value`.
+    pub(crate) fn starts_with_at_(
+        self,
+        agent: &impl StringHeapAccess,
+        search_value: Self,
+        start: usize,
+    ) -> bool {
+        let search_bytes = search_value.as_bytes_(agent);
+        if search_bytes.is_empty() {
+            return true;
+        }
+        if start + search_value.utf16_len_(agent) > self.utf16_len_(agent) {
+            return false;
+        }
+        if needs_utf16_search(search_bytes) {
+            return self
+                .as_wtf8_(agent)
+                .to_ill_formed_utf16()
+                .skip(start)
+                .zip(search_value.as_wtf8_(agent).to_ill_formed_utf16())
+                .all(|(a, b)| a == b);
+        }
+        // NOTE: A match cannot start in the middle of a surrogate pair as
+        // searchValue does not start with a trailing surrogate.
+        self.utf8_index_(agent, start)
+            .is_some_and(|utf8_start| self.as_bytes_(agent)[utf8_start..].starts_with(search_bytes))
+    }
+
     pub(crate) fn get_property_descriptor(
         self,
         agent: &mut Agent,
@@ -645,6 +814,18 @@ impl<'a> String<'a> {
     }
 }
 
+/// Returns true if searching for the WTF-8 bytes of a search value could miss
+/// matches that exist in the UTF-16 view of a string. This happens when the
+/// search value starts with a trailing surrogate or ends with a leading
+/// surrogate: these can match half of a surrogate pair, which WTF-8 encodes as
+/// a single four byte sequence.
+fn needs_utf16_search(search_bytes: &[u8]) -> bool {
+    // Lone surrogates are encoded as 0xED followed by 0xA0..=0xAF for leading
+    // and 0xB0..=0xBF for trailing surrogates, followed by one more byte.
+    matches!(search_bytes, [0xED, 0xB0..=0xBF, ..])
+        || matches!(search_bytes, [.., 0xED, 0xA0..=0xAF, _])
+}
+
 impl<'gc> String<'gc> {
     /// Create a [String] from a UTF-8 string slice.
     ///
@@ -709,11 +890,11 @@ impl<'gc> String<'gc> {
             match found {
                 Ok(string) => string,
                 Err(hash) => {
-                    let data = StringRecord::from_str(str);
+                    let data = StringRecord::from_str(str, hash);
                     // SAFETY: checked that the value is not found.
                     String::String(unsafe {
                         *alloc_counter += core::mem::size_of::<HeapString>();
-                        Self::insert_string_with_hash(strings, string_lookup_table, data, hash)
+                        Self::insert_string_with_hash(strings, string_lookup_table, data)
                     })
                     .bind(gc)
                 }
@@ -728,12 +909,12 @@ impl<'gc> String<'gc> {
         strings: &mut Vec<StringRecord>,
         string_lookup_table: &mut HashTable<HeapString<'static>>,
         data: StringRecord,
-        hash: u64,
     ) -> HeapString<'static> {
+        let hash = data.hash;
         strings.push(data);
         let index = BaseIndex::last(strings);
         let s = HeapString(index);
-        string_lookup_table.insert_unique(hash, s, |_| hash);
+        string_lookup_table.insert_unique(hash, s, |s| strings[s.get_index()].hash);
         s
     }
 
@@ -774,6 +955,40 @@ impl<'gc> String<'gc> {
         agent.heap.create(string).bind(gc)
     }
 
+    /// Create a [String] from WTF-16 code units.
+    ///
+    /// Surrogate pairs are joined and lone surrogates are preserved, so any
+    /// sequence of code units roundtrips through the String.
+    pub fn from_utf16(agent: &mut Agent, code_units: &[u16], gc: NoGcScope<'gc, '_>) -> Self {
+        if let Some(value) = SmallString::from_utf16(code_units) {
+            value.into()
+        } else {
+            agent
+                .heap
+                .create(Wtf8Buf::from_ill_formed_utf16(code_units))
+                .bind(gc)
+        }
+    }
+
+    /// Create a [String] from code points, which may include lone surrogates.
+    ///
+    /// A leading surrogate directly followed by a trailing surrogate is joined
+    /// into a single supplementary code point.
+    pub fn from_code_points(
+        agent: &mut Agent,
+        code_points: &[CodePoint],
+        gc: NoGcScope<'gc, '_>,
+    ) -> Self {
+        if let Some(value) = SmallString::from_code_points(code_points.iter().copied()) {
+            value.into()
+        } else {
+            agent
+                .heap
+                .create(code_points.iter().copied().collect::<Wtf8Buf>())
+                .bind(gc)
+        }
+    }
+
     /// Create a [String] from a statically allocated UTF-8 string slice.
     ///
     /// This does not copy the string data.
@@ -785,6 +1000,26 @@ impl<'gc> String<'gc> {
             unsafe { agent.heap.alloc_static_str(str) }
         }
     }
+
+    /// Create a [String] from UTF-8 data owned outside of the Agent's heap,
+    /// such as an `Arc<str>` or a memory-mapped file.
+    ///
+    /// This does not copy the string data: the heap keeps the data alive for
+    /// as long as the String lives. If an equal String already exists on the
+    /// heap, it is returned and the data is dropped. This is useful for
+    /// large script sources that the embedder also keeps in memory.
+    pub fn from_external(
+        agent: &mut Agent,
+        data: impl AsRef<str> + Send + Sync + 'static,
+        _gc: NoGcScope<'gc, '_>,
+    ) -> Self {
+        if let Ok(value) = String::try_from(data.as_ref()) {
+            value
+        } else {
+            // SAFETY: String couldn't be represented as a SmallString.
+            unsafe { agent.heap.alloc_external_str(Arc::new(data)) }
+        }
+    }
 }
 
 impl Scoped<'_, String<'static>> {
@@ -813,17 +1048,16 @@ impl Scoped<'_, String<'static>> {
     }
 }
 
-impl<'a> CreateHeapData<(StringRecord, u64), String<'a>> for Heap {
-    fn create(&mut self, (data, hash): (StringRecord, u64)) -> String<'a> {
+impl<'a> CreateHeapData<StringRecord, String<'a>> for Heap {
+    fn create(&mut self, data: StringRecord) -> String<'a> {
+        let hash = data.hash;
         self.strings.push(data);
         self.alloc_counter += core::mem::size_of::<StringRecord>();
         let index = BaseIndex::last(&self.strings);
         let s = HeapString(index);
         self.alloc_counter += core::mem::size_of::<HeapString>();
-        self.string_lookup_table.insert_unique(hash, s, |s| {
-            let s = self.strings[s.get_index()].as_wtf8();
-            self.string_hasher.hash_one(s)
-        });
+        self.string_lookup_table
+            .insert_unique(hash, s, |s| self.strings[s.get_index()].hash);
         String::String(s)
     }
 }
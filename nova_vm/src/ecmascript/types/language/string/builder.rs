@@ -0,0 +1,121 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use wtf8::{CodePoint, Wtf8, Wtf8Buf};
+
+use crate::{
+    ecmascript::{Agent, String},
+    engine::{Bindable, NoGcScope},
+    heap::CreateHeapData,
+};
+
+/// Builder for concatenating JavaScript strings.
+///
+/// The string data is collected into a single WTF-8 buffer which is moved
+/// into the heap as-is when the builder is finished, so building a string
+/// does not need any intermediate Rust strings. Finished strings of 7 bytes
+/// or fewer are stored as a [`SmallString`](super::SmallString) instead.
+///
+/// The builder does not hold on to any heap data, so it can be kept across
+/// calls that may trigger garbage collection.
+#[derive(Debug, Clone)]
+pub struct StringBuilder {
+    buf: Wtf8Buf,
+}
+
+impl Default for StringBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StringBuilder {
+    /// Create a new, empty StringBuilder.
+    pub fn new() -> Self {
+        Self {
+            buf: Wtf8Buf::new(),
+        }
+    }
+
+    /// Create a new, empty StringBuilder with space for at least `capacity`
+    /// bytes of WTF-8 data.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Wtf8Buf::with_capacity(capacity),
+        }
+    }
+
+    /// Byte length of the built string.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns true if nothing has been appended to the builder.
+    pub fn is_empty(&self) -> bool {
+        self.buf.len() == 0
+    }
+
+    /// Reserve space for at least `additional` more bytes of WTF-8 data.
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional);
+    }
+
+    /// The built string data so far.
+    pub fn as_wtf8(&self) -> &Wtf8 {
+        &self.buf
+    }
+
+    /// Append a JavaScript string.
+    pub fn push_string(&mut self, agent: &Agent, string: String) {
+        self.buf.push_wtf8(string.as_wtf8(agent));
+    }
+
+    /// Append the substring of `string` from the UTF-16 index `from` to `to`.
+    ///
+    /// Indexes that split a surrogate pair are handled by appending the lone
+    /// surrogate code units of the pair.
+    pub fn push_substring(&mut self, agent: &Agent, string: String, from: usize, to: usize) {
+        if from >= to {
+            return;
+        }
+        if let (Some(utf8_from), Some(utf8_to)) =
+            (string.utf8_index(agent, from), string.utf8_index(agent, to))
+        {
+            self.buf
+                .push_wtf8(string.as_wtf8(agent).slice(utf8_from, utf8_to));
+        } else {
+            for i in from..to {
+                self.buf.push(string.char_code_at(agent, i));
+            }
+        }
+    }
+
+    /// Append a UTF-8 string slice.
+    pub fn push_str(&mut self, str: &str) {
+        self.buf.push_str(str);
+    }
+
+    /// Append WTF-8 string data.
+    pub fn push_wtf8(&mut self, wtf8: &Wtf8) {
+        self.buf.push_wtf8(wtf8);
+    }
+
+    /// Append a single character.
+    pub fn push_char(&mut self, char: char) {
+        self.buf.push_char(char);
+    }
+
+    /// Append a single code point, which may be a lone surrogate.
+    ///
+    /// A trailing surrogate directly following a leading surrogate is joined
+    /// into a single supplementary code point.
+    pub fn push_code_point(&mut self, code_point: CodePoint) {
+        self.buf.push(code_point);
+    }
+
+    /// Finish building and create the JavaScript string.
+    pub fn finish<'gc>(self, agent: &mut Agent, gc: NoGcScope<'gc, '_>) -> String<'gc> {
+        agent.heap.create(self.buf).bind(gc)
+    }
+}
@@ -3,7 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use core::{cell::OnceCell, hash::Hash, num::NonZeroUsize};
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use wtf8::{CodePoint, Wtf8, Wtf8Buf};
 
@@ -13,6 +13,9 @@ use crate::heap::{CompactionLists, HeapMarkAndSweep, WorkQueues};
 pub(crate) struct StringRecord {
     pub(crate) data: StringBuffer,
     pub(crate) mapping: OnceCell<IndexMapping>,
+    /// Hash of the string data, as computed by the heap's string hasher when
+    /// the string was interned.
+    pub(crate) hash: u64,
 }
 
 impl PartialEq for StringRecord {
@@ -24,12 +27,7 @@ impl PartialEq for StringRecord {
         {
             return true;
         }
-        match (&self.data, &other.data) {
-            (StringBuffer::Owned(a), StringBuffer::Owned(b)) => a == b,
-            (StringBuffer::Owned(a), StringBuffer::Static(b)) => a == b,
-            (StringBuffer::Static(a), StringBuffer::Owned(b)) => a == b,
-            (StringBuffer::Static(a), StringBuffer::Static(b)) => a == b,
-        }
+        self.as_wtf8() == other.as_wtf8()
     }
 }
 impl Eq for StringRecord {}
@@ -50,6 +48,7 @@ pub(crate) enum IndexMapping {
 pub(crate) enum StringBuffer {
     Owned(Wtf8Buf),
     Static(&'static Wtf8),
+    External(ExternalStringBuffer),
 }
 
 impl Hash for StringBuffer {
@@ -57,10 +56,42 @@ impl Hash for StringBuffer {
         match self {
             StringBuffer::Owned(wtf8_buf) => wtf8_buf.hash(state),
             StringBuffer::Static(wtf8) => wtf8.hash(state),
+            StringBuffer::External(external) => external.data.hash(state),
+        }
+    }
+}
+
+/// String data owned outside of the heap, such as an `Arc<str>` or a
+/// memory-mapped file.
+#[derive(Clone)]
+pub(crate) struct ExternalStringBuffer {
+    /// Keeps the string data alive.
+    _owner: Arc<dyn AsRef<str> + Send + Sync>,
+    /// The string data borrowed from the owner.
+    data: &'static Wtf8,
+}
+
+impl ExternalStringBuffer {
+    pub(crate) fn new(owner: Arc<dyn AsRef<str> + Send + Sync>) -> Self {
+        let data: &Wtf8 = Wtf8::from_str((*owner).as_ref());
+        // SAFETY: The data is borrowed from the shared owner, which is never
+        // mutated or dropped while this buffer lives.
+        let data = unsafe { core::mem::transmute::<&Wtf8, &'static Wtf8>(data) };
+        Self {
+            _owner: owner,
+            data,
         }
     }
 }
 
+impl core::fmt::Debug for ExternalStringBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ExternalStringBuffer")
+            .field(&self.data)
+            .finish()
+    }
+}
+
 impl StringRecord {
     /// The maximum UTf-16 length of a JS string, according to the spec (2^53 - 1).
     const MAX_UTF16_LENGTH: usize = (1 << 53) - 1;
@@ -73,6 +104,7 @@ impl StringRecord {
         match &self.data {
             StringBuffer::Owned(buf) => buf.len(),
             StringBuffer::Static(buf) => buf.len(),
+            StringBuffer::External(buf) => buf.data.len(),
         }
     }
 
@@ -357,42 +389,60 @@ impl StringRecord {
         match &self.data {
             StringBuffer::Owned(buf) => buf,
             StringBuffer::Static(buf) => buf,
+            StringBuffer::External(buf) => buf.data,
         }
     }
 
-    pub(crate) fn from_str(str: &str) -> Self {
+    pub(crate) fn from_str(str: &str, hash: u64) -> Self {
         debug_assert!(str.len() > 7);
         assert!(str.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
         StringRecord {
             data: StringBuffer::Owned(Wtf8Buf::from_str(str)),
             mapping: OnceCell::new(),
+            hash,
         }
     }
 
-    pub(crate) fn from_static_str(str: &'static str) -> Self {
+    pub(crate) fn from_static_str(str: &'static str, hash: u64) -> Self {
         debug_assert!(str.len() > 7);
         assert!(str.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
         StringRecord {
             data: StringBuffer::Static(Wtf8::from_str(str)),
             mapping: OnceCell::new(),
+            hash,
         }
     }
 
-    pub(crate) fn from_string(str: String) -> Self {
+    pub(crate) fn from_string(str: String, hash: u64) -> Self {
         debug_assert!(str.len() > 7);
         assert!(str.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
         StringRecord {
             data: StringBuffer::Owned(Wtf8Buf::from_string(str)),
             mapping: OnceCell::new(),
+            hash,
+        }
+    }
+
+    pub(crate) fn from_external(str: ExternalStringBuffer, hash: u64) -> Self {
+        debug_assert!(str.data.len() > 7);
+        assert!(
+            str.data.len() <= Self::MAX_UTF8_LENGTH,
+            "String is too long."
+        );
+        StringRecord {
+            data: StringBuffer::External(str),
+            mapping: OnceCell::new(),
+            hash,
         }
     }
 
-    pub(crate) fn from_wtf8_buf(str: Wtf8Buf) -> Self {
+    pub(crate) fn from_wtf8_buf(str: Wtf8Buf, hash: u64) -> Self {
         debug_assert!(str.len() > 7);
         assert!(str.len() <= Self::MAX_UTF8_LENGTH, "String is too long.");
         StringRecord {
             data: StringBuffer::Owned(str),
             mapping: OnceCell::new(),
+            hash,
         }
     }
 }
@@ -402,6 +452,7 @@ impl HeapMarkAndSweep for StringRecord {
         let Self {
             data: _,
             mapping: _,
+            hash: _,
         } = self;
     }
 
@@ -409,6 +460,7 @@ impl HeapMarkAndSweep for StringRecord {
         let Self {
             data: _,
             mapping: _,
+            hash: _,
         } = self;
     }
 }
@@ -33,9 +33,10 @@ use num_bigint::Sign;
 
 use crate::{
     ecmascript::{
-        Agent, BigInt, ExceptionType, JsResult, Number, Numeric, Value, to_big_int64_big_int,
-        to_big_uint64_big_int, to_int8_number, to_int16_number, to_int32_number,
-        to_uint8_clamp_number, to_uint8_number, to_uint16_number, to_uint32_number,
+        Agent, BigInt, ExceptionType, JsError, JsResult, Number, Numeric, Value,
+        to_big_int64_big_int, to_big_uint64_big_int, to_int8_number, to_int16_number,
+        to_int32_number, to_uint8_clamp_number, to_uint8_number, to_uint16_number,
+        to_uint32_number,
     },
     engine::{NoGcScope, trivially_bindable},
     heap::ArenaAccess,
@@ -60,6 +61,11 @@ pub(crate) struct DataBlock {
 }
 trivially_bindable!(DataBlock);
 
+// SAFETY: The DataBlock uniquely owns its allocation, like a Box<[u8]>.
+unsafe impl Send for DataBlock {}
+// SAFETY: Shared references only give shared access to the bytes.
+unsafe impl Sync for DataBlock {}
+
 impl core::fmt::Debug for DataBlock {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(ptr) = self.ptr {
@@ -180,17 +186,24 @@ impl DataBlock {
 
     #[allow(unused)]
     pub(crate) fn get<T: Viewable>(&self, offset: usize) -> Option<T> {
-        let size = core::mem::size_of::<T>();
-        let byte_offset = offset * size;
-        if byte_offset >= self.byte_length {
-            None
-        } else {
-            self.ptr.map(|data| {
-                // SAFETY: The data is properly initialized, and the T being read is
-                // checked to be fully within the length of the data allocation.
-                unsafe { read_unaligned(data.as_ptr().add(offset).cast()) }
-            })
+        let byte_offset = offset.checked_mul(core::mem::size_of::<T>())?;
+        self.get_offset_by_byte(byte_offset)
+    }
+
+    /// Read a T from the buffer at `byte_offset`.
+    ///
+    /// Returns `None` if the buffer is detached or the T would not fit
+    /// entirely within the buffer.
+    pub(crate) fn get_offset_by_byte<T: Viewable>(&self, byte_offset: usize) -> Option<T> {
+        let end_byte_offset = byte_offset.checked_add(core::mem::size_of::<T>())?;
+        if end_byte_offset > self.byte_length {
+            return None;
         }
+        self.ptr.map(|data| {
+            // SAFETY: The data is properly initialized, and the T being read is
+            // checked to be fully within the length of the data allocation.
+            unsafe { read_unaligned(data.as_ptr().byte_add(byte_offset).cast()) }
+        })
     }
 
     /// Read a T from the buffer at `byte_offset`.
@@ -237,25 +250,24 @@ impl DataBlock {
 
     #[allow(unused)]
     pub(crate) fn set<T: Viewable>(&mut self, offset: usize, value: T) {
-        let size = core::mem::size_of::<T>();
-        if let Some(data) = self.ptr {
-            // Note: We have to check offset + 1 to ensure that the write does
-            // not reach data beyond the end of the DataBlock allocation.
-            let end_byte_offset = (offset + 1) * size;
-            if end_byte_offset <= self.byte_length {
-                // SAFETY: The data is properly initialized, and the T being written is
-                // checked to be fully within the length of the data allocation.
-                unsafe { write_unaligned(data.as_ptr().add(offset).cast(), value) }
-            }
+        if let Some(byte_offset) = offset.checked_mul(core::mem::size_of::<T>()) {
+            self.set_offset_by_byte(byte_offset, value);
         }
     }
 
+    /// Write a T into the buffer at `byte_offset`.
+    ///
+    /// Does nothing if the buffer is detached or the T would not fit entirely
+    /// within the buffer.
     pub(crate) fn set_offset_by_byte<T: Viewable>(&mut self, byte_offset: usize, value: T) {
         let size = core::mem::size_of::<T>();
         if let Some(data) = self.ptr {
-            // Note: We have to check offset + 1 to ensure that the write does
-            // not reach data beyond the end of the DataBlock allocation.
-            let end_byte_offset = byte_offset + size;
+            // Note: We have to check the end of the write to ensure that it
+            // does not reach data beyond the end of the DataBlock allocation.
+            // Huge offsets must not wrap around to a seemingly valid end.
+            let Some(end_byte_offset) = byte_offset.checked_add(size) else {
+                return;
+            };
             if end_byte_offset <= self.byte_length {
                 // SAFETY: The data is properly initialized, and the T being written is
                 // checked to be fully within the length of the data allocation.
@@ -1177,18 +1189,10 @@ pub(crate) fn create_byte_data_block<'a>(
     size: u64,
     gc: NoGcScope<'a, '_>,
 ) -> JsResult<'a, DataBlock> {
+    agent.check_data_block_allocation(size, gc)?;
     // 1. If size > 2**53 - 1, throw a RangeError exception.
-    if let Some(db) = usize::try_from(size)
-        .ok()
-        .and_then(|size| {
-            if size as u64 > DATA_BLOCK_SIZE_LIMIT {
-                None
-            } else {
-                Some(size)
-            }
-        })
-        .and_then(DataBlock::new)
-    {
+    if let Some(db) = new_byte_data_block(size) {
+        agent.record_data_block_allocation(db.len());
         // 2. Let db be a new Data Block value consisting of size bytes.
         // 3. Set all of the bytes of db to 0.
         // 4. Return db.
@@ -1196,14 +1200,45 @@ pub(crate) fn create_byte_data_block<'a>(
     } else {
         // 2. cont: If it is impossible to create such a Data Block, throw a
         //    RangeError exception.
-        Err(agent.throw_exception_with_static_message(
-            ExceptionType::RangeError,
-            "Failed to allocate ArrayBuffer",
-            gc,
-        ))
+        Err(throw_failed_to_allocate(agent, gc))
+    }
+}
+
+/// CreateByteDataBlock for a resizable ArrayBuffer: throws a RangeError if a
+/// Data Block of `max_size` bytes cannot be created, but returns a Data Block
+/// of `size` bytes. Only `size` bytes count towards the Agent's memory limit.
+pub(crate) fn create_resizable_byte_data_block<'a>(
+    agent: &mut Agent,
+    size: u64,
+    max_size: u64,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DataBlock> {
+    agent.check_data_block_allocation(size, gc)?;
+    let Some(mut db) = new_byte_data_block(max_size) else {
+        return Err(throw_failed_to_allocate(agent, gc));
+    };
+    db.realloc(size as usize);
+    agent.record_data_block_allocation(db.len());
+    Ok(db)
+}
+
+fn new_byte_data_block(size: u64) -> Option<DataBlock> {
+    if size > DATA_BLOCK_SIZE_LIMIT {
+        None
+    } else {
+        DataBlock::new(usize::try_from(size).ok()?)
     }
 }
 
+#[cold]
+fn throw_failed_to_allocate<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::RangeError,
+        "Failed to allocate ArrayBuffer",
+        gc,
+    )
+}
+
 /// ### [6.2.9.2 CreateSharedByteDataBlock ( size )](https://tc39.es/ecma262/#sec-createsharedbytedatablock)
 ///
 /// The abstract operation CreateSharedByteDataBlock takes argument size (a
@@ -1225,6 +1260,7 @@ pub(crate) unsafe fn create_shared_byte_data_block<'a>(
     unsafe {
         assert_unchecked(byte_length <= size);
     }
+    agent.check_data_block_allocation(size, gc)?;
     // 1. Let db be a new Shared Data Block value consisting of size bytes. If
     //    it is impossible to create such a Shared Data Block, throw a
     //    RangeError exception.
@@ -1255,6 +1291,7 @@ pub(crate) unsafe fn create_shared_byte_data_block<'a>(
         //    [[Block]]: db, [[ByteIndex]]: i, [[ElementSize]]: 1,
         //    [[Payload]]: zero } to eventsRecord.[[EventList]].
         // 6. Return db.
+        agent.record_data_block_allocation(size as usize);
         Ok(db)
     } else {
         // 2. cont: If it is impossible to create such a Data Block, throw a
@@ -1465,7 +1502,8 @@ pub(crate) trait Viewable:
     /// Convert a Viewable value into a u64 holding an integer.
     ///
     /// This is used to convert Viewables to other Viewables without having to
-    /// go through a conversion into Value.
+    /// go through a conversion into Value. Signed integers are sign-extended,
+    /// so the result can be reinterpreted as an i64.
     fn into_bits(self) -> u64;
     /// Convert a u64 holding an integer into a Viewable.
     ///
@@ -1754,7 +1792,8 @@ impl Viewable for U8Clamped {
     }
 
     fn from_bits(bits: u64) -> Self {
-        U8Clamped(bits.clamp(0, 255) as u8)
+        // Note: Negative integers from signed types clamp to 0.
+        U8Clamped((bits as i64).clamp(0, 255) as u8)
     }
 
     #[inline(always)]
@@ -3131,6 +3170,16 @@ mod tests {
         assert_eq!(db.get::<u8>(7), Some(8));
     }
 
+    #[test]
+    fn u8_clamped_from_bits() {
+        use super::{U8Clamped, Viewable};
+        assert_eq!(U8Clamped::from_bits((-1i8).into_bits()), U8Clamped(0));
+        assert_eq!(U8Clamped::from_bits((-70000i32).into_bits()), U8Clamped(0));
+        assert_eq!(U8Clamped::from_bits(300u16.into_bits()), U8Clamped(255));
+        assert_eq!(U8Clamped::from_bits(u32::MAX.into_bits()), U8Clamped(255));
+        assert_eq!(U8Clamped::from_bits(7u8.into_bits()), U8Clamped(7));
+    }
+
     #[test]
     #[cfg(feature = "shared-array-buffer")]
     fn new_shared_data_block() {
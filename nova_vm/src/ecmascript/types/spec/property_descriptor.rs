@@ -156,6 +156,56 @@ impl<'a> PropertyDescriptor<'a> {
         Self::non_enumerable_data_descriptor(function.into())
     }
 
+    /// Start building a data descriptor with the given value.
+    ///
+    /// The descriptor is non-writable, non-enumerable, and non-configurable
+    /// unless otherwise specified.
+    ///
+    /// ```rust,ignore
+    /// let desc = PropertyDescriptor::data(value).writable().enumerable().build();
+    /// ```
+    pub fn data(value: impl Into<Value<'a>>) -> DataDescriptorBuilder<'a> {
+        DataDescriptorBuilder {
+            value: value.into(),
+            writable: false,
+            enumerable: false,
+            configurable: false,
+        }
+    }
+
+    /// Start building an accessor descriptor.
+    ///
+    /// The descriptor has no getter or setter and is non-enumerable and
+    /// non-configurable unless otherwise specified.
+    ///
+    /// ```rust,ignore
+    /// let desc = PropertyDescriptor::accessor().getter(get).configurable().build();
+    /// ```
+    pub fn accessor() -> AccessorDescriptorBuilder<'a> {
+        AccessorDescriptorBuilder {
+            get: None,
+            set: None,
+            enumerable: false,
+            configurable: false,
+        }
+    }
+
+    /// Check that the descriptor does not have both data fields (\[\[Value]]
+    /// or \[\[Writable]]) and accessor fields (\[\[Get]] or \[\[Set]]).
+    ///
+    /// Such descriptors cannot be created from JavaScript, as
+    /// [ToPropertyDescriptor] throws a TypeError for them, and must not be
+    /// passed to the object internal methods.
+    ///
+    /// [ToPropertyDescriptor]: PropertyDescriptor::to_property_descriptor
+    pub fn validate(&self) -> Result<(), InvalidPropertyDescriptor> {
+        if self.is_data_descriptor() && self.is_accessor_descriptor() {
+            Err(InvalidPropertyDescriptor)
+        } else {
+            Ok(())
+        }
+    }
+
     /// ### [6.2.6.1 IsAccessorDescriptor ( Desc )](https://tc39.es/ecma262/#sec-isaccessordescriptor)
     pub fn is_accessor_descriptor(&self) -> bool {
         // 1. If Desc is undefined, return false.
@@ -295,7 +345,7 @@ impl<'a> PropertyDescriptor<'a> {
 
         // 1. If Obj is not an Object, throw a TypeError exception.
         let Ok(obj) = Object::try_from(obj) else {
-            let obj_repr = obj.unbind().string_repr(agent, gc.reborrow());
+            let obj_repr = obj.unbind().try_string_repr(agent, gc.nogc());
             let error_message = format!(
                 "Property descriptor must be an object, got '{}'.",
                 obj_repr.to_string_lossy_(agent)
@@ -739,3 +789,148 @@ impl<'a> PropertyDescriptor<'a> {
 }
 
 bindable_handle!(PropertyDescriptor);
+
+/// Builder for data property descriptors.
+///
+/// See [`PropertyDescriptor::data`].
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct DataDescriptorBuilder<'a> {
+    value: Value<'a>,
+    writable: bool,
+    enumerable: bool,
+    configurable: bool,
+}
+
+impl<'a> DataDescriptorBuilder<'a> {
+    /// Make the property writable.
+    pub fn writable(self) -> Self {
+        self.with_writable(true)
+    }
+
+    /// Make the property enumerable.
+    pub fn enumerable(self) -> Self {
+        self.with_enumerable(true)
+    }
+
+    /// Make the property configurable.
+    pub fn configurable(self) -> Self {
+        self.with_configurable(true)
+    }
+
+    /// Set the \[\[Writable]] attribute of the property.
+    pub fn with_writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+
+    /// Set the \[\[Enumerable]] attribute of the property.
+    pub fn with_enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = enumerable;
+        self
+    }
+
+    /// Set the \[\[Configurable]] attribute of the property.
+    pub fn with_configurable(mut self, configurable: bool) -> Self {
+        self.configurable = configurable;
+        self
+    }
+
+    /// Build the fully populated data descriptor.
+    pub fn build(self) -> PropertyDescriptor<'a> {
+        PropertyDescriptor {
+            value: Some(self.value),
+            writable: Some(self.writable),
+            get: None,
+            set: None,
+            enumerable: Some(self.enumerable),
+            configurable: Some(self.configurable),
+        }
+    }
+}
+
+impl<'a> From<DataDescriptorBuilder<'a>> for PropertyDescriptor<'a> {
+    fn from(value: DataDescriptorBuilder<'a>) -> Self {
+        value.build()
+    }
+}
+
+/// Builder for accessor property descriptors.
+///
+/// See [`PropertyDescriptor::accessor`].
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct AccessorDescriptorBuilder<'a> {
+    get: Option<Function<'a>>,
+    set: Option<Function<'a>>,
+    enumerable: bool,
+    configurable: bool,
+}
+
+impl<'a> AccessorDescriptorBuilder<'a> {
+    /// Set the \[\[Get]] function of the property.
+    pub fn getter(mut self, get: impl Into<Function<'a>>) -> Self {
+        self.get = Some(get.into());
+        self
+    }
+
+    /// Set the \[\[Set]] function of the property.
+    pub fn setter(mut self, set: impl Into<Function<'a>>) -> Self {
+        self.set = Some(set.into());
+        self
+    }
+
+    /// Make the property enumerable.
+    pub fn enumerable(self) -> Self {
+        self.with_enumerable(true)
+    }
+
+    /// Make the property configurable.
+    pub fn configurable(self) -> Self {
+        self.with_configurable(true)
+    }
+
+    /// Set the \[\[Enumerable]] attribute of the property.
+    pub fn with_enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = enumerable;
+        self
+    }
+
+    /// Set the \[\[Configurable]] attribute of the property.
+    pub fn with_configurable(mut self, configurable: bool) -> Self {
+        self.configurable = configurable;
+        self
+    }
+
+    /// Build the fully populated accessor descriptor. A missing getter or
+    /// setter is explicitly set to undefined.
+    pub fn build(self) -> PropertyDescriptor<'a> {
+        PropertyDescriptor {
+            value: None,
+            writable: None,
+            get: Some(self.get),
+            set: Some(self.set),
+            enumerable: Some(self.enumerable),
+            configurable: Some(self.configurable),
+        }
+    }
+}
+
+impl<'a> From<AccessorDescriptorBuilder<'a>> for PropertyDescriptor<'a> {
+    fn from(value: AccessorDescriptorBuilder<'a>) -> Self {
+        value.build()
+    }
+}
+
+/// Error returned by [`PropertyDescriptor::validate`] for descriptors that
+/// have both data and accessor fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPropertyDescriptor;
+
+impl core::fmt::Display for InvalidPropertyDescriptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Property descriptor has both data and accessor fields")
+    }
+}
+
+impl std::error::Error for InvalidPropertyDescriptor {}
@@ -566,54 +566,58 @@ fn handle_primitive_get_value<'a>(
     value: Value,
     gc: GcScope<'a, '_>,
 ) -> JsResult<'a, Value<'a>> {
+    let referenced_name = referenced_name.bind(gc.nogc());
+    let value = value.bind(gc.nogc());
     // Primitive value. annoying stuff.
     if referenced_name.is_private_name() {
         // i. Return ? PrivateGet(baseObj, V.[[ReferencedName]]).
         return Err(throw_no_private_name_error(agent, gc.into_nogc()));
     }
+    if value.is_undefined() || value.is_null() {
+        return Err(throw_read_undefined_or_null_error(
+            agent,
+            // SAFETY: We do not care about the conversion validity in
+            // error message logging.
+            unsafe { referenced_name.into_value_unchecked() }.unbind(),
+            value.unbind(),
+            gc.into_nogc(),
+        ));
+    }
+    if let Ok(string) = String::try_from(value)
+        && let Some(prop_desc) = string.get_property_descriptor(agent, referenced_name)
+    {
+        return Ok(prop_desc.value.unwrap());
+    }
+    let prototype = primitive_prototype(agent, value);
+    // Note: Primitive prototype lookups mostly target the prototype's own
+    // methods, so the property lookup cache of the key is likely to already
+    // know the offset of the property in the prototype's shape.
+    let cache = PropertyLookupCache::get(agent, referenced_name);
+    match prototype.try_get(agent, referenced_name, value, cache, gc.nogc()) {
+        ControlFlow::Continue(TryGetResult::Unset) => Ok(Value::Undefined),
+        ControlFlow::Continue(TryGetResult::Value(v)) => Ok(v.unbind().bind(gc.into_nogc())),
+        ControlFlow::Break(TryError::Err(err)) => Err(err.unbind().bind(gc.into_nogc())),
+        _ => prototype
+            .unbind()
+            .internal_get(agent, referenced_name.unbind(), value.unbind(), gc),
+    }
+}
+
+/// Get the intrinsic prototype object of the current Realm that property
+/// lookups on a non-nullish primitive value are performed on.
+///
+/// > NOTE: This avoids performing ToObject on the primitive value and thus
+/// > never allocates a wrapper object.
+fn primitive_prototype<'a>(agent: &Agent, value: Value) -> Object<'a> {
+    let intrinsics = agent.current_realm_record().intrinsics();
     match value {
-        Value::Undefined | Value::Null => {
-            Err(throw_read_undefined_or_null_error(
-                agent,
-                // SAFETY: We do not care about the conversion validity in
-                // error message logging.
-                unsafe { referenced_name.into_value_unchecked() },
-                value,
-                gc.into_nogc(),
-            ))
+        Value::Boolean(_) => intrinsics.boolean_prototype().into(),
+        Value::String(_) | Value::SmallString(_) => intrinsics.string_prototype().into(),
+        Value::Symbol(_) => intrinsics.symbol_prototype().into(),
+        Value::Number(_) | Value::Integer(_) | Value::SmallF64(_) => {
+            intrinsics.number_prototype().into()
         }
-        Value::Boolean(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .boolean_prototype()
-            .internal_get(agent, referenced_name.unbind(), value, gc),
-        Value::String(_) | Value::SmallString(_) => {
-            let string = String::try_from(value).unwrap();
-            if let Some(prop_desc) = string.get_property_descriptor(agent, referenced_name) {
-                Ok(prop_desc.value.unwrap())
-            } else {
-                agent
-                    .current_realm_record()
-                    .intrinsics()
-                    .string_prototype()
-                    .internal_get(agent, referenced_name.unbind(), value, gc)
-            }
-        }
-        Value::Symbol(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .symbol_prototype()
-            .internal_get(agent, referenced_name.unbind(), value, gc),
-        Value::Number(_) | Value::Integer(_) | Value::SmallF64(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .number_prototype()
-            .internal_get(agent, referenced_name.unbind(), value, gc),
-        Value::BigInt(_) | Value::SmallBigInt(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .big_int_prototype()
-            .internal_get(agent, referenced_name.unbind(), value, gc),
+        Value::BigInt(_) | Value::SmallBigInt(_) => intrinsics.big_int_prototype().into(),
         _ => unreachable!(),
     }
 }
@@ -651,51 +655,26 @@ fn try_handle_primitive_get_value<'a>(
         return throw_no_private_name_error(agent, gc).into();
     }
     // Primitive value. annoying stuff.
-    let prototype: Object = match receiver {
-        Value::Undefined | Value::Null => {
-            return throw_read_undefined_or_null_error(
-                agent,
-                // SAFETY: We do not care about the conversion validity in
-                // error message logging.
-                unsafe { referenced_name.into_value_unchecked() },
-                receiver,
-                gc,
-            )
-            .into();
-        }
-        Value::Boolean(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .boolean_prototype()
-            .into(),
-        Value::String(_) | Value::SmallString(_) => {
-            let string = String::try_from(receiver).unwrap();
-            if let Some(prop_desc) = string.get_property_descriptor(agent, referenced_name) {
-                return TryGetValueContinue::Value(prop_desc.value.unwrap()).into();
-            }
-            agent
-                .current_realm_record()
-                .intrinsics()
-                .string_prototype()
-                .into()
-        }
-        Value::Symbol(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .symbol_prototype()
-            .into(),
-        Value::Number(_) | Value::Integer(_) | Value::SmallF64(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .number_prototype()
-            .into(),
-        Value::BigInt(_) | Value::SmallBigInt(_) => agent
-            .current_realm_record()
-            .intrinsics()
-            .big_int_prototype()
-            .into(),
-        _ => unreachable!(),
-    };
+    if receiver.is_undefined() || receiver.is_null() {
+        return throw_read_undefined_or_null_error(
+            agent,
+            // SAFETY: We do not care about the conversion validity in
+            // error message logging.
+            unsafe { referenced_name.into_value_unchecked() },
+            receiver,
+            gc,
+        )
+        .into();
+    }
+    if let Ok(string) = String::try_from(receiver)
+        && let Some(prop_desc) = string.get_property_descriptor(agent, referenced_name)
+    {
+        return TryGetValueContinue::Value(prop_desc.value.unwrap()).into();
+    }
+    let prototype = primitive_prototype(agent, receiver);
+    // Note: computed property accesses do not carry a property lookup cache;
+    // fall back to the cache of the key, if one exists.
+    let cache = cache.or_else(|| PropertyLookupCache::get(agent, referenced_name));
     prototype
         .try_get(agent, referenced_name, receiver, cache, gc)
         .map_continue(|c| TryGetValueContinue::from_get_continue(c, receiver, referenced_name))
@@ -938,12 +917,8 @@ pub(crate) fn put_value<'a>(
             if !succeeded && let Some(scoped_referenced_name) = scoped_referenced_name {
                 // throw a TypeError exception.
                 // SAFETY: not shared.
-                let base_obj_repr = unsafe {
-                    Value::from(base_obj.take(agent))
-                        .string_repr(agent, gc.reborrow())
-                        .unbind()
-                        .bind(gc.nogc())
-                };
+                let base_obj_repr =
+                    unsafe { Value::from(base_obj.take(agent)).try_string_repr(agent, gc.nogc()) };
                 // SAFETY: not shared.
                 let referenced_name = unsafe { scoped_referenced_name.take(agent) }.bind(gc.nogc());
                 return Err(throw_cannot_set_property(
@@ -1006,10 +981,7 @@ pub(crate) fn put_value<'a>(
                 // d. If succeeded is false and V.[[Strict]] is true, throw a TypeError exception.
                 // SAFETY: not shared.
                 let base_obj_repr = unsafe {
-                    Value::from(scoped_base_obj.take(agent))
-                        .string_repr(agent, gc.reborrow())
-                        .unbind()
-                        .bind(gc.nogc())
+                    Value::from(scoped_base_obj.take(agent)).try_string_repr(agent, gc.nogc())
                 };
                 // SAFETY: not shared.
                 let referenced_name = unsafe { scoped_referenced_name.take(agent) }.bind(gc.nogc());
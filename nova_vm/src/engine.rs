@@ -52,6 +52,12 @@
 //! another value of the same type without any assignment, or the engine crashes
 //! from an out-of-bounds memory access.
 //!
+//! Handles that must be unbound for a while, such as values kept across a
+//! call that takes a [`GcScope`], can be unbound with the
+//! [`TrackUnrooted::unbind_tracked`] method instead. In debug builds, garbage
+//! collection then panics with the location of the unbind if the handle was
+//! not bound again or rooted before the collection.
+//!
 //! [`Object`]: crate::ecmascript::Object
 //! [`Value`]: crate::ecmascript::Value
 
@@ -102,6 +102,19 @@ impl<'s, 'gc> Place<'s, 'gc> {
         )
     }
 
+    /// Returns true if the place may refer to a declarative binding or an
+    /// Array element that can be updated in place through its Reference.
+    #[inline]
+    fn may_update_in_place(&self) -> bool {
+        matches!(
+            self,
+            Self::Env { .. }
+                | Self::Member {
+                    name: None | Some(PropertyKey::Integer(_))
+                }
+        )
+    }
+
     fn initialise_referenced_binding_to_undefined(&self, ctx: &mut CompileContext) {
         match self {
             Place::Env { .. } | Place::Global { .. } | Place::Member { .. } => {
@@ -589,6 +602,10 @@ fn variable_escapes_scope(
     identifier: &oxc_ast::ast::BindingIdentifier,
 ) -> bool {
     let agent = ctx.get_agent();
+    if agent.options.debug_scopes {
+        // Debuggers must be able to see all variables.
+        return true;
+    }
     let sc = ctx.get_source_code();
     let scoping = sc.get_scoping(agent);
     let nodes = sc.get_nodes(agent);
@@ -862,25 +879,34 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Identi
                         unreachable!();
                     }
                     let is_lexical = symbol_flags.intersects(SymbolFlags::BlockScopedVariable);
+                    let is_parameter = !is_lexical && is_parameter(nodes, decl_id);
                     // We might be in the temporal dead-zone.
-                    if is_lexical && ref_id < decl_id
-                        || !is_lexical && is_parameter_tdz(nodes, decl_id, ref_id)
-                    {
+                    if (is_lexical || is_parameter) && ref_id < decl_id {
                         // Reference before initialization: this is TDZ.
                         VariableKind::TemporalDeadZone
-                    } else if nodes.get_node(decl_id).scope_id() == scoping.symbol_scope_id(s) {
+                    } else if (is_lexical || is_parameter)
+                        && nodes.get_node(decl_id).scope_id() == scoping.symbol_scope_id(s)
+                    {
                         // If the node comes after the declaration and is in the
                         // same scope, it's still possible for it to be in the
                         // TDZ if it is itself within the declaration
                         // expression. To detect this, we iterate parent nodes
                         // until we find one that is equal to or before the
                         // declaration. If we found the declaration this way,
-                        // then this is TDZ.
+                        // then the reference is within the declaration and may
+                        // be evaluated before the binding is initialised.
                         let mut node = ref_id;
                         while decl_id < node {
                             node = nodes.parent_id(node);
                         }
-                        if decl_id == node {
+                        if decl_id == node
+                            && is_evaluated_before_binding(
+                                nodes,
+                                decl_id,
+                                ref_id,
+                                scoping.symbol_span(s),
+                            )
+                        {
                             // Self-referential declaration.
                             VariableKind::TemporalDeadZone
                         } else {
@@ -922,26 +948,62 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Identi
     }
 }
 
+/// Returns true if the declaration is a formal parameter.
+///
 /// Formal parameter lists also have a temporal dead-zone; when the list does
 /// not contain duplicates (which we consider always escaping), any reference to
 /// later parameters from earlier parameters' default expressions is in a TDZ.
-fn is_parameter_tdz(nodes: &oxc_semantic::AstNodes, decl_id: NodeId, ref_id: NodeId) -> bool {
+fn is_parameter(nodes: &oxc_semantic::AstNodes, decl_id: NodeId) -> bool {
     let decl_parent_id = nodes.parent_id(decl_id);
-    let oxc_ast::AstKind::FormalParameters(_) = nodes.get_node(decl_parent_id).kind() else {
-        // If the declaration isn't a formal parameter, then this cannot be in
-        // TDZ.
-        return false;
+    matches!(
+        nodes.get_node(decl_parent_id).kind(),
+        oxc_ast::AstKind::FormalParameters(_)
+    )
+}
+
+/// Returns true if a reference within a declaration is evaluated before the
+/// declared binding is initialised.
+///
+/// Declarations are evaluated left to right, except that an initializer is
+/// evaluated before the binding pattern it initialises. This means that a
+/// binding can be referenced by the default values of later elements in the
+/// same pattern, eg. `let [a, b = a] = [];`, but not by its own default value
+/// or the declaration's initializer.
+fn is_evaluated_before_binding(
+    nodes: &oxc_semantic::AstNodes,
+    decl_id: NodeId,
+    ref_id: NodeId,
+    binding_span: oxc_span::Span,
+) -> bool {
+    use oxc_span::GetSpan;
+
+    let ref_span = nodes.get_node(ref_id).span();
+    let contains = |outer: oxc_span::Span, inner: oxc_span::Span| {
+        outer.start <= inner.start && inner.end <= outer.end
     };
-    // Reference points to a formal parameter: this means that we might be in
-    // its TDZ.
-    if ref_id < decl_id {
-        // If our reference is before the formal parameter then we definitely
-        // are in the TDZ.
-        return true;
+    let mut node_id = ref_id;
+    while node_id != decl_id {
+        node_id = nodes.parent_id(node_id);
+        let initializer_span = match nodes.get_node(node_id).kind() {
+            oxc_ast::AstKind::AssignmentPattern(pattern)
+                if contains(pattern.left.span(), binding_span) =>
+            {
+                Some(pattern.right.span())
+            }
+            oxc_ast::AstKind::FormalParameter(parameter) => {
+                parameter.initializer.as_ref().map(|init| init.span())
+            }
+            oxc_ast::AstKind::VariableDeclarator(declarator) => {
+                declarator.init.as_ref().map(|init| init.span())
+            }
+            _ => None,
+        };
+        if initializer_span.is_some_and(|init| contains(init, ref_span)) {
+            // The initializer is evaluated before the binding pattern.
+            return true;
+        }
     }
-    // If our reference comes after the formal parameter we might still be in
-    // the TDZ but a followup check tests for that.
-    false
+    ref_span.start < binding_span.start
 }
 
 impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::BindingIdentifier<'s> {
@@ -1287,10 +1349,7 @@ fn create_object_with_shape<'s, 'gc>(
         if !prop.shorthand && prop.key.is_specific_static_name("__proto__") {
             continue;
         }
-        let ast::PropertyKey::StaticIdentifier(id) = &prop.key else {
-            unreachable!()
-        };
-        let identifier = ctx.create_property_key(&id.name);
+        let identifier = ctx.create_property_key(&prop.key.static_name().unwrap());
         shape = shape
             .get_child_shape(ctx.get_agent_mut(), identifier)
             .expect("Should perform GC here");
@@ -1326,7 +1385,11 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Object
                     };
                     prop.kind == ast::PropertyKind::Init
                         && !prop.method
-                        && prop.key.is_identifier()
+                        && matches!(
+                            prop.key,
+                            ast::PropertyKey::StaticIdentifier(_)
+                                | ast::PropertyKey::StringLiteral(_)
+                        )
                         && if prop.key.is_specific_static_name("__proto__") && !prop.shorthand {
                             prop.value.is_null_or_undefined()
                         } else {
@@ -1342,10 +1405,7 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Object
                     let ast::ObjectPropertyKind::ObjectProperty(prop) = prop else {
                         unreachable!()
                     };
-                    let ast::PropertyKey::StaticIdentifier(key) = &prop.key else {
-                        unreachable!()
-                    };
-                    key.name.as_str()
+                    prop.key.static_name().unwrap()
                 })
                 .collect::<Vec<_>>();
             dedup_keys.sort();
@@ -2797,6 +2857,22 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Update
             | ast::SimpleAssignmentTarget::TSAsExpression(_)
             | ast::SimpleAssignmentTarget::TSTypeAssertion(_) => unreachable!(),
         };
+        let (update_instruction, update_in_place_instruction) = match self.operator {
+            oxc_syntax::operator::UpdateOperator::Increment => (
+                Instruction::Increment,
+                Instruction::IncrementReferenceInPlace,
+            ),
+            oxc_syntax::operator::UpdateOperator::Decrement => (
+                Instruction::Decrement,
+                Instruction::DecrementReferenceInPlace,
+            ),
+        };
+        // Declarative bindings and Array elements holding a Number can be
+        // updated in place using the Reference; this jumps over the generic
+        // GetValue and PutValue sequence with the old value as the result.
+        let jump_to_updated_in_place = lref
+            .may_update_in_place()
+            .then(|| ctx.add_instruction_with_jump_slot(update_in_place_instruction));
         lref.get_value_keep_reference(ctx)?;
         ctx.add_instruction(Instruction::ToNumeric);
         let value_on_stack = if !self.prefix {
@@ -2806,17 +2882,21 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Update
         } else {
             None
         };
-        match self.operator {
-            oxc_syntax::operator::UpdateOperator::Increment => {
-                ctx.add_instruction(Instruction::Increment);
-            }
-            oxc_syntax::operator::UpdateOperator::Decrement => {
-                ctx.add_instruction(Instruction::Decrement);
-            }
-        }
+        ctx.add_instruction(update_instruction);
         let value_on_stack = value_on_stack.unwrap_or_else(|| ctx.load_copy_to_stack());
         let result = lref.put_value(ctx, ValueOutput::Value);
         value_on_stack.store(ctx);
+        if let Some(jump_to_updated_in_place) = jump_to_updated_in_place {
+            if self.prefix {
+                let jump_over_update = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+                ctx.set_jump_target_here(jump_to_updated_in_place);
+                // The in-place update leaves the old value as the result.
+                ctx.add_instruction(update_instruction);
+                ctx.set_jump_target_here(jump_over_update);
+            } else {
+                ctx.set_jump_target_here(jump_to_updated_in_place);
+            }
+        }
         result.map(|_| ValueOutput::Value)
     }
 }
@@ -750,12 +750,52 @@ fn compile_assignment_target_rest<'s>(
     // stack: []
     // reference: None
     // reference stack: [...source.properties]
+    let Some(target) = rest.target.as_simple_assignment_target() else {
+        ctx.add_instruction_with_immediate(
+            Instruction::CopyDataPropertiesIntoObject,
+            property_count,
+        );
+        // result: object copy
+        // stack: []
+        // reference: None
+        // reference stack: []
+        return rest.target.to_assignment_target_pattern().compile(ctx);
+    };
+    // 1. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an
+    //    ArrayLiteral, then
+    // a. Let lref be ? Evaluation of DestructuringAssignmentTarget.
+    let lref = if target.is_member_expression() {
+        let source_on_stack = ctx.load_to_stack();
+        // result: None
+        // stack: [source]
+        match target.compile(ctx) {
+            Ok(lref) => {
+                source_on_stack.store(ctx);
+                lref
+            }
+            Err(err) => {
+                source_on_stack.forget(ctx);
+                return Err(err);
+            }
+        }
+    } else {
+        target.compile(ctx)?
+    };
+    // result: source
+    // stack: []
+    // reference: &target
+    // reference stack: [...source.properties]
+    // 2. Let restObj be OrdinaryObjectCreate(%Object.prototype%).
+    // 3. Perform ? CopyDataProperties(restObj, value, excludedNames).
     ctx.add_instruction_with_immediate(Instruction::CopyDataPropertiesIntoObject, property_count);
     // result: object copy
     // stack: []
-    // reference: None
+    // reference: &target
     // reference stack: []
-    rest.target.compile(ctx)
+    // 4. If DestructuringAssignmentTarget is neither an ObjectLiteral nor an
+    //    ArrayLiteral, then
+    // a. Return ? PutValue(lref, restObj).
+    lref.put_value(ctx, ValueOutput::Value)
     // result: None
     // stack: []
     // reference: None
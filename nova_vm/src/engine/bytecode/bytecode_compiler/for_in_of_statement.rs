@@ -118,6 +118,32 @@ fn for_in_of_head_evaluation<'s, 'gc>(
     }
 }
 
+/// Returns true if lhs is a declaration of an array binding pattern
+/// containing only binding identifiers, eg. `const [key, value]`. Binding the
+/// iterated value to such a lexical declaration does not call into
+/// JavaScript, so Map entries can be destructured without creating the entry
+/// Array.
+fn is_identifier_array_binding(lhs: &ast::ForStatementLeft) -> bool {
+    let ast::ForStatementLeft::VariableDeclaration(decl) = lhs else {
+        return false;
+    };
+    let [declaration] = decl.declarations.as_slice() else {
+        return false;
+    };
+    let ast::BindingPattern::ArrayPattern(pattern) = &declaration.id else {
+        return false;
+    };
+    pattern
+        .elements
+        .iter()
+        .flatten()
+        .all(|element| matches!(element, ast::BindingPattern::BindingIdentifier(_)))
+        && pattern
+            .rest
+            .as_ref()
+            .is_none_or(|rest| matches!(rest.argument, ast::BindingPattern::BindingIdentifier(_)))
+}
+
 fn for_in_of_body_evaluation<'s, 'gc>(
     ctx: &mut CompileContext<'_, 's, 'gc, '_>,
     lhs: &'s ast::ForStatementLeft<'s>,
@@ -170,7 +196,11 @@ fn for_in_of_body_evaluation<'s, 'gc>(
         // d. Let done be ? IteratorComplete(nextResult).
         // e. If done is true, return V.
         // f. Let nextValue be ? IteratorValue(nextResult).
-        ctx.add_instruction_with_jump_slot(Instruction::IteratorStepValue)
+        if lhs_kind == LeftHandSideKind::LexicalBinding && is_identifier_array_binding(lhs) {
+            ctx.add_instruction_with_jump_slot(Instruction::IteratorStepEntry)
+        } else {
+            ctx.add_instruction_with_jump_slot(Instruction::IteratorStepValue)
+        }
     };
     // Note: stepping the iterator happens "outside" the loop in a sense;
     // errors thrown above do not close the iterator; the iterator must still
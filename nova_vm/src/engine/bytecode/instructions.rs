@@ -228,6 +228,19 @@ pub(crate) enum Instruction {
     HasPrivateElement,
     Increment,
     Decrement,
+    /// Increment the Number value of the binding or Array element referred
+    /// to by the reference register without looking it up twice, store the
+    /// old value as the result value, clear the reference register, and jump
+    /// to the address given as the first immediate argument.
+    ///
+    /// If the reference does not refer to an initialized mutable declarative
+    /// binding or a plain Array element containing a Number, nothing is done
+    /// and execution continues with the next instruction.
+    IncrementReferenceInPlace,
+    /// Decrement the Number value of the binding or Array element referred
+    /// to by the reference register in place. See
+    /// [Instruction::IncrementReferenceInPlace].
+    DecrementReferenceInPlace,
     /// Store InstanceofOperator() as the result value.
     InstanceofOperator,
     /// Store InstantiateArrowFunctionExpression() as the result value.
@@ -453,6 +466,15 @@ pub(crate) enum Instruction {
     /// Perform IteratorStepValue on the current iterator and jump to
     /// index if iterator completed.
     IteratorStepValue,
+    /// Perform IteratorStepValue on the current iterator and jump to index if
+    /// iterator completed. The value is immediately destructured by an array
+    /// binding pattern.
+    ///
+    /// If the iterator yields Map entries and destructuring the entry cannot
+    /// be observed, the entry's key and value are held in the iterator and
+    /// the result register is left empty instead of creating an entry Array.
+    /// The following `GetIteratorSync` then iterates the held entry.
+    IteratorStepEntry,
     /// Perform IteratorStepValue on the current iterator, putting the resulting
     /// value on the result value, or undefined if the iterator has completed.
     ///
@@ -553,9 +575,12 @@ impl Instruction {
             | Self::ClassDefinePrivateProperty
             | Self::InitializeVariableEnvironment
             | Self::IteratorStepValue
+            | Self::IteratorStepEntry
             | Self::IteratorComplete
             | Self::IteratorThrow
             | Self::IteratorReturn
+            | Self::IncrementReferenceInPlace
+            | Self::DecrementReferenceInPlace
             | Self::Jump
             | Self::JumpIfNot
             | Self::JumpIfTrue
@@ -607,6 +632,9 @@ impl Instruction {
         matches!(
             self,
             Self::IteratorStepValue
+                | Self::IteratorStepEntry
+                | Self::IncrementReferenceInPlace
+                | Self::DecrementReferenceInPlace
                 | Self::Jump
                 | Self::JumpIfNot
                 | Self::JumpIfTrue
@@ -684,11 +712,14 @@ impl Instruction {
             Self::IteratorComplete
                 | Self::IteratorThrow
                 | Self::IteratorReturn
+                | Self::IncrementReferenceInPlace
+                | Self::DecrementReferenceInPlace
                 | Self::Jump
                 | Self::JumpIfNot
                 | Self::JumpIfTrue
                 | Self::PushExceptionJumpTarget
                 | Self::IteratorStepValue
+                | Self::IteratorStepEntry
         )
     }
 
@@ -1215,6 +1246,8 @@ impl TryFrom<u8> for Instruction {
         const HASPRIVATEELEMENT: u8 = Instruction::HasPrivateElement.as_u8();
         const INCREMENT: u8 = Instruction::Increment.as_u8();
         const DECREMENT: u8 = Instruction::Decrement.as_u8();
+        const INCREMENTREFERENCEINPLACE: u8 = Instruction::IncrementReferenceInPlace.as_u8();
+        const DECREMENTREFERENCEINPLACE: u8 = Instruction::DecrementReferenceInPlace.as_u8();
         const INSTANCEOFOPERATOR: u8 = Instruction::InstanceofOperator.as_u8();
         const INSTANTIATEARROWFUNCTIONEXPRESSION: u8 =
             Instruction::InstantiateArrowFunctionExpression.as_u8();
@@ -1313,6 +1346,7 @@ impl TryFrom<u8> for Instruction {
         const GETITERATORSYNC: u8 = Instruction::GetIteratorSync.as_u8();
         const GETITERATORASYNC: u8 = Instruction::GetIteratorAsync.as_u8();
         const ITERATORSTEPVALUE: u8 = Instruction::IteratorStepValue.as_u8();
+        const ITERATORSTEPENTRY: u8 = Instruction::IteratorStepEntry.as_u8();
         const ITERATORSTEPVALUEORUNDEFINED: u8 = Instruction::IteratorStepValueOrUndefined.as_u8();
         const ITERATORNEXT: u8 = Instruction::IteratorCallNextMethod.as_u8();
         const ITERATORCOMPLETE: u8 = Instruction::IteratorComplete.as_u8();
@@ -1381,6 +1415,8 @@ impl TryFrom<u8> for Instruction {
             HASPRIVATEELEMENT => Ok(Instruction::HasPrivateElement),
             INCREMENT => Ok(Instruction::Increment),
             DECREMENT => Ok(Instruction::Decrement),
+            INCREMENTREFERENCEINPLACE => Ok(Instruction::IncrementReferenceInPlace),
+            DECREMENTREFERENCEINPLACE => Ok(Instruction::DecrementReferenceInPlace),
             INSTANCEOFOPERATOR => Ok(Instruction::InstanceofOperator),
             INSTANTIATEARROWFUNCTIONEXPRESSION => {
                 Ok(Instruction::InstantiateArrowFunctionExpression)
@@ -1476,6 +1512,7 @@ impl TryFrom<u8> for Instruction {
             GETITERATORSYNC => Ok(Instruction::GetIteratorSync),
             GETITERATORASYNC => Ok(Instruction::GetIteratorAsync),
             ITERATORSTEPVALUE => Ok(Instruction::IteratorStepValue),
+            ITERATORSTEPENTRY => Ok(Instruction::IteratorStepEntry),
             ITERATORSTEPVALUEORUNDEFINED => Ok(Instruction::IteratorStepValueOrUndefined),
             ITERATORNEXT => Ok(Instruction::IteratorCallNextMethod),
             ITERATORCOMPLETE => Ok(Instruction::IteratorComplete),
@@ -5,13 +5,21 @@
 use core::ops::ControlFlow;
 use std::{marker::PhantomData, ptr::NonNull};
 
+#[cfg(feature = "array-buffer")]
+use ecmascript_atomics::Ordering;
+
+#[cfg(feature = "array-buffer")]
+use crate::ecmascript::{
+    AnyTypedArray, TypedArrayAbstractOperations, make_typed_array_with_buffer_witness_record,
+};
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, AsyncFromSyncIteratorPrototype, BUILTIN_STRING_MEMORY,
-        ExceptionType, InternalMethods, IteratorRecord, JsError, JsResult, Object, OrdinaryObject,
-        Promise, PropertyKey, PropertyKeySet, ScopedArgumentsList, TryError, TryGetResult,
-        TryResult, Value, call_function, create_async_from_sync_iterator,
-        create_iter_result_object, get, get_iterator_from_method, get_method, get_object_method,
+        CollectionIteratorKind, ExceptionType, InternalMethods, IteratorRecord, JsError, JsResult,
+        MapIterator, Object, OrdinaryObject, Promise, PropertyKey, PropertyKeySet,
+        ScopedArgumentsList, TryError, TryGetResult, TryResult, Value, call_function,
+        create_array_from_list, create_async_from_sync_iterator, create_iter_result_object, get,
+        get_iterator_from_method, get_method, get_object_method, js_result_into_try,
         throw_not_callable, to_boolean, try_get,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, ScopeToken, Scoped, bindable_handle},
@@ -68,6 +76,25 @@ impl<'a> ActiveIterator<'a> {
                 .unbind()
                 .and_then(|r| convert_to_iter_result_object(agent, r.unbind(), gc.into_nogc()))
                 .map(|o| o.into()),
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(_) => {
+                let gc = gc.into_nogc();
+                TypedArrayValuesIterator::new(self)
+                    .next(agent, gc)
+                    .and_then(|r| convert_to_iter_result_object(agent, r, gc))
+                    .map(|o| o.into())
+            }
+            VmIteratorRecord::MapIterator(MapIteratorRecord { iterator, .. }) => {
+                let iterator = *iterator;
+                let gc = gc.into_nogc();
+                let result = MapIteratorRecord::step_value(agent, iterator, gc);
+                convert_to_iter_result_object(agent, result, gc).map(|o| o.into())
+            }
+            VmIteratorRecord::MapEntryValues(_) => {
+                // Map entry values are only iterated by array binding
+                // patterns.
+                unreachable!()
+            }
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => {
                 Ok(AsyncFromSyncGenericIterator::new(self)
                     .call_next(agent, value, gc)
@@ -98,13 +125,21 @@ impl<'a> ActiveIterator<'a> {
             VmIteratorRecord::ArrayValues(_) => {
                 ArrayValuesIterator::new(self).throw(agent, received_value, gc)
             }
+            // Note: TypedArray values iterators are ArrayIterators as well.
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(_) => {
+                ArrayValuesIterator::new(self).throw(agent, received_value, gc)
+            }
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => Ok(Some(
                 AsyncFromSyncGenericIterator::new(self)
                     .throw(agent, received_value, gc)
                     .into(),
             )),
-            VmIteratorRecord::GenericIterator(_) => {
-                GenericIterator::new(self).throw(agent, received_value, gc)
+            VmIteratorRecord::MapIterator(MapIteratorRecord { iterator, .. }) => {
+                GenericIterator::throw(agent, (*iterator).into(), received_value, gc)
+            }
+            VmIteratorRecord::GenericIterator(IteratorRecord { iterator, .. }) => {
+                GenericIterator::throw(agent, *iterator, received_value, gc)
             }
             _ => unreachable!(),
         }
@@ -120,11 +155,18 @@ impl<'a> ActiveIterator<'a> {
             VmIteratorRecord::ArrayValues(_) => {
                 ArrayValuesIterator::new(self).r#return(agent, received_value, gc)
             }
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(_) => {
+                ArrayValuesIterator::new(self).r#return(agent, received_value, gc)
+            }
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => Ok(Some(
                 AsyncFromSyncGenericIterator::new(self)
                     .r#return(agent, received_value, gc)
                     .into(),
             )),
+            VmIteratorRecord::MapIterator(MapIteratorRecord { iterator, .. }) => {
+                GenericIterator::r#return(agent, (*iterator).into(), received_value, gc)
+            }
             VmIteratorRecord::GenericIterator(IteratorRecord { iterator, .. })
             | VmIteratorRecord::InvalidIterator { iterator } => {
                 GenericIterator::r#return(agent, *iterator, received_value, gc)
@@ -146,6 +188,24 @@ impl<'a> ActiveIterator<'a> {
                 ObjectPropertiesIterator::new(self).step_value(agent, gc)
             }
             VmIteratorRecord::ArrayValues(_) => ArrayValuesIterator::new(self).next(agent, gc),
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(_) => {
+                TypedArrayValuesIterator::new(self).next(agent, gc.into_nogc())
+            }
+            VmIteratorRecord::MapIterator(MapIteratorRecord { iterator, .. }) => {
+                let iterator = *iterator;
+                Ok(MapIteratorRecord::step_value(
+                    agent,
+                    iterator,
+                    gc.into_nogc(),
+                ))
+            }
+            VmIteratorRecord::MapEntryValues(_) => {
+                let VmIteratorRecord::MapEntryValues(entry) = self.get_mut(agent) else {
+                    unreachable!()
+                };
+                Ok(entry.next().unbind().bind(gc.into_nogc()))
+            }
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => {
                 // We should never call this for async iterators!
                 unreachable!()
@@ -187,6 +247,14 @@ pub(crate) enum VmIteratorRecord<'a> {
     },
     ObjectProperties(Box<ObjectPropertiesIteratorRecord<'a>>),
     ArrayValues(ArrayValuesIteratorRecord<'a>),
+    #[cfg(feature = "array-buffer")]
+    TypedArrayValues(TypedArrayValuesIteratorRecord<'a>),
+    MapIterator(MapIteratorRecord<'a>),
+    /// Key and value of a Map entry being destructured by an array binding
+    /// pattern; see [`Instruction::IteratorStepEntry`].
+    ///
+    /// [`Instruction::IteratorStepEntry`]: crate::engine::Instruction::IteratorStepEntry
+    MapEntryValues(MapEntryValuesIteratorRecord<'a>),
     AsyncFromSyncGenericIterator(IteratorRecord<'a>),
     GenericIterator(IteratorRecord<'a>),
     SliceIterator(ScopedArgumentsList<'a>),
@@ -197,12 +265,14 @@ impl<'a> VmIteratorRecord<'a> {
     pub(super) fn remaining_length_estimate(&self, agent: &Agent) -> Option<usize> {
         match self {
             VmIteratorRecord::InvalidIterator { .. } => Some(0),
-            VmIteratorRecord::ObjectProperties(iter) => {
-                Some(iter.remaining_keys.as_ref().map_or(0, |k| k.len()))
-            }
+            VmIteratorRecord::ObjectProperties(iter) => Some(iter.remaining_keys.len()),
             VmIteratorRecord::ArrayValues(iter) => {
                 Some(iter.array.len(agent).saturating_sub(iter.index) as usize)
             }
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(iter) => Some(iter.remaining_length(agent)),
+            VmIteratorRecord::MapIterator(_) => None,
+            VmIteratorRecord::MapEntryValues(entry) => Some(entry.remaining_length()),
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => todo!(),
             VmIteratorRecord::GenericIterator(_) => None,
             VmIteratorRecord::SliceIterator(slice) => Some(slice.len(agent)),
@@ -215,9 +285,20 @@ impl<'a> VmIteratorRecord<'a> {
             VmIteratorRecord::ObjectProperties(_)
             | VmIteratorRecord::SliceIterator(_)
             | VmIteratorRecord::EmptySliceIterator => false,
+            // Note: Map entry values are only held when the ArrayIterator
+            // prototype has no return method, and destructuring the entry
+            // does not call into JavaScript.
+            VmIteratorRecord::MapEntryValues(_) => false,
+            VmIteratorRecord::MapIterator(MapIteratorRecord { iterator, .. }) => {
+                generic_iterator_record_requires_return_call(agent, (*iterator).into(), gc)
+            }
             VmIteratorRecord::ArrayValues(_) => {
                 array_iterator_record_requires_return_call(agent, gc)
             }
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(_) => {
+                array_iterator_record_requires_return_call(agent, gc)
+            }
             VmIteratorRecord::InvalidIterator { iterator }
             | VmIteratorRecord::GenericIterator(IteratorRecord { iterator, .. })
             | VmIteratorRecord::AsyncFromSyncGenericIterator(IteratorRecord { iterator, .. }) => {
@@ -266,19 +347,70 @@ impl<'a> VmIteratorRecord<'a> {
                         .current_realm_record()
                         .intrinsics()
                         .array_prototype_values()
-                        .into() =>
+                        .into()
+                    && array_iterator_protocol_is_intact(agent, gc.nogc()) =>
             {
                 Ok(VmIteratorRecord::ArrayValues(
                     ArrayValuesIteratorRecord::new(array.unbind()),
                 ))
             }
-            _ => Ok(
-                get_iterator_from_method(agent, value.unbind(), method.unbind(), gc)?
-                    .into_vm_iterator_record(),
-            ),
+            _ => {
+                // Optimisation: Check if we're using the TypedArray values
+                // iterator on a TypedArray.
+                #[cfg(feature = "array-buffer")]
+                if let Ok(typed_array) = AnyTypedArray::try_from(value)
+                    && method
+                        == agent
+                            .current_realm_record()
+                            .intrinsics()
+                            .typed_array_prototype_values()
+                            .into()
+                    && array_iterator_protocol_is_intact(agent, gc.nogc())
+                {
+                    return Ok(VmIteratorRecord::TypedArrayValues(
+                        TypedArrayValuesIteratorRecord::new(typed_array.unbind()),
+                    ));
+                }
+                let iterator_record =
+                    get_iterator_from_method(agent, value.unbind(), method.unbind(), gc)?
+                        .into_vm_iterator_record();
+                // Optimisation: Map Iterators using the intrinsic next method
+                // can be stepped without creating iterator result objects.
+                if let VmIteratorRecord::GenericIterator(IteratorRecord {
+                    iterator: Object::MapIterator(iterator),
+                    next_method,
+                }) = iterator_record
+                    && next_method
+                        == agent
+                            .current_realm_record()
+                            .intrinsics()
+                            .map_iterator_prototype_next()
+                            .into()
+                {
+                    return Ok(VmIteratorRecord::MapIterator(MapIteratorRecord::new(
+                        iterator,
+                    )));
+                }
+                Ok(iterator_record)
+            }
         }
     }
 
+    /// Take the Map entry held in this iterator record by
+    /// [`Instruction::IteratorStepEntry`], returning an iterator over its key
+    /// and value.
+    ///
+    /// [`Instruction::IteratorStepEntry`]: crate::engine::Instruction::IteratorStepEntry
+    pub(super) fn take_entry(&mut self) -> Self {
+        let VmIteratorRecord::MapIterator(MapIteratorRecord { entry, .. }) = self else {
+            unreachable!()
+        };
+        let (key, value) = entry.take().expect("No Map entry held");
+        VmIteratorRecord::MapEntryValues(MapEntryValuesIteratorRecord {
+            entry: [Some(key), Some(value)],
+        })
+    }
+
     /// ### [7.4.4 GetIterator ( obj, kind )](https://tc39.es/ecma262/#sec-getiterator)
     ///
     /// The abstract operation GetIterator takes arguments obj (an ECMAScript
@@ -311,7 +443,9 @@ impl<'a> VmIteratorRecord<'a> {
         let obj = unsafe { scoped_obj.take(agent).bind(gc.nogc()) };
 
         // 4. Return ? GetIteratorFromMethod(obj, method).
-        if let Some(array) = Array::is_iterable_array(agent, obj, method) {
+        if let Some(array) = Array::is_iterable_array(agent, obj, method)
+            && array_iterator_protocol_is_intact(agent, gc.nogc())
+        {
             // Optimisation: if we're using the Array values iterator on
             // an Array then we can use a special iterator case.
             Ok(VmIteratorRecord::ArrayValues(
@@ -361,6 +495,14 @@ impl<'a> VmIteratorRecord<'a> {
             VmIteratorRecord::InvalidIterator { .. } => throw_not_callable(agent, gc).into(),
             VmIteratorRecord::ObjectProperties(iter) => iter.try_step_value(agent, gc),
             VmIteratorRecord::ArrayValues(iter) => iter.try_next(agent, gc),
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(iter) => js_result_into_try(iter.next(agent, gc)),
+            VmIteratorRecord::MapIterator(iter) => {
+                TryResult::Continue(MapIteratorRecord::step_value(agent, iter.iterator, gc))
+            }
+            VmIteratorRecord::MapEntryValues(entry) => {
+                TryResult::Continue(entry.next().unbind().bind(gc))
+            }
             VmIteratorRecord::AsyncFromSyncGenericIterator(_) => {
                 // We should never call this for async iterators!
                 unreachable!()
@@ -406,15 +548,7 @@ impl<'a> ObjectPropertiesIterator<'a> {
                     .internal_own_property_keys(agent, gc.reborrow())
                     .unbind()?
                     .bind(gc.nogc());
-                let mut remaining_keys = Vec::with_capacity(keys.len());
-                for key in keys {
-                    if let PropertyKey::Symbol(_) = key {
-                        continue;
-                    } else {
-                        remaining_keys.push(key);
-                    }
-                }
-                self.set_remaining_keys(agent, remaining_keys);
+                self.set_remaining_keys(agent, keys);
             }
             while let Some((object, next_key)) = self.next_remaining_key(agent, gc.nogc()) {
                 let scoped_next_key = next_key.scope(agent, gc.nogc());
@@ -468,18 +602,15 @@ impl<'a> ObjectPropertiesIterator<'a> {
     }
 
     fn object_is_visited(&self, agent: &Agent) -> bool {
-        self.get(agent).remaining_keys.is_some()
+        self.get(agent).object_is_visited()
     }
 
-    fn set_remaining_keys(&mut self, agent: &mut Agent, mut remaining_keys: Vec<PropertyKey>) {
-        remaining_keys.reverse();
-        self.get_mut(agent).remaining_keys = Some(remaining_keys.unbind());
+    fn set_remaining_keys(&mut self, agent: &mut Agent, remaining_keys: Vec<PropertyKey>) {
+        self.get_mut(agent).set_remaining_keys(remaining_keys);
     }
 
     fn set_object(&mut self, agent: &mut Agent, object: Object) {
-        let iter = self.get_mut(agent);
-        iter.object = object.unbind();
-        iter.remaining_keys = None;
+        self.get_mut(agent).set_object(object);
     }
 
     fn next_remaining_key<'gc>(
@@ -491,7 +622,7 @@ impl<'a> ObjectPropertiesIterator<'a> {
         loop {
             // SAFETY: The iterator does not invalidate or move here.
             let iter_mut = unsafe { iter.as_mut() };
-            let next_key = iter_mut.remaining_keys.as_mut().unwrap().pop()?;
+            let next_key = iter_mut.remaining_keys.pop()?;
             // SAFETY: The iterator does not invalidate or move here.
             let iter_ref = unsafe { iter.as_ref() };
             if iter_ref.visited_keys.contains(agent, next_key) {
@@ -531,7 +662,11 @@ impl<'a> ObjectPropertiesIterator<'a> {
 pub(crate) struct ObjectPropertiesIteratorRecord<'a> {
     object: Object<'a>,
     visited_keys: PropertyKeySet<'a>,
-    remaining_keys: Option<Vec<PropertyKey<'a>>>,
+    /// Keys of the current object that are yet to be visited, in reverse
+    /// order. The buffer is reused when moving up the prototype chain.
+    remaining_keys: Vec<PropertyKey<'a>>,
+    /// True if the keys of the current object have been collected.
+    object_is_visited: bool,
 }
 
 impl<'a> ObjectPropertiesIteratorRecord<'a> {
@@ -540,22 +675,25 @@ impl<'a> ObjectPropertiesIteratorRecord<'a> {
             object,
             visited_keys: Default::default(),
             remaining_keys: Default::default(),
+            object_is_visited: false,
         }
     }
 
     fn object_is_visited(&self) -> bool {
-        self.remaining_keys.is_some()
+        self.object_is_visited
     }
 
     fn set_remaining_keys(&mut self, mut remaining_keys: Vec<PropertyKey>) {
+        remaining_keys.retain(|key| !key.is_symbol());
         remaining_keys.reverse();
-        self.remaining_keys = Some(remaining_keys.unbind());
+        self.remaining_keys = remaining_keys.unbind();
+        self.object_is_visited = true;
     }
 
     fn set_object(&mut self, object: Object) {
-        let iter = self;
-        iter.object = object.unbind();
-        iter.remaining_keys = None;
+        self.object = object.unbind();
+        self.remaining_keys.clear();
+        self.object_is_visited = false;
     }
 
     fn next_remaining_key<'gc>(
@@ -564,7 +702,7 @@ impl<'a> ObjectPropertiesIteratorRecord<'a> {
         gc: NoGcScope<'gc, '_>,
     ) -> Option<(Object<'gc>, PropertyKey<'gc>)> {
         loop {
-            let next_key = self.remaining_keys.as_mut().unwrap().pop()?;
+            let next_key = self.remaining_keys.pop()?;
             if self.visited_keys.contains(agent, next_key) {
                 // Skip visited keys.
                 continue;
@@ -591,16 +729,16 @@ impl<'a> ObjectPropertiesIteratorRecord<'a> {
         loop {
             let object = self.object.bind(gc);
             if !self.object_is_visited() {
-                let keys = object.try_own_property_keys(agent, gc)?;
-                let mut remaining_keys = Vec::with_capacity(keys.len());
-                for key in keys {
-                    if let PropertyKey::Symbol(_) = key {
-                        continue;
-                    } else {
-                        remaining_keys.push(key);
-                    }
+                // Note: the key buffer is empty here; reuse its allocation.
+                let mut keys = core::mem::take(&mut self.remaining_keys).bind(gc);
+                if let ControlFlow::Break(err) =
+                    object.try_own_property_keys_into(agent, &mut keys, gc)
+                {
+                    keys.clear();
+                    self.remaining_keys = keys.unbind();
+                    return ControlFlow::Break(err);
                 }
-                self.set_remaining_keys(remaining_keys);
+                self.set_remaining_keys(keys);
             }
             while let Some((object, next_key)) = self.next_remaining_key(agent, gc) {
                 let desc = object.try_get_own_property(agent, next_key, None, gc)?;
@@ -790,10 +928,14 @@ impl<'a> ArrayValuesIteratorRecord<'a> {
         }
         // 1. Let elementKey be ! ToString(indexNumber).
         // 2. Let elementValue be ? Get(array, elementKey).
-        let element_value = match try_get(agent, array.unbind(), index.into(), None, gc)? {
-            TryGetResult::Unset => Value::Undefined,
-            TryGetResult::Value(value) => value,
-            TryGetResult::Get(_) | TryGetResult::Proxy(_) => {
+        let element_value = match try_get(agent, array.unbind(), index.into(), None, gc) {
+            ControlFlow::Continue(TryGetResult::Unset) => Value::Undefined,
+            ControlFlow::Continue(TryGetResult::Value(value)) => value,
+            ControlFlow::Break(TryError::Err(err)) => return TryError::Err(err).into(),
+            _ => {
+                // Note: the step is retried with garbage collection, so the
+                // index must not be advanced.
+                self.index = index;
                 return TryError::GcError.into();
             }
         };
@@ -803,6 +945,211 @@ impl<'a> ArrayValuesIteratorRecord<'a> {
     }
 }
 
+/// Map Iterator using the intrinsic %MapIteratorPrototype%.next method.
+///
+/// The iterator object is still created as it may be observable, but it is
+/// stepped without creating iterator result objects.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MapIteratorRecord<'a> {
+    iterator: MapIterator<'a>,
+    /// Key and value of the Map entry held by
+    /// [`Instruction::IteratorStepEntry`] for the following
+    /// `GetIteratorSync`.
+    ///
+    /// [`Instruction::IteratorStepEntry`]: crate::engine::Instruction::IteratorStepEntry
+    entry: Option<(Value<'a>, Value<'a>)>,
+}
+
+impl<'a> MapIteratorRecord<'a> {
+    fn new(iterator: MapIterator<'a>) -> Self {
+        Self {
+            iterator,
+            entry: None,
+        }
+    }
+
+    /// ### [24.1.5.2.1 %MapIteratorPrototype%.next ( )](https://tc39.es/ecma262/#sec-%mapiteratorprototype%.next)
+    ///
+    /// Step the Map Iterator, returning the iterator result's value or None
+    /// if the iterator has completed.
+    fn step_value<'gc>(
+        agent: &mut Agent,
+        iterator: MapIterator,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<Value<'gc>> {
+        let iterator = iterator.bind(gc);
+        let (key, value) = iterator.next_entry(agent, gc)?;
+        Some(match iterator.kind(agent) {
+            CollectionIteratorKind::Key => key,
+            CollectionIteratorKind::Value => value,
+            CollectionIteratorKind::KeyAndValue => {
+                create_array_from_list(agent, &[key, value], gc).into()
+            }
+        })
+    }
+
+    /// Step the Map Iterator for a value that is immediately destructured by
+    /// an array binding pattern.
+    ///
+    /// If the iterator yields entries and destructuring an entry Array cannot
+    /// be observed, the next entry's key and value are held in this record
+    /// instead of creating the entry Array. Returns None if this does not
+    /// apply, and otherwise returns true if an entry is held or false if the
+    /// iterator has completed.
+    pub(super) fn step_entry(&mut self, agent: &mut Agent, gc: NoGcScope) -> Option<bool> {
+        if !matches!(
+            self.iterator.kind(agent),
+            CollectionIteratorKind::KeyAndValue
+        ) || !array_destructuring_is_intact(agent, gc)
+        {
+            return None;
+        }
+        debug_assert!(self.entry.is_none());
+        let iterator = self.iterator.bind(gc);
+        let entry = iterator.next_entry(agent, gc);
+        self.entry = entry.unbind();
+        Some(self.entry.is_some())
+    }
+}
+
+bindable_handle!(MapIteratorRecord);
+
+/// Key and value of a Map entry, iterated as if by the ArrayIterator of an
+/// entry Array.
+#[derive(Debug)]
+pub(crate) struct MapEntryValuesIteratorRecord<'a> {
+    entry: [Option<Value<'a>>; 2],
+}
+
+impl<'a> MapEntryValuesIteratorRecord<'a> {
+    fn next(&mut self) -> Option<Value<'a>> {
+        self.entry.iter_mut().find_map(Option::take)
+    }
+
+    fn remaining_length(&self) -> usize {
+        self.entry.iter().filter(|v| v.is_some()).count()
+    }
+}
+
+/// Iterator over the values of a TypedArray, stepped through the currently
+/// active VM's iterator stack.
+#[cfg(feature = "array-buffer")]
+struct TypedArrayValuesIterator<'a> {
+    iter: ActiveIterator<'a>,
+}
+
+#[cfg(feature = "array-buffer")]
+impl<'a> TypedArrayValuesIterator<'a> {
+    fn new(iter: &'a ActiveIterator) -> Self {
+        Self {
+            iter: iter.reborrow(),
+        }
+    }
+
+    fn next<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Option<Value<'gc>>> {
+        let VmIteratorRecord::TypedArrayValues(iter) = self.iter.get(agent) else {
+            unreachable!()
+        };
+        let typed_array = iter.typed_array.bind(gc);
+        let index = iter.index;
+        let result = TypedArrayValuesIteratorRecord::step(agent, typed_array, index, gc)?;
+        if result.is_some() {
+            let VmIteratorRecord::TypedArrayValues(iter) = self.iter.get_mut(agent) else {
+                unreachable!()
+            };
+            iter.index += 1;
+        }
+        Ok(result)
+    }
+}
+
+/// Iterator record for iterating over a TypedArray using the intrinsic
+/// %TypedArray.prototype.values% method. Elements are read directly from the
+/// viewed buffer instead of through an ArrayIterator object.
+#[cfg(feature = "array-buffer")]
+#[derive(Debug)]
+pub(crate) struct TypedArrayValuesIteratorRecord<'a> {
+    typed_array: AnyTypedArray<'a>,
+    index: usize,
+}
+
+#[cfg(feature = "array-buffer")]
+impl<'a> TypedArrayValuesIteratorRecord<'a> {
+    pub(super) fn new(typed_array: AnyTypedArray<'a>) -> Self {
+        Self {
+            typed_array,
+            // a. Let index be 0.
+            index: 0,
+        }
+    }
+
+    fn next<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Option<Value<'gc>>> {
+        let result = Self::step(agent, self.typed_array, self.index, gc)?;
+        if result.is_some() {
+            self.index += 1;
+        }
+        Ok(result)
+    }
+
+    /// Perform a step of the %ArrayIteratorPrototype%.next method for a
+    /// TypedArray at the given index. Returns None if the iterator is
+    /// exhausted.
+    fn step<'gc>(
+        agent: &mut Agent,
+        typed_array: AnyTypedArray,
+        index: usize,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Option<Value<'gc>>> {
+        // i. If array has a [[TypedArrayName]] internal slot, then
+        // a. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(array, seq-cst).
+        let ta_record =
+            make_typed_array_with_buffer_witness_record(agent, typed_array, Ordering::SeqCst);
+        // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if ta_record.is_typed_array_out_of_bounds(agent) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "TypedArray out of bounds",
+                gc,
+            ));
+        }
+        // c. Let len be TypedArrayLength(taRecord).
+        let len = ta_record.typed_array_length(agent);
+        // iii. If index ≥ len, return NormalCompletion(undefined).
+        if index >= len {
+            return Ok(None);
+        }
+        // 1. Let elementKey be ! ToString(indexNumber).
+        // 2. Let elementValue be ? Get(array, elementKey).
+        let element_value = typed_array
+            .typed_array_get_element(agent, index as i64, gc)
+            .map_or(Value::Undefined, Value::from);
+        // a. Let result be elementValue.
+        Ok(Some(element_value))
+    }
+
+    fn remaining_length(&self, agent: &Agent) -> usize {
+        let ta_record = make_typed_array_with_buffer_witness_record(
+            agent,
+            self.typed_array,
+            Ordering::Unordered,
+        );
+        if ta_record.is_typed_array_out_of_bounds(agent) {
+            return 0;
+        }
+        ta_record
+            .typed_array_length(agent)
+            .saturating_sub(self.index)
+    }
+}
+
 struct GenericIterator<'a> {
     iter: ActiveIterator<'a>,
 }
@@ -896,13 +1243,14 @@ impl<'a> GenericIterator<'a> {
     }
 
     fn throw<'gc>(
-        &mut self,
         agent: &mut Agent,
+        iterator: Object,
         received_value: Value,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Option<Value<'gc>>> {
         let received_value = received_value.scope(agent, gc.nogc());
-        let iterator = self.get(agent).iterator.bind(gc.nogc());
+        let iterator = iterator.bind(gc.nogc());
+        let scoped_iterator = iterator.scope(agent, gc.nogc());
         // 1. Let throw be ? GetMethod(iterator, "throw").
         let throw = get_object_method(
             agent,
@@ -914,7 +1262,8 @@ impl<'a> GenericIterator<'a> {
         .bind(gc.nogc());
         // 2. If throw is not undefined, then
         if let Some(throw) = throw {
-            let iterator = self.get(agent).iterator.bind(gc.nogc());
+            // SAFETY: not shared.
+            let iterator = unsafe { scoped_iterator.take(agent) }.bind(gc.nogc());
             // i. Return Call(throw, iterator, « received.[[Value]] »).
             Ok(Some(call_function(
                 agent,
@@ -1055,6 +1404,48 @@ impl<'a> AsyncFromSyncGenericIterator<'a> {
     }
 }
 
+/// Returns true if iterating an Array or TypedArray with its intrinsic values
+/// method can skip creating the ArrayIterator object: the intrinsic
+/// ArrayIteratorPrototype must still have its original next method and no
+/// return method, so that nothing can observe the iterator object.
+pub(crate) fn array_iterator_protocol_is_intact(agent: &mut Agent, gc: NoGcScope) -> bool {
+    let intrinsics = agent.current_realm_record().intrinsics();
+    let array_iterator_prototype = intrinsics.array_iterator_prototype();
+    let array_iterator_prototype_next = intrinsics.array_iterator_prototype_next();
+    let next = array_iterator_prototype.try_get(
+        agent,
+        BUILTIN_STRING_MEMORY.next.into(),
+        array_iterator_prototype.into(),
+        None,
+        gc,
+    );
+    let ControlFlow::Continue(TryGetResult::Value(next)) = next else {
+        return false;
+    };
+    next == array_iterator_prototype_next.into()
+        && !array_iterator_record_requires_return_call(agent, gc)
+}
+
+/// Returns true if destructuring an Array using the intrinsic values method
+/// cannot be observed: Array.prototype\[%Symbol.iterator%\] must be the
+/// intrinsic values method and the ArrayIterator protocol must be intact.
+fn array_destructuring_is_intact(agent: &mut Agent, gc: NoGcScope) -> bool {
+    let intrinsics = agent.current_realm_record().intrinsics();
+    let array_prototype = intrinsics.array_prototype();
+    let array_prototype_values = intrinsics.array_prototype_values();
+    let values = array_prototype.try_get(
+        agent,
+        PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+        array_prototype.into(),
+        None,
+        gc,
+    );
+    let ControlFlow::Continue(TryGetResult::Value(values)) = values else {
+        return false;
+    };
+    values == array_prototype_values.into() && array_iterator_protocol_is_intact(agent, gc)
+}
+
 fn array_iterator_record_requires_return_call(agent: &mut Agent, gc: NoGcScope) -> bool {
     // Note: no one can access the array values iterator while it is
     // iterating so we know its prototype is the intrinsic
@@ -1109,13 +1500,12 @@ impl HeapMarkAndSweep for ObjectPropertiesIteratorRecord<'static> {
             object,
             visited_keys,
             remaining_keys,
+            object_is_visited: _,
         } = self;
         object.mark_values(queues);
         visited_keys.mark_values(queues);
-        if let Some(remaining_keys) = remaining_keys {
-            for key in remaining_keys.iter() {
-                key.mark_values(queues);
-            }
+        for key in remaining_keys.iter() {
+            key.mark_values(queues);
         }
     }
 
@@ -1124,13 +1514,12 @@ impl HeapMarkAndSweep for ObjectPropertiesIteratorRecord<'static> {
             object,
             visited_keys,
             remaining_keys,
+            object_is_visited: _,
         } = self;
         object.sweep_values(compactions);
         visited_keys.sweep_values(compactions);
-        if let Some(remaining_keys) = remaining_keys {
-            for key in remaining_keys.iter_mut() {
-                key.sweep_values(compactions);
-            }
+        for key in remaining_keys.iter_mut() {
+            key.sweep_values(compactions);
         }
     }
 }
@@ -1145,12 +1534,57 @@ impl HeapMarkAndSweep for ArrayValuesIteratorRecord<'static> {
     }
 }
 
+#[cfg(feature = "array-buffer")]
+impl HeapMarkAndSweep for TypedArrayValuesIteratorRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        self.typed_array.mark_values(queues)
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        self.typed_array.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for MapIteratorRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self { iterator, entry } = self;
+        iterator.mark_values(queues);
+        if let Some((key, value)) = entry {
+            key.mark_values(queues);
+            value.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self { iterator, entry } = self;
+        iterator.sweep_values(compactions);
+        if let Some((key, value)) = entry {
+            key.sweep_values(compactions);
+            value.sweep_values(compactions);
+        }
+    }
+}
+
+impl HeapMarkAndSweep for MapEntryValuesIteratorRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        self.entry.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        self.entry.sweep_values(compactions);
+    }
+}
+
 impl HeapMarkAndSweep for VmIteratorRecord<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         match self {
             VmIteratorRecord::InvalidIterator { iterator } => iterator.mark_values(queues),
             VmIteratorRecord::ObjectProperties(iter) => iter.mark_values(queues),
             VmIteratorRecord::ArrayValues(iter) => iter.mark_values(queues),
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(iter) => iter.mark_values(queues),
+            VmIteratorRecord::MapIterator(iter) => iter.mark_values(queues),
+            VmIteratorRecord::MapEntryValues(iter) => iter.mark_values(queues),
             VmIteratorRecord::AsyncFromSyncGenericIterator(iter) => iter.mark_values(queues),
             VmIteratorRecord::GenericIterator(iter) => iter.mark_values(queues),
             VmIteratorRecord::SliceIterator(_) => {}
@@ -1163,6 +1597,10 @@ impl HeapMarkAndSweep for VmIteratorRecord<'static> {
             VmIteratorRecord::InvalidIterator { iterator } => iterator.sweep_values(compactions),
             VmIteratorRecord::ObjectProperties(iter) => iter.sweep_values(compactions),
             VmIteratorRecord::ArrayValues(iter) => iter.sweep_values(compactions),
+            #[cfg(feature = "array-buffer")]
+            VmIteratorRecord::TypedArrayValues(iter) => iter.sweep_values(compactions),
+            VmIteratorRecord::MapIterator(iter) => iter.sweep_values(compactions),
+            VmIteratorRecord::MapEntryValues(iter) => iter.sweep_values(compactions),
             VmIteratorRecord::AsyncFromSyncGenericIterator(iter) => iter.sweep_values(compactions),
             VmIteratorRecord::GenericIterator(iter) => iter.sweep_values(compactions),
             VmIteratorRecord::SliceIterator(_) => {}
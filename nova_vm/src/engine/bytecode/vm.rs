@@ -3,20 +3,21 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod binding_methods;
+mod call_args;
 mod execute_instructions;
 
+use call_args::CallArgs;
 use execute_instructions::*;
 
 use std::{hint::unreachable_unchecked, ptr::NonNull};
-use wtf8::Wtf8Buf;
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, BigInt, Environment, ExceptionType, JsError,
-        JsResult, Number, Object, Primitive, Promise, Reference, ScopedArgumentsList, String,
-        Value, call_function, get_method, is_callable, ordinary_has_instance, to_boolean,
-        to_numeric, to_numeric_primitive, to_primitive, to_property_key, to_string_primitive,
-        try_get_object_method, try_result_into_option_js,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, BigInt, Environment, ExceptionType, GcReason,
+        JsError, JsResult, Number, Object, Primitive, Promise, Reference, ScopedArgumentsList,
+        String, StringBuilder, Value, call_function, get_method, invariant_violation, is_callable,
+        ordinary_has_instance, to_boolean, to_numeric, to_numeric_primitive, to_primitive,
+        to_property_key, to_string_primitive, try_get_object_method, try_result_into_option_js,
     },
     engine::{
         Bindable, GcScope, NoGcScope, Scopable, Scoped, bindable_handle,
@@ -46,7 +47,7 @@ impl<'a> ExecutionResult<'a> {
         match self {
             ExecutionResult::Return(value) => Ok(value),
             ExecutionResult::Throw(err) => Err(err.unbind()),
-            _ => panic!("Unexpected yield or await"),
+            _ => invariant_violation("Unexpected yield or await"),
         }
     }
 }
@@ -78,6 +79,13 @@ enum ExceptionHandler<'a> {
     IgnoreErrorAndNextInstruction,
 }
 
+/// Maximum number of idle VM frames an Agent keeps for reuse.
+const VM_FRAME_POOL_SIZE: usize = 64;
+
+/// VM frames whose value stack has grown beyond this capacity are not kept
+/// for reuse, so that a single large call does not pin its memory.
+const VM_FRAME_POOL_MAX_STACK_CAPACITY: usize = 1024;
+
 /// ## Notes
 ///
 /// - This is inspired by and/or copied from Kiesel engine:
@@ -94,24 +102,27 @@ pub(crate) struct Vm {
     reference: Option<Reference<'static>>,
 }
 
+/// A saved VM frame of a suspended generator or async function.
+///
+/// Suspending and resuming moves the VM's stacks in and out of this struct
+/// as-is, retaining their allocations. Resuming after an `await` or `yield`
+/// thus continues from the saved instruction pointer without copying or
+/// reallocating any of the frame's state.
 #[derive(Debug)]
 pub(crate) struct SuspendedVm {
     ip: usize,
     /// Note: Stack is empty only if the code contains no local variables
-    /// optimised into stack slots or temporarily stored Values. A heap clone is
-    /// probably often performed by the `stack.into_boxed_slice()` call.
-    stack: Box<[Value<'static>]>,
+    /// optimised into stack slots or temporarily stored Values.
+    stack: Vec<Value<'static>>,
     /// Note: Reference stack is non-empty only if the code awaits inside a call
-    /// expression. This means that usually no heap data clone is required.
-    reference_stack: Box<[Reference<'static>]>,
+    /// expression.
+    reference_stack: Vec<Reference<'static>>,
     /// Note: Iterator stack is non-empty only if the code awaits inside a
-    /// for-in or for-of loop. This means that often no heap data clone is
-    /// required.
-    iterator_stack: Box<[VmIteratorRecord<'static>]>,
+    /// for-in or for-of loop.
+    iterator_stack: Vec<VmIteratorRecord<'static>>,
     /// Note: Exception jump stack is non-empty only if the code awaits inside a
-    /// try block or an await for-of loop. This means that often no heap data
-    /// clone is required.
-    exception_jump_target_stack: Box<[ExceptionHandler<'static>]>,
+    /// try block or an await for-of loop.
+    exception_jump_target_stack: Vec<ExceptionHandler<'static>>,
 }
 
 impl SuspendedVm {
@@ -175,7 +186,14 @@ impl SuspendedVm {
 }
 
 impl Vm {
-    fn new() -> Self {
+    /// Take an idle VM frame from the Agent's pool, or allocate a new one if
+    /// the pool is empty.
+    fn new(agent: &mut Agent) -> Self {
+        if let Some(vm) = agent.vm_frame_pool.pop() {
+            agent.gc_stats.vm_frames_reused += 1;
+            return vm;
+        }
+        agent.gc_stats.vm_frames_allocated += 1;
         Self {
             ip: 0,
             stack: Vec::with_capacity(32),
@@ -187,23 +205,45 @@ impl Vm {
         }
     }
 
+    /// Return a finished VM frame into the Agent's pool for reuse.
+    ///
+    /// Frames that were suspended have already moved their stacks out and
+    /// are simply dropped, as are frames whose stacks have grown unusually
+    /// large.
+    fn recycle(mut self, agent: &mut Agent) {
+        if self.stack.capacity() == 0
+            || self.stack.capacity() > VM_FRAME_POOL_MAX_STACK_CAPACITY
+            || agent.vm_frame_pool.len() >= VM_FRAME_POOL_SIZE
+        {
+            return;
+        }
+        self.ip = 0;
+        self.stack.clear();
+        self.reference_stack.clear();
+        self.iterator_stack.clear();
+        self.exception_handler_stack.clear();
+        self.result = None;
+        self.reference = None;
+        agent.vm_frame_pool.push(self);
+    }
+
     fn suspend(self) -> SuspendedVm {
         SuspendedVm {
             ip: self.ip,
-            stack: self.stack.into_boxed_slice(),
-            reference_stack: self.reference_stack.into_boxed_slice(),
-            iterator_stack: self.iterator_stack.into_boxed_slice(),
-            exception_jump_target_stack: self.exception_handler_stack.into_boxed_slice(),
+            stack: self.stack,
+            reference_stack: self.reference_stack,
+            iterator_stack: self.iterator_stack,
+            exception_jump_target_stack: self.exception_handler_stack,
         }
     }
 
     fn from_suspended(suspended: SuspendedVm) -> Self {
         Self {
             ip: suspended.ip,
-            stack: suspended.stack.into_vec(),
-            reference_stack: suspended.reference_stack.into_vec(),
-            iterator_stack: suspended.iterator_stack.into_vec(),
-            exception_handler_stack: suspended.exception_jump_target_stack.into_vec(),
+            stack: suspended.stack,
+            reference_stack: suspended.reference_stack,
+            iterator_stack: suspended.iterator_stack,
+            exception_handler_stack: suspended.exception_jump_target_stack,
             result: None,
             reference: None,
         }
@@ -216,7 +256,7 @@ impl Vm {
         arguments: Option<&mut [Value<'static>]>,
         gc: GcScope<'gc, '_>,
     ) -> ExecutionResult<'gc> {
-        let mut vm = Vm::new();
+        let mut vm = Vm::new(agent);
 
         if let Some(arguments) = arguments {
             ArgumentsList::from_mut_slice(arguments).with_scoped(
@@ -287,6 +327,7 @@ impl Vm {
             if agent.options.print_internals {
                 eprintln!("Exiting function with error\n");
             }
+            self.recycle(agent);
             return ExecutionResult::Throw(err);
         }
         self.inner_execute(agent, executable, gc)
@@ -302,8 +343,33 @@ impl Vm {
         let instructions = executable.get_instructions(agent);
         while let Some(instr) = Instr::consume_instruction(instructions, &mut self.ip) {
             if agent.check_gc() {
-                self.trigger_gc(agent, gc.reborrow());
+                self.trigger_gc(agent, GcReason::AllocationThreshold, gc.reborrow());
+            }
+            if agent.memory_limit_exceeded() {
+                // Collect garbage before giving up on the script.
+                self.trigger_gc(agent, GcReason::MemoryLimit, gc.reborrow());
+                if agent.memory_limit_exceeded() {
+                    let err = agent.throw_memory_limit_error(gc.nogc());
+                    // SAFETY: result is not Ok(ContinuationKind::Normal).
+                    if let Some(r) =
+                        unsafe { self.handle_execute_instruction_abnormal_result(agent, Err(err)) }
+                    {
+                        self.recycle(agent);
+                        return r.unbind().bind(gc.into_nogc());
+                    }
+                }
             }
+            if agent.consume_fuel() {
+                let err = agent.throw_fuel_exhausted_error(gc.nogc());
+                // SAFETY: result is not Ok(ContinuationKind::Normal).
+                if let Some(r) =
+                    unsafe { self.handle_execute_instruction_abnormal_result(agent, Err(err)) }
+                {
+                    self.recycle(agent);
+                    return r.unbind().bind(gc.into_nogc());
+                }
+            }
+            agent.sample_allocations();
             if agent.options.print_internals {
                 Self::print_executing(instr.kind);
             }
@@ -320,6 +386,7 @@ impl Vm {
                 _ => unsafe {
                     if let Some(r) = self.handle_execute_instruction_abnormal_result(agent, result)
                     {
+                        self.recycle(agent);
                         return r.unbind().bind(gc.into_nogc());
                     }
                 },
@@ -327,6 +394,7 @@ impl Vm {
             agent.stack_refs.borrow_mut().truncate(stack_depth);
         }
 
+        self.recycle(agent);
         ExecutionResult::Return(Value::Undefined)
     }
 
@@ -387,8 +455,13 @@ impl Vm {
 
     #[inline(never)]
     #[cold]
-    fn trigger_gc(&mut self, agent: &mut Agent, gc: GcScope) {
-        with_vm_gc(agent, self, |agent, gc| agent.gc(gc), gc);
+    fn trigger_gc(&mut self, agent: &mut Agent, reason: GcReason, gc: GcScope) {
+        with_vm_gc(
+            agent,
+            self,
+            |agent, gc| agent.gc_with_reason(reason, gc),
+            gc,
+        );
     }
 
     #[inline(never)]
@@ -425,6 +498,10 @@ impl Vm {
     #[cold]
     #[must_use]
     fn handle_error(&mut self, agent: &mut Agent, err: JsError) -> bool {
+        // Note: terminated code does not run catch or finally blocks.
+        if agent.is_terminating() {
+            return false;
+        }
         if let Some(handler) = self.exception_handler_stack.pop() {
             match handler {
                 ExceptionHandler::CatchBlock {
@@ -482,7 +559,15 @@ impl Vm {
             Instruction::PopStack => {
                 vm.execute_pop_stack();
             }
-            Instruction::Jump => execute_jump(agent, vm, instr),
+            Instruction::Jump => {
+                let ip = vm.ip;
+                execute_jump(agent, vm, instr);
+                // Note: loops jump backwards; check for termination requests
+                // on each iteration.
+                if vm.ip < ip && agent.is_terminating() {
+                    return Err(agent.throw_termination_error(gc.into_nogc()));
+                }
+            }
             Instruction::JumpIfNot => execute_jump_if_not(agent, vm, instr),
             Instruction::ResolveBinding => {
                 execute_resolve_binding(agent, vm, executable, instr, gc)?;
@@ -652,6 +737,12 @@ impl Vm {
             }
             Instruction::Increment => execute_increment(agent, vm, gc.into_nogc()),
             Instruction::Decrement => execute_decrement(agent, vm, gc.into_nogc()),
+            Instruction::IncrementReferenceInPlace => {
+                execute_update_reference_in_place(agent, vm, instr, 1.0)
+            }
+            Instruction::DecrementReferenceInPlace => {
+                execute_update_reference_in_place(agent, vm, instr, -1.0)
+            }
             Instruction::InstanceofOperator => execute_instanceof_operator(agent, vm, gc)?,
             Instruction::InstantiateArrowFunctionExpression => {
                 execute_instantiate_arrow_function_expression(agent, vm, executable, instr, gc)?
@@ -757,6 +848,7 @@ impl Vm {
             Instruction::GetIteratorSync => execute_get_iterator_sync(agent, vm, gc)?,
             Instruction::GetIteratorAsync => execute_get_iterator_async(agent, vm, gc)?,
             Instruction::IteratorStepValue => execute_iterator_step_value(agent, vm, instr, gc)?,
+            Instruction::IteratorStepEntry => execute_iterator_step_entry(agent, vm, instr, gc)?,
             Instruction::IteratorStepValueOrUndefined => {
                 execute_iterator_step_value_or_undefined(agent, vm, gc)?
             }
@@ -789,32 +881,40 @@ impl Vm {
             Instruction::VerifyIsObject => {
                 execute_verify_is_object(agent, vm, executable, instr, gc.into_nogc())?
             }
-            Instruction::Debug => execute_debug(agent, vm),
+            Instruction::Debug => execute_debug(agent, vm, gc),
         };
         Ok(ContinuationKind::Normal)
     }
 
-    fn get_call_args<'gc>(&mut self, instr: Instr, _gc: NoGcScope<'gc, '_>) -> Vec<Value<'gc>> {
+    fn get_call_args<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        instr: Instr,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, CallArgs<'gc>> {
         let instr_arg0 = instr.get_first_arg();
         if instr_arg0 != IndexType::MAX {
             // Static number of arguments less than IndexType::MAX.
             let arg_count = instr_arg0 as usize;
-            debug_assert!(self.stack.len() >= arg_count);
-            self.stack.split_off(self.stack.len() - arg_count)
+            agent.check_arguments_length(arg_count, gc)?;
+            Ok(CallArgs::split_off(&mut self.stack, arg_count))
         } else {
             // Dynamic number of arguments, or exactly IndexType::MAX or more
             // arguments. In this case the number of arguments is stored in the
             // result register for us. Additionally, an extra accumulator value
             // is stored on the stack before the arguments.
             let Value::Integer(integer) = self.result.take().unwrap() else {
-                panic!("Expected the number of function arguments to be an integer")
+                invariant_violation("Expected the number of function arguments to be an integer")
             };
             let arg_count = usize::try_from(integer.into_i64()).unwrap();
+            // Note: Arguments left on the stack are removed by the catch
+            // block's stack reset.
+            agent.check_arguments_length(arg_count, gc)?;
             debug_assert!(self.stack.len() > arg_count);
-            let args = self.stack.split_off(self.stack.len() - arg_count);
+            let args = CallArgs::split_off(&mut self.stack, arg_count);
             let integer_copy = self.stack.pop().unwrap();
             debug_assert_eq!(Value::Integer(integer), integer_copy);
-            args
+            Ok(args)
         }
     }
 
@@ -987,11 +1087,11 @@ fn concat_string_from_slice<'gc>(
     string_length: usize,
     gc: NoGcScope<'gc, '_>,
 ) -> String<'gc> {
-    let mut result_string = Wtf8Buf::with_capacity(string_length);
+    let mut result_string = StringBuilder::with_capacity(string_length);
     for string in slice.iter() {
-        result_string.push_wtf8(string.as_wtf8_(agent));
+        result_string.push_string(agent, *string);
     }
-    String::from_wtf8_buf(agent, result_string, gc)
+    result_string.finish(agent, gc)
 }
 
 /// ### [13.15.3 ApplyStringOrNumericBinaryOperator ( lval, opText, rval )](https://tc39.es/ecma262/#sec-applystringornumericbinaryoperator)
@@ -1313,7 +1413,7 @@ pub(crate) fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> Strin
         Value::BuiltinConstructorFunction(_) |
         Value::BuiltinPromiseResolvingFunction(_) |
         Value::BuiltinPromiseFinallyFunction(_) |
-        Value::BuiltinProxyRevokerFunction => BUILTIN_STRING_MEMORY.function,
+        Value::BuiltinProxyRevokerFunction(_) => BUILTIN_STRING_MEMORY.function,
         Value::Proxy(proxy) => {
             if proxy.is_callable(agent, gc) {
                 BUILTIN_STRING_MEMORY.function
@@ -1353,7 +1453,7 @@ pub(crate) fn instanceof_operator<'a, 'b>(
             "Invalid instanceof target {}.",
             target
                 .unbind()
-                .string_repr(agent, gc.reborrow())
+                .try_string_repr(agent, gc.nogc())
                 .to_string_lossy_(agent)
         );
         return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc.into_nogc()));
@@ -1403,7 +1503,7 @@ pub(crate) fn instanceof_operator<'a, 'b>(
                 "Invalid instanceof target {} is not a function.",
                 Value::from(target)
                     .unbind()
-                    .string_repr(agent, gc.reborrow())
+                    .try_string_repr(agent, gc.nogc())
                     .to_string_lossy_(agent)
             );
             return Err(agent.throw_exception(
@@ -1512,10 +1612,10 @@ impl HeapMarkAndSweep for SuspendedVm {
             iterator_stack,
             exception_jump_target_stack,
         } = self;
-        stack.mark_values(queues);
-        reference_stack.mark_values(queues);
-        iterator_stack.mark_values(queues);
-        exception_jump_target_stack.mark_values(queues);
+        stack.as_slice().mark_values(queues);
+        reference_stack.as_slice().mark_values(queues);
+        iterator_stack.as_slice().mark_values(queues);
+        exception_jump_target_stack.as_slice().mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -1526,10 +1626,12 @@ impl HeapMarkAndSweep for SuspendedVm {
             iterator_stack,
             exception_jump_target_stack,
         } = self;
-        stack.sweep_values(compactions);
-        reference_stack.sweep_values(compactions);
-        iterator_stack.sweep_values(compactions);
-        exception_jump_target_stack.sweep_values(compactions);
+        stack.as_mut_slice().sweep_values(compactions);
+        reference_stack.as_mut_slice().sweep_values(compactions);
+        iterator_stack.as_mut_slice().sweep_values(compactions);
+        exception_jump_target_stack
+            .as_mut_slice()
+            .sweep_values(compactions);
     }
 }
 
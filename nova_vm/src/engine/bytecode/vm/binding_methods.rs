@@ -14,7 +14,7 @@ use crate::{
     },
 };
 
-use super::with_vm_gc;
+use super::{verify_is_object, with_vm_gc};
 
 pub(super) fn execute_simple_array_binding<'a>(
     agent: &mut Agent,
@@ -199,24 +199,23 @@ pub(super) fn execute_simple_array_binding<'a>(
     // BindingPattern : ArrayBindingPattern
     // 3. If iteratorRecord.[[Done]] is false, return
     //    ? IteratorClose(iteratorRecord, result).
-    // NOTE: `result` here is always UNUSED. We use `undefined` as a stand-in
-    // since that way we don't need to implement a separate iterator_close.
+    // NOTE: `result` here is always UNUSED.
     if !iterator_is_done {
         let iter = vm.get_active_iterator_mut();
         if iter.requires_return_call(agent, gc.nogc()) {
             let result = with_vm_gc(
                 agent,
                 vm,
-                |agent, gc| {
-                    ActiveIterator::new(agent, gc.nogc()).r#return(
-                        agent,
-                        Some(Value::Undefined),
-                        gc,
-                    )
-                },
-                gc,
-            )?;
-            vm.result = result.unbind();
+                |agent, gc| ActiveIterator::new(agent, gc.nogc()).r#return(agent, None, gc),
+                gc.reborrow(),
+            );
+            // The iterator is closed even if the return call threw an error:
+            // make sure that our error handler does not observably call
+            // return again.
+            *vm.get_active_iterator_mut() = VmIteratorRecord::EmptySliceIterator;
+            if let Some(result) = result.unbind()?.bind(gc.nogc()) {
+                verify_is_object(agent, result.unbind(), gc.into_nogc())?;
+            }
         }
     }
 
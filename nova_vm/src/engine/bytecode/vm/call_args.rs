@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::Deref;
+
+use crate::{ecmascript::Value, engine::bindable_handle};
+
+/// Maximum number of call arguments that are stored inline.
+const INLINE_CALL_ARGS: usize = 4;
+
+/// Arguments taken off the VM stack for a call.
+///
+/// The vast majority of calls pass only a few arguments: these are stored in
+/// fixed inline slots so that a call does not need to allocate a Vec for its
+/// arguments. Calls with more arguments fall back to a heap allocated Vec.
+pub(super) enum CallArgs<'a> {
+    Inline {
+        len: u8,
+        values: [Value<'a>; INLINE_CALL_ARGS],
+    },
+    Heap(Vec<Value<'a>>),
+}
+bindable_handle!(CallArgs);
+
+impl<'a> CallArgs<'a> {
+    /// Move the last `count` values of `stack` into a new CallArgs.
+    #[inline]
+    pub(super) fn split_off(stack: &mut Vec<Value<'a>>, count: usize) -> Self {
+        debug_assert!(stack.len() >= count);
+        let start = stack.len() - count;
+        if count <= INLINE_CALL_ARGS {
+            let mut values = [Value::Undefined; INLINE_CALL_ARGS];
+            values[..count].copy_from_slice(&stack[start..]);
+            stack.truncate(start);
+            Self::Inline {
+                len: count as u8,
+                values,
+            }
+        } else {
+            Self::Heap(stack.split_off(start))
+        }
+    }
+
+    #[inline]
+    pub(super) fn as_mut_slice(&mut self) -> &mut [Value<'a>] {
+        match self {
+            Self::Inline { len, values } => &mut values[..*len as usize],
+            Self::Heap(values) => values.as_mut_slice(),
+        }
+    }
+}
+
+impl<'a> Deref for CallArgs<'a> {
+    type Target = [Value<'a>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Inline { len, values } => &values[..*len as usize],
+            Self::Heap(values) => values.as_slice(),
+        }
+    }
+}
@@ -165,10 +165,17 @@ pub(super) fn execute_bitwise_not<'gc>(
 
 #[inline(never)]
 #[cold]
-pub(super) fn execute_debug(agent: &Agent, vm: &Vm) {
+pub(super) fn execute_debug(agent: &mut Agent, vm: &mut Vm, gc: GcScope) {
     if agent.options.print_internals {
         eprintln!("Debug: {vm:#?}");
     }
+    let host_hooks = agent.host_hooks;
+    with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| host_hooks.debugger_statement(agent, gc),
+        gc,
+    );
 }
 
 #[inline(always)]
@@ -913,7 +920,8 @@ pub(super) fn execute_copy_data_properties_into_object<'gc>(
 
     let num_excluded_items = instr.get_first_index();
     let mut excluded_items = PropertyKeySet::with_capacity(num_excluded_items, gc.nogc());
-    assert!(vm.reference.is_none());
+    // Note: the reference register may hold the reference to the rest
+    // property's assignment target; it is left untouched.
     for _ in 0..num_excluded_items {
         let reference = vm.reference_stack.pop().unwrap();
         debug_assert_eq!(reference.base_value(), from.into());
@@ -1561,7 +1569,10 @@ pub(super) fn execute_direct_eval_call<'gc>(
     )
     .unbind()?
     .bind(gc.nogc());
-    let args = vm.get_call_args(instr, gc.nogc());
+    let args = vm
+        .get_call_args(agent, instr, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
 
     // a. If SameValue(func, %eval%) is true, then
     let result = if func == agent.current_realm_record().intrinsics().eval().into() {
@@ -1636,7 +1647,7 @@ pub(super) fn execute_evaluate_call<'gc>(
         // a. Let thisValue be undefined.
         Value::Undefined
     };
-    let mut args = vm.get_call_args(instr, gc.nogc()).unbind();
+    let mut args = vm.get_call_args(agent, instr, gc.nogc()).unbind()?;
     let func = vm.stack.pop().unwrap().unbind();
     let this_value = this_value.unbind();
     let result = with_vm_gc(
@@ -1661,20 +1672,15 @@ pub(super) fn execute_evaluate_new<'gc>(
     agent: &mut Agent,
     vm: &mut Vm,
     instr: Instr,
-    mut gc: GcScope<'gc, '_>,
+    gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, ()> {
-    let args = vm.get_call_args(instr, gc.nogc());
+    let args = vm
+        .get_call_args(agent, instr, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
     let constructor = vm.stack.pop().unwrap().bind(gc.nogc());
     let Some(constructor) = is_constructor(agent, constructor) else {
-        let constructor_string = {
-            let constructor = constructor.unbind();
-            with_vm_gc(
-                agent,
-                vm,
-                |agent, gc| constructor.string_repr(agent, gc),
-                gc.reborrow(),
-            )
-        };
+        let constructor_string = constructor.try_string_repr(agent, gc.nogc());
         let error_message = format!(
             "'{}' is not a constructor.",
             constructor_string.to_string_lossy_(agent)
@@ -1725,20 +1731,18 @@ pub(super) fn execute_evaluate_super<'gc>(
         )
     };
     // 4. Let argList be ? ArgumentListEvaluation of Arguments.
-    let arg_list = vm.get_call_args(instr, gc.nogc());
+    let arg_list = vm
+        .get_call_args(agent, instr, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
     // 5. If IsConstructor(func) is false, throw a TypeError exception.
     let Some(func) = func.and_then(|func| is_constructor(agent, func)) else {
         let constructor = func.map_or(Value::Null, |f| f.unbind().into());
-        let error_message = with_vm_gc(
-            agent,
-            vm,
-            |agent, gc| {
-                format!(
-                    "'{}' is not a constructor.",
-                    constructor.string_repr(agent, gc).to_string_lossy_(agent)
-                )
-            },
-            gc.reborrow(),
+        let error_message = format!(
+            "'{}' is not a constructor.",
+            constructor
+                .try_string_repr(agent, gc.nogc())
+                .to_string_lossy_(agent)
         );
         return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc.into_nogc()));
     };
@@ -2005,6 +2009,87 @@ pub(super) fn execute_decrement(agent: &mut Agent, vm: &mut Vm, gc: NoGcScope) {
     vm.result = Some(new_value.unbind());
 }
 
+/// Increment or decrement the Number value referred to by the reference
+/// register in place.
+///
+/// This avoids looking up the referenced binding or Array element separately
+/// for GetValue and PutValue. If the referenced value cannot be updated in
+/// place, the reference register is left untouched and execution continues
+/// with the generic update sequence following this instruction.
+pub(super) fn execute_update_reference_in_place(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    instr: Instr,
+    delta: f64,
+) {
+    let reference = vm.reference.as_ref().unwrap();
+    let slot = match reference {
+        Reference::Variable(_) | Reference::VariableStrict(_) => reference
+            .base_env()
+            .get_mutable_binding_value_mut(agent, reference.referenced_name_string()),
+        Reference::PropertyExpression(_) | Reference::PropertyExpressionStrict(_) => {
+            match (reference.base_value(), reference.referenced_name_value()) {
+                (Value::Array(array), Value::Integer(index)) => {
+                    get_array_data_element_mut(agent, array, index.into_i64())
+                }
+                _ => None,
+            }
+        }
+        Reference::Property(_) | Reference::PropertyStrict(_) => {
+            match (
+                reference.base_value(),
+                reference.referenced_name_property_key(),
+            ) {
+                (Value::Array(array), PropertyKey::Integer(index)) => {
+                    get_array_data_element_mut(agent, array, index.into_i64())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let Some(slot) = slot else {
+        return;
+    };
+    let old_value = *slot;
+    let Some(new_value) = add_to_stack_number(old_value, delta) else {
+        return;
+    };
+    *slot = new_value;
+    vm.result = Some(old_value);
+    vm.reference = None;
+    vm.ip = instr.get_jump_slot();
+}
+
+/// Get exclusive access to an Array element that is a writable data
+/// property.
+fn get_array_data_element_mut<'a>(
+    agent: &'a mut Agent,
+    array: Array,
+    index: i64,
+) -> Option<&'a mut Value<'static>> {
+    let index = u32::try_from(index).ok()?;
+    if let Some(descriptors) = array.get_storage(agent).descriptors
+        && descriptors.contains_key(&index)
+    {
+        return None;
+    }
+    array.as_mut_slice(agent).get_mut(index as usize)?.as_mut()
+}
+
+/// Add delta to a Number stored directly in a Value.
+///
+/// Returns None if the value is not a Number, is stored on the heap, or if
+/// the result would need to be allocated on the heap.
+fn add_to_stack_number(value: Value, delta: f64) -> Option<Value<'static>> {
+    let value = match value {
+        Value::Integer(value) => value.into_i64() as f64,
+        Value::SmallF64(value) => value.into_f64(),
+        _ => return None,
+    };
+    Value::try_from(value + delta).ok()
+}
+
 pub(super) fn execute_less_than<'gc>(
     agent: &mut Agent,
     vm: &mut Vm,
@@ -2530,7 +2615,13 @@ pub(super) fn execute_get_iterator_sync<'gc>(
     vm: &mut Vm,
     gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, ()> {
-    let expr_value = vm.result.take().unwrap();
+    let Some(expr_value) = vm.result.take() else {
+        // IteratorStepEntry held a Map entry in the active iterator instead of
+        // creating an entry Array: iterate the entry's key and value.
+        let entry = vm.get_active_iterator_mut().take_entry();
+        vm.iterator_stack.push(entry);
+        return Ok(());
+    };
     let result = with_vm_gc(
         agent,
         vm,
@@ -2563,12 +2654,21 @@ pub(super) fn execute_iterator_step_value<'gc>(
     instr: Instr,
     gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, ()> {
-    let result = with_vm_gc(
-        agent,
-        vm,
-        |agent, gc| ActiveIterator::new(agent, gc.nogc()).step_value(agent, gc),
-        gc,
-    )?;
+    // Fast path: Array and TypedArray values iterators can be stepped without
+    // calling into JavaScript.
+    let result = if let Some(r) = try_result_into_option_js(
+        vm.get_active_iterator_mut()
+            .try_step_value(agent, gc.nogc()),
+    ) {
+        r.unbind().bind(gc.into_nogc())?
+    } else {
+        with_vm_gc(
+            agent,
+            vm,
+            |agent, gc| ActiveIterator::new(agent, gc.nogc()).step_value(agent, gc),
+            gc,
+        )?
+    };
     vm.result = result.unbind();
     if result.is_none() {
         // Iterator finished: jump to escape the iterator loop.
@@ -2581,6 +2681,28 @@ pub(super) fn execute_iterator_step_value<'gc>(
     Ok(())
 }
 
+pub(super) fn execute_iterator_step_entry<'gc>(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    instr: Instr,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    // Fast path: Map entries can be destructured without creating an entry
+    // Array.
+    if let VmIteratorRecord::MapIterator(iter) = vm.get_active_iterator_mut()
+        && let Some(has_entry) = iter.step_entry(agent, gc.nogc())
+    {
+        if has_entry {
+            vm.result = None;
+        } else {
+            // Iterator finished: jump to escape the iterator loop.
+            vm.ip = instr.get_jump_slot();
+        }
+        return Ok(());
+    }
+    execute_iterator_step_value(agent, vm, instr, gc)
+}
+
 pub(super) fn execute_iterator_step_value_or_undefined<'gc>(
     agent: &mut Agent,
     vm: &mut Vm,
@@ -2833,10 +2955,12 @@ pub(super) fn execute_iterator_close<'gc>(
         vm,
         |agent, gc| ActiveIterator::new(agent, gc.nogc()).r#return(agent, None, gc),
         gc.reborrow(),
-    )
-    .unbind()?
-    .bind(gc.nogc());
-    if let Some(result) = result {
+    );
+    // The iterator is closed even if the return call threw an error: replace
+    // it with the empty slice iterator so that an error handler does not
+    // observably call return again.
+    *vm.get_active_iterator_mut() = VmIteratorRecord::EmptySliceIterator;
+    if let Some(result) = result.unbind()?.bind(gc.nogc()) {
         // We did get innerResult from return method call: we have
         // to check that it is an object.
         verify_is_object(agent, result.unbind(), gc.into_nogc())?;
@@ -3026,15 +3150,18 @@ pub(super) fn execute_import_meta(agent: &mut Agent, vm: &mut Vm, gc: NoGcScope)
             // a. Set importMeta to OrdinaryObjectCreate(null).
             // c. For each Record { [[Key]], [[Value]] } p of importMetaValues, do
             // i. Perform ! CreateDataPropertyOrThrow(importMeta, p.[[Key]], p.[[Value]]).
-            let import_meta = OrdinaryObject::create_object(
-                agent,
-                None,
-                &import_meta_values
-                    .into_iter()
-                    .map(|(key, value)| ObjectEntry::new_data_entry(key, value))
-                    .collect::<Box<[ObjectEntry]>>(),
-            )
-            .expect("Should perform GC here");
+            // NOTE: A repeated key overwrites the earlier value but keeps its
+            // original position in the property order.
+            let mut entries: Vec<ObjectEntry> = Vec::with_capacity(import_meta_values.len());
+            for (key, value) in import_meta_values {
+                if let Some(entry) = entries.iter_mut().find(|entry| entry.key == key) {
+                    *entry = ObjectEntry::new_data_entry(key, value);
+                } else {
+                    entries.push(ObjectEntry::new_data_entry(key, value));
+                }
+            }
+            let import_meta = OrdinaryObject::create_object(agent, None, &entries)
+                .expect("Should perform GC here");
             // d. Perform HostFinalizeImportMeta(importMeta, module).
             agent
                 .host_hooks
@@ -444,6 +444,7 @@ trivially_bindable!(f16);
 trivially_bindable!(f32);
 trivially_bindable!(f64);
 trivially_bindable!(CodePoint);
+trivially_bindable!(std::string::String);
 
 // SAFETY: Trivially safe.
 unsafe impl<'b, T: 'static + Rootable> Bindable for Scoped<'b, T> {
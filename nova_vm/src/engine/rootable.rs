@@ -4,9 +4,11 @@
 
 mod global;
 mod scoped;
+mod unrooted;
 
 pub use global::*;
 pub use scoped::*;
+pub use unrooted::*;
 
 pub(crate) use private::{HeapRootCollection, Rootable, RootableCollection};
 
@@ -71,16 +73,17 @@ use crate::{
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMASCRIPT_FUNCTION_DISCRIMINANT,
-        ECMAScriptFunction, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT, EmbedderObject,
-        Error, FINALIZATION_REGISTRY_DISCRIMINANT, FinalizationRegistry, FunctionEnvironment,
-        GENERATOR_DISCRIMINANT, Generator, GlobalEnvironment, HeapBigInt, HeapNumber, HeapString,
-        MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map, MapIterator, Module,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DeclarativeEnvironment,
+        ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction, EMBEDDER_OBJECT_DISCRIMINANT,
+        ERROR_DISCRIMINANT, EmbedderObject, Error, FINALIZATION_REGISTRY_DISCRIMINANT,
+        FinalizationRegistry, FunctionEnvironment, GENERATOR_DISCRIMINANT, Generator,
+        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, MAP_DISCRIMINANT,
+        MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map, MapIterator, Module,
         ModuleEnvironment, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, ObjectEnvironment,
         OrdinaryObject, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PrimitiveObject,
         PrivateEnvironment, Promise, PromiseGroup, PromiseReaction, PropertyLookupCache, Proxy,
         Realm, STRING_DISCRIMINANT, STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, Script,
-        SourceCode, SourceTextModule, StringIterator, Symbol,
+        SourceCode, SourceTextModule, StringIterator, Symbol, SyntheticModule,
     },
     heap::HeapMarkAndSweep,
 };
@@ -313,7 +316,8 @@ pub(crate) enum HeapRootData {
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'static>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'static>) =
+        BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'static>),
     Arguments(UnmappedArguments<'static>) = ARGUMENTS_DISCRIMINANT,
     Array(Array<'static>) = ARRAY_DISCRIMINANT,
@@ -425,6 +429,7 @@ pub(crate) enum HeapRootData {
     Realm(Realm<'static>),
     Script(Script<'static>),
     SourceTextModule(SourceTextModule<'static>),
+    SyntheticModule(SyntheticModule<'static>),
     SourceCode(SourceCode<'static>),
     DeclarativeEnvironment(DeclarativeEnvironment<'static>),
     FunctionEnvironment(FunctionEnvironment<'static>),
@@ -493,7 +498,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::BuiltinPromiseFinallyFunction(builtin_promise_finally_function) => {
                 builtin_promise_finally_function.mark_values(queues)
             }
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.mark_values(queues)
+            }
             Self::PrimitiveObject(primitive_object) => primitive_object.mark_values(queues),
             Self::Arguments(ordinary_object) => ordinary_object.mark_values(queues),
             Self::Array(array) => array.mark_values(queues),
@@ -600,6 +607,7 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::Script(script) => script.mark_values(queues),
             Self::SourceCode(source_code) => source_code.mark_values(queues),
             Self::SourceTextModule(m) => m.mark_values(queues),
+            Self::SyntheticModule(m) => m.mark_values(queues),
             Self::DeclarativeEnvironment(declarative_environment_index) => {
                 declarative_environment_index.mark_values(queues)
             }
@@ -647,7 +655,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::BuiltinPromiseFinallyFunction(builtin_promise_finally_function) => {
                 builtin_promise_finally_function.sweep_values(compactions);
             }
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.sweep_values(compactions);
+            }
             Self::PrimitiveObject(primitive_object) => primitive_object.sweep_values(compactions),
             Self::Arguments(ordinary_object) => ordinary_object.sweep_values(compactions),
             Self::Array(array) => array.sweep_values(compactions),
@@ -756,6 +766,7 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::Script(script) => script.sweep_values(compactions),
             Self::SourceCode(source_code) => source_code.sweep_values(compactions),
             Self::SourceTextModule(m) => m.sweep_values(compactions),
+            Self::SyntheticModule(m) => m.sweep_values(compactions),
             Self::DeclarativeEnvironment(declarative_environment_index) => {
                 declarative_environment_index.sweep_values(compactions)
             }
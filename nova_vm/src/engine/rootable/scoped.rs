@@ -92,6 +92,8 @@ impl<'scope, T: Rootable> Scoped<'scope, T> {
             }
             Err(heap_data) => heap_data,
         };
+        #[cfg(debug_assertions)]
+        agent.clear_unrooted_reference(value);
         let mut stack_refs = agent.stack_refs.borrow_mut();
         let next_index = stack_refs.len();
         stack_refs.push(value);
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Rooting validation
+//!
+//! Handles that are unbound from the garbage collector lifetime are not
+//! checked by the borrow checker: if garbage collection runs while an unbound
+//! handle is held in a local variable, the handle silently starts pointing to
+//! moved or reused heap data. In debug builds, such unbinds can be recorded
+//! with [`TrackUnrooted::unbind_tracked`]. The record is cleared when the
+//! handle is bound again using [`TrackUnrooted::bind_tracked`] or rooted using
+//! [`Scopable::scope`]. Garbage collection asserts that no records remain,
+//! turning use-after-compaction bugs into immediate failures that point at the
+//! offending unbind.
+//!
+//! In release builds the tracking methods are equal to plain
+//! [`Bindable::unbind`] and [`Bindable::bind`].
+//!
+//! [`Scopable::scope`]: crate::engine::Scopable::scope
+
+#[cfg(debug_assertions)]
+use core::panic::Location;
+
+#[cfg(debug_assertions)]
+use super::HeapRootData;
+use crate::{
+    ecmascript::Agent,
+    engine::{Bindable, NoGcScope, Rootable},
+};
+
+/// An unbound heap reference recorded by [`TrackUnrooted::unbind_tracked`].
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+pub(crate) struct UnrootedReference {
+    heap_data: HeapRootData,
+    location: &'static Location<'static>,
+}
+
+/// Methods for unbinding and rebinding handles while recording them as
+/// unrooted in debug builds.
+#[allow(private_bounds)]
+pub trait TrackUnrooted: Rootable + Bindable {
+    /// Unbind this value from the garbage collector lifetime, recording it as
+    /// an unrooted heap reference until it is bound again with
+    /// [`bind_tracked`] or rooted with [`Scopable::scope`].
+    ///
+    /// Performing garbage collection while the record exists panics in debug
+    /// builds.
+    ///
+    /// [`bind_tracked`]: TrackUnrooted::bind_tracked
+    /// [`Scopable::scope`]: crate::engine::Scopable::scope
+    #[track_caller]
+    fn unbind_tracked(self, agent: &Agent) -> Self::Of<'static> {
+        #[cfg(debug_assertions)]
+        if let Err(heap_data) = Self::to_root_repr(self) {
+            agent
+                .unrooted_references
+                .borrow_mut()
+                .push(UnrootedReference {
+                    heap_data,
+                    location: Location::caller(),
+                });
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = agent;
+        self.unbind()
+    }
+
+    /// Bind this value to the garbage collector lifetime, clearing a record
+    /// made by [`unbind_tracked`].
+    ///
+    /// [`unbind_tracked`]: TrackUnrooted::unbind_tracked
+    fn bind_tracked<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        #[cfg(debug_assertions)]
+        if let Err(heap_data) = Self::to_root_repr(self) {
+            agent.clear_unrooted_reference(heap_data);
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = agent;
+        self.bind(gc)
+    }
+}
+
+impl<T: Rootable + Bindable> TrackUnrooted for T {}
+
+#[cfg(debug_assertions)]
+impl Agent {
+    /// Clear the latest record of an unrooted heap reference to the given
+    /// heap data, if one exists.
+    pub(crate) fn clear_unrooted_reference(&self, heap_data: HeapRootData) {
+        let mut unrooted_references = self.unrooted_references.borrow_mut();
+        if let Some(index) = unrooted_references
+            .iter()
+            .rposition(|reference| reference.heap_data == heap_data)
+        {
+            unrooted_references.remove(index);
+        }
+    }
+
+    /// Assert that no unrooted heap references are recorded. This is called
+    /// at the start of garbage collection.
+    ///
+    /// The records are cleared before panicking, so that the failure is only
+    /// reported once.
+    pub(crate) fn assert_no_unrooted_references(&self) {
+        let unrooted_references = self.unrooted_references.take();
+        if unrooted_references.is_empty() {
+            return;
+        }
+        let references = unrooted_references
+            .iter()
+            .map(|reference| {
+                format!(
+                    "{:?} unbound at {}",
+                    reference.heap_data, reference.location
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("Garbage collection with unrooted heap references:\n{references}");
+    }
+}
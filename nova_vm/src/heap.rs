@@ -22,7 +22,7 @@ pub(crate) use indexes::*;
 pub(crate) use object_entry::*;
 
 use core::cell::RefCell;
-use std::ops::Deref;
+use std::{ops::Deref, sync::Arc};
 
 #[cfg(feature = "date")]
 use crate::ecmascript::DateHeapData;
@@ -49,14 +49,15 @@ use crate::{
         Agent, ArrayHeapData, ArrayIteratorHeapData, AsyncGeneratorHeapData, AwaitReactionRecord,
         BUILTIN_STRING_MEMORY, BUILTIN_STRINGS_LIST, BigIntHeapData, BoundFunctionHeapData,
         BuiltinConstructorRecord, BuiltinFunctionHeapData, Caches, ECMAScriptFunctionHeapData,
-        EmbedderObjectHeapData, Environments, ErrorHeapData, FinalizationRegistryRecord,
-        GeneratorHeapData, HeapString, MapHeapData, MapIteratorHeapData, ModuleHeapData,
-        ModuleRequestRecord, NumberHeapData, ObjectRecord, ObjectShapeRecord,
-        ObjectShapeTransitionMap, PrimitiveObjectRecord, PromiseFinallyFunctionHeapData,
-        PromiseGroupRecord, PromiseHeapData, PromiseReactionRecord,
-        PromiseResolvingFunctionHeapData, PrototypeShapeTable, ProxyHeapData, RealmRecord,
-        ScriptRecord, SourceCodeHeapData, SourceTextModuleHeap, String, StringIteratorHeapData,
-        StringRecord, SymbolHeapData,
+        EmbedderObjectHeapData, Environments, ErrorHeapData, ExternalStringBuffer,
+        FinalizationRegistryRecord, GeneratorHeapData, HeapString, MapHeapData,
+        MapIteratorHeapData, ModuleHeapData, ModuleRequestRecord, NumberHeapData, ObjectRecord,
+        ObjectShapeRecord, ObjectShapeTransitionMap, PrimitiveObjectRecord,
+        PromiseFinallyFunctionHeapData, PromiseGroupRecord, PromiseHeapData, PromiseReactionRecord,
+        PromiseResolvingFunctionHeapData, PrototypeShapeTable, ProxyHeapData,
+        ProxyRevokerFunctionHeapData, RealmRecord, ScriptRecord, SourceCodeHeapData,
+        SourceTextModuleHeap, String, StringIteratorHeapData, StringRecord, SymbolHeapData,
+        SyntheticModuleRecord,
     },
     engine::{ExecutableHeapData, HeapRootData},
 };
@@ -114,6 +115,7 @@ pub(crate) struct Heap {
     pub(crate) promise_finally_functions: Vec<PromiseFinallyFunctionHeapData<'static>>,
     pub(crate) promises: Vec<PromiseHeapData<'static>>,
     pub(crate) proxies: Vec<ProxyHeapData<'static>>,
+    pub(crate) proxy_revoker_functions: Vec<ProxyRevokerFunctionHeapData<'static>>,
     pub(crate) realms: Vec<RealmRecord<'static>>,
     pub(crate) promise_group_records: Vec<PromiseGroupRecord<'static>>,
     #[cfg(feature = "regexp")]
@@ -164,6 +166,7 @@ pub(crate) struct Heap {
     pub(crate) modules: Vec<ModuleHeapData<'static>>,
     pub(crate) module_request_records: Vec<ModuleRequestRecord<'static>>,
     pub(crate) source_text_module_records: SourceTextModuleHeap,
+    pub(crate) synthetic_module_records: Vec<SyntheticModuleRecord<'static>>,
     pub(crate) scripts: Vec<ScriptRecord<'static>>,
     pub(crate) string_iterators: Vec<StringIteratorHeapData<'static>>,
     // Parsed ASTs referred by functions must be dropped after functions.
@@ -290,6 +293,7 @@ impl Heap {
             promises: Vec::with_capacity(0),
             promise_group_records: Vec::with_capacity(0),
             proxies: Vec::with_capacity(0),
+            proxy_revoker_functions: Vec::with_capacity(0),
             realms: Vec::with_capacity(1),
             #[cfg(feature = "regexp")]
             regexps: Vec::with_capacity(1024),
@@ -303,6 +307,7 @@ impl Heap {
             #[cfg(feature = "shared-array-buffer")]
             shared_array_buffers: Vec::with_capacity(0),
             source_text_module_records: SourceTextModuleHeap(Vec::with_capacity(128)),
+            synthetic_module_records: Vec::with_capacity(0),
             strings: Vec::with_capacity(1024),
             string_iterators: Vec::with_capacity(0),
             string_lookup_table: HashTable::with_capacity(1024),
@@ -353,9 +358,9 @@ impl Heap {
         let string_lookup_table = &mut heap.string_lookup_table;
         for builtin_string in BUILTIN_STRINGS_LIST.iter() {
             let hash = string_hasher.hash_one(Wtf8::from_str(builtin_string));
-            let data = StringRecord::from_static_str(builtin_string);
+            let data = StringRecord::from_static_str(builtin_string, hash);
             // SAFETY: heap is entry.
-            unsafe { String::insert_string_with_hash(strings, string_lookup_table, data, hash) };
+            unsafe { String::insert_string_with_hash(strings, string_lookup_table, data) };
         }
 
         heap.symbols.extend_from_slice(&[
@@ -401,8 +406,8 @@ impl Heap {
         match found {
             Ok(string) => string,
             Err(hash) => {
-                let data = StringRecord::from_str(message);
-                self.create((data, hash))
+                let data = StringRecord::from_str(message, hash);
+                self.create(data)
             }
         }
     }
@@ -424,8 +429,8 @@ impl Heap {
         match found {
             Ok(string) => string,
             Err(hash) => {
-                let data = StringRecord::from_string(message);
-                self.create((data, hash))
+                let data = StringRecord::from_string(message, hash);
+                self.create(data)
             }
         }
     }
@@ -447,8 +452,35 @@ impl Heap {
         match found {
             Ok(string) => string,
             Err(hash) => {
-                let data = StringRecord::from_wtf8_buf(message);
-                self.create((data, hash))
+                let data = StringRecord::from_wtf8_buf(message, hash);
+                self.create(data)
+            }
+        }
+    }
+
+    /// Allocate string data owned outside of the heap onto the Agent heap
+    /// without copying it.
+    ///
+    /// This method will hash the input and look for a matching string on the
+    /// heap, and if found will return its HeapString and drop the external
+    /// data instead.
+    ///
+    /// # Safety
+    ///
+    /// The string being allocated must not be representable as a
+    /// SmallString. All SmallStrings must be kept on the stack to ensure that
+    /// comparison between heap allocated strings and SmallStrings can be
+    /// guaranteed to never equal true.
+    pub(crate) unsafe fn alloc_external_str(
+        &mut self,
+        data: Arc<dyn AsRef<str> + Send + Sync>,
+    ) -> String<'static> {
+        let found = self.find_equal_string((*data).as_ref());
+        match found {
+            Ok(string) => string,
+            Err(hash) => {
+                let data = StringRecord::from_external(ExternalStringBuffer::new(data), hash);
+                self.create(data)
             }
         }
     }
@@ -470,8 +502,8 @@ impl Heap {
         match found {
             Ok(string) => string,
             Err(hash) => {
-                let data = StringRecord::from_static_str(message);
-                self.create((data, hash))
+                let data = StringRecord::from_static_str(message, hash);
+                self.create(data)
             }
         }
     }
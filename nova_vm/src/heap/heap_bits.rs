@@ -37,13 +37,13 @@ use crate::{
     ecmascript::{
         Array, ArrayIterator, AsyncGenerator, AwaitReaction, BUILTIN_STRINGS_LIST, BoundFunction,
         BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMAScriptFunction,
-        EmbedderObject, Error, FinalizationRegistry, FunctionEnvironment, Generator,
-        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, Map, MapIterator, Module,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DeclarativeEnvironment,
+        ECMAScriptFunction, EmbedderObject, Error, FinalizationRegistry, FunctionEnvironment,
+        Generator, GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, Map, MapIterator, Module,
         ModuleEnvironment, ModuleRequest, ObjectEnvironment, ObjectShape, OrdinaryObject,
         PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup, PromiseReaction,
         PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule, StringIterator,
-        Symbol, Value, WeakKey,
+        Symbol, SyntheticModule, Value, WeakKey,
     },
     engine::Executable,
     heap::{
@@ -450,6 +450,7 @@ pub(crate) struct HeapBits {
     pub(super) promises: BitRange,
     pub(super) promise_group_records: BitRange,
     pub(super) proxies: BitRange,
+    pub(super) proxy_revoker_functions: BitRange,
     pub(super) realms: BitRange,
     #[cfg(feature = "regexp")]
     pub(super) regexps: BitRange,
@@ -467,6 +468,7 @@ pub(crate) struct HeapBits {
     #[cfg(feature = "shared-array-buffer")]
     pub(super) shared_typed_arrays: BitRange,
     pub(super) source_text_module_records: BitRange,
+    pub(super) synthetic_module_records: BitRange,
     pub(super) string_iterators: BitRange,
     pub(super) strings: BitRange,
     pub(super) symbols: BitRange,
@@ -554,6 +556,7 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) promise_finally_functions: Vec<BuiltinPromiseFinallyFunction<'static>>,
     pub(crate) promise_group_records: Vec<PromiseGroup<'static>>,
     pub(crate) proxies: Vec<Proxy<'static>>,
+    pub(crate) proxy_revoker_functions: Vec<BuiltinProxyRevokerFunction<'static>>,
     pub(crate) realms: Vec<Realm<'static>>,
     #[cfg(feature = "regexp")]
     pub(crate) regexps: Vec<RegExp<'static>>,
@@ -571,6 +574,7 @@ pub(crate) struct WorkQueues<'a> {
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_typed_arrays: Vec<SharedVoidArray<'static>>,
     pub(crate) source_text_module_records: Vec<SourceTextModule<'static>>,
+    pub(crate) synthetic_module_records: Vec<SyntheticModule<'static>>,
     pub(crate) string_iterators: Vec<StringIterator<'static>>,
     pub(crate) strings: Vec<HeapString<'static>>,
     pub(crate) symbols: Vec<Symbol<'static>>,
@@ -709,6 +713,8 @@ impl HeapBits {
         let promise_group_records =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.promise_group_records.len());
         let proxies = BitRange::from_bit_count_and_len(&mut bit_count, heap.proxies.len());
+        let proxy_revoker_functions =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.proxy_revoker_functions.len());
         let realms = BitRange::from_bit_count_and_len(&mut bit_count, heap.realms.len());
         #[cfg(feature = "regexp")]
         let regexps = BitRange::from_bit_count_and_len(&mut bit_count, heap.regexps.len());
@@ -732,6 +738,8 @@ impl HeapBits {
             BitRange::from_bit_count_and_len(&mut bit_count, heap.shared_typed_arrays.len());
         let source_text_module_records =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.source_text_module_records.len());
+        let synthetic_module_records =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.synthetic_module_records.len());
         let string_iterators =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.string_iterators.len());
         let strings = BitRange::from_bit_count_and_len(&mut bit_count, heap.strings.len());
@@ -822,6 +830,7 @@ impl HeapBits {
             promises,
             promise_group_records,
             proxies,
+            proxy_revoker_functions,
             realms,
             #[cfg(feature = "regexp")]
             regexps,
@@ -839,6 +848,7 @@ impl HeapBits {
             #[cfg(feature = "shared-array-buffer")]
             shared_typed_arrays,
             source_text_module_records,
+            synthetic_module_records,
             string_iterators,
             strings,
             symbols,
@@ -873,9 +883,9 @@ impl HeapBits {
             WeakKey::BuiltinPromiseFinallyFunction(d) => self
                 .promise_finally_functions
                 .get_bit(d.get_index(), &self.bits),
-            WeakKey::BuiltinProxyRevokerFunction => {
-                unreachable!()
-            }
+            WeakKey::BuiltinProxyRevokerFunction(d) => self
+                .proxy_revoker_functions
+                .get_bit(d.get_index(), &self.bits),
             WeakKey::PrimitiveObject(d) => {
                 self.primitive_objects.get_bit(d.get_index(), &self.bits)
             }
@@ -1085,6 +1095,7 @@ impl<'a> WorkQueues<'a> {
             promises: Vec::with_capacity(heap.promises.len() / 4),
             promise_group_records: Vec::with_capacity(heap.promise_group_records.len() / 4),
             proxies: Vec::with_capacity(heap.proxies.len() / 4),
+            proxy_revoker_functions: Vec::with_capacity(heap.proxy_revoker_functions.len() / 4),
             realms: Vec::with_capacity(heap.realms.len() / 4),
             #[cfg(feature = "regexp")]
             regexps: Vec::with_capacity(heap.regexps.len() / 4),
@@ -1104,6 +1115,7 @@ impl<'a> WorkQueues<'a> {
             source_text_module_records: Vec::with_capacity(
                 heap.source_text_module_records.len() / 4,
             ),
+            synthetic_module_records: Vec::with_capacity(heap.synthetic_module_records.len() / 4),
             string_iterators: Vec::with_capacity(heap.string_iterators.len() / 4),
             strings: Vec::with_capacity((heap.strings.len() / 4).max(BUILTIN_STRINGS_LIST.len())),
             symbols: Vec::with_capacity((heap.symbols.len() / 4).max(13)),
@@ -1193,6 +1205,7 @@ impl<'a> WorkQueues<'a> {
             promise_finally_functions,
             promise_group_records,
             proxies,
+            proxy_revoker_functions,
             realms,
             #[cfg(feature = "regexp")]
             regexps,
@@ -1210,6 +1223,7 @@ impl<'a> WorkQueues<'a> {
             #[cfg(feature = "shared-array-buffer")]
             shared_typed_arrays,
             source_text_module_records,
+            synthetic_module_records,
             string_iterators,
             strings,
             symbols,
@@ -1321,6 +1335,7 @@ impl<'a> WorkQueues<'a> {
             && promise_group_records.is_empty()
             && promises.is_empty()
             && proxies.is_empty()
+            && proxy_revoker_functions.is_empty()
             && realms.is_empty()
             && regexps.is_empty()
             && regexp_string_iterators.is_empty()
@@ -1331,6 +1346,7 @@ impl<'a> WorkQueues<'a> {
             && shared_data_views.is_empty()
             && shared_typed_arrays.is_empty()
             && source_text_module_records.is_empty()
+            && synthetic_module_records.is_empty()
             && string_iterators.is_empty()
             && strings.is_empty()
             && symbols.is_empty()
@@ -1664,6 +1680,7 @@ pub(crate) struct CompactionLists {
     pub(crate) embedder_objects: CompactionList,
     pub(crate) source_codes: CompactionList,
     pub(crate) source_text_module_records: CompactionList,
+    pub(crate) synthetic_module_records: CompactionList,
     pub(crate) errors: CompactionList,
     pub(crate) executables: CompactionList,
     pub(crate) finalization_registrys: CompactionList,
@@ -1687,6 +1704,7 @@ pub(crate) struct CompactionLists {
     pub(crate) promises: CompactionList,
     pub(crate) promise_group_records: CompactionList,
     pub(crate) proxies: CompactionList,
+    pub(crate) proxy_revoker_functions: CompactionList,
     pub(crate) realms: CompactionList,
     #[cfg(feature = "regexp")]
     pub(crate) regexps: CompactionList,
@@ -1864,6 +1882,10 @@ impl CompactionLists {
                 &bits.source_text_module_records,
                 &bits.bits,
             ),
+            synthetic_module_records: CompactionList::from_mark_bits(
+                &bits.synthetic_module_records,
+                &bits.bits,
+            ),
             symbols: CompactionList::from_mark_bits(&bits.symbols, &bits.bits),
             #[cfg(feature = "array-buffer")]
             data_views: CompactionList::from_mark_bits(&bits.data_views, &bits.bits),
@@ -1872,6 +1894,10 @@ impl CompactionLists {
                 &bits.bits,
             ),
             proxies: CompactionList::from_mark_bits(&bits.proxies, &bits.bits),
+            proxy_revoker_functions: CompactionList::from_mark_bits(
+                &bits.proxy_revoker_functions,
+                &bits.bits,
+            ),
             #[cfg(feature = "weak-refs")]
             weak_maps: CompactionList::from_mark_bits(&bits.weak_maps, &bits.bits),
             #[cfg(feature = "weak-refs")]
@@ -135,6 +135,40 @@ pub(crate) enum IntrinsicObjectIndexes {
     SyntaxErrorPrototype,
     TypeErrorPrototype,
 
+    // Internationalization
+    #[cfg(feature = "intl")]
+    IntlObject,
+    #[cfg(feature = "intl")]
+    IntlDateTimeFormatPrototype,
+    #[cfg(feature = "intl")]
+    IntlNumberFormatPrototype,
+
+    // Web APIs
+    #[cfg(feature = "web-abort")]
+    AbortControllerPrototype,
+    #[cfg(feature = "web-abort")]
+    AbortSignalPrototype,
+    #[cfg(feature = "web-blob")]
+    BlobPrototype,
+    #[cfg(feature = "web-events")]
+    EventPrototype,
+    #[cfg(feature = "web-events")]
+    EventTargetPrototype,
+    #[cfg(feature = "web-blob")]
+    FilePrototype,
+    #[cfg(feature = "web-fetch")]
+    HeadersPrototype,
+    #[cfg(feature = "web-streams")]
+    ReadableStreamPrototype,
+    #[cfg(feature = "web-streams")]
+    ReadableStreamDefaultReaderPrototype,
+    #[cfg(feature = "web-streams")]
+    ReadableStreamDefaultControllerPrototype,
+    #[cfg(feature = "web-fetch")]
+    RequestPrototype,
+    #[cfg(feature = "web-fetch")]
+    ResponsePrototype,
+
     // Others
     URIErrorPrototype,
     #[cfg(feature = "regexp")]
@@ -259,6 +293,38 @@ pub(crate) enum IntrinsicConstructorIndexes {
     SyntaxError,
     TypeError,
 
+    // Internationalization
+    #[cfg(feature = "intl")]
+    IntlDateTimeFormat,
+    #[cfg(feature = "intl")]
+    IntlNumberFormat,
+
+    // Web APIs
+    #[cfg(feature = "web-abort")]
+    AbortController,
+    #[cfg(feature = "web-abort")]
+    AbortSignal,
+    #[cfg(feature = "web-blob")]
+    Blob,
+    #[cfg(feature = "web-events")]
+    Event,
+    #[cfg(feature = "web-events")]
+    EventTarget,
+    #[cfg(feature = "web-blob")]
+    File,
+    #[cfg(feature = "web-fetch")]
+    Headers,
+    #[cfg(feature = "web-streams")]
+    ReadableStream,
+    #[cfg(feature = "web-streams")]
+    ReadableStreamDefaultReader,
+    #[cfg(feature = "web-streams")]
+    ReadableStreamDefaultController,
+    #[cfg(feature = "web-fetch")]
+    Request,
+    #[cfg(feature = "web-fetch")]
+    Response,
+
     // Others
     URIError,
 }
@@ -271,6 +337,7 @@ pub(crate) enum IntrinsicFunctionIndexes {
     // +===================================================================================+
     // | Plain functions: These do not have a corresponding object index reserved for them |
     // +===================================================================================+
+    ArrayIteratorPrototypeNext,
     ArrayPrototypeSort,
     ArrayPrototypeToString,
     ArrayPrototypeValues,
@@ -283,13 +350,20 @@ pub(crate) enum IntrinsicFunctionIndexes {
     #[cfg(feature = "annex-b-global")]
     Escape,
     Eval,
+    #[cfg(feature = "web-fetch")]
+    Fetch,
     GeneratorFunctionPrototypePrototypeNext,
+    #[cfg(feature = "web-fetch")]
+    HeadersPrototypeEntries,
     IsFinite,
     IsNaN,
+    MapIteratorPrototypeNext,
     MapPrototypeEntries,
     ObjectPrototypeToString,
+    ObjectPrototypeValueOf,
     ParseFloat,
     ParseInt,
+    PromisePrototypeThen,
     #[cfg(feature = "regexp")]
     RegExpPrototypeExec,
     #[cfg(feature = "set")]
@@ -24,13 +24,14 @@ use crate::{
     ecmascript::{
         Agent, Array, ArrayIterator, AsyncGenerator, AwaitReaction, BUILTIN_STRINGS_LIST,
         BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMAScriptFunction,
-        EmbedderObject, Environments, Error, FinalizationRegistry, FunctionEnvironment, Generator,
-        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, Map, MapIterator, Module,
-        ModuleEnvironment, ModuleRequest, ObjectEnvironment, ObjectShape, OrdinaryObject,
-        PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup, PromiseReaction,
-        PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule, StringIterator,
-        Symbol,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DeclarativeEnvironment,
+        ECMAScriptFunction, EmbedderObject, Environments, Error, FinalizationRegistry,
+        FunctionEnvironment, GcReason, Generator, GlobalEnvironment, HeapBigInt, HeapNumber,
+        HeapString, Map, MapIterator, Module, ModuleEnvironment, ModuleRequest, ObjectEnvironment,
+        ObjectShape, OrdinaryObject, PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup,
+        PromiseReaction, PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule,
+        StringIterator, Symbol, SyntheticModule, check_settled_promise_groups,
+        release_resolved_promise_capabilities,
     },
     engine::{Bindable, Executable, GcScope},
     heap::{
@@ -45,8 +46,22 @@ use crate::{
     ndt,
 };
 
-pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc: GcScope) {
+pub(crate) fn heap_gc(
+    agent: &mut Agent,
+    root_realms: &mut [Option<Realm<'static>>],
+    reason: GcReason,
+    gc: GcScope,
+) {
     ndt::gc_start!(|| ());
+    #[cfg(debug_assertions)]
+    agent.assert_no_unrooted_references();
+    agent.host_hooks.before_gc(reason, agent.gc_stats());
+    agent.record_allocation_sample();
+
+    release_resolved_promise_capabilities(&mut agent.heap);
+    if agent.options.check_promise_retention {
+        check_settled_promise_groups(&agent.heap);
+    }
 
     let mut bits = HeapBits::new(&agent.heap);
     bits.strings
@@ -114,6 +129,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             promises,
             promise_group_records,
             proxies,
+            proxy_revoker_functions,
             realms,
             #[cfg(feature = "regexp")]
             regexps,
@@ -127,6 +143,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             #[cfg(feature = "shared-array-buffer")]
             shared_array_buffers,
             source_text_module_records,
+            synthetic_module_records,
             string_iterators,
             strings,
             string_lookup_table: _,
@@ -695,6 +712,18 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 }
             });
         }
+        if !queues.proxy_revoker_functions.is_empty() {
+            let mut proxy_revoker_function_marks: Box<[BuiltinProxyRevokerFunction]> =
+                queues.proxy_revoker_functions.drain(..).collect();
+            proxy_revoker_function_marks.sort();
+            proxy_revoker_function_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.proxy_revoker_functions.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    proxy_revoker_functions.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         if !queues.maps.is_empty() {
             let mut map_marks: Box<[Map]> = queues.maps.drain(..).collect();
             map_marks.sort();
@@ -859,6 +888,18 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 }
             });
         }
+        if !queues.synthetic_module_records.is_empty() {
+            let mut synthetic_module_record_marks: Box<[SyntheticModule]> =
+                queues.synthetic_module_records.drain(..).collect();
+            synthetic_module_record_marks.sort();
+            synthetic_module_record_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.synthetic_module_records.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    synthetic_module_records.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         if !queues.string_iterators.is_empty() {
             let mut string_generator_marks: Box<[StringIterator]> =
                 queues.string_iterators.drain(..).collect();
@@ -1224,6 +1265,16 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
     if has_finalization_registrys {
         FinalizationRegistry::enqueue_cleanup_jobs(agent);
     }
+    agent.gc_stats.collections += 1;
+    agent.host_hooks.after_gc(reason, agent.gc_stats());
+    let threshold = agent.options.heap_size_notification_threshold;
+    if threshold != 0 || agent.options.memory_limit != 0 {
+        let statistics = agent.heap_statistics();
+        agent.retained_bytes = statistics.total_bytes();
+        if threshold != 0 && statistics.total_bytes() > threshold {
+            agent.host_hooks.heap_threshold_exceeded(&statistics);
+        }
+    }
     ndt::gc_done!(|| ());
 }
 
@@ -1292,6 +1343,7 @@ fn sweep(
         promises,
         promise_group_records,
         proxies,
+        proxy_revoker_functions,
         realms,
         #[cfg(feature = "regexp")]
         regexps,
@@ -1305,6 +1357,7 @@ fn sweep(
         #[cfg(feature = "shared-array-buffer")]
         shared_array_buffers,
         source_text_module_records,
+        synthetic_module_records,
         string_iterators,
         strings,
         string_lookup_table,
@@ -1347,7 +1400,11 @@ fn sweep(
         alloc_counter,
     } = &mut agent.heap;
     // Reset the allocation counter.
+    agent.gc_stats.allocated_bytes += *alloc_counter as u64;
     *alloc_counter = 0;
+    if let Some(tracker) = &mut agent.allocation_tracker {
+        tracker.reset_alloc_counter();
+    }
     let Environments {
         declarative,
         function,
@@ -1968,6 +2025,16 @@ fn sweep(
                 sweep_heap_vector_values(proxies, &compactions, &bits.proxies, &bits.bits);
             });
         }
+        if !proxy_revoker_functions.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    proxy_revoker_functions,
+                    &compactions,
+                    &bits.proxy_revoker_functions,
+                    &bits.bits,
+                );
+            });
+        }
         if !realms.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(realms, &compactions, &bits.realms, &bits.bits);
@@ -2033,6 +2100,16 @@ fn sweep(
                 );
             });
         }
+        if !synthetic_module_records.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    synthetic_module_records,
+                    &compactions,
+                    &bits.synthetic_module_records,
+                    &bits.bits,
+                );
+            });
+        }
         if !source_codes.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(
@@ -2130,7 +2207,7 @@ fn test_heap_gc() {
         OrdinaryObject::create_object(&mut agent, None, &[]).expect("Should perform GC here"),
     );
     agent.heap.globals.borrow_mut().push(obj);
-    heap_gc(&mut agent, &mut [], gc.reborrow());
+    heap_gc(&mut agent, &mut [], GcReason::Explicit, gc.reborrow());
 
     assert_eq!(agent.heap.objects.len(), 1);
     assert_eq!(agent.heap.elements.e2pow4.values.len(), 0);
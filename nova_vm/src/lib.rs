@@ -26,14 +26,23 @@
 //! JavaScript look and act similar to normal Rust code.
 //!
 //! ```rust
-//! use nova_vm::{ecmascript::{DefaultHostHooks, GcAgent}, engine::GcScope};
-//! let mut agent = GcAgent::new(Default::default(), &DefaultHostHooks);
-//! let realm = agent.create_default_realm();
+//! use nova_vm::ecmascript::AgentBuilder;
+//! let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
 //! let _ = agent.run_in_realm(&realm, |_agent, _gc| {
 //!   // do work here
 //! });
 //! ```
 //!
+//! The [`AgentBuilder`] is used to configure the engine, for instance by
+//! setting custom host hooks or garbage collection parameters.
+//!
+//! Embedders that must not unwind through their own code can use
+//! [`GcAgent::try_run_in_realm`] instead, which reports engine panics as a
+//! [`FatalError`] and terminates the Agent.
+//!
+//! [`GcAgent::try_run_in_realm`]: crate::ecmascript::GcAgent::try_run_in_realm
+//! [`FatalError`]: crate::ecmascript::FatalError
+//!
 //! ## Architecture
 //!
 //! The engine's public API relies on idiomatic Rust over traditional JavaScript
@@ -69,9 +78,12 @@
 //!    support RegExp patterns containing unpaired surrogates, and its groups
 //!    are slightly different from what the ECMAScript specification defines. In
 //!    short: it is not compliant.
-//! 1. [`Promise`] subclassing is currently not supported.
+//! 1. [`Promise`] subclasses must create their instances by calling the
+//!    `Promise` constructor through `super`; other promise-like constructors
+//!    are not supported.
 //! 1. The engine does not support [WebAssembly] execution.
 //!
+//! [`AgentBuilder`]: crate::ecmascript::AgentBuilder
 //! [`Agent`]: crate::ecmascript::Agent
 //! [`Array`]: crate::ecmascript::Array
 //! [`RegExp`]: crate::ecmascript::RegExp
@@ -88,6 +100,7 @@
 pub mod ecmascript;
 pub mod engine;
 pub mod heap;
+pub mod testing;
 
 /// DTrace / SystemTap USDT probes in Nova VM.
 #[usdt::provider(provider = "nova_vm")]
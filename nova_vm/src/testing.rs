@@ -0,0 +1,358 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! # Testing helpers
+//!
+//! Helpers for asserting on JavaScript values in tests of the engine and of
+//! embedders.
+//!
+//! Values are compared structurally: primitives are compared using
+//! [SameValue], while ordinary objects, Arrays, Errors, and arguments objects
+//! are compared by their \[\[Prototype]] and own properties. Other objects,
+//! such as functions, are compared by identity. Cyclic structures are
+//! supported.
+//!
+//! The comparison never calls into JavaScript: accessor properties are
+//! compared by their getter and setter functions, and objects that cannot be
+//! inspected without calling into JavaScript, such as Proxies, are compared by
+//! identity.
+//!
+//! ```rust
+//! use nova_vm::{
+//!     ecmascript::{AgentBuilder, String},
+//!     engine::{Bindable, Scopable},
+//!     testing::diff_values,
+//! };
+//! let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+//! agent.run_in_realm(&realm, |agent, mut gc| {
+//!     let source = String::from_static_str(agent, "({ a: [1, 2], b: 'x' })", gc.nogc());
+//!     let left = agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+//!     let left = left.unbind().scope(agent, gc.nogc());
+//!     let source = String::from_static_str(agent, "({ a: [1, 3], b: 'x' })", gc.nogc());
+//!     let right = agent.run_script(source.unbind(), gc.reborrow()).unwrap().unbind();
+//!     let gc = gc.into_nogc();
+//!     let diff = diff_values(agent, left.get(agent), right, gc).unwrap();
+//!     assert_eq!(diff.to_string(), "$.a[1]: 2 !== 3\n");
+//! });
+//! ```
+//!
+//! [SameValue]: https://tc39.es/ecma262/#sec-samevalue
+
+use core::{fmt, ops::ControlFlow};
+
+use crate::{
+    ecmascript::{
+        Agent, BigInt, Function, InternalMethods, Number, Object, PropertyDescriptor, PropertyKey,
+        String, Value, same_value,
+    },
+    engine::NoGcScope,
+};
+
+/// Returns true if the two values are structurally equal.
+///
+/// See the [module documentation](self) for details on the comparison.
+pub fn values_equal(agent: &mut Agent, left: Value, right: Value, gc: NoGcScope) -> bool {
+    diff_values(agent, left, right, gc).is_none()
+}
+
+/// Compare two values structurally and return their differences, or `None`
+/// if the values are equal.
+///
+/// See the [module documentation](self) for details on the comparison.
+pub fn diff_values(
+    agent: &mut Agent,
+    left: Value,
+    right: Value,
+    gc: NoGcScope,
+) -> Option<ValueDiff> {
+    let mut differ = Differ {
+        agent,
+        path: "$".into(),
+        visited: Vec::new(),
+        differences: Vec::new(),
+        gc,
+    };
+    differ.compare(left, right);
+    if differ.differences.is_empty() {
+        None
+    } else {
+        Some(ValueDiff {
+            differences: differ.differences,
+        })
+    }
+}
+
+/// Assert that the two values are structurally equal.
+///
+/// # Panics
+///
+/// Panics with a human-readable diff if the values are not equal.
+#[track_caller]
+pub fn assert_values_equal(agent: &mut Agent, left: Value, right: Value, gc: NoGcScope) {
+    if let Some(diff) = diff_values(agent, left, right, gc) {
+        panic!("assertion `left == right` failed for JavaScript values:\n{diff}");
+    }
+}
+
+/// The differences between two structurally compared values.
+///
+/// The [`Display`](fmt::Display) implementation prints one difference per
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueDiff {
+    differences: Vec<Difference>,
+}
+
+impl ValueDiff {
+    /// The differences between the values, in the order they were found.
+    pub fn differences(&self) -> &[Difference] {
+        &self.differences
+    }
+}
+
+impl fmt::Display for ValueDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for difference in &self.differences {
+            writeln!(f, "{difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two structurally compared values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Path from the compared values to the differing values, eg. `$.a[1]`.
+    pub path: std::string::String,
+    /// Description of the left value, or `<missing>` if the left object does
+    /// not have the property.
+    pub left: std::string::String,
+    /// Description of the right value, or `<missing>` if the right object
+    /// does not have the property.
+    pub right: std::string::String,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} !== {}", self.path, self.left, self.right)
+    }
+}
+
+const MISSING: &str = "<missing>";
+
+struct Differ<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    path: std::string::String,
+    /// Pairs of objects that have been or are being compared. Pairs are
+    /// assumed to be equal when they are encountered again; any differences
+    /// are reported by the first comparison.
+    visited: Vec<(Object<'gc>, Object<'gc>)>,
+    differences: Vec<Difference>,
+    gc: NoGcScope<'gc, 'scope>,
+}
+
+impl<'gc> Differ<'_, 'gc, '_> {
+    fn compare(&mut self, left: Value<'gc>, right: Value<'gc>) {
+        match (Object::try_from(left), Object::try_from(right)) {
+            (Ok(left), Ok(right)) => self.compare_objects(left, right),
+            _ => {
+                if !same_value(self.agent, left, right) {
+                    self.report(left, right);
+                }
+            }
+        }
+    }
+
+    fn compare_objects(&mut self, left: Object<'gc>, right: Object<'gc>) {
+        if left == right || self.visited.contains(&(left, right)) {
+            return;
+        }
+        if !is_structural(left) || !is_structural(right) {
+            self.report(left.into(), right.into());
+            return;
+        }
+        let gc = self.gc;
+        let (
+            ControlFlow::Continue(left_prototype),
+            ControlFlow::Continue(right_prototype),
+            ControlFlow::Continue(left_keys),
+            ControlFlow::Continue(right_keys),
+        ) = (
+            left.try_get_prototype_of(self.agent, gc),
+            right.try_get_prototype_of(self.agent, gc),
+            left.try_own_property_keys(self.agent, gc),
+            right.try_own_property_keys(self.agent, gc),
+        )
+        else {
+            self.report(left.into(), right.into());
+            return;
+        };
+        self.visited.push((left, right));
+
+        if left_prototype != right_prototype {
+            let left_prototype = left_prototype.map_or(Value::Null, |p| p.into());
+            let right_prototype = right_prototype.map_or(Value::Null, |p| p.into());
+            let length = self.path.len();
+            self.path.push_str(".[[Prototype]]");
+            self.report(left_prototype, right_prototype);
+            self.path.truncate(length);
+        }
+
+        for key in left_keys {
+            let Some(left_desc) = self.get_own_property(left, key) else {
+                continue;
+            };
+            let length = self.path.len();
+            self.push_key(key);
+            if let Some(right_desc) = self.get_own_property(right, key) {
+                self.compare_descriptors(left_desc, right_desc);
+            } else {
+                let left = self.describe_descriptor(left_desc);
+                self.push_difference(left, MISSING.into());
+            }
+            self.path.truncate(length);
+        }
+        for key in right_keys {
+            if self.get_own_property(left, key).is_some() {
+                continue;
+            }
+            let Some(right_desc) = self.get_own_property(right, key) else {
+                continue;
+            };
+            let length = self.path.len();
+            self.push_key(key);
+            let right = self.describe_descriptor(right_desc);
+            self.push_difference(MISSING.into(), right);
+            self.path.truncate(length);
+        }
+    }
+
+    fn compare_descriptors(
+        &mut self,
+        left: PropertyDescriptor<'gc>,
+        right: PropertyDescriptor<'gc>,
+    ) {
+        match (left.value, right.value) {
+            (Some(left), Some(right)) => self.compare(left, right),
+            (None, None) => {
+                let length = self.path.len();
+                self.path.push_str(".[[Get]]");
+                self.compare_accessor(left.get, right.get);
+                self.path.truncate(length);
+                self.path.push_str(".[[Set]]");
+                self.compare_accessor(left.set, right.set);
+                self.path.truncate(length);
+            }
+            _ => {
+                let left = self.describe_descriptor(left);
+                let right = self.describe_descriptor(right);
+                self.push_difference(left, right);
+            }
+        }
+    }
+
+    fn compare_accessor(
+        &mut self,
+        left: Option<Option<Function<'gc>>>,
+        right: Option<Option<Function<'gc>>>,
+    ) {
+        let left = left.flatten().map_or(Value::Undefined, |f| f.into());
+        let right = right.flatten().map_or(Value::Undefined, |f| f.into());
+        self.compare(left, right);
+    }
+
+    fn get_own_property(
+        &mut self,
+        object: Object<'gc>,
+        key: PropertyKey<'gc>,
+    ) -> Option<PropertyDescriptor<'gc>> {
+        match object.try_get_own_property(self.agent, key, None, self.gc) {
+            ControlFlow::Continue(desc) => desc,
+            ControlFlow::Break(_) => None,
+        }
+    }
+
+    fn push_key(&mut self, key: PropertyKey<'gc>) {
+        use fmt::Write;
+        match key {
+            PropertyKey::Integer(index) => {
+                let _ = write!(self.path, "[{}]", index.into_i64());
+            }
+            PropertyKey::Symbol(symbol) => {
+                let description = symbol.descriptive_string(self.agent, self.gc);
+                let _ = write!(self.path, "[{}]", description.to_string_lossy(self.agent));
+            }
+            _ => {
+                let name = key.as_display(self.agent).to_string();
+                let mut chars = name.chars();
+                let is_identifier = chars
+                    .next()
+                    .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                if is_identifier {
+                    let _ = write!(self.path, ".{name}");
+                } else {
+                    let _ = write!(self.path, "[{name:?}]");
+                }
+            }
+        }
+    }
+
+    fn report(&mut self, left: Value<'gc>, right: Value<'gc>) {
+        let left = self.describe(left);
+        let right = self.describe(right);
+        self.push_difference(left, right);
+    }
+
+    fn push_difference(&mut self, left: std::string::String, right: std::string::String) {
+        self.differences.push(Difference {
+            path: self.path.clone(),
+            left,
+            right,
+        });
+    }
+
+    fn describe_descriptor(&mut self, desc: PropertyDescriptor<'gc>) -> std::string::String {
+        match desc.value {
+            Some(value) => self.describe(value),
+            None => "<accessor>".into(),
+        }
+    }
+
+    fn describe(&mut self, value: Value<'gc>) -> std::string::String {
+        let agent = &mut *self.agent;
+        if let Ok(string) = String::try_from(value) {
+            return format!("{:?}", string.to_string_lossy(agent));
+        }
+        if let Ok(number) = Number::try_from(value) {
+            let number = number.into_f64(agent);
+            if number == 0.0 && number.is_sign_negative() {
+                return "-0".into();
+            }
+        }
+        if let Ok(bigint) = BigInt::try_from(value) {
+            let string = BigInt::to_string_radix_10(agent, bigint, self.gc);
+            return format!("{}n", string.to_string_lossy(agent));
+        }
+        match Object::try_from(value) {
+            Ok(Object::Array(array)) => format!("[Array({})]", array.len(agent)),
+            Ok(Object::Object(_)) => "[object Object]".into(),
+            Ok(_) if value.is_function() => "[Function]".into(),
+            Ok(_) => "[object]".into(),
+            Err(_) => value
+                .try_string_repr(agent, self.gc)
+                .to_string_lossy(agent)
+                .into_owned(),
+        }
+    }
+}
+
+/// Returns true if the object is compared by its prototype and own
+/// properties.
+fn is_structural(object: Object) -> bool {
+    matches!(
+        object,
+        Object::Object(_) | Object::Array(_) | Object::Error(_) | Object::Arguments(_)
+    )
+}
@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "web-abort")]
+
+mod common;
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use common::{create_agent, define_global, run_with_jobs};
+use nova_vm::{
+    ecmascript::{AbortController, Agent, Value},
+    engine::{Bindable, GcScope, Global},
+};
+
+#[test]
+fn abort_signal_tests() {
+    common::run_test_file("abortSignal.test.js");
+}
+
+#[test]
+fn abort_signal_timeout_uses_host_timer() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    assert_eq!(
+        run_with_jobs(
+            &mut agent,
+            &realm,
+            r#"
+            var signal = AbortSignal.timeout(250.7);
+            signal.aborted
+            "#,
+        ),
+        "false"
+    );
+    let (job, milliseconds) = host_hooks.timeout_jobs.pop().unwrap();
+    assert_eq!(milliseconds, 250);
+    agent.run_job(job, |_, result, _| result.unwrap());
+    assert_eq!(
+        run_with_jobs(
+            &mut agent,
+            &realm,
+            "[signal.aborted, signal.reason.name, signal.reason instanceof Error].join()",
+        ),
+        "true,TimeoutError,true"
+    );
+}
+
+#[test]
+fn abort_event_handler_errors_are_reported() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var controller = new AbortController();
+        controller.signal.onabort = () => { throw "handler error"; };
+        controller.abort();
+        "#,
+    );
+    assert_eq!(*host_hooks.job_errors.borrow(), vec!["handler error"]);
+}
+
+#[test]
+fn abort_controller_runs_host_algorithms() {
+    let (_, mut agent, realm) = create_agent();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+    let controller = agent.run_in_realm(&realm, |agent, gc| {
+        let controller = AbortController::new(agent, gc.nogc());
+        let signal = controller.signal(agent);
+        let calls_in_algorithm = calls.clone();
+        let reasons_in_algorithm = reasons.clone();
+        signal.add_algorithm(
+            agent,
+            move |agent: &mut Agent, reason: Value, mut gc: GcScope| {
+                calls_in_algorithm.fetch_add(1, Ordering::Relaxed);
+                let reason = reason
+                    .to_string(agent, gc.reborrow())
+                    .unwrap()
+                    .to_string_lossy(agent)
+                    .into_owned();
+                reasons_in_algorithm.lock().unwrap().push(reason);
+            },
+        );
+        let root = Global::new(agent, controller.unbind());
+        define_global(agent, "controller", controller.unbind().into(), gc);
+        root
+    });
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"controller.abort("cancelled"); controller.abort("twice");"#,
+    );
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(*reasons.lock().unwrap(), vec!["cancelled"]);
+    agent.run_in_realm(&realm, |agent, _| {
+        let controller = controller.take(agent);
+        assert!(controller.signal(agent).is_aborted(agent));
+    });
+}
@@ -0,0 +1,36 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, String, parse_script, script_evaluation},
+    engine::Bindable,
+};
+
+fn random_sequence(seed: u64) -> std::string::String {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_random_seed(seed)
+        .build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(
+            agent,
+            "[Math.random(), Math.random(), Math.random()].join()",
+            gc.nogc(),
+        );
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation(agent, script.unbind(), gc.reborrow())
+            .unbind()
+            .unwrap();
+        result
+            .string_repr(agent, gc)
+            .to_string_lossy(agent)
+            .into_owned()
+    })
+}
+
+#[test]
+fn seeded_math_random_is_deterministic() {
+    assert_eq!(random_sequence(42), random_sequence(42));
+    assert_ne!(random_sequence(42), random_sequence(43));
+}
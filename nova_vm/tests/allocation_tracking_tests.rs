@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent, create_agent_with, run};
+use nova_vm::ecmascript::{AgentBuilder, AllocationSite, GcAgent};
+
+fn function_bytes(agent: &mut GcAgent, name: &str) -> u64 {
+    agent
+        .allocation_report()
+        .sites()
+        .iter()
+        .filter(|site| matches!(&site.site, AllocationSite::Function { name: n, .. } if n == name))
+        .map(|site| site.bytes)
+        .sum()
+}
+
+#[test]
+fn allocations_are_attributed_to_functions() {
+    let (_, mut agent, realm) = create_agent_with(
+        TestHostHooks::default(),
+        AgentBuilder::new().with_allocation_sample_interval(1),
+    );
+    agent.clear_allocation_report();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function allocateMany() {
+            const result = [];
+            for (let i = 0; i < 1000; i++) {
+                result.push({ i });
+            }
+            return result;
+        }
+        function allocateFew() {
+            return { a: 1 };
+        }
+        allocateMany();
+        allocateFew();
+        "#,
+    );
+    let many = function_bytes(&mut agent, "allocateMany");
+    let few = function_bytes(&mut agent, "allocateFew");
+    assert!(many > few, "allocateMany: {many}, allocateFew: {few}");
+    assert!(few > 0);
+
+    let report = agent.allocation_report();
+    assert!(matches!(
+        &report.sites()[0].site,
+        AllocationSite::Function { name, .. } if name == "allocateMany"
+    ));
+    assert!(
+        report
+            .sites()
+            .iter()
+            .any(|s| s.site == AllocationSite::Script)
+    );
+    assert!(
+        report
+            .sites()
+            .windows(2)
+            .all(|pair| pair[0].bytes >= pair[1].bytes)
+    );
+    assert!(report.to_string().contains("allocateMany @ "));
+
+    agent.clear_allocation_report();
+    assert_eq!(agent.allocation_report().total_bytes(), 0);
+}
+
+#[test]
+fn allocations_survive_garbage_collection() {
+    let (_, mut agent, realm) = create_agent_with(
+        TestHostHooks::default(),
+        AgentBuilder::new()
+            .with_allocation_sample_interval(64)
+            .with_gc_allocation_threshold(4096),
+    );
+    agent.clear_allocation_report();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function churn() {
+            for (let i = 0; i < 10000; i++) {
+                ({ i });
+            }
+        }
+        churn();
+        "#,
+    );
+    assert!(agent.gc_stats().collections() > 0);
+    let report = agent.allocation_report();
+    assert!(report.total_bytes() > 10000 * 8);
+    assert!(matches!(
+        &report.sites()[0].site,
+        AllocationSite::Function { name, .. } if name == "churn"
+    ));
+}
+
+#[test]
+fn allocation_tracking_is_disabled_by_default() {
+    let (_, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        "function f() { return [1, 2, 3]; } f();",
+    );
+    assert_eq!(agent.allocation_report(), Default::default());
+}
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent_with, run};
+use nova_vm::ecmascript::{AgentBuilder, GcAgent, RealmRoot};
+
+fn create_agent(max_arguments_length: Option<usize>) -> (GcAgent, RealmRoot) {
+    let mut builder = AgentBuilder::new();
+    if let Some(max_arguments_length) = max_arguments_length {
+        builder = builder.with_max_arguments_length(max_arguments_length);
+    }
+    let (_, agent, realm) = create_agent_with(TestHostHooks::default(), builder);
+    (agent, realm)
+}
+
+const CALLS: &str = r#"
+function count() { return arguments.length; }
+function Count() { this.length = arguments.length; }
+function attempt(f) {
+    try {
+        return f();
+    } catch (err) {
+        return err.constructor.name;
+    }
+}
+function results(length) {
+    const array = new Array(length).fill(0);
+    const bound = count.bind(null, 0, 0);
+    const BoundCount = Count.bind(null, 0, 0);
+    return [
+        attempt(() => count(...array)),
+        attempt(() => new Count(...array).length),
+        attempt(() => count.apply(null, array)),
+        attempt(() => count.apply(null, { length })),
+        attempt(() => Reflect.apply(count, null, array)),
+        attempt(() => Reflect.construct(Count, array).length),
+        attempt(() => bound(...array.slice(2))),
+        attempt(() => new BoundCount(...array.slice(2)).length),
+    ].join();
+}
+"#;
+
+#[test]
+fn calls_within_limit() {
+    let (mut agent, realm) = create_agent(Some(100));
+    run(&mut agent, &realm, CALLS);
+    assert_eq!(
+        run(&mut agent, &realm, "results(100)"),
+        "100,100,100,100,100,100,100,100"
+    );
+}
+
+#[test]
+fn calls_over_limit_throw_range_error() {
+    let (mut agent, realm) = create_agent(Some(100));
+    run(&mut agent, &realm, CALLS);
+    assert_eq!(
+        run(&mut agent, &realm, "results(101)"),
+        "RangeError,RangeError,RangeError,RangeError,RangeError,RangeError,RangeError,RangeError"
+    );
+}
+
+#[test]
+fn huge_array_like_throws_without_reading_elements() {
+    let (mut agent, realm) = create_agent(None);
+    run(&mut agent, &realm, CALLS);
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "attempt(() => count.apply(null, { length: 2 ** 40 }))"
+        ),
+        "RangeError"
+    );
+}
+
+#[test]
+fn default_limit_allows_more_than_u16_arguments() {
+    let (mut agent, realm) = create_agent(None);
+    run(&mut agent, &realm, CALLS);
+    assert_eq!(
+        run(&mut agent, &realm, "results(100000)"),
+        "100000,100000,100000,100000,100000,100000,100000,100000"
+    );
+}
+
+#[test]
+fn static_argument_counts_around_inline_limit() {
+    let (mut agent, realm) = create_agent(None);
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            function list() { return [...arguments].join(""); }
+            function List() { this.value = [...arguments].join(""); }
+            class Sub extends List {
+                constructor() { super(1, 2, 3, 4, 5); }
+            }
+            class SubFour extends List {
+                constructor() { super(1, 2, 3, 4); }
+            }
+            [
+                list(), list(1), list(1, 2, 3), list(1, 2, 3, 4), list(1, 2, 3, 4, 5),
+                list(1, 2, 3, 4, 5, 6), new List(1, 2, 3, 4).value,
+                new List(1, 2, 3, 4, 5).value, new Sub().value, new SubFour().value,
+                eval("list(1, 2, 3, 4)", 0, 0, 0), eval("list(1, 2)", 0, 0, 0, 0),
+            ].join()
+            "#
+        ),
+        ",1,123,1234,12345,123456,1234,12345,12345,1234,1234,12"
+    );
+}
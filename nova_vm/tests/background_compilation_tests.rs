@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{sync::Arc, thread};
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, CompiledModule, CompiledScript, String, script_evaluation},
+    engine::Bindable,
+};
+
+#[test]
+fn scripts_compiled_on_another_thread_can_be_evaluated() {
+    let source: Arc<str> = Arc::from(
+        "function add(a, b) { return a + b; }\n[add(1, 2), add.toString(), this === globalThis].join()",
+    );
+    let compiled = thread::spawn({
+        let source = source.clone();
+        move || CompiledScript::compile(source, false).unwrap()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(compiled.source_text(), &*source);
+
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let script = compiled.instantiate(agent, realm, None, gc.nogc());
+        let result = script_evaluation(agent, script.unbind(), gc.reborrow())
+            .unbind()
+            .unwrap();
+        let Ok(result) = String::try_from(result) else {
+            panic!("Expected a String, got {result:?}");
+        };
+        assert_eq!(
+            result.to_string_lossy(agent),
+            "3,function add(a, b) { return a + b; },true"
+        );
+    });
+    // The source text is shared with the Script's source String and
+    // SourceCode instead of being copied.
+    assert_eq!(Arc::strong_count(&source), 3);
+}
+
+#[test]
+fn short_and_strict_scripts() {
+    let compiled = CompiledScript::compile("1 + 1", true).unwrap();
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let script = compiled.instantiate(agent, realm, None, gc.nogc());
+        let result = script_evaluation(agent, script.unbind(), gc.reborrow())
+            .unbind()
+            .unwrap();
+        assert_eq!(result.to_int32(agent, gc).unwrap(), 2);
+    });
+
+    assert!(CompiledScript::compile("with ({}) {}", true).is_err());
+    assert!(CompiledScript::compile("with ({}) {}", false).is_ok());
+}
+
+#[test]
+fn modules_compiled_on_another_thread_can_be_evaluated() {
+    let compiled = thread::spawn(|| {
+        CompiledModule::compile("export const answer = 42;\nglobalThis.result = answer;").unwrap()
+    })
+    .join()
+    .unwrap();
+
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let module = compiled.instantiate(agent, realm, None, gc.nogc());
+        agent
+            .run_module(module.unbind(), None, gc.reborrow())
+            .unbind()
+            .unwrap();
+        let source_text = String::from_static_str(agent, "result", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unbind()
+            .unwrap();
+        assert_eq!(result.to_int32(agent, gc).unwrap(), 42);
+    });
+}
+
+#[test]
+fn syntax_errors_are_reported_when_compiling() {
+    assert!(CompiledScript::compile("let let = 1;", false).is_err());
+    assert!(CompiledScript::compile("import 'foo';", false).is_err());
+    assert!(CompiledModule::compile("export default 1; export default 2;").is_err());
+    assert!(CompiledModule::compile("await 1;").is_ok());
+}
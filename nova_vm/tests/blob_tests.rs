@@ -0,0 +1,49 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "web-blob")]
+
+mod common;
+
+use common::{create_agent, define_global, run};
+use nova_vm::{
+    ecmascript::Blob,
+    engine::{Bindable, Global},
+};
+
+#[test]
+fn blob_tests() {
+    common::run_test_file("blob.test.js");
+}
+
+#[test]
+fn host_creates_blobs() {
+    let (_, mut agent, realm) = create_agent();
+    let blob = agent.run_in_realm(&realm, |agent, mut gc| {
+        let blob = Blob::new(agent, b"host bytes", "Application/Octet-Stream", gc.nogc()).unwrap();
+        assert!(!blob.is_file(agent));
+        let slice = blob.slice(agent, 5..100, "", gc.nogc());
+        assert_eq!(slice.to_vec(agent), b"bytes");
+        let root = Global::new(agent, blob.unbind());
+        define_global(agent, "blob", blob.unbind().into(), gc.reborrow());
+        let file =
+            Blob::new_file(agent, b"{}", "data.json", "application/json", 7, gc.nogc()).unwrap();
+        assert_eq!(file.last_modified(agent), Some(7));
+        define_global(agent, "file", file.unbind().into(), gc);
+        root
+    });
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "[blob.type, blob.size, file.name, file.type, file instanceof File].join()"
+        ),
+        "application/octet-stream,10,data.json,application/json,true"
+    );
+    agent.run_in_realm(&realm, |agent, _| {
+        let blob = blob.take(agent);
+        assert_eq!(blob.to_vec(agent), b"host bytes");
+        assert_eq!(blob.size(agent), 10);
+    });
+}
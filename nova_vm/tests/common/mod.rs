@@ -0,0 +1,356 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Fixtures shared by the integration tests.
+
+// Each test file uses only some of the fixtures.
+#![allow(dead_code)]
+
+use std::{cell::RefCell, collections::VecDeque, fs, path::PathBuf};
+
+use nova_vm::{
+    ecmascript::{
+        AbstractModule, Agent, AgentBuilder, GcAgent, GraphLoadingStateRecord, HostDefined,
+        HostHooks, InternalMethods, Job, JsError, ModuleRequest, Promise, PropertyDescriptor,
+        PropertyKey, RealmRoot, Referrer, String, Value, finish_loading_imported_module,
+        parse_module,
+    },
+    engine::{Bindable, GcScope, Global, NoGcScope},
+};
+
+/// A queue of Jobs, or of Jobs with additional data.
+pub struct JobQueue<T = Job>(RefCell<VecDeque<T>>);
+
+impl<T> Default for JobQueue<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+// Job doesn't implement Debug
+impl<T> core::fmt::Debug for JobQueue<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("JobQueue")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> JobQueue<T> {
+    pub fn push(&self, job: T) {
+        self.0.borrow_mut().push_back(job);
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        self.0.borrow_mut().pop_front()
+    }
+
+    /// Remove all queued Jobs.
+    pub fn take(&self) -> VecDeque<T> {
+        self.0.take()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+/// Host hooks that queue all Jobs, record reported errors and unhandled
+/// rejections, resolve module specifiers and load source text modules from
+/// static lists.
+#[derive(Debug, Default)]
+pub struct TestHostHooks {
+    pub generic_jobs: JobQueue,
+    pub promise_jobs: JobQueue,
+    pub timeout_jobs: JobQueue<(Job, u64)>,
+    pub cleanup_jobs: JobQueue,
+    /// Errors reported by [`HostHooks::report_job_error`].
+    pub job_errors: RefCell<Vec<std::string::String>>,
+    /// Reasons reported by [`HostHooks::report_unhandled_rejection`].
+    pub unhandled_rejections: RefCell<Vec<std::string::String>>,
+    /// Specifiers and source texts of the modules that can be imported.
+    sources: &'static [(&'static str, &'static str)],
+    loaded: RefCell<Vec<(&'static str, Global<AbstractModule<'static>>)>>,
+    /// Specifiers and the module keys they resolve to.
+    resolved_specifiers: &'static [(&'static str, &'static str)],
+}
+
+impl TestHostHooks {
+    /// Host hooks that load the given source text modules by specifier.
+    pub fn with_sources(sources: &'static [(&'static str, &'static str)]) -> Self {
+        Self {
+            sources,
+            ..Default::default()
+        }
+    }
+
+    /// Host hooks that resolve the given specifiers to module keys.
+    pub fn with_resolved_specifiers(
+        resolved_specifiers: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        Self {
+            resolved_specifiers,
+            ..Default::default()
+        }
+    }
+}
+
+impl HostHooks for TestHostHooks {
+    fn enqueue_generic_job(&self, job: Job) {
+        self.generic_jobs.push(job);
+    }
+
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_jobs.push(job);
+    }
+
+    fn enqueue_timeout_job(&self, timeout_job: Job, milliseconds: u64) {
+        self.timeout_jobs.push((timeout_job, milliseconds));
+    }
+
+    fn enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+        self.cleanup_jobs.push(job);
+    }
+
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        self.promise_jobs.pop()
+    }
+
+    fn dequeue_finalization_registry_cleanup_job(&self) -> Option<Job> {
+        self.cleanup_jobs.pop()
+    }
+
+    fn report_job_error(&self, agent: &mut Agent, error: JsError, gc: NoGcScope) {
+        let message = error.value().try_string_repr(agent, gc);
+        self.job_errors
+            .borrow_mut()
+            .push(message.to_string_lossy(agent).into_owned());
+    }
+
+    fn report_unhandled_rejection(
+        &self,
+        agent: &mut Agent,
+        _promise: Promise,
+        reason: Value,
+        gc: NoGcScope,
+    ) {
+        let reason = reason.try_string_repr(agent, gc);
+        self.unhandled_rejections
+            .borrow_mut()
+            .push(reason.to_string_lossy(agent).into_owned());
+    }
+
+    fn resolve_module_specifier(
+        &self,
+        _agent: &Agent,
+        _referrer: Referrer,
+        specifier: &str,
+    ) -> Option<std::string::String> {
+        self.resolved_specifiers
+            .iter()
+            .find(|(name, _)| *name == specifier)
+            .map(|(_, key)| (*key).into())
+    }
+
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: ModuleRequest<'gc>,
+        _host_defined: Option<HostDefined>,
+        payload: &mut GraphLoadingStateRecord<'gc>,
+        gc: NoGcScope<'gc, '_>,
+    ) {
+        let specifier = module_request.specifier(agent);
+        let specifier = specifier.to_string_lossy(agent);
+        let &(specifier, source) = self
+            .sources
+            .iter()
+            .find(|(name, _)| *name == specifier)
+            .expect("Unknown module specifier");
+        let loaded = self
+            .loaded
+            .borrow()
+            .iter()
+            .find(|(name, _)| *name == specifier)
+            .map(|(_, module)| module.get(agent, gc));
+        let module = loaded.unwrap_or_else(|| {
+            let source_text = String::from_static_str(agent, source, gc);
+            let realm = referrer.realm(agent, gc);
+            let module: AbstractModule = parse_module(agent, source_text, realm, None, gc)
+                .unwrap()
+                .into();
+            self.loaded
+                .borrow_mut()
+                .push((specifier, Global::new(agent, module.unbind())));
+            module
+        });
+        finish_loading_imported_module(agent, referrer, module_request, payload, Ok(module), gc);
+    }
+}
+
+/// Create an Agent with [`TestHostHooks`] and a default Realm.
+pub fn create_agent() -> (&'static TestHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(TestHostHooks::default(), AgentBuilder::new())
+}
+
+/// Create an Agent with the given host hooks and a default Realm from the
+/// builder.
+pub fn create_agent_with<H: HostHooks + 'static>(
+    host_hooks: H,
+    builder: AgentBuilder,
+) -> (&'static H, GcAgent, RealmRoot) {
+    let host_hooks: &'static H = Box::leak(Box::new(host_hooks));
+    let (agent, realm) = builder
+        .with_host_hooks(host_hooks)
+        .build_with_default_realm();
+    (host_hooks, agent, realm)
+}
+
+/// Run a Script and return its result or the error it threw as a string.
+pub fn try_run(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &str,
+) -> Result<std::string::String, std::string::String> {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_str(agent, source, gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unbind();
+        match result {
+            Ok(value) => Ok(value
+                .string_repr(agent, gc)
+                .to_string_lossy(agent)
+                .into_owned()),
+            Err(err) => Err(err
+                .value()
+                .string_repr(agent, gc)
+                .to_string_lossy(agent)
+                .into_owned()),
+        }
+    })
+}
+
+/// Run a Script in the current Realm and return its result.
+///
+/// Panics if the Script throws.
+pub fn eval<'gc>(agent: &mut Agent, source: &str, mut gc: GcScope<'gc, '_>) -> Value<'gc> {
+    let source_text = String::from_str(agent, source, gc.nogc());
+    match agent.run_script(source_text.unbind(), gc.reborrow()) {
+        Ok(value) => value.unbind().bind(gc.into_nogc()),
+        Err(err) => panic!(
+            "Script threw: {}",
+            err.value()
+                .unbind()
+                .string_repr(agent, gc)
+                .to_string_lossy(agent)
+        ),
+    }
+}
+
+/// Run a Script and return its result as a string.
+///
+/// Panics if the Script throws.
+pub fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> std::string::String {
+    try_run(agent, realm, source).unwrap_or_else(|err| panic!("Script threw: {err}"))
+}
+
+/// Run a Script and then the queued promise Jobs, returning the result of
+/// the Script as a string.
+///
+/// Panics if the Script throws.
+pub fn run_with_jobs(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> std::string::String {
+    let result = run(agent, realm, source);
+    agent.perform_microtask_checkpoint(realm);
+    result
+}
+
+/// Run a Module and return the error it threw as a string, if any.
+pub fn try_run_module(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &str,
+) -> Result<(), std::string::String> {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_str(agent, source, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+        match agent
+            .run_module(module.unbind(), None, gc.reborrow())
+            .unbind()
+        {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err
+                .value()
+                .string_repr(agent, gc)
+                .to_string_lossy(agent)
+                .into_owned()),
+        }
+    })
+}
+
+/// Run a Module.
+///
+/// Panics if the Module throws.
+pub fn run_module(agent: &mut GcAgent, realm: &RealmRoot, source: &str) {
+    if let Err(err) = try_run_module(agent, realm, source) {
+        panic!("Module threw: {err}");
+    }
+}
+
+/// Define a writable and configurable property on the global object.
+pub fn define_global(agent: &mut Agent, name: &'static str, value: Value, mut gc: GcScope) {
+    let value = value.bind(gc.nogc());
+    let key = PropertyKey::from_static_str(agent, name, gc.nogc());
+    let global = agent.current_global_object(gc.nogc());
+    global
+        .unbind()
+        .internal_define_own_property(
+            agent,
+            key.unbind(),
+            PropertyDescriptor::data(value.unbind())
+                .writable()
+                .configurable()
+                .build(),
+            gc.reborrow(),
+        )
+        .unwrap();
+}
+
+/// Run `tests/sources/{file_name}` as a Script with [`TestHostHooks`], and
+/// then run the queued promise Jobs.
+///
+/// If the Script defines a global `afterJobs` function, it is called after
+/// the Jobs have run to check their results. The test fails if any of these
+/// throw, or if a Promise rejection is left unhandled.
+pub fn run_test_file(file_name: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "sources", file_name]
+        .iter()
+        .collect();
+    let contents = fs::read_to_string(&path).expect("Should have been able to read the file");
+    let (host_hooks, mut agent, realm) = create_agent();
+    if let Err(err) = try_run(&mut agent, &realm, &contents) {
+        panic!("Test '{}' failed: {err}", path.display());
+    }
+    agent.perform_microtask_checkpoint(&realm);
+    if let Err(err) = try_run(
+        &mut agent,
+        &realm,
+        "if (typeof afterJobs === 'function') afterJobs();",
+    ) {
+        panic!("Test '{}' failed after Jobs: {err}", path.display());
+    }
+    let job_errors = host_hooks.job_errors.borrow();
+    assert!(job_errors.is_empty(), "Job errors: {job_errors:?}");
+    let unhandled_rejections = host_hooks.unhandled_rejections.borrow();
+    assert!(
+        unhandled_rejections.is_empty(),
+        "Unhandled rejections: {unhandled_rejections:?}"
+    );
+}
@@ -0,0 +1,205 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "date")]
+
+mod common;
+
+use std::cell::Cell;
+
+use common::{create_agent_with, run};
+use nova_vm::ecmascript::{AgentBuilder, GcAgent, HostHooks, Job, RealmRoot};
+
+const NS_PER_HOUR: i64 = 3_600_000_000_000;
+/// 2024-03-31T01:00:00Z, when summer time starts in the "Test/Summer" time
+/// zone.
+const SUMMER_START: i128 = 1_711_846_800_000;
+/// 2024-10-27T01:00:00Z, when summer time ends in the "Test/Summer" time
+/// zone.
+const SUMMER_END: i128 = 1_729_990_800_000;
+
+/// Host hooks with a manually advanced clock and a configurable time zone.
+#[derive(Debug)]
+struct ClockHostHooks {
+    now: Cell<i64>,
+    time_zone: &'static str,
+}
+
+impl HostHooks for ClockHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn current_time(&self) -> i64 {
+        self.now.get()
+    }
+
+    fn system_time_zone_identifier(&self) -> &str {
+        self.time_zone
+    }
+
+    /// "Test/Summer" is one hour ahead of UTC, and two hours ahead during
+    /// summer time.
+    fn get_named_time_zone_offset_nanoseconds(
+        &self,
+        time_zone_identifier: &str,
+        epoch_nanoseconds: i128,
+    ) -> i64 {
+        if time_zone_identifier != "Test/Summer" {
+            return 0;
+        }
+        let epoch_milliseconds = epoch_nanoseconds.div_euclid(1_000_000);
+        if (SUMMER_START..SUMMER_END).contains(&epoch_milliseconds) {
+            2 * NS_PER_HOUR
+        } else {
+            NS_PER_HOUR
+        }
+    }
+}
+
+fn create_agent(
+    now: i64,
+    time_zone: &'static str,
+) -> (&'static ClockHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(
+        ClockHostHooks {
+            now: Cell::new(now),
+            time_zone,
+        },
+        AgentBuilder::new(),
+    )
+}
+
+#[test]
+fn current_time_comes_from_the_host() {
+    let (host_hooks, mut agent, realm) = create_agent(1_700_000_000_123, "UTC");
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "[Date.now(), new Date().getTime(), Date()].join()"
+        ),
+        "1700000000123,1700000000123,Tue Nov 14 2023 22:13:20 GMT+0000"
+    );
+    host_hooks.now.set(-1);
+    assert_eq!(
+        run(&mut agent, &realm, "new Date().toISOString()"),
+        "1969-12-31T23:59:59.999Z"
+    );
+}
+
+#[test]
+fn default_host_uses_system_clock_and_utc() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let before = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let now: u64 = run(&mut agent, &realm, "Date.now()").parse().unwrap();
+    assert!(now >= before);
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "var d = new Date(2020, 1, 29, 12); [d.getTimezoneOffset(), d.toISOString()].join()"
+        ),
+        "0,2020-02-29T12:00:00.000Z"
+    );
+}
+
+#[test]
+fn offset_time_zone() {
+    let (_, mut agent, realm) = create_agent(0, "+05:30");
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var d = new Date(0);
+            [
+                d.getHours(),
+                d.getMinutes(),
+                d.getTimezoneOffset(),
+                d.toString(),
+                d.toTimeString(),
+                new Date(1970, 0, 1).getTime(),
+                Date.parse("1970-01-01T05:30"),
+                Date.parse("1970-01-01"),
+            ].join("|")
+            "#
+        ),
+        "5|30|-330|Thu Jan 01 1970 05:30:00 GMT+0530|05:30:00 GMT+0530|-19800000|0|0"
+    );
+}
+
+#[test]
+fn named_time_zone_with_summer_time() {
+    let (_, mut agent, realm) = create_agent(0, "Test/Summer");
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var winter = new Date(Date.UTC(2024, 0, 15, 12));
+            var summer = new Date(Date.UTC(2024, 6, 1, 12));
+            // 02:30 is skipped when summer time starts, and interpreted with
+            // the offset before the transition.
+            var skipped = new Date(2024, 2, 31, 2, 30);
+            // 02:30 is repeated when summer time ends, and the earlier
+            // instant is chosen.
+            var repeated = new Date(2024, 9, 27, 2, 30);
+            [
+                winter.getHours(),
+                winter.getTimezoneOffset(),
+                summer.getHours(),
+                summer.getTimezoneOffset(),
+                summer.toString(),
+                skipped.toISOString(),
+                skipped.getHours(),
+                repeated.toISOString(),
+                repeated.getTimezoneOffset(),
+                new Date(repeated.getTime() + 3600000).getHours(),
+            ].join("|")
+            "#
+        ),
+        "13|-60|14|-120|Mon Jul 01 2024 14:00:00 GMT+0200|2024-03-31T01:30:00.000Z|3|2024-10-27T00:30:00.000Z|-120|2"
+    );
+}
+
+#[test]
+fn parse_round_trips_date_strings() {
+    let (_, mut agent, realm) = create_agent(0, "-08:00");
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var results = [];
+            for (var t of [0, 1718454245000, -62198755200000, -8.64e15, 8.64e15]) {
+                var d = new Date(t);
+                results.push(
+                    Date.parse(d.toString()) === t &&
+                        Date.parse(d.toUTCString()) === t &&
+                        Date.parse(d.toISOString()) === t,
+                );
+            }
+            results.push(
+                new Date(-62198755200000).toISOString(),
+                new Date(8.64e15).toISOString(),
+                Date.parse("Thu Jan 01 1970 00:00:00 GMT+0100 (Central European Standard Time)"),
+                Date.parse("Thu, 01 Jan 1970 00:00:00 GMT"),
+                Date.parse("Thu Jan 01 1970 00:00:00 GMT"),
+                Date.parse("Thu, 32 Jan 1970 00:00:00 GMT"),
+                Date.parse("Foo Jan 01 1970 00:00:00 GMT+0000"),
+                Date.parse("Thu Jan 01 1970 24:00:01 GMT+0000"),
+                Date.parse("Thu, 01 Jan 1970 00:00:00 GMT+0000"),
+            );
+            results.join()
+            "#
+        ),
+        "true,true,true,true,true,-000001-01-01T00:00:00.000Z,+275760-09-13T00:00:00.000Z,-3600000,0,NaN,NaN,NaN,NaN,NaN"
+    );
+}
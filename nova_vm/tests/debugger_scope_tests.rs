@@ -0,0 +1,222 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::{create_agent_with, run};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, DebugScope, DebugScopeKind, GcAgent, HostHooks, Job, RealmRoot,
+        String, Value,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+#[derive(Debug, Default)]
+struct DebuggerHostHooks {
+    /// Names of bindings to set to 42 when paused.
+    assignments: Vec<&'static str>,
+    log: RefCell<Vec<std::string::String>>,
+}
+
+fn describe_scope(agent: &mut Agent, scope: DebugScope, gc: NoGcScope) -> std::string::String {
+    let bindings = scope
+        .bindings(agent, gc)
+        .into_iter()
+        .map(|binding| {
+            let value = match binding.value() {
+                Some(value) => value
+                    .try_string_repr(agent, gc)
+                    .to_string_lossy(agent)
+                    .into_owned(),
+                None => "<uninitialized>".to_owned(),
+            };
+            let kind = if binding.is_mutable() { "" } else { "const " };
+            format!("{kind}{}={value}", binding.name().to_string_lossy(agent))
+        })
+        .collect::<Vec<_>>();
+    format!("{:?}({})", scope.kind(), bindings.join(", "))
+}
+
+impl HostHooks for DebuggerHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn debugger_statement(&self, agent: &mut Agent, mut gc: GcScope) {
+        let mut frame = 0;
+        while let Some(scope) = agent.debug_scope(frame, gc.nogc()) {
+            let mut scopes = vec![];
+            let mut scope = Some(scope);
+            while let Some(s) = scope {
+                if s.kind() == DebugScopeKind::Global {
+                    break;
+                }
+                scopes.push(describe_scope(agent, s, gc.nogc()));
+                scope = s.outer(agent);
+            }
+            self.log
+                .borrow_mut()
+                .push(format!("frame {frame}: {}", scopes.join(" -> ")));
+            frame += 1;
+        }
+        for &name_str in &self.assignments {
+            let name = String::from_str(agent, name_str, gc.nogc());
+            // Set the binding in the innermost scope that has it, or in the
+            // innermost scope if none does.
+            let innermost = agent.debug_scope(0, gc.nogc()).unwrap();
+            let mut target = innermost;
+            let mut scope = Some(innermost);
+            while let Some(s) = scope {
+                if s.bindings(agent, gc.nogc())
+                    .iter()
+                    .any(|binding| binding.name() == name)
+                {
+                    target = s;
+                    break;
+                }
+                scope = s.outer(agent);
+            }
+            let result =
+                target
+                    .unbind()
+                    .set_binding(agent, name.unbind(), Value::from(42), gc.reborrow());
+            let outcome = match result {
+                Ok(()) => "ok".to_owned(),
+                Err(err) => err
+                    .value()
+                    .unbind()
+                    .try_string_repr(agent, gc.nogc())
+                    .to_string_lossy(agent)
+                    .split(':')
+                    .next()
+                    .unwrap()
+                    .to_owned(),
+            };
+            self.log
+                .borrow_mut()
+                .push(format!("set {name_str}: {outcome}"));
+        }
+    }
+}
+
+fn setup(
+    debug_scopes: bool,
+    assignments: Vec<&'static str>,
+) -> (&'static DebuggerHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(
+        DebuggerHostHooks {
+            assignments,
+            ..Default::default()
+        },
+        AgentBuilder::new().with_debug_scopes(debug_scopes),
+    )
+}
+
+#[test]
+fn debugger_statement_lists_scopes_of_each_frame() {
+    let (host_hooks, mut agent, realm) = setup(true, vec![]);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function inner(a) {
+            const b = a + 1;
+            {
+                let c = "block";
+                debugger;
+            }
+        }
+        function outer() {
+            let d = 4;
+            inner(d);
+        }
+        [1].forEach(outer);
+        "#,
+    );
+    // The forEach builtin's execution context is skipped; the script's
+    // LexicalEnvironment is the Global Environment Record.
+    assert_eq!(
+        host_hooks.log.borrow().as_slice(),
+        [
+            "frame 0: Declarative(c=block) -> Declarative(const b=5) -> Function(a=4)",
+            "frame 1: Declarative(d=4) -> Function()",
+            "frame 2: ",
+        ]
+    );
+}
+
+#[test]
+fn debugger_scope_shows_uninitialized_bindings() {
+    let (host_hooks, mut agent, realm) = setup(true, vec![]);
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        (function () {
+            debugger;
+            let x = 1;
+        })();
+        "#,
+    );
+    assert_eq!(
+        host_hooks.log.borrow().as_slice(),
+        [
+            "frame 0: Declarative(x=<uninitialized>) -> Function()",
+            "frame 1: ",
+        ]
+    );
+}
+
+#[test]
+fn debugger_scope_set_binding() {
+    let (host_hooks, mut agent, realm) = setup(true, vec!["x", "y", "c", "missing"]);
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            (function (x) {
+                const c = 1;
+                debugger;
+                let y = 2;
+                return [x, c, y].join();
+            })(1);
+            "#,
+        ),
+        "42,1,2"
+    );
+    assert_eq!(
+        &host_hooks.log.borrow()[2..],
+        [
+            "set x: ok",
+            "set y: ReferenceError",
+            "set c: TypeError",
+            "set missing: ReferenceError",
+        ]
+    );
+}
+
+#[test]
+fn debugger_scope_set_global_binding() {
+    let (host_hooks, mut agent, realm) = setup(false, vec!["g", "l"]);
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var g = 1;
+            let l = 2;
+            debugger;
+            [g, l].join();
+            "#,
+        ),
+        "42,42"
+    );
+    assert_eq!(&host_hooks.log.borrow()[1..], ["set g: ok", "set l: ok"]);
+}
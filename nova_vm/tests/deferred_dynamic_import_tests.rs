@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::{JobQueue, create_agent_with, run};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ExceptionType, GcAgent, GraphLoadingStateRecord, HostDefined,
+        HostHooks, Job, ModuleRequest, PendingDynamicImport, RealmRoot, Referrer, String,
+        parse_module,
+    },
+    engine::NoGcScope,
+};
+
+/// Host hooks that load every dynamically imported module asynchronously.
+#[derive(Debug, Default)]
+struct DeferringHostHooks {
+    promise_jobs: JobQueue,
+    pending: RefCell<Vec<(std::string::String, PendingDynamicImport)>>,
+}
+
+impl HostHooks for DeferringHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_jobs.push(job);
+    }
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        self.promise_jobs.pop()
+    }
+
+    fn get_supported_import_attributes(&self) -> &[&'static str] {
+        &["type"]
+    }
+
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: ModuleRequest<'gc>,
+        _host_defined: Option<HostDefined>,
+        payload: &mut GraphLoadingStateRecord<'gc>,
+        _gc: NoGcScope<'gc, '_>,
+    ) {
+        assert!(payload.is_dynamic_import());
+        let mut specifier = module_request
+            .specifier(agent)
+            .to_string_lossy(agent)
+            .into_owned();
+        for attribute in module_request.attributes(agent) {
+            specifier.push_str(&format!(
+                " {}={}",
+                attribute.key().to_string_lossy(agent),
+                attribute.value().to_string_lossy(agent)
+            ));
+        }
+        let pending = agent.defer_dynamic_import(referrer, module_request, payload);
+        self.pending.borrow_mut().push((specifier, pending));
+    }
+}
+
+fn create_agent() -> (&'static DeferringHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(DeferringHostHooks::default(), AgentBuilder::new())
+}
+
+/// Finish the oldest deferred dynamic import by loading `source`, or by
+/// failing the load if `source` is None.
+fn finish(
+    host_hooks: &DeferringHostHooks,
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: Option<&'static str>,
+) -> std::string::String {
+    let (specifier, pending) = host_hooks.pending.borrow_mut().remove(0);
+    agent.run_in_realm(realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let result = match source {
+            Some(source) => {
+                let realm = agent.current_realm(gc);
+                let source_text = String::from_static_str(agent, source, gc);
+                Ok(parse_module(agent, source_text, realm, None, gc)
+                    .unwrap()
+                    .into())
+            }
+            None => Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "module not found",
+                gc,
+            )),
+        };
+        agent.finish_dynamic_import(pending, result, gc);
+    });
+    agent.perform_microtask_checkpoint(realm);
+    specifier
+}
+
+#[test]
+fn deferred_dynamic_import_resolves_with_namespace() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        import("lib:value").then((ns) => log.push(`fulfilled ${ns.value}`));
+        log.push("returned");
+        "#,
+    );
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "returned");
+
+    // Collect garbage while the import is pending.
+    agent.gc();
+    let specifier = finish(
+        host_hooks,
+        &mut agent,
+        &realm,
+        Some("log.push('evaluated'); export const value = 1;"),
+    );
+    assert_eq!(specifier, "lib:value");
+    assert_eq!(
+        run(&mut agent, &realm, "log.join()"),
+        "returned,evaluated,fulfilled 1"
+    );
+    assert!(host_hooks.pending.borrow().is_empty());
+}
+
+#[test]
+fn deferred_dynamic_import_rejects_with_load_error() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        import("lib:missing").catch((err) => log.push(`rejected ${err.message}`));
+        import("lib:throws").catch((err) => log.push(`rejected ${err.message}`));
+        "#,
+    );
+    finish(host_hooks, &mut agent, &realm, None);
+    finish(
+        host_hooks,
+        &mut agent,
+        &realm,
+        Some("throw new Error('oops');"),
+    );
+    assert_eq!(
+        run(&mut agent, &realm, "log.join()"),
+        "rejected module not found,rejected oops"
+    );
+}
+
+#[test]
+fn dynamic_import_passes_attribute_values_to_host() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"import("lib:data", { with: { type: "json" } });"#,
+    );
+    let specifier = finish(host_hooks, &mut agent, &realm, Some("export default 1;"));
+    assert_eq!(specifier, "lib:data type=json");
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, EvaluationOptions, SourceKind, String, detect_source_kind},
+    engine::Bindable,
+};
+
+#[test]
+fn detect_source_kinds() {
+    assert_eq!(
+        detect_source_kind("var a = 1;"),
+        Ok(SourceKind::Script { strict: false })
+    );
+    assert_eq!(
+        detect_source_kind("'use strict'; var a = 1;"),
+        Ok(SourceKind::Script { strict: true })
+    );
+    assert_eq!(
+        detect_source_kind("var a = 'use strict';"),
+        Ok(SourceKind::Script { strict: false })
+    );
+    assert_eq!(
+        detect_source_kind("export const a = 1;"),
+        Ok(SourceKind::Module)
+    );
+    assert_eq!(
+        detect_source_kind("import { a } from 'a';"),
+        Ok(SourceKind::Module)
+    );
+    assert_eq!(detect_source_kind("import.meta;"), Ok(SourceKind::Module));
+    assert!(SourceKind::Module.is_strict());
+    assert!(!SourceKind::Script { strict: false }.is_strict());
+    assert!(detect_source_kind("var = ;").is_err());
+}
+
+/// Evaluates the source with the given evaluation function and returns the
+/// result, or the thrown error, as a string.
+fn evaluate(source: &'static str, kind: Option<SourceKind>, strict: bool) -> std::string::String {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, source, gc.nogc()).unbind();
+        let options = EvaluationOptions {
+            strict,
+            ..Default::default()
+        };
+        let result = match kind {
+            None => agent.evaluate(source, options, gc.reborrow()),
+            Some(SourceKind::Module) => agent.evaluate_module(source, options, gc.reborrow()),
+            Some(SourceKind::Script { .. }) => {
+                agent.evaluate_script(source, options, gc.reborrow())
+            }
+        };
+        let value = match result.unbind() {
+            Ok(value) => value,
+            Err(err) => err.value(),
+        };
+        value
+            .string_repr(agent, gc)
+            .to_string_lossy(agent)
+            .into_owned()
+    })
+}
+
+const SCRIPT: Option<SourceKind> = Some(SourceKind::Script { strict: false });
+
+#[test]
+fn evaluate_detects_source_kind() {
+    // Scripts return their completion value; `this` is the global object.
+    assert_eq!(evaluate("typeof this", None, false), "object");
+    // Modules are strict and their `this` is undefined.
+    assert_eq!(
+        evaluate(
+            "export const a = 1; globalThis.result = typeof this;",
+            None,
+            false
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        evaluate("import.meta; globalThis.x = 1;", None, false),
+        "undefined"
+    );
+    assert_eq!(
+        evaluate("var = ;", None, false),
+        "SyntaxError: Unexpected token"
+    );
+}
+
+#[test]
+fn evaluate_script_options() {
+    assert_eq!(
+        evaluate("(function () { return typeof this; })()", SCRIPT, false),
+        "object"
+    );
+    assert_eq!(
+        evaluate("(function () { return typeof this; })()", SCRIPT, true),
+        "undefined"
+    );
+    assert_eq!(
+        evaluate("export const a = 1;", SCRIPT, false),
+        "SyntaxError: Source text contains import or export declarations and must be evaluated as a Module"
+    );
+}
+
+#[test]
+fn evaluate_module_runs_module() {
+    assert_eq!(
+        evaluate(
+            "globalThis.value = (function () { return typeof this; })();",
+            Some(SourceKind::Module),
+            false
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        evaluate("with ({}) {}", Some(SourceKind::Module), false)
+            .split(':')
+            .next(),
+        Some("SyntaxError")
+    );
+}
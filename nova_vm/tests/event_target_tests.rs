@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "web-events")]
+
+mod common;
+
+use common::{create_agent, define_global, run};
+use nova_vm::{
+    ecmascript::{Event, EventInit, EventTarget, String},
+    engine::{Bindable, Global},
+};
+
+#[test]
+fn event_target_tests() {
+    common::run_test_file("eventTarget.test.js");
+}
+
+#[test]
+fn listener_errors_are_reported() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var target = new EventTarget();
+            var log = [];
+            target.addEventListener("ping", () => { throw "listener error"; });
+            target.addEventListener("ping", () => log.push("after"));
+            log.push(target.dispatchEvent(new Event("ping")));
+            log.join()
+            "#,
+        ),
+        "after,true"
+    );
+    assert_eq!(*host_hooks.job_errors.borrow(), vec!["listener error"]);
+}
+
+#[test]
+fn host_dispatches_trusted_events() {
+    let (_, mut agent, realm) = create_agent();
+    let target = agent.run_in_realm(&realm, |agent, gc| {
+        let target = EventTarget::new(agent, gc.nogc());
+        let root = Global::new(agent, target.unbind());
+        define_global(agent, "target", target.unbind().into(), gc);
+        root
+    });
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        target.addEventListener("message", (e) => {
+            log.push(e.type, e.isTrusted);
+            e.preventDefault();
+        });
+        "#,
+    );
+    let not_canceled = agent.run_in_realm(&realm, |agent, mut gc| {
+        let target = target.take(agent).bind(gc.nogc());
+        let event_type = String::from_static_str(agent, "message", gc.nogc());
+        let event = Event::new(
+            agent,
+            event_type,
+            EventInit {
+                cancelable: true,
+                ..Default::default()
+            },
+            gc.nogc(),
+        );
+        target
+            .unbind()
+            .dispatch_event(agent, event.unbind(), gc.reborrow())
+            .unwrap()
+    });
+    assert!(!not_canceled);
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "message,true");
+}
@@ -0,0 +1,65 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::sync::Arc;
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, String, parse_script, script_evaluation},
+    engine::Bindable,
+};
+
+const SOURCE: &str = "function add(a, b) { return a + b; }\nadd.toString();";
+
+#[test]
+fn external_strings_are_not_copied() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let source: Arc<str> = Arc::from(SOURCE);
+    agent.run_in_realm(&realm, |agent, gc| {
+        let string = String::from_external(agent, source.clone(), gc.nogc());
+        assert_eq!(Arc::strong_count(&source), 2);
+        let data = string.to_string_lossy(agent);
+        assert_eq!(data, SOURCE);
+        assert!(core::ptr::eq(data.as_ptr(), source.as_ptr()));
+    });
+    // The unrooted String is collected, releasing the external data.
+    agent.gc();
+    assert_eq!(Arc::strong_count(&source), 1);
+}
+
+#[test]
+fn equal_external_strings_are_stored_once() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let first: Arc<str> = Arc::from(SOURCE);
+    let second: Arc<str> = Arc::from(SOURCE);
+    agent.run_in_realm(&realm, |agent, gc| {
+        let a = String::from_external(agent, first.clone(), gc.nogc());
+        let b = String::from_external(agent, second.clone(), gc.nogc());
+        let c = String::from_string(agent, SOURCE.to_string(), gc.nogc());
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_eq!(Arc::strong_count(&first), 2);
+        assert_eq!(Arc::strong_count(&second), 1);
+    });
+}
+
+#[test]
+fn scripts_read_source_text_through_external_strings() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let source: Arc<str> = Arc::from(SOURCE);
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_external(agent, source.clone(), gc.nogc());
+        let script = parse_script(agent, source_text, realm, true, None, gc.nogc()).unwrap();
+        let result = script_evaluation(agent, script.unbind(), gc.reborrow())
+            .unbind()
+            .unwrap();
+        let Ok(result) = String::try_from(result) else {
+            panic!("Expected a String, got {result:?}");
+        };
+        assert_eq!(
+            result.to_string_lossy(agent),
+            "function add(a, b) { return a + b; }"
+        );
+    });
+}
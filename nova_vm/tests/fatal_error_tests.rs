@@ -0,0 +1,77 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, FatalErrorKind, String, parse_module},
+    engine::Bindable,
+    js_function,
+};
+
+js_function! {
+    struct Crash = "crash";
+    fn crash() -> bool {
+        panic!("host function crashed")
+    }
+}
+
+#[test]
+fn javascript_exceptions_are_not_fatal() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let result = agent.try_run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, "throw new Error('caught')", gc.nogc());
+        agent.run_script(source.unbind(), gc.reborrow()).is_err()
+    });
+    assert_eq!(result, Ok(true));
+    assert!(agent.fatal_error().is_none());
+
+    let result = agent.try_run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, "1 + 1", gc.nogc());
+        let value = agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+        value.unbind().to_int32(agent, gc).unwrap()
+    });
+    assert_eq!(result, Ok(2));
+}
+
+#[test]
+fn panics_terminate_the_agent() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.register_module("host:crash", |agent, module, gc| {
+        module.export_js_function::<Crash>(agent, gc);
+    });
+    let result = agent.try_run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(
+            agent,
+            r#"
+            import { crash } from "host:crash";
+            try {
+                crash();
+            } catch {
+                globalThis.caught = true;
+            } finally {
+                globalThis.finalized = true;
+            }
+            "#,
+            gc.nogc(),
+        );
+        let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+        let _ = agent.run_module(module.unbind(), None, gc.reborrow());
+    });
+    let error = result.unwrap_err();
+    assert_eq!(error.kind(), FatalErrorKind::Panic);
+    assert_eq!(error.message(), "host function crashed");
+    assert_eq!(agent.fatal_error(), Some(&error));
+
+    // The terminated Agent refuses to run any further code.
+    let mut ran = false;
+    let result = agent.try_run_in_realm(&realm, |_, _| ran = true);
+    assert!(!ran);
+    let error = result.unwrap_err();
+    assert_eq!(error.kind(), FatalErrorKind::Terminated);
+    assert_eq!(
+        error.to_string(),
+        "Agent was terminated: host function crashed"
+    );
+    agent.gc();
+}
@@ -0,0 +1,151 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "web-fetch")]
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::{JobQueue, create_agent_with, run_with_jobs};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, FetchRequest, FetchResponse, GcAgent, HostHooks, Job, PendingFetch,
+        RealmRoot,
+    },
+    engine::GcScope,
+};
+
+#[derive(Debug, Default)]
+struct FetchHostHooks {
+    promise_jobs: JobQueue,
+    /// Record requests instead of rejecting them.
+    record_requests: bool,
+    requests: RefCell<Vec<(FetchRequest, PendingFetch)>>,
+}
+
+impl HostHooks for FetchHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_jobs.push(job);
+    }
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        self.promise_jobs.pop()
+    }
+
+    fn fetch(&self, agent: &mut Agent, request: FetchRequest, pending: PendingFetch, gc: GcScope) {
+        if self.record_requests {
+            self.requests.borrow_mut().push((request, pending));
+        } else {
+            agent.finish_fetch(pending, Err("offline".to_owned()), gc);
+        }
+    }
+}
+
+fn create_agent(record_requests: bool) -> (&'static FetchHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(
+        FetchHostHooks {
+            record_requests,
+            ..Default::default()
+        },
+        AgentBuilder::new(),
+    )
+}
+
+#[test]
+fn fetch_tests() {
+    common::run_test_file("fetch.test.js");
+}
+
+#[test]
+fn fetch_is_performed_by_the_host() {
+    let (host_hooks, mut agent, realm) = create_agent(true);
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        fetch("https://example.com/data", {
+            method: "PUT",
+            body: "payload",
+            headers: { "X-Token": "abc" },
+        }).then((response) => {
+            log.push(response.status, response.ok, response.statusText, response.url);
+            log.push(response.headers.get("content-type"));
+            try { response.headers.set("x", "y"); } catch (e) { log.push(e.name); }
+            return response.text();
+        }).then((text) => log.push(text));
+        fetch(new Request("https://example.com/missing")).catch((e) => {
+            log.push(e.name, e.message);
+        });
+        fetch("https://example.com", { method: "TRACE" }).catch((e) => log.push(e.name));
+        "#,
+    );
+    let requests = host_hooks.requests.take();
+    assert_eq!(requests.len(), 2);
+    let mut requests = requests.into_iter();
+    let (request, pending) = requests.next().unwrap();
+    assert_eq!(
+        request,
+        FetchRequest {
+            url: "https://example.com/data".to_owned(),
+            method: "PUT".to_owned(),
+            headers: vec![
+                ("x-token".to_owned(), "abc".to_owned()),
+                (
+                    "content-type".to_owned(),
+                    "text/plain;charset=UTF-8".to_owned()
+                ),
+            ],
+            body: Some(b"payload".to_vec()),
+        }
+    );
+    assert_eq!(run_with_jobs(&mut agent, &realm, "log.join()"), "TypeError");
+    agent.run_in_realm(&realm, |agent, gc| {
+        agent.finish_fetch(
+            pending,
+            Ok(FetchResponse {
+                status: 200,
+                status_text: "OK".to_owned(),
+                headers: vec![("Content-Type".to_owned(), "text/plain".to_owned())],
+                body: b"response body".to_vec(),
+            }),
+            gc,
+        );
+    });
+    let (request, pending) = requests.next().unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.body, None);
+    agent.run_in_realm(&realm, |agent, gc| {
+        agent.finish_fetch(pending, Err("not found".to_owned()), gc);
+    });
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "TypeError,200,true,OK,https://example.com/data,text/plain,TypeError,TypeError,not found,response body"
+    );
+}
+
+#[test]
+fn default_host_rejects_fetch() {
+    let (_, mut agent, realm) = create_agent(false);
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        var promise = fetch("https://example.com");
+        log.push(promise instanceof Promise);
+        promise.catch((e) => log.push(e.name, e.message));
+        "#,
+    );
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "true,TypeError,offline"
+    );
+}
@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::Cell;
+
+use common::{create_agent_with, try_run};
+use nova_vm::ecmascript::{AgentBuilder, HostHooks, Job};
+
+/// Host hooks refuelling the Agent a limited number of times.
+#[derive(Debug, Default)]
+struct RefuellingHostHooks {
+    refuels_left: Cell<u32>,
+    refuels: Cell<u32>,
+}
+
+impl HostHooks for RefuellingHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn fuel_exhausted(&self) -> Option<u64> {
+        let refuels_left = self.refuels_left.get();
+        if refuels_left == 0 {
+            return None;
+        }
+        self.refuels_left.set(refuels_left - 1);
+        self.refuels.set(self.refuels.get() + 1);
+        Some(100)
+    }
+}
+
+#[test]
+fn fuel_metering_is_disabled_by_default() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    assert_eq!(agent.fuel(), None);
+    assert_eq!(
+        try_run(&mut agent, &realm, "let i = 0; while (i < 1000) i++; i").unwrap(),
+        "1000"
+    );
+    assert_eq!(agent.fuel(), None);
+}
+
+#[test]
+fn running_out_of_fuel_terminates_execution() {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_fuel(10_000)
+        .build_with_default_realm();
+    let error = try_run(
+        &mut agent,
+        &realm,
+        r#"
+        var caught = false;
+        try {
+            while (true) {}
+        } catch {
+            caught = true;
+        }
+        "#,
+    )
+    .unwrap_err();
+    assert_eq!(error, "Error: Fuel exhausted");
+    assert_eq!(agent.fuel(), Some(0));
+    // Running out of fuel does not request termination through the
+    // termination handle.
+    assert!(!agent.termination_handle().is_terminating());
+    assert_eq!(
+        try_run(&mut agent, &realm, "1 + 1").unwrap_err(),
+        "Error: Fuel exhausted"
+    );
+    // The Agent can run code again once refuelled.
+    agent.set_fuel(Some(10_000));
+    assert_eq!(try_run(&mut agent, &realm, "caught").unwrap(), "false");
+}
+
+#[test]
+fn refuelling_resumes_execution() {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_fuel(1_000)
+        .build_with_default_realm();
+    assert_eq!(
+        try_run(&mut agent, &realm, "var i = 0; while (true) i++;").unwrap_err(),
+        "Error: Fuel exhausted"
+    );
+    agent.set_fuel(Some(10_000));
+    assert_eq!(
+        try_run(&mut agent, &realm, "let j = 0; while (j < 100) j++; j").unwrap(),
+        "100"
+    );
+    assert!(agent.fuel().unwrap() < 10_000);
+}
+
+#[test]
+fn fuel_consumption_is_deterministic() {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_fuel(1_000_000)
+        .build_with_default_realm();
+    let source =
+        "(() => { let sum = 0; for (let i = 0; i < 100; i++) { sum += i; } return sum; })()";
+    let mut consumed = vec![];
+    for _ in 0..3 {
+        let before = agent.fuel().unwrap();
+        assert_eq!(try_run(&mut agent, &realm, source).unwrap(), "4950");
+        consumed.push(before - agent.fuel().unwrap());
+    }
+    assert!(consumed[0] > 100);
+    assert_eq!(consumed[0], consumed[1]);
+    assert_eq!(consumed[1], consumed[2]);
+}
+
+#[test]
+fn fuel_exhausted_hook_can_refuel() {
+    let (host_hooks, mut agent, realm) = create_agent_with(
+        RefuellingHostHooks {
+            refuels_left: Cell::new(u32::MAX),
+            refuels: Cell::new(0),
+        },
+        AgentBuilder::new().with_fuel(100),
+    );
+    assert_eq!(
+        try_run(&mut agent, &realm, "let i = 0; while (i < 1000) i++; i").unwrap(),
+        "1000"
+    );
+    assert!(host_hooks.refuels.get() > 10);
+}
+
+#[test]
+fn fuel_exhausted_hook_can_decline_to_refuel() {
+    let (host_hooks, mut agent, realm) = create_agent_with(
+        RefuellingHostHooks {
+            refuels_left: Cell::new(3),
+            refuels: Cell::new(0),
+        },
+        AgentBuilder::new().with_fuel(100),
+    );
+    assert_eq!(
+        try_run(&mut agent, &realm, "while (true) {}").unwrap_err(),
+        "Error: Fuel exhausted"
+    );
+    assert_eq!(host_hooks.refuels.get(), 3);
+    assert_eq!(agent.fuel(), Some(0));
+}
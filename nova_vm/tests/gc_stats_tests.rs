@@ -0,0 +1,201 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::run;
+use nova_vm::ecmascript::{AgentBuilder, GcReason, GcStats, HostHooks, Job};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GcEvent {
+    Before(GcReason, u64),
+    After(GcReason, u64),
+}
+
+#[derive(Debug, Default)]
+struct GcHostHooks {
+    events: RefCell<Vec<GcEvent>>,
+}
+
+impl HostHooks for GcHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn before_gc(&self, reason: GcReason, stats: GcStats) {
+        self.events
+            .borrow_mut()
+            .push(GcEvent::Before(reason, stats.collections()));
+    }
+
+    fn after_gc(&self, reason: GcReason, stats: GcStats) {
+        self.events
+            .borrow_mut()
+            .push(GcEvent::After(reason, stats.collections()));
+    }
+}
+
+#[test]
+fn function_calls_reuse_pooled_vm_frames() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let before = agent.gc_stats();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function add(a, b) { return a + b; }
+        function fail() { throw new Error(); }
+        var sum = 0;
+        for (let i = 0; i < 1000; i++) {
+            sum = add(sum, i);
+            try { fail(); } catch {}
+        }
+        "#,
+    );
+    let after = agent.gc_stats();
+    let allocated = after.vm_frames_allocated() - before.vm_frames_allocated();
+    let reused = after.vm_frames_reused() - before.vm_frames_reused();
+    // One frame for the script and one for each nesting level of calls.
+    assert!(allocated <= 2, "allocated {allocated} VM frames");
+    assert!(reused >= 1999, "reused {reused} VM frames");
+}
+
+#[test]
+fn number_to_string_conversions_are_cached() {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_gc_allocation_threshold(1024)
+        .build_with_default_realm();
+    let before = agent.gc_stats();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var rows = [];
+        for (let i = 0; i < 500; i++) {
+            rows.push(`${i % 10},${(i % 10) / 4},` + 1e21 + "," + -0);
+        }
+        var csv = rows.join("\n");
+        if (!csv.startsWith("0,0,1e+21,0\n1,0.25,1e+21,0\n2,0.5,1e+21,0")) {
+            throw new Error(csv.slice(0, 60));
+        }
+        "#,
+    );
+    let after = agent.gc_stats();
+    assert!(after.collections() > before.collections());
+    let hits = after.number_string_cache_hits() - before.number_string_cache_hits();
+    let misses = after.number_string_cache_misses() - before.number_string_cache_misses();
+    assert_eq!(hits + misses, 2000);
+    // Only the 20 distinct numbers and -0 miss.
+    assert!(misses <= 22, "{misses} cache misses");
+}
+
+#[test]
+fn number_to_string_cache_can_be_disabled() {
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_number_string_cache_size(0)
+        .build_with_default_realm();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var text = "";
+        for (let i = 0; i < 100; i++) text += i % 3;
+        if (text.length !== 100) throw new Error(text);
+        "#,
+    );
+    let stats = agent.gc_stats();
+    assert_eq!(stats.number_string_cache_hits(), 0);
+    assert_eq!(stats.number_string_cache_misses(), 0);
+}
+
+#[test]
+fn suspended_frames_are_not_shared() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function* values(a, b) { yield a; yield b; }
+        function identity(value) { return value; }
+        var first = values(1, 2);
+        var second = values(3, 4);
+        var results = [first.next().value, second.next().value];
+        identity(results);
+        results.push(first.next().value, second.next().value);
+        if (results.join() !== "1,3,2,4") throw new Error(results.join());
+        "#,
+    );
+}
+
+#[test]
+fn collections_are_counted() {
+    let (mut agent, _realm) = AgentBuilder::new().build_with_default_realm();
+    let before = agent.gc_stats().collections();
+    agent.gc();
+    agent.gc();
+    assert_eq!(agent.gc_stats().collections(), before + 2);
+}
+
+#[test]
+fn gc_hooks_surround_explicit_collections() {
+    let host_hooks: &'static GcHostHooks = Box::leak(Box::default());
+    let (mut agent, _realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .build_with_default_realm();
+    let before = agent.gc_stats().collections();
+    agent.gc();
+    assert_eq!(
+        *host_hooks.events.borrow(),
+        [
+            GcEvent::Before(GcReason::Explicit, before),
+            GcEvent::After(GcReason::Explicit, before + 1),
+        ]
+    );
+}
+
+#[test]
+fn gc_hooks_report_allocation_threshold_collections() {
+    let host_hooks: &'static GcHostHooks = Box::leak(Box::default());
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .with_gc_allocation_threshold(1024)
+        .build_with_default_realm();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var objects = [];
+        for (let i = 0; i < 1000; i++) {
+            objects.push({ i });
+        }
+        "#,
+    );
+    let events = host_hooks.events.borrow();
+    assert!(!events.is_empty());
+    for pair in events.chunks(2) {
+        let [GcEvent::Before(before_reason, count), after] = pair else {
+            panic!("unexpected GC events {pair:?}");
+        };
+        assert_eq!(*before_reason, GcReason::AllocationThreshold);
+        assert_eq!(
+            *after,
+            GcEvent::After(GcReason::AllocationThreshold, count + 1)
+        );
+    }
+}
+
+#[test]
+fn gc_hooks_are_not_called_when_gc_is_disabled() {
+    let host_hooks: &'static GcHostHooks = Box::leak(Box::default());
+    let (mut agent, _realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .with_gc_disabled(true)
+        .build_with_default_realm();
+    agent.gc();
+    assert!(host_hooks.events.borrow().is_empty());
+}
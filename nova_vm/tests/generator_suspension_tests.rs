@@ -0,0 +1,99 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, run};
+
+#[test]
+fn suspended_generators_survive_collection() {
+    let (_, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        function* counter(start) {
+            let local = { value: start };
+            try {
+                for (const step of [1, 2]) {
+                    yield local.value + step;
+                }
+            } finally {
+                yield "closed " + start;
+            }
+        }
+        var generators = [];
+        for (let i = 0; i < 10000; i++) {
+            const generator = counter(i * 10);
+            generator.next();
+            generators.push(generator);
+        }
+        "#,
+    );
+    // Suspended frames live on the heap, not on the native stack, and are
+    // traced and compacted by the garbage collector.
+    agent.gc();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        var results = [];
+        for (const generator of [generators[0], generators[9999]]) {
+            results.push(generator.next().value);
+            results.push(generator.return("done").value);
+            results.push(generator.next().value);
+        }
+        results.join()
+        "#,
+    );
+    assert_eq!(result, "2,closed 0,done,99992,closed 99990,done");
+}
+
+#[test]
+fn deeply_delegated_yields_resume_in_order() {
+    let (_, mut agent, realm) = create_agent();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        function* delegate(depth) {
+            if (depth === 0) {
+                yield "a";
+                yield "b";
+                return "c";
+            }
+            return yield* delegate(depth - 1);
+        }
+        var generator = delegate(50);
+        var values = [];
+        var step;
+        while (!(step = generator.next()).done) values.push(step.value);
+        values.push(step.value);
+        values.join()
+        "#,
+    );
+    assert_eq!(result, "a,b,c");
+}
+
+#[test]
+fn long_running_generator_yields_without_growing_the_stack() {
+    let (_, mut agent, realm) = create_agent();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        function* naturals() {
+            let i = 0;
+            while (true) yield i++;
+        }
+        var sum = 0;
+        for (const n of naturals()) {
+            if (n === 100000) break;
+            sum += n;
+        }
+        sum
+        "#,
+    );
+    assert_eq!(result, "4999950000");
+}
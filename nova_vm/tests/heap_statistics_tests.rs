@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::run;
+use nova_vm::ecmascript::{AgentBuilder, HeapStatistics, HostHooks, Job};
+
+#[derive(Debug, Default)]
+struct ThresholdHostHooks {
+    notifications: RefCell<Vec<HeapStatistics>>,
+}
+
+impl HostHooks for ThresholdHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn heap_threshold_exceeded(&self, statistics: &HeapStatistics) {
+        self.notifications.borrow_mut().push(statistics.clone());
+    }
+}
+
+#[test]
+fn heap_statistics_count_heap_vectors() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let before = agent.heap_statistics();
+    let allocated_before = agent.gc_stats().allocated_bytes();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var arrays = [];
+        for (let i = 0; i < 100; i++) {
+            arrays.push([i]);
+        }
+        "#,
+    );
+    let after = agent.heap_statistics();
+    let arrays_before = before.vector("arrays").unwrap();
+    let arrays_after = after.vector("arrays").unwrap();
+    assert!(arrays_after.entries >= arrays_before.entries + 101);
+    assert!(arrays_after.bytes > arrays_before.bytes);
+    assert!(after.heap_bytes() > before.heap_bytes());
+    assert!(agent.gc_stats().allocated_bytes() > allocated_before);
+    assert!(after.vector("no_such_vector").is_none());
+}
+
+#[test]
+fn heap_statistics_include_data_blocks() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let before = agent.heap_statistics();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var buffer = new ArrayBuffer(4096);
+        var detached = new ArrayBuffer(1024);
+        detached.transfer();
+        var shared = new SharedArrayBuffer(512);
+        "#,
+    );
+    let after = agent.heap_statistics();
+    // The transferred buffer's data block is now held by a new ArrayBuffer.
+    assert_eq!(
+        after.data_block_bytes() - before.data_block_bytes(),
+        4096 + 1024
+    );
+    assert_eq!(
+        after.shared_data_block_bytes() - before.shared_data_block_bytes(),
+        512
+    );
+    assert!(after.total_bytes() >= after.heap_bytes() + 4096 + 1024 + 512);
+}
+
+#[test]
+fn heap_statistics_survive_garbage_collection() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    run(
+        &mut agent,
+        &realm,
+        "var garbage = new Array(1000).fill({});",
+    );
+    let before = agent.heap_statistics();
+    let gc_stats_before = agent.gc_stats();
+    run(&mut agent, &realm, "garbage = undefined;");
+    agent.gc();
+    let after = agent.heap_statistics();
+    let gc_stats_after = agent.gc_stats();
+    assert_eq!(
+        gc_stats_after.collections(),
+        gc_stats_before.collections() + 1
+    );
+    // Bytes allocated before the collection are still counted.
+    assert!(gc_stats_after.allocated_bytes() >= gc_stats_before.allocated_bytes());
+    assert!(after.heap_bytes() < before.heap_bytes());
+}
+
+#[test]
+fn heap_threshold_exceeded_is_called_after_gc() {
+    let host_hooks: &'static ThresholdHostHooks = Box::leak(Box::default());
+    let (mut agent, _realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .with_heap_size_notification_threshold(1)
+        .build_with_default_realm();
+    agent.gc();
+    {
+        let notifications = host_hooks.notifications.borrow();
+        assert_eq!(notifications.len(), 1);
+        assert!(notifications[0].total_bytes() > 1);
+    }
+
+    let host_hooks: &'static ThresholdHostHooks = Box::leak(Box::default());
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .with_heap_size_notification_threshold(usize::MAX)
+        .build_with_default_realm();
+    run(&mut agent, &realm, "");
+    agent.gc();
+    assert!(host_hooks.notifications.borrow().is_empty());
+}
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::{cell::RefCell, rc::Rc};
+
+use common::create_agent_with;
+use nova_vm::{
+    ecmascript::{
+        AbstractModule, Agent, AgentBuilder, GraphLoadingStateRecord, HostDefined, HostHooks, Job,
+        ModuleRequest, Referrer, String, finish_loading_imported_module, parse_module,
+        parse_script,
+    },
+    engine::{Bindable, NoGcScope},
+};
+
+/// Path of a module, stored in the module's \[\[HostDefined]] field.
+#[derive(Debug, PartialEq)]
+struct ModulePath(&'static str);
+
+/// Data passed to LoadRequestedModules.
+#[derive(Debug, PartialEq)]
+struct LoadContext(u32);
+
+#[derive(Debug, Default)]
+struct RecordingHostHooks {
+    /// Referrer path, specifier, and load context of each loaded module.
+    loads: RefCell<Vec<(&'static str, std::string::String, Option<u32>)>>,
+}
+
+impl HostHooks for RecordingHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: ModuleRequest<'gc>,
+        host_defined: Option<HostDefined>,
+        payload: &mut GraphLoadingStateRecord<'gc>,
+        gc: NoGcScope<'gc, '_>,
+    ) {
+        let referrer_path = referrer.host_defined_as::<ModulePath>(agent).unwrap().0;
+        let specifier = module_request
+            .specifier(agent)
+            .to_string_lossy(agent)
+            .into_owned();
+        let context = host_defined
+            .and_then(|host_defined| host_defined.downcast::<LoadContext>().ok())
+            .map(|context| context.0);
+        let path: &'static str = match specifier.as_str() {
+            "./a.js" => "/a.js",
+            _ => "/b.js",
+        };
+        let source = match path {
+            "/a.js" => "import './b.js';",
+            _ => "",
+        };
+        self.loads
+            .borrow_mut()
+            .push((referrer_path, specifier, context));
+        let source_text = String::from_static_str(agent, source, gc);
+        let realm = referrer.realm(agent, gc);
+        let module: AbstractModule = parse_module(
+            agent,
+            source_text,
+            realm,
+            Some(Rc::new(ModulePath(path))),
+            gc,
+        )
+        .unwrap()
+        .into();
+        finish_loading_imported_module(agent, referrer, module_request, payload, Ok(module), gc);
+    }
+}
+
+#[test]
+fn module_host_defined_is_typed() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let realm = agent.current_realm(gc);
+        let source_text = String::from_static_str(agent, "", gc);
+        let module = parse_module(
+            agent,
+            source_text,
+            realm,
+            Some(Rc::new(ModulePath("/main.js"))),
+            gc,
+        )
+        .unwrap();
+        assert_eq!(
+            module.host_defined_as::<ModulePath>(agent).as_deref(),
+            Some(&ModulePath("/main.js"))
+        );
+        assert!(module.host_defined_as::<LoadContext>(agent).is_none());
+        let module = AbstractModule::from(module);
+        assert_eq!(
+            module.host_defined_as::<ModulePath>(agent).as_deref(),
+            Some(&ModulePath("/main.js"))
+        );
+
+        let source_text = String::from_static_str(agent, "", gc);
+        let module = parse_module(agent, source_text, realm, None, gc).unwrap();
+        assert!(module.host_defined(agent).is_none());
+        assert!(module.host_defined_as::<ModulePath>(agent).is_none());
+    });
+}
+
+#[test]
+fn script_host_defined_is_typed() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let realm = agent.current_realm(gc);
+        let source_text = String::from_static_str(agent, "", gc);
+        let script = parse_script(
+            agent,
+            source_text,
+            realm,
+            false,
+            Some(Rc::new(ModulePath("/script.js"))),
+            gc,
+        )
+        .unwrap();
+        assert_eq!(
+            script.host_defined_as::<ModulePath>(agent).as_deref(),
+            Some(&ModulePath("/script.js"))
+        );
+        assert!(script.host_defined_as::<LoadContext>(agent).is_none());
+    });
+}
+
+#[test]
+fn load_requested_modules_passes_host_defined_to_loads() {
+    let (host_hooks, mut agent, realm) =
+        create_agent_with(RecordingHostHooks::default(), AgentBuilder::new());
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(agent, "import './a.js';", gc.nogc());
+        let module = parse_module(
+            agent,
+            source_text,
+            realm,
+            Some(Rc::new(ModulePath("/main.js"))),
+            gc.nogc(),
+        )
+        .unwrap()
+        .unbind();
+        agent
+            .run_module(module, Some(Rc::new(LoadContext(7))), gc.reborrow())
+            .unwrap();
+    });
+    assert_eq!(
+        *host_hooks.loads.borrow(),
+        [
+            ("/main.js", "./a.js".to_owned(), Some(7)),
+            ("/a.js", "./b.js".to_owned(), Some(7)),
+        ]
+    );
+}
@@ -0,0 +1,220 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{JobQueue, create_agent_with, run, try_run_module};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, EvaluationOptions, GcAgent, GraphLoadingStateRecord, HostDefined,
+        HostHooks, ImportAttributesError, Job, ModuleRequest, ModuleType, RealmRoot, Referrer,
+        String, SyntheticModule, finish_loading_imported_module,
+    },
+    engine::NoGcScope,
+};
+
+/// Host hooks that support CSS modules, and load every non-JavaScript module
+/// as a synthetic module whose default export names its module type.
+#[derive(Debug, Default)]
+struct AttributesHostHooks {
+    promise_jobs: JobQueue,
+}
+
+impl HostHooks for AttributesHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_jobs.push(job);
+    }
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn dequeue_promise_job(&self) -> Option<Job> {
+        self.promise_jobs.pop()
+    }
+
+    fn get_supported_import_attributes(&self) -> &[&'static str] {
+        &["type", "media"]
+    }
+
+    fn validate_import_attributes(
+        &self,
+        agent: &Agent,
+        module_request: ModuleRequest,
+    ) -> Result<ModuleType, ImportAttributesError> {
+        let module_type = module_request
+            .attribute(agent, "type")
+            .map(|value| value.to_string_lossy(agent).into_owned());
+        let media = module_request
+            .attribute(agent, "media")
+            .map(|value| value.to_string_lossy(agent).into_owned());
+        match (module_type.as_deref(), media) {
+            (Some("css"), None) => Ok(ModuleType::HostDefined("css")),
+            (Some("css"), Some(media)) if media == "screen" || media == "print" => {
+                Ok(ModuleType::HostDefined("css"))
+            }
+            (Some("css"), Some(media)) => Err(ImportAttributesError::InvalidValue {
+                key: "media".into(),
+                value: media,
+            }),
+            (_, Some(_)) => Err(ImportAttributesError::Other(
+                "The media attribute is only supported for CSS modules".into(),
+            )),
+            (None, None) => Ok(ModuleType::JavaScript),
+            (Some("json"), None) => Ok(ModuleType::Json),
+            (Some(module_type), None) => {
+                Err(ImportAttributesError::UnsupportedType(module_type.into()))
+            }
+        }
+    }
+
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: ModuleRequest<'gc>,
+        _host_defined: Option<HostDefined>,
+        payload: &mut GraphLoadingStateRecord<'gc>,
+        gc: NoGcScope<'gc, '_>,
+    ) {
+        let kind = match module_request.module_type(agent) {
+            ModuleType::JavaScript => "javascript",
+            ModuleType::Json => "json",
+            ModuleType::HostDefined(name) => name,
+        };
+        let realm = agent.current_realm(gc);
+        let module = SyntheticModule::new(agent, realm, &["default"], None, gc);
+        let specifier = module_request.specifier(agent);
+        let value = format!("{kind}:{}", specifier.to_string_lossy(agent));
+        let value = String::from_string(agent, value, gc);
+        module.set_export(agent, "default", value.into(), gc);
+        finish_loading_imported_module(
+            agent,
+            referrer,
+            module_request,
+            payload,
+            Ok(module.into()),
+            gc,
+        );
+    }
+}
+
+fn create_agent() -> (GcAgent, RealmRoot) {
+    let (_, mut agent, realm) =
+        create_agent_with(AttributesHostHooks::default(), AgentBuilder::new());
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let source = String::from_static_str(agent, "export default 'registered';", gc);
+        agent
+            .load_module("lib:data", source, EvaluationOptions::default(), gc)
+            .unwrap();
+    });
+    (agent, realm)
+}
+
+#[test]
+fn host_chooses_module_type_from_attributes() {
+    let (mut agent, realm) = create_agent();
+    let error = try_run_module(
+        &mut agent,
+        &realm,
+        r#"
+        import js from "lib:data";
+        import json from "lib:data" with { type: "json" };
+        import css from "./styles.css" with { type: "css" };
+        import print from "./print.css" with { type: "css", media: "print" };
+        globalThis.result = [js, json, css, print].join();
+        "#,
+    );
+    assert_eq!(error, Ok(()));
+    // JSON module requests are not satisfied by registered JavaScript modules.
+    assert_eq!(
+        run(&mut agent, &realm, "result"),
+        "registered,json:lib:data,css:./styles.css,css:./print.css"
+    );
+}
+
+#[test]
+fn invalid_static_import_attributes_throw_type_errors() {
+    let (mut agent, realm) = create_agent();
+    assert_eq!(
+        try_run_module(
+            &mut agent,
+            &realm,
+            r#"import x from "./image.png" with { type: "image" };"#
+        )
+        .err()
+        .as_deref(),
+        Some("TypeError: Unsupported module type 'image'")
+    );
+    assert_eq!(
+        try_run_module(
+            &mut agent,
+            &realm,
+            r#"import x from "./styles.css" with { type: "css", media: "tv" };"#
+        )
+        .err()
+        .as_deref(),
+        Some("TypeError: Invalid value 'tv' for import attribute 'media'")
+    );
+    assert_eq!(
+        try_run_module(
+            &mut agent,
+            &realm,
+            r#"import x from "lib:data" with { media: "print" };"#
+        )
+        .err()
+        .as_deref(),
+        Some("TypeError: The media attribute is only supported for CSS modules")
+    );
+}
+
+#[test]
+fn invalid_dynamic_import_attributes_reject() {
+    let (mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        import("./styles.css", { with: { type: "css" } })
+            .then((ns) => log.push(ns.default));
+        import("./image.png", { with: { type: "image" } })
+            .catch((err) => log.push(`${err.name}: ${err.message}`));
+        "#,
+    );
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(
+        run(&mut agent, &realm, "log.sort().join()"),
+        "TypeError: Unsupported module type 'image',css:./styles.css"
+    );
+}
+
+#[test]
+fn default_hook_rejects_unknown_module_types() {
+    #[derive(Debug)]
+    struct DefaultHostHooks;
+
+    impl HostHooks for DefaultHostHooks {
+        fn enqueue_generic_job(&self, _job: Job) {}
+
+        fn enqueue_promise_job(&self, _job: Job) {}
+
+        fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+    }
+
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_host_hooks(&DefaultHostHooks)
+        .build_with_default_realm();
+    assert_eq!(
+        try_run_module(
+            &mut agent,
+            &realm,
+            r#"import x from "./styles.css" with { type: "css" };"#
+        )
+        .err()
+        .as_deref(),
+        Some("TypeError: Unsupported module type 'css'")
+    );
+}
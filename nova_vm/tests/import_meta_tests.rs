@@ -0,0 +1,161 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::{cell::Cell, rc::Rc};
+
+use common::{create_agent_with, run};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ArgumentsList, Behaviour, BuiltinFunctionArgs, EvaluationOptions,
+        GcAgent, HostHooks, Job, JsResult, OrdinaryObject, PropertyKey, RealmRoot,
+        SourceTextModule, String, Value, create_builtin_function,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+/// Host hooks that populate `import.meta` from the module's host defined URL.
+#[derive(Debug, Default)]
+struct ImportMetaHostHooks {
+    finalized: Cell<usize>,
+}
+
+impl HostHooks for ImportMetaHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn get_import_meta_properties<'gc>(
+        &self,
+        agent: &mut Agent,
+        module_record: SourceTextModule,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Vec<(PropertyKey<'gc>, Value<'gc>)> {
+        let url = module_record
+            .host_defined_as::<std::string::String>(agent)
+            .unwrap();
+        let url = String::from_str(agent, &url, gc);
+        let resolve = create_builtin_function(
+            agent,
+            Behaviour::Regular(resolve),
+            BuiltinFunctionArgs::new(1, "resolve"),
+            gc,
+        );
+        vec![
+            (PropertyKey::from_static_str(agent, "url", gc), url.into()),
+            (
+                PropertyKey::from_static_str(agent, "resolve", gc),
+                resolve.into(),
+            ),
+            (
+                PropertyKey::from_static_str(agent, "kind", gc),
+                String::from_static_str(agent, "first", gc).into(),
+            ),
+            (
+                PropertyKey::from_static_str(agent, "kind", gc),
+                String::from_static_str(agent, "second", gc).into(),
+            ),
+        ]
+    }
+
+    fn finalize_import_meta(
+        &self,
+        _agent: &mut Agent,
+        _import_meta: OrdinaryObject,
+        _module_record: SourceTextModule,
+        _gc: NoGcScope,
+    ) {
+        self.finalized.set(self.finalized.get() + 1);
+    }
+}
+
+/// Host function `import.meta.resolve(specifier)`.
+fn resolve<'gc>(
+    agent: &mut Agent,
+    _this: Value,
+    args: ArgumentsList,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let specifier = args.get(0).unbind().string_repr(agent, gc.reborrow());
+    let resolved = format!("file:///lib/{}", specifier.to_string_lossy(agent));
+    Ok(String::from_string(agent, resolved, gc.into_nogc()).into())
+}
+
+fn create_agent() -> (&'static ImportMetaHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(ImportMetaHostHooks::default(), AgentBuilder::new())
+}
+
+fn evaluate_module(agent: &mut GcAgent, realm: &RealmRoot, url: &str, source: &'static str) {
+    let url = url.to_owned();
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        let options = EvaluationOptions {
+            host_defined: Some(Rc::new(url)),
+            ..Default::default()
+        };
+        if let Err(err) = agent.evaluate_module(source_text.unbind(), options, gc.reborrow()) {
+            panic!(
+                "Module threw: {}",
+                err.unbind().to_string(agent, gc).to_string_lossy(agent)
+            );
+        }
+    });
+}
+
+#[test]
+fn import_meta_is_populated_by_host() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    evaluate_module(
+        &mut agent,
+        &realm,
+        "file:///main.js",
+        r#"
+        globalThis.url = import.meta.url;
+        globalThis.resolved = import.meta.resolve("dep.js");
+        globalThis.kind = import.meta.kind;
+        globalThis.keys = Object.keys(import.meta).join();
+        globalThis.nullProto = Object.getPrototypeOf(import.meta) === null;
+        globalThis.same = import.meta === import.meta && (() => import.meta)() === import.meta;
+        "#,
+    );
+    assert_eq!(run(&mut agent, &realm, "url"), "file:///main.js");
+    assert_eq!(run(&mut agent, &realm, "resolved"), "file:///lib/dep.js");
+    assert_eq!(run(&mut agent, &realm, "kind"), "second");
+    assert_eq!(run(&mut agent, &realm, "keys"), "url,resolve,kind");
+    assert_eq!(run(&mut agent, &realm, "nullProto"), "true");
+    assert_eq!(run(&mut agent, &realm, "same"), "true");
+    assert_eq!(host_hooks.finalized.get(), 1);
+}
+
+#[test]
+fn import_meta_is_created_per_module() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    evaluate_module(
+        &mut agent,
+        &realm,
+        "file:///a.js",
+        "globalThis.a = import.meta; import.meta.extra = 1;",
+    );
+    // Modules that never touch import.meta don't create it.
+    evaluate_module(&mut agent, &realm, "file:///unused.js", "globalThis.c = 1;");
+    evaluate_module(
+        &mut agent,
+        &realm,
+        "file:///b.js",
+        "globalThis.b = import.meta;",
+    );
+    agent.gc();
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "`${a.url} ${a.extra} ${b.url} ${a !== b}`"
+        ),
+        "file:///a.js 1 file:///b.js true"
+    );
+    assert_eq!(host_hooks.finalized.get(), 2);
+}
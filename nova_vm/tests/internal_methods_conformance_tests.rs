@@ -0,0 +1,47 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{fs, path::PathBuf};
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, Number, String, parse_module},
+    engine::Bindable,
+};
+
+#[test]
+fn internal_methods_conformance_tests() {
+    let d: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "sources",
+        "internalMethodsConformance.test.js",
+    ]
+    .iter()
+    .collect();
+    let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
+
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.register_module("host:exports", |agent, module, gc| {
+        module
+            .export_value(agent, "a", Number::from(1), gc)
+            .export_value(agent, "0", Number::from(2), gc)
+            .export_value(agent, "4294967295", Number::from(3), gc)
+            .export_value(agent, "length", Number::from(4), gc);
+    });
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_string(agent, contents, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+        if let Err(err) = agent.run_module(module.unbind(), None, gc.reborrow()) {
+            panic!(
+                "Test '{}' failed: {}",
+                d.display(),
+                err.value()
+                    .unbind()
+                    .string_repr(agent, gc)
+                    .to_string_lossy(agent)
+            )
+        }
+    });
+}
@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, JsFunction, String, parse_module},
+    engine::Bindable,
+    js_function,
+};
+
+js_function! {
+    struct Add = "add";
+    fn add(a: i32, b: i32) -> i32 {
+        a.wrapping_add(b)
+    }
+}
+
+js_function! {
+    struct Greet = "greet";
+    fn greet(name: &str, greeting: Option<&str>) -> std::string::String {
+        format!("{}, {name}!", greeting.unwrap_or("Hello"))
+    }
+}
+
+js_function! {
+    struct ParseInteger = "parseInteger";
+    fn parse_integer(text: &str) -> Result<f64, std::num::ParseIntError> {
+        text.parse::<i64>().map(|value| value as f64)
+    }
+}
+
+js_function! {
+    struct Sum = "sum";
+    fn sum(values: &[u8]) -> u32 {
+        values.iter().map(|&value| value as u32).sum()
+    }
+}
+
+const MODULE: &str = r#"
+import { add, greet, parseInteger, sum } from "host:functions";
+function assert(condition, message) {
+    if (!condition) {
+        throw new Error(message);
+    }
+}
+assert(add.length === 2 && add.name === "add", "add metadata");
+assert(add(2, "40") === 42, "add converts arguments");
+assert(add(1) === 1, "missing argument is undefined");
+assert(greet.length === 1, "optional argument does not count towards length");
+assert(greet("world") === "Hello, world!", "greet without greeting");
+assert(greet("world", "Hi") === "Hi, world!", "greet with greeting");
+assert(parseInteger("-12") === -12, "parseInteger parses");
+let error;
+try {
+    parseInteger("nope");
+} catch (err) {
+    error = err;
+}
+assert(error instanceof Error && error.message === "invalid digit found in string", "parseInteger throws");
+assert(sum(new Uint8Array([1, 2, 3])) === 6, "sum reads typed array");
+let typeError;
+try {
+    sum([1, 2, 3]);
+} catch (err) {
+    typeError = err;
+}
+assert(typeError instanceof TypeError, "sum throws on non-typed array");
+"#;
+
+#[test]
+fn js_function_metadata() {
+    assert_eq!(Add::NAME, "add");
+    assert_eq!(Add::LENGTH, 2);
+    assert_eq!(Greet::LENGTH, 1);
+    assert_eq!(Greet::greet("Rust", None), "Hello, Rust!");
+}
+
+#[test]
+fn call_js_functions() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.register_module("host:functions", |agent, module, gc| {
+        module
+            .export_js_function::<Add>(agent, gc)
+            .export_js_function::<Greet>(agent, gc)
+            .export_js_function::<ParseInteger>(agent, gc)
+            .export_js_function::<Sum>(agent, gc);
+    });
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(agent, MODULE, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+        if let Err(err) = agent.run_module(module.unbind(), None, gc.reborrow()) {
+            panic!(
+                "Module threw: {}",
+                err.value()
+                    .unbind()
+                    .string_repr(agent, gc)
+                    .to_string_lossy(agent)
+            );
+        }
+    });
+}
@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Views with byte offsets and lengths beyond `u32::MAX` store them in side
+//! tables of the heap; these tests make sure they are not truncated.
+
+#![cfg(target_pointer_width = "64")]
+
+mod common;
+
+#[test]
+fn large_array_buffer_tests() {
+    common::run_test_file("largeArrayBuffer.test.js");
+}
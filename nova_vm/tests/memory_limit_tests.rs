@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::Cell;
+
+use common::{create_agent_with, try_run};
+use nova_vm::ecmascript::{AgentBuilder, GcAgent, HeapStatistics, HostHooks, Job, RealmRoot};
+
+const MEMORY_LIMIT: usize = 8 * 1024 * 1024;
+
+#[derive(Debug, Default)]
+struct MemoryLimitHostHooks {
+    exceeded: Cell<Option<usize>>,
+}
+
+impl HostHooks for MemoryLimitHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn memory_limit_exceeded(&self, statistics: &HeapStatistics) {
+        self.exceeded.set(Some(statistics.total_bytes()));
+    }
+}
+
+fn limited_agent() -> (&'static MemoryLimitHostHooks, GcAgent, RealmRoot) {
+    create_agent_with(
+        MemoryLimitHostHooks::default(),
+        AgentBuilder::new().with_memory_limit(MEMORY_LIMIT),
+    )
+}
+
+#[test]
+fn array_buffer_beyond_memory_limit_throws_range_error() {
+    let (host_hooks, mut agent, realm) = limited_agent();
+    let result = try_run(
+        &mut agent,
+        &realm,
+        r#"
+        let message;
+        try {
+            new ArrayBuffer(16 * 1024 * 1024);
+        } catch (err) {
+            message = `${err.name}: ${err.message}`;
+        }
+        const buffer = new ArrayBuffer(1024, { maxByteLength: 64 * 1024 * 1024 });
+        try {
+            buffer.resize(64 * 1024 * 1024);
+        } catch (err) {
+            message += `, ${err.name}, byteLength ${buffer.byteLength}`;
+        }
+        new ArrayBuffer(1024 * 1024).byteLength;
+        message;
+        "#,
+    );
+    assert_eq!(
+        result.as_deref(),
+        Ok("RangeError: Memory limit exceeded, RangeError, byteLength 1024")
+    );
+    assert!(host_hooks.exceeded.get().is_none());
+    assert!(!agent.termination_handle().is_terminating());
+}
+
+#[test]
+fn heap_growth_beyond_memory_limit_terminates() {
+    let (host_hooks, mut agent, realm) = limited_agent();
+    let result = try_run(
+        &mut agent,
+        &realm,
+        r#"
+        function fill() {
+            const retained = [];
+            try {
+                while (true) {
+                    retained.push({ index: retained.length });
+                }
+            } catch {
+                // Termination cannot be caught.
+            }
+        }
+        fill();
+        "#,
+    );
+    assert_eq!(result, Err("RangeError: Memory limit exceeded".to_owned()));
+    let exceeded = host_hooks.exceeded.get().unwrap();
+    assert!(exceeded > MEMORY_LIMIT, "{exceeded}");
+    let handle = agent.termination_handle();
+    assert!(handle.is_terminating());
+
+    // The objects were only retained by the terminated function; once the
+    // embedder cancels termination the Agent is usable again.
+    handle.cancel();
+    agent.gc();
+    assert!(agent.heap_statistics().total_bytes() < MEMORY_LIMIT);
+    assert_eq!(
+        try_run(&mut agent, &realm, "[1, 2, 3].map(x => x * 2).join()").as_deref(),
+        Ok("2,4,6")
+    );
+}
+
+#[test]
+fn garbage_does_not_count_towards_memory_limit() {
+    let (host_hooks, mut agent, realm) = limited_agent();
+    let result = try_run(
+        &mut agent,
+        &realm,
+        r#"
+        let last;
+        for (let i = 0; i < 100; i++) {
+            last = new ArrayBuffer(1024 * 1024);
+            last = new Array(1000).fill(i);
+        }
+        last[0];
+        "#,
+    );
+    assert_eq!(result.as_deref(), Ok("99"));
+    assert!(host_hooks.exceeded.get().is_none());
+}
@@ -0,0 +1,75 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, run};
+
+#[test]
+fn microtask_checkpoint_drains_jobs_and_reports_rejections() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        Promise.reject("unhandled");
+        Promise.reject("handled").catch(() => log.push("caught"));
+        var late = Promise.reject("handled later");
+        Promise.resolve()
+            .then(() => {
+                log.push("first");
+                late.catch(() => log.push("caught later"));
+            })
+            .then(() => {
+                log.push("second");
+                throw "derived";
+            });
+        "#,
+    );
+    assert!(host_hooks.unhandled_rejections.borrow().is_empty());
+
+    agent.perform_microtask_checkpoint(&realm);
+
+    assert!(host_hooks.promise_jobs.is_empty());
+    assert_eq!(
+        run(&mut agent, &realm, "log.join()"),
+        "caught,first,caught later,second"
+    );
+    assert_eq!(
+        *host_hooks.unhandled_rejections.borrow(),
+        ["unhandled", "derived"]
+    );
+    assert!(host_hooks.job_errors.borrow().is_empty());
+
+    // Reported rejections are not reported again.
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(host_hooks.unhandled_rejections.borrow().len(), 2);
+}
+
+#[test]
+fn microtask_checkpoint_runs_finalization_registry_cleanup() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        var registry = new FinalizationRegistry((held) => {
+            log.push(held);
+            Promise.resolve().then(() => log.push("after " + held));
+        });
+        registry.register({}, "a");
+        Promise.resolve().then(() => log.push("microtask"));
+        "#,
+    );
+    agent.gc();
+    assert_eq!(host_hooks.cleanup_jobs.len(), 1);
+
+    agent.perform_microtask_checkpoint(&realm);
+
+    assert!(host_hooks.cleanup_jobs.is_empty());
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "microtask,a,after a");
+    assert!(host_hooks.unhandled_rejections.borrow().is_empty());
+}
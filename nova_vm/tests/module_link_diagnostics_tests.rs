@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent_with};
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, LinkDiagnostic, LinkDiagnosticKind, String, parse_module},
+    engine::Bindable,
+};
+
+/// Run the main module of the given sources, returning the thrown error
+/// message and the link diagnostic of the main module.
+fn link_error(
+    sources: &'static [(&'static str, &'static str)],
+) -> (std::string::String, LinkDiagnostic) {
+    let (_, mut agent, realm) =
+        create_agent_with(TestHostHooks::with_sources(sources), AgentBuilder::new());
+    let (main, _) = sources[0];
+    assert_eq!(main, "main");
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(agent, sources[0].1, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc())
+            .unwrap()
+            .unbind();
+        let err = agent
+            .run_module(module, None, gc.reborrow())
+            .expect_err("Module linking should fail")
+            .unbind();
+        let message = err.to_string(agent, gc).to_string_lossy(agent).into_owned();
+        let diagnostic = module
+            .link_diagnostic(agent)
+            .expect("Main module should have a link diagnostic")
+            .clone();
+        (message, diagnostic)
+    })
+}
+
+fn chain(diagnostic: &LinkDiagnostic) -> Vec<(Option<&str>, &str)> {
+    diagnostic
+        .chain()
+        .iter()
+        .map(|step| (step.specifier(), step.export_name()))
+        .collect()
+}
+
+#[test]
+fn missing_import_is_unresolved() {
+    let (message, diagnostic) = link_error(&[
+        ("main", "import { missing } from './a.js';"),
+        ("./a.js", "export const present = 1;"),
+    ]);
+    assert_eq!(diagnostic.kind(), &LinkDiagnosticKind::Unresolved);
+    assert_eq!(chain(&diagnostic), [(Some("./a.js"), "missing")]);
+    assert_eq!(
+        message,
+        "SyntaxError: Unresolved export: 'missing' from './a.js'"
+    );
+}
+
+#[test]
+fn unresolved_export_lists_reexport_chain() {
+    let (message, diagnostic) = link_error(&[
+        ("main", "import { x } from './a.js';"),
+        ("./a.js", "export * from './b.js';"),
+        ("./b.js", "export { y as x } from './c.js';"),
+        ("./c.js", "export const z = 1;"),
+    ]);
+    assert_eq!(diagnostic.kind(), &LinkDiagnosticKind::Unresolved);
+    // Note: './b.js' is linked before the main module and fails on its own
+    // re-export, which is not referred to through a specifier.
+    assert_eq!(chain(&diagnostic), [(None, "x"), (Some("./c.js"), "y")]);
+    assert_eq!(
+        message,
+        "SyntaxError: Unresolved export: 'x' -> 'y' from './c.js'"
+    );
+}
+
+#[test]
+fn conflicting_star_exports_are_ambiguous() {
+    let (message, diagnostic) = link_error(&[
+        ("main", "import { x } from './a.js';"),
+        ("./a.js", "export * from './b.js';"),
+        ("./b.js", "export * from './c.js'; export * from './d.js';"),
+        ("./c.js", "export const x = 1;"),
+        ("./d.js", "export const x = 2;"),
+    ]);
+    assert_eq!(
+        diagnostic.kind(),
+        &LinkDiagnosticKind::Ambiguous {
+            candidates: Box::new(["./c.js".into(), "./d.js".into()]),
+        }
+    );
+    assert_eq!(
+        chain(&diagnostic),
+        [(Some("./a.js"), "x"), (Some("./b.js"), "x")]
+    );
+    assert_eq!(
+        message,
+        "SyntaxError: Ambiguous export: 'x' from './a.js' -> 'x' from './b.js' (conflicting star exports from './c.js' and './d.js')"
+    );
+}
+
+#[test]
+fn circular_reexports_list_cycle() {
+    let (message, diagnostic) = link_error(&[
+        ("main", "import { x } from './a.js';"),
+        ("./a.js", "export { x } from './b.js';"),
+        ("./b.js", "export { x } from './a.js';"),
+    ]);
+    assert_eq!(diagnostic.kind(), &LinkDiagnosticKind::Circular);
+    assert_eq!(
+        chain(&diagnostic),
+        [(None, "x"), (Some("./a.js"), "x"), (Some("./b.js"), "x")]
+    );
+    assert_eq!(
+        message,
+        "SyntaxError: Circular export: 'x' -> 'x' from './a.js' -> 'x' from './b.js'"
+    );
+}
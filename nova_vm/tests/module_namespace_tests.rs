@@ -0,0 +1,263 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent_with, run_module};
+
+use nova_vm::ecmascript::{AgentBuilder, GcAgent, Number, RealmRoot};
+
+fn create_agent() -> (GcAgent, RealmRoot) {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.register_module("host:exports", |agent, module, gc| {
+        module
+            .export_value(agent, "b", Number::from(1), gc)
+            .export_value(agent, "a", Number::from(2), gc)
+            .export_value(agent, "10", Number::from(3), gc)
+            .export_value(agent, "0", Number::from(4), gc)
+            .export_value(agent, "\u{ffff}", Number::from(5), gc)
+            .export_value(agent, "\u{10000}", Number::from(6), gc)
+            .export_value(agent, "a long export name", Number::from(7), gc);
+    });
+    (agent, realm)
+}
+
+#[test]
+fn namespace_keys_are_sorted_by_code_units() {
+    let (mut agent, realm) = create_agent();
+    run_module(
+        &mut agent,
+        &realm,
+        r#"
+        import * as ns from "host:exports";
+        const keys = Reflect.ownKeys(ns);
+        const expected = [
+            "0", "10", "a", "a long export name", "b", "\u{10000}", "\u{ffff}",
+            Symbol.toStringTag,
+        ];
+        if (keys.length !== expected.length) {
+            throw new Error("Unexpected key count " + keys.length);
+        }
+        for (let i = 0; i < keys.length; i++) {
+            if (keys[i] !== expected[i]) {
+                throw new Error("Unexpected key at " + i);
+            }
+        }
+        "#,
+    );
+}
+
+#[test]
+fn namespace_lookups_find_every_export() {
+    let (mut agent, realm) = create_agent();
+    run_module(
+        &mut agent,
+        &realm,
+        r#"
+        import * as ns from "host:exports";
+        globalThis.ns = ns;
+        "#,
+    );
+    // Cached export bindings are traced by the garbage collector.
+    agent.gc();
+    run_module(
+        &mut agent,
+        &realm,
+        r#"
+        const ns = globalThis.ns;
+        const values = [
+            ns.b, ns.a, ns[10], ns[0], ns["\u{ffff}"], ns["\u{10000}"],
+            ns["a long export name"],
+        ];
+        if (values.join() !== "1,2,3,4,5,6,7") {
+            throw new Error("Unexpected values " + values.join());
+        }
+        for (const key of ["0", 0, 10, "a", "\u{10000}", "a long export name"]) {
+            if (!(key in ns)) {
+                throw new Error("Expected " + String(key) + " in namespace");
+            }
+            const desc = Reflect.getOwnPropertyDescriptor(ns, key);
+            if (!desc.writable || !desc.enumerable || desc.configurable) {
+                throw new Error("Unexpected descriptor for " + String(key));
+            }
+            if (Reflect.deleteProperty(ns, key)) {
+                throw new Error("Expected " + String(key) + " to be undeletable");
+            }
+        }
+        for (const key of ["c", "1", 1, "a long export nam", ""]) {
+            if (key in ns || ns[key] !== undefined) {
+                throw new Error("Unexpected " + String(key) + " in namespace");
+            }
+            if (Reflect.getOwnPropertyDescriptor(ns, key) !== undefined) {
+                throw new Error("Unexpected descriptor for " + String(key));
+            }
+            if (!Reflect.deleteProperty(ns, key)) {
+                throw new Error("Expected " + String(key) + " to be deletable");
+            }
+        }
+        "#,
+    );
+}
+
+/// Run the first of the given source text modules as the main module.
+fn run_source_modules(sources: &'static [(&'static str, &'static str)]) {
+    let (_, mut agent, realm) =
+        create_agent_with(TestHostHooks::with_sources(sources), AgentBuilder::new());
+    run_module(&mut agent, &realm, sources[0].1);
+}
+
+#[test]
+fn namespace_reads_live_bindings() {
+    run_source_modules(&[
+        (
+            "main",
+            r#"
+            import * as ns from "./counter.js";
+            if (ns.count !== 0) throw new Error("Unexpected initial count " + ns.count);
+            ns.increment();
+            ns.increment();
+            if (ns.count !== 2) throw new Error("Binding is not live: " + ns.count);
+            const desc = Reflect.getOwnPropertyDescriptor(ns, "count");
+            if (desc.value !== 2) throw new Error("Unexpected descriptor value " + desc.value);
+            if (ns.renamed !== ns.increment) throw new Error("Unexpected renamed export");
+            "#,
+        ),
+        (
+            "./counter.js",
+            r#"
+            export let count = 0;
+            export function increment() { count++; }
+            export { increment as renamed };
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn namespace_resolves_re_exports() {
+    run_source_modules(&[
+        (
+            "main",
+            r#"
+            import * as ns from "./re-export.js";
+            import * as direct from "./counter.js";
+            if (ns.inner !== direct) throw new Error("Namespace re-export is not the namespace");
+            if (ns.count !== 0 || ns.inner.count !== 0) throw new Error("Unexpected count");
+            direct.increment();
+            if (ns.count !== 1 || ns.inner.count !== 1) throw new Error("Re-export is not live");
+            if (ns.aliased !== 1) throw new Error("Indirect export is not live");
+            "#,
+        ),
+        (
+            "./re-export.js",
+            r#"
+            export * from "./counter.js";
+            export * as inner from "./counter.js";
+            export { count as aliased } from "./counter.js";
+            "#,
+        ),
+        (
+            "./counter.js",
+            r#"
+            export let count = 0;
+            export function increment() { count++; }
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn namespace_lists_diamond_star_exports_once() {
+    run_source_modules(&[
+        (
+            "main",
+            r#"
+            import * as ns from "./top.js";
+            const keys = Reflect.ownKeys(ns).map(String).join();
+            if (keys !== "count,local,Symbol(Symbol.toStringTag)") {
+                throw new Error("Unexpected keys " + keys);
+            }
+            "#,
+        ),
+        (
+            "./top.js",
+            r#"
+            export * from "./left.js";
+            export * from "./right.js";
+            export const local = 1;
+            "#,
+        ),
+        ("./left.js", r#"export * from "./counter.js";"#),
+        ("./right.js", r#"export * from "./counter.js";"#),
+        ("./counter.js", "export let count = 0;"),
+    ]);
+}
+
+#[test]
+fn namespace_throws_on_uninitialized_bindings() {
+    run_source_modules(&[
+        (
+            "main",
+            r#"
+            import "./cycle.js";
+            "#,
+        ),
+        (
+            "./cycle.js",
+            r#"
+            import * as self from "./cycle.js";
+            let threw = false;
+            try {
+                self.late;
+            } catch (err) {
+                threw = err instanceof ReferenceError;
+            }
+            if (!threw) throw new Error("Expected a ReferenceError");
+            if (!("late" in self)) throw new Error("Expected the export to exist");
+            export let late = 1;
+            if (self.late !== 1) throw new Error("Unexpected initialized value");
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn namespace_finds_integer_like_export_names() {
+    run_source_modules(&[
+        (
+            "main",
+            r#"
+            import * as ns from "./numeric.js";
+            if (ns[0] !== "zero" || ns["0"] !== "zero") throw new Error("Unexpected ns[0]");
+            if (ns[-1] !== "minus one") throw new Error("Unexpected ns[-1]");
+            if (ns[4294967295] !== "max") throw new Error("Unexpected ns[4294967295]");
+            if (ns[1] !== undefined || 1 in ns) throw new Error("Unexpected ns[1]");
+            for (const key of [0, -1, 4294967295]) {
+                if (!(key in ns)) throw new Error("Expected " + key + " in namespace");
+                const desc = Reflect.getOwnPropertyDescriptor(ns, key);
+                if (!desc.writable || !desc.enumerable || desc.configurable) {
+                    throw new Error("Unexpected descriptor for " + key);
+                }
+                if (Reflect.deleteProperty(ns, key)) {
+                    throw new Error("Expected " + key + " to be undeletable");
+                }
+                if (Reflect.set(ns, key, 1)) {
+                    throw new Error("Expected " + key + " to be read-only");
+                }
+            }
+            if (!Reflect.deleteProperty(ns, 1)) throw new Error("Expected 1 to be deletable");
+            if (Object.keys(ns).join() !== "-1,0,4294967295") {
+                throw new Error("Unexpected keys " + Object.keys(ns).join());
+            }
+            "#,
+        ),
+        (
+            "./numeric.js",
+            r#"
+            const zero = "zero", minusOne = "minus one", max = "max";
+            export { zero as "0", minusOne as "-1", max as "4294967295" };
+            "#,
+        ),
+    ]);
+}
@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ArgumentsList, Behaviour, JsResult, Number, String, Value,
+        parse_module,
+    },
+    engine::{Bindable, GcScope},
+};
+
+fn identity<'gc>(
+    _agent: &mut Agent,
+    _this: Value,
+    arguments: ArgumentsList,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    Ok(arguments.get(0).bind(gc.into_nogc()))
+}
+
+const MODULES: [&str; 2] = [
+    r#"
+    import { identity, answer } from "host:test";
+    import * as ns from "host:test";
+    if (identity(answer) !== 42) {
+        throw new Error("Expected identity(answer) to be 42");
+    }
+    if (ns.answer !== 42 || ns.identity !== identity) {
+        throw new Error("Expected namespace to contain native exports");
+    }
+    globalThis.firstNamespace = ns;
+    "#,
+    r#"
+    import * as ns from "host:test";
+    if (ns !== globalThis.firstNamespace) {
+        throw new Error("Expected native module to be instantiated once per realm");
+    }
+    "#,
+];
+
+#[test]
+fn import_native_module() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.register_module("host:test", |agent, module, gc| {
+        module
+            .export_function(agent, "identity", 1, Behaviour::Regular(identity), gc)
+            .export_value(agent, "answer", Number::from(42), gc);
+    });
+    for source in MODULES {
+        agent.run_in_realm(&realm, |agent, mut gc| {
+            let realm = agent.current_realm(gc.nogc());
+            let source_text = String::from_static_str(agent, source, gc.nogc());
+            let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+            if let Err(err) = agent.run_module(module.unbind(), None, gc.reborrow()) {
+                panic!(
+                    "Module threw: {}",
+                    err.value()
+                        .unbind()
+                        .string_repr(agent, gc)
+                        .to_string_lossy(agent)
+                );
+            }
+        });
+        agent.gc();
+    }
+}
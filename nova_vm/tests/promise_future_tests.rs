@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "futures")]
+
+mod common;
+
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    task::Wake,
+};
+
+use common::{TestHostHooks, create_agent, define_global, run_with_jobs};
+use nova_vm::{
+    ecmascript::{Agent, GcAgent, Promise, PromiseFuture, RealmRoot, String, Value},
+    engine::{Bindable, NoGcScope},
+};
+
+/// Waker counting the number of times it was woken.
+#[derive(Default)]
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl CountingWaker {
+    fn wakes(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+fn poll<F: Future + Unpin>(future: &mut F, waker: &Arc<CountingWaker>) -> Poll<F::Output> {
+    let waker = Waker::from(waker.clone());
+    Pin::new(future).poll(&mut Context::from_waker(&waker))
+}
+
+/// A future completing with the value sent into it.
+#[derive(Default)]
+struct Oneshot<T>(Rc<RefCell<(Option<T>, Option<Waker>)>>);
+
+impl<T> Oneshot<T> {
+    fn sender(&self) -> impl FnOnce(T) + use<T> {
+        let state = self.0.clone();
+        move |value| {
+            let mut state = state.borrow_mut();
+            state.0 = Some(value);
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T> Future for Oneshot<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.0.borrow_mut();
+        match state.0.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn promise_into_future(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &'static str,
+) -> PromiseFuture {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        let value = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        let Value::Promise(promise) = value else {
+            panic!("Expected a Promise");
+        };
+        promise.unbind().into_future(agent, gc.nogc())
+    })
+}
+
+fn define_global_promise(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    name: &'static str,
+    promise: impl FnOnce(&mut Agent, NoGcScope) -> Promise<'static>,
+) {
+    agent.run_in_realm(realm, |agent, gc| {
+        let promise = promise(agent, gc.nogc());
+        define_global(agent, name, promise.into(), gc);
+    });
+}
+
+fn run_generic_jobs(agent: &mut GcAgent, realm: &RealmRoot, host_hooks: &TestHostHooks) {
+    loop {
+        let Some(job) = host_hooks.generic_jobs.pop() else {
+            break;
+        };
+        agent.run_in_realm(realm, |agent, gc| job.run(agent, gc).unwrap());
+    }
+    agent.perform_microtask_checkpoint(realm);
+}
+
+#[test]
+fn promise_future_completes_when_promise_is_fulfilled() {
+    let (_, mut agent, realm) = create_agent();
+    let mut future = promise_into_future(
+        &mut agent,
+        &realm,
+        "var resolve; new Promise((r) => { resolve = r; })",
+    );
+    let waker = Arc::new(CountingWaker::default());
+    assert!(poll(&mut future, &waker).is_pending());
+    // Resolving the Promise only enqueues the reaction job.
+    agent.run_in_realm(&realm, |agent, gc| {
+        let source_text = String::from_static_str(agent, "resolve(42)", gc.nogc());
+        agent.run_script(source_text.unbind(), gc).unwrap();
+    });
+    assert_eq!(waker.wakes(), 0);
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(waker.wakes(), 1);
+    let Poll::Ready(Ok(value)) = poll(&mut future, &waker) else {
+        panic!("Expected the future to be fulfilled");
+    };
+    agent.run_in_realm(&realm, |agent, _| {
+        assert_eq!(value.take(agent), Value::from(42));
+    });
+}
+
+#[test]
+fn promise_future_completes_with_rejection_reason() {
+    let (_, mut agent, realm) = create_agent();
+    let mut future = promise_into_future(&mut agent, &realm, "Promise.reject('boom')");
+    let waker = Arc::new(CountingWaker::default());
+    assert!(poll(&mut future, &waker).is_pending());
+    agent.perform_microtask_checkpoint(&realm);
+    let Poll::Ready(Err(reason)) = poll(&mut future, &waker) else {
+        panic!("Expected the future to be rejected");
+    };
+    agent.run_in_realm(&realm, |agent, gc| {
+        let reason = reason.take(agent);
+        assert_eq!(
+            reason
+                .to_string(agent, gc)
+                .unwrap()
+                .to_string_lossy(agent)
+                .into_owned(),
+            "boom"
+        );
+    });
+}
+
+#[test]
+fn promise_from_future_settles_through_generic_job() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    let oneshot = Oneshot::<&'static str>::default();
+    let send = oneshot.sender();
+    let mut task = None;
+    define_global_promise(&mut agent, &realm, "p", |agent, gc| {
+        let (promise, promise_task) = Promise::from_future(agent, oneshot, gc);
+        task = Some(promise_task);
+        promise.unbind()
+    });
+    let mut task = task.unwrap();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        "var result; p.then((v) => { result = v; }); undefined",
+    );
+    let waker = Arc::new(CountingWaker::default());
+    assert!(poll(&mut task, &waker).is_pending());
+    send("hello");
+    assert_eq!(waker.wakes(), 1);
+    assert!(poll(&mut task, &waker).is_ready());
+    // The Promise is settled by the generic job, not by the task.
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "String(result)"),
+        "undefined"
+    );
+    run_generic_jobs(&mut agent, &realm, host_hooks);
+    assert_eq!(run_with_jobs(&mut agent, &realm, "result"), "hello");
+}
+
+#[test]
+fn promise_from_future_rejects_on_err_output() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    let future = async { Err::<i32, _>("request failed") };
+    let mut task = None;
+    define_global_promise(&mut agent, &realm, "p", |agent, gc| {
+        let (promise, promise_task) = Promise::from_future(agent, future, gc);
+        task = Some(promise_task);
+        promise.unbind()
+    });
+    let mut task = task.unwrap();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        "var message; p.catch((e) => { message = e.message; }); undefined",
+    );
+    let waker = Arc::new(CountingWaker::default());
+    assert!(poll(&mut task, &waker).is_ready());
+    run_generic_jobs(&mut agent, &realm, host_hooks);
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "message"),
+        "request failed"
+    );
+}
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent_with, run_with_jobs};
+use nova_vm::ecmascript::{AgentBuilder, GcAgent, RealmRoot};
+
+fn create_agent() -> (GcAgent, RealmRoot) {
+    let (_, agent, realm) = create_agent_with(
+        TestHostHooks::default(),
+        AgentBuilder::new().with_promise_retention_checks(true),
+    );
+    (agent, realm)
+}
+
+#[test]
+fn retained_resolving_functions_are_inert_after_collection() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var retained = [];
+        var results = [];
+        new Promise((resolve, reject) => {
+            retained.push(resolve, reject);
+            resolve({ value: "fulfilled" });
+        }).then((result) => results.push(result.value));
+        new Promise((resolve, reject) => {
+            retained.push(resolve, reject);
+            reject({ value: "rejected" });
+        }).catch((reason) => results.push(reason.value));
+        "#,
+    );
+    agent.gc();
+    let result = run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        for (const f of retained) f({ value: "late" });
+        results.join()
+        "#,
+    );
+    assert_eq!(result, "fulfilled,rejected");
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "results.join()"),
+        "fulfilled,rejected"
+    );
+}
+
+#[test]
+fn promise_combinators_release_values_after_settlement() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var pending = new Promise(() => {});
+        var results = [];
+        Promise.all([Promise.resolve({}), Promise.reject("all"), pending])
+            .catch((reason) => results.push(reason));
+        Promise.any([Promise.reject({}), Promise.resolve("any"), pending])
+            .then((result) => results.push(result));
+        Promise.allSettled([Promise.resolve("allSettled")])
+            .then(([result]) => results.push(result.value));
+        "#,
+    );
+    // Settled combinators must not keep their result arrays alive; the
+    // retention check terminates the Agent if they do.
+    agent.gc();
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "results.join()"),
+        "all,any,allSettled"
+    );
+}
+
+#[test]
+fn thenable_resolving_functions_are_called_once() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var result;
+        Promise.resolve({
+            then(resolve) {
+                resolve({ then(resolve) { resolve("first"); } });
+                resolve("second");
+            },
+        }).then((value) => { result = value; });
+        "#,
+    );
+    agent.gc();
+    assert_eq!(run_with_jobs(&mut agent, &realm, "result"), "first");
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ArgumentsList, Behaviour, BuiltinFunctionArgs, InternalMethods,
+        InvalidPropertyDescriptor, JsResult, PropertyDescriptor, PropertyKey, String, Value,
+        create_builtin_function,
+    },
+    engine::{Bindable, GcScope},
+};
+
+fn getter<'gc>(
+    _agent: &mut Agent,
+    _this: Value,
+    _args: ArgumentsList,
+    _gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    Ok(Value::from(7))
+}
+
+#[test]
+fn data_builder_sets_requested_attributes() {
+    let desc = PropertyDescriptor::data(Value::from(1))
+        .writable()
+        .enumerable()
+        .build();
+    assert_eq!(desc.value, Some(Value::from(1)));
+    assert_eq!(desc.writable, Some(true));
+    assert_eq!(desc.enumerable, Some(true));
+    assert_eq!(desc.configurable, Some(false));
+    assert!(desc.is_fully_populated());
+    assert!(desc.validate().is_ok());
+
+    let desc: PropertyDescriptor = PropertyDescriptor::data(Value::Null)
+        .with_configurable(true)
+        .into();
+    assert_eq!(desc.writable, Some(false));
+    assert_eq!(desc.enumerable, Some(false));
+    assert_eq!(desc.configurable, Some(true));
+}
+
+#[test]
+fn mixed_descriptor_fails_validation() {
+    let desc = PropertyDescriptor {
+        value: Some(Value::from(1)),
+        get: Some(None),
+        ..Default::default()
+    };
+    assert_eq!(desc.validate(), Err(InvalidPropertyDescriptor));
+    assert!(PropertyDescriptor::default().validate().is_ok());
+    assert!(PropertyDescriptor::accessor().build().validate().is_ok());
+}
+
+#[test]
+fn built_descriptors_define_properties() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let result = agent.run_in_realm(&realm, |agent, mut gc| {
+        let global = agent.current_realm(gc.nogc()).global_object(agent).unbind();
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(getter),
+            BuiltinFunctionArgs::new(0, "get seven"),
+            gc.nogc(),
+        );
+        let key = PropertyKey::from_static_str(agent, "seven", gc.nogc());
+        let desc = PropertyDescriptor::accessor()
+            .getter(function)
+            .configurable()
+            .build();
+        assert!(desc.validate().is_ok());
+        global
+            .internal_define_own_property(agent, key.unbind(), desc.unbind(), gc.reborrow())
+            .unwrap();
+        let key = PropertyKey::from_static_str(agent, "frozen", gc.nogc());
+        let desc = PropertyDescriptor::data(Value::from(3))
+            .enumerable()
+            .build();
+        global
+            .internal_define_own_property(agent, key.unbind(), desc, gc.reborrow())
+            .unwrap();
+
+        let source = String::from_static_str(
+            agent,
+            r#"
+            "use strict";
+            var sevenDesc = Object.getOwnPropertyDescriptor(globalThis, "seven");
+            var frozenDesc = Object.getOwnPropertyDescriptor(globalThis, "frozen");
+            let threw = false;
+            try { globalThis.frozen = 4; } catch { threw = true; }
+            [
+                sevenDesc.get.call() === 7, sevenDesc.set === undefined,
+                sevenDesc.configurable, !sevenDesc.enumerable,
+                frozenDesc.value === 3, !frozenDesc.writable, frozenDesc.enumerable,
+                !frozenDesc.configurable, threw,
+            ].every(Boolean)
+            "#,
+            gc.nogc(),
+        );
+        match agent.run_script(source.unbind(), gc.reborrow()) {
+            Ok(value) => value.unbind(),
+            Err(err) => panic!(
+                "Script threw: {}",
+                err.unbind().to_string(agent, gc).to_string_lossy(agent)
+            ),
+        }
+    });
+    assert_eq!(result, Value::Boolean(true));
+}
+
+#[test]
+fn descriptors_round_trip_through_objects() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let desc = PropertyDescriptor::data(Value::from(5))
+            .writable()
+            .configurable()
+            .build();
+        let object =
+            PropertyDescriptor::from_property_descriptor(Some(desc.clone()), agent, gc.nogc())
+                .unwrap();
+        let round_trip = PropertyDescriptor::to_property_descriptor(
+            agent,
+            object.unbind().into(),
+            gc.reborrow(),
+        )
+        .unwrap();
+        assert_eq!(round_trip, desc.unbind());
+    });
+}
@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, PropertyKey, PropertyKeySet, String},
+    engine::{Bindable, ScopableCollection},
+};
+
+#[test]
+fn property_key_set_operations() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let short = PropertyKey::from_static_str(agent, "a", gc);
+        let long = PropertyKey::from_static_str(agent, "a rather long property key", gc);
+        let index = PropertyKey::from(7u32);
+
+        let mut set = PropertyKeySet::new(gc);
+        assert!(set.is_empty());
+        assert!(set.insert(agent, short));
+        assert!(set.insert(agent, long));
+        assert!(!set.insert(agent, short));
+        assert_eq!(set.len(), 2);
+        // Equal strings are equal keys, whichever way they were created.
+        let long_copy = String::from_string(agent, "a rather long property key".into(), gc);
+        assert!(set.contains(agent, long_copy.to_property_key()));
+        assert!(!set.contains(agent, index));
+
+        set.extend(agent, [index, short]);
+        assert_eq!(set.len(), 3);
+        let keys = set.iter().collect::<Vec<_>>();
+        assert_eq!(keys.len(), 3);
+        assert!([index, short, long].iter().all(|key| keys.contains(key)));
+
+        assert!(set.remove(agent, short));
+        assert!(!set.remove(agent, short));
+        assert!(!set.contains(agent, short));
+        set.clear();
+        assert!(set.is_empty());
+    });
+}
+
+#[test]
+fn property_key_set_merges_key_lists() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let [a, b, c, d] =
+            ["a", "b", "c", "d"].map(|key| PropertyKey::from_static_str(agent, key, gc));
+
+        let mut set = PropertyKeySet::with_capacity(4, gc);
+        let mut first = vec![a, b, a, c];
+        set.retain_new(agent, &mut first);
+        assert_eq!(first, vec![a, b, c]);
+        let mut second = vec![d, c, b, d];
+        set.retain_new(agent, &mut second);
+        assert_eq!(second, vec![d]);
+        assert_eq!(set.len(), 4);
+    });
+}
+
+#[test]
+fn scoped_property_key_set_survives_gc() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let key = "a key that is allocated on the heap";
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let mut set = PropertyKeySet::new(gc.nogc()).scope(agent, gc.nogc());
+        let heap_key = String::from_string(agent, key.into(), gc.nogc());
+        set.insert(agent, heap_key.to_property_key());
+        // Allocate garbage and collect it while the key is only held by the set.
+        let source = String::from_static_str(
+            agent,
+            "for (let i = 0; i < 1000; i++) { ({ ['key' + i]: 'value'.repeat(i) }); }",
+            gc.nogc(),
+        );
+        agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+        agent.gc(gc.reborrow());
+        let heap_key = String::from_string(agent, key.into(), gc.nogc());
+        assert!(set.contains(agent, heap_key.to_property_key()));
+        assert!(!set.is_empty(agent));
+    });
+}
@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{fs, path::PathBuf};
+
+use nova_vm::{
+    ecmascript::{
+        AgentOptions, DefaultHostHooks, GcAgent, String, parse_script, script_evaluation,
+    },
+    engine::Bindable,
+};
+
+#[test]
+fn proxy_revocable_tests() {
+    let d: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "sources",
+        "proxyRevocable.test.js",
+    ]
+    .iter()
+    .collect();
+    let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
+
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_string(agent, contents, gc.nogc());
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+            panic!(
+                "Test '{}' failed: {:?}",
+                d.display(),
+                err.unbind().to_string(agent, gc).to_string_lossy(agent)
+            )
+        }
+    });
+}
+
+#[test]
+fn revocable_proxy_survives_garbage_collection() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let run = |agent: &mut GcAgent, source: &'static str| {
+        agent.run_in_realm(&realm, |agent, mut gc| {
+            let realm = agent.current_realm(gc.nogc());
+            let source_text = String::from_static_str(agent, source, gc.nogc());
+            let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+            if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+                panic!(
+                    "Script threw: {}",
+                    err.unbind().to_string(agent, gc).to_string_lossy(agent)
+                )
+            }
+        });
+    };
+    run(
+        &mut agent,
+        r#"
+        Proxy.revocable({}, {});
+        var { proxy, revoke } = Proxy.revocable({ value: 1 }, {});
+        Proxy.revocable({}, {});
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        r#"
+        if (proxy.value !== 1) throw new Error("Proxy target was lost");
+        revoke();
+        "#,
+    );
+    agent.gc();
+    run(
+        &mut agent,
+        r#"
+        try {
+            proxy.value;
+            throw new Error("Proxy was not revoked");
+        } catch (err) {
+            if (!(err instanceof TypeError)) throw err;
+        }
+        revoke();
+        "#,
+    );
+}
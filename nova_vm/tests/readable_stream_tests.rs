@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "web-streams")]
+
+mod common;
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use common::{create_agent, define_global, run_with_jobs};
+use nova_vm::{
+    ecmascript::{
+        Agent, GcAgent, JsResult, ReadableStream, ReadableStreamDefaultController, RealmRoot,
+        UnderlyingSource, Value,
+    },
+    engine::{Bindable, GcScope},
+};
+
+fn define_global_stream(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: impl UnderlyingSource,
+    high_water_mark: f64,
+) {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let stream = ReadableStream::new(agent, source, high_water_mark, gc.reborrow())
+            .unbind()
+            .unwrap()
+            .bind(gc.nogc());
+        define_global(agent, "stream", stream.unbind().into(), gc);
+    });
+    agent.perform_microtask_checkpoint(realm);
+}
+
+#[test]
+fn readable_stream_tests() {
+    common::run_test_file("readableStream.test.js");
+}
+
+/// Host source producing `count` numbers, one per pull.
+struct CountingSource {
+    next: usize,
+    count: usize,
+    cancelled: Arc<AtomicUsize>,
+}
+
+impl UnderlyingSource for CountingSource {
+    fn pull<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        controller: ReadableStreamDefaultController,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        if self.next == self.count {
+            return controller.close(agent, gc);
+        }
+        let value = Value::Integer((self.next as i32).into());
+        self.next += 1;
+        controller.enqueue(agent, value, gc)
+    }
+
+    fn cancel<'gc>(&mut self, _: &mut Agent, _: Value, _: GcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        self.cancelled.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[test]
+fn readable_stream_reads_chunks_from_host_source() {
+    let (_, mut agent, realm) = create_agent();
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    define_global_stream(
+        &mut agent,
+        &realm,
+        CountingSource {
+            next: 0,
+            count: 3,
+            cancelled: cancelled.clone(),
+        },
+        1.0,
+    );
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        (async () => {
+            const reader = stream.getReader();
+            let result;
+            while (!(result = await reader.read()).done) log.push(result.value);
+            log.push("done");
+        })();
+        "#,
+    );
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "0,1,2,done"
+    );
+    assert_eq!(cancelled.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn readable_stream_host_source_is_cancelled() {
+    let (_, mut agent, realm) = create_agent();
+    let cancelled = Arc::new(AtomicUsize::new(0));
+    define_global_stream(
+        &mut agent,
+        &realm,
+        CountingSource {
+            next: 0,
+            count: 10,
+            cancelled: cancelled.clone(),
+        },
+        0.0,
+    );
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        stream.cancel("reason").then((v) => log.push("cancelled:" + v));
+        "#,
+    );
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "cancelled:undefined"
+    );
+    assert_eq!(cancelled.load(Ordering::Relaxed), 1);
+    assert_eq!(run_with_jobs(&mut agent, &realm, "stream.locked"), "false");
+}
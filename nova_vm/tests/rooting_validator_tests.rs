@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, OrdinaryObject, Value},
+    engine::{Bindable, Scopable, TrackUnrooted},
+};
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Garbage collection with unrooted heap references")]
+fn gc_with_unbound_reference_panics() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = OrdinaryObject::create_empty_object(agent, gc.nogc());
+        let _object = object.unbind_tracked(agent);
+        agent.gc(gc.reborrow());
+    });
+}
+
+#[test]
+fn rebound_reference_is_not_reported() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = OrdinaryObject::create_empty_object(agent, gc.nogc());
+        let object = object.unbind_tracked(agent);
+        let object = object.bind_tracked(agent, gc.nogc());
+        let _object = object.scope(agent, gc.nogc());
+        agent.gc(gc.reborrow());
+    });
+}
+
+#[test]
+fn scoped_reference_is_not_reported() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = OrdinaryObject::create_empty_object(agent, gc.nogc());
+        let object = object.unbind_tracked(agent).scope(agent, gc.nogc());
+        agent.gc(gc.reborrow());
+        let _object = object.get(agent).bind(gc.nogc());
+    });
+}
+
+#[test]
+fn stack_values_are_not_tracked() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let _value = Value::from(42).unbind_tracked(agent);
+        agent.gc(gc.reborrow());
+    });
+}
@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{define_global, run_with_jobs};
+use nova_vm::{
+    ecmascript::{
+        Agent, ArgumentsList, Behaviour, BuiltinFunctionArgs, GcAgent, JsResult, Promise,
+        RealmRoot, Value, create_builtin_function,
+    },
+    engine::{Bindable, GcScope},
+};
+
+/// Host function `settled(value, rejected)` returning a Promise that is
+/// already settled with value.
+fn settled<'gc>(
+    agent: &mut Agent,
+    _this: Value,
+    args: ArgumentsList,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let gc = gc.into_nogc();
+    let value = args.get(0).bind(gc);
+    let promise = if args.get(1) == Value::Boolean(true) {
+        Promise::new_rejected(agent, value, gc)
+    } else {
+        Promise::new_resolved(agent, value)
+    };
+    Ok(promise.into())
+}
+
+fn create_agent() -> (GcAgent, RealmRoot) {
+    let (_, mut agent, realm) = common::create_agent();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(settled),
+            BuiltinFunctionArgs::new(2, "settled"),
+            gc.nogc(),
+        );
+        define_global(agent, "settled", function.unbind().into(), gc);
+    });
+    (agent, realm)
+}
+
+#[test]
+fn host_settled_promises_behave_like_native_ones() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        settled(1).then((v) => log.push(`fulfilled ${v}`));
+        settled(2, true).catch((v) => log.push(`rejected ${v}`));
+        Promise.resolve().then(() => log.push("tick"));
+        "#,
+    );
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "fulfilled 1,rejected 2,tick"
+    );
+}
+
+#[test]
+fn resolving_with_native_promises_keeps_job_ordering() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        new Promise((r) => r(settled("a"))).then((v) => log.push(v));
+        new Promise((r) => r(settled("b", true))).catch((v) => log.push(v));
+        Promise.resolve()
+            .then(() => log.push(1))
+            .then(() => log.push(2))
+            .then(() => log.push(3));
+        "#,
+    );
+    assert_eq!(run_with_jobs(&mut agent, &realm, "log.join()"), "1,2,a,b,3");
+}
+
+#[test]
+fn resolving_with_native_promises_looks_up_species_once() {
+    let (mut agent, realm) = create_agent();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        class Derived extends Promise {
+            constructor(executor) {
+                log.push("construct");
+                super(executor);
+            }
+        }
+        const promise = settled("value");
+        Object.defineProperty(promise, "constructor", {
+            get() {
+                log.push("constructor");
+                return Derived;
+            },
+        });
+        new Promise((r) => r(promise)).then((v) => log.push(v));
+        "#,
+    );
+    assert_eq!(
+        run_with_jobs(&mut agent, &realm, "log.join()"),
+        "constructor,construct,value"
+    );
+}
@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::ecmascript::{SourceDiagnostic, compile_module, compile_script};
+
+fn positions(diagnostics: &[SourceDiagnostic]) -> Vec<(u32, u32)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.line(), diagnostic.column()))
+        .collect()
+}
+
+#[test]
+fn valid_source_has_no_diagnostics() {
+    assert!(compile_script("var a = 1; function f() { return a; }", false).is_empty());
+    assert!(compile_script("'use strict'; let a = 1;", true).is_empty());
+    assert!(compile_module("import { a } from 'a'; export const b = a;").is_empty());
+}
+
+#[test]
+fn all_early_errors_are_reported() {
+    let source = "break;\nlet a = 1;\nlet a = 2;\nfunction f() {\n  continue;\n}\n";
+    let diagnostics = compile_script(source, false);
+    // Diagnostics are sorted by position. The redeclaration error points at
+    // the original declaration.
+    assert_eq!(positions(&diagnostics), [(1, 1), (2, 5), (5, 3)]);
+    assert_eq!(&source[diagnostics[0].span()], "break;");
+    assert!(diagnostics[1].message().contains("`a`"));
+    assert_eq!(&source[diagnostics[1].span()], "a");
+    assert_eq!(&source[diagnostics[2].span()], "continue;");
+}
+
+#[test]
+fn invalid_assignment_targets_are_reported() {
+    let source = "1 = 2;\nf() = 3;";
+    let diagnostics = compile_script(source, false);
+    assert!(!diagnostics.is_empty());
+    assert_eq!(diagnostics[0].line(), 1);
+    assert_eq!(&source[diagnostics[0].span()], "1");
+}
+
+#[test]
+fn strictness_is_respected() {
+    let source = "with (a) {}\nvar eval = 1;";
+    assert!(compile_script(source, false).is_empty());
+    assert!(!compile_script(source, true).is_empty());
+    assert!(!compile_module(source).is_empty());
+    // Module syntax is not allowed in strict Scripts.
+    assert!(!compile_script("export const a = 1;", true).is_empty());
+}
+
+#[test]
+fn columns_count_unicode_scalar_values() {
+    let source = "var s = 'äöü'; break;";
+    let diagnostics = compile_script(source, false);
+    assert_eq!(positions(&diagnostics), [(1, 16)]);
+    assert_eq!(diagnostics[0].span().start, 18);
+    assert_eq!(
+        diagnostics[0]
+            .to_string()
+            .split(':')
+            .take(2)
+            .collect::<Vec<_>>(),
+        ["1", "16"]
+    );
+}
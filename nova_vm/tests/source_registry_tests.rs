@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent, create_agent_with, run, run_module};
+use nova_vm::ecmascript::{AgentBuilder, EvaluationOptions, LoadedSource, Number, String};
+
+#[test]
+fn load_returns_registered_source() {
+    let (_, mut agent, realm) = create_agent();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let source = String::from_static_str(agent, "export const a = 1;", gc);
+        let first = agent
+            .load_module("file:///a.mjs", source, EvaluationOptions::default(), gc)
+            .unwrap();
+        let source = String::from_static_str(agent, "export const b = 2;", gc);
+        let second = agent
+            .load_module("file:///a.mjs", source, EvaluationOptions::default(), gc)
+            .unwrap();
+        assert_eq!(first, second);
+
+        let source = String::from_static_str(agent, "var a = 1;", gc);
+        let script = agent
+            .load_script("file:///a.js", source, EvaluationOptions::default(), gc)
+            .unwrap();
+        assert_eq!(
+            agent.get_loaded_source("file:///a.js", gc),
+            Some(LoadedSource::Script(script))
+        );
+
+        // A key cannot be loaded as both a Script and a Module.
+        let source = String::from_static_str(agent, "var a = 1;", gc);
+        assert!(
+            agent
+                .load_script("file:///a.mjs", source, EvaluationOptions::default(), gc)
+                .is_err()
+        );
+        let source = String::from_static_str(agent, "var a = 1;", gc);
+        assert!(
+            agent
+                .load_module("file:///b.mjs", source, EvaluationOptions::default(), gc)
+                .is_ok()
+        );
+    });
+    agent.gc();
+
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let mut keys = agent.loaded_source_keys().collect::<Vec<_>>();
+        keys.sort_unstable();
+        assert_eq!(keys, ["file:///a.js", "file:///a.mjs", "file:///b.mjs"]);
+        assert!(agent.remove_loaded_source("file:///b.mjs"));
+        assert!(!agent.remove_loaded_source("file:///b.mjs"));
+        assert!(matches!(
+            agent.get_loaded_source("file:///a.mjs", gc),
+            Some(LoadedSource::Module(_))
+        ));
+        assert_eq!(agent.get_loaded_source("file:///b.mjs", gc), None);
+    });
+}
+
+#[test]
+fn import_registered_module() {
+    let (_, mut agent, realm) = create_agent();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let source = String::from_static_str(
+            agent,
+            "export let count = 0; export function increment() { count++; }",
+            gc,
+        );
+        agent
+            .load_module("lib:counter", source, EvaluationOptions::default(), gc)
+            .unwrap();
+    });
+    agent.gc();
+    for _ in 0..2 {
+        run_module(
+            &mut agent,
+            &realm,
+            r#"
+            import { count, increment } from "lib:counter";
+            increment();
+            globalThis.count = count;
+            "#,
+        );
+    }
+    assert_eq!(run(&mut agent, &realm, "count"), "2");
+}
+
+#[test]
+fn import_resolved_specifiers() {
+    let (_, mut agent, realm) = create_agent_with(
+        TestHostHooks::with_resolved_specifiers(&[
+            ("#native", "host:native"),
+            ("./a.mjs", "file:///a.mjs"),
+            ("./b.mjs", "file:///b.mjs"),
+        ]),
+        AgentBuilder::new().with_synchronous_dynamic_import(true),
+    );
+    agent.register_module("host:native", |agent, module, gc| {
+        module.export_value(agent, "answer", Number::from(42), gc);
+    });
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        for (key, source) in [
+            (
+                "file:///a.mjs",
+                "import { answer } from '#native'; export const a = answer;",
+            ),
+            (
+                "file:///b.mjs",
+                "import { a } from './a.mjs'; globalThis.b = a + 1;",
+            ),
+        ] {
+            let source = String::from_static_str(agent, source, gc);
+            agent
+                .load_module(key, source, EvaluationOptions::default(), gc)
+                .unwrap();
+        }
+    });
+    assert_eq!(run(&mut agent, &realm, "import('./b.mjs'); b"), "43");
+}
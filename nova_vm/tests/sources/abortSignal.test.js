@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+function errorName(f) {
+  try {
+    f();
+  } catch (e) {
+    return e.name;
+  }
+  return "none";
+}
+
+// AbortController aborts its signal once.
+{
+  const controller = new AbortController();
+  const signal = controller.signal;
+  const log = [signal.aborted, signal.reason];
+  signal.onabort = function () {
+    log.push(this === signal, signal.reason);
+  };
+  controller.abort("stop");
+  controller.abort("again");
+  log.push(signal.aborted, signal.reason);
+  try {
+    signal.throwIfAborted();
+  } catch (e) {
+    log.push("threw:" + e);
+  }
+  assert(log.join(), "false,,true,stop,true,stop,threw:stop", "Abort");
+}
+
+// Aborting without a reason uses an AbortError.
+{
+  const controller = new AbortController();
+  controller.abort();
+  const reason = controller.signal.reason;
+  const signal = AbortSignal.abort();
+  assert(
+    [
+      reason instanceof Error,
+      reason.name,
+      signal.aborted,
+      signal.reason.name,
+      AbortSignal.abort(0).reason,
+      Object.prototype.toString.call(signal),
+      Object.prototype.toString.call(controller),
+    ].join(),
+    "true,AbortError,true,AbortError,0,[object AbortSignal],[object AbortController]",
+    "Default reason",
+  );
+}
+
+// Brand checks and argument validation.
+assert(
+  [
+    errorName(() => new AbortSignal()),
+    errorName(() => AbortController()),
+    errorName(() =>
+      Object.getOwnPropertyDescriptor(AbortSignal.prototype, "aborted").get.call(
+        {},
+      ),
+    ),
+    errorName(() => AbortController.prototype.abort.call({})),
+    errorName(() => AbortSignal.any([{}])),
+    errorName(() => AbortSignal.timeout(-1)),
+    errorName(() => AbortSignal.timeout(NaN)),
+  ].join(),
+  "TypeError,TypeError,TypeError,TypeError,TypeError,TypeError,TypeError",
+  "Brand checks",
+);
+
+// AbortSignal.any follows its source signals.
+{
+  const a = new AbortController();
+  const b = new AbortController();
+  const any = AbortSignal.any([a.signal, b.signal]);
+  const nested = AbortSignal.any([any]);
+  const log = [any.aborted];
+  any.onabort = () => log.push("any:" + any.reason);
+  nested.onabort = () => log.push("nested:" + nested.reason);
+  b.abort("b");
+  a.abort("a");
+  log.push(a.signal.aborted, AbortSignal.any([a.signal]).reason);
+  assert(log.join(), "false,any:b,nested:b,true,a", "AbortSignal.any");
+}
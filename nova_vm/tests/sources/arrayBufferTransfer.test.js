@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+function assertThrows(f, errorType, name) {
+  try {
+    f();
+  } catch (err) {
+    assertEq(err.constructor, errorType, name);
+    return;
+  }
+  throw new Error(`${name}: did not throw`);
+}
+
+// transfer keeps the contents and detaches the donor buffer.
+{
+  const donor = new ArrayBuffer(4);
+  new Uint8Array(donor).set([1, 2, 3, 4]);
+  const buffer = donor.transfer();
+  assertEq(donor.detached, true, "donor is detached");
+  assertEq(donor.byteLength, 0, "donor byteLength");
+  assertEq(buffer.detached, false, "new buffer is not detached");
+  assertEq(buffer.resizable, false, "fixed length stays fixed length");
+  assertEq(String(new Uint8Array(buffer)), "1,2,3,4", "contents");
+}
+
+// transfer preserves resizability.
+{
+  const donor = new ArrayBuffer(4, { maxByteLength: 8 });
+  new Uint8Array(donor).set([1, 2, 3, 4]);
+  const buffer = donor.transfer(6);
+  assertEq(buffer.resizable, true, "resizable");
+  assertEq(buffer.maxByteLength, 8, "maxByteLength");
+  assertEq(String(new Uint8Array(buffer)), "1,2,3,4,0,0", "grown contents");
+  buffer.resize(8);
+  assertEq(buffer.byteLength, 8, "resized byteLength");
+  const other = new ArrayBuffer(4, { maxByteLength: 8 });
+  assertThrows(() => other.transfer(9), RangeError, "over maxByteLength");
+  assertEq(other.detached, false, "failed transfer does not detach");
+}
+
+// transferToFixedLength drops resizability.
+{
+  const donor = new ArrayBuffer(4, { maxByteLength: 8 });
+  new Uint8Array(donor).set([1, 2, 3, 4]);
+  const buffer = donor.transferToFixedLength(2);
+  assertEq(donor.detached, true, "donor is detached");
+  assertEq(buffer.resizable, false, "not resizable");
+  assertEq(buffer.byteLength, 2, "byteLength");
+  assertEq(buffer.maxByteLength, 2, "maxByteLength");
+  assertEq(String(new Uint8Array(buffer)), "1,2", "shrunk contents");
+  const grown = buffer.transferToFixedLength(12);
+  assertEq(String(new Uint8Array(grown)), "1,2,0,0,0,0,0,0,0,0,0,0", "grown");
+}
+
+// Views over the donor see a detached buffer.
+{
+  const donor = new ArrayBuffer(4);
+  const view = new Uint8Array(donor);
+  donor.transfer();
+  assertEq(view.length, 0, "view length");
+  assertEq(view.byteLength, 0, "view byteLength");
+}
+
+// newLength is converted before the detached check.
+{
+  const donor = new ArrayBuffer(4);
+  const buffer = donor.transfer({ valueOf: () => 3 });
+  assertEq(buffer.byteLength, 3, "converted newLength");
+  assertThrows(() => donor.transfer(), TypeError, "detached donor");
+  assertThrows(
+    () => donor.transferToFixedLength(),
+    TypeError,
+    "detached donor fixed",
+  );
+  assertThrows(() => new ArrayBuffer(1).transfer(-1), RangeError, "negative");
+  assertThrows(
+    () => ArrayBuffer.prototype.transfer.call({}),
+    TypeError,
+    "non-buffer this",
+  );
+}
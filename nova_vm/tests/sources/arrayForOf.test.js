@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+const ArrayIteratorPrototype = Object.getPrototypeOf([].values());
+const next = ArrayIteratorPrototype.next;
+
+// Elements are read per step, including holes, getters and appended values.
+{
+  const array = [1, , 3];
+  Object.defineProperty(array, 2, { get: () => 30 });
+  const seen = [];
+  for (const value of array) {
+    seen.push(value);
+    if (seen.length === 1) {
+      array.push(4);
+    }
+  }
+  assertEq(seen.join(), "1,,30,4", "elements");
+}
+
+{
+  Array.prototype[1] = "inherited";
+  const seen = [];
+  for (const value of [0, , 2]) {
+    seen.push(value);
+  }
+  delete Array.prototype[1];
+  assertEq(seen.join(), "0,inherited,2", "inherited hole");
+}
+
+// A replaced next method is called with the ArrayIterator object.
+{
+  let calls = 0;
+  let receiver;
+  ArrayIteratorPrototype.next = function () {
+    calls++;
+    receiver = this;
+    return next.call(this);
+  };
+  const seen = [];
+  for (const value of [1, 2]) {
+    seen.push(value);
+  }
+  const [a] = [3];
+  ArrayIteratorPrototype.next = next;
+  assertEq(seen.join(), "1,2", "replaced next values");
+  assertEq(calls, 4, "replaced next calls");
+  assertEq(Object.getPrototypeOf(receiver), ArrayIteratorPrototype, "this");
+  assertEq(a, 3, "replaced next destructuring");
+}
+
+// Breaking out of the loop calls a return method installed on the
+// ArrayIterator prototype.
+{
+  let returnCalls = 0;
+  ArrayIteratorPrototype.return = function () {
+    returnCalls++;
+    return {};
+  };
+  for (const _ of [1, 2, 3]) {
+    break;
+  }
+  delete ArrayIteratorPrototype.return;
+  assertEq(returnCalls, 1, "return on break");
+}
+
+// Deleting next makes iteration throw.
+{
+  delete ArrayIteratorPrototype.next;
+  let error;
+  try {
+    for (const _ of [1]) {
+    }
+  } catch (err) {
+    error = err;
+  }
+  ArrayIteratorPrototype.next = next;
+  assertEq(error instanceof TypeError, true, "deleted next");
+}
+
+// Restoring the original methods restores normal iteration.
+{
+  let sum = 0;
+  for (const value of [1, 2, 3]) {
+    sum += value;
+  }
+  assertEq(sum, 6, "restored");
+}
@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+function args() {
+  return arguments;
+}
+
+// copyWithin, fill and slice on array-likes.
+{
+  const a = args(1, 2, 3, 4);
+  Array.prototype.copyWithin.call(a, 0, 2);
+  assert(Array.from(a).join(), "3,4,3,4", "copyWithin on arguments");
+  const o = { length: 4, 0: "a", 2: "c" };
+  Array.prototype.copyWithin.call(o, 1, 0, 3);
+  assert(
+    JSON.stringify(o),
+    '{"0":"a","1":"a","3":"c","length":4}',
+    "copyWithin on object",
+  );
+  assert(
+    [1, 2, 3, 4, 5].copyWithin(0, -Infinity, -2).join(),
+    "1,2,3,4,5",
+    "copyWithin from -Infinity",
+  );
+  assert(
+    [1, 2, 3, 4, 5].copyWithin(Infinity, 0).join(),
+    "1,2,3,4,5",
+    "copyWithin to Infinity",
+  );
+}
+{
+  const a = args(1, 2, 3, 4);
+  Array.prototype.fill.call(a, 0, 1, -1);
+  assert(Array.from(a).join(), "1,0,0,4", "fill on arguments");
+  let o = { length: "3" };
+  Array.prototype.fill.call(o, "x", -Infinity, Infinity);
+  assert(
+    JSON.stringify(o),
+    '{"0":"x","1":"x","2":"x","length":"3"}',
+    "fill with infinite bounds",
+  );
+  o = { length: 2 ** 53 + 10 };
+  Array.prototype.fill.call(o, "x", -1);
+  assert(Object.keys(o).join(), "length,9007199254740990", "fill clamps length");
+}
+{
+  assert(
+    Array.prototype.slice.call(args(1, 2, 3), 1).join(),
+    "2,3",
+    "slice on arguments",
+  );
+  const r = Array.prototype.slice.call({ length: 4, 1: "b", 3: "d" }, 1);
+  assert(`${r.length} ${1 in r}`, "3 false", "slice keeps holes");
+  assert(
+    [1, 2, 3].slice(-Infinity, Infinity).join(),
+    "1,2,3",
+    "slice with infinite bounds",
+  );
+  assert([1, 2, 3].slice(2, 1).length, 0, "slice with end before start");
+}
+
+// copyWithin and slice read holes through the prototype.
+{
+  Array.prototype[1] = "proto";
+  const a = [0, , 2, 3];
+  a.copyWithin(2, 0);
+  const s = [0, , 2].slice();
+  delete Array.prototype[1];
+  assert(
+    `${a.join()} ${a.hasOwnProperty(3)}`,
+    "0,,0,proto true",
+    "copyWithin reads prototype",
+  );
+  assert(
+    `${s.join()} ${s.hasOwnProperty(1)}`,
+    "0,proto,2 true",
+    "slice reads prototype",
+  );
+}
+
+// Generic methods on Proxies call traps in order.
+{
+  let log = [];
+  function logging(target) {
+    return new Proxy(target, {
+      has(t, k) {
+        log.push("has " + k);
+        return k in t;
+      },
+      get(t, k) {
+        log.push("get " + String(k));
+        return t[k];
+      },
+      set(t, k, v) {
+        log.push("set " + k);
+        t[k] = v;
+        return true;
+      },
+      deleteProperty(t, k) {
+        log.push("delete " + k);
+        return delete t[k];
+      },
+    });
+  }
+  Array.prototype.copyWithin.call(logging([1, , 3]), 0, 1);
+  assert(
+    log.join(),
+    "get length,has 1,delete 0,has 2,get 2,set 1",
+    "copyWithin traps",
+  );
+  log = [];
+  Array.prototype.fill.call(logging([1, 2, 3]), 0, 1);
+  assert(log.join(), "get length,set 1,set 2", "fill traps");
+  log = [];
+  Array.prototype.slice.call(logging([1, , 3]), 1);
+  assert(
+    log.join(),
+    "get length,get constructor,has 1,has 2,get 2",
+    "slice traps",
+  );
+}
+
+// slice survives a species constructor mutating the receiver.
+{
+  let a = [1, 2, 3, 4];
+  a.constructor = {
+    [Symbol.species]: function (n) {
+      a.length = 1;
+      return new Array(n);
+    },
+  };
+  let r = a.slice(1);
+  assert(`${r.length} ${0 in r}`, "3 false", "species shrinks receiver");
+
+  a = [1, 2, 3, 4];
+  a.constructor = {
+    [Symbol.species]: function () {
+      delete a[2];
+      return {};
+    },
+  };
+  assert(
+    JSON.stringify(a.slice(1)),
+    '{"0":2,"2":4,"length":3}',
+    "species deletes element",
+  );
+
+  a = [1, 2, 3, 4];
+  const defined = [];
+  a.constructor = {
+    [Symbol.species]: function () {
+      return new Proxy([], {
+        defineProperty(t, k, d) {
+          defined.push(k);
+          if (k === "0") a.length = 2;
+          return Reflect.defineProperty(t, k, d);
+        },
+      });
+    },
+  };
+  r = a.slice(1);
+  assert(
+    `${defined.join()} ${r.length}`,
+    "0,length 3",
+    "species result shrinks receiver",
+  );
+}
@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+function assertThrows(f, errorType, name) {
+  try {
+    f();
+  } catch (err) {
+    assertEq(err.constructor, errorType, name);
+    return;
+  }
+  throw new Error(`${name}: did not throw`);
+}
+
+assertEq(
+  Object.prototype.toString.call(Atomics),
+  "[object Atomics]",
+  "toStringTag",
+);
+
+// Read-modify-write operations return the previous value.
+for (const buffer of [new SharedArrayBuffer(16), new ArrayBuffer(16)]) {
+  const t = new Int32Array(buffer);
+  const kind = buffer.constructor.name;
+  assertEq(Atomics.store(t, 0, 5), 5, `${kind} store`);
+  assertEq(Atomics.add(t, 0, 1), 5, `${kind} add`);
+  assertEq(Atomics.sub(t, 0, 2), 6, `${kind} sub`);
+  assertEq(Atomics.and(t, 0, 6), 4, `${kind} and`);
+  assertEq(Atomics.or(t, 0, 1), 4, `${kind} or`);
+  assertEq(Atomics.xor(t, 0, 3), 5, `${kind} xor`);
+  assertEq(Atomics.exchange(t, 0, 42), 6, `${kind} exchange`);
+  assertEq(Atomics.compareExchange(t, 0, 1, 2), 42, `${kind} failed cas`);
+  assertEq(Atomics.compareExchange(t, 0, 42, 7), 42, `${kind} cas`);
+  assertEq(Atomics.load(t, 0), 7, `${kind} load`);
+}
+
+// Values are converted to the element type.
+const u8 = new Uint8Array(4);
+assertEq(Atomics.add(u8, 0, 300), 0, "Uint8 add");
+assertEq(u8[0], 44, "Uint8 add wraps");
+assertEq(Atomics.store(u8, 1, -1.5), -1, "store returns the integer");
+assertEq(u8[1], 255, "Uint8 store wraps");
+const i64 = new BigInt64Array(new SharedArrayBuffer(8));
+assertEq(Atomics.add(i64, 0, 5n), 0n, "BigInt64 add");
+assertEq(Atomics.sub(i64, 0, 7n), 5n, "BigInt64 sub");
+assertEq(i64[0], -2n, "BigInt64 result");
+
+// isLockFree reports the supported element sizes.
+assertEq(Atomics.isLockFree(4), true, "isLockFree(4)");
+assertEq(Atomics.isLockFree(3), false, "isLockFree(3)");
+
+// Invalid arrays and indices.
+assertThrows(
+  () => Atomics.add(new Float64Array(1), 0, 1),
+  TypeError,
+  "float array",
+);
+assertThrows(
+  () => Atomics.load(new Uint8ClampedArray(1), 0),
+  TypeError,
+  "clamped array",
+);
+assertThrows(() => Atomics.load(new Int32Array(1), 1), RangeError, "index");
+assertThrows(() => Atomics.add(i64, 0, 1), TypeError, "Number into BigInt");
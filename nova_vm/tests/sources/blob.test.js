@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Checks run after the promise jobs, by afterJobs.
+const checks = [];
+
+// Blob parts and options.
+{
+  const bytes = new Uint8Array([0x61, 0x62, 0x63, 0x64]);
+  const blob = new Blob(
+    [
+      "x\r\ny\rz",
+      bytes.subarray(1, 3),
+      new DataView(bytes.buffer, 3),
+      new Blob(["!"]),
+    ],
+    { type: "Text/Plain", endings: "native" },
+  );
+  const log = [
+    blob.size,
+    blob.type,
+    new Blob().size,
+    new Blob([], { type: "é" }).type,
+  ];
+  log.push(new Blob(["é", 1, null]).size);
+  try {
+    new Blob("abc");
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    new Blob([], { endings: "crlf" });
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    Blob();
+  } catch (e) {
+    log.push(e.name);
+  }
+  log.push(Object.prototype.toString.call(blob));
+  blob.text().then((text) => log.push(JSON.stringify(text)));
+  checks.push(() =>
+    assert(
+      log.join(),
+      '9,text/plain,0,,7,TypeError,TypeError,TypeError,[object Blob],"x\\ny\\nzbcd!"',
+      "Blob parts",
+    ),
+  );
+}
+
+// Slices share bytes.
+{
+  const log = [];
+  const blob = new Blob(["hello", " ", new Blob(["wide"]), " world"]);
+  const slices = [
+    blob.slice(),
+    blob.slice(6, 10, "TEXT/X"),
+    blob.slice(-5),
+    blob.slice(3, -8),
+    blob.slice(8, 2),
+    blob.slice(-100, 100),
+  ];
+  for (const slice of slices) {
+    log.push(slice.size, slice.type);
+  }
+  Promise.all(slices.map((slice) => slice.text())).then((texts) =>
+    log.push(texts.join("|")),
+  );
+  blob
+    .slice(2, 9)
+    .arrayBuffer()
+    .then((buffer) => {
+      log.push(
+        buffer instanceof ArrayBuffer,
+        new Uint8Array(buffer).join("-"),
+      );
+    });
+  checks.push(() =>
+    assert(
+      log.join(),
+      "16,,4,text/x,5,,5,,0,,16,,true,108-108-111-32-119-105-100,hello wide world|wide|world|lo wi||hello wide world",
+      "Slices",
+    ),
+  );
+}
+
+// text() decodes UTF-8.
+{
+  const log = [];
+  new Blob([new Uint8Array([0xef, 0xbb, 0xbf, 0x61, 0xff, 0x62])])
+    .text()
+    .then((text) => log.push(text));
+  Blob.prototype.text.call({}).catch((e) => log.push(e.name));
+  checks.push(() => assert(log.join(), "a\u{FFFD}b,TypeError", "UTF-8"));
+}
+
+// File extends Blob.
+{
+  const file = new File(["abc"], "notes.txt", {
+    type: "text/plain",
+    lastModified: 42.9,
+  });
+  const log = [
+    file.name,
+    file.size,
+    file.type,
+    file.lastModified,
+    file instanceof Blob,
+  ];
+  log.push(
+    Object.getPrototypeOf(File) === Blob,
+    Object.prototype.toString.call(file),
+  );
+  const now = Date.now();
+  log.push(new File([], "empty").lastModified >= now);
+  log.push(file.slice(1) instanceof File);
+  try {
+    new File(["abc"]);
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    Object.getOwnPropertyDescriptor(File.prototype, "name").get.call(
+      new Blob(),
+    );
+  } catch (e) {
+    log.push(e.name);
+  }
+  assert(
+    log.join(),
+    "notes.txt,3,text/plain,42,true,true,[object File],true,false,TypeError,TypeError",
+    "File",
+  );
+}
+
+function afterJobs() {
+  for (const check of checks) check();
+}
@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(condition, name) {
+  if (!condition) {
+    throw new Error(`${name} failed`);
+  }
+}
+
+// Array instances are exotic and species creates subclass instances.
+class MyArray extends Array {}
+const array = new MyArray(1, 2);
+array[3] = 4;
+assert(array instanceof MyArray && Array.isArray(array), "Array instance");
+assert(array.length === 4, "Array length");
+assert(new MyArray(3).length === 3, "Array length argument");
+assert(array.map((x) => x) instanceof MyArray, "Array map species");
+assert(array.filter((x) => x) instanceof MyArray, "Array filter species");
+assert(array.slice() instanceof MyArray, "Array slice species");
+assert(array.concat([]) instanceof MyArray, "Array concat species");
+assert(MyArray.from([1]) instanceof MyArray, "Array.from");
+assert(MyArray.of(1) instanceof MyArray, "Array.of");
+assert(!(array.toSorted() instanceof MyArray), "Array toSorted");
+
+// Error subclasses.
+class MyError extends TypeError {
+  constructor(message) {
+    super(message);
+    this.name = "MyError";
+  }
+}
+const error = new MyError("x");
+assert(error instanceof MyError && error instanceof TypeError, "Error");
+assert(String(error) === "MyError: x", "Error message");
+
+// Keyed collections call the overridden adder.
+class MyMap extends Map {
+  set(key, value) {
+    return super.set(key, value * 2);
+  }
+}
+assert(new MyMap([[1, 2]]).get(1) === 4, "Map adder");
+let added = 0;
+class MySet extends Set {
+  add(value) {
+    added++;
+    return super.add(value);
+  }
+}
+assert(new MySet([1, 2]).size === 2 && added === 2, "Set adder");
+
+// TypedArrays and ArrayBuffers.
+class MyUint8Array extends Uint8Array {}
+const typedArray = new MyUint8Array([1, 2]);
+assert(typedArray instanceof MyUint8Array, "TypedArray instance");
+assert(
+  typedArray.map((x) => x) instanceof MyUint8Array,
+  "TypedArray map species",
+);
+assert(
+  typedArray.subarray(0) instanceof MyUint8Array,
+  "TypedArray subarray species",
+);
+assert(MyUint8Array.from([1]) instanceof MyUint8Array, "TypedArray.from");
+class MyArrayBuffer extends ArrayBuffer {}
+const arrayBuffer = new MyArrayBuffer(2, { maxByteLength: 4 });
+assert(
+  Object.getPrototypeOf(arrayBuffer) === MyArrayBuffer.prototype,
+  "ArrayBuffer prototype",
+);
+assert(
+  arrayBuffer.resizable && arrayBuffer.maxByteLength === 4,
+  "ArrayBuffer resizable",
+);
+assert(arrayBuffer.slice(0) instanceof MyArrayBuffer, "ArrayBuffer slice");
+function NotABuffer() {}
+NotABuffer.prototype = null;
+assert(
+  Object.getPrototypeOf(Reflect.construct(ArrayBuffer, [1], NotABuffer)) ===
+    ArrayBuffer.prototype,
+  "ArrayBuffer fallback prototype",
+);
+
+// Other builtins.
+class MyDate extends Date {}
+assert(new MyDate(0).getTime() === 0, "Date");
+class MyRegExp extends RegExp {}
+assert(new MyRegExp("a").test("a"), "RegExp");
+class MyString extends String {}
+assert(new MyString("ab").length === 2, "String");
+class MyFunction extends Function {}
+assert(new MyFunction("return 1")() === 1, "Function");
+
+// Promise subclasses run their constructor for every derived promise.
+const log = [];
+class MyPromise extends Promise {
+  constructor(executor) {
+    log.push("constructor");
+    super(executor);
+  }
+}
+const promise = MyPromise.resolve(1);
+assert(promise instanceof MyPromise, "Promise.resolve");
+assert(MyPromise.resolve(promise) === promise, "Promise.resolve identity");
+assert(Promise.resolve(promise) !== promise, "Promise.resolve constructor");
+assert(promise.then() instanceof MyPromise, "Promise then species");
+assert(promise.finally() instanceof MyPromise, "Promise finally species");
+assert(MyPromise.all([]) instanceof MyPromise, "Promise.all");
+assert(MyPromise.try(() => 1) instanceof MyPromise, "Promise.try");
+assert(
+  MyPromise.withResolvers().promise instanceof MyPromise,
+  "Promise.withResolvers",
+);
+const rejected = MyPromise.reject(1);
+rejected.catch(() => {});
+assert(rejected instanceof MyPromise, "Promise.reject");
+assert(log.length === 8, "Promise constructor calls");
+class SpeciesPromise extends Promise {
+  static get [Symbol.species]() {
+    return Promise;
+  }
+}
+assert(
+  !(new SpeciesPromise(() => {}).then() instanceof SpeciesPromise),
+  "Promise species",
+);
+let threw = false;
+try {
+  Promise.resolve.call(function () {
+    return {};
+  }, 1);
+} catch (err) {
+  threw = err instanceof TypeError;
+}
+assert(threw, "Promise constructor returning a non-promise");
+
+// Reactions settle subclass promises.
+var results = [];
+promise
+  .then((value) => value + 1)
+  .finally(() => results.push("finally"))
+  .then((value) => results.push(`then ${value}`));
+MyPromise.all([1, MyPromise.resolve(2)]).then((values) =>
+  results.push(`all ${values}`),
+);
+
+function afterJobs() {
+  assert(results.join() === "finally,all 1,2,then 2", "Promise reactions");
+}
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Each entry is [expression, expected result]; a result naming an error, such
+// as "TypeError", means the expression is expected to throw that error.
+const cases = [
+  // String and BigInt: unparseable strings compare as undefined.
+  [() => "abc" < 1n, false],
+  [() => 1n < "abc", false],
+  [() => "abc" >= 1n, false],
+  [() => "1.5" < 2n, false],
+  [() => "1" < 2n, true],
+  [() => "1" >= 2n, false],
+  [() => "0x10" == 16n, true],
+  [() => "  12  " == 12n, true],
+  [() => "1e3" == 1000n, false],
+  [() => "" == 0n, true],
+  [() => "-0" == 0n, true],
+  [() => "1n" == 1n, false],
+  // ToBigInt throws where the comparisons return undefined.
+  [() => BigInt(" 0x1f "), 31n],
+  [() => BigInt("1n"), "SyntaxError"],
+  [() => BigInt.asIntN(8, "abc"), "SyntaxError"],
+  // BigInt and Number at equality boundaries.
+  [() => 1 < 1n, false],
+  [() => 1n < 1, false],
+  [() => 1n <= 1, true],
+  [() => 1 <= 1n, true],
+  [() => 0n == -0, true],
+  [() => 1n == 1.5, false],
+  [() => 1n < 1.5, true],
+  [() => 2n > 1.5, true],
+  [() => 1.5 < 2n, true],
+  [() => -1.5 < -1n, true],
+  [() => 0.5 > 0n, true],
+  // BigInt and Number beyond the safe integer range.
+  [() => 2 ** 53 == 9007199254740992n, true],
+  [() => 9007199254740993n == 9007199254740992, false],
+  [() => 9007199254740992 == 9007199254740993n, false],
+  [() => 9007199254740993n > 9007199254740992, true],
+  [() => 9007199254740992 < 9007199254740993n, true],
+  [() => 2n ** 64n == 2 ** 64, true],
+  [() => 2n ** 64n + 1n == 2 ** 64, false],
+  [() => 2n ** 64n + 1n > 2 ** 64, true],
+  [() => 2n ** 64n < 1.5, false],
+  [() => -(2n ** 64n) < 1.5, true],
+  [() => -(2n ** 63n) < -9223372036854775808, false],
+  [() => -(2n ** 63n) <= -9223372036854775808, true],
+  [() => 123456789012345678901234567890n == 123456789012345678901234567890, false],
+  [() => 123456789012345678901234567890n > 1.2345678901234568e29, true],
+  // BigInt and non-finite Numbers.
+  [() => 1n == NaN, false],
+  [() => 1n < NaN, false],
+  [() => 1n >= NaN, false],
+  [() => 1n < Infinity, true],
+  [() => 1n > -Infinity, true],
+  [() => 1n == Infinity, false],
+  // Objects, Booleans, Symbols, null and undefined.
+  [() => Object(1n) == 1n, true],
+  [() => Object(1n) == "1", true],
+  [() => [] == 0n, true],
+  [() => true == 1n, true],
+  [() => Symbol.iterator == Object(Symbol.iterator), true],
+  [() => null == 0, false],
+  [() => undefined == 0, false],
+  [() => null == false, false],
+  [() => "" == false, true],
+  [() => NaN == NaN, false],
+  [() => Symbol() < 1, "TypeError"],
+  // Strings compare by UTF-16 code units, not by code points.
+  [() => "\u{10000}" < "￿", true],
+  [() => "\uD800" < "￿", true],
+  [() => "\uD800" < "\u{10000}", false],
+  [() => "\uD800a" < "\u{10000}", true],
+  [() => "é\u{1F600}" < "é￿", true],
+  [() => "abc" < "abcd", true],
+  [() => -0 < 0, false],
+];
+
+for (const [expression, expected] of cases) {
+  let result;
+  try {
+    result = expression();
+  } catch (err) {
+    result = err.constructor.name;
+  }
+  if (result !== expected) {
+    throw new Error(`'${expression}' produced ${String(result)}`);
+  }
+}
+
+// ToPrimitive is performed on the operands from left to right, including for
+// > and <= which swap the operands of IsLessThan.
+const log = [];
+const a = {
+  valueOf() {
+    log.push("a");
+    return 1;
+  },
+};
+const b = {
+  valueOf() {
+    log.push("b");
+    return 2;
+  },
+};
+a < b;
+a > b;
+a <= b;
+a >= b;
+a == 1;
+1 == b;
+if (log.join() !== "a,b,a,b,a,b,a,b,a,b") {
+  throw new Error(`ToPrimitive was called in the wrong order: ${log.join()}`);
+}
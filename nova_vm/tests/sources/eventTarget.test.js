@@ -0,0 +1,154 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Listeners are called in order, and only once per callback.
+{
+  const target = new EventTarget();
+  const log = [];
+  function first(event) {
+    log.push(
+      "first",
+      this === target,
+      event.target === target,
+      event.eventPhase,
+    );
+  }
+  target.addEventListener("ping", first);
+  target.addEventListener("ping", first);
+  target.addEventListener("ping", {
+    handleEvent(event) {
+      log.push("object", event.type);
+    },
+  });
+  target.addEventListener("pong", () => log.push("pong"));
+  const event = new Event("ping");
+  log.push(target.dispatchEvent(event), event.eventPhase, event.currentTarget);
+  assert(log.join(), "first,true,true,2,object,ping,true,0,", "Listener order");
+}
+
+// removeEventListener matches the capture flag.
+{
+  const target = new EventTarget();
+  const log = [];
+  const listener = () => log.push("called");
+  target.addEventListener("ping", listener, { capture: true });
+  target.removeEventListener("ping", listener);
+  target.dispatchEvent(new Event("ping"));
+  target.removeEventListener("ping", listener, true);
+  target.dispatchEvent(new Event("ping"));
+  assert(log.join(), "called", "Capture");
+}
+
+// Listeners added or removed during dispatch.
+{
+  const target = new EventTarget();
+  const log = [];
+  const second = () => log.push("second");
+  target.addEventListener(
+    "ping",
+    () => {
+      log.push("first");
+      target.removeEventListener("ping", second);
+      target.addEventListener("ping", () => log.push("added"));
+    },
+    { once: true },
+  );
+  target.addEventListener("ping", second);
+  target.dispatchEvent(new Event("ping"));
+  log.push("|");
+  target.dispatchEvent(new Event("ping"));
+  assert(log.join(), "first,|,added", "Listener changes");
+}
+
+// preventDefault and stopImmediatePropagation.
+{
+  const target = new EventTarget();
+  const log = [];
+  target.addEventListener(
+    "ping",
+    (e) => {
+      e.preventDefault();
+      log.push(e.defaultPrevented);
+    },
+    { passive: true },
+  );
+  target.addEventListener("ping", (e) => {
+    e.preventDefault();
+    e.stopImmediatePropagation();
+  });
+  target.addEventListener("ping", () => log.push("unreachable"));
+  log.push(target.dispatchEvent(new Event("ping")));
+  log.push(target.dispatchEvent(new Event("ping", { cancelable: true })));
+  const stopped = new Event("ping");
+  stopped.stopPropagation();
+  log.push(target.dispatchEvent(stopped));
+  assert(log.join(), "false,true,false,false,true", "Cancellation");
+}
+
+// Event constructor and brand checks.
+{
+  const event = new Event("ping", { bubbles: 1, cancelable: "" });
+  const log = [
+    event.type,
+    event.bubbles,
+    event.cancelable,
+    event.isTrusted,
+    event.target,
+  ];
+  log.push(
+    Event.AT_TARGET,
+    Event.prototype.BUBBLING_PHASE,
+    Object.prototype.toString.call(event),
+  );
+  try {
+    Event();
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    new Event();
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    new EventTarget().dispatchEvent({});
+  } catch (e) {
+    log.push(e.name);
+  }
+  try {
+    EventTarget.prototype.addEventListener.call({}, "ping", null);
+  } catch (e) {
+    log.push(e.name);
+  }
+  class Emitter extends EventTarget {}
+  const emitter = new Emitter();
+  emitter.addEventListener("ping", null);
+  log.push(emitter instanceof EventTarget, emitter.dispatchEvent(event));
+  assert(
+    log.join(),
+    "ping,true,false,false,,2,3,[object Event],TypeError,TypeError,TypeError,TypeError,true,true",
+    "Event constructor",
+  );
+}
+
+// Redispatching an event throws an InvalidStateError.
+{
+  const target = new EventTarget();
+  const log = [];
+  target.addEventListener("ping", (e) => {
+    try {
+      target.dispatchEvent(e);
+    } catch (error) {
+      log.push(error.name);
+    }
+  });
+  target.dispatchEvent(new Event("ping"));
+  assert(log.join(), "InvalidStateError", "Redispatch");
+}
@@ -0,0 +1,177 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Checks run after the promise jobs, by afterJobs.
+const checks = [];
+
+// Headers methods and iteration.
+{
+  const headers = new Headers({ "X-B": " 2 ", "x-a": "1" });
+  headers.append("X-B", "3");
+  headers.append("Set-Cookie", "a=1");
+  headers.append("set-cookie", "b=2");
+  const log = [headers.get("x-b"), headers.has("X-A"), headers.get("missing")];
+  headers.set("x-a", "4");
+  headers.delete("X-C");
+  log.push([...headers].join("|"));
+  log.push([...headers.keys()].join(), [...headers.values()].join());
+  const copy = new Headers(headers);
+  copy.delete("set-cookie");
+  log.push(
+    [
+      ...new Headers([
+        ["y", "5"],
+        ["Z", "6"],
+      ]).entries(),
+    ].join("|"),
+  );
+  headers.forEach(function (value, name, object) {
+    log.push(name + "=" + value + (object === headers) + (this === log));
+  }, log);
+  for (const init of [
+    [["a"]],
+    "x",
+    { "a b": "1" },
+    { a: "a\0b" },
+    { a: "ā" },
+  ]) {
+    try {
+      new Headers(init);
+    } catch (e) {
+      log.push(e.name);
+    }
+  }
+  try {
+    Headers();
+  } catch (e) {
+    log.push(e.name);
+  }
+  log.push(copy.has("set-cookie"), headers.has("set-cookie"));
+  log.push(Object.prototype.toString.call(headers));
+  log.push(Headers.prototype[Symbol.iterator] === Headers.prototype.entries);
+  assert(
+    log.join("\n"),
+    [
+      "2, 3",
+      "true",
+      "",
+      "set-cookie,a=1|set-cookie,b=2|x-a,4|x-b,2, 3",
+      "set-cookie,set-cookie,x-a,x-b",
+      "a=1,b=2,4,2, 3",
+      "y,5|z,6",
+      "set-cookie=a=1truetrue",
+      "set-cookie=b=2truetrue",
+      "x-a=4truetrue",
+      "x-b=2, 3truetrue",
+      "TypeError",
+      "TypeError",
+      "TypeError",
+      "TypeError",
+      "TypeError",
+      "TypeError",
+      "false",
+      "true",
+      "[object Headers]",
+      "true",
+    ].join("\n"),
+    "Headers",
+  );
+}
+
+// Request and Response bodies.
+{
+  const log = [];
+  const request = new Request("https://example.com/a", {
+    method: "post",
+    body: "hello",
+    headers: { "X-Test": "1" },
+  });
+  log.push(request.url, request.method, request.headers.get("content-type"));
+  const copy = new Request(request, { method: "Patch" });
+  log.push(copy.method, copy.headers.get("x-test"), request.bodyUsed);
+  copy.text().then((text) => log.push(text, copy.bodyUsed));
+  copy.text().catch((e) => log.push(e.name));
+  for (const init of [
+    { body: "x" },
+    { method: "HEAD", body: "x" },
+    { method: "CONNECT" },
+    { method: "a b" },
+  ]) {
+    try {
+      new Request("/b", init);
+    } catch (e) {
+      log.push(e.name);
+    }
+  }
+  const response = new Response('{"a":[1,2]}', {
+    status: 201,
+    statusText: "Created",
+    headers: [["Content-Type", "application/json"]],
+  });
+  log.push(
+    response.status,
+    response.ok,
+    response.statusText,
+    response.url === "",
+  );
+  log.push(response.headers.get("content-type"));
+  response.json().then((value) => log.push(value.a.length));
+  new Response("abc")
+    .arrayBuffer()
+    .then((buffer) => log.push(buffer.byteLength));
+  new Response(null, { status: 404 })
+    .text()
+    .then((text) => log.push(JSON.stringify(text)));
+  new Response("{").json().catch((e) => log.push(e.name));
+  for (const init of [{ status: 600 }, { status: 204 }, { statusText: "\n" }]) {
+    try {
+      new Response("x", init);
+    } catch (e) {
+      log.push(e.name);
+    }
+  }
+  checks.push(() =>
+    assert(
+      log.join(),
+      [
+        "https://example.com/a",
+        "POST",
+        "text/plain;charset=UTF-8",
+        "Patch",
+        "1",
+        "true",
+        "TypeError",
+        "TypeError",
+        "TypeError",
+        "TypeError",
+        "201",
+        "true",
+        "Created",
+        "true",
+        "application/json",
+        "RangeError",
+        "TypeError",
+        "TypeError",
+        "hello",
+        "true",
+        "TypeError",
+        "2",
+        "3",
+        '""',
+        "SyntaxError",
+      ].join(),
+      "Bodies",
+    ),
+  );
+}
+
+function afterJobs() {
+  for (const check of checks) check();
+}
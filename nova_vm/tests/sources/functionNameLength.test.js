@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+const desc = (o, key) => Object.getOwnPropertyDescriptor(o, key);
+
+// Length: ExpectedArgumentCount stops at the first initializer or rest.
+function f(a, b = 1, c) {}
+assertEq(f.name, "f", "declaration name");
+assertEq(f.length, 1, "declaration length");
+assertEq(((a, ...b) => {}).length, 1, "rest length");
+assertEq(function ({ a }, [b]) {}.length, 2, "destructuring length");
+assertEq(class {}.length, 0, "class without constructor length");
+assertEq(
+  class {
+    constructor(a, b) {}
+  }.length,
+  2,
+  "class constructor length",
+);
+assertEq(new Function("a", "b", "").length, 2, "dynamic function length");
+assertEq(new Function("a", "b", "").name, "anonymous", "dynamic function");
+
+// Name inference from bindings and assignments.
+const arrow = () => {};
+assertEq(arrow.name, "arrow", "arrow");
+let assigned;
+assigned = function () {};
+assertEq(assigned.name, "assigned", "assignment");
+const named = function inner() {};
+assertEq(named.name, "inner", "named function expression");
+const paren = function () {};
+assertEq(paren.name, "paren", "parenthesized");
+const sequence = (0, function () {});
+assertEq(sequence.name, "", "sequence does not infer");
+const asyncArrow = async () => {};
+assertEq(asyncArrow.name, "asyncArrow", "async arrow");
+const generator = function* () {};
+assertEq(generator.name, "generator", "generator expression");
+const E = class {};
+assertEq(E.name, "E", "class expression");
+const Named = class Inner {};
+assertEq(Named.name, "Inner", "named class expression");
+let { x = () => {} } = {};
+assertEq(x.name, "x", "object destructuring default");
+let [y = function () {}] = [];
+assertEq(y.name, "y", "array destructuring default");
+function parameterDefault(z = () => {}) {
+  return z.name;
+}
+assertEq(parameterDefault(), "z", "parameter default");
+let w;
+[w = () => {}] = [];
+assertEq(w.name, "w", "assignment pattern default");
+let u;
+u ??= () => {};
+assertEq(u.name, "u", "logical assignment");
+
+// Property keys and method kinds.
+const s = Symbol("s");
+const o = {
+  m() {},
+  get g() {
+    return 1;
+  },
+  set g(v) {},
+  ["c" + "k"]: () => {},
+  [s]: function () {},
+  [Symbol()]: function () {},
+  q: class {},
+  async am() {},
+  *gm() {},
+  1: () => {},
+  "str key": () => {},
+};
+assertEq(o.m.name, "m", "method");
+assertEq(desc(o, "g").get.name, "get g", "getter");
+assertEq(desc(o, "g").set.name, "set g", "setter");
+assertEq(o.ck.name, "ck", "computed key");
+assertEq(o[s].name, "[s]", "symbol key");
+assertEq(o[Object.getOwnPropertySymbols(o)[1]].name, "", "symbol without desc");
+assertEq(o.q.name, "q", "class property");
+assertEq(o.am.name, "am", "async method");
+assertEq(o.gm.name, "gm", "generator method");
+assertEq(o[1].name, "1", "numeric key");
+assertEq(o["str key"].name, "str key", "string key");
+const proto = { __proto__: function () {} };
+assertEq(Object.getPrototypeOf(proto).name, "", "__proto__ does not infer");
+
+class C {
+  static sm() {}
+  #p() {}
+  static getP(c) {
+    return c.#p;
+  }
+  static x = () => {};
+  y = function () {};
+  static [Symbol.iterator]() {}
+  static #sp() {}
+  static getSp() {
+    return C.#sp;
+  }
+}
+assertEq(C.name, "C", "class");
+assertEq(C.sm.name, "sm", "static method");
+assertEq(C.getP(new C()).name, "#p", "private method");
+assertEq(C.getSp().name, "#sp", "static private method");
+assertEq(C.x.name, "x", "static field");
+assertEq(new C().y.name, "y", "instance field");
+assertEq(C[Symbol.iterator].name, "[Symbol.iterator]", "well-known symbol");
+class S {
+  static name = "custom";
+}
+assertEq(S.name, "custom", "static name field");
+
+// Built-in functions.
+assertEq(desc(Map.prototype, "size").get.name, "get size", "builtin getter");
+assertEq(Array.prototype[Symbol.iterator].name, "values", "builtin alias");
+assertEq(
+  desc(Array, Symbol.species).get.name,
+  "get [Symbol.species]",
+  "builtin symbol getter",
+);
+
+// Property descriptors and order.
+assertEq(
+  JSON.stringify(desc(f, "name")),
+  '{"value":"f","writable":false,"enumerable":false,"configurable":true}',
+  "name descriptor",
+);
+assertEq(
+  JSON.stringify(desc(f, "length")),
+  '{"value":1,"writable":false,"enumerable":false,"configurable":true}',
+  "length descriptor",
+);
+assertEq(
+  Object.getOwnPropertyNames(function () {}).join(),
+  "length,name,prototype",
+  "function property order",
+);
+assertEq(
+  Object.getOwnPropertyNames(() => {}).join(),
+  "length,name",
+  "arrow property order",
+);
+assertEq(
+  Object.getOwnPropertyNames(
+    class {
+      static a() {}
+    },
+  ).join(),
+  "length,name,prototype,a",
+  "class property order",
+);
+function deleted() {}
+delete deleted.name;
+assertEq(deleted.name, "", "deleted name");
+
+// Bound functions.
+const bound = f.bind(null, 1);
+assertEq(bound.name, "bound f", "bound name");
+assertEq(bound.length, 0, "bound length");
+assertEq(bound.bind(null).name, "bound bound f", "bound bound name");
+function L(a, b, c) {}
+assertEq(L.bind(null, 1).length, 2, "bound partial length");
+Object.defineProperty(L, "length", { value: Infinity });
+assertEq(L.bind().length, Infinity, "bound infinite length");
+assertEq(L.bind(null, 1).length, Infinity, "bound infinite partial length");
+Object.defineProperty(L, "length", { value: 1000 });
+assertEq(L.bind(null, 1, 2).length, 998, "bound large length");
+assertEq(L.bind(null, 1, 2).name, "bound L", "bound large length name");
+Object.defineProperty(L, "length", { value: -5 });
+assertEq(L.bind().length, 0, "bound negative length");
+Object.defineProperty(L, "length", { value: 2.5 });
+assertEq(L.bind(null, 1).length, 1, "bound fractional length");
+Object.defineProperty(L, "name", { value: 5 });
+assertEq(L.bind().name, "bound ", "bound non-string name");
+
+// Lengths that exceed 255.
+const params = [];
+const args = [];
+for (let i = 0; i < 300; i++) {
+  params.push(`a${i}`);
+  args.push(i);
+}
+const many = Function(params.join(), "return a299;");
+assertEq(many.length, 300, "many parameters length");
+assertEq(many.name, "anonymous", "many parameters name");
+assertEq(many(...args), 299, "many parameters call");
+assertEq(
+  Object.getOwnPropertyNames(many).join(),
+  "length,name,prototype",
+  "many parameters property order",
+);
+const manyDefault = Function(`${params.join()}, z = 1`, "");
+assertEq(manyDefault.length, 300, "many parameters with default length");
+assertEq(manyDefault.bind().name, "bound anonymous", "many parameters bound");
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+// String replacement patterns.
+assertEq("abc".replace("b", "[$$]"), "a[$]c", "$$");
+assertEq("abc".replace("b", "[$&]"), "a[b]c", "$&");
+assertEq("abc".replace("b", "[$`]"), "a[a]c", "$`");
+assertEq("abc".replace("b", "[$']"), "a[c]c", "$'");
+assertEq("abc".replace("b", "$"), "a$c", "$ at end");
+assertEq("abc".replace("b", "x$"), "ax$c", "$ at end after text");
+assertEq("abc".replace("b", "$x$"), "a$x$c", "$ before other character");
+assertEq("abc".replace("b", "$1"), "a$1c", "$1 without captures");
+assertEq("abc".replace("b", "$0"), "a$0c", "$0 without captures");
+assertEq("abc".replace("b", "$<x>"), "a$<x>c", "$< without named captures");
+assertEq("abc".replace("b", "a$$b$&c"), "aa$bbcc", "mixed patterns");
+assertEq("abc".replace("", "$'"), "abcabc", "empty search with $'");
+assertEq("abc".replace("c", "$'"), "ab", "$' at end of string");
+
+// Positions are UTF-16 indices.
+assertEq("\u{1F600}xy".replace("x", "$`"), "\u{1F600}\u{1F600}y", "$` astral");
+assertEq("x\u{1F600}y".replace("x", "$'"), "\u{1F600}y\u{1F600}y", "$' astral");
+assertEq("\u{1F600}x".replace("x", () => "$&"), "\u{1F600}$&", "functional");
+let position = -1;
+"\u{1F600}x".replace("x", (_, p) => { position = p; });
+assertEq(position, 2, "functional position");
+
+// replaceAll substitutes every match.
+assertEq("aXbXc".replaceAll("X", "[$&$`]"), "a[Xa]b[XaXb]c", "replaceAll");
+assertEq("ab".replaceAll("", "$'|"), "ab|ab|b|", "replaceAll empty search");
+assertEq("aaa".replaceAll("aa", "$$"), "$a", "replaceAll non-overlapping");
+const positions = [];
+"\u{1F600}x\u{1F600}x".replaceAll("x", (_, p) => positions.push(p));
+assertEq(positions.join(), "2,5", "replaceAll positions");
+
+// Lone surrogates survive substitution.
+const lone = "\uD800";
+assertEq("a".replace("a", lone + "$&"), lone + "a", "lone surrogate template");
+assertEq("\uD800a".replace("a", "$`"), "\uD800\uD800", "lone surrogate $`");
+assertEq("\uD83D".replace("\uD83D", "$&\uDE00"), "\u{1F600}", "surrogate pair");
+
+// RegExp replacement patterns.
+assertEq("abc".replace(/(b)/, "[$1]"), "a[b]c", "$1");
+assertEq("abc".replace(/(b)/, "[$01]"), "a[b]c", "$01");
+assertEq("abc".replace(/(b)/, "[$10]"), "a[b0]c", "$10 with one group");
+assertEq("abc".replace(/(b)/, "[$2]"), "a[$2]c", "$2 out of range");
+assertEq("abc".replace(/(b)/, "[$00]"), "a[$00]c", "$00");
+assertEq("abc".replace(/(b)|(z)/, "[$2]"), "a[]c", "undefined capture");
+
+// Named captures are read from the groups object of the exec result.
+function namedRegExp(groups) {
+  const re = /b/;
+  re.exec = function () {
+    const result = ["b"];
+    result.index = 1;
+    result.groups = groups;
+    return result;
+  };
+  return re;
+}
+const groups = { n: "B", num: 1 };
+assertEq("abc".replace(namedRegExp(groups), "[$<n>]"), "a[B]c", "$<n>");
+assertEq("abc".replace(namedRegExp(groups), "[$<num>]"), "a[1]c", "ToString");
+assertEq("abc".replace(namedRegExp(groups), "[$<m>]"), "a[]c", "missing group");
+assertEq("abc".replace(namedRegExp(groups), "[$<n]"), "a[$<n]c", "$< no >");
+const noGroups = namedRegExp(undefined);
+assertEq("abc".replace(noGroups, "[$<n>]"), "a[$<n>]c", "undefined groups");
+assertEq("abc".replace(/(b)/, "$"), "a$c", "$ at end with RegExp");
+assertEq("aXbX".replace(/X/g, "$'"), "abXb", "global $'");
+
+// String.raw
+assertEq(String.raw`a\n${1}b`, "a\\n1b", "String.raw template");
+assertEq(String.raw({ raw: ["x", "y", "z"] }, 1), "x1yz", "few substitutions");
+assertEq(String.raw({ raw: ["x"] }, 1, 2), "x", "extra substitutions");
+assertEq(String.raw({ raw: [] }, 1), "", "empty raw");
+const arrayLike = { length: 2, 0: 1, 1: 2 };
+assertEq(String.raw({ raw: arrayLike }, "-"), "1-2", "array-like");
+assertEq(String.raw({ raw: "abc" }, "-", "+"), "a-b+c", "string raw");
+let threw = false;
+try {
+  String.raw({});
+} catch (err) {
+  threw = err instanceof TypeError;
+}
+assertEq(threw, true, "String.raw without raw");
@@ -0,0 +1,270 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Checks the invariants of the essential internal methods of objects,
+// https://tc39.es/ecma262/#sec-invariants-of-the-essential-internal-methods,
+// against every kind of exotic object. The internal methods are called
+// through Reflect, which calls them directly.
+//
+// This file is evaluated as a Module that imports the namespace of the
+// "host:exports" module.
+
+import * as namespace from "host:exports";
+
+const violations = [];
+
+function describeKey(key) {
+  return typeof key === "symbol" ? key.toString() : JSON.stringify(key);
+}
+
+function report(name, method, key, message) {
+  violations.push(
+    `${name}: [[${method}]]${key === undefined ? "" : `(${describeKey(key)})`} ${message}`,
+  );
+}
+
+function isObject(value) {
+  return (typeof value === "object" && value !== null) || typeof value === "function";
+}
+
+function isCompleteDescriptor(desc) {
+  if (typeof desc.enumerable !== "boolean" || typeof desc.configurable !== "boolean") {
+    return false;
+  }
+  if ("value" in desc || "writable" in desc) {
+    return "value" in desc && typeof desc.writable === "boolean" && !("get" in desc) &&
+      !("set" in desc);
+  }
+  return "get" in desc && "set" in desc;
+}
+
+const probeKeys = [
+  "0",
+  "1",
+  "2",
+  "3",
+  "-0",
+  "1.5",
+  "4294967294",
+  "4294967295",
+  "9007199254740992",
+  "length",
+  "prototype",
+  "name",
+  "callee",
+  "caller",
+  "constructor",
+  "__proto__",
+  "x",
+  Symbol.iterator,
+  Symbol.toStringTag,
+  Symbol("unique"),
+];
+
+// A value that no property has.
+const sentinel = { sentinel: true };
+
+function checkObject(name, object) {
+  // [[GetPrototypeOf]]: the result is an Object or null.
+  const proto = Reflect.getPrototypeOf(object);
+  if (proto !== null && !isObject(proto)) {
+    report(name, "GetPrototypeOf", undefined, "returned a non-object");
+  }
+  const extensible = Reflect.isExtensible(object);
+  if (typeof extensible !== "boolean") {
+    report(name, "IsExtensible", undefined, "returned a non-boolean");
+  }
+
+  // [[OwnPropertyKeys]]: a List of unique Strings and Symbols.
+  const ownKeys = Reflect.ownKeys(object);
+  const ownKeySet = new Set();
+  for (const key of ownKeys) {
+    if (typeof key !== "string" && typeof key !== "symbol") {
+      report(name, "OwnPropertyKeys", undefined, `returned ${String(key)}`);
+    }
+    if (ownKeySet.has(key)) {
+      report(name, "OwnPropertyKeys", key, "returned a duplicate key");
+    }
+    ownKeySet.add(key);
+  }
+
+  for (const key of [...ownKeys, ...probeKeys]) {
+    let desc;
+    try {
+      desc = Reflect.getOwnPropertyDescriptor(object, key);
+    } catch (err) {
+      if (name.startsWith("module namespace") && err instanceof ReferenceError) {
+        // Uninitialized bindings throw.
+        continue;
+      }
+      report(name, "GetOwnProperty", key, `threw ${err}`);
+      continue;
+    }
+    if (desc === undefined) {
+      // An absent property cannot be reported by [[OwnPropertyKeys]] of a
+      // non-extensible object, and cannot be added to it.
+      if (!extensible) {
+        if (ownKeySet.has(key)) {
+          report(name, "OwnPropertyKeys", key, "listed a missing property of a non-extensible object");
+        }
+        if (Reflect.defineProperty(object, key, { value: 1, configurable: true })) {
+          report(name, "DefineOwnProperty", key, "added a property to a non-extensible object");
+        }
+        if (Reflect.getOwnPropertyDescriptor(object, key) !== undefined) {
+          report(name, "GetOwnProperty", key, "reported a new property of a non-extensible object");
+        }
+      }
+      continue;
+    }
+    if (!isCompleteDescriptor(desc)) {
+      report(name, "GetOwnProperty", key, "returned an incomplete descriptor");
+      continue;
+    }
+    if (!extensible && !ownKeySet.has(key)) {
+      report(name, "OwnPropertyKeys", key, "omitted a property of a non-extensible object");
+    }
+    // [[HasProperty]] is true for own properties.
+    if (!Reflect.has(object, key)) {
+      report(name, "HasProperty", key, "returned false for an own property");
+    }
+    if (desc.configurable) {
+      continue;
+    }
+    // Non-configurable properties must be listed and cannot be removed,
+    // reconfigured or, if non-writable, changed.
+    if (!ownKeySet.has(key)) {
+      report(name, "OwnPropertyKeys", key, "omitted a non-configurable property");
+    }
+    if (Reflect.deleteProperty(object, key)) {
+      report(name, "Delete", key, "deleted a non-configurable property");
+    }
+    if (Reflect.defineProperty(object, key, { configurable: true })) {
+      report(name, "DefineOwnProperty", key, "made a non-configurable property configurable");
+    }
+    if (Reflect.defineProperty(object, key, { enumerable: !desc.enumerable })) {
+      report(name, "DefineOwnProperty", key, "changed the enumerability of a non-configurable property");
+    }
+    if ("value" in desc && !desc.writable) {
+      // Array "length" converts the value to a Number, so use a different
+      // Number for numeric values.
+      const other = typeof desc.value === "number" ? desc.value + 1 : sentinel;
+      if (Reflect.defineProperty(object, key, { value: other })) {
+        report(name, "DefineOwnProperty", key, "changed a non-writable, non-configurable value");
+      }
+      if (Reflect.defineProperty(object, key, { writable: true })) {
+        report(name, "DefineOwnProperty", key, "made a non-writable, non-configurable property writable");
+      }
+      if (Reflect.set(object, key, other)) {
+        report(name, "Set", key, "changed a non-writable, non-configurable value");
+      }
+      if (!Object.is(Reflect.get(object, key), desc.value)) {
+        report(name, "Get", key, "disagreed with [[GetOwnProperty]] on a non-writable, non-configurable value");
+      }
+    }
+    if ("get" in desc) {
+      if (desc.set === undefined && Reflect.set(object, key, sentinel)) {
+        report(name, "Set", key, "succeeded on a non-configurable accessor without a setter");
+      }
+      if (desc.get === undefined && Reflect.get(object, key) !== undefined) {
+        report(name, "Get", key, "returned a value from a non-configurable accessor without a getter");
+      }
+    }
+    // Attributes of non-configurable properties cannot change on their own.
+    const again = Reflect.getOwnPropertyDescriptor(object, key);
+    if (
+      again === undefined || again.configurable || again.enumerable !== desc.enumerable ||
+      ("value" in desc && !desc.writable && (!Object.is(again.value, desc.value) || again.writable))
+    ) {
+      report(name, "GetOwnProperty", key, "changed a non-configurable property");
+    }
+  }
+
+  if (!extensible) {
+    // [[SetPrototypeOf]] can only set the same prototype on a non-extensible
+    // object, and [[IsExtensible]] stays false.
+    if (Reflect.setPrototypeOf(object, proto === null ? {} : null)) {
+      report(name, "SetPrototypeOf", undefined, "changed the prototype of a non-extensible object");
+    }
+    if (!Reflect.setPrototypeOf(object, proto)) {
+      report(name, "SetPrototypeOf", undefined, "failed to set the same prototype on a non-extensible object");
+    }
+    if (Reflect.getPrototypeOf(object) !== proto) {
+      report(name, "GetPrototypeOf", undefined, "changed for a non-extensible object");
+    }
+    if (Reflect.isExtensible(object)) {
+      report(name, "IsExtensible", undefined, "became true");
+    }
+  }
+}
+
+// Check the invariants of a fresh object from `create`, and again after
+// preventing extensions and after freezing it where possible.
+function check(name, create) {
+  checkObject(name, create());
+  const object = create();
+  if (Reflect.preventExtensions(object)) {
+    if (Reflect.isExtensible(object)) {
+      report(name, "PreventExtensions", undefined, "returned true but the object is extensible");
+    }
+    checkObject(`${name} (non-extensible)`, object);
+  }
+  const frozen = create();
+  let isFrozen = false;
+  try {
+    Object.freeze(frozen);
+    isFrozen = true;
+  } catch {
+    // Some exotic objects, eg. non-empty TypedArrays, cannot be frozen.
+  }
+  if (isFrozen) {
+    checkObject(`${name} (frozen)`, frozen);
+  }
+}
+
+check("ordinary object", () => ({ a: 1, get b() { return 2; }, [Symbol.iterator]: 3 }));
+check("Array", () => [1, 2, 3]);
+check("Array with holes", () => [1, , 3]);
+check("Array with non-writable length", () => {
+  const array = [1, 2];
+  Object.defineProperty(array, "length", { writable: false });
+  return array;
+});
+check("Array with accessor element", () => {
+  const array = [1];
+  Object.defineProperty(array, "1", { get() { return 2; } });
+  return array;
+});
+check("String exotic object", () => new String("abc"));
+check("empty String exotic object", () => new String(""));
+check("mapped arguments object", function () {
+  return (function (a, b) {
+    return arguments;
+  })(1, 2, 3);
+});
+check("unmapped arguments object", function () {
+  return (function (a, b) {
+    "use strict";
+    return arguments;
+  })(1, 2, 3);
+});
+check("arguments object with deleted mapping", function () {
+  return (function (a, b) {
+    delete arguments[0];
+    return arguments;
+  })(1, 2);
+});
+check("bound function", () => function f(a, b) {}.bind(null, 1));
+check("arrow function", () => (a) => a);
+check("class constructor", () => class A { static x = 1; });
+check("Uint8Array", () => new Uint8Array([1, 2, 3]));
+check("empty Float64Array", () => new Float64Array(0));
+check("length-tracking Int16Array", () =>
+  new Int16Array(new ArrayBuffer(4, { maxByteLength: 8 })));
+check("Proxy", () => new Proxy({ a: 1 }, {}));
+check("Proxy of Array", () => new Proxy([1, 2], {}));
+check("module namespace", () => namespace);
+
+if (violations.length > 0) {
+  throw new Error(`Internal method invariant violations:\n${violations.join("\n")}`);
+}
@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+function check(f) {
+  try {
+    f();
+    return "ok";
+  } catch (err) {
+    return err.constructor.name;
+  }
+}
+
+// The Intl namespace.
+assert(Object.prototype.toString.call(Intl), "[object Intl]", "Intl tag");
+assert(typeof Intl.NumberFormat, "function", "Intl.NumberFormat");
+assert(typeof Intl.DateTimeFormat, "function", "Intl.DateTimeFormat");
+assert(
+  Intl.getCanonicalLocales(["EN-us", "de-ch", "en-US"]).join(),
+  "en-US,de-CH",
+  "getCanonicalLocales",
+);
+assert(
+  check(() => Intl.getCanonicalLocales("not a locale")),
+  "RangeError",
+  "getCanonicalLocales with invalid locale",
+);
+
+// NumberFormat formats numbers.
+assert(
+  new Intl.NumberFormat("en-US").format(1234567.891),
+  "1,234,567.891",
+  "en-US number",
+);
+assert(
+  new Intl.NumberFormat("de-DE").format(1234567.891),
+  "1.234.567,891",
+  "de-DE number",
+);
+assert(
+  new Intl.NumberFormat("en-US", { minimumFractionDigits: 2 }).format(5),
+  "5.00",
+  "minimumFractionDigits",
+);
+assert(
+  new Intl.NumberFormat("en-US", { maximumSignificantDigits: 3 }).format(
+    123456,
+  ),
+  "123,000",
+  "maximumSignificantDigits",
+);
+assert(
+  new Intl.NumberFormat("en-US", { useGrouping: false }).format(123456),
+  "123456",
+  "useGrouping",
+);
+assert(
+  new Intl.NumberFormat("en-US", { style: "percent" }).format(0.256),
+  "26%",
+  "percent style",
+);
+assert(
+  new Intl.NumberFormat("en-US").format(12345678901234567890n),
+  "12,345,678,901,234,567,890",
+  "BigInt",
+);
+assert((1234.5).toLocaleString("en-US"), "1,234.5", "toLocaleString");
+
+// The NumberFormat format getter returns a bound function.
+{
+  const nf = new Intl.NumberFormat("en-US");
+  const format = nf.format;
+  assert(format, nf.format, "format is cached");
+  assert(format.length, 1, "format length");
+  assert([1000, 2000].map(format).join(" "), "1,000 2,000", "bound format");
+}
+
+// NumberFormat resolvedOptions.
+{
+  const o = new Intl.NumberFormat("en-US").resolvedOptions();
+  assert(
+    [
+      o.locale,
+      o.style,
+      o.minimumFractionDigits,
+      o.maximumFractionDigits,
+      o.useGrouping,
+    ].join(),
+    "en-US,decimal,0,3,auto",
+    "NumberFormat resolvedOptions",
+  );
+}
+
+// NumberFormat rejects invalid options and receivers.
+assert(
+  check(
+    () => new Intl.NumberFormat("en-US", { minimumFractionDigits: 200 }),
+  ),
+  "RangeError",
+  "minimumFractionDigits out of range",
+);
+assert(
+  check(() => Intl.NumberFormat.prototype.format),
+  "TypeError",
+  "format getter on prototype",
+);
+
+// DateTimeFormat formats dates.
+{
+  const date = Date.UTC(2020, 11, 31, 13, 5);
+  assert(
+    new Intl.DateTimeFormat("en-US", { timeZone: "UTC" }).format(date),
+    "12/31/2020",
+    "en-US date",
+  );
+  assert(
+    new Intl.DateTimeFormat("en-US", {
+      timeZone: "UTC",
+      hour: "numeric",
+      minute: "2-digit",
+    }).format(date),
+    "1:05\u202fPM",
+    "en-US time",
+  );
+  assert(
+    new Date(date).toLocaleDateString("de-DE", { timeZone: "UTC" }),
+    "31.12.2020",
+    "toLocaleDateString",
+  );
+  assert(
+    new Date(NaN).toLocaleString("en-US"),
+    "Invalid Date",
+    "toLocaleString of invalid Date",
+  );
+}
+
+// DateTimeFormat resolvedOptions.
+{
+  const o = new Intl.DateTimeFormat("en-US", {
+    timeZone: "utc",
+  }).resolvedOptions();
+  assert(
+    [o.locale, o.calendar, o.timeZone, o.year, o.month, o.day].join(),
+    "en-US,gregory,UTC,numeric,numeric,numeric",
+    "DateTimeFormat resolvedOptions",
+  );
+  assert(
+    check(() => new Intl.DateTimeFormat("en-US").format(8.64e15 + 1)),
+    "RangeError",
+    "format of out of range time",
+  );
+}
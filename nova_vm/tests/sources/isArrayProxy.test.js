@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function wrap(target, depth) {
+  for (let i = 0; i < depth; i++) {
+    target = new Proxy(target, {});
+  }
+  return target;
+}
+
+// Array.isArray looks through any number of Proxies to the final target.
+{
+  assertEq(Array.isArray(wrap([], 1)), true, "proxied array");
+  assertEq(Array.isArray(wrap([], 5)), true, "nested proxied array");
+  assertEq(Array.isArray(wrap({}, 5)), false, "nested proxied object");
+  assertEq(Array.isArray(wrap(function () {}, 2)), false, "proxied function");
+  assertEq(Array.isArray(wrap([], 100000)), true, "deeply proxied array");
+}
+
+// IsArray never consults the Proxy's traps.
+{
+  let trapped = false;
+  const handler = new Proxy({}, {
+    get() {
+      trapped = true;
+    },
+  });
+  assertEq(Array.isArray(new Proxy([], handler)), true, "trapless");
+  assertEq(trapped, false, "no traps");
+}
+
+// concat spreads proxied arrays unless isConcatSpreadable says otherwise.
+{
+  const proxy = wrap([1, 2], 3);
+  assertEq([0].concat(proxy).join(), "0,1,2", "concat proxied array");
+  assertEq([0].concat(wrap({ 0: 1, length: 1 }, 2)).length, 2, "concat object");
+  const unspreadable = [1, 2];
+  unspreadable[Symbol.isConcatSpreadable] = false;
+  assertEq([0].concat(wrap(unspreadable, 2)).length, 2, "not spreadable");
+}
+
+// JSON.stringify serializes proxied arrays as arrays.
+{
+  assertEq(JSON.stringify({ a: wrap([1, [2]], 2) }), '{"a":[1,[2]]}', "value");
+  const replacer = wrap(["b"], 2);
+  assertEq(JSON.stringify({ a: 1, b: 2 }, replacer), '{"b":2}', "replacer");
+}
@@ -0,0 +1,240 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function createIterator(values, log, returnValue = {}) {
+  let i = 0;
+  return {
+    [Symbol.iterator]() {
+      return this;
+    },
+    next() {
+      log.push("next");
+      return i < values.length
+        ? { value: values[i++], done: false }
+        : { value: undefined, done: true };
+    },
+    return() {
+      log.push(`return(${arguments.length})`);
+      if (returnValue instanceof Error) {
+        throw returnValue;
+      }
+      return returnValue;
+    },
+  };
+}
+
+function assertLog(name, expected, callback) {
+  const log = [];
+  try {
+    callback(log);
+  } catch (err) {
+    log.push(`throw ${err.constructor.name}`);
+  }
+  if (log.join() !== expected) {
+    throw new Error(`${name}: expected '${expected}', got '${log.join()}'`);
+  }
+}
+
+function thrower(ErrorConstructor = RangeError) {
+  throw new ErrorConstructor();
+}
+
+const throwingGetter = {
+  get a() {
+    thrower();
+  },
+};
+
+const throwingSetter = {
+  set x(v) {
+    thrower();
+  },
+};
+
+// Partially consumed iterators are closed, exhausted ones are not.
+assertLog("binding", "next,return(0)", (log) => {
+  const [a] = createIterator([1, 2], log);
+});
+assertLog("binding with default", "next,return(0)", (log) => {
+  const [a = thrower()] = createIterator([1, 2], log);
+});
+assertLog("assignment", "next,return(0)", (log) => {
+  let a;
+  [a] = createIterator([1, 2], log);
+});
+assertLog("parameter", "next,return(0)", (log) => {
+  (function ([a]) {})(createIterator([1, 2], log));
+});
+assertLog("elision", "next,next,return(0)", (log) => {
+  const [, ,] = createIterator([1, 2, 3], log);
+});
+assertLog("empty pattern", "return(0)", (log) => {
+  const [] = createIterator([1], log);
+});
+assertLog("exhausted", "next,next", (log) => {
+  const [a, b] = createIterator([1], log);
+});
+assertLog("rest", "next,next,next", (log) => {
+  const [...rest] = createIterator([1, 2], log);
+});
+
+// Abrupt completions inside the pattern close the iterator once, and the
+// original error is rethrown even if return throws or returns a non-object.
+for (const returnValue of [{}, 1, new TypeError()]) {
+  assertLog("throwing default", "next,return(0),throw RangeError", (log) => {
+    const [a = thrower()] = createIterator([undefined], log, returnValue);
+  });
+  assertLog("throwing getter", "next,return(0),throw RangeError", (log) => {
+    const [{ a }] = createIterator([throwingGetter], log, returnValue);
+  });
+  assertLog("non-object element", "next,return(0),throw TypeError", (log) => {
+    const [{ a }] = createIterator([null], log, returnValue);
+  });
+  assertLog("throwing setter", "next,return(0),throw RangeError", (log) => {
+    [throwingSetter.x] = createIterator([1], log, returnValue);
+  });
+  assertLog("throwing target", "return(0),throw RangeError", (log) => {
+    const object = {};
+    [object[thrower()]] = createIterator([1], log, returnValue);
+  });
+  assertLog("for-of body", "next,return(0),throw RangeError", (log) => {
+    for (const x of createIterator([1, 2], log, returnValue)) {
+      thrower();
+    }
+  });
+  assertLog("for-of binding", "next,return(0),throw TypeError", (log) => {
+    for (const [x] of createIterator([null], log, returnValue)) {
+    }
+  });
+  assertLog("for-of target", "next,return(0),throw RangeError", (log) => {
+    for (throwingSetter.x of createIterator([1], log, returnValue)) {
+    }
+  });
+}
+
+// A rest element exhausts the iterator: it is not closed if the rest target
+// throws.
+assertLog("throwing rest target", "next,next,throw RangeError", (log) => {
+  [...throwingSetter.x] = createIterator([1], log);
+});
+
+// Errors from the iterator itself do not close it.
+assertLog("throwing next", "throw RangeError", (log) => {
+  const iterator = createIterator([1], log);
+  iterator.next = () => thrower();
+  const [a] = iterator;
+});
+assertLog("throwing done", "throw RangeError", (log) => {
+  const iterator = createIterator([1], log);
+  iterator.next = () => ({
+    get done() {
+      thrower();
+    },
+  });
+  const [a] = iterator;
+});
+
+// Normal completions close the iterator and propagate errors from return.
+const closingPatterns = {
+  binding(iterator) {
+    const [a] = iterator;
+  },
+  "binding with default"(iterator) {
+    const [a = 1] = iterator;
+  },
+  assignment(iterator) {
+    let a;
+    [a] = iterator;
+  },
+  parameter(iterator) {
+    (function ([a]) {})(iterator);
+  },
+  "for-of break"(iterator) {
+    for (const x of iterator) {
+      break;
+    }
+  },
+};
+for (const [name, callback] of Object.entries(closingPatterns)) {
+  assertLog(`${name} non-object`, "next,return(0),throw TypeError", (log) => {
+    callback(createIterator([1, 2], log, 1));
+  });
+  assertLog(`${name} throwing`, "next,return(0),throw TypeError", (log) => {
+    callback(createIterator([1, 2], log, new TypeError()));
+  });
+}
+assertLog("return not callable", "next,throw TypeError", (log) => {
+  const iterator = createIterator([1, 2], log);
+  iterator.return = 1;
+  const [a] = iterator;
+});
+assertLog("return null", "next", (log) => {
+  const iterator = createIterator([1, 2], log);
+  iterator.return = null;
+  const [a] = iterator;
+});
+
+// Control flow out of for-of closes the iterator.
+assertLog("for-of return", "next,return(0)", (log) => {
+  (function () {
+    for (const x of createIterator([1, 2], log)) {
+      return;
+    }
+  })();
+});
+assertLog("for-of continue outer", "next,return(0),next,return(0)", (log) => {
+  outer: for (const y of [1, 2]) {
+    for (const x of createIterator([1, 2], log)) {
+      continue outer;
+    }
+  }
+});
+assertLog(
+  "nested for-of",
+  "next,next,return(0),return(0),throw RangeError",
+  (log) => {
+    for (const a of createIterator([1, 2], log)) {
+      for (const b of createIterator([1], log)) {
+        thrower();
+      }
+    }
+  },
+);
+assertLog("generator return", "next,return(0)", (log) => {
+  function* generator() {
+    for (const x of createIterator([1, 2], log)) {
+      yield x;
+    }
+  }
+  const iterator = generator();
+  iterator.next();
+  iterator.return();
+});
+assertLog("yield* without throw", "next,return(0),throw TypeError", (log) => {
+  function* generator() {
+    yield* createIterator([1, 2], log);
+  }
+  const iterator = generator();
+  iterator.next();
+  iterator.throw(new RangeError());
+});
+
+// Builtins close the iterator when consuming a value fails.
+assertLog("Array.from", "next,return(0),throw RangeError", (log) => {
+  Array.from(createIterator([1], log), () => thrower());
+});
+assertLog("Object.fromEntries", "next,return(0),throw TypeError", (log) => {
+  Object.fromEntries(createIterator([1], log));
+});
+assertLog("Map", "next,return(0),throw TypeError", (log) => {
+  new Map(createIterator([1], log));
+});
+assertLog("Set", "next,return(0),throw RangeError", (log) => {
+  class ThrowingSet extends Set {
+    add() {
+      thrower();
+    }
+  }
+  new ThrowingSet(createIterator([1], log));
+});
@@ -0,0 +1,129 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(f, ErrorType, name) {
+  try {
+    f();
+  } catch (err) {
+    if (!(err instanceof ErrorType)) {
+      throw new Error(`${name} failed: threw ${err}`);
+    }
+    return;
+  }
+  throw new Error(`${name} failed: did not throw`);
+}
+
+// Numbers keep their sign and overflow to infinities.
+assertEq(Object.is(JSON.parse("-0"), -0), true, "negative zero");
+assertEq(Object.is(JSON.parse("-0.0"), -0), true, "negative zero fraction");
+assertEq(JSON.parse("1e400"), Infinity, "overflow");
+assertEq(JSON.parse("-1e400"), -Infinity, "negative overflow");
+assertEq(JSON.parse("1e-400"), 0, "underflow");
+assertEq(JSON.parse("123456789012345678"), 123456789012345680, "long integer");
+assertEq(JSON.parse("9007199254740993"), 9007199254740992, "rounded integer");
+assertEq(JSON.parse("0.1"), 0.1, "fraction");
+assertEq(JSON.parse("-12.5E+2"), -1250, "exponent");
+
+// Strings are decoded to UTF-16, surrogates included.
+assertEq(JSON.parse('"\\ud83d\\ude00"'), "😀", "escaped pair");
+assertEq(JSON.parse('"\\ud800"').charCodeAt(0), 0xd800, "escaped lone lead");
+assertEq(JSON.parse('"\\udc00x"').charCodeAt(0), 0xdc00, "escaped lone trail");
+assertEq(JSON.parse('"\ud800"').charCodeAt(0), 0xd800, "raw lone surrogate");
+assertEq(JSON.parse('{"\ud800":1}')["\ud800"], 1, "lone surrogate key");
+assertEq(
+  JSON.parse('"a\\"\\\\\\/\\b\\f\\n\\r\\tb"'),
+  'a"\\/\b\f\n\r\tb',
+  "escapes",
+);
+assertEq(JSON.parse('"é中"'), "é中", "non-ASCII");
+
+// Repeated keys keep their first position and their last value.
+{
+  const object = JSON.parse('{"a":1,"b":2,"a":3}');
+  assertEq(Object.keys(object).join(), "a,b", "duplicate key order");
+  assertEq(object.a, 3, "duplicate key value");
+  let text = "{";
+  for (let i = 0; i < 40; i++) text += `"k${i % 20}":${i},`;
+  const many = JSON.parse(text + '"z":0}');
+  assertEq(Object.keys(many).length, 21, "many duplicate keys");
+  assertEq(many.k0, 20, "many duplicate keys value");
+}
+
+// Keys that only look like integers stay strings.
+{
+  const object = JSON.parse('{"b":0,"01":1,"1":2,"-0":3,"0":4}');
+  assertEq(Object.keys(object).join(), "0,1,b,01,-0", "integer key order");
+  assertEq(object["01"], 1, "leading zero key");
+  assertEq(object[1], 2, "integer key");
+}
+
+// "__proto__" members are ordinary own properties.
+{
+  const object = JSON.parse('{"__proto__":null,"__proto__":[1]}');
+  assertEq(Object.getPrototypeOf(object), Object.prototype, "__proto__ proto");
+  assertEq(Array.isArray(object.__proto__), true, "__proto__ own property");
+  assertEq(Object.keys(object).join(), "__proto__", "__proto__ key");
+}
+
+// Nested containers.
+{
+  const value = JSON.parse(' { "a" : [ 1 , { "b" : [ ] } , { } ] } ');
+  assertEq(JSON.stringify(value), '{"a":[1,{"b":[]},{}]}', "nesting");
+  assertEq(Array.isArray(value.a), true, "nested array");
+  assertEq(value.a.length, 3, "nested array length");
+  const depth = 100000;
+  const deep = JSON.parse("[".repeat(depth) + "]".repeat(depth));
+  let level = 0;
+  for (let v = deep; v.length !== 0; v = v[0]) level++;
+  assertEq(level, depth - 1, "deep nesting");
+}
+
+// Malformed texts throw SyntaxErrors.
+for (const text of [
+  "",
+  " ",
+  "[1,]",
+  '{"a":1,}',
+  "{a:1}",
+  '{"a" 1}',
+  "01",
+  "1.",
+  ".5",
+  "+1",
+  "1e",
+  "-",
+  "tru",
+  "nul",
+  "NaN",
+  '"\\x"',
+  '"\\u12g4"',
+  '"\\u12',
+  '"abc',
+  '"\u0001"',
+  "[1] 2",
+  "[",
+  "{",
+  "'a'",
+]) {
+  assertThrows(() => JSON.parse(text), SyntaxError, `JSON.parse(${text})`);
+}
+
+// The argument is converted with ToString, and the reviver walks the result.
+{
+  assertEq(JSON.parse({ toString: () => "[7]" })[0], 7, "ToString");
+  assertEq(JSON.parse(12), 12, "number argument");
+  const log = [];
+  const value = JSON.parse('{"a":[1,2],"b":{"c":3}}', function (key, v) {
+    log.push(key);
+    return typeof v === "number" ? v * 10 : v;
+  });
+  assertEq(log.join(), "0,1,a,c,b,", "reviver order");
+  assertEq(JSON.stringify(value), '{"a":[10,20],"b":{"c":30}}', "reviver");
+}
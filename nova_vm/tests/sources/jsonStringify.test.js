@@ -0,0 +1,143 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(f, ErrorType, name) {
+  try {
+    f();
+  } catch (err) {
+    if (!(err instanceof ErrorType)) {
+      throw new Error(`${name} failed: threw ${err}`);
+    }
+    return;
+  }
+  throw new Error(`${name} failed: did not throw`);
+}
+
+// Replacer functions see the holder as this, after toJSON has run.
+{
+  const log = [];
+  const value = { a: { toJSON: (key) => `toJSON:${key}` }, b: [1] };
+  const result = JSON.stringify(value, function (key, v) {
+    log.push(`${key}:${Array.isArray(this) ? "array" : typeof this}`);
+    return typeof v === "number" ? v * 2 : v;
+  });
+  assertEq(result, '{"a":"toJSON:a","b":[2]}', "replacer function");
+  assertEq(log.join(), ":object,a:object,b:object,0:array", "replacer holders");
+}
+
+// Replacer arrays pick keys in order, deduplicated, ignoring other values.
+{
+  const replacer = ["b", 1, "a", "b", new String("c"), new Number(1), {}, true];
+  const value = { a: 1, b: 2, 1: 3, c: { a: 4, b: 5 }, d: 6 };
+  assertEq(
+    JSON.stringify(value, replacer),
+    '{"b":2,"1":3,"a":1,"c":{"b":5,"a":4}}',
+    "replacer array",
+  );
+  assertEq(JSON.stringify([1, 2], ["0"]), "[1,2]", "replacer array on arrays");
+  const arrayLike = { length: 1, 0: "a" };
+  assertEq(JSON.stringify({ a: 1, b: 2 }, arrayLike), '{"a":1,"b":2}', "like");
+}
+
+// Number and String gaps are clamped to ten code units.
+{
+  assertEq(JSON.stringify([1], null, 20), "[\n          1\n]", "gap number");
+  assertEq(JSON.stringify([1], null, 2.9), "[\n  1\n]", "gap fraction");
+  assertEq(JSON.stringify([1], null, -1), "[1]", "gap negative");
+  assertEq(JSON.stringify([1], null, true), "[1]", "gap boolean");
+  const gap = "é".repeat(12);
+  assertEq(JSON.stringify([1], null, gap), `[\n${gap.slice(0, 10)}1\n]`, "é");
+  const cut = JSON.stringify([1], null, "123456789\u{1F600}");
+  assertEq(cut, "[\n123456789\ud83d1\n]", "gap cuts surrogate pair");
+  const number = new Number(1);
+  number.valueOf = () => 3;
+  assertEq(JSON.stringify([1], null, number), "[\n   1\n]", "gap Number");
+  const string = new String("ab");
+  string.toString = () => "-";
+  assertEq(JSON.stringify([1], null, string), "[\n-1\n]", "gap String");
+  const empty = JSON.stringify({ a: [], b: {} }, null, 1);
+  assertEq(empty, '{\n "a": [],\n "b": {}\n}', "gap empty containers");
+}
+
+// toJSON is called with the property key, including for the root value.
+{
+  const root = { toJSON: (key) => `root:${JSON.stringify(key)}` };
+  assertEq(JSON.stringify(root), '"root:\\"\\""', "root toJSON");
+  const uncallable = JSON.stringify({ toJSON: 1 });
+  assertEq(uncallable, '{"toJSON":1}', "uncallable toJSON");
+  assertEq(JSON.stringify(new Date(0)), '"1970-01-01T00:00:00.000Z"', "Date");
+}
+
+// BigInts throw unless a toJSON method handles them.
+{
+  assertThrows(() => JSON.stringify({ a: 1n }), TypeError, "BigInt");
+  assertThrows(() => JSON.stringify(Object(1n)), TypeError, "BigInt object");
+  BigInt.prototype.toJSON = function () {
+    return `${this}n`;
+  };
+  assertEq(JSON.stringify([1n]), '["1n"]', "BigInt toJSON");
+  delete BigInt.prototype.toJSON;
+}
+
+// Cycles throw a TypeError, but shared acyclic references are fine.
+{
+  const object = {};
+  object.self = object;
+  assertThrows(() => JSON.stringify(object), TypeError, "cyclic object");
+  const array = [];
+  array.push([array]);
+  assertThrows(() => JSON.stringify(array), TypeError, "cyclic array");
+  const proxied = {};
+  proxied.proxy = new Proxy({ inner: proxied }, {});
+  assertThrows(() => JSON.stringify(proxied), TypeError, "cyclic via Proxy");
+  const shared = { x: 1 };
+  assertEq(JSON.stringify([shared, shared]), '[{"x":1},{"x":1}]', "shared");
+}
+
+// Properties follow EnumerableOwnProperties order and are read once each.
+{
+  const symbol = Symbol();
+  const value = { b: 1, a: 2, 2: 3, 1: 4, [symbol]: 5 };
+  Object.defineProperty(value, "hidden", { value: 6 });
+  assertEq(JSON.stringify(value), '{"1":4,"2":3,"b":1,"a":2}', "key order");
+  const getter = {
+    get a() {
+      delete this.b;
+      return 1;
+    },
+    b: 2,
+  };
+  assertEq(JSON.stringify(getter), '{"a":1}', "getter deletes later key");
+}
+
+// Proxies are observed through their traps in spec order.
+{
+  const log = [];
+  const proxy = new Proxy({ a: 1, b: 2 }, {
+    get(target, key) {
+      log.push(String(key));
+      return target[key];
+    },
+    ownKeys(target) {
+      log.push("ownKeys");
+      return Reflect.ownKeys(target).reverse();
+    },
+    getOwnPropertyDescriptor(target, key) {
+      log.push(`describe ${key}`);
+      return Reflect.getOwnPropertyDescriptor(target, key);
+    },
+  });
+  assertEq(JSON.stringify(proxy), '{"b":2,"a":1}', "proxy object");
+  assertEq(log.join(), "toJSON,ownKeys,describe b,describe a,b,a", "proxy");
+  const array = new Proxy([5, 6, 7], {
+    get: (target, key) => (key === "length" ? 2 : target[key]),
+  });
+  assertEq(JSON.stringify(array), "[5,6]", "proxy array length");
+}
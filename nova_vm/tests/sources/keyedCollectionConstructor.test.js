@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(f, ErrorType, name) {
+  try {
+    f();
+  } catch (err) {
+    assertEq(err instanceof ErrorType, true, name);
+    return;
+  }
+  throw new Error(`${name} failed: did not throw`);
+}
+
+const ArrayIteratorPrototype = Object.getPrototypeOf([].values());
+
+// Arrays of pairs.
+{
+  const map = new Map([["a", 1], ["b", 2], ["a", 3], [-0, 4]]);
+  assertEq(map.size, 3, "Map size");
+  assertEq(map.get("a"), 3, "Map duplicate key");
+  assertEq(map.get(0), 4, "Map -0 key");
+  assertEq([...map.keys()].join(), "a,b,0", "Map key order");
+  assertEq(new Map([]).size, 0, "Map empty");
+
+  const set = new Set([1, 2, 1, -0]);
+  assertEq([...set].join(), "1,2,0", "Set");
+
+  const a = {};
+  const b = Symbol("b");
+  const weakMap = new WeakMap([[a, 1], [b, 2], [a, 3]]);
+  assertEq(weakMap.get(a), 3, "WeakMap duplicate key");
+  assertEq(weakMap.get(b), 2, "WeakMap symbol key");
+  assertThrows(() => new WeakMap([[a, 1], [1, 2]]), TypeError, "WeakMap primitive key");
+  assertThrows(
+    () => new WeakMap([[a, 1], [Symbol.for("registered"), 2]]),
+    TypeError,
+    "WeakMap registered symbol key",
+  );
+}
+
+// Entries that are not pairs of two.
+{
+  const map = new Map([["a"], ["b", 2, 3]]);
+  assertEq(map.get("a"), undefined, "Map short entry");
+  assertEq(map.get("b"), 2, "Map long entry");
+  const weakMap = new WeakMap([[Object, 1, 2]]);
+  assertEq(weakMap.get(Object), 1, "WeakMap long entry");
+  assertThrows(() => new Map([1]), TypeError, "Map non-object entry");
+  assertThrows(() => new WeakMap([1]), TypeError, "WeakMap non-object entry");
+  const sparse = [, 1];
+  Array.prototype[0] = "proto";
+  try {
+    assertEq(new Map([sparse]).get("proto"), 1, "Map hole in entry");
+  } finally {
+    delete Array.prototype[0];
+  }
+}
+
+// An overridden adder is called for every entry.
+for (const [Constructor, name, key] of [
+  [Map, "set", "a"],
+  [Set, "add", "a"],
+  [WeakMap, "set", Object],
+]) {
+  const original = Constructor.prototype[name];
+  let calls = 0;
+  Constructor.prototype[name] = function (...args) {
+    calls++;
+    return original.apply(this, args);
+  };
+  try {
+    new Constructor([[key, 1], [key, 2]]);
+    assertEq(calls, 2, `${Constructor.name} overridden ${name}`);
+  } finally {
+    Constructor.prototype[name] = original;
+  }
+}
+
+// An overridden ArrayIterator next method is used.
+{
+  const originalNext = ArrayIteratorPrototype.next;
+  let calls = 0;
+  ArrayIteratorPrototype.next = function () {
+    calls++;
+    return originalNext.call(this);
+  };
+  try {
+    new Map([["a", 1]]);
+    new Set([1]);
+    new WeakMap([[Object, 1]]);
+    new WeakSet([Object]);
+  } finally {
+    ArrayIteratorPrototype.next = originalNext;
+  }
+  // Two calls each: one for the entry and one that finishes iteration.
+  assertEq(calls, 8, "ArrayIterator next calls");
+}
+
+// An overridden Array iterator method is used.
+{
+  const array = [["a", 1]];
+  let calls = 0;
+  Object.defineProperty(array, Symbol.iterator, {
+    get() {
+      calls++;
+      return Array.prototype.values;
+    },
+  });
+  assertEq(new Map(array).get("a"), 1, "Map own iterator getter");
+  assertEq(calls, 1, "Map own iterator getter calls");
+}
+
+// Getters on entries are called.
+{
+  let calls = 0;
+  const entry = ["a"];
+  Object.defineProperty(entry, 1, {
+    get() {
+      calls++;
+      return 1;
+    },
+  });
+  assertEq(new Map([entry]).get("a"), 1, "Map entry getter");
+  entry[0] = Object;
+  assertEq(new WeakMap([entry]).get(Object), 1, "WeakMap entry getter");
+  assertEq(calls, 2, "entry getter calls");
+}
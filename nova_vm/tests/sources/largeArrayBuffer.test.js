@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Views with byte offsets beyond four gigabytes.
+const offset = 2 ** 32 + 8;
+const buffer = new ArrayBuffer(offset + 16);
+
+const bytes = new Uint8Array(buffer, offset, 16);
+assert(bytes.byteOffset, offset, "Uint8Array byteOffset");
+bytes[3] = 7;
+
+const low = new Uint8Array(buffer, 8, 16);
+assert(low[3], 0, "write to high offset does not alias a low offset");
+
+const doubles = new Float64Array(buffer, offset);
+assert(doubles.length, 2, "Float64Array length");
+doubles[1] = 1.5;
+
+const view = new DataView(buffer, offset);
+assert(view.byteOffset, offset, "DataView byteOffset");
+assert(view.getUint8(3), 7, "DataView integer read");
+assert(view.getFloat64(8, true), 1.5, "DataView float read");
+
+const all = new Uint8Array(buffer);
+assert(all.length, offset + 16, "full view length");
+assert(all[offset + 3], 7, "read through full view");
@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+const ArrayIteratorPrototype = Object.getPrototypeOf([].values());
+const MapIteratorPrototype = Object.getPrototypeOf(new Map().entries());
+
+function entries(iterable) {
+  const seen = [];
+  for (const [key, value] of iterable) {
+    seen.push(`${key}=${value}`);
+  }
+  return seen.join();
+}
+
+// Entries, keys and values.
+{
+  const map = new Map([["a", 1], ["b", 2]]);
+  assertEq(entries(map), "a=1,b=2", "map");
+  assertEq(entries(map.entries()), "a=1,b=2", "entries()");
+  const keys = [];
+  for (const key of map.keys()) {
+    keys.push(key);
+  }
+  assertEq(keys.join(), "a,b", "keys()");
+  const values = [];
+  for (const value of map.values()) {
+    values.push(value);
+  }
+  assertEq(values.join(), "1,2", "values()");
+  const arrays = [];
+  for (const entry of map) {
+    arrays.push(Array.isArray(entry) && entry.length === 2);
+  }
+  assertEq(arrays.join(), "true,true", "entry arrays");
+  assertEq([...map].join(";"), "a,1;b,2", "spread");
+}
+
+// Holes, rest elements and mutation during iteration.
+{
+  const map = new Map([["a", 1], ["b", 2], ["c", 3]]);
+  const seen = [];
+  for (const [key, , ...rest] of map) {
+    seen.push(`${key}:${rest.length}`);
+    if (key === "a") {
+      map.delete("b");
+      map.set("d", 4);
+    }
+  }
+  assertEq(seen.join(), "a:0,c:0,d:0", "mutation");
+  const [first] = map;
+  assertEq(first.join(), "a,1", "array destructuring");
+}
+
+// A partially consumed entries iterator continues where it was left.
+{
+  const iterator = new Map([["a", 1], ["b", 2], ["c", 3]]).entries();
+  iterator.next();
+  for (const [key] of iterator) {
+    if (key === "b") {
+      break;
+    }
+  }
+  assertEq(iterator.next().value.join(), "c,3", "shared iterator state");
+}
+
+// A replaced Array iteration protocol is observed when destructuring.
+{
+  const map = new Map([["a", 1], ["b", 2]]);
+  const values = Array.prototype[Symbol.iterator];
+  let calls = 0;
+  Array.prototype[Symbol.iterator] = function () {
+    calls++;
+    return values.call(this);
+  };
+  const result = entries(map);
+  Array.prototype[Symbol.iterator] = values;
+  assertEq(result, "a=1,b=2", "replaced array iterator values");
+  assertEq(calls, 2, "replaced array iterator calls");
+}
+
+{
+  let returnCalls = 0;
+  ArrayIteratorPrototype.return = function () {
+    returnCalls++;
+    return {};
+  };
+  for (const [key] of new Map([["a", 1], ["b", 2]])) {
+  }
+  delete ArrayIteratorPrototype.return;
+  assertEq(returnCalls, 2, "entry iterator return");
+}
+
+// A replaced Map Iterator protocol is observed.
+{
+  const next = MapIteratorPrototype.next;
+  let calls = 0;
+  MapIteratorPrototype.next = function () {
+    calls++;
+    return next.call(this);
+  };
+  const result = entries(new Map([["a", 1]]));
+  MapIteratorPrototype.next = next;
+  assertEq(result, "a=1", "replaced next values");
+  assertEq(calls, 2, "replaced next calls");
+}
+
+{
+  let returnCalls = 0;
+  MapIteratorPrototype.return = function () {
+    returnCalls++;
+    return {};
+  };
+  for (const [key, value] of new Map([["a", 1], ["b", 2]])) {
+    break;
+  }
+  delete MapIteratorPrototype.return;
+  assertEq(returnCalls, 1, "map iterator return");
+}
+
+// Entries are iterated through yield*.
+{
+  function* delegate(map) {
+    yield* map;
+  }
+  assertEq(entries(delegate(new Map([["a", 1]]))), "a=1", "yield*");
+}
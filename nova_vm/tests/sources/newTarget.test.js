@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(condition, name) {
+  if (!condition) {
+    throw new Error(`${name} failed`);
+  }
+}
+
+function assertThrows(ErrorConstructor, callback, name) {
+  try {
+    callback();
+  } catch (err) {
+    assert(err instanceof ErrorConstructor, name);
+    return;
+  }
+  throw new Error(`${name} did not throw`);
+}
+
+function NewTarget() {}
+
+// Ordinary functions.
+function F() {
+  this.nt = new.target;
+}
+assert(
+  (function () {
+    return new.target;
+  })() === undefined,
+  "call",
+);
+assert(new F().nt === F, "construct");
+assert(Reflect.construct(F, [], NewTarget).nt === NewTarget, "Reflect");
+assert(
+  Object.getPrototypeOf(Reflect.construct(F, [], NewTarget)) ===
+    NewTarget.prototype,
+  "prototype from newTarget",
+);
+function NoPrototype() {}
+NoPrototype.prototype = 1;
+assert(
+  Object.getPrototypeOf(Reflect.construct(F, [], NoPrototype)) ===
+    Object.prototype,
+  "fallback prototype",
+);
+assertThrows(
+  TypeError,
+  () => Reflect.construct(F, [], () => {}),
+  "non-constructor newTarget",
+);
+
+// Arrow functions and direct eval capture new.target lexically.
+function Arrow() {
+  this.nt = (() => () => new.target)()();
+}
+assert(new Arrow().nt === Arrow, "arrow");
+function Eval() {
+  this.nt = eval("new.target");
+  this.arrow = (() => eval("new.target"))();
+}
+const evalResult = Reflect.construct(Eval, [], NewTarget);
+assert(evalResult.nt === NewTarget, "eval");
+assert(evalResult.arrow === NewTarget, "eval in arrow");
+assertThrows(SyntaxError, () => eval("new.target"), "top-level eval");
+assertThrows(
+  SyntaxError,
+  () => (0, eval)("new.target"),
+  "indirect eval",
+);
+
+// Classes.
+class A {
+  constructor() {
+    this.nt = new.target;
+  }
+}
+class B extends A {}
+class C extends A {
+  constructor() {
+    super();
+    this.c = new.target;
+  }
+}
+assert(new A().nt === A, "class");
+assert(new B().nt === B, "default derived constructor");
+assert(new C().nt === C && new C().c === C, "derived constructor");
+assert(Reflect.construct(B, [], NewTarget).nt === NewTarget, "derived Reflect");
+assert(
+  Object.getPrototypeOf(Reflect.construct(B, [], NewTarget)) ===
+    NewTarget.prototype,
+  "derived prototype from newTarget",
+);
+class M {
+  m() {
+    return new.target;
+  }
+  static s() {
+    return new.target;
+  }
+  get g() {
+    return new.target;
+  }
+}
+assert(new M().m() === undefined, "method");
+assert(M.s() === undefined, "static method");
+assert(new M().g === undefined, "getter");
+function* generator() {
+  yield new.target;
+}
+assert(generator().next().value === undefined, "generator");
+
+// Bound functions forward newTarget, replacing themselves with the target.
+const bound = F.bind(null);
+assert(new bound().nt === F, "bound");
+assert(
+  Reflect.construct(bound, [], NewTarget).nt === NewTarget,
+  "bound Reflect",
+);
+
+// Builtin constructors take the prototype from newTarget.
+for (const [Constructor, args] of [
+  [Object, []],
+  [Function, []],
+  [Array, []],
+  [Boolean, []],
+  [Number, []],
+  [String, []],
+  [Date, []],
+  [RegExp, ["a"]],
+  [Error, []],
+  [TypeError, []],
+  [AggregateError, [[]]],
+  [Map, []],
+  [Set, []],
+  [WeakMap, []],
+  [WeakSet, []],
+  [WeakRef, [{}]],
+  [FinalizationRegistry, [() => {}]],
+  [ArrayBuffer, [1]],
+  [DataView, [new ArrayBuffer(1)]],
+  [Uint8Array, [1]],
+  [class extends Array {}, []],
+]) {
+  assert(
+    Object.getPrototypeOf(Reflect.construct(Constructor, args, NewTarget)) ===
+      NewTarget.prototype,
+    `${Constructor.name} newTarget`,
+  );
+}
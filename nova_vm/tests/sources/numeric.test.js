@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Numbers are stored as integers, stack floats or heap floats depending on
+// their value. Operands are read from variables so that each operation sees
+// the engine's own representation of its inputs instead of a constant.
+const zero = 0;
+const negZero = -0;
+const one = 1;
+const minusOne = -1;
+const two = 2;
+const minusTwo = -2;
+const three = 3;
+const minusFive = -5;
+const seven = 7;
+const half = 0.5;
+const maxSafe = 9007199254740991;
+const twoTo53 = 2 ** 53;
+const twoTo60 = 2 ** 60;
+
+// Each entry is [expression, expected result], compared with Object.is.
+const cases = [
+  // Exponentiation.
+  [() => minusFive ** two, 25],
+  [() => minusFive ** three, -125],
+  [() => minusTwo ** -two, 0.25],
+  [() => minusTwo ** -one, -0.5],
+  [() => minusTwo ** half, NaN],
+  [() => three ** 40, 12157665459056928801],
+  [() => two ** 53, 9007199254740992],
+  [() => minusTwo ** 63, -9223372036854775808],
+  [() => two ** 1024, Infinity],
+  [() => minusTwo ** 1023, -(2 ** 1023)],
+  [() => two ** -1074, 5e-324],
+  [() => two ** -1075, 0],
+  [() => minusTwo ** -1075, -0],
+  [() => zero ** zero, 1],
+  [() => NaN ** zero, 1],
+  [() => one ** NaN, NaN],
+  [() => zero ** -one, Infinity],
+  [() => negZero ** three, -0],
+  [() => negZero ** two, 0],
+  [() => negZero ** -three, -Infinity],
+  [() => negZero ** -two, Infinity],
+  [() => (-Infinity) ** three, -Infinity],
+  [() => (-Infinity) ** two, Infinity],
+  [() => (-Infinity) ** -three, -0],
+  [() => (-Infinity) ** -two, 0],
+  [() => Infinity ** -one, 0],
+  [() => one ** Infinity, NaN],
+  [() => minusOne ** -Infinity, NaN],
+  [() => two ** Infinity, Infinity],
+  [() => half ** Infinity, 0],
+  [() => two ** -Infinity, 0],
+  [() => minusTwo ** -Infinity, 0],
+  [() => half ** -Infinity, Infinity],
+  [() => Math.pow(minusFive, two), 25],
+  [() => Math.pow(negZero, -three), -Infinity],
+  // Remainder.
+  [() => minusOne % one, -0],
+  [() => minusFive % 5, -0],
+  [() => 5 % -5, 0],
+  [() => negZero % 5, -0],
+  [() => zero % minusOne, 0],
+  [() => 5 % zero, NaN],
+  [() => Infinity % two, NaN],
+  [() => 5 % Infinity, 5],
+  [() => negZero % Infinity, -0],
+  [() => -7 % two, -1],
+  [() => seven % minusTwo, 1],
+  [() => 5.5 % two, 1.5],
+  [() => -5.5 % two, -1.5],
+  [() => -2 % 1.5, -0.5],
+  [() => maxSafe % two, 1],
+  [() => -maxSafe % -maxSafe, -0],
+  [() => twoTo53 % three, 2],
+  [() => twoTo60 % seven, 1],
+  [() => -twoTo60 % seven, -1],
+  [() => -twoTo53 % twoTo53, -0],
+  // Multiplication and other operators producing negative zero.
+  [() => zero * minusOne, -0],
+  [() => minusOne * zero, -0],
+  [() => zero * one, 0],
+  [() => zero / minusOne, -0],
+  [() => -zero, -0],
+  [() => negZero + negZero, -0],
+  [() => negZero - zero, -0],
+  [() => zero - zero, 0],
+  // Overflow from integers to doubles.
+  [() => maxSafe + two, 9007199254740992],
+  [() => -maxSafe - two, -9007199254740992],
+  [() => maxSafe * two, 18014398509481982],
+  [() => 1e308 * 10, Infinity],
+  // BigInt.
+  [() => (-2n) ** 3n, -8n],
+  [() => 0n ** 0n, 1n],
+  [() => -7n % 2n, -1n],
+  [() => 2n ** 64n % 3n, 1n],
+  [() => -(2n ** 64n) % 3n, -1n],
+];
+
+for (const [expression, expected] of cases) {
+  const result = expression();
+  if (!Object.is(result, expected)) {
+    throw new Error(
+      `'${expression}' produced ${Object.is(result, -0) ? "-0" : result}`,
+    );
+  }
+}
+
+let compound = minusOne;
+compound %= one;
+if (!Object.is(compound, -0)) {
+  throw new Error("'-1 %= 1' did not produce -0");
+}
@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(condition, name) {
+  if (!condition) {
+    throw new Error(`${name} failed`);
+  }
+}
+
+// Quoted keys are created with the object's final shape.
+const quoted = { "a-b": 1, c: 2, "d e": 3 };
+assert(Object.keys(quoted).join() === "a-b,c,d e", "quoted key order");
+assert(quoted["a-b"] === 1 && quoted["d e"] === 3, "quoted key values");
+quoted.f = 4;
+assert(Object.keys(quoted).join() === "a-b,c,d e,f", "added key order");
+
+// Integer-like keys are enumerated first, in ascending order.
+const indexed = { b: 1, "2": 2, "1": 3, a: 4 };
+assert(Object.keys(indexed).join() === "1,2,b,a", "integer key order");
+assert(indexed[1] === 3 && indexed["2"] === 2, "integer key values");
+
+// Duplicate keys keep the first position and the last value.
+const duplicate = { a: 1, "b": 2, "a": 3 };
+assert(Object.keys(duplicate).join() === "a,b", "duplicate key order");
+assert(duplicate.a === 3, "duplicate key value");
+
+// A quoted __proto__ key sets the prototype.
+const nullProto = { "__proto__": null, x: 1 };
+assert(Object.getPrototypeOf(nullProto) === null, "null prototype");
+assert(Object.keys(nullProto).join() === "x", "null prototype keys");
+const defaultProto = { "__proto__": undefined, y: 1 };
+assert(
+  Object.getPrototypeOf(defaultProto) === Object.prototype,
+  "default prototype",
+);
+
+// Anonymous functions are named after quoted keys.
+const named = { "my-function": function () {}, "arrow": () => {} };
+assert(named["my-function"].name === "my-function", "function name");
+assert(named.arrow.name === "arrow", "arrow name");
+
+// Properties are writable, enumerable and configurable data properties.
+const descriptor = Object.getOwnPropertyDescriptor({ "k": 1 }, "k");
+assert(
+  descriptor.value === 1 &&
+    descriptor.writable &&
+    descriptor.enumerable &&
+    descriptor.configurable,
+  "data property descriptor",
+);
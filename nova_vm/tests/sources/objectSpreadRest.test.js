@@ -0,0 +1,227 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(condition, message) {
+  if (!condition) {
+    throw new Error(message);
+  }
+}
+
+function assertDataProperty(object, key, value, message) {
+  const desc = Object.getOwnPropertyDescriptor(object, key);
+  assert(desc !== undefined, `${message}: missing property '${String(key)}'`);
+  assert(
+    desc.value === value && desc.writable && desc.enumerable &&
+      desc.configurable,
+    `${message}: unexpected descriptor for '${String(key)}'`,
+  );
+}
+
+const log = [];
+
+// Spread and rest copy enumerable data properties into fresh, writable and
+// configurable properties.
+const plain = { a: 1, b: 2 };
+for (const copy of [{ ...plain }, (({ ...rest }) => rest)(plain)]) {
+  assert(copy !== plain, "copy is a new object");
+  assert(Object.keys(copy).join() === "a,b", "copy keys");
+  assertDataProperty(copy, "a", 1, "plain copy");
+  copy.a = 3;
+  copy.c = 4;
+  assert(plain.a === 1 && !("c" in plain), "copy is independent of source");
+}
+
+const frozen = Object.freeze({ a: 1 });
+for (const copy of [{ ...frozen }, (({ ...rest }) => rest)(frozen)]) {
+  assert(Object.isExtensible(copy), "copy of frozen object is extensible");
+  assertDataProperty(copy, "a", 1, "frozen copy");
+}
+
+const setterOnly = Object.defineProperty({}, "x", {
+  set(v) {},
+  enumerable: true,
+});
+for (const copy of [{ ...setterOnly }, (({ ...rest }) => rest)(setterOnly)]) {
+  assertDataProperty(copy, "x", undefined, "setter-only copy");
+}
+
+const symbol = Symbol("s");
+const withSymbol = { [symbol]: 1, z: 2 };
+assert({ ...withSymbol }[symbol] === 1, "symbol spread");
+const { z, ...symbolRest } = withSymbol;
+assert(symbolRest[symbol] === 1 && !("z" in symbolRest), "symbol rest");
+
+const hidden = Object.defineProperty({ v: 1 }, "h", {
+  value: 2,
+  enumerable: false,
+});
+assert(!("h" in { ...hidden }), "non-enumerable spread");
+const { ...hiddenRest } = hidden;
+assert(!("h" in hiddenRest), "non-enumerable rest");
+
+const inherited = Object.create({
+  get q() {
+    throw new Error("inherited getter called");
+  },
+});
+inherited.o = 1;
+const { ...inheritedRest } = inherited;
+assert(inheritedRest.o === 1 && !("q" in inheritedRest), "inherited rest");
+
+// Getters are called in property order and see the effects of earlier
+// getters.
+const getters = {
+  get a() {
+    log.push("a");
+    Object.defineProperty(this, "b", { enumerable: false });
+    return 1;
+  },
+  b: 2,
+  get c() {
+    log.push("c");
+    delete this.d;
+    return 3;
+  },
+  d: 4,
+  e: 5,
+};
+const spreadGetters = { ...getters };
+assert(log.join() === "a,c", `spread getter order: ${log}`);
+assert(Object.keys(spreadGetters).join() === "a,c,e", "spread getter effects");
+
+function resetGetters() {
+  log.length = 0;
+  Object.defineProperty(getters, "b", { enumerable: true });
+  getters.d = 4;
+}
+resetGetters();
+const { ...restGetters } = getters;
+assert(log.join() === "a,c", `rest getter order: ${log}`);
+assert(Object.keys(restGetters).join() === "a,c,e", "rest getter effects");
+resetGetters();
+const { e, ...excludedGetters } = getters;
+assert(
+  Object.keys(excludedGetters).join() === "a,c",
+  "rest getter effects with excluded keys",
+);
+
+// Excluded keys are compared after ToPropertyKey, which is only performed
+// once.
+let toStringCalls = 0;
+const key = {
+  toString() {
+    toStringCalls++;
+    return "a";
+  },
+};
+const { [key]: a, 1: one, ...excluded } = { 0: "x", 1: "y", a: 1, b: 2 };
+assert(toStringCalls === 1 && a === 1 && one === "y", "computed excluded key");
+assert(Object.keys(excluded).join() === "0,b", "excluded keys");
+
+const { length, ...stringRest } = "ab";
+assert(
+  length === 2 && stringRest[0] === "a" && stringRest[1] === "b",
+  "string rest",
+);
+
+let threw = false;
+try {
+  const { ...nullRest } = null;
+} catch (err) {
+  threw = err instanceof TypeError;
+}
+assert(threw, "rest of null throws a TypeError");
+assert(Object.keys({ ...null, ...undefined }).length === 0, "spread nullish");
+
+// Proxy traps are called in spec order.
+const proxy = new Proxy({ a: 1, b: 2 }, {
+  ownKeys(target) {
+    log.push("ownKeys");
+    return Reflect.ownKeys(target);
+  },
+  getOwnPropertyDescriptor(target, key) {
+    log.push(`gopd:${key}`);
+    return Reflect.getOwnPropertyDescriptor(target, key);
+  },
+  get(target, key) {
+    log.push(`get:${String(key)}`);
+    return Reflect.get(target, key);
+  },
+});
+log.length = 0;
+({ ...proxy });
+assert(
+  log.join() === "ownKeys,gopd:a,get:a,gopd:b,get:b",
+  `proxy spread order: ${log}`,
+);
+log.length = 0;
+const { a: proxyA, ...proxyRest } = proxy;
+assert(
+  log.join() === "get:a,ownKeys,gopd:b,get:b",
+  `proxy rest order: ${log}`,
+);
+
+// The rest assignment target is evaluated before the properties are copied.
+log.length = 0;
+const restTarget = {};
+({
+  ...restTarget[(log.push("target"), "rest")]
+} = {
+  get x() {
+    log.push("get");
+    return 1;
+  },
+});
+assert(log.join() === "target,get", `rest target order: ${log}`);
+assert(restTarget.rest.x === 1, "rest target assigned");
+
+// Defaults are only evaluated when the value is undefined, after the value is
+// read, and in source order.
+let defaults = 0;
+const next = () => ++defaults;
+const { p = next(), q = next() } = { p: null, q: undefined };
+assert(p === null && q === 1 && defaults === 1, "object pattern defaults");
+const [f0 = next(), f1 = next(), f2 = next(), f3 = next(), f4 = next()] = [
+  false,
+  0,
+  "",
+  NaN,
+  null,
+];
+assert(defaults === 1, "defaults are not evaluated for falsy values");
+const [hole = next()] = [,];
+assert(hole === 2, "defaults are evaluated for holes");
+
+log.length = 0;
+const getterSource = {
+  get k() {
+    log.push("get");
+    return undefined;
+  },
+};
+const { [(log.push("key"), "k")]: k = (log.push("default"), 1) } =
+  getterSource;
+assert(log.join() === "key,get,default", `default order: ${log}`);
+
+log.length = 0;
+const {
+  outer: { inner = (log.push("inner"), 1) } = (log.push("outer"), {}),
+} = {};
+assert(log.join() === "outer,inner", `nested default order: ${log}`);
+
+let parameters = 0;
+function withDefaults(
+  a = ++parameters,
+  b = a + 1,
+  { c } = { c: ++parameters },
+) {
+  return [a, b, c];
+}
+assert(withDefaults().join() === "1,2,2", "parameter defaults");
+
+const { fn = function () {}, cls = class {}, arrow = () => {} } = {};
+assert(
+  fn.name === "fn" && cls.name === "cls" && arrow.name === "arrow",
+  "default function names",
+);
@@ -0,0 +1,208 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertKeys(actual, expected, message) {
+  if (
+    actual.length !== expected.length ||
+    actual.some((key, i) => key !== expected[i])
+  ) {
+    const expectedKeys = expected.map(String);
+    const actualKeys = actual.map(String);
+    throw new Error(
+      `${message}: expected [${expectedKeys}], got [${actualKeys}]`,
+    );
+  }
+}
+
+function assertThrows(fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof TypeError) {
+      return;
+    }
+    throw new Error(`${message}: expected TypeError, got ${err}`);
+  }
+  throw new Error(`${message}: expected TypeError`);
+}
+
+// Array indices come first in ascending order, then strings and symbols in
+// insertion order. 2 ** 32 - 1 is not an array index.
+const symbolA = Symbol("a");
+const symbolB = Symbol("b");
+const ordered = {};
+ordered.b = 1;
+ordered[symbolB] = 1;
+ordered[2] = 1;
+ordered.a = 1;
+ordered[0] = 1;
+ordered[symbolA] = 1;
+ordered["-1"] = 1;
+ordered[4294967295] = 1;
+ordered[1] = 1;
+assertKeys(
+  Object.getOwnPropertyNames(ordered),
+  ["0", "1", "2", "b", "a", "-1", "4294967295"],
+  "ordinary object keys",
+);
+assertKeys(
+  Reflect.ownKeys(ordered),
+  ["0", "1", "2", "b", "a", "-1", "4294967295", symbolB, symbolA],
+  "ordinary object keys with symbols",
+);
+delete ordered.b;
+ordered.b = 1;
+assertKeys(
+  Object.keys(ordered),
+  ["0", "1", "2", "a", "-1", "4294967295", "b"],
+  "re-added key moves to the end",
+);
+
+// Many keys added in descending order still enumerate ascending.
+const many = {};
+const expectedMany = [];
+for (let i = 199; i >= 0; i--) {
+  many[i] = i;
+}
+for (let i = 0; i < 200; i++) {
+  expectedMany.push(String(i));
+}
+for (let i = 0; i < 200; i++) {
+  many[`k${i}`] = i;
+  expectedMany.push(`k${i}`);
+}
+assertKeys(Object.getOwnPropertyNames(many), expectedMany, "many keys");
+
+const array = [1, 2, 3];
+array.x = 1;
+assertKeys(
+  Object.getOwnPropertyNames(array),
+  ["0", "1", "2", "length", "x"],
+  "array keys",
+);
+
+// Proxy ownKeys trap results are returned as is.
+function proxyKeys(target, keys) {
+  return Reflect.ownKeys(new Proxy(target, { ownKeys: () => keys }));
+}
+assertKeys(proxyKeys({}, ["b", "a", "1"]), ["b", "a", "1"], "proxy keys");
+assertKeys(proxyKeys({}, expectedMany), expectedMany, "many proxy keys");
+
+// Duplicate keys are rejected for both short and long key lists.
+assertThrows(() => proxyKeys({}, ["a", "b", "a"]), "duplicate proxy keys");
+assertThrows(
+  () => proxyKeys({}, [symbolA, symbolA]),
+  "duplicate proxy symbol keys",
+);
+assertThrows(
+  () => proxyKeys({}, [...expectedMany, "k199"]),
+  "duplicate proxy keys in long list",
+);
+
+// Non-configurable keys of the target must be reported.
+const sealed = Object.defineProperty({}, "fixed", { value: 1 });
+assertKeys(
+  proxyKeys(sealed, ["extra", "fixed"]),
+  ["extra", "fixed"],
+  "non-configurable key reported",
+);
+assertThrows(
+  () => proxyKeys(sealed, ["extra"]),
+  "non-configurable key skipped",
+);
+const longSealed = Object.defineProperty({ ...many }, "fixed", { value: 1 });
+assertThrows(
+  () => proxyKeys(longSealed, expectedMany),
+  "non-configurable key skipped in long list",
+);
+
+// Non-extensible targets must report exactly their own keys.
+const frozen = Object.preventExtensions({ a: 1, b: 2 });
+assertKeys(proxyKeys(frozen, ["b", "a"]), ["b", "a"], "non-extensible keys");
+assertThrows(() => proxyKeys(frozen, ["a"]), "non-extensible key skipped");
+assertThrows(
+  () => proxyKeys(frozen, ["a", "b", "c"]),
+  "non-extensible extra key",
+);
+const longFrozen = Object.preventExtensions({ ...many });
+assertKeys(
+  proxyKeys(longFrozen, expectedMany),
+  expectedMany,
+  "long non-extensible keys",
+);
+assertThrows(
+  () => proxyKeys(longFrozen, [...expectedMany, "extra"]),
+  "long non-extensible extra key",
+);
+
+// Exotic objects list their intrinsic keys in the right position.
+const string = new String("ab");
+string[5] = 1;
+string.x = 1;
+string[symbolA] = 1;
+string[2] = 1;
+assertKeys(
+  Reflect.ownKeys(string),
+  ["0", "1", "2", "5", "length", "x", symbolA],
+  "string wrapper keys",
+);
+assertKeys(
+  Reflect.ownKeys(new String("ab")),
+  ["0", "1", "length"],
+  "plain string wrapper keys",
+);
+const sparse = [1, , 3];
+sparse[symbolA] = 1;
+sparse.y = 1;
+assertKeys(
+  Reflect.ownKeys(sparse),
+  ["0", "2", "length", "y", symbolA],
+  "sparse array keys",
+);
+const typed = new Uint8Array(2);
+typed.z = 1;
+typed[symbolB] = 1;
+assertKeys(
+  Reflect.ownKeys(typed),
+  ["0", "1", "z", symbolB],
+  "typed array keys",
+);
+assertKeys(
+  Reflect.ownKeys(new Error("m", { cause: 0 })),
+  ["message", "cause"],
+  "error keys",
+);
+assertKeys(Reflect.ownKeys(/a/), ["lastIndex"], "regexp keys");
+const fn = function named() {};
+fn.extra = 1;
+assertKeys(
+  Object.getOwnPropertyNames(fn),
+  ["length", "name", "prototype", "extra"],
+  "function keys",
+);
+
+// for-in walks the prototype chain, skipping shadowed and symbol keys.
+const base = { b: 1, shadowed: 1, hidden: 1, [symbolA]: 1 };
+const middle = Object.create(base);
+middle[1] = 1;
+Object.defineProperty(middle, "hidden", { value: 1, enumerable: false });
+middle.m = 1;
+const leaf = Object.create(middle);
+leaf[0] = 1;
+leaf.shadowed = 1;
+leaf[symbolB] = 1;
+const forInKeys = [];
+for (const key in leaf) {
+  forInKeys.push(key);
+}
+assertKeys(
+  forInKeys,
+  ["0", "shadowed", "1", "m", "b"],
+  "for-in keys",
+);
+const forInArrayKeys = [];
+for (const key in sparse) {
+  forInArrayKeys.push(key);
+}
+assertKeys(forInArrayKeys, ["0", "2", "y"], "for-in array keys");
@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+function check(f) {
+  try {
+    f();
+    return "ok";
+  } catch (err) {
+    return err.constructor.name;
+  }
+}
+
+// Pattern defaults see earlier bindings.
+{
+  const results = [];
+  function f([a, b = a], { c, d = c + b } = { c: 1 }) {
+    return [a, b, c, d].join();
+  }
+  results.push(f([7]));
+  results.push((({ a, b: [c = a] = [] }) => a + c)({ a: 2 }));
+  results.push(
+    (function ({ x: [a, { b = a }] }) {
+      return a + b;
+    })({ x: [3, {}] }),
+  );
+  results.push(
+    (function ([a, ...[b = a]]) {
+      return a + b;
+    })([4]),
+  );
+  const o = {
+    set p([a, b = a] = [5]) {
+      this.r = a + b;
+    },
+  };
+  o.p = undefined;
+  results.push(o.r);
+  let [la, lb = la] = [6];
+  var [va, vb = va] = [7];
+  results.push(la + lb, va + vb);
+  for (const { a, b = a } of [{ a: 8 }]) results.push(a + b);
+  (function () {
+    var x = x;
+    results.push(typeof x);
+  })();
+  assert(
+    results.join("|"),
+    "7,7,1,8|4|6|8|10|12|14|16|undefined",
+    "Pattern defaults",
+  );
+}
+
+// Pattern defaults respect the temporal dead zone.
+assert(
+  [
+    check(() => {
+      let x = x;
+    }),
+    check(() => {
+      let [x = x] = [];
+    }),
+    check(() => {
+      let [x] = [x];
+    }),
+    check(() => {
+      let [x = y, y] = [];
+    }),
+    check(() => {
+      const { [x]: x } = {};
+    }),
+    check(() => {
+      (function (a = b, b) {})();
+    }),
+    check(() => {
+      (function ([x = x]) {})([]);
+    }),
+    check(() => {
+      (function ({ x = y, y }) {})({});
+    }),
+    check(() => {
+      (function ([x] = [x]) {})();
+    }),
+    check(() => {
+      (function (a, b = a) {})(1);
+    }),
+  ].join(),
+  "ReferenceError,ReferenceError,ReferenceError,ReferenceError,ReferenceError," +
+    "ReferenceError,ReferenceError,ReferenceError,ReferenceError,ok",
+  "Temporal dead zone",
+);
+
+// Destructuring parameters in all function kinds.
+var results = [];
+function* gen({ a = 1 } = {}, [b = a + 1] = [], ...{ length }) {
+  yield a;
+  yield b;
+  yield length;
+}
+results.push([...gen()].join(), [...gen({ a: 5 }, [6], 7, 8)].join());
+class C {
+  constructor({ a = 1, ...rest } = {}, ...[b = a]) {
+    this.s = a + b + Object.keys(rest).join();
+  }
+  static m([x, y = x] = [2]) {
+    return x + y;
+  }
+}
+results.push(new C({ a: 2, c: 0, d: 0 }).s, C.m());
+const o = {
+  x: 10,
+  m({ a = 1 } = {}, b = this.x) {
+    return a + b;
+  },
+};
+results.push(o.m());
+async function af({ a = 1, b: [c] = [2] } = {}, d = a + c) {
+  return [a, c, d].join();
+}
+af().then((v) => results.push(v));
+async function rejects([x = x]) {}
+rejects([]).catch((e) => results.push(e.constructor.name));
+async function* ag({ a = 1 } = {}, b = a + 1) {
+  yield a + b;
+}
+ag()
+  .next()
+  .then((r) => results.push(r.value));
+
+function afterJobs() {
+  assert(
+    results.join("|"),
+    "1,2,0|5,6,2|4c,d|4|11|1,2,3|ReferenceError|3",
+    "Function kinds",
+  );
+}
@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Run each check repeatedly so that later rounds hit the property lookup
+// caches warmed by earlier ones.
+function repeat(f) {
+  for (let i = 0; i < 10; i++) {
+    f();
+  }
+}
+
+// Method calls on each kind of primitive.
+const symbol = Symbol("description");
+repeat(() => {
+  assert("abc".toUpperCase(), "ABC", "string method");
+  assert("abc"["charCodeAt"](1), 98, "computed string method");
+  assert("abc".length, 3, "string length");
+  assert("abc"[2], "c", "string index");
+  assert((255).toString(16), "ff", "number method");
+  assert((1.5).toFixed(2), "1.50", "fractional number method");
+  assert(true.toString(), "true", "boolean method");
+  assert(symbol.toString(), "Symbol(description)", "symbol method");
+  assert(symbol.description, "description", "symbol getter");
+  assert((10n).toString(2), "1010", "bigint method");
+  assert((2n ** 64n).toString(), "18446744073709551616", "heap bigint method");
+  assert("abc".hasOwnProperty("length"), true, "inherited Object method");
+  assert("abc".missing, undefined, "missing property");
+});
+
+// Getters on primitive prototypes receive the primitive as `this`.
+Object.defineProperty(String.prototype, "self", {
+  get() {
+    "use strict";
+    return this;
+  },
+  configurable: true,
+});
+Object.defineProperty(Number.prototype, "sloppySelf", {
+  get() {
+    return this;
+  },
+  configurable: true,
+});
+repeat(() => {
+  assert("abc".self, "abc", "strict getter receives primitive");
+  assert(typeof "abc".self, "string", "strict getter receives string");
+  const wrapper = (5).sloppySelf;
+  assert(typeof wrapper, "object", "sloppy getter receives wrapper");
+  assert(wrapper.valueOf(), 5, "sloppy getter wrapper value");
+});
+delete String.prototype.self;
+delete Number.prototype.sloppySelf;
+assert("abc".self, undefined, "deleted getter");
+
+// Prototype methods shadowed after the caches are warmed.
+repeat(() => {
+  assert((1).hasOwnProperty("x"), false, "Object method before shadowing");
+  assert("abc".at(0), "a", "String method before replacing");
+});
+const at = String.prototype.at;
+Number.prototype.hasOwnProperty = () => "shadowed";
+String.prototype.at = function () {
+  "use strict";
+  return `replaced ${this}`;
+};
+repeat(() => {
+  assert((1).hasOwnProperty("x"), "shadowed", "shadowing Number method");
+  assert(true.hasOwnProperty("x"), false, "other prototypes are unaffected");
+  assert("abc".at(0), "replaced abc", "replaced String method");
+});
+delete Number.prototype.hasOwnProperty;
+String.prototype.at = at;
+assert((1).hasOwnProperty("x"), false, "Object method after unshadowing");
+assert("abc".at(0), "a", "restored String method");
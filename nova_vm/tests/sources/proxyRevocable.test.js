@@ -0,0 +1,111 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(fn, ErrorType, name) {
+  try {
+    fn();
+  } catch (err) {
+    assertEq(err instanceof ErrorType, true, `${name} error type`);
+    return;
+  }
+  throw new Error(`${name} failed: did not throw`);
+}
+
+// Proxy.revocable returns a working proxy and a revoker function.
+{
+  const target = { a: 1 };
+  const result = Proxy.revocable(target, {
+    get(t, key, receiver) {
+      return key === "b" ? 2 : Reflect.get(t, key, receiver);
+    },
+  });
+  assertEq(Object.keys(result).join(), "proxy,revoke", "result keys");
+  assertEq(Object.getPrototypeOf(result), Object.prototype, "result prototype");
+  const { proxy, revoke } = result;
+  assertEq(proxy.a, 1, "forwarded get");
+  assertEq(proxy.b, 2, "trapped get");
+  assertEq(typeof revoke, "function", "revoke type");
+  assertEq(revoke.length, 0, "revoke length");
+  assertEq(revoke.name, "", "revoke name");
+  assertEq(Object.getPrototypeOf(revoke), Function.prototype, "revoke prototype");
+  assertEq(Object.isExtensible(revoke), true, "revoke extensible");
+  assertThrows(() => new revoke(), TypeError, "revoke is not a constructor");
+
+  assertEq(revoke(), undefined, "revoke result");
+  assertEq(revoke(), undefined, "second revoke result");
+  assertThrows(() => proxy.a, TypeError, "get on revoked proxy");
+  assertThrows(() => { proxy.a = 2; }, TypeError, "set on revoked proxy");
+  assertThrows(() => "a" in proxy, TypeError, "has on revoked proxy");
+  assertThrows(() => Object.keys(proxy), TypeError, "ownKeys on revoked proxy");
+  assertThrows(() => Object.getPrototypeOf(proxy), TypeError, "getPrototypeOf");
+  assertThrows(() => Array.isArray(proxy), TypeError, "isArray");
+  assertEq(typeof proxy, "object", "revoked proxy typeof");
+  assertEq(target.a, 1, "target untouched");
+}
+
+// Revoked callable proxies stay callable but throw when called.
+{
+  const { proxy, revoke } = Proxy.revocable(function (x) { return x * 2; }, {});
+  assertEq(proxy(21), 42, "call before revocation");
+  revoke();
+  assertEq(typeof proxy, "function", "revoked callable proxy typeof");
+  assertThrows(() => proxy(21), TypeError, "call after revocation");
+}
+
+// Revoked proxies cannot be used as targets or handlers.
+{
+  const { proxy, revoke } = Proxy.revocable({}, {});
+  revoke();
+  const fromTarget = new Proxy(proxy, {});
+  assertThrows(() => fromTarget.a, TypeError, "revoked target");
+  const fromHandler = new Proxy({}, proxy);
+  assertThrows(() => fromHandler.a, TypeError, "revoked handler");
+  assertThrows(() => Proxy.revocable(1, {}), TypeError, "non-object target");
+  assertThrows(() => Proxy.revocable({}, null), TypeError, "non-object handler");
+}
+
+// Revocation functions can be used to revoke from within a trap.
+{
+  let revoke;
+  const proxy = (({ proxy, revoke: r }) => {
+    revoke = r;
+    return proxy;
+  })(Proxy.revocable({}, {
+    get() {
+      revoke();
+      return "last";
+    },
+  }));
+  assertEq(proxy.x, "last", "trap revoking its own proxy");
+  assertThrows(() => proxy.x, TypeError, "after self-revocation");
+}
+
+// Trap results are checked against the target's invariants.
+{
+  const frozen = Object.freeze({ a: 1 });
+  const lying = new Proxy(frozen, {
+    get() { return 2; },
+    has() { return false; },
+    ownKeys() { return []; },
+    deleteProperty() { return true; },
+    getOwnPropertyDescriptor() { return undefined; },
+  });
+  assertThrows(() => lying.a, TypeError, "get invariant");
+  assertThrows(() => "a" in lying, TypeError, "has invariant");
+  assertThrows(() => Object.keys(lying), TypeError, "ownKeys invariant");
+  assertThrows(() => delete lying.a, TypeError, "deleteProperty invariant");
+  assertThrows(
+    () => Object.getOwnPropertyDescriptor(lying, "a"),
+    TypeError,
+    "getOwnPropertyDescriptor invariant",
+  );
+  const extensible = new Proxy({}, { isExtensible() { return false; } });
+  assertThrows(() => Object.isExtensible(extensible), TypeError, "isExtensible");
+}
@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: expected ${expected}, got ${actual}`);
+  }
+}
+
+// Checks run after the promise jobs, by afterJobs.
+const checks = [];
+
+// Reads chunks from a script source.
+{
+  const log = [];
+  let n = 0;
+  const stream = new ReadableStream({
+    start(controller) {
+      controller.enqueue("a");
+    },
+    pull(controller) {
+      n += 1;
+      if (n < 3) controller.enqueue(n);
+      else controller.close();
+    },
+  });
+  (async () => {
+    const reader = stream.getReader();
+    while (true) {
+      const { value, done } = await reader.read();
+      if (done) break;
+      log.push(value);
+    }
+    await reader.closed;
+    log.push("closed");
+  })();
+  checks.push(() => assert(log.join(), "a,1,2,closed", "Script source"));
+}
+
+// Locking.
+{
+  const stream = new ReadableStream();
+  const results = [stream.locked];
+  const reader = stream.getReader();
+  results.push(stream.locked);
+  try {
+    stream.getReader();
+  } catch (e) {
+    results.push(e.name);
+  }
+  reader.releaseLock();
+  results.push(stream.locked);
+  stream.getReader();
+  assert(results.join(), "false,true,TypeError,false", "Locking");
+  let result;
+  stream.cancel().catch((e) => {
+    result = e.name;
+  });
+  checks.push(() => assert(result, "TypeError", "Cancelling a locked stream"));
+}
+
+// Cancelling calls the underlying source.
+{
+  const log = [];
+  const stream = new ReadableStream({
+    cancel(reason) {
+      log.push("cancel:" + reason);
+    },
+  });
+  const reader = stream.getReader();
+  reader.read().then(({ done }) => log.push("done:" + done));
+  reader.cancel("stop").then((v) => log.push("cancelled:" + v));
+  checks.push(() =>
+    assert(log.join(), "cancel:stop,done:true,cancelled:undefined", "Cancel"),
+  );
+}
+
+// Erroring rejects pending reads.
+{
+  const log = [];
+  let controller;
+  const stream = new ReadableStream({
+    start(c) {
+      controller = c;
+    },
+  });
+  const reader = stream.getReader();
+  reader.read().catch((e) => log.push("read:" + e));
+  reader.closed.catch((e) => log.push("closed:" + e));
+  controller.error("boom");
+  reader.read().catch((e) => log.push("read2:" + e));
+  checks.push(() =>
+    assert(log.join(), "closed:boom,read:boom,read2:boom", "Error"),
+  );
+}
+
+// Desired size and high water mark.
+{
+  let controller;
+  new ReadableStream(
+    {
+      start(c) {
+        controller = c;
+      },
+    },
+    { highWaterMark: 2 },
+  );
+  const sizes = [controller.desiredSize];
+  controller.enqueue(1);
+  sizes.push(controller.desiredSize);
+  controller.close();
+  try {
+    controller.enqueue(2);
+  } catch (e) {
+    sizes.push(e.name);
+  }
+  try {
+    new ReadableStream({}, { highWaterMark: -1 });
+  } catch (e) {
+    sizes.push(e.name);
+  }
+  try {
+    new ReadableStreamDefaultController();
+  } catch (e) {
+    sizes.push(e.name);
+  }
+  assert(sizes.join(), "2,1,TypeError,RangeError,TypeError", "Desired size");
+}
+
+// Brand checks.
+{
+  const results = [Object.prototype.toString.call(new ReadableStream())];
+  try {
+    ReadableStream.prototype.getReader.call({});
+  } catch (e) {
+    results.push(e.name);
+  }
+  try {
+    ReadableStream();
+  } catch (e) {
+    results.push(e.name);
+  }
+  ReadableStreamDefaultReader.prototype.read.call({}).catch((e) => {
+    results.push(e.name);
+  });
+  assert(
+    results.join(),
+    "[object ReadableStream],TypeError,TypeError",
+    "Brand checks",
+  );
+  checks.push(() =>
+    assert(
+      results.join(),
+      "[object ReadableStream],TypeError,TypeError,TypeError",
+      "Reader brand check",
+    ),
+  );
+}
+
+function afterJobs() {
+  for (const check of checks) check();
+}
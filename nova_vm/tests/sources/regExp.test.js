@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+function assertThrows(f, errorType, name) {
+  try {
+    f();
+  } catch (err) {
+    assertEq(err.constructor, errorType, name);
+    return;
+  }
+  throw new Error(`${name}: did not throw`);
+}
+
+// Construction parses the pattern and flags.
+const r = new RegExp("a(b+)", "g");
+assertEq(Object.getPrototypeOf(r), RegExp.prototype, "prototype");
+assertEq(r.source, "a(b+)", "source");
+assertEq(r.flags, "g", "flags");
+assertEq(r.global, true, "global");
+assertEq(r.lastIndex, 0, "lastIndex");
+assertEq(RegExp("a") instanceof RegExp, true, "call without new");
+assertEq(RegExp(/q/i, "g").flags, "g", "flags override");
+assertEq(new RegExp(/q/im).flags, "im", "flags copied");
+assertEq(new RegExp("q", "ysmigd").flags, "dgimsy", "flags order");
+assertEq(new RegExp("").source, "(?:)", "empty source");
+assertEq(new RegExp("/").source, "\\/", "escaped source");
+assertThrows(() => new RegExp("("), SyntaxError, "invalid pattern");
+assertThrows(() => new RegExp("a", "gg"), SyntaxError, "duplicate flag");
+assertThrows(() => new RegExp("a", "x"), SyntaxError, "unknown flag");
+
+// exec and test advance lastIndex for global matchers.
+const m = r.exec("xabbbyab");
+assertEq(m[0], "abbb", "exec match");
+assertEq(m[1], "bbb", "exec capture");
+assertEq(m.index, 1, "exec index");
+assertEq(m.input, "xabbbyab", "exec input");
+assertEq(r.lastIndex, 5, "exec lastIndex");
+assertEq(r.test("ab"), false, "test from lastIndex");
+assertEq(r.lastIndex, 0, "test resets lastIndex");
+assertEq(r.test("ab"), true, "test");
+assertEq(/x/.exec("y"), null, "exec without match");
+const named = /(?<year>\d{4})-(?<month>\d{2})/.exec("2024-05");
+assertEq(named.groups.year, "2024", "named group");
+assertEq(named.groups.month, "05", "second named group");
+
+// String methods go through the Symbol-keyed methods.
+assertEq(String("aXbX".match(/x/gi)), "X,X", "global match");
+assertEq("abc".match(/b/).index, 1, "match index");
+assertEq("abc".search(/c/), 2, "search");
+assertEq("a1b22".replace(/\d+/g, "#"), "a#b#", "replace");
+assertEq(String("a,b;c".split(/[,;]/)), "a,b,c", "split");
+assertEq(
+  Array.from("a1b2".matchAll(/\d/g), (m) => m[0]).join(),
+  "1,2",
+  "matchAll",
+);
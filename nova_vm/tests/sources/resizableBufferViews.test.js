@@ -0,0 +1,151 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(f, ErrorType, name) {
+  try {
+    f();
+  } catch (err) {
+    if (!(err instanceof ErrorType)) {
+      throw new Error(`${name} failed: threw ${err}`);
+    }
+    return;
+  }
+  throw new Error(`${name} failed: did not throw`);
+}
+
+// Length-tracking DataViews follow the buffer as it grows and shrinks.
+{
+  const buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+  const view = new DataView(buffer, 2);
+  assertEq(view.byteLength, 6, "auto initial");
+  buffer.resize(16);
+  assertEq(view.byteLength, 14, "auto grown");
+  view.setUint8(13, 7);
+  assertEq(view.getUint8(13), 7, "auto grown access");
+  buffer.resize(2);
+  assertEq(view.byteLength, 0, "auto shrunk to offset");
+  assertEq(view.byteOffset, 2, "auto shrunk to offset offset");
+  buffer.resize(1);
+  assertThrows(() => view.byteLength, TypeError, "auto out of bounds");
+  assertThrows(() => view.byteOffset, TypeError, "auto out of bounds offset");
+  assertThrows(() => view.getUint8(0), TypeError, "auto out of bounds get");
+  buffer.resize(3);
+  assertEq(view.byteLength, 1, "auto back in bounds");
+}
+
+// Fixed-length DataViews go out of bounds once the buffer shrinks past them.
+{
+  const buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+  const view = new DataView(buffer, 2, 4);
+  buffer.resize(5);
+  assertThrows(() => view.byteLength, TypeError, "fixed out of bounds");
+  assertThrows(() => view.setUint8(0, 1), TypeError, "fixed out of bounds set");
+  buffer.resize(6);
+  assertEq(view.byteLength, 4, "fixed back in bounds");
+  assertThrows(() => view.getUint32(1), RangeError, "fixed index past end");
+}
+
+// TypedArrays and DataViews over the same buffer agree on their bounds.
+{
+  const buffer = new ArrayBuffer(16, { maxByteLength: 32 });
+  const views = [
+    [new DataView(buffer, 4), new Uint8Array(buffer, 4)],
+    [new DataView(buffer, 4, 8), new Uint8Array(buffer, 4, 8)],
+  ];
+  for (const size of [32, 12, 11, 4, 3, 0, 16]) {
+    buffer.resize(size);
+    for (const [view, array] of views) {
+      let viewLength;
+      try {
+        viewLength = view.byteLength;
+      } catch (err) {
+        assertEq(err instanceof TypeError, true, `view error at ${size}`);
+        viewLength = "out of bounds";
+      }
+      const arrayLength = array.byteOffset === 0 && array.byteLength === 0
+        ? "out of bounds"
+        : array.byteLength;
+      assertEq(viewLength, arrayLength, `bounds at ${size}`);
+    }
+  }
+}
+
+// The constructor checks the bounds against the buffer's current length.
+{
+  const buffer = new ArrayBuffer(4, { maxByteLength: 16 });
+  assertThrows(() => new DataView(buffer, 5), RangeError, "offset past end");
+  assertThrows(() => new DataView(buffer, 2, 3), RangeError, "length past end");
+  buffer.resize(8);
+  assertEq(new DataView(buffer, 2, 3).byteLength, 3, "after grow");
+}
+
+// copyWithin only copies what remains of a TypedArray that shrunk while its
+// arguments were converted.
+{
+  for (const C of [Uint8Array, Float64Array]) {
+    const buffer = new ArrayBuffer(24, { maxByteLength: 64 });
+    const array = new C(buffer);
+    const shrink = (index) => ({
+      valueOf() {
+        buffer.resize(C.BYTES_PER_ELEMENT);
+        return index;
+      },
+    });
+    assertEq(array.copyWithin(shrink(2), 1), array, `${C.name} copyWithin target`);
+    buffer.resize(24);
+    assertEq(array.copyWithin(0, shrink(2)), array, `${C.name} copyWithin start`);
+    buffer.resize(24);
+    const fixed = new C(buffer, 0, 2);
+    assertThrows(
+      () => fixed.copyWithin(0, shrink(1)),
+      TypeError,
+      `${C.name} copyWithin out of bounds`,
+    );
+  }
+  const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+  const array = new Uint8Array(buffer);
+  array.set([1, 2, 3, 4]);
+  array.copyWithin(0, {
+    valueOf() {
+      buffer.resize(3);
+      return 1;
+    },
+  });
+  assertEq(array.join(), "2,3,3", "copyWithin clamped to shrunk length");
+}
+
+// slice copies only what remains of a TypedArray that shrunk while its
+// arguments were converted; the result keeps the original count.
+{
+  for (const C of [Uint8Array, Float64Array]) {
+    const buffer = new ArrayBuffer(24, { maxByteLength: 64 });
+    const array = new C(buffer);
+    const shrink = (index) => ({
+      valueOf() {
+        buffer.resize(0);
+        return index;
+      },
+    });
+    const length = array.length;
+    assertEq(array.slice(shrink(2)).length, length - 2, `${C.name} slice start`);
+    buffer.resize(24);
+    assertEq(array.slice(0, shrink(2)).length, 2, `${C.name} slice end`);
+  }
+  const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+  const array = new Uint8Array(buffer);
+  array.set([1, 2, 3, 4]);
+  const result = array.slice({
+    valueOf() {
+      buffer.resize(2);
+      return 1;
+    },
+  });
+  assertEq(result.join(), "2,0,0", "slice clamped to shrunk length");
+}
@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+function assertThrows(f, errorType, name) {
+  try {
+    f();
+  } catch (err) {
+    assertEq(err.constructor, errorType, name);
+    return;
+  }
+  throw new Error(`${name}: did not throw`);
+}
+
+// Constructor and prototype intrinsics.
+const sab = new SharedArrayBuffer(8, { maxByteLength: 16 });
+assertEq(Object.getPrototypeOf(sab), SharedArrayBuffer.prototype, "proto");
+assertEq(
+  Object.prototype.toString.call(sab),
+  "[object SharedArrayBuffer]",
+  "toStringTag",
+);
+assertEq(sab.byteLength, 8, "byteLength");
+assertEq(sab.growable, true, "growable");
+assertEq(sab.maxByteLength, 16, "maxByteLength");
+assertEq(new SharedArrayBuffer(4).growable, false, "fixed length");
+assertEq(new SharedArrayBuffer(4).maxByteLength, 4, "fixed maxByteLength");
+assertEq(SharedArrayBuffer[Symbol.species], SharedArrayBuffer, "species");
+assertThrows(() => SharedArrayBuffer(8), TypeError, "call without new");
+assertThrows(
+  () => new SharedArrayBuffer(8, { maxByteLength: 4 }),
+  RangeError,
+  "length over maxByteLength",
+);
+assertThrows(
+  () => ArrayBuffer.prototype.slice.call(sab),
+  TypeError,
+  "ArrayBuffer method on SharedArrayBuffer",
+);
+
+// Views read and write the shared block.
+const i32 = new Int32Array(sab);
+i32[0] = 5;
+assertEq(Atomics.add(i32, 0, 2), 5, "Atomics.add");
+assertEq(Atomics.load(i32, 0), 7, "Atomics.load");
+assertEq(Atomics.compareExchange(i32, 0, 7, 9), 7, "compareExchange");
+assertEq(i32[0], 9, "after compareExchange");
+const dv = new DataView(sab);
+dv.setInt16(4, -2);
+assertEq(dv.getInt16(4), -2, "DataView round trip");
+assertEq(new Uint8Array(sab)[4], 0xff, "DataView writes are shared");
+
+// Growing is observed by length-tracking views.
+sab.grow(16);
+assertEq(sab.byteLength, 16, "grown byteLength");
+assertEq(i32.length, 4, "length-tracking view");
+assertEq(new Int32Array(sab, 0, 2).length, 2, "fixed-length view");
+assertThrows(() => sab.grow(8), RangeError, "shrinking");
+
+// slice copies into a new SharedArrayBuffer.
+const copy = sab.slice(0, 4);
+assertEq(copy instanceof SharedArrayBuffer, true, "slice type");
+assertEq(new Int32Array(copy)[0], 9, "slice contents");
+new Int32Array(copy)[0] = 1;
+assertEq(i32[0], 9, "slice is a copy");
@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name} failed: got ${actual}, expected ${expected}`);
+  }
+}
+
+function assertThrows(errorType, callback, name) {
+  try {
+    callback();
+  } catch (err) {
+    assertEq(err instanceof errorType, true, `${name} error type`);
+    return;
+  }
+  throw new Error(`${name} failed: no error thrown`);
+}
+
+// Element values are read per step.
+{
+  const ta = new Float64Array([0.5, 1.5, 2.5]);
+  let sum = 0;
+  for (const value of ta) {
+    sum += value;
+  }
+  assertEq(sum, 4.5, "sum");
+}
+
+{
+  const ta = new BigInt64Array([1n, -2n]);
+  let result = "";
+  for (const value of ta) {
+    result += typeof value + value;
+  }
+  assertEq(result, "bigint1bigint-2", "BigInt64Array");
+}
+
+{
+  const ta = new Uint8Array([1, 2, 3]);
+  const seen = [];
+  for (const value of ta) {
+    seen.push(value);
+    ta[2] = 9;
+  }
+  assertEq(seen.join(), "1,2,9", "writes during iteration");
+}
+
+{
+  const [a, b, c] = new Int16Array([-1, 2]);
+  assertEq(`${a},${b},${c}`, "-1,2,undefined", "destructuring");
+  assertEq([...new Uint32Array([7, 8])].join(), "7,8", "spread");
+}
+
+// Length-tracking TypedArrays see resizes.
+{
+  const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+  const ta = new Uint8Array(buffer);
+  let count = 0;
+  for (const _ of ta) {
+    if (count++ === 0) {
+      buffer.resize(2);
+    }
+  }
+  assertEq(count, 2, "shrink");
+  count = 0;
+  for (const _ of ta) {
+    if (count++ === 0) {
+      buffer.resize(8);
+    }
+  }
+  assertEq(count, 8, "grow");
+}
+
+// Fixed-length TypedArrays going out of bounds throw.
+{
+  const buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+  const ta = new Uint8Array(buffer, 0, 4);
+  assertThrows(
+    TypeError,
+    () => {
+      for (const _ of ta) {
+        buffer.resize(2);
+      }
+    },
+    "out of bounds",
+  );
+}
+
+// A replaced iterator method is still used.
+{
+  const ta = new Uint8Array([1, 2]);
+  ta[Symbol.iterator] = function* () {
+    yield 42;
+  };
+  const seen = [];
+  for (const value of ta) {
+    seen.push(value);
+  }
+  assertEq(seen.join(), "42", "own iterator method");
+}
+
+// Breaking out of the loop calls a return method installed on the
+// ArrayIterator prototype.
+{
+  const ArrayIteratorPrototype = Object.getPrototypeOf([].values());
+  let returnCalls = 0;
+  ArrayIteratorPrototype.return = function () {
+    returnCalls++;
+    return {};
+  };
+  for (const _ of new Uint8Array([1, 2, 3])) {
+    break;
+  }
+  delete ArrayIteratorPrototype.return;
+  assertEq(returnCalls, 1, "return on break");
+}
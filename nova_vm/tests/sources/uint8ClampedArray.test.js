@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEq(actual, expected, name) {
+  if (actual !== expected) {
+    throw new Error(`${name}: ${String(actual)} !== ${String(expected)}`);
+  }
+}
+
+// Numbers round half to even, clamp to [0, 255] and map NaN to 0.
+const c = new Uint8ClampedArray(8);
+[1.5, 2.5, -3, 300, NaN, 254.5, 0.5, Infinity].forEach((v, i) => {
+  c[i] = v;
+});
+assertEq(String(c), "2,2,0,255,0,254,0,255", "element stores");
+c.fill(3.5);
+assertEq(String(c), "4,4,4,4,4,4,4,4", "fill");
+assertEq(
+  String(Uint8ClampedArray.from([-1, 300, 1.5])),
+  "0,255,2",
+  "from",
+);
+
+// Conversions from other typed arrays clamp instead of wrapping.
+assertEq(
+  String(new Uint8ClampedArray(new Int8Array([-1, -128, 5]))),
+  "0,0,5",
+  "from Int8Array",
+);
+assertEq(
+  String(new Uint8ClampedArray(new Int32Array([-5, 70000, 7]))),
+  "0,255,7",
+  "from Int32Array",
+);
+assertEq(
+  String(new Uint8ClampedArray(new Uint16Array([300, 255, 256]))),
+  "255,255,255",
+  "from Uint16Array",
+);
+assertEq(
+  String(new Uint8ClampedArray(new Float64Array([-1, 300.7, NaN, 2.5]))),
+  "0,255,0,2",
+  "from Float64Array",
+);
+const set = new Uint8ClampedArray(3);
+set.set(new Int16Array([-1, 300, 6]));
+assertEq(String(set), "0,255,6", "set from Int16Array");
+
+// Views of the same buffer.
+const buffer = new ArrayBuffer(16);
+const i16 = new Int16Array(buffer);
+i16.set([-1, 300, 5, 6]);
+const view = new Uint8ClampedArray(buffer, 8, 4);
+view.set(i16.subarray(0, 4));
+assertEq(String(view), "0,255,5,6", "set from same buffer");
+
+// Conversions out of Uint8ClampedArray wrap like other integers.
+assertEq(
+  String(new Int8Array(new Uint8ClampedArray([200]))),
+  "-56",
+  "to Int8Array",
+);
+
+// Bulk operations.
+const u = new Uint8Array([1, 2, 3, 4, 5, 6, 7, 8]);
+u.copyWithin(2, 0, 5);
+assertEq(String(u), "1,2,1,2,3,4,5,8", "copyWithin");
+const v = new Uint16Array([1, 2, 3, 4, 5, 6, 7, 8]);
+v.set(v.subarray(0, 5), 2);
+assertEq(String(v), "1,2,1,2,3,4,5,8", "overlapping set");
@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assert(condition, name) {
+  if (!condition) {
+    throw new Error(`${name} failed`);
+  }
+}
+
+function assertThrows(fn, errorType, name) {
+  try {
+    fn();
+  } catch (err) {
+    assert(err instanceof errorType, name);
+    return;
+  }
+  throw new Error(`${name} did not throw`);
+}
+
+// Captured bindings live in declarative environments.
+(function () {
+  let x = 1;
+  const read = () => x;
+  assert(x++ === 1 && read() === 2, "postfix increment of binding");
+  assert(++x === 3 && read() === 3, "prefix increment of binding");
+  assert(x-- === 3 && read() === 2, "postfix decrement of binding");
+  assert(--x === 1 && read() === 1, "prefix decrement of binding");
+  x = 0.5;
+  assert(++x === 1.5 && read() === 1.5, "increment of fractional binding");
+  x = "7";
+  assert(x++ === 7 && read() === 8, "postfix increment converts strings");
+  x = 10n;
+  assert(++x === 11n && read() === 11n, "increment of BigInt binding");
+  x = 2 ** 53 - 1;
+  assert(++x === 2 ** 53 && read() === 2 ** 53, "increment past safe range");
+  x = -1;
+  assert(Object.is(++x, 0) && Object.is(read(), 0), "increment to zero");
+})();
+
+// Constant and uninitialized bindings keep their errors.
+(function () {
+  const c = 1;
+  const update = () => c++;
+  assertThrows(update, TypeError, "increment of const binding");
+  assert(c === 1, "const binding is unchanged");
+})();
+(function () {
+  const update = () => x++;
+  assertThrows(update, ReferenceError, "increment in temporal dead zone");
+  let x = 0;
+})();
+
+// Compound assignment reads the binding before evaluating the right side.
+(function () {
+  let x = 1;
+  const read = () => x;
+  x += (x = 5, 1);
+  assert(read() === 2, "compound assignment uses the old value");
+  let calls = 0;
+  const object = {
+    get key() {
+      calls++;
+      return "a";
+    },
+  };
+  const target = { a: 1 };
+  target[object.key] += 1;
+  target[object.key]++;
+  assert(target.a === 3 && calls === 2, "member key is evaluated once");
+})();
+
+// Dense Array elements.
+const array = [1, 2.5, "3", , 10n];
+assert(array[0]++ === 1 && array[0] === 2, "postfix increment of element");
+assert(--array[1] === 1.5 && array[1] === 1.5, "prefix decrement of element");
+assert(array["0"]++ === 2 && array[0] === 3, "string index key");
+assert(array[2]++ === 3 && array[2] === 4, "string element is converted");
+assert(Number.isNaN(array[3]++) && Number.isNaN(array[3]), "hole element");
+assert(array[4]-- === 10n && array[4] === 9n, "BigInt element");
+let index = 0;
+array[index++]++;
+assert(index === 1 && array[0] === 4, "computed key is evaluated once");
+
+// Element properties that are not plain writable data.
+const frozen = Object.freeze([1]);
+frozen[0]++;
+assert(frozen[0] === 1, "frozen element is unchanged");
+assertThrows(
+  () => {
+    "use strict";
+    frozen[0]++;
+  },
+  TypeError,
+  "strict increment of frozen element",
+);
+let stored = 5;
+const accessor = [0];
+Object.defineProperty(accessor, 0, {
+  get() {
+    return stored;
+  },
+  set(value) {
+    stored = value * 2;
+  },
+});
+assert(accessor[0]++ === 5 && stored === 12, "accessor element");
+assert(--accessor[0] === 11 && stored === 22, "prefix decrement of accessor");
+accessor[0] += 1;
+assert(stored === 46, "compound assignment of accessor");
+assert(
+  frozen[0]-- === 1 && ++frozen[0] === 2 && (frozen[0] += 1) === 2,
+  "other updates of frozen element",
+);
+assert(frozen[0] === 1, "frozen element is still unchanged");
+const fixed = [1, 2];
+Object.defineProperty(fixed, 0, { writable: false });
+for (let i = 0; i < 3; i++) {
+  fixed[0]++;
+  --fixed[0];
+  fixed[0] += 10;
+}
+assert(fixed[0] === 1 && fixed[1] === 2, "non-writable element is unchanged");
+fixed[1]++;
+assert(fixed[1] === 3, "writable element next to non-writable one");
+assertThrows(
+  () => {
+    "use strict";
+    fixed[0]++;
+  },
+  TypeError,
+  "strict increment of non-writable element",
+);
+const closed = Object.preventExtensions([1, , 3]);
+closed[1]++;
+closed[1] += 1;
+assert(!(1 in closed) && closed.length === 3, "hole of non-extensible array");
+
+// Holes read through and write past the prototype chain.
+Array.prototype[1] = 5;
+const holes = [0, , 2];
+assert(holes[1]++ === 5 && holes[1] === 6, "hole reads prototype element");
+assert(
+  holes.hasOwnProperty(1) && Array.prototype[1] === 5,
+  "hole increment defines own element",
+);
+const compoundHoles = [0, , 2];
+compoundHoles[1] += 1;
+assert(compoundHoles[1] === 6, "compound assignment of hole");
+delete Array.prototype[1];
+let inherited = 10;
+Object.defineProperty(Array.prototype, 1, {
+  get() {
+    return inherited;
+  },
+  set(value) {
+    inherited = value;
+  },
+  configurable: true,
+});
+const setterHoles = [0, , 2];
+assert(setterHoles[1]++ === 10 && inherited === 11, "hole calls setter");
+setterHoles[1] += 4;
+assert(inherited === 15, "compound assignment of hole calls setter");
+assert(!setterHoles.hasOwnProperty(1), "hole with setter stays a hole");
+delete Array.prototype[1];
+
+// Non-Array objects and global bindings use the generic path.
+const typed = new Int8Array([127]);
+typed[0]++;
+assert(typed[0] === -128, "typed array element wraps");
+globalThis.counter = 1;
+counter++;
+assert(globalThis.counter === 2, "global binding");
@@ -0,0 +1,277 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::{create_agent_with, run};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, Error, EvaluationOptions, ExceptionType, GcAgent, HostHooks, Job,
+        RealmRoot, String, Value, script_evaluation,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+};
+
+#[derive(Debug, Default)]
+struct StackCaptureHostHooks {
+    depth: usize,
+    frames: RefCell<Vec<std::string::String>>,
+}
+
+impl HostHooks for StackCaptureHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn debugger_statement(&self, agent: &mut Agent, gc: GcScope) {
+        let frames = agent
+            .capture_stack(self.depth, gc.nogc())
+            .into_iter()
+            .map(|frame| {
+                let name = frame.function_name().map_or_else(
+                    || "<anonymous>".to_owned(),
+                    |name| name.to_string_lossy(agent).into_owned(),
+                );
+                match frame.line_and_column(agent) {
+                    Some((line, column)) => format!("{name} ({line}:{column})"),
+                    None => format!("{name} (native)"),
+                }
+            })
+            .collect::<Vec<_>>();
+        *self.frames.borrow_mut() = frames;
+    }
+}
+
+fn capture(depth: usize, source: &'static str) -> Vec<std::string::String> {
+    let (host_hooks, mut agent, realm) = create_agent_with(
+        StackCaptureHostHooks {
+            depth,
+            ..Default::default()
+        },
+        AgentBuilder::new(),
+    );
+    run(&mut agent, &realm, source);
+    host_hooks.frames.borrow().clone()
+}
+
+#[test]
+fn capture_stack_describes_frames() {
+    let frames = capture(
+        10,
+        r#"function inner() {
+    debugger;
+}
+const obj = {
+    method() { [1].forEach(() => inner()); }
+};
+obj.method();
+"#,
+    );
+    // Method source text starts at the parameter list.
+    assert_eq!(
+        frames,
+        [
+            "inner (1:1)",
+            "<anonymous> (5:28)",
+            "forEach (native)",
+            "method (5:11)",
+            "<anonymous> (1:1)",
+        ]
+    );
+}
+
+#[test]
+fn capture_stack_respects_depth() {
+    let frames = capture(
+        2,
+        r#"function a() { b(); }
+function b() { c(); }
+function c() { debugger; }
+a();
+"#,
+    );
+    assert_eq!(frames, ["c (3:1)", "b (2:1)"]);
+}
+
+#[test]
+fn capture_stack_in_class_field_initializer() {
+    // Class fields are initialized by the implicit constructor, which is a
+    // native function.
+    let frames = capture(
+        10,
+        r#"class Klass {
+    field = (() => { debugger; })();
+}
+new Klass();
+"#,
+    );
+    assert_eq!(
+        frames,
+        [
+            "<anonymous> (2:14)",
+            "Klass (1:1)",
+            "Klass (native)",
+            "<anonymous> (1:1)",
+        ]
+    );
+}
+
+#[derive(Debug, Default)]
+struct PrepareStackTraceHostHooks;
+
+impl HostHooks for PrepareStackTraceHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn prepare_stack_trace<'gc>(
+        &self,
+        agent: &mut Agent,
+        error: Error,
+        header: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Option<String<'gc>> {
+        let frames = error
+            .stack_trace(agent)
+            .iter()
+            .map(|frame| frame.describe(agent))
+            .collect::<Vec<_>>()
+            .join(" <- ");
+        let stack = format!("[{}] {frames}", header.to_string_lossy(agent));
+        Some(String::from_string(agent, stack, gc))
+    }
+}
+
+/// Evaluate source text as a Script registered under "main.js", returning
+/// the result converted to a string.
+fn evaluate(host_hooks: &'static dyn HostHooks, source: &'static str) -> std::string::String {
+    let (mut agent, realm): (GcAgent, RealmRoot) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, source, gc.nogc());
+        let script = agent
+            .load_script(
+                "main.js",
+                source.unbind(),
+                EvaluationOptions::default(),
+                gc.nogc(),
+            )
+            .unwrap()
+            .unbind();
+        let result = script_evaluation(agent, script, gc.reborrow())
+            .unbind()
+            .unwrap();
+        result
+            .to_string(agent, gc)
+            .unwrap()
+            .to_string_lossy(agent)
+            .into_owned()
+    })
+}
+
+#[test]
+fn error_stack_describes_creation_frames() {
+    let stack = evaluate(
+        Box::leak(Box::<StackCaptureHostHooks>::default()),
+        r#"function inner() {
+    return new TypeError("boom");
+}
+function outer() { return [1].map(inner)[0]; }
+outer().stack;
+"#,
+    );
+    assert_eq!(
+        stack,
+        "TypeError: boom
+    at inner (main.js:1:1)
+    at map (native)
+    at outer (main.js:4:1)
+    at <anonymous> (main.js:1:1)"
+    );
+}
+
+#[test]
+fn error_stack_of_thrown_errors() {
+    let stack = evaluate(
+        Box::leak(Box::<StackCaptureHostHooks>::default()),
+        r#"function f() { null.x; }
+try { f(); } catch (err) { err.stack.split("\n").slice(1).join(); }
+"#,
+    );
+    assert_eq!(
+        stack,
+        "    at f (main.js:1:1),    at <anonymous> (main.js:1:1)"
+    );
+}
+
+#[test]
+fn error_stack_is_limited() {
+    let stack = evaluate(
+        Box::leak(Box::<StackCaptureHostHooks>::default()),
+        r#"class MyError extends Error {}
+function recurse(n) { return n === 0 ? new MyError() : recurse(n - 1); }
+const lines = recurse(20).stack.split("\n");
+[lines.length, lines[0], lines[1]].join();
+"#,
+    );
+    assert_eq!(stack, "11,Error,    at MyError (native)");
+}
+
+#[test]
+fn error_stack_accessor() {
+    let result = evaluate(
+        Box::leak(Box::<StackCaptureHostHooks>::default()),
+        r#"const results = [];
+const { get, set } = Object.getOwnPropertyDescriptor(Error.prototype, "stack");
+results.push(get.name, set.name, get.call({}));
+try { get.call(1); } catch (err) { results.push(err.name); }
+try { set.call(new Error()); } catch (err) { results.push(err.name); }
+try { Error.prototype.stack = "x"; } catch (err) { results.push(err.name); }
+const error = new Error("message");
+error.stack = "custom";
+results.push(Object.hasOwn(error, "stack"), error.stack, typeof Error.prototype.stack);
+results.join();
+"#,
+    );
+    assert_eq!(
+        result,
+        "get stack,set stack,,TypeError,TypeError,TypeError,true,custom,undefined"
+    );
+}
+
+#[test]
+fn prepare_stack_trace_formats_stack() {
+    let stack = evaluate(
+        Box::leak(Box::new(PrepareStackTraceHostHooks)),
+        r#"function f() { return new RangeError("out of range"); }
+f().stack;
+"#,
+    );
+    assert_eq!(
+        stack,
+        "[RangeError: out of range] f (main.js:1:1) <- <anonymous> (main.js:1:1)"
+    );
+}
+
+#[test]
+fn capture_stack_trace_from_host_function() {
+    let (_host_hooks, mut agent, realm) =
+        create_agent_with(StackCaptureHostHooks::default(), AgentBuilder::new());
+    agent.run_in_realm(&realm, |agent, gc| {
+        assert!(agent.capture_stack_trace(gc.nogc()).is_empty());
+        let error =
+            agent.create_exception_with_static_message(ExceptionType::Error, "native", gc.nogc());
+        let Value::Error(error) = error else {
+            unreachable!()
+        };
+        assert!(error.stack_trace(agent).is_empty());
+    });
+}
@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, String, StringBuilder},
+    engine::Bindable,
+};
+
+#[test]
+fn string_builder_concatenates_segments() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let small = String::from_small_string("abc");
+        let heap = String::from_static_str(agent, "a heap allocated string", gc);
+
+        let mut builder = StringBuilder::new();
+        assert!(builder.is_empty());
+        builder.push_string(agent, small);
+        builder.push_char('-');
+        builder.push_str("def");
+        let short = builder.finish(agent, gc);
+        assert!(matches!(short, String::SmallString(_)));
+        assert_eq!(short.to_string_lossy(agent), "abc-def");
+
+        let mut builder = StringBuilder::with_capacity(64);
+        builder.push_string(agent, heap);
+        builder.push_str(", then ");
+        builder.push_substring(agent, heap, 2, 6);
+        assert_eq!(builder.len(), 34);
+        let long = builder.finish(agent, gc);
+        assert_eq!(
+            long.to_string_lossy(agent),
+            "a heap allocated string, then heap"
+        );
+    });
+}
+
+#[test]
+fn string_builder_substrings_split_surrogate_pairs() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, "('x\\u{1F600}y')", gc.nogc());
+        let string = agent
+            .run_script(source.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let gc = gc.into_nogc();
+        let string = String::try_from(string).unwrap().bind(gc);
+        assert_eq!(string.utf16_len(agent), 4);
+
+        // Split the surrogate pair and join it back together.
+        let mut builder = StringBuilder::new();
+        builder.push_substring(agent, string, 0, 2);
+        builder.push_substring(agent, string, 2, 4);
+        let joined = builder.finish(agent, gc);
+        assert!(String::eq(agent, joined, string));
+
+        let mut builder = StringBuilder::new();
+        builder.push_substring(agent, string, 2, 4);
+        let trailing = builder.finish(agent, gc);
+        assert_eq!(trailing.utf16_len(agent), 2);
+        assert_eq!(trailing.char_code_at(agent, 0).to_u32(), 0xDE00);
+    });
+}
+
+#[test]
+fn builtins_build_strings_with_lone_surrogates() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(
+            agent,
+            r#"
+            const high = "\uD83D", low = "\uDE00";
+            const results = [
+                [high, low].join("") === "\u{1F600}",
+                [1, high, 2].join(low) === `1${low}${high}${low}2`,
+                `${high}${low}` === "\u{1F600}",
+                "a\u{1F600}b".replace("b", "$`").length === 6,
+                "\u{1F600}".replace(low, "x") === `${high}x`,
+                "a-b-c".replaceAll("-", "$&$&") === "a--b--c",
+                "abc".replace("b", (m) => m.toUpperCase()) === "aBc",
+            ];
+            results.join();
+            "#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let gc = gc.into_nogc();
+        assert_eq!(
+            String::try_from(result.bind(gc))
+                .unwrap()
+                .to_string_lossy(agent),
+            "true,true,true,true,true,true,true"
+        );
+    });
+}
@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, run};
+
+#[test]
+fn equal_strings_from_different_sources() {
+    let (_, mut agent, realm) = create_agent();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        var literal = "medium length string";
+        var sources = [
+            "medium length " + "string",
+            `medium ${"length"} string`,
+            "xmedium length stringx".slice(1, -1),
+            ["medium", "length", "string"].join(" "),
+            JSON.parse('"medium length string"'),
+            String.fromCharCode(...Array.from(literal, (c) => c.charCodeAt(0))),
+        ];
+        sources.map((s) => s === literal && Object.is(s, literal)).join()
+        "#,
+    );
+    assert_eq!(result, "true,true,true,true,true,true");
+}
+
+#[test]
+fn map_keys_survive_collection() {
+    let (_, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var map = new Map();
+        var set = new Set();
+        var object = {};
+        for (let i = 0; i < 100; i++) {
+            const key = "a medium length key " + i;
+            map.set(key, i);
+            set.add(key);
+            object[key] = i;
+        }
+        // Drop some strings to make the collector compact the string heap.
+        for (let i = 0; i < 100; i += 2) {
+            map.delete("a medium length key " + i);
+        }
+        "#,
+    );
+    agent.gc();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        var found = [];
+        for (let i = 95; i < 100; i++) {
+            const key = ["a medium length key", i].join(" ");
+            found.push(map.get(key), set.has(key), object[key]);
+        }
+        found.join()
+        "#,
+    );
+    assert_eq!(result, "95,true,95,,true,96,97,true,97,,true,98,99,true,99");
+}
+
+#[test]
+fn string_comparison() {
+    let (_, mut agent, realm) = create_agent();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        var a = "a medium length string";
+        var b = "a medium length strinG";
+        [a < a, a <= a + "", a > b, b < a, a == "a medium " + "length string"].join()
+        "#,
+    );
+    assert_eq!(result, "false,true,true,true,true");
+}
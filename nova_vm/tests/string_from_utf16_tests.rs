@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, String},
+    engine::Bindable,
+};
+use wtf8::CodePoint;
+
+#[test]
+fn from_utf16_preserves_lone_surrogates() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        let short = String::from_utf16(agent, &[0x61, 0xD800], gc);
+        assert!(matches!(short, String::SmallString(_)));
+        assert_eq!(short.utf16_len(agent), 2);
+        assert_eq!(short.char_code_at(agent, 1).to_u32(), 0xD800);
+
+        let units = [0xDE00, 0x61, 0x62, 0x63, 0xD83D, 0xDE00, 0x64, 0x65, 0xD83D];
+        let long = String::from_utf16(agent, &units, gc);
+        assert!(matches!(long, String::String(_)));
+        assert_eq!(long.utf16_len(agent), units.len());
+        for (i, &unit) in units.iter().enumerate() {
+            assert_eq!(long.char_code_at(agent, i).to_u32(), unit as u32);
+        }
+        assert_eq!(long.code_point_at(agent, 4).to_u32(), 0x1F600);
+
+        let code_points =
+            [0x1F600, 0xD83D, 0xDE00, 0x2D, 0xDE00].map(|cp| CodePoint::from_u32(cp).unwrap());
+        let joined = String::from_code_points(agent, &code_points, gc);
+        assert_eq!(joined.utf16_len(agent), 6);
+        let expected =
+            String::from_utf16(agent, &[0xD83D, 0xDE00, 0xD83D, 0xDE00, 0x2D, 0xDE00], gc);
+        assert!(String::eq(agent, joined, expected));
+    });
+}
+
+#[test]
+fn from_char_code_and_from_code_point_preserve_lone_surrogates() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(
+            agent,
+            r#"
+            const units = (s) => Array.from({ length: s.length }, (_, i) => s.charCodeAt(i)).join(" ");
+            [
+                units(String.fromCharCode(0xDE00, 0xD83D)),
+                units(String.fromCharCode(0x61, 0xD83D, 0xDE00, 0x62, 0x63, 0x64, 0x65, 0xD800)),
+                units(String.fromCodePoint(0xD83D, 0xDE00, 0xDFFF)),
+                String.fromCodePoint(0xD83D, 0xDE00) === "\u{1F600}",
+                String.fromCodePoint(0x1F600, -0, 0x61).length,
+            ].join("|")
+            "#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let gc = gc.into_nogc();
+        assert_eq!(
+            String::try_from(result.bind(gc))
+                .unwrap()
+                .to_string_lossy(agent),
+            "56832 55357\
+            |97 55357 56832 98 99 100 101 55296\
+            |55357 56832 57343\
+            |true\
+            |4"
+        );
+    });
+}
@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, eval, run};
+use nova_vm::{ecmascript::StringReprOptions, engine::Bindable};
+
+/// Evaluates the source and returns its result's string representation.
+fn repr(source: &'static str, options: StringReprOptions) -> std::string::String {
+    let (_, mut agent, realm) = create_agent();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let value = eval(agent, source, gc.reborrow()).unbind();
+        let gc = gc.into_nogc();
+        value
+            .bind(gc)
+            .try_string_repr_with_options(agent, options, gc)
+            .to_string_lossy(agent)
+            .into_owned()
+    })
+}
+
+#[test]
+fn string_repr_respects_max_depth() {
+    let depth = |max_depth| StringReprOptions {
+        max_depth,
+        ..Default::default()
+    };
+    let source = "[1, [2, [3]], 'x', undefined, null, Symbol('s')]";
+    assert_eq!(repr(source, depth(0)), "[object Array]");
+    assert_eq!(repr(source, depth(1)), "1,[object Array],x,,,Symbol(s)");
+    assert_eq!(repr(source, depth(2)), "1,2,[object Array],x,,,Symbol(s)");
+    assert_eq!(repr(source, depth(3)), "1,2,3,x,,,Symbol(s)");
+    // Cycles are bounded by the depth.
+    assert_eq!(
+        repr("var a = [1]; a.push(a); a", depth(3)),
+        "1,1,1,[object Array]"
+    );
+    assert_eq!(repr("new TypeError('boom')", depth(1)), "TypeError: boom");
+    assert_eq!(repr("new TypeError('boom')", depth(0)), "[object Error]");
+    assert_eq!(
+        repr(
+            "var e = new Error('boom'); e.name = ''; e",
+            StringReprOptions::default()
+        ),
+        "boom"
+    );
+}
+
+#[test]
+fn string_repr_respects_max_length() {
+    let length = |max_length| StringReprOptions {
+        max_length,
+        ..Default::default()
+    };
+    assert_eq!(repr("('abcdef')", length(6)), "abcdef");
+    assert_eq!(repr("('abcdef')", length(3)), "abc...");
+    assert_eq!(repr("[12, 34, 56]", length(4)), "12,3...");
+    assert_eq!(repr("123456", length(2)), "12...");
+    assert_eq!(repr("('\\u{1F600}\\u{1F600}')", length(2)), "\u{1F600}...");
+    let long = repr("'a'.repeat(100_000)", StringReprOptions::default());
+    assert_eq!(long.len(), 256 + 3);
+    assert!(long.ends_with("a..."));
+}
+
+#[test]
+fn string_repr_never_calls_getters() {
+    let source = r#"
+        var called = false;
+        var a = [1, 2, 3];
+        Object.defineProperty(a, 1, { get() { called = true; return 5; } });
+        var e = new Error("boom");
+        Object.defineProperty(e, "message", { get() { called = true; return "no"; } });
+        [a, e, { toString() { called = true; return "no"; } }]
+    "#;
+    let options = |skip_getters| StringReprOptions {
+        max_depth: 2,
+        skip_getters,
+        ..Default::default()
+    };
+    assert_eq!(repr(source, options(true)), "1,,3,Error,[object Object]");
+    assert_eq!(
+        repr(source, options(false)),
+        "1,[Getter],3,Error: [Getter],[object Object]"
+    );
+}
+
+#[test]
+fn error_messages_do_not_call_into_javascript() {
+    let (_, mut agent, realm) = create_agent();
+    let result = run(
+        &mut agent,
+        &realm,
+        r#"
+        "use strict";
+        var calls = 0;
+        var object = Object.freeze({
+            toString() { calls++; return "custom"; },
+            valueOf() { calls++; return 1; },
+        });
+        var messages = [];
+        try { object.property = 1; } catch (err) { messages.push(err.message); }
+        try { new object(); } catch (err) { messages.push(err.message); }
+        try { 1 instanceof object; } catch (err) { messages.push(err.message); }
+        try { Object.create(Symbol("s")); } catch (err) { messages.push(err.message); }
+        try { new ("x".repeat(10_000)); } catch (err) { messages.push(err.message.length < 300); }
+        `${calls} ${messages.join("|")}`
+        "#,
+    );
+    assert_eq!(
+        result,
+        "0 Could not set property 'property' of [object Object].\
+        |'[object Object]' is not a constructor.\
+        |Invalid instanceof target [object Object] is not a function.\
+        |Symbol(s) is not an object or null\
+        |true"
+    );
+}
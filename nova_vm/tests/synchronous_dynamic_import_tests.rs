@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, run};
+use nova_vm::ecmascript::{AgentBuilder, EvaluationOptions, GcAgent, RealmRoot, String};
+
+const SCRIPT: &str = r#"
+var log = [];
+import("lib:value").then((ns) => log.push(`fulfilled ${ns.value}`));
+import("lib:throws").catch((err) => log.push(`rejected ${err.message}`));
+log.push("returned");
+"#;
+
+fn create_agent(synchronous_dynamic_import: bool) -> (&'static TestHostHooks, GcAgent, RealmRoot) {
+    let host_hooks: &'static TestHostHooks = Box::leak(Box::default());
+    let (mut agent, realm) = AgentBuilder::new()
+        .with_host_hooks(host_hooks)
+        .with_synchronous_dynamic_import(synchronous_dynamic_import)
+        .build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let gc = gc.into_nogc();
+        for (key, source) in [
+            (
+                "lib:value",
+                "log.push('evaluated'); export const value = 1;",
+            ),
+            ("lib:throws", "throw new Error('oops');"),
+        ] {
+            let source = String::from_static_str(agent, source, gc);
+            agent
+                .load_module(key, source, EvaluationOptions::default(), gc)
+                .unwrap();
+        }
+    });
+    run(&mut agent, &realm, SCRIPT);
+    (host_hooks, agent, realm)
+}
+
+/// Run the promise jobs that are currently queued, but not the jobs that they
+/// queue in turn.
+fn run_queued_jobs(host_hooks: &TestHostHooks, agent: &mut GcAgent, realm: &RealmRoot) {
+    let jobs = host_hooks.promise_jobs.take();
+    agent.run_in_realm(realm, |agent, mut gc| {
+        for job in jobs {
+            job.run(agent, gc.reborrow()).unwrap();
+        }
+    });
+}
+
+#[test]
+fn dynamic_import_settles_synchronously() {
+    let (host_hooks, mut agent, realm) = create_agent(true);
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "evaluated,returned");
+    // Both promises are already settled, so their reactions run right away.
+    run_queued_jobs(host_hooks, &mut agent, &realm);
+    assert_eq!(
+        run(&mut agent, &realm, "log.join()"),
+        "evaluated,returned,fulfilled 1,rejected oops"
+    );
+}
+
+#[test]
+fn dynamic_import_evaluates_in_a_job_by_default() {
+    let (host_hooks, mut agent, realm) = create_agent(false);
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "returned");
+    run_queued_jobs(host_hooks, &mut agent, &realm);
+    assert_eq!(run(&mut agent, &realm, "log.join()"), "returned,evaluated");
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(
+        run(&mut agent, &realm, "log.join()"),
+        "returned,evaluated,fulfilled 1,rejected oops"
+    );
+}
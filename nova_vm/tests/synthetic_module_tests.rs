@@ -0,0 +1,162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::{create_agent_with, run, run_module};
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, GcAgent, GraphLoadingStateRecord, HostDefined, HostHooks, Job,
+        ModuleRequest, Number, RealmRoot, Referrer, String, SyntheticModule, Value,
+        finish_loading_imported_module, parse_module,
+    },
+    engine::{Bindable, Global, NoGcScope},
+};
+
+/// Host hooks that resolve every module request to a single synthetic module.
+#[derive(Debug, Default)]
+struct SyntheticHostHooks {
+    module: RefCell<Option<Global<SyntheticModule<'static>>>>,
+}
+
+impl HostHooks for SyntheticHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: ModuleRequest<'gc>,
+        _host_defined: Option<HostDefined>,
+        payload: &mut GraphLoadingStateRecord<'gc>,
+        gc: NoGcScope<'gc, '_>,
+    ) {
+        let module = self
+            .module
+            .borrow()
+            .as_ref()
+            .expect("Synthetic module was not created")
+            .get(agent, gc);
+        finish_loading_imported_module(
+            agent,
+            referrer,
+            module_request,
+            payload,
+            Ok(module.into()),
+            gc,
+        );
+    }
+}
+
+fn create_agent(
+    export_names: &'static [&'static str],
+) -> (GcAgent, RealmRoot, &'static SyntheticHostHooks) {
+    let (hooks, mut agent, realm) =
+        create_agent_with(SyntheticHostHooks::default(), AgentBuilder::new());
+    agent.run_in_realm(&realm, |agent, gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let module = SyntheticModule::new(agent, realm, export_names, None, gc.nogc());
+        module.set_export(agent, export_names[0], Number::from(1).into(), gc.nogc());
+        *hooks.module.borrow_mut() = Some(Global::new(agent, module.unbind()));
+    });
+    (agent, realm, hooks)
+}
+
+fn set_export(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    hooks: &SyntheticHostHooks,
+    name: &str,
+    value: f64,
+) {
+    agent.run_in_realm(realm, |agent, gc| {
+        let module = hooks
+            .module
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .get(agent, gc.nogc());
+        let value = Value::from_f64(agent, value, gc.nogc());
+        module.set_export(agent, name, value, gc.nogc());
+    });
+}
+
+#[test]
+fn source_text_modules_import_synthetic_exports() {
+    let (mut agent, realm, hooks) = create_agent(&["value", "other", "value"]);
+    set_export(&mut agent, &realm, hooks, "other", 2.0);
+    run_module(
+        &mut agent,
+        &realm,
+        r#"
+        import { value, other } from "synthetic";
+        import * as ns from "synthetic";
+        if (value !== 1 || other !== 2) throw new Error("Unexpected values");
+        if (Object.keys(ns).join() !== "other,value") {
+            throw new Error("Unexpected exports " + Object.keys(ns).join());
+        }
+        globalThis.read = () => value;
+        "#,
+    );
+    // Exports are live bindings.
+    set_export(&mut agent, &realm, hooks, "value", 3.0);
+    run(
+        &mut agent,
+        &realm,
+        r#"if (read() !== 3) throw new Error("Export is not live: " + read());"#,
+    );
+}
+
+#[test]
+fn synthetic_modules_can_be_evaluated_before_import() {
+    let (mut agent, realm, hooks) = create_agent(&["value"]);
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let module = hooks
+            .module
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .get(agent, gc.nogc());
+        module.link(agent, gc.nogc()).unwrap();
+        let _ = module.evaluate(agent, gc.reborrow());
+    });
+    set_export(&mut agent, &realm, hooks, "value", 4.0);
+    run_module(
+        &mut agent,
+        &realm,
+        r#"
+        import { value } from "synthetic";
+        if (value !== 4) throw new Error("Unexpected value " + value);
+        "#,
+    );
+}
+
+#[test]
+fn importing_missing_synthetic_export_fails_to_link() {
+    let (mut agent, realm, _) = create_agent(&["value"]);
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text =
+            String::from_static_str(agent, r#"import { missing } from "synthetic";"#, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc()).unwrap();
+        assert!(
+            agent
+                .run_module(module.unbind(), None, gc.reborrow())
+                .is_err()
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "does not export 'missing'")]
+fn setting_missing_synthetic_export_panics() {
+    let (mut agent, realm, hooks) = create_agent(&["value"]);
+    set_export(&mut agent, &realm, hooks, "missing", 0.0);
+}
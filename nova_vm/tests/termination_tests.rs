@@ -0,0 +1,429 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use core::time::Duration;
+use std::thread;
+
+use common::{create_agent, define_global, run, run_with_jobs};
+
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentBuilder, ArgumentsList, Behaviour, BuiltinFunctionArgs, InternalMethods,
+        JsResult, PropertyDescriptor, PropertyKey, String, TimedOut, Value,
+        create_builtin_function,
+    },
+    engine::{Bindable, GcScope},
+};
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+#[test]
+fn evaluate_with_timeout_returns_completed_result() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let source_text = String::from_static_str(agent, "1 + 2", gc.nogc());
+        let result = agent
+            .evaluate_with_timeout(source_text.unbind(), Duration::from_secs(10), gc)
+            .expect("Evaluation should not time out");
+        assert_eq!(result.unwrap(), Value::from(3));
+    });
+}
+
+#[test]
+fn evaluate_with_timeout_terminates_infinite_loop() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let source_text = String::from_static_str(agent, "while (true) {}", gc.nogc());
+        let result = agent.evaluate_with_timeout(source_text.unbind(), TIMEOUT, gc);
+        assert_eq!(result.err(), Some(TimedOut));
+    });
+}
+
+#[test]
+fn termination_skips_catch_and_finally() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text = String::from_static_str(
+            agent,
+            r#"
+            try {
+                for (;;) {}
+            } catch {
+                globalThis.caught = true;
+            } finally {
+                globalThis.finalized = true;
+            }
+            "#,
+            gc.nogc(),
+        );
+        let result = agent.evaluate_with_timeout(source_text.unbind(), TIMEOUT, gc.reborrow());
+        assert_eq!(result.err(), Some(TimedOut));
+        // The Agent can run code after the termination.
+        let source_text = String::from_static_str(
+            agent,
+            "typeof caught === 'undefined' && typeof finalized === 'undefined'",
+            gc.nogc(),
+        );
+        let result = agent.run_script(source_text.unbind(), gc).unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    });
+}
+
+#[test]
+fn evaluate_with_timeout_keeps_termination_requested_by_others() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let handle = agent.termination_handle();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(terminate),
+            BuiltinFunctionArgs::new(0, "terminate"),
+            gc.nogc(),
+        );
+        define_global(agent, "terminate", function.unbind().into(), gc.reborrow());
+        let source_text = String::from_static_str(agent, "terminate(); for (;;) {}", gc.nogc());
+        let result = agent
+            .evaluate_with_timeout(source_text.unbind(), Duration::from_secs(10), gc)
+            .expect("Evaluation should not time out");
+        assert!(result.is_err());
+    });
+    // The termination was not requested by the watchdog, so it is not
+    // cancelled.
+    assert!(handle.is_terminating());
+}
+
+#[test]
+fn termination_handle_interrupts_running_script() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let handle = agent.termination_handle();
+    let terminator = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            thread::sleep(TIMEOUT);
+            handle.terminate();
+        })
+    };
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text = String::from_static_str(
+            agent,
+            "function spin() { while (true) {} } spin();",
+            gc.nogc(),
+        );
+        let error = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap_err()
+            .unbind();
+        let message = error
+            .to_string(agent, gc)
+            .to_string_lossy(agent)
+            .into_owned();
+        assert_eq!(message, "Error: Execution terminated");
+    });
+    terminator.join().unwrap();
+    assert!(handle.is_terminating());
+    handle.cancel();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let source_text =
+            String::from_static_str(agent, "for (let i = 0; i < 10; i++) {}", gc.nogc());
+        assert!(agent.run_script(source_text.unbind(), gc).is_ok());
+    });
+}
+
+fn terminate<'gc>(
+    agent: &mut Agent,
+    _this: Value,
+    _args: ArgumentsList,
+    _gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    agent.terminate_execution();
+    Ok(Value::Undefined)
+}
+
+/// Run a script with a global `terminate` function that requests termination
+/// and assert that the script is terminated. After cancelling the termination,
+/// assert that `check` evaluates to true.
+fn assert_script_terminated(source: &'static str, check: &'static str) {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let handle = agent.termination_handle();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(terminate),
+            BuiltinFunctionArgs::new(0, "terminate"),
+            gc.nogc(),
+        );
+        let key = PropertyKey::from_static_str(agent, "terminate", gc.nogc());
+        let global = agent.current_global_object(gc.nogc());
+        global
+            .unbind()
+            .internal_define_own_property(
+                agent,
+                key.unbind(),
+                PropertyDescriptor::data(Value::from(function.unbind()))
+                    .writable()
+                    .configurable()
+                    .build(),
+                gc.reborrow(),
+            )
+            .unwrap();
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        let error = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap_err()
+            .unbind();
+        let message = error
+            .to_string(agent, gc.reborrow())
+            .to_string_lossy(agent)
+            .into_owned();
+        assert_eq!(message, "Error: Execution terminated");
+        assert!(handle.is_terminating());
+        handle.cancel();
+        let source_text = String::from_static_str(agent, check, gc.nogc());
+        let result = agent.run_script(source_text.unbind(), gc).unwrap();
+        assert_eq!(result, Value::Boolean(true), "{check}");
+    });
+}
+
+#[test]
+fn terminate_execution_interrupts_array_callback_loop() {
+    assert_script_terminated(
+        r#"
+        var seen = [];
+        try {
+            [1, 2, 3, 4].forEach(v => {
+                seen.push(v);
+                if (v === 2) terminate();
+            });
+        } catch {
+            seen.push("caught");
+        }
+        "#,
+        "seen.join() === '1,2'",
+    );
+}
+
+#[test]
+fn terminate_execution_interrupts_json_stringify() {
+    assert_script_terminated(
+        r#"
+        var seen = [];
+        JSON.stringify({ a: 1, b: [2, 3, 4], c: 5 }, (key, value) => {
+            seen.push(key);
+            if (key === "1") terminate();
+            return value;
+        });
+        "#,
+        "seen.join() === ',a,b,0,1'",
+    );
+}
+
+#[test]
+fn terminate_execution_interrupts_json_parse_reviver() {
+    assert_script_terminated(
+        r#"
+        var seen = [];
+        JSON.parse("[1, 2, 3]", (key, value) => {
+            seen.push(key);
+            if (key === "0") terminate();
+            return value;
+        });
+        "#,
+        "seen.join() === '0'",
+    );
+}
+
+#[test]
+fn terminate_execution_interrupts_global_reg_exp_matching() {
+    assert_script_terminated(
+        r#"
+        var execs = 0;
+        class CountingRegExp extends RegExp {
+            exec(s) {
+                execs++;
+                if (execs === 2) terminate();
+                return super.exec(s);
+            }
+        }
+        "aaaa".replace(new CountingRegExp("a", "g"), "b");
+        "#,
+        "execs === 2",
+    );
+}
+
+#[test]
+fn termination_handle_interrupts_builtin_loop() {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    let terminator = {
+        let handle = agent.termination_handle();
+        thread::spawn(move || {
+            thread::sleep(TIMEOUT);
+            handle.terminate();
+        })
+    };
+    agent.run_in_realm(&realm, |agent, gc| {
+        // Note: the loop runs entirely in the builtin, without running any
+        // JavaScript code.
+        let source_text = String::from_static_str(
+            agent,
+            "Array.prototype.indexOf.call({ length: 2 ** 53 - 1 }, 1);",
+            gc.nogc(),
+        );
+        assert!(agent.run_script(source_text.unbind(), gc).is_err());
+    });
+    terminator.join().unwrap();
+}
+
+/// Evaluate a script that loops forever with a timeout and assert that the
+/// script does not continue after the loop is terminated.
+fn assert_timed_out_without_continuing(source: &'static str) {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text = String::from_static_str(agent, source, gc.nogc());
+        let result = agent.evaluate_with_timeout(source_text.unbind(), TIMEOUT, gc.reborrow());
+        assert_eq!(result.err(), Some(TimedOut));
+        let source_text =
+            String::from_static_str(agent, "typeof continued === 'undefined'", gc.nogc());
+        let result = agent.run_script(source_text.unbind(), gc).unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    });
+}
+
+#[test]
+fn termination_aborts_promise_executor() {
+    assert_timed_out_without_continuing(
+        "new Promise(() => { while (true) {} }); globalThis.continued = true;",
+    );
+}
+
+#[test]
+fn termination_aborts_async_function() {
+    assert_timed_out_without_continuing(
+        "(async () => { while (true) {} })(); globalThis.continued = true;",
+    );
+}
+
+#[test]
+fn termination_aborts_async_generator() {
+    assert_timed_out_without_continuing(
+        "(async function* () { while (true) {} })().next(); globalThis.continued = true;",
+    );
+}
+
+/// Run a script whose Jobs loop forever and terminate them from another
+/// thread. Assert that the termination is reported as a Job error instead of
+/// rejecting a Promise.
+fn assert_jobs_terminated(source: &'static str) {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(&mut agent, &realm, source);
+    let handle = agent.termination_handle();
+    let terminator = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            thread::sleep(TIMEOUT);
+            handle.terminate();
+        })
+    };
+    agent.perform_microtask_checkpoint(&realm);
+    terminator.join().unwrap();
+    assert_eq!(
+        *host_hooks.job_errors.borrow(),
+        ["Error: Execution terminated"]
+    );
+    handle.cancel();
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(run(&mut agent, &realm, "rejected"), "false");
+    assert!(host_hooks.unhandled_rejections.borrow().is_empty());
+}
+
+#[test]
+fn termination_aborts_promise_reaction_job() {
+    assert_jobs_terminated(
+        r#"
+        var rejected = false;
+        Promise.resolve()
+            .then(() => { while (true) {} })
+            .catch(() => { rejected = true; });
+        "#,
+    );
+}
+
+#[test]
+fn termination_aborts_async_function_after_await() {
+    assert_jobs_terminated(
+        r#"
+        var rejected = false;
+        (async () => {
+            await null;
+            while (true) {}
+        })().catch(() => { rejected = true; });
+        "#,
+    );
+}
+
+#[test]
+fn termination_aborts_async_generator_after_await() {
+    assert_jobs_terminated(
+        r#"
+        var rejected = false;
+        (async function* () {
+            await null;
+            while (true) {}
+        })().next().catch(() => { rejected = true; });
+        "#,
+    );
+}
+
+#[test]
+fn errors_in_flight_when_termination_is_requested_reject_promises() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    let handle = agent.termination_handle();
+    agent.run_in_realm(&realm, |agent, gc| {
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(terminate),
+            BuiltinFunctionArgs::new(0, "terminate"),
+            gc.nogc(),
+        );
+        define_global(agent, "terminate", function.unbind().into(), gc);
+    });
+    // The TypeErrors are thrown before or after termination is requested,
+    // and are still propagating while it is requested. They are not the
+    // termination error, so they reject their Promises.
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var executor = new Promise(() => {
+            try {
+                null.x;
+            } finally {
+                terminate();
+            }
+        });
+        var asyncFunction = (async () => {
+            terminate();
+            null.x;
+        })();
+        "#,
+    );
+    assert!(handle.is_terminating());
+    handle.cancel();
+    run_with_jobs(
+        &mut agent,
+        &realm,
+        r#"
+        var reasons = [];
+        for (const promise of [executor, asyncFunction]) {
+            promise.catch((err) => reasons.push(err.constructor.name));
+        }
+        "#,
+    );
+    assert_eq!(
+        run(&mut agent, &realm, "reasons.join()"),
+        "TypeError,TypeError"
+    );
+    assert!(host_hooks.job_errors.borrow().is_empty());
+}
@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{TestHostHooks, create_agent_with, define_global, run};
+use nova_vm::{
+    ecmascript::{AgentBuilder, Promise, String, Value, parse_module},
+    engine::Bindable,
+};
+
+/// The observable outcome of running a module graph.
+#[derive(Debug, PartialEq)]
+struct Outcome {
+    /// Whether evaluation was still pending when [`Agent::run_module`]
+    /// returned.
+    pending: bool,
+    /// Contents of the global `log` array after the job queue was drained.
+    log: std::string::String,
+}
+
+/// Run the main module of the given sources, draining the job queue
+/// afterwards. The settlement of the evaluation is appended to the log.
+fn run_module_graph(sources: &'static [(&'static str, &'static str)]) -> Outcome {
+    let (_, mut agent, realm) =
+        create_agent_with(TestHostHooks::with_sources(sources), AgentBuilder::new());
+    let (main, _) = sources[0];
+    assert_eq!(main, "main");
+    run(&mut agent, &realm, "globalThis.log = [];");
+    let pending = agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(agent, sources[0].1, gc.nogc());
+        let module = parse_module(agent, source_text, realm, None, gc.nogc())
+            .unwrap()
+            .unbind();
+        let result = agent
+            .run_module(module, None, gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        let (evaluation, pending) = match result {
+            Ok(Value::Promise(promise)) => (Value::from(promise), true),
+            Ok(Value::Undefined) => (Promise::new_resolved(agent, Value::Undefined).into(), false),
+            Ok(_) => panic!("Unexpected module evaluation result"),
+            Err(err) => (
+                Promise::new_rejected(agent, err.value(), gc.nogc()).into(),
+                false,
+            ),
+        };
+        define_global(agent, "evaluation", evaluation.unbind(), gc);
+        pending
+    });
+    run(
+        &mut agent,
+        &realm,
+        "evaluation.then(() => log.push('fulfilled'), (e) => log.push('rejected: ' + e));",
+    );
+    agent.perform_microtask_checkpoint(&realm);
+    let log = run(&mut agent, &realm, "log.join()");
+    Outcome { pending, log }
+}
+
+#[test]
+fn synchronous_module_graph_is_fulfilled() {
+    let outcome = run_module_graph(&[
+        ("main", "import { x } from './a.js'; log.push('main ' + x);"),
+        ("./a.js", "log.push('a'); export const x = 1;"),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: false,
+            log: "a,main 1,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn top_level_await_in_main_module() {
+    let outcome = run_module_graph(&[(
+        "main",
+        "log.push('before'); await Promise.resolve(); log.push('after');",
+    )]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "before,after,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn importer_waits_for_async_dependency() {
+    let outcome = run_module_graph(&[
+        ("main", "import { x } from './a.js'; log.push('main ' + x);"),
+        (
+            "./a.js",
+            "export let x = 0; log.push('a start'); await null; x = 1; log.push('a end');",
+        ),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "a start,a end,main 1,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn synchronous_sibling_runs_before_async_dependency_finishes() {
+    let outcome = run_module_graph(&[
+        (
+            "main",
+            "import './a.js'; import './b.js'; log.push('main');",
+        ),
+        (
+            "./a.js",
+            "log.push('a start'); await null; await null; log.push('a end');",
+        ),
+        ("./b.js", "log.push('b');"),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "a start,b,a end,main,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn async_modules_execute_in_evaluation_order() {
+    let outcome = run_module_graph(&[
+        (
+            "main",
+            "import './a.js'; import './b.js'; log.push('main');",
+        ),
+        ("./a.js", "import './c.js'; log.push('a');"),
+        (
+            "./b.js",
+            "import './c.js'; log.push('b'); await null; log.push('b end');",
+        ),
+        (
+            "./c.js",
+            "log.push('c start'); await null; log.push('c end');",
+        ),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "c start,c end,a,b,b end,main,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn async_cycle_evaluates_through_cycle_root() {
+    let outcome = run_module_graph(&[
+        ("main", "import './a.js'; log.push('main');"),
+        ("./a.js", "import './b.js'; log.push('a');"),
+        (
+            "./b.js",
+            "import './a.js'; log.push('b start'); await null; log.push('b end');",
+        ),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "b start,b end,a,main,fulfilled".into(),
+        }
+    );
+}
+
+#[test]
+fn async_dependency_rejection_skips_importers() {
+    let outcome = run_module_graph(&[
+        (
+            "main",
+            "import './a.js'; import './b.js'; log.push('main');",
+        ),
+        ("./a.js", "await null; throw new Error('boom');"),
+        ("./b.js", "import './a.js'; log.push('b');"),
+    ]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "rejected: Error: boom".into(),
+        }
+    );
+}
+
+#[test]
+fn awaited_rejection_rejects_evaluation() {
+    let outcome = run_module_graph(&[(
+        "main",
+        "try { await Promise.reject(1); } catch (e) { log.push('caught ' + e); }
+        await Promise.reject(new TypeError('uncaught'));
+        log.push('unreachable');",
+    )]);
+    assert_eq!(
+        outcome,
+        Outcome {
+            pending: true,
+            log: "caught 1,rejected: TypeError: uncaught".into(),
+        }
+    );
+}
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{fs, path::PathBuf};
+
+use nova_vm::{
+    ecmascript::{
+        AgentOptions, DefaultHostHooks, GcAgent, String, parse_script, script_evaluation,
+    },
+    engine::Bindable,
+};
+
+#[test]
+fn typed_array_for_of_tests() {
+    let d: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "sources",
+        "typedArrayForOf.test.js",
+    ]
+    .iter()
+    .collect();
+    let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
+
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_string(agent, contents, gc.nogc());
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        if let Err(err) = script_evaluation(agent, script.unbind(), gc.reborrow()) {
+            panic!(
+                "Test '{}' failed: {:?}",
+                d.display(),
+                err.unbind().to_string(agent, gc).to_string_lossy(agent)
+            )
+        }
+    });
+}
+
+#[test]
+fn for_of_throws_after_detach() {
+    use nova_vm::ecmascript::{
+        Agent, ArgumentsList, Behaviour, BuiltinFunctionArgs, InternalMethods, JsResult,
+        PropertyDescriptor, PropertyKey, Value, create_builtin_function,
+    };
+    use nova_vm::engine::GcScope;
+
+    fn detach<'gc>(
+        agent: &mut Agent,
+        _this: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let Value::ArrayBuffer(buffer) = args.get(0) else {
+            panic!("Expected an ArrayBuffer");
+        };
+        buffer.detach(agent, None, gc.into_nogc())?;
+        Ok(Value::Undefined)
+    }
+
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(detach),
+            BuiltinFunctionArgs::new(1, "detach"),
+            gc.nogc(),
+        );
+        let key = PropertyKey::from_static_str(agent, "detach", gc.nogc());
+        let global = agent.current_global_object(gc.nogc());
+        global
+            .unbind()
+            .internal_define_own_property(
+                agent,
+                key.unbind(),
+                PropertyDescriptor::data(Value::from(function.unbind()))
+                    .writable()
+                    .configurable()
+                    .build(),
+                gc.reborrow(),
+            )
+            .unwrap();
+        let source_text = String::from_static_str(
+            agent,
+            r#"
+            const ta = new Int32Array([1, 2, 3]);
+            const seen = [];
+            let error;
+            try {
+                for (const value of ta) {
+                    seen.push(value);
+                    detach(ta.buffer);
+                }
+            } catch (err) {
+                error = err;
+            }
+            error instanceof TypeError && seen.join() === "1"
+            "#,
+            gc.nogc(),
+        );
+        let result = agent.run_script(source_text.unbind(), gc).unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    });
+}
@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, define_global, eval, run};
+use nova_vm::{
+    ecmascript::{BigInt64Array, Float64Array, Uint8Array},
+    engine::Bindable,
+};
+
+#[test]
+fn typed_arrays_round_trip_through_slices() {
+    let (_, mut agent, realm) = create_agent();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let bytes = Uint8Array::from_slice(agent, &[1, 2, 3, 255], gc.nogc()).unwrap();
+        assert_eq!(bytes.to_vec(agent), vec![1, 2, 3, 255]);
+        define_global(agent, "bytes", bytes.unbind().into(), gc.reborrow());
+        let floats = Float64Array::from_slice(agent, &[0.5, -1.25], gc.nogc()).unwrap();
+        define_global(agent, "floats", floats.unbind().into(), gc.reborrow());
+        let bigints = BigInt64Array::from_slice(agent, &[i64::MIN, 7], gc.nogc()).unwrap();
+        define_global(agent, "bigints", bigints.unbind().into(), gc);
+    });
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        if (!(bytes instanceof Uint8Array) || bytes.join() !== "1,2,3,255") {
+            throw new Error("Unexpected bytes " + bytes);
+        }
+        if (!(floats instanceof Float64Array) || floats.join() !== "0.5,-1.25") {
+            throw new Error("Unexpected floats " + floats);
+        }
+        if (!(bigints instanceof BigInt64Array) || bigints[0] !== -(2n ** 63n) || bigints[1] !== 7n) {
+            throw new Error("Unexpected bigints " + bigints);
+        }
+        if (bytes.buffer.byteLength !== 4 || floats.buffer.byteLength !== 16) {
+            throw new Error("Unexpected buffer lengths");
+        }
+        bytes[0] = 42;
+        floats[1] = 3;
+        bigints[1] = -1n;
+        "#,
+    );
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let bytes = Uint8Array::try_from(eval(agent, "bytes", gc.reborrow())).unwrap();
+        assert_eq!(bytes.to_vec(agent), vec![42, 2, 3, 255]);
+        let floats = Float64Array::try_from(eval(agent, "floats", gc.reborrow())).unwrap();
+        assert_eq!(floats.to_vec(agent), vec![0.5, 3.0]);
+        let bigints = BigInt64Array::try_from(eval(agent, "bigints", gc.reborrow())).unwrap();
+        assert_eq!(bigints.to_vec(agent), vec![i64::MIN, -1]);
+    });
+}
+
+#[test]
+fn empty_and_detached_typed_arrays_yield_empty_vecs() {
+    let (_, mut agent, realm) = create_agent();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let empty = Uint8Array::from_slice(agent, &[], gc.nogc()).unwrap();
+        assert!(empty.to_vec(agent).is_empty());
+
+        let detached = Float64Array::from_slice(agent, &[1.0, 2.0], gc.nogc()).unwrap();
+        define_global(agent, "detached", detached.unbind().into(), gc.reborrow());
+        let detached = Float64Array::try_from(eval(
+            agent,
+            "detached.buffer.transfer(); detached",
+            gc.reborrow(),
+        ))
+        .unwrap();
+        assert!(detached.to_vec(agent).is_empty());
+    });
+}
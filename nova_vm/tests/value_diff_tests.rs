@@ -0,0 +1,102 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentBuilder, String},
+    engine::{Bindable, Scopable},
+    testing::{assert_values_equal, diff_values, values_equal},
+};
+
+/// Evaluates both sources and returns the diff of their results.
+fn diff(left: &'static str, right: &'static str) -> Option<std::string::String> {
+    let (mut agent, realm) = AgentBuilder::new().build_with_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, left, gc.nogc());
+        let left = agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+        let left = left.unbind().scope(agent, gc.nogc());
+        let source = String::from_static_str(agent, right, gc.nogc());
+        let right = agent
+            .run_script(source.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let gc = gc.into_nogc();
+        let left = left.get(agent);
+        let diff = diff_values(agent, left, right, gc).map(|diff| diff.to_string());
+        assert_eq!(values_equal(agent, left, right, gc), diff.is_none());
+        if diff.is_none() {
+            assert_values_equal(agent, left, right, gc);
+        }
+        diff
+    })
+}
+
+#[test]
+fn equal_values() {
+    assert_eq!(diff("NaN", "NaN"), None);
+    assert_eq!(diff("('abc')", "'ab' + 'c'"), None);
+    assert_eq!(diff("10n ** 20n", "100000000000000000000n"), None);
+    assert_eq!(
+        diff(
+            "({ a: [1, { b: 'x' }], c: null })",
+            "({ a: [1, { b: 'x' }], c: null })"
+        ),
+        None
+    );
+}
+
+#[test]
+fn primitive_differences() {
+    assert_eq!(diff("0", "-0").as_deref(), Some("$: 0 !== -0\n"));
+    assert_eq!(diff("('a')", "1").as_deref(), Some("$: \"a\" !== 1\n"));
+    assert_eq!(diff("1n", "2n").as_deref(), Some("$: 1n !== 2n\n"));
+    assert_eq!(
+        diff("undefined", "null").as_deref(),
+        Some("$: undefined !== null\n")
+    );
+}
+
+#[test]
+fn object_differences() {
+    assert_eq!(
+        diff(
+            "({ a: 1, 'b c': [1, 2], d: 3 })",
+            "({ a: 2, 'b c': [1], e: 3 })"
+        )
+        .as_deref(),
+        Some(concat!(
+            "$.a: 1 !== 2\n",
+            "$[\"b c\"][1]: 2 !== <missing>\n",
+            "$[\"b c\"].length: 2 !== 1\n",
+            "$.d: 3 !== <missing>\n",
+            "$.e: <missing> !== 3\n",
+        ))
+    );
+    assert_eq!(
+        diff("({})", "Object.create(null)").as_deref(),
+        Some("$.[[Prototype]]: [object Object] !== null\n")
+    );
+    assert_eq!(
+        diff("(function () {})", "(function () {})").as_deref(),
+        Some("$: [Function] !== [Function]\n")
+    );
+}
+
+#[test]
+fn cyclic_values() {
+    assert_eq!(
+        diff(
+            "var a = { name: 'a' }; a.self = a; a",
+            "var b = { name: 'a' }; b.self = b; b"
+        ),
+        None
+    );
+    assert_eq!(
+        diff(
+            "var a = [1]; a.push(a); a",
+            "var b = [2]; b.push([2, b]); b"
+        )
+        .as_deref(),
+        Some("$[0]: 1 !== 2\n$[1][0]: 1 !== 2\n")
+    );
+}
@@ -0,0 +1,152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use common::{create_agent, run};
+use nova_vm::{
+    ecmascript::{String, Value},
+    engine::Bindable,
+};
+
+#[test]
+fn weak_ref_target_is_collected_after_turn() {
+    let (_, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        "var kept = {}; var keptRef = new WeakRef(kept); var ref = new WeakRef({});",
+    );
+    agent.gc();
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            "[ref.deref(), keptRef.deref() === kept].join()"
+        ),
+        ",true"
+    );
+}
+
+#[test]
+fn weak_ref_deref_keeps_target_alive_until_turn_ends() {
+    let (_, mut agent, realm) = create_agent();
+    // Creating a WeakRef adds its target to the kept objects; the target is
+    // only collectable after the turn that created it ends.
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source = String::from_static_str(agent, "var ref = new WeakRef({ a: 1 });", gc.nogc());
+        agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+        agent.gc(gc.reborrow());
+        let source = String::from_static_str(agent, "ref.deref().a", gc.nogc());
+        let value = agent.run_script(source.unbind(), gc.reborrow()).unwrap();
+        assert_eq!(value.unbind(), Value::from(1));
+    });
+    agent.gc();
+    assert_eq!(run(&mut agent, &realm, "typeof ref.deref()"), "undefined");
+}
+
+#[test]
+fn finalization_registry_cleans_up_every_collected_target() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        var registry = new FinalizationRegistry((held) => log.push(held));
+        var token = {};
+        registry.register({}, "a");
+        registry.register({}, "b");
+        registry.register({}, "c", token);
+        registry.register({}, "d", token);
+        registry.unregister(token);
+        (function () {
+            var target = {};
+            registry.register(target, "e");
+            registry.register(target, "f");
+        })();
+        "#,
+    );
+    agent.gc();
+    assert_eq!(host_hooks.cleanup_jobs.len(), 1);
+    agent.perform_microtask_checkpoint(&realm);
+    assert!(host_hooks.cleanup_jobs.is_empty());
+    assert_eq!(run(&mut agent, &realm, "log.sort().join()"), "a,b,e,f");
+}
+
+#[test]
+fn finalization_registry_cleanup_errors_are_reported() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        var registry = new FinalizationRegistry((held) => {
+            log.push(held);
+            if (log.length === 1) {
+                throw new Error("cleanup failed");
+            }
+        });
+        registry.register({}, "a");
+        registry.register({}, "b");
+        registry.register({}, "c");
+        "#,
+    );
+    agent.gc();
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(
+        host_hooks.job_errors.borrow().as_slice(),
+        ["Error: cleanup failed"]
+    );
+    // The held values left over after the error are cleaned up by a new Job.
+    assert!(host_hooks.cleanup_jobs.is_empty());
+    assert_eq!(run(&mut agent, &realm, "log.sort().join()"), "a,b,c");
+}
+
+#[cfg(feature = "proposal-cleanup-some")]
+#[test]
+fn finalization_registry_cleanup_some() {
+    let (host_hooks, mut agent, realm) = create_agent();
+    run(
+        &mut agent,
+        &realm,
+        r#"
+        var log = [];
+        var registry = new FinalizationRegistry((held) => log.push("job " + held));
+        registry.register({}, "a");
+        registry.register({}, "b");
+        "#,
+    );
+    agent.gc();
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            registry.cleanupSome((held) => log.push("some " + held));
+            registry.cleanupSome();
+            log.sort().join()
+            "#
+        ),
+        "some a,some b"
+    );
+    // The cleanup Job enqueued by garbage collection finds nothing to do.
+    agent.perform_microtask_checkpoint(&realm);
+    assert_eq!(run(&mut agent, &realm, "log.length"), "2");
+    assert_eq!(
+        run(
+            &mut agent,
+            &realm,
+            r#"
+            var errors = [];
+            try { registry.cleanupSome(1); } catch (e) { errors.push(e.name); }
+            try { FinalizationRegistry.prototype.cleanupSome.call({}); } catch (e) { errors.push(e.name); }
+            errors.join()
+            "#
+        ),
+        "TypeError,TypeError"
+    );
+    assert!(host_hooks.job_errors.borrow().is_empty());
+}
@@ -209,43 +209,55 @@ impl SmallString {
             return Some(utf16_idx);
         }
         let mut current_utf16_index = 0;
-        let mut scratch = [0u16; 2];
-        for (idx, ch) in self.as_wtf8().code_points().enumerate() {
+        let mut current_utf8_index = 0;
+        for ch in self.as_wtf8().code_points() {
             match current_utf16_index.cmp(&utf16_idx) {
-                Ordering::Equal => return Some(idx),
+                Ordering::Equal => return Some(current_utf8_index),
                 Ordering::Greater => return None,
                 Ordering::Less => {
-                    current_utf16_index += ch
+                    // Lone surrogates are encoded as three bytes in WTF-8.
+                    let (utf16_len, utf8_len) = ch
                         .to_char()
-                        .map(|ch| ch.encode_utf16(&mut scratch).len())
-                        .unwrap_or(1)
+                        .map_or((1, 3), |ch| (ch.len_utf16(), ch.len_utf8()));
+                    current_utf16_index += utf16_len;
+                    current_utf8_index += utf8_len;
                 }
             }
         }
-        if current_utf16_index > utf16_idx {
+        if current_utf16_index != utf16_idx {
             return None;
         }
-        debug_assert_eq!(utf16_idx, current_utf16_index);
         Some(self.len())
     }
 
-    /// Get the corresponding WTF-16 code unit index for a given UTF-8 code
-    /// point index.
+    /// Get the corresponding WTF-16 code unit index for a given WTF-8 byte
+    /// index.
+    ///
+    /// # Panics
+    ///
+    /// If the byte index is out of bounds or does not fall on a code point
+    /// boundary.
     pub fn utf16_index(&self, utf8_idx: usize) -> usize {
         if self.is_ascii() {
             return utf8_idx;
         }
-        let mut utf16_idx = 0;
-        for (idx, ch) in self.to_string_lossy().char_indices() {
-            if idx == utf8_idx {
-                return utf16_idx;
+        let mut current_utf16_index = 0;
+        let mut current_utf8_index = 0;
+        for ch in self.as_wtf8().code_points() {
+            if current_utf8_index == utf8_idx {
+                return current_utf16_index;
             }
-            assert!(idx < utf8_idx);
-            utf16_idx += ch.len_utf16();
+            assert!(current_utf8_index < utf8_idx);
+            // Lone surrogates are encoded as three bytes in WTF-8.
+            let (utf16_len, utf8_len) = ch
+                .to_char()
+                .map_or((1, 3), |ch| (ch.len_utf16(), ch.len_utf8()));
+            current_utf16_index += utf16_len;
+            current_utf8_index += utf8_len;
         }
 
         assert_eq!(utf8_idx, self.len());
-        utf16_idx
+        current_utf16_index
     }
 
     /// Lossily convert the string to UTF-8.
@@ -525,6 +537,66 @@ impl SmallString {
             SmallString { bytes }
         }
     }
+
+    /// Create a [SmallString] from a sequence of WTF-16 code units.
+    ///
+    /// Surrogate pairs are joined into a single code point and lone
+    /// surrogates are preserved as-is. Returns `None` if the resulting
+    /// WTF-8 data is longer than 7 bytes.
+    pub fn from_utf16(code_units: &[u16]) -> Option<Self> {
+        // Every code unit takes at least one byte.
+        if code_units.len() > MAX_LEN {
+            return None;
+        }
+        Self::from_code_points(
+            code_units
+                .iter()
+                // SAFETY: u16 values are always valid code points.
+                .map(|&cu| unsafe { CodePoint::from_u32_unchecked(cu as u32) }),
+        )
+    }
+
+    /// Create a [SmallString] from a sequence of code points.
+    ///
+    /// A leading surrogate directly followed by a trailing surrogate is
+    /// joined into a single supplementary code point, as required by WTF-8.
+    /// Returns `None` if the resulting WTF-8 data is longer than 7 bytes.
+    pub fn from_code_points(code_points: impl IntoIterator<Item = CodePoint>) -> Option<Self> {
+        let mut bytes = [0xFFu8; MAX_LEN];
+        let mut len = 0;
+        for cp in code_points {
+            let mut cp = cp.to_u32();
+            if (0xDC00..=0xDFFF).contains(&cp)
+                && len >= 3
+                && matches!(bytes[len - 3..len], [0xED, 0xA0..=0xAF, _])
+            {
+                // Trailing surrogate following a leading surrogate: join the
+                // pair.
+                let lead =
+                    0xD000 | ((bytes[len - 2] as u32 & 0x3F) << 6) | (bytes[len - 1] as u32 & 0x3F);
+                bytes[len - 3..len].fill(0xFF);
+                len -= 3;
+                cp = 0x10000 + ((lead - 0xD800) << 10) + (cp - 0xDC00);
+            }
+            let cp_len = match cp {
+                0..0x80 => 1,
+                0x80..0x800 => 2,
+                0x800..0x10000 => 3,
+                _ => 4,
+            };
+            if len + cp_len > MAX_LEN {
+                return None;
+            }
+            let small = Self::from_code_point(
+                // SAFETY: cp is either the original code point or a joined
+                // surrogate pair, both of which are valid code points.
+                unsafe { CodePoint::from_u32_unchecked(cp) },
+            );
+            bytes[len..len + cp_len].copy_from_slice(&small.bytes[..cp_len]);
+            len += cp_len;
+        }
+        Some(SmallString { bytes })
+    }
 }
 
 impl TryFrom<&str> for SmallString {
@@ -561,6 +633,12 @@ impl From<char> for SmallString {
     }
 }
 
+impl From<CodePoint> for SmallString {
+    fn from(ch: CodePoint) -> Self {
+        Self::from_code_point(ch)
+    }
+}
+
 #[test]
 fn valid_stack_strings() {
     assert!(SmallString::try_from("").is_ok());
@@ -615,3 +693,77 @@ fn str_conversion() {
     assert!(SmallString::try_from(too_large_unicode).is_err());
     assert!(SmallString::try_from(Wtf8::from_str(too_large_unicode)).is_err());
 }
+
+#[test]
+fn surrogate_pairs() {
+    let str = SmallString::try_from("😀ab").unwrap();
+    assert_eq!(str.utf16_len(), 4);
+    assert_eq!(str.char_code_at(0).to_u32(), 0xD83D);
+    assert_eq!(str.char_code_at(1).to_u32(), 0xDE00);
+    assert_eq!(str.char_code_at(2).to_u32(), 'a' as u32);
+    assert_eq!(str.code_point_at(0).to_u32(), 0x1F600);
+    assert_eq!(str.code_point_at(1).to_u32(), 0xDE00);
+    assert_eq!(str.utf8_index(0), Some(0));
+    assert_eq!(str.utf8_index(1), None);
+    assert_eq!(str.utf8_index(2), Some(4));
+    assert_eq!(str.utf8_index(4), Some(6));
+    assert_eq!(str.utf8_index(5), None);
+    assert_eq!(str.utf16_index(0), 0);
+    assert_eq!(str.utf16_index(4), 2);
+    assert_eq!(str.utf16_index(6), 4);
+}
+
+#[test]
+fn lone_surrogates() {
+    let high = CodePoint::from_u32(0xD83D).unwrap();
+    let low = CodePoint::from_u32(0xDE00).unwrap();
+    let str = SmallString::from(high);
+    assert_eq!(str.len(), 3);
+    assert_eq!(str.utf16_len(), 1);
+    assert_eq!(str.char_code_at(0), high);
+    assert_eq!(str.code_point_at(0), high);
+    assert!(str.as_str().is_none());
+    assert_eq!(str.to_string_lossy(), "\u{FFFD}");
+
+    // A trailing surrogate followed by a leading surrogate does not form a
+    // pair.
+    let mut buf = wtf8::Wtf8Buf::new();
+    buf.push(low);
+    buf.push_char('a');
+    buf.push(high);
+    let str = SmallString::try_from(&*buf).unwrap();
+    assert_eq!(str.len(), 7);
+    assert_eq!(str.utf16_len(), 3);
+    assert_eq!(str.char_code_at(0), low);
+    assert_eq!(str.char_code_at(1).to_u32(), 'a' as u32);
+    assert_eq!(str.code_point_at(2), high);
+    assert_eq!(str.utf8_index(1), Some(3));
+    assert_eq!(str.utf8_index(2), Some(4));
+    assert_eq!(str.utf8_index(3), Some(7));
+    assert_eq!(str.utf16_index(3), 1);
+    assert_eq!(str.utf16_index(4), 2);
+    assert_eq!(str.utf16_index(7), 3);
+}
+
+#[test]
+fn from_utf16_and_code_points() {
+    let str = SmallString::from_utf16(&[0x61, 0xD83D, 0xDE00, 0x62]).unwrap();
+    assert_eq!(str, "a😀b");
+    // Lone surrogates are preserved, in any order.
+    let str = SmallString::from_utf16(&[0xDE00, 0xD83D]).unwrap();
+    assert_eq!(str.len(), 6);
+    assert_eq!(str.utf16_len(), 2);
+    assert_eq!(str.char_code_at(0).to_u32(), 0xDE00);
+    assert_eq!(str.char_code_at(1).to_u32(), 0xD83D);
+    assert!(SmallString::from_utf16(&[]).unwrap().is_empty());
+    assert!(SmallString::from_utf16(&[0x61; 8]).is_none());
+    assert!(SmallString::from_utf16(&[0xD800, 0xD800, 0xD800]).is_none());
+
+    let cps = [0xD83D, 0xDE00, 0xDE00].map(|cp| CodePoint::from_u32(cp).unwrap());
+    let str = SmallString::from_code_points(cps).unwrap();
+    assert_eq!(str.len(), 7);
+    assert_eq!(str.utf16_len(), 3);
+    assert_eq!(str.code_point_at(0).to_u32(), 0x1F600);
+    assert_eq!(str.char_code_at(2).to_u32(), 0xDE00);
+    assert!(SmallString::from_code_points(cps.into_iter().chain(cps)).is_none());
+}